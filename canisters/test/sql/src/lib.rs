@@ -11,6 +11,7 @@ use icydb::{
     db::{
         DynamicQuery, StructuralMutation, StructuralPatch, TypedAdapterError, TypedEntityAdapter,
         TypedRowError, TypedWriteAdapter, TypedWriteError, TypedWriteHandle, WriteCell,
+        query::TypedQueryError,
     },
     prelude::FieldRef,
     types::{Id, Ulid},
@@ -21,8 +22,8 @@ use icydb::{db::query::asc, value::OutputValue};
 use icydb_model::base::types::web::MimeType;
 use icydb_model::{Inner as _, NormalizeAndValidate as _, normalize, validate};
 use icydb_testing_test_sql_fixtures::sql::{
-    SqlTestEnrollmentRobotInsert, SqlTestEnrollmentUser, SqlTestEnrollmentUserInsert,
-    SqlTestEnrollmentUserPrincipalInsert,
+    SqlTestEnrollmentDevice, SqlTestEnrollmentRobotInsert, SqlTestEnrollmentUser,
+    SqlTestEnrollmentUserInsert, SqlTestEnrollmentUserPrincipalInsert,
 };
 
 icydb::start!();
@@ -829,5 +830,77 @@ fn measure_identity_closeout_perf() -> Result<IdentityCloseoutPerfResult, icydb:
     })
 }
 
+/// Read one typed device page with its optional enrollment owner attached.
+///
+/// Two devices share one owner and one device has none, so the page proves
+/// deduplicated target reads alongside a null relation key.
+#[update]
+fn verify_typed_relation_include() -> Result<Vec<(u64, String, Option<String>)>, icydb::Error> {
+    icydb::db::with_request_execution(|| {
+        let session = icydb::db!()?;
+        let owner_id = Id::<SqlTestEnrollmentUser>::generate().map_err(icydb::Error::from)?;
+        execute_one_typed(&session, enrollment_user_input(owner_id, "Device Owner"))?;
+        session.execute_trusted_structural_insert_batch(
+            "SqlTestEnrollmentDevice",
+            vec![
+                enrollment_device_patch(1, Some(owner_id), "Owned Phone"),
+                enrollment_device_patch(2, None, "Unassigned Tablet"),
+                enrollment_device_patch(3, Some(owner_id), "Owned Laptop"),
+            ],
+        )?;
+
+        let page = session
+            .query::<SqlTestEnrollmentDevice>()
+            .map_err(|error| match error {
+                icydb::db::TypedBindingError::Adapter(_) => typed_fixture_invariant_error(),
+                icydb::db::TypedBindingError::Database(error) => error,
+            })?
+            .order_by(icydb::db::query::asc("id"))
+            .limit(10)
+            .include::<SqlTestEnrollmentUser>("user_id")
+            .execute_live_page(None)
+            .map_err(|error| match error {
+                TypedQueryError::Database(error) => error,
+                TypedQueryError::Row(error) => typed_row_fixture_error(error),
+            })?;
+        if page.continuation.is_some() {
+            return Err(typed_fixture_invariant_error());
+        }
+
+        Ok(page
+            .rows
+            .into_iter()
+            .map(|included| {
+                (
+                    included.row.id,
+                    included.row.label,
+                    included.related.map(|owner| owner.display_name),
+                )
+            })
+            .collect())
+    })
+}
+
+fn enrollment_device_patch(
+    id: u64,
+    owner_id: Option<Id<SqlTestEnrollmentUser>>,
+    label: &str,
+) -> StructuralPatch {
+    let patch = StructuralPatch::new()
+        .field("id", WriteCell::Value(InputValue::Nat64(id)))
+        .field(
+            "label",
+            WriteCell::Value(InputValue::Text(label.to_string())),
+        );
+
+    match owner_id {
+        Some(owner_id) => patch.field(
+            "user_id",
+            WriteCell::Value(InputValue::Ulid(owner_id.key())),
+        ),
+        None => patch,
+    }
+}
+
 #[cfg(feature = "candid-export")]
 ic_cdk::export_candid!();
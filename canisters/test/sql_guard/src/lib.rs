@@ -110,7 +110,7 @@ fn read_authorization_cost() -> Result<ReadAuthorizationCostResult, icydb::Error
         let query_result = icydb::__macro::with_query_metrics_context(|| {
             icydb::db::with_request_execution(|| {
                 crate::__icydb_generated::endpoint_handlers::sql_query::<true>(
-                    "SHOW ENTITIES".to_string(),
+                    icydb::db::sql::SqlQueryRequest::new("SHOW ENTITIES"),
                 )
             })
         });
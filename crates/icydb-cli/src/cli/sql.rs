@@ -17,6 +17,8 @@ pub(crate) struct SqlShellFields {
     pub(crate) history_file: PathBuf,
     pub(crate) sql: Option<String>,
     pub(crate) trailing_sql: Vec<String>,
+    pub(crate) page: bool,
+    pub(crate) cursor: Option<String>,
 }
 
 ///
@@ -33,6 +35,8 @@ pub(crate) struct SqlShellFields {
     after_help = "Examples:
   icydb sql -c demo_rpg
   icydb sql -c demo_rpg --sql \"SELECT name FROM character LIMIT 5\"
+  icydb sql -c demo_rpg --page --sql \"SELECT name FROM character ORDER BY name LIMIT 5\"
+  icydb sql -c demo_rpg --cursor <NEXT_CURSOR> --sql \"SELECT name FROM character ORDER BY name LIMIT 5\"
   icydb sql -c demo_rpg --sql \"CREATE INDEX character_renown_idx ON character (renown)\"
   icydb sql -c demo_rpg --sql \"DROP INDEX character_renown_idx ON character\""
 )]
@@ -59,6 +63,14 @@ pub(crate) struct SqlArgs {
     #[arg(long, conflicts_with = "trailing_sql", value_name = "SQL")]
     sql: Option<String>,
 
+    /// Return one ordered SELECT as a resumable page with its next_cursor.
+    #[arg(long)]
+    page: bool,
+
+    /// Resume one ordered SELECT from a previously printed next_cursor.
+    #[arg(long, value_name = "CURSOR")]
+    cursor: Option<String>,
+
     /// SQL statement, including supported DDL, passed without --sql.
     #[arg(value_name = "SQL", allow_hyphen_values = true)]
     trailing_sql: Vec<String>,
@@ -72,6 +84,8 @@ impl SqlArgs {
             history_file: self.history_file,
             sql: self.sql,
             trailing_sql: self.trailing_sql,
+            page: self.page,
+            cursor: self.cursor,
         }
     }
 }
@@ -934,6 +934,20 @@ const fn query_projection_text(reason: QueryProjectionCode) -> &'static str {
         QueryProjectionCode::BinaryOperandsIncompatible => {
             "projection binary operator operands are incompatible"
         }
+        QueryProjectionCode::CastOverflow => "CAST value overflows the target type",
+        QueryProjectionCode::CastNegativeToUnsigned => {
+            "CAST cannot convert a negative value to an unsigned type"
+        }
+        QueryProjectionCode::CastPrecisionLoss => "CAST would lose precision",
+        QueryProjectionCode::CastNotRepresentable => {
+            "CAST value is not representable in the target type"
+        }
+        QueryProjectionCode::TemporalInputRequired => {
+            "temporal function input must be a timestamp or date"
+        }
+        QueryProjectionCode::PatternMatchStepLimit => {
+            "text pattern match exceeded its per-value step ceiling"
+        }
     }
 }
 
@@ -1052,6 +1066,12 @@ const fn sql_lowering_text(reason: SqlLoweringCode) -> &'static str {
         }
         SqlLoweringCode::WhereExpressionShape => "unsupported SQL WHERE expression shape",
         SqlLoweringCode::ParameterPlacement => "unsupported SQL parameter placement",
+        SqlLoweringCode::ParameterBinding => "SQL bind values do not match statement parameters",
+        SqlLoweringCode::SemiJoinShape => "unsupported SQL subquery shape",
+        SqlLoweringCode::RelationPathShape => "unsupported SQL relation path",
+        SqlLoweringCode::LimitPerShape => "unsupported SQL LIMIT ... PER placement",
+        SqlLoweringCode::UnionShape => "unsupported SQL UNION shape",
+        SqlLoweringCode::CteShape => "unsupported SQL WITH shape",
         SqlLoweringCode::SqlDdlExecutionUnsupported => {
             "SQL DDL execution is not supported in this release"
         }
@@ -1469,6 +1489,27 @@ const fn sql_write_boundary_text(boundary: SqlWriteBoundaryCode) -> &'static str
         SqlWriteBoundaryCode::ResumableUpdateContinuationOperationMismatch => {
             "resumable UPDATE continuation belongs to another application operation"
         }
+        SqlWriteBoundaryCode::UpdateComputedValueIncompatible => {
+            "UPDATE assignment expression result is not compatible with the target field type"
+        }
+        SqlWriteBoundaryCode::ResumableUpdateComputedAssignmentUnsupported => {
+            "resumable UPDATE requires fixed literal or DEFAULT assignments"
+        }
+        SqlWriteBoundaryCode::InsertConflictTargetNotUnique => {
+            "INSERT ON CONFLICT target must name the primary key or one unconditional unique index"
+        }
+        SqlWriteBoundaryCode::InsertConflictRequiresValues => {
+            "INSERT ON CONFLICT requires a VALUES source"
+        }
+        SqlWriteBoundaryCode::InsertConflictExcludedValueUnavailable => {
+            "INSERT ON CONFLICT DO UPDATE references an EXCLUDED field without a proposed value"
+        }
+        SqlWriteBoundaryCode::InsertConflictRowAffectedTwice => {
+            "INSERT ON CONFLICT DO UPDATE cannot affect the same row twice"
+        }
+        SqlWriteBoundaryCode::GeneratedValueIncompatible => {
+            "generated column expression result is not compatible with the column type"
+        }
     }
 }
 
@@ -1506,19 +1547,72 @@ const fn sql_feature_text(feature: SqlFeatureCode) -> &'static str {
         | SqlFeatureCode::DropIndexModifiers
         | SqlFeatureCode::DropIndexIfExistsSyntax
         | SqlFeatureCode::DropStatementBeyondDropIndex
-        | SqlFeatureCode::ExpressionIndexUnsupportedFunction => sql_ddl_feature_text(feature),
+        | SqlFeatureCode::ExpressionIndexUnsupportedFunction
+        | SqlFeatureCode::TextIndexKeyShape
+        | SqlFeatureCode::MultivalueIndexKeyShape
+        | SqlFeatureCode::CreateIndexIncludeShape => sql_ddl_feature_text(feature),
+        SqlFeatureCode::CastTargetType
+        | SqlFeatureCode::LikePatternBeyondTrailingPrefix
+        | SqlFeatureCode::LowerFieldPredicateUnsupported
+        | SqlFeatureCode::NestedAggregateInput
+        | SqlFeatureCode::NestedProjectionFunctionInArithmetic
+        | SqlFeatureCode::NumericScaleFunctionArguments
+        | SqlFeatureCode::PredicateStartsWithFirstArgument
+        | SqlFeatureCode::ScalarFunctionExpressionPosition
+        | SqlFeatureCode::ScaleTakingNumericFunctionExpressionPosition
+        | SqlFeatureCode::TemporalUnit
+        | SqlFeatureCode::TextPatternBeyondMatcherBounds
+        | SqlFeatureCode::TextMatchQueryShape
+        | SqlFeatureCode::UpperFieldPredicateUnsupported => sql_expression_feature_text(feature),
         SqlFeatureCode::ColumnAlias => "column or expression aliases",
         SqlFeatureCode::DescribeModifier => "DESCRIBE modifiers",
         SqlFeatureCode::Having => "HAVING",
         SqlFeatureCode::Insert => "INSERT",
         SqlFeatureCode::Join => "JOIN",
+        SqlFeatureCode::MultiStatementSql => "multi-statement SQL input",
+        SqlFeatureCode::OrderByFieldNotOrderable => {
+            "ORDER BY fields whose accepted catalog type is not orderable"
+        }
+        SqlFeatureCode::OrderByUnsupportedForm => "unsupported ORDER BY expression form",
+        SqlFeatureCode::Other => "unsupported SQL feature",
+        SqlFeatureCode::QuotedIdentifiers => "backtick-quoted identifiers",
+        SqlFeatureCode::ReturningUnsupportedShape => "unsupported RETURNING shape",
+        SqlFeatureCode::SearchedCaseGroupedOrderBy => {
+            "searched CASE in grouped ORDER BY expressions"
+        }
+        SqlFeatureCode::ShowColumnsModifiers
+        | SqlFeatureCode::ShowConstraintsModifiers
+        | SqlFeatureCode::ShowEntitiesModifiers
+        | SqlFeatureCode::ShowIndexesModifiers
+        | SqlFeatureCode::ShowMemoryModifiers
+        | SqlFeatureCode::ShowRelationsModifiers
+        | SqlFeatureCode::ShowStoresModifiers
+        | SqlFeatureCode::ShowUnsupportedCommand => sql_show_feature_text(feature),
+        SqlFeatureCode::SimpleCaseExpression => "simple CASE expressions",
+        SqlFeatureCode::StandaloneLiteralProjectionItem => "standalone literal projection items",
+        SqlFeatureCode::SupportedGroupedOrderByExpressionFamily => {
+            "unsupported grouped ORDER BY expression family"
+        }
+        SqlFeatureCode::SupportedOrderByExpressionFamily => {
+            "unsupported ORDER BY expression family"
+        }
+        SqlFeatureCode::UnionIntersectExcept => "UNION, INTERSECT, or EXCEPT",
+        SqlFeatureCode::UnsupportedFunctionNamespace => "unsupported SQL function namespace",
+        SqlFeatureCode::Update => "UPDATE",
+        SqlFeatureCode::WindowFunction => "window functions",
+        SqlFeatureCode::With => "WITH",
+    }
+}
+
+const fn sql_expression_feature_text(feature: SqlFeatureCode) -> &'static str {
+    match feature {
+        SqlFeatureCode::CastTargetType => "unsupported CAST target types",
         SqlFeatureCode::LikePatternBeyondTrailingPrefix => {
             "LIKE patterns beyond trailing '%' prefix form"
         }
         SqlFeatureCode::LowerFieldPredicateUnsupported => {
             "LOWER(field) predicate forms beyond LIKE 'prefix%' or ordered text bounds"
         }
-        SqlFeatureCode::MultiStatementSql => "multi-statement SQL input",
         SqlFeatureCode::NestedAggregateInput => {
             "nested aggregate references inside aggregate input expressions"
         }
@@ -1528,25 +1622,31 @@ const fn sql_feature_text(feature: SqlFeatureCode) -> &'static str {
         SqlFeatureCode::NumericScaleFunctionArguments => {
             "scale-taking numeric function arguments beyond supported literal integer scale"
         }
-        SqlFeatureCode::OrderByFieldNotOrderable => {
-            "ORDER BY fields whose accepted catalog type is not orderable"
-        }
-        SqlFeatureCode::OrderByUnsupportedForm => "unsupported ORDER BY expression form",
-        SqlFeatureCode::Other => "unsupported SQL feature",
         SqlFeatureCode::PredicateStartsWithFirstArgument => {
             "STARTS_WITH first argument forms beyond plain or LOWER field wrappers"
         }
-        SqlFeatureCode::QuotedIdentifiers => "quoted identifiers",
-        SqlFeatureCode::ReturningUnsupportedShape => "unsupported RETURNING shape",
         SqlFeatureCode::ScalarFunctionExpressionPosition => {
             "functions beyond supported scalar forms in this expression position"
         }
         SqlFeatureCode::ScaleTakingNumericFunctionExpressionPosition => {
             "scale-taking numeric functions in this expression position"
         }
-        SqlFeatureCode::SearchedCaseGroupedOrderBy => {
-            "searched CASE in grouped ORDER BY expressions"
+        SqlFeatureCode::TemporalUnit => "unsupported temporal units or INTERVAL literals",
+        SqlFeatureCode::TextPatternBeyondMatcherBounds => {
+            "LIKE or regular-expression patterns that are not literals, are malformed, or exceed matcher bounds"
+        }
+        SqlFeatureCode::TextMatchQueryShape => {
+            "MATCH queries that are not text literals, are empty or malformed, or exceed term bounds"
         }
+        SqlFeatureCode::UpperFieldPredicateUnsupported => {
+            "UPPER(field) in reduced predicate-only contracts"
+        }
+        _ => "unsupported SQL feature",
+    }
+}
+
+const fn sql_show_feature_text(feature: SqlFeatureCode) -> &'static str {
+    match feature {
         SqlFeatureCode::ShowColumnsModifiers => "SHOW COLUMNS modifiers",
         SqlFeatureCode::ShowConstraintsModifiers => "SHOW CONSTRAINTS modifiers",
         SqlFeatureCode::ShowEntitiesModifiers => "SHOW ENTITIES modifiers",
@@ -1555,22 +1655,7 @@ const fn sql_feature_text(feature: SqlFeatureCode) -> &'static str {
         SqlFeatureCode::ShowRelationsModifiers => "SHOW RELATIONS modifiers",
         SqlFeatureCode::ShowStoresModifiers => "SHOW STORES modifiers",
         SqlFeatureCode::ShowUnsupportedCommand => "unsupported SHOW command",
-        SqlFeatureCode::SimpleCaseExpression => "simple CASE expressions",
-        SqlFeatureCode::StandaloneLiteralProjectionItem => "standalone literal projection items",
-        SqlFeatureCode::SupportedGroupedOrderByExpressionFamily => {
-            "unsupported grouped ORDER BY expression family"
-        }
-        SqlFeatureCode::SupportedOrderByExpressionFamily => {
-            "unsupported ORDER BY expression family"
-        }
-        SqlFeatureCode::UnionIntersectExcept => "UNION, INTERSECT, or EXCEPT",
-        SqlFeatureCode::UnsupportedFunctionNamespace => "unsupported SQL function namespace",
-        SqlFeatureCode::Update => "UPDATE",
-        SqlFeatureCode::UpperFieldPredicateUnsupported => {
-            "UPPER(field) in reduced predicate-only contracts"
-        }
-        SqlFeatureCode::WindowFunction => "window functions",
-        SqlFeatureCode::With => "WITH",
+        _ => "unsupported SQL feature",
     }
 }
 
@@ -1642,6 +1727,15 @@ const fn sql_ddl_feature_text(feature: SqlFeatureCode) -> &'static str {
         SqlFeatureCode::ExpressionIndexUnsupportedFunction => {
             "expression index functions beyond LOWER, UPPER, and TRIM"
         }
+        SqlFeatureCode::TextIndexKeyShape => {
+            "USING TEXT indexes that are unique or not keyed by exactly one field"
+        }
+        SqlFeatureCode::MultivalueIndexKeyShape => {
+            "USING MULTIVALUE indexes that are unique or not keyed by exactly one field"
+        }
+        SqlFeatureCode::CreateIndexIncludeShape => {
+            "INCLUDE on unique or expression indexes, or INCLUDE lists that repeat a field"
+        }
         _ => "unsupported SQL feature",
     }
 }
@@ -26,8 +26,10 @@ pub(super) fn icp_query(
     canister: &str,
     method: &str,
     escaped_sql: &str,
+    page: bool,
+    escaped_cursor: Option<&str>,
 ) -> Result<Vec<u8>, String> {
-    let candid_arg = sql_candid_arg(escaped_sql);
+    let candid_arg = sql_query_candid_arg(escaped_sql, page, escaped_cursor);
     call_query_hex(
         environment,
         canister,
@@ -57,6 +59,23 @@ fn sql_candid_arg(escaped_sql: &str) -> String {
     format!("(\"{escaped_sql}\")")
 }
 
+// The query endpoint takes one request record. Shell statements carry no bind
+// values, and requests without a cursor spell it as an explicit Candid `null`.
+pub(super) fn sql_query_candid_arg(
+    escaped_sql: &str,
+    page: bool,
+    escaped_cursor: Option<&str>,
+) -> String {
+    let cursor = match escaped_cursor {
+        Some(escaped_cursor) => format!("opt \"{escaped_cursor}\""),
+        None => "null".to_string(),
+    };
+
+    format!(
+        "(record {{ sql = \"{escaped_sql}\"; params = vec {{}}; page = {page}; cursor = {cursor} }})"
+    )
+}
+
 fn sql_call_error(
     call_kind: &str,
     environment: &str,
@@ -38,6 +38,8 @@ pub(super) fn run_interactive_shell(config: &ShellConfig) -> Result<(), String>
                     config.environment.as_str(),
                     config.canister.as_str(),
                     sql.as_str(),
+                    false,
+                    None,
                 ) {
                     Ok(output) => {
                         print_successful_command_output(output.as_str());
@@ -34,6 +34,8 @@ struct ShellConfig {
     environment: String,
     history_file: PathBuf,
     sql: Option<String>,
+    page: bool,
+    cursor: Option<String>,
 }
 
 impl ShellConfig {
@@ -44,6 +46,8 @@ impl ShellConfig {
             history_file,
             sql,
             trailing_sql,
+            page,
+            cursor,
         } = args.into_shell_fields();
         let sql = sql.or_else(|| (!trailing_sql.is_empty()).then(|| trailing_sql.join(" ")));
         Self {
@@ -51,6 +55,8 @@ impl ShellConfig {
             environment,
             history_file,
             sql,
+            page: page || cursor.is_some(),
+            cursor,
         }
     }
 }
@@ -64,12 +70,20 @@ pub(crate) fn run_sql_command(args: SqlArgs) -> Result<(), String> {
             config.environment.as_str(),
             config.canister.as_str(),
             sql.as_str(),
+            config.page,
+            config.cursor.as_deref(),
         )?;
         print!(
             "{}",
             render::finalize_successful_command_output(output.as_str())
         );
     } else {
+        if config.cursor.is_some() {
+            return Err("--cursor requires one SQL statement to resume".to_string());
+        }
+        if config.page {
+            return Err("--page requires one SQL statement to page".to_string());
+        }
         require_created_canister(config.environment.as_str(), config.canister.as_str())?;
         interactive::run_interactive_shell(&config)?;
     }
@@ -77,15 +91,35 @@ pub(crate) fn run_sql_command(args: SqlArgs) -> Result<(), String> {
     Ok(())
 }
 
-fn execute_sql(environment: &str, canister: &str, sql: &str) -> Result<String, String> {
+fn execute_sql(
+    environment: &str,
+    canister: &str,
+    sql: &str,
+    page: bool,
+    cursor: Option<&str>,
+) -> Result<String, String> {
     let call_kind = route::sql_shell_call_kind(sql)?;
+    if cursor.is_some() && call_kind != route::SqlShellCallKind::Query {
+        return Err("--cursor only resumes SQL query statements".to_string());
+    }
+    if page && call_kind != route::SqlShellCallKind::Query {
+        return Err("--page only pages SQL query statements".to_string());
+    }
     let endpoint = sql_endpoint(call_kind);
     require_created_canister(environment, canister)?;
 
     let escaped_sql = call::candid_escape_string(sql);
     match call_kind {
         route::SqlShellCallKind::Query => {
-            execute_trusted_sql_query(environment, canister, endpoint, &escaped_sql)
+            let escaped_cursor = cursor.map(call::candid_escape_string);
+            execute_trusted_sql_query(
+                environment,
+                canister,
+                endpoint,
+                &escaped_sql,
+                page,
+                escaped_cursor.as_deref(),
+            )
         }
         route::SqlShellCallKind::Ddl | route::SqlShellCallKind::Update => {
             execute_trusted_sql_mutation_call(environment, canister, endpoint, &escaped_sql)
@@ -106,8 +140,17 @@ fn execute_trusted_sql_query(
     canister: &str,
     endpoint: Endpoint,
     escaped_sql: &str,
+    page: bool,
+    escaped_cursor: Option<&str>,
 ) -> Result<String, String> {
-    let candid_bytes = call::icp_query(environment, canister, endpoint.method(), escaped_sql)?;
+    let candid_bytes = call::icp_query(
+        environment,
+        canister,
+        endpoint.method(),
+        escaped_sql,
+        page,
+        escaped_cursor,
+    )?;
     let response = Decode!(
         candid_bytes.as_slice(),
         Result<icydb::db::sql::SqlQueryPerfResult, icydb::Error>
@@ -217,6 +260,14 @@ pub(crate) mod test_support {
         super::call::candid_escape_string(sql)
     }
 
+    pub(crate) fn sql_query_candid_arg(
+        escaped_sql: &str,
+        page: bool,
+        escaped_cursor: Option<&str>,
+    ) -> String {
+        super::call::sql_query_candid_arg(escaped_sql, page, escaped_cursor)
+    }
+
     pub(crate) fn finalize_successful_command_output(rendered: &str) -> String {
         super::render::finalize_successful_command_output(rendered)
     }
@@ -245,4 +296,10 @@ pub(crate) mod test_support {
             config.sql,
         )
     }
+
+    pub(crate) fn sql_shell_config_paging(args: super::SqlArgs) -> (bool, Option<String>) {
+        let config = super::ShellConfig::from_sql_args(args);
+
+        (config.page, config.cursor)
+    }
 }
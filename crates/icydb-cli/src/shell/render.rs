@@ -50,6 +50,15 @@ fn render_shell_text(
         SqlQueryResult::Projection(rows) => {
             render_projection_shell_text(rows, attribution, render_attribution)
         }
+        SqlQueryResult::ProjectionPage {
+            projection,
+            next_cursor,
+        } => render_projection_page_shell_text(
+            &projection,
+            next_cursor.as_str(),
+            attribution,
+            render_attribution,
+        ),
         SqlQueryResult::Grouped(rows) => {
             render_grouped_shell_text(rows, attribution, render_attribution)
         }
@@ -62,16 +71,34 @@ pub(super) fn render_projection_shell_text(
     attribution: Option<ShellPerfAttribution>,
     render_attribution: Option<ShellLocalRenderAttribution>,
 ) -> String {
+    let mut lines = projection_shell_lines(&rows);
+
+    render_shell_lines(&mut lines, attribution, render_attribution)
+}
+
+// Render one paged projection with its continuation cursor on the first line,
+// matching the grouped page layout.
+fn render_projection_page_shell_text(
+    rows: &RowProjectionOutput,
+    next_cursor: &str,
+    attribution: Option<ShellPerfAttribution>,
+    render_attribution: Option<ShellLocalRenderAttribution>,
+) -> String {
+    let mut lines = vec![format!("next_cursor={next_cursor}")];
+    lines.extend(projection_shell_lines(rows));
+
+    render_shell_lines(&mut lines, attribution, render_attribution)
+}
+
+fn projection_shell_lines(rows: &RowProjectionOutput) -> Vec<String> {
     let mut rendered_rows = rows.rendered_rows();
     uppercase_null_cells(rendered_rows.as_mut_slice());
 
-    let mut lines = render_projection_display_rows_lines(
+    render_projection_display_rows_lines(
         rows.columns.as_slice(),
         rendered_rows.as_slice(),
         rows.row_count,
-    );
-
-    render_shell_lines(&mut lines, attribution, render_attribution)
+    )
 }
 
 pub(super) fn render_grouped_shell_text(
@@ -12,7 +12,7 @@ use crate::{
         CanisterCommand, CliArgs, CliCommand, DEFAULT_ENVIRONMENT, SchemaCommand,
         SchemaMigrationCommand,
     },
-    shell::test_support::sql_shell_config_inputs,
+    shell::test_support::{sql_shell_config_inputs, sql_shell_config_paging},
 };
 
 #[test]
@@ -195,6 +195,47 @@ fn cli_args_accept_explicit_sql_option() {
     assert_eq!(sql.as_deref(), Some("SELECT name FROM character;"));
 }
 
+#[test]
+fn cli_args_accept_sql_continuation_cursor() {
+    let args = CliArgs::try_parse_from([
+        "icydb",
+        "sql",
+        "--canister",
+        "demo_rpg",
+        "--cursor",
+        "0a0b0c",
+        "--sql",
+        "SELECT name FROM character ORDER BY name LIMIT 5;",
+    ])
+    .expect("--cursor should parse");
+    let CliCommand::Sql(sql_args) = args.into_command() else {
+        panic!("expected sql command");
+    };
+
+    assert_eq!(
+        sql_shell_config_paging(sql_args),
+        (true, Some("0a0b0c".to_string())),
+    );
+}
+
+#[test]
+fn cli_args_keep_sql_queries_unpaged_unless_page_is_requested() {
+    let parse_paging = |extra: &[&str]| {
+        let mut command_line = vec!["icydb", "sql", "--canister", "demo_rpg"];
+        command_line.extend_from_slice(extra);
+        command_line.extend(["--sql", "SELECT name FROM character ORDER BY name LIMIT 5;"]);
+        let args = CliArgs::try_parse_from(command_line).expect("sql args should parse");
+        let CliCommand::Sql(sql_args) = args.into_command() else {
+            panic!("expected sql command");
+        };
+
+        sql_shell_config_paging(sql_args)
+    };
+
+    assert_eq!(parse_paging(&[]), (false, None));
+    assert_eq!(parse_paging(&["--page"]), (true, None));
+}
+
 #[test]
 fn cli_args_require_sql_target_canister() {
     let err = CliArgs::try_parse_from(["icydb", "sql", "SELECT * FROM character;"])
@@ -10,7 +10,7 @@ use crate::{
         finalize_successful_command_output, interactive_start_message, is_shell_exit_command,
         is_shell_help_command, normalize_shell_statement_line, render_grouped_shell_text,
         render_perf_suffix, render_projection_shell_text, shell_help_text, shell_perf_attribution,
-        sql_error_with_recovery_hint, sql_shell_call_kind,
+        sql_error_with_recovery_hint, sql_query_candid_arg, sql_shell_call_kind,
     },
 };
 use candid::{Decode, Encode};
@@ -243,6 +243,22 @@ fn candid_escape_string_escapes_sql_for_wire_arg() {
     );
 }
 
+#[test]
+fn sql_query_candid_arg_builds_request_record_with_optional_cursor() {
+    assert_eq!(
+        sql_query_candid_arg("SELECT name FROM Character", false, None),
+        "(record { sql = \"SELECT name FROM Character\"; params = vec {}; page = false; cursor = null })",
+    );
+    assert_eq!(
+        sql_query_candid_arg("SELECT name FROM Character", true, None),
+        "(record { sql = \"SELECT name FROM Character\"; params = vec {}; page = true; cursor = null })",
+    );
+    assert_eq!(
+        sql_query_candid_arg("SELECT name FROM Character", true, Some("0a0b")),
+        "(record { sql = \"SELECT name FROM Character\"; params = vec {}; page = true; cursor = opt \"0a0b\" })",
+    );
+}
+
 #[test]
 fn sql_shell_call_kind_routes_sql_to_fixed_endpoint_family() {
     for sql in [
@@ -10,7 +10,7 @@ use crate::{
             EncodedValue, IndexBoundsSpec, IndexId, IndexKeyKind, IndexRangeBoundEncodeError,
             RawIndexStoreKey, build_index_bounds_lowering_for_arity,
             build_index_component_range_with_encoded_prefix,
-            build_index_prefix_bounds_for_encoded_components,
+            build_index_prefix_bounds_for_encoded_components, descending_index_component,
             encode_accepted_index_literal_component, raw_keys_for_component_prefix_with_kind,
         },
         schema::SchemaInfo,
@@ -485,6 +485,7 @@ fn lower_index_range_bounds_for_scope(
             encoded_prefix,
             lower,
             upper,
+            index.key_descending_at(prefix.len()),
         )
         .map_err(validated_spec_not_indexable)?
     } else {
@@ -863,6 +864,11 @@ fn encode_index_component(
             .map_err(|_| InternalError::query_executor_invariant())?
             .into_bytes(),
     };
+    let bytes = if index.key_descending_at(component_index) {
+        descending_index_component(bytes)
+    } else {
+        bytes
+    };
 
     Ok(EncodedValue::from_canonical_bytes(bytes))
 }
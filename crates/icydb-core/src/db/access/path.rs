@@ -8,7 +8,9 @@ use crate::{
         Predicate,
         index::SemanticIndexExpression,
         predicate::{normalize, parse_sql_predicate},
-        schema::{SchemaExpressionIndexInfo, SchemaExpressionIndexKeyItemInfo},
+        schema::{
+            SchemaExpressionIndexInfo, SchemaExpressionIndexKeyItemInfo, SchemaIndexFieldPathInfo,
+        },
     },
     value::Value,
 };
@@ -56,6 +58,8 @@ pub(in crate::db::access) struct SemanticIndexAccessContractInner {
     pub(in crate::db::access) name: String,
     pub(in crate::db::access) store_path: String,
     pub(in crate::db::access) key_items: Vec<SemanticIndexKeyItem>,
+    pub(in crate::db::access) descending: Vec<bool>,
+    pub(in crate::db::access) include: Vec<String>,
     pub(in crate::db::access) unique: bool,
     pub(in crate::db::access) predicate_semantics: Option<Predicate>,
 }
@@ -64,6 +68,7 @@ pub(in crate::db::access) struct SemanticIndexAccessContractInner {
 pub(in crate::db) enum SemanticIndexKeyItemRef<'a> {
     Field(&'a str),
     AcceptedExpression(&'a SemanticIndexExpression),
+    Scalar(&'a str),
 }
 
 impl<'a> SemanticIndexKeyItemRef<'a> {
@@ -72,6 +77,7 @@ impl<'a> SemanticIndexKeyItemRef<'a> {
         match self {
             Self::Field(field) => field,
             Self::AcceptedExpression(expression) => expression.field(),
+            Self::Scalar(_) => "",
         }
     }
 
@@ -80,12 +86,13 @@ impl<'a> SemanticIndexKeyItemRef<'a> {
         match self {
             Self::Field(field) => field.to_string(),
             Self::AcceptedExpression(expression) => expression.canonical_order_text(),
+            Self::Scalar(canonical) => canonical.to_string(),
         }
     }
 
     #[must_use]
     pub(crate) const fn is_expression(self) -> bool {
-        matches!(self, Self::AcceptedExpression(_))
+        matches!(self, Self::AcceptedExpression(_) | Self::Scalar(_))
     }
 }
 
@@ -93,6 +100,7 @@ impl<'a> SemanticIndexKeyItemRef<'a> {
 pub(in crate::db) enum SemanticIndexKeyItem {
     Field(String),
     Expression(SemanticIndexExpression),
+    Scalar(String),
 }
 
 impl SemanticIndexKeyItem {
@@ -100,6 +108,7 @@ impl SemanticIndexKeyItem {
         match self {
             Self::Field(field) => SemanticIndexKeyItemRef::Field(field.as_str()),
             Self::Expression(expression) => SemanticIndexKeyItemRef::AcceptedExpression(expression),
+            Self::Scalar(canonical) => SemanticIndexKeyItemRef::Scalar(canonical.as_str()),
         }
     }
 }
@@ -111,6 +120,8 @@ impl PartialEq for SemanticIndexAccessContract {
             && self.inner.name == other.inner.name
             && self.inner.store_path == other.inner.store_path
             && self.inner.key_items == other.inner.key_items
+            && self.inner.descending == other.inner.descending
+            && self.inner.include == other.inner.include
             && self.inner.unique == other.inner.unique
             && self.inner.predicate_semantics == other.inner.predicate_semantics
     }
@@ -139,6 +150,16 @@ impl SemanticIndexAccessContract {
                         ))
                     })
                     .collect(),
+                descending: accepted
+                    .fields()
+                    .iter()
+                    .map(SchemaIndexFieldPathInfo::descending)
+                    .collect(),
+                include: accepted
+                    .include()
+                    .iter()
+                    .map(|field| accepted_field_path_term(field.field_name(), field.path()))
+                    .collect(),
                 unique: accepted.unique(),
                 predicate_semantics: accepted_index_predicate_semantics(accepted),
             }),
@@ -160,6 +181,8 @@ impl SemanticIndexAccessContract {
                     .iter()
                     .map(accepted_expression_key_item)
                     .collect(),
+                descending: vec![false; accepted.key_items().len()],
+                include: Vec::new(),
                 unique: accepted.unique(),
                 predicate_semantics: accepted_index_predicate_semantics_from_sql(
                     accepted.predicate_sql(),
@@ -199,6 +222,19 @@ impl SemanticIndexAccessContract {
         self.inner.key_items.len()
     }
 
+    /// Return whether the key component at `slot` is stored in descending
+    /// order.
+    #[must_use]
+    pub(in crate::db) fn key_descending_at(&self, slot: usize) -> bool {
+        self.inner.descending.get(slot).copied().unwrap_or(false)
+    }
+
+    /// Borrow the non-key `INCLUDE` payload fields, in stored order.
+    #[must_use]
+    pub(in crate::db) fn include(&self) -> &[String] {
+        self.inner.include.as_slice()
+    }
+
     #[must_use]
     pub(in crate::db) fn key_item_at(&self, slot: usize) -> Option<SemanticIndexKeyItemRef<'_>> {
         self.inner
@@ -211,7 +247,9 @@ impl SemanticIndexAccessContract {
     pub(in crate::db) fn key_field_at(&self, slot: usize) -> Option<&str> {
         match self.key_item_at(slot)? {
             SemanticIndexKeyItemRef::Field(field) => Some(field),
-            SemanticIndexKeyItemRef::AcceptedExpression(_) => None,
+            SemanticIndexKeyItemRef::AcceptedExpression(_) | SemanticIndexKeyItemRef::Scalar(_) => {
+                None
+            }
         }
     }
 
@@ -227,10 +265,12 @@ impl SemanticIndexAccessContract {
 
     #[must_use]
     pub(in crate::db) fn has_expression_key_items(&self) -> bool {
-        self.inner
-            .key_items
-            .iter()
-            .any(|item| matches!(item, SemanticIndexKeyItem::Expression(_)))
+        self.inner.key_items.iter().any(|item| {
+            matches!(
+                item,
+                SemanticIndexKeyItem::Expression(_) | SemanticIndexKeyItem::Scalar(_)
+            )
+        })
     }
 
     #[must_use]
@@ -253,6 +293,9 @@ fn accepted_expression_key_item(item: &SchemaExpressionIndexKeyItemInfo) -> Sema
                 ),
             ))
         }
+        SchemaExpressionIndexKeyItemInfo::Scalar(scalar) => {
+            SemanticIndexKeyItem::Scalar(scalar.canonical_text().to_string())
+        }
     }
 }
 
@@ -715,6 +758,8 @@ mod tests {
                         "email".to_string(),
                     )),
                 ],
+                descending: vec![false, false],
+                include: Vec::new(),
                 unique: false,
                 predicate_semantics: None,
             }),
@@ -358,6 +358,8 @@ mod tests {
                 name: "by_label".to_string(),
                 store_path: "test::Store".to_string(),
                 key_items: vec![SemanticIndexKeyItem::Field("label".to_string())],
+                descending: vec![false],
+                include: Vec::new(),
                 unique: false,
                 predicate_semantics: None,
             }),
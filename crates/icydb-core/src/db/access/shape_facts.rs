@@ -134,6 +134,15 @@ impl IndexShapeDetails {
         self.index.key_field_at(component_index)
     }
 
+    /// Return the stored direction of every key component after the
+    /// equality-bound slots.
+    #[must_use]
+    pub(in crate::db) fn suffix_key_descending(&self) -> Vec<bool> {
+        (self.slot_arity..self.key_arity())
+            .map(|component_index| self.index.key_descending_at(component_index))
+            .collect()
+    }
+
     #[must_use]
     pub(in crate::db) fn first_key_field(&self) -> Option<&str> {
         self.key_field_at(0)
@@ -7,7 +7,7 @@ use crate::{
     db::{
         access::{
             AccessPath, AccessPlan, MAX_INDEX_BRANCH_SET_VALUES, SemanticIndexAccessContract,
-            SemanticIndexRangeSpec,
+            SemanticIndexKeyItemRef, SemanticIndexRangeSpec,
         },
         schema::{SchemaInfo, literal_matches_type},
     },
@@ -239,6 +239,11 @@ fn validate_index_reference_with_schema(
 ) -> Result<(), AccessPlanError> {
     if (0..index.key_arity()).all(|slot| {
         index.key_item_at(slot).is_some_and(|key_item| {
+            // Scalar keys read several sources through accepted expression
+            // authority; only single-field keys resolve one indexed field.
+            if matches!(key_item, SemanticIndexKeyItemRef::Scalar(_)) {
+                return true;
+            }
             let field = key_item.field();
 
             schema.field(field).is_some() && schema.field_is_indexed(field)
@@ -16,7 +16,7 @@ use crate::{
         },
         index::{
             IndexDelta, IndexDeltaGroup, IndexEntryValue, IndexMembershipDelta, IndexMutationPlan,
-            IndexPlanReadView, IndexReadContract, IndexRowIdentity, IndexStore, RawIndexStoreKey,
+            IndexPlanReadView, IndexReadContract, IndexStore, RawIndexStoreKey,
             StructuralIndexEntryReader, StructuralPrimaryRowReader,
             plan_index_mutation_for_slot_reader_structural,
         },
@@ -792,20 +792,71 @@ fn build_commit_ops_for_index_group<C>(
 where
     C: crate::traits::CanisterKind,
 {
-    let mut remove_delta = None;
-    let mut insert_delta = None;
+    let mut remove_deltas = Vec::new();
+    let mut insert_deltas = Vec::new();
     let index_store = db
         .with_store_registry(|registry| registry.try_get_store(group.index_store.as_str()))
         .map(|store| store.index_store())?;
 
     for delta in group.deltas {
         match delta {
-            IndexDelta::Remove(delta) => remove_delta = Some(delta),
-            IndexDelta::Insert(delta) => insert_delta = Some(delta),
+            IndexDelta::Remove(delta) => remove_deltas.push(delta),
+            IndexDelta::Insert(delta) => insert_deltas.push(delta),
         }
     }
 
-    build_commit_ops_for_index_delta_pair(commit_ops, index_store, remove_delta, insert_delta)?;
+    if remove_deltas.len() > 1 || insert_deltas.len() > 1 {
+        return build_commit_ops_for_multi_entry_index_group(
+            commit_ops,
+            index_store,
+            remove_deltas,
+            insert_deltas,
+        );
+    }
+
+    build_commit_ops_for_index_delta_pair(
+        commit_ops,
+        index_store,
+        remove_deltas.pop(),
+        insert_deltas.pop(),
+    )?;
+
+    Ok(())
+}
+
+// Compute commit-time index operations for one multi-entry index transition.
+// Planning already dropped keys shared by the old and new rows, so every
+// remaining delta touches a distinct key; emit them in raw key order.
+fn build_commit_ops_for_multi_entry_index_group(
+    commit_ops: &mut Vec<PreparedIndexMutation>,
+    store: &'static LocalKey<RefCell<crate::db::index::IndexStore>>,
+    remove_deltas: Vec<IndexMembershipDelta>,
+    insert_deltas: Vec<IndexMembershipDelta>,
+) -> Result<(), InternalError> {
+    let mut touched: Vec<(
+        RawIndexStoreKey,
+        Option<IndexEntryValue>,
+        PreparedIndexMutationBuilder,
+    )> = Vec::with_capacity(remove_deltas.len().saturating_add(insert_deltas.len()));
+    for remove_delta in remove_deltas {
+        touched.push((
+            remove_delta.key.to_raw()?,
+            None,
+            PreparedIndexMutation::index_remove,
+        ));
+    }
+    for insert_delta in insert_deltas {
+        touched.push((
+            insert_delta.key.to_raw()?,
+            Some(insert_delta.value),
+            PreparedIndexMutation::index_insert,
+        ));
+    }
+    touched.sort_by(|(left, _, _), (right, _, _)| left.cmp(right));
+
+    for (raw_key, value, build_commit_op) in touched {
+        push_commit_op_for_index_entry(commit_ops, store, raw_key, value, build_commit_op);
+    }
 
     Ok(())
 }
@@ -817,12 +868,19 @@ fn build_commit_ops_for_index_delta_pair(
     remove_delta: Option<IndexMembershipDelta>,
     insert_delta: Option<IndexMembershipDelta>,
 ) -> Result<(), InternalError> {
-    // Phase 1: same-key transitions preserve membership and need no write.
-    if remove_delta
-        .as_ref()
-        .zip(insert_delta.as_ref())
-        .is_some_and(|(old_delta, new_delta)| old_delta.key == new_delta.key)
+    // Phase 1: same-key transitions preserve membership. They only rewrite
+    // the entry when the insertion carries a changed `INCLUDE` payload.
+    if let (Some(old_delta), Some(new_delta)) = (remove_delta.as_ref(), insert_delta.as_ref())
+        && old_delta.key == new_delta.key
     {
+        if new_delta.value != IndexEntryValue::presence() {
+            commit_ops.push(PreparedIndexMutation::unchanged(
+                store,
+                new_delta.key.to_raw()?,
+                Some(new_delta.value.clone()),
+            ));
+        }
+
         return Ok(());
     }
 
@@ -830,12 +888,12 @@ fn build_commit_ops_for_index_delta_pair(
     // deterministic key order without the general BTreeMap machinery.
     let mut first: Option<(
         RawIndexStoreKey,
-        Option<IndexRowIdentity>,
+        Option<IndexEntryValue>,
         PreparedIndexMutationBuilder,
     )> = None;
     let mut second: Option<(
         RawIndexStoreKey,
-        Option<IndexRowIdentity>,
+        Option<IndexEntryValue>,
         PreparedIndexMutationBuilder,
     )> = None;
 
@@ -854,7 +912,7 @@ fn build_commit_ops_for_index_delta_pair(
             &mut first,
             &mut second,
             insert_delta.key.to_raw()?,
-            Some(IndexRowIdentity::new(&insert_delta.primary_key)),
+            Some(insert_delta.value),
             PreparedIndexMutation::index_insert,
         );
     }
@@ -873,16 +931,16 @@ fn build_commit_ops_for_index_delta_pair(
 fn insert_commit_candidate(
     first: &mut Option<(
         RawIndexStoreKey,
-        Option<IndexRowIdentity>,
+        Option<IndexEntryValue>,
         PreparedIndexMutationBuilder,
     )>,
     second: &mut Option<(
         RawIndexStoreKey,
-        Option<IndexRowIdentity>,
+        Option<IndexEntryValue>,
         PreparedIndexMutationBuilder,
     )>,
     raw_key: RawIndexStoreKey,
-    entry: Option<IndexRowIdentity>,
+    entry: Option<IndexEntryValue>,
     build_commit_op: PreparedIndexMutationBuilder,
 ) {
     match first {
@@ -906,11 +964,9 @@ fn push_commit_op_for_index_entry(
     commit_ops: &mut Vec<PreparedIndexMutation>,
     store: &'static LocalKey<RefCell<crate::db::index::IndexStore>>,
     raw_key: RawIndexStoreKey,
-    entry: Option<IndexRowIdentity>,
+    value: Option<IndexEntryValue>,
     build_commit_op: PreparedIndexMutationBuilder,
 ) {
-    let value = entry.map(|_| IndexEntryValue::presence());
-
     commit_ops.push(build_commit_op(store, raw_key, value));
 }
 
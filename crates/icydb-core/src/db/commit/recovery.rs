@@ -1598,10 +1598,15 @@ fn apply_journal_record<C: CanisterKind>(
             })
         }
         JournalRecord::AcceptedSchemaIndexDelete { keys, .. } => {
-            apply_recovered_accepted_schema_index_chunk(expected_handle, keys, false, mode)
+            apply_recovered_accepted_schema_index_chunk(expected_handle, keys, None, mode)
         }
-        JournalRecord::AcceptedSchemaIndexPut { keys, .. } => {
-            apply_recovered_accepted_schema_index_chunk(expected_handle, keys, true, mode)
+        JournalRecord::AcceptedSchemaIndexPut { keys, values, .. } => {
+            apply_recovered_accepted_schema_index_chunk(
+                expected_handle,
+                keys,
+                Some(values.as_slice()),
+                mode,
+            )
         }
         JournalRecord::ConstraintValidationJobPut {
             entity_tag,
@@ -1750,10 +1755,12 @@ fn fold_recovered_row_transition<C: CanisterKind>(
     prepared.fold_recovered()
 }
 
+// Fold one accepted-schema index chunk. `insert` carries the put record's
+// entry values: empty for presence-only chunks, otherwise one per key.
 fn apply_recovered_accepted_schema_index_chunk(
     handle: StoreHandle,
     keys: &[crate::db::index::RawIndexStoreKey],
-    insert: bool,
+    insert: Option<&[IndexEntryValue]>,
     mode: JournalRecordApplyMode,
 ) -> Result<(), InternalError> {
     if mode != JournalRecordApplyMode::Fold
@@ -1763,11 +1770,14 @@ fn apply_recovered_accepted_schema_index_chunk(
         return Err(InternalError::store_corruption());
     }
     handle.with_index_mut(|store| {
-        for key in keys {
-            store.fold_recovered_journal_entry(
-                key.clone(),
-                insert.then(IndexEntryValue::presence),
-            )?;
+        for (position, key) in keys.iter().enumerate() {
+            let value = insert.map(|values| {
+                values
+                    .get(position)
+                    .cloned()
+                    .unwrap_or_else(IndexEntryValue::presence)
+            });
+            store.fold_recovered_journal_entry(key.clone(), value)?;
         }
         Ok::<(), InternalError>(())
     })
@@ -1956,6 +1966,7 @@ fn validate_journal_batch_record<C: CanisterKind>(
             entity_tag,
             accepted_after_fingerprint,
             keys,
+            ..
         } => validate_accepted_schema_index_chunk(
             expected_store_path,
             expected_handle,
@@ -3008,10 +3019,17 @@ fn verify_recovered_record<C: CanisterKind>(
             }
         }
         JournalRecord::AcceptedSchemaIndexPut {
-            store_path, keys, ..
+            store_path,
+            keys,
+            values,
+            ..
         } => {
-            for key in keys {
-                verify_recovered_index_put(db, store_path, key, verified)?;
+            for (position, key) in keys.iter().enumerate() {
+                let expected = values
+                    .get(position)
+                    .cloned()
+                    .unwrap_or_else(IndexEntryValue::presence);
+                verify_recovered_index_value(db, store_path, key, &expected, verified)?;
             }
         }
         JournalRecord::RowDelete {
@@ -3153,6 +3171,16 @@ fn verify_recovered_index_put<C: CanisterKind>(
     store_path: &str,
     key: &crate::db::index::RawIndexStoreKey,
     verified: &mut BTreeSet<RecoveredEffectIdentity>,
+) -> Result<(), InternalError> {
+    verify_recovered_index_value(db, store_path, key, &IndexEntryValue::presence(), verified)
+}
+
+fn verify_recovered_index_value<C: CanisterKind>(
+    db: &Db<C>,
+    store_path: &str,
+    key: &crate::db::index::RawIndexStoreKey,
+    expected: &IndexEntryValue,
+    verified: &mut BTreeSet<RecoveredEffectIdentity>,
 ) -> Result<(), InternalError> {
     let identity = RecoveredEffectIdentity::Index {
         store_path: store_path.to_string(),
@@ -3160,7 +3188,7 @@ fn verify_recovered_index_put<C: CanisterKind>(
     };
     if verified.insert(identity) {
         let (_, handle) = registry_store_handle_for_path(db, store_path)?;
-        if handle.with_index(|store| store.get(key)) != Some(IndexEntryValue::presence()) {
+        if handle.with_index(|store| store.get(key)).as_ref() != Some(expected) {
             return Err(InternalError::recovery_effect_verification_failed());
         }
     }
@@ -594,11 +594,22 @@ fn append_staged_schema_domain_journal_records(
             .final_entries()
             .chunks(MAX_ACCEPTED_SCHEMA_INDEX_KEYS_PER_RECORD)
         {
+            // Presence-only chunks keep the compact key-only record shape;
+            // chunks with covering payloads carry every entry value.
+            let values = if entries
+                .iter()
+                .all(|entry| entry.value() == &IndexEntryValue::presence())
+            {
+                Vec::new()
+            } else {
+                entries.iter().map(|entry| entry.value().clone()).collect()
+            };
             records.push(JournalRecord::accepted_schema_index_put(
                 store_path,
                 entity_tag,
                 fingerprint,
                 entries.iter().map(|entry| entry.key().clone()).collect(),
+                values,
             )?);
         }
     }
@@ -23,7 +23,7 @@ use crate::{
         },
         direction::Direction,
         index::IndexId,
-        query::plan::{CardinalityTiebreakRoutePin, OrderDirection},
+        query::plan::{CardinalityTiebreakRoutePin, NullsOrder, OrderDirection},
     },
     types::EntityTag,
     value::Value,
@@ -44,7 +44,8 @@ const PAGE_MODE_LIVE: u8 = 0;
 const PAGE_MODE_EXHAUSTIVE: u8 = 1;
 const ORDER_DIRECTION_ASC: u8 = 0;
 const ORDER_DIRECTION_DESC: u8 = 1;
-const NULL_COMPARISON_CANONICAL_V1: u8 = 1;
+const NULL_PLACEMENT_NATURAL: u8 = 1;
+const NULL_PLACEMENT_INVERTED: u8 = 2;
 const SCALAR_ROUTE_PIN_LAYOUT_CURRENT: u8 = 0xA6;
 const SLOT_MISSING: u8 = 0;
 const SLOT_PRESENT: u8 = 1;
@@ -217,7 +218,11 @@ fn write_scalar_payload(out: &mut Vec<u8>, token: &ScalarPageToken) -> Result<()
             OrderDirection::Asc => ORDER_DIRECTION_ASC,
             OrderDirection::Desc => ORDER_DIRECTION_DESC,
         });
-        out.push(NULL_COMPARISON_CANONICAL_V1);
+        out.push(if term.nulls().is_natural_for(term.direction()) {
+            NULL_PLACEMENT_NATURAL
+        } else {
+            NULL_PLACEMENT_INVERTED
+        });
     }
 
     let progress = token.progress();
@@ -272,10 +277,16 @@ fn read_scalar_payload(mut cursor: ByteCursor<'_>) -> Result<ScalarPageToken, To
             ORDER_DIRECTION_DESC => OrderDirection::Desc,
             _ => return Err(TokenWireError::decode()),
         };
-        if cursor.read_u8()? != NULL_COMPARISON_CANONICAL_V1 {
-            return Err(TokenWireError::decode());
-        }
-        order_terms.push(ScalarOrderTermContract::new(identity, direction));
+        let natural = NullsOrder::natural_for(direction);
+        let nulls = match cursor.read_u8()? {
+            NULL_PLACEMENT_NATURAL => natural,
+            NULL_PLACEMENT_INVERTED => match natural {
+                NullsOrder::First => NullsOrder::Last,
+                NullsOrder::Last => NullsOrder::First,
+            },
+            _ => return Err(TokenWireError::decode()),
+        };
+        order_terms.push(ScalarOrderTermContract::new(identity, direction, nulls));
     }
 
     let boundary_start = cursor.remaining();
@@ -572,7 +583,11 @@ mod tests {
                 2,
             ),
             ScalarPageTokenWindow::new(0, Some(2_048), 0x55),
-            vec![ScalarOrderTermContract::new("id", OrderDirection::Asc)],
+            vec![ScalarOrderTermContract::new(
+                "id",
+                OrderDirection::Asc,
+                NullsOrder::First,
+            )],
             ScalarPageTokenProgress::new(None, None, None, 0, 2),
         )
     }
@@ -6,7 +6,7 @@
 use crate::{
     db::{
         cursor::{ContinuationSignature, CursorBoundary, token::TokenWireError},
-        query::plan::{CardinalityTiebreakRoutePin, OrderDirection},
+        query::plan::{CardinalityTiebreakRoutePin, NullsOrder, OrderDirection},
     },
     types::EntityTag,
 };
@@ -31,14 +31,20 @@ pub(in crate::db) enum ScalarPageMode {
 pub(in crate::db) struct ScalarOrderTermContract {
     identity: String,
     direction: OrderDirection,
+    nulls: NullsOrder,
 }
 
 impl ScalarOrderTermContract {
     #[must_use]
-    pub(in crate::db) fn new(identity: impl Into<String>, direction: OrderDirection) -> Self {
+    pub(in crate::db) fn new(
+        identity: impl Into<String>,
+        direction: OrderDirection,
+        nulls: NullsOrder,
+    ) -> Self {
         Self {
             identity: identity.into(),
             direction,
+            nulls,
         }
     }
 
@@ -51,6 +57,11 @@ impl ScalarOrderTermContract {
     pub(in crate::db) const fn direction(&self) -> OrderDirection {
         self.direction
     }
+
+    #[must_use]
+    pub(in crate::db) const fn nulls(&self) -> NullsOrder {
+        self.nulls
+    }
 }
 
 /// Runtime and accepted-schema authority bound into every scalar token.
@@ -292,7 +303,7 @@ mod tests {
         db::{
             cursor::CursorBoundarySlot,
             index::IndexId,
-            query::plan::{CardinalityTiebreakFamily, OrderDirection},
+            query::plan::{CardinalityTiebreakFamily, NullsOrder, OrderDirection},
         },
         value::Value,
     };
@@ -312,9 +323,9 @@ mod tests {
             None,
             ScalarPageTokenWindow::new(0, Some(2_048), 0x55),
             vec![
-                ScalarOrderTermContract::new("score", OrderDirection::Desc),
-                ScalarOrderTermContract::new("nullable", OrderDirection::Asc),
-                ScalarOrderTermContract::new("id", OrderDirection::Desc),
+                ScalarOrderTermContract::new("score", OrderDirection::Desc, NullsOrder::Last),
+                ScalarOrderTermContract::new("nullable", OrderDirection::Asc, NullsOrder::Last),
+                ScalarOrderTermContract::new("id", OrderDirection::Desc, NullsOrder::Last),
             ],
             ScalarPageTokenProgress::new(
                 Some(CursorBoundary {
@@ -400,6 +411,7 @@ mod tests {
             order_terms.push(ScalarOrderTermContract::new(
                 format!("field_{index}"),
                 OrderDirection::Asc,
+                NullsOrder::First,
             ));
         }
         let mut token = token();
@@ -6,6 +6,11 @@ use crate::db::codec::{
 use crate::db::data::CanonicalSlotReader;
 #[cfg(feature = "sql")]
 use crate::db::data::persisted_row::types::FieldSlot;
+#[cfg(feature = "sql")]
+use crate::db::{
+    executor::eval_compiled_scalar_expr_with_row_values,
+    schema::input_value_from_computed_sql_value_for_persisted_kind,
+};
 use crate::{
     db::schema::{FieldInsertGeneration, FieldWriteManagement},
     db::{
@@ -30,6 +35,7 @@ use crate::{
         schema::{
             AcceptedFieldPersistenceContract, AcceptedIdentityAllocation,
             AcceptedInsertOmissionPolicy, AcceptedRowDecodeContract,
+            CompiledAcceptedGeneratedField, CompiledAcceptedGeneratedFields,
             CompiledAcceptedRowConstraints, accepted_row_constraint_write_error,
             enum_catalog::{ValueAdmissionBudget, ValueAdmissionError},
         },
@@ -89,6 +95,8 @@ pub(in crate::db) enum AcceptedFieldWriteProvenance {
     InsertManaged(AcceptedInsertPolicyRequest),
     /// Accepted update-management policy evaluated for this after-image.
     UpdateManaged,
+    /// Accepted generated-column expression evaluated over this after-image.
+    Computed,
     /// Existing logical value preserved by an unassigned update field.
     Preserved,
     /// Database-owned primary-key value preserved by keyed replacement.
@@ -477,6 +485,11 @@ fn resolve_insert_active_slot(
     };
 
     if let Some(generation) = write_policy.insert_generation() {
+        if generation == FieldInsertGeneration::Computed {
+            // The generated-field phase replaces this placeholder once every
+            // source slot of the after-image has resolved.
+            return Ok((Vec::new(), AcceptedFieldWriteProvenance::Computed));
+        }
         let value =
             accepted_insert_generated_value(generation, slot, write_context, identity_allocation)?;
         let encoding = contract.required_accepted_field_persistence_contract(slot)?;
@@ -521,6 +534,92 @@ fn resolve_insert_active_slot(
     Ok((contract.insert_omission_payload(slot)?, provenance))
 }
 
+// Recompute every accepted generated field over the otherwise-resolved
+// after-image. Generated fields never read each other, so one pass in slot
+// order is exact. A value equal to the slot's current payload keeps that
+// slot's provenance, so an unchanged generated value still reads as preserved.
+fn resolve_generated_slots(
+    constraint_context: AcceptedRowConstraintWriteContext<'_>,
+    contract: &StructuralRowContract,
+    payloads: &mut [Option<Vec<u8>>],
+    provenance: &mut [Option<AcceptedFieldWriteProvenance>],
+) -> Result<(), InternalError> {
+    for generated in constraint_context.constraints.generated_fields().fields() {
+        let slot = generated.slot();
+        let input = generated_field_input(contract, generated, payloads)?;
+        let encoding = contract.required_accepted_field_persistence_contract(slot)?;
+        let payload = encode_authored_value_for_accepted_field_contract(
+            constraint_context,
+            slot,
+            encoding,
+            input,
+        )?;
+        let current = payloads
+            .get_mut(slot)
+            .ok_or_else(InternalError::persisted_row_encode_internal)?;
+        if current.as_deref() != Some(payload.as_slice()) {
+            *current = Some(payload);
+            provenance[slot] = Some(AcceptedFieldWriteProvenance::Computed);
+        }
+    }
+
+    Ok(())
+}
+
+// Evaluate one generated expression over decoded source slots and admit the
+// result for the generated field's accepted kind.
+#[cfg(feature = "sql")]
+fn generated_field_input(
+    contract: &StructuralRowContract,
+    generated: &CompiledAcceptedGeneratedField,
+    payloads: &[Option<Vec<u8>>],
+) -> Result<InputValue, InternalError> {
+    let expression = generated
+        .expression()
+        .ok_or_else(InternalError::accepted_row_constraint_program_corrupt)?;
+    let sources = generated
+        .source_slots()
+        .iter()
+        .map(|slot| {
+            let bytes = payloads
+                .get(*slot)
+                .and_then(Option::as_deref)
+                .ok_or_else(InternalError::persisted_row_encode_internal)?;
+            let value =
+                crate::db::data::decode_runtime_value_from_row_contract(contract, *slot, bytes)?;
+
+            Ok((*slot, value))
+        })
+        .collect::<Result<Vec<_>, InternalError>>()?;
+    let value = eval_compiled_scalar_expr_with_row_values(expression, &mut |slot| {
+        sources
+            .iter()
+            .find_map(|(source_slot, value)| (*source_slot == slot).then_some(value))
+    })?;
+    if matches!(value, Value::Null) {
+        return Ok(InputValue::Null);
+    }
+    let field = contract.required_accepted_field_contract(generated.slot())?;
+
+    input_value_from_computed_sql_value_for_persisted_kind(field.decode_contract().kind(), &value)
+        .ok_or_else(|| {
+            InternalError::query_sql_write_boundary(
+                icydb_diagnostic_code::SqlWriteBoundaryCode::GeneratedValueIncompatible,
+            )
+        })
+}
+
+// Builds without the SQL frontend cannot bind generated expressions, so any
+// write that reaches a generated field fails closed.
+#[cfg(not(feature = "sql"))]
+fn generated_field_input(
+    _contract: &StructuralRowContract,
+    _generated: &CompiledAcceptedGeneratedField,
+    _payloads: &[Option<Vec<u8>>],
+) -> Result<InputValue, InternalError> {
+    Err(InternalError::executor_unsupported())
+}
+
 /// Resolve one sparse insert patch through accepted insertion authority.
 ///
 /// Authored inputs remain distinct from omission, while accepted generation,
@@ -585,6 +684,14 @@ pub(in crate::db) fn resolve_insert_structural_patch_with_accepted_contract(
         provenance[slot] = Some(source);
     }
 
+    // Phase 3: recompute generated fields over the resolved after-image.
+    resolve_generated_slots(
+        constraint_context,
+        &contract,
+        &mut payloads,
+        &mut provenance,
+    )?;
+
     let slot_payloads = payloads
         .into_iter()
         .map(|payload| payload.ok_or_else(InternalError::persisted_row_encode_internal))
@@ -731,12 +838,25 @@ pub(in crate::db) fn resolve_update_structural_patch_with_accepted_contract(
         });
     }
 
-    // Phase 3: compare canonical logical values without letting the managed
-    // timestamp manufacture a change. Historical values are encoded through
-    // their accepted current contract before comparison.
+    // Phase 3: recompute generated fields over the candidate, keeping the
+    // preserved provenance of any generated value that did not change.
+    resolve_generated_slots(
+        constraint_context,
+        &contract,
+        &mut payloads,
+        &mut provenance,
+    )?;
+
+    // Phase 4: compare canonical logical values without letting the managed
+    // timestamp or derived generated values manufacture a change. Historical
+    // values are encoded through their accepted current contract first.
+    let generated_fields = constraints.generated_fields();
     let mut logical_changed = false;
     for (slot, payload) in payloads.iter().enumerate() {
-        if !contract.has_active_field_slot(slot) || updated_at_slot == Some(slot) {
+        if !contract.has_active_field_slot(slot)
+            || updated_at_slot == Some(slot)
+            || generated_fields.contains_slot(slot)
+        {
             continue;
         }
         let before = baseline.required_cached_value(slot)?;
@@ -748,7 +868,7 @@ pub(in crate::db) fn resolve_update_structural_patch_with_accepted_contract(
         }
     }
 
-    // Phase 4: refresh `UpdatedAt` only for a real logical row change. The
+    // Phase 5: refresh `UpdatedAt` only for a real logical row change. The
     // accepted clock contract is fail-closed: restored future timestamps are
     // preserved and block a write that would move managed time backward.
     if logical_changed && let Some(slot) = updated_at_slot {
@@ -859,20 +979,13 @@ pub(in crate::db) fn resolve_existing_replace_structural_patch_with_accepted_con
         )?);
     }
 
-    let mut logical_changed = false;
-    for (slot, payload) in payloads.iter().enumerate() {
-        if !contract.has_active_field_slot(slot) || updated_at_slot == Some(slot) {
-            continue;
-        }
-        let before = baseline.required_cached_value(slot)?;
-        let encoding = contract.required_accepted_field_persistence_contract(slot)?;
-        let before = encode_canonical_value_for_accepted_field_contract(encoding, before)?;
-        if payload.as_deref() != Some(before.as_slice()) {
-            logical_changed = true;
-            break;
-        }
-    }
-
+    let logical_changed = replacement_logically_changed(
+        &contract,
+        &baseline,
+        payloads.as_slice(),
+        updated_at_slot,
+        constraints.generated_fields(),
+    )?;
     if logical_changed && let Some(slot) = updated_at_slot {
         validate_managed_timestamp_progression(
             &contract,
@@ -890,6 +1003,20 @@ pub(in crate::db) fn resolve_existing_replace_structural_patch_with_accepted_con
         validate_existing_managed_timestamp_order(&contract, &baseline, mutation_context)?;
     }
 
+    // Managed timestamps may feed generated fields, so recompute them over
+    // the merged replacement rather than the fresh insert image.
+    resolve_generated_slots(
+        AcceptedRowConstraintWriteContext::new(
+            accepted_schema_fingerprint,
+            mutation_context.entity_tag(),
+            Some(mutation_context),
+            constraints,
+        ),
+        &contract,
+        &mut payloads,
+        &mut provenance,
+    )?;
+
     let slot_payloads = payloads
         .into_iter()
         .map(|payload| payload.ok_or_else(InternalError::persisted_row_encode_internal))
@@ -903,6 +1030,34 @@ pub(in crate::db) fn resolve_existing_replace_structural_patch_with_accepted_con
     Ok(ResolvedAcceptedMutationRow::new(row, provenance))
 }
 
+// Report whether one merged replacement differs from its before-image in any
+// authored slot. The refreshed `UpdatedAt` slot and generated slots follow
+// from the other slots, so they never count as a logical change themselves.
+fn replacement_logically_changed(
+    contract: &StructuralRowContract,
+    baseline: &StructuralSlotReader<'_>,
+    payloads: &[Option<Vec<u8>>],
+    updated_at_slot: Option<usize>,
+    generated_fields: &CompiledAcceptedGeneratedFields,
+) -> Result<bool, InternalError> {
+    for (slot, payload) in payloads.iter().enumerate() {
+        if !contract.has_active_field_slot(slot)
+            || updated_at_slot == Some(slot)
+            || generated_fields.contains_slot(slot)
+        {
+            continue;
+        }
+        let before = baseline.required_cached_value(slot)?;
+        let encoding = contract.required_accepted_field_persistence_contract(slot)?;
+        let before = encode_canonical_value_for_accepted_field_contract(encoding, before)?;
+        if payload.as_deref() != Some(before.as_slice()) {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
 fn validate_managed_timestamp_progression(
     contract: &StructuralRowContract,
     baseline: &StructuralSlotReader<'_>,
@@ -1042,6 +1197,7 @@ fn accepted_insert_generated_value(
         }
         FieldInsertGeneration::Ulid => Value::Ulid(Ulid::generate()?),
         FieldInsertGeneration::Timestamp => Value::Timestamp(write_context.operation_timestamp()),
+        FieldInsertGeneration::Computed => return Err(InternalError::executor_invariant()),
     })
 }
 
@@ -0,0 +1,401 @@
+//! Module: executor::aggregate::collection_reducer
+//! Responsibility: shared retained-value aggregate reducer semantics.
+//! Does not own: row access, FILTER admission, retained-value budgets, or grouped keys.
+//! Boundary: PERCENTILE_DISC, STRING_AGG, and ARRAY_AGG state transitions.
+
+// Single source of truth for retained-value aggregate semantics:
+// PERCENTILE_DISC, STRING_AGG, ARRAY_AGG.
+//
+// Scalar and grouped execution paths both delegate here. Callers charge the
+// retained-value budget before `ingest` so the reducer never grows past the
+// admitted per-group and total limits.
+
+use crate::{
+    db::{
+        numeric::canonical_value_compare,
+        query::plan::{AggregateArguments, AggregateKind, NullsOrder, OrderDirection},
+    },
+    error::InternalError,
+    types::Decimal,
+    value::Value,
+};
+use std::cmp::Ordering;
+
+///
+/// CollectionReducerMode
+///
+/// Finalization mode for one retained-value aggregate. The mode owns only the
+/// non-input arguments; aggregate-local ORDER BY keys travel beside each
+/// retained value.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum CollectionReducerMode {
+    PercentileDisc {
+        fraction: Decimal,
+        direction: OrderDirection,
+    },
+    StringAgg {
+        separator: String,
+    },
+    ArrayAgg,
+}
+
+///
+/// CollectionReducerSpec
+///
+/// Immutable retained-value aggregate contract shared by every group state.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(in crate::db::executor::aggregate) struct CollectionReducerSpec {
+    mode: CollectionReducerMode,
+    order: Vec<(OrderDirection, NullsOrder)>,
+}
+
+impl CollectionReducerSpec {
+    /// Build one retained-value reducer contract from planner arguments.
+    pub(in crate::db::executor::aggregate) fn from_arguments(
+        kind: AggregateKind,
+        arguments: Option<&AggregateArguments>,
+    ) -> Result<Self, InternalError> {
+        let mode = match (kind, arguments) {
+            (
+                AggregateKind::PercentileDisc,
+                Some(AggregateArguments::Percentile {
+                    fraction,
+                    direction,
+                }),
+            ) => CollectionReducerMode::PercentileDisc {
+                fraction: *fraction,
+                direction: *direction,
+            },
+            (AggregateKind::StringAgg, Some(AggregateArguments::StringAgg { separator, .. })) => {
+                CollectionReducerMode::StringAgg {
+                    separator: separator.clone(),
+                }
+            }
+            (AggregateKind::ArrayAgg, Some(AggregateArguments::ArrayAgg { .. }) | None) => {
+                CollectionReducerMode::ArrayAgg
+            }
+            _ => return Err(InternalError::query_executor_invariant()),
+        };
+        let order = arguments
+            .map(AggregateArguments::order_by)
+            .unwrap_or_default()
+            .iter()
+            .map(|term| (term.direction(), term.nulls()))
+            .collect();
+
+        Ok(Self { mode, order })
+    }
+
+    /// Return the number of aggregate-local ORDER BY keys each value carries.
+    #[must_use]
+    pub(in crate::db::executor::aggregate) const fn order_key_count(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Return whether one input value is retained. PERCENTILE_DISC and
+    /// STRING_AGG ignore NULL inputs; ARRAY_AGG keeps them.
+    #[must_use]
+    pub(in crate::db::executor::aggregate) const fn retains(&self, value: &Value) -> bool {
+        matches!(self.mode, CollectionReducerMode::ArrayAgg) || !matches!(value, Value::Null)
+    }
+}
+
+///
+/// CollectionReducerState
+///
+/// Retained values plus their evaluated aggregate-local ORDER BY keys.
+/// Arrival order is kept so equal sort keys stay deterministic under the
+/// stable sort applied at finalization.
+///
+
+pub(in crate::db::executor::aggregate) struct CollectionReducerState {
+    spec: CollectionReducerSpec,
+    entries: Vec<(Value, Vec<Value>)>,
+}
+
+impl CollectionReducerState {
+    #[must_use]
+    pub(in crate::db::executor::aggregate) const fn new(spec: CollectionReducerSpec) -> Self {
+        Self {
+            spec,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Borrow the immutable reducer contract.
+    #[must_use]
+    pub(in crate::db::executor::aggregate) const fn spec(&self) -> &CollectionReducerSpec {
+        &self.spec
+    }
+
+    /// Return the number of values retained so far.
+    #[must_use]
+    pub(in crate::db::executor::aggregate) const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Retain one admitted input value with its aggregate-local ORDER BY keys.
+    pub(in crate::db::executor::aggregate) fn ingest(
+        &mut self,
+        value: Value,
+        order_keys: Vec<Value>,
+    ) -> Result<(), InternalError> {
+        if order_keys.len() != self.spec.order_key_count() {
+            return Err(InternalError::query_executor_invariant());
+        }
+        if !self.spec.retains(&value) {
+            return Ok(());
+        }
+        if matches!(self.spec.mode, CollectionReducerMode::StringAgg { .. })
+            && !matches!(value, Value::Text(_))
+        {
+            return Err(InternalError::query_executor_invariant());
+        }
+
+        self.entries.push((value, order_keys));
+
+        Ok(())
+    }
+
+    /// Consume this reducer into the canonical structural aggregate value.
+    pub(in crate::db::executor::aggregate) fn into_final_value(
+        self,
+    ) -> Result<Value, InternalError> {
+        let Self { spec, mut entries } = self;
+        if entries.is_empty() {
+            return Ok(Value::Null);
+        }
+
+        match spec.mode {
+            CollectionReducerMode::PercentileDisc {
+                fraction,
+                direction,
+            } => {
+                entries.sort_by(|(left, _), (right, _)| {
+                    apply_direction(canonical_value_compare(left, right), direction)
+                });
+                let index = percentile_disc_index(fraction, entries.len())?;
+
+                Ok(entries.swap_remove(index).0)
+            }
+            CollectionReducerMode::StringAgg { separator } => {
+                sort_entries_by_order_keys(&mut entries, spec.order.as_slice());
+                let mut joined = String::new();
+                for (position, (value, _)) in entries.into_iter().enumerate() {
+                    let Value::Text(text) = value else {
+                        return Err(InternalError::query_executor_invariant());
+                    };
+                    if position > 0 {
+                        joined.push_str(separator.as_str());
+                    }
+                    joined.push_str(text.as_str());
+                }
+
+                Ok(Value::Text(joined))
+            }
+            CollectionReducerMode::ArrayAgg => {
+                sort_entries_by_order_keys(&mut entries, spec.order.as_slice());
+
+                Ok(Value::List(
+                    entries.into_iter().map(|(value, _)| value).collect(),
+                ))
+            }
+        }
+    }
+}
+
+const fn apply_direction(ordering: Ordering, direction: OrderDirection) -> Ordering {
+    match direction {
+        OrderDirection::Asc => ordering,
+        OrderDirection::Desc => ordering.reverse(),
+    }
+}
+
+// Stable-sort retained values by their aggregate-local ORDER BY keys using the
+// same null placement contract as top-level ORDER BY. Without ORDER BY terms
+// the arrival order is preserved unchanged.
+fn sort_entries_by_order_keys(
+    entries: &mut [(Value, Vec<Value>)],
+    order: &[(OrderDirection, NullsOrder)],
+) {
+    if order.is_empty() {
+        return;
+    }
+
+    entries.sort_by(|(_, left_keys), (_, right_keys)| {
+        for ((left, right), (direction, nulls)) in
+            left_keys.iter().zip(right_keys.iter()).zip(order.iter())
+        {
+            let ordering = match (left, right) {
+                (Value::Null, Value::Null) => Ordering::Equal,
+                (Value::Null, _) => nulls.null_before_present_ordering(),
+                (_, Value::Null) => nulls.null_before_present_ordering().reverse(),
+                (left, right) => apply_direction(canonical_value_compare(left, right), *direction),
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+
+        Ordering::Equal
+    });
+}
+
+// Resolve the discrete percentile position as the first sorted value whose
+// cumulative distribution reaches `fraction`, i.e. `ceil(fraction * n) - 1`
+// clamped onto the retained range.
+fn percentile_disc_index(fraction: Decimal, count: usize) -> Result<usize, InternalError> {
+    let count_decimal =
+        Decimal::from_num(count as u64).ok_or_else(InternalError::query_executor_invariant)?;
+    let position = fraction
+        .checked_mul(count_decimal)
+        .ok_or_else(InternalError::query_executor_invariant)?;
+    let scale_divisor = 10_i128
+        .checked_pow(position.scale())
+        .ok_or_else(InternalError::query_executor_invariant)?;
+    let mantissa = position.mantissa();
+    let mut rank = mantissa / scale_divisor;
+    if mantissa % scale_divisor != 0 {
+        rank += 1;
+    }
+    let rank =
+        usize::try_from(rank.max(1)).map_err(|_| InternalError::query_executor_invariant())?;
+
+    Ok(rank.min(count) - 1)
+}
+
+///
+/// TESTS
+///
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        db::{
+            executor::aggregate::collection_reducer::{
+                CollectionReducerSpec, CollectionReducerState,
+            },
+            query::plan::{
+                AggregateArguments, AggregateKind, NullsOrder, OrderDirection, OrderTerm,
+                expr::{Expr, FieldId},
+            },
+        },
+        types::Decimal,
+        value::Value,
+    };
+
+    fn decimal(value: &str) -> Decimal {
+        value.parse().expect("decimal literal")
+    }
+
+    fn state(kind: AggregateKind, arguments: &AggregateArguments) -> CollectionReducerState {
+        CollectionReducerState::new(
+            CollectionReducerSpec::from_arguments(kind, Some(arguments))
+                .expect("collection reducer spec"),
+        )
+    }
+
+    fn order_term(direction: OrderDirection, nulls: NullsOrder) -> OrderTerm {
+        OrderTerm::new(Expr::Field(FieldId::new("rank")), direction).with_nulls(nulls)
+    }
+
+    #[test]
+    fn percentile_disc_selects_first_value_reaching_fraction() {
+        let percentile = |fraction: &str, direction| {
+            let mut reducer = state(
+                AggregateKind::PercentileDisc,
+                &AggregateArguments::Percentile {
+                    fraction: decimal(fraction),
+                    direction,
+                },
+            );
+            for value in [40, 10, 30, 20] {
+                reducer
+                    .ingest(Value::Int64(value), Vec::new())
+                    .expect("percentile ingest");
+            }
+            reducer
+                .ingest(Value::Null, Vec::new())
+                .expect("percentile ignores null");
+
+            reducer.into_final_value().expect("percentile finalize")
+        };
+
+        assert_eq!(percentile("0.5", OrderDirection::Asc), Value::Int64(20));
+        assert_eq!(percentile("0", OrderDirection::Asc), Value::Int64(10));
+        assert_eq!(percentile("1", OrderDirection::Asc), Value::Int64(40));
+        assert_eq!(percentile("0.51", OrderDirection::Asc), Value::Int64(30));
+        assert_eq!(percentile("0.25", OrderDirection::Desc), Value::Int64(40));
+    }
+
+    #[test]
+    fn string_agg_orders_by_local_keys_and_skips_nulls() {
+        let mut reducer = state(
+            AggregateKind::StringAgg,
+            &AggregateArguments::StringAgg {
+                separator: ", ".to_string(),
+                order_by: vec![order_term(OrderDirection::Desc, NullsOrder::Last)],
+            },
+        );
+        reducer
+            .ingest(Value::Text("b".to_string()), vec![Value::Int64(2)])
+            .expect("string_agg ingest");
+        reducer
+            .ingest(Value::Null, vec![Value::Int64(9)])
+            .expect("string_agg ignores null");
+        reducer
+            .ingest(Value::Text("z".to_string()), vec![Value::Null])
+            .expect("string_agg ingest");
+        reducer
+            .ingest(Value::Text("c".to_string()), vec![Value::Int64(3)])
+            .expect("string_agg ingest");
+
+        assert_eq!(
+            reducer.into_final_value().expect("string_agg finalize"),
+            Value::Text("c, b, z".to_string()),
+        );
+    }
+
+    #[test]
+    fn array_agg_keeps_nulls_and_arrival_order_ties() {
+        let mut reducer = state(
+            AggregateKind::ArrayAgg,
+            &AggregateArguments::ArrayAgg {
+                order_by: vec![order_term(OrderDirection::Asc, NullsOrder::First)],
+            },
+        );
+        reducer
+            .ingest(Value::Int64(1), vec![Value::Int64(5)])
+            .expect("array_agg ingest");
+        reducer
+            .ingest(Value::Null, vec![Value::Int64(5)])
+            .expect("array_agg keeps null");
+        reducer
+            .ingest(Value::Int64(3), vec![Value::Int64(1)])
+            .expect("array_agg ingest");
+
+        assert_eq!(
+            reducer.into_final_value().expect("array_agg finalize"),
+            Value::List(vec![Value::Int64(3), Value::Int64(1), Value::Null]),
+        );
+    }
+
+    #[test]
+    fn empty_collection_aggregates_finalize_to_null() {
+        let reducer = state(
+            AggregateKind::ArrayAgg,
+            &AggregateArguments::ArrayAgg {
+                order_by: Vec::new(),
+            },
+        );
+
+        assert_eq!(
+            reducer.into_final_value().expect("array_agg finalize"),
+            Value::Null
+        );
+    }
+}
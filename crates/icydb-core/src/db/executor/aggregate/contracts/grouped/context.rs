@@ -551,7 +551,7 @@ impl ExecutionContext {
     }
 
     /// Record one new canonical group with one aggregate state slot.
-    pub(in crate::db::executor::aggregate) fn record_new_group(
+    pub(in crate::db::executor) fn record_new_group(
         &mut self,
         group_count_before_insert: usize,
         group_capacity_before_insert: usize,
@@ -693,6 +693,48 @@ impl ExecutionContext {
         Ok(true)
     }
 
+    /// Admit one retained aggregate input value (PERCENTILE_DISC, STRING_AGG,
+    /// ARRAY_AGG) under the same total, per-group, and byte limits that bound
+    /// grouped DISTINCT state. Aggregate-local ORDER BY keys retained beside
+    /// the value are charged as group bytes.
+    pub(in crate::db::executor) fn admit_retained_value(
+        &mut self,
+        retained_in_group: usize,
+        max_distinct_values_per_group: u64,
+        value: &crate::value::Value,
+        order_keys: &[crate::value::Value],
+    ) -> Result<(), GroupError> {
+        let attempted_total = self.budget.distinct_values().saturating_add(1);
+        if attempted_total > self.config.max_distinct_values_total() {
+            return Err(GroupError::distinct_budget_exceeded(
+                GroupBudgetResourceCode::DistinctValuesTotal,
+                attempted_total,
+                self.config.max_distinct_values_total(),
+            ));
+        }
+
+        let attempted_per_group = u64::try_from(retained_in_group)
+            .unwrap_or(u64::MAX)
+            .saturating_add(1);
+        if attempted_per_group > max_distinct_values_per_group {
+            return Err(GroupError::distinct_budget_exceeded(
+                GroupBudgetResourceCode::DistinctValuesPerGroup,
+                attempted_per_group,
+                max_distinct_values_per_group,
+            ));
+        }
+
+        self.record_distinct_value(value)?;
+        let order_key_bytes = order_keys.iter().fold(0u64, |bytes, key| {
+            bytes.saturating_add(runtime_value_work(key).0)
+        });
+        if order_key_bytes == 0 {
+            return Ok(());
+        }
+
+        self.record_structural_backing(order_key_bytes)
+    }
+
     /// Record one implicit singleton group for grouped shapes that are modeled
     /// without explicit group-key boundary transitions (for example zero-key
     /// global grouped aggregates).
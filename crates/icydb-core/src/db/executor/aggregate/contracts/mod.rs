@@ -27,6 +27,7 @@ pub(in crate::db::executor) use plan::{
 };
 #[cfg(feature = "sql")]
 pub(in crate::db::executor) use state::AggregateFoldMode;
+pub(in crate::db::executor::aggregate) use state::GroupedRetainedValueSpec;
 pub(in crate::db::executor) use state::{
     AggregateStateFactory, FoldControl, GroupedDistinctExecutionMode, GroupedTerminalAggregateState,
 };
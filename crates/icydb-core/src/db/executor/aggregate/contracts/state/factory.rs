@@ -54,6 +54,7 @@ impl AggregateStateFactory {
             target_field,
             grouped_input_expr,
             grouped_filter_expr,
+            grouped_order_exprs: Vec::new(),
             requires_primary_key_value: kind.requires_decoded_id(),
             reducer: GroupedAggregateReducerState::for_kind(kind),
         }
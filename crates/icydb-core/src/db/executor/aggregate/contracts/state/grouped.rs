@@ -9,6 +9,7 @@ use crate::{
         direction::Direction,
         executor::{
             aggregate::{
+                collection_reducer::CollectionReducerSpec,
                 contracts::{
                     AggregateKind,
                     error::GroupError,
@@ -19,6 +20,7 @@ use crate::{
                         GroupedDistinctExecutionMode, canonical_key_from_data_key,
                     },
                 },
+                exact_sum::SumTerm,
                 field::{
                     AggregateFieldValueError, FieldSlot as AggregateFieldSlot,
                     compare_orderable_field_values_with_slot,
@@ -29,9 +31,9 @@ use crate::{
             projection::ProjectionEvalError,
         },
         key_taxonomy::PrimaryKeyValue,
+        numeric::coerce_numeric_decimal,
     },
     error::InternalError,
-    types::Decimal,
     value::Value,
 };
 use std::borrow::Cow;
@@ -63,6 +65,7 @@ enum AggregateInputValue {
 enum SumLikeKind {
     Sum,
     Avg,
+    Statistic,
 }
 
 impl SumLikeKind {
@@ -72,12 +75,19 @@ impl SumLikeKind {
         match kind {
             AggregateKind::Sum => Some(Self::Sum),
             AggregateKind::Avg => Some(Self::Avg),
+            AggregateKind::VarPop
+            | AggregateKind::VarSamp
+            | AggregateKind::StddevPop
+            | AggregateKind::StddevSamp => Some(Self::Statistic),
             AggregateKind::Count
             | AggregateKind::Exists
             | AggregateKind::Min
             | AggregateKind::Max
             | AggregateKind::First
-            | AggregateKind::Last => None,
+            | AggregateKind::Last
+            | AggregateKind::PercentileDisc
+            | AggregateKind::StringAgg
+            | AggregateKind::ArrayAgg => None,
         }
     }
 
@@ -87,19 +97,59 @@ impl SumLikeKind {
         match self {
             Self::Sum => "SUM(input)",
             Self::Avg => "AVG(input)",
+            Self::Statistic => "VAR/STDDEV(input)",
         }
     }
 
-    // Apply one grouped numeric decimal payload through the SUM/AVG reducer
+    // Admit one non-null SUM/AVG input. SUM and AVG keep exact integer
+    // families exact; VAR/STDDEV always use the shared decimal coercion.
+    fn admit_value(self, value: &Value) -> Option<SumTerm> {
+        match self {
+            Self::Sum | Self::Avg => SumTerm::from_value(value),
+            Self::Statistic => coerce_numeric_decimal(value).map(SumTerm::Decimal),
+        }
+    }
+
+    // Apply one admitted grouped numeric payload through the SUM/AVG reducer
     // family.
-    fn apply_decimal(
+    fn apply_term(
         self,
         reducer: &mut GroupedAggregateReducerState,
-        decimal: Decimal,
+        term: SumTerm,
     ) -> Result<(), InternalError> {
-        match self {
-            Self::Sum => reducer.add_sum_value(decimal),
-            Self::Avg => reducer.add_average_value(decimal),
+        match (self, term) {
+            (Self::Sum, term) => reducer.add_sum_value(term),
+            (Self::Avg, term) => reducer.add_average_value(term),
+            (Self::Statistic, SumTerm::Decimal(decimal)) => reducer.add_statistic_value(decimal),
+            (Self::Statistic, SumTerm::Exact(..)) => Err(InternalError::query_executor_invariant()),
+        }
+    }
+}
+
+///
+/// GroupedRetainedValueSpec
+///
+/// GroupedRetainedValueSpec carries the retained-value reducer contract and
+/// the compiled aggregate-local ORDER BY expressions for one grouped
+/// PERCENTILE_DISC, STRING_AGG, or ARRAY_AGG slot.
+///
+
+#[derive(Clone)]
+pub(in crate::db::executor::aggregate) struct GroupedRetainedValueSpec {
+    collection: CollectionReducerSpec,
+    order_exprs: Vec<CompiledExpr>,
+}
+
+impl GroupedRetainedValueSpec {
+    /// Build one grouped retained-value slot contract.
+    #[must_use]
+    pub(in crate::db::executor::aggregate) const fn new(
+        collection: CollectionReducerSpec,
+        order_exprs: Vec<CompiledExpr>,
+    ) -> Self {
+        Self {
+            collection,
+            order_exprs,
         }
     }
 }
@@ -125,11 +175,24 @@ pub(in crate::db::executor) struct GroupedTerminalAggregateState {
         Option<CompiledExpr>,
     pub(in crate::db::executor::aggregate::contracts::state) grouped_filter_expr:
         Option<CompiledExpr>,
+    pub(in crate::db::executor::aggregate::contracts::state) grouped_order_exprs: Vec<CompiledExpr>,
     pub(in crate::db::executor::aggregate::contracts::state) requires_primary_key_value: bool,
     pub(in crate::db::executor::aggregate::contracts::state) reducer: GroupedAggregateReducerState,
 }
 
 impl GroupedTerminalAggregateState {
+    /// Bind the retained-value reducer contract for PERCENTILE_DISC,
+    /// STRING_AGG, and ARRAY_AGG slots.
+    #[must_use]
+    pub(in crate::db::executor) fn with_retained_values(
+        mut self,
+        retained: GroupedRetainedValueSpec,
+    ) -> Self {
+        self.reducer = GroupedAggregateReducerState::for_collection(retained.collection);
+        self.grouped_order_exprs = retained.order_exprs;
+        self
+    }
+
     // Build the canonical grouped terminal invariant for field-target-only kinds.
     fn field_target_execution_required(_kind: &'static str) -> InternalError {
         InternalError::query_executor_invariant()
@@ -153,21 +216,25 @@ impl GroupedTerminalAggregateState {
     // Build the canonical grouped terminal invariant for aggregate-input
     // expressions that drift outside the grouped compiled evaluator.
     fn input_expression_evaluation_failed(err: ProjectionEvalError) -> InternalError {
-        if let ProjectionEvalError::Numeric(err) = err {
-            return err.into_internal_error();
+        match err {
+            ProjectionEvalError::Numeric(err) => err.into_internal_error(),
+            err @ ProjectionEvalError::ValueRejected { .. } => {
+                err.into_grouped_projection_internal_error()
+            }
+            _ => InternalError::query_invalid_logical_plan(),
         }
-
-        InternalError::query_invalid_logical_plan()
     }
 
     // Build the canonical grouped terminal invariant for aggregate filters
     // that drift outside the grouped compiled evaluator.
     fn filter_expression_evaluation_failed(err: ProjectionEvalError) -> InternalError {
-        if let ProjectionEvalError::Numeric(err) = err {
-            return err.into_internal_error();
+        match err {
+            ProjectionEvalError::Numeric(err) => err.into_internal_error(),
+            err @ ProjectionEvalError::ValueRejected { .. } => {
+                err.into_grouped_projection_internal_error()
+            }
+            _ => InternalError::query_invalid_logical_plan(),
         }
-
-        InternalError::query_invalid_logical_plan()
     }
 
     // Evaluate one row-backed grouped expression for aggregate execution. Input
@@ -252,33 +319,23 @@ impl GroupedTerminalAggregateState {
         })
     }
 
-    // Coerce one already-resolved SUM/AVG input through the shared Value
-    // numeric operation boundary. This keeps grouped reducers from reopening
-    // Value-level arithmetic while preserving the global numeric admission
-    // contract for values that cannot be represented as Decimal.
-    fn coerce_sum_like_decimal(value: &Value) -> Option<Decimal> {
-        if value.supports_numeric_coercion() {
-            return value.to_numeric_decimal();
-        }
-
-        None
-    }
-
-    // Resolve one SUM/AVG input as an optional Decimal without cloning direct
-    // field-target slots. Compiled expression inputs still own their temporary
-    // result because the expression evaluator may synthesize a value.
-    fn resolve_sum_like_decimal_input(
+    // Resolve one SUM/AVG input as an optional admitted term without cloning
+    // direct field-target slots. Compiled expression inputs still own their
+    // temporary result because the expression evaluator may synthesize a value.
+    fn resolve_sum_like_input(
         &self,
         row_view: Option<&RowView>,
-        label: &'static str,
-    ) -> Result<Option<Decimal>, InternalError> {
+        sum_like_kind: SumLikeKind,
+    ) -> Result<Option<SumTerm>, InternalError> {
+        let label = sum_like_kind.input_label();
         if self.grouped_input_expr.is_some() {
             let value = self.evaluate_compiled_input_value(row_view)?;
             if matches!(value, Value::Null) {
                 return Ok(None);
             }
 
-            return Self::coerce_sum_like_decimal(&value)
+            return sum_like_kind
+                .admit_value(&value)
                 .map(Some)
                 .ok_or_else(InternalError::query_executor_invariant);
         }
@@ -291,7 +348,8 @@ impl GroupedTerminalAggregateState {
             return Ok(None);
         }
 
-        Self::coerce_sum_like_decimal(value.as_ref())
+        sum_like_kind
+            .admit_value(value.as_ref())
             .map(Some)
             .ok_or_else(|| Self::sum_like_field_requires_numeric_value(label, value.as_ref()))
     }
@@ -331,8 +389,7 @@ impl GroupedTerminalAggregateState {
             return Ok(FoldControl::Continue);
         }
 
-        self.apply_terminal_update(key, row_view)
-            .map_err(GroupError::from)
+        self.apply_terminal_update(key, row_view, execution_context)
     }
 
     /// Finalize this grouped aggregate state into one structural output value.
@@ -345,13 +402,19 @@ impl GroupedTerminalAggregateState {
         &mut self,
         key: &DecodedDataStoreKey,
         row_view: Option<&RowView>,
-    ) -> Result<FoldControl, InternalError> {
+        execution_context: &mut ExecutionContext,
+    ) -> Result<FoldControl, GroupError> {
         let primary_key_value = self
             .requires_primary_key_value
             .then(|| key.primary_key_value());
-        match self.kind {
+        let control = match self.kind {
             AggregateKind::Count => self.apply_count(primary_key_value.as_ref(), row_view),
-            AggregateKind::Sum | AggregateKind::Avg => {
+            AggregateKind::Sum
+            | AggregateKind::Avg
+            | AggregateKind::VarPop
+            | AggregateKind::VarSamp
+            | AggregateKind::StddevPop
+            | AggregateKind::StddevSamp => {
                 self.apply_sum_like(primary_key_value.as_ref(), row_view)
             }
             AggregateKind::Exists => self.apply_exists(primary_key_value.as_ref(), row_view),
@@ -363,7 +426,12 @@ impl GroupedTerminalAggregateState {
             }
             AggregateKind::First => self.apply_first(primary_key_value.as_ref(), row_view),
             AggregateKind::Last => self.apply_last(primary_key_value.as_ref(), row_view),
-        }
+            AggregateKind::PercentileDisc | AggregateKind::StringAgg | AggregateKind::ArrayAgg => {
+                return self.apply_retained_value(row_view, execution_context);
+            }
+        };
+
+        control.map_err(GroupError::from)
     }
 
     // Admit one grouped DISTINCT candidate at the reducer boundary. Value-based
@@ -448,12 +516,10 @@ impl GroupedTerminalAggregateState {
         let Some(sum_like_kind) = SumLikeKind::from_aggregate_kind(self.kind) else {
             return Err(Self::field_target_execution_required("SUM/AVG(input)"));
         };
-        let kind_label = sum_like_kind.input_label();
-
-        let Some(decimal) = self.resolve_sum_like_decimal_input(row_view, kind_label)? else {
+        let Some(term) = self.resolve_sum_like_input(row_view, sum_like_kind)? else {
             return Ok(FoldControl::Continue);
         };
-        sum_like_kind.apply_decimal(&mut self.reducer, decimal)?;
+        sum_like_kind.apply_term(&mut self.reducer, term)?;
 
         Ok(FoldControl::Continue)
     }
@@ -521,6 +587,53 @@ impl GroupedTerminalAggregateState {
         Ok(kind.fold_control_for_direction(self.direction))
     }
 
+    // Apply one PERCENTILE_DISC/STRING_AGG/ARRAY_AGG grouped terminal update.
+    // Each retained value is admitted through the grouped DISTINCT budget
+    // before the reducer grows, so retained state stays bounded per group.
+    fn apply_retained_value(
+        &mut self,
+        row_view: Option<&RowView>,
+        execution_context: &mut ExecutionContext,
+    ) -> Result<FoldControl, GroupError> {
+        let value = match self
+            .resolve_input_value(row_view, "PERCENTILE_DISC/STRING_AGG/ARRAY_AGG(input)")
+            .map_err(GroupError::from)?
+        {
+            AggregateInputValue::Null => Value::Null,
+            AggregateInputValue::Value(value) => value,
+        };
+        let collection = self.reducer.collection().map_err(GroupError::from)?;
+        if !collection.spec().retains(&value) {
+            return Ok(FoldControl::Continue);
+        }
+        let retained_in_group = collection.len();
+        let order_keys = self
+            .grouped_order_exprs
+            .iter()
+            .map(|expression| {
+                Self::evaluate_row_expression_value(
+                    row_view,
+                    expression,
+                    "grouped aggregate ORDER BY expression",
+                    Self::input_expression_evaluation_failed,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(GroupError::from)?;
+
+        execution_context.admit_retained_value(
+            retained_in_group,
+            self.max_distinct_values_per_group,
+            &value,
+            &order_keys,
+        )?;
+        self.reducer
+            .ingest_collection_value(value, order_keys)
+            .map_err(GroupError::from)?;
+
+        Ok(FoldControl::Continue)
+    }
+
     // Apply one FIRST grouped terminal update.
     fn apply_first(
         &mut self,
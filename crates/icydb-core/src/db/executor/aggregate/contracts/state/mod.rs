@@ -16,5 +16,6 @@ pub(in crate::db::executor) use control::FoldControl;
 pub(in crate::db::executor) use distinct::GroupedDistinctExecutionMode;
 pub(in crate::db::executor::aggregate::contracts::state) use distinct::canonical_key_from_data_key;
 pub(in crate::db::executor) use factory::AggregateStateFactory;
+pub(in crate::db::executor::aggregate) use grouped::GroupedRetainedValueSpec;
 pub(in crate::db::executor) use grouped::GroupedTerminalAggregateState;
 pub(in crate::db::executor::aggregate::contracts::state) use reducer::GroupedAggregateReducerState;
@@ -5,7 +5,12 @@
 
 use crate::{
     db::{
-        executor::aggregate::{contracts::AggregateKind, value_reducer::ValueReducerState},
+        executor::aggregate::{
+            collection_reducer::{CollectionReducerSpec, CollectionReducerState},
+            contracts::AggregateKind,
+            exact_sum::SumTerm,
+            value_reducer::ValueReducerState,
+        },
         key_taxonomy::PrimaryKeyValue,
     },
     error::InternalError,
@@ -30,6 +35,8 @@ pub(in crate::db::executor::aggregate::contracts::state) enum GroupedAggregateRe
     Max(ValueReducerState),
     First(Option<Value>),
     Last(Option<Value>),
+    Statistic(ValueReducerState),
+    Collection(Option<Box<CollectionReducerState>>),
 }
 
 impl GroupedAggregateReducerState {
@@ -52,9 +59,26 @@ impl GroupedAggregateReducerState {
             AggregateKind::Max => Self::Max(ValueReducerState::max()),
             AggregateKind::First => Self::First(None),
             AggregateKind::Last => Self::Last(None),
+            AggregateKind::VarPop => Self::Statistic(ValueReducerState::variance(false, false)),
+            AggregateKind::VarSamp => Self::Statistic(ValueReducerState::variance(true, false)),
+            AggregateKind::StddevPop => Self::Statistic(ValueReducerState::variance(false, true)),
+            AggregateKind::StddevSamp => Self::Statistic(ValueReducerState::variance(true, true)),
+            // Retained-value reducers need planner arguments, so they stay
+            // unbound until `for_collection` supplies the reducer contract.
+            AggregateKind::PercentileDisc | AggregateKind::StringAgg | AggregateKind::ArrayAgg => {
+                Self::Collection(None)
+            }
         }
     }
 
+    /// Build the initial grouped reducer state for one retained-value terminal.
+    #[must_use]
+    pub(in crate::db::executor::aggregate::contracts::state) fn for_collection(
+        spec: CollectionReducerSpec,
+    ) -> Self {
+        Self::Collection(Some(Box::new(CollectionReducerState::new(spec))))
+    }
+
     // Apply one COUNT reducer update.
     pub(in crate::db::executor::aggregate::contracts::state) fn increment_count(
         &mut self,
@@ -68,10 +92,10 @@ impl GroupedAggregateReducerState {
     // Apply one SUM reducer update.
     pub(in crate::db::executor::aggregate::contracts::state) fn add_sum_value(
         &mut self,
-        value: Decimal,
+        term: SumTerm,
     ) -> Result<(), InternalError> {
         match self {
-            Self::Sum(reducer) => reducer.ingest_decimal(value),
+            Self::Sum(reducer) => reducer.ingest_sum_term(term),
             _ => Err(Self::state_mismatch("SUM")),
         }
     }
@@ -79,14 +103,47 @@ impl GroupedAggregateReducerState {
     // Apply one AVG reducer update.
     pub(in crate::db::executor::aggregate::contracts::state) fn add_average_value(
         &mut self,
-        value: Decimal,
+        term: SumTerm,
     ) -> Result<(), InternalError> {
         match self {
-            Self::Avg(reducer) => reducer.ingest_decimal(value),
+            Self::Avg(reducer) => reducer.ingest_sum_term(term),
             _ => Err(Self::state_mismatch("AVG")),
         }
     }
 
+    // Apply one VAR/STDDEV reducer update.
+    pub(in crate::db::executor::aggregate::contracts::state) fn add_statistic_value(
+        &mut self,
+        value: Decimal,
+    ) -> Result<(), InternalError> {
+        match self {
+            Self::Statistic(reducer) => reducer.ingest_decimal(value),
+            _ => Err(Self::state_mismatch("VAR/STDDEV")),
+        }
+    }
+
+    // Borrow the bound retained-value reducer.
+    pub(in crate::db::executor::aggregate::contracts::state) fn collection(
+        &self,
+    ) -> Result<&CollectionReducerState, InternalError> {
+        match self {
+            Self::Collection(Some(reducer)) => Ok(reducer),
+            _ => Err(Self::state_mismatch("PERCENTILE_DISC/STRING_AGG/ARRAY_AGG")),
+        }
+    }
+
+    // Retain one admitted value with its aggregate-local ORDER BY keys.
+    pub(in crate::db::executor::aggregate::contracts::state) fn ingest_collection_value(
+        &mut self,
+        value: Value,
+        order_keys: Vec<Value>,
+    ) -> Result<(), InternalError> {
+        match self {
+            Self::Collection(Some(reducer)) => reducer.ingest(value, order_keys),
+            _ => Err(Self::state_mismatch("PERCENTILE_DISC/STRING_AGG/ARRAY_AGG")),
+        }
+    }
+
     // Apply one EXISTS reducer update.
     pub(in crate::db::executor::aggregate::contracts::state) fn set_exists_true(
         &mut self,
@@ -223,7 +280,12 @@ impl GroupedAggregateReducerState {
             | Self::Sum(reducer)
             | Self::Avg(reducer)
             | Self::Min(reducer)
-            | Self::Max(reducer) => reducer.into_final_value(),
+            | Self::Max(reducer)
+            | Self::Statistic(reducer) => reducer.into_final_value(),
+            Self::Collection(Some(reducer)) => reducer.into_final_value(),
+            Self::Collection(None) => {
+                Err(Self::state_mismatch("PERCENTILE_DISC/STRING_AGG/ARRAY_AGG"))
+            }
             Self::Exists(value) => Ok(Value::Bool(value)),
             Self::First(value) | Self::Last(value) => Ok(value.unwrap_or(Value::Null)),
         }
@@ -233,8 +295,10 @@ impl GroupedAggregateReducerState {
 #[cfg(test)]
 mod tests {
     use crate::{
-        db::executor::aggregate::{AggregateKind, contracts::state::GroupedAggregateReducerState},
-        types::Decimal,
+        db::executor::aggregate::{
+            AggregateKind, contracts::state::GroupedAggregateReducerState, exact_sum::SumTerm,
+        },
+        types::{Decimal, NatBig},
         value::Value,
     };
 
@@ -249,19 +313,46 @@ mod tests {
         assert_eq!(count.into_value().expect("count finalize"), Value::Nat64(2));
 
         let mut sum = GroupedAggregateReducerState::for_kind(AggregateKind::Sum);
-        sum.add_sum_value(one).expect("sum ingest");
-        sum.add_sum_value(three).expect("sum ingest");
+        sum.add_sum_value(SumTerm::Decimal(one))
+            .expect("sum ingest");
+        sum.add_sum_value(SumTerm::Decimal(three))
+            .expect("sum ingest");
         assert_eq!(
             sum.into_value().expect("sum finalize"),
             Value::Decimal(Decimal::from_i64(4).expect("decimal four")),
         );
 
         let mut avg = GroupedAggregateReducerState::for_kind(AggregateKind::Avg);
-        avg.add_average_value(one).expect("avg ingest");
-        avg.add_average_value(three).expect("avg ingest");
+        avg.add_average_value(SumTerm::Decimal(one))
+            .expect("avg ingest");
+        avg.add_average_value(SumTerm::Decimal(three))
+            .expect("avg ingest");
         assert_eq!(
             avg.into_value().expect("avg finalize"),
             Value::Decimal(Decimal::from_i64(2).expect("decimal two")),
         );
     }
+
+    #[test]
+    fn grouped_sum_and_avg_keep_nat_big_inputs_exact() {
+        let term = |value: u64| {
+            SumTerm::from_value(&Value::NatBig(NatBig::from(value))).expect("numeric input")
+        };
+
+        let mut sum = GroupedAggregateReducerState::for_kind(AggregateKind::Sum);
+        sum.add_sum_value(term(u64::MAX)).expect("sum ingest");
+        sum.add_sum_value(term(u64::MAX)).expect("sum ingest");
+        assert_eq!(
+            sum.into_value().expect("sum finalize"),
+            Value::NatBig(NatBig::from(u64::MAX) + NatBig::from(u64::MAX)),
+        );
+
+        let mut avg = GroupedAggregateReducerState::for_kind(AggregateKind::Avg);
+        avg.add_average_value(term(2)).expect("avg ingest");
+        avg.add_average_value(term(3)).expect("avg ingest");
+        assert_eq!(
+            avg.into_value().expect("avg finalize"),
+            Value::NatBig(NatBig::from(3_u64)),
+        );
+    }
 }
@@ -0,0 +1,318 @@
+//! Module: executor::aggregate::exact_sum
+//! Responsibility: exact SUM/AVG accumulation for 128-bit and arbitrary-precision integers.
+//! Does not own: DISTINCT admission, row access, or VAR/STDDEV decimal semantics.
+//! Boundary: keeps `Nat128`, `Int128`, `NatBig`, and `IntBig` sums out of bounded `Decimal`.
+
+use crate::{
+    db::numeric::{
+        NumericEvalError, add_decimal_terms_checked, average_decimal_terms_checked,
+        coerce_numeric_decimal,
+    },
+    types::{Decimal, IntBig, NatBig},
+    value::Value,
+};
+use num_bigint::{BigInt, BigUint, Sign};
+
+/// Maximum number of decimal digits one exact integer SUM/AVG accumulator may
+/// hold. Crossing it fails with a numeric overflow instead of growing without
+/// bound or narrowing.
+pub(in crate::db) const MAX_EXACT_SUM_DIGITS: u32 = 256;
+
+///
+/// ExactIntegerKind
+///
+/// Input integer family whose SUM/AVG stays exact. The kind also names the
+/// output type: results are produced in the same family as their inputs.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(in crate::db::executor::aggregate) enum ExactIntegerKind {
+    Nat128,
+    Int128,
+    NatBig,
+    IntBig,
+}
+
+impl ExactIntegerKind {
+    // Convert one finalized exact integer back into this kind's value type.
+    fn value_from_integer(self, integer: BigInt) -> Result<Value, NumericEvalError> {
+        match self {
+            Self::Nat128 => u128::try_from(&integer)
+                .map(Value::Nat128)
+                .map_err(|_| NumericEvalError::Overflow),
+            Self::Int128 => i128::try_from(&integer)
+                .map(Value::Int128)
+                .map_err(|_| NumericEvalError::Overflow),
+            Self::NatBig => integer
+                .to_biguint()
+                .map(|integer| Value::NatBig(NatBig::from_biguint(integer)))
+                .ok_or(NumericEvalError::Overflow),
+            Self::IntBig => Ok(Value::IntBig(IntBig::from_bigint(integer))),
+        }
+    }
+}
+
+///
+/// SumTerm
+///
+/// One non-null SUM/AVG input after numeric admission. Exact integer families
+/// keep their full magnitude; every other numeric input uses the shared
+/// decimal coercion boundary.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(in crate::db::executor::aggregate) enum SumTerm {
+    Decimal(Decimal),
+    Exact(ExactIntegerKind, BigInt),
+}
+
+impl SumTerm {
+    /// Admit one SUM/AVG input value, or return `None` when the value is not
+    /// numeric.
+    #[must_use]
+    pub(in crate::db::executor::aggregate) fn from_value(value: &Value) -> Option<Self> {
+        let exact = match value {
+            Value::Nat128(value) => (ExactIntegerKind::Nat128, BigInt::from(*value)),
+            Value::Int128(value) => (ExactIntegerKind::Int128, BigInt::from(*value)),
+            Value::NatBig(value) => (
+                ExactIntegerKind::NatBig,
+                BigInt::from(BigUint::new(value.u32_digits())),
+            ),
+            Value::IntBig(value) => {
+                let (negative, digits) = value.sign_and_u32_digits();
+                let sign = if negative { Sign::Minus } else { Sign::Plus };
+
+                (
+                    ExactIntegerKind::IntBig,
+                    BigInt::from_biguint(sign, BigUint::new(digits)),
+                )
+            }
+            _ => return coerce_numeric_decimal(value).map(Self::Decimal),
+        };
+
+        Some(Self::Exact(exact.0, exact.1))
+    }
+}
+
+///
+/// SumAccumulator
+///
+/// Running SUM/AVG total. The first admitted term selects the decimal or
+/// exact-integer lane; a later term from another lane fails closed instead of
+/// silently converting the running total.
+///
+
+#[derive(Debug, Default)]
+pub(in crate::db::executor::aggregate) enum SumAccumulator {
+    #[default]
+    Empty,
+    Decimal(Decimal),
+    Exact {
+        kind: ExactIntegerKind,
+        sum: BigInt,
+    },
+}
+
+impl SumAccumulator {
+    /// Add one admitted term to the running total.
+    pub(in crate::db::executor::aggregate) fn add(
+        &mut self,
+        term: SumTerm,
+    ) -> Result<(), NumericEvalError> {
+        if matches!(self, Self::Empty) {
+            *self = match term {
+                SumTerm::Decimal(value) => Self::Decimal(value),
+                SumTerm::Exact(kind, value) => {
+                    check_exact_digit_budget(&value)?;
+                    Self::Exact { kind, sum: value }
+                }
+            };
+
+            return Ok(());
+        }
+
+        match (self, term) {
+            (Self::Decimal(sum), SumTerm::Decimal(value)) => {
+                *sum = add_decimal_terms_checked(*sum, value)?;
+
+                Ok(())
+            }
+            (Self::Exact { kind, sum }, SumTerm::Exact(term_kind, value)) if *kind == term_kind => {
+                *sum += value;
+
+                check_exact_digit_budget(sum)
+            }
+            _ => Err(NumericEvalError::NotRepresentable),
+        }
+    }
+
+    /// Finalize one SUM. Exact totals keep their input integer type.
+    pub(in crate::db::executor::aggregate) fn into_sum_value(
+        self,
+    ) -> Result<Value, NumericEvalError> {
+        match self {
+            Self::Empty => Ok(Value::Null),
+            Self::Decimal(sum) => Ok(Value::Decimal(sum)),
+            Self::Exact { kind, sum } => kind.value_from_integer(sum),
+        }
+    }
+
+    /// Finalize one AVG over `count` admitted terms. Exact averages keep their
+    /// input integer type and round half away from zero.
+    pub(in crate::db::executor::aggregate) fn into_average_value(
+        self,
+        count: u64,
+    ) -> Result<Value, NumericEvalError> {
+        if count == 0 {
+            return Ok(Value::Null);
+        }
+
+        match self {
+            Self::Empty => Ok(Value::Null),
+            Self::Decimal(sum) => average_decimal_terms_checked(sum, count).map(Value::Decimal),
+            Self::Exact { kind, sum } => {
+                kind.value_from_integer(round_half_away_quotient(&sum, &BigInt::from(count)))
+            }
+        }
+    }
+}
+
+// Reject one exact running total whose magnitude needs more than
+// `MAX_EXACT_SUM_DIGITS` decimal digits. Any magnitude below `8^digits` fits,
+// so the power of ten is only built for totals close to the budget.
+fn check_exact_digit_budget(sum: &BigInt) -> Result<(), NumericEvalError> {
+    if sum.bits() <= u64::from(MAX_EXACT_SUM_DIGITS) * 3 {
+        return Ok(());
+    }
+    if *sum.magnitude() >= BigUint::from(10_u32).pow(MAX_EXACT_SUM_DIGITS) {
+        return Err(NumericEvalError::Overflow);
+    }
+
+    Ok(())
+}
+
+// Divide with the quotient rounded half away from zero. `BigInt` division
+// truncates toward zero and leaves the remainder with the dividend's sign.
+fn round_half_away_quotient(dividend: &BigInt, divisor: &BigInt) -> BigInt {
+    let quotient = dividend / divisor;
+    let remainder = dividend % divisor;
+    if remainder.magnitude() * 2_u32 < *divisor.magnitude() {
+        return quotient;
+    }
+
+    match dividend.sign() {
+        Sign::Minus => quotient - 1,
+        Sign::NoSign | Sign::Plus => quotient + 1,
+    }
+}
+
+///
+/// TESTS
+///
+
+#[cfg(test)]
+mod tests {
+    use super::{MAX_EXACT_SUM_DIGITS, SumAccumulator, SumTerm};
+    use crate::{
+        db::numeric::NumericEvalError,
+        types::{Decimal, IntBig, NatBig},
+        value::Value,
+    };
+    use num_bigint::{BigInt, BigUint};
+
+    fn accumulate(values: &[Value]) -> Result<SumAccumulator, NumericEvalError> {
+        let mut accumulator = SumAccumulator::default();
+        for value in values {
+            accumulator.add(SumTerm::from_value(value).expect("numeric SUM input"))?;
+        }
+
+        Ok(accumulator)
+    }
+
+    #[test]
+    fn nat_big_sum_stays_exact_beyond_the_decimal_domain() {
+        let large = NatBig::from_biguint(BigUint::from(10_u32).pow(40));
+        let values = [
+            Value::NatBig(large.clone()),
+            Value::NatBig(large),
+            Value::NatBig(NatBig::from(7_u64)),
+        ];
+
+        let sum = accumulate(&values).expect("exact sum").into_sum_value();
+
+        assert_eq!(
+            sum,
+            Ok(Value::NatBig(NatBig::from_biguint(
+                BigUint::from(10_u32).pow(40) * 2_u32 + 7_u32
+            ))),
+        );
+    }
+
+    #[test]
+    fn int_128_sum_and_average_keep_the_input_type() {
+        let values = [
+            Value::Int128(i128::MAX),
+            Value::Int128(-4),
+            Value::Int128(-i128::MAX),
+        ];
+
+        assert_eq!(
+            accumulate(&values).expect("exact sum").into_sum_value(),
+            Ok(Value::Int128(-4)),
+            "intermediate totals may leave the 128-bit range",
+        );
+        assert_eq!(
+            accumulate(&[Value::Int128(-3), Value::Int128(-4)])
+                .expect("exact sum")
+                .into_average_value(2),
+            Ok(Value::Int128(-4)),
+            "AVG rounds half away from zero",
+        );
+        assert_eq!(
+            accumulate(&[Value::Nat128(1), Value::Nat128(1), Value::Nat128(2)])
+                .expect("exact sum")
+                .into_average_value(3),
+            Ok(Value::Nat128(1)),
+        );
+    }
+
+    #[test]
+    fn exact_sums_fail_with_typed_overflow_instead_of_narrowing() {
+        assert_eq!(
+            accumulate(&[Value::Nat128(u128::MAX), Value::Nat128(1)])
+                .expect("exact sum")
+                .into_sum_value(),
+            Err(NumericEvalError::Overflow),
+        );
+
+        let near_budget =
+            IntBig::from_bigint(-(BigInt::from(10_u32).pow(MAX_EXACT_SUM_DIGITS) - 1_u32));
+        assert!(accumulate(&[Value::IntBig(near_budget.clone())]).is_ok());
+        assert_eq!(
+            accumulate(&[
+                Value::IntBig(near_budget),
+                Value::IntBig(IntBig::from_bigint(BigInt::from(-1)))
+            ])
+            .err(),
+            Some(NumericEvalError::Overflow),
+        );
+    }
+
+    #[test]
+    fn mixed_exact_and_decimal_terms_fail_closed() {
+        assert_eq!(
+            accumulate(&[Value::NatBig(NatBig::from(1_u64)), Value::Nat128(1)]).err(),
+            Some(NumericEvalError::NotRepresentable),
+        );
+        assert_eq!(
+            accumulate(&[Value::Nat64(1), Value::Int128(1)]).err(),
+            Some(NumericEvalError::NotRepresentable),
+        );
+        assert_eq!(
+            accumulate(&[Value::Nat64(1), Value::Nat64(2)])
+                .expect("decimal sum")
+                .into_sum_value(),
+            Ok(Value::Decimal(Decimal::from_i64(3).expect("decimal three"))),
+        );
+    }
+}
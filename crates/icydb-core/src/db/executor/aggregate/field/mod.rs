@@ -425,7 +425,12 @@ pub(in crate::db::executor) fn resolve_aggregate_target_slot_from_planner_slot(
     field_slot: &PlannedFieldSlot,
 ) -> Result<FieldSlot, AggregateFieldValueError> {
     match kind {
-        AggregateKind::Sum | AggregateKind::Avg => {
+        AggregateKind::Sum
+        | AggregateKind::Avg
+        | AggregateKind::VarPop
+        | AggregateKind::VarSamp
+        | AggregateKind::StddevPop
+        | AggregateKind::StddevSamp => {
             resolve_numeric_aggregate_target_slot_from_planner_slot(field_slot)
         }
         AggregateKind::Min | AggregateKind::Max => {
@@ -434,7 +439,12 @@ pub(in crate::db::executor) fn resolve_aggregate_target_slot_from_planner_slot(
         AggregateKind::Count
         | AggregateKind::Exists
         | AggregateKind::First
-        | AggregateKind::Last => resolve_any_aggregate_target_slot_from_planner_slot(field_slot),
+        | AggregateKind::Last
+        | AggregateKind::PercentileDisc
+        | AggregateKind::StringAgg
+        | AggregateKind::ArrayAgg => {
+            resolve_any_aggregate_target_slot_from_planner_slot(field_slot)
+        }
     }
 }
 
@@ -4,8 +4,10 @@
 //! Boundary: consumes accepted prepared plans and emits structural aggregate rows.
 
 pub(in crate::db::executor) mod capability;
+pub(in crate::db::executor::aggregate) mod collection_reducer;
 mod contracts;
 mod count_terminal;
+pub(in crate::db::executor::aggregate) mod exact_sum;
 pub(in crate::db::executor) mod field;
 pub(in crate::db::executor) mod runtime;
 #[cfg(feature = "sql")]
@@ -11,6 +11,7 @@ use crate::{
             aggregate::{
                 EffectiveRuntimeFilterProgram, ExecutionContext, GlobalDistinctAggregateKind,
                 GroupError, GroupedDistinctExecutionStrategy,
+                exact_sum::SumTerm,
                 field::{
                     AggregateFieldValueError, FieldSlot,
                     resolve_any_aggregate_target_slot_from_planner_slot,
@@ -22,11 +23,9 @@ use crate::{
             pipeline::contracts::ResolvedExecutionKeyStream,
             pipeline::runtime::{RowView, StructuralGroupedRowRuntime},
         },
-        numeric::coerce_numeric_decimal,
         predicate::MissingRowPolicy,
     },
     error::InternalError,
-    types::Decimal,
     value::Value,
 };
 
@@ -108,10 +107,10 @@ impl GlobalDistinctFieldAggregateDispatcher {
 
     // Extract the canonical distinct value and optional numeric payload from one
     // structural row view using one slot-reader pass.
-    fn extract(&self, row_view: &RowView) -> Result<(Value, Option<Decimal>), InternalError> {
+    fn extract(&self, row_view: &RowView) -> Result<(Value, Option<SumTerm>), InternalError> {
         let distinct_value = row_view.extract_orderable_field_value(self.field_slot)?;
         let numeric_value = if self.needs_numeric {
-            let Some(decimal) = coerce_numeric_decimal(&distinct_value) else {
+            let Some(term) = SumTerm::from_value(&distinct_value) else {
                 return Err(AggregateFieldValueError::field_value_type_mismatch(
                     self.field_slot,
                     &distinct_value,
@@ -119,7 +118,7 @@ impl GlobalDistinctFieldAggregateDispatcher {
                 .into_internal_error());
             };
 
-            Some(decimal)
+            Some(term)
         } else {
             None
         };
@@ -187,7 +186,7 @@ impl GlobalDistinctFieldAccumulator {
     // Apply one admitted distinct field value to the reducer state.
     fn apply_distinct_value(
         &mut self,
-        numeric_value: Option<Decimal>,
+        numeric_value: Option<SumTerm>,
     ) -> Result<(), InternalError> {
         match self.apply_mode {
             DistinctApplyMode::Count => self.reducer.increment_count(),
@@ -196,7 +195,7 @@ impl GlobalDistinctFieldAccumulator {
                     return Err(GroupError::numeric_ingest_payload_required().into_internal_error());
                 };
 
-                self.reducer.ingest_decimal(numeric_value)
+                self.reducer.ingest_sum_term(numeric_value)
             }
         }
     }
@@ -268,7 +267,7 @@ pub(in crate::db::executor) fn execute_global_distinct_field_aggregate(
 
 #[cfg(test)]
 mod tests {
-    use crate::{types::Decimal, value::Value};
+    use crate::{db::executor::aggregate::exact_sum::SumTerm, types::Decimal, value::Value};
 
     use super::{GlobalDistinctAggregateKind, GlobalDistinctFieldAccumulator};
 
@@ -285,8 +284,10 @@ mod tests {
 
         let mut sum =
             GlobalDistinctFieldAccumulator::new(GlobalDistinctAggregateKind::Sum.reducer_spec());
-        sum.apply_distinct_value(Some(one)).expect("sum ingest");
-        sum.apply_distinct_value(Some(three)).expect("sum ingest");
+        sum.apply_distinct_value(Some(SumTerm::Decimal(one)))
+            .expect("sum ingest");
+        sum.apply_distinct_value(Some(SumTerm::Decimal(three)))
+            .expect("sum ingest");
         assert_eq!(
             sum.finalize().expect("sum finalize"),
             Value::Decimal(Decimal::from_i64(4).expect("decimal four")),
@@ -294,8 +295,10 @@ mod tests {
 
         let mut avg =
             GlobalDistinctFieldAccumulator::new(GlobalDistinctAggregateKind::Avg.reducer_spec());
-        avg.apply_distinct_value(Some(one)).expect("avg ingest");
-        avg.apply_distinct_value(Some(three)).expect("avg ingest");
+        avg.apply_distinct_value(Some(SumTerm::Decimal(one)))
+            .expect("avg ingest");
+        avg.apply_distinct_value(Some(SumTerm::Decimal(three)))
+            .expect("avg ingest");
         assert_eq!(
             avg.finalize().expect("avg finalize"),
             Value::Decimal(Decimal::from_i64(2).expect("decimal two")),
@@ -11,7 +11,7 @@ use crate::{
             aggregate::{
                 AggregateKind, CompiledExpr, ExecutionContext, FieldSlot, FoldControl, GroupError,
                 contracts::{
-                    AggregateStateFactory, GroupedDistinctExecutionMode,
+                    AggregateStateFactory, GroupedDistinctExecutionMode, GroupedRetainedValueSpec,
                     GroupedTerminalAggregateState,
                 },
                 field::{
@@ -56,6 +56,7 @@ pub(super) struct GroupedAggregateBundleSpec {
     target_field: Option<AggregateFieldSlot>,
     grouped_input_expr: Option<CompiledExpr>,
     grouped_filter_expr: Option<CompiledExpr>,
+    retained: Option<GroupedRetainedValueSpec>,
     max_distinct_values_per_group: u64,
 }
 
@@ -94,13 +95,21 @@ impl GroupedAggregateBundleSpec {
             target_field,
             grouped_input_expr: compiled_input_expr,
             grouped_filter_expr: compiled_filter_expr,
+            retained: None,
             max_distinct_values_per_group,
         })
     }
 
+    /// Attach the retained-value contract for PERCENTILE_DISC, STRING_AGG,
+    /// and ARRAY_AGG slots.
+    pub(super) fn with_retained_values(mut self, retained: GroupedRetainedValueSpec) -> Self {
+        self.retained = Some(retained);
+        self
+    }
+
     // Materialize one grouped terminal reducer state for this aggregate slot.
     fn build_state(&self) -> GroupedTerminalAggregateState {
-        AggregateStateFactory::create_grouped_terminal(
+        let state = AggregateStateFactory::create_grouped_terminal(
             self.kind,
             self.direction,
             self.distinct_mode,
@@ -108,7 +117,12 @@ impl GroupedAggregateBundleSpec {
             self.grouped_input_expr.clone(),
             self.grouped_filter_expr.clone(),
             self.max_distinct_values_per_group,
-        )
+        );
+
+        match self.retained.as_ref() {
+            Some(retained) => state.with_retained_values(retained.clone()),
+            None => state,
+        }
     }
 }
 
@@ -453,6 +467,27 @@ impl GroupedAggregateBundle {
         .map_err(GroupError::from)
     }
 
+    // Search one stable-hash bucket for an existing group entry equal to one
+    // already materialized owned group key.
+    fn find_matching_owned_group_index(
+        &self,
+        group_key: &GroupKey,
+    ) -> Result<Option<usize>, GroupError> {
+        let Some(bucket) = self.bucket_index.get(&group_key.hash()) else {
+            return Ok(None);
+        };
+
+        find_matching_group_index_in_bucket(
+            bucket.as_slice(),
+            self.groups.len(),
+            |group_index| self.groups.get(group_index).map(|entry| &entry.group_key),
+            |existing| Ok(existing == group_key),
+            || {},
+            |_group_index, _group_count| InternalError::query_executor_invariant(),
+        )
+        .map_err(GroupError::from)
+    }
+
     // Create one new group entry and preserve grouped budget accounting under
     // the old per-aggregate-state budget model.
     fn insert_new_group(
@@ -529,6 +564,9 @@ impl GroupedAggregateBundle {
     ) -> Result<usize, GroupError> {
         let group_key = materialize_group_key_from_row_view(row_view, group_fields, None)
             .map_err(GroupError::from)?;
+        if let Some(group_index) = self.find_matching_owned_group_index(&group_key)? {
+            return Ok(group_index);
+        }
 
         self.insert_new_group(group_key, execution_context)
     }
@@ -34,6 +34,7 @@ use crate::{
             },
         },
         numeric::canonical_value_compare,
+        query::plan::NullsOrder,
     },
     error::InternalError,
     value::Value,
@@ -210,11 +211,13 @@ enum GroupedPageCandidateRanking {
     },
 }
 
-/// One evaluated grouped top-k order value paired with its comparison direction.
+/// One evaluated grouped top-k order value paired with its comparison
+/// direction and null placement.
 #[derive(Eq, PartialEq)]
 struct ResolvedGroupedTopKOrderTerm {
     value: Value,
     direction: OrderDirection,
+    nulls: NullsOrder,
 }
 
 struct CompiledGroupedTopKOrder {
@@ -224,6 +227,7 @@ struct CompiledGroupedTopKOrder {
 struct CompiledGroupedTopKOrderTerm {
     expr: CompiledExpr,
     direction: OrderDirection,
+    nulls: NullsOrder,
 }
 
 impl Ord for GroupedPageCandidate {
@@ -501,6 +505,7 @@ fn compile_grouped_top_k_order(
         terms.push(CompiledGroupedTopKOrderTerm {
             expr: compiled,
             direction: term.direction(),
+            nulls: term.nulls(),
         });
     }
 
@@ -534,6 +539,7 @@ fn compile_grouped_page_candidate_top_k_ranking(
                 .map(Cow::into_owned)
                 .map_err(ProjectionEvalError::into_grouped_projection_internal_error)?,
             direction: term.direction,
+            nulls: term.nulls,
         });
     }
 
@@ -765,15 +771,19 @@ fn compare_grouped_page_candidate_order(
             GroupedPageCandidateRanking::TopK { terms: left_terms },
             GroupedPageCandidateRanking::TopK { terms: right_terms },
         ) if left_terms.len() == right_terms.len()
-            && left_terms
-                .iter()
-                .zip(right_terms)
-                .all(|(left, right)| left.direction == right.direction) =>
+            && left_terms.iter().zip(right_terms).all(|(left, right)| {
+                left.direction == right.direction && left.nulls == right.nulls
+            }) =>
         {
             for (left, right) in left_terms.iter().zip(right_terms) {
-                let cmp = match left.direction {
-                    OrderDirection::Asc => canonical_value_compare(&left.value, &right.value),
-                    OrderDirection::Desc => canonical_value_compare(&right.value, &left.value),
+                let cmp = match (&left.value, &right.value) {
+                    (Value::Null, Value::Null) => Ordering::Equal,
+                    (Value::Null, _) => left.nulls.null_before_present_ordering(),
+                    (_, Value::Null) => left.nulls.null_before_present_ordering().reverse(),
+                    (left_value, right_value) => match left.direction {
+                        OrderDirection::Asc => canonical_value_compare(left_value, right_value),
+                        OrderDirection::Desc => canonical_value_compare(right_value, left_value),
+                    },
                 };
                 if !cmp.is_eq() {
                     return cmp;
@@ -958,6 +968,7 @@ mod tests {
                 terms: vec![ResolvedGroupedTopKOrderTerm {
                     value: Value::Text("order".repeat(64)),
                     direction: crate::db::query::plan::OrderDirection::Asc,
+                    nulls: crate::db::query::plan::NullsOrder::First,
                 }],
             },
         };
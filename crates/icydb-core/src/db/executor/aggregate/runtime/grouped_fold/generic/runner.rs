@@ -6,7 +6,8 @@ use crate::{
     db::executor::{
         aggregate::{
             ExecutionContext, FieldSlot, GroupError, ProjectionSpec,
-            contracts::GroupedDistinctExecutionMode,
+            collection_reducer::CollectionReducerSpec,
+            contracts::{GroupedDistinctExecutionMode, GroupedRetainedValueSpec},
             runtime::grouped_fold::{
                 bundle::{
                     GroupedAggregateBundle, GroupedAggregateBundleSpec, OrderedGroupedAggregateFold,
@@ -272,7 +273,7 @@ fn build_grouped_specs(
         .grouped_aggregate_execution_specs()
         .iter()
         .map(|aggregate_spec| {
-            GroupedAggregateBundleSpec::new(
+            let bundle_spec = GroupedAggregateBundleSpec::new(
                 aggregate_spec.kind(),
                 aggregate_spec.kind().materialized_fold_direction(),
                 GroupedDistinctExecutionMode::new(
@@ -285,6 +286,21 @@ fn build_grouped_specs(
                 grouped_execution_context
                     .config()
                     .max_distinct_values_per_group(),
+            )?;
+            if !aggregate_spec.kind().retains_input_values() {
+                return Ok(bundle_spec);
+            }
+
+            let collection = CollectionReducerSpec::from_arguments(
+                aggregate_spec.kind(),
+                aggregate_spec.arguments(),
+            )?;
+
+            Ok(
+                bundle_spec.with_retained_values(GroupedRetainedValueSpec::new(
+                    collection,
+                    aggregate_spec.compiled_order_exprs().to_vec(),
+                )),
             )
         })
         .collect::<Result<Vec<_>, _>>()
@@ -142,7 +142,7 @@ where
         .transpose()?
         .flatten();
 
-    let mut reducer_runtime = ScalarAggregateReducerRuntime::new(terminals);
+    let mut reducer_runtime = ScalarAggregateReducerRuntime::new(terminals)?;
     #[cfg(feature = "diagnostics")]
     {
         let (total_local_instructions, execution) = measure_phase(|| {
@@ -9,6 +9,8 @@ use crate::db::executor::aggregate::terminal_attribution::{
 use crate::{
     db::executor::{
         aggregate::{
+            AggregateKind,
+            collection_reducer::CollectionReducerState,
             scalar_terminals::{
                 expr_cache::ScalarTerminalExprCache,
                 terminal::{
@@ -146,13 +148,16 @@ struct ScalarAggregateReducerState {
 }
 
 impl ScalarAggregateReducerState {
-    fn new(output_index: usize, terminal: &InternedPreparedScalarAggregateTerminal) -> Self {
-        Self {
+    fn new(
+        output_index: usize,
+        terminal: &InternedPreparedScalarAggregateTerminal,
+    ) -> Result<Self, InternalError> {
+        Ok(Self {
             output_index,
             kind: terminal.kind,
             distinct_values: terminal.distinct.then(ScalarDistinctValueSet::new),
-            reducer: reducer_for_terminal_kind(terminal.kind),
-        }
+            reducer: reducer_for_terminal_kind(terminal.kind)?,
+        })
     }
 
     fn ingest_row(&mut self) -> Result<(), InternalError> {
@@ -181,8 +186,10 @@ impl ScalarAggregateReducerState {
             | ScalarAggregateTerminalKind::Sum
             | ScalarAggregateTerminalKind::Avg
             | ScalarAggregateTerminalKind::Min
-            | ScalarAggregateTerminalKind::Max => self.reducer.ingest(value),
-            ScalarAggregateTerminalKind::CountRows => {
+            | ScalarAggregateTerminalKind::Max
+            | ScalarAggregateTerminalKind::Statistic(_) => self.reducer.ingest(value),
+            ScalarAggregateTerminalKind::CountRows
+            | ScalarAggregateTerminalKind::RetainedValues => {
                 Err(InternalError::query_executor_invariant())
             }
         }
@@ -223,13 +230,15 @@ impl ScalarAggregateReducerState {
             | ScalarAggregateTerminalKind::Sum
             | ScalarAggregateTerminalKind::Avg
             | ScalarAggregateTerminalKind::Min
-            | ScalarAggregateTerminalKind::Max => {
+            | ScalarAggregateTerminalKind::Max
+            | ScalarAggregateTerminalKind::Statistic(_) => {
                 self.reducer.ingest(value)?;
                 distinct_values.insert(value_hash, value.clone())?;
 
                 Ok(())
             }
-            ScalarAggregateTerminalKind::CountRows => {
+            ScalarAggregateTerminalKind::CountRows
+            | ScalarAggregateTerminalKind::RetainedValues => {
                 Err(InternalError::query_executor_invariant())
             }
         }
@@ -242,8 +251,11 @@ impl ScalarAggregateReducerState {
 
 // Map one prepared terminal kind to the shared semantic reducer. Input routing
 // remains in this module; only value reducer payload semantics move to core.
-const fn reducer_for_terminal_kind(kind: ScalarAggregateTerminalKind) -> ValueReducerState {
-    match kind {
+// Retained-value terminals own a collection reducer instead.
+fn reducer_for_terminal_kind(
+    kind: ScalarAggregateTerminalKind,
+) -> Result<ValueReducerState, InternalError> {
+    let reducer = match kind {
         ScalarAggregateTerminalKind::CountRows | ScalarAggregateTerminalKind::CountValues => {
             ValueReducerState::count()
         }
@@ -251,7 +263,16 @@ const fn reducer_for_terminal_kind(kind: ScalarAggregateTerminalKind) -> ValueRe
         ScalarAggregateTerminalKind::Avg => ValueReducerState::avg(),
         ScalarAggregateTerminalKind::Min => ValueReducerState::min(),
         ScalarAggregateTerminalKind::Max => ValueReducerState::max(),
-    }
+        ScalarAggregateTerminalKind::Statistic(kind) => ValueReducerState::variance(
+            matches!(kind, AggregateKind::VarSamp | AggregateKind::StddevSamp),
+            matches!(kind, AggregateKind::StddevPop | AggregateKind::StddevSamp),
+        ),
+        ScalarAggregateTerminalKind::RetainedValues => {
+            return Err(InternalError::query_executor_invariant());
+        }
+    };
+
+    Ok(reducer)
 }
 
 ///
@@ -295,18 +316,36 @@ struct ExprAggregateReducer {
     expr_index: usize,
 }
 
+///
+/// RetainedValueAggregateReducer
+///
+/// RetainedValueAggregateReducer stores one PERCENTILE_DISC, STRING_AGG, or
+/// ARRAY_AGG terminal. Every admitted value and its aggregate-local ORDER BY
+/// keys are charged to the DISTINCT-state budget before they are retained.
+///
+
+struct RetainedValueAggregateReducer {
+    filter: Option<usize>,
+    output_index: usize,
+    input: InternedScalarAggregateInput,
+    order_exprs: Vec<usize>,
+    state: CollectionReducerState,
+}
+
 ///
 /// ScalarAggregateReducerRuntime
 ///
 /// ScalarAggregateReducerRuntime owns one scalar aggregate sink invocation.
-/// It keeps reducer states in row, field, and expression lists so terminal input
-/// strategy is resolved once before source rows enter the hot reducer loop.
+/// It keeps reducer states in row, field, expression, and retained-value lists
+/// so terminal input strategy is resolved once before source rows enter the
+/// hot reducer loop.
 ///
 
 pub(super) struct ScalarAggregateReducerRuntime {
     row_reducers: Vec<RowAggregateReducer>,
     field_reducers: Vec<FieldAggregateReducer>,
     expr_reducers: Vec<ExprAggregateReducer>,
+    retained_reducers: Vec<RetainedValueAggregateReducer>,
     terminal_count: usize,
     expr_cache: ScalarTerminalExprCache,
     #[cfg(feature = "diagnostics")]
@@ -316,7 +355,9 @@ pub(super) struct ScalarAggregateReducerRuntime {
 impl ScalarAggregateReducerRuntime {
     // Build a reducer sink from one prepared terminal set, preserving the
     // expression-interning tables created during terminal preparation.
-    pub(super) fn new(terminals: PreparedScalarAggregateTerminalSet) -> Self {
+    pub(super) fn new(
+        terminals: PreparedScalarAggregateTerminalSet,
+    ) -> Result<Self, InternalError> {
         let (terminals, input_exprs, filter_exprs) = terminals.into_runtime_inputs();
         let terminal_count = terminals.len();
         // Count reducer buckets before consuming the terminal vector so each
@@ -325,7 +366,12 @@ impl ScalarAggregateReducerRuntime {
         let mut row_reducer_capacity = 0;
         let mut field_reducer_capacity = 0;
         let mut expr_reducer_capacity = 0;
+        let mut retained_reducer_capacity = 0;
         for terminal in &terminals {
+            if terminal.retained.is_some() {
+                retained_reducer_capacity += 1;
+                continue;
+            }
             match &terminal.input {
                 InternedScalarAggregateInput::Rows => {
                     row_reducer_capacity += 1;
@@ -341,13 +387,27 @@ impl ScalarAggregateReducerRuntime {
         let mut row_reducers = Vec::with_capacity(row_reducer_capacity);
         let mut field_reducers = Vec::with_capacity(field_reducer_capacity);
         let mut expr_reducers = Vec::with_capacity(expr_reducer_capacity);
+        let mut retained_reducers = Vec::with_capacity(retained_reducer_capacity);
 
         // Classify terminal input strategy once, before row ingestion. The row
-        // loop then runs three concrete reducer lists instead of matching on
+        // loop then runs four concrete reducer lists instead of matching on
         // input kind for every reducer on every row.
         for (output_index, terminal) in terminals.into_iter().enumerate() {
-            let state = ScalarAggregateReducerState::new(output_index, &terminal);
             let filter = terminal.filter;
+            if let Some(retained) = terminal.retained {
+                if terminal.distinct {
+                    return Err(InternalError::query_executor_invariant());
+                }
+                retained_reducers.push(RetainedValueAggregateReducer {
+                    filter,
+                    output_index,
+                    input: terminal.input,
+                    order_exprs: retained.order_exprs,
+                    state: CollectionReducerState::new(retained.collection),
+                });
+                continue;
+            }
+            let state = ScalarAggregateReducerState::new(output_index, &terminal)?;
             match terminal.input {
                 InternedScalarAggregateInput::Rows => {
                     row_reducers.push(RowAggregateReducer { filter, state });
@@ -369,15 +429,16 @@ impl ScalarAggregateReducerRuntime {
             }
         }
 
-        Self {
+        Ok(Self {
             row_reducers,
             field_reducers,
             expr_reducers,
+            retained_reducers,
             terminal_count,
             expr_cache: ScalarTerminalExprCache::new(input_exprs, filter_exprs),
             #[cfg(feature = "diagnostics")]
             attribution: ScalarAggregateTerminalAttribution::none(),
-        }
+        })
     }
 
     // Ingest one scalar-window row into every aggregate reducer. Filters are
@@ -409,6 +470,7 @@ impl ScalarAggregateReducerRuntime {
         self.ingest_row_reducers(row)?;
         self.ingest_field_reducers(row)?;
         self.ingest_expr_reducers(row)?;
+        self.ingest_retained_reducers(row)?;
 
         Ok(())
     }
@@ -471,6 +533,64 @@ impl ScalarAggregateReducerRuntime {
         Ok(())
     }
 
+    // Retained-value reducers own their input value and ORDER BY keys, so
+    // they clone at admission and charge the retained bytes before storing.
+    fn ingest_retained_reducers(&mut self, row: &KernelRow) -> Result<(), InternalError> {
+        for reducer in &mut self.retained_reducers {
+            if !self.expr_cache.filter_matches(
+                reducer.filter,
+                row,
+                #[cfg(feature = "diagnostics")]
+                &mut self.attribution.filter_evaluations,
+            )? {
+                continue;
+            }
+            let value = match &reducer.input {
+                InternedScalarAggregateInput::Field { slot, .. } => row
+                    .slot_ref(*slot)
+                    .ok_or_else(|| {
+                        ProjectionEvalError::missing_slot_value(*slot)
+                            .into_invalid_logical_plan_internal_error()
+                    })?
+                    .clone(),
+                InternedScalarAggregateInput::Expr(expr_index) => self
+                    .expr_cache
+                    .input_value(
+                        row,
+                        *expr_index,
+                        #[cfg(feature = "diagnostics")]
+                        &mut self.attribution.expression_evaluations,
+                    )?
+                    .clone(),
+                InternedScalarAggregateInput::Rows => {
+                    return Err(InternalError::query_executor_invariant());
+                }
+            };
+            if !reducer.state.spec().retains(&value) {
+                continue;
+            }
+
+            let mut order_keys = Vec::new();
+            try_reserve_vec_elements(&mut order_keys, reducer.order_exprs.len())?;
+            for expr_index in &reducer.order_exprs {
+                order_keys.push(
+                    self.expr_cache
+                        .input_value(
+                            row,
+                            *expr_index,
+                            #[cfg(feature = "diagnostics")]
+                            &mut self.attribution.expression_evaluations,
+                        )?
+                        .clone(),
+                );
+            }
+            charge_retained_scalar_value(&value, order_keys.as_slice())?;
+            reducer.state.ingest(value, order_keys)?;
+        }
+
+        Ok(())
+    }
+
     // Finalize reducer states in original terminal order.
     pub(super) fn finalize(self) -> Result<Vec<Value>, InternalError> {
         let mut values = vec![None; self.terminal_count];
@@ -487,6 +607,11 @@ impl ScalarAggregateReducerRuntime {
                 self.expr_reducers
                     .into_iter()
                     .map(|reducer| reducer.state.finalize()),
+            )
+            .chain(
+                self.retained_reducers
+                    .into_iter()
+                    .map(|reducer| Ok((reducer.output_index, reducer.state.into_final_value()?))),
             );
         for finalized in finalized {
             let (index, value) = finalized?;
@@ -508,6 +633,26 @@ impl ScalarAggregateReducerRuntime {
     }
 }
 
+// Charge one retained-value entry against the scalar DISTINCT-state budget.
+// The value and its ORDER BY keys stay resident until finalization, so their
+// structural bytes and nested traversal steps are charged up front.
+fn charge_retained_scalar_value(value: &Value, order_keys: &[Value]) -> Result<(), InternalError> {
+    let (mut bytes, mut steps) = runtime_value_work(value);
+    for key in order_keys {
+        let (key_bytes, key_steps) = runtime_value_work(key);
+        bytes = bytes.saturating_add(key_bytes);
+        steps = steps.saturating_add(key_steps);
+    }
+    bytes = bytes.saturating_add(retained_vec_element_backing_bytes::<(Value, Vec<Value>)>());
+
+    charge_current_execution_budget(DiagnosticExecutionBudgetResource::GroupDistinctEntries, 1)?;
+    charge_current_execution_budget(
+        DiagnosticExecutionBudgetResource::GroupDistinctStateBytes,
+        bytes,
+    )?;
+    charge_current_execution_budget(DiagnosticExecutionBudgetResource::NestedValueSteps, steps)
+}
+
 #[cfg(test)]
 mod tests {
     use super::{ScalarDistinctValueBucket, ScalarDistinctValueSet};
@@ -8,7 +8,8 @@ use crate::{
             EntityAuthority,
             aggregate::{
                 AccessPlannedQuery, AggregateKind, CompiledExpr, Expr, FieldId, FieldSlot,
-                GroupedAggregateExecutionSpec, compile_scalar_projection_expr_with_schema,
+                GroupedAggregateExecutionSpec, collection_reducer::CollectionReducerSpec,
+                compile_scalar_projection_expr_with_schema,
                 scalar_terminals::expr_cache::intern_scalar_terminal_expr,
             },
             pipeline::{
@@ -17,6 +18,7 @@ use crate::{
             },
             terminal::RetainedSlotLayout,
         },
+        query::plan::AggregateArguments,
         schema::SchemaInfo,
     },
     error::InternalError,
@@ -37,6 +39,7 @@ pub(in crate::db) struct StructuralAggregateTerminal {
     input_expr: Option<Expr>,
     filter_expr: Option<Expr>,
     distinct: bool,
+    arguments: Option<AggregateArguments>,
 }
 
 impl StructuralAggregateTerminal {
@@ -55,8 +58,17 @@ impl StructuralAggregateTerminal {
             input_expr,
             filter_expr,
             distinct,
+            arguments: None,
         }
     }
+
+    /// Attach retained-value aggregate arguments such as the STRING_AGG
+    /// separator or aggregate-local ORDER BY terms.
+    #[must_use]
+    pub(in crate::db) fn with_arguments(mut self, arguments: Option<AggregateArguments>) -> Self {
+        self.arguments = arguments;
+        self
+    }
 }
 
 ///
@@ -65,6 +77,7 @@ impl StructuralAggregateTerminal {
 /// StructuralAggregateTerminalKind selects the reducer family requested by one
 /// structural global aggregate terminal. Count-row and count-value variants
 /// stay separate because they have different input and fast-count eligibility.
+/// Statistical and retained-value variants carry their exact planner kind.
 ///
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -75,6 +88,8 @@ pub(in crate::db) enum StructuralAggregateTerminalKind {
     Avg,
     Min,
     Max,
+    Statistic(AggregateKind),
+    RetainedValues(AggregateKind),
 }
 
 ///
@@ -145,7 +160,9 @@ impl PreparedScalarAggregateTerminalSet {
             ScalarAggregateTerminalKind::CountRows
             | ScalarAggregateTerminalKind::CountValues
             | ScalarAggregateTerminalKind::Sum
-            | ScalarAggregateTerminalKind::Avg => return None,
+            | ScalarAggregateTerminalKind::Avg
+            | ScalarAggregateTerminalKind::Statistic(_)
+            | ScalarAggregateTerminalKind::RetainedValues => return None,
         };
 
         Some((kind, field.as_str()))
@@ -225,6 +242,7 @@ pub(super) struct PreparedScalarAggregateTerminal {
     input: ScalarAggregateInput,
     filter: Option<CompiledExpr>,
     distinct: bool,
+    retained: Option<PreparedScalarRetainedValues>,
 }
 
 impl PreparedScalarAggregateTerminal {
@@ -241,9 +259,18 @@ impl PreparedScalarAggregateTerminal {
             input,
             filter,
             distinct,
+            retained: None,
         }
     }
 
+    /// Attach the retained-value reducer contract and compiled
+    /// aggregate-local ORDER BY expressions.
+    #[must_use]
+    pub(super) fn with_retained_values(mut self, retained: PreparedScalarRetainedValues) -> Self {
+        self.retained = Some(retained);
+        self
+    }
+
     fn into_interned(
         self,
         input_exprs: &mut Vec<CompiledExpr>,
@@ -261,22 +288,60 @@ impl PreparedScalarAggregateTerminal {
         let filter = self
             .filter
             .map(|expr| intern_scalar_terminal_expr(filter_exprs, expr));
+        let retained = self.retained.map(|retained| InternedScalarRetainedValues {
+            collection: retained.collection,
+            order_exprs: retained
+                .order_exprs
+                .into_iter()
+                .map(|expr| intern_scalar_terminal_expr(input_exprs, expr))
+                .collect(),
+        });
 
         InternedPreparedScalarAggregateTerminal {
             kind: self.kind,
             input,
             filter,
             distinct: self.distinct,
+            retained,
         }
     }
 }
 
+///
+/// PreparedScalarRetainedValues
+///
+/// PreparedScalarRetainedValues carries the retained-value reducer contract
+/// for PERCENTILE_DISC, STRING_AGG, and ARRAY_AGG terminals together with
+/// their compiled aggregate-local ORDER BY expressions.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) struct PreparedScalarRetainedValues {
+    collection: CollectionReducerSpec,
+    order_exprs: Vec<CompiledExpr>,
+}
+
+///
+/// InternedScalarRetainedValues
+///
+/// InternedScalarRetainedValues is the runtime form of one retained-value
+/// contract. ORDER BY expressions share the terminal set's input-expression
+/// table so they are evaluated at most once per row.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) struct InternedScalarRetainedValues {
+    pub(super) collection: CollectionReducerSpec,
+    pub(super) order_exprs: Vec<usize>,
+}
+
 ///
 /// ScalarAggregateTerminalKind
 ///
 /// ScalarAggregateTerminalKind selects the reducer family used for one
 /// scalar-window aggregate terminal. These variants intentionally model only
-/// row-count, value-count, numeric, and extrema reducers, not grouped folds.
+/// row-count, value-count, numeric, statistical, extrema, and retained-value
+/// reducers, not grouped folds.
 ///
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -287,6 +352,8 @@ pub(super) enum ScalarAggregateTerminalKind {
     Avg,
     Min,
     Max,
+    Statistic(AggregateKind),
+    RetainedValues,
 }
 
 ///
@@ -318,6 +385,7 @@ pub(super) struct InternedPreparedScalarAggregateTerminal {
     pub(super) input: InternedScalarAggregateInput,
     pub(super) filter: Option<usize>,
     pub(super) distinct: bool,
+    pub(super) retained: Option<InternedScalarRetainedValues>,
 }
 
 ///
@@ -364,6 +432,7 @@ pub(super) struct ResolvedStructuralAggregateTerminal<'a> {
     input: ResolvedStructuralAggregateInput<'a>,
     filter_expr: Option<&'a Expr>,
     distinct: bool,
+    arguments: Option<&'a AggregateArguments>,
 }
 
 impl ResolvedStructuralAggregateTerminal<'_> {
@@ -374,6 +443,7 @@ impl ResolvedStructuralAggregateTerminal<'_> {
             self.input.grouped_input_expr(),
             self.filter_expr.cloned(),
             self.distinct,
+            self.arguments.cloned(),
         )
     }
 
@@ -387,12 +457,32 @@ impl ResolvedStructuralAggregateTerminal<'_> {
             .map(|expr| compile_structural_aggregate_expr(schema, expr, "filter"))
             .transpose()?;
 
-        Ok(PreparedScalarAggregateTerminal::from_validated_inputs(
+        let prepared = PreparedScalarAggregateTerminal::from_validated_inputs(
             self.scalar_kind,
             input,
             filter,
             self.distinct,
-        ))
+        );
+        if !self.aggregate_kind.retains_input_values() {
+            return Ok(prepared);
+        }
+
+        // Retained-value terminals resolve their reducer contract and
+        // aggregate-local ORDER BY programs once, before any row is read.
+        let collection =
+            CollectionReducerSpec::from_arguments(self.aggregate_kind, self.arguments)?;
+        let order_exprs = self
+            .arguments
+            .map(AggregateArguments::order_by)
+            .unwrap_or_default()
+            .iter()
+            .map(|term| compile_structural_aggregate_expr(schema, term.expr(), "order"))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(prepared.with_retained_values(PreparedScalarRetainedValues {
+            collection,
+            order_exprs,
+        }))
     }
 }
 
@@ -454,7 +544,9 @@ pub(super) const fn resolve_structural_aggregate_terminal(
         | StructuralAggregateTerminalKind::Sum
         | StructuralAggregateTerminalKind::Avg
         | StructuralAggregateTerminalKind::Min
-        | StructuralAggregateTerminalKind::Max => {
+        | StructuralAggregateTerminalKind::Max
+        | StructuralAggregateTerminalKind::Statistic(_)
+        | StructuralAggregateTerminalKind::RetainedValues(_) => {
             if let Some(input_expr) = terminal.input_expr.as_ref() {
                 ResolvedStructuralAggregateInput::Expr(input_expr)
             } else if let Some(target_slot) = terminal.target_slot.as_ref() {
@@ -471,6 +563,7 @@ pub(super) const fn resolve_structural_aggregate_terminal(
         input,
         filter_expr: terminal.filter_expr.as_ref(),
         distinct: terminal.distinct,
+        arguments: terminal.arguments.as_ref(),
     }
 }
 
@@ -493,6 +586,8 @@ impl StructuralAggregateTerminalKind {
             Self::Avg => (AggregateKind::Avg, ScalarAggregateTerminalKind::Avg),
             Self::Min => (AggregateKind::Min, ScalarAggregateTerminalKind::Min),
             Self::Max => (AggregateKind::Max, ScalarAggregateTerminalKind::Max),
+            Self::Statistic(kind) => (kind, ScalarAggregateTerminalKind::Statistic(kind)),
+            Self::RetainedValues(kind) => (kind, ScalarAggregateTerminalKind::RetainedValues),
         }
     }
 }
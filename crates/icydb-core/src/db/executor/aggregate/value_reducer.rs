@@ -1,26 +1,30 @@
 //! Module: executor::aggregate::value_reducer
 //! Responsibility: shared value aggregate reducer semantics.
 //! Does not own: row access, DISTINCT admission, grouped keys, or execution routing.
-//! Boundary: allocation-free COUNT(value), SUM, AVG, MIN, and MAX state transitions.
+//! Boundary: allocation-free COUNT(value), SUM, AVG, MIN, MAX, VAR, and STDDEV state transitions.
 
 // Single source of truth for value aggregate semantics:
-// COUNT(value), SUM, AVG, MIN, MAX.
+// COUNT(value), SUM, AVG, MIN, MAX, VAR_POP/SAMP, STDDEV_POP/SAMP.
 //
 // All execution paths (scalar, grouped, global) must delegate here.
 // Does NOT handle DISTINCT, grouping, or key-stream semantics.
 
-use crate::{
-    db::numeric::{
-        NumericEvalError, add_decimal_terms_checked, average_decimal_terms_checked,
-        coerce_numeric_decimal,
-    },
-    types::Decimal,
-};
 use crate::{
     db::numeric::{canonical_value_compare, compare_numeric_or_strict_order},
     error::InternalError,
     value::Value,
 };
+use crate::{
+    db::{
+        executor::aggregate::exact_sum::{SumAccumulator, SumTerm},
+        numeric::{
+            NumericArithmeticOp, NumericEvalError, add_decimal_terms_checked,
+            apply_decimal_arithmetic_checked, coerce_numeric_decimal, decimal_sqrt_checked,
+            divide_decimal_terms_checked,
+        },
+    },
+    types::Decimal,
+};
 
 ///
 /// ValueReducerState
@@ -31,11 +35,30 @@ use crate::{
 /// control flow.
 ///
 pub(in crate::db::executor::aggregate) enum ValueReducerState {
-    Count { count: u64 },
-    Sum { sum: Option<Decimal>, count: u64 },
-    Avg { sum: Decimal, count: u64 },
-    Min { selected: Option<Value> },
-    Max { selected: Option<Value> },
+    Count {
+        count: u64,
+    },
+    Sum {
+        sum: SumAccumulator,
+        count: u64,
+    },
+    Avg {
+        sum: SumAccumulator,
+        count: u64,
+    },
+    Min {
+        selected: Option<Value>,
+    },
+    Max {
+        selected: Option<Value>,
+    },
+    Variance {
+        sum: Decimal,
+        sum_squares: Decimal,
+        count: u64,
+        sample: bool,
+        root: bool,
+    },
 }
 
 impl ValueReducerState {
@@ -47,7 +70,7 @@ impl ValueReducerState {
     #[must_use]
     pub(in crate::db::executor::aggregate) const fn sum() -> Self {
         Self::Sum {
-            sum: None,
+            sum: SumAccumulator::Empty,
             count: 0,
         }
     }
@@ -55,7 +78,7 @@ impl ValueReducerState {
     #[must_use]
     pub(in crate::db::executor::aggregate) const fn avg() -> Self {
         Self::Avg {
-            sum: Decimal::ZERO,
+            sum: SumAccumulator::Empty,
             count: 0,
         }
     }
@@ -70,9 +93,24 @@ impl ValueReducerState {
         Self::Max { selected: None }
     }
 
+    /// Build one VAR_POP/VAR_SAMP (`root == false`) or STDDEV_POP/STDDEV_SAMP
+    /// (`root == true`) reducer.
+    #[must_use]
+    pub(in crate::db::executor::aggregate) const fn variance(sample: bool, root: bool) -> Self {
+        Self::Variance {
+            sum: Decimal::ZERO,
+            sum_squares: Decimal::ZERO,
+            count: 0,
+            sample,
+            root,
+        }
+    }
+
     /// Ingest one borrowed aggregate input value.
     ///
-    /// COUNT ignores NULL, SUM/AVG coerce numeric values, and MIN/MAX compare
+    /// COUNT ignores NULL, SUM/AVG keep 128-bit and arbitrary-precision
+    /// integers exact and coerce other numeric values, VAR/STDDEV coerce
+    /// numeric values, and MIN/MAX compare
     /// with the same numeric-or-strict ordering used by scalar expression
     /// aggregates. Values are cloned only when they become the selected extrema.
     pub(in crate::db::executor::aggregate) fn ingest(
@@ -86,6 +124,12 @@ impl ValueReducerState {
         match self {
             Self::Count { .. } => self.increment_count(),
             Self::Sum { .. } | Self::Avg { .. } => {
+                let term = SumTerm::from_value(value)
+                    .ok_or_else(InternalError::query_executor_invariant)?;
+
+                self.ingest_sum_term(term)
+            }
+            Self::Variance { .. } => {
                 let decimal = coerce_numeric_decimal(value)
                     .ok_or_else(InternalError::query_executor_invariant)?;
 
@@ -120,6 +164,12 @@ impl ValueReducerState {
         match self {
             Self::Count { .. } => self.increment_count(),
             Self::Sum { .. } | Self::Avg { .. } => {
+                let term = SumTerm::from_value(&value)
+                    .ok_or_else(InternalError::query_executor_invariant)?;
+
+                self.ingest_sum_term(term)
+            }
+            Self::Variance { .. } => {
                 let decimal = coerce_numeric_decimal(&value)
                     .ok_or_else(InternalError::query_executor_invariant)?;
 
@@ -150,29 +200,51 @@ impl ValueReducerState {
                 *count = count.saturating_add(1);
                 Ok(())
             }
-            Self::Sum { .. } | Self::Avg { .. } | Self::Min { .. } | Self::Max { .. } => {
-                Err(reducer_state_mismatch("COUNT"))
-            }
+            Self::Sum { .. }
+            | Self::Avg { .. }
+            | Self::Min { .. }
+            | Self::Max { .. }
+            | Self::Variance { .. } => Err(reducer_state_mismatch("COUNT")),
         }
     }
 
-    pub(in crate::db::executor::aggregate) fn ingest_decimal(
+    /// Ingest one already admitted SUM/AVG term.
+    pub(in crate::db::executor::aggregate) fn ingest_sum_term(
         &mut self,
-        value: Decimal,
+        term: SumTerm,
     ) -> Result<(), InternalError> {
         match self {
-            Self::Sum { sum, count } => {
-                *sum = Some(match sum {
-                    Some(current) => add_decimal_terms_checked(*current, value)
-                        .map_err(NumericEvalError::into_internal_error)?,
-                    None => value,
-                });
+            Self::Sum { sum, count } | Self::Avg { sum, count } => {
+                sum.add(term)
+                    .map_err(NumericEvalError::into_internal_error)?;
                 *count = count.saturating_add(1);
                 Ok(())
             }
-            Self::Avg { sum, count } => {
+            Self::Count { .. } | Self::Min { .. } | Self::Max { .. } | Self::Variance { .. } => {
+                Err(reducer_state_mismatch("SUM/AVG"))
+            }
+        }
+    }
+
+    pub(in crate::db::executor::aggregate) fn ingest_decimal(
+        &mut self,
+        value: Decimal,
+    ) -> Result<(), InternalError> {
+        match self {
+            Self::Sum { .. } | Self::Avg { .. } => self.ingest_sum_term(SumTerm::Decimal(value)),
+            Self::Variance {
+                sum,
+                sum_squares,
+                count,
+                ..
+            } => {
+                let square =
+                    apply_decimal_arithmetic_checked(NumericArithmeticOp::Mul, value, value)
+                        .map_err(NumericEvalError::into_internal_error)?;
                 *sum = add_decimal_terms_checked(*sum, value)
                     .map_err(NumericEvalError::into_internal_error)?;
+                *sum_squares = add_decimal_terms_checked(*sum_squares, square)
+                    .map_err(NumericEvalError::into_internal_error)?;
                 *count = count.saturating_add(1);
                 Ok(())
             }
@@ -211,7 +283,7 @@ impl ValueReducerState {
 
                 Ok(())
             }
-            Self::Count { .. } | Self::Sum { .. } | Self::Avg { .. } => {
+            Self::Count { .. } | Self::Sum { .. } | Self::Avg { .. } | Self::Variance { .. } => {
                 Err(reducer_state_mismatch("MIN/MAX"))
             }
         }
@@ -221,7 +293,9 @@ impl ValueReducerState {
     pub(in crate::db::executor::aggregate) const fn selected(&self) -> Option<&Value> {
         match self {
             Self::Min { selected } | Self::Max { selected } => selected.as_ref(),
-            Self::Count { .. } | Self::Sum { .. } | Self::Avg { .. } => None,
+            Self::Count { .. } | Self::Sum { .. } | Self::Avg { .. } | Self::Variance { .. } => {
+                None
+            }
         }
     }
 
@@ -234,7 +308,7 @@ impl ValueReducerState {
                 *selected = Some(value);
                 Ok(())
             }
-            Self::Count { .. } | Self::Sum { .. } | Self::Avg { .. } => {
+            Self::Count { .. } | Self::Sum { .. } | Self::Avg { .. } | Self::Variance { .. } => {
                 Err(reducer_state_mismatch("MIN/MAX"))
             }
         }
@@ -248,21 +322,64 @@ impl ValueReducerState {
     ) -> Result<Value, InternalError> {
         match self {
             Self::Count { count } => Ok(finalize_count(count)),
-            Self::Sum { sum, .. } => Ok(sum.map_or(Value::Null, Value::Decimal)),
-            Self::Avg { sum, count } => {
-                if count == 0 {
-                    return Ok(Value::Null);
-                }
-
-                average_decimal_terms_checked(sum, count)
-                    .map(Value::Decimal)
-                    .map_err(NumericEvalError::into_internal_error)
-            }
+            Self::Sum { sum, .. } => sum
+                .into_sum_value()
+                .map_err(NumericEvalError::into_internal_error),
+            Self::Avg { sum, count } => sum
+                .into_average_value(count)
+                .map_err(NumericEvalError::into_internal_error),
             Self::Min { selected } | Self::Max { selected } => Ok(selected.unwrap_or(Value::Null)),
+            Self::Variance {
+                sum,
+                sum_squares,
+                count,
+                sample,
+                root,
+            } => finalize_variance(sum, sum_squares, count, sample, root)
+                .map_err(NumericEvalError::into_internal_error),
         }
     }
 }
 
+// Finalize one variance payload as `(n * sum_sq - sum^2) / (n * d)` where `d`
+// is `n` for population and `n - 1` for sample variance. Keeping the single
+// division last preserves exact decimal intermediates, and the numerator is
+// clamped at zero so STDDEV never observes a negative rounding artifact.
+fn finalize_variance(
+    sum: Decimal,
+    sum_squares: Decimal,
+    count: u64,
+    sample: bool,
+    root: bool,
+) -> Result<Value, NumericEvalError> {
+    let minimum_count = if sample { 2 } else { 1 };
+    if count < minimum_count {
+        return Ok(Value::Null);
+    }
+
+    let n = Decimal::from_num(count).ok_or(NumericEvalError::NotRepresentable)?;
+    let degrees = Decimal::from_num(if sample { count - 1 } else { count })
+        .ok_or(NumericEvalError::NotRepresentable)?;
+    let scaled_squares =
+        apply_decimal_arithmetic_checked(NumericArithmeticOp::Mul, n, sum_squares)?;
+    let squared_sum = apply_decimal_arithmetic_checked(NumericArithmeticOp::Mul, sum, sum)?;
+    let numerator =
+        apply_decimal_arithmetic_checked(NumericArithmeticOp::Sub, scaled_squares, squared_sum)?;
+    let numerator = if numerator.is_sign_negative() {
+        Decimal::ZERO
+    } else {
+        numerator
+    };
+    let denominator = apply_decimal_arithmetic_checked(NumericArithmeticOp::Mul, n, degrees)?;
+    let variance = divide_decimal_terms_checked(numerator, denominator)?;
+
+    if root {
+        return decimal_sqrt_checked(variance).map(|value| Value::Decimal(value.normalize()));
+    }
+
+    Ok(Value::Decimal(variance.normalize()))
+}
+
 /// Finalize one COUNT reducer payload through the shared aggregate policy.
 #[must_use]
 pub(in crate::db::executor::aggregate) const fn finalize_count(count: u64) -> Value {
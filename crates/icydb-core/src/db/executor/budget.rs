@@ -10,6 +10,7 @@ use crate::db::QueryError;
 #[cfg(feature = "diagnostics")]
 use crate::db::diagnostics::RequestDiagnosticResourceUsage;
 use crate::db::executor::{EntityAuthority, RuntimeGroupedRow, SharedPreparedExecutionPlan};
+use crate::db::query::plan::expr::take_text_pattern_match_steps;
 use crate::db::session::RequestExecutionScope;
 use crate::{error::InternalError, value::Value};
 use icydb_diagnostic_code::{
@@ -666,6 +667,15 @@ pub(in crate::db) fn charge_current_execution_budget(
     })
 }
 
+/// Charge matcher steps recorded by LIKE and regular-expression evaluation
+/// since the last drain as predicate/expression steps.
+pub(in crate::db) fn charge_current_text_pattern_match_steps() -> Result<(), InternalError> {
+    charge_current_execution_budget(
+        DiagnosticExecutionBudgetResource::PredicateExpressionSteps,
+        take_text_pattern_match_steps(),
+    )
+}
+
 /// Charge two maintained physical resources through one active-budget lookup.
 ///
 /// The charges retain their declared order: if the first charge exhausts its
@@ -970,6 +980,8 @@ fn with_execution_budget<T, E>(
     if !installed {
         return run();
     }
+    // Steps recorded outside any budgeted execution belong to no query.
+    let _ = take_text_pattern_match_steps();
 
     let result = run();
     let final_budget_result = match finish {
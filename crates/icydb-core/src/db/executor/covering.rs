@@ -20,7 +20,7 @@ use crate::{
             index_predicate_rejects_prefix_components, index_stream_chunk_entries_for_remaining,
             index_stream_output_limit_for_chunk, route::IndexPrefixChildExpansionBudget,
         },
-        index::{RawIndexStoreKey, predicate::IndexPredicateExecution},
+        index::{RawIndexStoreKey, ascending_index_component, predicate::IndexPredicateExecution},
         predicate::MissingRowPolicy,
         query::plan::{CoveringExistingRowMode, CoveringProjectionOrder},
         registry::StoreHandle,
@@ -809,6 +809,7 @@ impl<'a> CoveringComponentFlatMergeChild<'a> {
 
 impl FlatMergeOrderedChild for CoveringComponentFlatMergeChild<'_> {
     type Item = IndexComponentRow;
+    type Key = DecodedDataStoreKey;
     type KeyWitness = DecodedDataStoreKey;
 
     fn ensure_item(&mut self) -> Result<(), InternalError> {
@@ -1046,6 +1047,7 @@ where
 pub(in crate::db::executor) fn decode_covering_projection_component(
     component: &[u8],
 ) -> Result<Option<Value>, InternalError> {
+    let component = ascending_index_component(component);
     let Some((&tag, payload)) = component.split_first() else {
         return Err(InternalError::bytes_covering_component_payload_empty());
     };
@@ -1068,6 +1070,9 @@ pub(in crate::db::executor) fn decode_covering_projection_component(
     if tag == ValueTag::Unit.to_u8() {
         return Ok(Some(Value::Unit));
     }
+    if tag == ValueTag::Null.to_u8() && payload.is_empty() {
+        return Ok(Some(Value::Null));
+    }
 
     Ok(None)
 }
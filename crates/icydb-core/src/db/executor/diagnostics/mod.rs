@@ -168,7 +168,8 @@ fn collect_conjunctive_equality_fields<'a>(predicate: &'a Predicate, fields: &mu
         | Predicate::IsEmpty { .. }
         | Predicate::IsNotEmpty { .. }
         | Predicate::TextContains { .. }
-        | Predicate::TextContainsCi { .. } => {}
+        | Predicate::TextContainsCi { .. }
+        | Predicate::TextMatch { .. } => {}
     }
 }
 
@@ -192,7 +193,8 @@ fn collect_predicate_fields<'a>(predicate: &'a Predicate, fields: &mut Vec<&'a s
         | Predicate::IsEmpty { field }
         | Predicate::IsNotEmpty { field }
         | Predicate::TextContains { field, .. }
-        | Predicate::TextContainsCi { field, .. } => push_unique_field(fields, field),
+        | Predicate::TextContainsCi { field, .. }
+        | Predicate::TextMatch { field, .. } => push_unique_field(fields, field),
         Predicate::True | Predicate::False => {}
     }
 }
@@ -0,0 +1,86 @@
+//! Module: db::executor::explain::descriptor::common_table_expression
+//! Responsibility: EXPLAIN nodes for common table expressions the SQL
+//! frontend inlined into one outer plan.
+//! Does not own: CTE inlining or the grouped/DISTINCT budgets themselves.
+//! Boundary: reports which stage of the inlined plan bounds each CTE body.
+
+use crate::{
+    db::{
+        executor::explain::descriptor::shared::empty_execution_node_descriptor,
+        query::{
+            explain::{
+                ExplainExecutionMode, ExplainExecutionNodeDescriptor, ExplainExecutionNodeType,
+                property_keys,
+            },
+            plan::AccessPlannedQuery,
+        },
+    },
+    value::Value,
+};
+
+/// Attach one node for an inlined common table expression to the root of
+/// one execution descriptor. Callers attach entries innermost first.
+///
+/// Materialized bodies sit directly after the grouped or DISTINCT stage that
+/// bounds them, and grouped bodies report that stage's group budget. Inline
+/// bodies only add scalar filters and projections, so they lead the pipeline.
+pub(in crate::db) fn annotate_common_table_expression_node(
+    descriptor: &mut ExplainExecutionNodeDescriptor,
+    plan: &AccessPlannedQuery,
+    name: &str,
+    materialization: &'static str,
+    materialized: bool,
+) {
+    let execution_mode = if materialized {
+        ExplainExecutionMode::Materialized
+    } else {
+        ExplainExecutionMode::Streaming
+    };
+    let mut node = empty_execution_node_descriptor(
+        ExplainExecutionNodeType::CommonTableExpression,
+        execution_mode,
+    );
+    node.node_properties
+        .insert(property_keys::CTE_NAME, Value::from(name));
+    node.node_properties.insert(
+        property_keys::CTE_MATERIALIZATION,
+        Value::from(materialization),
+    );
+
+    let leading = descriptor
+        .children
+        .iter()
+        .take_while(|child| child.node_type == ExplainExecutionNodeType::CommonTableExpression)
+        .count();
+    let position = if materialized {
+        if let Some(grouped) = plan.grouped_plan() {
+            node.node_properties.insert(
+                property_keys::MAX_GROUPS,
+                Value::from(grouped.group.execution.max_groups),
+            );
+            node.node_properties.insert(
+                property_keys::MAX_GROUP_BYTES,
+                Value::from(grouped.group.execution.max_group_bytes),
+            );
+        }
+        descriptor
+            .children
+            .iter()
+            .rposition(|child| bounds_common_table_expression(child.node_type))
+            .map_or(leading, |index| index + 1)
+    } else {
+        leading
+    };
+    descriptor.children.insert(position, node);
+}
+
+const fn bounds_common_table_expression(node_type: ExplainExecutionNodeType) -> bool {
+    matches!(
+        node_type,
+        ExplainExecutionNodeType::GroupedAggregateHashMaterialized
+            | ExplainExecutionNodeType::GroupedAggregateOrderedStreaming
+            | ExplainExecutionNodeType::DistinctPreOrdered
+            | ExplainExecutionNodeType::DistinctMaterialized
+            | ExplainExecutionNodeType::CommonTableExpression
+    )
+}
@@ -347,6 +347,12 @@ fn load_modifier_execution_nodes(
     {
         nodes.push(node);
     }
+    if let Some(partition_limit) = plan.scalar_plan().partition_limit.as_ref() {
+        nodes.push(crate::db::executor::explain::descriptor::shared::partition_limit_execution_node_descriptor(
+            partition_limit,
+            execution_mode,
+        ));
+    }
     if let Some(node) = covering_projection_execution_node_descriptor(
         load_terminal_fast_path,
         hybrid_covering_read_plan,
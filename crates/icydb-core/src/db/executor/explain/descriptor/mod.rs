@@ -5,11 +5,15 @@
 
 #[cfg(feature = "sql")]
 mod aggregate;
+#[cfg(feature = "sql")]
+mod common_table_expression;
 mod load;
 pub(in crate::db::executor::explain::descriptor) mod shared;
 
 #[cfg(feature = "sql")]
 pub(in crate::db) use self::aggregate::assemble_scalar_aggregate_execution_descriptor_with_projection;
+#[cfg(feature = "sql")]
+pub(in crate::db) use self::common_table_expression::annotate_common_table_expression_node;
 pub(in crate::db::executor) use self::load::assemble_load_execution_verbose_diagnostics_from_route_facts;
 pub(in crate::db) use self::load::{
     LoadExecutionRouteFacts, assemble_load_execution_node_descriptor_from_route_facts,
@@ -26,8 +26,9 @@ use crate::{
             },
             plan::{
                 AccessChoiceExplainSnapshot, AccessChoiceRejectedIndex, AccessPlanProjection,
-                AccessPlannedQuery, AggregateKind, DistinctExecutionStrategy, OrderDirection,
-                OrderSpec, explain_access_strategy_label, project_explain_access_path,
+                AccessPlannedQuery, AggregateKind, DistinctExecutionStrategy, NullsOrder,
+                OrderDirection, OrderSpec, explain_access_strategy_label,
+                project_explain_access_path,
             },
         },
     },
@@ -730,11 +731,16 @@ pub(in crate::db::executor::explain::descriptor) fn materialized_order_index_hin
             .fields
             .iter()
             .map(|term| {
-                format!(
+                let label = format!(
                     "{} {}",
                     term.rendered_label(),
                     order_direction_hint_label(term.direction())
-                )
+                );
+                if term.overrides_natural_nulls() {
+                    format!("{label} {}", nulls_order_hint_label(term.nulls()))
+                } else {
+                    label
+                }
             })
             .collect::<Vec<_>>()
             .join(", "),
@@ -748,6 +754,13 @@ const fn order_direction_hint_label(direction: OrderDirection) -> &'static str {
     }
 }
 
+const fn nulls_order_hint_label(nulls: NullsOrder) -> &'static str {
+    match nulls {
+        NullsOrder::First => "NULLS FIRST",
+        NullsOrder::Last => "NULLS LAST",
+    }
+}
+
 pub(in crate::db::executor::explain::descriptor) const fn distinct_execution_node_descriptor(
     strategy: DistinctExecutionStrategy,
     execution_mode: ExplainExecutionMode,
@@ -782,6 +795,21 @@ pub(in crate::db::executor::explain::descriptor) fn limit_offset_execution_node_
     node
 }
 
+pub(in crate::db::executor::explain::descriptor) fn partition_limit_execution_node_descriptor(
+    partition_limit: &crate::db::query::plan::PartitionLimitSpec,
+    execution_mode: ExplainExecutionMode,
+) -> ExplainExecutionNodeDescriptor {
+    let mut node =
+        empty_execution_node_descriptor(ExplainExecutionNodeType::PartitionLimit, execution_mode);
+    node.limit = Some(partition_limit.limit);
+    node.node_properties.insert(
+        property_keys::PARTITION_BY,
+        value_list(partition_limit.fields.iter().cloned()),
+    );
+
+    node
+}
+
 pub(in crate::db::executor::explain::descriptor) fn cursor_resume_execution_node_descriptor(
     route_plan: &ExecutionRoutePlan,
     execution_mode: ExplainExecutionMode,
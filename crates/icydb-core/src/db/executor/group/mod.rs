@@ -8,6 +8,7 @@ mod tests;
 
 mod hash;
 mod key;
+mod top_k;
 
 use crate::db::{
     executor::aggregate::{ExecutionConfig, ExecutionContext},
@@ -20,6 +21,7 @@ pub(in crate::db::executor) use hash::{
     try_reserve_hash_entry, try_reserve_hash_set_entry, try_reserve_vec_elements,
 };
 pub(in crate::db::executor) use key::{CanonicalKey, GroupKey, GroupKeySet, KeyCanonicalError};
+pub(in crate::db::executor) use top_k::PartitionTopKWindow;
 
 ///
 /// Grouped execution ownership boundary.
@@ -4,9 +4,18 @@
 //! Boundary: exposes this module API while keeping implementation details internal.
 
 use super::{
-    GroupedExecutionConfig, grouped_budget_observability,
+    GroupedExecutionConfig, PartitionTopKWindow, grouped_budget_observability,
     grouped_execution_config_from_planner_config, grouped_execution_context_from_planner_config,
 };
+use crate::{
+    db::{
+        executor::terminal::{KernelRow, RetainedSlotRow},
+        query::plan::{
+            NullsOrder, OrderDirection, ResolvedOrder, ResolvedOrderField, ResolvedOrderValueSource,
+        },
+    },
+    value::Value,
+};
 
 #[test]
 fn grouped_execution_config_from_planner_config_prefers_planner_limits() {
@@ -86,3 +95,134 @@ fn grouped_budget_observability_contract_vectors_are_frozen() {
 
     assert_eq!(actual_vectors, expected_vectors);
 }
+
+fn partition_row(partition: u64, rank: u64) -> KernelRow {
+    KernelRow::new_slot_only(RetainedSlotRow::new(
+        2,
+        vec![(0, Value::Nat64(partition)), (1, Value::Nat64(rank))],
+    ))
+}
+
+fn partition_then_rank_order() -> ResolvedOrder {
+    ResolvedOrder::new(vec![
+        ResolvedOrderField::new(
+            ResolvedOrderValueSource::direct_field(0),
+            OrderDirection::Asc,
+            NullsOrder::First,
+        ),
+        ResolvedOrderField::new(
+            ResolvedOrderValueSource::direct_field(1),
+            OrderDirection::Desc,
+            NullsOrder::First,
+        ),
+    ])
+}
+
+fn partition_rank_pairs(rows: Vec<KernelRow>) -> Vec<(Option<Value>, Option<Value>)> {
+    rows.into_iter()
+        .map(|row| (row.slot(0), row.slot(1)))
+        .collect()
+}
+
+#[test]
+fn partition_top_k_streaming_keeps_leading_rows_of_each_clustered_partition() {
+    let order = partition_then_rank_order();
+    let mut window = PartitionTopKWindow::new(
+        &order,
+        1,
+        2,
+        true,
+        GroupedExecutionConfig::planner_default_bounded(),
+    )
+    .expect("window should build");
+    for (partition, rank) in [(1, 9), (1, 7), (1, 3), (2, 8), (3, 6), (3, 5), (3, 4)] {
+        window
+            .push(partition_row(partition, rank))
+            .expect("row should push");
+    }
+
+    assert_eq!(
+        partition_rank_pairs(window.into_rows()),
+        [(1, 9), (1, 7), (2, 8), (3, 6), (3, 5)]
+            .into_iter()
+            .map(|(partition, rank)| (Some(Value::Nat64(partition)), Some(Value::Nat64(rank))))
+            .collect::<Vec<_>>(),
+    );
+}
+
+#[test]
+fn partition_top_k_hashed_keeps_best_rows_per_unordered_partition() {
+    let order = partition_then_rank_order();
+    let mut window = PartitionTopKWindow::new(
+        &order,
+        1,
+        2,
+        false,
+        GroupedExecutionConfig::planner_default_bounded(),
+    )
+    .expect("window should build");
+    for (partition, rank) in [(2, 1), (1, 3), (2, 8), (1, 9), (2, 5), (1, 7), (3, 4)] {
+        window
+            .push(partition_row(partition, rank))
+            .expect("row should push");
+    }
+
+    let mut rows = window.into_rows();
+    crate::db::executor::order::apply_structural_order_window(&mut rows, &order, None)
+        .expect("retained rows should order");
+
+    assert_eq!(
+        partition_rank_pairs(rows),
+        [(1, 9), (1, 7), (2, 8), (2, 5), (3, 4)]
+            .into_iter()
+            .map(|(partition, rank)| (Some(Value::Nat64(partition)), Some(Value::Nat64(rank))))
+            .collect::<Vec<_>>(),
+    );
+}
+
+#[test]
+fn partition_top_k_fails_closed_before_exceeding_grouped_limits() {
+    let order = partition_then_rank_order();
+    let execution = GroupedExecutionConfig::planner_default_bounded();
+    let max_groups = grouped_execution_config_from_planner_config(Some(execution)).max_groups();
+    let mut window =
+        PartitionTopKWindow::new(&order, 1, 1, true, execution).expect("window should build");
+
+    // Partition admission shares the grouped group-count and byte caps, so
+    // the window must refuse a new partition no later than `max_groups + 1`.
+    let rejected =
+        (0..=max_groups).any(|partition| window.push(partition_row(partition, 0)).is_err());
+
+    assert!(
+        rejected,
+        "partition admission should fail closed at grouped limits"
+    );
+}
+
+#[test]
+fn partition_top_k_fails_closed_at_configured_max_groups() {
+    let order = partition_then_rank_order();
+
+    // Partition admission honours the plan's grouped limits rather than the
+    // planner defaults, in both the clustered and the hashed window.
+    for streaming in [true, false] {
+        let mut window = PartitionTopKWindow::new(
+            &order,
+            1,
+            1,
+            streaming,
+            GroupedExecutionConfig::with_hard_limits(2, 1024 * 1024),
+        )
+        .expect("window should build");
+        for partition in 0..2 {
+            window
+                .push(partition_row(partition, 0))
+                .expect("partitions within max_groups should push");
+        }
+
+        assert!(
+            window.push(partition_row(2, 0)).is_err(),
+            "third partition should exceed max_groups (streaming: {streaming})",
+        );
+    }
+}
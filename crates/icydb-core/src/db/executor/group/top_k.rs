@@ -0,0 +1,182 @@
+//! Module: executor::group::top_k
+//! Responsibility: bounded per-partition top-k row retention for `LIMIT k PER`.
+//! Does not own: canonical post-access ordering or partition-limit plan policy.
+//! Boundary: scan-time partition windows consumed by scalar load materialization.
+
+use crate::{
+    db::{
+        executor::{
+            BoundedOrderWindow, OrderReadableRow,
+            aggregate::{ExecutionContext, GroupError},
+            group::{
+                GroupKey, KeyCanonicalError, StableHashBuildHasher,
+                grouped_execution_context_from_planner_config, try_reserve_hash_entry,
+            },
+        },
+        query::plan::{GroupedExecutionConfig, ResolvedOrder},
+    },
+    error::InternalError,
+};
+use std::collections::HashMap;
+
+///
+/// PartitionTopKWindow
+///
+/// PartitionTopKWindow keeps at most `keep_count` rows for each partition key
+/// while a scalar scan is still running.
+/// Route-ordered scans already cluster partitions, so the streaming mode only
+/// tracks the active partition; unordered scans keep one bounded order window
+/// per partition. Both modes count distinct partitions against `max_groups`.
+/// Rows are not final-sorted here; post-access ordering remains the final
+/// ordering authority.
+///
+
+pub(in crate::db::executor) struct PartitionTopKWindow<'a, R> {
+    resolved_order: &'a ResolvedOrder,
+    partition_slots: Vec<usize>,
+    keep_count: usize,
+    context: ExecutionContext,
+    state: PartitionTopKState<'a, R>,
+}
+
+///
+/// PartitionTopKState
+///
+/// Mode-owned retained state selected once from the route order contract.
+///
+
+enum PartitionTopKState<'a, R> {
+    Streaming {
+        rows: Vec<R>,
+        current: Option<GroupKey>,
+        current_count: usize,
+    },
+    Hashed {
+        windows: HashMap<GroupKey, BoundedOrderWindow<'a, R>, StableHashBuildHasher>,
+    },
+}
+
+impl<'a, R> PartitionTopKWindow<'a, R>
+where
+    R: OrderReadableRow,
+{
+    /// Build one per-partition window over the leading `partition_len`
+    /// resolved order terms, admitting partitions under `execution`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a query-executor invariant error when the partition prefix is
+    /// not made of direct field slots.
+    pub(in crate::db::executor) fn new(
+        resolved_order: &'a ResolvedOrder,
+        partition_len: usize,
+        keep_count: usize,
+        streaming: bool,
+        execution: GroupedExecutionConfig,
+    ) -> Result<Self, InternalError> {
+        let partition_slots = resolved_order
+            .fields()
+            .get(..partition_len)
+            .and_then(|fields| {
+                fields
+                    .iter()
+                    .map(|field| field.source().direct_field_slot())
+                    .collect::<Option<Vec<_>>>()
+            })
+            .ok_or_else(InternalError::query_executor_invariant)?;
+        let state = if streaming {
+            PartitionTopKState::Streaming {
+                rows: Vec::new(),
+                current: None,
+                current_count: 0,
+            }
+        } else {
+            PartitionTopKState::Hashed {
+                windows: HashMap::with_hasher(StableHashBuildHasher),
+            }
+        };
+
+        Ok(Self {
+            resolved_order,
+            partition_slots,
+            keep_count,
+            context: grouped_execution_context_from_planner_config(Some(execution)),
+            state,
+        })
+    }
+
+    /// Offer one matched row to its partition window.
+    ///
+    /// # Errors
+    ///
+    /// Returns an execution-budget error once a new partition would exceed
+    /// `max_groups`, or an invariant error when a partition slot is missing.
+    pub(in crate::db::executor) fn push(&mut self, row: R) -> Result<(), InternalError> {
+        let key = self.partition_key(&row)?;
+
+        match &mut self.state {
+            PartitionTopKState::Streaming {
+                rows,
+                current,
+                current_count,
+            } => {
+                if current.as_ref() != Some(&key) {
+                    let groups_before =
+                        usize::try_from(self.context.budget().groups()).unwrap_or(usize::MAX);
+                    self.context
+                        .record_new_group(groups_before, 0, &key)
+                        .map_err(GroupError::into_internal_error)?;
+                    *current = Some(key);
+                    *current_count = 0;
+                }
+                if *current_count < self.keep_count {
+                    rows.push(row);
+                    *current_count = current_count.saturating_add(1);
+                }
+            }
+            PartitionTopKState::Hashed { windows } => {
+                if let Some(window) = windows.get_mut(&key) {
+                    return window.push(row);
+                }
+
+                self.context
+                    .record_new_group(windows.len(), windows.capacity(), &key)
+                    .map_err(GroupError::into_internal_error)?;
+                try_reserve_hash_entry(windows)?;
+                let mut window = BoundedOrderWindow::new(self.keep_count, self.resolved_order);
+                window.push(row)?;
+                windows.insert(key, window);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Consume every retained partition row.
+    ///
+    /// Streaming mode preserves route order; hashed mode returns partition
+    /// windows in unspecified order for the canonical post-access sort.
+    #[must_use]
+    pub(in crate::db::executor) fn into_rows(self) -> Vec<R> {
+        match self.state {
+            PartitionTopKState::Streaming { rows, .. } => rows,
+            PartitionTopKState::Hashed { windows } => windows
+                .into_values()
+                .flat_map(BoundedOrderWindow::into_unordered_rows)
+                .collect(),
+        }
+    }
+
+    // Build the canonical partition key from the row's partition slots.
+    fn partition_key(&self, row: &R) -> Result<GroupKey, InternalError> {
+        let mut values = Vec::with_capacity(self.partition_slots.len());
+        for slot in &self.partition_slots {
+            let value = row
+                .read_order_slot_cow(*slot)
+                .ok_or_else(InternalError::query_executor_invariant)?;
+            values.push(value.into_owned());
+        }
+
+        GroupKey::from_group_values(values).map_err(KeyCanonicalError::into_internal_error)
+    }
+}
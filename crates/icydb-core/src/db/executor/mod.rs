@@ -90,6 +90,8 @@ pub(in crate::db) use mutation::{
 pub(in crate::db) use mutation::{
     MutationCommitInterruption, interrupt_next_mutation_commit_for_tests,
 };
+#[cfg(feature = "sql")]
+pub(in crate::db::executor) use order::compare_order_term_values;
 pub(in crate::db::executor) use order::{
     BoundedOrderWindow, DataRowOrderWindow, OrderReadableRow, PendingOrderRows,
     compare_orderable_row_with_boundary,
@@ -137,8 +139,9 @@ pub(in crate::db) use projection::{
 };
 #[cfg(feature = "sql")]
 pub(in crate::db) use projection::{
-    StructuralProjectionScanBudget, eval_compiled_filter_expr_with_required_slot_reader,
-    execute_structural_projection_rows,
+    StructuralProjectionScanBudget, UnionRowOrderTerm, UnionRowWindow,
+    eval_compiled_filter_expr_with_required_slot_reader, eval_compiled_scalar_expr_with_row_values,
+    execute_structural_projection_rows, merge_union_projection_rows,
 };
 #[cfg(all(feature = "sql", feature = "diagnostics"))]
 pub(in crate::db) use projection::{
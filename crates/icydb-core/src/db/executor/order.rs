@@ -15,7 +15,9 @@ use crate::{
             terminal::RowLayout,
         },
         numeric::canonical_value_compare,
-        query::plan::{OrderDirection, ResolvedOrder, ResolvedOrderValueSource},
+        query::plan::{
+            NullsOrder, OrderDirection, ResolvedOrder, ResolvedOrderField, ResolvedOrderValueSource,
+        },
     },
     error::InternalError,
     value::Value,
@@ -572,6 +574,21 @@ where
             },
         }
     }
+
+    /// Consume retained rows while dropping any cached expression-order
+    /// values, for owners that merge several windows before one canonical
+    /// post-access ordering pass.
+    #[must_use]
+    pub(in crate::db::executor) fn into_unordered_rows(self) -> Vec<R> {
+        match self.candidates {
+            BoundedOrderCandidates::Direct(window) => window.into_rows(),
+            BoundedOrderCandidates::Cached(window) => window
+                .into_rows_with_cached_values()
+                .into_iter()
+                .map(|(row, _)| row)
+                .collect(),
+        }
+    }
 }
 
 ///
@@ -817,30 +834,27 @@ pub(in crate::db::executor) fn compare_orderable_row_with_boundary<R>(
 where
     R: OrderReadableRow,
 {
-    compare_structural_order_slots_fallible(resolved_order, |slot_index, source, direction| {
-        let row_slot = order_value_from_row(row, source);
+    compare_structural_order_slots_fallible(resolved_order, |slot_index, field| {
+        let row_slot = order_value_from_row(row, field.source());
         let boundary_slot = boundary
             .slots
             .get(slot_index)
             .ok_or_else(InternalError::query_executor_invariant)?;
 
-        Ok(apply_order_direction(
-            compare_order_value_with_boundary(row_slot, boundary_slot),
-            direction,
+        Ok(compare_order_value_with_boundary(
+            row_slot,
+            boundary_slot,
+            field,
         ))
     })
 }
 
 fn compare_structural_order_slots_fallible(
     resolved_order: &ResolvedOrder,
-    mut compare_slot: impl FnMut(
-        usize,
-        &ResolvedOrderValueSource,
-        OrderDirection,
-    ) -> Result<Ordering, InternalError>,
+    mut compare_slot: impl FnMut(usize, &ResolvedOrderField) -> Result<Ordering, InternalError>,
 ) -> Result<Ordering, InternalError> {
     for (slot_index, field) in resolved_order.fields().iter().enumerate() {
-        let ordering = compare_slot(slot_index, field.source(), field.direction())?;
+        let ordering = compare_slot(slot_index, field)?;
         if ordering != Ordering::Equal {
             return Ok(ordering);
         }
@@ -952,12 +966,10 @@ where
             return Ordering::Equal;
         };
 
-        let ordering = apply_order_direction(
-            compare_cached_order_values(
-                left.read_order_slot_ref(*slot),
-                right.read_order_slot_ref(*slot),
-            ),
-            field.direction(),
+        let ordering = compare_cached_order_values(
+            left.read_order_slot_ref(*slot),
+            right.read_order_slot_ref(*slot),
+            field,
         );
         if ordering != Ordering::Equal {
             return ordering;
@@ -1135,10 +1147,7 @@ fn compare_cached_order_value_lists(
         .zip(right.iter())
         .zip(resolved_order.fields().iter())
     {
-        let ordering = apply_order_direction(
-            compare_cached_order_values(left_slot.as_ref(), right_slot.as_ref()),
-            field.direction(),
-        );
+        let ordering = compare_cached_order_values(left_slot.as_ref(), right_slot.as_ref(), field);
         if ordering != Ordering::Equal {
             return ordering;
         }
@@ -1168,13 +1177,12 @@ where
 }
 
 // Compare two cached owned ordering values after key precomputation.
-fn compare_cached_order_values(left: Option<&Value>, right: Option<&Value>) -> Ordering {
-    match (left, right) {
-        (None, None) => Ordering::Equal,
-        (None, Some(_)) => Ordering::Less,
-        (Some(_), None) => Ordering::Greater,
-        (Some(left), Some(right)) => canonical_value_compare(left, right),
-    }
+fn compare_cached_order_values(
+    left: Option<&Value>,
+    right: Option<&Value>,
+    field: &ResolvedOrderField,
+) -> Ordering {
+    compare_present_order_values(present_order_value(left), present_order_value(right), field)
 }
 
 // Compare one row-provided ordering value against one persisted cursor
@@ -1182,13 +1190,69 @@ fn compare_cached_order_values(left: Option<&Value>, right: Option<&Value>) -> O
 fn compare_order_value_with_boundary(
     value: Option<Cow<'_, Value>>,
     boundary: &CursorBoundarySlot,
+    field: &ResolvedOrderField,
 ) -> Ordering {
-    match (value, boundary) {
-        (None, CursorBoundarySlot::Missing) => Ordering::Equal,
-        (None, CursorBoundarySlot::Present(_)) => Ordering::Less,
-        (Some(_), CursorBoundarySlot::Missing) => Ordering::Greater,
-        (Some(value), CursorBoundarySlot::Present(boundary_value)) => {
-            canonical_value_compare(value.as_ref(), boundary_value)
+    let boundary = match boundary {
+        CursorBoundarySlot::Missing => None,
+        CursorBoundarySlot::Present(value) => Some(value),
+    };
+
+    compare_present_order_values(
+        present_order_value(value.as_deref()),
+        present_order_value(boundary),
+        field,
+    )
+}
+
+// Apply one resolved order term to two canonical order slots. Null placement
+// is fixed by the term rather than by direction, so a null slot compares
+// against a present slot the same way whether the term is ASC or DESC.
+fn compare_present_order_values(
+    left: Option<&Value>,
+    right: Option<&Value>,
+    field: &ResolvedOrderField,
+) -> Ordering {
+    compare_order_values_with(left, right, field.direction(), field.nulls())
+}
+
+fn compare_order_values_with(
+    left: Option<&Value>,
+    right: Option<&Value>,
+    direction: OrderDirection,
+    nulls: NullsOrder,
+) -> Ordering {
+    match (left, right) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => nulls.null_before_present_ordering(),
+        (Some(_), None) => nulls.null_before_present_ordering().reverse(),
+        (Some(left), Some(right)) => {
+            apply_order_direction(canonical_value_compare(left, right), direction)
         }
     }
 }
+
+/// Compare two already projected values under one order direction and null
+/// placement, exactly as structural ordering compares one resolved term.
+#[cfg(feature = "sql")]
+pub(in crate::db::executor) fn compare_order_term_values(
+    left: Option<&Value>,
+    right: Option<&Value>,
+    direction: OrderDirection,
+    nulls: NullsOrder,
+) -> Ordering {
+    compare_order_values_with(
+        present_order_value(left),
+        present_order_value(right),
+        direction,
+        nulls,
+    )
+}
+
+// SQL NULL and an absent nullable slot share one canonical missing-slot
+// representation for ordering, matching `CachedOrderValues::push`.
+const fn present_order_value(value: Option<&Value>) -> Option<&Value> {
+    match value {
+        Some(Value::Null) | None => None,
+        Some(value) => Some(value),
+    }
+}
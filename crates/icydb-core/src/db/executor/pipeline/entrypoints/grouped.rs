@@ -572,6 +572,7 @@ impl GroupedPathRuntimeContext {
                 self.row_store,
                 self.authority.row_layout()?,
                 grouped_slot_layout,
+                route.group_fields(),
             ),
         )
     }
@@ -31,7 +31,7 @@ pub(in crate::db::executor) fn resolve_grouped_route_for_plan(
     if grouped_handoff
         .group_fields()
         .iter()
-        .any(|field| field.accepted_kind().is_none())
+        .any(|field| field.accepted_kind().is_none() && field.computed_expr().is_none())
     {
         return Err(InternalError::query_executor_invariant());
     }
@@ -22,7 +22,7 @@ use crate::{
         query::plan::{
             EffectiveRuntimeFilterProgram, FieldSlot as PlannedFieldSlot,
             GroupedAggregateExecutionSpec, GroupedDistinctExecutionStrategy,
-            expr::CompiledExprValueReader,
+            expr::{CompiledExpr, CompiledExprValueReader, ProjectionEvalError},
         },
         registry::StoreHandle,
     },
@@ -38,10 +38,13 @@ use std::borrow::Cow;
 /// Structural grouped row view used inside grouped runtime loops.
 /// Rows carry slot-indexed values only, so grouped execution can remain
 /// monomorphic after typed decode happens at the row-runtime boundary.
+/// Computed grouped key values live in one small overlay keyed by their
+/// virtual slot so key extraction reads them like any other slot.
 ///
 
 pub(in crate::db::executor) struct RowView {
     storage: RowViewStorage,
+    computed: Vec<(usize, Value)>,
 }
 
 // Compile one grouped ingest slot layout from the planner-owned grouped
@@ -57,7 +60,12 @@ pub(in crate::db::executor) fn compile_grouped_row_slot_layout_from_inputs(
     let mut required_slots = vec![false; field_count];
 
     // Phase 1: every grouped path needs the group key slots themselves.
+    // Computed keys instead need every row slot their expression reads.
     for field in group_fields {
+        if let Some(compiled) = field.compiled_computed_expr() {
+            compiled.mark_referenced_slots(&mut required_slots);
+            continue;
+        }
         if let Some(required_slot) = required_slots.get_mut(field.index()) {
             *required_slot = true;
         }
@@ -68,9 +76,9 @@ pub(in crate::db::executor) fn compile_grouped_row_slot_layout_from_inputs(
         effective_runtime_filter_program.mark_referenced_slots(&mut required_slots);
     }
 
-    // Phase 3: grouped reducer state needs every row slot referenced by either
-    // one direct field-target aggregate or one widened aggregate-input scalar
-    // expression carried into grouped fold runtime.
+    // Phase 3: grouped reducer state needs every row slot referenced by one
+    // direct field-target aggregate, one widened aggregate-input scalar
+    // expression, its `FILTER`, or its aggregate-local `ORDER BY` keys.
     for aggregate in grouped_aggregate_execution_specs {
         if let Some(target_slot) = aggregate.target_slot()
             && let Some(required_slot) = required_slots.get_mut(target_slot.index())
@@ -85,6 +93,10 @@ pub(in crate::db::executor) fn compile_grouped_row_slot_layout_from_inputs(
         if let Some(compiled_filter_expr) = aggregate.compiled_filter_expr() {
             compiled_filter_expr.mark_referenced_slots(&mut required_slots);
         }
+
+        for compiled_order_expr in aggregate.compiled_order_exprs() {
+            compiled_order_expr.mark_referenced_slots(&mut required_slots);
+        }
     }
 
     // Phase 4: the dedicated grouped DISTINCT path still reads its target
@@ -133,6 +145,7 @@ impl RowView {
     pub(in crate::db::executor) const fn new(slots: Vec<Option<Value>>) -> Self {
         Self {
             storage: RowViewStorage::Dense(slots),
+            computed: Vec::new(),
         }
     }
 
@@ -141,6 +154,7 @@ impl RowView {
     pub(in crate::db::executor) const fn from_retained_slots(row: RetainedSlotRow) -> Self {
         Self {
             storage: RowViewStorage::Retained(row),
+            computed: Vec::new(),
         }
     }
 
@@ -151,13 +165,25 @@ impl RowView {
     pub(in crate::db::executor) const fn from_single_value(slot: usize, value: Value) -> Self {
         Self {
             storage: RowViewStorage::Single { slot, value },
+            computed: Vec::new(),
         }
     }
 
+    // Borrow one computed grouped key value by its virtual slot.
+    fn computed_value(&self, index: usize) -> Option<&Value> {
+        self.computed
+            .iter()
+            .find_map(|(slot, value)| (*slot == index).then_some(value))
+    }
+
     /// Borrow one slot by index when the row view already owns decoded values.
     #[cfg(test)]
     #[must_use]
     pub(in crate::db::executor) fn borrow_slot_for_test(&self, index: usize) -> Option<&Value> {
+        if let Some(value) = self.computed_value(index) {
+            return Some(value);
+        }
+
         match &self.storage {
             RowViewStorage::Dense(slots) => slots.get(index).and_then(Option::as_ref),
             RowViewStorage::Single { slot, value } => (*slot == index).then_some(value),
@@ -167,6 +193,10 @@ impl RowView {
 
     /// Read one slot by index without cloning decoded grouped row values.
     pub(in crate::db::executor) fn slot_value(&self, index: usize) -> Option<Cow<'_, Value>> {
+        if let Some(value) = self.computed_value(index) {
+            return Some(Cow::Borrowed(value));
+        }
+
         match &self.storage {
             #[cfg(test)]
             RowViewStorage::Dense(slots) => {
@@ -181,6 +211,10 @@ impl RowView {
 
     /// Borrow one slot by index for value-only compiled expression readers.
     pub(in crate::db::executor) fn slot_value_ref(&self, index: usize) -> Option<&Value> {
+        if let Some(value) = self.computed_value(index) {
+            return Some(value);
+        }
+
         match &self.storage {
             #[cfg(test)]
             RowViewStorage::Dense(slots) => slots.get(index).and_then(Option::as_ref),
@@ -189,6 +223,23 @@ impl RowView {
         }
     }
 
+    // Evaluate computed grouped keys against the decoded row slots and keep
+    // their values in the virtual-slot overlay.
+    fn with_computed_group_keys(
+        mut self,
+        computed_group_keys: &[(usize, CompiledExpr)],
+    ) -> Result<Self, InternalError> {
+        for (slot, compiled) in computed_group_keys {
+            let value = compiled
+                .evaluate(&self)
+                .map(Cow::into_owned)
+                .map_err(ProjectionEvalError::into_grouped_projection_internal_error)?;
+            self.computed.push((*slot, value));
+        }
+
+        Ok(self)
+    }
+
     /// Read one required slot and fail closed when it is missing.
     pub(in crate::db::executor) fn require_slot_value(
         &self,
@@ -202,9 +253,13 @@ impl RowView {
     /// cloning. Use this only at callsites that no longer need any other row
     /// slots after extracting the selected value.
     pub(in crate::db::executor) fn into_required_slot_value(
-        self,
+        mut self,
         index: usize,
     ) -> Result<Value, InternalError> {
+        if let Some(position) = self.computed.iter().position(|(slot, _)| *slot == index) {
+            return Ok(self.computed.swap_remove(position).1);
+        }
+
         match self.storage {
             #[cfg(test)]
             RowViewStorage::Dense(mut slots) => slots
@@ -343,6 +398,7 @@ pub(in crate::db::executor) struct StructuralGroupedRowRuntime {
     row_layout: RowLayout,
     grouped_slot_layout: RetainedSlotLayout,
     single_grouped_slot_decode: Option<SingleGroupedSlotDecode>,
+    computed_group_keys: Vec<(usize, CompiledExpr)>,
 }
 
 impl StructuralGroupedRowRuntime {
@@ -353,6 +409,7 @@ impl StructuralGroupedRowRuntime {
         store: StoreHandle,
         row_layout: RowLayout,
         grouped_slot_layout: RetainedSlotLayout,
+        group_fields: &[PlannedFieldSlot],
     ) -> Self {
         let single_grouped_slot_decode = match grouped_slot_layout.required_slots() {
             [required_slot] => Some(SingleGroupedSlotDecode {
@@ -360,12 +417,21 @@ impl StructuralGroupedRowRuntime {
             }),
             _ => None,
         };
+        let computed_group_keys = group_fields
+            .iter()
+            .filter_map(|field| {
+                field
+                    .compiled_computed_expr()
+                    .map(|compiled| (field.index(), compiled.clone()))
+            })
+            .collect();
 
         Self {
             store,
             row_layout,
             grouped_slot_layout,
             single_grouped_slot_decode,
+            computed_group_keys,
         }
     }
 
@@ -375,6 +441,21 @@ impl StructuralGroupedRowRuntime {
         &self,
         key: &DecodedDataStoreKey,
         row: RawRow,
+    ) -> Result<RowView, InternalError> {
+        let row_view = self.decoded_row_view_from_data_row(key, row)?;
+        if self.computed_group_keys.is_empty() {
+            return Ok(row_view);
+        }
+
+        row_view.with_computed_group_keys(self.computed_group_keys.as_slice())
+    }
+
+    // Decode the stored slots of one grouped row view before computed
+    // grouped keys are evaluated over it.
+    fn decoded_row_view_from_data_row(
+        &self,
+        key: &DecodedDataStoreKey,
+        row: RawRow,
     ) -> Result<RowView, InternalError> {
         match self.row_decode_path() {
             GroupedRowDecodePath::Single(single_grouped_slot_decode) => {
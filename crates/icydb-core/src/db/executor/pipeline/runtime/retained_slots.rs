@@ -93,8 +93,9 @@ fn compile_retained_slot_layout(
     if plan.scalar_plan().order.as_ref().is_some()
         && let Some(order_slots) = plan.order_referenced_slots()
     {
-        let route_needs_order_slots =
-            !access_order_satisfied_by_route_mode(plan) || cursor_emission.enabled();
+        let route_needs_order_slots = !access_order_satisfied_by_route_mode(plan)
+            || cursor_emission.enabled()
+            || plan.scalar_plan().partition_limit.is_some();
 
         if route_needs_order_slots {
             required_slots.mark_slots(order_slots.iter().copied());
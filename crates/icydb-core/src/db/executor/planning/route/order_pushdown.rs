@@ -98,6 +98,10 @@ pub(in crate::db) enum SecondaryOrderPushdownRejection {
         expected_full: Vec<String>,
         actual: Vec<String>,
     },
+    NullPlacementNotSatisfiedByIndex {
+        index: String,
+        field: String,
+    },
 }
 
 impl From<SecondaryOrderPushdownRejection> for ExplainSecondaryOrderPushdownRejection {
@@ -138,6 +142,9 @@ impl From<SecondaryOrderPushdownRejection> for ExplainSecondaryOrderPushdownReje
                 expected_full,
                 actual,
             },
+            SecondaryOrderPushdownRejection::NullPlacementNotSatisfiedByIndex { index, field } => {
+                Self::NullPlacementNotSatisfiedByIndex { index, field }
+            }
         }
     }
 }
@@ -196,6 +203,12 @@ impl SecondaryOrderPushdownRejection {
                     "VariablePrefixSuffixOrderUnsupported(index={index},prefix_len={prefix_len},expected_full={expected_full:?},actual={actual:?})",
                 );
             }
+            Self::NullPlacementNotSatisfiedByIndex { index, field } => {
+                let _ = write!(
+                    out,
+                    "NullPlacementNotSatisfiedByIndex(index={index},field={field})",
+                );
+            }
         }
     }
 }
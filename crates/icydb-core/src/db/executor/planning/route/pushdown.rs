@@ -4,7 +4,7 @@
 //! Boundary: route-owned access-shape assessment over validated logical+access plans.
 
 use crate::db::{
-    access::{AccessPathKind, AccessShapeFacts, IndexShapeDetails, SemanticIndexKeyItem},
+    access::{AccessPathKind, AccessShapeFacts, IndexShapeDetails, SemanticIndexAccessContract},
     direction::Direction,
     executor::route::{
         AccessWindow, IndexPrefixChildExpansionBudget, IndexPrefixChildExpansionHint,
@@ -14,7 +14,7 @@ use crate::db::{
         AccessPlannedQuery, DeterministicSecondaryIndexOrderMatch,
         DeterministicSecondaryOrderContract, LogicalPushdownEligibility, OrderDirection,
         PlannerRouteProfile, access_satisfies_deterministic_secondary_order_contract,
-        deterministic_secondary_index_key_items_order_compatibility,
+        deterministic_secondary_index_order_compatibility,
     },
 };
 
@@ -47,15 +47,25 @@ pub(in crate::db) fn derive_secondary_pushdown_applicability_from_contract(
 fn match_secondary_order_pushdown_core(
     order_contract: &DeterministicSecondaryOrderContract,
     index_name: &str,
-    key_items: &[SemanticIndexKeyItem],
+    index: &SemanticIndexAccessContract,
     prefix_len: usize,
     variable_prefix_requires_full_order: bool,
 ) -> PushdownApplicability {
-    let compatibility = deterministic_secondary_index_key_items_order_compatibility(
-        order_contract,
-        key_items,
-        prefix_len,
-    );
+    // Index traversal only yields the natural null placement for its
+    // direction. The planner contract keeps only inverted placements on
+    // fields the accepted schema admits as nullable, and those still need the
+    // materialized sort even when the ORDER BY fields match the index.
+    if let Some(field) = order_contract.null_placement_override() {
+        return PushdownApplicability::Rejected(
+            SecondaryOrderPushdownRejection::NullPlacementNotSatisfiedByIndex {
+                index: index_name.to_string(),
+                field: field.to_string(),
+            },
+        );
+    }
+
+    let compatibility =
+        deterministic_secondary_index_order_compatibility(order_contract, index, prefix_len);
 
     match compatibility.match_kind() {
         DeterministicSecondaryIndexOrderMatch::Full => {
@@ -146,7 +156,7 @@ fn secondary_order_pushdown_applicability(
         return match_secondary_order_pushdown_core(
             order_contract,
             index_name,
-            details.key_items(),
+            &details.index_contract(),
             prefix_len,
             variable_prefix_requires_full_order,
         );
@@ -166,12 +176,15 @@ fn secondary_order_pushdown_applicability(
         let applicability = match_secondary_order_pushdown_core(
             order_contract,
             index_name,
-            details.key_items(),
+            &details.index_contract(),
             prefix_len,
             false,
         );
         return match applicability {
-            PushdownApplicability::Eligible { .. } => applicability,
+            PushdownApplicability::Eligible { .. }
+            | PushdownApplicability::Rejected(
+                SecondaryOrderPushdownRejection::NullPlacementNotSatisfiedByIndex { .. },
+            ) => applicability,
             PushdownApplicability::Rejected(_) => PushdownApplicability::Rejected(
                 SecondaryOrderPushdownRejection::AccessPathIndexRangeUnsupported {
                     index: index_name.to_string(),
@@ -207,9 +220,9 @@ pub(super) fn index_range_limit_pushdown_shape_supported_for_order_contract(
     let Some(order_contract) = order_contract else {
         return false;
     };
-    deterministic_secondary_index_key_items_order_compatibility(
+    deterministic_secondary_index_order_compatibility(
         order_contract,
-        details.key_items(),
+        &details.index_contract(),
         prefix_len,
     )
     .is_satisfied()
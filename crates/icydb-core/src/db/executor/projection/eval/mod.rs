@@ -8,7 +8,12 @@ mod contracts;
 mod scalar;
 
 use crate::{
-    db::{data::CanonicalSlotReader, executor::budget::charge_current_execution_budget},
+    db::{
+        data::CanonicalSlotReader,
+        executor::budget::{
+            charge_current_execution_budget, charge_current_text_pattern_match_steps,
+        },
+    },
     error::InternalError,
     value::Value,
 };
@@ -20,6 +25,8 @@ pub(in crate::db) use contracts::ProjectionEvalError;
 pub(in crate::db::executor) use scalar::eval_compiled_expr_with_required_slot_reader_cow;
 pub(in crate::db::executor) use scalar::eval_compiled_expr_with_value_reader;
 pub(in crate::db::executor) use scalar::eval_compiled_expr_with_value_ref_reader;
+#[cfg(feature = "sql")]
+pub(in crate::db) use scalar::eval_compiled_scalar_expr_with_row_values;
 pub(in crate::db) use scalar::{
     eval_compiled_filter_expr_with_required_slot_reader,
     eval_compiled_filter_expr_with_value_cow_reader,
@@ -47,7 +54,10 @@ pub(in crate::db::executor) fn eval_effective_runtime_filter_program_with_slot_r
         return Err(InternalError::query_executor_invariant());
     };
 
-    eval_compiled_filter_expr_with_required_slot_reader(filter_expr, slots)
+    let admitted = eval_compiled_filter_expr_with_required_slot_reader(filter_expr, slots);
+    charge_current_text_pattern_match_steps()?;
+
+    admitted
 }
 
 // Evaluate one planner-selected effective runtime filter program through one
@@ -76,5 +86,12 @@ where
         return Err(InternalError::query_executor_invariant());
     };
 
-    eval_compiled_filter_expr_with_value_cow_reader(filter_expr, read_slot, missing_slot_context)
+    let admitted = eval_compiled_filter_expr_with_value_cow_reader(
+        filter_expr,
+        read_slot,
+        missing_slot_context,
+    );
+    charge_current_text_pattern_match_steps()?;
+
+    admitted
 }
@@ -339,6 +339,20 @@ pub(in crate::db::executor) fn eval_compiled_expr_with_value_ref_reader<'a>(
     expr.evaluate(&reader).map(Cow::into_owned)
 }
 
+/// Evaluate one compiled scalar expression over one borrowed pre-image row.
+///
+/// SQL `UPDATE` assignment expressions use this boundary so computed write
+/// values share scalar projection semantics, including checked numeric
+/// failures, without session code owning an expression reader.
+#[cfg(feature = "sql")]
+pub(in crate::db) fn eval_compiled_scalar_expr_with_row_values<'a>(
+    expr: &CompiledExpr,
+    read_slot: &mut dyn FnMut(usize) -> Option<&'a Value>,
+) -> Result<Value, InternalError> {
+    eval_compiled_expr_with_value_ref_reader(expr, read_slot)
+        .map_err(ProjectionEvalError::into_invalid_logical_plan_internal_error)
+}
+
 /// Evaluate one compiled expression through a canonical raw-row slot reader.
 pub(in crate::db::executor) fn eval_compiled_expr_with_required_slot_reader_cow<'a>(
     expr: &'a CompiledExpr,
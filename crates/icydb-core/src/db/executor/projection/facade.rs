@@ -488,7 +488,13 @@ where
     let output_page_work_exhausted = output_work
         .as_ref()
         .is_some_and(ProductionScalarOutputWork::envelope_stopped);
-    let last_consumed_physical = if output_page_work_exhausted {
+    // A physical anchor skips every scanned key, including matched lookahead
+    // rows the page window dropped. A full page therefore resumes from its
+    // logical boundary; the anchor only serves pages the scan budget cut short.
+    let page_window_filled = cursor_page_row_limit.is_some_and(|page_row_limit| {
+        usize::try_from(rows.row_count()).is_ok_and(|emitted| emitted >= page_row_limit)
+    });
+    let last_consumed_physical = if output_page_work_exhausted || page_window_filled {
         None
     } else {
         scanned_physical_anchor
@@ -162,15 +162,16 @@ impl DistinctProjectionPage {
     }
 }
 
-/// Canonical projected-row key set used only by the complete global build.
-struct DistinctProjectionRowSet {
+/// Canonical projected-row key set shared by the complete global build and
+/// the SQL `UNION` merge.
+pub(super) struct DistinctProjectionRowSet {
     buckets: HashMap<StableHash, Vec<Value>>,
     retained_backing_bytes: u64,
     entries: usize,
 }
 
 impl DistinctProjectionRowSet {
-    fn new() -> Self {
+    pub(super) fn new() -> Self {
         Self {
             buckets: HashMap::new(),
             retained_backing_bytes: 0,
@@ -180,7 +181,7 @@ impl DistinctProjectionRowSet {
 
     // Hash and compare through borrowed values, then own a canonical key only
     // for a genuinely new projected row.
-    fn insert_row(&mut self, row: &RowView) -> Result<bool, InternalError> {
+    pub(super) fn insert_row(&mut self, row: &RowView) -> Result<bool, InternalError> {
         if row
             .values()
             .iter()
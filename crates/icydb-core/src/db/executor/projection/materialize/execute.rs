@@ -8,7 +8,7 @@ use crate::{
     db::{
         data::DataRow,
         executor::{
-            budget::charge_current_execution_budget,
+            budget::{charge_current_execution_budget, charge_current_text_pattern_match_steps},
             projection::{
                 eval::{
                     ProjectionEvalError, eval_compiled_expr_with_required_slot_reader_cow,
@@ -364,6 +364,7 @@ fn project_scalar_data_row_into(
         )?;
         shaped.push(value.into_owned());
     }
+    charge_current_text_pattern_match_steps()?;
 
     Ok(())
 }
@@ -10,6 +10,8 @@ mod metrics;
 mod plan;
 mod row_view;
 mod structural;
+#[cfg(feature = "sql")]
+mod union;
 
 pub(in crate::db::executor::projection) use distinct::{
     ProjectionDistinctStrategy, ProjectionDistinctWindow, projection_distinct_strategy,
@@ -24,3 +26,5 @@ pub(in crate::db) use structural::project;
 pub(in crate::db::executor::projection) use structural::{
     DistinctProjectionRuntime, project_admitted_page, project_distinct,
 };
+#[cfg(feature = "sql")]
+pub(in crate::db) use union::{UnionRowOrderTerm, UnionRowWindow, merge_union_projection_rows};
@@ -0,0 +1,266 @@
+//! Module: db::executor::projection::materialize::union
+//! Responsibility: ordered k-way merge of SQL `UNION [ALL]` branch rows.
+//! Does not own: branch planning, branch execution, or branch row ordering.
+//! Boundary: merges already ordered branch projection rows through the shared
+//! flat merge driver, then applies the combined DISTINCT and window.
+
+use crate::{
+    db::{
+        executor::{
+            compare_order_term_values,
+            projection::materialize::{distinct::DistinctProjectionRowSet, row_view::RowView},
+            stream::{FlatMergeKeyOrder, FlatMergeOrderedChild, FlatMergeStream},
+        },
+        numeric::canonical_value_compare,
+        query::plan::{NullsOrder, OrderDirection},
+    },
+    error::InternalError,
+    value::Value,
+};
+use std::{cmp::Ordering, vec::IntoIter};
+
+///
+/// UnionRowOrderTerm
+///
+/// One combined ORDER BY term over a visible union output column.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(in crate::db) struct UnionRowOrderTerm {
+    column: usize,
+    direction: OrderDirection,
+    nulls: NullsOrder,
+}
+
+impl UnionRowOrderTerm {
+    #[must_use]
+    pub(in crate::db) const fn new(
+        column: usize,
+        direction: OrderDirection,
+        nulls: NullsOrder,
+    ) -> Self {
+        Self {
+            column,
+            direction,
+            nulls,
+        }
+    }
+}
+
+///
+/// UnionRowWindow
+///
+/// Combined row contract applied after the branch merge. Branch rows carry
+/// `visible_width` output columns followed by hidden primary-key columns that
+/// break order ties and are dropped before rows leave the merge.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(in crate::db) struct UnionRowWindow {
+    pub(in crate::db) visible_width: usize,
+    pub(in crate::db) distinct: bool,
+    pub(in crate::db) offset: usize,
+    pub(in crate::db) limit: Option<usize>,
+}
+
+/// Merge ordered union branch rows into the combined ordered row window.
+pub(in crate::db) fn merge_union_projection_rows(
+    branches: Vec<Vec<Vec<Value>>>,
+    order: &[UnionRowOrderTerm],
+    window: UnionRowWindow,
+) -> Result<Vec<Vec<Value>>, InternalError> {
+    let comparator = UnionRowOrder {
+        order,
+        key_start: window.visible_width,
+    };
+    let mut merge = FlatMergeStream::new(
+        branches.into_iter().map(UnionBranchRows::new).collect(),
+        comparator,
+    );
+    let mut distinct_rows = window.distinct.then(DistinctProjectionRowSet::new);
+    let mut skipped = 0;
+    let mut rows = Vec::new();
+
+    while window.limit.is_none_or(|limit| rows.len() < limit) {
+        let Some(mut row) = merge.next_item()? else {
+            break;
+        };
+        row.truncate(window.visible_width);
+        let row = RowView::owned(row);
+        if let Some(distinct_rows) = distinct_rows.as_mut()
+            && !distinct_rows.insert_row(&row)?
+        {
+            continue;
+        }
+        if skipped < window.offset {
+            skipped += 1;
+            continue;
+        }
+        rows.push(row.into_owned());
+    }
+
+    Ok(rows)
+}
+
+///
+/// UnionRowOrder
+///
+/// Merge order over branch rows: the combined ORDER BY terms first, then the
+/// hidden primary-key columns ascending, matching each branch's own order.
+///
+
+#[derive(Clone, Copy)]
+struct UnionRowOrder<'a> {
+    order: &'a [UnionRowOrderTerm],
+    key_start: usize,
+}
+
+impl FlatMergeKeyOrder<[Value]> for UnionRowOrder<'_> {
+    fn compare_keys(&self, left: &[Value], right: &[Value]) -> Ordering {
+        for term in self.order {
+            let ordering = compare_order_term_values(
+                left.get(term.column),
+                right.get(term.column),
+                term.direction,
+                term.nulls,
+            );
+            if ordering.is_ne() {
+                return ordering;
+            }
+        }
+
+        let left_key = left.get(self.key_start..).unwrap_or_default();
+        let right_key = right.get(self.key_start..).unwrap_or_default();
+        for (left, right) in left_key.iter().zip(right_key) {
+            let ordering = canonical_value_compare(left, right);
+            if ordering.is_ne() {
+                return ordering;
+            }
+        }
+
+        Ordering::Equal
+    }
+}
+
+///
+/// UnionBranchRows
+///
+/// One executed branch as a flat merge child. Branches can return the same
+/// entity row, so no two heads are ever treated as one merged key.
+///
+
+struct UnionBranchRows {
+    rows: IntoIter<Vec<Value>>,
+    head: Option<Vec<Value>>,
+}
+
+impl UnionBranchRows {
+    fn new(rows: Vec<Vec<Value>>) -> Self {
+        Self {
+            rows: rows.into_iter(),
+            head: None,
+        }
+    }
+}
+
+impl FlatMergeOrderedChild for UnionBranchRows {
+    type Item = Vec<Value>;
+    type Key = [Value];
+    type KeyWitness = ();
+
+    fn ensure_item(&mut self) -> Result<(), InternalError> {
+        if self.head.is_none() {
+            self.head = self.rows.next();
+        }
+
+        Ok(())
+    }
+
+    fn head_key(&self) -> Option<&[Value]> {
+        self.head.as_deref()
+    }
+
+    fn take_item(&mut self) -> Option<Vec<Value>> {
+        self.head.take()
+    }
+
+    fn item_key(item: &Vec<Value>) -> &[Value] {
+        item
+    }
+
+    fn key_witness(_key: &[Value]) -> Self::KeyWitness {}
+
+    fn witness_matches_key((): &Self::KeyWitness, _key: &[Value]) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(score: Option<u64>, id: u64) -> Vec<Value> {
+        vec![score.map_or(Value::Null, Value::Nat64), Value::Nat64(id)]
+    }
+
+    fn merge(
+        branches: Vec<Vec<Vec<Value>>>,
+        direction: OrderDirection,
+        distinct: bool,
+    ) -> Vec<Vec<Value>> {
+        merge_union_projection_rows(
+            branches,
+            &[UnionRowOrderTerm::new(
+                0,
+                direction,
+                NullsOrder::natural_for(direction),
+            )],
+            UnionRowWindow {
+                visible_width: 1,
+                distinct,
+                offset: 0,
+                limit: None,
+            },
+        )
+        .expect("union merge should succeed")
+    }
+
+    #[test]
+    fn union_merge_interleaves_branches_in_combined_order() {
+        let merged = merge(
+            vec![
+                vec![row(Some(1), 1), row(Some(5), 2)],
+                vec![row(None, 5), row(Some(3), 3), row(Some(5), 4)],
+                vec![row(Some(2), 6)],
+            ],
+            OrderDirection::Asc,
+            false,
+        );
+
+        assert_eq!(
+            merged,
+            vec![
+                vec![Value::Null],
+                vec![Value::Nat64(1)],
+                vec![Value::Nat64(2)],
+                vec![Value::Nat64(3)],
+                vec![Value::Nat64(5)],
+                vec![Value::Nat64(5)],
+            ],
+        );
+    }
+
+    #[test]
+    fn union_merge_distinct_drops_repeated_visible_rows() {
+        let merged = merge(
+            vec![
+                vec![row(Some(9), 1), row(Some(4), 2)],
+                vec![row(Some(9), 3), row(Some(4), 2)],
+            ],
+            OrderDirection::Desc,
+            true,
+        );
+
+        assert_eq!(merged, vec![vec![Value::Nat64(9)], vec![Value::Nat64(4)]]);
+    }
+}
@@ -23,6 +23,8 @@ pub(in crate::db) use eval::ProjectionEvalError;
 pub(in crate::db::executor) use eval::eval_compiled_expr_with_value_ref_reader;
 #[cfg(feature = "sql")]
 pub(in crate::db) use eval::eval_compiled_filter_expr_with_required_slot_reader;
+#[cfg(feature = "sql")]
+pub(in crate::db) use eval::eval_compiled_scalar_expr_with_row_values;
 pub(in crate::db::executor) use eval::{
     eval_compiled_expr_with_value_reader, eval_effective_runtime_filter_program_with_slot_reader,
     eval_effective_runtime_filter_program_with_value_cow_reader,
@@ -51,6 +53,10 @@ pub(in crate::db) use materialize::{
 pub(in crate::db::executor) use materialize::{
     ProjectionValidationRow, validate_prepared_projection_row,
 };
+#[cfg(feature = "sql")]
+pub(in crate::db) use materialize::{
+    UnionRowOrderTerm, UnionRowWindow, merge_union_projection_rows,
+};
 pub(in crate::db::executor::projection) use materialize::{
     project_admitted_page, project_distinct,
 };
@@ -729,6 +729,7 @@ fn primary_key_suffix_resume_anchor_for_prefix(
                 &prefix_start,
                 prefix_len,
                 &[],
+                &[],
                 &primary_key,
             )?
             .to_raw()?,
@@ -747,6 +748,7 @@ fn primary_key_suffix_resume_anchor_for_prefix(
             &prefix_start,
             prefix_len,
             suffix_values.as_slice(),
+            index.suffix_key_descending().as_slice(),
             &primary_key,
         )?
         .to_raw()?,
@@ -1395,6 +1397,7 @@ struct IndexPrimaryKeySeek {
     prefix_start: IndexKey,
     prefix_len: usize,
     suffix_len: usize,
+    suffix_descending: Vec<bool>,
 }
 
 impl IndexPrimaryKeySeek {
@@ -1416,6 +1419,7 @@ impl IndexPrimaryKeySeek {
             prefix_start,
             prefix_len,
             suffix_len: key_arity.saturating_sub(prefix_len),
+            suffix_descending: index.suffix_key_descending(),
         })
     }
 
@@ -1430,6 +1434,7 @@ impl IndexPrimaryKeySeek {
                 &self.prefix_start,
                 self.prefix_len,
                 suffix_values.as_slice(),
+                self.suffix_descending.as_slice(),
                 &target.primary_key_value(),
             )?
             .to_raw()?,
@@ -2045,7 +2050,8 @@ fn resolve_physical_key_stream(
         key_order_state = KeyOrderState::FinalOrder;
     }
 
-    if matches!(key_order_state, KeyOrderState::Unordered) {
+    let sorted_here = matches!(key_order_state, KeyOrderState::Unordered);
+    if sorted_here {
         charge_sort_work::<DecodedDataStoreKey>(candidates.len())?;
     }
     normalize_ordered_keys(
@@ -2054,6 +2060,24 @@ fn resolve_physical_key_stream(
         key_order_state,
     );
 
+    // Multi-entry text indexes post one key per token, so a token range can
+    // reach the same row more than once. Once sorted, duplicates are adjacent
+    // and field-keyed ranges pass through unchanged.
+    if sorted_here && matches!(path, ExecutionPathPayload::IndexRange { .. }) {
+        candidates.dedup();
+    }
+
+    // Materialized candidates are collected from the start of each leaf, so a
+    // resumed primary-key-ordered page must drop everything it already passed
+    // before the page kernel counts rows toward its keep cap.
+    if let Some(boundary) = request.continuation.primary_key_boundary() {
+        let boundary = primary_key_boundary_data_key(runtime.entity_tag, boundary)?;
+        match request.continuation.direction() {
+            Direction::Asc => candidates.retain(|key| *key > boundary),
+            Direction::Desc => candidates.retain(|key| *key < boundary),
+        }
+    }
+
     Ok(ordered_key_stream_from_materialized_keys(candidates))
 }
 
@@ -2272,6 +2296,7 @@ mod physical_seek_tests {
             prefix_start: index_key(&index_id, component, 1),
             prefix_len: 1,
             suffix_len: 0,
+            suffix_descending: Vec::new(),
         };
         let mut stream = IndexRangeKeyStream::new(
             STORE,
@@ -1054,9 +1054,17 @@ impl IndexScan {
             DiagnosticExecutionBudgetResource::DecodedBytes,
             u64::try_from(raw_key.as_bytes().len()).unwrap_or(u64::MAX),
         )?;
+        // Components past the key arity address `INCLUDE` payload carried by
+        // the entry value.
         let mut components = Vec::with_capacity(component_indices.len());
         for component_index in component_indices {
-            let Some(component) = decoded_key.component(*component_index) else {
+            let component = match component_index.checked_sub(decoded_key.component_count()) {
+                None => decoded_key.component(*component_index),
+                Some(included_index) => value
+                    .included_component(included_index)
+                    .map_err(|_| InternalError::index_entry_decode_failed())?,
+            };
+            let Some(component) = component else {
                 return Err(InternalError::index_projection_component_required(
                     index.name(),
                     *component_index,
@@ -11,23 +11,35 @@ use crate::{
     error::InternalError,
 };
 use icydb_diagnostic_code::DiagnosticExecutionBudgetResource;
-use std::mem::size_of;
+use std::{cmp::Ordering, mem::size_of};
 
 pub(in crate::db::executor) trait FlatMergeOrderedChild {
     type Item;
+    type Key: ?Sized;
     type KeyWitness;
 
     fn ensure_item(&mut self) -> Result<(), InternalError>;
 
-    fn head_key(&self) -> Option<&DecodedDataStoreKey>;
+    fn head_key(&self) -> Option<&Self::Key>;
 
     fn take_item(&mut self) -> Option<Self::Item>;
 
-    fn item_key(item: &Self::Item) -> &DecodedDataStoreKey;
+    fn item_key(item: &Self::Item) -> &Self::Key;
 
-    fn key_witness(key: &DecodedDataStoreKey) -> Self::KeyWitness;
+    fn key_witness(key: &Self::Key) -> Self::KeyWitness;
 
-    fn witness_matches_key(witness: &Self::KeyWitness, key: &DecodedDataStoreKey) -> bool;
+    fn witness_matches_key(witness: &Self::KeyWitness, key: &Self::Key) -> bool;
+}
+
+/// Total order one flat merge applies to the head keys of its children.
+pub(in crate::db::executor) trait FlatMergeKeyOrder<K: ?Sized> {
+    fn compare_keys(&self, left: &K, right: &K) -> Ordering;
+}
+
+impl FlatMergeKeyOrder<DecodedDataStoreKey> for KeyOrderComparator {
+    fn compare_keys(&self, left: &DecodedDataStoreKey, right: &DecodedDataStoreKey) -> Ordering {
+        self.compare_data_keys(left, right)
+    }
 }
 
 pub(in crate::db::executor) enum FlatMergeSiblingSet<T> {
@@ -60,26 +72,25 @@ impl<T> FlatMergeSiblingSet<T> {
     }
 }
 
-pub(in crate::db::executor) struct FlatMergeStream<C>
+pub(in crate::db::executor) struct FlatMergeStream<C, O = KeyOrderComparator>
 where
     C: FlatMergeOrderedChild,
+    O: FlatMergeKeyOrder<C::Key>,
 {
     children: Vec<C>,
-    comparator: KeyOrderComparator,
+    comparator: O,
     last_emitted: Option<C::KeyWitness>,
     winner_tree: Vec<Option<usize>>,
     winner_tree_leaf_base: usize,
     dirty_child: Option<usize>,
 }
 
-impl<C> FlatMergeStream<C>
+impl<C, O> FlatMergeStream<C, O>
 where
     C: FlatMergeOrderedChild,
+    O: FlatMergeKeyOrder<C::Key>,
 {
-    pub(in crate::db::executor) const fn new(
-        children: Vec<C>,
-        comparator: KeyOrderComparator,
-    ) -> Self {
+    pub(in crate::db::executor) const fn new(children: Vec<C>, comparator: O) -> Self {
         Self {
             children,
             comparator,
@@ -155,11 +166,7 @@ where
                     .head_key();
                 match (left_key, right_key) {
                     (Some(left_key), Some(right_key)) => {
-                        if self
-                            .comparator
-                            .compare_data_keys(right_key, left_key)
-                            .is_lt()
-                        {
+                        if self.comparator.compare_keys(right_key, left_key).is_lt() {
                             Some(right)
                         } else {
                             Some(left)
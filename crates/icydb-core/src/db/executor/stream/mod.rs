@@ -8,6 +8,8 @@ mod flat_merge;
 pub(super) mod key;
 mod prefix_set;
 
+#[cfg(feature = "sql")]
+pub(in crate::db::executor) use flat_merge::FlatMergeKeyOrder;
 pub(in crate::db::executor) use flat_merge::{
     FlatMergeOrderedChild, FlatMergeSiblingSet, FlatMergeStream,
 };
@@ -62,7 +62,8 @@ pub(in crate::db) use metrics::{
     with_direct_data_row_phase_attribution, with_kernel_row_phase_attribution,
 };
 pub(in crate::db::executor) use plan::{
-    KernelRowOrderWindow, KernelRowScanStrategy, resolve_cursorless_short_path_plan,
+    KernelRowOrderWindow, KernelRowPartitionWindow, KernelRowScanStrategy,
+    resolve_cursorless_short_path_plan,
 };
 pub(in crate::db) use retained::RetainedSlotRow;
 pub(in crate::db::executor) use retained::{RetainedSlotLayout, RetainedSlotValueMode};
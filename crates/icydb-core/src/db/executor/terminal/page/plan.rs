@@ -17,7 +17,10 @@ use crate::{
             },
         },
         predicate::MissingRowPolicy,
-        query::plan::{AccessPlannedQuery, EffectiveRuntimeFilterProgram, ResolvedOrder},
+        query::plan::{
+            AccessPlannedQuery, EffectiveRuntimeFilterProgram, GroupedExecutionConfig,
+            ResolvedOrder,
+        },
     },
     error::InternalError,
 };
@@ -68,6 +71,7 @@ impl<'a> ScalarMaterializationPlan<'a> {
             scan_budget_hint,
             row_keep_cap: self.streaming_page_scan_keep_cap(plan, &continuation),
             order_window: self.bounded_materialized_order_scan_window(plan, &continuation)?,
+            partition_window: self.partition_scan_window(plan)?,
             load_order_route_mode,
             consistency,
             scan_strategy: self.kernel_row_scan_strategy,
@@ -100,6 +104,7 @@ impl<'a> ScalarMaterializationPlan<'a> {
         let logical = plan.scalar_plan();
         if !logical.mode.is_load()
             || logical.distinct
+            || logical.partition_limit.is_some()
             || logical
                 .order
                 .as_ref()
@@ -133,6 +138,7 @@ impl<'a> ScalarMaterializationPlan<'a> {
         let logical = plan.scalar_plan();
         if !logical.mode.is_load()
             || logical.distinct
+            || logical.partition_limit.is_some()
             || access_order_satisfied_by_route_mode(plan)
             || self.defer_retained_slot_distinct_window
             || !self.kernel_row_scan_strategy.materializes_slots()
@@ -160,6 +166,29 @@ impl<'a> ScalarMaterializationPlan<'a> {
         }))
     }
 
+    // Resolve the scan-time per-partition window for `LIMIT k PER` loads.
+    // Route-ordered scans already cluster partitions, so the window streams;
+    // otherwise it keeps one bounded order window per partition.
+    fn partition_scan_window(
+        &self,
+        plan: &'a AccessPlannedQuery,
+    ) -> Result<Option<KernelRowPartitionWindow<'a>>, InternalError> {
+        let Some(partition_limit) = plan.scalar_plan().partition_limit.as_ref() else {
+            return Ok(None);
+        };
+        if !self.kernel_row_scan_strategy.materializes_slots() {
+            return Err(InternalError::query_executor_invariant());
+        }
+
+        Ok(Some(KernelRowPartitionWindow {
+            resolved_order: plan.require_resolved_order()?,
+            partition_len: partition_limit.fields.len(),
+            keep_count: usize::try_from(partition_limit.limit).unwrap_or(usize::MAX),
+            streaming: access_order_satisfied_by_route_mode(plan),
+            execution: partition_limit.execution,
+        }))
+    }
+
     // Apply the remaining shared post-scan tail before cursor derivation and
     // final payload shaping.
     pub(super) fn apply_post_scan_tail(&self, rows: &[KernelRow]) -> Result<(), InternalError> {
@@ -211,6 +240,7 @@ impl<'a> CursorlessShortPathPlan<'a> {
             row_keep_cap: self.row_keep_cap,
             row_skip_count: self.row_skip_count,
             order_window: None,
+            partition_window: None,
             row_runtime,
         }
     }
@@ -303,6 +333,9 @@ pub(in crate::db::executor) fn resolve_cursorless_short_path_plan<'a>(
     capabilities: ScalarMaterializationCapabilities<'a>,
 ) -> Result<Option<CursorlessShortPathPlan<'a>>, InternalError> {
     let logical = plan.scalar_plan();
+    if logical.partition_limit.is_some() {
+        return Ok(None);
+    }
     let generic_short_path = logical.mode.is_load()
         && cursor_boundary.is_none()
         && logical.predicate.is_none()
@@ -460,6 +493,24 @@ pub(in crate::db::executor) struct KernelRowOrderWindow<'a> {
     pub(in crate::db::executor) keep_count: usize,
 }
 
+///
+/// KernelRowPartitionWindow
+///
+/// KernelRowPartitionWindow carries the scan-time `LIMIT k PER` window.
+/// The leading `partition_len` resolved order terms form the partition key;
+/// `streaming` records that route order already clusters each partition, and
+/// `execution` carries the plan's grouped limits for partition admission.
+///
+
+#[derive(Clone, Copy)]
+pub(in crate::db::executor) struct KernelRowPartitionWindow<'a> {
+    pub(in crate::db::executor) resolved_order: &'a ResolvedOrder,
+    pub(in crate::db::executor) partition_len: usize,
+    pub(in crate::db::executor) keep_count: usize,
+    pub(in crate::db::executor) streaming: bool,
+    pub(in crate::db::executor) execution: GroupedExecutionConfig,
+}
+
 // Resolve whether the scalar materializer can stay entirely on the direct
 // `DataRow` lane and, if so, which direct-lane strategy owns the scan.
 fn resolve_direct_data_row_path<'a>(
@@ -476,6 +527,7 @@ fn resolve_direct_data_row_path<'a>(
     // that do not need projection validation or retained-slot surfaces.
     let direct_load_surface_eligible = logical.mode.is_load()
         && !logical.distinct
+        && logical.partition_limit.is_none()
         && !validate_projection
         && !retain_slot_rows
         && !cursor_emission.enabled();
@@ -5,13 +5,14 @@ use crate::{
             BoundedOrderWindow, DataRowOrderWindow, OrderedKeyStreamBox, PendingOrderRows,
             ScalarContinuationContext, begin_production_scalar_page_unit,
             exact_output_key_count_hint, finish_production_scalar_page_unit,
+            group::PartitionTopKWindow,
             key_stream_budget_is_redundant, measure_execution_stats_phase,
             production_scalar_page_work_is_active, record_key_stream_micros,
             record_key_stream_yield,
             route::LoadOrderRouteMode,
             terminal::page::{
-                KernelRow, KernelRowOrderWindow, KernelRowScanStrategy, RetainedSlotLayout,
-                ScalarRowRuntimeHandle,
+                KernelRow, KernelRowOrderWindow, KernelRowPartitionWindow, KernelRowScanStrategy,
+                RetainedSlotLayout, ScalarRowRuntimeHandle,
             },
         },
         predicate::MissingRowPolicy,
@@ -64,6 +65,7 @@ struct KernelRowScanBounds<'a> {
     row_keep_cap: Option<usize>,
     row_skip_count: usize,
     order_window: Option<KernelRowOrderWindow<'a>>,
+    partition_window: Option<KernelRowPartitionWindow<'a>>,
 }
 
 impl<'a> KernelRowScanBounds<'a> {
@@ -71,11 +73,13 @@ impl<'a> KernelRowScanBounds<'a> {
         row_keep_cap: Option<usize>,
         row_skip_count: usize,
         order_window: Option<KernelRowOrderWindow<'a>>,
+        partition_window: Option<KernelRowPartitionWindow<'a>>,
     ) -> Self {
         Self {
             row_keep_cap,
             row_skip_count,
             order_window,
+            partition_window,
         }
     }
 }
@@ -88,6 +92,7 @@ pub(super) struct ScalarPageKernelRequest<'a, 'r> {
     pub(super) scan_budget_hint: Option<usize>,
     pub(super) row_keep_cap: Option<usize>,
     pub(super) order_window: Option<KernelRowOrderWindow<'a>>,
+    pub(super) partition_window: Option<KernelRowPartitionWindow<'a>>,
     pub(super) load_order_route_mode: LoadOrderRouteMode,
     pub(super) consistency: MissingRowPolicy,
     pub(super) scan_strategy: KernelRowScanStrategy<'a>,
@@ -113,6 +118,7 @@ pub(in crate::db::executor) struct KernelRowScanRequest<'a, 'r> {
     pub(in crate::db::executor) row_keep_cap: Option<usize>,
     pub(in crate::db::executor) row_skip_count: usize,
     pub(in crate::db::executor) order_window: Option<KernelRowOrderWindow<'a>>,
+    pub(in crate::db::executor) partition_window: Option<KernelRowPartitionWindow<'a>>,
     pub(in crate::db::executor) row_runtime: &'r mut ScalarRowRuntimeHandle<'a>,
 }
 
@@ -147,9 +153,11 @@ fn execute_kernel_row_scan_inner(
         row_keep_cap,
         row_skip_count,
         order_window,
+        partition_window,
         row_runtime,
     } = request;
-    let scan_bounds = KernelRowScanBounds::new(row_keep_cap, row_skip_count, order_window);
+    let scan_bounds =
+        KernelRowScanBounds::new(row_keep_cap, row_skip_count, order_window, partition_window);
 
     // Phase 1: select the concrete row-read kernel once so the inner scan
     // loop does not branch on payload shape or predicate mode per row.
@@ -289,6 +297,7 @@ pub(super) fn execute_scalar_page_kernel_dyn(
         scan_budget_hint,
         row_keep_cap,
         order_window,
+        partition_window,
         load_order_route_mode,
         consistency,
         scan_strategy,
@@ -307,6 +316,7 @@ pub(super) fn execute_scalar_page_kernel_dyn(
         row_keep_cap,
         row_skip_count: 0,
         order_window,
+        partition_window,
         row_runtime,
     })
 }
@@ -345,6 +355,14 @@ fn scan_kernel_rows_with(
             read_row,
         );
     }
+    if let Some(partition_window) = bounds.partition_window {
+        return scan_kernel_rows_with_partition_window(
+            key_stream,
+            bounds,
+            partition_window,
+            read_row,
+        );
+    }
 
     let result = scan_rows_with(
         key_stream,
@@ -404,6 +422,59 @@ fn scan_kernel_rows_with_bounded_order_window(
     Ok((window.into_pending_rows(), rows_scanned))
 }
 
+// Scan one key stream through the `LIMIT k PER` partition operator. Residual
+// filtering has already run inside `read_row`, so only matched rows count
+// toward each partition's window.
+fn scan_kernel_rows_with_partition_window(
+    key_stream: &mut OrderedKeyStreamBox,
+    bounds: KernelRowScanBounds<'_>,
+    partition_window: KernelRowPartitionWindow<'_>,
+    mut read_row: impl FnMut(DecodedDataStoreKey) -> Result<Option<KernelRow>, InternalError>,
+) -> Result<(PendingOrderRows<KernelRow>, usize), InternalError> {
+    if bounds.row_keep_cap.is_some() || bounds.row_skip_count != 0 {
+        return Err(InternalError::query_executor_invariant());
+    }
+    if partition_window.keep_count == 0 {
+        return Ok((PendingOrderRows::plain(Vec::new()), 0));
+    }
+
+    let mut rows_scanned = 0usize;
+    let mut window = PartitionTopKWindow::new(
+        partition_window.resolved_order,
+        partition_window.partition_len,
+        partition_window.keep_count,
+        partition_window.streaming,
+        partition_window.execution,
+    )?;
+
+    loop {
+        let page_unit = begin_scan_page_unit(key_stream)?;
+        if matches!(page_unit, ScanPageUnit::EnvelopeFull) {
+            break;
+        }
+        let key = next_kernel_scan_key(key_stream)?;
+        let Some(key) = key else {
+            finish_scan_page_unit(page_unit)?;
+            break;
+        };
+        record_key_stream_yield();
+
+        rows_scanned = rows_scanned.saturating_add(1);
+        let row = read_kernel_scan_row(key, &mut read_row)?;
+        finish_scan_page_unit(page_unit)?;
+        let Some(row) = row else {
+            continue;
+        };
+        if !row.has_materialized_slots() {
+            return Err(InternalError::query_executor_invariant());
+        }
+
+        window.push(row)?;
+    }
+
+    Ok((PendingOrderRows::plain(window.into_rows()), rows_scanned))
+}
+
 fn try_scan_borrowed_primary_rows_with_bounded_order_window(
     key_stream: &mut OrderedKeyStreamBox,
     bounds: KernelRowScanBounds<'_>,
@@ -4,17 +4,32 @@ use crate::db::{
     executor::terminal::page::post_access::{
         apply_load_cursor_and_pagination_window, compact_kernel_rows_in_place,
     },
-    query::plan::{OrderDirection, ResolvedOrder, ResolvedOrderField, ResolvedOrderValueSource},
+    query::plan::{
+        NullsOrder, OrderDirection, ResolvedOrder, ResolvedOrderField, ResolvedOrderValueSource,
+    },
 };
 
 fn kernel_row_u64(value: u64) -> KernelRow {
     KernelRow::new_slot_only(RetainedSlotRow::new(1, vec![(0, Value::Nat64(value))]))
 }
 
+fn kernel_row_null() -> KernelRow {
+    KernelRow::new_slot_only(RetainedSlotRow::new(1, vec![(0, Value::Null)]))
+}
+
 fn direct_field_order(slot: usize) -> ResolvedOrder {
+    direct_field_order_with(slot, OrderDirection::Asc, NullsOrder::First)
+}
+
+fn direct_field_order_with(
+    slot: usize,
+    direction: OrderDirection,
+    nulls: NullsOrder,
+) -> ResolvedOrder {
     ResolvedOrder::new(vec![ResolvedOrderField::new(
         ResolvedOrderValueSource::direct_field(slot),
-        OrderDirection::Asc,
+        direction,
+        nulls,
     )])
 }
 
@@ -143,3 +158,67 @@ fn compact_kernel_rows_in_place_preserves_kept_order() {
         vec![Some(Value::Nat64(2)), Some(Value::Nat64(4))]
     );
 }
+
+#[test]
+fn structural_order_window_honors_explicit_null_placement() {
+    for (direction, nulls, expected) in [
+        (
+            OrderDirection::Asc,
+            NullsOrder::Last,
+            vec![
+                Some(Value::Nat64(1)),
+                Some(Value::Nat64(2)),
+                Some(Value::Null),
+            ],
+        ),
+        (
+            OrderDirection::Desc,
+            NullsOrder::First,
+            vec![
+                Some(Value::Null),
+                Some(Value::Nat64(2)),
+                Some(Value::Nat64(1)),
+            ],
+        ),
+    ] {
+        let resolved_order = direct_field_order_with(0, direction, nulls);
+        let mut rows = vec![kernel_row_u64(2), kernel_row_null(), kernel_row_u64(1)];
+
+        crate::db::executor::order::apply_structural_order_window(&mut rows, &resolved_order, None)
+            .expect("explicit null placement should order");
+
+        assert_eq!(
+            rows.into_iter().map(|row| row.slot(0)).collect::<Vec<_>>(),
+            expected,
+            "{direction:?} {nulls:?}",
+        );
+    }
+}
+
+#[test]
+fn load_cursor_window_resumes_before_trailing_nulls() {
+    let resolved_order = direct_field_order_with(0, OrderDirection::Asc, NullsOrder::Last);
+    let boundary = CursorBoundary {
+        slots: vec![CursorBoundarySlot::Present(Value::Nat64(2))],
+    };
+    let mut rows = vec![
+        kernel_row_u64(1),
+        kernel_row_u64(2),
+        kernel_row_u64(3),
+        kernel_row_null(),
+    ];
+
+    let rows_after_cursor = apply_load_cursor_and_pagination_window(
+        &mut rows,
+        Some((&resolved_order, &boundary)),
+        0,
+        None,
+    )
+    .expect("valid cursor boundary should apply");
+
+    assert_eq!(rows_after_cursor, 2);
+    assert_eq!(
+        rows.into_iter().map(|row| row.slot(0)).collect::<Vec<_>>(),
+        vec![Some(Value::Nat64(3)), Some(Value::Null)]
+    );
+}
@@ -17,7 +17,16 @@ use std::borrow::Cow;
 const INDEX_ENTRY_WITNESS_BYTES: usize = 1;
 const INDEX_ENTRY_WITNESS_PRESENT: u8 = 0;
 const INDEX_ENTRY_WITNESS_MISSING: u8 = 1;
-pub(crate) const MAX_INDEX_ENTRY_BYTES: u32 = 1;
+const INDEX_ENTRY_INCLUDED_LEN_BYTES: usize = 2;
+pub(crate) const MAX_INDEX_ENTRY_BYTES: usize = INDEX_ENTRY_WITNESS_BYTES
+    + 1
+    + icydb_schema::MAX_INDEX_INCLUDE_FIELDS
+        * (INDEX_ENTRY_INCLUDED_LEN_BYTES + IndexKey::MAX_COMPONENT_SIZE);
+
+/// Included-component placeholder for one value the entry does not carry.
+/// No canonical value tag uses zero, so covering decode treats it as
+/// unsupported and falls back to the base row.
+pub(in crate::db) const INDEX_INCLUDED_COMPONENT_UNCARRIED: u8 = 0;
 
 ///
 /// IndexEntryCorruption
@@ -142,6 +151,80 @@ impl IndexEntryValue {
         Self::from_persisted_bytes(vec![IndexEntryExistenceWitness::Present.to_stored_byte()])
     }
 
+    /// Build one present entry carrying the index's `INCLUDE` payload
+    /// components in declaration order. Indexes without included fields keep
+    /// the presence-only encoding. A component count or length the layout
+    /// cannot record fails closed instead of being truncated.
+    pub(crate) fn presence_with_included(
+        included: &[Vec<u8>],
+    ) -> Result<Self, IndexEntryCorruption> {
+        if included.is_empty() {
+            return Ok(Self::presence());
+        }
+
+        let payload_len = included
+            .iter()
+            .map(|component| INDEX_ENTRY_INCLUDED_LEN_BYTES + component.len())
+            .sum::<usize>();
+        let mut bytes = Vec::with_capacity(INDEX_ENTRY_WITNESS_BYTES + 1 + payload_len);
+        bytes.push(IndexEntryExistenceWitness::Present.to_stored_byte());
+        bytes.push(u8::try_from(included.len()).map_err(|_| IndexEntryCorruption::TooLarge)?);
+        for component in included {
+            let len = u16::try_from(component.len()).map_err(|_| IndexEntryCorruption::TooLarge)?;
+            bytes.extend_from_slice(&len.to_be_bytes());
+            bytes.extend_from_slice(component);
+        }
+
+        Ok(Self::from_persisted_bytes(bytes))
+    }
+
+    /// Borrow one included payload component by declaration position.
+    /// Returns `None` when this entry carries fewer included components.
+    pub(in crate::db) fn included_component(
+        &self,
+        position: usize,
+    ) -> Result<Option<&[u8]>, IndexEntryCorruption> {
+        self.validate_witness()?;
+
+        Ok(self.included_components()?.get(position).copied())
+    }
+
+    // Walk the included payload after the witness byte. The caller validates
+    // the witness first; structural payload errors surface as length
+    // mismatches.
+    fn included_components(&self) -> Result<Vec<&[u8]>, IndexEntryCorruption> {
+        let bytes = self.as_bytes();
+        let Some((&count, mut rest)) = bytes
+            .get(INDEX_ENTRY_WITNESS_BYTES..)
+            .unwrap_or_default()
+            .split_first()
+        else {
+            return Ok(Vec::new());
+        };
+        if count == 0 || usize::from(count) > icydb_schema::MAX_INDEX_INCLUDE_FIELDS {
+            return Err(IndexEntryCorruption::LengthMismatch);
+        }
+        let mut components = Vec::with_capacity(usize::from(count));
+        for _ in 0..count {
+            let Some((len, tail)) = rest.split_first_chunk::<INDEX_ENTRY_INCLUDED_LEN_BYTES>()
+            else {
+                return Err(IndexEntryCorruption::LengthMismatch);
+            };
+            let len = usize::from(u16::from_be_bytes(*len));
+            if len == 0 || len > IndexKey::MAX_COMPONENT_SIZE || tail.len() < len {
+                return Err(IndexEntryCorruption::LengthMismatch);
+            }
+            let (component, tail) = tail.split_at(len);
+            components.push(component);
+            rest = tail;
+        }
+        if !rest.is_empty() {
+            return Err(IndexEntryCorruption::LengthMismatch);
+        }
+
+        Ok(components)
+    }
+
     pub(crate) fn decode_row_identity(
         &self,
         raw_key: &RawIndexStoreKey,
@@ -214,21 +297,23 @@ impl IndexEntryValue {
         self.validate_witness().map(|_| ())
     }
 
-    // Validate the raw index-entry witness payload. Row identity now belongs to
-    // `RawIndexStoreKey`; the value carries only a storage-owned existence witness.
+    // Validate the raw index-entry witness payload. Row identity belongs to
+    // `RawIndexStoreKey`; the value carries a storage-owned existence witness
+    // plus any `INCLUDE` payload components.
     fn validate_witness(&self) -> Result<IndexEntryExistenceWitness, IndexEntryCorruption> {
         let bytes = self.as_bytes();
-        if bytes.len() > MAX_INDEX_ENTRY_BYTES as usize {
+        if bytes.len() > MAX_INDEX_ENTRY_BYTES {
             return Err(IndexEntryCorruption::TooLarge);
         }
         if bytes.is_empty() {
             return Err(IndexEntryCorruption::EmptyEntry);
         }
+        let witness = IndexEntryExistenceWitness::try_from_stored_byte(bytes[0])?;
         if bytes.len() != INDEX_ENTRY_WITNESS_BYTES {
-            return Err(IndexEntryCorruption::LengthMismatch);
+            self.included_components()?;
         }
 
-        IndexEntryExistenceWitness::try_from_stored_byte(bytes[0])
+        Ok(witness)
     }
 
     #[must_use]
@@ -250,10 +335,10 @@ impl Storable for IndexEntryValue {
         self.into_bytes()
     }
 
-    const BOUND: Bound = Bound::Bounded {
-        max_size: MAX_INDEX_ENTRY_BYTES,
-        is_fixed_size: false,
-    };
+    // Presence-only entries stay one byte; covering payloads vary per index,
+    // so the stable map stores values unbounded and validation enforces
+    // `MAX_INDEX_ENTRY_BYTES`.
+    const BOUND: Bound = Bound::Unbounded;
 }
 
 ///
@@ -179,7 +179,7 @@ fn index_entry_value_rejects_invalid_witness() {
 #[test]
 fn index_entry_value_rejects_oversized_payload() {
     let raw_key = raw_key_for(PrimaryKeyComponent::Int64(1));
-    let bytes = vec![0u8; MAX_INDEX_ENTRY_BYTES as usize + 1];
+    let bytes = vec![0u8; MAX_INDEX_ENTRY_BYTES + 1];
     let raw = IndexEntryValue::from_bytes(Cow::Owned(bytes));
     std::assert_matches!(
         raw.decode_row_witness(&raw_key),
@@ -187,6 +187,51 @@ fn index_entry_value_rejects_oversized_payload() {
     );
 }
 
+#[test]
+fn index_entry_value_round_trips_included_components() {
+    let raw_key = raw_key_for(PrimaryKeyComponent::Int64(1));
+    let included = vec![vec![0x12, b'a'], vec![0x0F]];
+    let raw = IndexEntryValue::presence_with_included(&included).expect("encode included");
+
+    assert_eq!(
+        raw.decode_existence_witness().expect("decode witness"),
+        IndexEntryExistenceWitness::Present
+    );
+    assert!(raw.decode_row_witness(&raw_key).is_ok());
+    assert_eq!(
+        raw.included_component(1).expect("decode included"),
+        Some([0x0F].as_slice())
+    );
+    assert_eq!(raw.included_component(2).expect("decode included"), None);
+    assert_eq!(
+        IndexEntryValue::presence_with_included(&[]).expect("encode presence"),
+        IndexEntryValue::presence()
+    );
+}
+
+#[test]
+fn index_entry_value_rejects_unrepresentable_included_payload() {
+    std::assert_matches!(
+        IndexEntryValue::presence_with_included(&vec![vec![0x0F]; 256]),
+        Err(IndexEntryCorruption::TooLarge)
+    );
+    std::assert_matches!(
+        IndexEntryValue::presence_with_included(&[vec![0x12; usize::from(u16::MAX) + 1]]),
+        Err(IndexEntryCorruption::TooLarge)
+    );
+}
+
+#[test]
+fn index_entry_value_rejects_truncated_included_payload() {
+    let mut bytes = IndexEntryValue::presence_with_included(&[vec![0x12, b'a']])
+        .expect("encode included")
+        .into_bytes();
+    bytes.pop();
+    let raw = IndexEntryValue::from_bytes(Cow::Owned(bytes));
+
+    std::assert_matches!(raw.validate(), Err(IndexEntryCorruption::LengthMismatch));
+}
+
 #[test]
 fn index_entry_value_rejects_invalid_raw_key_primary_suffix() {
     let raw = IndexEntryValue::presence();
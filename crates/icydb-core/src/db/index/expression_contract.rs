@@ -61,6 +61,8 @@ impl SemanticIndexExpression {
             PersistedIndexExpressionOp::Year => format!("YEAR({field})"),
             PersistedIndexExpressionOp::Month => format!("MONTH({field})"),
             PersistedIndexExpressionOp::Day => format!("DAY({field})"),
+            PersistedIndexExpressionOp::Tokens => format!("TOKENS({field})"),
+            PersistedIndexExpressionOp::Elements => format!("ELEMENTS({field})"),
         }
     }
 }
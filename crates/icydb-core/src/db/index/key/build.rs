@@ -3,32 +3,41 @@
 //! Does not own: raw key byte framing (codec) or index-store writes.
 //! Boundary: planning/mutation paths call into this constructor layer.
 
+#[cfg(feature = "sql")]
+use crate::db::executor::eval_compiled_scalar_expr_with_row_values;
+use crate::db::query::plan::expr::CompiledExpr;
 use crate::db::schema::SchemaInfo;
 use crate::db::schema::{
     SchemaExpressionIndexRebuildExpression, SchemaExpressionIndexRebuildKey,
     SchemaExpressionIndexRebuildTarget,
 };
 use crate::{
-    MAX_INDEX_FIELDS,
+    MAX_INDEX_FIELDS, MAX_MULTIVALUE_INDEX_ELEMENTS,
+    db::text_search::tokenize_indexed_text,
     db::{
         data::CanonicalSlotReader,
         index::{
+            INDEX_INCLUDED_COMPONENT_UNCARRIED, IndexExpressionSourceClass,
             SemanticIndexExpression, derive_index_expression_value,
-            key::ordered::encode_canonical_index_component,
+            key::ordered::{descending_index_component, encode_canonical_index_component},
             key::{IndexId, IndexKey, IndexKeyEncodeError, IndexKeyKind, OrderedValueEncodeError},
+            normalize_scalar_index_key_value,
         },
-        key_taxonomy::PrimaryKeyValue,
+        key_taxonomy::{IndexEntryValue, PrimaryKeyValue},
         schema::{
-            AcceptedFieldKind, AcceptedValueAdmissionContract, SchemaExpressionIndexInfo,
-            SchemaExpressionIndexKeyItemInfo, SchemaFieldPathIndexRebuildKey,
-            SchemaFieldPathIndexRebuildTarget, SchemaIndexFieldPathInfo, SchemaIndexInfo,
-            ValueAdmissionBudget, encode_unit_enum_equality_key,
+            AcceptedFieldKind, AcceptedValueAdmissionContract, PersistedIndexExpressionOp,
+            SchemaExpressionIndexInfo, SchemaExpressionIndexKeyItemInfo,
+            SchemaFieldPathIndexRebuildKey, SchemaFieldPathIndexRebuildTarget,
+            SchemaIndexFieldPathInfo, SchemaIndexInfo, ValueAdmissionBudget,
+            encode_unit_enum_equality_key,
         },
     },
     error::InternalError,
     types::EntityTag,
-    value::Value,
+    value::{Value, ValueTag},
 };
+use std::collections::BTreeSet;
+
 type AcceptedFieldPathComponentEncoder<'a> = dyn FnMut(&SchemaIndexInfo, &SchemaIndexFieldPathInfo) -> Result<Option<Vec<u8>>, InternalError>
     + 'a;
 type AcceptedExpressionComponentEncoder<'a> =
@@ -119,6 +128,98 @@ impl IndexKey {
         )
     }
 
+    /// Build every entry one row owns in one accepted expression index.
+    ///
+    /// Token indexes derive one key per distinct token of the source text and
+    /// element indexes one key per distinct collection element; every other
+    /// expression index derives at most one key.
+    pub(crate) fn new_entries_from_slots_with_accepted_expression_index_primary_key_value(
+        entity_tag: EntityTag,
+        primary_key: &PrimaryKeyValue,
+        accepted_index: &SchemaExpressionIndexInfo,
+        slots: &dyn CanonicalSlotReader,
+    ) -> Result<Vec<Self>, InternalError> {
+        let Some(expression) = accepted_index.multi_entry_expression() else {
+            return Ok(
+                Self::new_from_slots_with_accepted_expression_index_primary_key_value(
+                    entity_tag,
+                    primary_key,
+                    accepted_index,
+                    slots,
+                )?
+                .into_iter()
+                .collect(),
+            );
+        };
+        let source = slots.required_value_by_contract_cow(expression.source().slot())?;
+
+        build_multi_entry_index_keys(
+            primary_key,
+            IndexId::new_with_generation(
+                entity_tag,
+                accepted_index.ordinal(),
+                accepted_index.physical_generation(),
+            ),
+            accepted_index.name(),
+            &SemanticIndexExpression::new(
+                expression.op(),
+                accepted_field_path_term(
+                    expression.source().field_name(),
+                    expression.source().path(),
+                ),
+            ),
+            source.as_ref(),
+        )
+    }
+
+    /// Build every entry one row owns in one expression-index rebuild target.
+    ///
+    /// Token and element targets derive one key per distinct token or
+    /// collection element; every other target derives at most one key.
+    pub(crate) fn new_entries_from_slots_with_expression_rebuild_target(
+        entity_tag: EntityTag,
+        primary_key: impl Into<PrimaryKeyValue>,
+        target: &SchemaExpressionIndexRebuildTarget,
+        slots: &dyn CanonicalSlotReader,
+    ) -> Result<Vec<Self>, InternalError> {
+        let primary_key = primary_key.into();
+        let Some(expression) = target.multi_entry_expression() else {
+            return Ok(Self::new_from_slots_with_expression_rebuild_target(
+                entity_tag,
+                primary_key,
+                target,
+                slots,
+            )?
+            .into_iter()
+            .collect());
+        };
+        let source =
+            slots.required_value_by_contract_cow(usize::from(expression.source().slot().get()))?;
+        let Some(source) =
+            resolve_field_path_rebuild_component(source.as_ref(), expression.source())?
+        else {
+            return Ok(Vec::new());
+        };
+
+        build_multi_entry_index_keys(
+            &primary_key,
+            IndexId::new_with_generation(
+                entity_tag,
+                target.ordinal(),
+                target.physical_generation(),
+            ),
+            target.name(),
+            &SemanticIndexExpression::new(
+                expression.op(),
+                accepted_field_path_term(
+                    expression.source().field_name(),
+                    expression.source().path(),
+                ),
+            ),
+            source,
+        )
+    }
+
     #[must_use]
     pub(crate) fn empty_with_kind(index_id: &IndexId, key_kind: IndexKeyKind) -> Self {
         Self {
@@ -147,11 +248,13 @@ impl IndexKey {
     }
 
     /// Build an index key from already-lowered prefix components plus a
-    /// semantic primary-key suffix.
+    /// semantic primary-key suffix. `suffix_descending` carries the stored
+    /// direction of each suffix component.
     pub(in crate::db) fn new_from_existing_prefix_and_suffix_values_with_primary_key_value(
         prefix_start: &Self,
         prefix_len: usize,
         suffix_values: &[Value],
+        suffix_descending: &[bool],
         primary_key: &PrimaryKeyValue,
     ) -> Result<Self, InternalError> {
         let mut components = Vec::with_capacity(prefix_len + suffix_values.len());
@@ -161,10 +264,19 @@ impl IndexKey {
                 .ok_or_else(InternalError::query_executor_invariant)?;
             push_index_key_component(&mut components, component.to_vec())?;
         }
-        for value in suffix_values {
+        for (component_index, value) in suffix_values.iter().enumerate() {
             let Some(component) = encode_value_index_component_ref(value)? else {
                 return Err(InternalError::query_executor_invariant());
             };
+            let component = if suffix_descending
+                .get(component_index)
+                .copied()
+                .unwrap_or(false)
+            {
+                descending_index_component(component)
+            } else {
+                component
+            };
             push_index_key_component(&mut components, component)?;
         }
 
@@ -195,6 +307,62 @@ fn accepted_field_path_component_bytes_from_slots(
     encode_accepted_field_path_index_component(accepted_index, field, source)
 }
 
+/// Build the entry value one accepted field-path index stores for a row,
+/// carrying its `INCLUDE` payload components in declaration order.
+pub(in crate::db) fn accepted_index_entry_value_from_slots(
+    accepted_index: &SchemaIndexInfo,
+    slots: &dyn CanonicalSlotReader,
+) -> Result<IndexEntryValue, InternalError> {
+    let included = accepted_index
+        .include()
+        .iter()
+        .map(|field| {
+            let source = slots.required_value_by_contract_cow(field.slot())?;
+            included_component(resolve_accepted_field_path_component(
+                source.as_ref(),
+                field,
+            )?)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    IndexEntryValue::presence_with_included(&included).map_err(|_| InternalError::index_invariant())
+}
+
+/// Build the entry value one rebuilt field-path index stores for a row from
+/// the accepted mutation target.
+pub(in crate::db) fn field_path_rebuild_entry_value_from_slots(
+    target: &SchemaFieldPathIndexRebuildTarget,
+    slots: &dyn CanonicalSlotReader,
+) -> Result<IndexEntryValue, InternalError> {
+    let included = target
+        .include_paths()
+        .iter()
+        .map(|field| {
+            let source = slots.required_value_by_contract_cow(usize::from(field.slot().get()))?;
+            included_component(resolve_field_path_rebuild_component(
+                source.as_ref(),
+                field,
+            )?)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    IndexEntryValue::presence_with_included(&included).map_err(|_| InternalError::index_invariant())
+}
+
+// Encode one included payload value. Null and missing paths keep the null
+// tag so covering reads return SQL NULL; values the canonical component
+// encoding cannot carry store the uncarried marker and force a row read.
+fn included_component(source: Option<&Value>) -> Result<Vec<u8>, InternalError> {
+    let Some(source) = source.filter(|source| !matches!(source, Value::Null)) else {
+        return Ok(vec![ValueTag::Null.to_u8()]);
+    };
+
+    Ok(match encode_value_index_component_ref(source)? {
+        Some(component) if component.len() <= IndexKey::MAX_COMPONENT_SIZE => component,
+        Some(_) | None => vec![INDEX_INCLUDED_COMPONENT_UNCARRIED],
+    })
+}
+
 fn encode_accepted_field_path_index_component(
     accepted_index: &SchemaIndexInfo,
     field: &SchemaIndexFieldPathInfo,
@@ -259,6 +427,9 @@ pub(in crate::db) fn encode_accepted_index_literal_component(
             SchemaExpressionIndexKeyItemInfo::Expression(_) => {
                 encode_value_index_component_ref(value)
             }
+            SchemaExpressionIndexKeyItemInfo::Scalar(_) => {
+                encode_value_index_component(normalize_scalar_index_key_value(value.clone()))
+            }
         };
     }
 
@@ -318,9 +489,53 @@ fn accepted_expression_component_bytes_from_slots(
 
             encode_value_index_component(value)
         }
+        SchemaExpressionIndexKeyItemInfo::Scalar(scalar) => {
+            scalar_expression_component_bytes_from_slots(
+                scalar.expression(),
+                scalar.source_slots().iter().copied(),
+                slots,
+            )
+        }
     }
 }
 
+// Evaluate one arbitrary scalar key expression over its declared source
+// slots and encode the normalized result. Missing compiled authority means
+// the accepted expression could not be bound in this build, so key
+// derivation fails closed rather than writing a partial index.
+#[cfg(feature = "sql")]
+fn scalar_expression_component_bytes_from_slots(
+    expression: Option<&CompiledExpr>,
+    source_slots: impl Iterator<Item = usize>,
+    slots: &dyn CanonicalSlotReader,
+) -> Result<Option<Vec<u8>>, InternalError> {
+    let expression = expression.ok_or_else(InternalError::index_unsupported)?;
+    let sources = source_slots
+        .map(|slot| {
+            slots
+                .required_value_by_contract_cow(slot)
+                .map(|value| (slot, value))
+        })
+        .collect::<Result<Vec<_>, InternalError>>()?;
+    let value = eval_compiled_scalar_expr_with_row_values(expression, &mut |slot| {
+        sources
+            .iter()
+            .find_map(|(source_slot, value)| (*source_slot == slot).then_some(value.as_ref()))
+    })?;
+
+    encode_value_index_component(normalize_scalar_index_key_value(value))
+}
+
+// Builds without the SQL frontend cannot evaluate stored key expressions.
+#[cfg(not(feature = "sql"))]
+fn scalar_expression_component_bytes_from_slots(
+    _expression: Option<&CompiledExpr>,
+    _source_slots: impl Iterator<Item = usize>,
+    _slots: &dyn CanonicalSlotReader,
+) -> Result<Option<Vec<u8>>, InternalError> {
+    Err(InternalError::index_unsupported())
+}
+
 fn field_path_rebuild_component_bytes_from_slots(
     field: &SchemaFieldPathIndexRebuildKey,
     slots: &dyn CanonicalSlotReader,
@@ -345,6 +560,16 @@ fn expression_rebuild_component_bytes_from_slots(
         SchemaExpressionIndexRebuildKey::Expression(expression) => {
             expression_rebuild_expression_component_bytes_from_slots(index_name, expression, slots)
         }
+        SchemaExpressionIndexRebuildKey::Scalar(scalar) => {
+            scalar_expression_component_bytes_from_slots(
+                scalar.expression(),
+                scalar
+                    .sources()
+                    .iter()
+                    .map(|source| usize::from(source.slot().get())),
+                slots,
+            )
+        }
     }
 }
 
@@ -484,6 +709,11 @@ fn build_field_path_rebuild_target_key(
         let Some(component) = component_bytes(field)? else {
             return Ok(None);
         };
+        let component = if field.descending() {
+            descending_index_component(component)
+        } else {
+            component
+        };
 
         if component.len() > IndexKey::MAX_COMPONENT_SIZE {
             return Err(InternalError::index_component_exceeds_max_size_at(
@@ -622,6 +852,11 @@ fn build_accepted_field_path_index_key_from_components(
         let Some(component) = component_bytes(accepted_index, field)? else {
             return Ok(None);
         };
+        let component = if field.descending() {
+            descending_index_component(component)
+        } else {
+            component
+        };
 
         if component.len() > IndexKey::MAX_COMPONENT_SIZE {
             return Err(InternalError::index_component_exceeds_max_size_at(
@@ -647,6 +882,103 @@ fn build_accepted_field_path_index_key_from_components(
     }))
 }
 
+// Derive every key one source value owns in one multi-entry index.
+fn build_multi_entry_index_keys(
+    primary_key: &PrimaryKeyValue,
+    index_id: IndexId,
+    index_name: &str,
+    expression: &SemanticIndexExpression,
+    source: &Value,
+) -> Result<Vec<IndexKey>, InternalError> {
+    let components = if expression.op() == PersistedIndexExpressionOp::Elements {
+        collection_element_index_components(index_name, expression, source)?
+    } else {
+        text_token_index_components(index_name, expression, source)?
+    };
+    let primary_key = IndexKey::compact_primary_key_value_bytes(primary_key)?;
+
+    components
+        .into_iter()
+        .map(|component| {
+            let mut components = Vec::with_capacity(1);
+            push_index_key_component(&mut components, component)?;
+
+            Ok(IndexKey {
+                key_kind: IndexKeyKind::User,
+                index_id,
+                components,
+                primary_key: primary_key.clone(),
+            })
+        })
+        .collect()
+}
+
+// Encode one component per distinct token of one text source value. `NULL`
+// sources own no postings; any other non-text source is a contract mismatch.
+fn text_token_index_components(
+    index_name: &str,
+    expression: &SemanticIndexExpression,
+    source: &Value,
+) -> Result<Vec<Vec<u8>>, InternalError> {
+    let text = match source {
+        Value::Null => return Ok(Vec::new()),
+        Value::Text(text) => text,
+        other => {
+            return Err(InternalError::index_expression_source_type_mismatch(
+                index_name,
+                expression.canonical_order_text(),
+                IndexExpressionSourceClass::Text,
+                other.canonical_tag().label(),
+            ));
+        }
+    };
+    let tokens = tokenize_indexed_text(text)
+        .map_err(|_| InternalError::index_text_token_limit_exceeded())?;
+
+    tokens
+        .into_iter()
+        .map(|token| {
+            encode_value_index_component(Value::Text(token))?
+                .ok_or_else(InternalError::index_invariant)
+        })
+        .collect()
+}
+
+// Encode one component per distinct element of one list or set source value.
+// `NULL` sources and `NULL` elements own no entries, so `CONTAINS` lookups
+// only ever see concrete element values.
+fn collection_element_index_components(
+    index_name: &str,
+    expression: &SemanticIndexExpression,
+    source: &Value,
+) -> Result<Vec<Vec<u8>>, InternalError> {
+    let elements = match source {
+        Value::Null => return Ok(Vec::new()),
+        Value::List(elements) => elements,
+        other => {
+            return Err(InternalError::index_expression_source_type_mismatch(
+                index_name,
+                expression.canonical_order_text(),
+                IndexExpressionSourceClass::Collection,
+                other.canonical_tag().label(),
+            ));
+        }
+    };
+
+    let mut components = BTreeSet::new();
+    for element in elements {
+        let Some(component) = encode_value_index_component_ref(element)? else {
+            continue;
+        };
+        components.insert(component);
+        if components.len() > MAX_MULTIVALUE_INDEX_ELEMENTS {
+            return Err(InternalError::index_multivalue_element_limit_exceeded());
+        }
+    }
+
+    Ok(components.into_iter().collect())
+}
+
 // Push one canonical component after enforcing the shared size contract.
 fn push_index_key_component(
     components: &mut Vec<Vec<u8>>,
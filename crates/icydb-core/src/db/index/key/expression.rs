@@ -9,20 +9,23 @@ use crate::{
         scalar_index_expression_op,
     },
     db::schema::PersistedIndexExpressionOp,
-    value::Value,
+    value::{Value, ops::numeric::to_numeric_decimal},
 };
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub(in crate::db) enum IndexExpressionSourceClass {
     Text,
     DateOrTimestamp,
+    Collection,
 }
 
 fn derive_text_expression_value(
     expression: PersistedIndexExpressionOp,
     source: Value,
 ) -> Result<Option<Value>, IndexExpressionSourceClass> {
-    let op = scalar_index_expression_op(expression);
+    let Some(op) = scalar_index_expression_op(expression) else {
+        return Err(IndexExpressionSourceClass::Text);
+    };
     let source = match source {
         Value::Null => return Ok(None),
         Value::Text(value) => ScalarExprValue::Text(value.into()),
@@ -39,7 +42,9 @@ fn derive_temporal_expression_value(
     expression: PersistedIndexExpressionOp,
     source: Value,
 ) -> Result<Option<Value>, IndexExpressionSourceClass> {
-    let op = scalar_index_expression_op(expression);
+    let Some(op) = scalar_index_expression_op(expression) else {
+        return Err(IndexExpressionSourceClass::DateOrTimestamp);
+    };
     let source = match source {
         Value::Null => return Ok(None),
         Value::Date(value) => ScalarExprValue::Date(value),
@@ -59,6 +64,9 @@ fn derive_temporal_expression_value(
 /// - `Ok(Some(...))` for one derived indexable value
 /// - `Ok(None)` for `NULL` source values (non-indexable)
 /// - `Err(expected_source_class)` for type-mismatched sources
+///
+/// Token expressions have no single derived value and always return
+/// `Ok(None)`; their keys derive through the multi-entry key builders.
 pub(in crate::db) fn derive_index_expression_value(
     expression: PersistedIndexExpressionOp,
     source: Value,
@@ -72,5 +80,24 @@ pub(in crate::db) fn derive_index_expression_value(
         | PersistedIndexExpressionOp::Year
         | PersistedIndexExpressionOp::Month
         | PersistedIndexExpressionOp::Day => derive_temporal_expression_value(expression, source),
+        PersistedIndexExpressionOp::Tokens | PersistedIndexExpressionOp::Elements => Ok(None),
+    }
+}
+
+/// Normalize one scalar expression-index key value into its stored lane.
+///
+/// Scalar arithmetic may yield integer or decimal results for the same
+/// expression, so exact numeric values share the decimal lane in both stored
+/// keys and planner lookup literals. Other values keep their own lane.
+#[must_use]
+pub(in crate::db) fn normalize_scalar_index_key_value(value: Value) -> Value {
+    match &value {
+        Value::Int64(_)
+        | Value::Int128(_)
+        | Value::IntBig(_)
+        | Value::Nat64(_)
+        | Value::Nat128(_)
+        | Value::NatBig(_) => to_numeric_decimal(&value).map_or(value, Value::Decimal),
+        _ => value,
     }
 }
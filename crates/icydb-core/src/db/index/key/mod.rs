@@ -13,10 +13,16 @@ mod ordered;
 mod tests;
 
 pub(in crate::db) use build::encode_accepted_index_literal_component;
+pub(in crate::db) use build::{
+    accepted_index_entry_value_from_slots, field_path_rebuild_entry_value_from_slots,
+};
 pub(in crate::db) use codec::IndexKeyEncodeError;
 pub(crate) use codec::{IndexKey, IndexKeyKind, RawIndexStoreKey};
 pub(in crate::db) use expression::IndexExpressionSourceClass;
-pub(in crate::db) use expression::derive_index_expression_value;
+pub(in crate::db) use expression::{
+    derive_index_expression_value, normalize_scalar_index_key_value,
+};
 pub(crate) use id::IndexId;
 pub(crate) use ordered::EncodedValue;
 pub(crate) use ordered::OrderedValueEncodeError;
+pub(in crate::db) use ordered::{ascending_index_component, descending_index_component};
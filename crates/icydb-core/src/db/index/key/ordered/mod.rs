@@ -13,6 +13,7 @@ use crate::db::key_taxonomy::PrimaryKeyComponent;
 #[cfg(test)]
 use crate::db::numeric::compare_numeric_or_strict_order;
 use crate::{db::index::key::ordered::semantics::OrderedEncode, value::Value};
+use std::borrow::Cow;
 #[cfg(test)]
 use std::cmp::Ordering;
 
@@ -22,6 +23,10 @@ const NEGATIVE_MARKER: u8 = 0x00;
 const ZERO_MARKER: u8 = 0x01;
 const POSITIVE_MARKER: u8 = 0x02;
 
+// Canonical components start with a value tag below this floor, so a stored
+// component whose first byte reaches it is a byte-inverted descending segment.
+const DESCENDING_COMPONENT_TAG_FLOOR: u8 = 0x80;
+
 ///
 /// EncodedValue
 ///
@@ -110,6 +115,34 @@ pub(crate) fn encode_canonical_index_component(
     Ok(out)
 }
 
+/// Invert one canonical component so ascending byte order over the stored
+/// segment follows descending value order.
+///
+/// Every canonical payload is prefix-free (fixed width, length-prefixed, or
+/// terminated), so inverting every byte exactly reverses segment order.
+#[must_use]
+pub(crate) fn descending_index_component(mut component: Vec<u8>) -> Vec<u8> {
+    for byte in &mut component {
+        *byte = !*byte;
+    }
+
+    component
+}
+
+/// Borrow one stored component in canonical ascending form, restoring
+/// byte-inverted descending segments.
+#[must_use]
+pub(crate) fn ascending_index_component(component: &[u8]) -> Cow<'_, [u8]> {
+    match component.first() {
+        Some(&tag) if tag >= DESCENDING_COMPONENT_TAG_FLOOR => {
+            let mut restored = Vec::with_capacity(component.len());
+            segments::push_inverted(&mut restored, component);
+            Cow::Owned(restored)
+        }
+        _ => Cow::Borrowed(component),
+    }
+}
+
 /// Encode one decoded primary-key value into canonical index-component bytes without
 /// materializing an owned runtime `Value`.
 #[cfg(test)]
@@ -8,7 +8,8 @@ use crate::{
         index::key::{
             OrderedValueEncodeError,
             ordered::{
-                compare_index_component_values, encode_canonical_index_component,
+                ascending_index_component, compare_index_component_values,
+                descending_index_component, encode_canonical_index_component,
                 encode_canonical_index_component_from_primary_key_value,
             },
         },
@@ -217,6 +218,34 @@ fn canonical_encoder_respects_numeric_order_for_scalars() {
     );
 }
 
+#[test]
+fn descending_component_reverses_order_and_restores_ascending_bytes() {
+    let values = [
+        Value::Int64(-2),
+        Value::Int64(7),
+        Value::Text("a".to_string()),
+        Value::Text("ab".to_string()),
+        Value::Decimal(Decimal::from_i128_with_scale(-15, 1)),
+        Value::Decimal(Decimal::from_i128_with_scale(3, 0)),
+    ];
+    let encoded = values
+        .iter()
+        .map(|value| encode_canonical_index_component(value).expect("value should encode"))
+        .collect::<Vec<_>>();
+
+    for left in &encoded {
+        let left_desc = descending_index_component(left.clone());
+        assert_eq!(
+            ascending_index_component(&left_desc).as_ref(),
+            left.as_slice()
+        );
+        for right in &encoded {
+            let right_desc = descending_index_component(right.clone());
+            assert_eq!(left_desc.cmp(&right_desc), right.cmp(left));
+        }
+    }
+}
+
 #[test]
 fn canonical_encoder_value_int_big_and_nat_big_same_value_same_bytes_across_construction_paths() {
     let int_cases = vec![
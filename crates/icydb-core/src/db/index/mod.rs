@@ -17,6 +17,7 @@ mod store;
 
 pub(in crate::db) use crate::db::key_taxonomy::IndexEntryValue;
 pub(in crate::db) use cardinality::UserIndexPrefixCardinalityKey;
+pub(in crate::db) use entry::INDEX_INCLUDED_COMPONENT_UNCARRIED;
 pub(in crate::db) use entry::IndexEntryExistenceWitness;
 pub(in crate::db) use entry::IndexEntryRowWitness;
 pub(in crate::db) use entry::IndexRowIdentity;
@@ -27,10 +28,13 @@ pub(in crate::db) use envelope::{
 pub(in crate::db) use expression_contract::SemanticIndexExpression;
 pub(in crate::db) use expression_contract::index_expression_supports_text_casefold_lookup;
 pub(in crate::db) use key::{
-    EncodedValue, IndexExpressionSourceClass, encode_accepted_index_literal_component,
+    EncodedValue, IndexExpressionSourceClass, accepted_index_entry_value_from_slots,
+    ascending_index_component, descending_index_component, encode_accepted_index_literal_component,
+    field_path_rebuild_entry_value_from_slots,
 };
 pub(in crate::db) use key::{
     IndexId, IndexKey, IndexKeyKind, RawIndexStoreKey, derive_index_expression_value,
+    normalize_scalar_index_key_value,
 };
 pub(in crate::db) use plan::{
     AcceptedIndexInspectionDomain, AcceptedIndexInspectionPlan, IndexDelta, IndexDeltaGroup,
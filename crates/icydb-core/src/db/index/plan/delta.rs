@@ -3,7 +3,7 @@
 //! Does not own: commit-op materialization, executor context wiring, or apply sequencing.
 //! Boundary: index planning emits these deltas; commit preparation lowers them to store writes.
 
-use crate::db::{index::IndexKey, key_taxonomy::PrimaryKeyValue};
+use crate::db::index::{IndexEntryValue, IndexKey};
 
 ///
 /// IndexMutationPlan
@@ -54,8 +54,9 @@ impl IndexDeltaGroup {
 /// IndexDelta
 ///
 /// One row-identity presence change for one secondary index key. The delta
-/// records only index-domain facts: which key owns the row identity, which row
-/// primary key is affected, and whether that presence is inserted or removed.
+/// records only index-domain facts: which key owns the row identity (the key
+/// suffix carries the row primary key), the entry value an insertion stores,
+/// and whether that presence is inserted or removed.
 ///
 
 #[derive(Debug)]
@@ -69,20 +70,24 @@ pub(in crate::db) enum IndexDelta {
 impl IndexDelta {
     /// Build one removal membership delta.
     #[must_use]
-    pub(in crate::db) const fn remove(key: IndexKey, primary_key: &PrimaryKeyValue) -> Self {
+    pub(in crate::db) fn remove(key: IndexKey) -> Self {
         Self::Remove(IndexMembershipDelta {
             key,
-            primary_key: *primary_key,
+            value: IndexEntryValue::presence(),
         })
     }
 
-    /// Build one insertion membership delta.
+    /// Build one insertion membership delta storing a presence-only entry.
     #[must_use]
-    pub(in crate::db) const fn insert(key: IndexKey, primary_key: &PrimaryKeyValue) -> Self {
-        Self::Insert(IndexMembershipDelta {
-            key,
-            primary_key: *primary_key,
-        })
+    pub(in crate::db) fn insert(key: IndexKey) -> Self {
+        Self::insert_with_value(key, IndexEntryValue::presence())
+    }
+
+    /// Build one insertion membership delta storing `value`, which may carry
+    /// `INCLUDE` payload components.
+    #[must_use]
+    pub(in crate::db) const fn insert_with_value(key: IndexKey, value: IndexEntryValue) -> Self {
+        Self::Insert(IndexMembershipDelta { key, value })
     }
 }
 
@@ -97,5 +102,5 @@ impl IndexDelta {
 #[derive(Debug)]
 pub(in crate::db) struct IndexMembershipDelta {
     pub(in crate::db) key: IndexKey,
-    pub(in crate::db) primary_key: PrimaryKeyValue,
+    pub(in crate::db) value: IndexEntryValue,
 }
@@ -7,9 +7,10 @@ use crate::{
     db::{
         data::{CanonicalSlotReader, StructuralRowContract},
         index::{
-            IndexId, IndexKey, IndexKeyKind, RawIndexStoreKey,
+            IndexEntryValue, IndexId, IndexKey, IndexKeyKind, RawIndexStoreKey,
+            accepted_index_entry_value_from_slots,
             plan::{
-                accepted_expression_index_key_for_slot_reader_with_membership_structural,
+                accepted_expression_index_keys_for_slot_reader_with_membership_structural,
                 accepted_field_path_index_key_for_slot_reader_with_membership_structural,
             },
             raw_keys_for_component_prefix_with_kind,
@@ -18,7 +19,7 @@ use crate::{
         predicate::{PredicateProgram, normalize, parse_sql_predicate},
         schema::{
             AcceptedSchemaSnapshot, AcceptedValueCatalogHandle, PersistedIndexKeySnapshot,
-            SchemaExpressionIndexInfo, SchemaIndexId, SchemaIndexInfo,
+            SchemaExpressionIndexInfo, SchemaIndexId, SchemaIndexInfo, SchemaInfo,
             schema_expression_index_info_from_accepted_index,
             schema_index_info_from_accepted_index,
         },
@@ -35,6 +36,7 @@ pub(in crate::db) struct AcceptedIndexInspectionWitness {
     schema_index_id: SchemaIndexId,
     store_path: String,
     raw_key: RawIndexStoreKey,
+    value: IndexEntryValue,
 }
 
 impl AcceptedIndexInspectionWitness {
@@ -55,6 +57,12 @@ impl AcceptedIndexInspectionWitness {
     pub(in crate::db) const fn raw_key(&self) -> &RawIndexStoreKey {
         &self.raw_key
     }
+
+    /// Borrow the exact entry value, including any INCLUDE payload.
+    #[must_use]
+    pub(in crate::db) const fn value(&self) -> &IndexEntryValue {
+        &self.value
+    }
 }
 
 /// Exact active physical domain for one accepted forward index.
@@ -132,6 +140,8 @@ impl AcceptedIndexInspectionPlan {
         row_contract: &StructuralRowContract,
     ) -> Result<Self, InternalError> {
         let snapshot = schema.persisted_snapshot();
+        let schema_info =
+            SchemaInfo::from_accepted_snapshot_and_catalog(schema, value_catalog.clone(), false);
         let mut indexes = Vec::with_capacity(snapshot.indexes().len());
 
         for accepted in snapshot.indexes() {
@@ -160,6 +170,7 @@ impl AcceptedIndexInspectionPlan {
                         accepted,
                         snapshot,
                         &value_catalog,
+                        &schema_info,
                     )
                     .ok_or_else(InternalError::store_corruption)?;
                     validate_projection_identity(
@@ -242,34 +253,44 @@ impl AcceptedIndexInspectionPlan {
         })
     }
 
-    /// Derive one exact optional witness for a decoded accepted row.
+    /// Derive every exact witness one decoded accepted row owns in one
+    /// index. Token indexes own one witness per distinct token; every other
+    /// index owns at most one.
     pub(in crate::db) fn project(
         &self,
         ordinal: usize,
         entity_tag: EntityTag,
         primary_key: &PrimaryKeyValue,
         row: &dyn CanonicalSlotReader,
-    ) -> Result<Option<AcceptedIndexInspectionWitness>, InternalError> {
+    ) -> Result<Vec<AcceptedIndexInspectionWitness>, InternalError> {
         let entry = self
             .indexes
             .get(ordinal)
             .ok_or_else(InternalError::store_invariant)?;
-        let (schema_index_id, store_path, key) = match entry {
+        let (schema_index_id, store_path, keys, value) = match entry {
             AcceptedIndexInspectionEntry::FieldPath {
                 schema_index_id,
                 info,
                 predicate,
-            } => (
-                *schema_index_id,
-                info.store(),
-                accepted_field_path_index_key_for_slot_reader_with_membership_structural(
-                    entity_tag,
-                    info,
-                    predicate.as_ref(),
-                    primary_key,
-                    row,
-                )?,
-            ),
+            } => {
+                let keys =
+                    accepted_field_path_index_key_for_slot_reader_with_membership_structural(
+                        entity_tag,
+                        info,
+                        predicate.as_ref(),
+                        primary_key,
+                        row,
+                    )?
+                    .into_iter()
+                    .collect::<Vec<_>>();
+                let value = if keys.is_empty() {
+                    IndexEntryValue::presence()
+                } else {
+                    accepted_index_entry_value_from_slots(info, row)?
+                };
+
+                (*schema_index_id, info.store(), keys, value)
+            }
             AcceptedIndexInspectionEntry::Expression {
                 schema_index_id,
                 info,
@@ -277,26 +298,27 @@ impl AcceptedIndexInspectionPlan {
             } => (
                 *schema_index_id,
                 info.store(),
-                accepted_expression_index_key_for_slot_reader_with_membership_structural(
+                accepted_expression_index_keys_for_slot_reader_with_membership_structural(
                     entity_tag,
                     info,
                     predicate.as_ref(),
                     primary_key,
                     row,
                 )?,
+                IndexEntryValue::presence(),
             ),
         };
 
-        let raw_key = key
-            .as_ref()
-            .map(IndexKey::to_raw)
-            .transpose()
-            .map_err(InternalError::from)?;
-        Ok(raw_key.map(|raw_key| AcceptedIndexInspectionWitness {
-            schema_index_id,
-            store_path: store_path.to_string(),
-            raw_key,
-        }))
+        keys.iter()
+            .map(|key| {
+                Ok(AcceptedIndexInspectionWitness {
+                    schema_index_id,
+                    store_path: store_path.to_string(),
+                    raw_key: key.to_raw().map_err(InternalError::from)?,
+                    value: value.clone(),
+                })
+            })
+            .collect()
     }
 }
 
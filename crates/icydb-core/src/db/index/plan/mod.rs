@@ -13,7 +13,10 @@ use crate::{
     db::{
         commit::CommitSchemaFingerprint,
         data::{CanonicalSlotReader, StructuralRowContract},
-        index::{IndexKey, IndexReadContract, IndexRowIdentity},
+        index::{
+            IndexEntryValue, IndexKey, IndexReadContract, IndexRowIdentity,
+            accepted_index_entry_value_from_slots,
+        },
         key_taxonomy::PrimaryKeyValue,
         predicate::{Predicate, PredicateProgram, normalize, parse_sql_predicate},
         schema::{
@@ -100,21 +103,21 @@ pub(in crate::db::index::plan) fn accepted_field_path_index_key_for_slot_reader_
     )
 }
 
-pub(in crate::db::index::plan) fn accepted_expression_index_key_for_slot_reader_with_membership_structural(
+pub(in crate::db::index::plan) fn accepted_expression_index_keys_for_slot_reader_with_membership_structural(
     entity_tag: EntityTag,
     accepted_index: &SchemaExpressionIndexInfo,
     predicate_program: Option<&PredicateProgram>,
     primary_key: &PrimaryKeyValue,
     slots: &dyn CanonicalSlotReader,
-) -> Result<Option<IndexKey>, InternalError> {
+) -> Result<Vec<IndexKey>, InternalError> {
     if let Some(predicate_program) = predicate_program {
         let keep_row = predicate_program.eval_with_structural_slot_reader(slots)?;
         if !keep_row {
-            return Ok(None);
+            return Ok(Vec::new());
         }
     }
 
-    IndexKey::new_from_slots_with_accepted_expression_index_primary_key_value(
+    IndexKey::new_entries_from_slots_with_accepted_expression_index_primary_key_value(
         entity_tag,
         primary_key,
         accepted_index,
@@ -143,6 +146,34 @@ fn load_structural_accepted_field_path_index_key(
     )
 }
 
+// Derive the key and entry value one row owns in a field-path index. Rows
+// outside the index keep a presence value that is never written.
+fn load_structural_accepted_field_path_index_entry(
+    lane: IndexKeyLane,
+    entity_tag: EntityTag,
+    accepted_index: &SchemaIndexInfo,
+    predicate_program: Option<&PredicateProgram>,
+    primary_key: Option<&PrimaryKeyValue>,
+    slots: &dyn CanonicalSlotReader,
+) -> Result<(Option<IndexKey>, IndexEntryValue), InternalError> {
+    let key = load_structural_accepted_field_path_index_key(
+        lane,
+        entity_tag,
+        accepted_index,
+        predicate_program,
+        primary_key,
+        slots,
+    )?;
+    let value = if key.is_some() && !accepted_index.include().is_empty() {
+        accepted_index_entry_value_from_slots(accepted_index, slots)?
+    } else {
+        IndexEntryValue::presence()
+    };
+
+    Ok((key, value))
+}
+
+// Single-entry expression indexes derive at most one key per row.
 fn load_structural_accepted_expression_index_key(
     lane: IndexKeyLane,
     entity_tag: EntityTag,
@@ -151,11 +182,30 @@ fn load_structural_accepted_expression_index_key(
     primary_key: Option<&PrimaryKeyValue>,
     slots: &dyn CanonicalSlotReader,
 ) -> Result<Option<IndexKey>, InternalError> {
+    Ok(load_structural_accepted_expression_index_entries(
+        lane,
+        entity_tag,
+        accepted_index,
+        predicate_program,
+        primary_key,
+        slots,
+    )?
+    .pop())
+}
+
+fn load_structural_accepted_expression_index_entries(
+    lane: IndexKeyLane,
+    entity_tag: EntityTag,
+    accepted_index: &SchemaExpressionIndexInfo,
+    predicate_program: Option<&PredicateProgram>,
+    primary_key: Option<&PrimaryKeyValue>,
+    slots: &dyn CanonicalSlotReader,
+) -> Result<Vec<IndexKey>, InternalError> {
     let Some(primary_key) = primary_key else {
         return Err(lane.missing_entity_key_error());
     };
 
-    accepted_expression_index_key_for_slot_reader_with_membership_structural(
+    accepted_expression_index_keys_for_slot_reader_with_membership_structural(
         entity_tag,
         accepted_index,
         predicate_program,
@@ -312,7 +362,11 @@ fn plan_accepted_field_path_index_mutation_for_slot_reader_structural(
     new_slots: Option<&mut dyn CanonicalSlotReader>,
 ) -> Result<(), IndexPlanError> {
     let mut referenced_slots = vec![false; row_contract.field_count()];
-    for field in accepted_index.fields() {
+    for field in accepted_index
+        .fields()
+        .iter()
+        .chain(accepted_index.include())
+    {
         if let Some(referenced) = referenced_slots.get_mut(field.slot()) {
             *referenced = true;
         }
@@ -337,8 +391,8 @@ fn plan_accepted_field_path_index_mutation_for_slot_reader_structural(
     let index_store = accepted_index.store();
     let index_is_unique = accepted_index.unique();
     let read_contract = IndexReadContract::new(index_store, index_is_unique);
-    let old_key = match old_slots {
-        Some(slots) => load_structural_accepted_field_path_index_key(
+    let (old_key, old_value) = match old_slots {
+        Some(slots) => load_structural_accepted_field_path_index_entry(
             IndexKeyLane::Old,
             entity_tag,
             accepted_index,
@@ -346,10 +400,10 @@ fn plan_accepted_field_path_index_mutation_for_slot_reader_structural(
             old_primary_key,
             slots,
         )?,
-        None => None,
+        None => (None, IndexEntryValue::presence()),
     };
-    let new_key = match new_slots {
-        Some(slots) => load_structural_accepted_field_path_index_key(
+    let (new_key, new_value) = match new_slots {
+        Some(slots) => load_structural_accepted_field_path_index_entry(
             IndexKeyLane::New,
             entity_tag,
             accepted_index,
@@ -357,13 +411,25 @@ fn plan_accepted_field_path_index_mutation_for_slot_reader_structural(
             new_primary_key,
             slots,
         )?,
-        None => None,
+        None => (None, IndexEntryValue::presence()),
     };
 
     // Unchanged membership cannot conflict or alter the accepted index. Avoid
     // a stable index read and commit delta for unrelated-field updates; full
     // index integrity remains owned by the maintained integrity surfaces.
+    // A changed `INCLUDE` payload under the same key only rewrites the entry.
     if old_key.as_ref() == new_key.as_ref() && old_primary_key == new_primary_key {
+        push_index_delta_group(
+            groups,
+            index_store,
+            old_key,
+            new_key,
+            old_primary_key,
+            new_primary_key,
+            &old_value,
+            new_value,
+        )?;
+
         return Ok(());
     }
 
@@ -398,11 +464,25 @@ fn plan_accepted_field_path_index_mutation_for_slot_reader_structural(
         new_key,
         old_primary_key,
         new_primary_key,
+        &old_value,
+        new_value,
     )?;
 
     Ok(())
 }
 
+// Return every row slot one accepted key item reads. Scalar expression keys
+// may read several fields; all other key items read exactly one.
+fn accepted_key_item_source_slots(item: &SchemaExpressionIndexKeyItemInfo) -> Vec<usize> {
+    match item {
+        SchemaExpressionIndexKeyItemInfo::FieldPath(field) => vec![field.slot()],
+        SchemaExpressionIndexKeyItemInfo::Expression(expression) => {
+            vec![expression.source().slot()]
+        }
+        SchemaExpressionIndexKeyItemInfo::Scalar(scalar) => scalar.source_slots().to_vec(),
+    }
+}
+
 #[expect(clippy::too_many_arguments)]
 fn plan_accepted_expression_index_mutation_for_slot_reader_structural(
     groups: &mut Vec<IndexDeltaGroup>,
@@ -420,12 +500,12 @@ fn plan_accepted_expression_index_mutation_for_slot_reader_structural(
     new_slots: Option<&mut dyn CanonicalSlotReader>,
 ) -> Result<(), IndexPlanError> {
     let mut referenced_slots = vec![false; row_contract.field_count()];
-    for item in accepted_index.key_items() {
-        let field = match item {
-            SchemaExpressionIndexKeyItemInfo::FieldPath(field) => field,
-            SchemaExpressionIndexKeyItemInfo::Expression(expression) => expression.source(),
-        };
-        if let Some(referenced) = referenced_slots.get_mut(field.slot()) {
+    for slot in accepted_index
+        .key_items()
+        .iter()
+        .flat_map(accepted_key_item_source_slots)
+    {
+        if let Some(referenced) = referenced_slots.get_mut(slot) {
             *referenced = true;
         }
     }
@@ -446,6 +526,21 @@ fn plan_accepted_expression_index_mutation_for_slot_reader_structural(
         return Ok(());
     }
 
+    if accepted_index.multi_entry_expression().is_some() {
+        return plan_accepted_multi_entry_index_mutation_structural(
+            groups,
+            entity_path,
+            entity_tag,
+            read_view,
+            accepted_index,
+            predicate_program,
+            old_primary_key,
+            old_slots,
+            new_primary_key,
+            new_slots,
+        );
+    }
+
     let index_store = accepted_index.store();
     let index_is_unique = accepted_index.unique();
     let read_contract = IndexReadContract::new(index_store, index_is_unique);
@@ -508,11 +603,84 @@ fn plan_accepted_expression_index_mutation_for_slot_reader_structural(
         new_key,
         old_primary_key,
         new_primary_key,
+        &IndexEntryValue::presence(),
+        IndexEntryValue::presence(),
     )?;
 
     Ok(())
 }
 
+// Diff the token postings one row owns in a multi-entry index. Every removed
+// and added posting lands in one delta group; token indexes are never unique,
+// so no uniqueness probe is needed.
+#[expect(clippy::too_many_arguments)]
+fn plan_accepted_multi_entry_index_mutation_structural(
+    groups: &mut Vec<IndexDeltaGroup>,
+    entity_path: &str,
+    entity_tag: EntityTag,
+    read_view: &dyn IndexPlanReadView,
+    accepted_index: &SchemaExpressionIndexInfo,
+    predicate_program: Option<&PredicateProgram>,
+    old_primary_key: Option<&PrimaryKeyValue>,
+    old_slots: Option<&mut dyn CanonicalSlotReader>,
+    new_primary_key: Option<&PrimaryKeyValue>,
+    new_slots: Option<&mut dyn CanonicalSlotReader>,
+) -> Result<(), IndexPlanError> {
+    let index_store = accepted_index.store();
+    let read_contract = IndexReadContract::new(index_store, accepted_index.unique());
+    let old_keys = match old_slots {
+        Some(slots) => load_structural_accepted_expression_index_entries(
+            IndexKeyLane::Old,
+            entity_tag,
+            accepted_index,
+            predicate_program,
+            old_primary_key,
+            slots,
+        )?,
+        None => Vec::new(),
+    };
+    let new_keys = match new_slots {
+        Some(slots) => load_structural_accepted_expression_index_entries(
+            IndexKeyLane::New,
+            entity_tag,
+            accepted_index,
+            predicate_program,
+            new_primary_key,
+            slots,
+        )?,
+        None => Vec::new(),
+    };
+
+    let mut deltas = Vec::new();
+    for old_key in old_keys.iter().filter(|key| !new_keys.contains(key)) {
+        let old_entry =
+            load_existing_entry_structural(read_view, read_contract, Some(old_key), entity_path)?;
+        validate_existing_old_index_membership(
+            entity_path,
+            false,
+            old_primary_key,
+            Some(old_key),
+            old_entry.as_ref(),
+        )?;
+        if old_primary_key.is_none() {
+            return Err(InternalError::index_commit_op_old_entity_key_required().into());
+        }
+        deltas.push(IndexDelta::remove(old_key.clone()));
+    }
+    for new_key in new_keys.into_iter().filter(|key| !old_keys.contains(key)) {
+        if new_primary_key.is_none() {
+            return Err(InternalError::index_commit_op_new_entity_key_required().into());
+        }
+        deltas.push(IndexDelta::insert(new_key));
+    }
+
+    if !deltas.is_empty() {
+        groups.push(IndexDeltaGroup::new(index_store, deltas));
+    }
+
+    Ok(())
+}
+
 fn unchanged_index_inputs(
     old_primary_key: Option<&PrimaryKeyValue>,
     old_slots: Option<&dyn CanonicalSlotReader>,
@@ -536,7 +704,8 @@ fn unchanged_index_inputs(
 
 // Convert one validated old/new key transition into index-domain membership
 // deltas. Commit preparation later materializes these deltas against its active
-// reader view, so this helper deliberately does not encode `IndexEntryValue`.
+// reader view; the insertion carries the new row's entry value.
+#[expect(clippy::too_many_arguments)]
 fn push_index_delta_group(
     groups: &mut Vec<IndexDeltaGroup>,
     index_store: &str,
@@ -544,28 +713,33 @@ fn push_index_delta_group(
     new_key: Option<IndexKey>,
     old_primary_key: Option<&PrimaryKeyValue>,
     new_primary_key: Option<&PrimaryKeyValue>,
+    old_value: &IndexEntryValue,
+    new_value: IndexEntryValue,
 ) -> Result<(), InternalError> {
-    // A row update that preserves both index key and row identity has already
-    // validated the committed membership above. It needs no marker payload,
-    // overlay entry, stable-index write, or index-generation movement.
-    if old_key.as_ref() == new_key.as_ref() && old_primary_key == new_primary_key {
+    // A row update that preserves index key, row identity, and entry value
+    // needs no marker payload, overlay entry, stable-index write, or
+    // index-generation movement.
+    if old_key.as_ref() == new_key.as_ref()
+        && old_primary_key == new_primary_key
+        && *old_value == new_value
+    {
         return Ok(());
     }
 
     let mut deltas = Vec::with_capacity(2);
 
     if let Some(old_key) = old_key {
-        let Some(old_primary_key) = old_primary_key else {
+        if old_primary_key.is_none() {
             return Err(InternalError::index_commit_op_old_entity_key_required());
-        };
-        deltas.push(IndexDelta::remove(old_key, old_primary_key));
+        }
+        deltas.push(IndexDelta::remove(old_key));
     }
 
     if let Some(new_key) = new_key {
-        let Some(new_primary_key) = new_primary_key else {
+        if new_primary_key.is_none() {
             return Err(InternalError::index_commit_op_new_entity_key_required());
-        };
-        deltas.push(IndexDelta::insert(new_key, new_primary_key));
+        }
+        deltas.push(IndexDelta::insert_with_value(new_key, new_value));
     }
 
     if !deltas.is_empty() {
@@ -629,6 +803,8 @@ mod tests {
             Some(key),
             Some(&primary_key),
             Some(&primary_key),
+            &IndexEntryValue::presence(),
+            IndexEntryValue::presence(),
         )
         .expect("unchanged membership should remain valid");
 
@@ -647,10 +823,34 @@ mod tests {
             Some(test_index_key(8, &primary_key)),
             Some(&primary_key),
             Some(&primary_key),
+            &IndexEntryValue::presence(),
+            IndexEntryValue::presence(),
         )
         .expect("changed membership should remain valid");
 
         assert_eq!(groups.len(), 1);
         assert_eq!(groups[0].deltas.len(), 2);
     }
+
+    #[test]
+    fn changed_included_payload_under_same_key_retains_rewrite_deltas() {
+        let primary_key = PrimaryKeyValue::from(PrimaryKeyComponent::Int64(41));
+        let key = test_index_key(7, &primary_key);
+        let mut groups = Vec::new();
+
+        push_index_delta_group(
+            &mut groups,
+            "store",
+            Some(key.clone()),
+            Some(key),
+            Some(&primary_key),
+            Some(&primary_key),
+            &IndexEntryValue::presence_with_included(&[vec![1]]).expect("encode included"),
+            IndexEntryValue::presence_with_included(&[vec![2]]).expect("encode included"),
+        )
+        .expect("payload-only change should remain valid");
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].deltas.len(), 2);
+    }
 }
@@ -240,7 +240,8 @@ fn compile_index_program_from_resolved_full(
         | ExecutablePredicate::IsEmpty { .. }
         | ExecutablePredicate::IsNotEmpty { .. }
         | ExecutablePredicate::TextContains { .. }
-        | ExecutablePredicate::TextContainsCi { .. } => None,
+        | ExecutablePredicate::TextContainsCi { .. }
+        | ExecutablePredicate::TextMatch { .. } => None,
     }
 }
 
@@ -281,7 +282,8 @@ fn compile_index_program_from_resolved_full_for_targets(
         | ExecutablePredicate::IsEmpty { .. }
         | ExecutablePredicate::IsNotEmpty { .. }
         | ExecutablePredicate::TextContains { .. }
-        | ExecutablePredicate::TextContainsCi { .. } => None,
+        | ExecutablePredicate::TextContainsCi { .. }
+        | ExecutablePredicate::TextMatch { .. } => None,
     }
 }
 
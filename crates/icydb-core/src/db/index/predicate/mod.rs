@@ -5,7 +5,7 @@
 
 pub(crate) mod compile;
 use crate::{
-    db::index::{EncodedValue, IndexKey},
+    db::index::{EncodedValue, IndexKey, ascending_index_component},
     error::InternalError,
     value::Value,
 };
@@ -77,6 +77,10 @@ pub(crate) fn eval_index_compare(
     op: IndexCompareOp,
     literal: &IndexLiteral,
 ) -> bool {
+    // Literals stay in ascending canonical form, so descending key segments
+    // are restored before comparison.
+    let component = ascending_index_component(component);
+    let component = component.as_ref();
     match (op, literal) {
         (IndexCompareOp::Eq, IndexLiteral::One(expected)) => component == expected.as_slice(),
         (IndexCompareOp::Ne, IndexLiteral::One(expected)) => component != expected.as_slice(),
@@ -3,7 +3,9 @@
 //! Does not own: continuation token verification or index-store scanning.
 //! Boundary: planner/cursor paths call this module to build raw bounds.
 
-use crate::db::index::{IndexId, IndexKey, IndexKeyKind, RawIndexStoreKey};
+use crate::db::index::{
+    IndexId, IndexKey, IndexKeyKind, RawIndexStoreKey, descending_index_component,
+};
 use crate::{db::index::EncodedValue, value::Value};
 use std::ops::Bound;
 
@@ -277,16 +279,26 @@ fn raw_bounds_for_encoded_index_component_range(
 }
 
 /// Lower one ordered range after its equality prefix has already been encoded
-/// against accepted index contracts.
+/// against accepted index contracts. A descending range component stores
+/// inverted bytes, so its semantic upper bound becomes the raw lower bound.
 pub(in crate::db) fn build_index_component_range_with_encoded_prefix(
     index_id: &IndexId,
     index_len: usize,
     encoded_prefix: Vec<EncodedValue>,
     lower: &Bound<Value>,
     upper: &Bound<Value>,
+    descending: bool,
 ) -> Result<IndexBoundsLowering, IndexRangeBoundEncodeError> {
     let encoded_lower = encode_semantic_component_bound(lower, IndexRangeBoundEncodeError::Lower)?;
     let encoded_upper = encode_semantic_component_bound(upper, IndexRangeBoundEncodeError::Upper)?;
+    let (encoded_lower, encoded_upper) = if descending {
+        (
+            descending_component_bound(encoded_upper),
+            descending_component_bound(encoded_lower),
+        )
+    } else {
+        (encoded_lower, encoded_upper)
+    };
     let (lower, upper) = raw_bounds_for_encoded_index_component_range(
         index_id,
         index_len,
@@ -354,6 +366,12 @@ const fn encoded_component_bound(bound: &Bound<EncodedValue>) -> Bound<&[u8]> {
     }
 }
 
+fn descending_component_bound(bound: Bound<EncodedValue>) -> Bound<EncodedValue> {
+    bound.map(|value| {
+        EncodedValue::from_canonical_bytes(descending_index_component(value.into_bytes()))
+    })
+}
+
 fn encode_semantic_component_bound(
     bound: &Bound<Value>,
     kind: IndexRangeBoundEncodeError,
@@ -8,7 +8,10 @@ use crate::{
         Db,
         data::{DecodedDataStoreKey, StructuralSlotReader},
         direction::Direction,
-        index::{AcceptedIndexInspectionDomain, IndexEntryValue, IndexKey, RawIndexStoreKey},
+        index::{
+            AcceptedIndexInspectionDomain, IndexEntryExistenceWitness, IndexEntryValue, IndexKey,
+            RawIndexStoreKey,
+        },
         integrity::{
             IntegrityEntityIdentity, IntegrityFinding, IntegrityFindingClass, IntegrityFindingKind,
             IntegrityPhase, IntegritySeverity, IntegrityVerifierFamily, PhysicalUnitCheckpoint,
@@ -406,7 +409,12 @@ fn inspect_index_witness(
         )
         .map(Some);
     };
-    if !domain.contains_decoded_key(&key) || raw_value != &IndexEntryValue::presence() {
+    if !domain.contains_decoded_key(&key)
+        || !matches!(
+            raw_value.decode_existence_witness(),
+            Ok(IndexEntryExistenceWitness::Present)
+        )
+    {
         return derived_finding(
             plan,
             raw_key,
@@ -475,8 +483,10 @@ fn inspect_index_witness(
         &primary_key,
         &reader,
     )?;
-    if expected.as_ref().is_some_and(|expected| {
-        expected.store_path() == domain.store_path() && expected.raw_key() == raw_key
+    if expected.iter().any(|expected| {
+        expected.store_path() == domain.store_path()
+            && expected.raw_key() == raw_key
+            && expected.value() == raw_value
     }) {
         return Ok(None);
     }
@@ -490,10 +500,10 @@ fn inspect_index_witness(
         Some(domain.schema_index_id().get()),
         None,
         "exact_row_derived_witness",
-        if expected.is_some() {
-            "different_key"
-        } else {
+        if expected.is_empty() {
             "conditional_membership_absent"
+        } else {
+            "different_key"
         },
     )
     .map(Some)
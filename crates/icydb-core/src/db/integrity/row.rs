@@ -630,7 +630,8 @@ fn inspect_row_atom<C: CanisterKind>(
                     | AcceptedRowConstraintEvaluationError::ValueDepthExceeded
                     | AcceptedRowConstraintEvaluationError::ValueNodeBudgetExceeded
                     | AcceptedRowConstraintEvaluationError::OperationBudgetExceeded
-                    | AcceptedRowConstraintEvaluationError::PathBudgetExceeded,
+                    | AcceptedRowConstraintEvaluationError::PathBudgetExceeded
+                    | AcceptedRowConstraintEvaluationError::GeneratedField(_),
                 ) => Err(InternalError::accepted_row_constraint_program_corrupt()),
             }
         }
@@ -647,36 +648,41 @@ fn inspect_row_atom<C: CanisterKind>(
             }
             let ordinal =
                 usize::try_from(atom.ordinal).map_err(|_| InternalError::store_invariant())?;
-            let Some(witness) = plan.index_inspection().project(
+            let witnesses = plan.index_inspection().project(
                 ordinal,
                 key.entity_tag(),
                 &key.primary_key_value(),
                 reader,
-            )?
-            else {
-                return Ok(RowAtomOutcome::Clean);
-            };
-            let index_store = db.store_handle(witness.store_path())?;
-            let actual = index_store.with_index(|store| store.get(witness.raw_key()));
-            match actual {
-                None => Ok(RowAtomOutcome::Finding(index_finding(
-                    plan,
-                    raw_key,
-                    key,
-                    witness.schema_index_id().get(),
-                    IntegrityFindingKind::MissingIndexEntry,
-                    "missing",
-                )?)),
-                Some(value) if value == IndexEntryValue::presence() => Ok(RowAtomOutcome::Clean),
-                Some(_) => Ok(RowAtomOutcome::Finding(index_finding(
-                    plan,
-                    raw_key,
-                    key,
-                    witness.schema_index_id().get(),
-                    IntegrityFindingKind::DivergentIndexEntry,
-                    "divergent",
-                )?)),
+            )?;
+            for witness in witnesses {
+                let index_store = db.store_handle(witness.store_path())?;
+                let actual = index_store.with_index(|store| store.get(witness.raw_key()));
+                match actual {
+                    None => {
+                        return Ok(RowAtomOutcome::Finding(index_finding(
+                            plan,
+                            raw_key,
+                            key,
+                            witness.schema_index_id().get(),
+                            IntegrityFindingKind::MissingIndexEntry,
+                            "missing",
+                        )?));
+                    }
+                    Some(value) if value == *witness.value() => {}
+                    Some(_) => {
+                        return Ok(RowAtomOutcome::Finding(index_finding(
+                            plan,
+                            raw_key,
+                            key,
+                            witness.schema_index_id().get(),
+                            IntegrityFindingKind::DivergentIndexEntry,
+                            "divergent",
+                        )?));
+                    }
+                }
             }
+
+            Ok(RowAtomOutcome::Clean)
         }
         IntegrityVerifierFamily::Relation => {
             let (Some(key), Some(Ok(reader))) = (decoded_key.as_ref().ok(), reader.as_mut()) else {
@@ -10,7 +10,7 @@ use crate::{
         commit::{CommitSchemaFingerprint, MAX_COMMIT_BYTES},
         data::{DecodedDataStoreKey, RawDataStoreKey},
         integrity::DatabaseIncarnationId,
-        key_taxonomy::{PrimaryKeyComponent, PrimaryKeyValue},
+        key_taxonomy::{IndexEntryValue, PrimaryKeyComponent, PrimaryKeyValue},
         schema::{
             AcceptedSchemaRevision, CandidateSchemaRevision, ConstraintId, ConstraintValidationJob,
             FieldId, IdentityRangeAdvance, IdentityStateOwner, MAX_CONSTRAINT_VALIDATION_JOB_BYTES,
@@ -60,6 +60,7 @@ const JOURNAL_RECORD_SCHEMA_MIGRATION_ROW_PUT: u8 = 9;
 const JOURNAL_RECORD_SCHEMA_MIGRATION_INDEX_PUT: u8 = 10;
 const JOURNAL_RECORD_ACCEPTED_SCHEMA_INDEX_DELETE: u8 = 11;
 const JOURNAL_RECORD_ACCEPTED_SCHEMA_INDEX_PUT: u8 = 12;
+const JOURNAL_RECORD_ACCEPTED_SCHEMA_INDEX_PUT_WITH_VALUES: u8 = 13;
 pub(in crate::db) const MAX_ACCEPTED_SCHEMA_INDEX_KEYS_PER_RECORD: usize = 64;
 
 pub(in crate::db) type JournalBatchId = [u8; JOURNAL_BATCH_ID_BYTES];
@@ -175,12 +176,15 @@ pub(in crate::db) enum JournalRecord {
         keys: Vec<RawIndexStoreKey>,
     },
     /// One bounded chunk inserted into the user-index domain paired with the
-    /// leading accepted-schema publication in this batch.
+    /// leading accepted-schema publication in this batch. `values` is empty
+    /// when every entry is presence-only; otherwise it pairs one entry value
+    /// with each key so covering `INCLUDE` payloads survive replay.
     AcceptedSchemaIndexPut {
         store_path: String,
         entity_tag: EntityTag,
         accepted_after_fingerprint: CommitSchemaFingerprint,
         keys: Vec<RawIndexStoreKey>,
+        values: Vec<IndexEntryValue>,
     },
     /// Schema-owned validation-job replacement for one live activation.
     ConstraintValidationJobPut {
@@ -388,12 +392,14 @@ impl JournalRecord {
         entity_tag: EntityTag,
         accepted_after_fingerprint: CommitSchemaFingerprint,
         keys: Vec<RawIndexStoreKey>,
+        values: Vec<IndexEntryValue>,
     ) -> Result<Self, InternalError> {
         let record = Self::AcceptedSchemaIndexPut {
             store_path: store_path.into(),
             entity_tag,
             accepted_after_fingerprint,
             keys,
+            values,
         };
         validate_journal_record(&record)?;
         Ok(record)
@@ -917,8 +923,13 @@ fn write_journal_record(out: &mut Vec<u8>, record: &JournalRecord) -> Result<(),
             entity_tag,
             accepted_after_fingerprint,
             keys,
+            values,
         } => {
-            out.push(JOURNAL_RECORD_ACCEPTED_SCHEMA_INDEX_PUT);
+            out.push(if values.is_empty() {
+                JOURNAL_RECORD_ACCEPTED_SCHEMA_INDEX_PUT
+            } else {
+                JOURNAL_RECORD_ACCEPTED_SCHEMA_INDEX_PUT_WITH_VALUES
+            });
             write_len_prefixed_bytes(
                 out,
                 store_path.as_bytes(),
@@ -927,6 +938,13 @@ fn write_journal_record(out: &mut Vec<u8>, record: &JournalRecord) -> Result<(),
             out.extend_from_slice(&entity_tag.value().to_le_bytes());
             out.extend_from_slice(accepted_after_fingerprint);
             write_index_key_chunk(out, keys)?;
+            for value in values {
+                write_len_prefixed_bytes(
+                    out,
+                    value.as_bytes(),
+                    "journal accepted schema index value",
+                )?;
+            }
         }
         JournalRecord::ConstraintValidationJobPut {
             store_path,
@@ -1113,6 +1131,25 @@ fn read_journal_record(bytes: &[u8], cursor: &mut usize) -> Result<JournalRecord
                 entity_tag,
                 accepted_after_fingerprint,
                 keys,
+                Vec::new(),
+            )
+        }
+        JOURNAL_RECORD_ACCEPTED_SCHEMA_INDEX_PUT_WITH_VALUES => {
+            let (store_path, entity_tag, accepted_after_fingerprint, keys) =
+                read_accepted_schema_index_chunk(bytes, cursor)?;
+            let values = keys
+                .iter()
+                .map(|_| {
+                    read_len_prefixed_bytes(bytes, cursor, "journal accepted schema index value")
+                        .map(|value| IndexEntryValue::from_persisted_bytes(value.to_vec()))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            JournalRecord::accepted_schema_index_put(
+                store_path,
+                entity_tag,
+                accepted_after_fingerprint,
+                keys,
+                values,
             )
         }
         JOURNAL_RECORD_CONSTRAINT_VALIDATION_JOB_PUT => {
@@ -1340,10 +1377,16 @@ pub(in crate::db) fn journal_record_payload_len(record: &JournalRecord) -> usize
             .saturating_add(size_of::<u32>() + schema_root_bytes.len()),
         JournalRecord::AcceptedSchemaIndexDelete {
             store_path, keys, ..
-        }
-        | JournalRecord::AcceptedSchemaIndexPut {
-            store_path, keys, ..
         } => accepted_schema_index_chunk_payload_len(store_path.len(), keys),
+        JournalRecord::AcceptedSchemaIndexPut {
+            store_path,
+            keys,
+            values,
+            ..
+        } => values.iter().fold(
+            accepted_schema_index_chunk_payload_len(store_path.len(), keys),
+            |bytes, value| bytes.saturating_add(size_of::<u32>() + value.len()),
+        ),
         JournalRecord::ConstraintValidationJobPut {
             store_path,
             job_bytes,
@@ -1601,6 +1644,7 @@ fn validate_accepted_schema_index_chunks(batch: &JournalBatch) -> Result<(), Int
                 entity_tag,
                 accepted_after_fingerprint,
                 keys,
+                ..
             } => (
                 store_path,
                 *entity_tag,
@@ -1865,6 +1909,13 @@ fn validate_journal_record(record: &JournalRecord) -> Result<(), InternalError>
                 }
                 previous = Some(key);
             }
+            if let JournalRecord::AcceptedSchemaIndexPut { values, .. } = record
+                && !values.is_empty()
+                && (values.len() != keys.len()
+                    || values.iter().any(|value| value.validate().is_err()))
+            {
+                return Err(journal_batch_corruption());
+            }
         }
         JournalRecord::ConstraintValidationJobPut {
             store_path,
@@ -17,7 +17,7 @@ use crate::{
         data::{DecodedDataStoreKey, RawDataStoreKey},
         index::{IndexId, IndexKey, IndexKeyKind, RawIndexStoreKey},
         integrity::DatabaseIncarnationId,
-        key_taxonomy::{PrimaryKeyComponent, PrimaryKeyValue},
+        key_taxonomy::{IndexEntryValue, PrimaryKeyComponent, PrimaryKeyValue},
         positioned_overlay::{OnlineOverlayDecision, classify_journal_overlay},
         schema::{
             AcceptedCheckExprV1, AcceptedSchemaFingerprint, AcceptedSchemaRevision,
@@ -226,6 +226,7 @@ fn every_journal_record_family_has_an_explicit_online_overlay_decision() {
                 EntityTag::new(1),
                 [0x51; 16],
                 vec![index_key.clone()],
+                Vec::new(),
             )
             .expect("accepted-schema index insertion should build"),
             OnlineOverlayDecision::IndexPositive,
@@ -448,6 +449,7 @@ fn journal_batch_codec_round_trips_bounded_accepted_schema_index_chunks() {
                 EntityTag::new(1),
                 [0x51; 16],
                 keys.to_vec(),
+                Vec::new(),
             )
             .expect("accepted schema index insertion should build"),
         );
@@ -463,6 +465,52 @@ fn journal_batch_codec_round_trips_bounded_accepted_schema_index_chunks() {
     assert_eq!(batch.records().len(), 5);
 }
 
+#[test]
+fn accepted_schema_index_put_round_trips_included_entry_values() {
+    let keys = vec![
+        accepted_schema_index_key(1, 1),
+        accepted_schema_index_key(2, 1),
+    ];
+    let values = vec![
+        IndexEntryValue::presence_with_included(&[vec![0x12, b'a']]).expect("encode included"),
+        IndexEntryValue::presence_with_included(&[vec![0x0F]]).expect("encode included"),
+    ];
+    let batch = JournalBatch::new(
+        [0x38; 16],
+        [0x48; 16],
+        JournalSequence::new(1),
+        vec![
+            accepted_schema_publish_record(),
+            JournalRecord::accepted_schema_index_put(
+                "test::Store",
+                EntityTag::new(1),
+                [0x51; 16],
+                keys.clone(),
+                values,
+            )
+            .expect("accepted schema index insertion with values should build"),
+        ],
+    )
+    .expect("accepted schema index batch should build");
+
+    let encoded = encode_journal_batch(&batch).expect("accepted schema index batch should encode");
+    assert_eq!(
+        decode_journal_batch(&encoded).expect("accepted schema index batch should decode"),
+        batch,
+    );
+    assert!(
+        JournalRecord::accepted_schema_index_put(
+            "test::Store",
+            EntityTag::new(1),
+            [0x51; 16],
+            keys,
+            vec![IndexEntryValue::presence()],
+        )
+        .is_err(),
+        "entry values must pair one-to-one with keys",
+    );
+}
+
 #[test]
 fn accepted_schema_index_replacement_and_validation_job_are_distinct_legal_shapes() {
     let job = validation_job();
@@ -489,6 +537,7 @@ fn accepted_schema_index_replacement_and_validation_job_are_distinct_legal_shape
                 EntityTag::new(1),
                 [0x51; 16],
                 vec![accepted_schema_index_key(1, 1)],
+                Vec::new(),
             )
             .expect("accepted schema index insertion should build"),
             JournalRecord::constraint_validation_job_put("test::Store", &job)
@@ -508,6 +557,7 @@ fn accepted_schema_index_chunks_reject_unbound_oversized_and_noncanonical_sets()
         EntityTag::new(1),
         [0x51; 16],
         vec![key.clone()],
+        Vec::new(),
     )
     .expect("structural index chunk should build");
     assert!(
@@ -527,6 +577,7 @@ fn accepted_schema_index_chunks_reject_unbound_oversized_and_noncanonical_sets()
             EntityTag::new(1),
             [0x51; 16],
             vec![key; MAX_ACCEPTED_SCHEMA_INDEX_KEYS_PER_RECORD + 1],
+            Vec::new(),
         )
         .is_err(),
         "one persisted chunk must reject max-plus-one keys before encoding",
@@ -559,6 +610,7 @@ fn accepted_schema_index_chunks_reject_store_path_drift_from_publication() {
                 EntityTag::new(1),
                 [0x51; 16],
                 vec![accepted_schema_index_key(1, 1)],
+                Vec::new(),
             )
             .expect("accepted schema index insertion should build"),
         ],
@@ -577,6 +629,7 @@ fn accepted_schema_index_chunk_decode_rejects_empty_and_max_plus_one_before_keys
         EntityTag::new(1),
         [0x51; 16],
         vec![accepted_schema_index_key(1, 1)],
+        Vec::new(),
     )
     .expect("bounded accepted schema index insertion should build");
     let schema_bytes = journal_record_payload_len(&schema);
@@ -1042,7 +1042,8 @@ impl PartialOrd for RawIndexStoreKey {
 }
 
 /// Secondary-index value. Primary-key membership belongs to the key, so this
-/// value carries only a storage-owned presence/existence witness.
+/// value carries a storage-owned presence/existence witness plus any
+/// `INCLUDE` payload components.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub(crate) struct IndexEntryValue {
     bytes: Vec<u8>,
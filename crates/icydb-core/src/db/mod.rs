@@ -48,6 +48,7 @@ pub(in crate::db) mod sql_shared;
 pub(in crate::db) mod test_support;
 #[cfg(test)]
 mod tests;
+pub(in crate::db) mod text_search;
 
 use crate::{
     db::{
@@ -140,19 +141,19 @@ pub use query::explain::{
 pub use query::plan::validate::PlanError;
 pub use query::{
     builder::{
-        AggregateExpr, FieldRef, TextProjectionExpr, ValueProjectionExpr, avg, contains, count,
-        count_by, ends_with, exists, first, last, left, length, lower, ltrim, max, max_by, min,
-        min_by, position, replace, right, rtrim, starts_with, substring, substring_with_length,
-        sum, trim, upper,
+        AggregateExpr, FieldRef, MapKeyRef, TextProjectionExpr, ValueProjectionExpr, avg, contains,
+        count, count_by, ends_with, exists, first, last, left, length, lower, ltrim, max, max_by,
+        min, min_by, position, replace, right, rtrim, starts_with, substring,
+        substring_with_length, sum, trim, upper,
     },
     explain::{
         ExplainAccessCandidate, ExplainAccessDecision, ExplainAccessDecisionKind,
         ExplainEligibleAlternative, ExplainPlan, ExplainRejectedIndex, ExplainResidualSummary,
         ExplainSelectedAccess,
     },
-    expr::{FilterExpr, FilterValue, OrderExpr, OrderTerm, asc, desc, field},
+    expr::{FilterExpr, FilterValue, GroupExpr, OrderExpr, OrderTerm, asc, desc, field},
     intent::{IntentError, QueryError, QueryExecutionError},
-    plan::{DeleteSpec, LoadSpec, OrderDirection, QueryMode},
+    plan::{DeleteSpec, LoadSpec, NullsOrder, OrderDirection, QueryMode},
     read_intent::ReadIntentKind,
     trace::TraceReuseEvent,
 };
@@ -211,20 +212,20 @@ pub use session::{
     MAX_TYPED_EXACT_KEY_BATCH_INPUT_BYTES, MAX_TYPED_EXACT_KEY_BATCH_ITEMS,
     MAX_TYPED_EXACT_KEY_BATCH_RESULT_BYTES, MAX_TYPED_EXACT_KEY_BATCH_STORED_BYTES,
 };
-#[cfg(all(feature = "sql", feature = "diagnostics"))]
-pub use session::{
-    SqlCompileAttribution, SqlDistinctProjectionAttribution, SqlExecutionAttribution,
-    SqlHybridCoveringAttribution, SqlOutputBlobAttribution, SqlPureCoveringAttribution,
-    SqlQueryCacheAttribution, SqlQueryExecutionAttribution,
-};
 #[cfg(feature = "sql")]
 pub use session::{
-    SqlConstraintValidationPage, SqlConstraintValidationRevisionStatus,
+    PreparedSqlQuery, SqlConstraintValidationPage, SqlConstraintValidationRevisionStatus,
     SqlConstraintValidationState, SqlDdlExecutionStatus, SqlDdlMutationKind,
     SqlDdlPreparationReport, SqlIntegrityError, SqlStatementDispatch, SqlStatementResult,
     SqlStatementShellSurface, SqlStatementSurface, sql_statement_dispatch,
     sql_statement_entity_name, sql_statement_shell_surface, sql_statement_surface,
 };
+#[cfg(all(feature = "sql", feature = "diagnostics"))]
+pub use session::{
+    SqlCompileAttribution, SqlDistinctProjectionAttribution, SqlExecutionAttribution,
+    SqlHybridCoveringAttribution, SqlOutputBlobAttribution, SqlPureCoveringAttribution,
+    SqlQueryCacheAttribution, SqlQueryExecutionAttribution,
+};
 #[cfg(feature = "sql")]
 pub use sql::identifier::{
     identifier_last_segment, normalize_identifier_to_scope, split_qualified_identifier,
@@ -707,6 +707,15 @@ const fn function_tag(function: Function) -> u8 {
         Function::Trim => 36,
         Function::Trunc => 37,
         Function::Upper => 38,
+        Function::Cast => 39,
+        Function::Age => 40,
+        Function::DateTrunc => 41,
+        Function::Extract => 42,
+        Function::Now => 43,
+        Function::Like => 44,
+        Function::RegexpMatches => 45,
+        Function::Match => 46,
+        Function::HasKey => 47,
     }
 }
 
@@ -751,6 +760,15 @@ const fn function_from_tag(tag: u8) -> Result<Function, MutationJobError> {
         36 => Function::Trim,
         37 => Function::Trunc,
         38 => Function::Upper,
+        39 => Function::Cast,
+        40 => Function::Age,
+        41 => Function::DateTrunc,
+        42 => Function::Extract,
+        43 => Function::Now,
+        44 => Function::Like,
+        45 => Function::RegexpMatches,
+        46 => Function::Match,
+        47 => Function::HasKey,
         _ => return Err(MutationJobError::CorruptProgressStore),
     };
     Ok(function)
@@ -302,7 +302,8 @@ fn classify_index_capability_with_compare(
         | ExecutablePredicate::IsEmpty { .. }
         | ExecutablePredicate::IsNotEmpty { .. }
         | ExecutablePredicate::TextContains { .. }
-        | ExecutablePredicate::TextContainsCi { .. } => IndexPredicateCapability::RequiresFullScan,
+        | ExecutablePredicate::TextContainsCi { .. }
+        | ExecutablePredicate::TextMatch { .. } => IndexPredicateCapability::RequiresFullScan,
     }
 }
 
@@ -352,7 +353,8 @@ fn predicate_is_scalar_safe(schema_info: &SchemaInfo, predicate: &ExecutablePred
         ExecutablePredicate::IsNull { field_slot }
         | ExecutablePredicate::IsNotNull { field_slot }
         | ExecutablePredicate::IsEmpty { field_slot }
-        | ExecutablePredicate::IsNotEmpty { field_slot } => {
+        | ExecutablePredicate::IsNotEmpty { field_slot }
+        | ExecutablePredicate::TextMatch { field_slot, .. } => {
             scalar_field_slot_supported(schema_info, *field_slot)
         }
         ExecutablePredicate::TextContains { field_slot, value }
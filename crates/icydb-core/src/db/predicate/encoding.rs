@@ -25,6 +25,7 @@ const SORT_PRED_IS_NOT_EMPTY: u8 = 0x09;
 const SORT_PRED_IS_NOT_NULL: u8 = 0x0A;
 const SORT_PRED_TEXT_CONTAINS: u8 = 0x0C;
 const SORT_PRED_TEXT_CONTAINS_CI: u8 = 0x0D;
+const SORT_PRED_TEXT_MATCH: u8 = 0x0E;
 
 ///
 /// Encode a predicate into deterministic sort-key bytes.
@@ -130,6 +131,11 @@ fn encode_predicate_sort_key_into(
             push_str_u64(out, field);
             push_value_sort_key_framed(out, value);
         }
+        Predicate::TextMatch { field, value } => {
+            out.push(SORT_PRED_TEXT_MATCH);
+            push_str_u64(out, field);
+            push_value_sort_key_framed(out, value);
+        }
     }
 }
 
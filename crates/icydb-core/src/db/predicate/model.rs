@@ -26,6 +26,7 @@ pub enum Predicate {
     IsNotEmpty { field: String },
     TextContains { field: String, value: Value },
     TextContainsCi { field: String, value: Value },
+    TextMatch { field: String, value: Value },
 }
 
 impl Predicate {
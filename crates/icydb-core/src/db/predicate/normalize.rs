@@ -77,6 +77,10 @@ pub(in crate::db) fn normalize(predicate: &Predicate) -> Predicate {
             field: field.clone(),
             value: value.clone(),
         },
+        Predicate::TextMatch { field, value } => Predicate::TextMatch {
+            field: field.clone(),
+            value: value.clone(),
+        },
     }
 }
 
@@ -104,6 +108,7 @@ fn normalize_owned(predicate: Predicate) -> Predicate {
         Predicate::IsNotEmpty { field } => Predicate::IsNotEmpty { field },
         Predicate::TextContains { field, value } => Predicate::TextContains { field, value },
         Predicate::TextContainsCi { field, value } => Predicate::TextContainsCi { field, value },
+        Predicate::TextMatch { field, value } => Predicate::TextMatch { field, value },
     }
 }
 
@@ -171,6 +176,10 @@ pub(in crate::db) fn normalize_enum_literals(
             field: field.clone(),
             value: value.clone(),
         }),
+        Predicate::TextMatch { field, value } => Ok(Predicate::TextMatch {
+            field: field.clone(),
+            value: value.clone(),
+        }),
     }
 }
 
@@ -772,7 +781,9 @@ const fn predicate_eval_cost_rank(predicate: &Predicate) -> u8 {
         | Predicate::IsEmpty { .. }
         | Predicate::IsNotEmpty { .. } => 1,
         Predicate::Not(_) => 4,
-        Predicate::TextContains { .. } | Predicate::TextContainsCi { .. } => 3,
+        Predicate::TextContains { .. }
+        | Predicate::TextContainsCi { .. }
+        | Predicate::TextMatch { .. } => 3,
         Predicate::And(_) | Predicate::Or(_) => 5,
     }
 }
@@ -6,7 +6,7 @@ use crate::db::{
             parse_compare_operator,
         },
     },
-    sql_shared::{SqlParseError, SqlTokenCursor, TokenKind},
+    sql_shared::{SqlParseError, SqlTokenCursor},
 };
 
 // Parse the plain-field compare lane once all reduced SQL special forms have
@@ -16,7 +16,7 @@ pub(in crate::db::predicate::parser::expression::atom::field::plain) fn parse_pl
     field: String,
 ) -> Result<Predicate, SqlParseError> {
     let op = parse_compare_operator(cursor)?;
-    if matches!(cursor.peek_kind(), Some(TokenKind::Identifier(_))) {
+    if cursor.peek_identifier() {
         let right_field = cursor.expect_identifier()?;
         return Ok(predicate_compare_fields(field, op, right_field));
     }
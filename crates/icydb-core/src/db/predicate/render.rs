@@ -86,7 +86,8 @@ fn render_sql_predicate(predicate: &Predicate) -> Option<String> {
         | Predicate::IsEmpty { .. }
         | Predicate::IsNotEmpty { .. }
         | Predicate::TextContains { .. }
-        | Predicate::TextContainsCi { .. } => None,
+        | Predicate::TextContainsCi { .. }
+        | Predicate::TextMatch { .. } => None,
     }
 }
 
@@ -4,7 +4,10 @@
 //! Boundary: produced once at predicate compile time and consumed by runtime and index execution.
 
 use crate::{
-    db::predicate::{coercion::CoercionSpec, model::CompareOp},
+    db::{
+        predicate::{coercion::CoercionSpec, model::CompareOp},
+        text_search::TextMatchQuery,
+    },
     value::Value,
 };
 
@@ -139,4 +142,8 @@ pub(in crate::db) enum ExecutablePredicate {
         field_slot: Option<usize>,
         value: Value,
     },
+    TextMatch {
+        field_slot: Option<usize>,
+        query: TextMatchQuery,
+    },
 }
@@ -66,6 +66,10 @@ where
             field: map_field(field),
             value,
         },
+        Predicate::TextMatch { field, value } => Predicate::TextMatch {
+            field: map_field(field),
+            value,
+        },
     }
 }
 
@@ -13,6 +13,7 @@ use crate::db::{
         PredicateCapabilityContext, ScalarPredicateCapability, classify_predicate_capabilities,
     },
     schema::SchemaInfo,
+    text_search::TextMatchQuery,
 };
 use crate::{
     db::schema::LeafCodec,
@@ -207,6 +208,19 @@ fn compile_predicate_program_with_resolver(
             field_slot: resolve_field(field),
             value: value.clone(),
         },
+        // Validation rejects malformed queries before planning; anything that
+        // still fails to parse here matches nothing.
+        Predicate::TextMatch { field, value } => match value {
+            Value::Text(query) => {
+                TextMatchQuery::parse(query).map_or(ExecutablePredicate::False, |query| {
+                    ExecutablePredicate::TextMatch {
+                        field_slot: resolve_field(field),
+                        query,
+                    }
+                })
+            }
+            _ => ExecutablePredicate::False,
+        },
     }
 }
 
@@ -249,7 +263,8 @@ fn mark_executable_predicate_referenced_slots(
         | ExecutablePredicate::IsEmpty { field_slot }
         | ExecutablePredicate::IsNotEmpty { field_slot }
         | ExecutablePredicate::TextContains { field_slot, .. }
-        | ExecutablePredicate::TextContainsCi { field_slot, .. } => {
+        | ExecutablePredicate::TextContainsCi { field_slot, .. }
+        | ExecutablePredicate::TextMatch { field_slot, .. } => {
             mark_predicate_slot(*field_slot, required_slots);
         }
     }
@@ -407,6 +422,11 @@ where
                 actual.text_contains(value, TextMode::Ci).unwrap_or(false)
             })
         }
+        ExecutablePredicate::TextMatch { field_slot, query } => on_present_slot_cow(
+            *field_slot,
+            read_slot,
+            |actual| matches!(actual, Value::Text(text) if query.matches_text(text)),
+        ),
     }
 }
 
@@ -470,6 +490,12 @@ fn eval_scalar_executable_predicate(
             };
             eval_optional_scalar_text_contains(*field_slot, needle, TextMode::Ci, slots)
         }
+        ExecutablePredicate::TextMatch { field_slot, query } => {
+            let Some(field_slot) = field_slot else {
+                return Ok(false);
+            };
+            eval_scalar_text_match(*field_slot, query, slots)
+        }
     }
 }
 
@@ -566,6 +592,17 @@ fn eval_scalar_text_contains(
     })
 }
 
+fn eval_scalar_text_match(
+    field_slot: usize,
+    query: &TextMatchQuery,
+    slots: &dyn CanonicalSlotReader,
+) -> Result<bool, crate::error::InternalError> {
+    eval_required_scalar_slot(field_slot, slots, |actual| match actual {
+        ScalarSlotValueRef::Value(ScalarValueRef::Text(actual)) => query.matches_text(actual),
+        ScalarSlotValueRef::Null | ScalarSlotValueRef::Value(_) => false,
+    })
+}
+
 fn eval_optional_scalar_text_contains(
     field_slot: Option<usize>,
     needle: &str,
@@ -634,6 +671,18 @@ fn eval_with_structural_slots(
         ExecutablePredicate::TextContainsCi { field_slot, value } => {
             eval_text_contains_with_structural_slots(*field_slot, value, TextMode::Ci, slots)
         }
+        ExecutablePredicate::TextMatch { field_slot, query } => eval_structural_field_slot(
+            *field_slot,
+            slots,
+            |field_slot, slots| eval_scalar_text_match(field_slot, query, slots),
+            |field_slot, slots| {
+                eval_required_value_slot(
+                    field_slot,
+                    slots,
+                    |actual| matches!(actual, Value::Text(text) if query.matches_text(text)),
+                )
+            },
+        ),
     }
 }
 
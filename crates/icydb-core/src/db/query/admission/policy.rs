@@ -168,6 +168,32 @@ impl QueryAdmissionPolicy {
         self
     }
 
+    /// Build the policy for one SQL semi-join source query.
+    ///
+    /// The source must be index-backed and may not materialize a sort. Its
+    /// row count is not capped by `LIMIT`; instead every returned key becomes
+    /// one primary-key input term of the outer query, so the caller enforces
+    /// `max_primary_key_input_terms()` against the rows the source produces.
+    /// Relation-path target lookups reuse the same budget for the distinct
+    /// keys one result page references.
+    #[must_use]
+    #[cfg(feature = "sql")]
+    pub(in crate::db) const fn sql_semi_join_source() -> Self {
+        Self {
+            lane: QueryAdmissionLane::PublicRead,
+            limit_required: false,
+            max_returned_rows: None,
+            max_primary_key_input_terms: Some(non_zero_default(
+                DEFAULT_BOUNDED_READ_MAX_PRIMARY_KEY_INPUT_TERMS,
+            )),
+            max_primary_key_input_bytes: Some(non_zero_default(
+                DEFAULT_BOUNDED_READ_MAX_PRIMARY_KEY_INPUT_BYTES,
+            )),
+            access: AccessAdmissionPolicy::BOUNDED_PUBLIC_READ,
+            grouped: GroupedAdmissionPolicy::disabled(),
+        }
+    }
+
     /// Build an EXPLAIN-only policy that cannot execute rows.
     #[must_use]
     #[cfg(feature = "sql")]
@@ -189,6 +215,13 @@ impl QueryAdmissionPolicy {
         self.lane
     }
 
+    /// Return the maximum primary-key input terms this policy admits.
+    #[must_use]
+    #[cfg(feature = "sql")]
+    pub(in crate::db) const fn max_primary_key_input_terms(&self) -> Option<NonZeroU32> {
+        self.max_primary_key_input_terms
+    }
+
     /// Return whether the surface requires caller-visible LIMIT.
     #[must_use]
     pub(in crate::db) const fn require_limit(&self) -> bool {
@@ -413,4 +446,16 @@ mod tests {
         assert!(policy.allow_full_scan());
         assert!(policy.allow_materialized_sort());
     }
+
+    #[cfg(feature = "sql")]
+    #[test]
+    fn sql_semi_join_source_requires_index_without_limit() {
+        let policy = QueryAdmissionPolicy::sql_semi_join_source();
+
+        assert!(!policy.require_limit());
+        assert!(policy.require_index());
+        assert!(!policy.allow_full_scan());
+        assert!(!policy.allow_materialized_sort());
+        assert!(policy.max_primary_key_input_terms().is_some());
+    }
 }
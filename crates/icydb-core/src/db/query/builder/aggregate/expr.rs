@@ -1,4 +1,4 @@
-use crate::db::query::plan::{AggregateKind, AggregateShape, expr::Expr};
+use crate::db::query::plan::{AggregateArguments, AggregateKind, AggregateShape, expr::Expr};
 
 ///
 /// AggregateExpr
@@ -55,6 +55,13 @@ impl AggregateExpr {
         self
     }
 
+    /// Attach the non-input arguments of one retained-value aggregate.
+    #[must_use]
+    pub(in crate::db) fn with_arguments(mut self, arguments: AggregateArguments) -> Self {
+        self.shape = self.shape.with_arguments(arguments);
+        self
+    }
+
     /// Enable DISTINCT modifier for this aggregate expression.
     #[must_use]
     pub const fn distinct(mut self) -> Self {
@@ -80,6 +87,12 @@ impl AggregateExpr {
         self.shape.filter_expr()
     }
 
+    /// Borrow the retained-value aggregate arguments, if any.
+    #[must_use]
+    pub(in crate::db) fn arguments(&self) -> Option<&AggregateArguments> {
+        self.shape.arguments()
+    }
+
     /// Borrow the optional target field when this aggregate input stays a plain field leaf.
     #[must_use]
     pub(in crate::db) fn target_field(&self) -> Option<&str> {
@@ -106,8 +119,16 @@ impl AggregateExpr {
             AggregateKind::Max => max(),
             AggregateKind::First => first(),
             AggregateKind::Last => last(),
-            AggregateKind::Sum | AggregateKind::Avg => unreachable!(
-                "AggregateExpr::terminal_for_kind does not support SUM/AVG field-target kinds"
+            AggregateKind::Sum
+            | AggregateKind::Avg
+            | AggregateKind::VarPop
+            | AggregateKind::VarSamp
+            | AggregateKind::StddevPop
+            | AggregateKind::StddevSamp
+            | AggregateKind::PercentileDisc
+            | AggregateKind::StringAgg
+            | AggregateKind::ArrayAgg => unreachable!(
+                "AggregateExpr::terminal_for_kind does not support input-bearing aggregate kinds"
             ),
         }
     }
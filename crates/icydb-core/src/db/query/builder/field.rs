@@ -219,6 +219,90 @@ impl FieldRef {
     pub fn not_between_fields(self, lower: impl AsRef<str>, upper: impl AsRef<str>) -> FilterExpr {
         FilterExpr::or(vec![self.lt_field(lower), self.gt_field(upper)])
     }
+
+    // ------------------------------------------------------------------
+    // Map key predicates
+    // ------------------------------------------------------------------
+
+    /// Text-keyed map field contains `key`.
+    #[must_use]
+    pub fn has_key(self, key: impl Into<String>) -> FilterExpr {
+        FilterExpr::has_key(self.0, key)
+    }
+
+    /// Address the value stored under one fixed key of a text-keyed map field.
+    #[must_use]
+    pub fn key(self, key: impl Into<String>) -> MapKeyRef {
+        MapKeyRef {
+            field: self.0,
+            key: key.into(),
+        }
+    }
+}
+
+///
+/// MapKeyRef
+///
+/// Reference to one fixed key of a text-keyed map field, the typed form of
+/// SQL `field['key']`.
+/// Comparisons lower onto residual field-path predicates, so rows that do not
+/// carry the key never match.
+///
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct MapKeyRef {
+    field: &'static str,
+    key: String,
+}
+
+impl MapKeyRef {
+    /// Return the underlying map field name.
+    #[must_use]
+    pub const fn field(&self) -> &'static str {
+        self.field
+    }
+
+    /// Return the addressed map key.
+    #[must_use]
+    pub const fn key(&self) -> &str {
+        self.key.as_str()
+    }
+
+    /// Strict equality comparison against the value stored under the key.
+    #[must_use]
+    pub fn eq(self, value: impl Into<FilterValue>) -> FilterExpr {
+        FilterExpr::map_key_eq(self.field, self.key, value)
+    }
+
+    /// Strict inequality comparison against the value stored under the key.
+    #[must_use]
+    pub fn ne(self, value: impl Into<FilterValue>) -> FilterExpr {
+        FilterExpr::map_key_ne(self.field, self.key, value)
+    }
+
+    /// Less-than comparison against the value stored under the key.
+    #[must_use]
+    pub fn lt(self, value: impl Into<FilterValue>) -> FilterExpr {
+        FilterExpr::map_key_lt(self.field, self.key, value)
+    }
+
+    /// Less-than-or-equal comparison against the value stored under the key.
+    #[must_use]
+    pub fn lte(self, value: impl Into<FilterValue>) -> FilterExpr {
+        FilterExpr::map_key_lte(self.field, self.key, value)
+    }
+
+    /// Greater-than comparison against the value stored under the key.
+    #[must_use]
+    pub fn gt(self, value: impl Into<FilterValue>) -> FilterExpr {
+        FilterExpr::map_key_gt(self.field, self.key, value)
+    }
+
+    /// Greater-than-or-equal comparison against the value stored under the key.
+    #[must_use]
+    pub fn gte(self, value: impl Into<FilterValue>) -> FilterExpr {
+        FilterExpr::map_key_gte(self.field, self.key, value)
+    }
 }
 
 // ----------------------------------------------------------------------
@@ -284,4 +368,16 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    fn field_ref_map_key_builders_address_one_fixed_key() {
+        assert_eq!(
+            FieldRef::new("settings").key("theme").eq("dark"),
+            FilterExpr::map_key_eq("settings", "theme", "dark"),
+        );
+        assert_eq!(
+            FieldRef::new("settings").has_key("lang"),
+            FilterExpr::has_key("settings", "lang"),
+        );
+    }
 }
@@ -12,7 +12,7 @@ pub(in crate::db) mod text_projection;
 pub use aggregate::{
     AggregateExpr, avg, count, count_by, exists, first, last, max, max_by, min, min_by, sum,
 };
-pub use field::FieldRef;
+pub use field::{FieldRef, MapKeyRef};
 pub(in crate::db) use numeric_projection::{NumericProjectionExpr, RoundProjectionExpr};
 pub(in crate::db) use scalar_projection::ScalarProjectionPlan;
 pub use scalar_projection::ValueProjectionExpr;
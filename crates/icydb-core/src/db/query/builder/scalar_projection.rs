@@ -9,7 +9,7 @@
 use crate::{
     db::{
         QueryError,
-        query::plan::expr::{Expr, FieldPath},
+        query::plan::expr::{Expr, FieldPath, Function},
     },
     value::Value,
 };
@@ -78,6 +78,26 @@ fn render_scalar_projection_expr_plan_label_with_parent(
         Expr::FieldPath(path) => render_field_path_plan_label(path),
         Expr::Literal(value) => render_scalar_projection_literal(value),
         Expr::FunctionCall { function, args } => {
+            // CAST keeps its SQL spelling so the label names the target type
+            // instead of echoing the frozen type-name literal argument.
+            if let (Function::Cast, [input, Expr::Literal(Value::Text(type_name))]) =
+                (function, args.as_slice())
+            {
+                let input =
+                    render_scalar_projection_expr_plan_label_with_parent(input, None, false);
+
+                return format!("CAST({input} AS {type_name})");
+            }
+            // EXTRACT keeps its `FIELD FROM input` spelling for the same reason.
+            if let (Function::Extract, [Expr::Literal(Value::Text(field)), input]) =
+                (function, args.as_slice())
+            {
+                let input =
+                    render_scalar_projection_expr_plan_label_with_parent(input, None, false);
+
+                return format!("EXTRACT({field} FROM {input})");
+            }
+
             let rendered_args = args
                 .iter()
                 .map(|arg| render_scalar_projection_expr_plan_label_with_parent(arg, None, false))
@@ -129,6 +149,13 @@ fn render_scalar_projection_expr_plan_label_with_parent(
             if let Some(input_expr) = aggregate.input_expr() {
                 let input =
                     render_scalar_projection_expr_plan_label_with_parent(input_expr, None, false);
+                if let Some(arguments) = aggregate.arguments() {
+                    return format!(
+                        "{}{}",
+                        arguments.render_call_label(aggregate.kind(), input.as_str()),
+                        filter.unwrap_or_default()
+                    );
+                }
 
                 return format!("{kind}({distinct}{input}){}", filter.unwrap_or_default());
             }
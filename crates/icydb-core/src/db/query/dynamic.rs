@@ -5,7 +5,7 @@
 
 use crate::db::query::{
     builder::AggregateExpr,
-    expr::{FilterExpr, OrderTerm},
+    expr::{FilterExpr, GroupExpr, OrderTerm},
 };
 
 ///
@@ -25,7 +25,7 @@ pub struct DynamicQuery {
     #[cfg(test)]
     distinct: bool,
     limit: Option<u32>,
-    group_fields: Vec<String>,
+    group_keys: Vec<GroupExpr>,
     aggregates: Vec<AggregateExpr>,
     grouped_limits: Option<(u32, u32)>,
     cursor: Option<String>,
@@ -43,7 +43,7 @@ impl DynamicQuery {
             #[cfg(test)]
             distinct: false,
             limit: None,
-            group_fields: Vec::new(),
+            group_keys: Vec::new(),
             aggregates: Vec::new(),
             grouped_limits: None,
             cursor: None,
@@ -100,7 +100,15 @@ impl DynamicQuery {
     /// Append one accepted field to the grouped key in declaration order.
     #[must_use]
     pub fn group_by(mut self, field: impl Into<String>) -> Self {
-        self.group_fields.push(field.into());
+        self.group_keys.push(GroupExpr::field(field));
+        self
+    }
+
+    /// Append one deterministic scalar expression to the grouped key in
+    /// declaration order.
+    #[must_use]
+    pub fn group_by_expr(mut self, expr: impl Into<GroupExpr>) -> Self {
+        self.group_keys.push(expr.into());
         self
     }
 
@@ -154,11 +162,11 @@ impl DynamicQuery {
     }
 
     pub(in crate::db) const fn has_grouping(&self) -> bool {
-        !self.group_fields.is_empty() || !self.aggregates.is_empty()
+        !self.group_keys.is_empty() || !self.aggregates.is_empty()
     }
 
-    pub(in crate::db) const fn group_fields(&self) -> &[String] {
-        self.group_fields.as_slice()
+    pub(in crate::db) const fn group_keys(&self) -> &[GroupExpr] {
+        self.group_keys.as_slice()
     }
 
     pub(in crate::db) const fn aggregates(&self) -> &[AggregateExpr] {
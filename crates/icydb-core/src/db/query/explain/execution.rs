@@ -103,6 +103,10 @@ pub(in crate::db) mod property_keys {
     pub(in crate::db) const COVERING_READ_ROUTE: &str = "cov_read_route";
     pub(in crate::db) const COVERING_SCAN_REASON: &str = "cov_scan_reason";
     pub(in crate::db) const COVERING_SOURCES: &str = "covering_sources";
+    #[cfg(feature = "sql")]
+    pub(in crate::db) const CTE_MATERIALIZATION: &str = "cte_materialization";
+    #[cfg(feature = "sql")]
+    pub(in crate::db) const CTE_NAME: &str = "cte";
     pub(in crate::db) const EXISTING_ROW_MODE: &str = "existing_row_mode";
     #[cfg(feature = "sql")]
     pub(in crate::db) const FILTER_EXPR: &str = "filter_expr";
@@ -120,11 +124,16 @@ pub(in crate::db) mod property_keys {
         "aggregate_direct_count_prefixes";
     pub(in crate::db) const INDEX: &str = "index";
     pub(in crate::db) const LIMIT_STOP_AFTER: &str = "limit_stop_after";
+    #[cfg(feature = "sql")]
+    pub(in crate::db) const MAX_GROUP_BYTES: &str = "max_group_bytes";
+    #[cfg(feature = "sql")]
+    pub(in crate::db) const MAX_GROUPS: &str = "max_groups";
     pub(in crate::db) const OFFSET: &str = "offset";
     pub(in crate::db) const ORDER_BY_INDEX: &str = "order_by_idx";
     pub(in crate::db) const ORDER_BY_INDEX_HINT: &str = "order_by_idx_hint";
     pub(in crate::db) const ORDER_ROUTE_MODE: &str = "ord_route_mode";
     pub(in crate::db) const ORDER_ROUTE_REASON: &str = "ord_route_reason";
+    pub(in crate::db) const PARTITION_BY: &str = "partition_by";
     pub(in crate::db) const PREDICATE_INDEX_CAPABILITY: &str = "pred_idx_cap";
     pub(in crate::db) const PREFIX_LEN: &str = "prefix_len";
     pub(in crate::db) const PREFIX_VALUES: &str = "prefix_values";
@@ -236,6 +245,7 @@ pub enum ExplainExecutionNodeType {
     CursorResume,
     IndexRangeLimitPushdown,
     TopNSeek,
+    PartitionLimit,
     AggregateCount,
     AggregateExists,
     AggregateMin,
@@ -243,11 +253,13 @@ pub enum ExplainExecutionNodeType {
     AggregateFirst,
     AggregateLast,
     AggregateSum,
+    AggregateRetainedValues,
     AggregateSeekFirst,
     AggregateSeekLast,
     GroupedAggregateHashMaterialized,
     GroupedAggregateOrderedStreaming,
     SecondaryOrderPushdown,
+    CommonTableExpression,
 }
 
 #[cfg_attr(
@@ -499,7 +511,15 @@ impl AggregateKind {
             Self::Max => ExplainExecutionNodeType::AggregateMax,
             Self::First => ExplainExecutionNodeType::AggregateFirst,
             Self::Last => ExplainExecutionNodeType::AggregateLast,
-            Self::Sum | Self::Avg => ExplainExecutionNodeType::AggregateSum,
+            Self::Sum
+            | Self::Avg
+            | Self::VarPop
+            | Self::VarSamp
+            | Self::StddevPop
+            | Self::StddevSamp => ExplainExecutionNodeType::AggregateSum,
+            Self::PercentileDisc | Self::StringAgg | Self::ArrayAgg => {
+                ExplainExecutionNodeType::AggregateRetainedValues
+            }
         }
     }
 }
@@ -531,6 +551,7 @@ impl ExplainExecutionNodeType {
             Self::CursorResume => "CursorResume",
             Self::IndexRangeLimitPushdown => "IndexRangeLimitPushdown",
             Self::TopNSeek => "TopNSeek",
+            Self::PartitionLimit => "PartitionLimit",
             Self::AggregateCount => "AggregateCount",
             Self::AggregateExists => "AggregateExists",
             Self::AggregateMin => "AggregateMin",
@@ -538,11 +559,13 @@ impl ExplainExecutionNodeType {
             Self::AggregateFirst => "AggregateFirst",
             Self::AggregateLast => "AggregateLast",
             Self::AggregateSum => "AggregateSum",
+            Self::AggregateRetainedValues => "AggregateRetainedValues",
             Self::AggregateSeekFirst => "AggregateSeekFirst",
             Self::AggregateSeekLast => "AggregateSeekLast",
             Self::GroupedAggregateHashMaterialized => "GroupedAggregateHashMaterialized",
             Self::GroupedAggregateOrderedStreaming => "GroupedAggregateOrderedStreaming",
             Self::SecondaryOrderPushdown => "SecondaryOrderPushdown",
+            Self::CommonTableExpression => "CommonTableExpression",
         }
     }
 
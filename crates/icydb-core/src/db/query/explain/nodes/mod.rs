@@ -56,7 +56,9 @@ const fn pipeline_layer_owns(node_type: ExplainExecutionNodeType) -> bool {
             | ExplainExecutionNodeType::CursorResume
             | ExplainExecutionNodeType::IndexRangeLimitPushdown
             | ExplainExecutionNodeType::TopNSeek
+            | ExplainExecutionNodeType::PartitionLimit
             | ExplainExecutionNodeType::SecondaryOrderPushdown
+            | ExplainExecutionNodeType::CommonTableExpression
     )
 }
 
@@ -72,6 +74,7 @@ const fn aggregate_layer_owns(node_type: ExplainExecutionNodeType) -> bool {
             | ExplainExecutionNodeType::AggregateFirst
             | ExplainExecutionNodeType::AggregateLast
             | ExplainExecutionNodeType::AggregateSum
+            | ExplainExecutionNodeType::AggregateRetainedValues
             | ExplainExecutionNodeType::AggregateSeekFirst
             | ExplainExecutionNodeType::AggregateSeekLast
             | ExplainExecutionNodeType::GroupedAggregateHashMaterialized
@@ -18,10 +18,11 @@ use crate::{
             plan::{
                 AccessChoiceCandidateExplainSummary, AccessChoiceExplainSnapshot,
                 AccessChoiceRejectedIndex, AccessChoiceResidualBurden, AccessChoiceSelectedReason,
-                AccessPlannedQuery, AggregateKind, DeleteLimitSpec, GroupedPlanAggregateFamily,
-                GroupedPlanFallbackReason, GroupedPlanStrategy, LogicalPlan, OrderDirection,
-                OrderSpec, PageSpec, QueryMode, ScalarPlan, explain_access_strategy_label,
-                expr::Expr, grouped_plan_strategy, render_scalar_filter_expr_plan_label,
+                AccessPlannedQuery, AggregateArguments, AggregateKind, DeleteLimitSpec,
+                GroupedPlanAggregateFamily, GroupedPlanFallbackReason, GroupedPlanStrategy,
+                LogicalPlan, NullsOrder, OrderDirection, OrderSpec, PageSpec, PartitionLimitSpec,
+                QueryMode, ScalarPlan, explain_access_strategy_label, expr::Expr,
+                grouped_plan_strategy, render_scalar_filter_expr_plan_label,
             },
         },
     },
@@ -295,6 +296,7 @@ pub struct ExplainGroupAggregate {
     pub(in crate::db) target_field: Option<String>,
     pub(in crate::db) input_expr: Option<String>,
     pub(in crate::db) filter_expr: Option<String>,
+    pub(in crate::db) arguments: Option<String>,
     pub(in crate::db) distinct: bool,
 }
 
@@ -323,6 +325,12 @@ impl ExplainGroupAggregate {
         self.filter_expr.as_deref()
     }
 
+    /// Borrow optional retained-value aggregate arguments label.
+    #[must_use]
+    pub fn arguments(&self) -> Option<&str> {
+        self.arguments.as_deref()
+    }
+
     /// Return whether grouped aggregate uses DISTINCT input semantics.
     #[must_use]
     pub const fn distinct(&self) -> bool {
@@ -405,6 +413,10 @@ pub enum SecondaryOrderPushdownRejection {
         expected_full: Vec<String>,
         actual: Vec<String>,
     },
+    NullPlacementNotSatisfiedByIndex {
+        index: String,
+        field: String,
+    },
 }
 
 ///
@@ -783,6 +795,10 @@ pub enum ExplainPredicate {
         field: String,
         value: Value,
     },
+    TextMatch {
+        field: String,
+        value: Value,
+    },
 }
 
 ///
@@ -800,13 +816,14 @@ pub enum ExplainOrderBy {
 ///
 /// ExplainOrder
 ///
-/// One canonical ORDER BY field + direction pair.
+/// One canonical ORDER BY field, direction, and resolved null placement.
 ///
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ExplainOrder {
     pub(in crate::db) field: String,
     pub(in crate::db) direction: OrderDirection,
+    pub(in crate::db) nulls: NullsOrder,
 }
 
 impl ExplainOrder {
@@ -821,6 +838,12 @@ impl ExplainOrder {
     pub const fn direction(&self) -> OrderDirection {
         self.direction
     }
+
+    /// Return resolved ORDER BY null placement.
+    #[must_use]
+    pub const fn nulls(&self) -> NullsOrder {
+        self.nulls
+    }
 }
 
 ///
@@ -833,6 +856,7 @@ impl ExplainOrder {
 pub enum ExplainPagination {
     None,
     Page { limit: Option<u32>, offset: u32 },
+    PartitionLimit { fields: Vec<String>, limit: u32 },
 }
 
 ///
@@ -902,6 +926,9 @@ impl AccessPlannedQuery {
                                 filter_expr: aggregate
                                     .filter_expr()
                                     .map(render_scalar_projection_expr_plan_label),
+                                arguments: aggregate
+                                    .arguments()
+                                    .map(AggregateArguments::render_label),
                                 distinct: aggregate.raw_distinct(),
                             })
                             .collect(),
@@ -950,7 +977,7 @@ where
     // Phase 2: project scalar-plan fields into explain-specific enums.
     let order_by = explain_order(logical.order.as_ref());
     let order_pushdown = explain_order_pushdown();
-    let page = explain_page(logical.page.as_ref());
+    let page = explain_page(logical.page.as_ref(), logical.partition_limit.as_ref());
     let delete_limit = explain_delete_limit(logical.delete_limit.as_ref());
 
     // Phase 3: assemble one stable explain payload.
@@ -1080,6 +1107,10 @@ impl ExplainPredicate {
                 field: field.clone(),
                 value: value.clone(),
             },
+            Predicate::TextMatch { field, value } => Self::TextMatch {
+                field: field.clone(),
+                value: value.clone(),
+            },
         }
     }
 
@@ -1109,18 +1140,26 @@ fn explain_order(order: Option<&OrderSpec>) -> ExplainOrderBy {
             .map(|term| ExplainOrder {
                 field: term.rendered_label(),
                 direction: term.direction(),
+                nulls: term.nulls(),
             })
             .collect(),
     )
 }
 
-const fn explain_page(page: Option<&PageSpec>) -> ExplainPagination {
-    match page {
-        Some(page) => ExplainPagination::Page {
+fn explain_page(
+    page: Option<&PageSpec>,
+    partition_limit: Option<&PartitionLimitSpec>,
+) -> ExplainPagination {
+    match (page, partition_limit) {
+        (_, Some(partition_limit)) => ExplainPagination::PartitionLimit {
+            fields: partition_limit.fields.clone(),
+            limit: partition_limit.limit,
+        },
+        (Some(page), None) => ExplainPagination::Page {
             limit: page.limit,
             offset: page.offset,
         },
-        None => ExplainPagination::None,
+        (None, None) => ExplainPagination::None,
     }
 }
 
@@ -1193,6 +1232,11 @@ fn write_logical_explain_json(explain: &ExplainPlan, out: &mut String) {
                 }
                 object.field_u64("offset", u64::from(*offset));
             }
+            ExplainPagination::PartitionLimit { fields, limit } => {
+                object.field_str("type", "PartitionLimit");
+                object.field_value_debug("fields", fields);
+                object.field_u64("limit", u64::from(*limit));
+            }
         }
         object.finish();
     });
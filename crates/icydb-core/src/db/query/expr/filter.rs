@@ -6,7 +6,7 @@
 use crate::{
     db::{
         codec::hex::encode_hex_lower,
-        query::plan::expr::{BinaryOp, Expr, FieldId, Function, UnaryOp},
+        query::plan::expr::{BinaryOp, Expr, FieldId, FieldPath, Function, UnaryOp},
         schema::SchemaInfo,
     },
     value::{InputValue, Value},
@@ -164,6 +164,10 @@ pub enum FilterExpr {
         field: String,
         value: FilterValue,
     },
+    TextMatch {
+        field: String,
+        value: FilterValue,
+    },
     StartsWith {
         field: String,
         value: FilterValue,
@@ -195,6 +199,40 @@ pub enum FilterExpr {
     IsNotEmpty {
         field: String,
     },
+    HasKey {
+        field: String,
+        key: String,
+    },
+    MapKeyEq {
+        field: String,
+        key: String,
+        value: FilterValue,
+    },
+    MapKeyNe {
+        field: String,
+        key: String,
+        value: FilterValue,
+    },
+    MapKeyLt {
+        field: String,
+        key: String,
+        value: FilterValue,
+    },
+    MapKeyLte {
+        field: String,
+        key: String,
+        value: FilterValue,
+    },
+    MapKeyGt {
+        field: String,
+        key: String,
+        value: FilterValue,
+    },
+    MapKeyGte {
+        field: String,
+        key: String,
+        value: FilterValue,
+    },
 }
 
 impl FilterExpr {
@@ -289,6 +327,11 @@ impl FilterExpr {
                 casefold_field_expr(field),
                 value.lower_value(),
             ),
+            Self::TextMatch { field, value } => text_function_expr(
+                Function::Match,
+                Expr::Field(FieldId::new(field.clone())),
+                value.lower_value(),
+            ),
             Self::StartsWith { field, value } => text_function_expr(
                 Function::StartsWith,
                 Expr::Field(FieldId::new(field.clone())),
@@ -314,6 +357,47 @@ impl FilterExpr {
             Self::IsMissing { field } => field_function_expr(Function::IsMissing, field),
             Self::IsEmpty { field } => field_function_expr(Function::IsEmpty, field),
             Self::IsNotEmpty { field } => field_function_expr(Function::IsNotEmpty, field),
+            Self::HasKey { field, key } => text_function_expr(
+                Function::HasKey,
+                Expr::Field(FieldId::new(field.clone())),
+                Value::Text(key.clone()),
+            ),
+            Self::MapKeyEq { field, key, value } => map_key_compare_expr(
+                BinaryOp::Eq,
+                field,
+                key,
+                lower_map_value(schema, field, value),
+            ),
+            Self::MapKeyNe { field, key, value } => map_key_compare_expr(
+                BinaryOp::Ne,
+                field,
+                key,
+                lower_map_value(schema, field, value),
+            ),
+            Self::MapKeyLt { field, key, value } => map_key_compare_expr(
+                BinaryOp::Lt,
+                field,
+                key,
+                lower_map_value(schema, field, value),
+            ),
+            Self::MapKeyLte { field, key, value } => map_key_compare_expr(
+                BinaryOp::Lte,
+                field,
+                key,
+                lower_map_value(schema, field, value),
+            ),
+            Self::MapKeyGt { field, key, value } => map_key_compare_expr(
+                BinaryOp::Gt,
+                field,
+                key,
+                lower_map_value(schema, field, value),
+            ),
+            Self::MapKeyGte { field, key, value } => map_key_compare_expr(
+                BinaryOp::Gte,
+                field,
+                key,
+                lower_map_value(schema, field, value),
+            ),
         }
     }
 
@@ -504,6 +588,15 @@ impl FilterExpr {
         }
     }
 
+    /// Match full-text terms against one text field.
+    #[must_use]
+    pub fn text_match(field: impl Into<String>, value: impl Into<FilterValue>) -> Self {
+        Self::TextMatch {
+            field: field.into(),
+            value: value.into(),
+        }
+    }
+
     /// Compare case-sensitive prefix match.
     #[must_use]
     pub fn starts_with(field: impl Into<String>, value: impl Into<FilterValue>) -> Self {
@@ -579,6 +672,99 @@ impl FilterExpr {
             field: field.into(),
         }
     }
+
+    /// Match rows whose text-keyed map `field` contains `key`.
+    #[must_use]
+    pub fn has_key(field: impl Into<String>, key: impl Into<String>) -> Self {
+        Self::HasKey {
+            field: field.into(),
+            key: key.into(),
+        }
+    }
+
+    /// Compare `field['key'] == value`; rows missing `key` never match.
+    #[must_use]
+    pub fn map_key_eq(
+        field: impl Into<String>,
+        key: impl Into<String>,
+        value: impl Into<FilterValue>,
+    ) -> Self {
+        Self::MapKeyEq {
+            field: field.into(),
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Compare `field['key'] != value`; rows missing `key` never match.
+    #[must_use]
+    pub fn map_key_ne(
+        field: impl Into<String>,
+        key: impl Into<String>,
+        value: impl Into<FilterValue>,
+    ) -> Self {
+        Self::MapKeyNe {
+            field: field.into(),
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Compare `field['key'] < value`; rows missing `key` never match.
+    #[must_use]
+    pub fn map_key_lt(
+        field: impl Into<String>,
+        key: impl Into<String>,
+        value: impl Into<FilterValue>,
+    ) -> Self {
+        Self::MapKeyLt {
+            field: field.into(),
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Compare `field['key'] <= value`; rows missing `key` never match.
+    #[must_use]
+    pub fn map_key_lte(
+        field: impl Into<String>,
+        key: impl Into<String>,
+        value: impl Into<FilterValue>,
+    ) -> Self {
+        Self::MapKeyLte {
+            field: field.into(),
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Compare `field['key'] > value`; rows missing `key` never match.
+    #[must_use]
+    pub fn map_key_gt(
+        field: impl Into<String>,
+        key: impl Into<String>,
+        value: impl Into<FilterValue>,
+    ) -> Self {
+        Self::MapKeyGt {
+            field: field.into(),
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Compare `field['key'] >= value`; rows missing `key` never match.
+    #[must_use]
+    pub fn map_key_gte(
+        field: impl Into<String>,
+        key: impl Into<String>,
+        value: impl Into<FilterValue>,
+    ) -> Self {
+        Self::MapKeyGte {
+            field: field.into(),
+            key: key.into(),
+            value: value.into(),
+        }
+    }
 }
 
 fn lower_compare(schema: &SchemaInfo, field: &str, value: &FilterValue) -> Value {
@@ -602,6 +788,13 @@ fn lower_collection_element(schema: &SchemaInfo, field: &str, value: &FilterValu
         .unwrap_or(raw)
 }
 
+fn lower_map_value(schema: &SchemaInfo, field: &str, value: &FilterValue) -> Value {
+    let raw = value.lower_value();
+    schema
+        .canonicalize_filter_map_value(field, &raw)
+        .unwrap_or(raw)
+}
+
 fn fold_filter_bool_chain(op: BinaryOp, exprs: &[FilterExpr], schema: &SchemaInfo) -> Expr {
     let mut exprs = exprs.iter();
     let Some(first) = exprs.next() else {
@@ -625,6 +818,17 @@ fn field_compare_expr(op: BinaryOp, field: &str, value: Value) -> Expr {
     }
 }
 
+fn map_key_compare_expr(op: BinaryOp, field: &str, key: &str, value: Value) -> Expr {
+    Expr::Binary {
+        op,
+        left: Box::new(Expr::FieldPath(FieldPath::new(
+            FieldId::new(field.to_string()),
+            vec![key.to_string()],
+        ))),
+        right: Box::new(Expr::Literal(value)),
+    }
+}
+
 fn field_compare_field_expr(op: BinaryOp, left_field: &str, right_field: &str) -> Expr {
     Expr::Binary {
         op,
@@ -0,0 +1,76 @@
+//! Module: db::query::expr::group
+//! Responsibility: typed fluent GROUP BY key expression DTOs.
+//! Does not own: grouped planner validation or grouped key evaluation.
+//! Boundary: carries fluent grouped keys into planner-owned grouped slots.
+
+use crate::db::query::{
+    builder::{FieldRef, NumericProjectionExpr, RoundProjectionExpr, TextProjectionExpr},
+    plan::expr::{Expr, FieldId},
+};
+
+///
+/// GroupExpr
+///
+/// Typed fluent GROUP BY key expression wrapper.
+/// Direct fields keep the accepted-field grouped key lane while scalar
+/// projection expressions become computed grouped keys.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupExpr {
+    expr: Expr,
+}
+
+impl GroupExpr {
+    /// Build one direct field GROUP BY key.
+    #[must_use]
+    pub fn field(field: impl Into<String>) -> Self {
+        Self::new(Expr::Field(FieldId::new(field.into())))
+    }
+
+    const fn new(expr: Expr) -> Self {
+        Self { expr }
+    }
+
+    /// Borrow the planner-owned grouped key expression.
+    #[must_use]
+    pub(in crate::db) const fn expr(&self) -> &Expr {
+        &self.expr
+    }
+}
+
+impl From<&str> for GroupExpr {
+    fn from(value: &str) -> Self {
+        Self::field(value)
+    }
+}
+
+impl From<String> for GroupExpr {
+    fn from(value: String) -> Self {
+        Self::field(value)
+    }
+}
+
+impl From<FieldRef> for GroupExpr {
+    fn from(value: FieldRef) -> Self {
+        Self::field(value.as_str())
+    }
+}
+
+impl From<TextProjectionExpr> for GroupExpr {
+    fn from(value: TextProjectionExpr) -> Self {
+        Self::new(value.expr().clone())
+    }
+}
+
+impl From<NumericProjectionExpr> for GroupExpr {
+    fn from(value: NumericProjectionExpr) -> Self {
+        Self::new(value.expr().clone())
+    }
+}
+
+impl From<RoundProjectionExpr> for GroupExpr {
+    fn from(value: RoundProjectionExpr) -> Self {
+        Self::new(value.expr().clone())
+    }
+}
@@ -1,10 +1,12 @@
 //! Module: db::query::expr
-//! Responsibility: schema-agnostic filter/order expression wrappers and lowering.
+//! Responsibility: schema-agnostic filter/group/order expression wrappers and lowering.
 //! Does not own: planner route selection or executor evaluation.
 //! Boundary: intent boundary lowers these to validated predicate/order forms.
 
 mod filter;
+mod group;
 mod order;
 
 pub use filter::{FilterExpr, FilterValue};
+pub use group::GroupExpr;
 pub use order::{OrderExpr, OrderTerm, asc, desc, field};
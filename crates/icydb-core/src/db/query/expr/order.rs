@@ -8,7 +8,7 @@ use crate::db::query::{
         AggregateExpr, FieldRef, NumericProjectionExpr, RoundProjectionExpr, TextProjectionExpr,
     },
     plan::{
-        OrderDirection, OrderTerm as PlannedOrderTerm,
+        NullsOrder, OrderDirection, OrderTerm as PlannedOrderTerm,
         expr::{Expr, FieldId},
     },
 };
@@ -97,14 +97,16 @@ impl From<AggregateExpr> for OrderExpr {
 /// OrderTerm
 ///
 /// Typed fluent ORDER BY term.
-/// Carries one typed ORDER BY expression plus direction so fluent builders can
-/// express deterministic ordering directly at the query boundary.
+/// Carries one typed ORDER BY expression plus direction and optional null
+/// placement so fluent builders can express deterministic ordering directly at
+/// the query boundary.
 ///
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct OrderTerm {
     expr: OrderExpr,
     direction: OrderDirection,
+    nulls: Option<NullsOrder>,
 }
 
 impl OrderTerm {
@@ -114,6 +116,7 @@ impl OrderTerm {
         Self {
             expr: expr.into(),
             direction: OrderDirection::Asc,
+            nulls: None,
         }
     }
 
@@ -123,13 +126,32 @@ impl OrderTerm {
         Self {
             expr: expr.into(),
             direction: OrderDirection::Desc,
+            nulls: None,
         }
     }
 
+    /// Place `NULL` values before present values for this term.
+    #[must_use]
+    pub const fn nulls_first(mut self) -> Self {
+        self.nulls = Some(NullsOrder::First);
+        self
+    }
+
+    /// Place `NULL` values after present values for this term.
+    #[must_use]
+    pub const fn nulls_last(mut self) -> Self {
+        self.nulls = Some(NullsOrder::Last);
+        self
+    }
+
     /// Lower one typed fluent order term directly into the planner-owned
     /// `OrderTerm` contract.
     pub(in crate::db) fn lower(&self) -> PlannedOrderTerm {
-        self.expr.lower(self.direction)
+        let term = self.expr.lower(self.direction);
+        match self.nulls {
+            Some(nulls) => term.with_nulls(nulls),
+            None => term,
+        }
     }
 }
 
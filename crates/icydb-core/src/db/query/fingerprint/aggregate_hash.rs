@@ -18,6 +18,7 @@ const AGGREGATE_NON_DISTINCT_TAG: u8 = 0x03;
 const AGGREGATE_INPUT_EXPR_PRESENT_TAG: u8 = 0x04;
 const AGGREGATE_FILTER_EXPR_PRESENT_TAG: u8 = 0x05;
 const AGGREGATE_FILTER_EXPR_ABSENT_TAG: u8 = 0x06;
+const AGGREGATE_ARGUMENTS_PRESENT_TAG: u8 = 0x07;
 
 ///
 /// AggregateHashShape
@@ -30,6 +31,7 @@ pub(in crate::db::query::fingerprint) struct AggregateHashShape<'a> {
     target_field: Option<&'a str>,
     input_expr: Option<String>,
     filter_expr: Option<String>,
+    arguments: Option<String>,
     distinct: bool,
 }
 
@@ -48,9 +50,20 @@ impl<'a> AggregateHashShape<'a> {
             target_field,
             input_expr,
             filter_expr,
+            arguments: None,
             distinct: AggregateIdentity::normalize_distinct_for_kind(kind, distinct),
         }
     }
+
+    /// Attach the rendered retained-value aggregate arguments label.
+    #[must_use]
+    pub(in crate::db::query::fingerprint) fn with_arguments(
+        mut self,
+        arguments: Option<String>,
+    ) -> Self {
+        self.arguments = arguments;
+        self
+    }
 }
 
 // Hash one grouped aggregate identity shape using the current structural encoding.
@@ -62,6 +75,7 @@ pub(in crate::db::query::fingerprint) fn hash_group_aggregate_structural_fingerp
     // - aggregate kind discriminant
     // - optional target field
     // - distinct modifier flag
+    // - optional retained-value aggregate arguments
     //
     // Aggregate fingerprint identity must remain purely semantic.
     write_tag(hasher, GROUP_AGGREGATE_STRUCTURAL_FINGERPRINT_TAG);
@@ -91,6 +105,10 @@ pub(in crate::db::query::fingerprint) fn hash_group_aggregate_structural_fingerp
     } else {
         write_tag(hasher, AGGREGATE_FILTER_EXPR_ABSENT_TAG);
     }
+    if let Some(arguments) = shape.arguments.as_deref() {
+        write_tag(hasher, AGGREGATE_ARGUMENTS_PRESENT_TAG);
+        write_str(hasher, arguments);
+    }
 }
 
 ///
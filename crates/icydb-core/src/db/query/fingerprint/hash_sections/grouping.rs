@@ -14,7 +14,7 @@ use crate::db::{
             projection_hash::hash_projection_structural_fingerprint,
         },
         plan::{
-            AccessPlannedQuery, GroupAggregateSpec, GroupedPlanAggregateFamily,
+            AccessPlannedQuery, AggregateArguments, GroupAggregateSpec, GroupedPlanAggregateFamily,
             GroupedPlanFallbackReason, GroupedPlanStrategy, expr::ProjectionSpec,
             grouped_plan_strategy,
         },
@@ -146,6 +146,7 @@ impl<'a> ProjectedGroupingShape<'a> {
                                 aggregate.filter_expr().map(str::to_string),
                                 aggregate.distinct(),
                             )
+                            .with_arguments(aggregate.arguments().map(str::to_string))
                         })
                         .collect(),
                     having: having
@@ -204,6 +205,7 @@ impl<'a> ProjectedGroupingShape<'a> {
                             .map(render_scalar_projection_expr_plan_label),
                         aggregate.semantic_distinct(),
                     )
+                    .with_arguments(aggregate.arguments().map(AggregateArguments::render_label))
                 })
                 .collect(),
             having: grouped.effective_having_expr().map(|expr| match expr {
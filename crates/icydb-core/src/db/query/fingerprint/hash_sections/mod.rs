@@ -17,7 +17,10 @@ use crate::{
         query::{
             explain::{ExplainDeleteLimit, ExplainOrderBy, ExplainPagination},
             fingerprint::projection_hash::hash_scalar_filter_expr_structural_fingerprint,
-            plan::{DeleteLimitSpec, OrderDirection, OrderSpec, PageSpec, QueryMode, expr::Expr},
+            plan::{
+                DeleteLimitSpec, NullsOrder, OrderDirection, OrderSpec, PageSpec,
+                PartitionLimitSpec, QueryMode, expr::Expr,
+            },
         },
     },
     error::InternalError,
@@ -46,6 +49,8 @@ const FILTER_EXPR_PRESENT_TAG: u8 = 0x21;
 
 const ORDER_NONE_TAG: u8 = 0x30;
 const ORDER_FIELDS_TAG: u8 = 0x31;
+const ORDER_NULLS_FIRST_TAG: u8 = 0x32;
+const ORDER_NULLS_LAST_TAG: u8 = 0x33;
 
 const PAGE_NONE_TAG: u8 = 0x40;
 const PAGE_PRESENT_TAG: u8 = 0x41;
@@ -53,6 +58,7 @@ const DELETE_LIMIT_NONE_TAG: u8 = 0x42;
 const DELETE_LIMIT_PRESENT_TAG: u8 = 0x43;
 const DISTINCT_ENABLED_TAG: u8 = 0x44;
 const DISTINCT_DISABLED_TAG: u8 = 0x45;
+const PAGE_PARTITION_LIMIT_TAG: u8 = 0x46;
 
 const CONSISTENCY_IGNORE_TAG: u8 = 0x50;
 const CONSISTENCY_ERROR_TAG: u8 = 0x51;
@@ -171,7 +177,7 @@ pub(super) fn hash_scalar_semantic_filter(
 
 enum ProjectedOrderShape {
     None,
-    Fields(Vec<(String, OrderDirection)>),
+    Fields(Vec<(String, OrderDirection, NullsOrder)>),
 }
 
 impl ProjectedOrderShape {
@@ -181,7 +187,7 @@ impl ProjectedOrderShape {
             ExplainOrderBy::Fields(fields) => Self::Fields(
                 fields
                     .iter()
-                    .map(|field| (field.field().to_owned(), field.direction()))
+                    .map(|field| (field.field().to_owned(), field.direction(), field.nulls()))
                     .collect(),
             ),
         }
@@ -193,7 +199,7 @@ impl ProjectedOrderShape {
                 order
                     .fields
                     .iter()
-                    .map(|term| (term.rendered_label(), term.direction()))
+                    .map(|term| (term.rendered_label(), term.direction(), term.nulls()))
                     .collect(),
             ),
             Some(_) | None => Self::None,
@@ -215,9 +221,14 @@ fn hash_projected_order_shape(hasher: &mut Sha256, order: &ProjectedOrderShape)
         ProjectedOrderShape::Fields(fields) => {
             write_tag(hasher, ORDER_FIELDS_TAG);
             write_u32(hasher, fields.len() as u32);
-            for (field, direction) in fields {
+            for (field, direction, nulls) in fields {
                 write_str(hasher, field);
                 write_tag(hasher, order_direction_tag(*direction));
+                // Natural placement writes nothing so plans without explicit
+                // `NULLS FIRST | LAST` keep their established fingerprints.
+                if !nulls.is_natural_for(*direction) {
+                    write_tag(hasher, order_nulls_tag(*nulls));
+                }
             }
         }
     }
@@ -327,6 +338,13 @@ const fn order_direction_tag(direction: OrderDirection) -> u8 {
     }
 }
 
+const fn order_nulls_tag(nulls: NullsOrder) -> u8 {
+    match nulls {
+        NullsOrder::First => ORDER_NULLS_FIRST_TAG,
+        NullsOrder::Last => ORDER_NULLS_LAST_TAG,
+    }
+}
+
 ///
 /// ProjectedPageWindow
 ///
@@ -335,9 +353,10 @@ const fn order_direction_tag(direction: OrderDirection) -> u8 {
 /// before the canonical page hash is written.
 ///
 
-enum ProjectedPageWindow {
+enum ProjectedPageWindow<'a> {
     None,
     Page { limit: Option<u32>, offset: u32 },
+    PartitionLimit { fields: &'a [String], limit: u32 },
 }
 
 ///
@@ -353,24 +372,37 @@ enum ProjectedDeleteWindow {
     Window { limit: Option<u32>, offset: u32 },
 }
 
-impl ProjectedPageWindow {
-    const fn from_explain(page: &ExplainPagination) -> Self {
+impl<'a> ProjectedPageWindow<'a> {
+    const fn from_explain(page: &'a ExplainPagination) -> Self {
         match page {
             ExplainPagination::None => Self::None,
             ExplainPagination::Page { limit, offset } => Self::Page {
                 limit: *limit,
                 offset: *offset,
             },
+            ExplainPagination::PartitionLimit { fields, limit } => Self::PartitionLimit {
+                fields: fields.as_slice(),
+                limit: *limit,
+            },
         }
     }
 
-    const fn from_plan(page: Option<&PageSpec>) -> Self {
-        match page {
-            Some(page) => Self::Page {
+    // Planner validation keeps partition limits and pages mutually
+    // exclusive, so the partition window takes the page slot when present.
+    const fn from_plan(
+        page: Option<&PageSpec>,
+        partition_limit: Option<&'a PartitionLimitSpec>,
+    ) -> Self {
+        match (page, partition_limit) {
+            (_, Some(partition_limit)) => Self::PartitionLimit {
+                fields: partition_limit.fields.as_slice(),
+                limit: partition_limit.limit,
+            },
+            (Some(page), None) => Self::Page {
                 limit: page.limit,
                 offset: page.offset,
             },
-            None => Self::None,
+            (None, None) => Self::None,
         }
     }
 }
@@ -405,8 +437,15 @@ pub(super) fn hash_page(hasher: &mut Sha256, page: &ExplainPagination) {
     hash_projected_page_window(hasher, &ProjectedPageWindow::from_explain(page));
 }
 
-pub(super) fn hash_page_spec(hasher: &mut Sha256, page: Option<&PageSpec>) {
-    hash_projected_page_window(hasher, &ProjectedPageWindow::from_plan(page));
+pub(super) fn hash_page_spec(
+    hasher: &mut Sha256,
+    page: Option<&PageSpec>,
+    partition_limit: Option<&PartitionLimitSpec>,
+) {
+    hash_projected_page_window(
+        hasher,
+        &ProjectedPageWindow::from_plan(page, partition_limit),
+    );
 }
 
 fn hash_projected_page_window(hasher: &mut Sha256, page: &ProjectedPageWindow) {
@@ -423,6 +462,14 @@ fn hash_projected_page_window(hasher: &mut Sha256, page: &ProjectedPageWindow) {
             }
             write_u32(hasher, *offset);
         }
+        ProjectedPageWindow::PartitionLimit { fields, limit } => {
+            write_tag(hasher, PAGE_PARTITION_LIMIT_TAG);
+            write_u32(hasher, u32::try_from(fields.len()).unwrap_or(u32::MAX));
+            for field in *fields {
+                write_str(hasher, field);
+            }
+            write_u32(hasher, *limit);
+        }
     }
 }
 
@@ -263,7 +263,11 @@ impl<'a> ExplainHashSource<'a> {
             ),
             ExplainHashField::Order => hash_order_spec(hasher, scalar.order.as_ref()),
             ExplainHashField::Distinct => hash_distinct(hasher, scalar.distinct),
-            ExplainHashField::Page => hash_page_spec(hasher, scalar.page.as_ref()),
+            ExplainHashField::Page => hash_page_spec(
+                hasher,
+                scalar.page.as_ref(),
+                scalar.partition_limit.as_ref(),
+            ),
             ExplainHashField::DeleteLimit => {
                 hash_delete_limit_spec(hasher, scalar.delete_limit.as_ref());
             }
@@ -13,8 +13,8 @@ use crate::{
             },
             intent::{model::QueryModel, state::GroupedIntent},
             plan::{
-                AggregateIdentity, OrderDirection, OrderSpec, PreparedQueryParameterContract,
-                QueryMode,
+                AggregateArguments, AggregateIdentity, NullsOrder, OrderDirection, OrderSpec,
+                PartitionLimitSpec, PreparedQueryParameterContract, QueryMode,
                 expr::{Expr, Function, ProjectionField, ProjectionSelection},
             },
         },
@@ -39,6 +39,7 @@ pub(in crate::db) struct StructuralQueryCacheKey {
     filter_expr: Option<ProjectionExprCacheKey>,
     order: Option<Vec<OrderFieldCacheKey>>,
     distinct: bool,
+    partition_limit: Option<PartitionLimitSpec>,
     projection: ProjectionCacheKey,
     grouping: Option<GroupingCacheKey>,
     consistency: ConsistencyCacheKey,
@@ -80,14 +81,15 @@ impl DiagnosticCacheKey {
 ///
 /// Canonical representation of one `ORDER BY` field in the structural query
 /// cache key.
-/// This wrapper keeps the field name and normalized direction explicit so cache
-/// hits do not accidentally cross different sort layouts.
+/// This wrapper keeps the field name, normalized direction, and null placement
+/// explicit so cache hits do not accidentally cross different sort layouts.
 ///
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 struct OrderFieldCacheKey {
     field: String,
     direction: OrderDirectionCacheKey,
+    nulls: NullsOrderCacheKey,
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -96,6 +98,12 @@ enum OrderDirectionCacheKey {
     Desc,
 }
 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum NullsOrderCacheKey {
+    First,
+    Last,
+}
+
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 enum ProjectionCacheKey {
     All,
@@ -172,6 +180,7 @@ struct AggregateCacheKey {
     target_field: Option<String>,
     input_expr: Option<String>,
     filter_expr: Option<String>,
+    arguments: Option<String>,
     distinct: bool,
 }
 
@@ -269,6 +278,7 @@ impl StructuralQueryCacheKey {
                 .as_ref()
                 .map(OrderFieldCacheKey::from_order_spec),
             distinct: scalar.distinct,
+            partition_limit: scalar.partition_limit.clone(),
             projection: ProjectionCacheKey::from_projection_selection(&scalar.projection_selection),
             grouping: model
                 .grouped_intent_for_cache_key()
@@ -312,6 +322,7 @@ impl OrderFieldCacheKey {
             .map(|term| Self {
                 field: term.rendered_label(),
                 direction: OrderDirectionCacheKey::from_order_direction(term.direction()),
+                nulls: NullsOrderCacheKey::from_nulls_order(term.nulls()),
             })
             .collect()
     }
@@ -326,6 +337,15 @@ impl OrderDirectionCacheKey {
     }
 }
 
+impl NullsOrderCacheKey {
+    const fn from_nulls_order(nulls: NullsOrder) -> Self {
+        match nulls {
+            NullsOrder::First => Self::First,
+            NullsOrder::Last => Self::Last,
+        }
+    }
+}
+
 impl ProjectionCacheKey {
     fn from_projection_selection(projection: &ProjectionSelection) -> Self {
         match projection {
@@ -458,6 +478,7 @@ impl AggregateCacheKey {
                 .input_expr()
                 .map(render_scalar_projection_expr_plan_label),
             filter_expr: filter_expr.map(render_scalar_projection_expr_plan_label),
+            arguments: identity.arguments().map(AggregateArguments::render_label),
             distinct: identity.distinct(),
         }
     }
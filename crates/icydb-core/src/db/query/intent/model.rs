@@ -19,7 +19,7 @@ use crate::db::{
             build_query_model_plan_with_indexes_from_scalar_planning_state,
             expr::{Expr, ProjectionSelection, is_normalized_bool_expr, normalize_bool_expr},
             prepare_query_model_scalar_planning_state_with_schema_info,
-            resolve_group_field_slot_with_schema,
+            resolve_group_expr_slot_with_schema, resolve_group_field_slot_with_schema,
             try_build_trivial_scalar_load_plan_with_schema_info,
         },
     },
@@ -90,6 +90,17 @@ impl QueryModel {
         self.consistency
     }
 
+    /// Borrow the normalized expression filter when the predicate subset
+    /// cannot carry the full `WHERE` semantics.
+    #[must_use]
+    pub(in crate::db::query) fn logical_filter_expr(&self) -> Option<&Expr> {
+        self.intent
+            .scalar()
+            .filter
+            .as_ref()
+            .and_then(|filter| filter.logical_filter_expr())
+    }
+
     #[must_use]
     pub(in crate::db::query) fn planning_access_inputs(&self) -> AccessPlanningInputs<'_> {
         self.intent.planning_access_inputs()
@@ -111,6 +122,7 @@ impl QueryModel {
         let logical_inputs = self.planning_logical_inputs();
         let scalar_shape_supported = access_inputs.order().is_none()
             && !logical_inputs.distinct()
+            && !logical_inputs.has_partition_limit()
             && !logical_inputs.has_group()
             && !logical_inputs.has_having_expr();
         let visible_filter_fully_covered =
@@ -164,6 +176,7 @@ impl QueryModel {
         let scalar = self.intent.scalar();
         if scalar.filter.is_some()
             || scalar.distinct
+            || scalar.partition_limit.is_some()
             || self.intent.is_grouped()
             || !matches!(scalar.projection_selection, ProjectionSelection::All)
         {
@@ -293,6 +306,18 @@ impl QueryModel {
         self
     }
 
+    /// Keep only the first `limit` ordered rows of each partition keyed by
+    /// `fields`.
+    #[must_use]
+    pub(in crate::db::query) fn limit_per_partition(
+        mut self,
+        limit: u32,
+        fields: Vec<String>,
+    ) -> Self {
+        self.intent.set_partition_limit(fields, limit);
+        self
+    }
+
     /// Select one explicit scalar field projection list for internal SQL and
     /// planning tests that compare fluent and structural query shapes.
     #[must_use]
@@ -335,6 +360,29 @@ impl QueryModel {
         Ok(self)
     }
 
+    // Resolve one grouped key expression through an explicit schema view.
+    // Computed keys take the next virtual slot past the accepted row layout so
+    // they never collide with stored field slots.
+    pub(in crate::db::query::intent) fn push_group_expr_with_schema(
+        mut self,
+        expr: Expr,
+        schema: &SchemaInfo,
+    ) -> Result<Self, QueryError> {
+        let declared_keys = self
+            .intent
+            .grouped()
+            .map_or(0, |grouped| grouped.group.group_fields.len());
+        let field_slot = resolve_group_expr_slot_with_schema(
+            schema,
+            expr,
+            schema.field_slot_bound() + declared_keys,
+        )
+        .map_err(QueryError::from)?;
+        self.intent.push_group_field_slot(field_slot);
+
+        Ok(self)
+    }
+
     // Append one grouped aggregate terminal to the grouped declarative spec.
     pub(in crate::db::query::intent) fn push_group_aggregate(
         mut self,
@@ -12,6 +12,7 @@ use crate::db::query::{
     },
     plan::{
         FieldSlot, GroupAggregateSpec, GroupedExecutionConfig, OrderSpec, OrderTerm,
+        PartitionLimitSpec,
         expr::{BinaryOp, Expr, canonicalize_grouped_having_bool_expr, normalize_bool_expr},
     },
 };
@@ -73,6 +74,19 @@ impl QueryIntent {
         self.scalar_mut().distinct = true;
     }
 
+    /// Keep only the first `limit` ordered rows of each partition.
+    pub(in crate::db::query::intent) fn set_partition_limit(
+        &mut self,
+        fields: Vec<String>,
+        limit: u32,
+    ) {
+        self.scalar_mut().partition_limit = Some(PartitionLimitSpec {
+            fields,
+            limit,
+            execution: GroupedExecutionConfig::planner_default_bounded(),
+        });
+    }
+
     /// Override scalar projection selection with one explicit planner contract.
     pub(in crate::db::query::intent) fn set_projection_selection(
         &mut self,
@@ -88,11 +102,9 @@ impl QueryIntent {
         };
 
         let group = &mut grouped.group;
-        if !group
-            .group_fields
-            .iter()
-            .any(|existing| existing.index() == field_slot.index())
-        {
+        if !group.group_fields.iter().any(|existing| {
+            existing.index() == field_slot.index() || existing.field() == field_slot.field()
+        }) {
             group.group_fields.push(field_slot);
         }
     }
@@ -110,11 +122,21 @@ impl QueryIntent {
     }
 
     /// Set explicit hard limits for grouped execution.
+    ///
+    /// A scalar `LIMIT k PER` load keeps its scalar shape and applies the
+    /// limits to its partition window instead.
     pub(in crate::db::query::intent) fn set_grouped_execution_limits(
         &mut self,
         max_groups: u64,
         max_group_bytes: u64,
     ) {
+        if !self.is_grouped()
+            && let Some(partition_limit) = self.scalar_mut().partition_limit.as_mut()
+        {
+            partition_limit.execution =
+                GroupedExecutionConfig::with_hard_limits(max_groups, max_group_bytes);
+            return;
+        }
         let Some(grouped) = self.grouped_mutation_target() else {
             return;
         };
@@ -86,11 +86,7 @@ impl StructuralQuery {
 
     #[must_use]
     pub(in crate::db) fn scalar_filter_expr(&self) -> Option<&Expr> {
-        self.intent
-            .scalar_intent_for_cache_key()
-            .filter
-            .as_ref()
-            .and_then(|filter| filter.logical_filter_expr())
+        self.intent.logical_filter_expr()
     }
 
     #[must_use]
@@ -160,6 +156,12 @@ impl StructuralQuery {
         self
     }
 
+    #[must_use]
+    pub(in crate::db) fn limit_per_partition(mut self, limit: u32, fields: Vec<String>) -> Self {
+        self.intent = self.intent.limit_per_partition(limit, fields);
+        self
+    }
+
     #[must_use]
     pub(in crate::db) fn select_fields<I, S>(mut self, fields: I) -> Self
     where
@@ -184,6 +186,14 @@ impl StructuralQuery {
         self.try_map_intent(|intent| intent.push_group_field_with_schema(field.as_ref(), schema))
     }
 
+    pub(in crate::db) fn group_by_expr_with_schema(
+        self,
+        expr: Expr,
+        schema: &SchemaInfo,
+    ) -> Result<Self, QueryError> {
+        self.try_map_intent(|intent| intent.push_group_expr_with_schema(expr, schema))
+    }
+
     #[must_use]
     pub(in crate::db) fn aggregate(mut self, aggregate: AggregateExpr) -> Self {
         self.intent = self.intent.push_group_aggregate(aggregate);
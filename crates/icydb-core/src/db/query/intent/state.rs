@@ -7,7 +7,8 @@ use crate::db::{
     predicate::Predicate,
     query::plan::{
         AccessPlanningInputs, DeleteSpec, GroupSpec, GroupedExecutionConfig, LoadSpec,
-        LogicalPlanningInputs, OrderSpec, QueryMode,
+        LogicalPlanningInputs, OrderSpec, OrderTerm, PartitionLimitSpec, QueryMode,
+        bind_computed_group_key_refs,
         expr::{
             BinaryOp, Expr, ProjectionSelection, derive_normalized_bool_expr_predicate_subset,
             is_normalized_bool_expr, normalize_bool_expr,
@@ -31,14 +32,6 @@ pub(in crate::db::query::intent) enum FilterPredicateCoverage {
 }
 
 impl FilterPredicateCoverage {
-    const fn from_extracted_subset(predicate_subset: Option<&Predicate>) -> Self {
-        if predicate_subset.is_some() {
-            Self::Full
-        } else {
-            Self::None
-        }
-    }
-
     const fn combine_for_and(existing: Self, appended: Self, combined_subset_exists: bool) -> Self {
         if !combined_subset_exists {
             return Self::None;
@@ -80,9 +73,19 @@ impl NormalizedFilter {
             "intent-owned filter expressions must be normalized before storage",
         );
 
-        let predicate_subset = derive_normalized_bool_expr_predicate_subset(&expr);
-        let predicate_coverage =
-            FilterPredicateCoverage::from_extracted_subset(predicate_subset.as_ref());
+        let (predicate_subset, predicate_coverage) =
+            if let Some(predicate) = derive_normalized_bool_expr_predicate_subset(&expr) {
+                (Some(predicate), FilterPredicateCoverage::Full)
+            } else {
+                let conjunct_subset = derive_conjunct_predicate_subset(&expr);
+                let coverage = if conjunct_subset.is_some() {
+                    FilterPredicateCoverage::Partial
+                } else {
+                    FilterPredicateCoverage::None
+                };
+
+                (conjunct_subset, coverage)
+            };
 
         Self {
             semantic_authority: FilterSemanticAuthority::ExpressionBacked(expr),
@@ -204,6 +207,42 @@ impl NormalizedFilter {
     }
 }
 
+// Derive the predicate subset of one top-level AND chain whose whole
+// expression has no runtime predicate form. Every extracted conjunct is implied
+// by the full expression, so access planning may use it while the expression
+// stays the residual filter.
+fn derive_conjunct_predicate_subset(expr: &Expr) -> Option<Predicate> {
+    let mut conjuncts = Vec::new();
+    collect_and_conjuncts(expr, &mut conjuncts);
+    if conjuncts.len() < 2 {
+        return None;
+    }
+
+    let mut predicates = conjuncts
+        .into_iter()
+        .filter_map(derive_normalized_bool_expr_predicate_subset)
+        .collect::<Vec<_>>();
+    match predicates.len() {
+        0 => None,
+        1 => predicates.pop(),
+        _ => Some(Predicate::And(predicates)),
+    }
+}
+
+fn collect_and_conjuncts<'a>(expr: &'a Expr, conjuncts: &mut Vec<&'a Expr>) {
+    if let Expr::Binary {
+        op: BinaryOp::And,
+        left,
+        right,
+    } = expr
+    {
+        collect_and_conjuncts(left, conjuncts);
+        collect_and_conjuncts(right, conjuncts);
+    } else {
+        conjuncts.push(expr);
+    }
+}
+
 // Combine independently-derived predicate subsets while preserving the old
 // append behavior that left final query predicate normalization to planning.
 fn combine_predicate_subset(
@@ -230,6 +269,7 @@ pub(in crate::db::query::intent) struct ScalarIntent {
     pub(in crate::db::query::intent) filter: Option<NormalizedFilter>,
     pub(in crate::db::query::intent) order: Option<OrderSpec>,
     pub(in crate::db::query::intent) distinct: bool,
+    pub(in crate::db::query::intent) partition_limit: Option<PartitionLimitSpec>,
     pub(in crate::db::query::intent) projection_selection: ProjectionSelection,
 }
 
@@ -240,6 +280,7 @@ impl ScalarIntent {
             filter: None,
             order: None,
             distinct: false,
+            partition_limit: None,
             projection_selection: ProjectionSelection::All,
         }
     }
@@ -521,6 +562,34 @@ impl QueryIntent {
             None => (None, None),
         };
 
+        // Computed grouped keys are referenced by label downstream, so bind
+        // ORDER BY and HAVING occurrences of the key expression onto that label.
+        let computed_keys = group.as_ref().map_or_else(Vec::new, |group| {
+            group
+                .group_fields
+                .iter()
+                .filter_map(|field| field.computed_expr().cloned())
+                .collect::<Vec<_>>()
+        });
+        let mut order = self.scalar().order.clone();
+        let mut having_expr = having_expr;
+        if !computed_keys.is_empty() {
+            if let Some(order) = order.as_mut() {
+                order.fields = std::mem::take(&mut order.fields)
+                    .into_iter()
+                    .map(|term| {
+                        OrderTerm::new(
+                            bind_computed_group_key_refs(term.expr, &computed_keys),
+                            term.direction,
+                        )
+                        .with_nulls(term.nulls)
+                    })
+                    .collect();
+            }
+            having_expr =
+                having_expr.map(|expr| bind_computed_group_key_refs(expr, &computed_keys));
+        }
+
         LogicalPlanningInputs::new(
             self.mode(),
             self.scalar()
@@ -532,11 +601,12 @@ impl QueryIntent {
                 .filter
                 .as_ref()
                 .is_some_and(NormalizedFilter::predicate_subset_covers_expr),
-            self.scalar().order.clone(),
+            order,
             self.scalar().distinct,
             group,
             having_expr,
         )
+        .with_partition_limit(self.scalar().partition_limit.clone())
     }
 }
 
@@ -186,6 +186,47 @@ fn append_extractable_predicate_to_unextractable_expr_marks_partial_coverage() {
     );
 }
 
+#[test]
+fn mixed_conjunction_expr_derives_partial_predicate_subset() {
+    let name = Expr::Field(FieldId::new("name"));
+    let expr = normalize_bool_expr(Expr::Binary {
+        op: BinaryOp::And,
+        left: Box::new(Expr::FunctionCall {
+            function: Function::StartsWith,
+            args: vec![name.clone(), Expr::Literal(Value::Text("al".to_string()))],
+        }),
+        right: Box::new(Expr::FunctionCall {
+            function: Function::Like,
+            args: vec![name, Expr::Literal(Value::Text("al%e".to_string()))],
+        }),
+    });
+    let mut intent = QueryIntent::new();
+    intent.append_filter_expr(expr);
+
+    let filter = intent
+        .scalar()
+        .filter
+        .as_ref()
+        .expect("expression append should create one scalar filter");
+
+    assert_eq!(
+        filter.predicate_coverage(),
+        FilterPredicateCoverage::Partial,
+        "only the extractable conjunct should be predicate-backed",
+    );
+    assert!(
+        matches!(
+            filter.predicate_subset(),
+            Some(Predicate::Compare(compare)) if compare.field() == "name"
+        ),
+        "the extractable conjunct should still feed access planning",
+    );
+    assert!(
+        !filter.predicate_subset_covers_expr(),
+        "the residual conjunct must keep the expression filter visible",
+    );
+}
+
 #[test]
 fn push_order_terms_preserve_declared_order_sequence() {
     let mut intent = QueryIntent::new();
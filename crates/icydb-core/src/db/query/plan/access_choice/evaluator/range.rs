@@ -280,6 +280,9 @@ fn evaluate_ordered_range_compare_candidate(
                 return Err(AccessChoiceRejectedReason::OperatorNotRangeSupported);
             }
         }
+        SemanticIndexKeyItemRef::Scalar(_) => {
+            return Err(AccessChoiceRejectedReason::LeadingFieldMismatch);
+        }
     }
 
     if index_contract.key_arity() != 1 {
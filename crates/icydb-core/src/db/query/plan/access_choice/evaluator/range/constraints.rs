@@ -86,6 +86,7 @@ pub(super) fn classify_range_constraints_for_key_item(
                             continue;
                         }
                     }
+                    SemanticIndexKeyItemRef::Scalar(_) => continue,
                 }
                 if constraint.eq_value.is_some() {
                     return Err(AccessChoiceRejectedReason::EqRangeConflict);
@@ -608,6 +608,7 @@ fn count_predicate_terms(predicate: &Predicate) -> usize {
         | Predicate::IsEmpty { .. }
         | Predicate::IsNotEmpty { .. }
         | Predicate::TextContains { .. }
-        | Predicate::TextContainsCi { .. } => 1,
+        | Predicate::TextContainsCi { .. }
+        | Predicate::TextMatch { .. } => 1,
     }
 }
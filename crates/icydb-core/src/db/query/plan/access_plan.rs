@@ -15,7 +15,7 @@ use crate::db::{
             project_access_choice_explain_snapshot_with_semantic_indexes_and_schema,
         },
         expr::{CompiledExpr, Expr, ProjectionSelection, ProjectionSpec},
-        model::OrderDirection,
+        model::{NullsOrder, OrderDirection},
     },
     schema::SchemaInfo,
 };
@@ -87,13 +87,14 @@ impl ResolvedOrderValueSource {
 /// ResolvedOrderField
 ///
 /// ResolvedOrderField freezes one planner-validated ORDER BY term.
-/// Each field already carries its structural row source and final direction,
-/// so executor ordering paths can stay purely consumptive.
+/// Each field already carries its structural row source, final direction, and
+/// null placement, so executor ordering paths can stay purely consumptive.
 ///
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(in crate::db) struct ResolvedOrderField {
     source: ResolvedOrderValueSource,
     direction: OrderDirection,
+    nulls: NullsOrder,
 }
 
 impl ResolvedOrderField {
@@ -102,8 +103,13 @@ impl ResolvedOrderField {
     pub(in crate::db) const fn new(
         source: ResolvedOrderValueSource,
         direction: OrderDirection,
+        nulls: NullsOrder,
     ) -> Self {
-        Self { source, direction }
+        Self {
+            source,
+            direction,
+            nulls,
+        }
     }
 
     /// Borrow the planner-resolved structural row source.
@@ -117,6 +123,12 @@ impl ResolvedOrderField {
     pub(in crate::db) const fn direction(&self) -> OrderDirection {
         self.direction
     }
+
+    /// Return the final null placement for this order term.
+    #[must_use]
+    pub(in crate::db) const fn nulls(&self) -> NullsOrder {
+        self.nulls
+    }
 }
 
 ///
@@ -598,7 +610,9 @@ fn predicate_contains_text_scan_operator(predicate: &Predicate) -> bool {
 fn predicate_is_text_scan_operator(predicate: &Predicate) -> bool {
     match predicate {
         Predicate::Compare(compare) if compare.op() == CompareOp::EndsWith => true,
-        Predicate::TextContains { .. } | Predicate::TextContainsCi { .. } => true,
+        Predicate::TextContains { .. }
+        | Predicate::TextContainsCi { .. }
+        | Predicate::TextMatch { .. } => true,
         _ => false,
     }
 }
@@ -732,6 +746,7 @@ impl AccessPlannedQuery {
             distinct: false,
             delete_limit: None,
             page: None,
+            partition_limit: None,
             consistency,
         });
 
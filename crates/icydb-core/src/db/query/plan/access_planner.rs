@@ -10,7 +10,7 @@ use crate::{
         predicate::{Predicate, normalize, normalize_enum_literals},
         query::plan::{
             OrderSpec, PlannedAccessSelection, PlannerError, VisibleIndexes,
-            canonicalize_order_spec_for_grouping,
+            canonicalize_order_spec_for_grouping, expr::Expr,
             plan_access_selection_with_order_and_accepted_semantic_indexes,
         },
         schema::{SchemaInfo, ValidateError},
@@ -78,6 +78,7 @@ pub(in crate::db::query) fn plan_query_access_with_accepted_schema(
     visible_indexes: &VisibleIndexes,
     schema_info: &SchemaInfo,
     normalized_predicate: Option<&Predicate>,
+    filter_expr: Option<&Expr>,
     order: Option<&OrderSpec>,
     grouped: bool,
     key_access_override: Option<AccessPlan<Value>>,
@@ -96,6 +97,7 @@ pub(in crate::db::query) fn plan_query_access_with_accepted_schema(
         visible_indexes.accepted_field_path_indexes(),
         schema_info,
         normalized_predicate,
+        filter_expr,
         canonical_order.as_ref(),
         grouped,
     )
@@ -3,11 +3,152 @@
 //! Does not own: aggregate semantic equality, validation, or executor state.
 //! Boundary: builder and logical-plan wrappers choose their own equality over this raw shape.
 
-use crate::db::query::plan::{
-    expr::{Expr, FieldId, canonicalize_aggregate_input_expr},
-    model::AggregateKind,
+use crate::{
+    db::query::plan::{
+        expr::{Expr, FieldId, canonicalize_aggregate_input_expr},
+        model::{AggregateKind, NullsOrder, OrderDirection, OrderTerm},
+    },
+    types::Decimal,
 };
 
+///
+/// AggregateArguments
+///
+/// Non-input arguments carried by retained-value aggregates.
+/// `PERCENTILE_DISC` orders by its own input, so only the direction is kept;
+/// `STRING_AGG` and `ARRAY_AGG` carry independent aggregate-local ORDER BY
+/// terms that break arrival-order ties deterministically.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(in crate::db) enum AggregateArguments {
+    Percentile {
+        fraction: Decimal,
+        direction: OrderDirection,
+    },
+    StringAgg {
+        separator: String,
+        order_by: Vec<OrderTerm>,
+    },
+    ArrayAgg {
+        order_by: Vec<OrderTerm>,
+    },
+}
+
+impl AggregateArguments {
+    /// Borrow the aggregate-local ORDER BY terms evaluated beside the input.
+    #[must_use]
+    pub(in crate::db) const fn order_by(&self) -> &[OrderTerm] {
+        match self {
+            Self::Percentile { .. } => &[],
+            Self::StringAgg { order_by, .. } | Self::ArrayAgg { order_by } => order_by.as_slice(),
+        }
+    }
+
+    /// Return whether these arguments belong to one aggregate kind.
+    #[must_use]
+    pub(in crate::db) const fn matches_kind(&self, kind: AggregateKind) -> bool {
+        matches!(
+            (self, kind),
+            (Self::Percentile { .. }, AggregateKind::PercentileDisc)
+                | (Self::StringAgg { .. }, AggregateKind::StringAgg)
+                | (Self::ArrayAgg { .. }, AggregateKind::ArrayAgg)
+        )
+    }
+
+    /// Render the arguments alone for explain and fingerprint surfaces that
+    /// already carry the aggregate input label separately.
+    #[must_use]
+    pub(in crate::db) fn render_label(&self) -> String {
+        match self {
+            Self::Percentile {
+                fraction,
+                direction,
+            } => match direction {
+                OrderDirection::Asc => format!("{fraction}"),
+                OrderDirection::Desc => format!("{fraction} DESC"),
+            },
+            Self::StringAgg {
+                separator,
+                order_by,
+            } => format!(
+                "'{}'{}",
+                separator.replace('\'', "''"),
+                render_order_by_suffix(order_by),
+            ),
+            Self::ArrayAgg { order_by } => {
+                render_order_by_suffix(order_by).trim_start().to_string()
+            }
+        }
+    }
+
+    /// Render one aggregate call label around an already-rendered input label.
+    #[must_use]
+    pub(in crate::db) fn render_call_label(
+        &self,
+        kind: AggregateKind,
+        input_label: &str,
+    ) -> String {
+        let kind_label = kind.canonical_label();
+        match self {
+            Self::Percentile {
+                fraction,
+                direction,
+            } => {
+                let direction = match direction {
+                    OrderDirection::Asc => "",
+                    OrderDirection::Desc => " DESC",
+                };
+
+                format!("{kind_label}({fraction}) WITHIN GROUP (ORDER BY {input_label}{direction})")
+            }
+            Self::StringAgg {
+                separator,
+                order_by,
+            } => format!(
+                "{kind_label}({input_label}, '{}'{})",
+                separator.replace('\'', "''"),
+                render_order_by_suffix(order_by),
+            ),
+            Self::ArrayAgg { order_by } => {
+                format!(
+                    "{kind_label}({input_label}{})",
+                    render_order_by_suffix(order_by)
+                )
+            }
+        }
+    }
+}
+
+// Render the optional aggregate-local ORDER BY suffix with the same term
+// labels used by top-level ORDER BY diagnostics.
+fn render_order_by_suffix(order_by: &[OrderTerm]) -> String {
+    if order_by.is_empty() {
+        return String::new();
+    }
+
+    let terms = order_by
+        .iter()
+        .map(|term| {
+            let mut label = term.rendered_label();
+            if term.direction() == OrderDirection::Desc {
+                label.push_str(" DESC");
+            }
+            if term.overrides_natural_nulls() {
+                label.push_str(match term.nulls() {
+                    NullsOrder::First => " NULLS FIRST",
+                    NullsOrder::Last => " NULLS LAST",
+                });
+            }
+
+            label
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(" ORDER BY {terms}")
+}
+
 /// Raw aggregate declaration fields shared by builder and logical-plan wrappers.
 ///
 /// Equality on this type is deliberately structural. Semantic aggregate
@@ -17,6 +158,7 @@ pub(in crate::db) struct AggregateShape {
     kind: AggregateKind,
     input_expr: Option<Box<Expr>>,
     filter_expr: Option<Box<Expr>>,
+    arguments: Option<Box<AggregateArguments>>,
     distinct: bool,
 }
 
@@ -28,6 +170,7 @@ impl AggregateShape {
             kind,
             input_expr: None,
             filter_expr: None,
+            arguments: None,
             distinct: false,
         }
     }
@@ -39,6 +182,7 @@ impl AggregateShape {
             kind,
             input_expr: Some(Box::new(Expr::Field(FieldId::new(field)))),
             filter_expr: None,
+            arguments: None,
             distinct: false,
         }
     }
@@ -52,6 +196,7 @@ impl AggregateShape {
                 kind, input_expr,
             ))),
             filter_expr: None,
+            arguments: None,
             distinct: false,
         }
     }
@@ -67,6 +212,7 @@ impl AggregateShape {
             kind,
             input_expr: target_field.map(|field| Box::new(Expr::Field(FieldId::new(field)))),
             filter_expr: None,
+            arguments: None,
             distinct,
         }
     }
@@ -78,6 +224,13 @@ impl AggregateShape {
         self
     }
 
+    /// Attach the non-input arguments of one retained-value aggregate.
+    #[must_use]
+    pub(in crate::db) fn with_arguments(mut self, arguments: AggregateArguments) -> Self {
+        self.arguments = Some(Box::new(arguments));
+        self
+    }
+
     /// Replace the raw authored DISTINCT bit without applying semantic normalization.
     #[must_use]
     pub(in crate::db) const fn with_raw_distinct(mut self, distinct: bool) -> Self {
@@ -108,6 +261,12 @@ impl AggregateShape {
         self.filter_expr.as_deref()
     }
 
+    /// Borrow the retained-value aggregate arguments, if present.
+    #[must_use]
+    pub(in crate::db) fn arguments(&self) -> Option<&AggregateArguments> {
+        self.arguments.as_deref()
+    }
+
     /// Return the raw authored DISTINCT bit.
     #[must_use]
     pub(in crate::db) const fn raw_distinct(&self) -> bool {
@@ -14,7 +14,7 @@ use crate::db::{
         AccessPlannedQuery, DeterministicSecondaryIndexOrderMatch, FieldSlot, OrderDirection,
         OrderSpec,
         expr::{Expr, FieldId, Function, ProjectionSelection, ProjectionSpec},
-        index_key_item_order_terms,
+        index_order_terms,
     },
     schema::SchemaInfo,
 };
@@ -381,6 +381,7 @@ fn primary_store_covering_plan(
     if plan.grouped_plan().is_some()
         || !plan.scalar_plan().mode.is_load()
         || plan.scalar_plan().distinct
+        || plan.scalar_plan().partition_limit.is_some()
         || plan.has_any_residual_filter()
     {
         return None;
@@ -488,7 +489,7 @@ struct IndexCoveringAccessFacts<'a> {
 fn index_covering_access_facts<K>(access: &AccessPlan<K>) -> Option<IndexCoveringAccessFacts<'_>> {
     if let Some((index, values)) = access.as_index_prefix_contract_path() {
         return Some(IndexCoveringAccessFacts {
-            order_terms: index_key_item_order_terms(index.key_items()),
+            order_terms: index_order_terms(&index),
             coverable_component_fields: coverable_component_fields_for_contract(&index),
             coverable_component_exprs: coverable_component_exprs_for_contract(&index),
             prefix_values: values,
@@ -499,7 +500,7 @@ fn index_covering_access_facts<K>(access: &AccessPlan<K>) -> Option<IndexCoverin
     }
     if let Some((index, _values)) = access.as_index_multi_lookup_contract_path() {
         return Some(IndexCoveringAccessFacts {
-            order_terms: index_key_item_order_terms(index.key_items()),
+            order_terms: index_order_terms(&index),
             coverable_component_fields: coverable_component_fields_for_contract(&index),
             coverable_component_exprs: coverable_component_exprs_for_contract(&index),
             prefix_values: &[],
@@ -511,7 +512,7 @@ fn index_covering_access_facts<K>(access: &AccessPlan<K>) -> Option<IndexCoverin
     if let Some(spec) = access.as_index_branch_set_spec_path() {
         let index = spec.index();
         return (!spec.is_empty()).then(|| IndexCoveringAccessFacts {
-            order_terms: index_key_item_order_terms(index.key_items()),
+            order_terms: index_order_terms(&index),
             coverable_component_fields: coverable_component_fields_for_contract(&index),
             coverable_component_exprs: coverable_component_exprs_for_contract(&index),
             prefix_values: spec.fixed_values(),
@@ -523,7 +524,7 @@ fn index_covering_access_facts<K>(access: &AccessPlan<K>) -> Option<IndexCoverin
     if let Some(spec) = access.as_index_range_path() {
         let index = spec.index();
         return Some(IndexCoveringAccessFacts {
-            order_terms: index_key_item_order_terms(index.key_items()),
+            order_terms: index_order_terms(&index),
             coverable_component_fields: coverable_component_fields_for_contract(&index),
             coverable_component_exprs: coverable_component_exprs_for_contract(&index),
             prefix_values: spec.prefix_values(),
@@ -587,6 +588,11 @@ fn covering_index_projection_plan(
 ) -> Option<CoveringReadPlan> {
     // Phase 1: reject unsupported plan shapes and freeze the shared
     // index-backed covering contract once for the whole projection.
+    // Per-partition limits must observe every row through the partition
+    // window operator, which covering reads bypass.
+    if plan.scalar_plan().partition_limit.is_some() {
+        return None;
+    }
     if matches!(
         source_policy,
         CoveringProjectionFieldSourcePolicy::HybridRowFallback
@@ -842,7 +848,8 @@ fn primary_key_names_from_schema(schema: &SchemaInfo) -> Option<Vec<&str>> {
 // Project one component-field layout that preserves only directly recoverable
 // raw entity fields. Expression key items intentionally map to `None` here so
 // covering reads do not claim the original field can be reconstructed from the
-// derived component bytes.
+// derived component bytes. `INCLUDE` payload fields follow the key items and
+// are addressed by the same component index space.
 fn coverable_component_fields_for_contract(
     index: &SemanticIndexAccessContract,
 ) -> Vec<Option<String>> {
@@ -851,8 +858,11 @@ fn coverable_component_fields_for_contract(
         .iter()
         .map(|item| match item.as_ref() {
             SemanticIndexKeyItemRef::Field(field) => Some(field.to_string()),
-            SemanticIndexKeyItemRef::AcceptedExpression(_) => None,
+            SemanticIndexKeyItemRef::AcceptedExpression(_) | SemanticIndexKeyItemRef::Scalar(_) => {
+                None
+            }
         })
+        .chain(index.include().iter().cloned().map(Some))
         .collect()
 }
 
@@ -866,11 +876,12 @@ fn coverable_component_exprs_for_contract(
         .key_items()
         .iter()
         .map(|item| match item.as_ref() {
-            SemanticIndexKeyItemRef::Field(_) => None,
+            SemanticIndexKeyItemRef::Field(_) | SemanticIndexKeyItemRef::Scalar(_) => None,
             SemanticIndexKeyItemRef::AcceptedExpression(expression) => {
                 expression_projection_expr_for_accepted_index_expression(expression)
             }
         })
+        .chain(index.include().iter().map(|_| None))
         .collect()
 }
 
@@ -902,7 +913,9 @@ fn expression_projection_expr_for_accepted_index_expression(
         PersistedIndexExpressionOp::Date
         | PersistedIndexExpressionOp::Year
         | PersistedIndexExpressionOp::Month
-        | PersistedIndexExpressionOp::Day => None,
+        | PersistedIndexExpressionOp::Day
+        | PersistedIndexExpressionOp::Tokens
+        | PersistedIndexExpressionOp::Elements => None,
     }
 }
 
@@ -8,7 +8,9 @@ use crate::{
         numeric::{NumericArithmeticOp, apply_numeric_arithmetic_checked, coerce_numeric_decimal},
         query::plan::{
             AggregateKind,
-            expr::{AggregateInputConstantFoldShape, BinaryOp, Expr, Function},
+            expr::{
+                AggregateInputConstantFoldShape, BinaryOp, Expr, Function, eval_temporal_arithmetic,
+            },
         },
     },
     value::Value,
@@ -34,7 +36,14 @@ pub(in crate::db) fn canonicalize_aggregate_input_expr(kind: AggregateKind, expr
         | AggregateKind::Max
         | AggregateKind::Exists
         | AggregateKind::First
-        | AggregateKind::Last => folded,
+        | AggregateKind::Last
+        | AggregateKind::VarPop
+        | AggregateKind::VarSamp
+        | AggregateKind::StddevPop
+        | AggregateKind::StddevSamp
+        | AggregateKind::PercentileDisc
+        | AggregateKind::StringAgg
+        | AggregateKind::ArrayAgg => folded,
     }
 }
 
@@ -106,6 +115,9 @@ fn fold_aggregate_input_constant_binary(op: BinaryOp, left: &Expr, right: &Expr)
     if matches!(left, Value::Null) || matches!(right, Value::Null) {
         return Some(Expr::Literal(Value::Null));
     }
+    if let Some(result) = eval_temporal_arithmetic(op, left, right) {
+        return result.ok().map(Expr::Literal);
+    }
 
     let arithmetic_op = match op {
         BinaryOp::Or
@@ -221,14 +221,19 @@ impl BinaryOp {
 #[remain::sorted]
 pub(in crate::db) enum Function {
     Abs,
+    Age,
+    Cast,
     Cbrt,
     Ceiling,
     Coalesce,
     CollectionContains,
     Contains,
+    DateTrunc,
     EndsWith,
     Exp,
+    Extract,
     Floor,
+    HasKey,
     InList,
     IsEmpty,
     IsMissing,
@@ -237,17 +242,21 @@ pub(in crate::db) enum Function {
     IsNull,
     Left,
     Length,
+    Like,
     Ln,
     Log,
     Log2,
     Log10,
     Lower,
     Ltrim,
+    Match,
     Mod,
+    Now,
     NullIf,
     OctetLength,
     Position,
     Power,
+    RegexpMatches,
     Replace,
     Right,
     Round,
@@ -267,14 +276,19 @@ impl Function {
     pub(in crate::db) const fn canonical_label(self) -> &'static str {
         match self {
             Self::Abs => "ABS",
+            Self::Age => "AGE",
+            Self::Cast => "CAST",
             Self::Cbrt => "CBRT",
             Self::Ceiling => "CEILING",
             Self::Coalesce => "COALESCE",
             Self::CollectionContains => "COLLECTION_CONTAINS",
             Self::Contains => "CONTAINS",
+            Self::DateTrunc => "DATE_TRUNC",
             Self::EndsWith => "ENDS_WITH",
             Self::Exp => "EXP",
+            Self::Extract => "EXTRACT",
             Self::Floor => "FLOOR",
+            Self::HasKey => "HAS_KEY",
             Self::InList => "IN_LIST",
             Self::IsEmpty => "IS_EMPTY",
             Self::IsMissing => "IS_MISSING",
@@ -283,17 +297,21 @@ impl Function {
             Self::IsNull => "IS_NULL",
             Self::Left => "LEFT",
             Self::Length => "LENGTH",
+            Self::Like => "LIKE",
             Self::Ln => "LN",
             Self::Log => "LOG",
             Self::Log10 => "LOG10",
             Self::Log2 => "LOG2",
             Self::Lower => "LOWER",
             Self::Ltrim => "LTRIM",
+            Self::Match => "MATCH",
             Self::Mod => "MOD",
+            Self::Now => "NOW",
             Self::NullIf => "NULLIF",
             Self::OctetLength => "OCTET_LENGTH",
             Self::Position => "POSITION",
             Self::Power => "POWER",
+            Self::RegexpMatches => "REGEXP_MATCHES",
             Self::Replace => "REPLACE",
             Self::Round => "ROUND",
             Self::Right => "RIGHT",
@@ -26,9 +26,9 @@ use crate::db::query::plan::expr::canonicalize::{
     case::canonicalize_normalized_bool_case_in_bool_context, truth_admission::TruthWrapperScope,
 };
 
-pub(in crate::db) use normalize::is_normalized_bool_expr;
 #[cfg(feature = "sql")]
 pub(in crate::db) use normalize::simplify_bool_expr_constants;
+pub(in crate::db) use normalize::{is_normalized_bool_expr, normalize_scalar_operand_expr};
 #[cfg(feature = "sql")]
 pub(in crate::db) use truth_admission::scalar_where_truth_condition_is_admitted;
 pub(in crate::db) use truth_admission::truth_condition_binary_compare_op;
@@ -288,6 +288,15 @@ fn normalize_bool_compare_expr(op: BinaryOp, left: Expr, right: Expr) -> Expr {
     }
 }
 
+/// Normalize one scalar comparison operand outside of a boolean context.
+///
+/// Expression-index fingerprints use this so the stored key expression and a
+/// normalized `WHERE` operand render to the same canonical text.
+#[must_use]
+pub(in crate::db) fn normalize_scalar_operand_expr(expr: Expr) -> Expr {
+    normalize_bool_compare_operand(expr)
+}
+
 fn normalize_bool_compare_operand(expr: Expr) -> Expr {
     match expr {
         Expr::FunctionCall { function, args } => Expr::FunctionCall {
@@ -340,7 +349,8 @@ fn normalize_bool_function_call(function: Function, args: Vec<Expr>) -> Expr {
         Some(
             BooleanFunctionShape::NullTest
             | BooleanFunctionShape::FieldPredicate
-            | BooleanFunctionShape::CollectionContains,
+            | BooleanFunctionShape::CollectionContains
+            | BooleanFunctionShape::MapHasKey,
         )
         | None => Expr::FunctionCall { function, args },
         Some(BooleanFunctionShape::Membership) => match <[Expr; 2]>::try_from(args) {
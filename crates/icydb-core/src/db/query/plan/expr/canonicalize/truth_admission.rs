@@ -170,6 +170,7 @@ impl TruthAdmission {
                 Some(
                     BooleanFunctionShape::NullTest
                     | BooleanFunctionShape::FieldPredicate
+                    | BooleanFunctionShape::MapHasKey
                     | BooleanFunctionShape::Membership
                     | BooleanFunctionShape::TextPredicate,
                 ) => true,
@@ -246,6 +247,9 @@ pub(in crate::db::query::plan::expr::canonicalize) fn bool_function_args_match(
         Some(BooleanFunctionShape::CollectionContains) => {
             matches!(args, [Expr::Field(_), Expr::Literal(_)])
         }
+        Some(BooleanFunctionShape::MapHasKey) => {
+            matches!(args, [target, Expr::Literal(Value::Text(_))] if compare_arg(target))
+        }
         Some(BooleanFunctionShape::Membership) => {
             matches!(args, [target, Expr::Literal(Value::List(_))] if compare_arg(target))
         }
@@ -11,8 +11,8 @@ use crate::{
         plan::{
             FieldSlot, GroupedAggregateExecutionSpec,
             expr::{
-                BinaryOp, CompiledExpr, CompiledExprCaseArm, Expr, ProjectionEvalError,
-                ProjectionSpec, ScalarProjectionCaseArm, ScalarProjectionExpr,
+                BinaryOp, CompiledExpr, CompiledExprCaseArm, Expr, Function, ProjectionEvalError,
+                ProjectionSpec, ScalarProjectionCaseArm, ScalarProjectionExpr, TextPattern,
             },
         },
     },
@@ -40,14 +40,10 @@ impl CompiledExpr {
                     .into_boxed_slice(),
             },
             ScalarProjectionExpr::Literal(value) => Self::Literal(value.clone()),
-            ScalarProjectionExpr::FunctionCall { function, args } => Self::FunctionCall {
-                function: *function,
-                args: args
-                    .iter()
-                    .map(Self::compile)
-                    .collect::<Vec<_>>()
-                    .into_boxed_slice(),
-            },
+            ScalarProjectionExpr::FunctionCall { function, args } => Self::compile_function_call(
+                *function,
+                args.iter().map(Self::compile).collect::<Vec<_>>(),
+            ),
             ScalarProjectionExpr::Unary { op, expr } => Self::Unary {
                 op: *op,
                 expr: Box::new(Self::compile(expr)),
@@ -127,6 +123,27 @@ impl CompiledExpr {
         }
     }
 
+    // Precompile LIKE / regular-expression calls whose pattern is one text
+    // literal, so row evaluation never re-parses the pattern. Patterns that
+    // fail to compile keep the generic call and fail on first evaluation.
+    pub(super) fn compile_function_call(function: Function, args: Vec<Self>) -> Self {
+        if let Some(syntax) = function.text_pattern_syntax()
+            && let [input, Self::Literal(Value::Text(pattern))] = args.as_slice()
+            && let Ok(pattern) = TextPattern::compile(syntax, pattern)
+        {
+            return Self::TextPatternMatch {
+                function,
+                input: Box::new(input.clone()),
+                pattern: Box::new(pattern),
+            };
+        }
+
+        Self::FunctionCall {
+            function,
+            args: args.into_boxed_slice(),
+        }
+    }
+
     // Collapse direct slot arithmetic into dedicated variants. These are the
     // grouped aggregate input shapes that previously paid a full expression
     // traversal for every row.
@@ -312,16 +329,14 @@ pub(in crate::db) fn compile_grouped_projection_expr(
             Ok(CompiledExpr::Aggregate { index })
         }
         Expr::Literal(value) => Ok(CompiledExpr::Literal(value.clone())),
-        Expr::FunctionCall { function, args } => Ok(CompiledExpr::FunctionCall {
-            function: *function,
-            args: args
-                .iter()
+        Expr::FunctionCall { function, args } => Ok(CompiledExpr::compile_function_call(
+            *function,
+            args.iter()
                 .map(|arg| {
                     compile_grouped_projection_expr(arg, group_fields, aggregate_execution_specs)
                 })
-                .collect::<Result<Vec<_>, _>>()?
-                .into_boxed_slice(),
-        }),
+                .collect::<Result<Vec<_>, _>>()?,
+        )),
         Expr::Case {
             when_then_arms,
             else_expr,
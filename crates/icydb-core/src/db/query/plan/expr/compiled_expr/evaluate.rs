@@ -8,9 +8,10 @@ use crate::{
         numeric::NumericEvalError,
         query::plan::expr::{
             BinaryOp, CompiledExpr, CompiledExprCaseArm, CompiledExprValueReader, Function,
-            ProjectionEvalError, ProjectionFunctionEvalError, UnaryOp,
+            ProjectionEvalError, ProjectionFunctionEvalError, TextPattern, UnaryOp,
             admit_true_only_boolean_value, collapse_true_only_boolean_admission,
             compiled_expr::missing_field_value, eval_projection_function_call_checked,
+            eval_temporal_arithmetic, eval_text_pattern_match,
         },
     },
     value::{
@@ -18,6 +19,7 @@ use crate::{
         ops::{numeric as value_numeric, ordering as value_ordering},
     },
 };
+use icydb_diagnostic_code::QueryProjectionCode;
 use std::borrow::Cow;
 
 impl CompiledExpr {
@@ -191,6 +193,11 @@ impl CompiledExpr {
             Self::FunctionCall { function, args } => {
                 Self::evaluate_function_call(reader, *function, args)
             }
+            Self::TextPatternMatch {
+                function,
+                input,
+                pattern,
+            } => Self::evaluate_text_pattern_match(reader, *function, input, pattern),
             Self::Unary { op, expr } => {
                 let value = expr.evaluate(reader)?;
 
@@ -209,6 +216,25 @@ impl CompiledExpr {
         }
     }
 
+    // Match one precompiled text pattern. NULL input stays NULL and non-text
+    // input reports the same projection error as the generic call path.
+    fn evaluate_text_pattern_match<'row>(
+        reader: &'row dyn CompiledExprValueReader,
+        function: Function,
+        input: &'row Self,
+        pattern: &TextPattern,
+    ) -> Result<Cow<'row, Value>, ProjectionEvalError> {
+        match input.evaluate(reader)?.as_ref() {
+            Value::Null => Ok(Cow::Owned(Value::Null)),
+            Value::Text(text) => eval_text_pattern_match(pattern, text)
+                .map(Cow::Owned)
+                .map_err(|err| map_grouped_function_error(function, 2, err)),
+            _ => Err(ProjectionEvalError::invalid_projection(
+                QueryProjectionCode::TextInputRequired,
+            )),
+        }
+    }
+
     // Resolve one required slot through row-view storage without constructing
     // a caller closure or walking another expression node.
     fn evaluate_slot<'row>(
@@ -482,15 +508,27 @@ fn eval_grouped_function_call(
     function: Function,
     args: &[Value],
 ) -> Result<Value, ProjectionEvalError> {
-    eval_projection_function_call_checked(function, args).map_err(|err| match err {
+    eval_projection_function_call_checked(function, args)
+        .map_err(|err| map_grouped_function_error(function, args.len(), err))
+}
+
+fn map_grouped_function_error(
+    function: Function,
+    argument_count: usize,
+    err: ProjectionFunctionEvalError,
+) -> ProjectionEvalError {
+    match err {
         ProjectionFunctionEvalError::Numeric(err) => ProjectionEvalError::Numeric(err),
+        ProjectionFunctionEvalError::ValueRejected(reason) => {
+            ProjectionEvalError::ValueRejected { reason }
+        }
         ProjectionFunctionEvalError::Query(err) => {
             ProjectionFunctionEvalError::query_projection_reason(&err).map_or_else(
-                || ProjectionEvalError::invalid_function_call(function, args.len()),
+                || ProjectionEvalError::invalid_function_call(function, argument_count),
                 ProjectionEvalError::invalid_projection,
             )
         }
-    })
+    }
 }
 
 const fn evaluate_unary_expr(op: UnaryOp, value: &Value) -> Result<Value, ProjectionEvalError> {
@@ -570,6 +608,9 @@ fn evaluate_numeric_binary_expr(
     if matches!(left, Value::Null) || matches!(right, Value::Null) {
         return Ok(Value::Null);
     }
+    if let Some(result) = eval_temporal_arithmetic(op, left, right) {
+        return result.map_err(ProjectionEvalError::Numeric);
+    }
 
     let result = match op {
         BinaryOp::Add => value_numeric::add(left, right),
@@ -21,7 +21,7 @@ use crate::{
         numeric::NumericEvalError,
         query::plan::{
             AggregateKind,
-            expr::{BinaryOp, Function, UnaryOp},
+            expr::{BinaryOp, Function, TextPattern, UnaryOp},
         },
     },
     error::{ErrorClass, ErrorOrigin, InternalError},
@@ -164,13 +164,16 @@ pub(in crate::db) struct ProjectionFunctionCode(u8);
 
 impl ProjectionFunctionCode {
     const ABS: Self = Self(0);
+    const AGE: Self = Self(40);
     const CBRT: Self = Self(1);
     const CEILING: Self = Self(2);
     const COALESCE: Self = Self(3);
     const COLLECTION_CONTAINS: Self = Self(4);
     const CONTAINS: Self = Self(5);
+    const DATE_TRUNC: Self = Self(41);
     const ENDS_WITH: Self = Self(6);
     const EXP: Self = Self(7);
+    const EXTRACT: Self = Self(42);
     const FLOOR: Self = Self(8);
     const IN_LIST: Self = Self(38);
     const IS_EMPTY: Self = Self(9);
@@ -187,6 +190,11 @@ impl ProjectionFunctionCode {
     const LOWER: Self = Self(20);
     const LTRIM: Self = Self(21);
     const MOD: Self = Self(22);
+    const NOW: Self = Self(43);
+    const LIKE: Self = Self(44);
+    const REGEXP_MATCHES: Self = Self(45);
+    const MATCH: Self = Self(46);
+    const HAS_KEY: Self = Self(47);
     const NULLIF: Self = Self(23);
     const OCTET_LENGTH: Self = Self(24);
     const POSITION: Self = Self(25);
@@ -202,17 +210,22 @@ impl ProjectionFunctionCode {
     const TRIM: Self = Self(35);
     const TRUNC: Self = Self(36);
     const UPPER: Self = Self(37);
+    const CAST: Self = Self(39);
 
     const fn from_function(function: Function) -> Self {
         match function {
             Function::Abs => Self::ABS,
+            Function::Age => Self::AGE,
+            Function::Cast => Self::CAST,
             Function::Cbrt => Self::CBRT,
             Function::Ceiling => Self::CEILING,
             Function::Coalesce => Self::COALESCE,
             Function::CollectionContains => Self::COLLECTION_CONTAINS,
             Function::Contains => Self::CONTAINS,
+            Function::DateTrunc => Self::DATE_TRUNC,
             Function::EndsWith => Self::ENDS_WITH,
             Function::Exp => Self::EXP,
+            Function::Extract => Self::EXTRACT,
             Function::Floor => Self::FLOOR,
             Function::InList => Self::IN_LIST,
             Function::IsEmpty => Self::IS_EMPTY,
@@ -222,17 +235,22 @@ impl ProjectionFunctionCode {
             Function::IsNull => Self::IS_NULL,
             Function::Left => Self::LEFT,
             Function::Length => Self::LENGTH,
+            Function::Like => Self::LIKE,
             Function::Ln => Self::LN,
             Function::Log => Self::LOG,
             Function::Log2 => Self::LOG2,
             Function::Log10 => Self::LOG10,
             Function::Lower => Self::LOWER,
             Function::Ltrim => Self::LTRIM,
+            Function::Match => Self::MATCH,
+            Function::HasKey => Self::HAS_KEY,
             Function::Mod => Self::MOD,
+            Function::Now => Self::NOW,
             Function::NullIf => Self::NULLIF,
             Function::OctetLength => Self::OCTET_LENGTH,
             Function::Position => Self::POSITION,
             Function::Power => Self::POWER,
+            Function::RegexpMatches => Self::REGEXP_MATCHES,
             Function::Replace => Self::REPLACE,
             Function::Right => Self::RIGHT,
             Function::Round => Self::ROUND,
@@ -260,6 +278,13 @@ impl ProjectionAggregateKindCode {
     const MAX: Self = Self(5);
     const FIRST: Self = Self(6);
     const LAST: Self = Self(7);
+    const VAR_POP: Self = Self(8);
+    const VAR_SAMP: Self = Self(9);
+    const STDDEV_POP: Self = Self(10);
+    const STDDEV_SAMP: Self = Self(11);
+    const PERCENTILE_DISC: Self = Self(12);
+    const STRING_AGG: Self = Self(13);
+    const ARRAY_AGG: Self = Self(14);
 
     const fn from_aggregate_kind(kind: AggregateKind) -> Self {
         match kind {
@@ -271,6 +296,13 @@ impl ProjectionAggregateKindCode {
             AggregateKind::Max => Self::MAX,
             AggregateKind::First => Self::FIRST,
             AggregateKind::Last => Self::LAST,
+            AggregateKind::VarPop => Self::VAR_POP,
+            AggregateKind::VarSamp => Self::VAR_SAMP,
+            AggregateKind::StddevPop => Self::STDDEV_POP,
+            AggregateKind::StddevSamp => Self::STDDEV_SAMP,
+            AggregateKind::PercentileDisc => Self::PERCENTILE_DISC,
+            AggregateKind::StringAgg => Self::STRING_AGG,
+            AggregateKind::ArrayAgg => Self::ARRAY_AGG,
         }
     }
 }
@@ -335,6 +367,10 @@ pub(in crate::db) enum ProjectionEvalError {
 
     Numeric(NumericEvalError),
 
+    ValueRejected {
+        reason: QueryProjectionCode,
+    },
+
     InvalidGroupedHavingResult {
         found: ProjectionValueKindCode,
     },
@@ -464,6 +500,7 @@ impl ProjectionEvalError {
     fn into_internal_error(self) -> InternalError {
         match self {
             Self::Numeric(err) => err.into_internal_error(),
+            Self::ValueRejected { reason } => InternalError::query_unsupported_projection(reason),
             Self::FieldPathEvaluationFailed { class, origin }
             | Self::ReaderFailed { class, origin } => InternalError::classified(class, origin),
             Self::UnknownField { access } | Self::MissingFieldValue { access, .. } => {
@@ -690,6 +727,11 @@ pub(in crate::db) enum CompiledExpr {
         function: Function,
         args: Box<[Self]>,
     },
+    TextPatternMatch {
+        function: Function,
+        input: Box<Self>,
+        pattern: Box<TextPattern>,
+    },
     Unary {
         op: UnaryOp,
         expr: Box<Self>,
@@ -757,7 +799,9 @@ impl CompiledExpr {
         match self {
             Self::FieldPath { .. } => true,
             Self::FunctionCall { args, .. } => args.iter().any(Self::contains_field_path),
-            Self::Unary { expr, .. } => expr.contains_field_path(),
+            Self::TextPatternMatch { input: expr, .. } | Self::Unary { expr, .. } => {
+                expr.contains_field_path()
+            }
             Self::Case {
                 when_then_arms,
                 else_expr,
@@ -879,7 +923,9 @@ impl CompiledExpr {
                     arg.for_each_referenced_slot(visit);
                 }
             }
-            Self::Unary { expr, .. } => expr.for_each_referenced_slot(visit),
+            Self::TextPatternMatch { input: expr, .. } | Self::Unary { expr, .. } => {
+                expr.for_each_referenced_slot(visit);
+            }
             Self::Case {
                 when_then_arms,
                 else_expr,
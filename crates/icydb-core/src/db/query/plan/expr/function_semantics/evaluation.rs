@@ -7,14 +7,15 @@ use crate::{
             decimal_power_checked, decimal_sign, decimal_sqrt_checked,
         },
         query::plan::expr::{
-            Function,
+            BinaryOp, Function,
             function_semantics::types::{
                 BinaryNumericFunctionKind, LeftRightTextFunctionKind, NumericScaleFunctionKind,
-                UnaryNumericFunctionKind, UnaryTextFunctionKind,
+                TemporalEvalError, TemporalField, UnaryNumericFunctionKind, UnaryTextFunctionKind,
             },
         },
+        schema::{AcceptedFieldKind, CheckedCastError, checked_cast_value},
     },
-    types::Decimal,
+    types::{CurrentTimestamp, Date, Decimal, Duration, Timestamp},
     value::{Value, lower_text, upper_text},
 };
 
@@ -216,6 +217,22 @@ impl Function {
         )
     }
 
+    /// Evaluate one admitted CAST call through the shared checked-cast matrix.
+    /// NULL propagates here so the matrix only ever sees concrete sources.
+    pub(in crate::db::query::plan::expr) fn eval_cast(
+        self,
+        value: &Value,
+        target: &AcceptedFieldKind,
+    ) -> Result<Value, CheckedCastError> {
+        debug_assert!(matches!(self, Self::Cast));
+
+        if matches!(value, Value::Null) {
+            return Ok(Value::Null);
+        }
+
+        checked_cast_value(value, target)
+    }
+
     /// Convert one found substring byte offset into the stable 1-based SQL
     /// char position used by POSITION(...).
     fn text_position_1_based(haystack: &str, needle: &str) -> u64 {
@@ -249,3 +266,198 @@ impl Function {
         }
     }
 }
+
+const MILLIS_PER_SECOND: i64 = 1_000;
+const MILLIS_PER_MINUTE: i64 = 60 * MILLIS_PER_SECOND;
+const MILLIS_PER_HOUR: i64 = 60 * MILLIS_PER_MINUTE;
+const MILLIS_PER_DAY: i64 = 24 * MILLIS_PER_HOUR;
+
+impl Function {
+    /// Evaluate `NOW()` from the runtime clock. On the IC this is the message
+    /// time, which stays fixed for every row read by one request.
+    #[must_use]
+    pub(in crate::db::query::plan::expr) fn eval_now(self) -> Value {
+        debug_assert!(matches!(self, Self::Now));
+
+        Value::Timestamp(Timestamp::now())
+    }
+
+    /// Evaluate one admitted `DATE_TRUNC` call. Timestamps truncate to the
+    /// start of the enclosing bucket; dates are already midnight-aligned, so
+    /// sub-day units leave them unchanged.
+    pub(in crate::db::query::plan::expr) fn eval_date_trunc(
+        self,
+        field: TemporalField,
+        value: &Value,
+    ) -> Result<Value, TemporalEvalError> {
+        debug_assert!(matches!(self, Self::DateTrunc));
+
+        match value {
+            Value::Null => Ok(Value::Null),
+            Value::Timestamp(timestamp) => {
+                let millis = timestamp.as_millis();
+                let truncated = match field {
+                    TemporalField::Second => floor_to_multiple(millis, MILLIS_PER_SECOND),
+                    TemporalField::Minute => floor_to_multiple(millis, MILLIS_PER_MINUTE),
+                    TemporalField::Hour => floor_to_multiple(millis, MILLIS_PER_HOUR),
+                    _ => {
+                        let date = truncate_date(timestamp_date(millis)?, field)?;
+                        i64::from(date.as_days_since_epoch())
+                            .checked_mul(MILLIS_PER_DAY)
+                            .ok_or(NumericEvalError::Overflow)?
+                    }
+                };
+
+                Ok(Value::Timestamp(Timestamp::from_millis(truncated)))
+            }
+            Value::Date(date) => Ok(Value::Date(truncate_date(*date, field)?)),
+            _ => Err(TemporalEvalError::NonTemporalInput),
+        }
+    }
+
+    /// Evaluate one admitted `EXTRACT` call into one signed integer field.
+    pub(in crate::db::query::plan::expr) fn eval_extract(
+        self,
+        field: TemporalField,
+        value: &Value,
+    ) -> Result<Value, TemporalEvalError> {
+        debug_assert!(matches!(self, Self::Extract));
+
+        let (date, millis_of_day) = match value {
+            Value::Null => return Ok(Value::Null),
+            Value::Timestamp(timestamp) => {
+                let millis = timestamp.as_millis();
+                (timestamp_date(millis)?, millis.rem_euclid(MILLIS_PER_DAY))
+            }
+            Value::Date(date) => (*date, 0),
+            _ => return Err(TemporalEvalError::NonTemporalInput),
+        };
+        let days = i64::from(date.as_days_since_epoch());
+        let extracted = match field {
+            TemporalField::Year => i64::from(date.year()),
+            TemporalField::Month => i64::from(date.month()),
+            TemporalField::Week => iso_week_number(days)?,
+            TemporalField::Day => i64::from(date.day()),
+            TemporalField::Hour => millis_of_day / MILLIS_PER_HOUR,
+            TemporalField::Minute => (millis_of_day / MILLIS_PER_MINUTE) % 60,
+            TemporalField::Second => (millis_of_day / MILLIS_PER_SECOND) % 60,
+            TemporalField::DayOfWeek => (days + 4).rem_euclid(7),
+            TemporalField::DayOfYear => days - year_start_days(date.year())? + 1,
+            TemporalField::Epoch => match value {
+                Value::Timestamp(timestamp) => timestamp.as_millis().div_euclid(MILLIS_PER_SECOND),
+                _ => days * (MILLIS_PER_DAY / MILLIS_PER_SECOND),
+            },
+        };
+
+        Ok(Value::Int64(extracted))
+    }
+
+    /// Evaluate one admitted `AGE(later, earlier)` call into the elapsed
+    /// `Duration`. Durations are unsigned, so a later argument that precedes
+    /// the earlier one is not representable.
+    pub(in crate::db::query::plan::expr) fn eval_age(
+        self,
+        later: &Value,
+        earlier: &Value,
+    ) -> Result<Value, TemporalEvalError> {
+        debug_assert!(matches!(self, Self::Age));
+
+        let elapsed_millis = match (later, earlier) {
+            (Value::Null, _) | (_, Value::Null) => return Ok(Value::Null),
+            (Value::Timestamp(later), Value::Timestamp(earlier)) => later
+                .as_millis()
+                .checked_sub(earlier.as_millis())
+                .ok_or(NumericEvalError::Overflow)?,
+            (Value::Date(later), Value::Date(earlier)) => later
+                .days_since(*earlier)
+                .checked_mul(MILLIS_PER_DAY)
+                .ok_or(NumericEvalError::Overflow)?,
+            _ => return Err(TemporalEvalError::NonTemporalInput),
+        };
+        let elapsed_millis =
+            u64::try_from(elapsed_millis).map_err(|_| NumericEvalError::NotRepresentable)?;
+
+        Ok(Value::Duration(Duration::from_millis(elapsed_millis)))
+    }
+}
+
+/// Evaluate one `timestamp ± duration` or `duration + timestamp` arithmetic
+/// step. Returns `None` when the operands are not one of those temporal pairs
+/// so callers keep the ordinary numeric arithmetic lane for everything else.
+pub(in crate::db::query::plan::expr) fn eval_temporal_arithmetic(
+    op: BinaryOp,
+    left: &Value,
+    right: &Value,
+) -> Option<Result<Value, NumericEvalError>> {
+    let (timestamp, duration, negate) = match (op, left, right) {
+        (BinaryOp::Add, Value::Timestamp(timestamp), Value::Duration(duration))
+        | (BinaryOp::Add, Value::Duration(duration), Value::Timestamp(timestamp)) => {
+            (timestamp, duration, false)
+        }
+        (BinaryOp::Sub, Value::Timestamp(timestamp), Value::Duration(duration)) => {
+            (timestamp, duration, true)
+        }
+        _ => return None,
+    };
+
+    let Ok(offset) = i64::try_from(duration.as_millis()) else {
+        return Some(Err(NumericEvalError::Overflow));
+    };
+    let shifted = if negate {
+        timestamp.as_millis().checked_sub(offset)
+    } else {
+        timestamp.as_millis().checked_add(offset)
+    };
+
+    Some(
+        shifted
+            .map(|millis| Value::Timestamp(Timestamp::from_millis(millis)))
+            .ok_or(NumericEvalError::Overflow),
+    )
+}
+
+const fn floor_to_multiple(millis: i64, unit: i64) -> i64 {
+    millis - millis.rem_euclid(unit)
+}
+
+fn timestamp_date(millis: i64) -> Result<Date, NumericEvalError> {
+    Date::try_from_i64(millis.div_euclid(MILLIS_PER_DAY)).ok_or(NumericEvalError::Overflow)
+}
+
+fn year_start_days(year: i32) -> Result<i64, NumericEvalError> {
+    Date::try_new(year, 1, 1)
+        .map(|date| i64::from(date.as_days_since_epoch()))
+        .ok_or(NumericEvalError::Overflow)
+}
+
+// Truncate one calendar date to the start of its bucket. Weeks start on
+// Monday, matching ISO-8601 and the `EXTRACT(WEEK ...)` numbering.
+fn truncate_date(date: Date, field: TemporalField) -> Result<Date, NumericEvalError> {
+    let truncated = match field {
+        TemporalField::Year => Date::try_new(date.year(), 1, 1),
+        TemporalField::Month => Date::try_new(date.year(), date.month(), 1),
+        TemporalField::Week => {
+            let days = i64::from(date.as_days_since_epoch());
+            date.checked_sub_days((days + 3).rem_euclid(7))
+        }
+        TemporalField::Day
+        | TemporalField::Hour
+        | TemporalField::Minute
+        | TemporalField::Second
+        | TemporalField::DayOfWeek
+        | TemporalField::DayOfYear
+        | TemporalField::Epoch => Some(date),
+    };
+
+    truncated.ok_or(NumericEvalError::Overflow)
+}
+
+// ISO-8601 week number: the week belongs to the year that holds its Thursday.
+fn iso_week_number(days: i64) -> Result<i64, NumericEvalError> {
+    let thursday = days - (days + 3).rem_euclid(7) + 3;
+    let year = Date::try_from_i64(thursday)
+        .ok_or(NumericEvalError::Overflow)?
+        .year();
+
+    Ok((thursday - year_start_days(year)?) / 7 + 1)
+}
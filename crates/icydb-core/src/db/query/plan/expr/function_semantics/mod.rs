@@ -7,10 +7,13 @@ mod evaluation;
 mod spec;
 mod types;
 
+pub(in crate::db::query::plan::expr) use evaluation::eval_temporal_arithmetic;
+pub(in crate::db::query::plan::expr) use spec::{cast_target_kind, temporal_field_arg};
+
 #[cfg(feature = "sql")]
 pub(in crate::db) use types::FunctionSurface;
 pub(in crate::db::query::plan::expr) use types::{
     AggregateInputConstantFoldShape, BooleanFunctionShape, FieldPredicateFunctionKind,
-    FunctionTypeInferenceShape, NullTestFunctionKind, ScalarEvalFunctionShape,
+    FunctionTypeInferenceShape, NullTestFunctionKind, ScalarEvalFunctionShape, TemporalEvalError,
 };
-pub(in crate::db) use types::{NumericSubtype, TextPredicateFunctionKind};
+pub(in crate::db) use types::{NumericSubtype, TemporalField, TextPredicateFunctionKind};
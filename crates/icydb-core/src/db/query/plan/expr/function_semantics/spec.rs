@@ -1,14 +1,15 @@
 use crate::db::query::plan::expr::Expr;
 use crate::db::query::plan::expr::{
-    Function,
+    Function, TextPatternSyntax,
     function_semantics::types::{
         AggregateInputConstantFoldShape, BinaryNumericFunctionKind, BooleanFunctionShape,
         FieldPredicateFunctionKind, FunctionCategory, FunctionNullBehavior, FunctionSurface,
         FunctionTypeInferenceShape, LeftRightTextFunctionKind, NullTestFunctionKind,
-        NumericScaleFunctionKind, NumericSubtype, ScalarEvalFunctionShape,
+        NumericScaleFunctionKind, NumericSubtype, ScalarEvalFunctionShape, TemporalField,
         TextPredicateFunctionKind, UnaryNumericFunctionKind, UnaryTextFunctionKind,
     },
 };
+use crate::db::schema::{AcceptedFieldKind, resolve_checked_cast_target};
 use crate::value::Value;
 
 ///
@@ -100,6 +101,20 @@ impl FunctionSpec {
         )
     }
 
+    /// Build one strict specification admitted on every general scalar surface.
+    #[must_use]
+    const fn strict_general(
+        category: FunctionCategory,
+        type_inference: FunctionTypeInferenceShape,
+    ) -> Self {
+        Self::new(
+            category,
+            FunctionNullBehavior::Strict,
+            type_inference,
+            GENERAL_SCALAR_FUNCTION_SURFACES,
+        )
+    }
+
     /// Build one strict text-predicate boolean specification.
     #[must_use]
     const fn strict_text_bool_result(text_positions: &'static [usize]) -> Self {
@@ -175,6 +190,16 @@ impl Function {
             | Self::Log2
             | Self::Sign
             | Self::Sqrt => FunctionSpec::strict_numeric_result(&[], &[0], NumericSubtype::Decimal),
+            Self::Age | Self::DateTrunc | Self::Extract | Self::Now => {
+                FunctionSpec::strict_general(
+                    FunctionCategory::Temporal,
+                    FunctionTypeInferenceShape::TemporalResult,
+                )
+            }
+            Self::Cast => FunctionSpec::strict_general(
+                FunctionCategory::Conversion,
+                FunctionTypeInferenceShape::CastResult,
+            ),
             Self::Coalesce => FunctionSpec::new(
                 FunctionCategory::NullHandling,
                 FunctionNullBehavior::NullIgnoring,
@@ -187,9 +212,16 @@ impl Function {
                 FunctionTypeInferenceShape::CollectionContains,
                 BOOLEAN_FUNCTION_SURFACES,
             ),
-            Self::Contains | Self::EndsWith | Self::StartsWith => {
-                FunctionSpec::strict_text_bool_result(&[0, 1])
-            }
+            Self::HasKey => FunctionSpec::strict_general(
+                FunctionCategory::Collection,
+                FunctionTypeInferenceShape::MapHasKey,
+            ),
+            Self::Contains
+            | Self::EndsWith
+            | Self::Like
+            | Self::Match
+            | Self::RegexpMatches
+            | Self::StartsWith => FunctionSpec::strict_text_bool_result(&[0, 1]),
             Self::InList => FunctionSpec::new(
                 FunctionCategory::Collection,
                 FunctionNullBehavior::NullObserving,
@@ -210,11 +242,9 @@ impl Function {
                 },
                 GENERAL_SCALAR_FUNCTION_SURFACES,
             ),
-            Self::OctetLength => FunctionSpec::new(
+            Self::OctetLength => FunctionSpec::strict_general(
                 FunctionCategory::Numeric,
-                FunctionNullBehavior::Strict,
                 FunctionTypeInferenceShape::ByteLengthResult,
-                GENERAL_SCALAR_FUNCTION_SURFACES,
             ),
             Self::Lower | Self::Ltrim | Self::Rtrim | Self::Trim | Self::Upper => {
                 FunctionSpec::strict_unary_text_result()
@@ -274,6 +304,8 @@ impl Function {
                 | FunctionTypeInferenceShape::NumericResult { .. }
                 | FunctionTypeInferenceShape::ByteLengthResult
                 | FunctionTypeInferenceShape::NumericScaleResult
+                | FunctionTypeInferenceShape::CastResult
+                | FunctionTypeInferenceShape::TemporalResult
                 | FunctionTypeInferenceShape::DynamicCoalesce
                 | FunctionTypeInferenceShape::DynamicNullIf
         )
@@ -283,6 +315,12 @@ impl Function {
     /// and its planner-frozen arguments, if the function family carries one.
     #[must_use]
     pub(in crate::db) fn fixed_decimal_scale(self, args: &[Expr]) -> Option<u32> {
+        if matches!(self, Self::Cast) {
+            return match cast_target_kind(args)? {
+                AcceptedFieldKind::Decimal { scale } => Some(scale),
+                _ => None,
+            };
+        }
         if !matches!(self, Self::Round | Self::Trunc) {
             return None;
         }
@@ -304,18 +342,26 @@ impl Function {
         match self {
             Self::Coalesce => Some(BooleanFunctionShape::TruthCoalesce),
             Self::IsNull | Self::IsNotNull => Some(BooleanFunctionShape::NullTest),
-            Self::StartsWith | Self::EndsWith | Self::Contains => {
-                Some(BooleanFunctionShape::TextPredicate)
-            }
+            Self::StartsWith
+            | Self::EndsWith
+            | Self::Contains
+            | Self::Like
+            | Self::Match
+            | Self::RegexpMatches => Some(BooleanFunctionShape::TextPredicate),
             Self::IsMissing | Self::IsEmpty | Self::IsNotEmpty => {
                 Some(BooleanFunctionShape::FieldPredicate)
             }
             Self::CollectionContains => Some(BooleanFunctionShape::CollectionContains),
+            Self::HasKey => Some(BooleanFunctionShape::MapHasKey),
             Self::InList => Some(BooleanFunctionShape::Membership),
             Self::Abs
+            | Self::Age
+            | Self::Cast
             | Self::Cbrt
             | Self::Ceiling
+            | Self::DateTrunc
             | Self::Exp
+            | Self::Extract
             | Self::Floor
             | Self::Left
             | Self::Length
@@ -326,6 +372,7 @@ impl Function {
             | Self::Lower
             | Self::Ltrim
             | Self::Mod
+            | Self::Now
             | Self::NullIf
             | Self::OctetLength
             | Self::Position
@@ -366,6 +413,19 @@ impl Function {
             Self::StartsWith => Some(TextPredicateFunctionKind::StartsWith),
             Self::EndsWith => Some(TextPredicateFunctionKind::EndsWith),
             Self::Contains => Some(TextPredicateFunctionKind::Contains),
+            Self::Match => Some(TextPredicateFunctionKind::Match),
+            _ => None,
+        }
+    }
+
+    /// Return the pattern language matched by one residual text-pattern
+    /// function. These stay outside the runtime predicate surface, so
+    /// `boolean_text_predicate_kind` returns `None` for them.
+    #[must_use]
+    pub(in crate::db) const fn text_pattern_syntax(self) -> Option<TextPatternSyntax> {
+        match self {
+            Self::Like => Some(TextPatternSyntax::Like),
+            Self::RegexpMatches => Some(TextPatternSyntax::Regex),
             _ => None,
         }
     }
@@ -469,6 +529,9 @@ impl Function {
         self,
     ) -> ScalarEvalFunctionShape {
         match self {
+            Self::Cast => ScalarEvalFunctionShape::Cast,
+            Self::Age | Self::DateTrunc | Self::Extract => ScalarEvalFunctionShape::Temporal,
+            Self::Now => ScalarEvalFunctionShape::RequestTime,
             Self::IsNull | Self::IsNotNull => ScalarEvalFunctionShape::NullTest,
             Self::IsMissing | Self::IsEmpty | Self::IsNotEmpty | Self::CollectionContains => {
                 ScalarEvalFunctionShape::NonExecutableProjection
@@ -491,14 +554,16 @@ impl Function {
             | Self::Sqrt => ScalarEvalFunctionShape::UnaryNumeric,
             Self::Log | Self::Mod | Self::Power => ScalarEvalFunctionShape::BinaryNumeric,
             Self::Left | Self::Right => ScalarEvalFunctionShape::LeftRightText,
-            Self::StartsWith | Self::EndsWith | Self::Contains => {
+            Self::StartsWith | Self::EndsWith | Self::Contains | Self::Match => {
                 ScalarEvalFunctionShape::TextPredicate
             }
+            Self::Like | Self::RegexpMatches => ScalarEvalFunctionShape::PatternMatch,
             Self::Position => ScalarEvalFunctionShape::PositionText,
             Self::Replace => ScalarEvalFunctionShape::ReplaceText,
             Self::Substring => ScalarEvalFunctionShape::SubstringText,
             Self::Round | Self::Trunc => ScalarEvalFunctionShape::NumericScale,
             Self::InList => ScalarEvalFunctionShape::Membership,
+            Self::HasKey => ScalarEvalFunctionShape::MapHasKey,
         }
     }
 
@@ -526,7 +591,12 @@ impl Function {
             | Self::Log2
             | Self::Sign
             | Self::Sqrt => Some(AggregateInputConstantFoldShape::UnaryNumeric),
-            Self::IsNull
+            Self::Age
+            | Self::Cast
+            | Self::DateTrunc
+            | Self::Extract
+            | Self::Now
+            | Self::IsNull
             | Self::IsNotNull
             | Self::IsMissing
             | Self::IsEmpty
@@ -540,7 +610,11 @@ impl Function {
             | Self::StartsWith
             | Self::EndsWith
             | Self::Contains
+            | Self::Like
+            | Self::Match
+            | Self::RegexpMatches
             | Self::CollectionContains
+            | Self::HasKey
             | Self::Position
             | Self::Replace
             | Self::Substring
@@ -551,3 +625,27 @@ impl Function {
         }
     }
 }
+
+/// Resolve the checked-cast target kind frozen into one `CAST` call's second
+/// argument, if the call still carries the canonical type-name literal.
+#[must_use]
+pub(in crate::db::query::plan::expr) fn cast_target_kind(
+    args: &[Expr],
+) -> Option<AcceptedFieldKind> {
+    let Some(Expr::Literal(Value::Text(type_name))) = args.get(1) else {
+        return None;
+    };
+
+    resolve_checked_cast_target(type_name)
+}
+
+/// Resolve the calendar field frozen into one `DATE_TRUNC` / `EXTRACT` call's
+/// first argument, if the call still carries the canonical label literal.
+#[must_use]
+pub(in crate::db::query::plan::expr) fn temporal_field_arg(args: &[Expr]) -> Option<TemporalField> {
+    let Some(Expr::Literal(Value::Text(label))) = args.first() else {
+        return None;
+    };
+
+    TemporalField::from_sql_label(label)
+}
@@ -1,4 +1,7 @@
-use crate::value::Value;
+use crate::{
+    db::{numeric::NumericEvalError, text_search::text_matches_query},
+    value::Value,
+};
 
 ///
 /// NumericSubtype
@@ -29,8 +32,10 @@ pub(in crate::db) enum NumericSubtype {
 pub(in crate::db::query::plan::expr) enum FunctionCategory {
     BooleanPredicate,
     Collection,
+    Conversion,
     NullHandling,
     Numeric,
+    Temporal,
     Text,
 }
 
@@ -63,9 +68,11 @@ pub(in crate::db::query::plan::expr) enum FunctionTypeInferenceShape {
     BoolResult {
         text_positions: &'static [usize],
     },
+    CastResult,
     CollectionContains,
     DynamicCoalesce,
     DynamicNullIf,
+    MapHasKey,
     Membership,
     NumericResult {
         text_positions: &'static [usize],
@@ -73,6 +80,7 @@ pub(in crate::db::query::plan::expr) enum FunctionTypeInferenceShape {
         subtype: NumericSubtype,
     },
     NumericScaleResult,
+    TemporalResult,
     TextResult {
         text_positions: &'static [usize],
         numeric_positions: &'static [usize],
@@ -113,6 +121,7 @@ pub(in crate::db) enum FunctionSurface {
 pub(in crate::db::query::plan::expr) enum BooleanFunctionShape {
     CollectionContains,
     FieldPredicate,
+    MapHasKey,
     Membership,
     NullTest,
     TextPredicate,
@@ -159,6 +168,7 @@ impl NullTestFunctionKind {
 pub(in crate::db) enum TextPredicateFunctionKind {
     Contains,
     EndsWith,
+    Match,
     StartsWith,
 }
 
@@ -169,6 +179,7 @@ impl TextPredicateFunctionKind {
         Value::Bool(match self {
             Self::Contains => text.contains(needle),
             Self::EndsWith => text.ends_with(needle),
+            Self::Match => text_matches_query(text, needle),
             Self::StartsWith => text.starts_with(needle),
         })
     }
@@ -302,19 +313,115 @@ pub(in crate::db::query::plan::expr) enum AggregateInputConstantFoldShape {
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub(in crate::db::query::plan::expr) enum ScalarEvalFunctionShape {
+    Cast,
     DynamicCoalesce,
     DynamicNullIf,
     BinaryNumeric,
     LeftRightText,
+    MapHasKey,
     Membership,
     NonExecutableProjection,
     NullTest,
+    PatternMatch,
     PositionText,
     ReplaceText,
+    RequestTime,
     NumericScale,
     OctetLength,
     SubstringText,
+    Temporal,
     TextPredicate,
     UnaryNumeric,
     UnaryText,
 }
+
+///
+/// TemporalField
+///
+/// TemporalField is the closed calendar-field vocabulary shared by
+/// `DATE_TRUNC('unit', x)` and `EXTRACT(field FROM x)`. Only the civil units
+/// admit truncation; the derived `DOW`, `DOY`, and `EPOCH` fields are
+/// extraction-only.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[remain::sorted]
+pub(in crate::db) enum TemporalField {
+    Day,
+    DayOfWeek,
+    DayOfYear,
+    Epoch,
+    Hour,
+    Minute,
+    Month,
+    Second,
+    Week,
+    Year,
+}
+
+impl TemporalField {
+    /// Resolve one SQL unit or field label, case-insensitively.
+    #[must_use]
+    pub(in crate::db) fn from_sql_label(label: &str) -> Option<Self> {
+        const TEMPORAL_FIELDS: [(&str, TemporalField); 10] = [
+            ("year", TemporalField::Year),
+            ("month", TemporalField::Month),
+            ("week", TemporalField::Week),
+            ("day", TemporalField::Day),
+            ("hour", TemporalField::Hour),
+            ("minute", TemporalField::Minute),
+            ("second", TemporalField::Second),
+            ("dow", TemporalField::DayOfWeek),
+            ("doy", TemporalField::DayOfYear),
+            ("epoch", TemporalField::Epoch),
+        ];
+
+        TEMPORAL_FIELDS
+            .iter()
+            .find(|(candidate, _)| label.eq_ignore_ascii_case(candidate))
+            .map(|(_, field)| *field)
+    }
+
+    /// Return the canonical uppercase SQL label frozen into lowered calls.
+    #[must_use]
+    pub(in crate::db) const fn sql_label(self) -> &'static str {
+        match self {
+            Self::Day => "DAY",
+            Self::DayOfWeek => "DOW",
+            Self::DayOfYear => "DOY",
+            Self::Epoch => "EPOCH",
+            Self::Hour => "HOUR",
+            Self::Minute => "MINUTE",
+            Self::Month => "MONTH",
+            Self::Second => "SECOND",
+            Self::Week => "WEEK",
+            Self::Year => "YEAR",
+        }
+    }
+
+    /// Return whether `DATE_TRUNC` admits this field as a bucketing unit.
+    #[must_use]
+    pub(in crate::db) const fn admits_truncation(self) -> bool {
+        !matches!(self, Self::DayOfWeek | Self::DayOfYear | Self::Epoch)
+    }
+}
+
+///
+/// TemporalEvalError
+///
+/// TemporalEvalError keeps non-temporal inputs distinct from calendar-range
+/// failures so projection paths can report the former as a typed projection
+/// rejection and the latter through the shared checked numeric taxonomy.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(in crate::db::query::plan::expr) enum TemporalEvalError {
+    NonTemporalInput,
+    Numeric(NumericEvalError),
+}
+
+impl From<NumericEvalError> for TemporalEvalError {
+    fn from(err: NumericEvalError) -> Self {
+        Self::Numeric(err)
+    }
+}
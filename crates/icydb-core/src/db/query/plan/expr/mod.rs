@@ -79,6 +79,7 @@ mod projection_eval;
 #[cfg(feature = "sql")]
 mod rewrite;
 mod scalar;
+mod text_pattern;
 mod truth_value;
 mod type_inference;
 
@@ -96,7 +97,7 @@ pub(in crate::db) use ast::{
 pub(in crate::db) use canonicalize::canonicalize_scalar_where_bool_expr;
 pub(in crate::db) use canonicalize::{
     CanonicalExpr, canonicalize_grouped_having_bool_expr, is_normalized_bool_expr,
-    normalize_bool_expr, truth_condition_binary_compare_op,
+    normalize_bool_expr, normalize_scalar_operand_expr, truth_condition_binary_compare_op,
 };
 #[cfg(feature = "sql")]
 pub(in crate::db) use canonicalize::{
@@ -110,9 +111,12 @@ pub(in crate::db) use compiled_expr::{
 pub(in crate::db) use function_semantics::FunctionSurface;
 pub(in crate::db::query::plan::expr) use function_semantics::{
     AggregateInputConstantFoldShape, BooleanFunctionShape, FieldPredicateFunctionKind,
-    FunctionTypeInferenceShape, NullTestFunctionKind, ScalarEvalFunctionShape,
+    FunctionTypeInferenceShape, NullTestFunctionKind, ScalarEvalFunctionShape, TemporalEvalError,
+    cast_target_kind, eval_temporal_arithmetic, temporal_field_arg,
+};
+pub(in crate::db) use function_semantics::{
+    NumericSubtype, TemporalField, TextPredicateFunctionKind,
 };
-pub(in crate::db) use function_semantics::{NumericSubtype, TextPredicateFunctionKind};
 pub(in crate::db) use path::CompiledPath;
 #[cfg(all(test, feature = "sql"))]
 pub(in crate::db) use predicate::compile_normalized_bool_expr_to_predicate;
@@ -125,6 +129,7 @@ pub(in crate::db) use projection::{
     ProjectionSelection, ProjectionSpec, classify_grouped_order_term_for_field,
     classify_grouped_top_k_order_term, grouped_top_k_order_term_requires_heap,
 };
+pub(in crate::db::query::plan::expr) use projection_eval::eval_text_pattern_match;
 pub(in crate::db) use projection_eval::{
     ProjectionFunctionEvalError, eval_builder_expr_for_value_preview,
     eval_projection_function_call_checked,
@@ -135,6 +140,11 @@ pub(in crate::db) use scalar::{
     ScalarProjectionCaseArm, ScalarProjectionExpr, compile_scalar_projection_expr_with_schema,
     compile_scalar_projection_plan_with_schema,
 };
+#[cfg(feature = "sql")]
+pub(in crate::db) use text_pattern::canonicalize_like_pattern;
+pub(in crate::db) use text_pattern::{
+    TextPattern, TextPatternSyntax, take_text_pattern_match_steps,
+};
 pub(in crate::db) use truth_value::{
     admit_true_only_boolean_value, collapse_true_only_boolean_admission,
 };
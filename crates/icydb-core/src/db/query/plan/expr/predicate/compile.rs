@@ -455,6 +455,9 @@ fn compile_bool_function_truth_predicate(
                 TextPredicateFunctionKind::Contains => {
                     compile_bool_contains_function_truth_predicate(args, truth)
                 }
+                TextPredicateFunctionKind::Match => {
+                    compile_bool_text_match_function_truth_predicate(args, truth)
+                }
             }
         }
         Some(BooleanFunctionShape::FieldPredicate) => {
@@ -492,7 +495,7 @@ fn compile_bool_function_truth_predicate(
         Some(BooleanFunctionShape::Membership) => {
             compile_bool_membership_truth_predicate(args, truth)
         }
-        Some(BooleanFunctionShape::TruthCoalesce) | None => None,
+        Some(BooleanFunctionShape::MapHasKey | BooleanFunctionShape::TruthCoalesce) | None => None,
     }
 }
 
@@ -551,7 +554,7 @@ fn compile_bool_prefix_text_function_truth_predicate(
     let op = match kind {
         TextPredicateFunctionKind::StartsWith => CompareOp::StartsWith,
         TextPredicateFunctionKind::EndsWith => CompareOp::EndsWith,
-        TextPredicateFunctionKind::Contains => return None,
+        TextPredicateFunctionKind::Contains | TextPredicateFunctionKind::Match => return None,
     };
     let when_true = Predicate::Compare(ComparePredicate::with_coercion(
         field,
@@ -591,6 +594,23 @@ fn compile_bool_contains_function_truth_predicate(
     Some(wrap_truth_predicate(when_true, truth))
 }
 
+// Compile one MATCH full-text predicate. Tokenization already folds case, so
+// only plain field targets compile onto the runtime shell.
+fn compile_bool_text_match_function_truth_predicate(
+    args: &[Expr],
+    truth: BoolTruth,
+) -> Option<Predicate> {
+    let [Expr::Field(field), Expr::Literal(value @ Value::Text(_))] = args else {
+        return None;
+    };
+    let when_true = Predicate::TextMatch {
+        field: field.as_str().to_string(),
+        value: value.clone(),
+    };
+
+    Some(wrap_truth_predicate(when_true, truth))
+}
+
 // Compile one single-field boolean function onto the requested runtime
 // predicate branch.
 fn compile_bool_field_predicate_truth_predicate(
@@ -914,8 +934,18 @@ impl RuntimePredicateAdmission {
             Some(BooleanFunctionShape::NullTest) => {
                 matches!(args, [Expr::Field(_) | Expr::Literal(_)])
             }
+            // LIKE and regular-expression matches have no runtime predicate
+            // shell; they stay residual expressions.
             Some(BooleanFunctionShape::TextPredicate) => {
-                matches!(args, [left, Expr::Literal(Value::Text(_))] if Self::is_text_target(left))
+                match boolean_text_predicate_kind(function) {
+                    Some(TextPredicateFunctionKind::Match) => {
+                        matches!(args, [Expr::Field(_), Expr::Literal(Value::Text(_))])
+                    }
+                    Some(_) => {
+                        matches!(args, [left, Expr::Literal(Value::Text(_))] if Self::is_text_target(left))
+                    }
+                    None => false,
+                }
             }
             Some(BooleanFunctionShape::FieldPredicate) => {
                 matches!(args, [Expr::Field(_)])
@@ -931,7 +961,11 @@ impl RuntimePredicateAdmission {
                             && membership_values_are_predicate_admissible(target, values)
                 )
             }
-            Some(BooleanFunctionShape::TruthCoalesce) | None => false,
+            // Map key checks have no runtime predicate shell; they stay
+            // residual expressions.
+            Some(BooleanFunctionShape::MapHasKey | BooleanFunctionShape::TruthCoalesce) | None => {
+                false
+            }
         }
     }
 
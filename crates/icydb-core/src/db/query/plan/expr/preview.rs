@@ -5,9 +5,10 @@ use crate::{
             compare_numeric_eq, compare_numeric_or_strict_order,
         },
         query::plan::expr::{
-            BinaryOp, CaseWhenArm, Expr, Function, ScalarEvalFunctionShape, UnaryOp,
-            collapse_true_only_boolean_admission,
+            BinaryOp, CaseWhenArm, Expr, Function, ScalarEvalFunctionShape, TemporalField, UnaryOp,
+            collapse_true_only_boolean_admission, eval_temporal_arithmetic,
         },
+        schema::resolve_checked_cast_target,
     },
     value::Value,
 };
@@ -97,6 +98,14 @@ fn eval_literal_only_function_call(function: Function, args: &[Expr]) -> Option<
         .collect::<Option<Vec<_>>>()?;
 
     match function.scalar_eval_shape() {
+        ScalarEvalFunctionShape::Cast => eval_cast_function_call(function, &evaluated_args),
+        ScalarEvalFunctionShape::Temporal => eval_temporal_function_call(function, &evaluated_args),
+        // NOW() reads request time and pattern matches charge matcher steps
+        // to the executing query, so neither may fold into a cached plan. Map
+        // key checks never see a literal map input.
+        ScalarEvalFunctionShape::RequestTime
+        | ScalarEvalFunctionShape::PatternMatch
+        | ScalarEvalFunctionShape::MapHasKey => None,
         ScalarEvalFunctionShape::NullTest => {
             eval_null_test_function_call(function, &evaluated_args)
         }
@@ -170,6 +179,9 @@ fn eval_literal_only_binary_expr(op: BinaryOp, left: &Value, right: &Value) -> O
             if matches!(left, Value::Null) || matches!(right, Value::Null) {
                 return Some(Value::Null);
             }
+            if let Some(result) = eval_temporal_arithmetic(op, left, right) {
+                return result.ok();
+            }
 
             let arithmetic_op = match op {
                 BinaryOp::Add => NumericArithmeticOp::Add,
@@ -464,6 +476,33 @@ fn eval_numeric_scale_function_call(function: Function, args: &[Value]) -> Optio
     }
 }
 
+// Fold one literal-only CAST only when the checked-cast matrix accepts it, so
+// representability failures stay runtime errors instead of folding to NULL.
+fn eval_cast_function_call(function: Function, args: &[Value]) -> Option<Value> {
+    let [input, Value::Text(type_name)] = args else {
+        return None;
+    };
+    let target = resolve_checked_cast_target(type_name)?;
+
+    function.eval_cast(input, &target).ok()
+}
+
+// Fold one literal-only temporal call; calendar failures stay runtime errors.
+fn eval_temporal_function_call(function: Function, args: &[Value]) -> Option<Value> {
+    let result = match (function, args) {
+        (Function::Age, [later, earlier]) => function.eval_age(later, earlier),
+        (Function::DateTrunc, [Value::Text(label), input]) => {
+            function.eval_date_trunc(TemporalField::from_sql_label(label)?, input)
+        }
+        (Function::Extract, [Value::Text(label), input]) => {
+            function.eval_extract(TemporalField::from_sql_label(label)?, input)
+        }
+        _ => return None,
+    };
+
+    result.ok()
+}
+
 // Decode one literal text argument, preserving NULL as its own boundary.
 const fn text_value(value: &Value) -> Option<NullableTextArg<'_>> {
     match value {
@@ -26,7 +26,9 @@ use crate::{
         query::plan::expr::{
             BinaryOp, CompiledExpr, CompiledExprValueReader, Expr, Function, ProjectionEvalError,
             ScalarEvalFunctionShape, ScalarProjectionCaseArm, ScalarProjectionExpr,
+            TemporalEvalError, TemporalField, TextPattern, eval_temporal_arithmetic,
         },
+        schema::{CheckedCastError, resolve_checked_cast_target},
     },
     value::Value,
 };
@@ -56,15 +58,17 @@ impl CompiledExprValueReader for PreviewValueReader<'_> {
 ///
 /// ProjectionFunctionEvalError
 ///
-/// ProjectionFunctionEvalError keeps checked numeric failures distinct from
-/// ordinary function-shape failures so executor projection paths can preserve
-/// numeric overflow as a query execution error instead of reclassifying it as
-/// an invalid logical plan.
+/// ProjectionFunctionEvalError keeps checked numeric failures and value
+/// rejections (checked casts, temporal inputs) distinct from ordinary
+/// function-shape failures so executor projection paths can preserve them as
+/// query execution errors instead of reclassifying them as an invalid logical
+/// plan.
 ///
 
 pub(in crate::db) enum ProjectionFunctionEvalError {
     Query(QueryError),
     Numeric(NumericEvalError),
+    ValueRejected(QueryProjectionCode),
 }
 
 impl ProjectionFunctionEvalError {
@@ -74,6 +78,7 @@ impl ProjectionFunctionEvalError {
         match self {
             Self::Query(err) => err,
             Self::Numeric(err) => QueryError::from_numeric_eval_error(err),
+            Self::ValueRejected(reason) => QueryError::unsupported_projection(reason),
         }
     }
 
@@ -123,6 +128,9 @@ pub(in crate::db) fn eval_projection_function_call_checked(
     args: &[Value],
 ) -> Result<Value, ProjectionFunctionEvalError> {
     match function.scalar_eval_shape() {
+        ScalarEvalFunctionShape::Cast => eval_cast_function_call(function, args),
+        ScalarEvalFunctionShape::Temporal => eval_temporal_function_call(function, args),
+        ScalarEvalFunctionShape::RequestTime => eval_request_time_function_call(function, args),
         ScalarEvalFunctionShape::NullTest => eval_null_test_function_call(function, args),
         ScalarEvalFunctionShape::NonExecutableProjection => Err(QueryError::invariant().into()),
         ScalarEvalFunctionShape::UnaryText => eval_unary_text_function_call(function, args),
@@ -134,12 +142,14 @@ pub(in crate::db) fn eval_projection_function_call_checked(
             eval_left_right_text_function_call(function, args)
         }
         ScalarEvalFunctionShape::TextPredicate => eval_text_predicate_function_call(function, args),
+        ScalarEvalFunctionShape::PatternMatch => eval_pattern_match_function_call(function, args),
         ScalarEvalFunctionShape::PositionText => eval_position_text_function_call(function, args),
         ScalarEvalFunctionShape::ReplaceText => eval_replace_text_function_call(function, args),
         ScalarEvalFunctionShape::SubstringText => eval_substring_text_function_call(function, args),
         ScalarEvalFunctionShape::NumericScale => eval_numeric_scale_function_call(function, args),
         ScalarEvalFunctionShape::OctetLength => eval_octet_length_function_call(function, args),
         ScalarEvalFunctionShape::Membership => eval_membership_function_call(function, args),
+        ScalarEvalFunctionShape::MapHasKey => eval_map_has_key_function_call(function, args),
     }
 }
 
@@ -237,7 +247,8 @@ fn compile_builder_preview_expr(
 fn preview_eval_error_into_query_error(err: ProjectionEvalError) -> QueryError {
     match err {
         ProjectionEvalError::Numeric(err) => QueryError::from_numeric_eval_error(err),
-        ProjectionEvalError::InvalidProjection { reason } => {
+        ProjectionEvalError::InvalidProjection { reason }
+        | ProjectionEvalError::ValueRejected { reason } => {
             QueryError::unsupported_projection(reason)
         }
         ProjectionEvalError::InvalidUnaryOperand { .. } => {
@@ -458,6 +469,42 @@ fn eval_text_predicate_function_call(
     }
 }
 
+// Residual LIKE / regex calls normally evaluate through the pattern compiled
+// once by `CompiledExpr`; this path recompiles per call and only serves
+// callers that bypass that compile step.
+fn eval_pattern_match_function_call(
+    function: Function,
+    args: &[Value],
+) -> Result<Value, ProjectionFunctionEvalError> {
+    let input = required_function_arg(function, args, 0, "input")?;
+    let pattern = text_literal_arg(function, args, 1, "pattern")?;
+
+    match (input, pattern) {
+        (Value::Null, _) | (_, None) => Ok(Value::Null),
+        (Value::Text(text), Some(pattern)) => {
+            let syntax = function
+                .text_pattern_syntax()
+                .ok_or_else(QueryError::invariant)?;
+            let pattern =
+                TextPattern::compile(syntax, pattern).map_err(|_| QueryError::invariant())?;
+
+            eval_text_pattern_match(&pattern, text)
+        }
+        (other, _) => Err(text_input_error(function, other).into()),
+    }
+}
+
+/// Match one text value against one compiled pattern, failing closed once the
+/// per-evaluation step ceiling is exceeded.
+pub(in crate::db::query::plan::expr) fn eval_text_pattern_match(
+    pattern: &TextPattern,
+    text: &str,
+) -> Result<Value, ProjectionFunctionEvalError> {
+    pattern.is_match(text).map(Value::Bool).map_err(|_| {
+        ProjectionFunctionEvalError::ValueRejected(QueryProjectionCode::PatternMatchStepLimit)
+    })
+}
+
 fn eval_position_text_function_call(
     function: Function,
     args: &[Value],
@@ -532,6 +579,101 @@ fn eval_numeric_scale_function_call(
     }
 }
 
+fn eval_cast_function_call(
+    function: Function,
+    args: &[Value],
+) -> Result<Value, ProjectionFunctionEvalError> {
+    let input = required_function_arg(function, args, 0, "input")?;
+    let Some(target) =
+        text_literal_arg(function, args, 1, "target")?.and_then(resolve_checked_cast_target)
+    else {
+        return Err(QueryError::invariant().into());
+    };
+
+    function
+        .eval_cast(input, &target)
+        .map_err(|err| ProjectionFunctionEvalError::ValueRejected(cast_projection_code(err)))
+}
+
+// Project one checked-cast failure onto its public projection reason. NULL
+// sources never reach the matrix here because CAST propagates NULL first.
+const fn cast_projection_code(err: CheckedCastError) -> QueryProjectionCode {
+    match err {
+        CheckedCastError::Overflow => QueryProjectionCode::CastOverflow,
+        CheckedCastError::NegativeToUnsigned => QueryProjectionCode::CastNegativeToUnsigned,
+        CheckedCastError::PrecisionLoss => QueryProjectionCode::CastPrecisionLoss,
+        CheckedCastError::NullSource | CheckedCastError::ValueContract => {
+            QueryProjectionCode::CastNotRepresentable
+        }
+    }
+}
+
+fn eval_temporal_function_call(
+    function: Function,
+    args: &[Value],
+) -> Result<Value, ProjectionFunctionEvalError> {
+    let result = match function {
+        Function::Age => {
+            let later = required_function_arg(function, args, 0, "later")?;
+            let earlier = required_function_arg(function, args, 1, "earlier")?;
+
+            function.eval_age(later, earlier)
+        }
+        Function::DateTrunc | Function::Extract => {
+            let Some(field) = text_literal_arg(function, args, 0, "field")?
+                .and_then(TemporalField::from_sql_label)
+            else {
+                return Err(QueryError::invariant().into());
+            };
+            let input = required_function_arg(function, args, 1, "input")?;
+            if matches!(function, Function::DateTrunc) {
+                function.eval_date_trunc(field, input)
+            } else {
+                function.eval_extract(field, input)
+            }
+        }
+        _ => return Err(QueryError::invariant().into()),
+    };
+
+    result.map_err(|err| match err {
+        TemporalEvalError::NonTemporalInput => {
+            ProjectionFunctionEvalError::ValueRejected(QueryProjectionCode::TemporalInputRequired)
+        }
+        TemporalEvalError::Numeric(err) => err.into(),
+    })
+}
+
+fn eval_request_time_function_call(
+    function: Function,
+    args: &[Value],
+) -> Result<Value, ProjectionFunctionEvalError> {
+    if !args.is_empty() {
+        return Err(QueryError::invariant().into());
+    }
+
+    Ok(function.eval_now())
+}
+
+// Report whether one map carries an entry under one text key. Planner typing
+// only admits map-typed inputs, so any other runtime shape is an invariant.
+fn eval_map_has_key_function_call(
+    function: Function,
+    args: &[Value],
+) -> Result<Value, ProjectionFunctionEvalError> {
+    let map = required_function_arg(function, args, 0, "map")?;
+    let key = text_literal_arg(function, args, 1, "key")?;
+
+    match (map, key) {
+        (Value::Null, _) | (_, None) => Ok(Value::Null),
+        (Value::Map(entries), Some(key)) => {
+            Ok(Value::Bool(entries.iter().any(
+                |(entry_key, _)| matches!(entry_key, Value::Text(text) if text == key),
+            )))
+        }
+        _ => Err(QueryError::invariant().into()),
+    }
+}
+
 fn eval_membership_function_call(
     function: Function,
     args: &[Value],
@@ -626,6 +768,9 @@ fn eval_preview_numeric_binary_expr(
     left: &Value,
     right: &Value,
 ) -> Result<Value, QueryError> {
+    if let Some(result) = eval_temporal_arithmetic(op, left, right) {
+        return result.map_err(QueryError::from_numeric_eval_error);
+    }
     let Some(result) = apply_numeric_arithmetic_checked(numeric_arithmetic_op(op), left, right)
         .map_err(QueryError::from_numeric_eval_error)?
     else {
@@ -1,7 +1,7 @@
 use crate::{
     db::{
         numeric::{NumericArithmeticOp, apply_numeric_arithmetic_checked},
-        query::plan::expr::{BinaryOp, CaseWhenArm, Expr},
+        query::plan::expr::{BinaryOp, CaseWhenArm, Expr, eval_temporal_arithmetic},
     },
     value::Value,
 };
@@ -105,6 +105,22 @@ fn rewrite_affine_field_compare(affine_side: &Expr, literal_side: &Expr) -> Opti
     };
 
     let (field, offset, arithmetic_op) = affine_field_offset(affine_side)?;
+
+    // Timestamp targets shifted by one duration stay timestamps, so move the
+    // offset through the temporal lane instead of collapsing it to a decimal.
+    if matches!(target, Value::Timestamp(_)) && matches!(offset, Value::Duration(_)) {
+        let inverse = match arithmetic_op {
+            NumericArithmeticOp::Add => BinaryOp::Sub,
+            NumericArithmeticOp::Sub => BinaryOp::Add,
+            NumericArithmeticOp::Mul | NumericArithmeticOp::Div | NumericArithmeticOp::Rem => {
+                return None;
+            }
+        };
+        let rewritten = eval_temporal_arithmetic(inverse, target, offset)?.ok()?;
+
+        return Some((field.clone(), rewritten));
+    }
+
     let rewritten = match arithmetic_op {
         NumericArithmeticOp::Add => {
             apply_numeric_arithmetic_checked(NumericArithmeticOp::Sub, target, offset)
@@ -0,0 +1,237 @@
+//! Module: query::plan::expr::text_pattern
+//! Responsibility: bounded LIKE and regular-expression matching over text values.
+//! Does not own: SQL clause parsing, predicate compilation, or execution-budget
+//! charging.
+//! Boundary: compiles one pattern into a linear-time instruction program and
+//! reports per-match steps through a thread-local meter that executor filter
+//! and projection seams drain into the active execution budget.
+//!
+//! Matching runs a Thompson-NFA simulation without backtracking or captures,
+//! so one evaluation costs at most `instructions * (text chars + 1)` steps.
+//! Pattern size, bounded repetition, and per-evaluation steps all have hard
+//! ceilings; exceeding them fails closed instead of degrading.
+
+mod parse;
+mod program;
+
+///
+/// TESTS
+///
+
+#[cfg(test)]
+mod tests;
+
+use crate::db::query::plan::expr::text_pattern::{
+    parse::{PatternNode, parse_like_pattern, parse_regex_pattern},
+    program::{Program, StepMeter},
+};
+use std::cell::Cell;
+
+/// Maximum pattern length, in characters, admitted by the matcher compiler.
+pub(in crate::db) const MAX_TEXT_PATTERN_CHARS: usize = 512;
+
+/// Maximum compiled instruction count for one pattern program.
+pub(in crate::db) const MAX_TEXT_PATTERN_INSTRUCTIONS: usize = 2_048;
+
+/// Maximum `{m,n}` repetition bound admitted by the regular-expression parser.
+pub(in crate::db) const MAX_TEXT_PATTERN_REPEAT: u32 = 100;
+
+/// Maximum group nesting depth admitted by the regular-expression parser.
+pub(in crate::db) const MAX_TEXT_PATTERN_NESTING: usize = 32;
+
+/// Maximum matcher steps one evaluation may spend before failing closed.
+pub(in crate::db) const MAX_TEXT_PATTERN_MATCH_STEPS: u64 = 1 << 20;
+
+thread_local! {
+    static TEXT_PATTERN_MATCH_STEPS: Cell<u64> = const { Cell::new(0) };
+}
+
+///
+/// TextPatternSyntax
+///
+/// Pattern language accepted by one compiled text pattern.
+/// `Like` patterns use the canonical backslash escape produced by SQL lowering
+/// and always match the whole input; `Regex` patterns search unanchored unless
+/// they carry explicit `^` / `$` assertions.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(in crate::db) enum TextPatternSyntax {
+    Like,
+    Regex,
+}
+
+///
+/// TextPatternError
+///
+/// Compile-time text-pattern rejection.
+/// `Malformed` covers syntax outside the supported subset, while `TooLarge`
+/// covers patterns whose length, nesting, repetition, or program size exceed
+/// the matcher ceilings.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(in crate::db) enum TextPatternError {
+    Malformed,
+    TooLarge,
+}
+
+///
+/// TextPatternStepLimit
+///
+/// Evaluation-time rejection raised when one match would exceed
+/// `MAX_TEXT_PATTERN_MATCH_STEPS`.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(in crate::db) struct TextPatternStepLimit;
+
+///
+/// TextPattern
+///
+/// One compiled, size-bounded text pattern plus the literal prefix every
+/// matching input must start with.
+/// The prefix lets lowering keep an index-usable `STARTS_WITH` bound beside
+/// the residual match.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(in crate::db) struct TextPattern {
+    program: Program,
+    literal_prefix: String,
+    prefix_only: bool,
+}
+
+impl TextPattern {
+    /// Compile one pattern in the given syntax.
+    pub(in crate::db) fn compile(
+        syntax: TextPatternSyntax,
+        pattern: &str,
+    ) -> Result<Self, TextPatternError> {
+        if pattern.chars().count() > MAX_TEXT_PATTERN_CHARS {
+            return Err(TextPatternError::TooLarge);
+        }
+        let node = match syntax {
+            TextPatternSyntax::Like => parse_like_pattern(pattern)?,
+            TextPatternSyntax::Regex => parse_regex_pattern(pattern)?,
+        };
+        let program = Program::compile(&node)?;
+
+        Ok(Self {
+            program,
+            literal_prefix: node.literal_prefix(),
+            prefix_only: node.is_prefix_only(),
+        })
+    }
+
+    /// Borrow the literal text every matching input must start with.
+    #[must_use]
+    pub(in crate::db) const fn literal_prefix(&self) -> &str {
+        self.literal_prefix.as_str()
+    }
+
+    /// Return whether matching is exactly `STARTS_WITH(input, literal_prefix)`.
+    #[must_use]
+    pub(in crate::db) const fn is_prefix_only(&self) -> bool {
+        self.prefix_only
+    }
+
+    /// Match one text value, recording the steps spent on the thread meter.
+    pub(in crate::db) fn is_match(&self, text: &str) -> Result<bool, TextPatternStepLimit> {
+        let mut meter = StepMeter::default();
+        let matched = self.program.is_match(text, &mut meter);
+        TEXT_PATTERN_MATCH_STEPS.with(|steps| steps.set(steps.get().saturating_add(meter.steps())));
+
+        matched
+    }
+}
+
+/// Rewrite one authored LIKE pattern onto the canonical backslash escape.
+///
+/// SQL LIKE has no escape character unless `ESCAPE` names one, so an authored
+/// backslash is literal and becomes `\\` in the canonical form.
+#[cfg(feature = "sql")]
+pub(in crate::db) fn canonicalize_like_pattern(
+    pattern: &str,
+    escape: Option<char>,
+) -> Result<String, TextPatternError> {
+    let mut canonical = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(ch) = chars.next() {
+        if Some(ch) == escape {
+            let escaped = chars.next().ok_or(TextPatternError::Malformed)?;
+            canonical.push('\\');
+            canonical.push(escaped);
+        } else if ch == '\\' {
+            canonical.push_str("\\\\");
+        } else {
+            canonical.push(ch);
+        }
+    }
+
+    Ok(canonical)
+}
+
+/// Drain the matcher steps recorded on this thread since the last drain.
+pub(in crate::db) fn take_text_pattern_match_steps() -> u64 {
+    TEXT_PATTERN_MATCH_STEPS.with(|steps| steps.replace(0))
+}
+
+impl PatternNode {
+    // Collect the literal characters that directly follow a leading start
+    // assertion. Anything else, including top-level alternation, yields an
+    // empty prefix.
+    fn literal_prefix(&self) -> String {
+        let Self::Concat(nodes) = self else {
+            return String::new();
+        };
+        let mut nodes = nodes.iter();
+        if !matches!(nodes.next(), Some(Self::Start)) {
+            return String::new();
+        }
+
+        nodes
+            .map_while(|node| match node {
+                Self::Char(ch) => Some(*ch),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // Recognize `^literal`, `^literal.*`, and `^literal.*$` shapes whose
+    // matching set is exactly the inputs starting with the literal prefix.
+    fn is_prefix_only(&self) -> bool {
+        let Self::Concat(nodes) = self else {
+            return matches!(self, Self::Start);
+        };
+        let Some((Self::Start, rest)) = nodes.split_first() else {
+            return false;
+        };
+        let literal_len = rest
+            .iter()
+            .take_while(|node| matches!(node, Self::Char(_)))
+            .count();
+
+        match &rest[literal_len..] {
+            [] => true,
+            [
+                Self::Repeat {
+                    node,
+                    min: 0,
+                    max: None,
+                },
+            ] => matches!(node.as_ref(), Self::Any),
+            [
+                Self::Repeat {
+                    node,
+                    min: 0,
+                    max: None,
+                },
+                Self::End,
+            ] => {
+                matches!(node.as_ref(), Self::Any)
+            }
+            _ => false,
+        }
+    }
+}
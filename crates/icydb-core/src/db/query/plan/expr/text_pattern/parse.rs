@@ -0,0 +1,379 @@
+//! Module: query::plan::expr::text_pattern::parse
+//! Responsibility: LIKE and regular-expression pattern syntax.
+//! Does not own: instruction compilation or match execution.
+//! Boundary: turns one length-checked pattern string into the shared pattern
+//! node tree consumed by the program compiler.
+
+use crate::db::query::plan::expr::text_pattern::{
+    MAX_TEXT_PATTERN_NESTING, MAX_TEXT_PATTERN_REPEAT, TextPatternError,
+};
+
+///
+/// PatternNode
+///
+/// Syntax-neutral pattern tree shared by LIKE and regular-expression parsing.
+/// LIKE patterns always parse as `Start ... End` concatenations so both
+/// languages compile through one program builder.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) enum PatternNode {
+    Empty,
+    Char(char),
+    Any,
+    Class(CharClass),
+    Start,
+    End,
+    Concat(Vec<Self>),
+    Alternate(Vec<Self>),
+    Repeat {
+        node: Box<Self>,
+        min: u32,
+        max: Option<u32>,
+    },
+}
+
+///
+/// CharClass
+///
+/// Inclusive character ranges plus negation for one bracket or shorthand
+/// class.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) struct CharClass {
+    ranges: Vec<(char, char)>,
+    negated: bool,
+}
+
+impl CharClass {
+    const DIGIT: &[(char, char)] = &[('0', '9')];
+    const WORD: &[(char, char)] = &[('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')];
+    const SPACE: &[(char, char)] = &[('\t', '\r'), (' ', ' ')];
+
+    fn shorthand(ranges: &[(char, char)], negated: bool) -> Self {
+        Self {
+            ranges: ranges.to_vec(),
+            negated,
+        }
+    }
+
+    /// Return whether one character belongs to this class.
+    pub(super) fn matches(&self, ch: char) -> bool {
+        self.ranges
+            .iter()
+            .any(|(low, high)| (*low..=*high).contains(&ch))
+            != self.negated
+    }
+}
+
+/// Parse one canonical LIKE pattern, where `\` escapes the next character.
+pub(super) fn parse_like_pattern(pattern: &str) -> Result<PatternNode, TextPatternError> {
+    let mut nodes = vec![PatternNode::Start];
+    let mut chars = pattern.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => nodes.push(PatternNode::Char(
+                chars.next().ok_or(TextPatternError::Malformed)?,
+            )),
+            '%' => {
+                // Adjacent `%` wildcards match the same inputs as one.
+                if !matches!(nodes.last(), Some(PatternNode::Repeat { .. })) {
+                    nodes.push(any_run());
+                }
+            }
+            '_' => nodes.push(PatternNode::Any),
+            ch => nodes.push(PatternNode::Char(ch)),
+        }
+    }
+    nodes.push(PatternNode::End);
+
+    Ok(PatternNode::Concat(nodes))
+}
+
+/// Parse one regular expression from the supported subset: literals, `.`,
+/// bracket classes, `\d \w \s` shorthands, `^ $`, groups, `|`, and the
+/// `* + ? {m,n}` quantifiers.
+pub(super) fn parse_regex_pattern(pattern: &str) -> Result<PatternNode, TextPatternError> {
+    let mut parser = RegexParser {
+        chars: pattern.chars().collect(),
+        pos: 0,
+        depth: 0,
+    };
+    let node = parser.parse_alternation()?;
+    if parser.pos != parser.chars.len() {
+        return Err(TextPatternError::Malformed);
+    }
+
+    Ok(node)
+}
+
+fn any_run() -> PatternNode {
+    PatternNode::Repeat {
+        node: Box::new(PatternNode::Any),
+        min: 0,
+        max: None,
+    }
+}
+
+///
+/// RegexParser
+///
+/// Recursive-descent cursor over one regular-expression pattern.
+/// Group nesting is capped so hostile patterns cannot exhaust the stack.
+///
+
+struct RegexParser {
+    chars: Vec<char>,
+    pos: usize,
+    depth: usize,
+}
+
+impl RegexParser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos.saturating_add(offset)).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.pos += 1;
+
+        Some(ch)
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            return true;
+        }
+
+        false
+    }
+
+    fn parse_alternation(&mut self) -> Result<PatternNode, TextPatternError> {
+        let first = self.parse_concat()?;
+        if self.peek() != Some('|') {
+            return Ok(first);
+        }
+
+        let mut branches = vec![first];
+        while self.eat('|') {
+            branches.push(self.parse_concat()?);
+        }
+
+        Ok(PatternNode::Alternate(branches))
+    }
+
+    fn parse_concat(&mut self) -> Result<PatternNode, TextPatternError> {
+        let mut nodes = Vec::new();
+        while let Some(ch) = self.peek() {
+            if ch == '|' || ch == ')' {
+                break;
+            }
+            let atom = self.parse_atom()?;
+            nodes.push(self.parse_quantifier(atom)?);
+        }
+
+        Ok(match nodes.len() {
+            0 => PatternNode::Empty,
+            1 => nodes.pop().unwrap_or(PatternNode::Empty),
+            _ => PatternNode::Concat(nodes),
+        })
+    }
+
+    fn parse_atom(&mut self) -> Result<PatternNode, TextPatternError> {
+        match self.bump().ok_or(TextPatternError::Malformed)? {
+            '(' => self.parse_group(),
+            '[' => self.parse_class().map(PatternNode::Class),
+            '.' => Ok(PatternNode::Any),
+            '^' => Ok(PatternNode::Start),
+            '$' => Ok(PatternNode::End),
+            '\\' => self.parse_escape(),
+            '*' | '+' | '?' | '{' | ')' => Err(TextPatternError::Malformed),
+            ch => Ok(PatternNode::Char(ch)),
+        }
+    }
+
+    // Groups only affect precedence: matching is boolean, so `(?:...)` and
+    // `(...)` are the same node.
+    fn parse_group(&mut self) -> Result<PatternNode, TextPatternError> {
+        self.depth += 1;
+        if self.depth > MAX_TEXT_PATTERN_NESTING {
+            return Err(TextPatternError::TooLarge);
+        }
+        if self.eat('?') && !self.eat(':') {
+            return Err(TextPatternError::Malformed);
+        }
+        let inner = self.parse_alternation()?;
+        if !self.eat(')') {
+            return Err(TextPatternError::Malformed);
+        }
+        self.depth -= 1;
+
+        Ok(inner)
+    }
+
+    fn parse_escape(&mut self) -> Result<PatternNode, TextPatternError> {
+        let ch = self.bump().ok_or(TextPatternError::Malformed)?;
+        let node = match ch {
+            'd' => PatternNode::Class(CharClass::shorthand(CharClass::DIGIT, false)),
+            'D' => PatternNode::Class(CharClass::shorthand(CharClass::DIGIT, true)),
+            'w' => PatternNode::Class(CharClass::shorthand(CharClass::WORD, false)),
+            'W' => PatternNode::Class(CharClass::shorthand(CharClass::WORD, true)),
+            's' => PatternNode::Class(CharClass::shorthand(CharClass::SPACE, false)),
+            'S' => PatternNode::Class(CharClass::shorthand(CharClass::SPACE, true)),
+            'n' => PatternNode::Char('\n'),
+            'r' => PatternNode::Char('\r'),
+            't' => PatternNode::Char('\t'),
+            ch if is_regex_meta(ch) => PatternNode::Char(ch),
+            _ => return Err(TextPatternError::Malformed),
+        };
+
+        Ok(node)
+    }
+
+    fn parse_class(&mut self) -> Result<CharClass, TextPatternError> {
+        let negated = self.eat('^');
+        let mut ranges = Vec::new();
+        let mut first = true;
+        loop {
+            let ch = self.bump().ok_or(TextPatternError::Malformed)?;
+            if ch == ']' && !first {
+                break;
+            }
+            first = false;
+
+            let low = match self.parse_class_char(ch)? {
+                ClassItem::Char(low) => low,
+                ClassItem::Shorthand(class) => {
+                    ranges.extend(class.ranges);
+                    continue;
+                }
+            };
+            if self.peek() == Some('-') && !matches!(self.peek_at(1), Some(']') | None) {
+                self.pos += 1;
+                let next = self.bump().ok_or(TextPatternError::Malformed)?;
+                let ClassItem::Char(high) = self.parse_class_char(next)? else {
+                    return Err(TextPatternError::Malformed);
+                };
+                if high < low {
+                    return Err(TextPatternError::Malformed);
+                }
+                ranges.push((low, high));
+            } else {
+                ranges.push((low, low));
+            }
+        }
+
+        Ok(CharClass { ranges, negated })
+    }
+
+    fn parse_class_char(&mut self, ch: char) -> Result<ClassItem, TextPatternError> {
+        if ch != '\\' {
+            return Ok(ClassItem::Char(ch));
+        }
+
+        match self.parse_escape()? {
+            PatternNode::Char(ch) => Ok(ClassItem::Char(ch)),
+            PatternNode::Class(class) if !class.negated => Ok(ClassItem::Shorthand(class)),
+            _ => Err(TextPatternError::Malformed),
+        }
+    }
+
+    fn parse_quantifier(&mut self, atom: PatternNode) -> Result<PatternNode, TextPatternError> {
+        let (min, max) = match self.peek() {
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('?') => (0, Some(1)),
+            Some('{') => {
+                self.pos += 1;
+                self.parse_repeat_bounds()?
+            }
+            _ => return Ok(atom),
+        };
+        self.pos += 1;
+
+        // A lazy suffix changes which match is preferred, never whether one
+        // exists, so it is accepted and ignored.
+        let _ = self.eat('?');
+        if matches!(self.peek(), Some('*' | '+' | '?' | '{')) {
+            return Err(TextPatternError::Malformed);
+        }
+
+        Ok(PatternNode::Repeat {
+            node: Box::new(atom),
+            min,
+            max,
+        })
+    }
+
+    // Parse `m}`, `m,}`, or `m,n}` after an opening brace, leaving the cursor
+    // on the closing brace.
+    fn parse_repeat_bounds(&mut self) -> Result<(u32, Option<u32>), TextPatternError> {
+        let min = self
+            .parse_repeat_count()
+            .ok_or(TextPatternError::Malformed)?;
+        let max = if self.eat(',') {
+            self.parse_repeat_count()
+        } else {
+            Some(min)
+        };
+        if self.peek() != Some('}') {
+            return Err(TextPatternError::Malformed);
+        }
+        if min > MAX_TEXT_PATTERN_REPEAT || max.is_some_and(|max| max > MAX_TEXT_PATTERN_REPEAT) {
+            return Err(TextPatternError::TooLarge);
+        }
+        if max.is_some_and(|max| max < min) {
+            return Err(TextPatternError::Malformed);
+        }
+
+        Ok((min, max))
+    }
+
+    fn parse_repeat_count(&mut self) -> Option<u32> {
+        let mut count: Option<u32> = None;
+        while let Some(digit) = self.peek().and_then(|ch| ch.to_digit(10)) {
+            self.pos += 1;
+            count = Some(count.unwrap_or(0).saturating_mul(10).saturating_add(digit));
+        }
+
+        count
+    }
+}
+
+///
+/// ClassItem
+///
+/// One bracket-class member: a single character or an embedded shorthand.
+///
+
+enum ClassItem {
+    Char(char),
+    Shorthand(CharClass),
+}
+
+const fn is_regex_meta(ch: char) -> bool {
+    matches!(
+        ch,
+        '\\' | '.'
+            | '+'
+            | '*'
+            | '?'
+            | '('
+            | ')'
+            | '|'
+            | '['
+            | ']'
+            | '{'
+            | '}'
+            | '^'
+            | '$'
+            | '-'
+            | '/'
+    )
+}
@@ -0,0 +1,334 @@
+//! Module: query::plan::expr::text_pattern::program
+//! Responsibility: pattern instruction programs and their linear-time matcher.
+//! Does not own: pattern syntax or thread-level step accounting.
+//! Boundary: compiles one pattern node tree under the instruction ceiling and
+//! simulates it as a Thompson NFA with one deduplicated thread set per input
+//! position.
+
+use crate::db::query::plan::expr::text_pattern::{
+    MAX_TEXT_PATTERN_INSTRUCTIONS, MAX_TEXT_PATTERN_MATCH_STEPS, TextPatternError,
+    TextPatternStepLimit,
+    parse::{CharClass, PatternNode},
+};
+
+///
+/// Inst
+///
+/// One matcher instruction. `Char`, `Any`, and `Class` consume one input
+/// character; the rest are epsilon transitions or assertions.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Inst {
+    Char(char),
+    Any,
+    Class(CharClass),
+    Split(usize, usize),
+    Jump(usize),
+    AssertStart,
+    AssertEnd,
+    Match,
+}
+
+///
+/// Program
+///
+/// Compiled instruction list for one pattern.
+/// `anchored` records a leading start assertion so the matcher can stop
+/// seeding new threads after the first input position.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) struct Program {
+    insts: Box<[Inst]>,
+    anchored: bool,
+}
+
+impl Program {
+    /// Compile one pattern tree, failing once the instruction ceiling is hit.
+    pub(super) fn compile(node: &PatternNode) -> Result<Self, TextPatternError> {
+        let mut compiler = ProgramCompiler { insts: Vec::new() };
+        compiler.emit(node)?;
+        compiler.push(Inst::Match)?;
+
+        Ok(Self {
+            insts: compiler.insts.into_boxed_slice(),
+            anchored: node.is_start_anchored(),
+        })
+    }
+
+    /// Return whether any substring match exists, charging every instruction
+    /// visit to the caller-owned meter.
+    pub(super) fn is_match(
+        &self,
+        text: &str,
+        meter: &mut StepMeter,
+    ) -> Result<bool, TextPatternStepLimit> {
+        let mut current = ThreadSet::new(self.insts.len());
+        let mut next = ThreadSet::new(self.insts.len());
+        let mut stack = Vec::new();
+        let mut chars = text.chars().peekable();
+
+        let at_end = chars.peek().is_none();
+        if self.add_thread(&mut current, &mut stack, 0, true, at_end, meter)? {
+            return Ok(true);
+        }
+
+        while let Some(ch) = chars.next() {
+            let at_end = chars.peek().is_none();
+            next.clear();
+            for &pc in &current.dense {
+                meter.charge()?;
+                let advances = match &self.insts[pc] {
+                    Inst::Char(expected) => *expected == ch,
+                    Inst::Any => true,
+                    Inst::Class(class) => class.matches(ch),
+                    Inst::Split(..)
+                    | Inst::Jump(_)
+                    | Inst::AssertStart
+                    | Inst::AssertEnd
+                    | Inst::Match => false,
+                };
+                if advances
+                    && self.add_thread(&mut next, &mut stack, pc + 1, false, at_end, meter)?
+                {
+                    return Ok(true);
+                }
+            }
+            if !self.anchored && self.add_thread(&mut next, &mut stack, 0, false, at_end, meter)? {
+                return Ok(true);
+            }
+            if next.dense.is_empty() && self.anchored {
+                return Ok(false);
+            }
+            std::mem::swap(&mut current, &mut next);
+        }
+
+        Ok(false)
+    }
+
+    // Follow epsilon transitions from one program counter, recording every
+    // reached instruction once per input position. Returns `true` as soon as
+    // the match instruction is reachable.
+    fn add_thread(
+        &self,
+        set: &mut ThreadSet,
+        stack: &mut Vec<usize>,
+        pc: usize,
+        at_start: bool,
+        at_end: bool,
+        meter: &mut StepMeter,
+    ) -> Result<bool, TextPatternStepLimit> {
+        stack.push(pc);
+        while let Some(pc) = stack.pop() {
+            if !set.insert(pc) {
+                continue;
+            }
+            meter.charge()?;
+            match &self.insts[pc] {
+                Inst::Split(first, second) => {
+                    stack.push(*second);
+                    stack.push(*first);
+                }
+                Inst::Jump(target) => stack.push(*target),
+                Inst::AssertStart if at_start => stack.push(pc + 1),
+                Inst::AssertEnd if at_end => stack.push(pc + 1),
+                Inst::Match => {
+                    stack.clear();
+                    return Ok(true);
+                }
+                Inst::AssertStart
+                | Inst::AssertEnd
+                | Inst::Char(_)
+                | Inst::Any
+                | Inst::Class(_) => {}
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+///
+/// StepMeter
+///
+/// Per-evaluation matcher step counter with a hard ceiling.
+///
+
+#[derive(Default)]
+pub(super) struct StepMeter {
+    steps: u64,
+}
+
+impl StepMeter {
+    /// Return the steps charged so far.
+    pub(super) const fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    const fn charge(&mut self) -> Result<(), TextPatternStepLimit> {
+        self.steps += 1;
+        if self.steps > MAX_TEXT_PATTERN_MATCH_STEPS {
+            return Err(TextPatternStepLimit);
+        }
+
+        Ok(())
+    }
+}
+
+///
+/// ThreadSet
+///
+/// Sparse set of live program counters for one input position. Clearing is
+/// constant time, which keeps each position's cost proportional to the
+/// threads actually reached.
+///
+
+struct ThreadSet {
+    dense: Vec<usize>,
+    sparse: Box<[usize]>,
+}
+
+impl ThreadSet {
+    fn new(capacity: usize) -> Self {
+        Self {
+            dense: Vec::with_capacity(capacity),
+            sparse: vec![0; capacity].into_boxed_slice(),
+        }
+    }
+
+    fn insert(&mut self, pc: usize) -> bool {
+        let index = self.sparse[pc];
+        if self.dense.get(index) == Some(&pc) {
+            return false;
+        }
+        self.sparse[pc] = self.dense.len();
+        self.dense.push(pc);
+
+        true
+    }
+
+    fn clear(&mut self) {
+        self.dense.clear();
+    }
+}
+
+///
+/// ProgramCompiler
+///
+/// Instruction emitter that enforces `MAX_TEXT_PATTERN_INSTRUCTIONS` on every
+/// push, so expanded bounded repetition cannot grow past the ceiling.
+///
+
+struct ProgramCompiler {
+    insts: Vec<Inst>,
+}
+
+impl ProgramCompiler {
+    fn push(&mut self, inst: Inst) -> Result<usize, TextPatternError> {
+        if self.insts.len() >= MAX_TEXT_PATTERN_INSTRUCTIONS {
+            return Err(TextPatternError::TooLarge);
+        }
+        self.insts.push(inst);
+
+        Ok(self.insts.len() - 1)
+    }
+
+    fn emit(&mut self, node: &PatternNode) -> Result<(), TextPatternError> {
+        match node {
+            PatternNode::Empty => {}
+            PatternNode::Char(ch) => {
+                self.push(Inst::Char(*ch))?;
+            }
+            PatternNode::Any => {
+                self.push(Inst::Any)?;
+            }
+            PatternNode::Class(class) => {
+                self.push(Inst::Class(class.clone()))?;
+            }
+            PatternNode::Start => {
+                self.push(Inst::AssertStart)?;
+            }
+            PatternNode::End => {
+                self.push(Inst::AssertEnd)?;
+            }
+            PatternNode::Concat(nodes) => {
+                for node in nodes {
+                    self.emit(node)?;
+                }
+            }
+            PatternNode::Alternate(branches) => self.emit_alternation(branches)?,
+            PatternNode::Repeat { node, min, max } => self.emit_repeat(node, *min, *max)?,
+        }
+
+        Ok(())
+    }
+
+    // Lay out `a|b|c` as a split chain whose branches all jump to one shared
+    // exit.
+    fn emit_alternation(&mut self, branches: &[PatternNode]) -> Result<(), TextPatternError> {
+        let mut exits = Vec::with_capacity(branches.len());
+        let Some((last, leading)) = branches.split_last() else {
+            return Ok(());
+        };
+        for branch in leading {
+            let split = self.push(Inst::Split(0, 0))?;
+            self.emit(branch)?;
+            exits.push(self.push(Inst::Jump(0))?);
+            self.insts[split] = Inst::Split(split + 1, self.insts.len());
+        }
+        self.emit(last)?;
+
+        let exit = self.insts.len();
+        for jump in exits {
+            self.insts[jump] = Inst::Jump(exit);
+        }
+
+        Ok(())
+    }
+
+    // Expand `{min,max}` into `min` required copies followed by either one
+    // star loop or `max - min` optional copies.
+    fn emit_repeat(
+        &mut self,
+        node: &PatternNode,
+        min: u32,
+        max: Option<u32>,
+    ) -> Result<(), TextPatternError> {
+        for _ in 0..min {
+            self.emit(node)?;
+        }
+
+        let Some(max) = max else {
+            let split = self.push(Inst::Split(0, 0))?;
+            self.emit(node)?;
+            self.push(Inst::Jump(split))?;
+            self.insts[split] = Inst::Split(split + 1, self.insts.len());
+
+            return Ok(());
+        };
+
+        let mut splits = Vec::new();
+        for _ in min..max {
+            splits.push(self.push(Inst::Split(0, 0))?);
+            self.emit(node)?;
+        }
+        let exit = self.insts.len();
+        for split in splits {
+            self.insts[split] = Inst::Split(split + 1, exit);
+        }
+
+        Ok(())
+    }
+}
+
+impl PatternNode {
+    // A leading start assertion pins every match to input position zero.
+    fn is_start_anchored(&self) -> bool {
+        match self {
+            Self::Start => true,
+            Self::Concat(nodes) => matches!(nodes.first(), Some(Self::Start)),
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,172 @@
+#[cfg(feature = "sql")]
+use crate::db::query::plan::expr::text_pattern::canonicalize_like_pattern;
+use crate::db::query::plan::expr::text_pattern::{
+    MAX_TEXT_PATTERN_CHARS, TextPattern, TextPatternError, TextPatternStepLimit, TextPatternSyntax,
+    take_text_pattern_match_steps,
+};
+
+fn like(pattern: &str) -> TextPattern {
+    TextPattern::compile(TextPatternSyntax::Like, pattern).expect("LIKE pattern should compile")
+}
+
+fn regex(pattern: &str) -> TextPattern {
+    TextPattern::compile(TextPatternSyntax::Regex, pattern).expect("regex should compile")
+}
+
+fn matches(pattern: &TextPattern, text: &str) -> bool {
+    pattern
+        .is_match(text)
+        .expect("match should stay within the step ceiling")
+}
+
+#[test]
+fn like_patterns_match_whole_input_with_wildcards() {
+    let cases = [
+        ("abc", "abc", true),
+        ("abc", "abcd", false),
+        ("a%", "alpha", true),
+        ("%ha", "alpha", true),
+        ("%ph%", "alpha", true),
+        ("%ph%", "beta", false),
+        ("a_p%a", "alpha", true),
+        ("a_p%a", "alp", false),
+        ("___", "abc", true),
+        ("___", "ab", false),
+        ("%", "", true),
+        ("_", "", false),
+        ("%%%x", "aax", true),
+        ("é_", "éa", true),
+    ];
+
+    for (pattern, text, expected) in cases {
+        assert_eq!(
+            matches(&like(pattern), text),
+            expected,
+            "{pattern} LIKE {text}"
+        );
+    }
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn like_canonical_escape_matches_wildcards_literally() {
+    let canonical = canonicalize_like_pattern("100!%!_%", Some('!')).expect("escape is complete");
+    assert_eq!(canonical, "100\\%\\_%");
+
+    let pattern = like(&canonical);
+    assert!(matches(&pattern, "100%_ off"));
+    assert!(!matches(&pattern, "1000 off"));
+
+    let backslash = canonicalize_like_pattern("a\\b%", None).expect("no escape is declared");
+    assert!(matches(&like(&backslash), "a\\bc"));
+
+    assert_eq!(
+        canonicalize_like_pattern("abc!", Some('!')),
+        Err(TextPatternError::Malformed),
+    );
+}
+
+#[test]
+fn regex_subset_searches_unanchored_unless_asserted() {
+    let cases = [
+        ("b", "abc", true),
+        ("^b", "abc", false),
+        ("c$", "abc", true),
+        ("^a.c$", "abc", true),
+        ("^(ab|cd)+$", "abcdab", true),
+        ("^(ab|cd)+$", "abce", false),
+        ("[0-9]{3}-\\d{4}", "call 555-1234 now", true),
+        ("^[^aeiou]+$", "rhythm", true),
+        ("^[^aeiou]+$", "rhyme", false),
+        ("colou?r", "color", true),
+        ("^\\w+@\\w+\\.com$", "ann@example.com", true),
+        ("^\\w+@\\w+\\.com$", "ann@example.org", false),
+        ("a{2,}", "caab", true),
+        ("^a{2,3}$", "aaaa", false),
+        ("^(?:x|y)*z", "xyxz", true),
+        ("[a\\-z]", "-", true),
+        ("", "anything", true),
+    ];
+
+    for (pattern, text, expected) in cases {
+        assert_eq!(
+            matches(&regex(pattern), text),
+            expected,
+            "{pattern} ~ {text}"
+        );
+    }
+}
+
+#[test]
+fn literal_prefix_is_extracted_only_from_anchored_leading_literals() {
+    assert_eq!(like("abc%").literal_prefix(), "abc");
+    assert!(like("abc%").is_prefix_only());
+    assert_eq!(like("ab_c%").literal_prefix(), "ab");
+    assert!(!like("ab_c%").is_prefix_only());
+    assert_eq!(like("%abc").literal_prefix(), "");
+    assert!(!like("abc").is_prefix_only());
+    assert_eq!(like("a\\%b%").literal_prefix(), "a%b");
+
+    assert_eq!(regex("^abc").literal_prefix(), "abc");
+    assert!(regex("^abc").is_prefix_only());
+    assert!(regex("^abc.*$").is_prefix_only());
+    assert_eq!(regex("^ab*").literal_prefix(), "a");
+    assert!(!regex("^ab*").is_prefix_only());
+    assert_eq!(regex("abc").literal_prefix(), "");
+    assert_eq!(regex("^abc|abd").literal_prefix(), "");
+}
+
+#[test]
+fn malformed_and_oversized_patterns_fail_closed() {
+    for pattern in [
+        "(ab", "ab)", "[abc", "a**", "*a", "a{3,1}", "\\q", "(?=a)", "a{,2}",
+    ] {
+        assert_eq!(
+            TextPattern::compile(TextPatternSyntax::Regex, pattern),
+            Err(TextPatternError::Malformed),
+            "{pattern}",
+        );
+    }
+    assert_eq!(
+        TextPattern::compile(TextPatternSyntax::Like, "abc\\"),
+        Err(TextPatternError::Malformed),
+    );
+
+    let oversized = [
+        "a".repeat(MAX_TEXT_PATTERN_CHARS + 1),
+        "a{101}".to_string(),
+        "((((((((((((((((((((((((((((((((((a))))))))))))))))))))))))))))))))))".to_string(),
+        "((a{100}){100}){100}".to_string(),
+    ];
+    for pattern in oversized {
+        assert_eq!(
+            TextPattern::compile(TextPatternSyntax::Regex, &pattern),
+            Err(TextPatternError::TooLarge),
+            "{pattern}",
+        );
+    }
+}
+
+#[test]
+fn nested_quantifiers_match_in_linear_steps() {
+    let pattern = regex("^(a*)*b$");
+    let text = "a".repeat(4_096);
+
+    let _ = take_text_pattern_match_steps();
+    assert!(!matches(&pattern, &text));
+    let steps = take_text_pattern_match_steps();
+
+    assert!(steps > 0);
+    assert!(steps < 32 * 4_096, "steps should stay linear: {steps}");
+}
+
+#[test]
+fn evaluation_beyond_step_ceiling_is_rejected() {
+    let pattern = regex(&format!("{}y", "(x?){100}".repeat(5)));
+
+    assert!(!matches(&pattern, &"x".repeat(100)));
+    assert_eq!(
+        pattern.is_match(&"x".repeat(4_000)),
+        Err(TextPatternStepLimit)
+    );
+}
@@ -26,8 +26,30 @@ pub(super) fn infer_aggregate_expr_type(
         AggregateKind::Sum | AggregateKind::Avg => {
             infer_sum_aggregate_type(kind, input_expr, schema)
         }
-        AggregateKind::Min | AggregateKind::Max | AggregateKind::First | AggregateKind::Last => {
-            infer_target_field_aggregate_type(input_expr, schema)
+        AggregateKind::VarPop
+        | AggregateKind::VarSamp
+        | AggregateKind::StddevPop
+        | AggregateKind::StddevSamp => {
+            // Statistical aggregates share SUM's numeric-input contract but
+            // always finalize through decimal arithmetic.
+            infer_sum_aggregate_type(kind, input_expr, schema)?;
+
+            Ok(ExprType::Numeric(NumericSubtype::Decimal))
+        }
+        AggregateKind::Min
+        | AggregateKind::Max
+        | AggregateKind::First
+        | AggregateKind::Last
+        | AggregateKind::PercentileDisc => infer_target_field_aggregate_type(input_expr, schema),
+        AggregateKind::StringAgg => {
+            infer_target_field_aggregate_type(input_expr, schema)?;
+
+            Ok(ExprType::Text)
+        }
+        AggregateKind::ArrayAgg => {
+            infer_target_field_aggregate_type(input_expr, schema)?;
+
+            Ok(ExprType::Collection)
         }
     }
 }
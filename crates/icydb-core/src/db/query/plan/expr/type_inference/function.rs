@@ -2,25 +2,30 @@ use crate::db::{
     query::plan::{
         PlanError,
         expr::{
-            Expr, Function, FunctionTypeInferenceShape, NumericSubtype,
+            Expr, Function, FunctionTypeInferenceShape, NumericSubtype, TemporalField,
+            cast_target_kind, temporal_field_arg,
             type_inference::{
-                ExprType, FunctionArgumentFamily, infer_expr_type, unify::unify_coalesce_expr_types,
+                ExprType, FunctionArgumentFamily, infer_expr_type,
+                source::expr_reads_text_keyed_map, unify::unify_coalesce_expr_types,
             },
         },
         validate::{ExprPlanError, ExprPlanTypeClass},
     },
-    schema::SchemaInfo,
+    schema::{AcceptedFieldKind, SchemaInfo},
 };
 
 impl FunctionTypeInferenceShape {
     fn argument_family(self, index: usize) -> Option<FunctionArgumentFamily> {
         match self {
             Self::ByteLengthResult
+            | Self::CastResult
             | Self::UnaryBoolPredicate
             | Self::CollectionContains
             | Self::DynamicCoalesce
             | Self::DynamicNullIf
-            | Self::Membership => None,
+            | Self::MapHasKey
+            | Self::Membership
+            | Self::TemporalResult => None,
             Self::TextResult {
                 text_positions,
                 numeric_positions,
@@ -54,9 +59,12 @@ impl FunctionTypeInferenceShape {
     fn infer_function_result_type(
         self,
         function: Function,
+        arg_exprs: &[Expr],
         args: &[ExprType],
     ) -> Result<ExprType, PlanError> {
         match self {
+            Self::CastResult => infer_cast_function_type(function, arg_exprs, args),
+            Self::TemporalResult => infer_temporal_function_type(function, arg_exprs, args),
             Self::ByteLengthResult => {
                 validate_byte_length_function_args(function, args)?;
 
@@ -77,6 +85,11 @@ impl FunctionTypeInferenceShape {
 
                 Ok(ExprType::Bool)
             }
+            Self::MapHasKey => {
+                validate_map_has_key_function_args(function, args)?;
+
+                Ok(ExprType::Bool)
+            }
             Self::TextResult { .. } => {
                 validate_function_arg_families(function, args, self)?;
 
@@ -116,6 +129,21 @@ fn validate_membership_function_args(
     Ok(())
 }
 
+// `HAS_KEY` takes one map input and one text key. The map input itself is
+// checked against the schema by `infer_function_expr_type`.
+fn validate_map_has_key_function_args(
+    function: Function,
+    args: &[ExprType],
+) -> Result<(), PlanError> {
+    validate_exact_function_arg_count(function, args.len(), 2)?;
+
+    if !expr_type_accepts_required_family(&args[1], FunctionArgumentFamily::Text) {
+        return Err(invalid_function_argument(function, 1, &args[1]));
+    }
+
+    Ok(())
+}
+
 /// Report whether planner typing classifies one scalar function as part of the
 /// text/numeric compare-operand family consumed by canonicalization.
 #[must_use]
@@ -134,10 +162,18 @@ pub(super) fn infer_function_expr_type(
         .iter()
         .map(|arg| infer_expr_type(arg, schema))
         .collect::<Result<Vec<_>, _>>()?;
+    if matches!(
+        function.type_inference_shape(),
+        FunctionTypeInferenceShape::MapHasKey
+    ) && let Some(input) = args.first()
+        && !expr_reads_text_keyed_map(input, schema)
+    {
+        return Err(invalid_function_argument(function, 0, &arg_types[0]));
+    }
 
     function
         .type_inference_shape()
-        .infer_function_result_type(function, arg_types.as_slice())
+        .infer_function_result_type(function, args, arg_types.as_slice())
 }
 
 fn validate_exact_function_arg_count(
@@ -249,6 +285,103 @@ fn validate_numeric_scale_function_args(
     Ok(())
 }
 
+// CAST freezes its target type name as the second literal argument, so the
+// result type comes from that literal while the source only has to belong to
+// one coarse family the checked-cast matrix can read.
+fn infer_cast_function_type(
+    function: Function,
+    arg_exprs: &[Expr],
+    args: &[ExprType],
+) -> Result<ExprType, PlanError> {
+    validate_exact_function_arg_count(function, args.len(), 2)?;
+
+    let source_compatible = matches!(
+        args[0],
+        ExprType::Text
+            | ExprType::Numeric(
+                NumericSubtype::Integer | NumericSubtype::Decimal | NumericSubtype::Unknown
+            )
+            | ExprType::Opaque
+            | ExprType::Unknown
+    ) || {
+        #[cfg(test)]
+        {
+            matches!(args[0], ExprType::Null)
+        }
+        #[cfg(not(test))]
+        {
+            false
+        }
+    };
+    if !source_compatible {
+        return Err(invalid_function_argument(function, 0, &args[0]));
+    }
+
+    let Some(target) = cast_target_kind(arg_exprs) else {
+        return Err(invalid_function_argument(function, 1, &args[1]));
+    };
+
+    Ok(match target {
+        AcceptedFieldKind::Decimal { .. } => ExprType::Numeric(NumericSubtype::Decimal),
+        AcceptedFieldKind::Text { .. } => ExprType::Text,
+        AcceptedFieldKind::Ulid => ExprType::Opaque,
+        _ => ExprType::Numeric(NumericSubtype::Integer),
+    })
+}
+
+// Temporal functions read timestamps and dates, which planner typing only
+// tracks as integer-shaped or opaque values, so admission stays at that coarse
+// family while the frozen field literal decides the calendar unit.
+fn infer_temporal_function_type(
+    function: Function,
+    arg_exprs: &[Expr],
+    args: &[ExprType],
+) -> Result<ExprType, PlanError> {
+    let (arity, input_positions): (usize, &[usize]) = match function {
+        Function::Now => (0, &[]),
+        Function::Age => (2, &[0, 1]),
+        _ => (2, &[1]),
+    };
+    validate_exact_function_arg_count(function, args.len(), arity)?;
+
+    if matches!(function, Function::DateTrunc | Function::Extract) {
+        let field = temporal_field_arg(arg_exprs);
+        let admitted = match function {
+            Function::DateTrunc => field.is_some_and(TemporalField::admits_truncation),
+            _ => field.is_some(),
+        };
+        if !admitted {
+            return Err(invalid_function_argument(function, 0, &args[0]));
+        }
+    }
+
+    for &index in input_positions {
+        let input_compatible = matches!(
+            args[index],
+            ExprType::Numeric(NumericSubtype::Integer | NumericSubtype::Unknown)
+                | ExprType::Opaque
+                | ExprType::Unknown
+        ) || {
+            #[cfg(test)]
+            {
+                matches!(args[index], ExprType::Null)
+            }
+            #[cfg(not(test))]
+            {
+                false
+            }
+        };
+        if !input_compatible {
+            return Err(invalid_function_argument(function, index, &args[index]));
+        }
+    }
+
+    Ok(match function {
+        Function::DateTrunc => args[1].clone(),
+        _ => ExprType::Numeric(NumericSubtype::Integer),
+    })
+}
+
 fn infer_coalesce_function_type(
     function: Function,
     args: &[ExprType],
@@ -2,7 +2,7 @@ use crate::{
     db::{
         query::plan::{
             PlanError,
-            expr::{FieldId, FieldPath, NumericSubtype, type_inference::ExprType},
+            expr::{Expr, FieldId, FieldPath, NumericSubtype, type_inference::ExprType},
             validate::ExprPlanError,
         },
         schema::{FieldType, SchemaInfo},
@@ -48,6 +48,22 @@ pub(super) fn infer_field_path_expr_type(
     )
 }
 
+/// Return whether one expression reads a text-keyed map field or map-valued
+/// nested path. The coarse expression type lattice folds maps into collections,
+/// so `HAS_KEY` resolves its input against the schema directly.
+pub(super) fn expr_reads_text_keyed_map(expr: &Expr, schema: &SchemaInfo) -> bool {
+    let field_type = match expr {
+        Expr::Field(field) => schema.field(field.as_str()).cloned(),
+        Expr::FieldPath(path) => schema.nested_field_type(path.root().as_str(), path.segments()),
+        _ => None,
+    };
+
+    matches!(
+        field_type,
+        Some(FieldType::Map { key, .. }) if *key == FieldType::Scalar(ScalarKind::Text)
+    )
+}
+
 pub(super) fn render_field_path(path: &FieldPath) -> String {
     let mut label = path.root().as_str().to_string();
     for segment in path.segments() {
@@ -8,8 +8,8 @@ use crate::{
         query::{
             builder::AggregateExpr,
             plan::{
-                AccessPlannedQuery, AggregateIdentity, AggregateKind, AggregateSemanticKey,
-                FieldSlot, GlobalDistinctAggregateKind, GroupAggregateSpec,
+                AccessPlannedQuery, AggregateArguments, AggregateIdentity, AggregateKind,
+                AggregateSemanticKey, FieldSlot, GlobalDistinctAggregateKind, GroupAggregateSpec,
                 GroupDistinctAdmissibility, GroupDistinctPolicyReason, GroupedExecutionConfig,
                 GroupedPlanStrategy,
                 expr::{
@@ -55,6 +55,7 @@ pub(in crate::db) struct GroupedAggregateExecutionSpec {
     filter_expr: Option<Expr>,
     compiled_input_expr: Option<CompiledExpr>,
     compiled_filter_expr: Option<CompiledExpr>,
+    compiled_order_exprs: Vec<CompiledExpr>,
 }
 
 ///
@@ -129,6 +130,7 @@ impl GroupedAggregateExecutionSpec {
             filter_expr: aggregate_expr.filter_expr().cloned(),
             compiled_input_expr: None,
             compiled_filter_expr: None,
+            compiled_order_exprs: Vec::new(),
         }
     }
 
@@ -145,13 +147,15 @@ impl GroupedAggregateExecutionSpec {
         input_expr: Option<Expr>,
         filter_expr: Option<Expr>,
         distinct: bool,
+        arguments: Option<AggregateArguments>,
     ) -> Self {
         Self {
-            identity: AggregateIdentity::from_kind_input_and_distinct(kind, input_expr, distinct),
+            identity: AggregateIdentity::from_parts(kind, input_expr, distinct, arguments),
             target_slot,
             filter_expr,
             compiled_input_expr: None,
             compiled_filter_expr: None,
+            compiled_order_exprs: Vec::new(),
         }
     }
 
@@ -170,6 +174,14 @@ impl GroupedAggregateExecutionSpec {
                 Self::compile_attached_scalar_expr(schema_info, self.kind(), "filter", expr)
             })
             .transpose()?;
+        let compiled_order_exprs = self
+            .arguments()
+            .map_or(&[][..], AggregateArguments::order_by)
+            .iter()
+            .map(|term| {
+                Self::compile_attached_scalar_expr(schema_info, self.kind(), "order", term.expr())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
         let target_slot = self
             .target_field()
             .map(|field| {
@@ -184,6 +196,7 @@ impl GroupedAggregateExecutionSpec {
             filter_expr: self.filter_expr().cloned(),
             compiled_input_expr,
             compiled_filter_expr,
+            compiled_order_exprs,
         })
     }
 
@@ -220,6 +233,12 @@ impl GroupedAggregateExecutionSpec {
         self.filter_expr.as_ref()
     }
 
+    /// Borrow the retained-value aggregate arguments, if any.
+    #[must_use]
+    pub(in crate::db) const fn arguments(&self) -> Option<&AggregateArguments> {
+        self.identity.arguments()
+    }
+
     /// Build the aggregate identity represented by this execution spec.
     #[must_use]
     pub(in crate::db) fn identity(&self) -> AggregateIdentity {
@@ -263,6 +282,12 @@ impl GroupedAggregateExecutionSpec {
         self.compiled_filter_expr.as_ref()
     }
 
+    /// Borrow the compiled aggregate-local ORDER BY key expressions, in term order.
+    #[must_use]
+    pub(in crate::db) const fn compiled_order_exprs(&self) -> &[CompiledExpr] {
+        self.compiled_order_exprs.as_slice()
+    }
+
     /// Return whether this grouped aggregate stays eligible for the dedicated
     /// grouped `COUNT(*)` fold path.
     #[must_use]
@@ -300,6 +325,7 @@ impl GroupedAggregateExecutionSpec {
             filter_expr: None,
             compiled_input_expr: None,
             compiled_filter_expr: None,
+            compiled_order_exprs: Vec::new(),
         }
     }
 }
@@ -10,6 +10,7 @@ use crate::{
         predicate::CoercionId,
         scalar_expr::{
             ScalarExprValue, derive_non_null_scalar_expression_value, scalar_expr_value_into_value,
+            scalar_index_expression_op,
         },
         schema::PersistedIndexExpressionOp,
     },
@@ -31,6 +32,7 @@ pub(in crate::db::query::plan) fn key_item_matches_field_and_coercion<'a>(
             expression.field() == field
                 && accepted_expression_supports_lookup_coercion(expression, coercion)
         }
+        SemanticIndexKeyItemRef::Scalar(_) => false,
     }
 }
 
@@ -44,6 +46,24 @@ const fn accepted_expression_supports_lookup_coercion(
     }
 }
 
+/// Render one key-item as a canonical ORDER BY term in its stored direction.
+///
+/// Descending components carry the same relative `DESC` marker that the
+/// deterministic secondary order contract puts on terms running against the
+/// primary-key tie-break, so mixed-direction orders match by term equality.
+#[must_use]
+pub(in crate::db::query::plan) fn key_item_order_term<'a>(
+    key_item: impl Into<SemanticIndexKeyItemRef<'a>>,
+    descending: bool,
+) -> String {
+    let term = key_item.into().canonical_text();
+    if descending {
+        format!("{term} DESC")
+    } else {
+        term
+    }
+}
+
 /// Try to lower one predicate literal into a canonical key-item lookup value.
 #[must_use]
 pub(in crate::db::query::plan) fn eq_lookup_value_for_key_item<'a>(
@@ -85,6 +105,7 @@ fn lower_lookup_value_for_key_item(
                 .ok()
                 .flatten()
         }
+        SemanticIndexKeyItemRef::Scalar(_) => None,
     }
 }
 
@@ -105,6 +126,7 @@ fn derive_accepted_index_expression_value(
         | PersistedIndexExpressionOp::Day => {
             derive_accepted_temporal_expression_value(expression.op(), source)
         }
+        PersistedIndexExpressionOp::Tokens | PersistedIndexExpressionOp::Elements => Ok(None),
     }
 }
 
@@ -118,7 +140,11 @@ fn derive_accepted_text_expression_value(
         _ => return Err(IndexExpressionSourceClass::Text),
     };
 
-    derive_non_null_scalar_expression_value(accepted_expression_op(op), source)
+    let Some(op) = scalar_index_expression_op(op) else {
+        return Err(IndexExpressionSourceClass::Text);
+    };
+
+    derive_non_null_scalar_expression_value(op, source)
         .map(scalar_expr_value_into_value)
         .map_err(|_| IndexExpressionSourceClass::Text)
         .map(Some)
@@ -135,29 +161,16 @@ fn derive_accepted_temporal_expression_value(
         _ => return Err(IndexExpressionSourceClass::DateOrTimestamp),
     };
 
-    derive_non_null_scalar_expression_value(accepted_expression_op(op), source)
+    let Some(op) = scalar_index_expression_op(op) else {
+        return Err(IndexExpressionSourceClass::DateOrTimestamp);
+    };
+
+    derive_non_null_scalar_expression_value(op, source)
         .map(scalar_expr_value_into_value)
         .map_err(|_| IndexExpressionSourceClass::DateOrTimestamp)
         .map(Some)
 }
 
-const fn accepted_expression_op(
-    op: PersistedIndexExpressionOp,
-) -> crate::db::scalar_expr::ScalarIndexExpressionOp {
-    match op {
-        PersistedIndexExpressionOp::Lower => crate::db::scalar_expr::ScalarIndexExpressionOp::Lower,
-        PersistedIndexExpressionOp::Upper => crate::db::scalar_expr::ScalarIndexExpressionOp::Upper,
-        PersistedIndexExpressionOp::Trim => crate::db::scalar_expr::ScalarIndexExpressionOp::Trim,
-        PersistedIndexExpressionOp::LowerTrim => {
-            crate::db::scalar_expr::ScalarIndexExpressionOp::LowerTrim
-        }
-        PersistedIndexExpressionOp::Date => crate::db::scalar_expr::ScalarIndexExpressionOp::Date,
-        PersistedIndexExpressionOp::Year => crate::db::scalar_expr::ScalarIndexExpressionOp::Year,
-        PersistedIndexExpressionOp::Month => crate::db::scalar_expr::ScalarIndexExpressionOp::Month,
-        PersistedIndexExpressionOp::Day => crate::db::scalar_expr::ScalarIndexExpressionOp::Day,
-    }
-}
-
 /// Try to lower one starts-with predicate literal into a canonical key-item prefix value.
 #[must_use]
 pub(in crate::db::query::plan) fn starts_with_lookup_value_for_key_item<'a>(
@@ -6,8 +6,9 @@
 use crate::db::{
     predicate::{MissingRowPolicy, Predicate},
     query::plan::{
-        DeleteLimitSpec, GroupPlan, GroupSpec, LogicalPlan, OrderDirection, OrderSpec, PageSpec,
-        QueryMode, ScalarPlan, expr::Expr,
+        DeleteLimitSpec, GroupPlan, GroupSpec, LogicalPlan, NullsOrder, OrderDirection, OrderSpec,
+        PageSpec, PartitionLimitSpec, QueryMode, ScalarPlan, expr::Expr,
+        order_contract::order_term_is_provably_non_null,
     },
     schema::SchemaInfo,
 };
@@ -27,6 +28,7 @@ pub(in crate::db::query) struct LogicalPlanningInputs {
     filter_predicate_covers_expr: bool,
     order: Option<OrderSpec>,
     distinct: bool,
+    partition_limit: Option<PartitionLimitSpec>,
     group: Option<GroupSpec>,
     having_expr: Option<Expr>,
 }
@@ -49,11 +51,22 @@ impl LogicalPlanningInputs {
             filter_predicate_covers_expr,
             order,
             distinct,
+            partition_limit: None,
             group,
             having_expr,
         }
     }
 
+    /// Attach one `LIMIT k PER` partition-limit declaration.
+    #[must_use]
+    pub(in crate::db::query) fn with_partition_limit(
+        mut self,
+        partition_limit: Option<PartitionLimitSpec>,
+    ) -> Self {
+        self.partition_limit = partition_limit;
+        self
+    }
+
     /// Drop the semantic scalar filter expression when a stronger access
     /// contract already proves the same exact primary-key semantics.
     #[must_use]
@@ -78,6 +91,11 @@ impl LogicalPlanningInputs {
         self.distinct
     }
 
+    #[must_use]
+    pub(in crate::db::query) const fn has_partition_limit(&self) -> bool {
+        self.partition_limit.is_some()
+    }
+
     #[must_use]
     pub(in crate::db::query) const fn has_group(&self) -> bool {
         self.group.is_some()
@@ -105,6 +123,7 @@ pub(in crate::db::query) struct LogicalQuery {
     pub(in crate::db::query) normalized_predicate: Option<Predicate>,
     pub(in crate::db::query) order: Option<OrderSpec>,
     pub(in crate::db::query) distinct: bool,
+    pub(in crate::db::query) partition_limit: Option<PartitionLimitSpec>,
     pub(in crate::db::query) group: Option<GroupSpec>,
     pub(in crate::db::query) having_expr: Option<Expr>,
     pub(in crate::db::query) consistency: MissingRowPolicy,
@@ -123,6 +142,7 @@ pub(in crate::db::query) fn logical_query_from_logical_inputs(
         filter_predicate_covers_expr,
         order,
         distinct,
+        partition_limit,
         group,
         having_expr,
     } = inputs;
@@ -134,6 +154,7 @@ pub(in crate::db::query) fn logical_query_from_logical_inputs(
         normalized_predicate,
         order,
         distinct,
+        partition_limit,
         group,
         having_expr,
         consistency,
@@ -153,6 +174,7 @@ pub(in crate::db::query) fn build_logical_plan(
         normalized_predicate,
         order,
         distinct,
+        partition_limit,
         group,
         having_expr,
         consistency,
@@ -184,6 +206,7 @@ pub(in crate::db::query) fn build_logical_plan(
             }),
             QueryMode::Load(_) | QueryMode::Delete(_) => None,
         },
+        partition_limit,
         consistency,
     };
 
@@ -230,6 +253,7 @@ fn canonicalize_order_spec_with_primary_key_tie_break(
     append_primary_key_tie_break: bool,
 ) -> Option<OrderSpec> {
     let mut order = order?;
+    canonicalize_provably_non_null_order_terms(schema, &mut order);
     if !append_primary_key_tie_break {
         return Some(order);
     }
@@ -261,3 +285,23 @@ fn canonicalize_order_spec_with_primary_key_tie_break(
 
     Some(order)
 }
+
+// Explicit null placement is meaningless on a direct field that can never hold
+// `NULL`. Folding those terms back to the natural placement keeps plan
+// identity, cursor contracts, and index-order eligibility independent of
+// redundant `NULLS FIRST | LAST` spellings.
+fn canonicalize_provably_non_null_order_terms(schema: &SchemaInfo, order: &mut OrderSpec) {
+    let primary_key_names: Vec<&str> = schema
+        .primary_key_names()
+        .iter()
+        .map(String::as_str)
+        .collect();
+    for term in &mut order.fields {
+        if !term.overrides_natural_nulls() {
+            continue;
+        }
+        if order_term_is_provably_non_null(schema, primary_key_names.as_slice(), term) {
+            term.nulls = NullsOrder::natural_for(term.direction());
+        }
+    }
+}
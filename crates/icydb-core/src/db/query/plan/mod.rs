@@ -46,7 +46,7 @@ pub(in crate::db) use access_plan::{
 pub(in crate::db::query) use access_planner::{
     AccessPlanningInputs, normalize_query_predicate, plan_query_access_with_accepted_schema,
 };
-pub(in crate::db) use aggregate_shape::AggregateShape;
+pub(in crate::db) use aggregate_shape::{AggregateArguments, AggregateShape};
 pub(in crate::db) use cardinality_tiebreak::{
     CardinalityTiebreakCandidate, CardinalityTiebreakCandidateEvidence, CardinalityTiebreakFamily,
     CardinalityTiebreakRoutePin, CardinalityTiebreakState, ExactCardinalityTiebreakEvidence,
@@ -81,26 +81,26 @@ pub(in crate::db::query) use logical_builder::{
     LogicalPlanningInputs, build_logical_plan, canonicalize_order_spec_for_grouping,
     logical_query_from_logical_inputs,
 };
-pub(in crate::db::query::plan) use model::FieldSlotAuthority;
-pub use model::OrderDirection;
 pub(in crate::db) use model::OrderTerm;
 pub(in crate::db) use model::render_scalar_filter_expr_plan_label;
 pub(in crate::db) use model::{AggregateKind, DistinctExecutionStrategy};
+pub(in crate::db::query::plan) use model::{ComputedGroupKey, FieldSlotAuthority};
 pub(in crate::db) use model::{ContinuationPolicy, ExecutionShapeSignature, PlannerRouteProfile};
 pub(in crate::db) use model::{
     DeleteLimitSpec, FieldSlot, GlobalDistinctAggregateKind, GroupAggregateSpec, GroupPlan,
     GroupSpec, GroupedExecutionConfig, GroupedPlanAggregateFamily, LogicalPlan, OrderSpec,
-    PageSpec, ScalarPlan,
+    PageSpec, PartitionLimitSpec, ScalarPlan,
 };
 pub use model::{DeleteSpec, LoadSpec, QueryMode};
+pub use model::{NullsOrder, OrderDirection};
 pub(in crate::db) use order_contract::{
     DeterministicSecondaryIndexOrderMatch, DeterministicSecondaryOrderContract,
     ExecutionOrderContract, ExecutionOrdering,
     access_satisfies_deterministic_secondary_order_contract,
-    deterministic_secondary_index_key_items_order_compatibility,
+    deterministic_secondary_index_order_compatibility,
     deterministic_secondary_index_order_terms_satisfied, grouped_index_order_terms_satisfied,
 };
-pub(in crate::db) use order_term::index_key_item_order_terms;
+pub(in crate::db) use order_term::{index_order_terms, scalar_expression_index_term};
 pub(in crate::db) use parameters::PreparedQueryParameterContract;
 pub(in crate::db) use pipeline::PreparedScalarPlanningState;
 pub(in crate::db) use pipeline::apply_exact_cardinality_tiebreak_selection;
@@ -141,15 +141,16 @@ pub(in crate::db) use semantics::{
     project_explain_access_path, resolve_global_distinct_field_aggregate,
 };
 pub(in crate::db) use semantics::{
-    LogicalPushdownEligibility, derive_logical_pushdown_eligibility,
-    grouped_cursor_policy_violation, grouped_plan_strategy,
+    LogicalPushdownEligibility, bind_computed_group_key_refs, derive_logical_pushdown_eligibility,
+    expand_computed_group_key_refs, grouped_cursor_policy_violation, grouped_plan_strategy,
 };
 pub(crate) use validate::PlanError;
 pub(crate) use validate::PolicyPlanError;
 #[cfg(feature = "sql")]
 pub(in crate::db) use validate::resolve_aggregate_target_field_slot_with_schema;
 pub(in crate::db) use validate::{
-    resolve_group_field_slot_with_schema, validate_cursor_order_plan_shape,
+    resolve_group_expr_slot_with_schema, resolve_group_field_slot_with_schema,
+    validate_cursor_order_plan_shape,
 };
 pub(in crate::db::query) use validate::{
     validate_group_query_semantics_with_schema, validate_intent_plan_shape,
@@ -377,6 +378,7 @@ pub(in crate::db) struct AcceptedPlannerFieldPathIndexField {
     field_name: String,
     slot: usize,
     path: Vec<String>,
+    descending: bool,
 }
 
 impl AcceptedPlannerFieldPathIndexField {
@@ -385,6 +387,7 @@ impl AcceptedPlannerFieldPathIndexField {
             field_name: field.field_name().to_string(),
             slot: field.slot(),
             path: field.path().to_vec(),
+            descending: field.descending(),
         }
     }
 
@@ -407,11 +410,16 @@ impl AcceptedPlannerFieldPathIndexField {
     }
 
     fn term(&self) -> String {
-        if self.path.len() <= 1 {
-            return self.field_name.clone();
+        let term = if self.path.len() <= 1 {
+            self.field_name.clone()
+        } else {
+            self.path.join(".")
+        };
+        if self.descending {
+            return format!("{term} DESC");
         }
 
-        self.path.join(".")
+        term
     }
 
     fn debug_contract_consistent(&self) -> bool {
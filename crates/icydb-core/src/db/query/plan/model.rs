@@ -11,7 +11,7 @@ use crate::db::{
         builder::scalar_projection::render_scalar_projection_expr_plan_label,
         plan::{
             aggregate_shape::AggregateShape,
-            expr::{Expr, FieldId, normalize_bool_expr},
+            expr::{CompiledExpr, Expr, FieldId, normalize_bool_expr},
             order_contract::DeterministicSecondaryOrderContract,
             semantics::LogicalPushdownEligibility,
         },
@@ -96,26 +96,84 @@ pub enum OrderDirection {
     Desc,
 }
 
+///
+/// NullsOrder
+/// Executor-facing null placement for one ORDER BY term.
+///
+/// The canonical value comparator sorts `NULL` before present values, so
+/// `ASC` naturally places nulls first and `DESC` naturally places them last.
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NullsOrder {
+    First,
+    Last,
+}
+
+impl NullsOrder {
+    /// Return the null placement the canonical comparator yields for one
+    /// direction when no explicit placement was requested.
+    #[must_use]
+    pub const fn natural_for(direction: OrderDirection) -> Self {
+        match direction {
+            OrderDirection::Asc => Self::First,
+            OrderDirection::Desc => Self::Last,
+        }
+    }
+
+    /// Return whether this placement matches the natural placement for one
+    /// direction.
+    #[must_use]
+    pub const fn is_natural_for(self, direction: OrderDirection) -> bool {
+        matches!(
+            (self, direction),
+            (Self::First, OrderDirection::Asc) | (Self::Last, OrderDirection::Desc)
+        )
+    }
+
+    /// Return how one null value compares against one present value under
+    /// this placement, independent of the term direction.
+    #[must_use]
+    pub(in crate::db) const fn null_before_present_ordering(self) -> std::cmp::Ordering {
+        match self {
+            Self::First => std::cmp::Ordering::Less,
+            Self::Last => std::cmp::Ordering::Greater,
+        }
+    }
+}
+
 ///
 /// OrderTerm
 ///
 /// Planner-owned canonical ORDER BY term contract.
-/// Carries one semantic expression plus direction so downstream validation and
-/// execution stay expression-first, with rendered labels derived only at
-/// diagnostic, explain, and hashing edges.
+/// Carries one semantic expression plus direction and resolved null placement
+/// so downstream validation and execution stay expression-first, with
+/// rendered labels derived only at diagnostic, explain, and hashing edges.
 ///
 
 #[derive(Clone, Eq, PartialEq)]
 pub(in crate::db) struct OrderTerm {
     pub(in crate::db) expr: Expr,
     pub(in crate::db) direction: OrderDirection,
+    pub(in crate::db) nulls: NullsOrder,
 }
 
 impl OrderTerm {
     /// Construct one planner-owned ORDER BY term from one semantic expression.
+    /// Null placement starts at the natural placement for `direction`.
     #[must_use]
     pub(in crate::db) const fn new(expr: Expr, direction: OrderDirection) -> Self {
-        Self { expr, direction }
+        Self {
+            expr,
+            direction,
+            nulls: NullsOrder::natural_for(direction),
+        }
+    }
+
+    /// Replace the null placement carried by this ORDER BY term.
+    #[must_use]
+    pub(in crate::db) const fn with_nulls(mut self, nulls: NullsOrder) -> Self {
+        self.nulls = nulls;
+        self
     }
 
     /// Construct one direct field ORDER BY term.
@@ -151,6 +209,19 @@ impl OrderTerm {
     pub(in crate::db) const fn direction(&self) -> OrderDirection {
         self.direction
     }
+
+    /// Return the resolved null placement for this ORDER BY term.
+    #[must_use]
+    pub(in crate::db) const fn nulls(&self) -> NullsOrder {
+        self.nulls
+    }
+
+    /// Return whether this term requests a null placement the canonical
+    /// comparator does not already produce for its direction.
+    #[must_use]
+    pub(in crate::db) const fn overrides_natural_nulls(&self) -> bool {
+        !self.nulls.is_natural_for(self.direction)
+    }
 }
 
 impl std::fmt::Debug for OrderTerm {
@@ -159,6 +230,7 @@ impl std::fmt::Debug for OrderTerm {
             .field("label", &self.rendered_label())
             .field("expr", &self.expr)
             .field("direction", &self.direction)
+            .field("nulls", &self.nulls)
             .finish()
     }
 }
@@ -378,6 +450,22 @@ pub(in crate::db) struct PageSpec {
     pub(in crate::db) offset: u32,
 }
 
+///
+/// PartitionLimitSpec
+/// Executor-facing per-partition row window (`LIMIT k PER field, ...`).
+///
+/// Rows are partitioned by the listed fields and only the first `limit`
+/// rows of each partition, in plan order, survive. Distinct partitions count
+/// against `execution` exactly like grouped keys.
+///
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub(in crate::db) struct PartitionLimitSpec {
+    pub(in crate::db) fields: Vec<String>,
+    pub(in crate::db) limit: u32,
+    pub(in crate::db) execution: GroupedExecutionConfig,
+}
+
 ///
 /// AggregateKind
 ///
@@ -397,6 +485,13 @@ pub enum AggregateKind {
     Max,
     First,
     Last,
+    VarPop,
+    VarSamp,
+    StddevPop,
+    StddevSamp,
+    PercentileDisc,
+    StringAgg,
+    ArrayAgg,
 }
 
 ///
@@ -457,6 +552,13 @@ impl AggregateKind {
             Self::Last => "LAST",
             Self::Min => "MIN",
             Self::Max => "MAX",
+            Self::VarPop => "VAR_POP",
+            Self::VarSamp => "VAR_SAMP",
+            Self::StddevPop => "STDDEV_POP",
+            Self::StddevSamp => "STDDEV_SAMP",
+            Self::PercentileDisc => "PERCENTILE_DISC",
+            Self::StringAgg => "STRING_AGG",
+            Self::ArrayAgg => "ARRAY_AGG",
         }
     }
 
@@ -478,19 +580,36 @@ impl AggregateKind {
         matches!(self, Self::Min | Self::Max)
     }
 
-    /// Return whether this kind supports one grouped or global field target.
+    /// Return whether this terminal kind belongs to the variance/standard
+    /// deviation family.
     #[must_use]
-    pub(in crate::db) const fn supports_field_target(self) -> bool {
+    pub(in crate::db) const fn is_statistical(self) -> bool {
+        matches!(
+            self,
+            Self::VarPop | Self::VarSamp | Self::StddevPop | Self::StddevSamp
+        )
+    }
+
+    /// Return whether this terminal kind retains every admitted input value
+    /// until finalization instead of folding into fixed-size state.
+    #[must_use]
+    pub(in crate::db) const fn retains_input_values(self) -> bool {
         matches!(
             self,
-            Self::Count | Self::Sum | Self::Avg | Self::Min | Self::Max
+            Self::PercentileDisc | Self::StringAgg | Self::ArrayAgg
         )
     }
 
+    /// Return whether this kind supports one grouped or global field target.
+    #[must_use]
+    pub(in crate::db) const fn supports_field_target(self) -> bool {
+        !matches!(self, Self::Exists | Self::First | Self::Last)
+    }
+
     /// Return whether reducer updates for this kind require a decoded id payload.
     #[must_use]
     pub(in crate::db) const fn requires_decoded_id(self) -> bool {
-        !matches!(self, Self::Count | Self::Sum | Self::Avg | Self::Exists)
+        matches!(self, Self::Min | Self::Max | Self::First | Self::Last)
     }
 
     /// Return whether grouped aggregate DISTINCT is supported for this kind.
@@ -512,6 +631,13 @@ impl AggregateKind {
             Self::First => 0x06,
             Self::Last => 0x07,
             Self::Avg => 0x08,
+            Self::VarPop => 0x09,
+            Self::VarSamp => 0x0A,
+            Self::StddevPop => 0x0B,
+            Self::StddevSamp => 0x0C,
+            Self::PercentileDisc => 0x0D,
+            Self::StringAgg => 0x0E,
+            Self::ArrayAgg => 0x0F,
         }
     }
 
@@ -522,7 +648,18 @@ impl AggregateKind {
             Self::Count => Some(GlobalDistinctAggregateKind::Count),
             Self::Sum => Some(GlobalDistinctAggregateKind::Sum),
             Self::Avg => Some(GlobalDistinctAggregateKind::Avg),
-            Self::Exists | Self::Min | Self::Max | Self::First | Self::Last => None,
+            Self::Exists
+            | Self::Min
+            | Self::Max
+            | Self::First
+            | Self::Last
+            | Self::VarPop
+            | Self::VarSamp
+            | Self::StddevPop
+            | Self::StddevSamp
+            | Self::PercentileDisc
+            | Self::StringAgg
+            | Self::ArrayAgg => None,
         }
     }
 
@@ -552,6 +689,11 @@ impl AggregateKind {
         has_target_field: bool,
         distinct: bool,
     ) -> bool {
+        // Retained-value kinds charge per-value state until finalization, so
+        // they stay on the blocking hash path like grouped DISTINCT.
+        if self.retains_input_values() {
+            return false;
+        }
         if self.supports_field_target() {
             return !distinct && (self.is_count() || has_target_field);
         }
@@ -565,7 +707,19 @@ impl AggregateKind {
         match self {
             Self::Min => Some(Direction::Asc),
             Self::Max => Some(Direction::Desc),
-            Self::Count | Self::Sum | Self::Avg | Self::Exists | Self::First | Self::Last => None,
+            Self::Count
+            | Self::Sum
+            | Self::Avg
+            | Self::Exists
+            | Self::First
+            | Self::Last
+            | Self::VarPop
+            | Self::VarSamp
+            | Self::StddevPop
+            | Self::StddevSamp
+            | Self::PercentileDisc
+            | Self::StringAgg
+            | Self::ArrayAgg => None,
         }
     }
 
@@ -580,14 +734,24 @@ impl AggregateKind {
             | Self::Exists
             | Self::Max
             | Self::First
-            | Self::Last => Direction::Asc,
+            | Self::Last
+            | Self::VarPop
+            | Self::VarSamp
+            | Self::StddevPop
+            | Self::StddevSamp
+            | Self::PercentileDisc
+            | Self::StringAgg
+            | Self::ArrayAgg => Direction::Asc,
         }
     }
 
     /// Return true when this kind can use bounded aggregate probe hints.
     #[must_use]
     pub(in crate::db) const fn supports_bounded_probe_hint(self) -> bool {
-        !self.is_count() && !self.is_sum()
+        matches!(
+            self,
+            Self::Exists | Self::Min | Self::Max | Self::First | Self::Last
+        )
     }
 
     /// Derive a bounded aggregate probe fetch hint for this kind.
@@ -603,7 +767,18 @@ impl AggregateKind {
             Self::Min if direction == Direction::Asc => Some(offset.saturating_add(1)),
             Self::Max if direction == Direction::Desc => Some(offset.saturating_add(1)),
             Self::Last => page_limit.map(|limit| offset.saturating_add(limit)),
-            Self::Count | Self::Sum | Self::Avg | Self::Min | Self::Max => None,
+            Self::Count
+            | Self::Sum
+            | Self::Avg
+            | Self::Min
+            | Self::Max
+            | Self::VarPop
+            | Self::VarSamp
+            | Self::StddevPop
+            | Self::StddevSamp
+            | Self::PercentileDisc
+            | Self::StringAgg
+            | Self::ArrayAgg => None,
         }
     }
 
@@ -701,12 +876,29 @@ impl GroupedPlanAggregateFamily {
 /// `index` is the stable accepted field slot; `field` is retained
 /// for diagnostics and explain surfaces.
 /// `authority` freezes exactly one planner metadata source.
+/// Computed grouped keys carry a virtual slot past the accepted row slots and
+/// use their canonical expression label as `field`.
 ///
 
 #[derive(Clone, Debug)]
 pub(in crate::db::query::plan) enum FieldSlotAuthority {
     Unresolved,
     Accepted(AcceptedFieldKind),
+    Computed(Box<ComputedGroupKey>),
+}
+
+///
+/// ComputedGroupKey
+///
+/// Planner-frozen scalar expression behind one computed GROUP BY key.
+/// Grouped ingest evaluates the compiled form once per source row before
+/// hashing, so computed keys always use hash-materialized key state.
+///
+
+#[derive(Clone, Debug)]
+pub(in crate::db::query::plan) struct ComputedGroupKey {
+    pub(in crate::db::query::plan) expr: Expr,
+    pub(in crate::db::query::plan) compiled: CompiledExpr,
 }
 
 #[derive(Clone, Debug)]
@@ -732,7 +924,7 @@ impl Eq for FieldSlot {}
 /// defaults and enforcement strategy at runtime boundaries.
 ///
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub(in crate::db) struct GroupedExecutionConfig {
     pub(in crate::db) max_groups: u64,
     pub(in crate::db) max_group_bytes: u64,
@@ -799,6 +991,9 @@ pub(in crate::db) struct ScalarPlan {
     /// Optional pagination specification.
     pub(in crate::db) page: Option<PageSpec>,
 
+    /// Optional per-partition row window (load intents only).
+    pub(in crate::db) partition_limit: Option<PartitionLimitSpec>,
+
     /// Missing-row policy for execution.
     pub(in crate::db) consistency: MissingRowPolicy,
 }
@@ -4,18 +4,20 @@
 //! Boundary: exposes immutable order contracts consumed across planner/executor boundaries.
 
 use crate::db::{
-    access::{AccessPathKind, AccessShapeFacts, SemanticIndexKeyItem},
+    access::{AccessPathKind, AccessShapeFacts, SemanticIndexAccessContract},
     direction::Direction,
-    query::plan::{OrderDirection, OrderSpec, order_term::index_key_item_order_terms},
+    query::plan::{OrderDirection, OrderSpec, OrderTerm, order_term::index_order_terms},
+    schema::SchemaInfo,
 };
 
 ///
 /// DeterministicSecondaryOrderContract
 ///
-/// Planner-owned shared `..., primary_key_fields` order contract with one
-/// uniform direction. The non-primary-key term list may be empty, which
-/// represents the primary-key-only order shape under the same normalized
-/// contract.
+/// Planner-owned shared `..., primary_key_fields` order contract. The
+/// traversal direction is the primary-key tie-break direction; non-primary-key
+/// terms that run against it carry a relative `DESC` marker. The
+/// non-primary-key term list may be empty, which represents the
+/// primary-key-only order shape under the same normalized contract.
 ///
 
 ///
@@ -55,10 +57,10 @@ impl DeterministicSecondaryIndexOrderCompatibility {
     #[must_use]
     fn new(
         order_contract: &DeterministicSecondaryOrderContract,
-        key_items: &[SemanticIndexKeyItem],
+        index: &SemanticIndexAccessContract,
         prefix_len: usize,
     ) -> Self {
-        let index_terms = index_key_item_order_terms(key_items);
+        let index_terms = index_order_terms(index);
         let match_kind = order_contract.classify_index_match(&index_terms, prefix_len);
 
         Self {
@@ -129,48 +131,79 @@ pub(in crate::db) struct DeterministicSecondaryOrderContract {
     non_primary_key_terms: Vec<String>,
     primary_key_terms: Vec<String>,
     direction: OrderDirection,
+    null_placement_override: Option<String>,
 }
 
 impl DeterministicSecondaryOrderContract {
     /// Build one normalized deterministic order contract with an ordered
-    /// primary-key field suffix.
+    /// primary-key field suffix. Without accepted nullability every explicit
+    /// non-natural null placement counts as one the index cannot satisfy.
     #[must_use]
     pub(in crate::db) fn from_order_spec_fields(
         order: &OrderSpec,
         primary_key_names: &[&str],
+        schema: Option<&SchemaInfo>,
     ) -> Option<Self> {
         let direction = order.fields.last()?.direction();
         has_exact_ordered_primary_key_tie_break_fields(order.fields.as_slice(), primary_key_names)
             .then_some(())?;
-        if order
-            .fields
+        let primary_key_start = order.fields.len().saturating_sub(primary_key_names.len());
+        if order.fields[primary_key_start..]
             .iter()
             .any(|term| term.direction() != direction)
         {
             return None;
         }
 
+        // Non-primary-key terms may run against the traversal direction. They
+        // render as `term DESC` relative to the primary-key tie-break so one
+        // descending index component matches both `x DESC, id ASC` forward
+        // and `x ASC, id DESC` in reverse.
         Some(Self {
             non_primary_key_terms: order
                 .fields
                 .iter()
-                .take(order.fields.len().saturating_sub(primary_key_names.len()))
-                .map(crate::db::query::plan::OrderTerm::rendered_label)
+                .take(primary_key_start)
+                .map(|term| {
+                    let label = term.rendered_label();
+                    if term.direction() == direction {
+                        label
+                    } else {
+                        format!("{label} DESC")
+                    }
+                })
                 .collect(),
             primary_key_terms: primary_key_names
                 .iter()
                 .map(|name| (*name).to_string())
                 .collect(),
             direction,
+            null_placement_override: order
+                .fields
+                .iter()
+                .find(|term| {
+                    term.overrides_natural_nulls()
+                        && !schema.is_some_and(|schema| {
+                            order_term_is_provably_non_null(schema, primary_key_names, term)
+                        })
+                })
+                .map(OrderTerm::rendered_label),
         })
     }
 
-    /// Return the shared direction across the full deterministic order shape.
+    /// Return the traversal direction shared by the primary-key tie-break.
     #[must_use]
     pub(in crate::db) const fn direction(&self) -> OrderDirection {
         self.direction
     }
 
+    /// Borrow the first possibly-null ORDER BY term whose requested null
+    /// placement differs from the natural placement for its direction, if any.
+    #[must_use]
+    pub(in crate::db) fn null_placement_override(&self) -> Option<&str> {
+        self.null_placement_override.as_deref()
+    }
+
     /// Borrow the normalized non-primary-key ORDER BY terms.
     #[must_use]
     pub(in crate::db) const fn non_primary_key_terms(&self) -> &[String] {
@@ -231,6 +264,9 @@ impl DeterministicSecondaryOrderContract {
     where
         S: AsRef<str>,
     {
+        if self.null_placement_override.is_some() {
+            return DeterministicSecondaryIndexOrderMatch::None;
+        }
         if self.matches_index_suffix(index_fields, prefix_len) {
             return DeterministicSecondaryIndexOrderMatch::Suffix;
         }
@@ -267,14 +303,14 @@ impl DeterministicSecondaryOrderContract {
 }
 
 /// Return the shared scalar secondary-index order compatibility fact from
-/// reduced key-item facts.
+/// one reduced index access contract.
 #[must_use]
-pub(in crate::db) fn deterministic_secondary_index_key_items_order_compatibility(
+pub(in crate::db) fn deterministic_secondary_index_order_compatibility(
     order_contract: &DeterministicSecondaryOrderContract,
-    key_items: &[SemanticIndexKeyItem],
+    index: &SemanticIndexAccessContract,
     prefix_len: usize,
 ) -> DeterministicSecondaryIndexOrderCompatibility {
-    DeterministicSecondaryIndexOrderCompatibility::new(order_contract, key_items, prefix_len)
+    DeterministicSecondaryIndexOrderCompatibility::new(order_contract, index, prefix_len)
 }
 
 /// Return whether accepted field-path index order terms satisfy one
@@ -317,17 +353,14 @@ fn variable_prefix_shape_requires_full_secondary_order(
     })
 }
 
-fn deterministic_secondary_index_key_items_order_satisfied_for_access_shape(
+fn deterministic_secondary_index_order_satisfied_for_access_shape(
     access_shape_facts: &AccessShapeFacts,
     order_contract: &DeterministicSecondaryOrderContract,
-    key_items: &[SemanticIndexKeyItem],
+    index: &SemanticIndexAccessContract,
     prefix_len: usize,
 ) -> bool {
-    let compatibility = deterministic_secondary_index_key_items_order_compatibility(
-        order_contract,
-        key_items,
-        prefix_len,
-    );
+    let compatibility =
+        deterministic_secondary_index_order_compatibility(order_contract, index, prefix_len);
 
     match compatibility.match_kind() {
         DeterministicSecondaryIndexOrderMatch::Full => true,
@@ -351,10 +384,10 @@ pub(in crate::db) fn access_satisfies_deterministic_secondary_order_contract(
 
     if let Some(details) = access_shape_facts.single_path_index_prefix_details() {
         return prefix_order_contract_safe(access_shape_facts)
-            && deterministic_secondary_index_key_items_order_satisfied_for_access_shape(
+            && deterministic_secondary_index_order_satisfied_for_access_shape(
                 access_shape_facts,
                 order_contract,
-                details.key_items(),
+                &details.index_contract(),
                 details.slot_arity(),
             );
     }
@@ -362,10 +395,10 @@ pub(in crate::db) fn access_satisfies_deterministic_secondary_order_contract(
     access_shape_facts
         .single_path_index_range_details()
         .is_some_and(|details| {
-            deterministic_secondary_index_key_items_order_satisfied_for_access_shape(
+            deterministic_secondary_index_order_satisfied_for_access_shape(
                 access_shape_facts,
                 order_contract,
-                details.key_items(),
+                &details.index_contract(),
                 details.slot_arity(),
             )
         })
@@ -373,7 +406,7 @@ pub(in crate::db) fn access_satisfies_deterministic_secondary_order_contract(
 
 impl GroupedIndexOrderContract {
     /// Build one grouped ORDER BY contract from one uniform-direction grouped
-    /// order spec.
+    /// order spec that keeps the natural null placement on every term.
     #[must_use]
     pub(in crate::db) fn from_order_spec(order: &OrderSpec) -> Option<Self> {
         let direction = order
@@ -383,7 +416,7 @@ impl GroupedIndexOrderContract {
         if order
             .fields
             .iter()
-            .any(|term| term.direction() != direction)
+            .any(|term| term.direction() != direction || term.overrides_natural_nulls())
         {
             return None;
         }
@@ -493,7 +526,9 @@ impl OrderSpec {
             .iter()
             .zip(primary_key_names.iter())
             .all(|(term, primary_key_name)| {
-                term.direct_field() == Some(*primary_key_name) && term.direction() == direction
+                term.direct_field() == Some(*primary_key_name)
+                    && term.direction() == direction
+                    && !term.overrides_natural_nulls()
             })
             .then_some(direction)
     }
@@ -505,7 +540,28 @@ impl OrderSpec {
         &self,
         primary_key_names: &[&str],
     ) -> Option<DeterministicSecondaryOrderContract> {
-        DeterministicSecondaryOrderContract::from_order_spec_fields(self, primary_key_names)
+        DeterministicSecondaryOrderContract::from_order_spec_fields(self, primary_key_names, None)
+    }
+
+    /// Return the normalized deterministic order contract for one accepted
+    /// schema. Explicit null placements on fields the schema proves
+    /// non-nullable stay index-satisfiable because no row can carry NULL.
+    #[must_use]
+    pub(in crate::db) fn deterministic_secondary_order_contract_for_schema(
+        &self,
+        schema: &SchemaInfo,
+    ) -> Option<DeterministicSecondaryOrderContract> {
+        let primary_key_names: Vec<&str> = schema
+            .primary_key_names()
+            .iter()
+            .map(String::as_str)
+            .collect();
+
+        DeterministicSecondaryOrderContract::from_order_spec_fields(
+            self,
+            primary_key_names.as_slice(),
+            Some(schema),
+        )
     }
 
     /// Return the grouped order contract when grouped ORDER BY stays on one
@@ -556,7 +612,7 @@ impl ExecutionOrderContract {
     /// Build one execution ordering contract from grouped/order plan shape.
     #[must_use]
     pub(in crate::db) fn from_plan(is_grouped: bool, order: Option<&OrderSpec>) -> Self {
-        let direction = primary_scan_direction(order);
+        let direction = primary_scan_direction(is_grouped, order);
         let ordering = if is_grouped {
             ExecutionOrdering::Grouped(order.cloned())
         } else {
@@ -601,11 +657,19 @@ impl ExecutionOrderContract {
     }
 }
 
-fn primary_scan_direction(order: Option<&OrderSpec>) -> Direction {
+// Scalar orders end in the primary-key tie-break, whose direction decides the
+// traversal even when leading terms run the other way over descending index
+// components. Grouped orders carry no tie-break and follow their first term.
+fn primary_scan_direction(is_grouped: bool, order: Option<&OrderSpec>) -> Direction {
     let Some(order) = order else {
         return Direction::Asc;
     };
-    let Some(term) = order.fields.first() else {
+    let term = if is_grouped {
+        order.fields.first()
+    } else {
+        order.fields.last()
+    };
+    let Some(term) = term else {
         return Direction::Asc;
     };
 
@@ -615,6 +679,20 @@ fn primary_scan_direction(order: Option<&OrderSpec>) -> Direction {
     }
 }
 
+/// Return whether one ORDER BY term is a direct field the accepted schema
+/// proves can never hold NULL, so its null placement cannot change the order.
+#[must_use]
+pub(in crate::db::query::plan) fn order_term_is_provably_non_null(
+    schema: &SchemaInfo,
+    primary_key_names: &[&str],
+    term: &OrderTerm,
+) -> bool {
+    term.direct_field().is_some_and(|field| {
+        primary_key_names.contains(&field)
+            || schema.accepted_field_is_nullable(field) == Some(false)
+    })
+}
+
 fn has_exact_ordered_primary_key_tie_break_fields(
     fields: &[crate::db::query::plan::OrderTerm],
     primary_key_names: &[&str],
@@ -641,8 +719,10 @@ fn has_exact_ordered_primary_key_tie_break_fields(
 
 #[cfg(test)]
 mod tests {
-    use super::{GroupedIndexOrderContract, GroupedIndexOrderMatch};
-    use crate::db::query::plan::OrderDirection;
+    use super::{
+        DeterministicSecondaryIndexOrderMatch, GroupedIndexOrderContract, GroupedIndexOrderMatch,
+    };
+    use crate::db::query::plan::{NullsOrder, OrderDirection, OrderSpec, OrderTerm};
 
     fn grouped_contract(terms: &[&str]) -> GroupedIndexOrderContract {
         GroupedIndexOrderContract {
@@ -683,4 +763,92 @@ mod tests {
             GroupedIndexOrderMatch::None
         );
     }
+
+    #[test]
+    fn secondary_order_with_non_natural_null_placement_never_matches_index() {
+        let natural = OrderSpec {
+            fields: vec![
+                OrderTerm::field("rank", OrderDirection::Desc),
+                OrderTerm::field("id", OrderDirection::Desc),
+            ],
+        };
+        let inverted = OrderSpec {
+            fields: vec![
+                OrderTerm::field("rank", OrderDirection::Desc).with_nulls(NullsOrder::First),
+                OrderTerm::field("id", OrderDirection::Desc),
+            ],
+        };
+        let index = ["rank", "id"];
+
+        let natural = natural
+            .deterministic_secondary_order_contract_fields(&["id"])
+            .expect("natural placement should keep the deterministic contract");
+        assert_eq!(natural.null_placement_override(), None);
+        assert_eq!(
+            natural.classify_index_match(&index, 0),
+            DeterministicSecondaryIndexOrderMatch::Suffix
+        );
+
+        let inverted = inverted
+            .deterministic_secondary_order_contract_fields(&["id"])
+            .expect("explicit placement should keep the deterministic contract");
+        assert_eq!(inverted.null_placement_override(), Some("rank"));
+        assert_eq!(
+            inverted.classify_index_match(&index, 0),
+            DeterministicSecondaryIndexOrderMatch::None
+        );
+    }
+
+    #[test]
+    fn secondary_order_with_mixed_directions_matches_descending_index_terms() {
+        let forward = OrderSpec {
+            fields: vec![
+                OrderTerm::field("score", OrderDirection::Desc),
+                OrderTerm::field("created_at", OrderDirection::Asc),
+                OrderTerm::field("id", OrderDirection::Asc),
+            ],
+        };
+        let reverse = OrderSpec {
+            fields: vec![
+                OrderTerm::field("score", OrderDirection::Asc),
+                OrderTerm::field("created_at", OrderDirection::Desc),
+                OrderTerm::field("id", OrderDirection::Desc),
+            ],
+        };
+        let descending_index = ["score DESC", "created_at", "id"];
+        let ascending_index = ["score", "created_at", "id"];
+
+        let forward = forward
+            .deterministic_secondary_order_contract_fields(&["id"])
+            .expect("mixed directions should keep the deterministic contract");
+        assert_eq!(forward.direction(), OrderDirection::Asc);
+        assert_eq!(
+            forward.classify_index_match(&descending_index, 0),
+            DeterministicSecondaryIndexOrderMatch::Suffix
+        );
+        assert_eq!(
+            forward.classify_index_match(&ascending_index, 0),
+            DeterministicSecondaryIndexOrderMatch::None
+        );
+
+        let reverse = reverse
+            .deterministic_secondary_order_contract_fields(&["id"])
+            .expect("mixed directions should keep the deterministic contract");
+        assert_eq!(reverse.direction(), OrderDirection::Desc);
+        assert_eq!(
+            reverse.classify_index_match(&descending_index, 0),
+            DeterministicSecondaryIndexOrderMatch::Suffix
+        );
+    }
+
+    #[test]
+    fn grouped_order_with_non_natural_null_placement_has_no_index_contract() {
+        let order = OrderSpec {
+            fields: vec![
+                OrderTerm::field("group_key", OrderDirection::Asc).with_nulls(NullsOrder::Last),
+            ],
+        };
+
+        assert!(order.grouped_index_order_contract().is_none());
+    }
 }
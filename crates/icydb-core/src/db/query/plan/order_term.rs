@@ -3,13 +3,36 @@
 //! Does not own: query expression parsing or executor slot resolution.
 //! Boundary: keeps index-key canonicalization in one place.
 
-use crate::db::access::SemanticIndexKeyItem;
+use crate::db::{
+    access::SemanticIndexAccessContract,
+    query::{
+        builder::scalar_projection::render_scalar_projection_expr_plan_label,
+        plan::{
+            expr::{Expr, normalize_scalar_operand_expr},
+            key_item_match::key_item_order_term,
+        },
+    },
+};
 
 /// Return one canonical ORDER BY term list from reduced index key-item facts.
 #[must_use]
-pub(in crate::db) fn index_key_item_order_terms(key_items: &[SemanticIndexKeyItem]) -> Vec<String> {
-    key_items
+pub(in crate::db) fn index_order_terms(index: &SemanticIndexAccessContract) -> Vec<String> {
+    index
+        .key_items()
         .iter()
-        .map(|item| item.as_ref().canonical_text())
+        .enumerate()
+        .map(|(component_index, item)| {
+            key_item_order_term(item.as_ref(), index.key_descending_at(component_index))
+        })
         .collect()
 }
+
+/// Render one scalar expression as the canonical term stored by an arbitrary
+/// expression-index key item.
+///
+/// DDL binding and planner matching both route through this one renderer, so
+/// index fingerprints and normalized query operands compare by text.
+#[must_use]
+pub(in crate::db) fn scalar_expression_index_term(expr: &Expr) -> String {
+    render_scalar_projection_expr_plan_label(&normalize_scalar_operand_expr(expr.clone()))
+}
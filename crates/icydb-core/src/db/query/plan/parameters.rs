@@ -130,7 +130,8 @@ impl ParameterPredicate {
             | Predicate::IsEmpty { .. }
             | Predicate::IsNotEmpty { .. }
             | Predicate::TextContains { .. }
-            | Predicate::TextContainsCi { .. } => None,
+            | Predicate::TextContainsCi { .. }
+            | Predicate::TextMatch { .. } => None,
         }
     }
 
@@ -621,6 +621,7 @@ fn plan_access_from_normalized_predicate(
         visible_indexes,
         schema_info,
         normalized_predicate,
+        query.logical_filter_expr(),
         order,
         query.is_grouped(),
         key_access_override,
@@ -696,6 +697,7 @@ fn collect_primary_key_in_resource(
         | Predicate::IsNotEmpty { .. }
         | Predicate::TextContains { .. }
         | Predicate::TextContainsCi { .. }
+        | Predicate::TextMatch { .. }
         | Predicate::IsNull { .. }
         | Predicate::IsNotNull { .. }
         | Predicate::True
@@ -0,0 +1,94 @@
+//! Module: query::plan::planner::collection_contains
+//! Responsibility: planner collection `CONTAINS` lowering onto multivalue element indexes.
+//! Does not own: element key derivation or residual `CONTAINS` evaluation.
+//! Boundary: derives element prefix/multi-lookup access from collection compare predicates.
+
+use crate::{
+    db::{
+        access::{AccessPlan, SemanticIndexAccessContract, SemanticIndexKeyItemRef},
+        predicate::{CoercionId, CompareOp, ComparePredicate, Predicate},
+        schema::{FieldType, PersistedIndexExpressionOp, SchemaInfo, literal_matches_type},
+    },
+    value::{Value, canonicalize_value_set},
+};
+use icydb_schema::ScalarKind;
+
+/// Plan one `field CONTAINS value` compare against the visible element indexes.
+///
+/// The element lookup only narrows candidates; the original compare stays in
+/// the residual filter, so the lookup only needs to be a superset.
+pub(super) fn plan_collection_contains(
+    candidate_indexes: &[SemanticIndexAccessContract],
+    schema: &SchemaInfo,
+    cmp: &ComparePredicate,
+) -> Option<AccessPlan<Value>> {
+    let index = element_index_for_contains(candidate_indexes, schema, cmp)?;
+
+    Some(AccessPlan::index_prefix_from_contract(
+        index.clone(),
+        vec![cmp.value.clone()],
+    ))
+}
+
+/// Plan one disjunction of `field CONTAINS value` compares over the same
+/// element index as a single multi-lookup ("any element IN values").
+pub(super) fn plan_collection_contains_any(
+    candidate_indexes: &[SemanticIndexAccessContract],
+    schema: &SchemaInfo,
+    children: &[Predicate],
+) -> Option<AccessPlan<Value>> {
+    let mut selected: Option<&SemanticIndexAccessContract> = None;
+    let mut values = Vec::with_capacity(children.len());
+    for child in children {
+        let Predicate::Compare(cmp) = child else {
+            return None;
+        };
+        let index = element_index_for_contains(candidate_indexes, schema, cmp)?;
+        if selected.is_some_and(|selected| selected.name() != index.name()) {
+            return None;
+        }
+        selected = Some(index);
+        values.push(cmp.value.clone());
+    }
+    canonicalize_value_set(&mut values);
+
+    Some(AccessPlan::index_multi_lookup_from_contract(
+        selected?.clone(),
+        values,
+    ))
+}
+
+// Resolve the element index that can serve one strict `CONTAINS` compare.
+// Enum elements stay residual because loose enum literals do not share the
+// stored element encoding.
+fn element_index_for_contains<'a>(
+    candidate_indexes: &'a [SemanticIndexAccessContract],
+    schema: &SchemaInfo,
+    cmp: &ComparePredicate,
+) -> Option<&'a SemanticIndexAccessContract> {
+    if cmp.op != CompareOp::Contains || cmp.coercion.id != CoercionId::Strict {
+        return None;
+    }
+    let (FieldType::List(element) | FieldType::Set(element)) = schema.field(&cmp.field)? else {
+        return None;
+    };
+    if !matches!(element.as_ref(), FieldType::Scalar(kind) if *kind != ScalarKind::Enum)
+        || !literal_matches_type(&cmp.value, element)
+    {
+        return None;
+    }
+
+    candidate_indexes
+        .iter()
+        .find(|index| index_is_element_index_on(index, &cmp.field))
+}
+
+// Return whether the index is keyed by the element expansion of one field.
+fn index_is_element_index_on(index: &SemanticIndexAccessContract, field: &str) -> bool {
+    matches!(
+        index.key_item_at(0),
+        Some(SemanticIndexKeyItemRef::AcceptedExpression(expression))
+            if expression.op() == PersistedIndexExpressionOp::Elements
+                && expression.field() == field
+    )
+}
@@ -16,7 +16,9 @@ use crate::{
             key_item_match::{eq_lookup_value_for_key_item, starts_with_lookup_value_for_key_item},
             planner::{
                 AccessCandidateScore, access_candidate_score_from_index_contract,
-                access_candidate_score_outranks, index_literal_matches_schema,
+                access_candidate_score_outranks,
+                collection_contains::plan_collection_contains,
+                index_literal_matches_schema,
                 prefix::{index_multi_lookup_for_in, index_prefix_for_eq},
                 range_bound_count,
             },
@@ -121,6 +123,11 @@ pub(super) fn plan_compare(
                 return path;
             }
         }
+        CompareOp::Contains => {
+            if let Some(path) = plan_collection_contains(candidate_indexes, schema, cmp) {
+                return path;
+            }
+        }
         _ => {
             // NOTE: Other non-equality comparisons do not currently map to key access paths.
         }
@@ -324,6 +331,7 @@ fn plan_ordered_compare(
                     continue;
                 }
             }
+            SemanticIndexKeyItemRef::Scalar(_) => continue,
         }
 
         let (lower, upper) = match cmp.op {
@@ -519,7 +519,8 @@ where
         | Predicate::IsEmpty { .. }
         | Predicate::IsNotEmpty { .. }
         | Predicate::TextContains { .. }
-        | Predicate::TextContainsCi { .. } => Some(query_predicate.clone()),
+        | Predicate::TextContainsCi { .. }
+        | Predicate::TextMatch { .. } => Some(query_predicate.clone()),
     }
 }
 
@@ -603,7 +604,8 @@ fn query_implication_clauses(predicate: &Predicate) -> QueryImplicationClauses<'
         | Predicate::IsEmpty { .. }
         | Predicate::IsNotEmpty { .. }
         | Predicate::TextContains { .. }
-        | Predicate::TextContainsCi { .. } => QueryImplicationClauses::Unknown,
+        | Predicate::TextContainsCi { .. }
+        | Predicate::TextMatch { .. } => QueryImplicationClauses::Unknown,
     }
 }
 
@@ -677,7 +679,8 @@ fn collect_implication_clauses<'a>(
         | Predicate::IsEmpty { .. }
         | Predicate::IsNotEmpty { .. }
         | Predicate::TextContains { .. }
-        | Predicate::TextContainsCi { .. } => CompareClauseCollect::Unknown,
+        | Predicate::TextContainsCi { .. }
+        | Predicate::TextMatch { .. } => CompareClauseCollect::Unknown,
     }
 }
 
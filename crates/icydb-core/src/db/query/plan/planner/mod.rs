@@ -7,6 +7,7 @@
 //! Determinism: canonicalization is delegated to predicate/access ownership
 //! boundaries so the same model and predicate shape produce identical access plans.
 
+mod collection_contains;
 mod compare;
 mod index_select;
 mod order_select;
@@ -14,12 +15,15 @@ mod predicate;
 mod prefix;
 mod range;
 mod ranking;
+mod scalar_expression;
+mod text_match;
 use crate::{
     db::{
         access::{AccessPlan, SemanticIndexAccessContract, normalize_access_plan_value},
         predicate::Predicate,
         query::plan::{
             AcceptedPlannerFieldPathIndex, OrderSpec, PlanError, PlannedNonIndexAccessReason,
+            expr::Expr,
         },
         schema::SchemaInfo,
     },
@@ -114,6 +118,7 @@ pub(in crate::db::query) fn plan_access_selection_with_order_and_accepted_semant
     accepted_field_path_indexes: &[AcceptedPlannerFieldPathIndex],
     schema: &SchemaInfo,
     predicate: Option<&Predicate>,
+    filter_expr: Option<&Expr>,
     order: Option<&OrderSpec>,
     grouped: bool,
 ) -> Result<PlannedAccessSelection, PlannerError> {
@@ -121,6 +126,7 @@ pub(in crate::db::query) fn plan_access_selection_with_order_and_accepted_semant
         semantic_candidate_indexes,
         schema,
         predicate,
+        filter_expr,
         order,
         grouped,
         OrderFallbackIndexAuthority::AcceptedFieldPathIndexes(accepted_field_path_indexes),
@@ -140,6 +146,7 @@ pub(in crate::db::query) fn plan_access_selection_with_order_and_semantic_indexe
         semantic_candidate_indexes,
         schema,
         predicate,
+        None,
         order,
         grouped,
         OrderFallbackIndexAuthority::AcceptedSemanticIndexes,
@@ -156,6 +163,7 @@ fn plan_access_selection_with_order_from_authority(
     visible_indexes: &[SemanticIndexAccessContract],
     schema: &SchemaInfo,
     predicate: Option<&Predicate>,
+    filter_expr: Option<&Expr>,
     order: Option<&OrderSpec>,
     grouped: bool,
     order_fallback_authority: OrderFallbackIndexAuthority<'_>,
@@ -163,6 +171,9 @@ fn plan_access_selection_with_order_from_authority(
     let Some(predicate) = predicate else {
         let true_predicate = Predicate::True;
         let eligible_indexes = eligible_sorted_index_contracts(visible_indexes, &true_predicate);
+        if let Some(access) = scalar_expression_filter_access(&eligible_indexes, filter_expr) {
+            return Ok(PlannedAccessSelection::new(access, None));
+        }
 
         return Ok(order_fallback_selection(
             eligible_indexes.as_slice(),
@@ -199,6 +210,9 @@ fn plan_access_selection_with_order_from_authority(
     if !plan.is_single_full_scan() {
         return Ok(PlannedAccessSelection::new(plan, planned_non_index_reason));
     }
+    if let Some(access) = scalar_expression_filter_access(&eligible_indexes, filter_expr) {
+        return Ok(PlannedAccessSelection::new(access, None));
+    }
 
     Ok(index_range_from_order_with_authority(
         eligible_indexes.as_slice(),
@@ -218,6 +232,15 @@ fn plan_access_selection_with_order_from_authority(
     ))
 }
 
+// Expression filters the predicate subset cannot carry may still match one
+// scalar expression index before planning degrades to order-only fallback.
+fn scalar_expression_filter_access(
+    eligible_indexes: &[SemanticIndexAccessContract],
+    filter_expr: Option<&Expr>,
+) -> Option<AccessPlan<Value>> {
+    scalar_expression::plan_scalar_expression_filter(eligible_indexes, filter_expr?)
+}
+
 // Order-only planning is the final planner-owned fallback once predicate
 // access either does not exist or degenerates to a full scan.
 fn order_fallback_selection(
@@ -5,11 +5,16 @@
 
 use crate::{
     db::{
-        access::{AccessPlan, SemanticIndexAccessContract, SemanticIndexRangeSpec},
+        access::{
+            AccessPlan, SemanticIndexAccessContract, SemanticIndexKeyItemRef,
+            SemanticIndexRangeSpec,
+        },
+        predicate::Predicate,
         query::plan::{
             AcceptedPlannerFieldPathIndex, OrderSpec,
             deterministic_secondary_index_order_terms_satisfied,
-            grouped_index_order_terms_satisfied, index_key_item_order_terms,
+            grouped_index_order_terms_satisfied, index_order_terms,
+            planner::index_select::predicate_implies_predicate_for_planner,
         },
         schema::SchemaInfo,
     },
@@ -30,16 +35,19 @@ pub(in crate::db::query::plan::planner) fn index_range_from_order_with_accepted_
         .then_some(order)
         .flatten()
         .and_then(OrderSpec::grouped_index_order_contract);
-    let scalar_order_contract = (!grouped).then_some(order).flatten().and_then(|order| {
-        let primary_key_names = ordered_primary_key_names_from_schema(schema);
-        order.deterministic_secondary_order_contract_fields(primary_key_names.as_slice())
-    });
+    let scalar_order_contract = (!grouped)
+        .then_some(order)
+        .flatten()
+        .and_then(|order| order.deterministic_secondary_order_contract_for_schema(schema));
 
     // Order-driven access fallback is only valid when the canonical ORDER BY
     // already carries one uniform-direction `..., primary_key` tie-break
     // shape. The caller prefilters candidate indexes so filtered guards are
     // checked once at the planner entry boundary.
     for index_contract in candidate_indexes {
+        if !index_keys_every_candidate_row(schema, index_contract) {
+            continue;
+        }
         if let Some(accepted) = accepted_field_path_index_for_candidate(
             accepted_field_path_indexes,
             index_contract.name(),
@@ -72,23 +80,20 @@ pub(in crate::db::query::plan::planner) fn index_range_from_order_with_accepted_
         if !index_contract.has_expression_key_items() {
             continue;
         }
-        let index_order_terms = index_key_item_order_terms(index_contract.key_items());
+        let index_terms = index_order_terms(index_contract);
         if grouped {
             let Some(order_contract) = grouped_order_contract.as_ref() else {
                 continue;
             };
-            if !grouped_index_order_terms_satisfied(order_contract, &index_order_terms, 0) {
+            if !grouped_index_order_terms_satisfied(order_contract, &index_terms, 0) {
                 continue;
             }
         } else {
             let Some(order_contract) = scalar_order_contract.as_ref() else {
                 continue;
             };
-            if !deterministic_secondary_index_order_terms_satisfied(
-                order_contract,
-                &index_order_terms,
-                0,
-            ) {
+            if !deterministic_secondary_index_order_terms_satisfied(order_contract, &index_terms, 0)
+            {
                 continue;
             }
         }
@@ -117,20 +122,23 @@ pub(in crate::db::query::plan::planner) fn index_range_from_order_with_accepted_
         .then_some(order)
         .flatten()
         .and_then(OrderSpec::grouped_index_order_contract);
-    let scalar_order_contract = (!grouped).then_some(order).flatten().and_then(|order| {
-        let primary_key_names = ordered_primary_key_names_from_schema(schema);
-        order.deterministic_secondary_order_contract_fields(primary_key_names.as_slice())
-    });
+    let scalar_order_contract = (!grouped)
+        .then_some(order)
+        .flatten()
+        .and_then(|order| order.deterministic_secondary_order_contract_for_schema(schema));
 
     for index in candidate_indexes {
-        let index_order_terms = index_key_item_order_terms(index.key_items());
+        if !index_keys_every_candidate_row(schema, index) {
+            continue;
+        }
+        let index_terms = index_order_terms(index);
         let satisfied = if grouped {
             grouped_order_contract.as_ref().is_some_and(|contract| {
-                grouped_index_order_terms_satisfied(contract, &index_order_terms, 0)
+                grouped_index_order_terms_satisfied(contract, &index_terms, 0)
             })
         } else {
             scalar_order_contract.as_ref().is_some_and(|contract| {
-                deterministic_secondary_index_order_terms_satisfied(contract, &index_order_terms, 0)
+                deterministic_secondary_index_order_terms_satisfied(contract, &index_terms, 0)
             })
         };
         if satisfied {
@@ -141,12 +149,29 @@ pub(in crate::db::query::plan::planner) fn index_range_from_order_with_accepted_
     None
 }
 
-fn ordered_primary_key_names_from_schema(schema: &SchemaInfo) -> Vec<&str> {
-    schema
-        .primary_key_names()
-        .iter()
-        .map(String::as_str)
-        .collect()
+// Rows whose key component evaluates to `NULL` are not indexed, so a whole
+// index scan only yields every row when each key source is the primary key,
+// is accepted as non-nullable, or is excluded by the index's own guard.
+fn index_keys_every_candidate_row(
+    schema: &SchemaInfo,
+    index: &SemanticIndexAccessContract,
+) -> bool {
+    index.key_items().iter().all(|item| {
+        let field = match item.as_ref() {
+            SemanticIndexKeyItemRef::Field(field) => field,
+            SemanticIndexKeyItemRef::AcceptedExpression(expression) => expression.field(),
+            SemanticIndexKeyItemRef::Scalar(_) => return true,
+        };
+
+        schema.primary_key_names().iter().any(|name| name == field)
+            || schema.accepted_field_is_nullable(field) == Some(false)
+            || index.predicate_semantics().is_some_and(|guard| {
+                predicate_implies_predicate_for_planner(
+                    guard,
+                    &Predicate::is_not_null(field.to_string()),
+                )
+            })
+    })
 }
 
 fn whole_index_ordered_range_scan_from_contract(
@@ -11,9 +11,9 @@ use crate::{
             key_item_match::eq_lookup_value_for_key_item,
             planner::{
                 AndFamilyCandidateScore, AndFamilyPriorityClass, PlannedAccessSelection,
-                and_family_candidate_score_outranks, compare, index_field_literal_matcher,
-                index_literal_matches_schema, prefix, range,
-                selected_index_contract_satisfies_secondary_order,
+                and_family_candidate_score_outranks, collection_contains, compare,
+                index_field_literal_matcher, index_literal_matches_schema, prefix, range,
+                selected_index_contract_satisfies_secondary_order, text_match,
             },
         },
         schema::SchemaInfo,
@@ -146,18 +146,40 @@ pub(super) fn plan_predicate(
                 Some(PlannedNonIndexAccessReason::PlannerCompositeNonIndex),
             )
         }
-        Predicate::Or(children) => PlannedAccessSelection::new(
-            AccessPlan::union(
-                children
-                    .iter()
-                    .map(|child| {
-                        plan_predicate(candidate_indexes, schema, child, order, grouped)
-                            .map(PlannedAccessSelection::into_access)
-                    })
-                    .collect::<Result<Vec<_>, _>>()?,
-            ),
-            Some(PlannedNonIndexAccessReason::PlannerCompositeNonIndex),
-        ),
+        Predicate::Or(children) => {
+            // Disjunctions of element `CONTAINS` on one multivalue index
+            // collapse into one element multi-lookup instead of a union.
+            if let Some(access) = collection_contains::plan_collection_contains_any(
+                candidate_indexes,
+                schema,
+                children,
+            ) {
+                let reason = planned_non_index_reason_for_access(&access);
+
+                return Ok(PlannedAccessSelection::new(access, reason));
+            }
+
+            PlannedAccessSelection::new(
+                AccessPlan::union(
+                    children
+                        .iter()
+                        .map(|child| {
+                            plan_predicate(candidate_indexes, schema, child, order, grouped)
+                                .map(PlannedAccessSelection::into_access)
+                        })
+                        .collect::<Result<Vec<_>, _>>()?,
+                ),
+                Some(PlannedNonIndexAccessReason::PlannerCompositeNonIndex),
+            )
+        }
+        Predicate::TextMatch { field, value } => {
+            let access = text_match::plan_text_match(candidate_indexes, field, value);
+
+            PlannedAccessSelection::new(
+                access.clone(),
+                planned_non_index_reason_for_access(&access),
+            )
+        }
         Predicate::Compare(cmp) => {
             let access = compare::plan_compare(candidate_indexes, schema, cmp, order, grouped);
 
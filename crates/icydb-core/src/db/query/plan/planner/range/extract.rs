@@ -338,9 +338,8 @@ fn key_item_constraint_for_index_slot(
                     &prefix,
                     match key_item {
                         SemanticIndexKeyItemRef::Field(_) => TextPrefixBoundMode::Strict,
-                        SemanticIndexKeyItemRef::AcceptedExpression(_) => {
-                            TextPrefixBoundMode::LowerOnly
-                        }
+                        SemanticIndexKeyItemRef::AcceptedExpression(_)
+                        | SemanticIndexKeyItemRef::Scalar(_) => TextPrefixBoundMode::LowerOnly,
                     },
                 )?;
                 let candidate = RangeConstraint { lower, upper };
@@ -392,6 +391,7 @@ fn merge_ordered_compare_constraint_for_key_item(
                 return Some(());
             }
         }
+        SemanticIndexKeyItemRef::Scalar(_) => return Some(()),
     }
 
     let mut range = match constraint {
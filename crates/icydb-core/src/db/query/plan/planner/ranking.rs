@@ -7,7 +7,7 @@ use crate::db::{
     access::SemanticIndexAccessContract,
     query::plan::{
         OrderSpec, deterministic_secondary_index_order_terms_satisfied,
-        grouped_index_order_terms_satisfied, index_key_item_order_terms,
+        grouped_index_order_terms_satisfied, index_order_terms,
     },
     schema::SchemaInfo,
 };
@@ -190,7 +190,7 @@ pub(in crate::db::query::plan) fn selected_index_contract_satisfies_secondary_or
     prefix_len: usize,
     grouped: bool,
 ) -> bool {
-    let index_terms = index_key_item_order_terms(index.key_items());
+    let index_terms = index_order_terms(index);
 
     if grouped {
         let Some(order_contract) = order.and_then(OrderSpec::grouped_index_order_contract) else {
@@ -204,13 +204,8 @@ pub(in crate::db::query::plan) fn selected_index_contract_satisfies_secondary_or
         );
     }
 
-    let primary_key_names: Vec<&str> = schema
-        .primary_key_names()
-        .iter()
-        .map(String::as_str)
-        .collect();
-    let Some(order_contract) = order
-        .and_then(|order| order.deterministic_secondary_order_contract_fields(&primary_key_names))
+    let Some(order_contract) =
+        order.and_then(|order| order.deterministic_secondary_order_contract_for_schema(schema))
     else {
         return false;
     };
@@ -0,0 +1,129 @@
+//! Module: query::plan::planner::scalar_expression
+//! Responsibility: planner `WHERE` pushdown onto arbitrary scalar expression indexes.
+//! Does not own: expression normalization, key derivation, or residual filter evaluation.
+//! Boundary: matches normalized filter operands against accepted index fingerprints.
+
+use crate::{
+    db::{
+        access::{
+            AccessPlan, SemanticIndexAccessContract, SemanticIndexKeyItemRef,
+            SemanticIndexRangeSpec,
+        },
+        index::normalize_scalar_index_key_value,
+        query::plan::{
+            expr::{BinaryOp, Expr},
+            scalar_expression_index_term,
+        },
+    },
+    value::Value,
+};
+use std::ops::Bound;
+
+/// Plan one index lookup from the top-level conjuncts of a normalized filter.
+///
+/// Only `<expr> <cmp> <literal>` conjuncts whose operand renders to the
+/// leading scalar key fingerprint participate. The lookup only narrows
+/// candidates; the original filter stays residual, so mixed conjuncts and
+/// unmatched terms never change query results.
+pub(super) fn plan_scalar_expression_filter(
+    candidate_indexes: &[SemanticIndexAccessContract],
+    filter: &Expr,
+) -> Option<AccessPlan<Value>> {
+    let mut conjuncts = Vec::new();
+    collect_and_conjuncts(filter, &mut conjuncts);
+    let compares: Vec<(String, BinaryOp, Value)> = conjuncts
+        .into_iter()
+        .filter_map(scalar_literal_compare)
+        .collect();
+    if compares.is_empty() {
+        return None;
+    }
+
+    let mut best_range = None;
+    for index in candidate_indexes {
+        let Some(SemanticIndexKeyItemRef::Scalar(fingerprint)) = index.key_item_at(0) else {
+            continue;
+        };
+        let mut lower = Bound::Unbounded;
+        let mut upper = Bound::Unbounded;
+        for (term, op, value) in &compares {
+            if term != fingerprint {
+                continue;
+            }
+            let value = normalize_scalar_index_key_value(value.clone());
+            match op {
+                BinaryOp::Eq => {
+                    return Some(AccessPlan::index_prefix_from_contract(
+                        index.clone(),
+                        vec![value],
+                    ));
+                }
+                BinaryOp::Gt if matches!(lower, Bound::Unbounded) => {
+                    lower = Bound::Excluded(value);
+                }
+                BinaryOp::Gte if matches!(lower, Bound::Unbounded) => {
+                    lower = Bound::Included(value);
+                }
+                BinaryOp::Lt if matches!(upper, Bound::Unbounded) => {
+                    upper = Bound::Excluded(value);
+                }
+                BinaryOp::Lte if matches!(upper, Bound::Unbounded) => {
+                    upper = Bound::Included(value);
+                }
+                _ => {}
+            }
+        }
+        if best_range.is_none()
+            && !(matches!(lower, Bound::Unbounded) && matches!(upper, Bound::Unbounded))
+        {
+            best_range = Some((index, lower, upper));
+        }
+    }
+
+    best_range.map(|(index, lower, upper)| {
+        AccessPlan::index_range(SemanticIndexRangeSpec::from_access_contract(
+            index.clone(),
+            vec![0usize],
+            Vec::new(),
+            lower,
+            upper,
+        ))
+    })
+}
+
+// Flatten one normalized `AND` chain into its conjunct operands.
+fn collect_and_conjuncts<'a>(expr: &'a Expr, out: &mut Vec<&'a Expr>) {
+    match expr {
+        Expr::Binary {
+            op: BinaryOp::And,
+            left,
+            right,
+        } => {
+            collect_and_conjuncts(left, out);
+            collect_and_conjuncts(right, out);
+        }
+        expr => out.push(expr),
+    }
+}
+
+// Project one ordered compare against a non-null literal into its canonical
+// operand fingerprint. Normalization already moved literals to the right.
+fn scalar_literal_compare(expr: &Expr) -> Option<(String, BinaryOp, Value)> {
+    let Expr::Binary { op, left, right } = expr else {
+        return None;
+    };
+    if !matches!(
+        op,
+        BinaryOp::Eq | BinaryOp::Lt | BinaryOp::Lte | BinaryOp::Gt | BinaryOp::Gte
+    ) {
+        return None;
+    }
+    let Expr::Literal(value) = right.as_ref() else {
+        return None;
+    };
+    if matches!(value, Value::Null) || matches!(left.as_ref(), Expr::Literal(_)) {
+        return None;
+    }
+
+    Some((scalar_expression_index_term(left), *op, value.clone()))
+}
@@ -0,0 +1,95 @@
+//! Module: query::plan::planner::text_match
+//! Responsibility: planner `MATCH` access-path lowering onto text token indexes.
+//! Does not own: tokenization, query parsing, or residual `MATCH` evaluation.
+//! Boundary: derives union/intersection token lookups from one parsed query.
+
+use crate::{
+    db::{
+        access::{
+            AccessPlan, SemanticIndexAccessContract, SemanticIndexKeyItemRef,
+            SemanticIndexRangeSpec,
+        },
+        index::{TextPrefixBoundMode, starts_with_component_bounds},
+        schema::PersistedIndexExpressionOp,
+        text_search::{TextMatchQuery, TextMatchTerm},
+    },
+    value::Value,
+};
+
+/// Plan one `MATCH(field, query)` predicate against the visible text indexes.
+///
+/// Each OR group becomes an intersection of per-term token lookups and the
+/// groups are unioned. Exact terms seek one token prefix; prefix terms scan the
+/// token range that starts with the term. The full predicate always stays in
+/// the residual filter, so the token routes only need to be a superset.
+pub(super) fn plan_text_match(
+    candidate_indexes: &[SemanticIndexAccessContract],
+    field: &str,
+    value: &Value,
+) -> AccessPlan<Value> {
+    let Value::Text(query) = value else {
+        return AccessPlan::full_scan();
+    };
+    let Ok(query) = TextMatchQuery::parse(query) else {
+        return AccessPlan::full_scan();
+    };
+    let Some(index) = candidate_indexes
+        .iter()
+        .find(|index| index_is_text_token_index_on(index, field))
+    else {
+        return AccessPlan::full_scan();
+    };
+
+    let groups = query
+        .groups()
+        .iter()
+        .map(|terms| {
+            AccessPlan::intersection(
+                terms
+                    .iter()
+                    .map(|term| text_match_term_access(index, term))
+                    .collect(),
+            )
+        })
+        .collect();
+
+    AccessPlan::union(groups)
+}
+
+// Return whether the index is keyed by the token expansion of one field.
+fn index_is_text_token_index_on(index: &SemanticIndexAccessContract, field: &str) -> bool {
+    matches!(
+        index.key_item_at(0),
+        Some(SemanticIndexKeyItemRef::AcceptedExpression(expression))
+            if expression.op() == PersistedIndexExpressionOp::Tokens && expression.field() == field
+    )
+}
+
+// Lower one parsed term onto its token lookup.
+fn text_match_term_access(
+    index: &SemanticIndexAccessContract,
+    term: &TextMatchTerm,
+) -> AccessPlan<Value> {
+    match term {
+        TextMatchTerm::Exact(token) => {
+            AccessPlan::index_prefix_from_contract(index.clone(), vec![Value::Text(token.clone())])
+        }
+        TextMatchTerm::Prefix(prefix) => {
+            // Token components share the canonical text encoding used by
+            // field keys, so the strict next-prefix upper bound is exact.
+            let Some((lower, upper)) =
+                starts_with_component_bounds(prefix, TextPrefixBoundMode::Strict)
+            else {
+                return AccessPlan::full_scan();
+            };
+
+            AccessPlan::index_range(SemanticIndexRangeSpec::from_access_contract(
+                index.clone(),
+                vec![0usize],
+                Vec::new(),
+                lower,
+                upper,
+            ))
+        }
+    }
+}
@@ -9,9 +9,15 @@ use crate::{
     db::{
         query::{
             builder::AggregateExpr,
+            builder::scalar_projection::render_scalar_projection_expr_plan_label,
             plan::{
-                AggregateIdentity, AggregateKind, AggregateSemanticKey, AggregateShape, FieldSlot,
-                FieldSlotAuthority, GroupAggregateSpec, GroupPlan, expr::Expr,
+                AggregateArguments, AggregateIdentity, AggregateKind, AggregateSemanticKey,
+                AggregateShape, ComputedGroupKey, FieldSlot, FieldSlotAuthority,
+                GroupAggregateSpec, GroupPlan,
+                expr::{
+                    CaseWhenArm, CompiledExpr, Expr, FieldId,
+                    compile_scalar_projection_expr_with_schema,
+                },
             },
         },
         schema::{AcceptedFieldKind, SchemaInfo, canonicalize_filter_literal_for_persisted_kind},
@@ -92,10 +98,11 @@ impl GroupAggregateSpec {
     /// Build the canonical aggregate identity for this grouped terminal.
     #[must_use]
     pub(in crate::db) fn identity(&self) -> AggregateIdentity {
-        AggregateIdentity::from_kind_input_and_distinct(
+        AggregateIdentity::from_parts(
             self.kind(),
             self.identity_input_expr_owned(),
             self.raw_distinct(),
+            self.shape().arguments().cloned(),
         )
     }
 
@@ -126,6 +133,12 @@ impl GroupAggregateSpec {
         self.shape().filter_expr()
     }
 
+    /// Borrow the retained-value aggregate arguments, if any.
+    #[must_use]
+    pub(in crate::db) fn arguments(&self) -> Option<&AggregateArguments> {
+        self.shape().arguments()
+    }
+
     /// Build the canonical grouped aggregate input expression for identity-only
     /// comparisons.
     #[must_use]
@@ -177,6 +190,89 @@ pub(in crate::db) fn group_aggregate_spec_expr(aggregate: &GroupAggregateSpec) -
     )
 }
 
+/// Replace every subexpression equal to one computed grouped key with a
+/// direct reference to that key's canonical label.
+///
+/// Grouped projection, HAVING, and ORDER BY resolve grouped keys by name, so
+/// binding computed keys onto their labels lets those lanes stay name-based.
+/// Aggregate leaves are never rewritten because their inputs are row-local.
+#[must_use]
+pub(in crate::db) fn bind_computed_group_key_refs(expr: Expr, keys: &[Expr]) -> Expr {
+    rewrite_group_key_tree(expr, &mut |node| {
+        keys.iter()
+            .find(|key| !matches!(key, Expr::Field(_)) && *key == node)
+            .map(|key| Expr::Field(FieldId::new(render_scalar_projection_expr_plan_label(key))))
+    })
+}
+
+/// Replace direct references to computed grouped key labels with the key
+/// expressions so schema type inference sees the source expression again.
+#[must_use]
+pub(in crate::db) fn expand_computed_group_key_refs(
+    expr: Expr,
+    group_fields: &[FieldSlot],
+) -> Expr {
+    rewrite_group_key_tree(expr, &mut |node| {
+        let Expr::Field(field) = node else {
+            return None;
+        };
+
+        group_fields
+            .iter()
+            .find(|group_field| group_field.field() == field.as_str())
+            .and_then(FieldSlot::computed_expr)
+            .cloned()
+    })
+}
+
+// Rewrite one planner expression top-down, replacing any node the caller maps
+// and descending into every non-aggregate child otherwise.
+fn rewrite_group_key_tree(expr: Expr, replace: &mut impl FnMut(&Expr) -> Option<Expr>) -> Expr {
+    if let Some(replacement) = replace(&expr) {
+        return replacement;
+    }
+
+    match expr {
+        Expr::FunctionCall { function, args } => Expr::FunctionCall {
+            function,
+            args: args
+                .into_iter()
+                .map(|arg| rewrite_group_key_tree(arg, replace))
+                .collect(),
+        },
+        Expr::Unary { op, expr } => Expr::Unary {
+            op,
+            expr: Box::new(rewrite_group_key_tree(*expr, replace)),
+        },
+        Expr::Binary { op, left, right } => Expr::Binary {
+            op,
+            left: Box::new(rewrite_group_key_tree(*left, replace)),
+            right: Box::new(rewrite_group_key_tree(*right, replace)),
+        },
+        Expr::Case {
+            when_then_arms,
+            else_expr,
+        } => Expr::Case {
+            when_then_arms: when_then_arms
+                .into_iter()
+                .map(|arm| {
+                    CaseWhenArm::new(
+                        rewrite_group_key_tree(arm.condition().clone(), replace),
+                        rewrite_group_key_tree(arm.result().clone(), replace),
+                    )
+                })
+                .collect(),
+            else_expr: Box::new(rewrite_group_key_tree(*else_expr, replace)),
+        },
+        #[cfg(test)]
+        Expr::Alias { expr, name } => Expr::Alias {
+            expr: Box::new(rewrite_group_key_tree(*expr, replace)),
+            name,
+        },
+        Expr::Field(_) | Expr::FieldPath(_) | Expr::Literal(_) | Expr::Aggregate(_) => expr,
+    }
+}
+
 impl FieldSlot {
     /// Build one unresolved field slot used only where no field contract exists.
     #[must_use]
@@ -204,6 +300,41 @@ impl FieldSlot {
         Some(Self::from_accepted_kind(index, field, kind))
     }
 
+    /// Resolve one computed grouped key onto one virtual slot.
+    ///
+    /// The expression must compile against accepted schema authority; the
+    /// canonical rendered expression becomes the key label that grouped
+    /// projection, HAVING, and ORDER BY references bind to.
+    #[must_use]
+    pub(in crate::db) fn computed_with_schema(
+        schema: &SchemaInfo,
+        index: usize,
+        expr: Expr,
+    ) -> Option<Self> {
+        let scalar = compile_scalar_projection_expr_with_schema(schema, &expr)?;
+        let compiled = CompiledExpr::compile(&scalar);
+
+        Some(Self {
+            index,
+            field: render_scalar_projection_expr_plan_label(&expr),
+            authority: FieldSlotAuthority::Computed(Box::new(ComputedGroupKey { expr, compiled })),
+        })
+    }
+
+    /// Rebind this slot onto one accepted schema, recompiling computed keys
+    /// instead of resolving their labels as field names.
+    #[must_use]
+    pub(in crate::db) fn rebind_with_schema(&self, schema: &SchemaInfo) -> Option<Self> {
+        match &self.authority {
+            FieldSlotAuthority::Computed(key) => {
+                Self::computed_with_schema(schema, self.index, key.expr.clone())
+            }
+            FieldSlotAuthority::Unresolved | FieldSlotAuthority::Accepted(_) => {
+                Self::resolve_with_schema(schema, self.field())
+            }
+        }
+    }
+
     /// Return the stable accepted field slot.
     #[must_use]
     pub(in crate::db) const fn index(&self) -> usize {
@@ -221,7 +352,25 @@ impl FieldSlot {
     pub(in crate::db) const fn accepted_kind(&self) -> Option<&AcceptedFieldKind> {
         match &self.authority {
             FieldSlotAuthority::Accepted(kind) => Some(kind),
-            FieldSlotAuthority::Unresolved => None,
+            FieldSlotAuthority::Unresolved | FieldSlotAuthority::Computed(_) => None,
+        }
+    }
+
+    /// Borrow the planner expression behind one computed grouped key.
+    #[must_use]
+    pub(in crate::db) fn computed_expr(&self) -> Option<&Expr> {
+        match &self.authority {
+            FieldSlotAuthority::Computed(key) => Some(&key.expr),
+            FieldSlotAuthority::Unresolved | FieldSlotAuthority::Accepted(_) => None,
+        }
+    }
+
+    /// Borrow the compiled row expression behind one computed grouped key.
+    #[must_use]
+    pub(in crate::db) fn compiled_computed_expr(&self) -> Option<&CompiledExpr> {
+        match &self.authority {
+            FieldSlotAuthority::Computed(key) => Some(&key.compiled),
+            FieldSlotAuthority::Unresolved | FieldSlotAuthority::Accepted(_) => None,
         }
     }
 
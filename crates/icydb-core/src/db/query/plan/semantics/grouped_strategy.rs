@@ -297,10 +297,12 @@ fn grouped_order_strategy_projection(
         .iter()
         .map(FieldSlot::field)
         .collect::<Vec<_>>();
-    let top_k_required = order
-        .fields
-        .iter()
-        .any(|term| grouped_top_k_order_term_requires_heap(term.expr()));
+    // Canonical grouped ordering ranks by the composite group key, which only
+    // knows the natural null placement, so explicit `NULLS FIRST | LAST`
+    // overrides also reserve the per-term Top-K ranking lane.
+    let top_k_required = order.fields.iter().any(|term| {
+        grouped_top_k_order_term_requires_heap(term.expr()) || term.overrides_natural_nulls()
+    });
 
     if top_k_required {
         return grouped_top_k_strategy_projection(order, grouped_field_names.as_slice());
@@ -404,15 +406,24 @@ fn grouped_access_path_proves_group_order<K>(
     // grouped-order proof may skip them until the next declared grouped key.
     // Any gap beyond the equality prefix remains unfixed and therefore blocks
     // ordered grouping.
+    //
+    // Key items compare by canonical text so computed grouped keys such as
+    // `LOWER(email)` only match the identical accepted expression key item.
+    let key_text_at = |cursor: usize| {
+        details
+            .key_items()
+            .get(cursor)
+            .map(|item| item.as_ref().canonical_text())
+    };
     for group_field in group_fields {
         while cursor < prefix_len
             && cursor < details.key_arity()
-            && details.key_field_at(cursor) != Some(group_field.field())
+            && key_text_at(cursor).as_deref() != Some(group_field.field())
         {
             cursor = cursor.saturating_add(1);
         }
         if cursor >= details.key_arity()
-            || details.key_field_at(cursor) != Some(group_field.field())
+            || key_text_at(cursor).as_deref() != Some(group_field.field())
         {
             return false;
         }
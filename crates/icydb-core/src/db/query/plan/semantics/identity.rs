@@ -6,7 +6,7 @@
 use crate::db::query::{
     builder::AggregateExpr,
     plan::{
-        AggregateKind,
+        AggregateArguments, AggregateKind,
         expr::{Expr, aggregate_count_input_expr_is_non_null_literal},
     },
 };
@@ -52,6 +52,15 @@ pub(in crate::db) enum AggregateIdentity {
         input_expr: Option<Expr>,
         distinct: bool,
     },
+    Statistic {
+        kind: AggregateKind,
+        input_expr: Option<Expr>,
+    },
+    Retained {
+        kind: AggregateKind,
+        input_expr: Option<Expr>,
+        arguments: Option<AggregateArguments>,
+    },
 }
 
 impl AggregateIdentity {
@@ -60,11 +69,27 @@ impl AggregateIdentity {
     /// Non-distinct `COUNT` over a non-null literal normalizes to row count
     /// identity so SQL, grouped, and fluent aggregate callers share the same
     /// meaning-level key.
+    #[cfg(test)]
     #[must_use]
     pub(in crate::db) fn from_kind_input_and_distinct(
         kind: AggregateKind,
         input_expr: Option<Expr>,
         distinct: bool,
+    ) -> Self {
+        Self::from_parts(kind, input_expr, distinct, None)
+    }
+
+    /// Build aggregate identity from its kind, input, DISTINCT bit, and
+    /// retained-value arguments.
+    ///
+    /// Arguments only participate in identity for the kinds that own them,
+    /// so `STRING_AGG(x, ',')` and `STRING_AGG(x, ';')` stay distinct terminals.
+    #[must_use]
+    pub(in crate::db) fn from_parts(
+        kind: AggregateKind,
+        input_expr: Option<Expr>,
+        distinct: bool,
+        arguments: Option<AggregateArguments>,
     ) -> Self {
         let input_expr = normalize_aggregate_identity_input(kind, input_expr, distinct);
 
@@ -95,16 +120,28 @@ impl AggregateIdentity {
                 input_expr,
                 distinct,
             },
+            AggregateKind::VarPop
+            | AggregateKind::VarSamp
+            | AggregateKind::StddevPop
+            | AggregateKind::StddevSamp => Self::Statistic { kind, input_expr },
+            AggregateKind::PercentileDisc | AggregateKind::StringAgg | AggregateKind::ArrayAgg => {
+                Self::Retained {
+                    kind,
+                    input_expr,
+                    arguments: arguments.filter(|arguments| arguments.matches_kind(kind)),
+                }
+            }
         }
     }
 
     /// Build aggregate identity from one raw aggregate expression.
     #[must_use]
     pub(in crate::db) fn from_aggregate_expr(aggregate: &AggregateExpr) -> Self {
-        Self::from_kind_input_and_distinct(
+        Self::from_parts(
             aggregate.kind(),
             aggregate.input_expr().cloned(),
             aggregate.is_distinct(),
+            aggregate.arguments().cloned(),
         )
     }
 
@@ -115,7 +152,15 @@ impl AggregateIdentity {
         distinct: bool,
     ) -> bool {
         match kind {
-            AggregateKind::Min | AggregateKind::Max => false,
+            AggregateKind::Min
+            | AggregateKind::Max
+            | AggregateKind::VarPop
+            | AggregateKind::VarSamp
+            | AggregateKind::StddevPop
+            | AggregateKind::StddevSamp
+            | AggregateKind::PercentileDisc
+            | AggregateKind::StringAgg
+            | AggregateKind::ArrayAgg => false,
             AggregateKind::Count
             | AggregateKind::Sum
             | AggregateKind::Avg
@@ -137,6 +182,7 @@ impl AggregateIdentity {
             Self::Exists { .. } => AggregateKind::Exists,
             Self::First { .. } => AggregateKind::First,
             Self::Last { .. } => AggregateKind::Last,
+            Self::Statistic { kind, .. } | Self::Retained { kind, .. } => *kind,
         }
     }
 
@@ -151,7 +197,26 @@ impl AggregateIdentity {
             | Self::Max { input_expr }
             | Self::Exists { input_expr, .. }
             | Self::First { input_expr, .. }
-            | Self::Last { input_expr, .. } => input_expr.as_ref(),
+            | Self::Last { input_expr, .. }
+            | Self::Statistic { input_expr, .. }
+            | Self::Retained { input_expr, .. } => input_expr.as_ref(),
+        }
+    }
+
+    /// Borrow the retained-value aggregate arguments, if any.
+    #[must_use]
+    pub(in crate::db) const fn arguments(&self) -> Option<&AggregateArguments> {
+        match self {
+            Self::Retained { arguments, .. } => arguments.as_ref(),
+            Self::Count { .. }
+            | Self::Sum { .. }
+            | Self::Avg { .. }
+            | Self::Min { .. }
+            | Self::Max { .. }
+            | Self::Exists { .. }
+            | Self::First { .. }
+            | Self::Last { .. }
+            | Self::Statistic { .. } => None,
         }
     }
 
@@ -165,7 +230,10 @@ impl AggregateIdentity {
             | Self::Exists { distinct, .. }
             | Self::First { distinct, .. }
             | Self::Last { distinct, .. } => *distinct,
-            Self::Min { .. } | Self::Max { .. } => false,
+            Self::Min { .. }
+            | Self::Max { .. }
+            | Self::Statistic { .. }
+            | Self::Retained { .. } => false,
         }
     }
 
@@ -10,14 +10,15 @@ use crate::{
         predicate::{IndexCompileTarget, IndexCompileTargetKind, Predicate, PredicateProgram},
         query::plan::{
             AccessPlannedQuery, ContinuationPolicy, DistinctExecutionStrategy,
-            EffectiveRuntimeFilterProgram, ExecutionShapeSignature, FieldSlot, GroupPlan,
+            EffectiveRuntimeFilterProgram, ExecutionShapeSignature, GroupPlan,
             GroupedAggregateExecutionSpec, GroupedDistinctExecutionStrategy, GroupedPlanStrategy,
-            LogicalPlan, PlannerRouteProfile, PredicatePushdownDiagnostics, QueryMode,
+            LogicalPlan, OrderTerm, PlannerRouteProfile, PredicatePushdownDiagnostics, QueryMode,
             ResidualFilterContract, ResidualFilterShape, ResolvedOrder, ResolvedOrderField,
             ResolvedOrderValueSource, ScalarPlan, StaticExecutionPlanningContract,
-            derive_logical_pushdown_eligibility,
+            derive_logical_pushdown_eligibility, expand_computed_group_key_refs,
             expr::{
-                CompiledExpr, Expr, ProjectionSpec, compile_scalar_projection_expr_with_schema,
+                CompiledExpr, Expr, ProjectionField, ProjectionSpec,
+                compile_scalar_projection_expr_with_schema,
                 compile_scalar_projection_plan_with_schema,
             },
             extend_unique_grouped_aggregate_specs_from_expr, grouped_aggregate_execution_specs,
@@ -293,8 +294,8 @@ impl AccessPlannedQuery {
         Ok(())
     }
 
-    // Resolve authoring-time group field names onto accepted slots before the
-    // plan becomes executable.
+    // Resolve authoring-time group field names onto accepted slots, and
+    // recompile computed group keys, before the plan becomes executable.
     fn bind_group_field_slots_to_schema(
         &mut self,
         schema_info: &SchemaInfo,
@@ -307,7 +308,7 @@ impl AccessPlannedQuery {
             .group
             .group_fields
             .iter()
-            .map(|field_slot| FieldSlot::resolve_with_schema(schema_info, field_slot.field()))
+            .map(|field_slot| field_slot.rebind_with_schema(schema_info))
             .collect::<Option<Vec<_>>>()
             .ok_or_else(InternalError::planner_executor_invariant)?;
         grouped.group.group_fields = accepted_slots;
@@ -504,10 +505,11 @@ pub(in crate::db) fn project_planner_route_profile_for_schema(
     schema_info: &SchemaInfo,
     plan: &AccessPlannedQuery,
 ) -> PlannerRouteProfile {
-    let primary_key_names = primary_key_names_from_schema(schema_info);
-    let secondary_order_contract = plan.scalar_plan().order.as_ref().and_then(|order| {
-        order.deterministic_secondary_order_contract_fields(primary_key_names.as_slice())
-    });
+    let secondary_order_contract = plan
+        .scalar_plan()
+        .order
+        .as_ref()
+        .and_then(|order| order.deterministic_secondary_order_contract_for_schema(schema_info));
 
     PlannerRouteProfile::new(
         derive_continuation_policy_validated(plan),
@@ -516,6 +518,39 @@ pub(in crate::db) fn project_planner_route_profile_for_schema(
     )
 }
 
+// Resolve projection-referenced slots for one plan. Computed grouped keys
+// surface in grouped projections as references to their canonical labels, so
+// expand those back onto the source expressions before slot resolution.
+fn projection_referenced_slots_for_schema_plan(
+    schema_info: &SchemaInfo,
+    plan: &AccessPlannedQuery,
+    projection_spec: &ProjectionSpec,
+) -> Result<Vec<usize>, InternalError> {
+    let Some(grouped) = plan.grouped_plan() else {
+        return projection_spec.referenced_slots_for_schema(schema_info);
+    };
+    let group_fields = grouped.group.group_fields.as_slice();
+    if group_fields
+        .iter()
+        .all(|field| field.computed_expr().is_none())
+    {
+        return projection_spec.referenced_slots_for_schema(schema_info);
+    }
+
+    ProjectionSpec::new(
+        projection_spec
+            .fields()
+            .map(|field| match field {
+                ProjectionField::Scalar { expr, alias } => ProjectionField::Scalar {
+                    expr: expand_computed_group_key_refs(expr.clone(), group_fields),
+                    alias: alias.clone(),
+                },
+            })
+            .collect(),
+    )
+    .referenced_slots_for_schema(schema_info)
+}
+
 fn project_static_execution_planning_contract_with_schema(
     schema_info: &SchemaInfo,
     plan: &AccessPlannedQuery,
@@ -570,7 +605,8 @@ fn project_static_execution_planning_contract_with_schema(
         &plan.logical,
         &plan.projection_selection,
     );
-    let projection_referenced_slots = projection_spec.referenced_slots_for_schema(schema_info)?;
+    let projection_referenced_slots =
+        projection_referenced_slots_for_schema_plan(schema_info, plan, &projection_spec)?;
     let projected_slot_mask =
         projected_slot_mask_for_spec(schema_info, projection_direct_slots.as_deref());
     let projection_is_model_identity = projection_spec.is_schema_identity_for(schema_info);
@@ -601,14 +637,6 @@ fn project_static_execution_planning_contract_with_schema(
     })
 }
 
-fn primary_key_names_from_schema(schema_info: &SchemaInfo) -> Vec<&str> {
-    schema_info
-        .primary_key_names()
-        .iter()
-        .map(String::as_str)
-        .collect()
-}
-
 // Compile the executor-owned residual scalar filter contract once from the
 // planner-derived residual artifacts so runtime never has to rediscover
 // residual presence or shape from semantic/filter/pushdown state.
@@ -618,10 +646,12 @@ fn compile_effective_runtime_filter_program(
     residual_filter_predicate: Option<&Predicate>,
 ) -> Result<Option<EffectiveRuntimeFilterProgram>, InternalError> {
     // Keep the existing predicate fast path when the residual semantics still
-    // fit the derived predicate contract. The expression-owned lane is only
-    // needed once pushdown loses semantic coverage and a residual predicate no
-    // longer exists.
-    if let Some(predicate) = residual_filter_predicate {
+    // fit the derived predicate contract. A surviving residual expression means
+    // the predicate covers only part of the filter, so the expression-owned
+    // lane must evaluate the full semantics.
+    if residual_filter_expr.is_none()
+        && let Some(predicate) = residual_filter_predicate
+    {
         return Ok(Some(EffectiveRuntimeFilterProgram::predicate(
             PredicateProgram::compile_with_schema_info(schema_info, predicate),
         )));
@@ -849,11 +879,31 @@ fn resolved_order_for_plan(
         return Ok(None);
     };
 
+    // Grouped ORDER BY terms reference computed grouped keys by label, so
+    // resolve those terms over the underlying key expressions instead.
+    let group_fields = plan
+        .grouped_plan()
+        .map_or(&[][..], |grouped| grouped.group.group_fields.as_slice());
     let mut fields = Vec::with_capacity(order.fields.len());
     for term in &order.fields {
+        let source = if group_fields
+            .iter()
+            .any(|field| field.computed_expr().is_some())
+        {
+            resolved_order_value_source_for_term(
+                schema_info,
+                &OrderTerm::new(
+                    expand_computed_group_key_refs(term.expr().clone(), group_fields),
+                    term.direction(),
+                ),
+            )?
+        } else {
+            resolved_order_value_source_for_term(schema_info, term)?
+        };
         fields.push(ResolvedOrderField::new(
-            resolved_order_value_source_for_term(schema_info, term)?,
+            source,
             term.direction(),
+            term.nulls(),
         ));
     }
 
@@ -862,7 +912,7 @@ fn resolved_order_for_plan(
 
 fn resolved_order_value_source_for_term(
     schema_info: &SchemaInfo,
-    term: &crate::db::query::plan::OrderTerm,
+    term: &OrderTerm,
 ) -> Result<ResolvedOrderValueSource, InternalError> {
     if term.direct_field().is_none() {
         let rendered = term.rendered_label();
@@ -984,6 +1034,7 @@ fn index_compile_targets_for_schema_plan(
                 SemanticIndexKeyItemRef::AcceptedExpression(expression) => {
                     IndexCompileTargetKind::Expression(expression.op())
                 }
+                SemanticIndexKeyItemRef::Scalar(_) => return None,
             },
         });
     }
@@ -29,7 +29,9 @@ pub(in crate::db) use group_having::{
 };
 #[cfg(feature = "sql")]
 pub(in crate::db) use group_model::canonicalize_grouped_having_numeric_literal_for_slot;
-pub(in crate::db) use group_model::group_aggregate_spec_expr;
+pub(in crate::db) use group_model::{
+    bind_computed_group_key_refs, expand_computed_group_key_refs, group_aggregate_spec_expr,
+};
 pub(in crate::db) use grouped_strategy::grouped_plan_strategy;
 pub(in crate::db) use grouped_strategy::{GroupedPlanFallbackReason, GroupedPlanStrategy};
 pub(in crate::db) use identity::{AggregateIdentity, AggregateSemanticKey};
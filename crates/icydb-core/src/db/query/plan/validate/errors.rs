@@ -37,6 +37,13 @@ const fn diagnostic_aggregate_kind(kind: AggregateKind) -> DiagnosticAggregateKi
         AggregateKind::Max => DiagnosticAggregateKind::Max,
         AggregateKind::First => DiagnosticAggregateKind::First,
         AggregateKind::Last => DiagnosticAggregateKind::Last,
+        AggregateKind::VarPop => DiagnosticAggregateKind::VarPop,
+        AggregateKind::VarSamp => DiagnosticAggregateKind::VarSamp,
+        AggregateKind::StddevPop => DiagnosticAggregateKind::StddevPop,
+        AggregateKind::StddevSamp => DiagnosticAggregateKind::StddevSamp,
+        AggregateKind::PercentileDisc => DiagnosticAggregateKind::PercentileDisc,
+        AggregateKind::StringAgg => DiagnosticAggregateKind::StringAgg,
+        AggregateKind::ArrayAgg => DiagnosticAggregateKind::ArrayAgg,
     }
 }
 
@@ -396,6 +403,13 @@ pub enum PolicyPlanError {
 
     /// Pagination requires an explicit ordering.
     UnorderedPagination,
+
+    /// Per-partition limits are load-only and exclude grouping, DISTINCT,
+    /// and pagination.
+    PartitionLimitShapeUnsupported,
+
+    /// Per-partition limits require ORDER BY to lead with the partition fields.
+    PartitionLimitRequiresOrderPrefix,
 }
 
 impl PolicyPlanError {
@@ -428,6 +442,16 @@ impl PolicyPlanError {
     pub(in crate::db::query) const fn unordered_pagination() -> Self {
         Self::UnorderedPagination
     }
+
+    /// Construct one unsupported-partition-limit-shape policy error.
+    pub(in crate::db::query) const fn partition_limit_shape_unsupported() -> Self {
+        Self::PartitionLimitShapeUnsupported
+    }
+
+    /// Construct one partition-limit-requires-order-prefix policy error.
+    pub(in crate::db::query) const fn partition_limit_requires_order_prefix() -> Self {
+        Self::PartitionLimitRequiresOrderPrefix
+    }
 }
 
 ///
@@ -869,13 +893,17 @@ pub struct ExprPlanFunctionCode(DiagnosticFunctionKind);
 
 impl ExprPlanFunctionCode {
     pub const ABS: Self = Self(DiagnosticFunctionKind::Abs);
+    pub const AGE: Self = Self(DiagnosticFunctionKind::Age);
+    pub const CAST: Self = Self(DiagnosticFunctionKind::Cast);
     pub const CBRT: Self = Self(DiagnosticFunctionKind::Cbrt);
     pub const CEILING: Self = Self(DiagnosticFunctionKind::Ceiling);
     pub const COALESCE: Self = Self(DiagnosticFunctionKind::Coalesce);
     pub const COLLECTION_CONTAINS: Self = Self(DiagnosticFunctionKind::CollectionContains);
     pub const CONTAINS: Self = Self(DiagnosticFunctionKind::Contains);
+    pub const DATE_TRUNC: Self = Self(DiagnosticFunctionKind::DateTrunc);
     pub const ENDS_WITH: Self = Self(DiagnosticFunctionKind::EndsWith);
     pub const EXP: Self = Self(DiagnosticFunctionKind::Exp);
+    pub const EXTRACT: Self = Self(DiagnosticFunctionKind::Extract);
     pub const FLOOR: Self = Self(DiagnosticFunctionKind::Floor);
     pub const IN_LIST: Self = Self(DiagnosticFunctionKind::InList);
     pub const IS_EMPTY: Self = Self(DiagnosticFunctionKind::IsEmpty);
@@ -885,17 +913,22 @@ impl ExprPlanFunctionCode {
     pub const IS_NULL: Self = Self(DiagnosticFunctionKind::IsNull);
     pub const LEFT: Self = Self(DiagnosticFunctionKind::Left);
     pub const LENGTH: Self = Self(DiagnosticFunctionKind::Length);
+    pub const LIKE: Self = Self(DiagnosticFunctionKind::Like);
     pub const LN: Self = Self(DiagnosticFunctionKind::Ln);
     pub const LOG: Self = Self(DiagnosticFunctionKind::Log);
     pub const LOG2: Self = Self(DiagnosticFunctionKind::Log2);
     pub const LOG10: Self = Self(DiagnosticFunctionKind::Log10);
     pub const LOWER: Self = Self(DiagnosticFunctionKind::Lower);
     pub const LTRIM: Self = Self(DiagnosticFunctionKind::Ltrim);
+    pub const MATCH: Self = Self(DiagnosticFunctionKind::Match);
+    pub const HAS_KEY: Self = Self(DiagnosticFunctionKind::HasKey);
     pub const MOD: Self = Self(DiagnosticFunctionKind::Mod);
+    pub const NOW: Self = Self(DiagnosticFunctionKind::Now);
     pub const NULLIF: Self = Self(DiagnosticFunctionKind::NullIf);
     pub const OCTET_LENGTH: Self = Self(DiagnosticFunctionKind::OctetLength);
     pub const POSITION: Self = Self(DiagnosticFunctionKind::Position);
     pub const POWER: Self = Self(DiagnosticFunctionKind::Power);
+    pub const REGEXP_MATCHES: Self = Self(DiagnosticFunctionKind::RegexpMatches);
     pub const REPLACE: Self = Self(DiagnosticFunctionKind::Replace);
     pub const RIGHT: Self = Self(DiagnosticFunctionKind::Right);
     pub const ROUND: Self = Self(DiagnosticFunctionKind::Round);
@@ -911,13 +944,17 @@ impl ExprPlanFunctionCode {
     pub(in crate::db) const fn from_function(function: Function) -> Self {
         match function {
             Function::Abs => Self::ABS,
+            Function::Age => Self::AGE,
+            Function::Cast => Self::CAST,
             Function::Cbrt => Self::CBRT,
             Function::Ceiling => Self::CEILING,
             Function::Coalesce => Self::COALESCE,
             Function::CollectionContains => Self::COLLECTION_CONTAINS,
             Function::Contains => Self::CONTAINS,
+            Function::DateTrunc => Self::DATE_TRUNC,
             Function::EndsWith => Self::ENDS_WITH,
             Function::Exp => Self::EXP,
+            Function::Extract => Self::EXTRACT,
             Function::Floor => Self::FLOOR,
             Function::InList => Self::IN_LIST,
             Function::IsEmpty => Self::IS_EMPTY,
@@ -927,17 +964,22 @@ impl ExprPlanFunctionCode {
             Function::IsNull => Self::IS_NULL,
             Function::Left => Self::LEFT,
             Function::Length => Self::LENGTH,
+            Function::Like => Self::LIKE,
             Function::Ln => Self::LN,
             Function::Log => Self::LOG,
             Function::Log2 => Self::LOG2,
             Function::Log10 => Self::LOG10,
             Function::Lower => Self::LOWER,
             Function::Ltrim => Self::LTRIM,
+            Function::Match => Self::MATCH,
+            Function::HasKey => Self::HAS_KEY,
             Function::Mod => Self::MOD,
+            Function::Now => Self::NOW,
             Function::NullIf => Self::NULLIF,
             Function::OctetLength => Self::OCTET_LENGTH,
             Function::Position => Self::POSITION,
             Function::Power => Self::POWER,
+            Function::RegexpMatches => Self::REGEXP_MATCHES,
             Function::Replace => Self::REPLACE,
             Function::Right => Self::RIGHT,
             Function::Round => Self::ROUND,
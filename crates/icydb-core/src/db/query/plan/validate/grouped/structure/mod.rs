@@ -5,7 +5,7 @@
 
 use crate::db::{
     query::plan::{
-        AggregateSemanticKey, GroupSpec,
+        AggregateSemanticKey, FieldSlot, GroupSpec,
         expr::{Expr, ProjectionSpec},
         validate::grouped::projection_expr::validate_group_projection_expr_compatibility,
         validate::{GroupPlanError, PlanError, resolve_group_aggregate_target_field_type},
@@ -44,7 +44,7 @@ fn validate_grouped_having_structure(
 // Validate that HAVING group-field references are a subset of declared GROUP BY keys.
 fn validate_having_group_field_reference(
     group: &GroupSpec,
-    field_slot: &crate::db::query::plan::FieldSlot,
+    field_slot: &FieldSlot,
     index: usize,
 ) -> Result<(), PlanError> {
     group
@@ -102,6 +102,12 @@ fn validate_group_spec_structure(schema: &SchemaInfo, group: &GroupSpec) -> Resu
 
     let mut seen_accepted_group_slots = Vec::<usize>::with_capacity(group.group_fields.len());
     for (group_index, field_slot) in group.group_fields.iter().enumerate() {
+        // Computed keys own virtual slots past the accepted row layout and are
+        // identified by their canonical expression label instead.
+        if field_slot.computed_expr().is_some() {
+            validate_computed_group_key_structure(schema, group, group_index, field_slot)?;
+            continue;
+        }
         let Some(accepted_slot) = schema.field_slot_index(field_slot.field()) else {
             return Err(PlanError::from(GroupPlanError::unknown_group_field_at(
                 group_index,
@@ -146,6 +152,34 @@ fn validate_group_spec_structure(schema: &SchemaInfo, group: &GroupSpec) -> Resu
     Ok(())
 }
 
+// Validate one computed grouped key against the accepted row slot bound and
+// the labels of earlier grouped keys.
+fn validate_computed_group_key_structure(
+    schema: &SchemaInfo,
+    group: &GroupSpec,
+    group_index: usize,
+    field_slot: &FieldSlot,
+) -> Result<(), PlanError> {
+    if field_slot.index() < schema.field_slot_bound() {
+        return Err(PlanError::from(GroupPlanError::unknown_group_field_at(
+            group_index,
+            field_slot.field(),
+        ))
+        .attach_query_field(QueryFieldRole::GroupBy));
+    }
+    if group.group_fields[..group_index]
+        .iter()
+        .any(|earlier| earlier.field() == field_slot.field())
+    {
+        return Err(PlanError::from(GroupPlanError::duplicate_group_field(
+            group_index,
+            field_slot.field(),
+        )));
+    }
+
+    Ok(())
+}
+
 fn validate_grouped_having_expr_structure(
     group: &GroupSpec,
     expr: &Expr,
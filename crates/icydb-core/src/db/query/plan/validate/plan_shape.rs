@@ -3,7 +3,9 @@
 //! Does not own: cursor wire semantics or executor defensive runtime checks.
 //! Boundary: enforces planner plan-shape policy constraints before execution handoff.
 
-use crate::db::query::plan::{LogicalPlan, OrderSpec, QueryMode, validate::PolicyPlanError};
+use crate::db::query::plan::{
+    LogicalPlan, OrderSpec, PartitionLimitSpec, QueryMode, validate::PolicyPlanError,
+};
 
 ///
 /// PlanShapePolicyContext
@@ -20,6 +22,8 @@ struct PlanShapePolicyContext {
     has_order: bool,
     has_page: bool,
     has_delete_window: bool,
+    distinct: bool,
+    has_partition_limit: bool,
 }
 
 impl PlanShapePolicyContext {
@@ -31,6 +35,8 @@ impl PlanShapePolicyContext {
         has_order: bool,
         has_page: bool,
         has_delete_window: bool,
+        distinct: bool,
+        has_partition_limit: bool,
     ) -> Self {
         Self {
             is_delete_mode,
@@ -38,6 +44,8 @@ impl PlanShapePolicyContext {
             has_order,
             has_page,
             has_delete_window,
+            distinct,
+            has_partition_limit,
         }
     }
 }
@@ -83,6 +91,10 @@ const PLAN_SHAPE_POLICY_RULES: &[PlanShapePolicyRule] = &[
         PolicyPlanError::unordered_pagination(),
         plan_shape_unordered_scalar_load_pagination_violated,
     ),
+    PlanShapePolicyRule::new(
+        PolicyPlanError::partition_limit_shape_unsupported(),
+        plan_shape_partition_limit_shape_violated,
+    ),
 ];
 
 const fn plan_shape_delete_window_requires_order_violated(ctx: PlanShapePolicyContext) -> bool {
@@ -107,6 +119,12 @@ const fn plan_shape_unordered_scalar_load_pagination_violated(ctx: PlanShapePoli
     !ctx.is_delete_mode && ctx.has_page && !ctx.has_order && !ctx.grouped
 }
 
+// Per-partition windows run as one bounded scalar load operator, so they stay
+// out of delete, grouped, DISTINCT, and paged shapes.
+const fn plan_shape_partition_limit_shape_violated(ctx: PlanShapePolicyContext) -> bool {
+    ctx.has_partition_limit && (ctx.is_delete_mode || ctx.grouped || ctx.distinct || ctx.has_page)
+}
+
 fn first_plan_shape_policy_violation(ctx: PlanShapePolicyContext) -> Option<PolicyPlanError> {
     for rule in PLAN_SHAPE_POLICY_RULES {
         if (rule.violated)(ctx) {
@@ -146,6 +164,37 @@ pub(in crate::db::query::plan::validate) fn validate_order_shape(
     Ok(())
 }
 
+// Partition fields must be the leading direct-field ORDER BY terms (in any
+// order) so each partition's rows are ranked by the remaining terms.
+fn validate_partition_limit_order_prefix(
+    partition_limit: &PartitionLimitSpec,
+    order: Option<&OrderSpec>,
+) -> Result<(), PolicyPlanError> {
+    let Some(order) = order else {
+        return Err(PolicyPlanError::partition_limit_requires_order_prefix());
+    };
+    let Some(prefix) = order.fields.get(..partition_limit.fields.len()) else {
+        return Err(PolicyPlanError::partition_limit_requires_order_prefix());
+    };
+    let prefix_matches = prefix.iter().all(|term| {
+        term.direct_field().is_some_and(|field| {
+            partition_limit
+                .fields
+                .iter()
+                .any(|partition| partition == field)
+        })
+    }) && partition_limit.fields.iter().all(|partition| {
+        prefix
+            .iter()
+            .any(|term| term.direct_field() == Some(partition))
+    });
+    if !prefix_matches {
+        return Err(PolicyPlanError::partition_limit_requires_order_prefix());
+    }
+
+    Ok(())
+}
+
 /// Validate mode/order/pagination invariants for one logical plan.
 pub(in crate::db::query) fn validate_plan_shape(plan: &LogicalPlan) -> Result<(), PolicyPlanError> {
     let grouped = matches!(plan, LogicalPlan::Grouped(_));
@@ -161,6 +210,13 @@ pub(in crate::db::query) fn validate_plan_shape(plan: &LogicalPlan) -> Result<()
         has_explicit_order(plan.order.as_ref()),
         plan.page.is_some(),
         plan.delete_limit.is_some(),
+        plan.distinct,
+        plan.partition_limit.is_some(),
     );
-    validate_plan_shape_policy_rules(context)
+    validate_plan_shape_policy_rules(context)?;
+    if let Some(partition_limit) = plan.partition_limit.as_ref() {
+        validate_partition_limit_order_prefix(partition_limit, plan.order.as_ref())?;
+    }
+
+    Ok(())
 }
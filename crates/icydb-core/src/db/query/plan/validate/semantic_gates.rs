@@ -6,7 +6,9 @@
 use crate::db::{
     access::validate_access_runtime_invariants_with_schema,
     query::plan::{
-        AccessPlannedQuery, LogicalPlan, OrderSpec, ScalarPlan,
+        AccessPlannedQuery, FieldSlot, LogicalPlan, OrderSpec, OrderTerm, ScalarPlan,
+        expand_computed_group_key_refs,
+        expr::{Expr, Function, ProjectionField, ProjectionSpec, infer_expr_type},
         validate::{
             GroupPlanError, PlanError,
             grouped::{
@@ -71,23 +73,66 @@ pub(in crate::db::query) fn validate_group_query_semantics_with_schema(
         }
     };
     let projection = plan.projection_spec_with_schema(schema);
+    let group_fields = group.group_fields.as_slice();
 
+    // Computed grouped keys appear as label references in ORDER BY and the
+    // projection, so type inference runs over the expanded key expressions.
     validate_scalar_plan_semantic_gates(
         schema,
         logical,
         plan,
-        validate_order,
+        |schema, order| validate_order(schema, &expand_grouped_order(order, group_fields)),
         validate_accepted_access_structure_for_plan,
         false,
     )?;
     validate_group_structure(schema, group, &projection, having_expr)?;
     validate_group_policy(schema, logical, group, having_expr)?;
     validate_group_cursor_constraints(logical, group)?;
-    validate_projection_expr_types(schema, &projection)?;
+    validate_projection_expr_types(
+        schema,
+        &expand_grouped_projection(&projection, group_fields),
+    )?;
 
     Ok(())
 }
 
+// Expand computed grouped key labels inside ORDER BY terms back to their
+// source expressions for schema-owned order validation.
+fn expand_grouped_order(order: &OrderSpec, group_fields: &[FieldSlot]) -> OrderSpec {
+    OrderSpec {
+        fields: order
+            .fields
+            .iter()
+            .map(|term| {
+                OrderTerm::new(
+                    expand_computed_group_key_refs(term.expr().clone(), group_fields),
+                    term.direction(),
+                )
+                .with_nulls(term.nulls())
+            })
+            .collect(),
+    }
+}
+
+// Expand computed grouped key labels inside projection expressions back to
+// their source expressions for schema-owned type inference.
+fn expand_grouped_projection(
+    projection: &ProjectionSpec,
+    group_fields: &[FieldSlot],
+) -> ProjectionSpec {
+    ProjectionSpec::new(
+        projection
+            .fields()
+            .map(|field| match field {
+                ProjectionField::Scalar { expr, alias } => ProjectionField::Scalar {
+                    expr: expand_computed_group_key_refs(expr.clone(), group_fields),
+                    alias: alias.clone(),
+                },
+            })
+            .collect(),
+    )
+}
+
 // Shared scalar-plan semantic gates owned by planner validation.
 fn validate_scalar_plan_semantic_gates<FOrder, FAccess>(
     schema: &SchemaInfo,
@@ -107,6 +152,10 @@ where
         })?;
     }
 
+    if let Some(filter_expr) = &logical.filter_expr {
+        validate_filter_map_key_calls(schema, filter_expr)?;
+    }
+
     if let Some(order) = &logical.order {
         validate_order_fn(schema, order)?;
         validate_no_duplicate_non_pk_order_fields(schema, order)?;
@@ -120,3 +169,17 @@ where
 
     Ok(())
 }
+
+// Residual filter expressions skip full type inference, so map key checks type
+// their arguments here before a non-map input can reach row evaluation.
+fn validate_filter_map_key_calls(schema: &SchemaInfo, filter_expr: &Expr) -> Result<(), PlanError> {
+    filter_expr.try_for_each_tree_expr(&mut |expr| match expr {
+        Expr::FunctionCall {
+            function: Function::HasKey,
+            ..
+        } => infer_expr_type(expr, schema)
+            .map(|_| ())
+            .map_err(|error| error.attach_query_field(QueryFieldRole::Predicate)),
+        _ => Ok(()),
+    })
+}
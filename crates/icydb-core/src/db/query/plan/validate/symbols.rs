@@ -6,9 +6,11 @@
 
 use crate::{
     db::query::{
+        builder::scalar_projection::render_scalar_projection_expr_plan_label,
         intent::QueryError,
         plan::{
             FieldSlot,
+            expr::{Expr, ExprType, infer_expr_type},
             validate::{ExprPlanError, GroupPlanError, PlanError},
         },
     },
@@ -27,6 +29,39 @@ pub(in crate::db) fn resolve_group_field_slot_with_schema(
     })
 }
 
+/// Resolve one computed grouped key through schema expression authority.
+///
+/// Direct field expressions keep the accepted field-slot lane. Every other
+/// key must type-check as one scalar value and receives the caller-selected
+/// virtual slot.
+pub(in crate::db) fn resolve_group_expr_slot_with_schema(
+    schema: &SchemaInfo,
+    expr: Expr,
+    virtual_slot: usize,
+) -> Result<FieldSlot, PlanError> {
+    let expr = match expr {
+        Expr::Field(field) => {
+            return resolve_group_field_slot_with_schema(schema, field.as_str());
+        }
+        expr => expr,
+    };
+    let expr_type = infer_expr_type(&expr, schema)
+        .map_err(|error| error.attach_query_field(QueryFieldRole::GroupBy))?;
+    if matches!(expr_type, ExprType::Collection | ExprType::Structured) {
+        return Err(unknown_group_expr_error(&expr));
+    }
+
+    FieldSlot::computed_with_schema(schema, virtual_slot, expr.clone())
+        .ok_or_else(|| unknown_group_expr_error(&expr))
+}
+
+fn unknown_group_expr_error(expr: &Expr) -> PlanError {
+    PlanError::from(GroupPlanError::unknown_group_field(
+        render_scalar_projection_expr_plan_label(expr),
+    ))
+    .attach_query_field(QueryFieldRole::GroupBy)
+}
+
 /// Resolve one aggregate target field through schema slot authority.
 ///
 /// The physical slot, field label, and type metadata all come from the
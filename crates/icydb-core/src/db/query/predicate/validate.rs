@@ -13,6 +13,7 @@ use crate::{
             FieldType, SchemaInfo, SchemaLiteralValidationReason, SchemaValidationOperator,
             ValidateError, literal_matches_type,
         },
+        text_search::TextMatchQuery,
     },
     value::Value,
 };
@@ -70,6 +71,7 @@ pub(in crate::db) fn validate_predicate(
             value,
             SchemaValidationOperator::TextContainsCi,
         ),
+        Predicate::TextMatch { field, value } => validate_text_match(schema, field, value),
     }
 }
 
@@ -315,6 +317,24 @@ fn validate_text_contains(
     Ok(())
 }
 
+fn validate_text_match(
+    schema: &SchemaInfo,
+    field: &str,
+    value: &Value,
+) -> Result<(), ValidateError> {
+    validate_text_contains(schema, field, value, SchemaValidationOperator::TextMatch)?;
+    if let Value::Text(query) = value
+        && TextMatchQuery::parse(query).is_err()
+    {
+        return Err(ValidateError::invalid_literal(
+            field,
+            SchemaLiteralValidationReason::MalformedTextMatchQuery,
+        ));
+    }
+
+    Ok(())
+}
+
 fn ensure_field<'a>(schema: &'a SchemaInfo, field: &str) -> Result<&'a FieldType, ValidateError> {
     let field_type = schema
         .field(field)
@@ -65,19 +65,23 @@ pub(in crate::db) fn scalar_expr_value_into_value(value: ScalarExprValue<'_>) ->
 }
 
 /// Map one index expression shape to its shared scalar transform opcode.
+///
+/// Multi-entry token expressions derive many keys per value and have no
+/// scalar transform.
 #[must_use]
 pub(in crate::db) const fn scalar_index_expression_op(
     expression: PersistedIndexExpressionOp,
-) -> ScalarIndexExpressionOp {
+) -> Option<ScalarIndexExpressionOp> {
     match expression {
-        PersistedIndexExpressionOp::Lower => ScalarIndexExpressionOp::Lower,
-        PersistedIndexExpressionOp::Upper => ScalarIndexExpressionOp::Upper,
-        PersistedIndexExpressionOp::Trim => ScalarIndexExpressionOp::Trim,
-        PersistedIndexExpressionOp::LowerTrim => ScalarIndexExpressionOp::LowerTrim,
-        PersistedIndexExpressionOp::Date => ScalarIndexExpressionOp::Date,
-        PersistedIndexExpressionOp::Year => ScalarIndexExpressionOp::Year,
-        PersistedIndexExpressionOp::Month => ScalarIndexExpressionOp::Month,
-        PersistedIndexExpressionOp::Day => ScalarIndexExpressionOp::Day,
+        PersistedIndexExpressionOp::Lower => Some(ScalarIndexExpressionOp::Lower),
+        PersistedIndexExpressionOp::Upper => Some(ScalarIndexExpressionOp::Upper),
+        PersistedIndexExpressionOp::Trim => Some(ScalarIndexExpressionOp::Trim),
+        PersistedIndexExpressionOp::LowerTrim => Some(ScalarIndexExpressionOp::LowerTrim),
+        PersistedIndexExpressionOp::Date => Some(ScalarIndexExpressionOp::Date),
+        PersistedIndexExpressionOp::Year => Some(ScalarIndexExpressionOp::Year),
+        PersistedIndexExpressionOp::Month => Some(ScalarIndexExpressionOp::Month),
+        PersistedIndexExpressionOp::Day => Some(ScalarIndexExpressionOp::Day),
+        PersistedIndexExpressionOp::Tokens | PersistedIndexExpressionOp::Elements => None,
     }
 }
 
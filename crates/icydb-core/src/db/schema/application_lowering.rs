@@ -1767,10 +1767,12 @@ fn lower_existing_fields(
             PersistedFieldOrigin::Generated,
             storage_decode,
             leaf_codec,
-        );
+        )
+        .with_generated_expression(lower_generated_expression(proposed.insert_policy()));
         if candidate.kind() != accepted.kind()
             || candidate.nullable() != accepted.nullable()
             || candidate.write_policy() != accepted.write_policy()
+            || candidate.generated_expression() != accepted.generated_expression()
             || candidate.storage_decode() != accepted.storage_decode()
             || candidate.leaf_codec() != accepted.leaf_codec()
         {
@@ -1841,7 +1843,8 @@ pub(in crate::db::schema) fn lower_migration_field(
         PersistedFieldOrigin::Generated,
         storage_decode,
         leaf_codec,
-    ))
+    )
+    .with_generated_expression(lower_generated_expression(proposed.insert_policy())))
 }
 
 fn lower_existing_indexes(
@@ -1970,7 +1973,7 @@ pub(in crate::db::schema) fn lower_new_migration_index(
         revision,
         AcceptedSchemaFingerprint::new([1; 32]),
     );
-    let key = lower_index_key(proposed.key(), entity_tag, snapshot, bindings)?;
+    let key = lower_index_key(proposed, entity_tag, snapshot, bindings)?;
     let predicate_sql = proposed
         .predicate()
         .map(|predicate| {
@@ -1986,6 +1989,7 @@ pub(in crate::db::schema) fn lower_new_migration_index(
             render_accepted_check_expr_sql(&accepted, snapshot, &value_catalog)
         })
         .transpose()?;
+    let include = lower_index_include(proposed.include(), entity_tag, snapshot, bindings)?;
     Ok(PersistedIndexSnapshot::new(
         schema_id,
         ordinal,
@@ -1995,6 +1999,7 @@ pub(in crate::db::schema) fn lower_new_migration_index(
         key,
         predicate_sql,
     )
+    .with_include(include)
     .clone_with_schema_identity(schema_id, ordinal, physical_generation))
 }
 
@@ -2011,12 +2016,7 @@ impl ExistingIndexLowering<'_> {
             self.revision,
             AcceptedSchemaFingerprint::new([1; 32]),
         );
-        let key = lower_index_key(
-            proposed.key(),
-            self.entity_tag,
-            self.snapshot,
-            self.bindings,
-        )?;
+        let key = lower_index_key(proposed, self.entity_tag, self.snapshot, self.bindings)?;
         let predicate_sql = proposed
             .predicate()
             .map(|predicate| {
@@ -2032,6 +2032,12 @@ impl ExistingIndexLowering<'_> {
                 render_accepted_check_expr_sql(&accepted_expression, self.snapshot, &value_catalog)
             })
             .transpose()?;
+        let include = lower_index_include(
+            proposed.include(),
+            self.entity_tag,
+            self.snapshot,
+            self.bindings,
+        )?;
         Ok(PersistedIndexSnapshot::new(
             accepted.schema_id(),
             accepted.ordinal(),
@@ -2041,6 +2047,7 @@ impl ExistingIndexLowering<'_> {
             key,
             predicate_sql,
         )
+        .with_include(include)
         .clone_with_schema_identity(
             accepted.schema_id(),
             accepted.ordinal(),
@@ -2715,21 +2722,24 @@ fn lower_initial_entity_fields(
             storage_decode,
             leaf_codec,
         )?;
-        fields.push(PersistedFieldSnapshot::new_with_write_policy_and_origin(
-            id,
-            field.name().as_str().to_string(),
-            slot,
-            kind,
-            nested_leaves,
-            field.nullable(),
-            RowLayoutVersion::INITIAL,
-            insert_default,
-            SchemaHistoricalFill::Reject,
-            write_policy,
-            PersistedFieldOrigin::Generated,
-            storage_decode,
-            leaf_codec,
-        ));
+        fields.push(
+            PersistedFieldSnapshot::new_with_write_policy_and_origin(
+                id,
+                field.name().as_str().to_string(),
+                slot,
+                kind,
+                nested_leaves,
+                field.nullable(),
+                RowLayoutVersion::INITIAL,
+                insert_default,
+                SchemaHistoricalFill::Reject,
+                write_policy,
+                PersistedFieldOrigin::Generated,
+                storage_decode,
+                leaf_codec,
+            )
+            .with_generated_expression(lower_generated_expression(field.insert_policy())),
+        );
         layout.push((id, slot));
         bindings.insert((entity_tag, field.source_key().clone()), id);
     }
@@ -2851,7 +2861,7 @@ fn lower_initial_indexes(
                 .and_then(|value| value.checked_add(1))
                 .ok_or_else(InternalError::store_unsupported)?;
             let id = SchemaIndexId::new(raw_id).ok_or_else(InternalError::store_unsupported)?;
-            let key = lower_index_key(index.key(), entity_tag, snapshot, bindings)?;
+            let key = lower_index_key(index, entity_tag, snapshot, bindings)?;
             let predicate_sql = index
                 .predicate()
                 .map(|predicate| {
@@ -2867,6 +2877,7 @@ fn lower_initial_indexes(
                     render_accepted_check_expr_sql(&accepted, snapshot, &context.value_catalog)
                 })
                 .transpose()?;
+            let include = lower_index_include(index.include(), entity_tag, snapshot, bindings)?;
             accepted_bindings.insert((entity_tag, index.source_key().clone()), id);
             Ok(PersistedIndexSnapshot::new(
                 id,
@@ -2876,18 +2887,50 @@ fn lower_initial_indexes(
                 index.unique(),
                 key,
                 predicate_sql,
+            )
+            .with_include(include))
+        })
+        .collect()
+}
+
+// Lower generated included columns onto accepted top-level field paths in
+// declaration order; the entry payload stores them in exactly this order.
+fn lower_index_include(
+    include: &[icydb_schema::FieldSourceKey],
+    entity_tag: EntityTag,
+    snapshot: &PersistedSchemaSnapshot,
+    bindings: &AcceptedSourceBindingCatalog,
+) -> Result<Vec<PersistedIndexFieldPathSnapshot>, InternalError> {
+    include
+        .iter()
+        .map(|source| {
+            let field_id = bindings
+                .field(entity_tag, source)
+                .ok_or_else(InternalError::store_invariant)?;
+            let field = snapshot
+                .fields()
+                .iter()
+                .find(|field| field.id() == field_id)
+                .ok_or_else(InternalError::store_invariant)?;
+            Ok(PersistedIndexFieldPathSnapshot::new(
+                field.id(),
+                field.slot(),
+                vec![field.name().to_string()],
+                field.kind().clone(),
+                field.nullable(),
             ))
         })
         .collect()
 }
 
 fn lower_index_key(
-    key: &[IndexKeyFragment],
+    index: &icydb_schema::IndexFragment,
     entity_tag: EntityTag,
     snapshot: &PersistedSchemaSnapshot,
     bindings: &AcceptedSourceBindingCatalog,
 ) -> Result<PersistedIndexKeySnapshot, InternalError> {
-    let items = key
+    let items = index
+        .key()
         .iter()
         .map(|component| {
             let field_id = bindings
@@ -2905,8 +2948,10 @@ fn lower_index_key(
                 field.kind().clone(),
                 field.nullable(),
             );
-            if let IndexKeyFragment::Field(_) = component {
-                Ok::<_, InternalError>(PersistedIndexKeyItemSnapshot::FieldPath(source))
+            if let IndexKeyFragment::Field(field) = component {
+                Ok::<_, InternalError>(PersistedIndexKeyItemSnapshot::FieldPath(
+                    source.with_descending(index.descending().contains(field)),
+                ))
             } else {
                 let op =
                     index_expression_op(component).ok_or_else(InternalError::store_invariant)?;
@@ -2933,7 +2978,8 @@ fn lower_index_key(
                 .into_iter()
                 .filter_map(|item| match item {
                     PersistedIndexKeyItemSnapshot::FieldPath(path) => Some(path),
-                    PersistedIndexKeyItemSnapshot::Expression(_) => None,
+                    PersistedIndexKeyItemSnapshot::Expression(_)
+                    | PersistedIndexKeyItemSnapshot::Scalar(_) => None,
                 })
                 .collect(),
         ))
@@ -3015,6 +3061,18 @@ fn lower_initial_relations(
         .collect()
 }
 
+// The expression text stays unparsed here; the accepted write program parses
+// and binds it against the published snapshot before any row is admitted.
+fn lower_generated_expression(insert: &FieldInsertPolicy) -> Option<String> {
+    match insert {
+        FieldInsertPolicy::Computed(expression) => Some(expression.clone()),
+        FieldInsertPolicy::Required
+        | FieldInsertPolicy::Nullable
+        | FieldInsertPolicy::Default(_)
+        | FieldInsertPolicy::Generated => None,
+    }
+}
+
 fn lower_write_policy(
     insert: &FieldInsertPolicy,
     management: Option<FieldManagementPolicy>,
@@ -3040,6 +3098,7 @@ fn lower_write_policy(
             Some(FieldInsertGeneration::Timestamp)
         }
         FieldInsertPolicy::Generated => return Err(InternalError::store_unsupported()),
+        FieldInsertPolicy::Computed(_) => Some(FieldInsertGeneration::Computed),
         FieldInsertPolicy::Required
         | FieldInsertPolicy::Nullable
         | FieldInsertPolicy::Default(_) => None,
@@ -3152,6 +3211,8 @@ const fn index_expression_op(component: &IndexKeyFragment) -> Option<PersistedIn
         IndexKeyFragment::Year(_) => Some(PersistedIndexExpressionOp::Year),
         IndexKeyFragment::Month(_) => Some(PersistedIndexExpressionOp::Month),
         IndexKeyFragment::Day(_) => Some(PersistedIndexExpressionOp::Day),
+        IndexKeyFragment::Tokens(_) => Some(PersistedIndexExpressionOp::Tokens),
+        IndexKeyFragment::Elements(_) => Some(PersistedIndexExpressionOp::Elements),
         IndexKeyFragment::Field(_) => None,
     }
 }
@@ -3187,6 +3248,15 @@ fn index_expression_output_kind(
         {
             Some(AcceptedFieldKind::Int64)
         }
+        PersistedIndexExpressionOp::Tokens if matches!(source, AcceptedFieldKind::Text { .. }) => {
+            Some(AcceptedFieldKind::Text { max_len: None })
+        }
+        PersistedIndexExpressionOp::Elements => match source {
+            AcceptedFieldKind::List(item) | AcceptedFieldKind::Set(item) => {
+                Some(item.as_ref().clone())
+            }
+            _ => None,
+        },
         _ => None,
     }
 }
@@ -3201,6 +3271,8 @@ fn index_expression_text(op: PersistedIndexExpressionOp, field: &str) -> String
         PersistedIndexExpressionOp::Year => format!("expr:v1:YEAR({field})"),
         PersistedIndexExpressionOp::Month => format!("expr:v1:MONTH({field})"),
         PersistedIndexExpressionOp::Day => format!("expr:v1:DAY({field})"),
+        PersistedIndexExpressionOp::Tokens => format!("expr:v1:TOKENS({field})"),
+        PersistedIndexExpressionOp::Elements => format!("expr:v1:ELEMENTS({field})"),
     }
 }
 
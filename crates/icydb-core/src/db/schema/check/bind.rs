@@ -489,7 +489,8 @@ fn sql_check_expr_input(
         | SqlExpr::Param { .. }
         | SqlExpr::Like { .. }
         | SqlExpr::FunctionCall { .. }
-        | SqlExpr::Case { .. } => Err(AcceptedCheckExprV1Error::UnsupportedOperator),
+        | SqlExpr::Case { .. }
+        | SqlExpr::SemiJoin { .. } => Err(AcceptedCheckExprV1Error::UnsupportedOperator),
     }
 }
 
@@ -531,7 +532,8 @@ fn sql_check_value_input(
         | SqlExpr::Like { .. }
         | SqlExpr::Unary { .. }
         | SqlExpr::Binary { .. }
-        | SqlExpr::Case { .. } => Err(AcceptedCheckExprV1Error::UnsupportedOperator),
+        | SqlExpr::Case { .. }
+        | SqlExpr::SemiJoin { .. } => Err(AcceptedCheckExprV1Error::UnsupportedOperator),
     }
 }
 
@@ -574,6 +576,8 @@ const fn sql_check_compare_op(
         SqlExprBinaryOp::Gte => Ok(AcceptedCheckCompareOpV1::Gte),
         SqlExprBinaryOp::Or
         | SqlExprBinaryOp::And
+        | SqlExprBinaryOp::IsDistinctFrom
+        | SqlExprBinaryOp::IsNotDistinctFrom
         | SqlExprBinaryOp::Add
         | SqlExprBinaryOp::Sub
         | SqlExprBinaryOp::Mul
@@ -16,8 +16,9 @@ use crate::{
         predicate::{PredicateProgram, normalize, parse_sql_predicate},
         schema::{
             AcceptedCompositeCatalog, AcceptedConstraintKind, AcceptedEnumCatalog,
-            AcceptedFieldDecodeContract, AcceptedRowLayoutRuntimeContract, AcceptedSchemaSnapshot,
-            AcceptedValueCatalogHandle, ConstraintActivationKind, ConstraintId,
+            AcceptedFieldDecodeContract, AcceptedGeneratedFieldError,
+            AcceptedRowLayoutRuntimeContract, AcceptedSchemaSnapshot, AcceptedValueCatalogHandle,
+            CompiledAcceptedGeneratedFields, ConstraintActivationKind, ConstraintId,
         },
     },
     error::{AcceptedConstraintFactContext, InternalError, MutationDiagnosticContext},
@@ -68,6 +69,7 @@ pub(in crate::db) enum AcceptedRowConstraintEvaluationError {
     ValueNodeBudgetExceeded,
     OperationBudgetExceeded,
     PathBudgetExceeded,
+    GeneratedField(AcceptedGeneratedFieldError),
     TargetedRuleViolation {
         constraint_id: ConstraintId,
         path: AcceptedTargetPath,
@@ -180,6 +182,7 @@ pub(in crate::db) struct CompiledAcceptedRowConstraints {
     required_slots: Vec<usize>,
     unique_write_barriers: Vec<CompiledUniqueWriteBarrier>,
     targeted_rules: CompiledAcceptedTargetedRules,
+    generated_fields: CompiledAcceptedGeneratedFields,
     field_count: usize,
 }
 
@@ -237,6 +240,8 @@ impl CompiledAcceptedRowConstraints {
         compiled
             .unique_write_barriers
             .sort_unstable_by_key(|barrier| barrier.id);
+        compiled.generated_fields = CompiledAcceptedGeneratedFields::compile(schema, value_catalog)
+            .map_err(AcceptedRowConstraintEvaluationError::GeneratedField)?;
         Ok(compiled)
     }
 
@@ -345,6 +350,7 @@ impl CompiledAcceptedRowConstraints {
             required_slots,
             unique_write_barriers: Vec::new(),
             targeted_rules,
+            generated_fields: CompiledAcceptedGeneratedFields::empty(),
             field_count: snapshot.row_layout().allocated_slot_count(),
         })
     }
@@ -357,6 +363,12 @@ impl CompiledAcceptedRowConstraints {
             && self.targeted_rules.is_empty()
     }
 
+    /// Borrow the generated fields recomputed by every write of this schema.
+    #[must_use]
+    pub(in crate::db) const fn generated_fields(&self) -> &CompiledAcceptedGeneratedFields {
+        &self.generated_fields
+    }
+
     /// Borrow the sorted unique row slots read by this compiled program.
     #[must_use]
     pub(in crate::db) const fn required_slots(&self) -> &[usize] {
@@ -825,7 +837,8 @@ pub(in crate::db) fn accepted_row_constraint_write_error(
         | AcceptedRowConstraintEvaluationError::ValueDepthExceeded
         | AcceptedRowConstraintEvaluationError::ValueNodeBudgetExceeded
         | AcceptedRowConstraintEvaluationError::OperationBudgetExceeded
-        | AcceptedRowConstraintEvaluationError::PathBudgetExceeded => {
+        | AcceptedRowConstraintEvaluationError::PathBudgetExceeded
+        | AcceptedRowConstraintEvaluationError::GeneratedField(_) => {
             InternalError::accepted_row_constraint_program_corrupt()
         }
     }
@@ -0,0 +1,447 @@
+//! Closed exact checked-cast matrix shared by source migrations and SQL `CAST`.
+//!
+//! Every admitted conversion either preserves the exact source value in the
+//! target domain or fails with one typed representability reason. Callers own
+//! their own NULL policy: migrations reject NULL sources while SQL `CAST`
+//! propagates NULL before consulting the matrix.
+
+use crate::{
+    db::schema::AcceptedFieldKind,
+    types::{Decimal, Timestamp, Ulid},
+    value::Value,
+};
+
+/// One exact checked-cast failure reason.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(in crate::db) enum CheckedCastError {
+    NullSource,
+    Overflow,
+    NegativeToUnsigned,
+    PrecisionLoss,
+    ValueContract,
+}
+
+/// Return whether the checked-cast matrix admits one source/target kind pair.
+#[cfg(any(test, feature = "migration"))]
+#[must_use]
+pub(in crate::db) const fn supported_checked_cast(
+    source: &AcceptedFieldKind,
+    target: &AcceptedFieldKind,
+) -> bool {
+    match (source, target) {
+        (source, target)
+            if checked_cast_numeric_kind(source) && checked_cast_numeric_kind(target) =>
+        {
+            true
+        }
+        (AcceptedFieldKind::Text { .. }, target) if checked_cast_numeric_kind(target) => true,
+        (source, AcceptedFieldKind::Text { .. }) if checked_cast_numeric_kind(source) => true,
+        (AcceptedFieldKind::Timestamp, AcceptedFieldKind::Int64)
+        | (AcceptedFieldKind::Int64, AcceptedFieldKind::Timestamp)
+        | (AcceptedFieldKind::Ulid, AcceptedFieldKind::Text { .. })
+        | (AcceptedFieldKind::Text { .. }, AcceptedFieldKind::Ulid) => true,
+        _ => false,
+    }
+}
+
+/// Resolve one SQL `CAST` target type name onto its accepted field kind.
+///
+/// Type names follow the SQL DDL column vocabulary. `DECIMAL(scale)` carries
+/// the exact accepted scale because decimal fields have no separate precision.
+#[must_use]
+pub(in crate::db) fn resolve_checked_cast_target(type_name: &str) -> Option<AcceptedFieldKind> {
+    let normalized = type_name.trim().to_ascii_lowercase();
+    let kind = match normalized.as_str() {
+        "int8" => AcceptedFieldKind::Int8,
+        "int16" => AcceptedFieldKind::Int16,
+        "int32" => AcceptedFieldKind::Int32,
+        "int64" => AcceptedFieldKind::Int64,
+        "int128" => AcceptedFieldKind::Int128,
+        "nat8" => AcceptedFieldKind::Nat8,
+        "nat16" => AcceptedFieldKind::Nat16,
+        "nat32" => AcceptedFieldKind::Nat32,
+        "nat64" => AcceptedFieldKind::Nat64,
+        "nat128" => AcceptedFieldKind::Nat128,
+        "text" | "string" => AcceptedFieldKind::Text { max_len: None },
+        "timestamp" => AcceptedFieldKind::Timestamp,
+        "ulid" => AcceptedFieldKind::Ulid,
+        other => {
+            let scale = other
+                .strip_prefix("decimal(")?
+                .strip_suffix(')')?
+                .trim()
+                .parse::<u32>()
+                .ok()
+                .filter(|scale| *scale <= Decimal::max_supported_scale())?;
+
+            AcceptedFieldKind::Decimal { scale }
+        }
+    };
+
+    Some(kind)
+}
+
+/// Convert one runtime value into the exact target domain.
+pub(in crate::db) fn checked_cast_value(
+    source: &Value,
+    target: &AcceptedFieldKind,
+) -> Result<Value, CheckedCastError> {
+    if matches!(source, Value::Null) {
+        return Err(CheckedCastError::NullSource);
+    }
+    match target {
+        AcceptedFieldKind::Text { max_len } => {
+            return checked_cast_to_text(source, *max_len).map(Value::Text);
+        }
+        AcceptedFieldKind::Timestamp => {
+            return checked_cast_to_timestamp(source).map(Value::Timestamp);
+        }
+        AcceptedFieldKind::Ulid => return checked_cast_to_ulid(source).map(Value::Ulid),
+        _ => {}
+    }
+    let parsed;
+    let source = match source {
+        Value::Text(text) => {
+            parsed = parse_numeric_text(text)?;
+            &parsed
+        }
+        Value::Timestamp(timestamp) if matches!(target, AcceptedFieldKind::Int64) => {
+            return Ok(Value::Int64(timestamp.as_millis()));
+        }
+        source => source,
+    };
+    if let AcceptedFieldKind::Decimal { scale } = target {
+        return checked_cast_to_decimal(source, *scale).map(Value::Decimal);
+    }
+    if matches!(source, Value::Decimal(_)) {
+        return Err(CheckedCastError::PrecisionLoss);
+    }
+    match target {
+        AcceptedFieldKind::Int8 => signed_value(source, i8::MIN.into(), i8::MAX.into())
+            .and_then(|value| i64::try_from(value).map_err(|_| CheckedCastError::Overflow))
+            .map(Value::Int64),
+        AcceptedFieldKind::Int16 => signed_value(source, i16::MIN.into(), i16::MAX.into())
+            .and_then(|value| i64::try_from(value).map_err(|_| CheckedCastError::Overflow))
+            .map(Value::Int64),
+        AcceptedFieldKind::Int32 => signed_value(source, i32::MIN.into(), i32::MAX.into())
+            .and_then(|value| i64::try_from(value).map_err(|_| CheckedCastError::Overflow))
+            .map(Value::Int64),
+        AcceptedFieldKind::Int64 => signed_value(source, i64::MIN.into(), i64::MAX.into())
+            .and_then(|value| i64::try_from(value).map_err(|_| CheckedCastError::Overflow))
+            .map(Value::Int64),
+        AcceptedFieldKind::Int128 => signed_value(source, i128::MIN, i128::MAX).map(Value::Int128),
+        AcceptedFieldKind::Nat8 => unsigned_value(source, u8::MAX.into())
+            .and_then(|value| u64::try_from(value).map_err(|_| CheckedCastError::Overflow))
+            .map(Value::Nat64),
+        AcceptedFieldKind::Nat16 => unsigned_value(source, u16::MAX.into())
+            .and_then(|value| u64::try_from(value).map_err(|_| CheckedCastError::Overflow))
+            .map(Value::Nat64),
+        AcceptedFieldKind::Nat32 => unsigned_value(source, u32::MAX.into())
+            .and_then(|value| u64::try_from(value).map_err(|_| CheckedCastError::Overflow))
+            .map(Value::Nat64),
+        AcceptedFieldKind::Nat64 => unsigned_value(source, u64::MAX.into())
+            .and_then(|value| u64::try_from(value).map_err(|_| CheckedCastError::Overflow))
+            .map(Value::Nat64),
+        AcceptedFieldKind::Nat128 => unsigned_value(source, u128::MAX).map(Value::Nat128),
+        _ => Err(CheckedCastError::ValueContract),
+    }
+}
+
+#[cfg(any(test, feature = "migration"))]
+const fn checked_cast_numeric_kind(kind: &AcceptedFieldKind) -> bool {
+    matches!(
+        kind,
+        AcceptedFieldKind::Int8
+            | AcceptedFieldKind::Int16
+            | AcceptedFieldKind::Int32
+            | AcceptedFieldKind::Int64
+            | AcceptedFieldKind::Int128
+            | AcceptedFieldKind::Nat8
+            | AcceptedFieldKind::Nat16
+            | AcceptedFieldKind::Nat32
+            | AcceptedFieldKind::Nat64
+            | AcceptedFieldKind::Nat128
+            | AcceptedFieldKind::Decimal { .. }
+    )
+}
+
+// Parse numeric text into the narrowest exact runtime value so the numeric
+// matrix applies the same sign, overflow, and precision rules to text input.
+fn parse_numeric_text(text: &str) -> Result<Value, CheckedCastError> {
+    let text = text.trim();
+    if let Ok(value) = text.parse::<i128>() {
+        return Ok(Value::Int128(value));
+    }
+    if let Ok(value) = text.parse::<u128>() {
+        return Ok(Value::Nat128(value));
+    }
+    let digits = text
+        .strip_prefix('-')
+        .or_else(|| text.strip_prefix('+'))
+        .unwrap_or(text);
+    if !digits.is_empty() && digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(CheckedCastError::Overflow);
+    }
+
+    text.parse::<Decimal>()
+        .map(Value::Decimal)
+        .map_err(|_| CheckedCastError::ValueContract)
+}
+
+fn checked_cast_to_text(source: &Value, max_len: Option<u32>) -> Result<String, CheckedCastError> {
+    let text = match source {
+        Value::Int64(value) => value.to_string(),
+        Value::Int128(value) => value.to_string(),
+        Value::Nat64(value) => value.to_string(),
+        Value::Nat128(value) => value.to_string(),
+        Value::Decimal(value) => value.to_string(),
+        Value::Ulid(value) => value.to_string(),
+        Value::Text(value) => value.clone(),
+        _ => return Err(CheckedCastError::ValueContract),
+    };
+    if let Some(max_len) = max_len
+        && text.chars().count() > usize::try_from(max_len).unwrap_or(usize::MAX)
+    {
+        return Err(CheckedCastError::Overflow);
+    }
+
+    Ok(text)
+}
+
+fn checked_cast_to_timestamp(source: &Value) -> Result<Timestamp, CheckedCastError> {
+    match source {
+        Value::Timestamp(value) => Ok(*value),
+        Value::Decimal(_) => Err(CheckedCastError::PrecisionLoss),
+        source => {
+            let millis = i64::try_from(signed_value(source, i64::MIN.into(), i64::MAX.into())?)
+                .map_err(|_| CheckedCastError::Overflow)?;
+
+            Timestamp::try_from_i64(millis).ok_or(CheckedCastError::Overflow)
+        }
+    }
+}
+
+fn checked_cast_to_ulid(source: &Value) -> Result<Ulid, CheckedCastError> {
+    match source {
+        Value::Ulid(value) => Ok(*value),
+        Value::Text(text) => text
+            .parse::<Ulid>()
+            .map_err(|_| CheckedCastError::ValueContract),
+        _ => Err(CheckedCastError::ValueContract),
+    }
+}
+
+fn signed_value(source: &Value, minimum: i128, maximum: i128) -> Result<i128, CheckedCastError> {
+    let value = match source {
+        Value::Int64(value) => i128::from(*value),
+        Value::Int128(value) => *value,
+        Value::Nat64(value) => i128::from(*value),
+        Value::Nat128(value) => i128::try_from(*value).map_err(|_| CheckedCastError::Overflow)?,
+        _ => return Err(CheckedCastError::ValueContract),
+    };
+    if value < minimum || value > maximum {
+        return Err(CheckedCastError::Overflow);
+    }
+    Ok(value)
+}
+
+fn unsigned_value(source: &Value, maximum: u128) -> Result<u128, CheckedCastError> {
+    let value = match source {
+        Value::Int64(value) => {
+            u128::try_from(*value).map_err(|_| CheckedCastError::NegativeToUnsigned)?
+        }
+        Value::Int128(value) => {
+            u128::try_from(*value).map_err(|_| CheckedCastError::NegativeToUnsigned)?
+        }
+        Value::Nat64(value) => u128::from(*value),
+        Value::Nat128(value) => *value,
+        _ => return Err(CheckedCastError::ValueContract),
+    };
+    if value > maximum {
+        return Err(CheckedCastError::Overflow);
+    }
+    Ok(value)
+}
+
+fn checked_cast_to_decimal(source: &Value, target_scale: u32) -> Result<Decimal, CheckedCastError> {
+    let mantissa = match source {
+        Value::Int64(value) => scale_integer(i128::from(*value), target_scale)?,
+        Value::Int128(value) => scale_integer(*value, target_scale)?,
+        Value::Nat64(value) => scale_integer(i128::from(*value), target_scale)?,
+        Value::Nat128(value) => scale_integer(
+            i128::try_from(*value).map_err(|_| CheckedCastError::Overflow)?,
+            target_scale,
+        )?,
+        Value::Decimal(value) => rescale_decimal(*value, target_scale)?,
+        _ => return Err(CheckedCastError::ValueContract),
+    };
+    Decimal::try_from_i128_with_scale(mantissa, target_scale).ok_or(CheckedCastError::Overflow)
+}
+
+fn scale_integer(value: i128, scale: u32) -> Result<i128, CheckedCastError> {
+    value
+        .checked_mul(
+            10_i128
+                .checked_pow(scale)
+                .ok_or(CheckedCastError::Overflow)?,
+        )
+        .ok_or(CheckedCastError::Overflow)
+}
+
+fn rescale_decimal(value: Decimal, target_scale: u32) -> Result<i128, CheckedCastError> {
+    if value.scale() <= target_scale {
+        return value
+            .scale_to_integer(target_scale)
+            .ok_or(CheckedCastError::Overflow);
+    }
+    let divisor = 10_i128
+        .checked_pow(value.scale() - target_scale)
+        .ok_or(CheckedCastError::Overflow)?;
+    if value.mantissa() % divisor != 0 {
+        return Err(CheckedCastError::PrecisionLoss);
+    }
+    Ok(value.mantissa() / divisor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_cast_matrix_is_exact_for_sign_overflow_precision_and_null() {
+        assert_eq!(
+            checked_cast_value(&Value::Int64(-1), &AcceptedFieldKind::Nat8),
+            Err(CheckedCastError::NegativeToUnsigned),
+        );
+        assert_eq!(
+            checked_cast_value(&Value::Nat64(256), &AcceptedFieldKind::Nat8),
+            Err(CheckedCastError::Overflow),
+        );
+        assert_eq!(
+            checked_cast_value(
+                &Value::Decimal(Decimal::from_i128_with_scale(15, 1)),
+                &AcceptedFieldKind::Decimal { scale: 0 },
+            ),
+            Err(CheckedCastError::PrecisionLoss),
+        );
+        assert_eq!(
+            checked_cast_value(&Value::Null, &AcceptedFieldKind::Int64),
+            Err(CheckedCastError::NullSource),
+        );
+        assert_eq!(
+            checked_cast_value(&Value::Int64(7), &AcceptedFieldKind::Int128),
+            Ok(Value::Int128(7)),
+        );
+        assert_eq!(
+            checked_cast_value(
+                &Value::Decimal(Decimal::from_i128_with_scale(150, 2)),
+                &AcceptedFieldKind::Decimal { scale: 1 },
+            ),
+            Ok(Value::Decimal(Decimal::from_i128_with_scale(15, 1))),
+        );
+    }
+
+    #[test]
+    fn checked_cast_matrix_converts_text_timestamp_and_ulid_exactly() {
+        assert_eq!(
+            checked_cast_value(&Value::Text(" 42 ".to_string()), &AcceptedFieldKind::Nat16),
+            Ok(Value::Nat64(42)),
+        );
+        assert_eq!(
+            checked_cast_value(&Value::Text("-1".to_string()), &AcceptedFieldKind::Nat64),
+            Err(CheckedCastError::NegativeToUnsigned),
+        );
+        assert_eq!(
+            checked_cast_value(&Value::Text("7.5".to_string()), &AcceptedFieldKind::Int64),
+            Err(CheckedCastError::PrecisionLoss),
+        );
+        assert_eq!(
+            checked_cast_value(
+                &Value::Text("1.25".to_string()),
+                &AcceptedFieldKind::Decimal { scale: 2 },
+            ),
+            Ok(Value::Decimal(Decimal::from_i128_with_scale(125, 2))),
+        );
+        assert_eq!(
+            checked_cast_value(
+                &Value::Text("999999999999999999999999999999999999999999".to_string()),
+                &AcceptedFieldKind::Nat128,
+            ),
+            Err(CheckedCastError::Overflow),
+        );
+        assert_eq!(
+            checked_cast_value(&Value::Text("seven".to_string()), &AcceptedFieldKind::Int64),
+            Err(CheckedCastError::ValueContract),
+        );
+        assert_eq!(
+            checked_cast_value(
+                &Value::Int64(-9),
+                &AcceptedFieldKind::Text { max_len: None }
+            ),
+            Ok(Value::Text("-9".to_string())),
+        );
+        assert_eq!(
+            checked_cast_value(
+                &Value::Nat64(1234),
+                &AcceptedFieldKind::Text { max_len: Some(3) }
+            ),
+            Err(CheckedCastError::Overflow),
+        );
+        assert_eq!(
+            checked_cast_value(
+                &Value::Timestamp(Timestamp::from_millis(1_700)),
+                &AcceptedFieldKind::Int64,
+            ),
+            Ok(Value::Int64(1_700)),
+        );
+        assert_eq!(
+            checked_cast_value(&Value::Int64(1_700), &AcceptedFieldKind::Timestamp),
+            Ok(Value::Timestamp(Timestamp::from_millis(1_700))),
+        );
+
+        let ulid = Ulid::from_bytes([7; 16]);
+        assert_eq!(
+            checked_cast_value(
+                &Value::Ulid(ulid),
+                &AcceptedFieldKind::Text { max_len: None }
+            ),
+            Ok(Value::Text(ulid.to_string())),
+        );
+        assert_eq!(
+            checked_cast_value(&Value::Text(ulid.to_string()), &AcceptedFieldKind::Ulid,),
+            Ok(Value::Ulid(ulid)),
+        );
+        assert_eq!(
+            checked_cast_value(
+                &Value::Text("not-a-ulid".to_string()),
+                &AcceptedFieldKind::Ulid
+            ),
+            Err(CheckedCastError::ValueContract),
+        );
+    }
+
+    #[test]
+    fn unsupported_cast_domains_remain_closed() {
+        assert!(!supported_checked_cast(
+            &AcceptedFieldKind::Timestamp,
+            &AcceptedFieldKind::Nat64,
+        ));
+        assert!(!supported_checked_cast(
+            &AcceptedFieldKind::Float64,
+            &AcceptedFieldKind::Int64,
+        ));
+        assert!(!supported_checked_cast(
+            &AcceptedFieldKind::Ulid,
+            &AcceptedFieldKind::Int128,
+        ));
+        assert_eq!(
+            checked_cast_value(&Value::Bool(true), &AcceptedFieldKind::Int64),
+            Err(CheckedCastError::ValueContract),
+        );
+        assert_eq!(resolve_checked_cast_target("float64"), None);
+        assert_eq!(
+            resolve_checked_cast_target("DECIMAL(3)"),
+            Some(AcceptedFieldKind::Decimal { scale: 3 }),
+        );
+    }
+}
@@ -37,7 +37,7 @@ pub(super) fn encode_field(
     writer.push_u32(field.introduced_in_layout().get());
     encode_insert_default(writer, field.insert_default())?;
     encode_historical_fill(writer, field.historical_fill())?;
-    encode_write_policy(writer, field.write_policy());
+    encode_write_policy(writer, field.write_policy(), field.generated_expression())?;
     encode_field_origin(writer, field.origin());
     encode_storage_decode(writer, field.storage_decode());
     encode_leaf_codec(writer, field.leaf_codec());
@@ -59,7 +59,7 @@ pub(super) fn decode_field(
         RowLayoutVersion::new(reader.read_u32()?).ok_or_else(InternalError::store_corruption)?;
     let insert_default = decode_insert_default(reader)?;
     let historical_fill = decode_historical_fill(reader)?;
-    let write_policy = decode_write_policy(reader)?;
+    let (write_policy, generated_expression) = decode_write_policy(reader)?;
     let origin = decode_field_origin(reader)?;
     let storage_decode = decode_storage_decode(reader)?;
     let leaf_codec = decode_leaf_codec(reader)?;
@@ -77,7 +77,8 @@ pub(super) fn decode_field(
         origin,
         storage_decode,
         leaf_codec,
-    ))
+    )
+    .with_generated_expression(generated_expression))
 }
 
 fn encode_nested_leaf(
@@ -329,28 +330,50 @@ fn decode_historical_fill(
     }
 }
 
-fn encode_write_policy(writer: &mut SnapshotWriter, value: SchemaFieldWritePolicy) {
-    match value.insert_generation() {
-        None => writer.push_u8(0),
-        Some(FieldInsertGeneration::Identity) => writer.push_u8(1),
-        Some(FieldInsertGeneration::Ulid) => writer.push_u8(2),
-        Some(FieldInsertGeneration::Timestamp) => writer.push_u8(3),
+// Computed fields carry their expression inline after the generation tag, so
+// one field's write policy and generated expression can never drift apart.
+fn encode_write_policy(
+    writer: &mut SnapshotWriter,
+    value: SchemaFieldWritePolicy,
+    generated_expression: Option<&str>,
+) -> Result<(), InternalError> {
+    match (value.insert_generation(), generated_expression) {
+        (None, None) => writer.push_u8(0),
+        (Some(FieldInsertGeneration::Identity), None) => writer.push_u8(1),
+        (Some(FieldInsertGeneration::Ulid), None) => writer.push_u8(2),
+        (Some(FieldInsertGeneration::Timestamp), None) => writer.push_u8(3),
+        (Some(FieldInsertGeneration::Computed), Some(expression)) => {
+            writer.push_u8(4);
+            writer.push_bounded_string(
+                expression,
+                icydb_schema::MAX_GENERATED_FIELD_EXPRESSION_BYTES,
+            )?;
+        }
+        _ => return Err(InternalError::store_unsupported()),
     }
     match value.write_management() {
         None => writer.push_u8(0),
         Some(FieldWriteManagement::CreatedAt) => writer.push_u8(1),
         Some(FieldWriteManagement::UpdatedAt) => writer.push_u8(2),
     }
+    Ok(())
 }
 
 fn decode_write_policy(
     reader: &mut SnapshotReader<'_>,
-) -> Result<SchemaFieldWritePolicy, InternalError> {
+) -> Result<(SchemaFieldWritePolicy, Option<String>), InternalError> {
+    let mut generated_expression = None;
     let insert_generation = match reader.read_u8()? {
         0 => None,
         1 => Some(FieldInsertGeneration::Identity),
         2 => Some(FieldInsertGeneration::Ulid),
         3 => Some(FieldInsertGeneration::Timestamp),
+        4 => {
+            generated_expression = Some(
+                reader.read_bounded_string(icydb_schema::MAX_GENERATED_FIELD_EXPRESSION_BYTES)?,
+            );
+            Some(FieldInsertGeneration::Computed)
+        }
         _ => return Err(InternalError::store_corruption()),
     };
     let write_management = match reader.read_u8()? {
@@ -359,9 +382,9 @@ fn decode_write_policy(
         2 => Some(FieldWriteManagement::UpdatedAt),
         _ => return Err(InternalError::store_corruption()),
     };
-    Ok(SchemaFieldWritePolicy::from_model_policies(
-        insert_generation,
-        write_management,
+    Ok((
+        SchemaFieldWritePolicy::from_model_policies(insert_generation, write_management),
+        generated_expression,
     ))
 }
 
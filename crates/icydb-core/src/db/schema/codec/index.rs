@@ -10,12 +10,19 @@ use crate::{
         FieldId, MAX_ACCEPTED_RECURSIVE_DEPTH, PersistedIndexExpressionOp,
         PersistedIndexExpressionSnapshot, PersistedIndexFieldPathSnapshot,
         PersistedIndexKeyItemSnapshot, PersistedIndexKeySnapshot, PersistedIndexOrigin,
-        PersistedIndexSnapshot, PersistedRelationEdgeSnapshot, RelationId, SchemaFieldSlot,
-        SchemaIndexId, enum_catalog::MAX_SCHEMA_STORE_PATH_BYTES,
+        PersistedIndexScalarExpressionSnapshot, PersistedIndexSnapshot,
+        PersistedRelationEdgeSnapshot, RelationId, SchemaFieldSlot, SchemaIndexId,
+        enum_catalog::MAX_SCHEMA_STORE_PATH_BYTES,
     },
     error::InternalError,
 };
 
+// Index trailer flags. Bit 0 keeps the original predicate marker byte, so
+// indexes without included columns or descending keys encode exactly as before.
+const INDEX_TRAILER_PREDICATE: u8 = 0b001;
+const INDEX_TRAILER_INCLUDE: u8 = 0b010;
+const INDEX_TRAILER_DESCENDING: u8 = 0b100;
+
 pub(super) fn encode_index(
     writer: &mut SnapshotWriter,
     index: &PersistedIndexSnapshot,
@@ -28,12 +35,42 @@ pub(super) fn encode_index(
     writer.push_bool(index.unique());
     encode_index_origin(writer, index.origin());
     encode_index_key(writer, index.key())?;
-    match index.predicate_sql() {
-        None => writer.push_u8(0),
-        Some(predicate) => {
-            writer.push_u8(1);
-            writer.push_bounded_string(predicate, MAX_SQL_TEXT_BYTES)?;
-        }
+    let mut trailer = 0;
+    if index.predicate_sql().is_some() {
+        trailer |= INDEX_TRAILER_PREDICATE;
+    }
+    if !index.include().is_empty() {
+        trailer |= INDEX_TRAILER_INCLUDE;
+    }
+    let descending = index.key().descending_positions();
+    if !descending.is_empty() {
+        trailer |= INDEX_TRAILER_DESCENDING;
+    }
+    writer.push_u8(trailer);
+    if let Some(predicate) = index.predicate_sql() {
+        writer.push_bounded_string(predicate, MAX_SQL_TEXT_BYTES)?;
+    }
+    if !index.include().is_empty() {
+        encode_sequence!(
+            writer,
+            index.include(),
+            icydb_schema::MAX_FRAGMENT_FIELDS,
+            |path| {
+                encode_field_path(writer, path)?;
+            }
+        );
+    }
+    if !descending.is_empty() {
+        encode_sequence!(
+            writer,
+            descending.as_slice(),
+            icydb_schema::MAX_FRAGMENT_FIELDS,
+            |position| {
+                writer.push_u16(
+                    u16::try_from(*position).map_err(|_| InternalError::store_corruption())?,
+                );
+            }
+        );
     }
     Ok(())
 }
@@ -50,10 +87,40 @@ pub(super) fn decode_index(
     let unique = reader.read_bool()?;
     let origin = decode_index_origin(reader)?;
     let key = decode_index_key(reader)?;
-    let predicate_sql = match reader.read_u8()? {
-        0 => None,
-        1 => Some(reader.read_bounded_string(MAX_SQL_TEXT_BYTES)?),
-        _ => return Err(InternalError::store_corruption()),
+    let trailer = reader.read_u8()?;
+    if trailer & !(INDEX_TRAILER_PREDICATE | INDEX_TRAILER_INCLUDE | INDEX_TRAILER_DESCENDING) != 0
+    {
+        return Err(InternalError::store_corruption());
+    }
+    let predicate_sql = if trailer & INDEX_TRAILER_PREDICATE == 0 {
+        None
+    } else {
+        Some(reader.read_bounded_string(MAX_SQL_TEXT_BYTES)?)
+    };
+    let include = if trailer & INDEX_TRAILER_INCLUDE == 0 {
+        Vec::new()
+    } else {
+        let include = decode_sequence!(
+            reader,
+            icydb_schema::MAX_FRAGMENT_FIELDS,
+            decode_field_path(reader)?
+        );
+        if include.is_empty() {
+            return Err(InternalError::store_corruption());
+        }
+        include
+    };
+    let key = if trailer & INDEX_TRAILER_DESCENDING == 0 {
+        key
+    } else {
+        let descending = decode_sequence!(reader, icydb_schema::MAX_FRAGMENT_FIELDS, {
+            usize::from(reader.read_u16()?)
+        });
+        if descending.is_empty() || descending.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(InternalError::store_corruption());
+        }
+        key.with_descending_positions(&descending)
+            .ok_or_else(InternalError::store_corruption)?
     };
     let index = match origin {
         PersistedIndexOrigin::Generated => {
@@ -69,7 +136,11 @@ pub(super) fn decode_index(
             predicate_sql,
         ),
     };
-    Ok(index.clone_with_schema_identity(schema_id, ordinal, physical_generation))
+    Ok(index.with_include(include).clone_with_schema_identity(
+        schema_id,
+        ordinal,
+        physical_generation,
+    ))
 }
 
 pub(super) fn encode_relation(
@@ -159,6 +230,10 @@ fn encode_index_item(
             writer.push_u8(2);
             encode_index_expression(writer, expression)?;
         }
+        PersistedIndexKeyItemSnapshot::Scalar(expression) => {
+            writer.push_u8(3);
+            encode_index_scalar_expression(writer, expression)?;
+        }
     }
     Ok(())
 }
@@ -171,6 +246,9 @@ fn decode_index_item(
         2 => decode_index_expression(reader)
             .map(Box::new)
             .map(PersistedIndexKeyItemSnapshot::Expression),
+        3 => decode_index_scalar_expression(reader)
+            .map(Box::new)
+            .map(PersistedIndexKeyItemSnapshot::Scalar),
         _ => Err(InternalError::store_corruption()),
     }
 }
@@ -233,6 +311,37 @@ fn decode_index_expression(
     ))
 }
 
+fn encode_index_scalar_expression(
+    writer: &mut SnapshotWriter,
+    expression: &PersistedIndexScalarExpressionSnapshot,
+) -> Result<(), InternalError> {
+    encode_sequence!(
+        writer,
+        expression.sources(),
+        icydb_schema::MAX_FRAGMENT_FIELDS,
+        |source| {
+            encode_field_path(writer, source)?;
+        }
+    );
+    writer.push_bounded_string(expression.expression_sql(), MAX_SQL_TEXT_BYTES)?;
+    writer.push_bounded_string(expression.canonical_text(), MAX_SQL_TEXT_BYTES)?;
+    Ok(())
+}
+
+fn decode_index_scalar_expression(
+    reader: &mut SnapshotReader<'_>,
+) -> Result<PersistedIndexScalarExpressionSnapshot, InternalError> {
+    Ok(PersistedIndexScalarExpressionSnapshot::new(
+        decode_sequence!(
+            reader,
+            icydb_schema::MAX_FRAGMENT_FIELDS,
+            decode_field_path(reader)?
+        ),
+        reader.read_bounded_string(MAX_SQL_TEXT_BYTES)?,
+        reader.read_bounded_string(MAX_SQL_TEXT_BYTES)?,
+    ))
+}
+
 direct_unit_enum_codec! {
     encode = encode_index_origin,
     decode = decode_index_origin,
@@ -258,5 +367,7 @@ direct_unit_enum_codec! {
         6 => PersistedIndexExpressionOp::Year,
         7 => PersistedIndexExpressionOp::Month,
         8 => PersistedIndexExpressionOp::Day,
+        9 => PersistedIndexExpressionOp::Tokens,
+        10 => PersistedIndexExpressionOp::Elements,
     }
 }
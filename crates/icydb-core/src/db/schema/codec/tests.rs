@@ -10,9 +10,10 @@ use crate::{
         LeafCodec, MAX_ACCEPTED_RECURSIVE_DEPTH, MAX_SCHEMA_SNAPSHOT_BYTES, PersistedFieldOrigin,
         PersistedFieldSnapshot, PersistedIndexExpressionOp, PersistedIndexExpressionSnapshot,
         PersistedIndexFieldPathSnapshot, PersistedIndexKeyItemSnapshot, PersistedIndexKeySnapshot,
-        PersistedIndexSnapshot, PersistedRelationEdgeSnapshot, PersistedSchemaSnapshot, RelationId,
-        RowLayoutVersion, ScalarCodec, SchemaFieldSlot, SchemaFieldWritePolicy,
-        SchemaHistoricalFill, SchemaIndexId, SchemaInsertDefault, SchemaRowLayout, SchemaVersion,
+        PersistedIndexScalarExpressionSnapshot, PersistedIndexSnapshot,
+        PersistedRelationEdgeSnapshot, PersistedSchemaSnapshot, RelationId, RowLayoutVersion,
+        ScalarCodec, SchemaFieldSlot, SchemaFieldWritePolicy, SchemaHistoricalFill, SchemaIndexId,
+        SchemaInsertDefault, SchemaRowLayout, SchemaVersion,
         accepted_schema_cache_fingerprint_for_persisted_snapshot,
         composite_catalog::CompositeTypeId, decode_persisted_schema_snapshot,
         encode_persisted_schema_snapshot,
@@ -882,6 +883,60 @@ fn persisted_schema_snapshot_round_trips_field_write_policy() {
     );
 }
 
+#[test]
+fn persisted_schema_snapshot_round_trips_generated_expression() {
+    let snapshot = PersistedSchemaSnapshot::new(
+        SchemaVersion::initial(),
+        "entities::Line".to_string(),
+        "Line".to_string(),
+        FieldId::new(1),
+        SchemaRowLayout::initial(vec![
+            (FieldId::new(1), SchemaFieldSlot::new(0)),
+            (FieldId::new(2), SchemaFieldSlot::new(1)),
+        ]),
+        vec![
+            PersistedFieldSnapshot::new_initial(
+                FieldId::new(1),
+                "id".to_string(),
+                SchemaFieldSlot::new(0),
+                AcceptedFieldKind::Int64,
+                Vec::new(),
+                false,
+                SchemaInsertDefault::None,
+                FieldStorageDecode::ByKind,
+                LeafCodec::Scalar(ScalarCodec::Int64),
+            ),
+            PersistedFieldSnapshot::new_initial_with_write_policy(
+                FieldId::new(2),
+                "doubled".to_string(),
+                SchemaFieldSlot::new(1),
+                AcceptedFieldKind::Int64,
+                Vec::new(),
+                true,
+                SchemaInsertDefault::None,
+                SchemaFieldWritePolicy::from_model_policies(
+                    Some(FieldInsertGeneration::Computed),
+                    None,
+                ),
+                FieldStorageDecode::ByKind,
+                LeafCodec::Scalar(ScalarCodec::Int64),
+            )
+            .with_generated_expression(Some("id * 2".to_string())),
+        ],
+    );
+    let encoded =
+        encode_persisted_schema_snapshot(&snapshot).expect("generated schema should encode");
+    let decoded =
+        decode_persisted_schema_snapshot(&encoded).expect("generated schema should decode");
+
+    assert_eq!(
+        decoded.fields()[1].write_policy().insert_generation(),
+        Some(FieldInsertGeneration::Computed),
+    );
+    assert_eq!(decoded.fields()[1].generated_expression(), Some("id * 2"));
+    assert_eq!(decoded.fields()[0].generated_expression(), None);
+}
+
 #[test]
 fn persisted_schema_snapshot_round_trips_identity_generation() {
     let snapshot = PersistedSchemaSnapshot::new(
@@ -1481,3 +1536,80 @@ fn persisted_schema_snapshot_round_trips_expression_indexes() {
     assert_eq!(expression.source().field_id(), FieldId::new(2));
     assert_eq!(expression.canonical_text(), "expr:v1:LOWER(email)");
 }
+
+#[test]
+fn persisted_schema_snapshot_round_trips_scalar_expression_indexes() {
+    let source = PersistedIndexFieldPathSnapshot::new(
+        FieldId::new(2),
+        SchemaFieldSlot::new(1),
+        vec!["email".to_string()],
+        AcceptedFieldKind::Text { max_len: None },
+        false,
+    );
+    let snapshot = PersistedSchemaSnapshot::new_with_indexes(
+        SchemaVersion::initial(),
+        "entities::ExpressionIndexed".to_string(),
+        "ExpressionIndexed".to_string(),
+        FieldId::new(1),
+        SchemaRowLayout::initial(vec![
+            (FieldId::new(1), SchemaFieldSlot::new(0)),
+            (FieldId::new(2), SchemaFieldSlot::new(1)),
+        ]),
+        vec![
+            PersistedFieldSnapshot::new_initial_with_write_policy(
+                FieldId::new(1),
+                "id".to_string(),
+                SchemaFieldSlot::new(0),
+                AcceptedFieldKind::Ulid,
+                Vec::new(),
+                false,
+                SchemaInsertDefault::None,
+                SchemaFieldWritePolicy::none(),
+                FieldStorageDecode::ByKind,
+                LeafCodec::Scalar(ScalarCodec::Ulid),
+            ),
+            PersistedFieldSnapshot::new_initial_with_write_policy(
+                FieldId::new(2),
+                "email".to_string(),
+                SchemaFieldSlot::new(1),
+                AcceptedFieldKind::Text { max_len: None },
+                Vec::new(),
+                false,
+                SchemaInsertDefault::None,
+                SchemaFieldWritePolicy::none(),
+                FieldStorageDecode::ByKind,
+                LeafCodec::Scalar(ScalarCodec::Text),
+            ),
+        ],
+        vec![PersistedIndexSnapshot::new(
+            SchemaIndexId::new(1).expect("test index identity should be non-zero"),
+            1,
+            "idx_expression_indexed__email_initial".to_string(),
+            "expression_indexed::email_initial".to_string(),
+            false,
+            PersistedIndexKeySnapshot::Items(vec![PersistedIndexKeyItemSnapshot::Scalar(
+                Box::new(PersistedIndexScalarExpressionSnapshot::new(
+                    vec![source],
+                    "LEFT(email, 1)".to_string(),
+                    "expr:v1:LEFT(email, 1)".to_string(),
+                )),
+            )]),
+            None,
+        )],
+    );
+    let encoded = encode_persisted_schema_snapshot(&snapshot)
+        .expect("schema snapshot should encode accepted scalar expression index contracts");
+
+    let decoded = decode_persisted_schema_snapshot(&encoded)
+        .expect("schema snapshot should decode accepted scalar expression index contracts");
+
+    let PersistedIndexKeySnapshot::Items(items) = decoded.indexes()[0].key() else {
+        panic!("scalar expression index should decode as explicit accepted key items");
+    };
+    let PersistedIndexKeyItemSnapshot::Scalar(expression) = &items[0] else {
+        panic!("scalar key item should decode as an accepted scalar expression");
+    };
+    assert_eq!(expression.sources()[0].field_id(), FieldId::new(2));
+    assert_eq!(expression.expression_sql(), "LEFT(email, 1)");
+    assert_eq!(expression.canonical_text(), "expr:v1:LEFT(email, 1)");
+}
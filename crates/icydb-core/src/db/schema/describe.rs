@@ -21,6 +21,7 @@ use crate::{
             query_field_kind_from_persisted_kind, render_accepted_check_expr_sql,
             runtime::AcceptedRowLayoutRuntimeField,
         },
+        sql_shared::{render_sql_identifier, render_sql_identifier_path},
     },
     error::InternalError,
     value::{OutputValue, render_output_value_text},
@@ -127,7 +128,7 @@ impl SqlColumnSummary {
         })
     }
 
-    /// Borrow the canonical accepted query path.
+    /// Borrow the canonical accepted query path in its SQL spelling.
     #[must_use]
     pub const fn name(&self) -> &str {
         self.name.as_str()
@@ -788,7 +789,7 @@ impl EntityFieldDescription {
         }
     }
 
-    /// Borrow the field name.
+    /// Borrow the field display name in its SQL spelling.
     #[must_use]
     pub const fn name(&self) -> &str {
         self.name.as_str()
@@ -1459,6 +1460,9 @@ fn describe_persisted_index_fields(key: &PersistedIndexKeySnapshot) -> Vec<Strin
                 PersistedIndexKeyItemSnapshot::Expression(expression) => {
                     expression.canonical_text().to_string()
                 }
+                PersistedIndexKeyItemSnapshot::Scalar(expression) => {
+                    expression.canonical_text().to_string()
+                }
             })
             .collect(),
     }
@@ -1505,7 +1509,7 @@ pub(in crate::db) fn describe_compact_columns_with_persisted_schema(
         );
 
         columns.push(SqlColumnSummary::new(
-            field.name().to_string(),
+            render_sql_identifier(field.name()),
             summarize_persisted_field_kind(field.kind(), value_catalog)?,
             field.nullable(),
             compact_column_key(snapshot, field.name()),
@@ -1521,7 +1525,7 @@ pub(in crate::db) fn describe_compact_columns_with_persisted_schema(
             canonical_path.extend(leaf.path().iter().map(String::as_str));
             let canonical_name = canonical_path.join(".");
             columns.push(SqlColumnSummary::new(
-                canonical_name.clone(),
+                render_sql_identifier_path(canonical_path.iter().copied()),
                 summarize_persisted_field_kind(leaf.kind(), value_catalog)?,
                 nested_path_nullable(field.nullable(), field.nested_leaves(), leaf.path()),
                 compact_column_key(snapshot, canonical_name.as_str()),
@@ -1785,10 +1789,13 @@ fn push_described_field_row(
 ) {
     // Nested field rows keep a compact tree marker so table-oriented describe
     // output scans as a hierarchy without assigning nested leaves row slots.
+    // Names are shown in their SQL spelling, quoted when a bare identifier
+    // would not read back as the same name.
+    let name = render_sql_identifier(name);
     let display_name = if let Some(prefix) = tree_prefix {
         format!("{prefix}{name}")
     } else {
-        name.to_string()
+        name
     };
 
     fields.push(EntityFieldDescription::new_with_temporal_facts(
@@ -2570,7 +2577,7 @@ mod tests {
         };
         let encoded_output =
             Encode!(&output).expect("reachable accepted compact output should encode");
-        assert_eq!(encoded_output.len(), 3_693_116);
+        assert_eq!(encoded_output.len(), 3_693_876);
         assert!(
             encoded_output.len() > IC_QUERY_REPLY_BYTES,
             "a valid accepted schema must exercise the generated endpoint reply guard",
@@ -20,12 +20,13 @@ use crate::{
         schema::{
             AcceptedFieldDecodeContract, AcceptedFieldKind, AcceptedSchemaSnapshot,
             AcceptedSourceBindingCatalog, AcceptedTypedAdapterNames, MAX_ACCEPTED_RECURSIVE_DEPTH,
-            MAX_SCHEMA_SNAPSHOT_BYTES, PersistedFieldSnapshot, PersistedIndexFieldPathSnapshot,
-            PersistedIndexKeyItemSnapshot, PersistedIndexKeySnapshot, PersistedSchemaSnapshot,
-            SchemaSnapshotAcceptanceError, classify_accepted_field_kind,
-            decode_accepted_source_bindings, decode_persisted_schema_snapshot,
-            encode_accepted_source_bindings, encode_persisted_schema_snapshot,
-            validate_accepted_targeted_rules, validate_schema_snapshot_acceptance,
+            MAX_SCHEMA_SNAPSHOT_BYTES, PersistedFieldSnapshot, PersistedIndexExpressionOp,
+            PersistedIndexFieldPathSnapshot, PersistedIndexKeyItemSnapshot,
+            PersistedIndexKeySnapshot, PersistedSchemaSnapshot, SchemaSnapshotAcceptanceError,
+            classify_accepted_field_kind, decode_accepted_source_bindings,
+            decode_persisted_schema_snapshot, encode_accepted_source_bindings,
+            encode_persisted_schema_snapshot, validate_accepted_targeted_rules,
+            validate_schema_snapshot_acceptance,
             wire::{SchemaWireReader, SchemaWireWriter},
         },
     },
@@ -555,9 +556,24 @@ fn validate_index_capabilities(
                         PersistedIndexKeyItemSnapshot::FieldPath(path) => {
                             validate_index_path(catalog, path)?;
                         }
+                        PersistedIndexKeyItemSnapshot::Expression(expression)
+                            if expression.op() == PersistedIndexExpressionOp::Elements =>
+                        {
+                            // Element indexes key each collection member, so
+                            // the element kind carries the key capability.
+                            if !classify_accepted_field_kind(expression.source().kind())
+                                .is_collection()
+                            {
+                                return Err(InternalError::store_unsupported());
+                            }
+                            validate_index_kind(catalog, expression.output_kind())?;
+                        }
                         PersistedIndexKeyItemSnapshot::Expression(expression) => {
                             validate_index_path(catalog, expression.source())?;
                         }
+                        // Scalar expression sources are only read; the key
+                        // is the expression result, never a source value.
+                        PersistedIndexKeyItemSnapshot::Scalar(_) => {}
                     }
                 }
             }
@@ -571,11 +587,18 @@ fn validate_index_path(
     catalog: &AcceptedEnumCatalog,
     path: &PersistedIndexFieldPathSnapshot,
 ) -> Result<(), InternalError> {
-    let semantics = classify_accepted_field_kind(path.kind());
+    validate_index_kind(catalog, path.kind())
+}
+
+fn validate_index_kind(
+    catalog: &AcceptedEnumCatalog,
+    kind: &AcceptedFieldKind,
+) -> Result<(), InternalError> {
+    let semantics = classify_accepted_field_kind(kind);
     if semantics.is_collection() || semantics.is_composite() {
         return Err(InternalError::store_unsupported());
     }
-    let AcceptedFieldKind::Enum { type_id } = path.kind() else {
+    let AcceptedFieldKind::Enum { type_id } = kind else {
         return Ok(());
     };
     let capability = enum_equality_capability(catalog, *type_id)
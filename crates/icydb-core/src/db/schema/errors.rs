@@ -15,6 +15,7 @@ pub enum SchemaValidationOperator {
     IsNotEmpty,
     TextContains,
     TextContainsCi,
+    TextMatch,
 }
 
 impl SchemaValidationOperator {
@@ -41,6 +42,7 @@ impl fmt::Display for SchemaValidationOperator {
             Self::IsNotEmpty => f.write_str("is_not_empty"),
             Self::TextContains => f.write_str("text_contains"),
             Self::TextContainsCi => f.write_str("text_contains_ci"),
+            Self::TextMatch => f.write_str("text_match"),
         }
     }
 }
@@ -56,6 +58,7 @@ pub enum SchemaLiteralValidationReason {
     EnumPathMismatch,
     UnknownEnumVariant,
     EnumBodyMismatch,
+    MalformedTextMatchQuery,
 }
 
 impl fmt::Display for SchemaLiteralValidationReason {
@@ -73,6 +76,9 @@ impl fmt::Display for SchemaLiteralValidationReason {
             Self::EnumBodyMismatch => {
                 f.write_str("enum payload does not match the accepted variant contract")
             }
+            Self::MalformedTextMatchQuery => {
+                f.write_str("MATCH query is empty, malformed, or exceeds its term bound")
+            }
         }
     }
 }
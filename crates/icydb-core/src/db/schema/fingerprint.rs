@@ -223,7 +223,9 @@ fn index_with_identity_and_name(
             index.predicate_sql().map(str::to_string),
         ),
     };
-    renamed.clone_with_schema_identity(schema_id, index.ordinal(), index.physical_generation())
+    renamed
+        .with_include(index.include().to_vec())
+        .clone_with_schema_identity(schema_id, index.ordinal(), index.physical_generation())
 }
 
 fn hash_labeled_str(hasher: &mut Sha256, label: &str, value: &str) {
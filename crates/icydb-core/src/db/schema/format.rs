@@ -31,6 +31,7 @@ pub(in crate::db) fn show_indexes_for_schema_info_with_runtime_state(
             "PRIMARY KEY",
             None,
             &primary_key_fields,
+            &[],
             None,
             runtime_state,
             Some("generated"),
@@ -41,9 +42,22 @@ pub(in crate::db) fn show_indexes_for_schema_info_with_runtime_state(
         let fields: Vec<String> = index
             .fields()
             .iter()
-            .map(|field| field.path().join("."))
+            .map(|field| {
+                let path = field.path().join(".");
+                if field.descending() {
+                    format!("{path} DESC")
+                } else {
+                    path
+                }
+            })
             .collect();
         let field_refs: Vec<&str> = fields.iter().map(String::as_str).collect();
+        let include: Vec<String> = index
+            .include()
+            .iter()
+            .map(|field| field.path().join("."))
+            .collect();
+        let include_refs: Vec<&str> = include.iter().map(String::as_str).collect();
         indexes.push(render_index_listing_line(
             if index.unique() {
                 "UNIQUE INDEX"
@@ -52,6 +66,7 @@ pub(in crate::db) fn show_indexes_for_schema_info_with_runtime_state(
             },
             Some(index.name()),
             &field_refs,
+            &include_refs,
             index.predicate_sql(),
             runtime_state,
             index_origin(snapshot.indexes(), index.ordinal()),
@@ -67,6 +82,9 @@ pub(in crate::db) fn show_indexes_for_schema_info_with_runtime_state(
                 SchemaExpressionIndexKeyItemInfo::Expression(expression) => {
                     expression.canonical_text().to_string()
                 }
+                SchemaExpressionIndexKeyItemInfo::Scalar(scalar) => {
+                    scalar.canonical_text().to_string()
+                }
             })
             .collect();
         let field_refs: Vec<&str> = fields.iter().map(String::as_str).collect();
@@ -78,6 +96,7 @@ pub(in crate::db) fn show_indexes_for_schema_info_with_runtime_state(
             },
             Some(index.name()),
             &field_refs,
+            &[],
             index.predicate_sql(),
             runtime_state,
             index_origin(snapshot.indexes(), index.ordinal()),
@@ -114,6 +133,7 @@ fn render_index_listing_line(
     kind: &str,
     name: Option<&str>,
     fields: &[&str],
+    include: &[&str],
     predicate_sql: Option<&str>,
     runtime_state: Option<IndexState>,
     origin: Option<&str>,
@@ -137,6 +157,17 @@ fn render_index_listing_line(
 
     rendered.push(')');
 
+    if !include.is_empty() {
+        rendered.push_str(" INCLUDE (");
+        for (index, field) in include.iter().enumerate() {
+            if index > 0 {
+                rendered.push_str(", ");
+            }
+            rendered.push_str(field);
+        }
+        rendered.push(')');
+    }
+
     if let Some(predicate_sql) = predicate_sql {
         let _ = write!(rendered, " WHERE {predicate_sql}");
     }
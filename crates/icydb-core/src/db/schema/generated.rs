@@ -0,0 +1,210 @@
+//! Module: db::schema::generated
+//! Responsibility: compile accepted generated-column expressions for row writes.
+//! Does not own: after-image slot resolution, value admission, or DDL backfill.
+//! Boundary: binds persisted generated SQL text against one accepted snapshot.
+
+use crate::db::{
+    query::plan::expr::CompiledExpr,
+    schema::{AcceptedSchemaSnapshot, AcceptedValueCatalogHandle},
+};
+#[cfg(feature = "sql")]
+use crate::db::{
+    query::plan::expr::{
+        Expr, Function, collect_scalar_expr_field_roots, compile_scalar_projection_expr_with_schema,
+    },
+    schema::{FieldInsertGeneration, SchemaInfo},
+    sql::{lowering::lower_sql_write_value_expr, parse_scalar_expr_sql},
+};
+#[cfg(feature = "sql")]
+use std::collections::BTreeSet;
+
+///
+/// AcceptedGeneratedFieldError
+///
+/// Typed reason one accepted generated-column expression cannot bind.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(in crate::db) enum AcceptedGeneratedFieldError {
+    /// The stored SQL text is not one row-local scalar expression.
+    InvalidExpression,
+    /// The expression reads wall-clock time and cannot be recomputed exactly.
+    NonDeterministic,
+    /// The expression reads another generated field, including itself.
+    GeneratedSource,
+    /// The generated field has no accepted physical slot.
+    MissingSlot,
+}
+
+///
+/// CompiledAcceptedGeneratedField
+///
+/// One generated field bound to its accepted slot and source slots.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(in crate::db) struct CompiledAcceptedGeneratedField {
+    slot: usize,
+    source_slots: Vec<usize>,
+    expression: Option<CompiledExpr>,
+}
+
+impl CompiledAcceptedGeneratedField {
+    /// Return the accepted physical slot that receives the computed value.
+    #[must_use]
+    pub(in crate::db) const fn slot(&self) -> usize {
+        self.slot
+    }
+
+    /// Borrow the sorted accepted slots read by the expression.
+    #[must_use]
+    pub(in crate::db) const fn source_slots(&self) -> &[usize] {
+        self.source_slots.as_slice()
+    }
+
+    /// Borrow the compiled expression, when this build can evaluate it.
+    ///
+    /// Builds without the SQL frontend cannot bind stored expression text, so
+    /// writes that reach a generated field fail closed instead of guessing.
+    #[must_use]
+    pub(in crate::db) const fn expression(&self) -> Option<&CompiledExpr> {
+        self.expression.as_ref()
+    }
+}
+
+///
+/// CompiledAcceptedGeneratedFields
+///
+/// Generated fields of one accepted schema in stable slot order.
+/// Sources never include generated fields, so evaluation order is free.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(in crate::db) struct CompiledAcceptedGeneratedFields {
+    fields: Vec<CompiledAcceptedGeneratedField>,
+}
+
+impl CompiledAcceptedGeneratedFields {
+    /// Build one empty generated-field program.
+    #[must_use]
+    pub(in crate::db) const fn empty() -> Self {
+        Self { fields: Vec::new() }
+    }
+
+    /// Compile every generated field of one accepted schema snapshot.
+    pub(in crate::db) fn compile(
+        schema: &AcceptedSchemaSnapshot,
+        value_catalog: &AcceptedValueCatalogHandle,
+    ) -> Result<Self, AcceptedGeneratedFieldError> {
+        let snapshot = schema.persisted_snapshot();
+        let mut fields = Vec::new();
+        for field in snapshot.fields() {
+            let Some(expression) = field.generated_expression() else {
+                continue;
+            };
+            let slot = snapshot
+                .row_layout()
+                .slot_for_field(field.id())
+                .map(|slot| usize::from(slot.get()))
+                .ok_or(AcceptedGeneratedFieldError::MissingSlot)?;
+            fields.push(compile_generated_field(
+                schema,
+                value_catalog,
+                slot,
+                expression,
+            )?);
+        }
+        fields.sort_unstable_by_key(CompiledAcceptedGeneratedField::slot);
+
+        Ok(Self { fields })
+    }
+
+    /// Return whether one accepted slot is generated.
+    #[must_use]
+    pub(in crate::db) fn contains_slot(&self, slot: usize) -> bool {
+        self.fields.iter().any(|field| field.slot == slot)
+    }
+
+    /// Borrow generated fields in stable slot order.
+    #[must_use]
+    pub(in crate::db) const fn fields(&self) -> &[CompiledAcceptedGeneratedField] {
+        self.fields.as_slice()
+    }
+}
+
+// Bind one stored expression through the same parse, lowering, and scalar
+// compile pipeline used by computed `UPDATE` assignments.
+#[cfg(feature = "sql")]
+fn compile_generated_field(
+    schema: &AcceptedSchemaSnapshot,
+    value_catalog: &AcceptedValueCatalogHandle,
+    slot: usize,
+    expression: &str,
+) -> Result<CompiledAcceptedGeneratedField, AcceptedGeneratedFieldError> {
+    let schema_info =
+        SchemaInfo::from_accepted_snapshot_and_catalog(schema, value_catalog.clone(), false);
+    let parsed = parse_scalar_expr_sql(expression)
+        .map_err(|_| AcceptedGeneratedFieldError::InvalidExpression)?;
+    let lowered = lower_sql_write_value_expr(&parsed, &schema_info)
+        .map_err(|_| AcceptedGeneratedFieldError::InvalidExpression)?;
+    if lowered.any_tree_expr(&mut |expr| {
+        matches!(
+            expr,
+            Expr::FunctionCall {
+                function: Function::Now,
+                ..
+            }
+        )
+    }) {
+        return Err(AcceptedGeneratedFieldError::NonDeterministic);
+    }
+
+    let mut roots = BTreeSet::new();
+    if !collect_scalar_expr_field_roots(&lowered, &mut roots) {
+        return Err(AcceptedGeneratedFieldError::InvalidExpression);
+    }
+    let snapshot = schema.persisted_snapshot();
+    let mut source_slots = Vec::with_capacity(roots.len());
+    for root in &roots {
+        let generated_source = snapshot.fields().iter().any(|field| {
+            field.name() == root
+                && field.write_policy().insert_generation() == Some(FieldInsertGeneration::Computed)
+        });
+        if generated_source {
+            return Err(AcceptedGeneratedFieldError::GeneratedSource);
+        }
+        source_slots.push(
+            schema_info
+                .field_slot_index(root)
+                .ok_or(AcceptedGeneratedFieldError::InvalidExpression)?,
+        );
+    }
+    source_slots.sort_unstable();
+    let expression = compile_scalar_projection_expr_with_schema(&schema_info, &lowered)
+        .map(|expr| CompiledExpr::compile(&expr))
+        .ok_or(AcceptedGeneratedFieldError::InvalidExpression)?;
+
+    Ok(CompiledAcceptedGeneratedField {
+        slot,
+        source_slots,
+        expression: Some(expression),
+    })
+}
+
+#[cfg(not(feature = "sql"))]
+#[expect(
+    clippy::unnecessary_wraps,
+    reason = "builds without the SQL frontend keep the fallible binding signature"
+)]
+const fn compile_generated_field(
+    _schema: &AcceptedSchemaSnapshot,
+    _value_catalog: &AcceptedValueCatalogHandle,
+    slot: usize,
+    _expression: &str,
+) -> Result<CompiledAcceptedGeneratedField, AcceptedGeneratedFieldError> {
+    Ok(CompiledAcceptedGeneratedField {
+        slot,
+        source_slots: Vec::new(),
+        expression: None,
+    })
+}
@@ -0,0 +1,50 @@
+//! Module: db::schema::index_scalar
+//! Responsibility: compile accepted scalar expression-index key items.
+//! Does not own: key encoding, planner fingerprint matching, or DDL binding.
+//! Boundary: binds persisted key-expression SQL text against one accepted schema view.
+
+use crate::db::{query::plan::expr::CompiledExpr, schema::SchemaInfo};
+#[cfg(feature = "sql")]
+use crate::db::{
+    query::plan::expr::{Expr, Function, compile_scalar_projection_expr_with_schema},
+    sql::{lowering::lower_sql_write_value_expr, parse_scalar_expr_sql},
+};
+
+/// Compile one accepted scalar key expression against one schema view.
+///
+/// DDL binding already proved the text deterministic and row-local, so any
+/// failure here means the accepted authority drifted and key derivation must
+/// fail closed instead of guessing.
+#[cfg(feature = "sql")]
+#[must_use]
+pub(in crate::db::schema) fn compile_index_scalar_expression(
+    schema: &SchemaInfo,
+    expression_sql: &str,
+) -> Option<CompiledExpr> {
+    let parsed = parse_scalar_expr_sql(expression_sql).ok()?;
+    let lowered = lower_sql_write_value_expr(&parsed, schema).ok()?;
+    if lowered.any_tree_expr(&mut |expr| {
+        matches!(
+            expr,
+            Expr::FunctionCall {
+                function: Function::Now,
+                ..
+            }
+        )
+    }) {
+        return None;
+    }
+
+    compile_scalar_projection_expr_with_schema(schema, &lowered)
+        .map(|expr| CompiledExpr::compile(&expr))
+}
+
+/// Builds without the SQL frontend cannot bind stored expression text.
+#[cfg(not(feature = "sql"))]
+#[must_use]
+pub(in crate::db::schema) const fn compile_index_scalar_expression(
+    _schema: &SchemaInfo,
+    _expression_sql: &str,
+) -> Option<CompiledExpr> {
+    None
+}
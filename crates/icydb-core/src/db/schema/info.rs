@@ -17,6 +17,8 @@ use crate::db::schema::{
 #[cfg(feature = "sql")]
 use crate::db::schema::{SqlCapabilities, sql_capabilities_with_enum_catalog};
 use crate::{
+    db::query::plan::expr::CompiledExpr,
+    db::schema::index_scalar::compile_index_scalar_expression,
     db::schema::{
         canonicalize_filter_collection_element_for_persisted_kind,
         canonicalize_filter_literal_for_persisted_kind, enum_catalog::ValueAdmissionBudget,
@@ -43,6 +45,31 @@ fn schema_field_info<'a>(
         .map(|index| &fields[index].1)
 }
 
+// Resolve the value kind of one text-keyed map field.
+fn text_keyed_map_value_kind(field: &SchemaFieldInfo) -> Option<&AcceptedFieldKind> {
+    match &field.query_kind {
+        AcceptedFieldKind::Map { key, value }
+            if matches!(key.as_ref(), AcceptedFieldKind::Text { .. }) =>
+        {
+            Some(value.as_ref())
+        }
+        _ => None,
+    }
+}
+
+// Resolve the value kind addressed by one `field['key']` path. Text-keyed maps
+// expose every key as a one-segment nested path whose value may be absent.
+fn map_key_value_kind<'a>(
+    field: &'a SchemaFieldInfo,
+    segments: &[String],
+) -> Option<&'a AcceptedFieldKind> {
+    if segments.len() != 1 {
+        return None;
+    }
+
+    text_keyed_map_value_kind(field)
+}
+
 // Resolve top-level index membership from accepted persisted index contracts
 // once per schema view. Runtime accepted schema views must not reopen generated
 // generated index declarations after schema acceptance.
@@ -117,6 +144,7 @@ pub(in crate::db) struct SchemaIndexInfo {
     unique: bool,
     unique_constraint: Option<AcceptedConstraintIdentity>,
     fields: Vec<SchemaIndexFieldPathInfo>,
+    include: Vec<SchemaIndexFieldPathInfo>,
     predicate_sql: Option<String>,
     value_catalog: AcceptedValueCatalogHandle,
 }
@@ -167,6 +195,12 @@ impl SchemaIndexInfo {
         self.fields.as_slice()
     }
 
+    /// Borrow accepted non-key payload fields carried in each index entry.
+    #[must_use]
+    pub(in crate::db) const fn include(&self) -> &[SchemaIndexFieldPathInfo] {
+        self.include.as_slice()
+    }
+
     /// Borrow optional predicate SQL display metadata.
     #[must_use]
     pub(in crate::db) const fn predicate_sql(&self) -> Option<&str> {
@@ -185,6 +219,7 @@ impl SchemaIndexInfo {
         if !self
             .fields
             .iter()
+            .chain(&self.include)
             .any(|candidate| std::ptr::eq(candidate, field))
         {
             return None;
@@ -259,6 +294,20 @@ impl SchemaExpressionIndexInfo {
         self.key_items.as_slice()
     }
 
+    /// Borrow the single token expression when this index stores one entry
+    /// per text token instead of one entry per row.
+    #[must_use]
+    pub(in crate::db) fn multi_entry_expression(&self) -> Option<&SchemaIndexExpressionInfo> {
+        match self.key_items.as_slice() {
+            [SchemaExpressionIndexKeyItemInfo::Expression(expression)]
+                if expression.op().is_multi_entry() =>
+            {
+                Some(expression)
+            }
+            _ => None,
+        }
+    }
+
     /// Borrow optional accepted index-membership predicate SQL metadata.
     #[must_use]
     pub(in crate::db) const fn predicate_sql(&self) -> Option<&str> {
@@ -281,6 +330,7 @@ impl SchemaExpressionIndexInfo {
             SchemaExpressionIndexKeyItemInfo::Expression(expression) => {
                 std::ptr::eq(expression.source(), field)
             }
+            SchemaExpressionIndexKeyItemInfo::Scalar(_) => false,
         }) {
             return None;
         }
@@ -297,6 +347,7 @@ impl SchemaExpressionIndexInfo {
 pub(in crate::db) enum SchemaExpressionIndexKeyItemInfo {
     FieldPath(SchemaIndexFieldPathInfo),
     Expression(Box<SchemaIndexExpressionInfo>),
+    Scalar(Box<SchemaIndexScalarExpressionInfo>),
 }
 
 ///
@@ -331,6 +382,39 @@ impl SchemaIndexExpressionInfo {
     }
 }
 
+///
+/// SchemaIndexScalarExpressionInfo
+///
+/// Accepted arbitrary scalar-expression key contract for one expression-index
+/// key item, compiled against the same accepted schema view.
+///
+#[derive(Clone, Debug)]
+pub(in crate::db) struct SchemaIndexScalarExpressionInfo {
+    source_slots: Vec<usize>,
+    canonical_text: String,
+    expression: Option<CompiledExpr>,
+}
+
+impl SchemaIndexScalarExpressionInfo {
+    /// Borrow the accepted top-level slots read by the expression.
+    #[must_use]
+    pub(in crate::db) const fn source_slots(&self) -> &[usize] {
+        self.source_slots.as_slice()
+    }
+
+    /// Borrow the normalized expression fingerprint matched by the planner.
+    #[must_use]
+    pub(in crate::db) const fn canonical_text(&self) -> &str {
+        self.canonical_text.as_str()
+    }
+
+    /// Borrow the compiled key expression, when this build can evaluate it.
+    #[must_use]
+    pub(in crate::db) const fn expression(&self) -> Option<&CompiledExpr> {
+        self.expression.as_ref()
+    }
+}
+
 ///
 /// SchemaIndexFieldPathInfo
 ///
@@ -345,6 +429,7 @@ pub(in crate::db) struct SchemaIndexFieldPathInfo {
     persisted_kind: AcceptedFieldKind,
     accepted_value_contract: Option<Box<AcceptedValueContract>>,
     nullable: bool,
+    descending: bool,
 }
 
 impl SchemaIndexFieldPathInfo {
@@ -366,6 +451,13 @@ impl SchemaIndexFieldPathInfo {
         self.path.as_slice()
     }
 
+    /// Return whether this key item's ordered segment sorts in descending
+    /// order.
+    #[must_use]
+    pub(in crate::db) const fn descending(&self) -> bool {
+        self.descending
+    }
+
     /// Borrow the accepted persisted field kind.
     #[must_use]
     pub(in crate::db) fn persisted_kind(&self) -> Option<&AcceptedFieldKind> {
@@ -441,6 +533,19 @@ impl SchemaInfo {
         schema_field_info(self.fields.as_slice(), name).map(|field| field.slot)
     }
 
+    /// Return one past the highest accepted top-level row slot.
+    ///
+    /// Computed grouped keys allocate their virtual slots from this bound so
+    /// they can never alias an accepted field slot.
+    #[must_use]
+    pub(in crate::db) fn field_slot_bound(&self) -> usize {
+        self.fields
+            .iter()
+            .map(|(_, field)| field.slot.saturating_add(1))
+            .max()
+            .unwrap_or(0)
+    }
+
     /// Return accepted field names in canonical physical-slot order.
     ///
     /// Structural identity projection uses this ordering instead of reopening
@@ -539,7 +644,8 @@ impl SchemaInfo {
 
     /// Return SQL operation capabilities for one nested field path.
     ///
-    /// Nested paths resolve from persisted accepted leaf metadata.
+    /// Nested paths resolve from persisted accepted leaf metadata, or from the
+    /// value kind of one text-keyed map entry.
     #[must_use]
     #[cfg(feature = "sql")]
     pub(in crate::db) fn nested_sql_capabilities(
@@ -560,11 +666,16 @@ impl SchemaInfo {
                 );
                 accepted_sql_capabilities(&query_kind, &self.value_catalog)
             })
+            .or_else(|| {
+                map_key_value_kind(field, segments)
+                    .map(|kind| accepted_sql_capabilities(kind, &self.value_catalog))
+            })
     }
 
     /// Return the type for one nested field path rooted at a top-level field.
     ///
-    /// Nested paths resolve from persisted accepted leaf metadata.
+    /// Nested paths resolve from persisted accepted leaf metadata, or from the
+    /// value kind of one text-keyed map entry.
     #[must_use]
     pub(crate) fn nested_field_type(&self, name: &str, segments: &[String]) -> Option<FieldType> {
         let field = schema_field_info(self.fields.as_slice(), name)?;
@@ -580,13 +691,15 @@ impl SchemaInfo {
                 );
                 field_type_from_persisted_kind(&query_kind)
             })
+            .or_else(|| map_key_value_kind(field, segments).map(field_type_from_persisted_kind))
     }
 
     /// Return whether one top-level field exposes any nested path metadata.
     #[must_use]
     pub(crate) fn field_has_nested_paths(&self, name: &str) -> bool {
-        schema_field_info(self.fields.as_slice(), name)
-            .is_some_and(|field| !field.nested_leaves.is_empty())
+        schema_field_info(self.fields.as_slice(), name).is_some_and(|field| {
+            !field.nested_leaves.is_empty() || text_keyed_map_value_kind(field).is_some()
+        })
     }
 
     /// Canonicalize one strict SQL literal against this schema's field authority.
@@ -672,6 +785,23 @@ impl SchemaInfo {
         canonicalize_filter_collection_element_for_persisted_kind(&field.query_kind, value)
     }
 
+    /// Canonicalize one map-key comparison literal against the accepted value
+    /// kind of a text-keyed map field.
+    #[must_use]
+    pub(in crate::db) fn canonicalize_filter_map_value(
+        &self,
+        field_name: &str,
+        value: &Value,
+    ) -> Option<Value> {
+        let field = schema_field_info(self.fields.as_slice(), field_name)?;
+        let value_kind = text_keyed_map_value_kind(field)?;
+        if value_kind.contains_enum() {
+            return None;
+        }
+
+        canonicalize_filter_literal_for_persisted_kind(value_kind, value)
+    }
+
     /// Build one accepted-only schema view retaining its immutable value catalog.
     ///
     /// Integrity and other catalog-native consumers must not require a
@@ -744,7 +874,7 @@ impl SchemaInfo {
             })
             .collect();
 
-        Self {
+        let mut info = Self {
             fields,
             indexes: snapshot
                 .indexes()
@@ -753,25 +883,29 @@ impl SchemaInfo {
                     schema_index_info_from_accepted_index(index, snapshot, &value_catalog)
                 })
                 .collect(),
-            expression_indexes: snapshot
+            expression_indexes: Vec::new(),
+            value_catalog,
+            entity_name: Some(schema.entity_name().to_string()),
+            primary_key_names,
+        };
+        // Scalar key expressions bind against the field view, so expression
+        // indexes are projected once every field contract is in place.
+        if include_expression_indexes {
+            info.expression_indexes = snapshot
                 .indexes()
                 .iter()
                 .filter_map(|index| {
-                    include_expression_indexes
-                        .then(|| {
-                            schema_expression_index_info_from_accepted_index(
-                                index,
-                                snapshot,
-                                &value_catalog,
-                            )
-                        })
-                        .flatten()
+                    schema_expression_index_info_from_accepted_index(
+                        index,
+                        snapshot,
+                        &info.value_catalog,
+                        &info,
+                    )
                 })
-                .collect(),
-            value_catalog,
-            entity_name: Some(schema.entity_name().to_string()),
-            primary_key_names,
+                .collect();
         }
+
+        info
     }
 }
 
@@ -811,6 +945,11 @@ pub(in crate::db) fn schema_index_info_from_accepted_index(
             .iter()
             .map(|path| schema_index_field_path_info_from_accepted(path, snapshot, value_catalog))
             .collect(),
+        include: index
+            .include()
+            .iter()
+            .map(|path| schema_index_field_path_info_from_accepted(path, snapshot, value_catalog))
+            .collect(),
         predicate_sql: index.predicate_sql().map(str::to_string),
         value_catalog: value_catalog.clone(),
     })
@@ -820,14 +959,15 @@ pub(in crate::db) fn schema_expression_index_info_from_accepted_index(
     index: &PersistedIndexSnapshot,
     snapshot: &PersistedSchemaSnapshot,
     value_catalog: &AcceptedValueCatalogHandle,
+    schema: &SchemaInfo,
 ) -> Option<SchemaExpressionIndexInfo> {
     let PersistedIndexKeySnapshot::Items(items) = index.key() else {
         return None;
     };
 
-    if !items
+    if items
         .iter()
-        .any(|item| matches!(item, PersistedIndexKeyItemSnapshot::Expression(_)))
+        .all(|item| matches!(item, PersistedIndexKeyItemSnapshot::FieldPath(_)))
     {
         return None;
     }
@@ -844,7 +984,9 @@ pub(in crate::db) fn schema_expression_index_info_from_accepted_index(
             .flatten(),
         key_items: items
             .iter()
-            .map(|item| schema_expression_index_key_item_info(item, snapshot, value_catalog))
+            .map(|item| {
+                schema_expression_index_key_item_info(item, snapshot, value_catalog, schema)
+            })
             .collect(),
         predicate_sql: index.predicate_sql().map(str::to_string),
         value_catalog: value_catalog.clone(),
@@ -855,6 +997,7 @@ fn schema_expression_index_key_item_info(
     item: &PersistedIndexKeyItemSnapshot,
     snapshot: &PersistedSchemaSnapshot,
     value_catalog: &AcceptedValueCatalogHandle,
+    schema: &SchemaInfo,
 ) -> SchemaExpressionIndexKeyItemInfo {
     match item {
         PersistedIndexKeyItemSnapshot::FieldPath(path) => {
@@ -875,6 +1018,23 @@ fn schema_expression_index_key_item_info(
                 canonical_text: expression.canonical_text().to_string(),
             }))
         }
+        PersistedIndexKeyItemSnapshot::Scalar(expression) => {
+            let mut source_slots = expression
+                .sources()
+                .iter()
+                .map(|source| accepted_slot_index(source.slot()))
+                .collect::<Vec<_>>();
+            source_slots.sort_unstable();
+
+            SchemaExpressionIndexKeyItemInfo::Scalar(Box::new(SchemaIndexScalarExpressionInfo {
+                source_slots,
+                canonical_text: expression
+                    .canonical_text()
+                    .trim_start_matches("expr:v1:")
+                    .to_string(),
+                expression: compile_index_scalar_expression(schema, expression.expression_sql()),
+            }))
+        }
     }
 }
 
@@ -903,6 +1063,7 @@ fn schema_index_field_path_info_from_accepted(
         persisted_kind: path.kind().clone(),
         accepted_value_contract,
         nullable: path.nullable(),
+        descending: path.descending(),
     }
 }
 
@@ -213,9 +213,19 @@ fn insert_generation_detail(
 ) -> Option<()> {
     for field in fields {
         let Some(generation) = field.write_policy().insert_generation() else {
+            if field.generated_expression().is_some() {
+                return Some(());
+            }
             continue;
         };
-        if !field.generated()
+        if generation == FieldInsertGeneration::Computed {
+            if computed_field_detail(primary_key_field_ids, field).is_some() {
+                return Some(());
+            }
+            continue;
+        }
+        if field.generated_expression().is_some()
+            || !field.generated()
             || field.nullable()
             || field.insert_default().slot_payload().is_some()
             || field.write_policy().write_management().is_some()
@@ -237,7 +247,8 @@ fn insert_generation_detail(
             (
                 FieldInsertGeneration::Identity
                 | FieldInsertGeneration::Ulid
-                | FieldInsertGeneration::Timestamp,
+                | FieldInsertGeneration::Timestamp
+                | FieldInsertGeneration::Computed,
                 _,
             ) => return Some(()),
         }
@@ -246,6 +257,34 @@ fn insert_generation_detail(
     None
 }
 
+// Computed fields may come from generated metadata or SQL DDL and may be
+// nullable, but they always own one row-local scalar expression and nothing
+// else may synthesize their value.
+fn computed_field_detail(
+    primary_key_field_ids: &[FieldId],
+    field: &PersistedFieldSnapshot,
+) -> Option<()> {
+    let expression_missing = field
+        .generated_expression()
+        .is_none_or(|expression| expression.trim().is_empty());
+    if expression_missing
+        || primary_key_field_ids.contains(&field.id())
+        || field.insert_default().slot_payload().is_some()
+        || field.write_policy().write_management().is_some()
+        || matches!(
+            field.kind(),
+            AcceptedFieldKind::List(_)
+                | AcceptedFieldKind::Set(_)
+                | AcceptedFieldKind::Map { .. }
+                | AcceptedFieldKind::Composite { .. }
+        )
+    {
+        return Some(());
+    }
+
+    None
+}
+
 // Find duplicate row-layout entries before slot lookup can hide the ambiguity
 // by returning only the first matching field ID.
 fn duplicate_row_layout_detail(_subject: &str, row_layout: &SchemaRowLayout) -> Option<()> {
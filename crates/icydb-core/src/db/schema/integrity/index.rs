@@ -3,8 +3,8 @@
 use crate::db::schema::{
     AcceptedFieldKind, PersistedFieldSnapshot, PersistedIndexExpressionOp,
     PersistedIndexExpressionSnapshot, PersistedIndexFieldPathSnapshot,
-    PersistedIndexKeyItemSnapshot, PersistedIndexKeySnapshot, PersistedIndexSnapshot,
-    SchemaRowLayout,
+    PersistedIndexKeyItemSnapshot, PersistedIndexKeySnapshot,
+    PersistedIndexScalarExpressionSnapshot, PersistedIndexSnapshot, SchemaRowLayout,
 };
 
 // Build the first deterministic accepted-index integrity diagnostic. Index
@@ -73,14 +73,32 @@ fn index_key_detail(
         PersistedIndexKeySnapshot::FieldPath(paths) => paths
             .iter()
             .find_map(|path| index_field_path_detail(subject, row_layout, fields, index, path)),
-        PersistedIndexKeySnapshot::Items(items) => items.iter().find_map(|item| match item {
-            PersistedIndexKeyItemSnapshot::FieldPath(path) => {
-                index_field_path_detail(subject, row_layout, fields, index, path)
-            }
-            PersistedIndexKeyItemSnapshot::Expression(expression) => {
-                index_expression_detail(subject, row_layout, fields, index, expression)
+        PersistedIndexKeySnapshot::Items(items) => {
+            // Token expressions own one entry per token, so they must be the
+            // only key item of a non-unique index.
+            let multi_entry = items.iter().any(|item| {
+                matches!(
+                    item,
+                    PersistedIndexKeyItemSnapshot::Expression(expression)
+                        if expression.op().is_multi_entry()
+                )
+            });
+            if multi_entry && (items.len() > 1 || index.unique()) {
+                return Some(());
             }
-        }),
+
+            items.iter().find_map(|item| match item {
+                PersistedIndexKeyItemSnapshot::FieldPath(path) => {
+                    index_field_path_detail(subject, row_layout, fields, index, path)
+                }
+                PersistedIndexKeyItemSnapshot::Expression(expression) => {
+                    index_expression_detail(subject, row_layout, fields, index, expression)
+                }
+                PersistedIndexKeyItemSnapshot::Scalar(expression) => {
+                    index_scalar_expression_detail(subject, row_layout, fields, index, expression)
+                }
+            })
+        }
     }
 }
 
@@ -106,6 +124,28 @@ fn index_expression_detail(
     index_field_path_detail(subject, row_layout, fields, index, expression.source())
 }
 
+// Scalar expression keys are rebound from their SQL, so acceptance checks
+// only that the text is present and every source still names its field.
+fn index_scalar_expression_detail(
+    subject: &str,
+    row_layout: &SchemaRowLayout,
+    fields: &[PersistedFieldSnapshot],
+    index: &PersistedIndexSnapshot,
+    expression: &PersistedIndexScalarExpressionSnapshot,
+) -> Option<()> {
+    if expression.expression_sql().is_empty()
+        || expression.canonical_text().is_empty()
+        || expression.sources().is_empty()
+    {
+        return Some(());
+    }
+
+    expression
+        .sources()
+        .iter()
+        .find_map(|source| index_field_path_detail(subject, row_layout, fields, index, source))
+}
+
 const fn expression_output_kind_matches_op(
     op: PersistedIndexExpressionOp,
     output_kind: &AcceptedFieldKind,
@@ -114,7 +154,8 @@ const fn expression_output_kind_matches_op(
         PersistedIndexExpressionOp::Lower
         | PersistedIndexExpressionOp::Upper
         | PersistedIndexExpressionOp::Trim
-        | PersistedIndexExpressionOp::LowerTrim => {
+        | PersistedIndexExpressionOp::LowerTrim
+        | PersistedIndexExpressionOp::Tokens => {
             matches!(output_kind, AcceptedFieldKind::Text { .. })
         }
         PersistedIndexExpressionOp::Date => {
@@ -125,6 +166,10 @@ const fn expression_output_kind_matches_op(
         | PersistedIndexExpressionOp::Day => {
             matches!(output_kind, AcceptedFieldKind::Int64)
         }
+        PersistedIndexExpressionOp::Elements => !matches!(
+            output_kind,
+            AcceptedFieldKind::List(_) | AcceptedFieldKind::Set(_) | AcceptedFieldKind::Map { .. }
+        ),
     }
 }
 
@@ -161,7 +206,12 @@ fn index_field_path_detail(
             .nested_leaves()
             .iter()
             .find(|leaf| leaf.path() == relative_path)
-            .map(|leaf| (leaf.kind(), leaf.nullable())),
+            .map(|leaf| (leaf.kind(), leaf.nullable()))
+            .or_else(|| {
+                field
+                    .map_key_value_kind(relative_path)
+                    .map(|kind| (kind, true))
+            }),
         None => None,
     };
     let Some((expected_kind, expected_nullable)) = expected else {
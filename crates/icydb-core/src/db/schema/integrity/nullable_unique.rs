@@ -130,13 +130,19 @@ fn distinct_index_sources(index: &PersistedIndexSnapshot) -> Vec<&PersistedIndex
         }
         PersistedIndexKeySnapshot::Items(items) => {
             for item in items {
-                let source = match item {
-                    PersistedIndexKeyItemSnapshot::FieldPath(path) => path,
+                match item {
+                    PersistedIndexKeyItemSnapshot::FieldPath(path) => {
+                        push_distinct_source(&mut sources, path);
+                    }
                     PersistedIndexKeyItemSnapshot::Expression(expression) => {
-                        expression_source(expression)
+                        push_distinct_source(&mut sources, expression_source(expression));
+                    }
+                    PersistedIndexKeyItemSnapshot::Scalar(expression) => {
+                        for source in expression.sources() {
+                            push_distinct_source(&mut sources, source);
+                        }
                     }
-                };
-                push_distinct_source(&mut sources, source);
+                }
             }
         }
     }
@@ -165,7 +171,9 @@ const fn expression_source(
         | PersistedIndexExpressionOp::Date
         | PersistedIndexExpressionOp::Year
         | PersistedIndexExpressionOp::Month
-        | PersistedIndexExpressionOp::Day => expression.source(),
+        | PersistedIndexExpressionOp::Day
+        | PersistedIndexExpressionOp::Tokens
+        | PersistedIndexExpressionOp::Elements => expression.source(),
     }
 }
 
@@ -199,6 +207,13 @@ fn source_omission_class(
     if field.nullable() {
         return SourceOmissionClass::UnsupportedNullableAncestor;
     }
+    if let Some(value_kind) = field.map_key_value_kind(relative_path) {
+        return if value_kind == source.kind() && source.nullable() {
+            SourceOmissionClass::TerminalNullOmits
+        } else {
+            SourceOmissionClass::InvalidSourcePath
+        };
+    }
     for prefix_len in 1..relative_path.len() {
         let Some(ancestor) = field
             .nested_leaves()
@@ -246,7 +261,8 @@ fn predicate_fields_bind(fields: &[PersistedFieldSnapshot], predicate: &Predicat
         | Predicate::IsEmpty { field }
         | Predicate::IsNotEmpty { field }
         | Predicate::TextContains { field, .. }
-        | Predicate::TextContainsCi { field, .. } => field_id_by_name(fields, field).is_some(),
+        | Predicate::TextContainsCi { field, .. }
+        | Predicate::TextMatch { field, .. } => field_id_by_name(fields, field).is_some(),
     }
 }
 
@@ -288,7 +304,8 @@ fn collect_exact_top_level_non_null_guards(
         | Predicate::IsEmpty { .. }
         | Predicate::IsNotEmpty { .. }
         | Predicate::TextContains { .. }
-        | Predicate::TextContainsCi { .. } => {}
+        | Predicate::TextContainsCi { .. }
+        | Predicate::TextMatch { .. } => {}
     }
 }
 
@@ -327,23 +327,22 @@ fn rewrite_entity_page(
                 let mut row_effects = Vec::new();
                 let mut row_index_bytes = 0usize;
                 for projection in &prepared.indexes {
-                    let Some(key) =
-                        projection.derive_key(&decoded.primary_key_value(), &candidate_reader)?
-                    else {
-                        continue;
-                    };
-                    row_index_bytes = row_index_bytes
-                        .checked_add(key.as_bytes().len())
-                        .ok_or_else(InternalError::store_invariant)?;
-                    row_effects.push(MigrationPhysicalEffect {
-                        store_path: program.store_path(),
-                        store,
-                        record: JournalRecord::schema_migration_index_put(
-                            program.store_path(),
-                            key,
-                            plan_digest,
-                        )?,
-                    });
+                    for key in
+                        projection.derive_keys(&decoded.primary_key_value(), &candidate_reader)?
+                    {
+                        row_index_bytes = row_index_bytes
+                            .checked_add(key.as_bytes().len())
+                            .ok_or_else(InternalError::store_invariant)?;
+                        row_effects.push(MigrationPhysicalEffect {
+                            store_path: program.store_path(),
+                            store,
+                            record: JournalRecord::schema_migration_index_put(
+                                program.store_path(),
+                                key,
+                                plan_digest,
+                            )?,
+                        });
+                    }
                 }
                 for relation in &prepared.relations {
                     let projected = relation.project_row(
@@ -749,13 +748,14 @@ fn final_validate_entity_page(
                     InternalError::schema_migration(SchemaMigrationCode::CandidateMismatch)
                 })?;
             for projection in &prepared.indexes {
-                if let Some(key) = projection.derive_key(&decoded.primary_key_value(), &row)?
-                    && store.with_index(|index| index.get(&key))
+                for key in projection.derive_keys(&decoded.primary_key_value(), &row)? {
+                    if store.with_index(|index| index.get(&key))
                         != Some(IndexEntryValue::presence())
-                {
-                    return Err(InternalError::schema_migration(
-                        SchemaMigrationCode::CandidateMismatch,
-                    ));
+                    {
+                        return Err(InternalError::schema_migration(
+                            SchemaMigrationCode::CandidateMismatch,
+                        ));
+                    }
                 }
             }
             for relation in &prepared.relations {
@@ -797,6 +797,31 @@ fn candidate_for_program<'a>(
         .ok_or_else(InternalError::store_invariant)
 }
 
+// Project every candidate index whose physical generation is new relative to
+// the before schema, plus any planner-invisible activation candidates.
+fn candidate_index_projections(
+    program: &CompiledMigrationEntityProgram,
+    before: &crate::db::schema::PersistedSchemaSnapshot,
+    candidate: &crate::db::schema::PersistedSchemaSnapshot,
+    candidate_contract: &crate::db::data::StructuralRowContract,
+) -> Result<Vec<MigrationIndexProjection>, InternalError> {
+    candidate
+        .indexes()
+        .iter()
+        .filter(|index| {
+            before
+                .indexes()
+                .iter()
+                .find(|before| before.schema_id() == index.schema_id())
+                .is_none_or(|before| before.physical_generation() != index.physical_generation())
+        })
+        .chain(candidate.candidate_indexes())
+        .map(|index| {
+            MigrationIndexProjection::new(program.entity(), candidate, index, candidate_contract)
+        })
+        .collect()
+}
+
 fn prepare_candidate_entity<C: CanisterKind>(
     db: &Db<C>,
     program: &CompiledMigrationEntityProgram,
@@ -827,21 +852,12 @@ fn prepare_candidate_entity<C: CanisterKind>(
         })?
         .ok_or_else(InternalError::store_corruption)?
         .decode_verified()?;
-    let indexes = candidate_schema
-        .persisted_snapshot()
-        .indexes()
-        .iter()
-        .filter(|index| {
-            before_schema
-                .persisted_snapshot()
-                .indexes()
-                .iter()
-                .find(|before| before.schema_id() == index.schema_id())
-                .is_none_or(|before| before.physical_generation() != index.physical_generation())
-        })
-        .chain(candidate_schema.persisted_snapshot().candidate_indexes())
-        .map(|index| MigrationIndexProjection::new(program.entity(), index, &candidate_contract))
-        .collect::<Result<Vec<_>, _>>()?;
+    let indexes = candidate_index_projections(
+        program,
+        before_schema.persisted_snapshot(),
+        candidate_schema.persisted_snapshot(),
+        &candidate_contract,
+    )?;
     let source = ReverseRelationSourceInfo::new(program.candidate_path(), program.entity());
     let relations = candidate_schema
         .persisted_snapshot()
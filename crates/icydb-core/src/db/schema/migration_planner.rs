@@ -1261,6 +1261,7 @@ fn index_contract_matches_ignoring_name(
         && accepted.unique() == target.unique()
         && accepted.origin() == target.origin()
         && accepted.key() == target.key()
+        && accepted.include() == target.include()
         && accepted.predicate_sql() == target.predicate_sql()
 }
 
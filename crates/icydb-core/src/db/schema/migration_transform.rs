@@ -18,9 +18,9 @@ use crate::{
         },
         schema::{
             AcceptedCatalogSnapshotSelection, AcceptedFieldKind, AcceptedSchemaSnapshot,
-            CandidateSchemaRevision, ExistingProposalStore, FieldId,
+            CandidateSchemaRevision, CheckedCastError, ExistingProposalStore, FieldId,
             PersistedSchemaMigrationTransformReason, SchemaFieldSlot, ValueAdmissionBudget,
-            lower_scalar_type, source_literal_input,
+            checked_cast_value, lower_scalar_type, source_literal_input, supported_checked_cast,
         },
     },
     error::InternalError,
@@ -253,11 +253,11 @@ impl CompiledMigrationEntityProgram {
                         target_field: *target,
                         reason: PersistedSchemaMigrationTransformReason::ValueContract,
                     })?;
-                let cast = checked_cast_value(value.as_ref(), target_kind).map_err(|reason| {
+                let cast = checked_cast_value(value.as_ref(), target_kind).map_err(|err| {
                     MigrationTransformFinding {
                         source_field: Some(*source),
                         target_field: *target,
-                        reason,
+                        reason: err.into(),
                     }
                 })?;
                 set_candidate_value(values, *target_slot, cast).map_err(|reason| {
@@ -507,8 +507,7 @@ fn compile_transform(
             let (source_id, source) = source_field(store, entity, before_snapshot, from)?;
             let declared_target = lower_scalar_type(scalar);
             if declared_target != *target.kind()
-                || !supported_cast_source(source.kind())
-                || !supported_cast_target(target.kind())
+                || !supported_checked_cast(source.kind(), target.kind())
             {
                 return Err(InternalError::store_unsupported());
             }
@@ -619,221 +618,14 @@ fn set_candidate_value(
     Ok(())
 }
 
-const fn supported_cast_source(kind: &AcceptedFieldKind) -> bool {
-    matches!(
-        kind,
-        AcceptedFieldKind::Int8
-            | AcceptedFieldKind::Int16
-            | AcceptedFieldKind::Int32
-            | AcceptedFieldKind::Int64
-            | AcceptedFieldKind::Int128
-            | AcceptedFieldKind::Nat8
-            | AcceptedFieldKind::Nat16
-            | AcceptedFieldKind::Nat32
-            | AcceptedFieldKind::Nat64
-            | AcceptedFieldKind::Nat128
-            | AcceptedFieldKind::Decimal { .. }
-    )
-}
-
-const fn supported_cast_target(kind: &AcceptedFieldKind) -> bool {
-    supported_cast_source(kind)
-}
-
-fn checked_cast_value(
-    source: &Value,
-    target: &AcceptedFieldKind,
-) -> Result<Value, PersistedSchemaMigrationTransformReason> {
-    if matches!(source, Value::Null) {
-        return Err(PersistedSchemaMigrationTransformReason::NullSource);
-    }
-    if let AcceptedFieldKind::Decimal { scale } = target {
-        return checked_cast_to_decimal(source, *scale).map(Value::Decimal);
-    }
-    if matches!(source, Value::Decimal(_)) {
-        return Err(PersistedSchemaMigrationTransformReason::PrecisionLoss);
-    }
-    match target {
-        AcceptedFieldKind::Int8 => signed_value(source, i8::MIN.into(), i8::MAX.into())
-            .and_then(|value| {
-                i64::try_from(value).map_err(|_| PersistedSchemaMigrationTransformReason::Overflow)
-            })
-            .map(Value::Int64),
-        AcceptedFieldKind::Int16 => signed_value(source, i16::MIN.into(), i16::MAX.into())
-            .and_then(|value| {
-                i64::try_from(value).map_err(|_| PersistedSchemaMigrationTransformReason::Overflow)
-            })
-            .map(Value::Int64),
-        AcceptedFieldKind::Int32 => signed_value(source, i32::MIN.into(), i32::MAX.into())
-            .and_then(|value| {
-                i64::try_from(value).map_err(|_| PersistedSchemaMigrationTransformReason::Overflow)
-            })
-            .map(Value::Int64),
-        AcceptedFieldKind::Int64 => signed_value(source, i64::MIN.into(), i64::MAX.into())
-            .and_then(|value| {
-                i64::try_from(value).map_err(|_| PersistedSchemaMigrationTransformReason::Overflow)
-            })
-            .map(Value::Int64),
-        AcceptedFieldKind::Int128 => signed_value(source, i128::MIN, i128::MAX).map(Value::Int128),
-        AcceptedFieldKind::Nat8 => unsigned_value(source, u8::MAX.into())
-            .and_then(|value| {
-                u64::try_from(value).map_err(|_| PersistedSchemaMigrationTransformReason::Overflow)
-            })
-            .map(Value::Nat64),
-        AcceptedFieldKind::Nat16 => unsigned_value(source, u16::MAX.into())
-            .and_then(|value| {
-                u64::try_from(value).map_err(|_| PersistedSchemaMigrationTransformReason::Overflow)
-            })
-            .map(Value::Nat64),
-        AcceptedFieldKind::Nat32 => unsigned_value(source, u32::MAX.into())
-            .and_then(|value| {
-                u64::try_from(value).map_err(|_| PersistedSchemaMigrationTransformReason::Overflow)
-            })
-            .map(Value::Nat64),
-        AcceptedFieldKind::Nat64 => unsigned_value(source, u64::MAX.into())
-            .and_then(|value| {
-                u64::try_from(value).map_err(|_| PersistedSchemaMigrationTransformReason::Overflow)
-            })
-            .map(Value::Nat64),
-        AcceptedFieldKind::Nat128 => unsigned_value(source, u128::MAX).map(Value::Nat128),
-        _ => Err(PersistedSchemaMigrationTransformReason::ValueContract),
-    }
-}
-
-fn signed_value(
-    source: &Value,
-    minimum: i128,
-    maximum: i128,
-) -> Result<i128, PersistedSchemaMigrationTransformReason> {
-    let value = match source {
-        Value::Int64(value) => i128::from(*value),
-        Value::Int128(value) => *value,
-        Value::Nat64(value) => i128::from(*value),
-        Value::Nat128(value) => {
-            i128::try_from(*value).map_err(|_| PersistedSchemaMigrationTransformReason::Overflow)?
+impl From<CheckedCastError> for PersistedSchemaMigrationTransformReason {
+    fn from(err: CheckedCastError) -> Self {
+        match err {
+            CheckedCastError::NullSource => Self::NullSource,
+            CheckedCastError::Overflow => Self::Overflow,
+            CheckedCastError::NegativeToUnsigned => Self::NegativeToUnsigned,
+            CheckedCastError::PrecisionLoss => Self::PrecisionLoss,
+            CheckedCastError::ValueContract => Self::ValueContract,
         }
-        _ => return Err(PersistedSchemaMigrationTransformReason::ValueContract),
-    };
-    if value < minimum || value > maximum {
-        return Err(PersistedSchemaMigrationTransformReason::Overflow);
-    }
-    Ok(value)
-}
-
-fn unsigned_value(
-    source: &Value,
-    maximum: u128,
-) -> Result<u128, PersistedSchemaMigrationTransformReason> {
-    let value = match source {
-        Value::Int64(value) => u128::try_from(*value)
-            .map_err(|_| PersistedSchemaMigrationTransformReason::NegativeToUnsigned)?,
-        Value::Int128(value) => u128::try_from(*value)
-            .map_err(|_| PersistedSchemaMigrationTransformReason::NegativeToUnsigned)?,
-        Value::Nat64(value) => u128::from(*value),
-        Value::Nat128(value) => *value,
-        _ => return Err(PersistedSchemaMigrationTransformReason::ValueContract),
-    };
-    if value > maximum {
-        return Err(PersistedSchemaMigrationTransformReason::Overflow);
-    }
-    Ok(value)
-}
-
-fn checked_cast_to_decimal(
-    source: &Value,
-    target_scale: u32,
-) -> Result<icydb_schema::Decimal, PersistedSchemaMigrationTransformReason> {
-    let mantissa = match source {
-        Value::Int64(value) => scale_integer(i128::from(*value), target_scale)?,
-        Value::Int128(value) => scale_integer(*value, target_scale)?,
-        Value::Nat64(value) => scale_integer(i128::from(*value), target_scale)?,
-        Value::Nat128(value) => scale_integer(
-            i128::try_from(*value)
-                .map_err(|_| PersistedSchemaMigrationTransformReason::Overflow)?,
-            target_scale,
-        )?,
-        Value::Decimal(value) => rescale_decimal(*value, target_scale)?,
-        _ => return Err(PersistedSchemaMigrationTransformReason::ValueContract),
-    };
-    icydb_schema::Decimal::try_from_i128_with_scale(mantissa, target_scale)
-        .ok_or(PersistedSchemaMigrationTransformReason::Overflow)
-}
-
-fn scale_integer(value: i128, scale: u32) -> Result<i128, PersistedSchemaMigrationTransformReason> {
-    value
-        .checked_mul(
-            10_i128
-                .checked_pow(scale)
-                .ok_or(PersistedSchemaMigrationTransformReason::Overflow)?,
-        )
-        .ok_or(PersistedSchemaMigrationTransformReason::Overflow)
-}
-
-fn rescale_decimal(
-    value: icydb_schema::Decimal,
-    target_scale: u32,
-) -> Result<i128, PersistedSchemaMigrationTransformReason> {
-    if value.scale() <= target_scale {
-        return value
-            .scale_to_integer(target_scale)
-            .ok_or(PersistedSchemaMigrationTransformReason::Overflow);
-    }
-    let divisor = 10_i128
-        .checked_pow(value.scale() - target_scale)
-        .ok_or(PersistedSchemaMigrationTransformReason::Overflow)?;
-    if value.mantissa() % divisor != 0 {
-        return Err(PersistedSchemaMigrationTransformReason::PrecisionLoss);
-    }
-    Ok(value.mantissa() / divisor)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn checked_cast_matrix_is_exact_for_sign_overflow_precision_and_null() {
-        assert_eq!(
-            checked_cast_value(&Value::Int64(-1), &AcceptedFieldKind::Nat8),
-            Err(PersistedSchemaMigrationTransformReason::NegativeToUnsigned),
-        );
-        assert_eq!(
-            checked_cast_value(&Value::Nat64(256), &AcceptedFieldKind::Nat8),
-            Err(PersistedSchemaMigrationTransformReason::Overflow),
-        );
-        assert_eq!(
-            checked_cast_value(
-                &Value::Decimal(icydb_schema::Decimal::from_i128_with_scale(15, 1)),
-                &AcceptedFieldKind::Decimal { scale: 0 },
-            ),
-            Err(PersistedSchemaMigrationTransformReason::PrecisionLoss),
-        );
-        assert_eq!(
-            checked_cast_value(&Value::Null, &AcceptedFieldKind::Int64),
-            Err(PersistedSchemaMigrationTransformReason::NullSource),
-        );
-        assert_eq!(
-            checked_cast_value(&Value::Int64(7), &AcceptedFieldKind::Int128),
-            Ok(Value::Int128(7)),
-        );
-        assert_eq!(
-            checked_cast_value(
-                &Value::Decimal(icydb_schema::Decimal::from_i128_with_scale(150, 2)),
-                &AcceptedFieldKind::Decimal { scale: 1 },
-            ),
-            Ok(Value::Decimal(icydb_schema::Decimal::from_i128_with_scale(
-                15, 1
-            ))),
-        );
-    }
-
-    #[test]
-    fn unsupported_cast_domains_remain_closed() {
-        assert!(!supported_cast_source(&AcceptedFieldKind::Timestamp));
-        assert!(!supported_cast_target(&AcceptedFieldKind::Float64));
-        assert_eq!(
-            checked_cast_value(&Value::Text("7".to_string()), &AcceptedFieldKind::Int64),
-            Err(PersistedSchemaMigrationTransformReason::ValueContract),
-        );
     }
 }
@@ -14,6 +14,7 @@ mod capabilities;
 pub(in crate::db) mod cardinality_build;
 pub(in crate::db) mod cardinality_generation;
 mod check;
+mod checked_cast;
 mod codec;
 mod composite_catalog;
 mod constraint;
@@ -26,8 +27,10 @@ mod field_kind_semantics;
 mod fingerprint;
 #[cfg(any(test, feature = "sql"))]
 mod format;
+mod generated;
 mod identity;
 mod identity_state;
+mod index_scalar;
 mod info;
 mod inspection_plan;
 mod integrity;
@@ -137,6 +140,11 @@ pub(in crate::db::schema) use check::{
     bind_source_check_expr, bind_source_rule_literal, source_literal_input,
     validate_accepted_check_literals,
 };
+#[cfg(any(test, feature = "migration"))]
+pub(in crate::db) use checked_cast::supported_checked_cast;
+pub(in crate::db) use checked_cast::{
+    CheckedCastError, checked_cast_value, resolve_checked_cast_target,
+};
 #[cfg(test)]
 pub(in crate::db) use codec::encode_unchecked_persisted_schema_snapshot_for_tests;
 pub(in crate::db) use codec::{
@@ -224,6 +232,9 @@ pub(in crate::db) use fingerprint::{
 };
 #[cfg(feature = "sql")]
 pub(in crate::db) use format::show_indexes_for_schema_info_with_runtime_state;
+pub(in crate::db) use generated::{
+    AcceptedGeneratedFieldError, CompiledAcceptedGeneratedField, CompiledAcceptedGeneratedFields,
+};
 pub(in crate::db) use identity::{
     ConstraintId, ConstraintIdAllocator, FieldId, RelationId, SchemaIndexId,
 };
@@ -297,6 +308,8 @@ pub(in crate::db::schema) use migration_record::{
 pub(in crate::db) use migration_record::{
     prepared_schema_migration_record_op_for_tests, schema_migration_record_lifecycle_ops_for_tests,
 };
+#[cfg(feature = "sql")]
+pub(in crate::db) use mutation::MAX_SCHEMA_SOURCE_ROWS;
 #[cfg(any(test, feature = "migration"))]
 pub(in crate::db) use mutation::MigrationIndexProjection;
 #[cfg(any(test, feature = "sql"))]
@@ -320,9 +333,9 @@ pub(in crate::db) use mutation::{
     SchemaDdlSecondaryIndexAdditionCandidate, SchemaDdlSecondaryIndexAdditionCandidateError,
     SchemaDdlSecondaryIndexExpressionIntent, SchemaDdlSecondaryIndexExpressionOpIntent,
     SchemaDdlSecondaryIndexFieldPathIntent, SchemaDdlSecondaryIndexKeyCandidateError,
-    SchemaDdlSecondaryIndexKeyIntent, SchemaDdlVersionContractPreflightError,
-    SchemaFieldDropTarget, SchemaFieldNullabilityTarget, SchemaFieldRenameTarget,
-    SchemaInsertDefaultTarget, build_sql_ddl_field_addition_candidate,
+    SchemaDdlSecondaryIndexKeyIntent, SchemaDdlSecondaryIndexScalarIntent,
+    SchemaDdlVersionContractPreflightError, SchemaFieldDropTarget, SchemaFieldNullabilityTarget,
+    SchemaFieldRenameTarget, SchemaInsertDefaultTarget, build_sql_ddl_field_addition_candidate,
     build_sql_ddl_secondary_index_candidate, derive_sql_ddl_expression_index_accepted_after,
     derive_sql_ddl_field_addition_accepted_after, derive_sql_ddl_field_default_accepted_after,
     derive_sql_ddl_field_drop_accepted_after, derive_sql_ddl_field_nullability_accepted_after,
@@ -371,8 +384,9 @@ pub(in crate::db) use snapshot::{
     AcceptedSchemaSnapshot, PersistedFieldOrigin, PersistedFieldSnapshot,
     PersistedIndexExpressionOp, PersistedIndexExpressionSnapshot, PersistedIndexFieldPathSnapshot,
     PersistedIndexKeyItemSnapshot, PersistedIndexKeySnapshot, PersistedIndexOrigin,
-    PersistedIndexSnapshot, PersistedNestedLeafSnapshot, PersistedRelationEdgeSnapshot,
-    PersistedSchemaSnapshot, SchemaFieldWritePolicy, SchemaHistoricalFill, SchemaInsertDefault,
+    PersistedIndexScalarExpressionSnapshot, PersistedIndexSnapshot, PersistedNestedLeafSnapshot,
+    PersistedRelationEdgeSnapshot, PersistedSchemaSnapshot, SchemaFieldWritePolicy,
+    SchemaHistoricalFill, SchemaInsertDefault,
 };
 #[cfg(test)]
 pub(in crate::db) use source_binding::AcceptedNamedTypeIdentity;
@@ -488,11 +502,14 @@ pub(crate) use types::FieldType;
 #[cfg(feature = "sql")]
 pub(in crate::db) use types::canonicalize_strict_sql_literal_for_persisted_kind;
 pub(in crate::db) use types::field_type_from_persisted_kind;
-#[cfg(feature = "sql")]
-pub(in crate::db) use types::input_value_from_strict_sql_literal_for_persisted_kind;
 pub(crate) use types::literal_matches_type;
 pub(in crate::db) use types::query_field_kind_from_persisted_kind;
 pub(in crate::db) use types::{
     canonicalize_filter_collection_element_for_persisted_kind,
     canonicalize_filter_literal_for_persisted_kind,
 };
+#[cfg(feature = "sql")]
+pub(in crate::db) use types::{
+    input_value_from_computed_sql_value_for_persisted_kind,
+    input_value_from_strict_sql_literal_for_persisted_kind,
+};
@@ -7,7 +7,7 @@
 use crate::error::SchemaTransitionBudgetResource;
 
 #[cfg(any(test, feature = "sql"))]
-const MAX_SOURCE_ROWS: usize = 65_536;
+pub(in crate::db) const MAX_SCHEMA_SOURCE_ROWS: usize = 65_536;
 #[cfg(any(test, feature = "sql"))]
 const MAX_SOURCE_ROW_BYTES: usize = 256 * 1024 * 1024;
 #[cfg(any(test, feature = "sql"))]
@@ -48,7 +48,7 @@ impl SchemaTransitionSourceBudget {
             .source_rows
             .checked_add(1)
             .ok_or(SchemaTransitionBudgetResource::SourceRows)?;
-        if self.source_rows > MAX_SOURCE_ROWS {
+        if self.source_rows > MAX_SCHEMA_SOURCE_ROWS {
             return Err(SchemaTransitionBudgetResource::SourceRows);
         }
 
@@ -83,7 +83,7 @@ mod tests {
     #[test]
     fn source_budget_matches_the_maintained_complete_domain_caps() {
         let mut rows = SchemaTransitionSourceBudget::standard();
-        for _ in 0..MAX_SOURCE_ROWS {
+        for _ in 0..MAX_SCHEMA_SOURCE_ROWS {
             rows.consume_source_row(0)
                 .expect("rows through the exact cap should admit");
         }
@@ -4,8 +4,9 @@ use super::{
     field_removal::{DenseFieldRemovalError, derive_dense_field_removal_candidate},
 };
 use crate::db::schema::{
-    AcceptedSchemaSnapshot, FieldId, PersistedFieldSnapshot, PersistedSchemaSnapshot,
-    SchemaFieldSlot, SchemaInsertDefault, SchemaRowLayout,
+    AcceptedSchemaSnapshot, FieldId, PersistedFieldSnapshot, PersistedIndexKeyItemSnapshot,
+    PersistedIndexKeySnapshot, PersistedSchemaSnapshot, SchemaFieldSlot, SchemaInsertDefault,
+    SchemaRowLayout,
 };
 
 ///
@@ -252,6 +253,8 @@ pub(in crate::db) enum SchemaDdlFieldRenameCandidateError {
     Duplicate,
     /// The requested accepted field is generated-owned.
     Generated,
+    /// A scalar expression index names the field in its stored SQL text.
+    ExpressionIndexSource,
 }
 
 /// Admit one SQL DDL field addition through the schema-owned mutation request
@@ -384,7 +387,7 @@ pub(in crate::db) fn validate_sql_ddl_field_default_change_candidate(
     if field.insert_default() == default {
         return Ok(());
     }
-    if field.generated() {
+    if field.generated() || field.generated_expression().is_some() {
         return Err(SchemaDdlFieldDefaultCandidateError::Generated);
     }
     Ok(())
@@ -465,6 +468,18 @@ pub(in crate::db) fn resolve_sql_ddl_field_rename_candidate(
         return Err(SchemaDdlFieldRenameCandidateError::Generated);
     }
 
+    if accepted.indexes().iter().any(|index| {
+        let PersistedIndexKeySnapshot::Items(items) = index.key() else {
+            return false;
+        };
+        items.iter().any(|item| {
+            matches!(item, PersistedIndexKeyItemSnapshot::Scalar(_))
+                && item.references_field(field.id())
+        })
+    }) {
+        return Err(SchemaDdlFieldRenameCandidateError::ExpressionIndexSource);
+    }
+
     Ok(field.clone())
 }
 
@@ -727,6 +742,7 @@ fn derive_field_nullability_shape(
                 field.storage_decode(),
                 field.leaf_codec(),
             )
+            .with_generated_expression(field.generated_expression().map(str::to_string))
         })
         .collect::<Vec<_>>();
     (history_floor, fields)
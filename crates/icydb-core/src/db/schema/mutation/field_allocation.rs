@@ -1,9 +1,9 @@
 //! Schema-owned field allocation helpers for DDL-authored field candidates.
 
 use crate::db::schema::{
-    AcceptedFieldKind, AcceptedSchemaSnapshot, FieldId, PersistedFieldOrigin,
-    PersistedFieldSnapshot, SchemaFieldSlot, SchemaFieldWritePolicy, SchemaHistoricalFill,
-    SchemaInsertDefault,
+    AcceptedFieldKind, AcceptedSchemaSnapshot, FieldId, FieldInsertGeneration,
+    PersistedFieldOrigin, PersistedFieldSnapshot, SchemaFieldSlot, SchemaFieldWritePolicy,
+    SchemaHistoricalFill, SchemaInsertDefault,
 };
 use crate::db::schema::{FieldStorageDecode, LeafCodec};
 
@@ -37,7 +37,12 @@ pub(in crate::db) fn resolve_sql_ddl_field_addition_name_candidate(
 
 /// Build one DDL-owned additive field candidate with schema-owned ID and slot
 /// allocation. SQL DDL supplies author intent; schema mutation code assigns
-/// durable catalog identity.
+/// durable catalog identity. A generated expression makes the field a stored
+/// computed column whose value the write boundary always owns.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "DDL field candidates carry each authored column fact explicitly"
+)]
 pub(in crate::db) fn build_sql_ddl_field_addition_candidate(
     accepted_before: &AcceptedSchemaSnapshot,
     name: String,
@@ -46,6 +51,7 @@ pub(in crate::db) fn build_sql_ddl_field_addition_candidate(
     default: SchemaInsertDefault,
     storage_decode: FieldStorageDecode,
     leaf_codec: LeafCodec,
+    generated_expression: Option<String>,
 ) -> Result<PersistedFieldSnapshot, SchemaDdlFieldAdditionCandidateError> {
     resolve_sql_ddl_field_addition_name_candidate(accepted_before, name.as_str())?;
 
@@ -60,6 +66,9 @@ pub(in crate::db) fn build_sql_ddl_field_addition_candidate(
         None if nullable => SchemaHistoricalFill::Null,
         None => SchemaHistoricalFill::Reject,
     };
+    let insert_generation = generated_expression
+        .as_ref()
+        .map(|_| FieldInsertGeneration::Computed);
 
     Ok(PersistedFieldSnapshot::new_with_write_policy_and_origin(
         next_sql_ddl_field_id(accepted_before)?,
@@ -71,11 +80,12 @@ pub(in crate::db) fn build_sql_ddl_field_addition_candidate(
         introduced_in_layout,
         default,
         historical_fill,
-        SchemaFieldWritePolicy::from_model_policies(None, None),
+        SchemaFieldWritePolicy::from_model_policies(insert_generation, None),
         PersistedFieldOrigin::SqlDdl,
         storage_decode,
         leaf_codec,
-    ))
+    )
+    .with_generated_expression(generated_expression))
 }
 
 fn next_sql_ddl_field_id(
@@ -5,16 +5,19 @@ use super::{
     SchemaDdlMutationAdmission, SchemaDdlMutationAdmissionError, SchemaDdlMutationTarget,
     schema_mutation_request_for_snapshots,
 };
-use crate::db::schema::{
-    AcceptedFieldKind, FieldId, PersistedIndexExpressionOp, PersistedIndexFieldPathSnapshot,
-    PersistedIndexKeyItemSnapshot, PersistedIndexKeySnapshot, PersistedIndexSnapshot,
-    SchemaFieldSlot,
-};
 #[cfg(feature = "sql")]
 use crate::db::schema::{
     AcceptedSchemaSnapshot, ConstraintActivationKind, ConstraintActivationSnapshot, ConstraintId,
     ConstraintOrigin, PersistedSchemaSnapshot,
 };
+use crate::db::{
+    query::plan::expr::CompiledExpr,
+    schema::{
+        AcceptedFieldKind, FieldId, PersistedIndexExpressionOp, PersistedIndexFieldPathSnapshot,
+        PersistedIndexKeyItemSnapshot, PersistedIndexKeySnapshot, PersistedIndexSnapshot,
+        SchemaFieldSlot, SchemaInfo, index_scalar::compile_index_scalar_expression,
+    },
+};
 
 ///
 /// SchemaFieldPathIndexRebuildTarget
@@ -33,6 +36,7 @@ pub(in crate::db) struct SchemaFieldPathIndexRebuildTarget {
     pub(in crate::db::schema::mutation) unique: bool,
     pub(in crate::db::schema::mutation) predicate_sql: Option<String>,
     pub(in crate::db::schema::mutation) key_paths: Vec<SchemaFieldPathIndexRebuildKey>,
+    pub(in crate::db::schema::mutation) include_paths: Vec<SchemaFieldPathIndexRebuildKey>,
 }
 
 impl SchemaFieldPathIndexRebuildTarget {
@@ -57,6 +61,12 @@ impl SchemaFieldPathIndexRebuildTarget {
     pub(in crate::db) const fn key_paths(&self) -> &[SchemaFieldPathIndexRebuildKey] {
         self.key_paths.as_slice()
     }
+
+    /// Borrow the non-key payload fields stored in each rebuilt entry.
+    #[must_use]
+    pub(in crate::db) const fn include_paths(&self) -> &[SchemaFieldPathIndexRebuildKey] {
+        self.include_paths.as_slice()
+    }
 }
 
 ///
@@ -73,6 +83,7 @@ pub(in crate::db) struct SchemaFieldPathIndexRebuildKey {
     pub(in crate::db::schema::mutation) path: Vec<String>,
     pub(in crate::db::schema::mutation) kind: AcceptedFieldKind,
     pub(in crate::db::schema::mutation) nullable: bool,
+    pub(in crate::db::schema::mutation) descending: bool,
 }
 
 impl SchemaFieldPathIndexRebuildKey {
@@ -90,6 +101,11 @@ impl SchemaFieldPathIndexRebuildKey {
     pub(in crate::db) fn field_name(&self) -> &str {
         self.path.first().map_or("", String::as_str)
     }
+
+    #[must_use]
+    pub(in crate::db) const fn descending(&self) -> bool {
+        self.descending
+    }
 }
 
 ///
@@ -136,6 +152,22 @@ impl SchemaExpressionIndexRebuildTarget {
     pub(in crate::db) const fn key_items(&self) -> &[SchemaExpressionIndexRebuildKey] {
         self.key_items.as_slice()
     }
+
+    /// Borrow the single token expression when this target rebuilds one
+    /// entry per text token instead of one entry per row.
+    #[must_use]
+    pub(in crate::db) fn multi_entry_expression(
+        &self,
+    ) -> Option<&SchemaExpressionIndexRebuildExpression> {
+        match self.key_items.as_slice() {
+            [SchemaExpressionIndexRebuildKey::Expression(expression)]
+                if expression.op().is_multi_entry() =>
+            {
+                Some(expression)
+            }
+            _ => None,
+        }
+    }
 }
 
 ///
@@ -149,6 +181,7 @@ impl SchemaExpressionIndexRebuildTarget {
 pub(in crate::db) enum SchemaExpressionIndexRebuildKey {
     FieldPath(SchemaFieldPathIndexRebuildKey),
     Expression(Box<SchemaExpressionIndexRebuildExpression>),
+    Scalar(Box<SchemaExpressionIndexRebuildScalar>),
 }
 
 ///
@@ -180,6 +213,58 @@ impl SchemaExpressionIndexRebuildExpression {
     }
 }
 
+///
+/// SchemaExpressionIndexRebuildScalar
+///
+/// One accepted arbitrary scalar-expression key component. The expression is
+/// compiled only once the accepted schema view that owns it is bound.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(in crate::db) struct SchemaExpressionIndexRebuildScalar {
+    sources: Vec<SchemaFieldPathIndexRebuildKey>,
+    expression_sql: String,
+    canonical_text: String,
+    expression: Option<CompiledExpr>,
+}
+
+impl SchemaExpressionIndexRebuildScalar {
+    /// Borrow the accepted top-level source fields read by the expression.
+    #[must_use]
+    pub(in crate::db) const fn sources(&self) -> &[SchemaFieldPathIndexRebuildKey] {
+        self.sources.as_slice()
+    }
+
+    /// Borrow the compiled key expression, when one schema view bound it.
+    #[must_use]
+    pub(in crate::db) const fn expression(&self) -> Option<&CompiledExpr> {
+        self.expression.as_ref()
+    }
+}
+
+impl SchemaExpressionIndexRebuildTarget {
+    /// Bind every scalar key expression against the accepted schema view the
+    /// rebuild derives keys for. Unbound scalar items fail key derivation.
+    #[must_use]
+    pub(in crate::db) fn with_compiled_scalar_expressions(mut self, schema: &SchemaInfo) -> Self {
+        for key_item in &mut self.key_items {
+            if let SchemaExpressionIndexRebuildKey::Scalar(scalar) = key_item {
+                scalar.expression = compile_index_scalar_expression(schema, &scalar.expression_sql);
+            }
+        }
+
+        self
+    }
+
+    /// Return whether any key item is an arbitrary scalar expression.
+    #[must_use]
+    pub(in crate::db) fn has_scalar_key_items(&self) -> bool {
+        self.key_items
+            .iter()
+            .any(|key_item| matches!(key_item, SchemaExpressionIndexRebuildKey::Scalar(_)))
+    }
+}
+
 impl SchemaMutationRequest<'_> {
     /// Lower one accepted field-path index snapshot into a mutation request.
     /// Expression/mixed indexes stay on their dedicated lowering path.
@@ -195,6 +280,7 @@ impl SchemaMutationRequest<'_> {
         }
 
         let key_paths = paths.iter().map(field_path_rebuild_key).collect();
+        let include_paths = index.include().iter().map(field_path_rebuild_key).collect();
 
         Ok(Self::AddFieldPathIndex {
             target: SchemaFieldPathIndexRebuildTarget {
@@ -205,6 +291,7 @@ impl SchemaMutationRequest<'_> {
                 unique: index.unique(),
                 predicate_sql: index.predicate_sql().map(str::to_string),
                 key_paths,
+                include_paths,
             },
         })
     }
@@ -242,6 +329,21 @@ impl SchemaMutationRequest<'_> {
                         },
                     ))
                 }
+                PersistedIndexKeyItemSnapshot::Scalar(expression) => {
+                    has_expression = true;
+                    SchemaExpressionIndexRebuildKey::Scalar(Box::new(
+                        SchemaExpressionIndexRebuildScalar {
+                            sources: expression
+                                .sources()
+                                .iter()
+                                .map(field_path_rebuild_key)
+                                .collect(),
+                            expression_sql: expression.expression_sql().to_string(),
+                            canonical_text: expression.canonical_text().to_string(),
+                            expression: None,
+                        },
+                    ))
+                }
             })
             .collect();
 
@@ -377,6 +479,10 @@ fn ddl_drop_index_key_item_text(item: &PersistedIndexKeyItemSnapshot) -> String
             .canonical_text()
             .trim_start_matches("expr:v1:")
             .to_string(),
+        PersistedIndexKeyItemSnapshot::Scalar(expression) => expression
+            .canonical_text()
+            .trim_start_matches("expr:v1:")
+            .to_string(),
     }
 }
 
@@ -491,5 +597,6 @@ pub(super) fn field_path_rebuild_key(
         path: path.path().to_vec(),
         kind: path.kind().clone(),
         nullable: path.nullable(),
+        descending: path.descending(),
     }
 }
@@ -3,8 +3,8 @@
 use crate::db::schema::{
     AcceptedFieldKind, AcceptedSchemaSnapshot, PersistedIndexExpressionOp,
     PersistedIndexExpressionSnapshot, PersistedIndexFieldPathSnapshot,
-    PersistedIndexKeyItemSnapshot, PersistedIndexKeySnapshot, PersistedIndexSnapshot,
-    SchemaIndexId,
+    PersistedIndexKeyItemSnapshot, PersistedIndexKeySnapshot,
+    PersistedIndexScalarExpressionSnapshot, PersistedIndexSnapshot, SchemaIndexId,
 };
 
 /// Schema-owned outcome for resolving one SQL DDL secondary-index addition
@@ -32,6 +32,7 @@ pub(in crate::db) enum SchemaDdlSecondaryIndexAdditionCandidateError {
 pub(in crate::db) enum SchemaDdlSecondaryIndexKeyIntent {
     FieldPath(SchemaDdlSecondaryIndexFieldPathIntent),
     Expression(Box<SchemaDdlSecondaryIndexExpressionIntent>),
+    Scalar(Box<SchemaDdlSecondaryIndexScalarIntent>),
 }
 
 /// Schema-owned SQL DDL deterministic-expression operation intent.
@@ -40,6 +41,8 @@ pub(in crate::db) enum SchemaDdlSecondaryIndexExpressionOpIntent {
     Lower,
     Upper,
     Trim,
+    Tokens,
+    Elements,
 }
 
 /// Schema-owned field-path intent for one SQL DDL index key item.
@@ -47,13 +50,30 @@ pub(in crate::db) enum SchemaDdlSecondaryIndexExpressionOpIntent {
 pub(in crate::db) struct SchemaDdlSecondaryIndexFieldPathIntent {
     root: String,
     segments: Vec<String>,
+    descending: bool,
 }
 
 impl SchemaDdlSecondaryIndexFieldPathIntent {
     /// Build one SQL DDL field-path index key intent.
     #[must_use]
     pub(in crate::db) const fn new(root: String, segments: Vec<String>) -> Self {
-        Self { root, segments }
+        Self {
+            root,
+            segments,
+            descending: false,
+        }
+    }
+
+    /// Mark this key component as stored in descending order.
+    #[must_use]
+    pub(in crate::db) const fn with_descending(mut self, descending: bool) -> Self {
+        self.descending = descending;
+        self
+    }
+
+    #[must_use]
+    pub(in crate::db) const fn descending(&self) -> bool {
+        self.descending
     }
 
     #[must_use]
@@ -107,6 +127,45 @@ impl SchemaDdlSecondaryIndexExpressionIntent {
     }
 }
 
+/// Schema-owned scalar-expression intent for one SQL DDL index key item.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(in crate::db) struct SchemaDdlSecondaryIndexScalarIntent {
+    sources: Vec<SchemaDdlSecondaryIndexFieldPathIntent>,
+    expression_sql: String,
+    canonical_sql: String,
+}
+
+impl SchemaDdlSecondaryIndexScalarIntent {
+    /// Build one SQL DDL scalar-expression index key intent.
+    #[must_use]
+    pub(in crate::db) const fn new(
+        sources: Vec<SchemaDdlSecondaryIndexFieldPathIntent>,
+        expression_sql: String,
+        canonical_sql: String,
+    ) -> Self {
+        Self {
+            sources,
+            expression_sql,
+            canonical_sql,
+        }
+    }
+
+    #[must_use]
+    pub(in crate::db) const fn sources(&self) -> &[SchemaDdlSecondaryIndexFieldPathIntent] {
+        self.sources.as_slice()
+    }
+
+    #[must_use]
+    pub(in crate::db) const fn expression_sql(&self) -> &str {
+        self.expression_sql.as_str()
+    }
+
+    #[must_use]
+    pub(in crate::db) const fn canonical_sql(&self) -> &str {
+        self.canonical_sql.as_str()
+    }
+}
+
 /// Secondary-index key candidate construction failures for SQL DDL-authored
 /// schema mutations.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -171,9 +230,14 @@ pub(in crate::db) fn build_sql_ddl_secondary_index_candidate(
     store: String,
     unique: bool,
     key_items: &[SchemaDdlSecondaryIndexKeyIntent],
+    include: &[SchemaDdlSecondaryIndexFieldPathIntent],
     predicate_sql: Option<String>,
 ) -> Result<PersistedIndexSnapshot, SchemaDdlSecondaryIndexKeyCandidateError> {
     let key = sql_ddl_secondary_index_key_snapshot(accepted_before, key_items)?;
+    let include = include
+        .iter()
+        .map(|field_path| sql_ddl_index_field_path_snapshot(accepted_before, field_path))
+        .collect::<Result<Vec<_>, _>>()?;
     let schema_id = next_sql_ddl_schema_index_id(accepted_before)?;
     let ordinal = next_sql_ddl_secondary_index_ordinal(accepted_before)?;
 
@@ -185,7 +249,8 @@ pub(in crate::db) fn build_sql_ddl_secondary_index_candidate(
         unique,
         key,
         predicate_sql,
-    ))
+    )
+    .with_include(include))
 }
 
 fn next_sql_ddl_schema_index_id(
@@ -259,6 +324,9 @@ fn sql_ddl_secondary_index_key_snapshot(
             SchemaDdlSecondaryIndexKeyIntent::Expression(expression) => {
                 sql_ddl_index_expression_snapshot(accepted_before, expression)
             }
+            SchemaDdlSecondaryIndexKeyIntent::Scalar(expression) => {
+                sql_ddl_index_scalar_expression_snapshot(accepted_before, expression)
+            }
         })
         .collect::<Result<Vec<_>, _>>()
         .map(PersistedIndexKeySnapshot::Items)
@@ -282,29 +350,37 @@ fn sql_ddl_index_field_path_snapshot(
     let (kind, nullable) = if field_path.segments().is_empty() {
         (field.kind(), field.nullable())
     } else {
-        let leaf = field
+        field
             .nested_leaves()
             .iter()
             .find(|leaf| leaf.path() == field_path.segments())
+            .map(|leaf| (leaf.kind(), leaf.nullable()))
+            .or_else(|| {
+                field
+                    .map_key_value_kind(field_path.segments())
+                    .map(|kind| (kind, true))
+            })
             .ok_or_else(|| {
                 SchemaDdlSecondaryIndexKeyCandidateError::FieldPathNotAcceptedCatalogBacked {
                     field_path: sql_ddl_index_field_path_text(field_path),
                 }
-            })?;
-        (leaf.kind(), leaf.nullable())
+            })?
     };
 
     let mut path = Vec::with_capacity(field_path.segments().len() + 1);
     path.push(field_path.root().to_string());
     path.extend(field_path.segments().iter().cloned());
 
-    Ok(PersistedIndexFieldPathSnapshot::new(
-        field.id(),
-        field.slot(),
-        path,
-        kind.clone(),
-        nullable,
-    ))
+    Ok(
+        PersistedIndexFieldPathSnapshot::new(
+            field.id(),
+            field.slot(),
+            path,
+            kind.clone(),
+            nullable,
+        )
+        .with_descending(field_path.descending()),
+    )
 }
 
 fn sql_ddl_index_expression_snapshot(
@@ -333,6 +409,25 @@ fn sql_ddl_index_expression_snapshot(
     )))
 }
 
+fn sql_ddl_index_scalar_expression_snapshot(
+    accepted_before: &AcceptedSchemaSnapshot,
+    expression: &SchemaDdlSecondaryIndexScalarIntent,
+) -> Result<PersistedIndexKeyItemSnapshot, SchemaDdlSecondaryIndexKeyCandidateError> {
+    let sources = expression
+        .sources()
+        .iter()
+        .map(|source| sql_ddl_index_field_path_snapshot(accepted_before, source))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(PersistedIndexKeyItemSnapshot::Scalar(Box::new(
+        PersistedIndexScalarExpressionSnapshot::new(
+            sources,
+            expression.expression_sql().to_string(),
+            format!("expr:v1:{}", expression.canonical_sql()),
+        ),
+    )))
+}
+
 const fn persisted_expression_op_for_sql_ddl_intent(
     intent: SchemaDdlSecondaryIndexExpressionOpIntent,
 ) -> PersistedIndexExpressionOp {
@@ -340,6 +435,8 @@ const fn persisted_expression_op_for_sql_ddl_intent(
         SchemaDdlSecondaryIndexExpressionOpIntent::Lower => PersistedIndexExpressionOp::Lower,
         SchemaDdlSecondaryIndexExpressionOpIntent::Upper => PersistedIndexExpressionOp::Upper,
         SchemaDdlSecondaryIndexExpressionOpIntent::Trim => PersistedIndexExpressionOp::Trim,
+        SchemaDdlSecondaryIndexExpressionOpIntent::Tokens => PersistedIndexExpressionOp::Tokens,
+        SchemaDdlSecondaryIndexExpressionOpIntent::Elements => PersistedIndexExpressionOp::Elements,
     }
 }
 
@@ -380,6 +477,19 @@ fn sql_ddl_index_expression_output_kind(
                 None
             }
         }
+        PersistedIndexExpressionOp::Tokens => {
+            if matches!(source_kind, AcceptedFieldKind::Text { .. }) {
+                Some(AcceptedFieldKind::Text { max_len: None })
+            } else {
+                None
+            }
+        }
+        PersistedIndexExpressionOp::Elements => match source_kind {
+            AcceptedFieldKind::List(item) | AcceptedFieldKind::Set(item) => {
+                Some(item.as_ref().clone())
+            }
+            _ => None,
+        },
     }
 }
 
@@ -402,5 +512,7 @@ fn secondary_index_duplicate_contract_match(
     existing: &PersistedIndexSnapshot,
     candidate: &PersistedIndexSnapshot,
 ) -> bool {
-    existing.predicate_sql() == candidate.predicate_sql() && existing.key() == candidate.key()
+    existing.predicate_sql() == candidate.predicate_sql()
+        && existing.key() == candidate.key()
+        && existing.include() == candidate.include()
 }
@@ -8,6 +8,8 @@ use crate::db::schema::PersistedFieldSnapshot;
 mod budget;
 #[cfg(any(test, feature = "sql"))]
 pub(in crate::db) use budget::MAX_SCHEMA_PROJECTION_ENTRIES;
+#[cfg(feature = "sql")]
+pub(in crate::db) use budget::MAX_SCHEMA_SOURCE_ROWS;
 #[cfg(any(test, feature = "sql"))]
 pub(in crate::db) use budget::SchemaTransitionSourceBudget;
 pub(in crate::db) use budget::{MAX_SCHEMA_PROJECTION_WORK_UNITS, MAX_SCHEMA_STAGED_RAW_BYTES};
@@ -75,8 +77,8 @@ pub(in crate::db) use index_candidate::{
     SchemaDdlSecondaryIndexAdditionCandidate, SchemaDdlSecondaryIndexAdditionCandidateError,
     SchemaDdlSecondaryIndexExpressionIntent, SchemaDdlSecondaryIndexExpressionOpIntent,
     SchemaDdlSecondaryIndexFieldPathIntent, SchemaDdlSecondaryIndexKeyCandidateError,
-    SchemaDdlSecondaryIndexKeyIntent, build_sql_ddl_secondary_index_candidate,
-    resolve_sql_ddl_secondary_index_addition_candidate,
+    SchemaDdlSecondaryIndexKeyIntent, SchemaDdlSecondaryIndexScalarIntent,
+    build_sql_ddl_secondary_index_candidate, resolve_sql_ddl_secondary_index_addition_candidate,
 };
 
 mod index;
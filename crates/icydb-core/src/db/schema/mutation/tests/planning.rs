@@ -666,6 +666,7 @@ fn sql_ddl_index_identity_exhaustion_is_typed_and_fail_closed() {
         "test::mutation::next_index".to_string(),
         false,
         &[key],
+        &[],
         None,
     )
     .expect_err("logical index identity exhaustion must reject instead of panicking");
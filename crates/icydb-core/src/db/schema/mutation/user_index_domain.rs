@@ -18,13 +18,13 @@ use crate::{
         data::{CanonicalSlotReader, StructuralRowContract},
         index::{
             IndexEntryValue, IndexKey, IndexKeyKind, IndexState, IndexStore, IndexStoreVisit,
-            RawIndexStoreKey,
+            RawIndexStoreKey, field_path_rebuild_entry_value_from_slots,
         },
         key_taxonomy::PrimaryKeyValue,
         predicate::{PredicateProgram, normalize, parse_sql_predicate},
         schema::{
-            MAX_SCHEMA_PROJECTION_WORK_UNITS, MAX_SCHEMA_STAGED_RAW_BYTES,
-            SchemaExpressionIndexRebuildTarget, SchemaFieldPathIndexRebuildTarget,
+            AcceptedSchemaSnapshot, MAX_SCHEMA_PROJECTION_WORK_UNITS, MAX_SCHEMA_STAGED_RAW_BYTES,
+            SchemaExpressionIndexRebuildTarget, SchemaFieldPathIndexRebuildTarget, SchemaInfo,
             mutation::SchemaMutationRequest,
         },
     },
@@ -104,7 +104,7 @@ impl StagedUserIndexDomainEntry {
     }
 
     /// Borrow the prevalidated raw store value.
-    #[cfg(test)]
+    #[cfg(any(test, feature = "sql"))]
     #[must_use]
     pub(in crate::db) const fn value(&self) -> &IndexEntryValue {
         &self.value
@@ -511,6 +511,7 @@ struct PreparedUserIndex {
 impl PreparedUserIndex {
     fn from_accepted_index(
         index: &crate::db::schema::PersistedIndexSnapshot,
+        schema: Option<&crate::db::schema::PersistedSchemaSnapshot>,
         predicate_row_contract: Option<&StructuralRowContract>,
     ) -> Result<Self, StagedUserIndexDomainError> {
         let request = if index.key().is_field_path_only() {
@@ -524,7 +525,11 @@ impl PreparedUserIndex {
                 PreparedUserIndexTarget::FieldPath(target)
             }
             SchemaMutationRequest::AddExpressionIndex { target } => {
-                PreparedUserIndexTarget::Expression(target)
+                PreparedUserIndexTarget::Expression(bind_scalar_key_expressions(
+                    target,
+                    schema,
+                    predicate_row_contract,
+                ))
             }
             SchemaMutationRequest::ExactMatch | SchemaMutationRequest::AppendOnlyFields(_) => {
                 return Err(StagedUserIndexDomainError::UnsupportedAcceptedIndex);
@@ -549,18 +554,18 @@ impl PreparedUserIndex {
         Ok(Self { target, predicate })
     }
 
-    fn derive_key(
+    fn derive_keys(
         &self,
         entity_tag: EntityTag,
         row: &SchemaUserIndexDomainRow<'_>,
         slots: &dyn CanonicalSlotReader,
-    ) -> Result<Option<IndexKey>, StagedUserIndexDomainError> {
+    ) -> Result<Vec<IndexKey>, StagedUserIndexDomainError> {
         if let Some(predicate) = self.predicate.as_ref()
             && !predicate
                 .eval_with_structural_slot_reader(slots)
                 .map_err(StagedUserIndexDomainError::PredicateEvaluation)?
         {
-            return Ok(None);
+            return Ok(Vec::new());
         }
 
         match &self.target {
@@ -571,9 +576,10 @@ impl PreparedUserIndex {
                     target,
                     slots,
                 )
+                .map(|key| key.into_iter().collect())
             }
             PreparedUserIndexTarget::Expression(target) => {
-                IndexKey::new_from_slots_with_expression_rebuild_target(
+                IndexKey::new_entries_from_slots_with_expression_rebuild_target(
                     entity_tag,
                     row.primary_key_value,
                     target,
@@ -583,6 +589,55 @@ impl PreparedUserIndex {
         }
         .map_err(StagedUserIndexDomainError::KeyDerivation)
     }
+
+    // Build the entry value every key derived for this row stores. Only
+    // field-path indexes carry `INCLUDE` payloads.
+    fn entry_value(
+        &self,
+        slots: &dyn CanonicalSlotReader,
+    ) -> Result<IndexEntryValue, StagedUserIndexDomainError> {
+        match &self.target {
+            PreparedUserIndexTarget::FieldPath(target) => {
+                field_path_rebuild_entry_value_from_slots(target, slots)
+                    .map_err(StagedUserIndexDomainError::KeyDerivation)
+            }
+            PreparedUserIndexTarget::Expression(_) => Ok(IndexEntryValue::presence()),
+        }
+    }
+
+    // Journaled constraint-validation and migration entries are key-only, so
+    // those lanes cannot reproduce covering payloads and fail closed.
+    const fn carries_included_payload(&self) -> bool {
+        match &self.target {
+            PreparedUserIndexTarget::FieldPath(target) => !target.include_paths.is_empty(),
+            PreparedUserIndexTarget::Expression(_) => false,
+        }
+    }
+}
+
+// Scalar key expressions bind against the accepted schema that owns the
+// index. Without that schema they stay unbound and key derivation fails closed.
+fn bind_scalar_key_expressions(
+    target: SchemaExpressionIndexRebuildTarget,
+    schema: Option<&crate::db::schema::PersistedSchemaSnapshot>,
+    row_contract: Option<&StructuralRowContract>,
+) -> SchemaExpressionIndexRebuildTarget {
+    if !target.has_scalar_key_items() {
+        return target;
+    }
+    let (Some(schema), Some(row_contract)) = (schema, row_contract) else {
+        return target;
+    };
+    let Ok(accepted) = AcceptedSchemaSnapshot::try_new(schema.clone()) else {
+        return target;
+    };
+    let schema_info = SchemaInfo::from_accepted_snapshot_and_catalog(
+        &accepted,
+        row_contract.accepted_value_catalog_handle().clone(),
+        false,
+    );
+
+    target.with_compiled_scalar_expressions(&schema_info)
 }
 
 /// One accepted-schema unique-index projection bound to an exact physical generation.
@@ -605,8 +660,11 @@ impl UniqueConstraintProjection {
         if !index.unique() || index.physical_generation() == 0 {
             return Err(InternalError::store_invariant());
         }
-        let prepared = PreparedUserIndex::from_accepted_index(index, Some(row_contract))
+        let prepared = PreparedUserIndex::from_accepted_index(index, None, Some(row_contract))
             .map_err(StagedUserIndexDomainError::into_internal_error)?;
+        if prepared.carries_included_payload() {
+            return Err(InternalError::index_unsupported());
+        }
         Ok(Self {
             entity_tag,
             prepared,
@@ -620,9 +678,12 @@ impl UniqueConstraintProjection {
         slots: &dyn CanonicalSlotReader,
     ) -> Result<Option<RawIndexStoreKey>, InternalError> {
         let row = SchemaUserIndexDomainRow::new(*primary_key, slots, slots, 0);
+        // Unique indexes are never multi-entry, so one row derives at most one key.
         self.prepared
-            .derive_key(self.entity_tag, &row, slots)
+            .derive_keys(self.entity_tag, &row, slots)
             .map_err(StagedUserIndexDomainError::into_internal_error)?
+            .into_iter()
+            .next()
             .map(|key| key.to_raw().map_err(|_| InternalError::index_invariant()))
             .transpose()
     }
@@ -643,14 +704,19 @@ pub(in crate::db) struct MigrationIndexProjection {
 impl MigrationIndexProjection {
     pub(in crate::db) fn new(
         entity_tag: EntityTag,
+        schema: &crate::db::schema::PersistedSchemaSnapshot,
         index: &crate::db::schema::PersistedIndexSnapshot,
         row_contract: &StructuralRowContract,
     ) -> Result<Self, InternalError> {
         if index.physical_generation() == 0 {
             return Err(InternalError::store_invariant());
         }
-        let prepared = PreparedUserIndex::from_accepted_index(index, Some(row_contract))
-            .map_err(StagedUserIndexDomainError::into_internal_error)?;
+        let prepared =
+            PreparedUserIndex::from_accepted_index(index, Some(schema), Some(row_contract))
+                .map_err(StagedUserIndexDomainError::into_internal_error)?;
+        if prepared.carries_included_payload() {
+            return Err(InternalError::index_unsupported());
+        }
         Ok(Self {
             entity_tag,
             index_id: crate::db::index::IndexId::new_with_generation(
@@ -667,17 +733,19 @@ impl MigrationIndexProjection {
         self.index_id
     }
 
-    pub(in crate::db) fn derive_key(
+    /// Derive every candidate key one validated canonical row owns.
+    pub(in crate::db) fn derive_keys(
         &self,
         primary_key: &crate::db::key_taxonomy::PrimaryKeyValue,
         slots: &dyn CanonicalSlotReader,
-    ) -> Result<Option<RawIndexStoreKey>, InternalError> {
+    ) -> Result<Vec<RawIndexStoreKey>, InternalError> {
         let row = SchemaUserIndexDomainRow::new(*primary_key, slots, slots, 0);
         self.prepared
-            .derive_key(self.entity_tag, &row, slots)
+            .derive_keys(self.entity_tag, &row, slots)
             .map_err(StagedUserIndexDomainError::into_internal_error)?
+            .into_iter()
             .map(|key| key.to_raw().map_err(|_| InternalError::index_invariant()))
-            .transpose()
+            .collect()
     }
 }
 
@@ -719,7 +787,11 @@ impl PreparedUserIndexProjection {
         let mut unique_index_ids = BTreeSet::new();
 
         for index in snapshot.indexes() {
-            let prepared = PreparedUserIndex::from_accepted_index(index, predicate_row_contract)?;
+            let prepared = PreparedUserIndex::from_accepted_index(
+                index,
+                Some(snapshot),
+                predicate_row_contract,
+            )?;
             if index.unique() {
                 unique_index_ids.insert(IndexId::new_with_generation(
                     entity_tag,
@@ -746,15 +818,22 @@ impl PreparedUserIndexProjection {
     ) -> Result<(), StagedUserIndexDomainError> {
         for index in &self.indexes {
             budget.consume_projection_work()?;
-            let Some(key) = index.derive_key(entity_tag, row, slots)? else {
+            let keys = index.derive_keys(entity_tag, row, slots)?;
+            if keys.is_empty() {
                 continue;
-            };
-            let key = key
-                .to_raw()
-                .map_err(|_| StagedUserIndexDomainError::KeyEncode)?;
-            let value = IndexEntryValue::presence();
-            budget.consume_projection_entry(key.as_bytes().len())?;
-            entries.push(StagedUserIndexDomainEntry { key, value });
+            }
+            let value = index.entry_value(slots)?;
+            for key in keys {
+                let key = key
+                    .to_raw()
+                    .map_err(|_| StagedUserIndexDomainError::KeyEncode)?;
+                let value = value.clone();
+                // The entry budget already counts the one-byte presence witness.
+                budget.consume_projection_entry(
+                    key.as_bytes().len() + value.len().saturating_sub(1),
+                )?;
+                entries.push(StagedUserIndexDomainEntry { key, value });
+            }
         }
 
         Ok(())
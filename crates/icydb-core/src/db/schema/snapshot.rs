@@ -365,6 +365,7 @@ impl PersistedSchemaSnapshot {
                     field.storage_decode(),
                     field.leaf_codec(),
                 )
+                .with_generated_expression(field.generated_expression().map(str::to_string))
             })
             .collect();
         let constraint_catalog = match activation.state() {
@@ -937,6 +938,7 @@ pub(in crate::db) struct PersistedIndexSnapshot {
     unique: bool,
     origin: PersistedIndexOrigin,
     key: PersistedIndexKeySnapshot,
+    include: Vec<PersistedIndexFieldPathSnapshot>,
     predicate_sql: Option<String>,
 }
 
@@ -961,6 +963,7 @@ impl PersistedIndexSnapshot {
             unique,
             origin: PersistedIndexOrigin::Generated,
             key,
+            include: Vec::new(),
             predicate_sql,
         }
     }
@@ -985,10 +988,21 @@ impl PersistedIndexSnapshot {
             unique,
             origin: PersistedIndexOrigin::SqlDdl,
             key,
+            include: Vec::new(),
             predicate_sql,
         }
     }
 
+    /// Attach non-key payload columns stored in every index entry.
+    #[must_use]
+    pub(in crate::db) fn with_include(
+        mut self,
+        include: Vec<PersistedIndexFieldPathSnapshot>,
+    ) -> Self {
+        self.include = include;
+        self
+    }
+
     /// Return the accepted stable logical index-definition identity.
     #[must_use]
     pub(in crate::db) const fn schema_id(&self) -> SchemaIndexId {
@@ -1012,6 +1026,7 @@ impl PersistedIndexSnapshot {
             unique: self.unique,
             origin: self.origin,
             key: self.key.clone(),
+            include: self.include.clone(),
             predicate_sql: self.predicate_sql.clone(),
         }
     }
@@ -1064,6 +1079,12 @@ impl PersistedIndexSnapshot {
         &self.key
     }
 
+    /// Borrow accepted non-key payload columns in entry payload order.
+    #[must_use]
+    pub(in crate::db) const fn include(&self) -> &[PersistedIndexFieldPathSnapshot] {
+        self.include.as_slice()
+    }
+
     /// Borrow optional schema-declared predicate SQL display metadata.
     #[must_use]
     pub(in crate::db) const fn predicate_sql(&self) -> Option<&str> {
@@ -1075,12 +1096,14 @@ impl PersistedIndexSnapshot {
 
     /// Return whether this index depends on one accepted field.
     ///
-    /// Both key components and filtered-index predicates participate. A
+    /// Key components, included payload columns, and filtered-index
+    /// predicates participate. A
     /// malformed accepted predicate fails closed as a dependency because a
     /// metadata-only field mutation must not risk stale physical index state.
     #[cfg(any(test, feature = "sql"))]
     pub(in crate::db) fn references_field(&self, field_id: FieldId, field_name: &str) -> bool {
         self.key.references_field(field_id)
+            || self.include.iter().any(|path| path.field_id() == field_id)
             || self.predicate_sql().is_some_and(|predicate_sql| {
                 sql_predicate_references_field_root(predicate_sql, field_name).unwrap_or(true)
             })
@@ -1114,6 +1137,11 @@ impl PersistedIndexSnapshot {
             key: self
                 .key
                 .clone_with_renamed_field_path_root(field_id, new_name),
+            include: self
+                .include
+                .iter()
+                .map(|path| path.clone_with_renamed_root(field_id, new_name))
+                .collect(),
             predicate_sql,
         })
     }
@@ -1139,6 +1167,11 @@ impl PersistedIndexSnapshot {
             key: self
                 .key
                 .clone_with_top_level_field_nullability(field_id, nullable),
+            include: self
+                .include
+                .iter()
+                .map(|path| path.clone_with_top_level_nullability(field_id, nullable))
+                .collect(),
             predicate_sql: self.predicate_sql.clone(),
         }
     }
@@ -1159,6 +1192,11 @@ impl PersistedIndexSnapshot {
             unique: self.unique,
             origin: self.origin,
             key: self.key.clone_with_mapped_field_layout(map)?,
+            include: self
+                .include
+                .iter()
+                .map(|path| path.clone_with_mapped_field_layout(map))
+                .collect::<Option<Vec<_>>>()?,
             predicate_sql: self.predicate_sql.clone(),
         })
     }
@@ -1212,6 +1250,31 @@ impl PersistedIndexKeySnapshot {
         }
     }
 
+    /// Return component positions whose ordered segments sort in descending
+    /// order. Only field-path-only keys carry descending components.
+    #[must_use]
+    pub(in crate::db) fn descending_positions(&self) -> Vec<usize> {
+        self.field_paths()
+            .iter()
+            .enumerate()
+            .filter_map(|(position, path)| path.descending().then_some(position))
+            .collect()
+    }
+
+    /// Mark the supplied component positions as descending. Returns `None`
+    /// when a position is out of range or the key carries expression items.
+    #[must_use]
+    pub(in crate::db) fn with_descending_positions(mut self, positions: &[usize]) -> Option<Self> {
+        let Self::FieldPath(paths) = &mut self else {
+            return None;
+        };
+        for &position in positions {
+            paths.get_mut(position)?.descending = true;
+        }
+
+        Some(self)
+    }
+
     /// Return whether any key component references the accepted field ID.
     #[must_use]
     pub(in crate::db) fn references_field(&self, field_id: FieldId) -> bool {
@@ -1295,6 +1358,7 @@ impl PersistedIndexKeySnapshot {
 pub(in crate::db) enum PersistedIndexKeyItemSnapshot {
     FieldPath(PersistedIndexFieldPathSnapshot),
     Expression(Box<PersistedIndexExpressionSnapshot>),
+    Scalar(Box<PersistedIndexScalarExpressionSnapshot>),
 }
 
 impl PersistedIndexKeyItemSnapshot {
@@ -1304,6 +1368,10 @@ impl PersistedIndexKeyItemSnapshot {
         match self {
             Self::FieldPath(path) => path.field_id() == field_id,
             Self::Expression(expression) => expression.source().field_id() == field_id,
+            Self::Scalar(expression) => expression
+                .sources()
+                .iter()
+                .any(|source| source.field_id() == field_id),
         }
     }
 
@@ -1322,6 +1390,9 @@ impl PersistedIndexKeyItemSnapshot {
             Self::Expression(expression) => Self::Expression(Box::new(
                 expression.clone_with_renamed_source_root(field_id, new_name),
             )),
+            Self::Scalar(expression) => Self::Scalar(Box::new(
+                expression.clone_with_renamed_source_root(field_id, new_name),
+            )),
         }
     }
 
@@ -1334,6 +1405,9 @@ impl PersistedIndexKeyItemSnapshot {
             Self::Expression(expression) => Self::Expression(Box::new(
                 expression.clone_with_top_level_source_nullability(field_id, nullable),
             )),
+            Self::Scalar(expression) => Self::Scalar(Box::new(
+                expression.clone_with_top_level_source_nullability(field_id, nullable),
+            )),
         }
     }
 
@@ -1349,6 +1423,10 @@ impl PersistedIndexKeyItemSnapshot {
                 .clone_with_mapped_field_layout(map)
                 .map(Box::new)
                 .map(Self::Expression),
+            Self::Scalar(expression) => expression
+                .clone_with_mapped_field_layout(map)
+                .map(Box::new)
+                .map(Self::Scalar),
         }
     }
 }
@@ -1368,6 +1446,7 @@ pub(in crate::db) struct PersistedIndexFieldPathSnapshot {
     path: Vec<String>,
     kind: AcceptedFieldKind,
     nullable: bool,
+    descending: bool,
 }
 
 impl PersistedIndexFieldPathSnapshot {
@@ -1386,9 +1465,17 @@ impl PersistedIndexFieldPathSnapshot {
             path,
             kind,
             nullable,
+            descending: false,
         }
     }
 
+    /// Mark whether this key item's ordered segment sorts in descending order.
+    #[must_use]
+    pub(in crate::db) const fn with_descending(mut self, descending: bool) -> Self {
+        self.descending = descending;
+        self
+    }
+
     /// Return the accepted top-level field identity.
     #[must_use]
     pub(in crate::db) const fn field_id(&self) -> FieldId {
@@ -1419,6 +1506,13 @@ impl PersistedIndexFieldPathSnapshot {
         self.nullable
     }
 
+    /// Return whether this key item's ordered segment is byte-inverted so the
+    /// index stores it in descending order.
+    #[must_use]
+    pub(in crate::db) const fn descending(&self) -> bool {
+        self.descending
+    }
+
     /// Clone this key item with a renamed top-level accepted field label when
     /// it targets the supplied durable field ID.
     #[must_use]
@@ -1437,6 +1531,7 @@ impl PersistedIndexFieldPathSnapshot {
             path,
             kind: self.kind.clone(),
             nullable: self.nullable,
+            descending: self.descending,
         }
     }
 
@@ -1452,6 +1547,7 @@ impl PersistedIndexFieldPathSnapshot {
             } else {
                 self.nullable
             },
+            descending: self.descending,
         }
     }
 
@@ -1466,6 +1562,7 @@ impl PersistedIndexFieldPathSnapshot {
             path: self.path.clone(),
             kind: self.kind.clone(),
             nullable: self.nullable,
+            descending: self.descending,
         })
     }
 }
@@ -1584,6 +1681,99 @@ impl PersistedIndexExpressionSnapshot {
     }
 }
 
+///
+/// PersistedIndexScalarExpressionSnapshot
+///
+/// Accepted key-item metadata for one arbitrary deterministic scalar
+/// expression component. The expression SQL is rebound against the accepted
+/// schema to derive keys; the canonical text is the normalized fingerprint the
+/// planner matches query expressions against.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(in crate::db) struct PersistedIndexScalarExpressionSnapshot {
+    sources: Vec<PersistedIndexFieldPathSnapshot>,
+    expression_sql: String,
+    canonical_text: String,
+}
+
+impl PersistedIndexScalarExpressionSnapshot {
+    /// Build one accepted scalar expression key-item snapshot.
+    #[must_use]
+    pub(in crate::db) const fn new(
+        sources: Vec<PersistedIndexFieldPathSnapshot>,
+        expression_sql: String,
+        canonical_text: String,
+    ) -> Self {
+        Self {
+            sources,
+            expression_sql,
+            canonical_text,
+        }
+    }
+
+    /// Borrow the accepted top-level source fields in stable slot order.
+    #[must_use]
+    pub(in crate::db) const fn sources(&self) -> &[PersistedIndexFieldPathSnapshot] {
+        self.sources.as_slice()
+    }
+
+    /// Borrow the accepted expression SQL rebound for key derivation.
+    #[must_use]
+    pub(in crate::db) const fn expression_sql(&self) -> &str {
+        self.expression_sql.as_str()
+    }
+
+    /// Borrow the accepted canonical expression text.
+    #[must_use]
+    pub(in crate::db) const fn canonical_text(&self) -> &str {
+        self.canonical_text.as_str()
+    }
+
+    // Expression SQL names its sources, so SQL DDL rejects renaming them; the
+    // source labels still follow the rename to keep catalog paths coherent.
+    #[cfg(any(test, feature = "sql", feature = "migration"))]
+    fn clone_with_renamed_source_root(&self, field_id: FieldId, new_name: &str) -> Self {
+        Self {
+            sources: self
+                .sources
+                .iter()
+                .map(|source| source.clone_with_renamed_root(field_id, new_name))
+                .collect(),
+            expression_sql: self.expression_sql.clone(),
+            canonical_text: self.canonical_text.clone(),
+        }
+    }
+
+    #[cfg(any(test, feature = "sql", feature = "migration"))]
+    fn clone_with_top_level_source_nullability(&self, field_id: FieldId, nullable: bool) -> Self {
+        Self {
+            sources: self
+                .sources
+                .iter()
+                .map(|source| source.clone_with_top_level_nullability(field_id, nullable))
+                .collect(),
+            expression_sql: self.expression_sql.clone(),
+            canonical_text: self.canonical_text.clone(),
+        }
+    }
+
+    fn clone_with_mapped_field_layout(
+        &self,
+        map: impl Copy + Fn(FieldId, SchemaFieldSlot) -> Option<(FieldId, SchemaFieldSlot)>,
+    ) -> Option<Self> {
+        Some(Self {
+            sources: self
+                .sources
+                .iter()
+                .map(|source| source.clone_with_mapped_field_layout(map))
+                .collect::<Option<Vec<_>>>()?,
+            expression_sql: self.expression_sql.clone(),
+            canonical_text: self.canonical_text.clone(),
+        })
+    }
+}
+
 #[cfg(any(test, feature = "sql", feature = "migration"))]
 fn canonical_expression_text_for_path(op: PersistedIndexExpressionOp, path: &[String]) -> String {
     let path = path.join(".");
@@ -1596,6 +1786,8 @@ fn canonical_expression_text_for_path(op: PersistedIndexExpressionOp, path: &[St
         PersistedIndexExpressionOp::Year => format!("expr:v1:YEAR({path})"),
         PersistedIndexExpressionOp::Month => format!("expr:v1:MONTH({path})"),
         PersistedIndexExpressionOp::Day => format!("expr:v1:DAY({path})"),
+        PersistedIndexExpressionOp::Tokens => format!("expr:v1:TOKENS({path})"),
+        PersistedIndexExpressionOp::Elements => format!("expr:v1:ELEMENTS({path})"),
     }
 }
 
@@ -1617,6 +1809,17 @@ pub(in crate::db) enum PersistedIndexExpressionOp {
     Year,
     Month,
     Day,
+    Tokens,
+    Elements,
+}
+
+impl PersistedIndexExpressionOp {
+    /// Return whether this operation derives one index entry per text token
+    /// or collection element instead of exactly one key component per row.
+    #[must_use]
+    pub(in crate::db) const fn is_multi_entry(self) -> bool {
+        matches!(self, Self::Tokens | Self::Elements)
+    }
 }
 
 ///
@@ -1652,6 +1855,7 @@ pub(in crate::db) struct PersistedFieldSnapshot {
     insert_default: SchemaInsertDefault,
     historical_fill: SchemaHistoricalFill,
     write_policy: SchemaFieldWritePolicy,
+    generated_expression: Option<String>,
     origin: PersistedFieldOrigin,
     storage_decode: FieldStorageDecode,
     leaf_codec: LeafCodec,
@@ -1798,12 +2002,23 @@ impl PersistedFieldSnapshot {
             insert_default,
             historical_fill,
             write_policy,
+            generated_expression: None,
             origin,
             storage_decode,
             leaf_codec,
         }
     }
 
+    /// Return this field with its accepted generated-column SQL expression.
+    #[must_use]
+    pub(in crate::db) fn with_generated_expression(
+        mut self,
+        generated_expression: Option<String>,
+    ) -> Self {
+        self.generated_expression = generated_expression;
+        self
+    }
+
     /// Return the durable field identity.
     #[must_use]
     pub(in crate::db) const fn id(&self) -> FieldId {
@@ -1834,6 +2049,27 @@ impl PersistedFieldSnapshot {
         self.nested_leaves.as_slice()
     }
 
+    /// Borrow the value kind addressed by one fixed key of this field when it
+    /// is a text-keyed map. Absent keys read as `NULL`, so the path is always
+    /// nullable.
+    #[must_use]
+    pub(in crate::db) fn map_key_value_kind(
+        &self,
+        relative_path: &[String],
+    ) -> Option<&AcceptedFieldKind> {
+        let [_] = relative_path else {
+            return None;
+        };
+        match &self.kind {
+            AcceptedFieldKind::Map { key, value }
+                if matches!(key.as_ref(), AcceptedFieldKind::Text { .. }) =>
+            {
+                Some(value.as_ref())
+            }
+            _ => None,
+        }
+    }
+
     /// Return whether this field permits explicit persisted `NULL`.
     #[must_use]
     pub(in crate::db) const fn nullable(&self) -> bool {
@@ -1875,6 +2111,7 @@ impl PersistedFieldSnapshot {
             insert_default,
             historical_fill: self.historical_fill.clone(),
             write_policy: self.write_policy,
+            generated_expression: self.generated_expression.clone(),
             origin: self.origin,
             storage_decode: self.storage_decode,
             leaf_codec: self.leaf_codec,
@@ -1896,6 +2133,7 @@ impl PersistedFieldSnapshot {
             insert_default: self.insert_default.clone(),
             historical_fill: self.historical_fill.clone(),
             write_policy: self.write_policy,
+            generated_expression: self.generated_expression.clone(),
             origin: self.origin,
             storage_decode: self.storage_decode,
             leaf_codec: self.leaf_codec,
@@ -1922,6 +2160,7 @@ impl PersistedFieldSnapshot {
             insert_default: self.insert_default.clone(),
             historical_fill: self.historical_fill.clone(),
             write_policy: self.write_policy,
+            generated_expression: self.generated_expression.clone(),
             origin: self.origin,
             storage_decode: self.storage_decode,
             leaf_codec: self.leaf_codec,
@@ -1942,6 +2181,7 @@ impl PersistedFieldSnapshot {
             insert_default: self.insert_default.clone(),
             historical_fill: self.historical_fill.clone(),
             write_policy: self.write_policy,
+            generated_expression: self.generated_expression.clone(),
             origin: self.origin,
             storage_decode: self.storage_decode,
             leaf_codec: self.leaf_codec,
@@ -1970,6 +2210,13 @@ impl PersistedFieldSnapshot {
         self.write_policy
     }
 
+    /// Borrow the accepted generated-column SQL expression, when this field
+    /// is computed from the other fields of its row.
+    #[must_use]
+    pub(in crate::db) fn generated_expression(&self) -> Option<&str> {
+        self.generated_expression.as_deref()
+    }
+
     /// Return the durable field origin.
     #[must_use]
     pub(in crate::db) const fn origin(&self) -> PersistedFieldOrigin {
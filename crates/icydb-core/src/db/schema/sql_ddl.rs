@@ -4,19 +4,29 @@ mod user_index_domain;
 
 use crate::{
     db::{
+        data::{
+            AcceptedMutationIntentPatch, RawDataStoreKey, StoreVisit,
+            resolve_update_structural_patch_with_accepted_contract,
+        },
+        key_taxonomy::RawDataStoreKeyRange,
         registry::StoreHandle,
         schema::{
-            AcceptedCatalogIdentity, AcceptedSchemaRevision, AcceptedSchemaRevisionBundle,
-            AcceptedSchemaSnapshot, CandidateSchemaRevision, MutationPublicationPreflight,
-            PersistedSchemaSnapshot, SchemaDdlAcceptedSnapshotDerivation, SchemaStore,
-            SchemaTransitionDecision, SchemaTransitionPlanKind, StagedUserIndexDomainReplacement,
-            decide_schema_transition, mutation::required_empty_entity_field_addition_matches,
+            AcceptedCatalogIdentity, AcceptedRowLayoutRuntimeContract, AcceptedSchemaRevision,
+            AcceptedSchemaRevisionBundle, AcceptedSchemaSnapshot, CandidateSchemaRevision,
+            CompiledAcceptedRowConstraints, MutationPublicationPreflight, PersistedSchemaSnapshot,
+            SchemaDdlAcceptedSnapshotDerivation, SchemaStore, SchemaTransitionDecision,
+            SchemaTransitionPlanKind, StagedUserIndexDomainReplacement,
+            accepted_schema_cache_fingerprint, decide_schema_transition,
+            mutation::required_empty_entity_field_addition_matches,
             transition::SchemaTransitionPlan,
         },
+        write_context::AcceptedWriteContext,
     },
-    error::InternalError,
-    types::EntityTag,
+    error::{InternalError, MutationDiagnosticContext},
+    types::{CurrentTimestamp, EntityTag, Timestamp},
 };
+use icydb_diagnostic_code::DiagnosticMutationOperation;
+use std::ops::Bound;
 use user_index_domain::stage_sql_ddl_user_index_domain_replacement;
 
 pub(in crate::db) use constraint::{
@@ -303,10 +313,82 @@ pub(in crate::db) fn execute_admin_sql_ddl_field_addition(
         )?;
         validate_publishable_transition_plan(entity_path, &plan)?;
     }
+    if added_field.generated_expression().is_some() {
+        validate_unpublished_generated_field_rows(
+            store,
+            entity_tag,
+            entity_path,
+            &accepted_before_identity,
+            derivation.accepted_after(),
+        )?;
+    }
 
     envelope.publish()
 }
 
+// Resolve every stored row through the assignment-free update that backfills
+// a new generated field, against the unpublished candidate schema. Any row
+// whose generated value or constraints fail rejects the addition before the
+// candidate becomes visible, so the backfill that follows publication cannot
+// strand a published column over stale rows.
+fn validate_unpublished_generated_field_rows(
+    store: StoreHandle,
+    entity_tag: EntityTag,
+    entity_path: &str,
+    accepted_before_identity: &AcceptedCatalogIdentity,
+    accepted_after: &AcceptedSchemaSnapshot,
+) -> Result<(), InternalError> {
+    let value_catalog = store
+        .with_schema(|schema_store| {
+            schema_store.current_accepted_catalog_selection(
+                entity_tag,
+                entity_path,
+                accepted_before_identity.store_path(),
+            )
+        })?
+        .ok_or_else(InternalError::store_corruption)?
+        .value_catalog_handle()
+        .clone();
+    let fingerprint = accepted_schema_cache_fingerprint(accepted_after)?;
+    let constraints =
+        CompiledAcceptedRowConstraints::compile(accepted_after, &value_catalog, fingerprint)
+            .map_err(|_| InternalError::accepted_row_constraint_program_corrupt())?;
+    let row_decode_contract =
+        AcceptedRowLayoutRuntimeContract::from_accepted_schema(accepted_after)?
+            .row_decode_contract(value_catalog);
+    let write_context = AcceptedWriteContext::new(Timestamp::now());
+    let patch = AcceptedMutationIntentPatch::new();
+    let range = RawDataStoreKeyRange::entity_prefix(entity_tag);
+    let lower = Bound::Included(RawDataStoreKey::store_range_lower_key(&range));
+    let upper = range
+        .upper_exclusive()
+        .map(RawDataStoreKey::from_store_range_bound)
+        .map_or(Bound::Unbounded, Bound::Excluded);
+    let mut batch_position = 0u32;
+
+    store.with_data(|data| {
+        data.visit_range((lower, upper), |_, raw_row| {
+            resolve_update_structural_patch_with_accepted_contract(
+                entity_path,
+                row_decode_contract.clone(),
+                fingerprint,
+                &constraints,
+                raw_row,
+                &patch,
+                write_context,
+                MutationDiagnosticContext::new(
+                    entity_tag.value(),
+                    DiagnosticMutationOperation::Update,
+                    batch_position,
+                ),
+            )?;
+            batch_position = batch_position.saturating_add(1);
+
+            Ok::<_, InternalError>(StoreVisit::Continue)
+        })
+    })
+}
+
 /// Require an exact empty-entity proof for one current physical-shape transition.
 /// Missing or invalid cardinality metadata is conservatively nonempty.
 pub(super) fn require_exact_empty_sql_ddl_entity(
@@ -83,6 +83,8 @@ pub(in crate::db) enum FieldInsertGeneration {
     Ulid,
     /// Generate the operation timestamp.
     Timestamp,
+    /// Recompute the accepted row-local generated expression on every write.
+    Computed,
 }
 
 ///
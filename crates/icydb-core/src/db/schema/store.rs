@@ -4339,6 +4339,15 @@ fn hash_persisted_index_key(hasher: &mut sha2::Sha256, key: &PersistedIndexKeySn
                         hash_accepted_field_kind(hasher, expression.output_kind());
                         write_hash_str_u32(hasher, expression.canonical_text());
                     }
+                    PersistedIndexKeyItemSnapshot::Scalar(expression) => {
+                        write_hash_tag_u8(hasher, 3);
+                        write_hash_len_u32(hasher, expression.sources().len());
+                        for source in expression.sources() {
+                            hash_persisted_index_field_path(hasher, source);
+                        }
+                        write_hash_str_u32(hasher, expression.expression_sql());
+                        write_hash_str_u32(hasher, expression.canonical_text());
+                    }
                 }
             }
         }
@@ -4473,6 +4482,8 @@ const fn persisted_expression_op_name(
         crate::db::schema::PersistedIndexExpressionOp::Year => "year",
         crate::db::schema::PersistedIndexExpressionOp::Month => "month",
         crate::db::schema::PersistedIndexExpressionOp::Day => "day",
+        crate::db::schema::PersistedIndexExpressionOp::Tokens => "tokens",
+        crate::db::schema::PersistedIndexExpressionOp::Elements => "elements",
     }
 }
 
@@ -332,6 +332,7 @@ fn index_contract_matches_ignoring_name(
         && actual.store() == expected.store()
         && actual.unique() == expected.unique()
         && actual.key() == expected.key()
+        && actual.include() == expected.include()
         && actual.predicate_sql() == expected.predicate_sql()
 }
 
@@ -562,6 +562,65 @@ pub(in crate::db) fn input_value_from_strict_sql_literal_for_persisted_kind(
         .flatten()
 }
 
+/// Target-type one computed scalar expression result against accepted
+/// persisted metadata.
+///
+/// Checked scalar arithmetic widens numeric results to `Decimal`, so integral
+/// decimals narrow back to the integer family before the strict literal
+/// admission used by authored values. Fractional or out-of-range results fail
+/// closed instead of truncating. `NULL` stays the caller's nullability check.
+#[must_use]
+#[cfg(feature = "sql")]
+pub(in crate::db) fn input_value_from_computed_sql_value_for_persisted_kind(
+    kind: &AcceptedFieldKind,
+    value: &Value,
+) -> Option<InputValue> {
+    let narrowed = narrow_computed_decimal_for_persisted_kind(kind, value);
+    input_value_from_strict_sql_literal_for_persisted_kind(kind, narrowed.as_ref().unwrap_or(value))
+}
+
+#[cfg(feature = "sql")]
+fn narrow_computed_decimal_for_persisted_kind(
+    kind: &AcceptedFieldKind,
+    value: &Value,
+) -> Option<Value> {
+    let Value::Decimal(decimal) = value else {
+        return None;
+    };
+
+    match kind {
+        AcceptedFieldKind::Relation { key_kind, .. } => {
+            narrow_computed_decimal_for_persisted_kind(key_kind, value)
+        }
+        AcceptedFieldKind::Int8
+        | AcceptedFieldKind::Int16
+        | AcceptedFieldKind::Int32
+        | AcceptedFieldKind::Int64
+        | AcceptedFieldKind::Int128
+        | AcceptedFieldKind::IntBig { .. }
+        | AcceptedFieldKind::Nat8
+        | AcceptedFieldKind::Nat16
+        | AcceptedFieldKind::Nat32
+        | AcceptedFieldKind::Nat64
+        | AcceptedFieldKind::Nat128
+        | AcceptedFieldKind::NatBig { .. } => decimal
+            .to_i64()
+            .map(Value::Int64)
+            .or_else(|| decimal.to_u64().map(Value::Nat64))
+            .or_else(|| decimal.to_i128().map(Value::Int128))
+            .or_else(|| decimal.to_u128().map(Value::Nat128)),
+        AcceptedFieldKind::Float32 => decimal
+            .to_f32()
+            .and_then(Float32::try_new)
+            .map(Value::Float32),
+        AcceptedFieldKind::Float64 => decimal
+            .to_f64()
+            .and_then(Float64::try_new)
+            .map(Value::Float64),
+        _ => None,
+    }
+}
+
 #[cfg(any(test, feature = "sql"))]
 fn canonicalize_signed64_persisted_literal(
     kind: &AcceptedFieldKind,
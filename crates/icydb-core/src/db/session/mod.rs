@@ -48,20 +48,20 @@ pub use request::RequestExecutionRoot;
 pub(in crate::db) use request::RequestExecutionScope;
 pub(in crate::db) use response::finalize_structural_grouped_projection_result;
 pub(in crate::db) use response::grouped_cursor_from_bytes;
-#[cfg(all(feature = "sql", feature = "diagnostics"))]
-pub use sql::{
-    SqlCompileAttribution, SqlDistinctProjectionAttribution, SqlExecutionAttribution,
-    SqlHybridCoveringAttribution, SqlOutputBlobAttribution, SqlPureCoveringAttribution,
-    SqlQueryCacheAttribution, SqlQueryExecutionAttribution,
-};
 #[cfg(feature = "sql")]
 pub use sql::{
-    SqlConstraintValidationPage, SqlConstraintValidationRevisionStatus,
+    PreparedSqlQuery, SqlConstraintValidationPage, SqlConstraintValidationRevisionStatus,
     SqlConstraintValidationState, SqlDdlExecutionStatus, SqlDdlMutationKind,
     SqlDdlPreparationReport, SqlIntegrityError, SqlStatementDispatch, SqlStatementResult,
     SqlStatementShellSurface, SqlStatementSurface, sql_statement_dispatch,
     sql_statement_entity_name, sql_statement_shell_surface, sql_statement_surface,
 };
+#[cfg(all(feature = "sql", feature = "diagnostics"))]
+pub use sql::{
+    SqlCompileAttribution, SqlDistinctProjectionAttribution, SqlExecutionAttribution,
+    SqlHybridCoveringAttribution, SqlOutputBlobAttribution, SqlPureCoveringAttribution,
+    SqlQueryCacheAttribution, SqlQueryExecutionAttribution,
+};
 #[cfg(feature = "sql")]
 pub(in crate::db::session) use write::{
     AcceptedStructuralMutation, AcceptedStructuralMutationTarget,
@@ -200,7 +200,7 @@ fn accepted_schema_has_expression_indexes(accepted_schema: &AcceptedSchemaSnapsh
             PersistedIndexKeySnapshot::FieldPath(_) => false,
             PersistedIndexKeySnapshot::Items(items) => items
                 .iter()
-                .any(|item| matches!(item, PersistedIndexKeyItemSnapshot::Expression(_))),
+                .any(|item| !matches!(item, PersistedIndexKeyItemSnapshot::FieldPath(_))),
         })
 }
 
@@ -25,7 +25,7 @@ use crate::{
             admission::{QueryAdmissionPolicy, QueryAdmissionSummary},
             expr::{FilterExpr, OrderTerm as FluentOrderTerm},
             intent::{IntentError, StructuralQuery},
-            plan::CardinalityTiebreakRoutePin,
+            plan::{CardinalityTiebreakRoutePin, expr::Expr},
         },
         session::AcceptedSchemaCatalogContext,
     },
@@ -67,12 +67,12 @@ enum DynamicReadLane {
     Trusted,
 }
 
-struct ScalarCursorContract {
-    signature: crate::db::cursor::ContinuationSignature,
-    authority: ScalarPageTokenAuthority,
-    route_pin: Option<CardinalityTiebreakRoutePin>,
-    window: ScalarPageTokenWindow,
-    order_terms: Vec<ScalarOrderTermContract>,
+pub(in crate::db::session) struct ScalarCursorContract {
+    pub(in crate::db::session) signature: crate::db::cursor::ContinuationSignature,
+    pub(in crate::db::session) authority: ScalarPageTokenAuthority,
+    pub(in crate::db::session) route_pin: Option<CardinalityTiebreakRoutePin>,
+    pub(in crate::db::session) window: ScalarPageTokenWindow,
+    pub(in crate::db::session) order_terms: Vec<ScalarOrderTermContract>,
 }
 
 impl<C: CanisterKind> DbSession<C> {
@@ -137,8 +137,11 @@ impl<C: CanisterKind> DbSession<C> {
         if let Some(limit) = page_limit.or_else(|| request.row_limit()) {
             query = query.limit(limit);
         }
-        for field in request.group_fields() {
-            query = query.group_by_with_schema(field, schema)?;
+        for key in request.group_keys() {
+            query = match key.expr() {
+                Expr::Field(field) => query.group_by_with_schema(field.as_str(), schema)?,
+                expr => query.group_by_expr_with_schema(expr.clone(), schema)?,
+            };
         }
         for aggregate in request.aggregates() {
             query = query.aggregate(aggregate.clone());
@@ -153,14 +156,14 @@ impl<C: CanisterKind> DbSession<C> {
         Ok(query)
     }
 
-    fn scalar_page_cursor_error() -> QueryError {
+    pub(in crate::db::session) fn scalar_page_cursor_error() -> QueryError {
         QueryError::from_cursor_plan_error(CursorPlanError::invalid_continuation_cursor_payload(
             DiagnosticDecodeReason::CursorTokenDecode,
         ))
     }
 
-    fn scalar_cursor_contract(
-        request: &DynamicQuery,
+    pub(in crate::db::session) fn scalar_cursor_contract(
+        row_limit: Option<u32>,
         catalog: &AcceptedSchemaCatalogContext,
         envelope: PageWorkEnvelope,
         prepared_plan: &crate::db::executor::SharedPreparedExecutionPlan,
@@ -194,8 +197,7 @@ impl<C: CanisterKind> DbSession<C> {
             catalog.fingerprint(),
             prepared_plan.authority_ref().entity_tag(),
         );
-        let window =
-            ScalarPageTokenWindow::new(0, request.row_limit(), envelope.profile_identity());
+        let window = ScalarPageTokenWindow::new(0, row_limit, envelope.profile_identity());
         let canonical_order = prepared_plan
             .logical_plan()
             .scalar_plan()
@@ -205,7 +207,9 @@ impl<C: CanisterKind> DbSession<C> {
         let order_terms = canonical_order
             .fields
             .iter()
-            .map(|term| ScalarOrderTermContract::new(term.rendered_label(), term.direction()))
+            .map(|term| {
+                ScalarOrderTermContract::new(term.rendered_label(), term.direction(), term.nulls())
+            })
             .collect::<Vec<_>>();
 
         Ok(ScalarCursorContract {
@@ -219,7 +223,7 @@ impl<C: CanisterKind> DbSession<C> {
         })
     }
 
-    fn validate_scalar_page_token(
+    pub(in crate::db::session) fn validate_scalar_page_token(
         token: &ScalarPageToken,
         mode: ScalarPageMode,
         contract: &ScalarCursorContract,
@@ -268,6 +272,70 @@ impl<C: CanisterKind> DbSession<C> {
         Ok(CursorBoundary { slots })
     }
 
+    // Decode one optional opaque scalar page token under the database-owned
+    // cursor authentication key.
+    pub(in crate::db::session) fn decode_scalar_page_token(
+        continuation: Option<&str>,
+    ) -> Result<Option<ScalarPageToken>, QueryError> {
+        decode_optional_cursor_token(continuation)
+            .map_err(QueryError::from_cursor_plan_error)?
+            .map(|bytes| {
+                ScalarPageToken::decode(
+                    bytes.as_slice(),
+                    &cursor_authentication_key().map_err(QueryError::execute)?,
+                )
+                .map_err(|error| {
+                    QueryError::from_cursor_plan_error(CursorPlanError::from_token_wire_error(
+                        error,
+                    ))
+                })
+            })
+            .transpose()
+    }
+
+    // Rebuild the executor continuation boundary carried by one decoded
+    // scalar page token. Resumed tokens must always carry progress.
+    pub(in crate::db::session) fn scalar_continuation_context(
+        token: Option<&ScalarPageToken>,
+        catalog: &AcceptedSchemaCatalogContext,
+    ) -> Result<ScalarContinuationContext, QueryError> {
+        let Some(token) = token else {
+            return Ok(ScalarContinuationContext::initial());
+        };
+        if token.progress().unconsumed_lookahead().is_some() {
+            return Err(Self::scalar_page_cursor_error());
+        }
+        let logical = token.progress().last_emitted_logical().cloned();
+        let context = match token.progress().last_consumed_physical() {
+            Some(physical) => ScalarContinuationContext::resumed_with_primary_progress(
+                logical,
+                Self::physical_primary_key_boundary(physical, catalog)?,
+            ),
+            None => logical.map_or_else(
+                ScalarContinuationContext::initial,
+                ScalarContinuationContext::resumed,
+            ),
+        };
+        if !context.has_progress() {
+            return Err(Self::scalar_page_cursor_error());
+        }
+
+        Ok(context)
+    }
+
+    // Encode one authenticated scalar page token into its outward cursor text.
+    pub(in crate::db::session) fn encode_scalar_page_token(
+        token: &ScalarPageToken,
+    ) -> Result<String, QueryError> {
+        let bytes = token
+            .encode(&cursor_authentication_key().map_err(QueryError::execute)?)
+            .map_err(|error| {
+                QueryError::from_cursor_plan_error(CursorPlanError::from_token_wire_error(error))
+            })?;
+
+        Ok(encode_cursor(bytes.as_slice()))
+    }
+
     fn execute_dynamic_grouped_query_against_catalog(
         &self,
         request: &DynamicQuery,
@@ -359,20 +427,7 @@ impl<C: CanisterKind> DbSession<C> {
             .and_then(|limit| usize::try_from(limit).ok())
             .unwrap_or(SCALAR_PAGE_OUTPUT_ROWS)
             .min(SCALAR_PAGE_OUTPUT_ROWS);
-        let decoded_token = decode_optional_cursor_token(continuation)
-            .map_err(QueryError::from_cursor_plan_error)?
-            .map(|bytes| {
-                ScalarPageToken::decode(
-                    bytes.as_slice(),
-                    &cursor_authentication_key().map_err(QueryError::execute)?,
-                )
-                .map_err(|error| {
-                    QueryError::from_cursor_plan_error(CursorPlanError::from_token_wire_error(
-                        error,
-                    ))
-                })
-            })
-            .transpose()?;
+        let decoded_token = Self::decode_scalar_page_token(continuation)?;
         let prior_rows_emitted = decoded_token
             .as_ref()
             .map_or(0, |token| token.progress().rows_emitted());
@@ -451,7 +506,7 @@ impl<C: CanisterKind> DbSession<C> {
             .as_ref()
             .map(|token| {
                 let contract = Self::scalar_cursor_contract(
-                    request,
+                    request.row_limit(),
                     &catalog,
                     envelope,
                     &prepared_plan,
@@ -464,28 +519,8 @@ impl<C: CanisterKind> DbSession<C> {
             .transpose()?;
         let deferred_cursor_plan =
             (!exact_initial_exhaustion && decoded_token.is_none()).then(|| prepared_plan.clone());
-        let continuation_context = match decoded_token.as_ref() {
-            None => ScalarContinuationContext::initial(),
-            Some(token) if token.progress().unconsumed_lookahead().is_some() => {
-                return Err(Self::scalar_page_cursor_error().into());
-            }
-            Some(token) => {
-                let logical = token.progress().last_emitted_logical().cloned();
-                match token.progress().last_consumed_physical() {
-                    Some(physical) => ScalarContinuationContext::resumed_with_primary_progress(
-                        logical,
-                        Self::physical_primary_key_boundary(physical, &catalog)?,
-                    ),
-                    None => logical.map_or_else(
-                        ScalarContinuationContext::initial,
-                        ScalarContinuationContext::resumed,
-                    ),
-                }
-            }
-        };
-        if decoded_token.is_some() && !continuation_context.has_progress() {
-            return Err(Self::scalar_page_cursor_error().into());
-        }
+        let continuation_context =
+            Self::scalar_continuation_context(decoded_token.as_ref(), &catalog)?;
 
         let value_catalog = prepared_plan
             .authority_ref()
@@ -544,7 +579,7 @@ impl<C: CanisterKind> DbSession<C> {
                     .as_ref()
                     .ok_or_else(Self::scalar_page_cursor_error)?;
                 Self::scalar_cursor_contract(
-                    request,
+                    request.row_limit(),
                     &catalog,
                     envelope,
                     prepared_plan,
@@ -569,16 +604,7 @@ impl<C: CanisterKind> DbSession<C> {
                     rows_emitted,
                 ),
             );
-            Some(encode_cursor(
-                token
-                    .encode(&cursor_authentication_key().map_err(QueryError::execute)?)
-                    .map_err(|error| {
-                        QueryError::from_cursor_plan_error(CursorPlanError::from_token_wire_error(
-                            error,
-                        ))
-                    })?
-                    .as_slice(),
-            ))
+            Some(Self::encode_scalar_page_token(&token)?)
         } else {
             None
         };
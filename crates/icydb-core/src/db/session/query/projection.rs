@@ -136,7 +136,12 @@ pub(in crate::db::session) fn projection_labels_from_projection_spec(
                         if let Some(input_expr) = aggregate.input_expr() {
                             let input = render_scalar_projection_expr_plan_label(input_expr);
 
-                            format!("{kind}({distinct}{input})")
+                            match aggregate.arguments() {
+                                Some(arguments) => {
+                                    arguments.render_call_label(aggregate.kind(), input.as_str())
+                                }
+                                None => format!("{kind}({distinct}{input})"),
+                            }
                         } else {
                             format!("{kind}({distinct}*)")
                         }
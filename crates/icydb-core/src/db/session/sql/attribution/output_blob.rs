@@ -24,7 +24,10 @@ pub(super) fn sql_output_blob_attribution(result: &SqlStatementResult) -> SqlOut
     let mut attribution = SqlOutputBlobAttribution::default();
 
     match result {
-        SqlStatementResult::Projection { rows, .. } => {
+        SqlStatementResult::Projection { rows, .. }
+        | SqlStatementResult::Upsert {
+            rows: Some(rows), ..
+        } => {
             for row in rows {
                 for value in row {
                     record_output_value_blob_attribution(value, &mut attribution);
@@ -39,6 +42,7 @@ pub(super) fn sql_output_blob_attribution(result: &SqlStatementResult) -> SqlOut
             }
         }
         SqlStatementResult::Count { .. }
+        | SqlStatementResult::Upsert { rows: None, .. }
         | SqlStatementResult::Describe(_)
         | SqlStatementResult::ShowConstraints(_)
         | SqlStatementResult::ShowIndexes(_)
@@ -10,11 +10,12 @@ use crate::{
         session::{
             AcceptedSchemaCatalogContext,
             bounded_cache::BoundedCache,
-            sql::compiled::{CompiledSqlCommand, SqlCompiledSchemaFingerprint},
+            sql::compiled::{SqlCompiledCacheEntry, SqlCompiledSchemaFingerprint},
         },
     },
     metrics::sink::CacheMissReason,
     traits::CanisterKind,
+    value::Value,
 };
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
@@ -47,13 +48,15 @@ pub(in crate::db) struct SqlCacheAttribution {
 ///
 /// SqlCompiledCommandSurface separates SQL query and mutation API cache lanes so
 /// identical text cannot alias across public session surfaces with different
-/// admissible statement families.
+/// admissible statement families. Parameterized query templates use their own
+/// lane because they cache a pre-bind template rather than a compiled command.
 ///
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub(in crate::db::session::sql) enum SqlCompiledCommandSurface {
     Query,
     Mutation,
+    PreparedQuery,
 }
 
 ///
@@ -64,6 +67,8 @@ pub(in crate::db::session::sql) enum SqlCompiledCommandSurface {
 /// The key is intentionally conservative: surface kind, entity path, schema
 /// runtime-root identity, entity schema revision/version, schema fingerprint,
 /// and raw SQL text must all match before execution can reuse a prior compile.
+/// Bound prepared-query commands also carry the canonical type of each bind
+/// value, so a template keeps one bound entry per parameter type signature.
 ///
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -75,10 +80,11 @@ pub(in crate::db) struct SqlCompiledCommandCacheKey {
     schema_version: SchemaVersion,
     schema_fingerprint: SqlCompiledSchemaFingerprint,
     sql: String,
+    parameter_types: Option<Vec<u8>>,
 }
 
 pub(in crate::db) type SqlCompiledCommandCache =
-    BoundedCache<SqlCompiledCommandCacheKey, CompiledSqlCommand>;
+    BoundedCache<SqlCompiledCommandCacheKey, SqlCompiledCacheEntry>;
 
 // Classify one SQL compiled-command cache miss by comparing the missed key
 // against already-warmed entries. The comparison order preserves the most
@@ -240,7 +246,6 @@ impl SqlCacheAttribution {
         result.map(|result| (result, Self::default()))
     }
 
-    #[cfg(feature = "diagnostics")]
     #[must_use]
     pub(in crate::db::session::sql) const fn merge(self, other: Self) -> Self {
         Self {
@@ -287,8 +292,23 @@ impl SqlCompiledCommandCacheKey {
             schema_version,
             schema_fingerprint,
             sql: sql.to_string(),
+            parameter_types: None,
         }
     }
+
+    /// Narrow one prepared-query template key onto the bound-command entry
+    /// for the canonical types of one execution's bind values.
+    #[must_use]
+    pub(in crate::db::session::sql) fn with_parameter_types(mut self, values: &[Value]) -> Self {
+        self.parameter_types = Some(
+            values
+                .iter()
+                .map(|value| value.canonical_tag().to_u8())
+                .collect(),
+        );
+
+        self
+    }
 }
 
 impl<C: CanisterKind> DbSession<C> {
@@ -7,29 +7,39 @@
 use std::sync::Arc;
 
 #[cfg(feature = "sql")]
-use crate::db::sql::lowering::lower_sql_explain_command_from_prepared_statement_with_schema;
+use crate::db::sql::{
+    lowering::{
+        lower_sql_explain_command_from_prepared_statement_with_schema,
+        sql_with_statement_common_table_expressions,
+    },
+    parser::{SqlExplainStatement, SqlExplainTarget},
+};
 use crate::{
     db::{
         DbSession, MissingRowPolicy, QueryError,
+        executor::{UnionRowOrderTerm, UnionRowWindow},
         schema::SchemaInfo,
         session::sql::{
-            CompiledSqlCommand, CompiledSqlInsertCommand, SqlCompiledCommandSurface,
+            CompiledSqlCommand, CompiledSqlInsertCommand, CompiledSqlUnionCommand,
+            SqlCompiledCommandSurface,
             compile::{SqlCompileArtifacts, SqlCompilePhaseAttribution},
             measured,
         },
         sql::{
             lowering::{
-                PreparedSqlStatement, bind_lowered_sql_delete_query_structural_with_schema,
+                PreparedSqlStatement, SqlLoweringError, SqlUnionReason,
+                bind_lowered_sql_delete_query_structural_with_schema,
                 bind_lowered_sql_select_query_structural_with_schema,
                 bind_sql_select_statement_structural_with_schema,
                 compile_sql_global_aggregate_command_from_prepared_with_schema,
-                extract_prepared_sql_insert_statement, extract_prepared_sql_update_statement,
-                lower_prepared_sql_delete_statement,
-                lower_prepared_sql_select_statement_with_schema, prepare_sql_statement,
+                extract_prepared_sql_insert_statement, extract_prepared_sql_relation_paths,
+                extract_prepared_sql_update_statement, lower_prepared_sql_delete_statement,
+                lower_prepared_sql_select_statement_with_schema, lower_sql_union_statement,
+                prepare_sql_statement,
             },
             parser::{
                 SqlExpr, SqlInsertSource, SqlOrderDirection, SqlOrderTerm, SqlSelectStatement,
-                SqlStatement,
+                SqlStatement, SqlUnionStatement,
             },
         },
     },
@@ -48,6 +58,8 @@ impl<C: CanisterKind> DbSession<C> {
 
         match statement {
             SqlStatement::Select(_) => Self::compile_select(statement, entity_name, schema),
+            SqlStatement::Union(statement) => Self::compile_union(statement, entity_name, schema),
+            SqlStatement::With(_) => Self::compile_select(statement, entity_name, schema),
             SqlStatement::Delete(_) => Self::compile_delete(statement, entity_name, schema),
             SqlStatement::Insert(_) => Self::compile_insert(statement, entity_name, schema),
             SqlStatement::Update(_) => Self::compile_update(statement, entity_name),
@@ -126,6 +138,10 @@ impl<C: CanisterKind> DbSession<C> {
         prepare_local_instructions: u64,
     ) -> Result<SqlCompileArtifacts, QueryError> {
         let (lower_local_instructions, command) = measured(|| {
+            // Aggregate outputs cannot carry relation paths; extraction only
+            // runs here so misplaced paths report their own diagnostic.
+            let (prepared, _) = extract_prepared_sql_relation_paths(prepared, schema)
+                .map_err(QueryError::from_sql_lowering_error)?;
             compile_sql_global_aggregate_command_from_prepared_with_schema(
                 prepared,
                 MissingRowPolicy::Ignore,
@@ -152,9 +168,13 @@ impl<C: CanisterKind> DbSession<C> {
         aggregate_lane_check_local_instructions: u64,
         prepare_local_instructions: u64,
     ) -> Result<SqlCompileArtifacts, QueryError> {
-        let (lower_local_instructions, select) = measured(|| {
-            lower_prepared_sql_select_statement_with_schema(prepared, schema)
-                .map_err(QueryError::from_sql_lowering_error)
+        let (lower_local_instructions, (select, relation_paths)) = measured(|| {
+            let (prepared, relation_paths) = extract_prepared_sql_relation_paths(prepared, schema)
+                .map_err(QueryError::from_sql_lowering_error)?;
+            let select = lower_prepared_sql_select_statement_with_schema(prepared, schema)
+                .map_err(QueryError::from_sql_lowering_error)?;
+
+            Ok((select, relation_paths))
         })?;
         let (bind_local_instructions, query) = measured(|| {
             bind_lowered_sql_select_query_structural_with_schema(
@@ -166,7 +186,74 @@ impl<C: CanisterKind> DbSession<C> {
         })?;
 
         Ok(SqlCompileArtifacts::new(
-            CompiledSqlCommand::select(query),
+            CompiledSqlCommand::select(query, relation_paths),
+            aggregate_lane_check_local_instructions,
+            prepare_local_instructions,
+            lower_local_instructions,
+            bind_local_instructions,
+        ))
+    }
+
+    // Compile UNION by rewriting its branches once, then compiling every
+    // rewritten branch through the ordinary scalar SELECT lane. Branches that
+    // land on another lane cannot feed the ordered row merge.
+    fn compile_union(
+        statement: &SqlUnionStatement,
+        entity_name: &str,
+        schema: &SchemaInfo,
+    ) -> Result<SqlCompileArtifacts, QueryError> {
+        let (union_lower_local_instructions, union) = measured(|| {
+            lower_sql_union_statement(statement, schema)
+                .map_err(QueryError::from_sql_lowering_error)
+        })?;
+        let mut aggregate_lane_check_local_instructions = 0u64;
+        let mut prepare_local_instructions = 0u64;
+        let mut lower_local_instructions = union_lower_local_instructions;
+        let mut bind_local_instructions = 0u64;
+        let mut branches = Vec::with_capacity(union.branches.len());
+
+        for branch in union.branches {
+            let artifacts =
+                Self::compile_select(&SqlStatement::Select(branch), entity_name, schema)?;
+            let CompiledSqlCommand::Select {
+                query,
+                relation_paths,
+                ..
+            } = artifacts.command
+            else {
+                return Err(QueryError::from_sql_lowering_error(
+                    SqlLoweringError::unsupported_union(SqlUnionReason::BranchShape),
+                ));
+            };
+            if !relation_paths.is_empty() {
+                return Err(QueryError::from_sql_lowering_error(
+                    SqlLoweringError::unsupported_union(SqlUnionReason::BranchShape),
+                ));
+            }
+            aggregate_lane_check_local_instructions = aggregate_lane_check_local_instructions
+                .saturating_add(artifacts.aggregate_lane_check);
+            prepare_local_instructions =
+                prepare_local_instructions.saturating_add(artifacts.prepare);
+            lower_local_instructions = lower_local_instructions.saturating_add(artifacts.lower);
+            bind_local_instructions = bind_local_instructions.saturating_add(artifacts.bind);
+            branches.push(Arc::unwrap_or_clone(query));
+        }
+        let order = union
+            .order
+            .iter()
+            .map(|slot| UnionRowOrderTerm::new(slot.column, slot.direction, slot.nulls))
+            .collect();
+        let window = UnionRowWindow {
+            visible_width: union.visible_width,
+            distinct: union.distinct,
+            offset: union.offset,
+            limit: union.limit,
+        };
+
+        Ok(SqlCompileArtifacts::new(
+            CompiledSqlCommand::Union(Arc::new(CompiledSqlUnionCommand::new(
+                branches, order, window,
+            ))),
             aggregate_lane_check_local_instructions,
             prepare_local_instructions,
             lower_local_instructions,
@@ -293,6 +380,16 @@ impl<C: CanisterKind> DbSession<C> {
             lower_sql_explain_command_from_prepared_statement_with_schema(prepared, schema)
                 .map_err(QueryError::from_sql_lowering_error)
         })?;
+        // Preparation inlines `WITH` targets, so read the entries they named
+        // from the parsed statement before rendering.
+        let lowered = match statement {
+            SqlStatement::Explain(SqlExplainStatement {
+                statement: SqlExplainTarget::With(with),
+                ..
+            }) => lowered
+                .with_common_table_expressions(sql_with_statement_common_table_expressions(with)),
+            _ => lowered,
+        };
 
         Ok(SqlCompileArtifacts::new(
             CompiledSqlCommand::Explain(Box::new(lowered)),
@@ -484,6 +581,7 @@ fn insert_select_source_with_primary_key_order(
         source.order_by.push(SqlOrderTerm {
             field: SqlExpr::Field(primary_key_name.clone()),
             direction: SqlOrderDirection::Asc,
+            nulls: None,
         });
     }
 
@@ -10,13 +10,17 @@ use crate::{
         session::{
             AcceptedSchemaCatalogContext,
             sql::{
-                CompiledSqlCommand, SqlCacheAttribution, SqlCompileAttributionBuilder,
-                SqlCompilePhaseAttribution, SqlCompiledCommandCacheContext,
-                SqlCompiledCommandCacheKey, SqlCompiledCommandExecutionContext,
-                SqlCompiledCommandSurface, measured, sql_compiled_command_cache_miss_reason,
+                CompiledSqlCommand, CompiledSqlTemplate, SqlCacheAttribution,
+                SqlCompileAttributionBuilder, SqlCompilePhaseAttribution, SqlCompiledCacheEntry,
+                SqlCompiledCommandCacheContext, SqlCompiledCommandCacheKey,
+                SqlCompiledCommandExecutionContext, SqlCompiledCommandSurface, measured,
+                sql_compiled_command_cache_miss_reason,
             },
         },
-        sql::parser::parse_sql_with_attribution,
+        sql::{
+            lowering::{sql_statement_has_semi_join, sql_statement_parameter_count},
+            parser::{SqlParsePhaseAttribution, SqlStatement, parse_sql_with_attribution},
+        },
     },
     error::InternalError,
     metrics::sink::{
@@ -40,16 +44,25 @@ impl<C: CanisterKind> DbSession<C> {
         ),
         QueryError,
     > {
-        let catalog = match entity_name {
+        let catalog = self.sql_query_catalog_for_entity_name(entity_name)?;
+        self.compile_sql_surface_with_catalog(sql, SqlCompiledCommandSurface::Query, catalog)
+    }
+
+    // Resolve the accepted catalog for one query-surface statement. Named
+    // entities must exist; entity-free introspection uses the default catalog.
+    pub(in crate::db::session::sql) fn sql_query_catalog_for_entity_name(
+        &self,
+        entity_name: Option<&str>,
+    ) -> Result<AcceptedSchemaCatalogContext, QueryError> {
+        match entity_name {
             Some(entity_name) => self
                 .find_accepted_schema_catalog_context_for_entity_name(entity_name)
                 .map_err(QueryError::execute)?
-                .ok_or_else(|| QueryError::execute(InternalError::sql_query_entity_not_found()))?,
+                .ok_or_else(|| QueryError::execute(InternalError::sql_query_entity_not_found())),
             None => self
                 .accepted_schema_catalog_context_for_entity_name(None)
-                .map_err(QueryError::execute)?,
-        };
-        self.compile_sql_surface_with_catalog(sql, SqlCompiledCommandSurface::Query, catalog)
+                .map_err(QueryError::execute),
+        }
     }
 
     pub(in crate::db) fn compile_sql_mutation_with_execution_context(
@@ -126,55 +139,51 @@ impl<C: CanisterKind> DbSession<C> {
         ),
         QueryError,
     > {
-        let (cache_lookup_local_instructions, (cached, entries, miss_reason)) = measured(|| {
-            let cache_state = self.with_sql_compiled_command_cache(|cache| {
-                let cached = cache.get(&cache_key).cloned();
-                let miss_reason = cached
-                    .is_none()
-                    .then(|| sql_compiled_command_cache_miss_reason(cache, &cache_key));
-
-                (cached, cache.len(), miss_reason)
-            });
-            Ok::<_, QueryError>(cache_state)
-        })?;
+        let (cache_lookup_local_instructions, cached) =
+            self.lookup_sql_compiled_cache_entry(&cache_key, entity_path)?;
         attribution.record_cache_lookup(cache_lookup_local_instructions);
-        record_cache_entries(CacheKind::SqlCompiledCommand, entries);
-        if let Some(compiled) = cached {
-            record_cache_event_for_path(
-                CacheKind::SqlCompiledCommand,
-                CacheOutcome::Hit,
-                entity_path,
-            );
-            return Ok((
-                compiled,
-                SqlCacheAttribution::sql_compiled_command_cache_hit(),
-                attribution.finish(),
-                None,
-            ));
-        }
-        record_cache_event_for_path(
-            CacheKind::SqlCompiledCommand,
-            CacheOutcome::Miss,
-            entity_path,
-        );
-        if let Some(reason) = miss_reason {
-            record_cache_miss_reason_for_path(CacheKind::SqlCompiledCommand, reason, entity_path);
-        }
-
         let authority = catalog.accepted_entity_authority();
         let schema = catalog.accepted_schema_info();
+        match cached {
+            Some(SqlCompiledCacheEntry::Command(compiled)) => {
+                return Ok((
+                    compiled,
+                    SqlCacheAttribution::sql_compiled_command_cache_hit(),
+                    attribution.finish(),
+                    None,
+                ));
+            }
+            // Semi-join statements keep only their parsed form in the cache:
+            // the compiled command embeds source rows read by this execution.
+            Some(SqlCompiledCacheEntry::Template(template)) => {
+                let resolved = self.resolve_sql_semi_joins(template.statement())?;
+                let (artifacts, compile_attribution) =
+                    Self::compile_sql_statement_measured(&resolved, surface, schema)?;
+                attribution.record_core_compile(compile_attribution);
 
-        let parse_result =
-            measured(|| parse_sql_with_attribution(sql).map_err(QueryError::from_sql_parse_error));
-        let (parse_local_instructions, (parsed, parse_attribution)) = match parse_result {
-            Ok(parsed) => parsed,
-            Err(error) => {
-                record_sql_compile_reject_for_path(SqlCompileRejectPhase::Parse, entity_path);
-                return Err(error);
+                return Ok((
+                    artifacts.command,
+                    SqlCacheAttribution::sql_compiled_command_cache_hit(),
+                    attribution.finish(),
+                    Some(authority),
+                ));
             }
-        };
+            Some(SqlCompiledCacheEntry::Bound(_)) => return Err(QueryError::invariant()),
+            None => {}
+        }
+
+        let (parse_local_instructions, (parsed, parse_attribution)) =
+            Self::parse_sql_for_compile_cache(sql, entity_path)?;
         attribution.record_parse(parse_local_instructions, parse_attribution);
-        let compile_result = Self::compile_sql_statement_measured(&parsed, surface, schema);
+        let semi_join = matches!(surface, SqlCompiledCommandSurface::Query)
+            && sql_statement_has_semi_join(&parsed);
+        let compile_result = if semi_join {
+            self.resolve_sql_semi_joins(&parsed).and_then(|resolved| {
+                Self::compile_sql_statement_measured(&resolved, surface, schema)
+            })
+        } else {
+            Self::compile_sql_statement_measured(&parsed, surface, schema)
+        };
         let (artifacts, compile_attribution) = match compile_result {
             Ok(compiled) => compiled,
             Err(error) => {
@@ -184,15 +193,138 @@ impl<C: CanisterKind> DbSession<C> {
         };
         attribution.record_core_compile(compile_attribution);
         let compiled = artifacts.command;
+        let entry = if semi_join {
+            SqlCompiledCacheEntry::Template(CompiledSqlTemplate::new(parsed, 0))
+        } else {
+            SqlCompiledCacheEntry::Command(compiled.clone())
+        };
+
+        let cache_insert_local_instructions =
+            self.insert_sql_compiled_cache_entry(cache_key, entry, entity_path)?;
+        attribution.record_cache_insert(cache_insert_local_instructions);
 
+        Ok((
+            compiled,
+            SqlCacheAttribution::sql_compiled_command_cache_miss(),
+            attribution.finish(),
+            Some(authority),
+        ))
+    }
+
+    // Resolve one parameterized query template through the prepared-query
+    // cache lane. The key is the placeholder SQL text, so every execution of
+    // the same shape reuses one parse and surface check regardless of its
+    // bind values.
+    pub(in crate::db::session::sql) fn compile_sql_template_with_cache(
+        &self,
+        sql: &str,
+        catalog: AcceptedSchemaCatalogContext,
+    ) -> Result<
+        (
+            CompiledSqlTemplate,
+            AcceptedSchemaCatalogContext,
+            SqlCacheAttribution,
+        ),
+        QueryError,
+    > {
+        let entity_path = catalog.identity().entity_path_handle();
+        let (cache_key, catalog) = SqlCompiledCommandCacheContext::from_catalog(
+            SqlCompiledCommandSurface::PreparedQuery,
+            sql,
+            catalog,
+        )
+        .into_cache_inputs();
+        let (_, cached) = self.lookup_sql_compiled_cache_entry(&cache_key, entity_path.as_ref())?;
+        if let Some(entry) = cached {
+            let SqlCompiledCacheEntry::Template(template) = entry else {
+                return Err(QueryError::invariant());
+            };
+
+            return Ok((
+                template,
+                catalog,
+                SqlCacheAttribution::sql_compiled_command_cache_hit(),
+            ));
+        }
+
+        let (_, (parsed, _)) = Self::parse_sql_for_compile_cache(sql, entity_path.as_ref())?;
+        let template = Self::ensure_sql_statement_supported_for_surface(
+            &parsed,
+            SqlCompiledCommandSurface::PreparedQuery,
+        )
+        .and_then(|()| {
+            sql_statement_parameter_count(&parsed).map_err(QueryError::from_sql_lowering_error)
+        })
+        .map(|parameter_count| CompiledSqlTemplate::new(parsed, parameter_count));
+        let template = match template {
+            Ok(template) => template,
+            Err(error) => {
+                record_sql_compile_reject_for_path(
+                    SqlCompileRejectPhase::Semantic,
+                    entity_path.as_ref(),
+                );
+                return Err(error);
+            }
+        };
+        self.insert_sql_compiled_cache_entry(
+            cache_key,
+            SqlCompiledCacheEntry::Template(template.clone()),
+            entity_path.as_ref(),
+        )?;
+
+        Ok((
+            template,
+            catalog,
+            SqlCacheAttribution::sql_compiled_command_cache_miss(),
+        ))
+    }
+
+    // Look up one compile-cache entry and record the shared hit/miss metrics
+    // for its entity path.
+    pub(in crate::db::session::sql) fn lookup_sql_compiled_cache_entry(
+        &self,
+        cache_key: &SqlCompiledCommandCacheKey,
+        entity_path: &str,
+    ) -> Result<(u64, Option<SqlCompiledCacheEntry>), QueryError> {
+        let (cache_lookup_local_instructions, (cached, entries, miss_reason)) = measured(|| {
+            let cache_state = self.with_sql_compiled_command_cache(|cache| {
+                let cached = cache.get(cache_key).cloned();
+                let miss_reason = cached
+                    .is_none()
+                    .then(|| sql_compiled_command_cache_miss_reason(cache, cache_key));
+
+                (cached, cache.len(), miss_reason)
+            });
+            Ok::<_, QueryError>(cache_state)
+        })?;
+        record_cache_entries(CacheKind::SqlCompiledCommand, entries);
+        let outcome = if cached.is_some() {
+            CacheOutcome::Hit
+        } else {
+            CacheOutcome::Miss
+        };
+        record_cache_event_for_path(CacheKind::SqlCompiledCommand, outcome, entity_path);
+        if let Some(reason) = miss_reason {
+            record_cache_miss_reason_for_path(CacheKind::SqlCompiledCommand, reason, entity_path);
+        }
+
+        Ok((cache_lookup_local_instructions, cached))
+    }
+
+    // Insert one freshly compiled entry and record the shared insert metrics.
+    pub(in crate::db::session::sql) fn insert_sql_compiled_cache_entry(
+        &self,
+        cache_key: SqlCompiledCommandCacheKey,
+        entry: SqlCompiledCacheEntry,
+        entity_path: &str,
+    ) -> Result<u64, QueryError> {
         let (cache_insert_local_instructions, entries) = measured(|| {
             let entries = self.with_sql_compiled_command_cache(|cache| {
-                cache.insert(cache_key, compiled.clone());
+                cache.insert(cache_key, entry);
                 cache.len()
             });
             Ok::<_, QueryError>(entries)
         })?;
-        attribution.record_cache_insert(cache_insert_local_instructions);
         record_cache_entries(CacheKind::SqlCompiledCommand, entries);
         record_cache_event_for_path(
             CacheKind::SqlCompiledCommand,
@@ -200,11 +332,18 @@ impl<C: CanisterKind> DbSession<C> {
             entity_path,
         );
 
-        Ok((
-            compiled,
-            SqlCacheAttribution::sql_compiled_command_cache_miss(),
-            attribution.finish(),
-            Some(authority),
-        ))
+        Ok(cache_insert_local_instructions)
+    }
+
+    // Parse one cache-miss statement and record parse rejections against the
+    // entity path that owns the cache lane.
+    fn parse_sql_for_compile_cache(
+        sql: &str,
+        entity_path: &str,
+    ) -> Result<(u64, (SqlStatement, SqlParsePhaseAttribution)), QueryError> {
+        measured(|| parse_sql_with_attribution(sql).map_err(QueryError::from_sql_parse_error))
+            .inspect_err(|_| {
+                record_sql_compile_reject_for_path(SqlCompileRejectPhase::Parse, entity_path);
+            })
     }
 }
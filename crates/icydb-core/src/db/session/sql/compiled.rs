@@ -10,5 +10,8 @@ mod context;
 pub(in crate::db) use cache::{
     SqlCompiledSchemaFingerprint, SqlGlobalAggregateCountPlanCacheEntry,
 };
-pub(in crate::db) use command::{CompiledSqlCommand, CompiledSqlInsertCommand};
+pub(in crate::db) use command::{
+    CompiledSqlBoundCommand, CompiledSqlCommand, CompiledSqlInsertCommand, CompiledSqlTemplate,
+    CompiledSqlUnionCommand, SqlCompiledCacheEntry,
+};
 pub(in crate::db) use context::SqlCompiledCommandExecutionContext;
@@ -7,16 +7,23 @@ use super::cache::{
 };
 #[cfg(feature = "sql")]
 use crate::db::sql::lowering::LoweredSqlCommand;
-use crate::db::{
-    executor::SharedPreparedExecutionPlan,
-    query::intent::StructuralQuery,
-    session::query::StructuralProjectionContract,
-    sql::{
-        lowering::SqlGlobalAggregateCommand,
-        parser::{SqlDescribeMode, SqlInsertStatement, SqlReturningProjection, SqlUpdateStatement},
+use crate::{
+    db::{
+        executor::{SharedPreparedExecutionPlan, UnionRowOrderTerm, UnionRowWindow},
+        query::intent::StructuralQuery,
+        session::query::StructuralProjectionContract,
+        sql::{
+            lowering::{SqlGlobalAggregateCommand, SqlRelationPath},
+            parser::{
+                SqlDescribeMode, SqlInsertStatement, SqlReturningProjection, SqlStatement,
+                SqlUpdateStatement,
+            },
+        },
     },
+    value::Value,
 };
 use std::{
+    cmp::Ordering,
     rc::Rc,
     sync::{Arc, OnceLock},
 };
@@ -33,8 +40,10 @@ use std::{
 pub(in crate::db) enum CompiledSqlCommand {
     Select {
         query: Arc<StructuralQuery>,
+        relation_paths: Arc<[SqlRelationPath]>,
         plan_cache: Rc<OnceLock<Rc<SqlSelectPlanCacheEntry>>>,
     },
+    Union(Arc<CompiledSqlUnionCommand>),
     Delete {
         query: Arc<StructuralQuery>,
         returning: Option<SqlReturningProjection>,
@@ -67,6 +76,97 @@ pub(in crate::db) enum CompiledSqlCommand {
     ShowMemory,
 }
 
+///
+/// CompiledSqlTemplate
+///
+/// CompiledSqlTemplate is the parsed, surface-checked form of one
+/// parameterized SQL statement. Each execution binds its own values into a
+/// copy of the template before semantic compilation, so the cached artifact
+/// never carries one caller's bind values into another execution.
+///
+
+#[derive(Clone, Debug)]
+pub(in crate::db) struct CompiledSqlTemplate {
+    statement: Arc<SqlStatement>,
+    parameter_count: usize,
+}
+
+impl CompiledSqlTemplate {
+    #[must_use]
+    pub(in crate::db) fn new(statement: SqlStatement, parameter_count: usize) -> Self {
+        Self {
+            statement: Arc::new(statement),
+            parameter_count,
+        }
+    }
+
+    /// Borrow the parsed parameterized statement.
+    #[must_use]
+    pub(in crate::db) fn statement(&self) -> &SqlStatement {
+        &self.statement
+    }
+
+    /// Return the number of dense `$1..$n` bind slots this template expects.
+    #[must_use]
+    pub(in crate::db) const fn parameter_count(&self) -> usize {
+        self.parameter_count
+    }
+}
+
+///
+/// SqlCompiledCacheEntry
+///
+/// SqlCompiledCacheEntry is one value in the session SQL compile cache.
+/// Literal SQL text caches its compiled command, while parameterized SQL
+/// caches its template on a separate surface lane keyed by the placeholder
+/// text itself, plus one bound command per parameter type signature.
+///
+
+#[derive(Clone, Debug)]
+pub(in crate::db) enum SqlCompiledCacheEntry {
+    Command(CompiledSqlCommand),
+    Template(CompiledSqlTemplate),
+    Bound(CompiledSqlBoundCommand),
+}
+
+///
+/// CompiledSqlBoundCommand
+///
+/// CompiledSqlBoundCommand is the compiled form of one prepared template
+/// bound to one value set. Compiled commands embed their literals, so the
+/// entry only serves executions that bind the same canonical values; a
+/// different value set recompiles and replaces it.
+///
+
+#[derive(Clone, Debug)]
+pub(in crate::db) struct CompiledSqlBoundCommand {
+    values: Arc<[Value]>,
+    command: CompiledSqlCommand,
+}
+
+impl CompiledSqlBoundCommand {
+    #[must_use]
+    pub(in crate::db) fn new(values: &[Value], command: CompiledSqlCommand) -> Self {
+        Self {
+            values: values.into(),
+            command,
+        }
+    }
+
+    /// Return the compiled command when `values` canonically match the
+    /// values this entry was compiled with.
+    #[must_use]
+    pub(in crate::db) fn command_for(&self, values: &[Value]) -> Option<CompiledSqlCommand> {
+        (self.values.len() == values.len()
+            && self
+                .values
+                .iter()
+                .zip(values)
+                .all(|(cached, value)| Value::canonical_cmp(cached, value) == Ordering::Equal))
+        .then(|| self.command.clone())
+    }
+}
+
 ///
 /// CompiledSqlInsertCommand
 ///
@@ -110,6 +210,56 @@ impl CompiledSqlInsertCommand {
     }
 }
 
+///
+/// CompiledSqlUnionCommand
+///
+/// CompiledSqlUnionCommand carries the bound branch queries of one
+/// `UNION [ALL]` plus the merge contract over their projected rows.
+/// Every branch already orders by the combined ORDER BY and the primary key,
+/// so execution only runs the branches and merges their rows.
+///
+
+#[derive(Clone, Debug)]
+pub(in crate::db) struct CompiledSqlUnionCommand {
+    branches: Vec<Arc<StructuralQuery>>,
+    order: Vec<UnionRowOrderTerm>,
+    window: UnionRowWindow,
+}
+
+impl CompiledSqlUnionCommand {
+    /// Build one compiled UNION command from its bound branch queries.
+    #[must_use]
+    pub(in crate::db) fn new(
+        branches: Vec<StructuralQuery>,
+        order: Vec<UnionRowOrderTerm>,
+        window: UnionRowWindow,
+    ) -> Self {
+        Self {
+            branches: branches.into_iter().map(Arc::new).collect(),
+            order,
+            window,
+        }
+    }
+
+    /// Borrow the bound branch queries in statement order.
+    #[must_use]
+    pub(in crate::db) fn branches(&self) -> &[Arc<StructuralQuery>] {
+        &self.branches
+    }
+
+    /// Borrow the combined ORDER BY terms over visible output columns.
+    #[must_use]
+    pub(in crate::db) fn order(&self) -> &[UnionRowOrderTerm] {
+        &self.order
+    }
+
+    /// Return the combined DISTINCT and window contract.
+    #[must_use]
+    pub(in crate::db) const fn window(&self) -> UnionRowWindow {
+        self.window
+    }
+}
+
 impl CompiledSqlCommand {
     /// Return whether this command executes through the singleton global
     /// aggregate lane.
@@ -131,7 +281,7 @@ impl CompiledSqlCommand {
     #[must_use]
     pub(in crate::db::session::sql) const fn returns_rows(&self) -> bool {
         match self {
-            Self::Select { .. } | Self::GlobalAggregate { .. } => true,
+            Self::Select { .. } | Self::Union(_) | Self::GlobalAggregate { .. } => true,
             Self::Delete { returning, .. } => returning.is_some(),
             Self::Insert(command) => command.statement().returning.is_some(),
             Self::Update(statement) => statement.returning.is_some(),
@@ -149,13 +299,26 @@ impl CompiledSqlCommand {
     }
 
     #[must_use]
-    pub(in crate::db) fn select(query: StructuralQuery) -> Self {
+    pub(in crate::db) fn select(
+        query: StructuralQuery,
+        relation_paths: Vec<SqlRelationPath>,
+    ) -> Self {
         Self::Select {
             query: Arc::new(query),
+            relation_paths: relation_paths.into(),
             plan_cache: Rc::new(OnceLock::new()),
         }
     }
 
+    /// Borrow the projected `relation.field` columns of one scalar SELECT.
+    #[must_use]
+    pub(in crate::db) fn relation_paths(&self) -> &[SqlRelationPath] {
+        match self {
+            Self::Select { relation_paths, .. } => relation_paths,
+            _ => &[],
+        }
+    }
+
     #[must_use]
     pub(in crate::db) fn global_aggregate(command: SqlGlobalAggregateCommand) -> Self {
         Self::GlobalAggregate {
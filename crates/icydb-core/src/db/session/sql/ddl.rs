@@ -11,13 +11,13 @@ use crate::{
         registry::StoreHandle,
         schema::{
             AcceptedCatalogIdentity, AcceptedSchemaSnapshot, ConstraintValidationProgress,
-            SchemaDdlAcceptedSnapshotDerivation, SqlDdlFieldNullabilityOutcome,
-            advance_check_constraint_activation, advance_not_null_constraint_activation,
-            advance_unique_constraint_activation, constraint_validation_finding_output,
-            execute_admin_sql_ddl_check_addition, execute_admin_sql_ddl_check_drop,
-            execute_admin_sql_ddl_expression_index_addition, execute_admin_sql_ddl_field_addition,
-            execute_admin_sql_ddl_field_default_change, execute_admin_sql_ddl_field_drop,
-            execute_admin_sql_ddl_field_nullability_change,
+            MAX_SCHEMA_SOURCE_ROWS, SchemaDdlAcceptedSnapshotDerivation,
+            SqlDdlFieldNullabilityOutcome, advance_check_constraint_activation,
+            advance_not_null_constraint_activation, advance_unique_constraint_activation,
+            constraint_validation_finding_output, execute_admin_sql_ddl_check_addition,
+            execute_admin_sql_ddl_check_drop, execute_admin_sql_ddl_expression_index_addition,
+            execute_admin_sql_ddl_field_addition, execute_admin_sql_ddl_field_default_change,
+            execute_admin_sql_ddl_field_drop, execute_admin_sql_ddl_field_nullability_change,
             execute_admin_sql_ddl_field_path_index_addition, execute_admin_sql_ddl_field_rename,
             execute_admin_sql_ddl_not_null_activation_abort,
             execute_admin_sql_ddl_secondary_index_drop,
@@ -33,13 +33,13 @@ use crate::{
         },
         sql::{
             ddl::{
-                BoundSqlCreateIndexRequest, BoundSqlDdlStatement, BoundSqlValidationConstraintKind,
-                PreparedSqlDdlCommand, prepare_sql_ddl_statement,
+                BoundSqlAddColumnRequest, BoundSqlCreateIndexRequest, BoundSqlDdlStatement,
+                BoundSqlValidationConstraintKind, PreparedSqlDdlCommand, prepare_sql_ddl_statement,
             },
             parser::parse_sql_with_attribution,
         },
     },
-    error::InternalError,
+    error::{InternalError, SchemaTransitionBudgetResource},
     traits::CanisterKind,
 };
 
@@ -281,6 +281,16 @@ impl<C: CanisterKind> DbSession<C> {
                     drop,
                 ))
             }
+            BoundSqlDdlStatement::AddColumn(add)
+                if add.field().generated_expression().is_some() =>
+            {
+                Some(self.execute_prepared_generated_column_addition(
+                    store,
+                    accepted_before,
+                    prepared,
+                    add,
+                ))
+            }
             BoundSqlDdlStatement::AddColumn(_)
             | BoundSqlDdlStatement::AlterColumnDefault(_)
             | BoundSqlDdlStatement::DropColumn(_)
@@ -327,6 +337,58 @@ impl<C: CanisterKind> DbSession<C> {
         ))
     }
 
+    // Publish one generated column, then rebuild every existing row through
+    // the accepted write boundary so stored values never stay unset.
+    fn execute_prepared_generated_column_addition(
+        &self,
+        store: StoreHandle,
+        accepted_before: &AcceptedSchemaCatalogContext,
+        prepared: &PreparedSqlDdlCommand,
+        add: &BoundSqlAddColumnRequest,
+    ) -> Result<SqlStatementResult, QueryError> {
+        let Some(derivation) = prepared.derivation() else {
+            return Err(QueryError::unsupported_query());
+        };
+        let identity = accepted_before.identity();
+
+        // The rebuild is one bounded update, so an entity that cannot be
+        // proven to fit inside it rejects before publication.
+        if store
+            .exact_entity_count(identity.entity_tag())
+            .and_then(|count| usize::try_from(count).ok())
+            .is_none_or(|count| count > MAX_SCHEMA_SOURCE_ROWS)
+        {
+            return Err(QueryError::execute(
+                InternalError::schema_transition_budget_exceeded(
+                    SchemaTransitionBudgetResource::SourceRows,
+                ),
+            ));
+        }
+        let max_staged_rows =
+            u32::try_from(MAX_SCHEMA_SOURCE_ROWS).map_err(|_| QueryError::invariant())?;
+
+        execute_admin_sql_ddl_field_addition(
+            store,
+            identity.entity_tag(),
+            identity.entity_path(),
+            accepted_before.snapshot(),
+            identity.clone(),
+            derivation,
+        )
+        .map_err(QueryError::from_sql_ddl_execution_error)?;
+        self.invalidate_accepted_schema_runtime_root();
+        let rows_rebuilt =
+            self.execute_sql_generated_column_backfill(add.entity_name(), max_staged_rows)?;
+
+        Ok(SqlStatementResult::Ddl(
+            prepared
+                .report()
+                .clone()
+                .with_execution_status(SqlDdlExecutionStatus::Published)
+                .with_execution_metrics(usize::try_from(rows_rebuilt).unwrap_or(usize::MAX), 0),
+        ))
+    }
+
     fn execute_prepared_unique_index_activation(
         &self,
         store: StoreHandle,
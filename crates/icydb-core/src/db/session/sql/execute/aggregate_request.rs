@@ -62,7 +62,7 @@ impl PreparedAggregateRequestBundle {
 fn build_structural_aggregate_terminal_from_sql_strategy(
     strategy: PreparedSqlScalarAggregateStrategy,
 ) -> Result<StructuralAggregateTerminal, QueryError> {
-    let (descriptor, target_slot, input_expr, filter_expr, distinct_input) =
+    let (descriptor, target_slot, input_expr, filter_expr, distinct_input, arguments) =
         strategy.into_structural_terminal_inputs();
 
     let kind = match descriptor {
@@ -84,17 +84,29 @@ fn build_structural_aggregate_terminal_from_sql_strategy(
         PreparedSqlScalarAggregatePlanFragment::ExtremalWinnerField {
             kind: AggregateKind::Max,
         } => StructuralAggregateTerminalKind::Max,
+        PreparedSqlScalarAggregatePlanFragment::NumericField { kind } if kind.is_statistical() => {
+            StructuralAggregateTerminalKind::Statistic(kind)
+        }
+        PreparedSqlScalarAggregatePlanFragment::RetainedValueField { kind }
+            if kind.retains_input_values() =>
+        {
+            StructuralAggregateTerminalKind::RetainedValues(kind)
+        }
         PreparedSqlScalarAggregatePlanFragment::NumericField { .. }
-        | PreparedSqlScalarAggregatePlanFragment::ExtremalWinnerField { .. } => {
+        | PreparedSqlScalarAggregatePlanFragment::ExtremalWinnerField { .. }
+        | PreparedSqlScalarAggregatePlanFragment::RetainedValueField { .. } => {
             return Err(QueryError::invariant());
         }
     };
 
-    Ok(StructuralAggregateTerminal::new(
-        kind,
-        target_slot,
-        input_expr,
-        filter_expr,
-        distinct_input,
-    ))
+    Ok(
+        StructuralAggregateTerminal::new(
+            kind,
+            target_slot,
+            input_expr,
+            filter_expr,
+            distinct_input,
+        )
+        .with_arguments(arguments),
+    )
 }
@@ -88,6 +88,7 @@ pub(super) fn direct_count_rows_statement_result(
             fixed_scales,
             rows: vec![vec![OutputValue::Nat64(value)]],
             row_count: 1,
+            next_cursor: None,
         },
         cache_attribution,
     ))
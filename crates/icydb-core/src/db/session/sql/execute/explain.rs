@@ -8,15 +8,19 @@ use crate::{
         DbSession, MissingRowPolicy, QueryError,
         executor::{
             EntityAuthority, assemble_load_execution_node_descriptor_from_route_facts,
-            explain::assemble_scalar_aggregate_execution_descriptor_with_projection,
+            explain::{
+                annotate_common_table_expression_node,
+                assemble_scalar_aggregate_execution_descriptor_with_projection,
+            },
             freeze_load_execution_route_facts_for_authority,
         },
         query::{
             admission::{QueryAdmissionLane, QueryAdmissionPolicy, QueryAdmissionSummary},
             builder::scalar_projection::render_scalar_projection_expr_plan_label,
             explain::{
-                ExplainAggregateTerminalPlan, ExplainExecutionDescriptor, ExplainPlan,
-                FinalizedQueryDiagnostics, property_keys,
+                ExplainAggregateTerminalPlan, ExplainExecutionDescriptor,
+                ExplainExecutionNodeDescriptor, ExplainPlan, FinalizedQueryDiagnostics,
+                property_keys,
             },
             intent::StructuralQuery,
             plan::AccessPlannedQuery,
@@ -29,8 +33,9 @@ use crate::{
         },
         sql::{
             lowering::{
-                LoweredSqlCommand, PreparedSqlScalarAggregateStrategy, SqlGlobalAggregateCommand,
-                bind_lowered_sql_explain_global_aggregate_with_schema,
+                LoweredSqlCommand, PreparedSqlScalarAggregateStrategy,
+                SqlCommonTableExpressionMaterialization, SqlCommonTableExpressionSummary,
+                SqlGlobalAggregateCommand, bind_lowered_sql_explain_global_aggregate_with_schema,
                 bind_lowered_sql_query_structural_with_schema,
             },
             parser::SqlExplainMode,
@@ -146,18 +151,17 @@ impl<C: CanisterKind> DbSession<C> {
             return Ok(rendered);
         }
 
-        if let Some((mode, verbose, command)) =
-            bind_lowered_sql_explain_global_aggregate_with_schema(
-                lowered,
-                MissingRowPolicy::Ignore,
-                schema_info,
-            )
-            .map_err(QueryError::from_sql_lowering_error)?
+        if let Some((mode, _, command)) = bind_lowered_sql_explain_global_aggregate_with_schema(
+            lowered,
+            MissingRowPolicy::Ignore,
+            schema_info,
+        )
+        .map_err(QueryError::from_sql_lowering_error)?
         {
             return self.explain_sql_global_aggregate_structural_for_authority(
                 mode,
-                verbose,
                 command,
+                lowered.common_table_expressions(),
                 authority,
                 catalog,
                 schema_info,
@@ -263,6 +267,11 @@ impl<C: CanisterKind> DbSession<C> {
                         annotate_sql_projection_debug_on_execution_descriptor(
                             descriptor, &plan, projection,
                         );
+                        annotate_sql_common_table_expressions_on_execution_descriptor(
+                            descriptor,
+                            &plan,
+                            lowered.common_table_expressions(),
+                        );
                     },
                 )?;
 
@@ -285,6 +294,11 @@ impl<C: CanisterKind> DbSession<C> {
                     plan,
                     projection,
                 );
+                annotate_sql_common_table_expressions_on_execution_descriptor(
+                    &mut descriptor,
+                    plan,
+                    lowered.common_table_expressions(),
+                );
 
                 let diagnostics =
                     FinalizedQueryDiagnostics::new(descriptor, Vec::new(), Vec::new(), None)
@@ -332,8 +346,8 @@ impl<C: CanisterKind> DbSession<C> {
     fn explain_sql_global_aggregate_structural_for_authority(
         &self,
         mode: SqlExplainMode,
-        verbose: bool,
         command: SqlGlobalAggregateCommand,
+        common_table_expressions: &[SqlCommonTableExpressionSummary],
         authority: EntityAuthority,
         catalog: &AcceptedSchemaCatalogContext,
         schema_info: &SchemaInfo,
@@ -348,42 +362,49 @@ impl<C: CanisterKind> DbSession<C> {
                     &command,
                     |plan| Ok(plan.explain().render_text_canonical()),
                 ),
-            SqlExplainMode::Execution => {
-                let _ = verbose;
-
-                self.try_map_cached_sql_global_aggregate_explain_plan_for_accepted_authority(
-                    authority.clone(),
-                    catalog,
-                    &command,
-                    |plan| {
-                        self.render_global_aggregate_execution_explain(
-                            &command,
-                            strategies,
-                            plan,
-                            &authority,
-                            schema_info,
-                        )
-                    },
-                )
-            }
-            SqlExplainMode::ExecutionJson => {
-                let _ = verbose;
-
-                self.try_map_cached_sql_global_aggregate_explain_plan_for_accepted_authority(
+            SqlExplainMode::Execution | SqlExplainMode::ExecutionJson => self
+                .try_map_cached_sql_global_aggregate_explain_plan_for_accepted_authority(
                     authority.clone(),
                     catalog,
                     &command,
                     |plan| {
-                        self.render_global_aggregate_execution_explain_json(
-                            &command,
-                            strategies,
-                            plan,
-                            &authority,
-                            schema_info,
-                        )
+                        let query_explain = plan.explain();
+                        let mut rendered = Vec::with_capacity(strategies.len());
+                        for strategy in strategies {
+                            let mut descriptor = self.global_aggregate_terminal_node_descriptor(
+                                &command,
+                                strategy,
+                                &query_explain,
+                                plan,
+                                &authority,
+                                schema_info,
+                            )?;
+                            annotate_sql_common_table_expressions_on_execution_descriptor(
+                                &mut descriptor,
+                                plan,
+                                common_table_expressions,
+                            );
+                            let diagnostics = FinalizedQueryDiagnostics::new(
+                                descriptor,
+                                Vec::new(),
+                                Vec::new(),
+                                None,
+                            )
+                            .with_admission(diagnostic_explain_admission_for_plan(plan));
+                            rendered.push(if mode == SqlExplainMode::Execution {
+                                render_sql_execution_explain(&diagnostics)
+                            } else {
+                                render_sql_execution_explain_json(&diagnostics)
+                            });
+                        }
+
+                        Ok(if mode == SqlExplainMode::Execution {
+                            rendered.join("\n\n")
+                        } else {
+                            render_sql_execution_explain_json_array(&rendered)
+                        })
                     },
-                )
-            }
+                ),
             SqlExplainMode::Json => self
                 .try_map_cached_sql_global_aggregate_explain_plan_for_accepted_authority(
                     authority,
@@ -394,57 +415,9 @@ impl<C: CanisterKind> DbSession<C> {
         }
     }
 
-    fn render_global_aggregate_execution_explain(
-        &self,
-        command: &SqlGlobalAggregateCommand,
-        strategies: &[PreparedSqlScalarAggregateStrategy],
-        plan: &AccessPlannedQuery,
-        authority: &EntityAuthority,
-        schema_info: &SchemaInfo,
-    ) -> Result<String, QueryError> {
-        let query_explain = plan.explain();
-        let mut rendered = Vec::with_capacity(strategies.len());
-
-        for strategy in strategies {
-            rendered.push(self.render_global_aggregate_terminal_explain(
-                command,
-                strategy,
-                &query_explain,
-                plan,
-                authority,
-                schema_info,
-            )?);
-        }
-
-        Ok(rendered.join("\n\n"))
-    }
-
-    fn render_global_aggregate_execution_explain_json(
-        &self,
-        command: &SqlGlobalAggregateCommand,
-        strategies: &[PreparedSqlScalarAggregateStrategy],
-        plan: &AccessPlannedQuery,
-        authority: &EntityAuthority,
-        schema_info: &SchemaInfo,
-    ) -> Result<String, QueryError> {
-        let query_explain = plan.explain();
-        let mut rendered = Vec::with_capacity(strategies.len());
-
-        for strategy in strategies {
-            rendered.push(self.render_global_aggregate_terminal_explain_json(
-                command,
-                strategy,
-                &query_explain,
-                plan,
-                authority,
-                schema_info,
-            )?);
-        }
-
-        Ok(render_sql_execution_explain_json_array(&rendered))
-    }
-
-    fn render_global_aggregate_terminal_explain(
+    // Build the execution tree for one aggregate terminal of a global
+    // aggregate command.
+    fn global_aggregate_terminal_node_descriptor(
         &self,
         command: &SqlGlobalAggregateCommand,
         strategy: &PreparedSqlScalarAggregateStrategy,
@@ -452,7 +425,7 @@ impl<C: CanisterKind> DbSession<C> {
         plan: &AccessPlannedQuery,
         authority: &EntityAuthority,
         schema_info: &SchemaInfo,
-    ) -> Result<String, QueryError> {
+    ) -> Result<ExplainExecutionNodeDescriptor, QueryError> {
         let execution = self.global_aggregate_terminal_execution_descriptor(
             command,
             strategy,
@@ -466,47 +439,7 @@ impl<C: CanisterKind> DbSession<C> {
             execution,
         );
 
-        Ok(render_sql_execution_explain(
-            &FinalizedQueryDiagnostics::new(
-                terminal_plan.execution_node_descriptor(),
-                Vec::new(),
-                Vec::new(),
-                None,
-            )
-            .with_admission(diagnostic_explain_admission_for_plan(plan)),
-        ))
-    }
-
-    fn render_global_aggregate_terminal_explain_json(
-        &self,
-        command: &SqlGlobalAggregateCommand,
-        strategy: &PreparedSqlScalarAggregateStrategy,
-        query_explain: &ExplainPlan,
-        plan: &AccessPlannedQuery,
-        authority: &EntityAuthority,
-        schema_info: &SchemaInfo,
-    ) -> Result<String, QueryError> {
-        let execution = self.global_aggregate_terminal_execution_descriptor(
-            command,
-            strategy,
-            plan,
-            authority,
-            schema_info,
-        )?;
-        let terminal_plan = ExplainAggregateTerminalPlan::new(
-            query_explain.clone(),
-            strategy.aggregate_kind(),
-            execution,
-        );
-        let diagnostics = FinalizedQueryDiagnostics::new(
-            terminal_plan.execution_node_descriptor(),
-            Vec::new(),
-            Vec::new(),
-            None,
-        )
-        .with_admission(diagnostic_explain_admission_for_plan(plan));
-
-        Ok(render_sql_execution_explain_json(&diagnostics))
+        Ok(terminal_plan.execution_node_descriptor())
     }
 
     fn global_aggregate_terminal_execution_descriptor(
@@ -579,3 +512,21 @@ impl<C: CanisterKind> DbSession<C> {
         Ok(())
     }
 }
+
+// Report every common table expression one EXPLAIN target inlined as its own
+// node on the execution root, innermost first.
+fn annotate_sql_common_table_expressions_on_execution_descriptor(
+    descriptor: &mut ExplainExecutionNodeDescriptor,
+    plan: &AccessPlannedQuery,
+    common_table_expressions: &[SqlCommonTableExpressionSummary],
+) {
+    for cte in common_table_expressions {
+        annotate_common_table_expression_node(
+            descriptor,
+            plan,
+            cte.name.as_str(),
+            cte.materialization.as_str(),
+            cte.materialization != SqlCommonTableExpressionMaterialization::Inline,
+        );
+    }
+}
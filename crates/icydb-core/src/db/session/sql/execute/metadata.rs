@@ -221,6 +221,7 @@ impl<C: CanisterKind> DbSession<C> {
             }
             CompiledSqlCommand::ShowMemory => Ok(self.show_memory_sql_statement_result()),
             CompiledSqlCommand::Select { .. }
+            | CompiledSqlCommand::Union(_)
             | CompiledSqlCommand::Delete { .. }
             | CompiledSqlCommand::GlobalAggregate { .. }
             | CompiledSqlCommand::Insert(_)
@@ -15,6 +15,7 @@ mod global_aggregate;
 mod metadata;
 mod select;
 mod select_plan;
+mod union;
 mod write;
 mod write_returning;
 
@@ -41,6 +42,8 @@ use crate::{
 use diagnostics::measure_scalar_aggregate_execute_phase_with_physical_access;
 use write::execute_compiled_sql_write_with_default_cache;
 
+pub(in crate::db) use select::SqlSelectContinuation;
+
 impl<C: CanisterKind> DbSession<C> {
     fn ensure_sql_query_execution_context_is_current(
         &self,
@@ -94,6 +97,7 @@ impl<C: CanisterKind> DbSession<C> {
     pub(in crate::db) fn execute_compiled_sql_query_context_with_phase_attribution(
         &self,
         context: &SqlCompiledCommandExecutionContext,
+        continuation: SqlSelectContinuation<'_>,
     ) -> Result<
         (
             SqlStatementResult,
@@ -105,9 +109,13 @@ impl<C: CanisterKind> DbSession<C> {
         self.ensure_sql_query_execution_context_is_current(context)?;
 
         match context.command() {
-            CompiledSqlCommand::Select { query, .. } => {
-                self.execute_select_compiled_sql_with_context_phase_attribution(query, context)
-            }
+            CompiledSqlCommand::Select { query, .. } => self
+                .execute_select_compiled_sql_with_context_phase_attribution(
+                    query,
+                    context,
+                    continuation,
+                ),
+            _ if continuation.cursor().is_some() => Err(Self::scalar_page_cursor_error()),
             CompiledSqlCommand::GlobalAggregate { command, .. } => self
                 .execute_global_aggregate_compiled_statement_ref_with_phase_attribution(
                     context.command(),
@@ -161,9 +169,17 @@ impl<C: CanisterKind> DbSession<C> {
         self.ensure_sql_query_execution_context_is_current(context)?;
 
         match context.command() {
-            CompiledSqlCommand::Select { query, .. } => {
-                self.execute_select_compiled_sql_with_context(query, context)
-            }
+            CompiledSqlCommand::Select { query, .. } => self
+                .execute_select_compiled_sql_with_context(
+                    query,
+                    context,
+                    SqlSelectContinuation::Unpaged,
+                ),
+            CompiledSqlCommand::Union(command) => self.execute_union_compiled_sql_with_catalog(
+                command,
+                context.accepted_catalog(),
+                context.accepted_authority(),
+            ),
             #[cfg(feature = "sql")]
             CompiledSqlCommand::Explain(lowered) => self
                 .execute_accepted_explain_sql_with_catalog_cache_attribution(
@@ -182,13 +198,15 @@ impl<C: CanisterKind> DbSession<C> {
     pub(in crate::db) fn execute_compiled_sql_query_context_with_cache_attribution(
         &self,
         context: &SqlCompiledCommandExecutionContext,
+        continuation: SqlSelectContinuation<'_>,
     ) -> Result<(SqlStatementResult, SqlCacheAttribution), QueryError> {
         self.ensure_sql_query_execution_context_is_current(context)?;
 
         match context.command() {
             CompiledSqlCommand::Select { query, .. } => {
-                self.execute_select_compiled_sql_with_context(query, context)
+                self.execute_select_compiled_sql_with_context(query, context, continuation)
             }
+            _ if continuation.cursor().is_some() => Err(Self::scalar_page_cursor_error()),
             CompiledSqlCommand::GlobalAggregate { command, .. } => self
                 .execute_global_aggregate_compiled_statement_ref_with_catalog(
                     context.command(),
@@ -217,6 +235,13 @@ impl<C: CanisterKind> DbSession<C> {
         {
             return result;
         }
+        if let CompiledSqlCommand::Union(command) = compiled {
+            return self.execute_union_compiled_sql_with_catalog(
+                command,
+                catalog,
+                accepted_authority,
+            );
+        }
 
         #[cfg(feature = "sql")]
         if let CompiledSqlCommand::Explain(lowered) = compiled {
@@ -275,9 +300,10 @@ impl<C: CanisterKind> DbSession<C> {
     pub(in crate::db) fn execute_compiled_sql_query_context_owned(
         &self,
         context: SqlCompiledCommandExecutionContext,
+        continuation: SqlSelectContinuation<'_>,
     ) -> Result<SqlStatementResult, QueryError> {
         let (result, _) =
-            self.execute_compiled_sql_query_context_with_cache_attribution(&context)?;
+            self.execute_compiled_sql_query_context_with_cache_attribution(&context, continuation)?;
 
         Ok(result)
     }
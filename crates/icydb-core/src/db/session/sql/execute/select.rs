@@ -12,9 +12,12 @@ use crate::db::session::{
 use crate::{
     db::{
         DbSession, QueryError,
+        cursor::{ScalarPageMode, ScalarPageToken, ScalarPageTokenProgress},
         executor::{
-            EntityAuthority, SharedPreparedExecutionPlan, StructuralGroupedProjectionResult,
-            StructuralProjectionScanBudget,
+            CoveringProjectionMetricsRecorder, EntityAuthority, PageWorkEnvelope,
+            ProjectionMaterializationMetricsRecorder, SharedPreparedExecutionPlan,
+            StructuralGroupedProjectionResult, StructuralProjectionRequest,
+            StructuralProjectionScanBudget, execute_structural_projection_page,
         },
         query::intent::StructuralQuery,
         schema::AcceptedSchemaSnapshot,
@@ -31,7 +34,7 @@ use crate::{
     },
     traits::CanisterKind,
 };
-use icydb_diagnostic_code::DiagnosticExecutionLane;
+use icydb_diagnostic_code::{DiagnosticExecutionBudgetResource, DiagnosticExecutionLane};
 
 use super::diagnostics::GroupedSqlDiagnosticsCollector;
 #[cfg(feature = "diagnostics")]
@@ -41,6 +44,42 @@ use super::select_plan::ResolvedSelectPreparedPlan;
 #[cfg(feature = "diagnostics")]
 use crate::db::session::sql::projection::execute_sql_projection_rows_for_canister_with_direct_data_row_attribution;
 
+///
+/// SqlSelectContinuation
+///
+/// Continuation contract requested by one SQL SELECT execution. Unpaged
+/// execution neither accepts nor emits cursors, so covering and buffered
+/// projection lanes stay available; paged execution emits one authenticated
+/// cursor and resumes from the supplied one.
+///
+
+#[derive(Clone, Copy)]
+pub(in crate::db) enum SqlSelectContinuation<'a> {
+    Unpaged,
+    Paged(Option<&'a str>),
+}
+
+impl<'a> SqlSelectContinuation<'a> {
+    /// Return the caller-supplied cursor, if any.
+    pub(in crate::db) const fn cursor(self) -> Option<&'a str> {
+        match self {
+            Self::Unpaged => None,
+            Self::Paged(cursor) => cursor,
+        }
+    }
+}
+
+// Return the page size of one scalar SQL SELECT that can emit a live cursor.
+// Only ordered, `LIMIT`-bounded statements without `OFFSET` page: the limit
+// is the page size and the cursor replaces the offset on later pages.
+fn sql_scalar_page_size(prepared_plan: &SharedPreparedExecutionPlan) -> Option<u32> {
+    let scalar = prepared_plan.logical_plan().scalar_plan();
+    scalar.order.as_ref()?;
+    let page = scalar.page.as_ref()?;
+
+    if page.offset == 0 { page.limit } else { None }
+}
+
 impl<C: CanisterKind> DbSession<C> {
     // Convert one grouped executor result plus SQL projection labels into the
     // statement result shape shared by normal and diagnostics SQL execution.
@@ -99,7 +138,7 @@ impl<C: CanisterKind> DbSession<C> {
     // Execute one SQL projection and immediately shape it into the public
     // statement-result envelope. Diagnostics keeps using the payload-returning
     // sibling so it can measure response finalization separately.
-    fn execute_sql_statement_from_structural_prepared_plan(
+    pub(in crate::db::session::sql) fn execute_sql_statement_from_structural_prepared_plan(
         &self,
         prepared_plan: SharedPreparedExecutionPlan,
         projection: StructuralProjectionContract,
@@ -119,6 +158,163 @@ impl<C: CanisterKind> DbSession<C> {
         ))
     }
 
+    // Re-plan one resumed scalar SQL page on the route pinned by its cursor
+    // when the freshly resolved plan chose a different cardinality tie-break.
+    fn sql_page_prepared_plan_for_route_pin(
+        &self,
+        query: &StructuralQuery,
+        context: &SqlCompiledCommandExecutionContext,
+        prepared_plan: SharedPreparedExecutionPlan,
+        decoded_token: Option<&ScalarPageToken>,
+    ) -> Result<SharedPreparedExecutionPlan, QueryError> {
+        let Some(route_pin) = decoded_token.and_then(ScalarPageToken::route_pin) else {
+            return Ok(prepared_plan);
+        };
+        if prepared_plan
+            .logical_plan()
+            .cardinality_tiebreak_route_pin()
+            == Some(route_pin)
+        {
+            return Ok(prepared_plan);
+        }
+
+        Ok(self
+            .structural_projection_prepared_plan_for_accepted_authority_with_route_pin(
+                query,
+                Self::select_authority_for_context(context),
+                context.accepted_catalog().snapshot(),
+                DiagnosticExecutionLane::TrustedRead,
+                route_pin,
+            )?
+            .ok_or_else(Self::scalar_page_cursor_error)?
+            .0)
+    }
+
+    // Execute one scalar SQL page through the authenticated live continuation
+    // shared with dynamic pages. The token binds the prepared plan signature,
+    // accepted authority, route pin, and canonical order; `LIMIT` stays the
+    // page size on every page.
+    fn execute_sql_projection_page_from_prepared_plan(
+        &self,
+        query: &StructuralQuery,
+        context: &SqlCompiledCommandExecutionContext,
+        prepared_plan: SharedPreparedExecutionPlan,
+        projection: StructuralProjectionContract,
+        page_size: u32,
+        cursor: Option<&str>,
+    ) -> Result<SqlStatementResult, QueryError> {
+        let catalog = context.accepted_catalog();
+        let envelope = PageWorkEnvelope::default_scalar();
+        let page_size = usize::try_from(page_size).unwrap_or(usize::MAX);
+        let page_output_limit = envelope
+            .limit(DiagnosticExecutionBudgetResource::ResultRows)
+            .and_then(|limit| usize::try_from(limit).ok())
+            .map_or(page_size, |limit| limit.min(page_size));
+        let decoded_token = Self::decode_scalar_page_token(cursor)?;
+
+        // Phase 1: keep the cardinality route chosen by the first page so a
+        // later tie-break flip cannot reorder the remaining rows.
+        let prepared_plan = self.sql_page_prepared_plan_for_route_pin(
+            query,
+            context,
+            prepared_plan,
+            decoded_token.as_ref(),
+        )?;
+
+        // Phase 2: authenticate the supplied cursor against the current
+        // statement before any row is read.
+        let cursor_contract = Self::scalar_cursor_contract(
+            None,
+            catalog,
+            envelope,
+            &prepared_plan,
+            ScalarPageMode::Live,
+            None,
+        )?;
+        if let Some(token) = decoded_token.as_ref() {
+            Self::validate_scalar_page_token(
+                token,
+                ScalarPageMode::Live,
+                &cursor_contract,
+                catalog.snapshot().entity_name(),
+            )?;
+        }
+        let continuation_context =
+            Self::scalar_continuation_context(decoded_token.as_ref(), catalog)?;
+        let prior_rows_emitted = decoded_token
+            .as_ref()
+            .map_or(0, |token| token.progress().rows_emitted());
+
+        // Phase 3: execute one cursor-emitting page and shape the SQL rows.
+        let value_catalog = prepared_plan
+            .authority_ref()
+            .accepted_schema_info()
+            .map(crate::db::schema::SchemaInfo::value_catalog_handle)
+            .cloned()
+            .ok_or_else(QueryError::invariant)?;
+        let (columns, fixed_scales) = projection.into_components();
+        let page = execute_structural_projection_page(
+            &self.db,
+            StructuralProjectionRequest::new(
+                self.debug,
+                prepared_plan,
+                CoveringProjectionMetricsRecorder::none(),
+                ProjectionMaterializationMetricsRecorder::none(),
+                DiagnosticExecutionLane::TrustedRead,
+            )
+            .with_distinct_output_offset(usize::try_from(prior_rows_emitted).unwrap_or(usize::MAX))
+            .with_page_work_envelope(envelope)
+            .with_continuation(continuation_context)
+            .with_cursor_emission(page_output_limit),
+        )
+        .map_err(QueryError::execute)?;
+        let row_count = page.rows.row_count();
+        let next_cursor = if page.has_more {
+            if page.last_emitted_logical.is_none() && page.last_consumed_physical.is_none() {
+                return Err(Self::scalar_page_cursor_error());
+            }
+            let token = ScalarPageToken::new(
+                ScalarPageMode::Live,
+                cursor_contract.signature,
+                cursor_contract.authority,
+                cursor_contract.route_pin,
+                cursor_contract.window,
+                cursor_contract.order_terms,
+                ScalarPageTokenProgress::new(
+                    page.last_emitted_logical,
+                    page.last_consumed_physical,
+                    None,
+                    decoded_token
+                        .as_ref()
+                        .map_or(0, |token| token.progress().matching_rows_skipped()),
+                    prior_rows_emitted.saturating_add(u64::from(row_count)),
+                ),
+            );
+
+            Some(Self::encode_scalar_page_token(&token)?)
+        } else {
+            None
+        };
+        let mut statement_result = sql_statement_result_from_structural_projection_payload(
+            StructuralProjectionPayload::new(
+                columns,
+                fixed_scales,
+                page.rows.into_value_rows(),
+                row_count,
+                value_catalog,
+            ),
+        )?;
+        if let SqlStatementResult::Projection {
+            next_cursor: result_cursor,
+            ..
+        } = &mut statement_result
+        {
+            *result_cursor = next_cursor;
+        }
+
+        Ok(statement_result)
+    }
+
     // Execute one grouped SQL statement from one shared lowered prepared plan
     // plus one shared structural projection contract. Normal and diagnostics surfaces
     // share this plan-to-statement shell; diagnostics only swaps response
@@ -235,10 +431,67 @@ impl<C: CanisterKind> DbSession<C> {
         )
     }
 
+    // Execute one grouped SELECT for the diagnostics lane, attributing the
+    // grouped executor phases separately from response finalization.
+    #[cfg(feature = "diagnostics")]
+    fn execute_grouped_select_compiled_sql_with_phase_attribution(
+        &self,
+        continuation: SqlSelectContinuation<'_>,
+        resolve_plan: impl FnOnce() -> Result<
+            (ResolvedSelectPreparedPlan, QueryPlanCompilePhaseAttribution),
+            QueryError,
+        >,
+    ) -> Result<
+        (
+            SqlStatementResult,
+            SqlCacheAttribution,
+            SqlExecutePhaseAttribution,
+        ),
+        QueryError,
+    > {
+        let (planner_local_instructions, resolved_query_plan) = measure_sql_stage(resolve_plan);
+        let (resolved, plan_compile_attribution) = resolved_query_plan?;
+        let (prepared_plan, projection, cache_attribution) = resolved.into_parts();
+
+        let ((execute_local_instructions, store_local_instructions), statement_result) =
+            measure_execute_phase_with_physical_access(move || {
+                self.execute_grouped_sql_statement_with_response_attribution(
+                    prepared_plan,
+                    projection,
+                    |session, prepared_plan| {
+                        session
+                            .execute_structural_grouped_with_phase_attribution(
+                                prepared_plan,
+                                continuation.cursor(),
+                                DiagnosticExecutionLane::TrustedRead,
+                            )
+                            .map(|(result, _trace, phase_attribution)| (result, phase_attribution))
+                    },
+                )
+            });
+        let (statement_result, grouped_phase_attribution, response_finalization_local_instructions) =
+            statement_result?;
+
+        Ok((
+            statement_result,
+            cache_attribution,
+            SqlExecutePhaseAttribution::from_grouped_select_phase(
+                planner_local_instructions,
+                plan_compile_attribution,
+                execute_local_instructions,
+                store_local_instructions,
+                response_finalization_local_instructions,
+                grouped_phase_attribution,
+            ),
+        ))
+    }
+
     #[cfg(feature = "diagnostics")]
     pub(super) fn execute_select_compiled_sql_with_phase_attribution_from_resolver(
         &self,
         query: &StructuralQuery,
+        context: &SqlCompiledCommandExecutionContext,
+        continuation: SqlSelectContinuation<'_>,
         resolve_plan: impl FnOnce() -> Result<
             (ResolvedSelectPreparedPlan, QueryPlanCompilePhaseAttribution),
             QueryError,
@@ -252,51 +505,44 @@ impl<C: CanisterKind> DbSession<C> {
         QueryError,
     > {
         if query.has_grouping() {
-            let (planner_local_instructions, resolved_query_plan) = measure_sql_stage(resolve_plan);
-            let (resolved, plan_compile_attribution) = resolved_query_plan?;
-            let (prepared_plan, projection, cache_attribution) = resolved.into_parts();
+            return self.execute_grouped_select_compiled_sql_with_phase_attribution(
+                continuation,
+                resolve_plan,
+            );
+        }
 
+        let (planner_local_instructions, resolved_query_plan) = measure_sql_stage(resolve_plan);
+        let (resolved, plan_compile_attribution) = resolved_query_plan?;
+        let (prepared_plan, projection, cache_attribution) = resolved.into_parts();
+        if let SqlSelectContinuation::Paged(cursor) = continuation
+            && let Some(page_size) = sql_scalar_page_size(&prepared_plan)
+        {
             let ((execute_local_instructions, store_local_instructions), statement_result) =
-                measure_execute_phase_with_physical_access(move || {
-                    self.execute_grouped_sql_statement_with_response_attribution(
+                measure_execute_phase_with_physical_access(|| {
+                    self.execute_sql_projection_page_from_prepared_plan(
+                        query,
+                        context,
                         prepared_plan,
                         projection,
-                        |session, prepared_plan| {
-                            session
-                                .execute_structural_grouped_with_phase_attribution(
-                                    prepared_plan,
-                                    None,
-                                    DiagnosticExecutionLane::TrustedRead,
-                                )
-                                .map(|(result, _trace, phase_attribution)| {
-                                    (result, phase_attribution)
-                                })
-                        },
+                        page_size,
+                        cursor,
                     )
                 });
-            let (
-                statement_result,
-                grouped_phase_attribution,
-                response_finalization_local_instructions,
-            ) = statement_result?;
 
             return Ok((
-                statement_result,
+                statement_result?,
                 cache_attribution,
-                SqlExecutePhaseAttribution::from_grouped_select_phase(
+                SqlExecutePhaseAttribution::from_query_plan_execute_total_and_store_total(
                     planner_local_instructions,
                     plan_compile_attribution,
                     execute_local_instructions,
                     store_local_instructions,
-                    response_finalization_local_instructions,
-                    grouped_phase_attribution,
                 ),
             ));
         }
-
-        let (planner_local_instructions, resolved_query_plan) = measure_sql_stage(resolve_plan);
-        let (resolved, plan_compile_attribution) = resolved_query_plan?;
-        let (prepared_plan, projection, cache_attribution) = resolved.into_parts();
+        if continuation.cursor().is_some() {
+            return Err(Self::scalar_page_cursor_error());
+        }
 
         let enum_catalog = prepared_plan
             .authority_ref()
@@ -351,16 +597,23 @@ impl<C: CanisterKind> DbSession<C> {
         &self,
         query: &StructuralQuery,
         context: &SqlCompiledCommandExecutionContext,
+        continuation: SqlSelectContinuation<'_>,
     ) -> Result<(SqlStatementResult, SqlCacheAttribution), QueryError> {
         let resolved = self.resolve_select_prepared_plan_for_context(query, context)?;
         let (prepared_plan, projection, cache_attribution) = resolved.into_parts();
-
-        self.execute_select_compiled_sql_from_prepared_plan(
+        let (result, cache_attribution) = self.execute_select_compiled_sql_from_prepared_plan(
             query,
+            context,
             prepared_plan,
             projection,
             cache_attribution,
-        )
+            continuation,
+        )?;
+
+        Ok((
+            self.attach_sql_relation_paths(result, context.command().relation_paths())?,
+            cache_attribution,
+        ))
     }
 
     #[cfg(feature = "diagnostics")]
@@ -368,6 +621,7 @@ impl<C: CanisterKind> DbSession<C> {
         &self,
         query: &StructuralQuery,
         context: &SqlCompiledCommandExecutionContext,
+        continuation: SqlSelectContinuation<'_>,
     ) -> Result<
         (
             SqlStatementResult,
@@ -376,19 +630,33 @@ impl<C: CanisterKind> DbSession<C> {
         ),
         QueryError,
     > {
-        self.execute_select_compiled_sql_with_phase_attribution_from_resolver(query, || {
-            self.resolve_select_prepared_plan_for_context_with_compile_phase_attribution(
-                query, context,
-            )
-        })
+        let (result, cache_attribution, phase_attribution) = self
+            .execute_select_compiled_sql_with_phase_attribution_from_resolver(
+                query,
+                context,
+                continuation,
+                || {
+                    self.resolve_select_prepared_plan_for_context_with_compile_phase_attribution(
+                        query, context,
+                    )
+                },
+            )?;
+
+        Ok((
+            self.attach_sql_relation_paths(result, context.command().relation_paths())?,
+            cache_attribution,
+            phase_attribution,
+        ))
     }
 
     fn execute_select_compiled_sql_from_prepared_plan(
         &self,
         query: &StructuralQuery,
+        context: &SqlCompiledCommandExecutionContext,
         prepared_plan: SharedPreparedExecutionPlan,
         projection: StructuralProjectionContract,
         cache_attribution: SqlCacheAttribution,
+        continuation: SqlSelectContinuation<'_>,
     ) -> Result<(SqlStatementResult, SqlCacheAttribution), QueryError> {
         if query.has_grouping() {
             let (statement_result, ()) = self.execute_grouped_sql_statement_from_prepared_plan(
@@ -398,7 +666,7 @@ impl<C: CanisterKind> DbSession<C> {
                     session
                         .execute_structural_grouped_with_trace(
                             prepared_plan,
-                            None,
+                            continuation.cursor(),
                             DiagnosticExecutionLane::TrustedRead,
                         )
                         .map(|(result, _trace)| (result, ()))
@@ -407,6 +675,23 @@ impl<C: CanisterKind> DbSession<C> {
 
             return Ok((statement_result, cache_attribution));
         }
+        if let SqlSelectContinuation::Paged(cursor) = continuation
+            && let Some(page_size) = sql_scalar_page_size(&prepared_plan)
+        {
+            return self
+                .execute_sql_projection_page_from_prepared_plan(
+                    query,
+                    context,
+                    prepared_plan,
+                    projection,
+                    page_size,
+                    cursor,
+                )
+                .map(|result| (result, cache_attribution));
+        }
+        if continuation.cursor().is_some() {
+            return Err(Self::scalar_page_cursor_error());
+        }
 
         self.execute_sql_statement_from_structural_prepared_plan(
             prepared_plan,
@@ -176,7 +176,7 @@ impl<C: CanisterKind> DbSession<C> {
         Self::sql_select_projection_from_prepared_plan(prepared_plan, authority, cache_attribution)
     }
 
-    fn select_authority_for_context(
+    pub(super) fn select_authority_for_context(
         context: &SqlCompiledCommandExecutionContext,
     ) -> EntityAuthority {
         match context.accepted_authority() {
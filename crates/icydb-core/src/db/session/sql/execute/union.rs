@@ -0,0 +1,71 @@
+//! Module: db::session::sql::execute::union
+//! Responsibility: SQL `UNION [ALL]` branch execution and result shaping.
+//! Does not own: branch rewriting, branch planning, or the ordered row merge.
+//! Boundary: runs each rewritten branch as one scalar SELECT projection, then
+//! hands the branch rows to the executor-owned union merge.
+
+use crate::{
+    db::{
+        DbSession, QueryError,
+        executor::{EntityAuthority, merge_union_projection_rows},
+        session::{
+            AcceptedSchemaCatalogContext,
+            query::StructuralProjectionPayload,
+            sql::{
+                CompiledSqlUnionCommand, SqlCacheAttribution, SqlStatementResult,
+                projection::sql_statement_result_from_structural_projection_payload,
+            },
+        },
+    },
+    traits::CanisterKind,
+};
+
+impl<C: CanisterKind> DbSession<C> {
+    // Execute every branch of one compiled UNION through the scalar SELECT
+    // projection lane, then merge the ordered branch rows. Output labels and
+    // scales come from the first branch, as for the combined ORDER BY.
+    pub(super) fn execute_union_compiled_sql_with_catalog(
+        &self,
+        command: &CompiledSqlUnionCommand,
+        catalog: &AcceptedSchemaCatalogContext,
+        accepted_authority: Option<&EntityAuthority>,
+    ) -> Result<(SqlStatementResult, SqlCacheAttribution), QueryError> {
+        let authority = catalog.accepted_or_provided_entity_authority(accepted_authority);
+        let window = command.window();
+        let mut labels = None;
+        let mut branch_rows = Vec::with_capacity(command.branches().len());
+        let mut cache_attribution = SqlCacheAttribution::none();
+
+        for query in command.branches() {
+            let (payload, branch_attribution) = self
+                .execute_sql_projection_from_structural_query_without_sql_compiled_cache(
+                    query.as_ref().clone(),
+                    authority.clone(),
+                    catalog.snapshot(),
+                )?;
+            let (columns, fixed_scales, rows, _) = payload.into_runtime_components();
+            labels.get_or_insert((columns, fixed_scales));
+            branch_rows.push(rows);
+            cache_attribution = cache_attribution.merge(branch_attribution);
+        }
+
+        let (mut columns, mut fixed_scales) = labels.ok_or_else(QueryError::invariant)?;
+        columns.truncate(window.visible_width);
+        fixed_scales.truncate(window.visible_width);
+        let rows = merge_union_projection_rows(branch_rows, command.order(), window)
+            .map_err(QueryError::execute)?;
+        let row_count = u32::try_from(rows.len()).unwrap_or(u32::MAX);
+        let payload = StructuralProjectionPayload::new(
+            columns,
+            fixed_scales,
+            rows,
+            row_count,
+            catalog.value_catalog_handle().clone(),
+        );
+
+        Ok((
+            sql_statement_result_from_structural_projection_payload(payload)?,
+            cache_attribution,
+        ))
+    }
+}
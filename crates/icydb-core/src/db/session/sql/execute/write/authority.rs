@@ -11,7 +11,8 @@ use crate::{
         executor::EntityAuthority,
         schema::{
             AcceptedFieldKind, AcceptedRowLayoutRuntimeContract, SchemaFieldWritePolicy,
-            SchemaInfo, input_value_from_strict_sql_literal_for_persisted_kind,
+            SchemaInfo, input_value_from_computed_sql_value_for_persisted_kind,
+            input_value_from_strict_sql_literal_for_persisted_kind,
         },
         session::{
             AcceptedSchemaCatalogContext,
@@ -117,6 +118,34 @@ pub(super) fn sql_write_input_for_accepted_field(
     sql_write_input_for_accepted_kind(accepted_field.kind(), value)
 }
 
+/// Admit one computed `UPDATE` assignment result for its accepted target field.
+///
+/// Integral decimal results narrow through the shared accepted computed-value
+/// admission, so assignments and generated columns agree on which results fit.
+pub(super) fn sql_write_input_for_computed_accepted_field(
+    descriptor: &AcceptedRowLayoutRuntimeContract<'_>,
+    field_name: &str,
+    value: &Value,
+) -> Result<InputValue, QueryError> {
+    let accepted_field = descriptor
+        .field_by_name(field_name)
+        .ok_or_else(QueryError::invariant)?;
+    if matches!(value, Value::Null) {
+        return accepted_field
+            .decode_contract()
+            .nullable()
+            .then_some(InputValue::Null)
+            .ok_or_else(incompatible_sql_update_computed_value);
+    }
+
+    input_value_from_computed_sql_value_for_persisted_kind(accepted_field.kind(), value)
+        .ok_or_else(incompatible_sql_update_computed_value)
+}
+
+fn incompatible_sql_update_computed_value() -> QueryError {
+    QueryError::sql_write_boundary(SqlWriteBoundaryCode::UpdateComputedValueIncompatible)
+}
+
 fn invalid_sql_write_field_literal() -> QueryError {
     QueryError::sql_write_boundary(SqlWriteBoundaryCode::InvalidFieldLiteral)
 }
@@ -195,7 +195,7 @@ impl<C: CanisterKind> DbSession<C> {
         Ok(patch)
     }
 
-    fn sql_insert_values_patch(
+    pub(super) fn sql_insert_values_patch(
         descriptor: &AcceptedRowLayoutRuntimeContract<'_>,
         columns: &[String],
         values: &[SqlWriteValue],
@@ -221,6 +221,10 @@ impl<C: CanisterKind> DbSession<C> {
                 SqlWriteValue::Default => {
                     sql_write_patch_set_insert_default(descriptor, patch, field)?
                 }
+                // `VALUES` rows parse literals, `DEFAULT`, and literal `CAST`
+                // expressions; prepare folds every `CAST` into a literal, so
+                // no expression reaches execution.
+                SqlWriteValue::Expr(_) => return Err(QueryError::invariant()),
             };
         }
 
@@ -341,9 +345,19 @@ impl<C: CanisterKind> DbSession<C> {
                 if !matches!(statement.source, SqlInsertSource::DefaultValues) {
                     ensure_sql_insert_required_fields(&descriptor, columns.as_slice())?;
                 }
-                let write_context = AcceptedWriteContext::new(Timestamp::now());
                 let candidate_bounds =
                     sql_insert_candidate_bounds(execution_bounds, statement.returning.is_some());
+                if statement.on_conflict.is_some() {
+                    return self.execute_sql_insert_on_conflict_statement(
+                        catalog,
+                        &descriptor,
+                        statement,
+                        columns.as_slice(),
+                        candidate_bounds,
+                        execution_bounds.map(|bounds| bounds.returning),
+                    );
+                }
+                let write_context = AcceptedWriteContext::new(Timestamp::now());
                 let mut collection = SqlWriteCandidateCollection::new();
 
                 match &statement.source {
@@ -3,6 +3,7 @@ mod candidate;
 mod delete;
 mod insert;
 mod update;
+mod upsert;
 
 use crate::{
     db::{
@@ -36,8 +37,8 @@ use crate::{
 use authority::{
     reject_explicit_sql_write_to_generated_field, reject_explicit_sql_write_to_managed_field,
     require_sql_write_policy_plan, sql_write_input_for_accepted_field,
-    sql_write_patch_set_accepted_field, sql_write_patch_set_insert_default,
-    sql_write_patch_set_update_default,
+    sql_write_input_for_computed_accepted_field, sql_write_patch_set_accepted_field,
+    sql_write_patch_set_insert_default, sql_write_patch_set_update_default,
 };
 use candidate::{
     SqlWriteCandidateAccounting, SqlWriteCandidateBoundCheck, SqlWriteCandidateBounds,
@@ -147,6 +148,7 @@ where
             ))
         }
         CompiledSqlCommand::Select { .. }
+        | CompiledSqlCommand::Union(_)
         | CompiledSqlCommand::GlobalAggregate { .. }
         | CompiledSqlCommand::DescribeEntity { .. }
         | CompiledSqlCommand::ShowConstraintsEntity
@@ -213,23 +215,112 @@ fn sql_write_mutation_statement_result(
     }
 }
 
+///
+/// SqlWriteMutationModes
+///
+/// Mutation mode applied to one staged SQL write batch.
+/// Most statements share one mode; `INSERT ... ON CONFLICT` stages one mode
+/// per row because fresh inserts and conflict-target updates share a batch.
+///
+
+enum SqlWriteMutationModes {
+    Statement(MutationMode),
+    PerRow(Vec<MutationMode>),
+}
+
+impl SqlWriteMutationModes {
+    fn row_mode(&self, index: usize) -> Result<MutationMode, QueryError> {
+        match self {
+            Self::Statement(mode) => Ok(*mode),
+            Self::PerRow(modes) => modes.get(index).copied().ok_or_else(QueryError::invariant),
+        }
+    }
+
+    // Report `(inserted, updated)` counts only for per-row upsert batches so
+    // the statement result can distinguish both outcomes.
+    fn upsert_counts(&self) -> Option<(u32, u32)> {
+        let Self::PerRow(modes) = self else {
+            return None;
+        };
+        let inserted = modes
+            .iter()
+            .filter(|mode| matches!(mode, MutationMode::Insert))
+            .count();
+        let updated = modes.len().saturating_sub(inserted);
+
+        Some((
+            u32::try_from(inserted).unwrap_or(u32::MAX),
+            u32::try_from(updated).unwrap_or(u32::MAX),
+        ))
+    }
+}
+
+// Re-shape one executed upsert batch result so callers see inserted and
+// updated counts next to the optional RETURNING rows.
+fn sql_upsert_statement_result(
+    result: SqlStatementResult,
+    inserted_count: u32,
+    updated_count: u32,
+) -> Result<SqlStatementResult, QueryError> {
+    match result {
+        SqlStatementResult::Count { .. } => Ok(SqlStatementResult::Upsert {
+            inserted_count,
+            updated_count,
+            columns: Vec::new(),
+            fixed_scales: Vec::new(),
+            rows: None,
+        }),
+        SqlStatementResult::Projection {
+            columns,
+            fixed_scales,
+            rows,
+            ..
+        } => Ok(SqlStatementResult::Upsert {
+            inserted_count,
+            updated_count,
+            columns,
+            fixed_scales,
+            rows: Some(rows),
+        }),
+        _ => Err(QueryError::invariant()),
+    }
+}
+
 struct SqlWriteMutationExecution {
     rows: SqlWriteMutationBatch<AcceptedStructuralMutationTarget>,
     staged_rows: SqlWriteCandidateRows,
     kind: SqlWriteKind,
-    mode: MutationMode,
+    modes: SqlWriteMutationModes,
     context: AcceptedWriteContext,
     returning_bounds: Option<SqlWriteReturningBounds>,
 }
 
 impl SqlWriteMutationExecution {
     fn from_bounded_collection(
-        mut collection: SqlWriteCandidateCollection<AcceptedStructuralMutationTarget>,
+        collection: SqlWriteCandidateCollection<AcceptedStructuralMutationTarget>,
         bounds: SqlWriteCandidateBounds,
         kind: SqlWriteKind,
         mode: MutationMode,
         context: AcceptedWriteContext,
         returning_bounds: Option<SqlWriteReturningBounds>,
+    ) -> Result<Self, QueryError> {
+        Self::from_bounded_collection_with_modes(
+            collection,
+            bounds,
+            kind,
+            SqlWriteMutationModes::Statement(mode),
+            context,
+            returning_bounds,
+        )
+    }
+
+    fn from_bounded_collection_with_modes(
+        mut collection: SqlWriteCandidateCollection<AcceptedStructuralMutationTarget>,
+        bounds: SqlWriteCandidateBounds,
+        kind: SqlWriteKind,
+        modes: SqlWriteMutationModes,
+        context: AcceptedWriteContext,
+        returning_bounds: Option<SqlWriteReturningBounds>,
     ) -> Result<Self, QueryError> {
         let staged_rows = collection
             .validate_staged_rows_at(bounds, SqlWriteCandidateBoundCheck::MutationBatchHandoff)?;
@@ -239,7 +330,7 @@ impl SqlWriteMutationExecution {
             rows,
             staged_rows,
             kind,
-            mode,
+            modes,
             context,
             returning_bounds,
         })
@@ -313,12 +404,20 @@ impl<C: CanisterKind> DbSession<C> {
         returning: Option<&SqlReturningProjection>,
     ) -> Result<SqlStatementResult, QueryError> {
         let entity_path = catalog.identity().entity_path_handle();
+        let upsert_counts = execution.modes.upsert_counts();
         let rows = execution
             .rows
             .into_rows()
             .into_iter()
-            .map(|(target, patch)| AcceptedStructuralMutation::save(execution.mode, target, patch))
-            .collect();
+            .enumerate()
+            .map(|(index, (target, patch))| {
+                Ok(AcceptedStructuralMutation::save(
+                    execution.modes.row_mode(index)?,
+                    target,
+                    patch,
+                ))
+            })
+            .collect::<Result<Vec<_>, QueryError>>()?;
         let columns = projection_labels_from_accepted_write_descriptor(descriptor);
         let rows = self
             .execute_accepted_structural_save_batch(
@@ -348,7 +447,7 @@ impl<C: CanisterKind> DbSession<C> {
             )
             .map_err(QueryError::execute)?;
 
-        sql_write_mutation_statement_result(
+        let result = sql_write_mutation_statement_result(
             entity_path.as_ref(),
             execution.kind,
             execution.staged_rows,
@@ -356,6 +455,13 @@ impl<C: CanisterKind> DbSession<C> {
             returning,
             descriptor,
             catalog,
-        )
+        )?;
+
+        match upsert_counts {
+            Some((inserted_count, updated_count)) => {
+                sql_upsert_statement_result(result, inserted_count, updated_count)
+            }
+            None => Ok(result),
+        }
     }
 }
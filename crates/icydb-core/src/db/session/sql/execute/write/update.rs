@@ -2,16 +2,22 @@ use super::{
     SqlWriteMutationExecution, reject_explicit_sql_write_to_generated_field,
     reject_explicit_sql_write_to_managed_field, require_sql_write_policy_plan,
     sql_exact_update_candidate_bounds, sql_update_candidate_bounds,
-    sql_write_input_for_accepted_field, sql_write_patch_set_accepted_field,
-    sql_write_patch_set_update_default,
+    sql_write_input_for_accepted_field, sql_write_input_for_computed_accepted_field,
+    sql_write_patch_set_accepted_field, sql_write_patch_set_update_default,
 };
 use crate::{
     db::{
         DbSession, MissingRowPolicy, QueryError,
         data::AcceptedMutationIntentPatch,
-        executor::StructuralProjectionScanBudget,
-        query::intent::StructuralQuery,
-        schema::AcceptedRowLayoutRuntimeContract,
+        executor::{StructuralProjectionScanBudget, eval_compiled_scalar_expr_with_row_values},
+        query::{
+            intent::StructuralQuery,
+            plan::expr::{
+                CompiledExpr, collect_scalar_expr_field_roots,
+                compile_scalar_projection_expr_with_schema,
+            },
+        },
+        schema::{AcceptedRowLayoutRuntimeContract, SchemaInfo},
         session::{
             AcceptedSchemaCatalogContext, AcceptedStructuralMutationTarget,
             sql::{
@@ -19,13 +25,16 @@ use crate::{
                 SqlPublicPrimaryKeyUpdatePlan, SqlStatementResult, SqlTrustedExactUpdatePlan,
                 SqlUpdateExposurePolicy, SqlUpdatePolicyRejection, SqlUpdatePolicyReport,
                 SqlValidatedUpdatePlan, classify_sql_update_policy_for_entity,
-                with_accepted_sql_update_policy_context, write_policy::SqlWriteExecutionBounds,
+                with_accepted_sql_update_policy_context,
+                write_policy::{SqlWriteExecutionBounds, SqlWriteReturningBounds},
             },
             structural_data_key_from_runtime_values,
         },
         sql::{
-            lowering::bind_sql_update_selector_query_structural_with_schema,
-            parser::{SqlUpdateStatement, SqlWriteValue},
+            lowering::{
+                bind_sql_update_selector_query_structural_with_schema, lower_sql_write_value_expr,
+            },
+            parser::{SqlExpr, SqlUpdateStatement, SqlWriteValue},
         },
         write_context::{AcceptedWriteContext, MutationMode},
     },
@@ -35,6 +44,7 @@ use crate::{
     value::Value,
 };
 use icydb_diagnostic_code::{DiagnosticFactTag, SqlWriteBoundaryCode};
+use std::collections::BTreeSet;
 
 fn sql_exact_update_policy_error(
     require_affected_at_most: u32,
@@ -158,60 +168,180 @@ impl SqlUpdateExecutionContract {
     }
 }
 
-impl<C: CanisterKind> DbSession<C> {
-    pub(in crate::db::session::sql) fn sql_structural_patch(
+///
+/// SqlUpdateComputedAssignment
+///
+/// One `UPDATE` assignment whose value is computed per selected row from the
+/// pre-image fields projected alongside the primary key.
+///
+
+struct SqlUpdateComputedAssignment {
+    field: String,
+    expr: CompiledExpr,
+}
+
+///
+/// SqlUpdateAssignmentProgram
+///
+/// Accepted `UPDATE` assignment program.
+/// Literal and `DEFAULT` assignments freeze into one shared patch; computed
+/// assignments keep their compiled expressions and the accepted slots the
+/// selector projects after the primary key so each row authors its own patch.
+///
+
+pub(super) struct SqlUpdateAssignmentProgram {
+    fixed: AcceptedMutationIntentPatch,
+    computed: Vec<SqlUpdateComputedAssignment>,
+    source_fields: Vec<String>,
+    source_slots: Vec<usize>,
+}
+
+impl SqlUpdateAssignmentProgram {
+    pub(super) fn compile(
         descriptor: &AcceptedRowLayoutRuntimeContract<'_>,
+        schema_info: &SchemaInfo,
         statement: &SqlUpdateStatement,
-    ) -> Result<AcceptedMutationIntentPatch, QueryError> {
-        let mut patch = AcceptedMutationIntentPatch::new();
+    ) -> Result<Self, QueryError> {
+        let mut fixed = AcceptedMutationIntentPatch::new();
+        let mut computed = Vec::new();
+        let mut source_roots = BTreeSet::new();
         for assignment in &statement.assignments {
-            if descriptor.is_primary_key_field_name(assignment.field.as_str()) {
+            let field = assignment.field.as_str();
+            if descriptor.is_primary_key_field_name(field) {
                 return Err(QueryError::sql_write_boundary(
                     SqlWriteBoundaryCode::UpdatePrimaryKeyMutation,
                 ));
             }
-            patch = match &assignment.value {
+            match &assignment.value {
                 SqlWriteValue::Literal(value) => {
-                    reject_explicit_sql_write_to_generated_field(
-                        descriptor,
-                        assignment.field.as_str(),
-                    )?;
-                    reject_explicit_sql_write_to_managed_field(
-                        descriptor,
-                        assignment.field.as_str(),
-                    )?;
-                    let input = sql_write_input_for_accepted_field(
-                        descriptor,
-                        assignment.field.as_str(),
-                        value,
-                    )?;
-                    sql_write_patch_set_accepted_field(
-                        descriptor,
-                        patch,
-                        assignment.field.as_str(),
-                        input,
-                    )?
+                    reject_explicit_sql_write_to_generated_field(descriptor, field)?;
+                    reject_explicit_sql_write_to_managed_field(descriptor, field)?;
+                    let input = sql_write_input_for_accepted_field(descriptor, field, value)?;
+                    fixed = sql_write_patch_set_accepted_field(descriptor, fixed, field, input)?;
                 }
-                SqlWriteValue::Default => sql_write_patch_set_update_default(
-                    descriptor,
-                    patch,
-                    assignment.field.as_str(),
-                )?,
+                SqlWriteValue::Default => {
+                    fixed = sql_write_patch_set_update_default(descriptor, fixed, field)?;
+                }
+                SqlWriteValue::Expr(expr) => {
+                    reject_explicit_sql_write_to_generated_field(descriptor, field)?;
+                    reject_explicit_sql_write_to_managed_field(descriptor, field)?;
+                    let lowered = lower_sql_write_value_expr(expr, schema_info)
+                        .map_err(QueryError::from_sql_lowering_error)?;
+                    if !collect_scalar_expr_field_roots(&lowered, &mut source_roots) {
+                        return Err(QueryError::unsupported_query());
+                    }
+                    let expr = compile_scalar_projection_expr_with_schema(schema_info, &lowered)
+                        .map(|expr| CompiledExpr::compile(&expr))
+                        .ok_or_else(QueryError::unsupported_query)?;
+                    computed.push(SqlUpdateComputedAssignment {
+                        field: assignment.field.clone(),
+                        expr,
+                    });
+                }
+            }
+        }
+
+        // Primary-key roots already lead every selector row, so only the
+        // remaining pre-image fields extend the projection.
+        let mut source_fields = schema_info.primary_key_names().to_vec();
+        source_fields.extend(
+            source_roots
+                .into_iter()
+                .filter(|root| !descriptor.is_primary_key_field_name(root)),
+        );
+        let source_slots = source_fields
+            .iter()
+            .map(|field| schema_info.field_slot_index(field))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(QueryError::invariant)?;
+
+        Ok(Self {
+            fixed,
+            computed,
+            source_fields,
+            source_slots,
+        })
+    }
+
+    // Keep the selector projection at the primary key unless a computed
+    // assignment needs pre-image source fields.
+    pub(super) fn selected_fields(&self) -> &[String] {
+        &self.source_fields
+    }
+
+    // Resumable jobs persist one fixed patch, so computed assignments cannot
+    // cross that boundary.
+    fn into_fixed_patch(self) -> Option<AcceptedMutationIntentPatch> {
+        self.computed.is_empty().then_some(self.fixed)
+    }
+
+    pub(super) fn row_patch(
+        &self,
+        descriptor: &AcceptedRowLayoutRuntimeContract<'_>,
+        row: &[Value],
+    ) -> Result<AcceptedMutationIntentPatch, QueryError> {
+        let mut patch = self.fixed.clone();
+        for assignment in &self.computed {
+            let mut read_slot = |slot: usize| {
+                self.source_slots
+                    .iter()
+                    .position(|source_slot| *source_slot == slot)
+                    .and_then(|index| row.get(index))
             };
+            let value = eval_compiled_scalar_expr_with_row_values(&assignment.expr, &mut read_slot)
+                .map_err(QueryError::execute)?;
+            let input = sql_write_input_for_computed_accepted_field(
+                descriptor,
+                assignment.field.as_str(),
+                &value,
+            )?;
+            patch = sql_write_patch_set_accepted_field(
+                descriptor,
+                patch,
+                assignment.field.as_str(),
+                input,
+            )?;
         }
 
         Ok(patch)
     }
+}
+
+impl<C: CanisterKind> DbSession<C> {
+    pub(in crate::db::session::sql) fn sql_structural_patch(
+        descriptor: &AcceptedRowLayoutRuntimeContract<'_>,
+        schema_info: &SchemaInfo,
+        statement: &SqlUpdateStatement,
+    ) -> Result<AcceptedMutationIntentPatch, QueryError> {
+        SqlUpdateAssignmentProgram::compile(descriptor, schema_info, statement)?
+            .into_fixed_patch()
+            .ok_or_else(|| {
+                QueryError::sql_write_boundary(
+                    SqlWriteBoundaryCode::ResumableUpdateComputedAssignmentUnsupported,
+                )
+            })
+    }
 
     pub(in crate::db::session::sql) fn sql_update_selector_query(
-        schema_info: &crate::db::schema::SchemaInfo,
+        schema_info: &SchemaInfo,
         statement: &SqlUpdateStatement,
+    ) -> Result<StructuralQuery, QueryError> {
+        Self::sql_update_selector_query_for_fields(
+            schema_info,
+            statement,
+            schema_info.primary_key_names(),
+        )
+    }
+
+    pub(super) fn sql_update_selector_query_for_fields(
+        schema_info: &SchemaInfo,
+        statement: &SqlUpdateStatement,
+        selected_fields: &[String],
     ) -> Result<StructuralQuery, QueryError> {
         if schema_info.primary_key_names().is_empty() {
             return Err(QueryError::invariant());
         }
-        let primary_key_names = schema_info
-            .primary_key_names()
+        let selected_fields = selected_fields
             .iter()
             .map(String::as_str)
             .collect::<Vec<_>>();
@@ -222,20 +352,20 @@ impl<C: CanisterKind> DbSession<C> {
         )
         .map_err(QueryError::from_sql_lowering_error)?;
 
-        Ok(selector.select_fields(primary_key_names))
+        Ok(selector.select_fields(selected_fields))
     }
 
-    fn sql_write_key_from_projected_row(
+    pub(super) fn sql_write_key_from_projected_row(
         entity_tag: crate::types::EntityTag,
         descriptor: &AcceptedRowLayoutRuntimeContract<'_>,
         row: &[Value],
     ) -> Result<crate::db::data::DecodedDataStoreKey, QueryError> {
-        let primary_key_names = descriptor.primary_key_names();
-        if row.len() != primary_key_names.len() {
+        let primary_key_len = descriptor.primary_key_names().len();
+        let Some(primary_key_values) = row.get(..primary_key_len) else {
             return Err(QueryError::invariant());
-        }
+        };
 
-        structural_data_key_from_runtime_values(entity_tag, row.to_vec())
+        structural_data_key_from_runtime_values(entity_tag, primary_key_values.to_vec())
             .map_err(QueryError::execute)
     }
 
@@ -253,9 +383,14 @@ impl<C: CanisterKind> DbSession<C> {
                 let (authority, schema_info) =
                     Self::accepted_sql_write_authority_schema_info(catalog);
                 let entity_tag = catalog.identity().entity_tag();
-                let selector = execution_contract
-                    .selector(Self::sql_update_selector_query(&schema_info, statement)?);
-                let patch = Self::sql_structural_patch(&descriptor, statement)?;
+                let assignments =
+                    SqlUpdateAssignmentProgram::compile(&descriptor, &schema_info, statement)?;
+                let selector =
+                    execution_contract.selector(Self::sql_update_selector_query_for_fields(
+                        &schema_info,
+                        statement,
+                        assignments.selected_fields(),
+                    )?);
                 let write_context = AcceptedWriteContext::new(Timestamp::now());
                 let candidate_bounds = execution_contract.candidate_bounds();
                 let scan_budget = execution_contract.scan_budget()?;
@@ -275,7 +410,7 @@ impl<C: CanisterKind> DbSession<C> {
 
                             Ok((
                                 AcceptedStructuralMutationTarget::expected(key),
-                                patch.clone(),
+                                assignments.row_patch(&descriptor, row)?,
                             ))
                         },
                     )?;
@@ -360,6 +495,46 @@ impl<C: CanisterKind> DbSession<C> {
         )
     }
 
+    /// Recompute the stored generated columns of every row of one entity.
+    ///
+    /// An assignment-free update re-resolves each after-image through the
+    /// accepted write boundary, which alone owns generated values. The caller
+    /// supplies the staged-row bound for the whole rebuild.
+    pub(in crate::db::session::sql) fn execute_sql_generated_column_backfill(
+        &self,
+        entity: &str,
+        max_staged_rows: u32,
+    ) -> Result<u32, QueryError> {
+        let statement = SqlUpdateStatement {
+            entity: entity.to_string(),
+            table_alias: None,
+            assignments: Vec::new(),
+            // UPDATE admission requires a selector; the backfill rewrites
+            // every row so the resolver recomputes generated slots.
+            predicate: Some(SqlExpr::Literal(Value::Bool(true))),
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+            returning: None,
+        };
+        let result = self.execute_sql_update_statement_with_contract(
+            &statement,
+            None,
+            SqlUpdateExecutionContract::Validated(SqlWriteExecutionBounds {
+                max_staged_rows: Some(max_staged_rows),
+                returning: SqlWriteReturningBounds {
+                    max_rows: None,
+                    max_response_bytes: None,
+                },
+            }),
+        )?;
+        let SqlStatementResult::Count { row_count } = result else {
+            return Err(QueryError::invariant());
+        };
+
+        Ok(row_count)
+    }
+
     /// Execute a validated exact complete-set SQL `UPDATE` plan.
     #[doc(hidden)]
     pub(in crate::db) fn execute_validated_sql_trusted_exact_update(
@@ -0,0 +1,500 @@
+use super::{
+    SqlWriteCandidateBoundCheck, SqlWriteCandidateBounds, SqlWriteCandidateCollection,
+    SqlWriteCandidateRows, SqlWriteMutationExecution, SqlWriteMutationModes,
+    update::SqlUpdateAssignmentProgram,
+};
+use crate::{
+    db::{
+        DbSession, QueryError,
+        data::{AcceptedMutationIntentPatch, decode_validated_check_literal_payload},
+        schema::{
+            AcceptedInsertOmissionPolicy, AcceptedRowLayoutRuntimeContract,
+            AcceptedValueCatalogHandle, FieldInsertGeneration, SchemaInfo,
+        },
+        session::{
+            AcceptedSchemaCatalogContext, AcceptedStructuralMutationTarget,
+            sql::{SqlStatementResult, write_policy::SqlWriteReturningBounds},
+        },
+        sql::parser::{
+            SqlAssignment, SqlCaseArm, SqlExpr, SqlExprBinaryOp, SqlInsertConflictAction,
+            SqlInsertSource, SqlInsertStatement, SqlUpdateStatement, SqlWriteValue,
+        },
+        sql_shared::SqlSyntaxErrorKind,
+        write_context::{AcceptedWriteContext, MutationMode},
+    },
+    metrics::sink::SqlWriteKind,
+    traits::CanisterKind,
+    types::{CurrentTimestamp, GenerateKey, Timestamp, Ulid},
+    value::Value,
+};
+use icydb_diagnostic_code::SqlWriteBoundaryCode;
+use std::collections::BTreeSet;
+
+// `DO UPDATE` assignments read the proposed row through this pseudo-table.
+const SQL_INSERT_CONFLICT_EXCLUDED_ROOT: &str = "EXCLUDED";
+
+// Admit one conflict target only when it names exactly the primary key or the
+// top-level fields of one unconditional unique index, so the probe for each
+// proposed row can match at most one stored row.
+fn sql_insert_conflict_target_is_unique(schema_info: &SchemaInfo, target: &[String]) -> bool {
+    let target_fields = target.iter().map(String::as_str).collect::<BTreeSet<_>>();
+    if target_fields.len() != target.len() {
+        return false;
+    }
+
+    let primary_key_fields = schema_info
+        .primary_key_names()
+        .iter()
+        .map(String::as_str)
+        .collect::<BTreeSet<_>>();
+    if target_fields == primary_key_fields {
+        return true;
+    }
+
+    schema_info.field_path_indexes().iter().any(|index| {
+        index.unique()
+            && index.predicate_sql().is_none()
+            && index.fields().len() == target_fields.len()
+            && index.fields().iter().all(|field| {
+                field.path().len() == 1
+                    && field.path()[0] == field.field_name()
+                    && target_fields.contains(field.field_name())
+            })
+    })
+}
+
+// Collect the proposed conflict-target values for one VALUES tuple. A target
+// field that is omitted, `DEFAULT`, or `NULL` cannot conflict, so the tuple
+// stays a plain insert.
+fn sql_insert_conflict_target_values(
+    target: &[String],
+    columns: &[String],
+    tuple: &[SqlWriteValue],
+) -> Option<Vec<Value>> {
+    target
+        .iter()
+        .map(|field| {
+            let index = columns.iter().position(|column| column == field)?;
+            match tuple.get(index)? {
+                SqlWriteValue::Literal(Value::Null)
+                | SqlWriteValue::Default
+                | SqlWriteValue::Expr(_) => None,
+                SqlWriteValue::Literal(value) => Some(value.clone()),
+            }
+        })
+        .collect()
+}
+
+///
+/// SqlInsertExcludedRow
+///
+/// One proposed VALUES tuple read as the `EXCLUDED` pseudo-row of
+/// `DO UPDATE`. Columns the tuple omits or sets to `DEFAULT` read the value
+/// the insert itself would resolve from accepted field policy.
+///
+
+#[derive(Clone, Copy)]
+struct SqlInsertExcludedRow<'a> {
+    descriptor: &'a AcceptedRowLayoutRuntimeContract<'a>,
+    value_catalog: &'a AcceptedValueCatalogHandle,
+    write_context: AcceptedWriteContext,
+    columns: &'a [String],
+    tuple: &'a [SqlWriteValue],
+}
+
+impl SqlInsertExcludedRow<'_> {
+    // Resolve one `EXCLUDED.field` reference against the proposed tuple.
+    fn value(&self, segments: &[String]) -> Result<Value, QueryError> {
+        let [field] = segments else {
+            return Err(sql_insert_excluded_value_unavailable());
+        };
+        let proposed = self
+            .columns
+            .iter()
+            .position(|column| column == field)
+            .and_then(|index| self.tuple.get(index));
+
+        match proposed {
+            Some(SqlWriteValue::Literal(value)) => Ok(value.clone()),
+            Some(SqlWriteValue::Default) | None => self.insert_default_value(field),
+            Some(SqlWriteValue::Expr(_)) => Err(sql_insert_excluded_value_unavailable()),
+        }
+    }
+
+    // Resolve the value an insert assigns one omitted or `DEFAULT` field:
+    // its generated or managed value, its accepted insert default, or `NULL`
+    // for a nullable field without one. Identity keys and computed columns
+    // only resolve inside the mutation itself, and required fields have no
+    // default, so those references stay unavailable.
+    fn insert_default_value(&self, field_name: &str) -> Result<Value, QueryError> {
+        let field = self
+            .descriptor
+            .field_by_name(field_name)
+            .ok_or_else(sql_insert_excluded_value_unavailable)?;
+        let write_policy = field.write_policy();
+        match write_policy.insert_generation() {
+            Some(FieldInsertGeneration::Timestamp) => {
+                return Ok(Value::Timestamp(self.write_context.operation_timestamp()));
+            }
+            Some(FieldInsertGeneration::Ulid) => {
+                return Ulid::generate()
+                    .map(Value::Ulid)
+                    .map_err(QueryError::execute);
+            }
+            Some(FieldInsertGeneration::Identity | FieldInsertGeneration::Computed) => {
+                return Err(sql_insert_excluded_value_unavailable());
+            }
+            None => {}
+        }
+        if write_policy.write_management().is_some() {
+            return Ok(Value::Timestamp(self.write_context.operation_timestamp()));
+        }
+
+        match field.insert_omission_policy() {
+            AcceptedInsertOmissionPolicy::NullIfMissing => Ok(Value::Null),
+            AcceptedInsertOmissionPolicy::DefaultIfMissing => {
+                let payload = field
+                    .insert_default()
+                    .slot_payload()
+                    .ok_or_else(QueryError::invariant)?;
+
+                decode_validated_check_literal_payload(
+                    self.value_catalog.enum_catalog(),
+                    self.value_catalog.composite_catalog(),
+                    field.decode_contract(),
+                    payload,
+                )
+                .map_err(QueryError::execute)
+            }
+            AcceptedInsertOmissionPolicy::Required => Err(sql_insert_excluded_value_unavailable()),
+        }
+    }
+}
+
+fn sql_insert_excluded_value_unavailable() -> QueryError {
+    QueryError::sql_write_boundary(SqlWriteBoundaryCode::InsertConflictExcludedValueUnavailable)
+}
+
+fn sql_expr_is_excluded_root(root: &str) -> bool {
+    root.eq_ignore_ascii_case(SQL_INSERT_CONFLICT_EXCLUDED_ROOT)
+}
+
+// Replace every `EXCLUDED.field` leaf with the proposed row value so the
+// rewritten assignment compiles on the ordinary row-local UPDATE lane.
+fn sql_expr_with_excluded_values(
+    expr: &SqlExpr,
+    excluded: SqlInsertExcludedRow<'_>,
+) -> Result<SqlExpr, QueryError> {
+    let rewrite = |expr: &SqlExpr| sql_expr_with_excluded_values(expr, excluded);
+
+    Ok(match expr {
+        SqlExpr::FieldPath { root, segments } if sql_expr_is_excluded_root(root) => {
+            SqlExpr::Literal(excluded.value(segments)?)
+        }
+        SqlExpr::Field(_)
+        | SqlExpr::FieldPath { .. }
+        | SqlExpr::Aggregate(_)
+        | SqlExpr::Literal(_)
+        | SqlExpr::Param { .. }
+        | SqlExpr::SemiJoin { .. } => expr.clone(),
+        SqlExpr::Membership {
+            expr,
+            values,
+            negated,
+        } => SqlExpr::Membership {
+            expr: Box::new(rewrite(expr)?),
+            values: values.clone(),
+            negated: *negated,
+        },
+        SqlExpr::NullTest { expr, negated } => SqlExpr::NullTest {
+            expr: Box::new(rewrite(expr)?),
+            negated: *negated,
+        },
+        SqlExpr::Like {
+            expr,
+            pattern,
+            escape,
+            negated,
+            casefold,
+        } => SqlExpr::Like {
+            expr: Box::new(rewrite(expr)?),
+            pattern: pattern.clone(),
+            escape: *escape,
+            negated: *negated,
+            casefold: *casefold,
+        },
+        SqlExpr::FunctionCall { function, args } => SqlExpr::FunctionCall {
+            function: *function,
+            args: args.iter().map(rewrite).collect::<Result<_, _>>()?,
+        },
+        SqlExpr::Unary { op, expr } => SqlExpr::Unary {
+            op: *op,
+            expr: Box::new(rewrite(expr)?),
+        },
+        SqlExpr::Binary { op, left, right } => SqlExpr::Binary {
+            op: *op,
+            left: Box::new(rewrite(left)?),
+            right: Box::new(rewrite(right)?),
+        },
+        SqlExpr::Case { arms, else_expr } => SqlExpr::Case {
+            arms: arms
+                .iter()
+                .map(|arm| {
+                    Ok(SqlCaseArm {
+                        condition: rewrite(&arm.condition)?,
+                        result: rewrite(&arm.result)?,
+                    })
+                })
+                .collect::<Result<_, QueryError>>()?,
+            else_expr: else_expr.as_deref().map(rewrite).transpose()?.map(Box::new),
+        },
+    })
+}
+
+// Bind `DO UPDATE` assignments to one proposed tuple. A bare
+// `EXCLUDED.field` value keeps the literal admission path of ordinary
+// `UPDATE ... SET field = <literal>`.
+fn sql_insert_conflict_assignments(
+    action: &SqlInsertConflictAction,
+    excluded: SqlInsertExcludedRow<'_>,
+) -> Result<Vec<SqlAssignment>, QueryError> {
+    let SqlInsertConflictAction::DoUpdate(assignments) = action else {
+        return Ok(Vec::new());
+    };
+
+    assignments
+        .iter()
+        .map(|assignment| {
+            let value = match &assignment.value {
+                SqlWriteValue::Expr(SqlExpr::FieldPath { root, segments })
+                    if sql_expr_is_excluded_root(root) =>
+                {
+                    SqlWriteValue::Literal(excluded.value(segments)?)
+                }
+                SqlWriteValue::Expr(expr) => {
+                    SqlWriteValue::Expr(sql_expr_with_excluded_values(expr, excluded)?)
+                }
+                value @ (SqlWriteValue::Literal(_) | SqlWriteValue::Default) => value.clone(),
+            };
+
+            Ok(SqlAssignment {
+                field: assignment.field.clone(),
+                value,
+            })
+        })
+        .collect()
+}
+
+// Build the conflict probe for one proposed tuple as a reduced `UPDATE`
+// whose predicate pins every conflict-target field to its proposed value.
+fn sql_insert_conflict_probe_statement(
+    entity: &str,
+    target: &[String],
+    target_values: Vec<Value>,
+    assignments: Vec<SqlAssignment>,
+) -> SqlUpdateStatement {
+    let predicate = target
+        .iter()
+        .zip(target_values)
+        .map(|(field, value)| SqlExpr::Binary {
+            op: SqlExprBinaryOp::Eq,
+            left: Box::new(SqlExpr::Field(field.clone())),
+            right: Box::new(SqlExpr::Literal(value)),
+        })
+        .reduce(|left, right| SqlExpr::Binary {
+            op: SqlExprBinaryOp::And,
+            left: Box::new(left),
+            right: Box::new(right),
+        });
+
+    SqlUpdateStatement {
+        entity: entity.to_string(),
+        table_alias: None,
+        assignments,
+        predicate,
+        order_by: Vec::new(),
+        limit: None,
+        offset: None,
+        returning: None,
+    }
+}
+
+impl<C: CanisterKind> DbSession<C> {
+    // Execute one `INSERT ... VALUES ... ON CONFLICT` statement. Each tuple
+    // probes its conflict target first and stages either a fresh insert, a
+    // per-field update of the stored row, or nothing; every staged row then
+    // commits through one atomic structural mutation batch.
+    pub(super) fn execute_sql_insert_on_conflict_statement(
+        &self,
+        catalog: &AcceptedSchemaCatalogContext,
+        descriptor: &AcceptedRowLayoutRuntimeContract<'_>,
+        statement: &SqlInsertStatement,
+        columns: &[String],
+        candidate_bounds: SqlWriteCandidateBounds,
+        returning_bounds: Option<SqlWriteReturningBounds>,
+    ) -> Result<SqlStatementResult, QueryError> {
+        let Some(on_conflict) = statement.on_conflict.as_ref() else {
+            return Err(QueryError::invariant());
+        };
+        let SqlInsertSource::Values(values) = &statement.source else {
+            return Err(QueryError::sql_write_boundary(
+                SqlWriteBoundaryCode::InsertConflictRequiresValues,
+            ));
+        };
+        if !sql_insert_conflict_target_is_unique(
+            catalog.accepted_schema_info(),
+            on_conflict.target.as_slice(),
+        ) {
+            return Err(QueryError::sql_write_boundary(
+                SqlWriteBoundaryCode::InsertConflictTargetNotUnique,
+            ));
+        }
+        candidate_bounds.validate_at(
+            SqlWriteCandidateRows::from_len(values.len()),
+            SqlWriteCandidateBoundCheck::InsertValuesSource,
+        )?;
+
+        let write_context = AcceptedWriteContext::new(Timestamp::now());
+        let mut collection = SqlWriteCandidateCollection::with_capacity(values.len());
+        let mut modes = Vec::with_capacity(values.len());
+        let mut seen_targets = Vec::<Vec<Value>>::new();
+        for tuple in values {
+            if tuple.len() != columns.len() {
+                return Err(QueryError::from_sql_parse_error(
+                    crate::db::sql::parser::SqlParseError::invalid_syntax(
+                        SqlSyntaxErrorKind::InsertValuesTupleLengthMismatch,
+                    ),
+                ));
+            }
+
+            let Some(target_values) = sql_insert_conflict_target_values(
+                on_conflict.target.as_slice(),
+                columns,
+                tuple.as_slice(),
+            ) else {
+                collection.push(
+                    AcceptedStructuralMutationTarget::ResolveFromAfterImage,
+                    Self::sql_insert_values_patch(descriptor, columns, tuple.as_slice())?,
+                );
+                modes.push(MutationMode::Insert);
+                continue;
+            };
+
+            // One statement may not resolve the same conflict target twice:
+            // `DO NOTHING` keeps the first proposal, `DO UPDATE` rejects.
+            if seen_targets.contains(&target_values) {
+                match on_conflict.action {
+                    SqlInsertConflictAction::DoNothing => continue,
+                    SqlInsertConflictAction::DoUpdate(_) => {
+                        return Err(QueryError::sql_write_boundary(
+                            SqlWriteBoundaryCode::InsertConflictRowAffectedTwice,
+                        ));
+                    }
+                }
+            }
+            seen_targets.push(target_values.clone());
+
+            let excluded = SqlInsertExcludedRow {
+                descriptor,
+                value_catalog: catalog.value_catalog_handle(),
+                write_context,
+                columns,
+                tuple: tuple.as_slice(),
+            };
+            if let Some((mode, target, patch)) = self.stage_sql_insert_conflict_row(
+                catalog,
+                descriptor,
+                statement,
+                excluded,
+                target_values,
+            )? {
+                collection.push(target, patch);
+                modes.push(mode);
+            }
+        }
+
+        self.execute_sql_write_mutation_batch(
+            catalog,
+            descriptor,
+            SqlWriteMutationExecution::from_bounded_collection_with_modes(
+                collection,
+                candidate_bounds,
+                SqlWriteKind::Insert,
+                SqlWriteMutationModes::PerRow(modes),
+                write_context,
+                returning_bounds,
+            )?,
+            statement.returning.as_ref(),
+        )
+    }
+
+    // Probe the conflict target of one proposed tuple. A miss stages the
+    // tuple as a fresh insert; a hit stages nothing for `DO NOTHING` or the
+    // bound `DO UPDATE` patch against the stored row's primary key.
+    fn stage_sql_insert_conflict_row(
+        &self,
+        catalog: &AcceptedSchemaCatalogContext,
+        descriptor: &AcceptedRowLayoutRuntimeContract<'_>,
+        statement: &SqlInsertStatement,
+        excluded: SqlInsertExcludedRow<'_>,
+        target_values: Vec<Value>,
+    ) -> Result<
+        Option<(
+            MutationMode,
+            AcceptedStructuralMutationTarget,
+            AcceptedMutationIntentPatch,
+        )>,
+        QueryError,
+    > {
+        let on_conflict = statement
+            .on_conflict
+            .as_ref()
+            .ok_or_else(QueryError::invariant)?;
+        let schema_info = catalog.accepted_schema_info();
+        let entity_tag = catalog.identity().entity_tag();
+        let probe = sql_insert_conflict_probe_statement(
+            statement.entity.as_str(),
+            on_conflict.target.as_slice(),
+            target_values,
+            sql_insert_conflict_assignments(&on_conflict.action, excluded)?,
+        );
+        let assignments = SqlUpdateAssignmentProgram::compile(descriptor, schema_info, &probe)?;
+        let selector = Self::sql_update_selector_query_for_fields(
+            schema_info,
+            &probe,
+            assignments.selected_fields(),
+        )?;
+        let stored = self
+            .collect_bounded_sql_write_candidate_collection_from_structural_query(
+                catalog.snapshot(),
+                catalog.accepted_entity_authority(),
+                &selector,
+                SqlWriteCandidateBounds::from_max_rows(Some(1)),
+                None,
+                |row| {
+                    let key = Self::sql_write_key_from_projected_row(entity_tag, descriptor, row)?;
+
+                    Ok((
+                        AcceptedStructuralMutationTarget::expected(key),
+                        assignments.row_patch(descriptor, row)?,
+                    ))
+                },
+            )?
+            .into_batch()
+            .into_rows()
+            .into_iter()
+            .next();
+
+        Ok(match (stored, &on_conflict.action) {
+            (None, _) => Some((
+                MutationMode::Insert,
+                AcceptedStructuralMutationTarget::ResolveFromAfterImage,
+                Self::sql_insert_values_patch(descriptor, excluded.columns, excluded.tuple)?,
+            )),
+            (Some(_), SqlInsertConflictAction::DoNothing) => None,
+            (Some((target, patch)), SqlInsertConflictAction::DoUpdate(_)) => {
+                Some((MutationMode::Update, target, patch))
+            }
+        })
+    }
+}
@@ -12,9 +12,12 @@ mod ddl;
 mod delete_policy;
 mod execute;
 mod integrity;
+mod prepared;
 mod projection;
+mod relation_path;
 mod result;
 mod resumable_update;
+mod semi_join;
 mod surface;
 mod update_policy;
 mod write_policy;
@@ -33,6 +36,7 @@ use crate::{
     db::{DbSession, QueryError},
     traits::CanisterKind,
 };
+use execute::SqlSelectContinuation;
 
 pub(in crate::db::session::sql) use crate::db::diagnostics::measure_local_instruction_delta as measure_sql_stage;
 pub use crate::db::sql::ddl::{
@@ -59,7 +63,8 @@ pub(in crate::db::session::sql) use compile::{
     SqlCompileAttributionBuilder, SqlCompilePhaseAttribution,
 };
 pub(in crate::db) use compiled::{
-    CompiledSqlCommand, CompiledSqlInsertCommand, SqlCompiledCommandExecutionContext,
+    CompiledSqlBoundCommand, CompiledSqlCommand, CompiledSqlInsertCommand, CompiledSqlTemplate,
+    CompiledSqlUnionCommand, SqlCompiledCacheEntry, SqlCompiledCommandExecutionContext,
     SqlCompiledSchemaFingerprint, SqlGlobalAggregateCountPlanCacheEntry,
 };
 pub(in crate::db) use delete_policy::{
@@ -67,6 +72,7 @@ pub(in crate::db) use delete_policy::{
     SqlPublicPrimaryKeyDeletePlan, SqlValidatedDeletePlan, classify_sql_delete_policy,
 };
 pub use integrity::SqlIntegrityError;
+pub use prepared::PreparedSqlQuery;
 pub use result::SqlStatementResult;
 pub(in crate::db::session) use resumable_update::validate_current_initial_mutation_job_continuation;
 pub use surface::{
@@ -123,7 +129,44 @@ impl<C: CanisterKind> DbSession<C> {
         let entity_name = sql_statement_entity_name(sql)?;
         let (compiled, _, _) =
             self.compile_sql_query_with_execution_context(entity_name.as_deref(), sql)?;
-        let result = self.execute_compiled_sql_query_context_owned(compiled)?;
+        let result = self
+            .execute_compiled_sql_query_context_owned(compiled, SqlSelectContinuation::Unpaged)?;
+
+        Ok((result, entity_name.unwrap_or_default()))
+    }
+
+    /// Execute one trusted SQL query as one resumable page.
+    ///
+    /// Scalar `SELECT ... ORDER BY ... LIMIT n` without `OFFSET` treats `n` as
+    /// the page size and returns `next_cursor` while rows remain; grouped
+    /// reads return their grouped cursor. Passing that cursor back with the
+    /// same statement resumes after the last returned row. Cursors are bound
+    /// to the statement, accepted authority, route, and ordering, and follow
+    /// live-state keyset semantics rather than snapshot isolation.
+    pub fn execute_trusted_sql_query_page(
+        &self,
+        sql: &str,
+        cursor: Option<&str>,
+    ) -> Result<SqlStatementResult, QueryError> {
+        self.execute_trusted_sql_query_page_with_entity_name(sql, cursor)
+            .map(|(result, _)| result)
+    }
+
+    /// Execute one trusted query page and return the entity name resolved by
+    /// the same canonical parse used for compilation.
+    #[doc(hidden)]
+    pub fn execute_trusted_sql_query_page_with_entity_name(
+        &self,
+        sql: &str,
+        cursor: Option<&str>,
+    ) -> Result<(SqlStatementResult, String), QueryError> {
+        let entity_name = sql_statement_entity_name(sql)?;
+        let (compiled, _, _) =
+            self.compile_sql_query_with_execution_context(entity_name.as_deref(), sql)?;
+        let result = self.execute_compiled_sql_query_context_owned(
+            compiled,
+            SqlSelectContinuation::Paged(cursor),
+        )?;
 
         Ok((result, entity_name.unwrap_or_default()))
     }
@@ -136,13 +179,63 @@ impl<C: CanisterKind> DbSession<C> {
         &self,
         sql: &str,
     ) -> Result<(SqlStatementResult, SqlQueryExecutionAttribution), QueryError> {
-        begin_sql_structural_work_attribution();
+        self.execute_trusted_sql_query_with_compile_attribution(
+            || self.compile_sql_query_with_sql_text(sql),
+            SqlSelectContinuation::Unpaged,
+        )
+    }
+
+    /// Execute one resumable SQL query page while reporting the
+    /// compile/execute split at the top-level SQL seam.
+    #[cfg(feature = "diagnostics")]
+    #[doc(hidden)]
+    pub fn execute_trusted_sql_query_page_with_attribution(
+        &self,
+        sql: &str,
+        cursor: Option<&str>,
+    ) -> Result<(SqlStatementResult, SqlQueryExecutionAttribution), QueryError> {
+        self.execute_trusted_sql_query_with_compile_attribution(
+            || self.compile_sql_query_with_sql_text(sql),
+            SqlSelectContinuation::Paged(cursor),
+        )
+    }
+
+    // Resolve the entity named by one literal SQL query and compile it on the
+    // ordinary query cache lane.
+    #[cfg(feature = "diagnostics")]
+    fn compile_sql_query_with_sql_text(
+        &self,
+        sql: &str,
+    ) -> Result<
+        (
+            SqlCompiledCommandExecutionContext,
+            SqlCacheAttribution,
+            SqlCompilePhaseAttribution,
+        ),
+        QueryError,
+    > {
         let entity_name = sql_statement_entity_name(sql)?;
+
+        self.compile_sql_query_with_execution_context(entity_name.as_deref(), sql)
+    }
+
+    #[cfg(feature = "diagnostics")]
+    fn execute_trusted_sql_query_with_compile_attribution(
+        &self,
+        compile: impl FnOnce() -> Result<
+            (
+                SqlCompiledCommandExecutionContext,
+                SqlCacheAttribution,
+                SqlCompilePhaseAttribution,
+            ),
+            QueryError,
+        >,
+        continuation: SqlSelectContinuation<'_>,
+    ) -> Result<(SqlStatementResult, SqlQueryExecutionAttribution), QueryError> {
+        begin_sql_structural_work_attribution();
         // Phase 1: measure the compile side of the new seam, including parse,
         // surface validation, and semantic command construction.
-        let (compile_local_instructions, compiled) = measure_sql_stage(|| {
-            self.compile_sql_query_with_execution_context(entity_name.as_deref(), sql)
-        });
+        let (compile_local_instructions, compiled) = measure_sql_stage(compile);
         let (compiled, compile_cache_attribution, compile_phase_attribution) = compiled?;
 
         // Phase 2: measure the execute side separately so repeat-run cache
@@ -153,7 +246,10 @@ impl<C: CanisterKind> DbSession<C> {
             current_pure_covering_row_assembly_local_instructions();
         let (executed, projection_materialization) =
             with_sql_projection_materialization_metrics(|| {
-                self.execute_compiled_sql_query_context_with_phase_attribution(&compiled)
+                self.execute_compiled_sql_query_context_with_phase_attribution(
+                    &compiled,
+                    continuation,
+                )
             });
         let (result, execute_cache_attribution, execute_phase_attribution) = executed?;
         let structural_work = finish_sql_structural_work_attribution();
@@ -0,0 +1,342 @@
+//! Module: db::session::sql::prepared
+//! Responsibility: trusted parameterized SQL query preparation and bind-value
+//! execution.
+//! Does not own: placeholder parsing, bind substitution, or SQL execution dispatch.
+//! Boundary: resolves one cached template, binds public input values, and hands
+//! the bound statement to the ordinary compiled-query execution lane.
+
+use crate::{
+    db::{
+        DbSession, QueryError,
+        session::{
+            AcceptedSchemaCatalogContext,
+            sql::{
+                CompiledSqlBoundCommand, CompiledSqlCommand, CompiledSqlTemplate,
+                SqlCacheAttribution, SqlCompileAttributionBuilder, SqlCompilePhaseAttribution,
+                SqlCompiledCacheEntry, SqlCompiledCommandCacheContext,
+                SqlCompiledCommandExecutionContext, SqlCompiledCommandSurface, SqlStatementResult,
+                execute::SqlSelectContinuation, sql_statement_entity_name,
+            },
+        },
+        sql::lowering::{
+            SqlLoweringError, SqlParameterBindingReason, bind_sql_statement_parameters,
+            sql_statement_has_semi_join,
+        },
+    },
+    traits::CanisterKind,
+    value::{InputValue, Value},
+};
+
+///
+/// PreparedSqlQuery
+///
+/// PreparedSqlQuery is one trusted SQL query checked for syntax, query-surface
+/// admission, and dense `$1..$n` placeholders. It holds only the statement
+/// text: each execution resolves the current accepted schema and reuses the
+/// session compile cache keyed on that parameterized text.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PreparedSqlQuery {
+    sql: String,
+    entity_name: Option<String>,
+    parameter_count: usize,
+}
+
+impl PreparedSqlQuery {
+    /// Borrow the parameterized SQL text this handle executes.
+    #[must_use]
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /// Return how many bind values each execution must supply.
+    #[must_use]
+    pub const fn parameter_count(&self) -> usize {
+        self.parameter_count
+    }
+}
+
+impl<C: CanisterKind> DbSession<C> {
+    /// Prepare one trusted/admin SQL query that uses `$1..$n` placeholders.
+    ///
+    /// Preparation parses the statement once, checks that it is admitted on
+    /// the query surface, and warms the compile cache for its parameterized
+    /// text. Placeholders may appear in scalar expression positions such as
+    /// comparison operands, projections, and function arguments, and must be
+    /// numbered densely from `$1`. `IN (...)` lists and `LIKE`/`~` patterns
+    /// still require literals and reject placeholders at prepare time.
+    pub fn prepare_trusted_sql_query(&self, sql: &str) -> Result<PreparedSqlQuery, QueryError> {
+        let entity_name = sql_statement_entity_name(sql)?;
+        let catalog = self.sql_query_catalog_for_entity_name(entity_name.as_deref())?;
+        let (template, _, _) = self.compile_sql_template_with_cache(sql, catalog)?;
+
+        Ok(PreparedSqlQuery {
+            sql: sql.to_string(),
+            entity_name,
+            parameter_count: template.parameter_count(),
+        })
+    }
+
+    /// Execute one prepared SQL query with one bind value per placeholder.
+    ///
+    /// `params[0]` binds `$1`, `params[1]` binds `$2`, and so on. Bound values
+    /// behave exactly like the same values written as SQL literals.
+    pub fn execute_prepared_sql_query(
+        &self,
+        prepared: &PreparedSqlQuery,
+        params: Vec<InputValue>,
+    ) -> Result<SqlStatementResult, QueryError> {
+        self.execute_sql_query_with_params(
+            prepared.sql(),
+            prepared.entity_name.as_deref(),
+            params,
+            SqlSelectContinuation::Unpaged,
+        )
+    }
+
+    /// Execute one prepared SQL query as one resumable page.
+    ///
+    /// Paging follows `execute_trusted_sql_query_page`; a cursor only resumes
+    /// the same statement executed with the same bind values.
+    pub fn execute_prepared_sql_query_page(
+        &self,
+        prepared: &PreparedSqlQuery,
+        params: Vec<InputValue>,
+        cursor: Option<&str>,
+    ) -> Result<SqlStatementResult, QueryError> {
+        self.execute_sql_query_with_params(
+            prepared.sql(),
+            prepared.entity_name.as_deref(),
+            params,
+            SqlSelectContinuation::Paged(cursor),
+        )
+    }
+
+    /// Execute one trusted parameterized query without a separate prepare
+    /// call and return the resolved entity name.
+    ///
+    /// Generated endpoints use this seam for unpaged requests so one request
+    /// envelope can carry SQL text and bind values while still sharing the
+    /// prepared template cache lane.
+    #[doc(hidden)]
+    pub fn execute_trusted_sql_query_with_params_with_entity_name(
+        &self,
+        sql: &str,
+        params: Vec<InputValue>,
+    ) -> Result<(SqlStatementResult, String), QueryError> {
+        let entity_name = sql_statement_entity_name(sql)?;
+        let result = self.execute_sql_query_with_params(
+            sql,
+            entity_name.as_deref(),
+            params,
+            SqlSelectContinuation::Unpaged,
+        )?;
+
+        Ok((result, entity_name.unwrap_or_default()))
+    }
+
+    /// Execute one trusted parameterized query page without a separate
+    /// prepare call and return the resolved entity name.
+    ///
+    /// Generated endpoints use this seam so one request envelope can carry
+    /// SQL text, bind values, and a cursor while still sharing the prepared
+    /// template cache lane.
+    #[doc(hidden)]
+    pub fn execute_trusted_sql_query_with_params_page_with_entity_name(
+        &self,
+        sql: &str,
+        params: Vec<InputValue>,
+        cursor: Option<&str>,
+    ) -> Result<(SqlStatementResult, String), QueryError> {
+        let entity_name = sql_statement_entity_name(sql)?;
+        let result = self.execute_sql_query_with_params(
+            sql,
+            entity_name.as_deref(),
+            params,
+            SqlSelectContinuation::Paged(cursor),
+        )?;
+
+        Ok((result, entity_name.unwrap_or_default()))
+    }
+
+    /// Execute one trusted parameterized query while reporting the
+    /// compile/execute split at the top-level SQL seam.
+    #[cfg(feature = "diagnostics")]
+    #[doc(hidden)]
+    pub fn execute_trusted_sql_query_with_params_with_attribution(
+        &self,
+        sql: &str,
+        params: Vec<InputValue>,
+    ) -> Result<(SqlStatementResult, crate::db::SqlQueryExecutionAttribution), QueryError> {
+        self.execute_trusted_sql_query_with_compile_attribution(
+            || {
+                let entity_name = sql_statement_entity_name(sql)?;
+                self.compile_sql_query_with_params(sql, entity_name.as_deref(), params)
+            },
+            SqlSelectContinuation::Unpaged,
+        )
+    }
+
+    /// Execute one trusted parameterized query page while reporting the
+    /// compile/execute split at the top-level SQL seam.
+    #[cfg(feature = "diagnostics")]
+    #[doc(hidden)]
+    pub fn execute_trusted_sql_query_with_params_page_with_attribution(
+        &self,
+        sql: &str,
+        params: Vec<InputValue>,
+        cursor: Option<&str>,
+    ) -> Result<(SqlStatementResult, crate::db::SqlQueryExecutionAttribution), QueryError> {
+        self.execute_trusted_sql_query_with_compile_attribution(
+            || {
+                let entity_name = sql_statement_entity_name(sql)?;
+                self.compile_sql_query_with_params(sql, entity_name.as_deref(), params)
+            },
+            SqlSelectContinuation::Paged(cursor),
+        )
+    }
+
+    fn execute_sql_query_with_params(
+        &self,
+        sql: &str,
+        entity_name: Option<&str>,
+        params: Vec<InputValue>,
+        continuation: SqlSelectContinuation<'_>,
+    ) -> Result<SqlStatementResult, QueryError> {
+        let (compiled, _, _) = self.compile_sql_query_with_params(sql, entity_name, params)?;
+
+        self.execute_compiled_sql_query_context_owned(compiled, continuation)
+    }
+
+    // Resolve the cached template for one parameterized query, bind this
+    // execution's values, and compile the bound statement on the ordinary
+    // query surface. The bound command is cached under the template text plus
+    // the bind value types and is reused while executions repeat the same
+    // values; new values recompile it, and the shared plan cache still reuses
+    // value-independent plans through its parameter contracts. Semi-join
+    // templates stay uncached because their commands embed source rows.
+    pub(in crate::db::session::sql) fn compile_sql_query_with_params(
+        &self,
+        sql: &str,
+        entity_name: Option<&str>,
+        params: Vec<InputValue>,
+    ) -> Result<
+        (
+            SqlCompiledCommandExecutionContext,
+            SqlCacheAttribution,
+            SqlCompilePhaseAttribution,
+        ),
+        QueryError,
+    > {
+        let catalog = self.sql_query_catalog_for_entity_name(entity_name)?;
+        let (template, catalog, cache_attribution) =
+            self.compile_sql_template_with_cache(sql, catalog)?;
+        let values = sql_bind_values(params)?;
+        if sql_statement_has_semi_join(template.statement()) {
+            let (command, phase_attribution) =
+                self.compile_bound_sql_query(&template, &catalog, &values)?;
+            let authority = catalog.accepted_entity_authority();
+            let context = SqlCompiledCommandExecutionContext::new(
+                command,
+                catalog,
+                Some(authority),
+                SqlCompiledCommandSurface::Query,
+            );
+
+            return Ok((context, cache_attribution, phase_attribution));
+        }
+
+        let entity_path = catalog.identity().entity_path_handle();
+        let (cache_key, catalog) = SqlCompiledCommandCacheContext::from_catalog(
+            SqlCompiledCommandSurface::PreparedQuery,
+            sql,
+            catalog,
+        )
+        .into_cache_inputs();
+        let cache_key = cache_key.with_parameter_types(&values);
+        let (_, cached) = self.lookup_sql_compiled_cache_entry(&cache_key, entity_path.as_ref())?;
+        if let Some(command) = cached.as_ref().and_then(|entry| match entry {
+            SqlCompiledCacheEntry::Bound(bound) => bound.command_for(&values),
+            SqlCompiledCacheEntry::Command(_) | SqlCompiledCacheEntry::Template(_) => None,
+        }) {
+            let context = SqlCompiledCommandExecutionContext::new(
+                command,
+                catalog,
+                None,
+                SqlCompiledCommandSurface::Query,
+            );
+
+            return Ok((
+                context,
+                SqlCacheAttribution::sql_compiled_command_cache_hit(),
+                SqlCompileAttributionBuilder::default().finish(),
+            ));
+        }
+
+        let (command, phase_attribution) =
+            self.compile_bound_sql_query(&template, &catalog, &values)?;
+        self.insert_sql_compiled_cache_entry(
+            cache_key,
+            SqlCompiledCacheEntry::Bound(CompiledSqlBoundCommand::new(&values, command.clone())),
+            entity_path.as_ref(),
+        )?;
+        let authority = catalog.accepted_entity_authority();
+        let context = SqlCompiledCommandExecutionContext::new(
+            command,
+            catalog,
+            Some(authority),
+            SqlCompiledCommandSurface::Query,
+        );
+
+        Ok((
+            context,
+            SqlCacheAttribution::sql_compiled_command_cache_miss(),
+            phase_attribution,
+        ))
+    }
+
+    fn compile_bound_sql_query(
+        &self,
+        template: &CompiledSqlTemplate,
+        catalog: &AcceptedSchemaCatalogContext,
+        values: &[Value],
+    ) -> Result<(CompiledSqlCommand, SqlCompilePhaseAttribution), QueryError> {
+        let bound =
+            bind_sql_statement_parameters(template.statement(), template.parameter_count(), values)
+                .map_err(QueryError::from_sql_lowering_error)?;
+        let bound = if sql_statement_has_semi_join(&bound) {
+            self.resolve_sql_semi_joins(&bound)?
+        } else {
+            bound
+        };
+        let (artifacts, compile_attribution) = Self::compile_sql_statement_measured(
+            &bound,
+            SqlCompiledCommandSurface::Query,
+            catalog.accepted_schema_info(),
+        )?;
+        let mut attribution = SqlCompileAttributionBuilder::default();
+        attribution.record_core_compile(compile_attribution);
+
+        Ok((artifacts.command, attribution.finish()))
+    }
+}
+
+// Lower public bind values onto runtime literals. Enum inputs need accepted
+// catalog admission that only applies once a literal meets its field, so
+// callers bind enum fields with the variant name as text, the same form SQL
+// enum literals use.
+fn sql_bind_values(params: Vec<InputValue>) -> Result<Vec<Value>, QueryError> {
+    params
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| {
+            value.try_into_runtime_non_enum().ok_or_else(|| {
+                QueryError::from_sql_lowering_error(SqlLoweringError::parameter_binding(
+                    index,
+                    SqlParameterBindingReason::EnumValueUnsupported,
+                ))
+            })
+        })
+        .collect()
+}
@@ -26,6 +26,7 @@ pub(in crate::db::session::sql) fn sql_statement_result_from_structural_projecti
         fixed_scales,
         rows,
         row_count,
+        next_cursor: None,
     })
 }
 
@@ -48,6 +49,7 @@ pub(in crate::db::session::sql) fn sql_projection_statement_result_from_value_ro
             .map(|row| sql_output_row_from_value_row(catalog, row))
             .collect::<Result<Vec<_>, _>>()?,
         row_count,
+        next_cursor: None,
     })
 }
 
@@ -72,6 +74,7 @@ pub(in crate::db::session::sql) fn sql_projection_statement_result_from_fallible
         fixed_scales,
         rows,
         row_count,
+        next_cursor: None,
     })
 }
 
@@ -0,0 +1,241 @@
+//! Module: db::session::sql::relation_path
+//! Responsibility: bounded batch lookup of `relation.field` projection values.
+//! Does not own: relation-path syntax, placement rules, or projection rewrite.
+//! Boundary: reads the related rows for one already-materialized SELECT page
+//! with one primary-key membership read per target entity, then replaces each
+//! relation key column with the projected target value.
+
+use crate::{
+    db::{
+        DbSession, QueryError,
+        query::admission::{QueryAdmissionPolicy, QueryAdmissionSummary},
+        session::sql::{CompiledSqlCommand, SqlCompiledCommandSurface, SqlStatementResult},
+        sql::{
+            lowering::{SqlLoweringError, SqlRelationPath, SqlRelationPathReason},
+            parser::{SqlExpr, SqlProjection, SqlSelectItem, SqlSelectStatement, SqlStatement},
+        },
+    },
+    traits::CanisterKind,
+    value::{OutputValue, Value},
+};
+use icydb_diagnostic_code::QueryReadAdmissionCode;
+use std::{cmp::Ordering, collections::BTreeMap};
+
+///
+/// SqlRelationTargetRows
+///
+/// Related rows read for one target entity, sorted by primary key. Each row
+/// carries its projected target fields in `fields` order.
+///
+
+struct SqlRelationTargetRows {
+    fields: Vec<String>,
+    rows: Vec<(Value, Vec<OutputValue>)>,
+    fixed_scales: Vec<Option<u32>>,
+}
+
+impl SqlRelationTargetRows {
+    fn field_index(&self, field: &str) -> Option<usize> {
+        self.fields.iter().position(|candidate| candidate == field)
+    }
+
+    fn row(&self, key: &Value) -> Option<&[OutputValue]> {
+        self.rows
+            .binary_search_by(|(candidate, _)| Value::canonical_cmp(candidate, key))
+            .ok()
+            .map(|index| self.rows[index].1.as_slice())
+    }
+}
+
+impl<C: CanisterKind> DbSession<C> {
+    // Replace every relation key column of one scalar SELECT result with the
+    // target value it names. Missing targets and `NULL` keys project `NULL`.
+    pub(in crate::db::session::sql) fn attach_sql_relation_paths(
+        &self,
+        mut result: SqlStatementResult,
+        relation_paths: &[SqlRelationPath],
+    ) -> Result<SqlStatementResult, QueryError> {
+        if relation_paths.is_empty() {
+            return Ok(result);
+        }
+        let SqlStatementResult::Projection {
+            rows, fixed_scales, ..
+        } = &mut result
+        else {
+            return Err(QueryError::invariant());
+        };
+
+        // Phase 1: read each target entity once for every key on this page.
+        let mut targets = BTreeMap::<&str, Vec<&SqlRelationPath>>::new();
+        for path in relation_paths {
+            targets.entry(path.target_entity()).or_default().push(path);
+        }
+        let mut target_rows = BTreeMap::new();
+        for (target_entity, paths) in &targets {
+            let keys = relation_path_keys(rows, paths)?;
+            let mut fields = Vec::<String>::new();
+            for path in paths {
+                if !fields.iter().any(|field| field == path.target_field()) {
+                    fields.push(path.target_field().to_string());
+                }
+            }
+            let lookup = self.read_sql_relation_targets(target_entity, fields, keys)?;
+            target_rows.insert(*target_entity, lookup);
+        }
+
+        // Phase 2: replace each key cell with its related field value.
+        for path in relation_paths {
+            let lookup = target_rows
+                .get(path.target_entity())
+                .ok_or_else(QueryError::invariant)?;
+            let field_index = lookup
+                .field_index(path.target_field())
+                .ok_or_else(QueryError::invariant)?;
+            for row in rows.iter_mut() {
+                let cell = row
+                    .get_mut(path.column())
+                    .ok_or_else(QueryError::invariant)?;
+                let related = match cell.to_key_value() {
+                    Some(key) => lookup
+                        .row(&key)
+                        .and_then(|fields| fields.get(field_index))
+                        .cloned(),
+                    None => None,
+                };
+                *cell = related.unwrap_or(OutputValue::Null);
+            }
+            if let Some(scale) = fixed_scales.get_mut(path.column()) {
+                *scale = lookup.fixed_scales[field_index];
+            }
+        }
+
+        Ok(result)
+    }
+
+    // Read the requested fields of every keyed target row through one
+    // primary-key membership SELECT under semi-join source admission.
+    fn read_sql_relation_targets(
+        &self,
+        target_entity: &str,
+        fields: Vec<String>,
+        keys: Vec<Value>,
+    ) -> Result<SqlRelationTargetRows, QueryError> {
+        let fixed_scales = vec![None; fields.len()];
+        if keys.is_empty() {
+            return Ok(SqlRelationTargetRows {
+                fields,
+                rows: Vec::new(),
+                fixed_scales,
+            });
+        }
+
+        let policy = QueryAdmissionPolicy::sql_semi_join_source();
+        let max_keys = policy
+            .max_primary_key_input_terms()
+            .ok_or_else(QueryError::invariant)?
+            .get();
+        if keys.len() > max_keys as usize {
+            return Err(QueryReadAdmissionCode::PrimaryKeyInputExceedsPolicy.into());
+        }
+        let catalog = self.sql_query_catalog_for_entity_name(Some(target_entity))?;
+        let [primary_key] = catalog.accepted_schema_info().primary_key_names() else {
+            return Err(QueryError::from_sql_lowering_error(
+                SqlLoweringError::unsupported_relation_path(
+                    SqlRelationPathReason::CompositeTargetKey,
+                ),
+            ));
+        };
+
+        let mut items = vec![SqlSelectItem::Field(primary_key.clone())];
+        items.extend(fields.iter().cloned().map(SqlSelectItem::Field));
+        let source = SqlSelectStatement {
+            entity: target_entity.to_string(),
+            table_alias: None,
+            projection_aliases: vec![None; items.len()],
+            projection: SqlProjection::Items(items),
+            predicate: Some(SqlExpr::Membership {
+                expr: Box::new(SqlExpr::Field(primary_key.clone())),
+                values: keys,
+                negated: false,
+            }),
+            distinct: false,
+            group_by: Vec::new(),
+            having: Vec::new(),
+            order_by: Vec::new(),
+            limit: None,
+            limit_per: Vec::new(),
+            offset: None,
+        };
+        let (artifacts, _) = Self::compile_sql_statement_measured(
+            &SqlStatement::Select(source),
+            SqlCompiledCommandSurface::Query,
+            catalog.accepted_schema_info(),
+        )?;
+        let CompiledSqlCommand::Select { query, .. } = artifacts.command else {
+            return Err(QueryError::invariant());
+        };
+        let (prepared_plan, projection, cache_attribution) = self
+            .sql_select_prepared_plan_for_accepted_authority(
+                &query,
+                catalog.accepted_entity_authority(),
+                catalog.snapshot(),
+            )?;
+        let summary = policy.evaluate(QueryAdmissionSummary::from_plan(
+            policy.lane(),
+            prepared_plan.logical_plan(),
+        ));
+        if let Some(rejection) = summary.rejection() {
+            return Err(QueryError::from(rejection.code()));
+        }
+
+        let (result, _) = self.execute_sql_statement_from_structural_prepared_plan(
+            prepared_plan,
+            projection,
+            cache_attribution,
+        )?;
+        let SqlStatementResult::Projection {
+            rows, fixed_scales, ..
+        } = result
+        else {
+            return Err(QueryError::invariant());
+        };
+        let mut target_rows = rows
+            .into_iter()
+            .map(|mut row| {
+                let key = row.first().and_then(OutputValue::to_key_value)?;
+                row.remove(0);
+                Some((key, row))
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(QueryError::invariant)?;
+        target_rows.sort_by(|(left, _), (right, _)| Value::canonical_cmp(left, right));
+
+        Ok(SqlRelationTargetRows {
+            fields,
+            rows: target_rows,
+            fixed_scales: fixed_scales.into_iter().skip(1).collect(),
+        })
+    }
+}
+
+// Collect the distinct non-null relation keys one page references through
+// any of the supplied relation-path columns.
+fn relation_path_keys(
+    rows: &[Vec<OutputValue>],
+    paths: &[&SqlRelationPath],
+) -> Result<Vec<Value>, QueryError> {
+    let mut keys = Vec::new();
+    for row in rows {
+        for path in paths {
+            let cell = row.get(path.column()).ok_or_else(QueryError::invariant)?;
+            if matches!(cell, OutputValue::Null) {
+                continue;
+            }
+            keys.push(cell.to_key_value().ok_or_else(QueryError::invariant)?);
+        }
+    }
+    keys.sort_by(Value::canonical_cmp);
+    keys.dedup_by(|left, right| Value::canonical_cmp(left, right) == Ordering::Equal);
+
+    Ok(keys)
+}
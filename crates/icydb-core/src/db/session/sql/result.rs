@@ -23,6 +23,14 @@ pub enum SqlStatementResult {
         fixed_scales: Vec<Option<u32>>,
         rows: Vec<Vec<OutputValue>>,
         row_count: u32,
+        next_cursor: Option<String>,
+    },
+    Upsert {
+        inserted_count: u32,
+        updated_count: u32,
+        columns: Vec<String>,
+        fixed_scales: Vec<Option<u32>>,
+        rows: Option<Vec<Vec<OutputValue>>>,
     },
     Grouped {
         columns: Vec<String>,
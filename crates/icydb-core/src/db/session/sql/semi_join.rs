@@ -0,0 +1,111 @@
+//! Module: db::session::sql::semi_join
+//! Responsibility: bounded execution of SQL semi-join source queries.
+//! Does not own: semi-join syntax, source-shape rules, or predicate rewrite.
+//! Boundary: runs each uncorrelated `IN (SELECT ...)` / `EXISTS (SELECT ...)`
+//! source under the semi-join read-admission policy and hands its key set to
+//! the lowering-owned rewrite.
+
+use crate::{
+    db::{
+        DbSession, QueryError,
+        query::admission::{QueryAdmissionPolicy, QueryAdmissionSummary},
+        session::sql::{
+            CompiledSqlCommand, SqlCompiledCommandSurface,
+            projection::execute_sql_projection_rows_for_canister,
+        },
+        sql::{
+            lowering::{SqlLoweringError, resolve_sql_statement_semi_joins},
+            parser::{SqlExpr, SqlOrderDirection, SqlOrderTerm, SqlSelectStatement, SqlStatement},
+        },
+    },
+    traits::CanisterKind,
+    value::Value,
+};
+use icydb_diagnostic_code::QueryReadAdmissionCode;
+
+impl<C: CanisterKind> DbSession<C> {
+    // Replace every semi-join in one query-surface statement with the literal
+    // predicate implied by its current source rows. The result is never cached:
+    // source rows can change between executions of the same SQL text.
+    pub(in crate::db::session::sql) fn resolve_sql_semi_joins(
+        &self,
+        statement: &SqlStatement,
+    ) -> Result<SqlStatement, QueryError> {
+        resolve_sql_statement_semi_joins(statement, &mut |source| {
+            self.execute_sql_semi_join_source(source)
+                .map_err(SqlLoweringError::from)
+        })
+        .map_err(QueryError::from_sql_lowering_error)
+    }
+
+    // Execute one semi-join source against its own accepted catalog. The
+    // source must pass index-backed read admission, and its key set may not
+    // exceed the primary-key input budget the outer membership will carry.
+    fn execute_sql_semi_join_source(
+        &self,
+        source: &SqlSelectStatement,
+    ) -> Result<Vec<Value>, QueryError> {
+        let policy = QueryAdmissionPolicy::sql_semi_join_source();
+        let max_keys = policy
+            .max_primary_key_input_terms()
+            .ok_or_else(QueryError::invariant)?
+            .get();
+
+        // Read at most one row past the budget so an oversized source is
+        // detected without materializing its whole key set. Bounded reads
+        // need a deterministic order, so unordered sources page by primary key.
+        let catalog = self.sql_query_catalog_for_entity_name(Some(source.entity.as_str()))?;
+        let mut source = source.clone();
+        let probe_limit = max_keys.saturating_add(1);
+        source.limit = Some(
+            source
+                .limit
+                .map_or(probe_limit, |limit| limit.min(probe_limit)),
+        );
+        if source.order_by.is_empty() {
+            source.order_by = catalog
+                .accepted_schema_info()
+                .primary_key_names()
+                .iter()
+                .map(|name| SqlOrderTerm {
+                    field: SqlExpr::Field(name.clone()),
+                    direction: SqlOrderDirection::Asc,
+                    nulls: None,
+                })
+                .collect();
+        }
+
+        let (artifacts, _) = Self::compile_sql_statement_measured(
+            &SqlStatement::Select(source),
+            SqlCompiledCommandSurface::Query,
+            catalog.accepted_schema_info(),
+        )?;
+        let CompiledSqlCommand::Select { query, .. } = artifacts.command else {
+            return Err(QueryError::invariant());
+        };
+        let (prepared_plan, _, _) = self.sql_select_prepared_plan_for_accepted_authority(
+            &query,
+            catalog.accepted_entity_authority(),
+            catalog.snapshot(),
+        )?;
+        let summary = policy.evaluate(QueryAdmissionSummary::from_plan(
+            policy.lane(),
+            prepared_plan.logical_plan(),
+        ));
+        if let Some(rejection) = summary.rejection() {
+            return Err(QueryError::from(rejection.code()));
+        }
+
+        let (rows, _) =
+            execute_sql_projection_rows_for_canister(&self.db, self.debug, prepared_plan)
+                .map_err(QueryError::execute)?;
+        if rows.len() > max_keys as usize {
+            return Err(QueryReadAdmissionCode::PrimaryKeyInputExceedsPolicy.into());
+        }
+
+        Ok(rows
+            .into_iter()
+            .map(|row| row.into_iter().next().unwrap_or(Value::Null))
+            .collect())
+    }
+}
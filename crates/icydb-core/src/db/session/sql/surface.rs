@@ -118,6 +118,8 @@ const fn sql_statement_surface_from_statement(statement: &SqlStatement) -> SqlSt
     match statement {
         SqlStatement::Ddl(_) => SqlStatementSurface::Ddl,
         SqlStatement::Select(_)
+        | SqlStatement::Union(_)
+        | SqlStatement::With(_)
         | SqlStatement::Delete(_)
         | SqlStatement::Insert(_)
         | SqlStatement::Update(_)
@@ -141,6 +143,8 @@ const fn sql_statement_shell_surface_from_statement(
         SqlStatement::Ddl(_) => SqlStatementShellSurface::Ddl,
         SqlStatement::Update(_) => SqlStatementShellSurface::Update,
         SqlStatement::Select(_)
+        | SqlStatement::Union(_)
+        | SqlStatement::With(_)
         | SqlStatement::Delete(_)
         | SqlStatement::Insert(_)
         | SqlStatement::Describe(_)
@@ -169,6 +173,8 @@ const fn sql_statement_requires_introspection_from_statement(statement: &SqlStat
         | SqlStatement::ShowStores(_)
         | SqlStatement::ShowMemory(_) => true,
         SqlStatement::Select(_)
+        | SqlStatement::Union(_)
+        | SqlStatement::With(_)
         | SqlStatement::Delete(_)
         | SqlStatement::Insert(_)
         | SqlStatement::Update(_)
@@ -176,9 +182,14 @@ const fn sql_statement_requires_introspection_from_statement(statement: &SqlStat
     }
 }
 
-const fn sql_statement_entity_name_from_statement(statement: &SqlStatement) -> Option<&str> {
+fn sql_statement_entity_name_from_statement(statement: &SqlStatement) -> Option<&str> {
     match statement {
         SqlStatement::Select(statement) => Some(statement.entity.as_str()),
+        SqlStatement::Union(statement) => match statement.branches.as_slice().first() {
+            Some(branch) => Some(branch.entity.as_str()),
+            None => None,
+        },
+        SqlStatement::With(statement) => Some(statement.entity()),
         SqlStatement::Delete(statement) => Some(statement.entity.as_str()),
         SqlStatement::Insert(statement) => Some(statement.entity.as_str()),
         SqlStatement::Update(statement) => Some(statement.entity.as_str()),
@@ -213,6 +224,7 @@ const fn sql_statement_entity_name_from_statement(statement: &SqlStatement) -> O
         #[cfg(feature = "sql")]
         SqlStatement::Explain(statement) => match &statement.statement {
             SqlExplainTarget::Select(statement) => Some(statement.entity.as_str()),
+            SqlExplainTarget::With(statement) => Some(statement.entity()),
             SqlExplainTarget::Delete(statement) => Some(statement.entity.as_str()),
         },
         SqlStatement::Describe(statement) => Some(statement.entity.as_str()),
@@ -236,8 +248,10 @@ impl<C: CanisterKind> DbSession<C> {
     ) -> Result<(), QueryError> {
         match (surface, statement) {
             (
-                SqlCompiledCommandSurface::Query,
+                SqlCompiledCommandSurface::Query | SqlCompiledCommandSurface::PreparedQuery,
                 SqlStatement::Select(_)
+                | SqlStatement::Union(_)
+                | SqlStatement::With(_)
                 | SqlStatement::Describe(_)
                 | SqlStatement::ShowConstraints(_)
                 | SqlStatement::ShowIndexes(_)
@@ -248,7 +262,10 @@ impl<C: CanisterKind> DbSession<C> {
                 | SqlStatement::ShowMemory(_),
             ) => Ok(()),
             #[cfg(feature = "sql")]
-            (SqlCompiledCommandSurface::Query, SqlStatement::Explain(_)) => Ok(()),
+            (
+                SqlCompiledCommandSurface::Query | SqlCompiledCommandSurface::PreparedQuery,
+                SqlStatement::Explain(_),
+            ) => Ok(()),
             (
                 SqlCompiledCommandSurface::Mutation,
                 SqlStatement::Insert(_) | SqlStatement::Update(_) | SqlStatement::Delete(_),
@@ -256,18 +273,30 @@ impl<C: CanisterKind> DbSession<C> {
             (_, SqlStatement::Ddl(_)) => Err(QueryError::sql_lowering(
                 SqlLoweringCode::SqlDdlExecutionUnsupported,
             )),
-            (SqlCompiledCommandSurface::Query, SqlStatement::Insert(_)) => Err(
-                QueryError::sql_surface_mismatch(SqlSurfaceMismatchCode::QueryRejectsInsert),
-            ),
-            (SqlCompiledCommandSurface::Query, SqlStatement::Update(_)) => Err(
-                QueryError::sql_surface_mismatch(SqlSurfaceMismatchCode::QueryRejectsUpdate),
-            ),
-            (SqlCompiledCommandSurface::Query, SqlStatement::Delete(_)) => Err(
-                QueryError::sql_surface_mismatch(SqlSurfaceMismatchCode::QueryRejectsDelete),
-            ),
-            (SqlCompiledCommandSurface::Mutation, SqlStatement::Select(_)) => Err(
-                QueryError::sql_surface_mismatch(SqlSurfaceMismatchCode::MutationRejectsSelect),
-            ),
+            (
+                SqlCompiledCommandSurface::Query | SqlCompiledCommandSurface::PreparedQuery,
+                SqlStatement::Insert(_),
+            ) => Err(QueryError::sql_surface_mismatch(
+                SqlSurfaceMismatchCode::QueryRejectsInsert,
+            )),
+            (
+                SqlCompiledCommandSurface::Query | SqlCompiledCommandSurface::PreparedQuery,
+                SqlStatement::Update(_),
+            ) => Err(QueryError::sql_surface_mismatch(
+                SqlSurfaceMismatchCode::QueryRejectsUpdate,
+            )),
+            (
+                SqlCompiledCommandSurface::Query | SqlCompiledCommandSurface::PreparedQuery,
+                SqlStatement::Delete(_),
+            ) => Err(QueryError::sql_surface_mismatch(
+                SqlSurfaceMismatchCode::QueryRejectsDelete,
+            )),
+            (
+                SqlCompiledCommandSurface::Mutation,
+                SqlStatement::Select(_) | SqlStatement::Union(_) | SqlStatement::With(_),
+            ) => Err(QueryError::sql_surface_mismatch(
+                SqlSurfaceMismatchCode::MutationRejectsSelect,
+            )),
             #[cfg(feature = "sql")]
             (SqlCompiledCommandSurface::Mutation, SqlStatement::Explain(_)) => Err(
                 QueryError::sql_surface_mismatch(SqlSurfaceMismatchCode::MutationRejectsExplain),
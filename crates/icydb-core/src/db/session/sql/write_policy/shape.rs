@@ -169,7 +169,8 @@ fn conjunctive_leaves(expr: &SqlExpr) -> Vec<&SqlExpr> {
         | SqlExpr::FunctionCall { .. }
         | SqlExpr::Unary { .. }
         | SqlExpr::Binary { .. }
-        | SqlExpr::Case { .. } => vec![expr],
+        | SqlExpr::Case { .. }
+        | SqlExpr::SemiJoin { .. } => vec![expr],
     }
 }
 
@@ -218,7 +219,8 @@ fn simple_field_name<'a>(
         | SqlExpr::FunctionCall { .. }
         | SqlExpr::Unary { .. }
         | SqlExpr::Binary { .. }
-        | SqlExpr::Case { .. } => None,
+        | SqlExpr::Case { .. }
+        | SqlExpr::SemiJoin { .. } => None,
     }
 }
 
@@ -267,14 +269,17 @@ mod tests {
         let asc_id = SqlOrderTerm {
             field: SqlExpr::Field("id".to_string()),
             direction: SqlOrderDirection::Asc,
+            nulls: None,
         };
         let desc_id = SqlOrderTerm {
             field: SqlExpr::Field("id".to_string()),
             direction: SqlOrderDirection::Desc,
+            nulls: None,
         };
         let asc_other = SqlOrderTerm {
             field: SqlExpr::Field("name".to_string()),
             direction: SqlOrderDirection::Asc,
+            nulls: None,
         };
 
         assert_eq!(
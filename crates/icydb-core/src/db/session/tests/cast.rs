@@ -0,0 +1,92 @@
+//! End-to-end proof for checked `CAST` and `::` conversions.
+
+use crate::{
+    db::{
+        SqlStatementResult,
+        session::tests::support::{initialize_identity_row, insert_identity_row, projection_rows},
+    },
+    value::OutputValue,
+};
+use icydb_diagnostic_code::{DiagnosticCode, DiagnosticDetail, QueryProjectionCode};
+
+#[test]
+fn sql_cast_converts_values_across_read_and_write_positions() {
+    let session = initialize_identity_row();
+    let first = insert_identity_row(&session, 300);
+
+    let result = session
+        .execute_trusted_sql_mutation(
+            "INSERT INTO IdentityRow (id, payload) VALUES (DEFAULT, CAST('41' AS NAT64)) \
+             RETURNING id",
+        )
+        .expect("INSERT VALUES should accept literal CAST conversions");
+    let SqlStatementResult::Projection { rows, .. } = result else {
+        panic!("INSERT RETURNING should project the inserted key");
+    };
+    let [OutputValue::Nat64(second)] = rows[0].as_slice() else {
+        panic!("INSERT RETURNING should project one Nat64 key");
+    };
+    let _result = session
+        .execute_trusted_sql_exact_update(
+            format!("UPDATE IdentityRow SET payload = '42'::nat64 WHERE id = {second}").as_str(),
+            1,
+        )
+        .expect("UPDATE SET should accept postfix CAST conversions");
+
+    assert_eq!(
+        projection_rows(
+            &session,
+            format!(
+                "SELECT CAST(payload AS TEXT), payload::int64 FROM IdentityRow \
+                 WHERE CAST(payload AS TEXT) = '42' OR id = {first} ORDER BY id ASC"
+            )
+            .as_str(),
+        ),
+        vec![
+            vec![
+                OutputValue::Text("300".to_string()),
+                OutputValue::Int64(300)
+            ],
+            vec![OutputValue::Text("42".to_string()), OutputValue::Int64(42)],
+        ],
+    );
+
+    for (sql, reason) in [
+        (
+            format!("SELECT CAST(payload AS NAT8) FROM IdentityRow WHERE id = {first}"),
+            QueryProjectionCode::CastOverflow,
+        ),
+        (
+            "INSERT INTO IdentityRow (id, payload) VALUES (DEFAULT, CAST('-1' AS NAT64))"
+                .to_string(),
+            QueryProjectionCode::CastNegativeToUnsigned,
+        ),
+        (
+            "INSERT INTO IdentityRow (id, payload) VALUES (DEFAULT, CAST('abc' AS NAT64))"
+                .to_string(),
+            QueryProjectionCode::CastNotRepresentable,
+        ),
+    ] {
+        let error = if sql.starts_with("SELECT") {
+            session.execute_trusted_sql_query(sql.as_str())
+        } else {
+            session.execute_trusted_sql_mutation(sql.as_str())
+        }
+        .expect_err("failed checked casts should surface typed projection errors");
+        let diagnostic = error.diagnostic();
+        assert_eq!(
+            diagnostic.code(),
+            DiagnosticCode::QueryUnsupportedProjection,
+            "{sql}",
+        );
+        assert!(
+            matches!(
+                diagnostic.detail(),
+                Some(DiagnosticDetail::QueryProjection {
+                    reason: actual,
+                }) if *actual == reason
+            ),
+            "{sql}",
+        );
+    }
+}
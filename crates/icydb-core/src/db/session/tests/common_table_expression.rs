@@ -0,0 +1,246 @@
+//! End-to-end proof for non-recursive `WITH` common table expressions inlined
+//! onto one entity.
+
+use crate::{
+    db::{
+        DynamicStructuralPatch, DynamicWriteCell, SqlStatementResult,
+        schema::AcceptedFieldKind,
+        session::tests::support::{SchemaFixture, TestSession, field, field_index, index_path},
+    },
+    types::EntityTag,
+    value::{InputValue, OutputValue},
+};
+
+const ENTITY_SOURCE: &str = "db::session::tests::common_table_expression::Score";
+const ENTITY_NAME: &str = "Score";
+const ENTITY_TAG: EntityTag = EntityTag::new(240);
+const TOTALS: &str = "WITH totals AS (\
+                      SELECT player, SUM(points) AS total FROM Score GROUP BY player)";
+
+#[test]
+fn grouped_cte_is_filtered_ordered_and_limited_by_the_outer_query() {
+    let session = initialize();
+    seed_rows(&session);
+
+    let sql = format!(
+        "{TOTALS} SELECT player, total FROM totals WHERE total > 20 ORDER BY total DESC LIMIT 10"
+    );
+    let (columns, rows) = grouped(&session, sql.as_str());
+    assert_eq!(columns, ["player", "total"]);
+    assert_eq!(
+        rows.iter().map(|row| row[0].clone()).collect::<Vec<_>>(),
+        ["bob", "ada"].map(|player| OutputValue::Text(player.to_string())),
+        "players above the threshold should come back by descending total",
+    );
+
+    let sql = format!(
+        "{TOTALS} SELECT player, total FROM totals WHERE total > 20 ORDER BY total ASC LIMIT 1"
+    );
+    let (_, rows) = grouped(&session, sql.as_str());
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0][0], OutputValue::Text("ada".to_string()));
+}
+
+#[test]
+fn row_ctes_chain_onto_the_base_entity() {
+    let session = initialize();
+    seed_rows(&session);
+
+    let sql = "WITH recent AS (SELECT id, player, points FROM Score WHERE id > 2), \
+               big AS (SELECT player AS who, points FROM recent WHERE points >= 7) \
+               SELECT who, points FROM big ORDER BY points DESC";
+    let (columns, rows) = projection(&session, sql);
+    assert_eq!(columns, ["who", "points"]);
+    assert_eq!(
+        rows,
+        [("ada", 30), ("bob", 25), ("cyd", 7)]
+            .map(|(player, points)| vec![
+                OutputValue::Text(player.to_string()),
+                OutputValue::Nat64(points),
+            ])
+            .to_vec(),
+    );
+
+    let sql = "WITH high AS (SELECT player FROM Score WHERE points > 20) \
+               SELECT * FROM high ORDER BY player";
+    assert_eq!(projection(&session, sql).1, texts(&["ada", "bob"]));
+
+    let sql = "WITH players AS (SELECT DISTINCT player FROM Score) \
+               SELECT player FROM players WHERE player <> 'cyd' ORDER BY player";
+    assert_eq!(projection(&session, sql).1, texts(&["ada", "bob"]));
+}
+
+#[test]
+fn unsupported_cte_shapes_fail_closed() {
+    let session = initialize();
+    seed_rows(&session);
+
+    for sql in [
+        "WITH RECURSIVE t AS (SELECT id FROM Score) SELECT id FROM t",
+        "WITH t (x) AS (SELECT id FROM Score) SELECT x FROM t",
+        "WITH t AS (SELECT id FROM Score), t AS (SELECT id FROM Score) SELECT id FROM t",
+        "WITH t AS (SELECT id FROM Score), u AS (SELECT id FROM Score) SELECT id FROM t",
+        "WITH t AS (SELECT id FROM Score) SELECT points FROM t",
+        "WITH t AS (SELECT id FROM Score ORDER BY id LIMIT 2) SELECT id FROM t WHERE id > 1",
+        "WITH t AS (SELECT DISTINCT player, points FROM Score) SELECT player FROM t",
+        "WITH t AS (SELECT player, SUM(points) AS total FROM Score GROUP BY player) \
+         SELECT COUNT(*) FROM t",
+    ] {
+        session
+            .execute_trusted_sql_query(sql)
+            .expect_err("unsupported WITH shape should fail closed");
+    }
+}
+
+#[test]
+fn explain_reports_each_materialized_cte_as_its_own_node() {
+    let session = initialize();
+    seed_rows(&session);
+
+    let sql = format!("{TOTALS} SELECT player, total FROM totals WHERE total > 20");
+    assert_eq!(
+        explain(&session, format!("EXPLAIN {sql}").as_str()),
+        explain(
+            &session,
+            "EXPLAIN SELECT player, SUM(points) AS total FROM Score \
+             GROUP BY player HAVING SUM(points) > 20",
+        ),
+        "logical EXPLAIN should show the inlined plan",
+    );
+
+    let execution = explain(&session, format!("EXPLAIN EXECUTION {sql}").as_str());
+    for expected in [
+        "CommonTableExpression",
+        "cte=Text(\"totals\")",
+        "cte_materialization=Text(\"grouped\")",
+        "max_groups=Nat64(",
+        "max_group_bytes=Nat64(",
+    ] {
+        assert!(
+            execution.contains(expected),
+            "execution EXPLAIN should report `{expected}`: {execution}",
+        );
+    }
+
+    let json = explain(&session, format!("EXPLAIN EXECUTION JSON {sql}").as_str());
+    assert!(
+        json.contains("CommonTableExpression") && json.contains("totals"),
+        "execution JSON should report the CTE node: {json}",
+    );
+
+    let sql = "EXPLAIN EXECUTION WITH high AS (SELECT id FROM Score WHERE points > 5) \
+               SELECT COUNT(*) FROM high";
+    let execution = explain(&session, sql);
+    assert!(
+        execution.contains("cte=Text(\"high\")")
+            && execution.contains("cte_materialization=Text(\"inline\")"),
+        "global aggregates over a CTE should report the CTE node: {execution}",
+    );
+}
+
+fn initialize() -> TestSession {
+    SchemaFixture::new()
+        .entity(
+            ENTITY_TAG,
+            ENTITY_SOURCE,
+            ENTITY_NAME,
+            vec![
+                field(1, "id", 0, AcceptedFieldKind::Nat64),
+                field(2, "player", 1, AcceptedFieldKind::Text { max_len: None }),
+                field(3, "points", 2, AcceptedFieldKind::Nat64),
+            ],
+            vec![field_index(
+                1,
+                "player_idx",
+                vec![index_path(
+                    2,
+                    1,
+                    "player",
+                    AcceptedFieldKind::Text { max_len: None },
+                )],
+            )],
+        )
+        .initialize()
+}
+
+fn seed_rows(session: &TestSession) {
+    let rows = [
+        (1, "ada", 10),
+        (2, "bob", 20),
+        (3, "ada", 30),
+        (4, "cyd", 5),
+        (5, "bob", 25),
+        (6, "cyd", 7),
+        (7, "ada", 2),
+    ]
+    .into_iter()
+    .map(|(id, player, points)| row(id, player, points))
+    .collect();
+    session
+        .execute_trusted_dynamic_insert_batch(ENTITY_NAME, rows)
+        .expect("cte fixture rows should insert");
+}
+
+fn row(id: u64, player: &str, points: u64) -> DynamicStructuralPatch {
+    DynamicStructuralPatch::new(vec![
+        (
+            "id".to_string(),
+            DynamicWriteCell::Value(InputValue::Nat64(id)),
+        ),
+        (
+            "player".to_string(),
+            DynamicWriteCell::Value(InputValue::Text(player.to_string())),
+        ),
+        (
+            "points".to_string(),
+            DynamicWriteCell::Value(InputValue::Nat64(points)),
+        ),
+    ])
+}
+
+fn texts(values: &[&str]) -> Vec<Vec<OutputValue>> {
+    values
+        .iter()
+        .map(|value| vec![OutputValue::Text((*value).to_string())])
+        .collect()
+}
+
+fn projection(session: &TestSession, sql: &str) -> (Vec<String>, Vec<Vec<OutputValue>>) {
+    let SqlStatementResult::Projection { columns, rows, .. } = session
+        .execute_trusted_sql_query(sql)
+        .expect("cte query should execute")
+    else {
+        panic!("cte query should return rows");
+    };
+    (columns, rows)
+}
+
+fn grouped(session: &TestSession, sql: &str) -> (Vec<String>, Vec<Vec<OutputValue>>) {
+    let SqlStatementResult::Grouped { columns, rows, .. } = session
+        .execute_trusted_sql_query(sql)
+        .expect("cte query should execute")
+    else {
+        panic!("grouped cte query should return grouped rows");
+    };
+    let rows = rows
+        .iter()
+        .map(|row| {
+            row.group_key()
+                .iter()
+                .chain(row.aggregate_values())
+                .cloned()
+                .collect()
+        })
+        .collect();
+    (columns, rows)
+}
+
+fn explain(session: &TestSession, sql: &str) -> String {
+    let SqlStatementResult::Explain(explain) = session
+        .execute_trusted_sql_query(sql)
+        .expect("cte explain should execute")
+    else {
+        panic!("cte explain should return text");
+    };
+    explain
+}
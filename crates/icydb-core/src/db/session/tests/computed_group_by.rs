@@ -0,0 +1,200 @@
+//! End-to-end proof for computed `GROUP BY` keys and their grouped routing.
+
+use crate::{
+    db::{
+        SqlStatementResult,
+        schema::{
+            AcceptedFieldKind, PersistedIndexExpressionOp, PersistedIndexExpressionSnapshot,
+            PersistedIndexKeyItemSnapshot, PersistedIndexKeySnapshot,
+        },
+        session::tests::support::{
+            SchemaFixture, TestSession, field, index, index_path, initialize_identity_row,
+            insert_identity_row, insert_row,
+        },
+    },
+    types::{Decimal, EntityTag},
+    value::{InputValue, OutputValue},
+};
+use icydb_diagnostic_code::DiagnosticCode;
+
+const MEMBER_SOURCE: &str = "db::session::tests::computed_group_by::Member";
+const MEMBER_NAME: &str = "Member";
+const MEMBER_TAG: EntityTag = EntityTag::new(219);
+
+#[test]
+fn sql_group_by_computed_keys_bucket_rows_and_bind_having_and_order() {
+    let session = initialize_identity_row();
+    for payload in [100, 150, 210, 250, 290, 300] {
+        insert_identity_row(&session, payload);
+    }
+
+    let (columns, rows) = grouped_rows(
+        &session,
+        "SELECT FLOOR(payload / 100), COUNT(*) FROM IdentityRow \
+         GROUP BY FLOOR(payload / 100) HAVING COUNT(*) > 1 \
+         ORDER BY FLOOR(payload / 100) DESC LIMIT 10",
+    );
+    assert_eq!(
+        columns,
+        vec!["FLOOR(payload / 100)".to_string(), "COUNT(*)".to_string()],
+    );
+    assert_eq!(
+        rows,
+        vec![
+            vec![
+                OutputValue::Decimal(Decimal::new(2, 0)),
+                OutputValue::Nat64(3)
+            ],
+            vec![
+                OutputValue::Decimal(Decimal::new(1, 0)),
+                OutputValue::Nat64(2)
+            ],
+        ],
+    );
+
+    let (columns, rows) = grouped_rows(
+        &session,
+        "SELECT CASE WHEN payload >= 200 THEN 'high' ELSE 'low' END AS bucket, \
+         SUM(payload) FROM IdentityRow \
+         GROUP BY CASE WHEN payload >= 200 THEN 'high' ELSE 'low' END",
+    );
+    assert_eq!(
+        columns,
+        vec!["bucket".to_string(), "SUM(payload)".to_string()]
+    );
+    assert_eq!(
+        rows,
+        vec![
+            vec![
+                OutputValue::Text("high".to_string()),
+                OutputValue::Decimal(Decimal::new(1050, 0)),
+            ],
+            vec![
+                OutputValue::Text("low".to_string()),
+                OutputValue::Decimal(Decimal::new(250, 0)),
+            ],
+        ],
+    );
+
+    let error = session
+        .execute_trusted_sql_query(
+            "SELECT payload + 1, COUNT(*) FROM IdentityRow GROUP BY payload / 100",
+        )
+        .expect_err("grouped projection must reference the declared computed key");
+    assert_eq!(
+        error.diagnostic_code(),
+        DiagnosticCode::QueryUnsupportedSqlFeature,
+    );
+}
+
+#[test]
+fn computed_group_keys_stream_only_over_a_matching_expression_index() {
+    let session = initialize_members();
+    for (id, name, team) in [
+        (1, "Ada", "red"),
+        (2, "ADA", "blue"),
+        (3, "bob", "red"),
+        (4, "Bob", "Red"),
+        (5, "ada", "blue"),
+    ] {
+        insert_row(
+            &session,
+            MEMBER_NAME,
+            vec![
+                ("id", InputValue::Nat64(id)),
+                ("name", InputValue::Text(name.to_string())),
+                ("team", InputValue::Text(team.to_string())),
+            ],
+        );
+    }
+
+    let ordered = explain_plan(
+        &session,
+        "SELECT LOWER(name), COUNT(*) FROM Member \
+         GROUP BY LOWER(name) ORDER BY LOWER(name) ASC LIMIT 10",
+    );
+    assert!(ordered.contains("ordered_group"), "{ordered}");
+    assert!(ordered.contains("LOWER(name)"), "{ordered}");
+
+    let hashed = explain_plan(
+        &session,
+        "SELECT LOWER(team), COUNT(*) FROM Member \
+         GROUP BY LOWER(team) ORDER BY LOWER(team) ASC LIMIT 10",
+    );
+    assert!(hashed.contains("group_key_order_unavailable"), "{hashed}");
+
+    let (_, rows) = grouped_rows(
+        &session,
+        "SELECT LOWER(name), COUNT(*) FROM Member \
+         WHERE LOWER(name) = 'ada' GROUP BY LOWER(name)",
+    );
+    assert_eq!(
+        rows,
+        vec![vec![
+            OutputValue::Text("ada".to_string()),
+            OutputValue::Nat64(3)
+        ]],
+    );
+}
+
+fn initialize_members() -> TestSession {
+    let text = || AcceptedFieldKind::Text { max_len: None };
+    SchemaFixture::new()
+        .entity(
+            MEMBER_TAG,
+            MEMBER_SOURCE,
+            MEMBER_NAME,
+            vec![
+                field(1, "id", 0, AcceptedFieldKind::Nat64),
+                field(2, "name", 1, text()),
+                field(3, "team", 2, text()),
+            ],
+            vec![index(
+                1,
+                "lower_name_idx",
+                PersistedIndexKeySnapshot::Items(vec![PersistedIndexKeyItemSnapshot::Expression(
+                    Box::new(PersistedIndexExpressionSnapshot::new(
+                        PersistedIndexExpressionOp::Lower,
+                        index_path(2, 1, "name", text()),
+                        text(),
+                        text(),
+                        "expr:v1:LOWER(name)".to_string(),
+                    )),
+                )]),
+            )],
+        )
+        .initialize()
+}
+
+fn grouped_rows(session: &TestSession, sql: &str) -> (Vec<String>, Vec<Vec<OutputValue>>) {
+    let SqlStatementResult::Grouped { columns, rows, .. } = session
+        .execute_trusted_sql_query(sql)
+        .expect("focused grouped SQL should execute")
+    else {
+        panic!("focused grouped SQL should return grouped rows")
+    };
+
+    (
+        columns,
+        rows.into_iter()
+            .map(|row| {
+                row.group_key()
+                    .iter()
+                    .chain(row.aggregate_values())
+                    .cloned()
+                    .collect()
+            })
+            .collect(),
+    )
+}
+
+fn explain_plan(session: &TestSession, sql: &str) -> String {
+    let SqlStatementResult::Explain(explain) = session
+        .execute_trusted_sql_query(format!("EXPLAIN {sql}").as_str())
+        .expect("computed grouped query should explain")
+    else {
+        panic!("EXPLAIN should return an explain payload");
+    };
+
+    explain
+}
@@ -0,0 +1,177 @@
+//! End-to-end proof for index key components stored in descending order.
+
+use crate::{
+    db::{
+        DynamicStructuralPatch, DynamicWriteCell, SqlStatementResult,
+        schema::AcceptedFieldKind,
+        session::tests::support::{
+            SchemaFixture, TestSession, explain, field, field_index, index_path, projection_rows,
+        },
+    },
+    types::EntityTag,
+    value::{InputValue, OutputValue},
+};
+
+const ENTITY_SOURCE: &str = "db::session::tests::descending_index::Player";
+const ENTITY_NAME: &str = "Player";
+const ENTITY_TAG: EntityTag = EntityTag::new(243);
+const LEADERBOARD_SQL: &str =
+    "SELECT id FROM Player ORDER BY score DESC, created_at ASC, id ASC LIMIT 10";
+
+#[test]
+fn mixed_direction_order_streams_from_descending_index_both_ways() {
+    let session = initialize(true);
+    seed_rows(&session);
+
+    assert_eq!(ids(&session, LEADERBOARD_SQL), vec![3, 1, 5, 2, 4]);
+    let forward = explain(&session, LEADERBOARD_SQL);
+    assert!(forward.contains("IndexRange"), "{forward}");
+    assert!(forward.contains("OrderByAccessSatisfied"), "{forward}");
+
+    let reverse = "SELECT id FROM Player ORDER BY score ASC, created_at DESC, id DESC LIMIT 10";
+    assert_eq!(ids(&session, reverse), vec![4, 2, 5, 1, 3]);
+    let reverse = explain(&session, reverse);
+    assert!(reverse.contains("OrderByAccessSatisfied"), "{reverse}");
+
+    let uniform = "SELECT id FROM Player ORDER BY score DESC, created_at DESC, id DESC";
+    assert_eq!(ids(&session, uniform), vec![1, 3, 5, 2, 4]);
+    let uniform = explain(&session, uniform);
+    assert!(uniform.contains("OrderByMaterializedSort"), "{uniform}");
+}
+
+#[test]
+fn descending_index_serves_ranges_prefixes_and_windows() {
+    let session = initialize(true);
+    seed_rows(&session);
+
+    assert_eq!(
+        ids(
+            &session,
+            "SELECT id FROM Player WHERE score >= 10 AND score < 30 \
+             ORDER BY score DESC, created_at ASC, id ASC",
+        ),
+        vec![5, 2],
+    );
+    assert_eq!(
+        ids(
+            &session,
+            "SELECT id FROM Player WHERE score > -5 AND score <= 20 \
+             ORDER BY score ASC, created_at DESC, id DESC",
+        ),
+        vec![2, 5],
+    );
+    assert_eq!(
+        ids(
+            &session,
+            "SELECT id FROM Player WHERE score = 30 ORDER BY created_at ASC, id ASC",
+        ),
+        vec![3, 1],
+    );
+    assert_eq!(
+        ids(
+            &session,
+            "SELECT id FROM Player ORDER BY score DESC, created_at ASC, id ASC LIMIT 2 OFFSET 1",
+        ),
+        vec![1, 5],
+    );
+
+    session
+        .execute_trusted_sql_exact_update("UPDATE Player SET score = 40 WHERE id = 4", 1)
+        .expect("key update should apply");
+    assert_eq!(ids(&session, LEADERBOARD_SQL), vec![4, 3, 1, 5, 2]);
+}
+
+#[test]
+fn create_index_desc_rebuilds_and_shows_direction() {
+    let session = initialize(false);
+    seed_rows(&session);
+
+    session
+        .execute_admin_sql_ddl(
+            "CREATE INDEX leaderboard_idx ON Player (score DESC, created_at ASC) \
+             EXPECT SCHEMA VERSION 1 SET SCHEMA VERSION 2",
+        )
+        .expect("descending index DDL should execute");
+
+    assert_eq!(ids(&session, LEADERBOARD_SQL), vec![3, 1, 5, 2, 4]);
+    let explain = explain(&session, LEADERBOARD_SQL);
+    assert!(explain.contains("OrderByAccessSatisfied"), "{explain}");
+
+    let SqlStatementResult::ShowIndexes(indexes) = session
+        .execute_trusted_sql_query("SHOW INDEXES FROM Player")
+        .expect("SHOW INDEXES should execute")
+    else {
+        panic!("SHOW INDEXES should return index lines");
+    };
+    assert!(
+        indexes
+            .iter()
+            .any(|line| line.starts_with("INDEX leaderboard_idx (score DESC, created_at)")),
+        "SHOW INDEXES should report descending keys: {indexes:?}",
+    );
+}
+
+fn initialize(with_index: bool) -> TestSession {
+    let indexes = if with_index {
+        vec![field_index(
+            1,
+            "leaderboard_idx",
+            vec![
+                index_path(2, 1, "score", AcceptedFieldKind::Int64).with_descending(true),
+                index_path(3, 2, "created_at", AcceptedFieldKind::Nat64),
+            ],
+        )]
+    } else {
+        Vec::new()
+    };
+    SchemaFixture::new()
+        .entity(
+            ENTITY_TAG,
+            ENTITY_SOURCE,
+            ENTITY_NAME,
+            vec![
+                field(1, "id", 0, AcceptedFieldKind::Nat64),
+                field(2, "score", 1, AcceptedFieldKind::Int64),
+                field(3, "created_at", 2, AcceptedFieldKind::Nat64),
+            ],
+            indexes,
+        )
+        .initialize()
+}
+
+fn seed_rows(session: &TestSession) {
+    let rows = [(1, 30, 5), (2, 10, 1), (3, 30, 2), (4, -5, 3), (5, 20, 4)]
+        .into_iter()
+        .map(|(id, score, created_at)| row(id, score, created_at))
+        .collect();
+    session
+        .execute_trusted_dynamic_insert_batch(ENTITY_NAME, rows)
+        .expect("descending index fixture rows should insert");
+}
+
+fn row(id: u64, score: i64, created_at: u64) -> DynamicStructuralPatch {
+    DynamicStructuralPatch::new(vec![
+        (
+            "id".to_string(),
+            DynamicWriteCell::Value(InputValue::Nat64(id)),
+        ),
+        (
+            "score".to_string(),
+            DynamicWriteCell::Value(InputValue::Int64(score)),
+        ),
+        (
+            "created_at".to_string(),
+            DynamicWriteCell::Value(InputValue::Nat64(created_at)),
+        ),
+    ])
+}
+
+fn ids(session: &TestSession, sql: &str) -> Vec<u64> {
+    projection_rows(session, sql)
+        .into_iter()
+        .map(|row| match row.as_slice() {
+            [OutputValue::Nat64(id)] => *id,
+            other => panic!("descending index query should project one id: {other:?}"),
+        })
+        .collect()
+}
@@ -0,0 +1,195 @@
+//! End-to-end proof for exact `SUM`/`AVG` over arbitrary-precision and
+//! 128-bit integer columns.
+
+use crate::{
+    db::{
+        DynamicStructuralPatch, DynamicWriteCell, SqlStatementResult,
+        schema::AcceptedFieldKind,
+        session::tests::support::{SchemaFixture, TestSession, field, field_index, index_path},
+    },
+    types::{Decimal, EntityTag, NatBig},
+    value::{InputValue, OutputValue},
+};
+use num_bigint::BigUint;
+
+const ENTITY_SOURCE: &str = "db::session::tests::exact_sum::Ledger";
+const ENTITY_NAME: &str = "Ledger";
+const ENTITY_TAG: EntityTag = EntityTag::new(241);
+
+#[test]
+fn nat_big_sum_and_avg_stay_exact_in_the_input_type() {
+    let session = initialize();
+    seed_rows(&session);
+
+    let (_, rows) = projection(&session, "SELECT SUM(supply), AVG(supply) FROM Ledger");
+    assert_eq!(
+        rows,
+        [vec![
+            OutputValue::NatBig(supply(24)),
+            OutputValue::NatBig(NatBig::from_biguint(BigUint::from(10_u32).pow(39) * 48_u32,)),
+        ]],
+        "24 * 10^40 summed over five rows averages to 4.8 * 10^40 exactly",
+    );
+
+    let (_, rows) = projection(&session, "SELECT SUM(DISTINCT supply) FROM Ledger");
+    assert_eq!(rows, [vec![OutputValue::NatBig(supply(19))]]);
+}
+
+#[test]
+fn grouped_nat_big_sums_filter_and_order_exactly() {
+    let session = initialize();
+    seed_rows(&session);
+
+    let (columns, rows) = grouped(
+        &session,
+        "SELECT account, SUM(supply) AS total FROM Ledger GROUP BY account \
+         ORDER BY account ASC LIMIT 10",
+    );
+    assert_eq!(columns, ["account", "total"]);
+    assert_eq!(
+        rows,
+        [("burn", 11), ("mint", 13)]
+            .map(|(account, units)| vec![
+                OutputValue::Text(account.to_string()),
+                OutputValue::NatBig(supply(units)),
+            ])
+            .to_vec(),
+    );
+}
+
+#[test]
+fn int_128_sums_stay_exact_and_fail_on_overflow() {
+    let session = initialize();
+    seed_rows(&session);
+
+    let (_, rows) = projection(
+        &session,
+        "SELECT SUM(delta), AVG(delta) FROM Ledger WHERE id <= 4",
+    );
+    assert_eq!(
+        rows,
+        [vec![OutputValue::Int128(-2), OutputValue::Int128(-1)]],
+        "intermediate totals may leave the 128-bit range while the final SUM fits",
+    );
+
+    // The integer AVG rounds -0.5 away from zero; a decimal cast keeps the
+    // fractional mean.
+    let (_, rows) = projection(
+        &session,
+        "SELECT AVG(delta), AVG(CAST(delta AS DECIMAL(1))) FROM Ledger WHERE id IN (2, 4)",
+    );
+    assert_eq!(
+        rows,
+        [vec![
+            OutputValue::Int128(-1),
+            OutputValue::Decimal(Decimal::new(-5, 1)),
+        ]],
+    );
+
+    let err = session
+        .execute_trusted_sql_query("SELECT SUM(delta) FROM Ledger WHERE account = 'burn'")
+        .expect_err("an Int128 SUM below i128::MIN should not narrow");
+    assert_eq!(
+        err.diagnostic_code(),
+        icydb_diagnostic_code::DiagnosticCode::QueryNumericOverflow,
+        "overflow should surface as a typed numeric failure",
+    );
+}
+
+fn initialize() -> TestSession {
+    SchemaFixture::new()
+        .entity(
+            ENTITY_TAG,
+            ENTITY_SOURCE,
+            ENTITY_NAME,
+            vec![
+                field(1, "id", 0, AcceptedFieldKind::Nat64),
+                field(2, "account", 1, AcceptedFieldKind::Text { max_len: None }),
+                field(3, "supply", 2, AcceptedFieldKind::NatBig { max_bytes: 64 }),
+                field(4, "delta", 3, AcceptedFieldKind::Int128),
+            ],
+            vec![field_index(
+                1,
+                "account_idx",
+                vec![index_path(
+                    2,
+                    1,
+                    "account",
+                    AcceptedFieldKind::Text { max_len: None },
+                )],
+            )],
+        )
+        .initialize()
+}
+
+fn seed_rows(session: &TestSession) {
+    let rows = [
+        (1, "mint", 3, i128::MAX),
+        (2, "mint", 5, -2),
+        (3, "burn", 4, i128::MIN),
+        (4, "mint", 5, 1),
+        (5, "burn", 7, -1),
+    ]
+    .into_iter()
+    .map(|(id, account, units, delta)| row(id, account, supply(units), delta))
+    .collect();
+    session
+        .execute_trusted_dynamic_insert_batch(ENTITY_NAME, rows)
+        .expect("exact sum fixture rows should insert");
+}
+
+// Supplies sit far outside the bounded decimal domain: `units * 10^40`.
+fn supply(units: u32) -> NatBig {
+    NatBig::from_biguint(BigUint::from(10_u32).pow(40) * units)
+}
+
+fn row(id: u64, account: &str, supply: NatBig, delta: i128) -> DynamicStructuralPatch {
+    DynamicStructuralPatch::new(vec![
+        (
+            "id".to_string(),
+            DynamicWriteCell::Value(InputValue::Nat64(id)),
+        ),
+        (
+            "account".to_string(),
+            DynamicWriteCell::Value(InputValue::Text(account.to_string())),
+        ),
+        (
+            "supply".to_string(),
+            DynamicWriteCell::Value(InputValue::NatBig(supply)),
+        ),
+        (
+            "delta".to_string(),
+            DynamicWriteCell::Value(InputValue::Int128(delta)),
+        ),
+    ])
+}
+
+fn projection(session: &TestSession, sql: &str) -> (Vec<String>, Vec<Vec<OutputValue>>) {
+    let SqlStatementResult::Projection { columns, rows, .. } = session
+        .execute_trusted_sql_query(sql)
+        .expect("exact sum query should execute")
+    else {
+        panic!("exact sum query should return rows");
+    };
+    (columns, rows)
+}
+
+fn grouped(session: &TestSession, sql: &str) -> (Vec<String>, Vec<Vec<OutputValue>>) {
+    let SqlStatementResult::Grouped { columns, rows, .. } = session
+        .execute_trusted_sql_query(sql)
+        .expect("exact sum query should execute")
+    else {
+        panic!("grouped cte query should return grouped rows");
+    };
+    let rows = rows
+        .iter()
+        .map(|row| {
+            row.group_key()
+                .iter()
+                .chain(row.aggregate_values())
+                .cloned()
+                .collect()
+        })
+        .collect();
+    (columns, rows)
+}
@@ -0,0 +1,237 @@
+//! End-to-end proof for stored generated columns computed at write time.
+
+use crate::{
+    db::{
+        DynamicStructuralPatch, DynamicWriteCell,
+        schema::{
+            AcceptedFieldKind, FieldId, FieldInsertGeneration, FieldStorageDecode,
+            PersistedFieldSnapshot, PersistedIndexFieldPathSnapshot, SchemaFieldSlot,
+            SchemaFieldWritePolicy, SchemaInsertDefault,
+        },
+        session::tests::support::{
+            SchemaFixture, TestSession, explain, field, field_index, projection_rows,
+        },
+    },
+    types::EntityTag,
+    value::{InputValue, OutputValue},
+};
+
+const ENTITY_SOURCE: &str = "db::session::tests::generated_column::Line";
+const ENTITY_NAME: &str = "Line";
+const ENTITY_TAG: EntityTag = EntityTag::new(244);
+const TOTALS_SQL: &str = "SELECT id, total FROM Line ORDER BY id ASC";
+
+#[test]
+fn generated_column_is_computed_on_insert_and_recomputed_on_update() {
+    let session = initialize(true);
+    seed_rows(&session);
+
+    assert_eq!(
+        totals(&session, TOTALS_SQL),
+        vec![(1, Some(20)), (2, Some(9)), (3, Some(0))],
+    );
+
+    session
+        .execute_trusted_sql_exact_update("UPDATE Line SET quantity = 7 WHERE id = 2", 1)
+        .expect("source update should recompute the generated column");
+    assert_eq!(
+        totals(&session, TOTALS_SQL),
+        vec![(1, Some(20)), (2, Some(21)), (3, Some(0))],
+    );
+
+    let by_total = "SELECT id, total FROM Line WHERE total >= 20 ORDER BY total DESC, id DESC";
+    assert_eq!(
+        totals(&session, by_total),
+        vec![(2, Some(21)), (1, Some(20))]
+    );
+    let explain = explain(&session, by_total);
+    assert!(explain.contains("IndexRange"), "{explain}");
+}
+
+#[test]
+fn explicit_generated_column_writes_reject() {
+    let session = initialize(false);
+    seed_rows(&session);
+
+    let mut cells = row_cells(4, 2, 2);
+    cells.push((
+        "total".to_string(),
+        DynamicWriteCell::Value(InputValue::Int64(99)),
+    ));
+    session
+        .execute_trusted_dynamic_insert_batch(ENTITY_NAME, vec![DynamicStructuralPatch::new(cells)])
+        .expect_err("explicit generated value should reject");
+    session
+        .execute_trusted_sql_exact_update("UPDATE Line SET total = 1 WHERE id = 1", 1)
+        .expect_err("generated column assignment should reject");
+
+    assert_eq!(
+        totals(&session, TOTALS_SQL),
+        vec![(1, Some(20)), (2, Some(9)), (3, Some(0))],
+    );
+}
+
+#[test]
+fn add_generated_column_backfills_rows_and_can_be_indexed() {
+    let session = initialize_without_total();
+    seed_rows(&session);
+
+    session
+        .execute_admin_sql_ddl(
+            "ALTER TABLE Line ADD COLUMN total INT64 \
+             GENERATED ALWAYS AS (price * quantity) STORED \
+             EXPECT SCHEMA VERSION 1 SET SCHEMA VERSION 2",
+        )
+        .expect("generated column DDL should publish and backfill");
+    assert_eq!(
+        totals(&session, TOTALS_SQL),
+        vec![(1, Some(20)), (2, Some(9)), (3, Some(0))],
+    );
+
+    session
+        .execute_admin_sql_ddl(
+            "CREATE INDEX total_idx ON Line (total) \
+             EXPECT SCHEMA VERSION 2 SET SCHEMA VERSION 3",
+        )
+        .expect("generated column index DDL should execute");
+    let by_total = "SELECT id, total FROM Line WHERE total < 10 ORDER BY total ASC, id ASC";
+    assert_eq!(totals(&session, by_total), vec![(3, Some(0)), (2, Some(9))]);
+    let explain = explain(&session, by_total);
+    assert!(explain.contains("IndexRange"), "{explain}");
+
+    session
+        .execute_admin_sql_ddl(
+            "ALTER TABLE Line DROP COLUMN price \
+             EXPECT SCHEMA VERSION 3 SET SCHEMA VERSION 4",
+        )
+        .expect_err("generated column source should not drop");
+    session
+        .execute_admin_sql_ddl(
+            "ALTER TABLE Line ADD COLUMN stamped TIMESTAMP \
+             GENERATED ALWAYS AS (NOW()) STORED \
+             EXPECT SCHEMA VERSION 3 SET SCHEMA VERSION 4",
+        )
+        .expect_err("nondeterministic generated column should reject");
+}
+
+#[test]
+fn add_generated_column_rejects_before_publication_when_one_row_fails() {
+    let session = initialize_without_total();
+    seed_rows(&session);
+
+    // Row 3 has `quantity = 0`, so its generated value divides by zero.
+    session
+        .execute_admin_sql_ddl(
+            "ALTER TABLE Line ADD COLUMN unit_price INT64 \
+             GENERATED ALWAYS AS (price * quantity / quantity) STORED \
+             EXPECT SCHEMA VERSION 1 SET SCHEMA VERSION 2",
+        )
+        .expect_err("generated column DDL should reject a row it cannot compute");
+    session
+        .execute_trusted_sql_query("SELECT unit_price FROM Line ORDER BY id ASC")
+        .expect_err("rejected generated column should stay unpublished");
+
+    // The accepted schema version did not advance, and every row is intact.
+    session
+        .execute_admin_sql_ddl(
+            "ALTER TABLE Line ADD COLUMN total INT64 \
+             GENERATED ALWAYS AS (price * quantity) STORED \
+             EXPECT SCHEMA VERSION 1 SET SCHEMA VERSION 2",
+        )
+        .expect("schema should still accept version 1 after the rejected DDL");
+    assert_eq!(
+        totals(&session, TOTALS_SQL),
+        vec![(1, Some(20)), (2, Some(9)), (3, Some(0))],
+    );
+}
+
+fn initialize(with_index: bool) -> TestSession {
+    schema(true, with_index).initialize()
+}
+
+fn initialize_without_total() -> TestSession {
+    schema(false, false).initialize()
+}
+
+fn schema(with_total: bool, with_index: bool) -> SchemaFixture {
+    let mut fields = vec![
+        field(1, "id", 0, AcceptedFieldKind::Nat64),
+        field(2, "price", 1, AcceptedFieldKind::Int64),
+        field(3, "quantity", 2, AcceptedFieldKind::Int64),
+    ];
+    if with_total {
+        fields.push(
+            PersistedFieldSnapshot::new_initial_with_write_policy(
+                FieldId::new(4),
+                "total".to_string(),
+                SchemaFieldSlot::new(3),
+                AcceptedFieldKind::Int64,
+                Vec::new(),
+                true,
+                SchemaInsertDefault::None,
+                SchemaFieldWritePolicy::from_model_policies(
+                    Some(FieldInsertGeneration::Computed),
+                    None,
+                ),
+                FieldStorageDecode::ByKind,
+                AcceptedFieldKind::Int64.leaf_codec_for_storage(FieldStorageDecode::ByKind),
+            )
+            .with_generated_expression(Some("price * quantity".to_string())),
+        );
+    }
+    let indexes = if with_index {
+        vec![field_index(
+            1,
+            "total_idx",
+            vec![PersistedIndexFieldPathSnapshot::new(
+                FieldId::new(4),
+                SchemaFieldSlot::new(3),
+                vec!["total".to_string()],
+                AcceptedFieldKind::Int64,
+                true,
+            )],
+        )]
+    } else {
+        Vec::new()
+    };
+
+    SchemaFixture::new().entity(ENTITY_TAG, ENTITY_SOURCE, ENTITY_NAME, fields, indexes)
+}
+
+fn seed_rows(session: &TestSession) {
+    let rows = [(1, 5, 4), (2, 3, 3), (3, 8, 0)]
+        .into_iter()
+        .map(|(id, price, quantity)| DynamicStructuralPatch::new(row_cells(id, price, quantity)))
+        .collect();
+    session
+        .execute_trusted_dynamic_insert_batch(ENTITY_NAME, rows)
+        .expect("generated column fixture rows should insert");
+}
+
+fn row_cells(id: u64, price: i64, quantity: i64) -> Vec<(String, DynamicWriteCell)> {
+    vec![
+        (
+            "id".to_string(),
+            DynamicWriteCell::Value(InputValue::Nat64(id)),
+        ),
+        (
+            "price".to_string(),
+            DynamicWriteCell::Value(InputValue::Int64(price)),
+        ),
+        (
+            "quantity".to_string(),
+            DynamicWriteCell::Value(InputValue::Int64(quantity)),
+        ),
+    ]
+}
+
+fn totals(session: &TestSession, sql: &str) -> Vec<(u64, Option<i64>)> {
+    projection_rows(session, sql)
+        .into_iter()
+        .map(|row| match row.as_slice() {
+            [OutputValue::Nat64(id), OutputValue::Int64(total)] => (*id, Some(*total)),
+            [OutputValue::Nat64(id), OutputValue::Null] => (*id, None),
+            other => panic!("generated column query should project id and total: {other:?}"),
+        })
+        .collect()
+}
@@ -0,0 +1,186 @@
+//! End-to-end proof for covering indexes that carry `INCLUDE` payload columns.
+
+use crate::{
+    db::{
+        DynamicStructuralPatch, DynamicWriteCell, SqlStatementResult,
+        schema::AcceptedFieldKind,
+        session::tests::support::{
+            SchemaFixture, TestSession, field, field_index, index_path, projection_rows,
+        },
+    },
+    types::EntityTag,
+    value::{InputValue, OutputValue},
+};
+
+const ENTITY_SOURCE: &str = "db::session::tests::include_index::Player";
+const ENTITY_NAME: &str = "Player";
+const ENTITY_TAG: EntityTag = EntityTag::new(242);
+const COVERING_SQL: &str =
+    "SELECT id, name, avatar FROM Player WHERE score >= 0 ORDER BY score ASC, id ASC LIMIT 10";
+
+#[test]
+fn included_payload_covers_projection_and_follows_updates() {
+    let session = initialize(true);
+    seed_rows(&session);
+
+    assert!(
+        explain(&session).contains("cov_read_route=Text(\"covering_read\")"),
+        "included columns should let the index cover the projection",
+    );
+    assert_eq!(
+        projection_rows(&session, COVERING_SQL),
+        expected(&[(2, "bo", "b.png"), (3, "cy", "c.png"), (1, "al", "a.png")]),
+    );
+
+    session
+        .execute_trusted_sql_exact_update("UPDATE Player SET avatar = 'b2.png' WHERE id = 2", 1)
+        .expect("payload-only update should apply");
+    session
+        .execute_trusted_sql_exact_update("UPDATE Player SET score = 50 WHERE id = 3", 1)
+        .expect("key update should apply");
+    session
+        .execute_trusted_sql_mutation("DELETE FROM Player WHERE id = 1")
+        .expect("delete should apply");
+
+    assert_eq!(
+        projection_rows(&session, COVERING_SQL),
+        expected(&[(2, "bo", "b2.png"), (3, "cy", "c.png")]),
+    );
+}
+
+#[test]
+fn create_index_include_rebuilds_payload_and_shows_it() {
+    let session = initialize(false);
+    seed_rows(&session);
+
+    session
+        .execute_admin_sql_ddl(
+            "CREATE INDEX score_idx ON Player (score) INCLUDE (name, avatar) \
+             EXPECT SCHEMA VERSION 1 SET SCHEMA VERSION 2",
+        )
+        .expect("INCLUDE index DDL should execute");
+
+    assert!(explain(&session).contains("cov_read_route=Text(\"covering_read\")"));
+    assert_eq!(
+        projection_rows(&session, COVERING_SQL),
+        expected(&[(2, "bo", "b.png"), (3, "cy", "c.png"), (1, "al", "a.png")]),
+    );
+
+    let SqlStatementResult::ShowIndexes(indexes) = session
+        .execute_trusted_sql_query("SHOW INDEXES FROM Player")
+        .expect("SHOW INDEXES should execute")
+    else {
+        panic!("SHOW INDEXES should return index lines");
+    };
+    assert!(
+        indexes
+            .iter()
+            .any(|line| line.starts_with("INDEX score_idx (score) INCLUDE (name, avatar)")),
+        "SHOW INDEXES should report included columns: {indexes:?}",
+    );
+
+    for sql in [
+        "CREATE UNIQUE INDEX name_idx ON Player (name) INCLUDE (avatar)",
+        "CREATE INDEX lower_name_idx ON Player (LOWER(name)) INCLUDE (avatar)",
+        "CREATE INDEX repeat_idx ON Player (name) INCLUDE (name)",
+    ] {
+        let err = session
+            .execute_admin_sql_ddl(sql)
+            .expect_err("unsupported INCLUDE shapes should reject");
+        assert_eq!(
+            err.diagnostic_code(),
+            icydb_diagnostic_code::DiagnosticCode::QueryUnsupportedSqlFeature,
+            "{sql}",
+        );
+    }
+}
+
+fn initialize(with_index: bool) -> TestSession {
+    let text = || AcceptedFieldKind::Text { max_len: None };
+    let indexes = if with_index {
+        vec![
+            field_index(
+                1,
+                "score_idx",
+                vec![index_path(4, 3, "score", AcceptedFieldKind::Nat64)],
+            )
+            .with_include(vec![
+                index_path(2, 1, "name", text()),
+                index_path(3, 2, "avatar", text()),
+            ]),
+        ]
+    } else {
+        Vec::new()
+    };
+    SchemaFixture::new()
+        .entity(
+            ENTITY_TAG,
+            ENTITY_SOURCE,
+            ENTITY_NAME,
+            vec![
+                field(1, "id", 0, AcceptedFieldKind::Nat64),
+                field(2, "name", 1, text()),
+                field(3, "avatar", 2, text()),
+                field(4, "score", 3, AcceptedFieldKind::Nat64),
+            ],
+            indexes,
+        )
+        .initialize()
+}
+
+fn seed_rows(session: &TestSession) {
+    let rows = [
+        (1, "al", "a.png", 30),
+        (2, "bo", "b.png", 10),
+        (3, "cy", "c.png", 20),
+    ]
+    .into_iter()
+    .map(|(id, name, avatar, score)| row(id, name, avatar, score))
+    .collect();
+    session
+        .execute_trusted_dynamic_insert_batch(ENTITY_NAME, rows)
+        .expect("include index fixture rows should insert");
+}
+
+fn row(id: u64, name: &str, avatar: &str, score: u64) -> DynamicStructuralPatch {
+    DynamicStructuralPatch::new(vec![
+        (
+            "id".to_string(),
+            DynamicWriteCell::Value(InputValue::Nat64(id)),
+        ),
+        (
+            "name".to_string(),
+            DynamicWriteCell::Value(InputValue::Text(name.to_string())),
+        ),
+        (
+            "avatar".to_string(),
+            DynamicWriteCell::Value(InputValue::Text(avatar.to_string())),
+        ),
+        (
+            "score".to_string(),
+            DynamicWriteCell::Value(InputValue::Nat64(score)),
+        ),
+    ])
+}
+
+fn expected(rows: &[(u64, &str, &str)]) -> Vec<Vec<OutputValue>> {
+    rows.iter()
+        .map(|(id, name, avatar)| {
+            vec![
+                OutputValue::Nat64(*id),
+                OutputValue::Text((*name).to_string()),
+                OutputValue::Text((*avatar).to_string()),
+            ]
+        })
+        .collect()
+}
+
+fn explain(session: &TestSession) -> String {
+    let SqlStatementResult::Explain(explain) = session
+        .execute_trusted_sql_query(format!("EXPLAIN EXECUTION VERBOSE {COVERING_SQL}").as_str())
+        .expect("include index query should explain")
+    else {
+        panic!("EXPLAIN EXECUTION VERBOSE should return an explain payload");
+    };
+    explain
+}
@@ -0,0 +1,260 @@
+//! End-to-end proof for map key access and key-existence predicates.
+
+use crate::{
+    db::{
+        DynamicQuery, DynamicStructuralPatch, DynamicWriteCell, FieldRef, FilterExpr,
+        SqlStatementResult, asc,
+        schema::AcceptedFieldKind,
+        session::tests::support::{SchemaFixture, TestSession, field, projection_rows},
+    },
+    types::EntityTag,
+    value::{InputValue, OutputValue},
+};
+
+const ENTITY_SOURCE: &str = "db::session::tests::map_key::Player";
+const ENTITY_NAME: &str = "Player";
+const ENTITY_TAG: EntityTag = EntityTag::new(237);
+
+#[test]
+fn map_key_subscripts_project_and_filter() {
+    let session = initialize();
+    seed_rows(&session);
+
+    assert_eq!(
+        projection_rows(
+            &session,
+            "SELECT id, settings['theme'] FROM Player ORDER BY id ASC",
+        ),
+        [
+            vec![OutputValue::Nat64(1), OutputValue::Text("dark".to_string())],
+            vec![
+                OutputValue::Nat64(2),
+                OutputValue::Text("light".to_string())
+            ],
+            vec![OutputValue::Nat64(3), OutputValue::Null],
+            vec![OutputValue::Nat64(4), OutputValue::Text("dark".to_string())],
+            vec![OutputValue::Nat64(5), OutputValue::Null],
+        ],
+    );
+    assert_eq!(
+        ids(
+            &session,
+            "SELECT id FROM Player WHERE settings['theme'] = 'dark' ORDER BY id ASC",
+        ),
+        [1, 4],
+    );
+    assert_eq!(
+        ids(
+            &session,
+            "SELECT id FROM Player WHERE settings['lang'] <> 'en' ORDER BY id ASC",
+        ),
+        [3],
+    );
+}
+
+#[test]
+fn missing_map_keys_never_match_filters() {
+    let session = initialize();
+    seed_rows(&session);
+
+    // Absent keys project as NULL but, like other missing field paths, are
+    // rejected before `IS NULL` can admit them. `NOT HAS_KEY` is the spelling
+    // for "key absent".
+    assert!(
+        ids(
+            &session,
+            "SELECT id FROM Player WHERE settings['theme'] IS NULL ORDER BY id ASC",
+        )
+        .is_empty()
+    );
+    assert_eq!(
+        ids(
+            &session,
+            "SELECT id FROM Player WHERE NOT HAS_KEY(settings, 'theme') ORDER BY id ASC",
+        ),
+        [3, 5],
+    );
+}
+
+#[test]
+fn has_key_filters_and_projects() {
+    let session = initialize();
+    seed_rows(&session);
+
+    assert_eq!(
+        ids(
+            &session,
+            "SELECT id FROM Player WHERE HAS_KEY(settings, 'lang') ORDER BY id ASC",
+        ),
+        [1, 3, 4],
+    );
+    assert_eq!(
+        projection_rows(
+            &session,
+            "SELECT HAS_KEY(settings, 'theme') FROM Player ORDER BY id ASC",
+        ),
+        [true, true, false, true, false]
+            .map(|present| vec![OutputValue::Bool(present)])
+            .to_vec(),
+    );
+}
+
+#[test]
+fn has_key_rejects_non_map_input() {
+    let session = initialize();
+
+    session
+        .execute_trusted_sql_query("SELECT id FROM Player WHERE HAS_KEY(id, 'theme')")
+        .expect_err("HAS_KEY should require a text-keyed map input");
+}
+
+#[test]
+fn typed_map_key_filters_match_sql() {
+    let session = initialize();
+    seed_rows(&session);
+
+    let settings = FieldRef::new("settings");
+    assert_eq!(
+        query_ids(&session, settings.key("theme").eq("dark")),
+        [1, 4]
+    );
+    assert_eq!(query_ids(&session, settings.key("lang").gt("en")), [3]);
+    assert_eq!(query_ids(&session, settings.has_key("lang")), [1, 3, 4]);
+    assert_eq!(
+        query_ids(&session, FilterExpr::not(settings.has_key("theme"))),
+        [3, 5],
+    );
+}
+
+#[test]
+fn fixed_map_key_index_indexes_present_keys_only() {
+    let session = initialize();
+    seed_rows(&session);
+
+    let SqlStatementResult::Ddl(report) = session
+        .execute_admin_sql_ddl(
+            "CREATE INDEX player_theme ON Player (settings.theme) \
+             EXPECT SCHEMA VERSION 1 SET SCHEMA VERSION 2",
+        )
+        .expect("map key index DDL should execute")
+    else {
+        panic!("CREATE INDEX should return a DDL report");
+    };
+    assert_eq!(report.rows_scanned(), 5);
+    assert_eq!(report.index_keys_written(), 3);
+
+    session
+        .execute_trusted_dynamic_insert_batch(ENTITY_NAME, vec![row(6, &[("theme", "amber")])])
+        .expect("indexed map key row should insert");
+    assert_eq!(
+        ids(
+            &session,
+            "SELECT id FROM Player WHERE settings['theme'] = 'amber' ORDER BY id ASC",
+        ),
+        [6],
+    );
+
+    session
+        .execute_admin_sql_ddl(
+            "CREATE UNIQUE INDEX player_lang ON Player (settings.lang) \
+             EXPECT SCHEMA VERSION 2 SET SCHEMA VERSION 3",
+        )
+        .expect_err("unique map key indexes cannot guard absent keys");
+}
+
+fn initialize() -> TestSession {
+    SchemaFixture::new()
+        .entity(
+            ENTITY_TAG,
+            ENTITY_SOURCE,
+            ENTITY_NAME,
+            vec![
+                field(1, "id", 0, AcceptedFieldKind::Nat64),
+                field(2, "settings", 1, settings_kind()),
+            ],
+            Vec::new(),
+        )
+        .initialize()
+}
+
+fn settings_kind() -> AcceptedFieldKind {
+    AcceptedFieldKind::Map {
+        key: Box::new(AcceptedFieldKind::Text { max_len: None }),
+        value: Box::new(AcceptedFieldKind::Text { max_len: None }),
+    }
+}
+
+// Five players; row 3 has no `theme` key and row 5 has no settings at all.
+fn seed_rows(session: &TestSession) {
+    let rows: [&[(&str, &str)]; 5] = [
+        &[("theme", "dark"), ("lang", "en")],
+        &[("theme", "light")],
+        &[("lang", "fr")],
+        &[("lang", "en"), ("theme", "dark")],
+        &[],
+    ];
+    let rows = rows
+        .into_iter()
+        .zip(1..)
+        .map(|(settings, id)| row(id, settings))
+        .collect();
+    session
+        .execute_trusted_dynamic_insert_batch(ENTITY_NAME, rows)
+        .expect("map key fixture rows should insert");
+}
+
+fn row(id: u64, settings: &[(&str, &str)]) -> DynamicStructuralPatch {
+    DynamicStructuralPatch::new(vec![
+        (
+            "id".to_string(),
+            DynamicWriteCell::Value(InputValue::Nat64(id)),
+        ),
+        (
+            "settings".to_string(),
+            DynamicWriteCell::Value(InputValue::Map(
+                settings
+                    .iter()
+                    .map(|(key, value)| {
+                        (
+                            InputValue::Text((*key).to_string()),
+                            InputValue::Text((*value).to_string()),
+                        )
+                    })
+                    .collect(),
+            )),
+        ),
+    ])
+}
+
+fn ids(session: &TestSession, sql: &str) -> Vec<u64> {
+    projection_rows(session, sql)
+        .into_iter()
+        .map(|row| match row.as_slice() {
+            [OutputValue::Nat64(id)] => *id,
+            other => panic!("map key query should project one id: {other:?}"),
+        })
+        .collect()
+}
+
+fn query_ids(session: &TestSession, filter: FilterExpr) -> Vec<u64> {
+    let query = DynamicQuery::new(ENTITY_NAME)
+        .filter(filter)
+        .select(["id"])
+        .order_by(asc("id"))
+        .limit(20);
+    let mut ids = Vec::new();
+    let mut continuation = None;
+    loop {
+        let page = session
+            .execute_trusted_live_page(&query, continuation.as_deref())
+            .expect("map key query should execute");
+        ids.extend(page.rows.iter().map(|row| match row.as_slice() {
+            [OutputValue::Nat64(id)] => *id,
+            other => panic!("map key query should project one id: {other:?}"),
+        }));
+        continuation = page.continuation;
+        if continuation.is_none() {
+            return ids;
+        }
+    }
+}
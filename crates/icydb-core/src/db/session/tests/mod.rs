@@ -1,5 +1,31 @@
 //! Native session-level correctness harnesses that require the complete SQL facade.
 
 mod cardinality_tiebreak;
+mod cast;
+mod common_table_expression;
+mod computed_group_by;
+mod descending_index;
+mod exact_sum;
+mod generated_column;
+mod include_index;
+mod map_key;
+mod multivalue_index;
+mod null_safe_compare;
+mod nulls_order;
+mod partition_limit;
+mod prepared_query;
+mod quoted_identifier;
+mod relation_path;
+mod scalar_expression_index;
+mod semi_join;
+mod sql_paging;
+mod statistical_aggregate;
+mod support;
+mod temporal;
+mod text_pattern;
+mod text_search;
 mod tier_c_reference;
+mod union;
 mod unit_ordering;
+mod update_expression;
+mod upsert;
@@ -0,0 +1,243 @@
+//! End-to-end proof for multivalue (collection element) indexes.
+
+use crate::{
+    MAX_MULTIVALUE_INDEX_ELEMENTS,
+    db::{
+        DynamicMutation, DynamicQuery, DynamicStructuralPatch, DynamicWriteCell, FilterExpr,
+        SqlStatementResult, asc,
+        schema::{
+            AcceptedFieldKind, PersistedIndexExpressionOp, PersistedIndexExpressionSnapshot,
+            PersistedIndexKeyItemSnapshot, PersistedIndexKeySnapshot,
+        },
+        session::tests::support::{SchemaFixture, TestSession, field, index, index_path},
+    },
+    types::EntityTag,
+    value::{InputValue, OutputValue},
+};
+
+const ENTITY_SOURCE: &str = "db::session::tests::multivalue_index::Post";
+const ENTITY_NAME: &str = "Post";
+const ENTITY_TAG: EntityTag = EntityTag::new(236);
+
+#[test]
+fn contains_seeks_the_element_index() {
+    let session = initialize(true);
+    seed_rows(&session);
+
+    for (tag, expected) in [
+        ("rust", &[1, 3][..]),
+        ("db", &[1, 2, 4]),
+        ("wasm", &[3]),
+        ("missing", &[]),
+    ] {
+        let (ids, entries_visited) = query_ids(&session, FilterExpr::contains("tags", tag));
+        assert_eq!(ids, expected, "tags CONTAINS {tag}");
+        // A full page re-reads its lookahead posting when the next page resumes.
+        assert!(
+            entries_visited <= expected.len() as u64 + 1,
+            "tags CONTAINS {tag} should only visit its element postings: {entries_visited}",
+        );
+    }
+}
+
+#[test]
+fn contains_any_element_uses_one_multi_lookup_without_duplicates() {
+    let session = initialize(true);
+    seed_rows(&session);
+
+    let (ids, entries_visited) = query_ids(
+        &session,
+        FilterExpr::or(vec![
+            FilterExpr::contains("tags", "rust"),
+            FilterExpr::contains("tags", "db"),
+            FilterExpr::contains("tags", "rust"),
+        ]),
+    );
+
+    assert_eq!(ids, [1, 2, 3, 4]);
+    assert!(
+        entries_visited < 6,
+        "element lookups should not scan every row: {entries_visited}",
+    );
+
+    let (mixed, _) = query_ids(
+        &session,
+        FilterExpr::and(vec![
+            FilterExpr::contains("tags", "db"),
+            FilterExpr::contains("tags", "rust"),
+        ]),
+    );
+    assert_eq!(mixed, [1]);
+}
+
+#[test]
+fn element_entries_follow_updates_and_deletes() {
+    let session = initialize(true);
+    seed_rows(&session);
+
+    session
+        .execute_trusted_dynamic_mutation(&DynamicMutation::Replace {
+            entity: ENTITY_NAME.to_string(),
+            key: InputValue::Nat64(3),
+            patch: row(3, &["db", "wasm"]),
+        })
+        .expect("multivalue update should apply");
+    session
+        .execute_trusted_sql_mutation("DELETE FROM Post WHERE id = 2")
+        .expect("multivalue delete should apply");
+
+    for (tag, expected) in [
+        ("rust", &[1][..]),
+        ("db", &[1, 3, 4]),
+        ("wasm", &[3]),
+        ("sql", &[]),
+    ] {
+        let (ids, _) = query_ids(&session, FilterExpr::contains("tags", tag));
+        assert_eq!(ids, expected, "tags CONTAINS {tag}");
+    }
+}
+
+#[test]
+fn create_index_rebuilds_existing_element_entries() {
+    let session = initialize(false);
+    seed_rows(&session);
+
+    let SqlStatementResult::Ddl(report) = session
+        .execute_admin_sql_ddl(
+            "CREATE INDEX post_tags ON Post USING MULTIVALUE (tags) \
+             EXPECT SCHEMA VERSION 1 SET SCHEMA VERSION 2",
+        )
+        .expect("multivalue index DDL should execute")
+    else {
+        panic!("CREATE INDEX should return a DDL report");
+    };
+    assert_eq!(report.rows_scanned(), 6);
+    assert_eq!(report.index_keys_written(), 8);
+
+    let (ids, entries_visited) = query_ids(&session, FilterExpr::contains("tags", "db"));
+    assert_eq!(ids, [1, 2, 4]);
+    assert!(
+        entries_visited <= 4,
+        "rebuilt element entries should serve the lookup: {entries_visited}",
+    );
+}
+
+#[test]
+fn oversized_collections_fail_closed() {
+    let session = initialize(true);
+    let tags = (0..=MAX_MULTIVALUE_INDEX_ELEMENTS)
+        .map(|n| format!("tag-{n}"))
+        .collect::<Vec<_>>();
+    let tags = tags.iter().map(String::as_str).collect::<Vec<_>>();
+
+    session
+        .execute_trusted_dynamic_insert_batch(ENTITY_NAME, vec![row(1, &tags)])
+        .expect_err("collections above the element bound should not be indexed");
+    session
+        .execute_trusted_dynamic_insert_batch(
+            ENTITY_NAME,
+            vec![row(1, &tags[..MAX_MULTIVALUE_INDEX_ELEMENTS])],
+        )
+        .expect("collections at the element bound should be indexed");
+}
+
+fn initialize(with_index: bool) -> TestSession {
+    let indexes = if with_index {
+        vec![index(
+            1,
+            "post_tags",
+            PersistedIndexKeySnapshot::Items(vec![PersistedIndexKeyItemSnapshot::Expression(
+                Box::new(PersistedIndexExpressionSnapshot::new(
+                    PersistedIndexExpressionOp::Elements,
+                    index_path(2, 1, "tags", tags_kind()),
+                    tags_kind(),
+                    AcceptedFieldKind::Text { max_len: None },
+                    "expr:v1:ELEMENTS(tags)".to_string(),
+                )),
+            )]),
+        )]
+    } else {
+        Vec::new()
+    };
+    SchemaFixture::new()
+        .entity(
+            ENTITY_TAG,
+            ENTITY_SOURCE,
+            ENTITY_NAME,
+            vec![
+                field(1, "id", 0, AcceptedFieldKind::Nat64),
+                field(2, "tags", 1, tags_kind()),
+            ],
+            indexes,
+        )
+        .initialize()
+}
+
+fn tags_kind() -> AcceptedFieldKind {
+    AcceptedFieldKind::List(Box::new(AcceptedFieldKind::Text { max_len: None }))
+}
+
+// Six rows owning eight distinct (row, element) pairs; row 2 repeats `db`
+// and row 5 is empty so neither may contribute extra entries.
+fn seed_rows(session: &TestSession) {
+    let rows: [&[&str]; 6] = [
+        &["rust", "db"],
+        &["db", "sql", "db"],
+        &["rust", "wasm"],
+        &["db"],
+        &[],
+        &["ops"],
+    ];
+    let rows = rows
+        .into_iter()
+        .zip(1..)
+        .map(|(tags, id)| row(id, tags))
+        .collect();
+    session
+        .execute_trusted_dynamic_insert_batch(ENTITY_NAME, rows)
+        .expect("multivalue index fixture rows should insert");
+}
+
+fn row(id: u64, tags: &[&str]) -> DynamicStructuralPatch {
+    DynamicStructuralPatch::new(vec![
+        (
+            "id".to_string(),
+            DynamicWriteCell::Value(InputValue::Nat64(id)),
+        ),
+        (
+            "tags".to_string(),
+            DynamicWriteCell::Value(InputValue::List(
+                tags.iter()
+                    .map(|tag| InputValue::Text((*tag).to_string()))
+                    .collect(),
+            )),
+        ),
+    ])
+}
+
+// Drain every live page for one filter, returning the matched ids and the
+// total number of physical entries visited across pages.
+fn query_ids(session: &TestSession, filter: FilterExpr) -> (Vec<u64>, u64) {
+    let query = DynamicQuery::new(ENTITY_NAME)
+        .filter(filter)
+        .select(["id"])
+        .order_by(asc("id"))
+        .limit(20);
+    let mut ids = Vec::new();
+    let mut entries_visited = 0;
+    let mut continuation = None;
+    loop {
+        let page = session
+            .execute_trusted_live_page(&query, continuation.as_deref())
+            .expect("multivalue index query should execute");
+        entries_visited += page.work.entries_visited;
+        ids.extend(page.rows.iter().map(|row| match row.as_slice() {
+            [OutputValue::Nat64(id)] => *id,
+            other => panic!("multivalue index query should project one id: {other:?}"),
+        }));
+        continuation = page.continuation;
+        if continuation.is_none() {
+            return (ids, entries_visited);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! End-to-end proof for `IS [NOT] DISTINCT FROM` and simple `CASE` over
+//! `NULL` operands.
+
+use crate::{
+    db::{
+        schema::AcceptedFieldKind,
+        session::tests::support::{
+            SchemaFixture, TestSession, field, insert_row, nullable_field, projection_rows,
+        },
+    },
+    types::EntityTag,
+    value::{InputValue, OutputValue},
+};
+
+const ENTITY_SOURCE: &str = "db::session::tests::null_safe_compare::Pair";
+const ENTITY_NAME: &str = "Pair";
+const ENTITY_TAG: EntityTag = EntityTag::new(248);
+
+#[test]
+fn distinct_from_treats_null_literals_as_comparable() {
+    let session = initialize();
+    seed_rows(&session);
+
+    for (filter, expected) in [
+        ("a IS NOT DISTINCT FROM NULL", vec![3, 5]),
+        ("a IS DISTINCT FROM NULL", vec![1, 2, 4]),
+        ("NULL IS NOT DISTINCT FROM a", vec![3, 5]),
+        ("a IS NOT DISTINCT FROM 1", vec![1, 2]),
+        // Unlike `a <> 1`, null-safe inequality keeps the NULL rows.
+        ("a IS DISTINCT FROM 1", vec![3, 4, 5]),
+        ("NULL IS NOT DISTINCT FROM NULL", vec![1, 2, 3, 4, 5]),
+        ("NULL IS DISTINCT FROM NULL", vec![]),
+    ] {
+        let sql = format!("SELECT id FROM Pair WHERE {filter} ORDER BY id ASC LIMIT 10");
+        assert_eq!(ids(&session, sql.as_str()), expected, "{filter}");
+    }
+}
+
+#[test]
+fn distinct_from_compares_two_nullable_fields() {
+    let session = initialize();
+    seed_rows(&session);
+
+    assert_eq!(
+        ids(
+            &session,
+            "SELECT id FROM Pair WHERE a IS NOT DISTINCT FROM b ORDER BY id ASC LIMIT 10",
+        ),
+        vec![1, 5],
+    );
+    assert_eq!(
+        ids(
+            &session,
+            "SELECT id FROM Pair WHERE a IS DISTINCT FROM b ORDER BY id ASC LIMIT 10",
+        ),
+        vec![2, 3, 4],
+    );
+    assert_eq!(
+        labels(
+            &session,
+            "SELECT id, CASE WHEN a IS NOT DISTINCT FROM b THEN 'same' ELSE 'diff' END \
+             FROM Pair ORDER BY id ASC LIMIT 10",
+        ),
+        ["same", "diff", "diff", "diff", "same"],
+    );
+}
+
+#[test]
+fn simple_case_never_matches_a_null_operand() {
+    let session = initialize();
+    seed_rows(&session);
+
+    // `WHEN NULL` desugars to `a = NULL`, which is never true, so NULL
+    // operands fall through to `ELSE`.
+    assert_eq!(
+        labels(
+            &session,
+            "SELECT id, CASE a WHEN NULL THEN 'null' WHEN 1 THEN 'one' ELSE 'other' END \
+             FROM Pair ORDER BY id ASC LIMIT 10",
+        ),
+        ["one", "one", "other", "other", "other"],
+    );
+
+    // Field-to-field `WHEN` keeps equality semantics: row 5 has two NULLs
+    // and still does not match, unlike `IS NOT DISTINCT FROM`.
+    assert_eq!(
+        labels(
+            &session,
+            "SELECT id, CASE a WHEN b THEN 'same' ELSE 'diff' END \
+             FROM Pair ORDER BY id ASC LIMIT 10",
+        ),
+        ["same", "diff", "diff", "diff", "diff"],
+    );
+}
+
+fn initialize() -> TestSession {
+    SchemaFixture::new()
+        .entity(
+            ENTITY_TAG,
+            ENTITY_SOURCE,
+            ENTITY_NAME,
+            vec![
+                field(1, "id", 0, AcceptedFieldKind::Nat64),
+                nullable_field(2, "a", 1, AcceptedFieldKind::Int64),
+                nullable_field(3, "b", 2, AcceptedFieldKind::Int64),
+            ],
+            Vec::new(),
+        )
+        .initialize()
+}
+
+fn seed_rows(session: &TestSession) {
+    for (id, a, b) in [
+        (1, Some(1), Some(1)),
+        (2, Some(1), Some(2)),
+        (3, None, Some(1)),
+        (4, Some(2), None),
+        (5, None, None),
+    ] {
+        insert_row(
+            session,
+            ENTITY_NAME,
+            vec![
+                ("id", InputValue::Nat64(id)),
+                ("a", a.map_or(InputValue::Null, InputValue::Int64)),
+                ("b", b.map_or(InputValue::Null, InputValue::Int64)),
+            ],
+        );
+    }
+}
+
+fn ids(session: &TestSession, sql: &str) -> Vec<u64> {
+    projection_rows(session, sql)
+        .into_iter()
+        .map(|row| match row.as_slice() {
+            [OutputValue::Nat64(id)] => *id,
+            other => panic!("null-safe compare query should project one id: {other:?}"),
+        })
+        .collect()
+}
+
+fn labels(session: &TestSession, sql: &str) -> Vec<String> {
+    projection_rows(session, sql)
+        .into_iter()
+        .map(|row| match row.as_slice() {
+            [OutputValue::Nat64(_), OutputValue::Text(label)] => label.clone(),
+            other => panic!("CASE query should project an id and a label: {other:?}"),
+        })
+        .collect()
+}
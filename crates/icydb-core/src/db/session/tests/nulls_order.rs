@@ -0,0 +1,177 @@
+//! End-to-end proof for explicit `NULLS FIRST | LAST` placement in SQL ORDER BY.
+
+use crate::{
+    db::{
+        SqlStatementResult,
+        schema::{AcceptedFieldKind, FieldId, PersistedIndexFieldPathSnapshot, SchemaFieldSlot},
+        session::tests::support::{
+            SchemaFixture, TestSession, explain, field, field_index, index_path, insert_row,
+            nullable_field, projection_rows,
+        },
+    },
+    types::EntityTag,
+    value::{InputValue, OutputValue},
+};
+use icydb_diagnostic_code::DiagnosticCode;
+
+const ENTITY_SOURCE: &str = "db::session::tests::nulls_order::Runner";
+const ENTITY_NAME: &str = "Runner";
+const ENTITY_TAG: EntityTag = EntityTag::new(247);
+
+#[test]
+fn explicit_null_placement_pages_through_null_rows_with_cursor() {
+    let session = initialize();
+    seed_rows(&session);
+
+    for (order, expected) in [
+        ("rank ASC NULLS FIRST, id ASC", vec![2, 4, 3, 5, 1]),
+        ("rank ASC NULLS LAST, id ASC", vec![3, 5, 1, 2, 4]),
+        ("rank DESC NULLS FIRST, id ASC", vec![2, 4, 1, 5, 3]),
+        ("rank DESC NULLS LAST, id ASC", vec![1, 5, 3, 2, 4]),
+    ] {
+        let sql = format!("SELECT id FROM Runner ORDER BY {order} LIMIT 2");
+        assert_eq!(ids(&session, sql.as_str()), expected[..2], "{order}");
+
+        // Every page boundary resumes strictly after its anchor, including
+        // the boundary that falls between two NULL ranks.
+        let mut walked = Vec::new();
+        let mut pages = 0;
+        let mut cursor = None::<String>;
+        loop {
+            let (rows, next_cursor) = ids_page(&session, sql.as_str(), cursor.as_deref());
+            walked.extend(rows);
+            pages += 1;
+            match next_cursor {
+                Some(next_cursor) => cursor = Some(next_cursor),
+                None => break,
+            }
+        }
+        assert_eq!(walked, expected, "{order}");
+        assert_eq!(pages, 3, "{order}");
+    }
+
+    // A cursor carries its null placement, so it cannot resume the natural
+    // spelling of the same ORDER BY.
+    let (_, cursor) = ids_page(
+        &session,
+        "SELECT id FROM Runner ORDER BY rank ASC NULLS LAST, id ASC LIMIT 2",
+        None,
+    );
+    let cursor = cursor.expect("first NULLS LAST page should emit a cursor");
+    let error = session
+        .execute_trusted_sql_query_page(
+            "SELECT id FROM Runner ORDER BY rank ASC, id ASC LIMIT 2",
+            Some(cursor.as_str()),
+        )
+        .expect_err("a cursor from another null placement must fail closed");
+    assert_eq!(
+        error.diagnostic_code(),
+        DiagnosticCode::QueryInvalidContinuationCursor,
+    );
+}
+
+#[test]
+fn null_placement_keeps_index_order_only_for_non_nullable_fields() {
+    let session = initialize();
+    seed_rows(&session);
+
+    let non_nullable = "SELECT id FROM Runner ORDER BY score ASC NULLS LAST, id ASC LIMIT 10";
+    assert_eq!(ids(&session, non_nullable), vec![2, 3, 1, 4, 5]);
+    let plan = explain(&session, non_nullable);
+    assert!(plan.contains("OrderByAccessSatisfied"), "{plan}");
+
+    let nullable = "SELECT id FROM Runner ORDER BY rank ASC NULLS LAST, id ASC LIMIT 10";
+    assert_eq!(ids(&session, nullable), vec![3, 5, 1, 2, 4]);
+    let plan = explain(&session, nullable);
+    assert!(plan.contains("OrderByMaterializedSort"), "{plan}");
+
+    // `rank_idx` omits NULL ranks, so even the natural placement cannot be
+    // served by traversing it without dropping those rows.
+    let natural = "SELECT id FROM Runner ORDER BY rank ASC NULLS FIRST, id ASC LIMIT 10";
+    assert_eq!(ids(&session, natural), vec![2, 4, 3, 5, 1]);
+    let plan = explain(&session, natural);
+    assert!(!plan.contains("IndexRange(rank_idx)"), "{plan}");
+}
+
+fn initialize() -> TestSession {
+    SchemaFixture::new()
+        .entity(
+            ENTITY_TAG,
+            ENTITY_SOURCE,
+            ENTITY_NAME,
+            vec![
+                field(1, "id", 0, AcceptedFieldKind::Nat64),
+                nullable_field(2, "rank", 1, AcceptedFieldKind::Int64),
+                field(3, "score", 2, AcceptedFieldKind::Int64),
+            ],
+            vec![
+                field_index(
+                    1,
+                    "rank_idx",
+                    vec![PersistedIndexFieldPathSnapshot::new(
+                        FieldId::new(2),
+                        SchemaFieldSlot::new(1),
+                        vec!["rank".to_string()],
+                        AcceptedFieldKind::Int64,
+                        true,
+                    )],
+                ),
+                field_index(
+                    2,
+                    "score_idx",
+                    vec![index_path(3, 2, "score", AcceptedFieldKind::Int64)],
+                ),
+            ],
+        )
+        .initialize()
+}
+
+fn seed_rows(session: &TestSession) {
+    for (id, rank, score) in [
+        (1, Some(3), 30),
+        (2, None, 10),
+        (3, Some(1), 20),
+        (4, None, 40),
+        (5, Some(2), 50),
+    ] {
+        insert_row(
+            session,
+            ENTITY_NAME,
+            vec![
+                ("id", InputValue::Nat64(id)),
+                ("rank", rank.map_or(InputValue::Null, InputValue::Int64)),
+                ("score", InputValue::Int64(score)),
+            ],
+        );
+    }
+}
+
+fn ids(session: &TestSession, sql: &str) -> Vec<u64> {
+    projection_rows(session, sql)
+        .into_iter()
+        .map(|row| id_cell(row.as_slice()))
+        .collect()
+}
+
+fn ids_page(session: &TestSession, sql: &str, cursor: Option<&str>) -> (Vec<u64>, Option<String>) {
+    let SqlStatementResult::Projection {
+        rows, next_cursor, ..
+    } = session
+        .execute_trusted_sql_query_page(sql, cursor)
+        .expect("NULLS ordering page should execute")
+    else {
+        panic!("NULLS ordering page should return projection rows")
+    };
+
+    (
+        rows.iter().map(|row| id_cell(row.as_slice())).collect(),
+        next_cursor,
+    )
+}
+
+fn id_cell(row: &[OutputValue]) -> u64 {
+    match row {
+        [OutputValue::Nat64(id)] => *id,
+        other => panic!("NULLS ordering query should project one id: {other:?}"),
+    }
+}
@@ -0,0 +1,160 @@
+//! End-to-end proof for `LIMIT k PER` per-partition top-k selection.
+
+use crate::{
+    db::{
+        DynamicStructuralPatch, DynamicWriteCell, QueryError,
+        schema::AcceptedFieldKind,
+        session::tests::support::{
+            SchemaFixture, TestSession, explain, field, field_index, index_path, projection_rows,
+        },
+    },
+    types::EntityTag,
+    value::{InputValue, OutputValue},
+};
+
+const ENTITY_SOURCE: &str = "db::session::tests::partition_limit::EventRow";
+const ENTITY_NAME: &str = "EventRow";
+const ENTITY_TAG: EntityTag = EntityTag::new(233);
+
+#[test]
+fn limit_per_keeps_top_rows_of_each_partition_without_a_matching_index() {
+    let session = initialize();
+    seed_rows(&session);
+
+    let sql =
+        "SELECT player, score FROM EventRow ORDER BY player ASC, score DESC LIMIT 2 PER player";
+    assert_eq!(
+        projection_rows(&session, sql),
+        expected_rows(&[
+            ("alice", 9),
+            ("alice", 7),
+            ("bob", 8),
+            ("carol", 6),
+            ("carol", 5)
+        ]),
+    );
+
+    let explain = explain(&session, sql);
+    assert!(explain.contains("PartitionLimit"), "{explain}");
+    assert!(explain.contains("OrderByMaterializedSort"), "{explain}");
+}
+
+#[test]
+fn limit_per_streams_partitions_over_a_matching_composite_index() {
+    let session = initialize();
+    seed_rows(&session);
+
+    let sql =
+        "SELECT player, score FROM EventRow ORDER BY player ASC, score ASC LIMIT 2 PER player";
+    assert_eq!(
+        projection_rows(&session, sql),
+        expected_rows(&[
+            ("alice", 3),
+            ("alice", 7),
+            ("bob", 8),
+            ("carol", 4),
+            ("carol", 5)
+        ]),
+    );
+
+    let explain = explain(&session, sql);
+    assert!(explain.contains("PartitionLimit"), "{explain}");
+    assert!(explain.contains("OrderByAccessSatisfied"), "{explain}");
+}
+
+#[test]
+fn limit_per_requires_partition_fields_to_lead_order_by() {
+    let session = initialize();
+
+    for sql in [
+        "SELECT player, score FROM EventRow LIMIT 2 PER player",
+        "SELECT player, score FROM EventRow ORDER BY score DESC LIMIT 2 PER player",
+        "SELECT DISTINCT player FROM EventRow ORDER BY player ASC LIMIT 2 PER player",
+        "SELECT player, score FROM EventRow ORDER BY player ASC LIMIT 2 PER player OFFSET 1",
+    ] {
+        let err = session
+            .execute_trusted_sql_query(sql)
+            .expect_err("unsupported LIMIT PER shape should fail closed");
+        assert!(matches!(err, QueryError::Plan(_)), "{sql}: {err:?}");
+    }
+
+    for sql in [
+        "SELECT COUNT(*) FROM EventRow LIMIT 2 PER player",
+        "SELECT player, COUNT(*) FROM EventRow GROUP BY player ORDER BY player LIMIT 2 PER player",
+    ] {
+        session
+            .execute_trusted_sql_query(sql)
+            .expect_err("aggregate LIMIT PER should fail closed");
+    }
+}
+
+fn initialize() -> TestSession {
+    SchemaFixture::new()
+        .entity(
+            ENTITY_TAG,
+            ENTITY_SOURCE,
+            ENTITY_NAME,
+            vec![
+                field(1, "id", 0, AcceptedFieldKind::Nat64),
+                field(2, "player", 1, AcceptedFieldKind::Text { max_len: None }),
+                field(3, "score", 2, AcceptedFieldKind::Nat64),
+            ],
+            vec![field_index(
+                1,
+                "player_score_idx",
+                vec![
+                    index_path(2, 1, "player", AcceptedFieldKind::Text { max_len: None }),
+                    index_path(3, 2, "score", AcceptedFieldKind::Nat64),
+                ],
+            )],
+        )
+        .initialize()
+}
+
+// Rows are inserted out of partition order so hashed windows see interleaved
+// partitions.
+fn seed_rows(session: &TestSession) {
+    let rows = [
+        (1, "bob", 8),
+        (2, "alice", 3),
+        (3, "carol", 6),
+        (4, "alice", 9),
+        (5, "carol", 4),
+        (6, "alice", 7),
+        (7, "carol", 5),
+    ]
+    .into_iter()
+    .map(|(id, player, score)| row(id, player, score))
+    .collect();
+    session
+        .execute_trusted_dynamic_insert_batch(ENTITY_NAME, rows)
+        .expect("partition limit fixture rows should insert");
+}
+
+fn row(id: u64, player: &str, score: u64) -> DynamicStructuralPatch {
+    DynamicStructuralPatch::new(vec![
+        (
+            "id".to_string(),
+            DynamicWriteCell::Value(InputValue::Nat64(id)),
+        ),
+        (
+            "player".to_string(),
+            DynamicWriteCell::Value(InputValue::Text(player.to_string())),
+        ),
+        (
+            "score".to_string(),
+            DynamicWriteCell::Value(InputValue::Nat64(score)),
+        ),
+    ])
+}
+
+fn expected_rows(rows: &[(&str, u64)]) -> Vec<Vec<OutputValue>> {
+    rows.iter()
+        .map(|(player, score)| {
+            vec![
+                OutputValue::Text((*player).to_string()),
+                OutputValue::Nat64(*score),
+            ]
+        })
+        .collect()
+}
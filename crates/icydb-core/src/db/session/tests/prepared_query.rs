@@ -0,0 +1,150 @@
+//! End-to-end proof for prepared SQL queries bound with positional values.
+
+use crate::{
+    db::{
+        SqlStatementResult,
+        session::tests::support::{initialize_identity_row, insert_identity_row},
+    },
+    value::{InputValue, InputValueEnum, OutputValue},
+};
+use icydb_diagnostic_code::{DiagnosticFactTag, ErrorCode};
+
+#[test]
+fn prepared_sql_query_binds_positional_values_per_execution() {
+    let session = initialize_identity_row();
+    for payload in [40, 10, 30, 20] {
+        insert_identity_row(&session, payload);
+    }
+    let prepared = session
+        .prepare_trusted_sql_query(
+            "SELECT payload FROM IdentityRow \
+             WHERE payload >= $1 AND payload < $2 ORDER BY payload ASC",
+        )
+        .expect("dense positional placeholders should prepare");
+    assert_eq!(prepared.parameter_count(), 2);
+
+    // Phase 1: one prepared template executes with different bind values.
+    for ((low, high), expected) in [((20, 40), vec![20, 30]), ((10, 20), vec![10])] {
+        let SqlStatementResult::Projection { rows, .. } = session
+            .execute_prepared_sql_query(
+                &prepared,
+                vec![InputValue::Nat64(low), InputValue::Nat64(high)],
+            )
+            .expect("bound prepared query should execute")
+        else {
+            panic!("bound prepared query should return projection rows")
+        };
+        assert_eq!(
+            rows,
+            expected
+                .into_iter()
+                .map(|payload| vec![OutputValue::Nat64(payload)])
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    // Phase 2: bind arity and enum values fail closed at the offending slot.
+    for (params, index, reason) in [
+        (vec![InputValue::Nat64(10)], 1, "missing `$2` must reject"),
+        (
+            vec![
+                InputValue::Nat64(10),
+                InputValue::Nat64(20),
+                InputValue::Nat64(30),
+            ],
+            2,
+            "an extra bind value must reject",
+        ),
+        (
+            vec![
+                InputValue::Nat64(10),
+                InputValue::Enum(InputValueEnum::loose("Active")),
+            ],
+            1,
+            "enum bind values must use variant text",
+        ),
+    ] {
+        let error = session
+            .execute_prepared_sql_query(&prepared, params)
+            .expect_err(reason);
+        assert_eq!(
+            error.diagnostic().error_code(),
+            ErrorCode::SQL_LOWERING_PARAMETER_BINDING,
+            "{reason}",
+        );
+        assert_eq!(
+            error.diagnostic_facts(),
+            vec![(DiagnosticFactTag::ParameterIndex, index)],
+            "{reason}",
+        );
+    }
+
+    // Phase 3: sparse slots and text-only execution keep rejecting placeholders.
+    let sparse = session
+        .prepare_trusted_sql_query("SELECT payload FROM IdentityRow WHERE payload = $2")
+        .expect_err("a statement that skips `$1` must not prepare");
+    assert_eq!(
+        sparse.diagnostic().error_code(),
+        ErrorCode::SQL_LOWERING_PARAMETER_BINDING,
+    );
+    let unbound = session
+        .execute_trusted_sql_query("SELECT payload FROM IdentityRow WHERE payload = $1")
+        .expect_err("text-only execution must not accept unbound placeholders");
+    assert_eq!(
+        unbound.diagnostic().error_code(),
+        ErrorCode::SQL_LOWERING_PARAMETER_PLACEMENT,
+    );
+}
+
+#[test]
+fn prepared_sql_query_rejects_placeholders_in_list_and_pattern_positions() {
+    let session = initialize_identity_row();
+    for sql in [
+        "SELECT payload FROM IdentityRow WHERE payload IN ($1, $2)",
+        "SELECT payload FROM IdentityRow WHERE payload NOT IN (10, $1)",
+        "SELECT payload FROM IdentityRow WHERE payload LIKE $1",
+        "SELECT payload FROM IdentityRow WHERE payload ~ $1",
+    ] {
+        let error = session
+            .prepare_trusted_sql_query(sql)
+            .expect_err("list and pattern placeholders must not prepare");
+        assert_eq!(
+            error.diagnostic().error_code(),
+            ErrorCode::RUNTIME_UNSUPPORTED,
+            "{sql}",
+        );
+    }
+}
+
+#[test]
+fn prepared_sql_query_reuses_bound_commands_for_repeated_values() {
+    let session = initialize_identity_row();
+    for payload in [10, 20, 30] {
+        insert_identity_row(&session, payload);
+    }
+    let execute = |low: u64| {
+        let (result, attribution) = session
+            .execute_trusted_sql_query_with_params_page_with_attribution(
+                "SELECT payload FROM IdentityRow WHERE payload >= $1 ORDER BY payload ASC",
+                vec![InputValue::Nat64(low)],
+                None,
+            )
+            .expect("bound prepared query should execute");
+        let SqlStatementResult::Projection { rows, .. } = result else {
+            panic!("bound prepared query should return projection rows")
+        };
+
+        (
+            rows.len(),
+            attribution.cache.sql_compiled_command_hits,
+            attribution.cache.sql_compiled_command_misses,
+        )
+    };
+
+    // The bound command is keyed on the template and bind types, and only
+    // serves executions that repeat its canonical values.
+    assert_eq!(execute(20), (2, 0, 1));
+    assert_eq!(execute(20), (2, 1, 0));
+    assert_eq!(execute(10), (3, 0, 1));
+    assert_eq!(execute(10), (3, 1, 0));
+}
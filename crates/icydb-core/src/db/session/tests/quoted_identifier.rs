@@ -0,0 +1,119 @@
+//! End-to-end proof for double-quoted SQL identifiers over reserved and
+//! case-sensitive accepted field names.
+
+use crate::{
+    db::{
+        EntityFieldDescription, SqlColumnSummary, SqlDescribeOutput, SqlStatementResult,
+        schema::AcceptedFieldKind,
+        session::tests::support::{SchemaFixture, TestSession, field, projection_rows},
+    },
+    types::EntityTag,
+    value::OutputValue,
+};
+
+const ENTITY_SOURCE: &str = "db::session::tests::quoted_identifier::Roster";
+const ENTITY_NAME: &str = "Roster";
+const ENTITY_TAG: EntityTag = EntityTag::new(239);
+
+#[test]
+fn quoted_identifiers_reach_reserved_field_names_across_statements() {
+    let session = initialize();
+
+    session
+        .execute_trusted_sql_mutation(
+            "INSERT INTO \"Roster\" (id, \"order\", \"Group\", Score) \
+             VALUES (1, 3, 'red', 10), (2, 1, 'blue', 20), (3, 2, 'red', 30)",
+        )
+        .expect("quoted INSERT columns should resolve");
+    session
+        .execute_trusted_sql_exact_update(
+            "UPDATE Roster SET \"order\" = 4 WHERE \"Group\" = 'blue'",
+            1,
+        )
+        .expect("quoted UPDATE assignment should resolve");
+    session
+        .execute_trusted_sql_mutation("DELETE FROM Roster WHERE \"order\" = 2")
+        .expect("quoted DELETE predicate should resolve");
+
+    assert_eq!(
+        projection_rows(
+            &session,
+            "SELECT id, \"order\" AS \"limit\", \"Group\" FROM Roster \
+             WHERE \"order\" >= 3 ORDER BY \"order\" DESC",
+        ),
+        [(2, 4, "blue"), (1, 3, "red")]
+            .map(|(id, order, group)| vec![
+                OutputValue::Nat64(id),
+                OutputValue::Nat64(order),
+                OutputValue::Text(group.to_string()),
+            ])
+            .to_vec(),
+    );
+}
+
+#[test]
+fn quoted_identifiers_resolve_field_names_exactly() {
+    let session = initialize();
+
+    for sql in [
+        "SELECT \"group\" FROM Roster",
+        "SELECT \"score\" FROM Roster",
+        "SELECT \"ORDER\" FROM Roster",
+    ] {
+        session
+            .execute_trusted_sql_query(sql)
+            .expect_err("quoted names should not fold case onto accepted fields");
+    }
+    assert!(projection_rows(&session, "SELECT \"Score\", Score FROM Roster").is_empty());
+}
+
+#[test]
+fn describe_renders_field_names_in_their_sql_spelling() {
+    let session = initialize();
+
+    let SqlStatementResult::Describe(SqlDescribeOutput::Compact { columns, .. }) = session
+        .execute_trusted_sql_query("DESCRIBE Roster")
+        .expect("DESCRIBE should execute")
+    else {
+        panic!("DESCRIBE should return compact columns");
+    };
+    assert_eq!(
+        columns
+            .iter()
+            .map(SqlColumnSummary::name)
+            .collect::<Vec<_>>(),
+        ["id", "\"order\"", "\"Group\"", "Score"],
+    );
+
+    let SqlStatementResult::Describe(SqlDescribeOutput::Verbose { description }) = session
+        .execute_trusted_sql_query("DESCRIBE Roster VERBOSE")
+        .expect("verbose DESCRIBE should execute")
+    else {
+        panic!("verbose DESCRIBE should return the schema description");
+    };
+    assert_eq!(
+        description
+            .fields()
+            .iter()
+            .map(EntityFieldDescription::name)
+            .collect::<Vec<_>>(),
+        ["id", "\"order\"", "\"Group\"", "Score"],
+    );
+}
+
+fn initialize() -> TestSession {
+    SchemaFixture::new()
+        .entity(
+            ENTITY_TAG,
+            ENTITY_SOURCE,
+            ENTITY_NAME,
+            vec![
+                field(1, "id", 0, AcceptedFieldKind::Nat64),
+                field(2, "order", 1, AcceptedFieldKind::Nat64),
+                field(3, "Group", 2, AcceptedFieldKind::Text { max_len: None }),
+                field(4, "Score", 3, AcceptedFieldKind::Nat64),
+            ],
+            Vec::new(),
+        )
+        .initialize()
+}
@@ -0,0 +1,116 @@
+//! End-to-end coverage for SQL `relation.field` projection lookups.
+
+use crate::{
+    db::{
+        SqlStatementResult,
+        schema::AcceptedFieldKind,
+        session::tests::support::{STORE_PATH, SchemaFixture, TestSession, field, insert_row},
+    },
+    types::EntityTag,
+    value::{InputValue, OutputValue},
+};
+use icydb_diagnostic_code::ErrorCode;
+
+const AUTHOR_SOURCE: &str = "db::session::tests::relation_path::Author";
+const AUTHOR_NAME: &str = "Author";
+const AUTHOR_TAG: EntityTag = EntityTag::new(231);
+const POST_SOURCE: &str = "db::session::tests::relation_path::Post";
+const POST_NAME: &str = "Post";
+const POST_TAG: EntityTag = EntityTag::new(232);
+
+#[test]
+fn relation_paths_project_target_fields_through_one_batched_lookup() {
+    let session = initialize();
+    for (id, name) in [(1, "Ada"), (2, "Grace")] {
+        insert_row(
+            &session,
+            AUTHOR_NAME,
+            vec![
+                ("id", InputValue::Nat64(id)),
+                ("name", InputValue::Text(name.to_string())),
+            ],
+        );
+    }
+    for (id, author_id, title) in [(10, 1, "first"), (11, 2, "second"), (12, 1, "third")] {
+        insert_row(
+            &session,
+            POST_NAME,
+            vec![
+                ("id", InputValue::Nat64(id)),
+                ("author_id", InputValue::Nat64(author_id)),
+                ("title", InputValue::Text(title.to_string())),
+            ],
+        );
+    }
+
+    // Phase 1: the target field replaces the key column under the path label.
+    let SqlStatementResult::Projection { columns, rows, .. } = session
+        .execute_trusted_sql_query(
+            "SELECT id, author_id.name, title FROM Post ORDER BY id LIMIT 10",
+        )
+        .expect("relation-path projection should execute")
+    else {
+        panic!("relation-path projection should return rows");
+    };
+    assert_eq!(columns, ["id", "author_id.name", "title"]);
+    assert_eq!(
+        rows.iter().map(|row| row[1].clone()).collect::<Vec<_>>(),
+        ["Ada", "Grace", "Ada"]
+            .map(|name| OutputValue::Text(name.to_string()))
+            .to_vec(),
+    );
+
+    // Phase 2: relation paths outside scalar projection fail closed.
+    for sql in [
+        "SELECT id FROM Post WHERE author_id.name = 'Ada' ORDER BY id LIMIT 10",
+        "SELECT id FROM Post ORDER BY author_id.name LIMIT 10",
+        "SELECT COUNT(*), author_id.name FROM Post",
+        "SELECT id, author_id.name.first FROM Post ORDER BY id LIMIT 10",
+    ] {
+        let error = session
+            .execute_trusted_sql_query(sql)
+            .expect_err("unsupported relation path should reject");
+        assert_eq!(
+            error.diagnostic().error_code(),
+            ErrorCode::SQL_LOWERING_RELATION_PATH_SHAPE,
+            "{sql}",
+        );
+    }
+}
+
+fn initialize() -> TestSession {
+    SchemaFixture::new()
+        .entity(
+            AUTHOR_TAG,
+            AUTHOR_SOURCE,
+            AUTHOR_NAME,
+            vec![
+                field(1, "id", 0, AcceptedFieldKind::Nat64),
+                field(2, "name", 1, AcceptedFieldKind::Text { max_len: None }),
+            ],
+            Vec::new(),
+        )
+        .entity(
+            POST_TAG,
+            POST_SOURCE,
+            POST_NAME,
+            vec![
+                field(1, "id", 0, AcceptedFieldKind::Nat64),
+                field(
+                    2,
+                    "author_id",
+                    1,
+                    AcceptedFieldKind::Relation {
+                        target_path: AUTHOR_SOURCE.to_string(),
+                        target_entity_name: AUTHOR_NAME.to_string(),
+                        target_entity_tag: AUTHOR_TAG,
+                        target_store_path: STORE_PATH.to_string(),
+                        key_kind: Box::new(AcceptedFieldKind::Nat64),
+                    },
+                ),
+                field(3, "title", 2, AcceptedFieldKind::Text { max_len: None }),
+            ],
+            Vec::new(),
+        )
+        .initialize()
+}
@@ -0,0 +1,210 @@
+//! End-to-end proof for indexes keyed by arbitrary deterministic scalar expressions.
+
+use crate::{
+    db::{
+        DynamicStructuralPatch, DynamicWriteCell, SqlStatementResult,
+        schema::AcceptedFieldKind,
+        session::tests::support::{
+            SchemaFixture, TestSession, explain, field, nullable_field, projection_rows,
+        },
+    },
+    types::EntityTag,
+    value::{InputValue, OutputValue},
+};
+
+const ENTITY_SOURCE: &str = "db::session::tests::scalar_expression_index::Line";
+const ENTITY_NAME: &str = "Line";
+const ENTITY_TAG: EntityTag = EntityTag::new(245);
+const REVENUE_INDEX_SQL: &str = "CREATE INDEX revenue_idx ON Line ((price * quantity)) \
+                                 EXPECT SCHEMA VERSION 1 SET SCHEMA VERSION 2";
+
+#[test]
+fn arithmetic_expression_index_serves_where_and_order_routes() {
+    let session = initialize();
+    seed_rows(&session);
+    session
+        .execute_admin_sql_ddl(REVENUE_INDEX_SQL)
+        .expect("arithmetic expression index DDL should execute");
+
+    let eq = "SELECT id FROM Line WHERE price * quantity = 20 ORDER BY id ASC";
+    assert_eq!(ids(&session, eq), vec![1, 5]);
+    let eq = explain(&session, eq);
+    assert!(eq.contains("IndexPrefix"), "{eq}");
+
+    let range = "SELECT id FROM Line WHERE price * quantity >= 10 AND price * quantity < 30 \
+                 ORDER BY id ASC";
+    assert_eq!(ids(&session, range), vec![1, 5]);
+    let range = explain(&session, range);
+    assert!(range.contains("IndexRange"), "{range}");
+
+    let flipped = "SELECT id FROM Line WHERE 10 < price * quantity ORDER BY id ASC";
+    assert_eq!(ids(&session, flipped), vec![1, 4, 5]);
+    let flipped = explain(&session, flipped);
+    assert!(flipped.contains("IndexRange"), "{flipped}");
+
+    let ordered = "SELECT id FROM Line ORDER BY price * quantity ASC, id ASC LIMIT 10";
+    assert_eq!(ids(&session, ordered), vec![3, 2, 1, 5, 4]);
+    let ordered = explain(&session, ordered);
+    assert!(ordered.contains("revenue_idx"), "{ordered}");
+
+    session
+        .execute_trusted_sql_exact_update("UPDATE Line SET quantity = 1 WHERE id = 4", 1)
+        .expect("source update should maintain the expression key");
+    assert_eq!(
+        ids(
+            &session,
+            "SELECT id FROM Line WHERE price * quantity = 6 ORDER BY id ASC",
+        ),
+        vec![4],
+    );
+    assert_eq!(
+        ids(
+            &session,
+            "SELECT id FROM Line WHERE price * quantity = 30 ORDER BY id ASC",
+        ),
+        Vec::<u64>::new(),
+    );
+
+    let SqlStatementResult::ShowIndexes(indexes) = session
+        .execute_trusted_sql_query("SHOW INDEXES FROM Line")
+        .expect("SHOW INDEXES should execute")
+    else {
+        panic!("SHOW INDEXES should return index lines");
+    };
+    assert!(
+        indexes
+            .iter()
+            .any(|line| line.starts_with("INDEX revenue_idx (price * quantity)")),
+        "SHOW INDEXES should report the canonical key expression: {indexes:?}",
+    );
+}
+
+#[test]
+fn text_and_case_expression_indexes_match_normalized_filters() {
+    let session = initialize();
+    seed_rows(&session);
+    session
+        .execute_admin_sql_ddl(
+            "CREATE INDEX initial_idx ON Line ((COALESCE(LEFT(name, 1), '?'))) \
+             EXPECT SCHEMA VERSION 1 SET SCHEMA VERSION 2",
+        )
+        .expect("text expression index DDL should execute");
+    session
+        .execute_admin_sql_ddl(
+            "CREATE INDEX tier_idx ON Line ((CASE WHEN price > 3 THEN 'high' ELSE 'low' END)) \
+             EXPECT SCHEMA VERSION 2 SET SCHEMA VERSION 3",
+        )
+        .expect("CASE expression index DDL should execute");
+
+    let initial = "SELECT id FROM Line WHERE COALESCE(LEFT(name, 1), '?') = 'b' ORDER BY id ASC";
+    assert_eq!(ids(&session, initial), vec![2, 4]);
+    let initial = explain(&session, initial);
+    assert!(initial.contains("initial_idx"), "{initial}");
+
+    let tier = "SELECT id FROM Line \
+                WHERE CASE WHEN price > 3 THEN 'high' ELSE 'low' END = 'high' ORDER BY id ASC";
+    assert_eq!(ids(&session, tier), vec![1, 4]);
+    let tier = explain(&session, tier);
+    assert!(tier.contains("tier_idx"), "{tier}");
+}
+
+#[test]
+fn scalar_expression_index_ddl_rejects_unsupported_shapes() {
+    let session = initialize();
+    seed_rows(&session);
+
+    session
+        .execute_admin_sql_ddl(
+            "CREATE UNIQUE INDEX revenue_idx ON Line ((price * quantity)) \
+             EXPECT SCHEMA VERSION 1 SET SCHEMA VERSION 2",
+        )
+        .expect_err("unique expression index should reject");
+    session
+        .execute_admin_sql_ddl(
+            "CREATE INDEX stamp_idx ON Line ((NOW())) \
+             EXPECT SCHEMA VERSION 1 SET SCHEMA VERSION 2",
+        )
+        .expect_err("nondeterministic expression index should reject");
+    session
+        .execute_admin_sql_ddl(
+            "CREATE INDEX missing_idx ON Line ((price * missing)) \
+             EXPECT SCHEMA VERSION 1 SET SCHEMA VERSION 2",
+        )
+        .expect_err("unknown expression source should reject");
+
+    session
+        .execute_admin_sql_ddl(REVENUE_INDEX_SQL)
+        .expect("arithmetic expression index DDL should execute");
+    session
+        .execute_admin_sql_ddl(
+            "ALTER TABLE Line RENAME COLUMN price TO unit_price \
+             EXPECT SCHEMA VERSION 2 SET SCHEMA VERSION 3",
+        )
+        .expect_err("expression index source should not rename");
+}
+
+fn initialize() -> TestSession {
+    SchemaFixture::new()
+        .entity(
+            ENTITY_TAG,
+            ENTITY_SOURCE,
+            ENTITY_NAME,
+            vec![
+                field(1, "id", 0, AcceptedFieldKind::Nat64),
+                field(2, "price", 1, AcceptedFieldKind::Int64),
+                field(3, "quantity", 2, AcceptedFieldKind::Int64),
+                nullable_field(4, "name", 3, AcceptedFieldKind::Text { max_len: None }),
+            ],
+            Vec::new(),
+        )
+        .initialize()
+}
+
+fn seed_rows(session: &TestSession) {
+    let rows = [
+        (1, 4, 5, Some("alpha")),
+        (2, 3, 3, Some("beta")),
+        (3, 0, 7, None),
+        (4, 6, 5, Some("bravo")),
+        (5, 2, 10, Some("gamma")),
+    ]
+    .into_iter()
+    .map(|(id, price, quantity, name)| row(id, price, quantity, name))
+    .collect();
+    session
+        .execute_trusted_dynamic_insert_batch(ENTITY_NAME, rows)
+        .expect("scalar expression index fixture rows should insert");
+}
+
+fn row(id: u64, price: i64, quantity: i64, name: Option<&str>) -> DynamicStructuralPatch {
+    DynamicStructuralPatch::new(vec![
+        (
+            "id".to_string(),
+            DynamicWriteCell::Value(InputValue::Nat64(id)),
+        ),
+        (
+            "price".to_string(),
+            DynamicWriteCell::Value(InputValue::Int64(price)),
+        ),
+        (
+            "quantity".to_string(),
+            DynamicWriteCell::Value(InputValue::Int64(quantity)),
+        ),
+        (
+            "name".to_string(),
+            DynamicWriteCell::Value(
+                name.map_or(InputValue::Null, |name| InputValue::Text(name.to_string())),
+            ),
+        ),
+    ])
+}
+
+fn ids(session: &TestSession, sql: &str) -> Vec<u64> {
+    projection_rows(session, sql)
+        .into_iter()
+        .map(|row| match row.as_slice() {
+            [OutputValue::Nat64(id)] => *id,
+            other => panic!("scalar expression index query should project one id: {other:?}"),
+        })
+        .collect()
+}
@@ -0,0 +1,198 @@
+//! End-to-end proof for uncorrelated `IN (SELECT ...)` and `EXISTS` filters.
+
+use crate::{
+    db::{
+        schema::AcceptedFieldKind,
+        session::tests::support::{
+            SchemaFixture, TestSession, field, field_index, index_path, insert_row, nullable_field,
+            projection_rows,
+        },
+    },
+    types::EntityTag,
+    value::{InputValue, OutputValue},
+};
+use icydb_diagnostic_code::ErrorCode;
+
+const MEMBER_SOURCE: &str = "db::session::tests::semi_join::Member";
+const MEMBER_NAME: &str = "Member";
+const MEMBER_TAG: EntityTag = EntityTag::new(221);
+
+#[test]
+fn semi_join_sources_resolve_through_bounded_index_reads() {
+    let session = initialize_members();
+    let in_red = "SELECT id FROM Member \
+                  WHERE id IN (SELECT id FROM Member WHERE team = 'red') \
+                  ORDER BY id LIMIT 20";
+
+    // Phase 1: keyed and EXISTS semi-joins fold onto literal outer filters.
+    assert_eq!(ids(&session, in_red), nat64s([1, 2]));
+    assert_eq!(
+        ids(
+            &session,
+            "SELECT id FROM Member \
+             WHERE id NOT IN (SELECT id FROM Member WHERE team = 'red') \
+             ORDER BY id LIMIT 20",
+        ),
+        nat64s([3, 4, 5]),
+    );
+    assert!(
+        ids(
+            &session,
+            "SELECT id FROM Member \
+             WHERE EXISTS (SELECT id FROM Member WHERE team = 'gold') \
+             ORDER BY id LIMIT 20",
+        )
+        .is_empty()
+    );
+    assert_eq!(
+        ids(
+            &session,
+            "SELECT id FROM Member \
+             WHERE NOT EXISTS (SELECT id FROM Member WHERE team = 'gold') \
+             ORDER BY id LIMIT 20",
+        ),
+        nat64s([1, 2, 3, 4, 5]),
+    );
+
+    // Phase 2: a compile-cache hit on the same SQL text re-reads the source.
+    insert_member(&session, 6, "red", None);
+    assert_eq!(ids(&session, in_red), nat64s([1, 2, 6]));
+
+    // Phase 3: unindexed sources, multi-column sources, and write selectors
+    // fail closed before any outer row is read.
+    for (sql, code) in [
+        (
+            "SELECT id FROM Member WHERE id IN (SELECT id FROM Member) \
+             ORDER BY id LIMIT 20",
+            ErrorCode::QUERY_READ_UNBOUNDED_FULL_SCAN_REJECTED,
+        ),
+        (
+            "SELECT id FROM Member \
+             WHERE id IN (SELECT id, team FROM Member WHERE team = 'red') \
+             ORDER BY id LIMIT 20",
+            ErrorCode::SQL_LOWERING_SEMI_JOIN_SHAPE,
+        ),
+    ] {
+        let error = session
+            .execute_trusted_sql_query(sql)
+            .expect_err("unsupported semi-join should reject");
+        assert_eq!(error.diagnostic().error_code(), code, "{sql}");
+    }
+    let error = session
+        .execute_trusted_sql_mutation(
+            "DELETE FROM Member WHERE id IN (SELECT id FROM Member WHERE team = 'red')",
+        )
+        .expect_err("write selectors do not resolve semi-joins");
+    assert_eq!(
+        error.diagnostic().error_code(),
+        ErrorCode::SQL_LOWERING_SEMI_JOIN_SHAPE,
+    );
+}
+
+#[test]
+fn semi_join_null_source_keys_keep_membership_unknown() {
+    let session = initialize_members();
+
+    // Red managers are `{NULL, 1}`: only member 1 is known to be a manager,
+    // and no member is known not to be one.
+    for (filter, expected) in [
+        (
+            "id IN (SELECT manager FROM Member WHERE team = 'red')",
+            vec![1],
+        ),
+        (
+            "NOT (id IN (SELECT manager FROM Member WHERE team = 'red'))",
+            vec![],
+        ),
+        (
+            "id NOT IN (SELECT manager FROM Member WHERE team = 'red')",
+            vec![],
+        ),
+        (
+            "NOT (id NOT IN (SELECT manager FROM Member WHERE team = 'red'))",
+            vec![1],
+        ),
+        // Green managers are `{NULL}`: membership is unknown for every probe.
+        (
+            "id IN (SELECT manager FROM Member WHERE team = 'green')",
+            vec![],
+        ),
+        (
+            "id NOT IN (SELECT manager FROM Member WHERE team = 'green')",
+            vec![],
+        ),
+        (
+            "NOT (id NOT IN (SELECT manager FROM Member WHERE team = 'green'))",
+            vec![],
+        ),
+    ] {
+        assert_eq!(
+            ids(
+                &session,
+                format!("SELECT id FROM Member WHERE {filter} ORDER BY id LIMIT 20").as_str(),
+            ),
+            nat64s(expected),
+            "{filter}",
+        );
+    }
+}
+
+// Seed five members across three teams; `manager` is `NULL` for one red and
+// the only green member.
+fn initialize_members() -> TestSession {
+    let text = || AcceptedFieldKind::Text { max_len: None };
+    let session = SchemaFixture::new()
+        .entity(
+            MEMBER_TAG,
+            MEMBER_SOURCE,
+            MEMBER_NAME,
+            vec![
+                field(1, "id", 0, AcceptedFieldKind::Nat64),
+                field(2, "team", 1, text()),
+                nullable_field(3, "manager", 2, AcceptedFieldKind::Nat64),
+            ],
+            vec![field_index(
+                1,
+                "team_idx",
+                vec![index_path(2, 1, "team", text())],
+            )],
+        )
+        .initialize();
+    for (id, team, manager) in [
+        (1, "red", None),
+        (2, "red", Some(1)),
+        (3, "blue", Some(1)),
+        (4, "blue", Some(2)),
+        (5, "green", None),
+    ] {
+        insert_member(&session, id, team, manager);
+    }
+
+    session
+}
+
+fn insert_member(session: &TestSession, id: u64, team: &str, manager: Option<u64>) {
+    insert_row(
+        session,
+        MEMBER_NAME,
+        vec![
+            ("id", InputValue::Nat64(id)),
+            ("team", InputValue::Text(team.to_string())),
+            (
+                "manager",
+                manager.map_or(InputValue::Null, InputValue::Nat64),
+            ),
+        ],
+    );
+}
+
+fn ids(session: &TestSession, sql: &str) -> Vec<OutputValue> {
+    projection_rows(session, sql)
+        .into_iter()
+        .map(|row| row[0].clone())
+        .collect()
+}
+
+fn nat64s(ids: impl IntoIterator<Item = u64>) -> Vec<OutputValue> {
+    ids.into_iter().map(OutputValue::Nat64).collect()
+}
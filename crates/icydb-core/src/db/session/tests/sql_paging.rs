@@ -0,0 +1,95 @@
+//! End-to-end proof for SQL scalar pages resumed from continuation cursors.
+
+use crate::{
+    db::{
+        SqlStatementResult,
+        session::tests::support::{TestSession, initialize_identity_row, insert_identity_row},
+    },
+    value::OutputValue,
+};
+use icydb_diagnostic_code::DiagnosticCode;
+
+#[test]
+fn sql_scalar_pages_resume_from_authenticated_cursor() {
+    let session = initialize_identity_row();
+    for payload in [50, 10, 40, 20, 30] {
+        insert_identity_row(&session, payload);
+    }
+    let sql = "SELECT payload FROM IdentityRow ORDER BY payload ASC LIMIT 2";
+
+    // Phase 1: walk every page and keep `LIMIT` as the page size.
+    let mut pages = Vec::new();
+    let mut cursor = None::<String>;
+    loop {
+        let (rows, next_cursor) = projection_page(&session, sql, cursor.as_deref());
+        pages.push(rows);
+        match next_cursor {
+            Some(next_cursor) => cursor = Some(next_cursor),
+            None => break,
+        }
+    }
+    assert_eq!(
+        pages,
+        vec![
+            vec![vec![OutputValue::Nat64(10)], vec![OutputValue::Nat64(20)]],
+            vec![vec![OutputValue::Nat64(30)], vec![OutputValue::Nat64(40)]],
+            vec![vec![OutputValue::Nat64(50)]],
+        ],
+    );
+
+    // Phase 2: a cursor only resumes the statement that emitted it.
+    let (_, first_cursor) = projection_page(&session, sql, None);
+    let first_cursor = first_cursor.expect("first page should emit a continuation cursor");
+    for (other_sql, reason) in [
+        (
+            "SELECT payload FROM IdentityRow ORDER BY payload DESC LIMIT 2",
+            "a cursor from another ordering must fail closed",
+        ),
+        (
+            "SELECT payload FROM IdentityRow ORDER BY payload ASC",
+            "SELECT without LIMIT cannot resume from a cursor",
+        ),
+        (
+            "SELECT payload FROM IdentityRow ORDER BY payload ASC LIMIT 2 OFFSET 1",
+            "OFFSET SELECT cannot resume from a cursor",
+        ),
+    ] {
+        let error = session
+            .execute_trusted_sql_query_page(other_sql, Some(first_cursor.as_str()))
+            .expect_err(reason);
+        assert_eq!(
+            error.diagnostic_code(),
+            DiagnosticCode::QueryInvalidContinuationCursor,
+            "{reason}",
+        );
+    }
+
+    // Phase 3: statements that cannot page keep their one-shot shape.
+    let (rows, next_cursor) = projection_page(
+        &session,
+        "SELECT payload FROM IdentityRow ORDER BY payload ASC LIMIT 2 OFFSET 3",
+        None,
+    );
+    assert_eq!(
+        rows,
+        vec![vec![OutputValue::Nat64(40)], vec![OutputValue::Nat64(50)]],
+    );
+    assert_eq!(next_cursor, None);
+}
+
+fn projection_page(
+    session: &TestSession,
+    sql: &str,
+    cursor: Option<&str>,
+) -> (Vec<Vec<OutputValue>>, Option<String>) {
+    let SqlStatementResult::Projection {
+        rows, next_cursor, ..
+    } = session
+        .execute_trusted_sql_query_page(sql, cursor)
+        .expect("focused paged SQL should execute")
+    else {
+        panic!("focused paged SQL should return projection rows")
+    };
+
+    (rows, next_cursor)
+}
@@ -0,0 +1,233 @@
+//! End-to-end proof for statistical, percentile, and collection aggregates in
+//! scalar and grouped SQL.
+
+use crate::{
+    db::{
+        SqlStatementResult,
+        schema::AcceptedFieldKind,
+        session::tests::support::{
+            SchemaFixture, TestSession, field, field_index, index_path, insert_row, nullable_field,
+            projection_rows,
+        },
+    },
+    types::{Decimal, EntityTag},
+    value::{InputValue, OutputValue},
+};
+
+const ENTITY_SOURCE: &str = "db::session::tests::statistical_aggregate::Score";
+const ENTITY_NAME: &str = "Score";
+const ENTITY_TAG: EntityTag = EntityTag::new(249);
+
+#[test]
+fn scalar_aggregates_skip_null_inputs_except_array_agg() {
+    let session = initialize();
+    seed_rows(&session);
+
+    assert_eq!(
+        projection_rows(
+            &session,
+            "SELECT VAR_SAMP(points), STDDEV_SAMP(points), VARIANCE(points), \
+             STDDEV(points) FROM Score",
+        ),
+        [vec![decimal(4), decimal(2), decimal(4), decimal(2)]],
+        "non-null points 1, 3, 5 have sample variance 4",
+    );
+    assert_eq!(
+        projection_rows(
+            &session,
+            "SELECT VAR_POP(points), STDDEV_POP(points) FROM Score WHERE team = 'red'",
+        ),
+        [vec![decimal(1), decimal(1)]],
+        "non-null red points 1, 3 have population variance 1",
+    );
+    assert_eq!(
+        projection_rows(
+            &session,
+            "SELECT PERCENTILE_DISC(0.5) WITHIN GROUP (ORDER BY points), \
+             PERCENTILE_DISC(1) WITHIN GROUP (ORDER BY points), \
+             PERCENTILE_DISC(0.5) WITHIN GROUP (ORDER BY points DESC), MEDIAN(points) \
+             FROM Score",
+        ),
+        [vec![
+            OutputValue::Int64(3),
+            OutputValue::Int64(5),
+            OutputValue::Int64(3),
+            OutputValue::Int64(3),
+        ]],
+    );
+    assert_eq!(
+        projection_rows(
+            &session,
+            "SELECT STRING_AGG(name, ',' ORDER BY id DESC), ARRAY_AGG(points ORDER BY id) \
+             FROM Score",
+        ),
+        [vec![
+            text("eve,dee,cid,ann"),
+            points(&[Some(1), Some(3), None, Some(5), None, None]),
+        ]],
+    );
+}
+
+#[test]
+fn scalar_aggregates_over_empty_input_return_null() {
+    let session = initialize();
+    seed_rows(&session);
+
+    assert_eq!(
+        projection_rows(
+            &session,
+            "SELECT COUNT(points), VAR_POP(points), STDDEV_SAMP(points), \
+             PERCENTILE_DISC(0.5) WITHIN GROUP (ORDER BY points), \
+             STRING_AGG(name, ','), ARRAY_AGG(points) FROM Score WHERE id > 100",
+        ),
+        [vec![
+            OutputValue::Nat64(0),
+            OutputValue::Null,
+            OutputValue::Null,
+            OutputValue::Null,
+            OutputValue::Null,
+            OutputValue::Null,
+        ]],
+    );
+}
+
+#[test]
+fn grouped_aggregates_finalize_per_group_with_null_inputs() {
+    let session = initialize();
+    seed_rows(&session);
+
+    assert_eq!(
+        grouped(
+            &session,
+            "SELECT team, VAR_POP(points), STDDEV_POP(points), VAR_SAMP(points) \
+             FROM Score GROUP BY team ORDER BY team ASC LIMIT 10",
+        ),
+        [
+            // One non-null input: population forms are zero, sample forms
+            // need two inputs.
+            vec![text("blue"), decimal(0), decimal(0), OutputValue::Null],
+            vec![
+                text("green"),
+                OutputValue::Null,
+                OutputValue::Null,
+                OutputValue::Null,
+            ],
+            vec![text("red"), decimal(1), decimal(1), decimal(2)],
+        ],
+    );
+    assert_eq!(
+        grouped(
+            &session,
+            "SELECT team, MEDIAN(points), STRING_AGG(name, '|' ORDER BY id), \
+             ARRAY_AGG(points ORDER BY id) FROM Score GROUP BY team \
+             ORDER BY team ASC LIMIT 10",
+        ),
+        [
+            vec![
+                text("blue"),
+                OutputValue::Int64(5),
+                text("dee|eve"),
+                points(&[Some(5), None]),
+            ],
+            vec![
+                text("green"),
+                OutputValue::Null,
+                OutputValue::Null,
+                points(&[None]),
+            ],
+            vec![
+                text("red"),
+                OutputValue::Int64(1),
+                text("ann|cid"),
+                points(&[Some(1), Some(3), None]),
+            ],
+        ],
+    );
+}
+
+fn initialize() -> TestSession {
+    SchemaFixture::new()
+        .entity(
+            ENTITY_TAG,
+            ENTITY_SOURCE,
+            ENTITY_NAME,
+            vec![
+                field(1, "id", 0, AcceptedFieldKind::Nat64),
+                field(2, "team", 1, AcceptedFieldKind::Text { max_len: None }),
+                nullable_field(3, "name", 2, AcceptedFieldKind::Text { max_len: None }),
+                nullable_field(4, "points", 3, AcceptedFieldKind::Int64),
+            ],
+            vec![field_index(
+                1,
+                "team_idx",
+                vec![index_path(
+                    2,
+                    1,
+                    "team",
+                    AcceptedFieldKind::Text { max_len: None },
+                )],
+            )],
+        )
+        .initialize()
+}
+
+fn seed_rows(session: &TestSession) {
+    for (id, team, name, points) in [
+        (1, "red", Some("ann"), Some(1)),
+        (2, "red", None, Some(3)),
+        (3, "red", Some("cid"), None),
+        (4, "blue", Some("dee"), Some(5)),
+        (5, "blue", Some("eve"), None),
+        (6, "green", None, None),
+    ] {
+        insert_row(
+            session,
+            ENTITY_NAME,
+            vec![
+                ("id", InputValue::Nat64(id)),
+                ("team", InputValue::Text(team.to_string())),
+                (
+                    "name",
+                    name.map_or(InputValue::Null, |name| InputValue::Text(name.to_string())),
+                ),
+                ("points", points.map_or(InputValue::Null, InputValue::Int64)),
+            ],
+        );
+    }
+}
+
+const fn decimal(value: i64) -> OutputValue {
+    OutputValue::Decimal(Decimal::new(value, 0))
+}
+
+fn text(value: &str) -> OutputValue {
+    OutputValue::Text(value.to_string())
+}
+
+fn points(values: &[Option<i64>]) -> OutputValue {
+    OutputValue::List(
+        values
+            .iter()
+            .map(|value| value.map_or(OutputValue::Null, OutputValue::Int64))
+            .collect(),
+    )
+}
+
+fn grouped(session: &TestSession, sql: &str) -> Vec<Vec<OutputValue>> {
+    let SqlStatementResult::Grouped { rows, .. } = session
+        .execute_trusted_sql_query(sql)
+        .expect("grouped aggregate query should execute")
+    else {
+        panic!("grouped aggregate query should return grouped rows");
+    };
+
+    rows.iter()
+        .map(|row| {
+            row.group_key()
+                .iter()
+                .chain(row.aggregate_values())
+                .cloned()
+                .collect()
+        })
+        .collect()
+}
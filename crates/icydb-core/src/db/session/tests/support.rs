@@ -0,0 +1,335 @@
+//! Shared single-store fixture for the native session correctness harnesses.
+//!
+//! Every harness runs against one heap store registered under [`STORE_PATH`];
+//! each test describes its entities through [`SchemaFixture`] and publishes
+//! them as the initial accepted schema before executing SQL.
+
+use crate::{
+    db::{
+        DbSession, DynamicMutation, DynamicStructuralPatch, DynamicWriteCell, QueryError,
+        SqlStatementResult,
+        data::DataStore,
+        index::IndexStore,
+        registry::{StoreAllocationIdentities, StoreRegistry, StoreRuntimeStorageCapabilities},
+        schema::{
+            AcceptedFieldKind, AcceptedSchemaRevision, CandidateSchemaRevision, FieldId,
+            FieldInsertGeneration, FieldStorageDecode, PersistedFieldSnapshot,
+            PersistedIndexFieldPathSnapshot, PersistedIndexKeySnapshot, PersistedIndexSnapshot,
+            PersistedSchemaSnapshot, SchemaFieldSlot, SchemaFieldWritePolicy, SchemaIndexId,
+            SchemaInsertDefault, SchemaRowLayout, SchemaStore, SchemaVersion,
+            accepted_schema_candidate_with_field_bindings_for_tests,
+        },
+    },
+    traits::{CanisterKind, Path},
+    types::EntityTag,
+    value::{InputValue, OutputValue},
+};
+use icydb_diagnostic_code::{DiagnosticCode, DiagnosticDetail, SqlWriteBoundaryCode};
+use icydb_schema::FieldSourceKey;
+use std::{cell::RefCell, collections::BTreeMap};
+
+pub(super) const STORE_PATH: &str = "db::session::tests::support::Store";
+const IDENTITY_ROW_SOURCE: &str = "db::session::tests::support::IdentityRow";
+const IDENTITY_ROW_NAME: &str = "IdentityRow";
+const IDENTITY_ROW_TAG: EntityTag = EntityTag::new(246);
+
+pub(super) struct SessionTestCanister;
+
+impl Path for SessionTestCanister {
+    const PATH: &'static str = "db::session::tests::support::Canister";
+}
+
+impl CanisterKind for SessionTestCanister {
+    const COMMIT_MEMORY_ID: u8 = 140;
+    const COMMIT_STABLE_KEY: &'static str = "icydb.test.session_support.commit.v1";
+    const STARTUP_MEMORY_ID: u8 = 141;
+    const STARTUP_STABLE_KEY: &'static str = "icydb.test.session_support.startup.v1";
+    const INTEGRITY_PROGRESS_MEMORY_ID: u8 = 142;
+    const INTEGRITY_PROGRESS_STABLE_KEY: &'static str = "icydb.test.session_support.integrity.v1";
+}
+
+pub(super) type TestSession = DbSession<SessionTestCanister>;
+
+thread_local! {
+    static DATA_STORE: RefCell<DataStore> = const { RefCell::new(DataStore::init_heap()) };
+    static INDEX_STORE: RefCell<IndexStore> = const { RefCell::new(IndexStore::init_heap()) };
+    static SCHEMA_STORE: RefCell<SchemaStore> = const { RefCell::new(SchemaStore::init_heap()) };
+    static STORE_REGISTRY: StoreRegistry = {
+        let mut registry = StoreRegistry::new();
+        registry.register_store(
+            STORE_PATH,
+            &DATA_STORE,
+            &INDEX_STORE,
+            &SCHEMA_STORE,
+            StoreAllocationIdentities::absent(),
+            StoreRuntimeStorageCapabilities::heap(),
+        ).expect("session test store should register");
+        registry
+    };
+}
+
+///
+/// SchemaFixture
+///
+/// Builder for the initial accepted schema of one session harness. Field
+/// bindings are derived from each entity source and field name.
+///
+
+#[derive(Default)]
+pub(super) struct SchemaFixture {
+    snapshots: BTreeMap<EntityTag, PersistedSchemaSnapshot>,
+    bindings: BTreeMap<(EntityTag, FieldSourceKey), FieldId>,
+}
+
+impl SchemaFixture {
+    pub(super) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add one entity whose primary key is field 1.
+    pub(super) fn entity(
+        mut self,
+        tag: EntityTag,
+        source: &str,
+        name: &str,
+        fields: Vec<PersistedFieldSnapshot>,
+        indexes: Vec<PersistedIndexSnapshot>,
+    ) -> Self {
+        for field in &fields {
+            let key = FieldSourceKey::try_new(format!("{source}::{}", field.name()))
+                .expect("session fixture field source should admit");
+            self.bindings.insert((tag, key), field.id());
+        }
+        let snapshot = PersistedSchemaSnapshot::new_with_indexes(
+            SchemaVersion::initial(),
+            source.to_string(),
+            name.to_string(),
+            FieldId::new(1),
+            SchemaRowLayout::initial(
+                fields
+                    .iter()
+                    .map(|field| (field.id(), field.slot()))
+                    .collect(),
+            ),
+            fields,
+            indexes,
+        );
+        self.snapshots.insert(tag, snapshot);
+
+        self
+    }
+
+    pub(super) fn candidate(self) -> CandidateSchemaRevision {
+        accepted_schema_candidate_with_field_bindings_for_tests(
+            STORE_PATH,
+            AcceptedSchemaRevision::INITIAL,
+            self.snapshots,
+            self.bindings,
+        )
+    }
+
+    /// Reset the shared store and publish this schema as its first revision.
+    pub(super) fn initialize(self) -> TestSession {
+        initialize_with(&self.candidate())
+    }
+}
+
+/// Publish `IdentityRow`: a database-generated `id` key plus one `payload`
+/// column under the non-unique `by_payload` index.
+pub(super) fn initialize_identity_row() -> TestSession {
+    let id = PersistedFieldSnapshot::new_initial_with_write_policy(
+        FieldId::new(1),
+        "id".to_string(),
+        SchemaFieldSlot::new(0),
+        AcceptedFieldKind::Nat64,
+        Vec::new(),
+        false,
+        SchemaInsertDefault::None,
+        SchemaFieldWritePolicy::from_model_policies(Some(FieldInsertGeneration::Identity), None),
+        FieldStorageDecode::ByKind,
+        AcceptedFieldKind::Nat64.leaf_codec_for_storage(FieldStorageDecode::ByKind),
+    );
+    SchemaFixture::new()
+        .entity(
+            IDENTITY_ROW_TAG,
+            IDENTITY_ROW_SOURCE,
+            IDENTITY_ROW_NAME,
+            vec![id, field(2, "payload", 1, AcceptedFieldKind::Nat64)],
+            vec![field_index(
+                1,
+                "by_payload",
+                vec![index_path(2, 1, "payload", AcceptedFieldKind::Nat64)],
+            )],
+        )
+        .initialize()
+}
+
+/// Insert one `IdentityRow` and return its generated key.
+pub(super) fn insert_identity_row(session: &TestSession, payload: u64) -> u64 {
+    let output = session
+        .execute_trusted_dynamic_mutation(&DynamicMutation::Insert {
+            entity: IDENTITY_ROW_NAME.to_string(),
+            patch: row_patch(vec![("payload", InputValue::Nat64(payload))]),
+        })
+        .expect("identity fixture insert should commit");
+    match output.rows.as_slice() {
+        [row] => match row.as_slice() {
+            [OutputValue::Nat64(id), OutputValue::Nat64(actual)] if *actual == payload => *id,
+            _ => panic!("identity fixture insert should return its key and payload"),
+        },
+        _ => panic!("identity fixture insert should return one row"),
+    }
+}
+
+pub(super) fn initialize_with(candidate: &CandidateSchemaRevision) -> TestSession {
+    DATA_STORE.with(|store| *store.borrow_mut() = DataStore::init_heap());
+    INDEX_STORE.with(|store| *store.borrow_mut() = IndexStore::init_heap());
+    SCHEMA_STORE.with(|store| *store.borrow_mut() = SchemaStore::init_heap());
+    let session = DbSession::new(
+        &STORE_REGISTRY,
+        &crate::db::RequestExecutionRoot::__new_runtime_root(),
+    );
+    session
+        .db
+        .drive_startup_recovery_page()
+        .expect("session test database should initialize");
+    let store = session
+        .db
+        .store_handle(STORE_PATH)
+        .expect("session test store should resolve");
+    crate::db::commit::publish_accepted_schema_candidate(
+        STORE_PATH,
+        store,
+        AcceptedSchemaRevision::NONE,
+        candidate,
+    )
+    .expect("session test schema should publish");
+
+    session
+}
+
+pub(super) fn field(
+    id: u32,
+    name: &str,
+    slot: u16,
+    kind: AcceptedFieldKind,
+) -> PersistedFieldSnapshot {
+    field_with_nullability(id, name, slot, kind, false)
+}
+
+pub(super) fn nullable_field(
+    id: u32,
+    name: &str,
+    slot: u16,
+    kind: AcceptedFieldKind,
+) -> PersistedFieldSnapshot {
+    field_with_nullability(id, name, slot, kind, true)
+}
+
+fn field_with_nullability(
+    id: u32,
+    name: &str,
+    slot: u16,
+    kind: AcceptedFieldKind,
+    nullable: bool,
+) -> PersistedFieldSnapshot {
+    let storage_decode = FieldStorageDecode::ByKind;
+    let leaf_codec = kind.leaf_codec_for_storage(storage_decode);
+    PersistedFieldSnapshot::new_initial(
+        FieldId::new(id),
+        name.to_string(),
+        SchemaFieldSlot::new(slot),
+        kind,
+        Vec::new(),
+        nullable,
+        SchemaInsertDefault::None,
+        storage_decode,
+        leaf_codec,
+    )
+}
+
+pub(super) fn insert_row(session: &TestSession, entity: &str, cells: Vec<(&str, InputValue)>) {
+    session
+        .execute_trusted_dynamic_insert_batch(entity, vec![row_patch(cells)])
+        .expect("session fixture row should insert");
+}
+
+fn row_patch(cells: Vec<(&str, InputValue)>) -> DynamicStructuralPatch {
+    DynamicStructuralPatch::new(
+        cells
+            .into_iter()
+            .map(|(field, value)| (field.to_string(), DynamicWriteCell::Value(value)))
+            .collect(),
+    )
+}
+
+pub(super) fn index_path(
+    field_id: u32,
+    slot: u16,
+    field_name: &str,
+    kind: AcceptedFieldKind,
+) -> PersistedIndexFieldPathSnapshot {
+    PersistedIndexFieldPathSnapshot::new(
+        FieldId::new(field_id),
+        SchemaFieldSlot::new(slot),
+        vec![field_name.to_string()],
+        kind,
+        false,
+    )
+}
+
+/// Build one non-unique index in the shared store.
+pub(super) fn index(
+    ordinal: u16,
+    name: &str,
+    key: PersistedIndexKeySnapshot,
+) -> PersistedIndexSnapshot {
+    PersistedIndexSnapshot::new(
+        SchemaIndexId::new(u32::from(ordinal)).expect("test index identity should be nonzero"),
+        ordinal,
+        name.to_string(),
+        STORE_PATH.to_string(),
+        false,
+        key,
+        None,
+    )
+}
+
+pub(super) fn field_index(
+    ordinal: u16,
+    name: &str,
+    paths: Vec<PersistedIndexFieldPathSnapshot>,
+) -> PersistedIndexSnapshot {
+    index(ordinal, name, PersistedIndexKeySnapshot::FieldPath(paths))
+}
+
+pub(super) fn projection_rows(session: &TestSession, sql: &str) -> Vec<Vec<OutputValue>> {
+    let SqlStatementResult::Projection { rows, .. } = session
+        .execute_trusted_sql_query(sql)
+        .expect("session fixture query should execute")
+    else {
+        panic!("session fixture query should return projection rows");
+    };
+
+    rows
+}
+
+pub(super) fn explain(session: &TestSession, sql: &str) -> String {
+    let SqlStatementResult::Explain(explain) = session
+        .execute_trusted_sql_query(format!("EXPLAIN EXECUTION {sql}").as_str())
+        .expect("session fixture query should explain")
+    else {
+        panic!("EXPLAIN EXECUTION should return an explain payload");
+    };
+
+    explain
+}
+
+pub(super) fn assert_sql_write_boundary(error: &QueryError, boundary: SqlWriteBoundaryCode) {
+    let diagnostic = error.diagnostic();
+    assert_eq!(diagnostic.code(), DiagnosticCode::QuerySqlWriteBoundary);
+    assert!(matches!(
+        diagnostic.detail(),
+        Some(DiagnosticDetail::SqlWriteBoundary { boundary: actual }) if *actual == boundary
+    ));
+}
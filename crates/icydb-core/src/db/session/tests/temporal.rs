@@ -0,0 +1,67 @@
+//! End-to-end proof for temporal bucketing, extraction, and interval arithmetic.
+
+use crate::{
+    db::session::tests::support::{initialize_identity_row, insert_identity_row, projection_rows},
+    types::{Duration, Timestamp},
+    value::OutputValue,
+};
+use icydb_diagnostic_code::{DiagnosticDetail, QueryProjectionCode};
+
+#[test]
+fn sql_temporal_functions_bucket_extract_and_shift_timestamps() {
+    let session = initialize_identity_row();
+    let first = insert_identity_row(&session, 300);
+    // 2024-03-15T10:30:45Z, a Friday.
+    let at = "CAST(1710498645000 AS TIMESTAMP)";
+    let timestamp = |text: &str| {
+        OutputValue::Timestamp(Timestamp::parse_rfc3339(text).expect("fixture timestamp"))
+    };
+
+    assert_eq!(
+        projection_rows(
+            &session,
+            format!(
+                "SELECT DATE_TRUNC('day', {at}), DATE_TRUNC('week', {at}), \
+                 EXTRACT(YEAR FROM {at}), EXTRACT(dow FROM {at}), \
+                 {at} + INTERVAL '2 hours', {at} - INTERVAL '1d', \
+                 AGE({at}, CAST(1710496800000 AS TIMESTAMP)) \
+                 FROM IdentityRow WHERE id = {first}"
+            )
+            .as_str(),
+        ),
+        vec![vec![
+            timestamp("2024-03-15T00:00:00Z"),
+            timestamp("2024-03-11T00:00:00Z"),
+            OutputValue::Int64(2024),
+            OutputValue::Int64(5),
+            timestamp("2024-03-15T12:30:45Z"),
+            timestamp("2024-03-14T10:30:45Z"),
+            OutputValue::Duration(Duration::from_secs(1_845)),
+        ]],
+    );
+    assert_eq!(
+        projection_rows(
+            &session,
+            format!(
+                "SELECT payload FROM IdentityRow \
+                 WHERE NOW() - INTERVAL '1h' > {at} AND EXTRACT(MONTH FROM {at}) = 3 \
+                 ORDER BY EXTRACT(HOUR FROM {at}) ASC"
+            )
+            .as_str(),
+        ),
+        vec![vec![OutputValue::Nat64(300)]],
+    );
+
+    let error = session
+        .execute_trusted_sql_query(
+            format!("SELECT EXTRACT(YEAR FROM payload) FROM IdentityRow WHERE id = {first}")
+                .as_str(),
+        )
+        .expect_err("EXTRACT should reject non-temporal runtime inputs");
+    assert!(matches!(
+        error.diagnostic().detail(),
+        Some(DiagnosticDetail::QueryProjection {
+            reason: QueryProjectionCode::TemporalInputRequired,
+        })
+    ));
+}
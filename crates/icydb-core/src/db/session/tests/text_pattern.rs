@@ -0,0 +1,157 @@
+//! End-to-end proof for general LIKE and bounded regular-expression matching.
+
+use crate::{
+    db::{
+        DynamicStructuralPatch, DynamicWriteCell,
+        schema::AcceptedFieldKind,
+        session::tests::support::{
+            SchemaFixture, TestSession, explain, field, field_index, index_path, projection_rows,
+        },
+    },
+    types::EntityTag,
+    value::{InputValue, OutputValue},
+};
+
+const ENTITY_SOURCE: &str = "db::session::tests::text_pattern::Contact";
+const ENTITY_NAME: &str = "Contact";
+const ENTITY_TAG: EntityTag = EntityTag::new(234);
+
+#[test]
+fn like_matches_general_patterns_with_escape_and_casefold() {
+    let session = initialize();
+    seed_rows(&session);
+
+    let cases: [(&str, &[&str]); 6] = [
+        ("name LIKE '%ert'", &["albert"]),
+        ("name LIKE 'al_ce'", &["alice"]),
+        ("name NOT LIKE '%l%'", &["bob", "100% pure", "a_b"]),
+        ("name LIKE '100!%%' ESCAPE '!'", &["100% pure"]),
+        ("name LIKE 'a!_b' ESCAPE '!'", &["a_b"]),
+        ("name ILIKE 'AL%D'", &["Alfred"]),
+    ];
+    for (predicate, expected) in cases {
+        let sql = format!("SELECT name FROM Contact WHERE {predicate} ORDER BY id ASC");
+        assert_eq!(names(&session, &sql), expected, "{sql}");
+    }
+}
+
+#[test]
+fn regex_operators_match_bounded_patterns() {
+    let session = initialize();
+    seed_rows(&session);
+
+    let cases: [(&str, &[&str]); 4] = [
+        ("name ~ '^b'", &["bob"]),
+        ("name ~ 'l(i|b)'", &["alice", "albert"]),
+        ("name !~ '[a-z]'", &[]),
+        ("REGEXP_MATCHES(name, '^[0-9]+% ')", &["100% pure"]),
+    ];
+    for (predicate, expected) in cases {
+        let sql = format!("SELECT name FROM Contact WHERE {predicate} ORDER BY id ASC");
+        assert_eq!(names(&session, &sql), expected, "{sql}");
+    }
+
+    assert_eq!(
+        projection_rows(
+            &session,
+            "SELECT REGEXP_MATCHES(name, 'b$') FROM Contact WHERE id <= 2 ORDER BY id ASC",
+        ),
+        vec![
+            vec![OutputValue::Bool(false)],
+            vec![OutputValue::Bool(true)]
+        ],
+    );
+}
+
+#[test]
+fn pattern_literal_prefix_keeps_index_prefix_access() {
+    let session = initialize();
+    seed_rows(&session);
+
+    let sql = "SELECT name FROM Contact WHERE name LIKE 'al%e' ORDER BY id ASC";
+    assert_eq!(names(&session, sql), ["alice"]);
+
+    let explain = explain(&session, sql);
+    assert!(explain.contains("selected_access=index_range"), "{explain}");
+    assert!(explain.contains("selected_index=name_idx"), "{explain}");
+}
+
+#[test]
+fn malformed_and_oversized_patterns_fail_closed() {
+    let session = initialize();
+    let oversized = format!(
+        "SELECT name FROM Contact WHERE name LIKE '{}'",
+        "_".repeat(600)
+    );
+
+    for sql in [
+        "SELECT name FROM Contact WHERE name ~ '(ab'",
+        "SELECT name FROM Contact WHERE name ~ 'a{1000}'",
+        "SELECT name FROM Contact WHERE name LIKE 'ab!' ESCAPE '!'",
+        "SELECT name FROM Contact WHERE name LIKE 'ab' ESCAPE '!!'",
+        "SELECT name FROM Contact WHERE REGEXP_MATCHES(name, name)",
+        oversized.as_str(),
+    ] {
+        session
+            .execute_trusted_sql_query(sql)
+            .expect_err("unsupported text pattern should fail closed");
+    }
+}
+
+fn initialize() -> TestSession {
+    SchemaFixture::new()
+        .entity(
+            ENTITY_TAG,
+            ENTITY_SOURCE,
+            ENTITY_NAME,
+            vec![
+                field(1, "id", 0, AcceptedFieldKind::Nat64),
+                field(2, "name", 1, AcceptedFieldKind::Text { max_len: None }),
+            ],
+            vec![field_index(
+                1,
+                "name_idx",
+                vec![index_path(
+                    2,
+                    1,
+                    "name",
+                    AcceptedFieldKind::Text { max_len: None },
+                )],
+            )],
+        )
+        .initialize()
+}
+
+fn seed_rows(session: &TestSession) {
+    let rows = ["alice", "bob", "albert", "100% pure", "a_b", "Alfred"]
+        .into_iter()
+        .zip(1..)
+        .map(|(name, id)| row(id, name))
+        .collect();
+    session
+        .execute_trusted_dynamic_insert_batch(ENTITY_NAME, rows)
+        .expect("text pattern fixture rows should insert");
+}
+
+fn row(id: u64, name: &str) -> DynamicStructuralPatch {
+    DynamicStructuralPatch::new(vec![
+        (
+            "id".to_string(),
+            DynamicWriteCell::Value(InputValue::Nat64(id)),
+        ),
+        (
+            "name".to_string(),
+            DynamicWriteCell::Value(InputValue::Text(name.to_string())),
+        ),
+    ])
+}
+
+fn names(session: &TestSession, sql: &str) -> Vec<String> {
+    projection_rows(session, sql)
+        .into_iter()
+        .map(|row| match row.as_slice() {
+            [OutputValue::Text(name)] => name.clone(),
+            other => panic!("text pattern query should project one name: {other:?}"),
+        })
+        .collect()
+}
@@ -0,0 +1,202 @@
+//! End-to-end proof for text token indexes and the `MATCH` predicate.
+
+use crate::{
+    db::{
+        DynamicStructuralPatch, DynamicWriteCell,
+        schema::{
+            AcceptedFieldKind, PersistedIndexExpressionOp, PersistedIndexExpressionSnapshot,
+            PersistedIndexKeyItemSnapshot, PersistedIndexKeySnapshot,
+        },
+        session::tests::support::{
+            SchemaFixture, TestSession, explain, field, index, index_path, projection_rows,
+        },
+    },
+    types::EntityTag,
+    value::{InputValue, OutputValue},
+};
+
+const ENTITY_SOURCE: &str = "db::session::tests::text_search::Note";
+const ENTITY_NAME: &str = "Note";
+const ENTITY_TAG: EntityTag = EntityTag::new(235);
+
+#[test]
+fn match_applies_and_or_and_prefix_terms() {
+    let session = initialize();
+    seed_rows(&session);
+
+    let cases: [(&str, &[u64]); 7] = [
+        ("quick", &[1, 2]),
+        ("QUICK brown", &[1]),
+        ("fox OR bread", &[1, 4]),
+        ("app*", &[2, 3, 5]),
+        ("appl* rules OR pie", &[2, 3]),
+        ("brown-fox", &[1]),
+        ("missing", &[]),
+    ];
+    for (query, expected) in cases {
+        let sql = format!("SELECT id FROM Note WHERE MATCH(body, '{query}') ORDER BY id ASC");
+        assert_eq!(ids(&session, &sql), expected, "{sql}");
+    }
+
+    assert_eq!(
+        ids(
+            &session,
+            "SELECT id FROM Note WHERE MATCH(body, 'app*') AND id > 2 ORDER BY id ASC LIMIT 1",
+        ),
+        [3],
+    );
+    assert_eq!(
+        ids(
+            &session,
+            "SELECT id FROM Note WHERE NOT MATCH(body, 'brown') ORDER BY id ASC",
+        ),
+        [2, 3, 5],
+    );
+}
+
+#[test]
+fn match_routes_to_the_text_token_index() {
+    let session = initialize();
+    seed_rows(&session);
+
+    let exact = explain(
+        &session,
+        "SELECT id FROM Note WHERE MATCH(body, 'quick') ORDER BY id ASC",
+    );
+    assert!(exact.contains("selected_access=index_prefix"), "{exact}");
+    assert!(exact.contains("selected_index=body_text"), "{exact}");
+
+    let prefix = explain(
+        &session,
+        "SELECT id FROM Note WHERE MATCH(body, 'app*') ORDER BY id ASC",
+    );
+    assert!(prefix.contains("selected_access=index_range"), "{prefix}");
+
+    let composite = explain(
+        &session,
+        "SELECT id FROM Note WHERE MATCH(body, 'quick brown OR bread') ORDER BY id ASC",
+    );
+    assert!(composite.contains("selected_access=union"), "{composite}");
+    assert!(composite.contains("Intersection"), "{composite}");
+}
+
+#[test]
+fn text_index_postings_follow_updates_and_deletes() {
+    let session = initialize();
+    seed_rows(&session);
+
+    session
+        .execute_trusted_sql_exact_update(
+            "UPDATE Note SET body = 'slow green turtle' WHERE id = 1",
+            1,
+        )
+        .expect("text search update should apply");
+    session
+        .execute_trusted_sql_mutation("DELETE FROM Note WHERE id = 4")
+        .expect("text search delete should apply");
+
+    for (query, expected) in [
+        ("quick", &[2][..]),
+        ("brown", &[][..]),
+        ("turtle", &[1][..]),
+        ("bread", &[][..]),
+    ] {
+        let sql = format!("SELECT id FROM Note WHERE MATCH(body, '{query}') ORDER BY id ASC");
+        assert_eq!(ids(&session, &sql), expected, "{sql}");
+    }
+}
+
+#[test]
+fn malformed_match_queries_fail_closed() {
+    let session = initialize();
+    let oversized = format!(
+        "SELECT id FROM Note WHERE MATCH(body, '{}')",
+        vec!["term"; 40].join(" ")
+    );
+
+    for sql in [
+        "SELECT id FROM Note WHERE MATCH(body, '')",
+        "SELECT id FROM Note WHERE MATCH(body, 'OR quick')",
+        "SELECT id FROM Note WHERE MATCH(body, 'quick OR')",
+        "SELECT id FROM Note WHERE MATCH(body, '*')",
+        "SELECT id FROM Note WHERE MATCH(body, body)",
+        oversized.as_str(),
+    ] {
+        let error = session
+            .execute_trusted_sql_query(sql)
+            .expect_err("malformed MATCH query should fail closed");
+        assert_eq!(
+            error.diagnostic().error_code(),
+            icydb_diagnostic_code::ErrorCode::SQL_FEATURE_TEXT_MATCH_QUERY_SHAPE,
+            "{sql}",
+        );
+    }
+}
+
+fn initialize() -> TestSession {
+    let text = || AcceptedFieldKind::Text { max_len: None };
+    SchemaFixture::new()
+        .entity(
+            ENTITY_TAG,
+            ENTITY_SOURCE,
+            ENTITY_NAME,
+            vec![
+                field(1, "id", 0, AcceptedFieldKind::Nat64),
+                field(2, "body", 1, text()),
+            ],
+            vec![index(
+                1,
+                "body_text",
+                PersistedIndexKeySnapshot::Items(vec![PersistedIndexKeyItemSnapshot::Expression(
+                    Box::new(PersistedIndexExpressionSnapshot::new(
+                        PersistedIndexExpressionOp::Tokens,
+                        index_path(2, 1, "body", text()),
+                        text(),
+                        text(),
+                        "expr:v1:TOKENS(body)".to_string(),
+                    )),
+                )]),
+            )],
+        )
+        .initialize()
+}
+
+fn seed_rows(session: &TestSession) {
+    let rows = [
+        "The quick brown fox",
+        "Quick apple pie",
+        "Apply the rules",
+        "brown bread",
+        "apple, apply, applause",
+    ]
+    .into_iter()
+    .zip(1..)
+    .map(|(body, id)| row(id, body))
+    .collect();
+    session
+        .execute_trusted_dynamic_insert_batch(ENTITY_NAME, rows)
+        .expect("text search fixture rows should insert");
+}
+
+fn row(id: u64, body: &str) -> DynamicStructuralPatch {
+    DynamicStructuralPatch::new(vec![
+        (
+            "id".to_string(),
+            DynamicWriteCell::Value(InputValue::Nat64(id)),
+        ),
+        (
+            "body".to_string(),
+            DynamicWriteCell::Value(InputValue::Text(body.to_string())),
+        ),
+    ])
+}
+
+fn ids(session: &TestSession, sql: &str) -> Vec<u64> {
+    projection_rows(session, sql)
+        .into_iter()
+        .map(|row| match row.as_slice() {
+            [OutputValue::Nat64(id)] => *id,
+            other => panic!("text search query should project one id: {other:?}"),
+        })
+        .collect()
+}
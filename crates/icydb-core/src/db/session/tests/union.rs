@@ -0,0 +1,201 @@
+//! End-to-end proof for `UNION [ALL]` over one entity through ordered branch merging.
+
+use crate::{
+    db::{
+        DynamicStructuralPatch, DynamicWriteCell, SqlStatementResult,
+        schema::AcceptedFieldKind,
+        session::tests::support::{SchemaFixture, TestSession, field, field_index, index_path},
+    },
+    types::EntityTag,
+    value::{InputValue, OutputValue},
+};
+
+const ENTITY_SOURCE: &str = "db::session::tests::union::Ticket";
+const ENTITY_NAME: &str = "Ticket";
+const ENTITY_TAG: EntityTag = EntityTag::new(238);
+
+#[test]
+fn union_all_merges_disjoint_index_reads_under_the_combined_order() {
+    let session = initialize();
+    seed_rows(&session);
+
+    let sql = "SELECT id, owner, priority FROM Ticket WHERE owner = 'ada' \
+               UNION ALL SELECT id, owner, priority FROM Ticket WHERE priority >= 7 \
+               ORDER BY priority DESC LIMIT 4";
+    let (columns, rows) = projection(&session, sql);
+
+    assert_eq!(columns, ["id", "owner", "priority"]);
+    assert_eq!(
+        rows,
+        expected_rows(&[(2, "bob", 9), (4, "cyd", 9), (3, "ada", 7), (3, "ada", 7)]),
+        "UNION ALL keeps the row both branches matched",
+    );
+}
+
+#[test]
+fn union_distinct_drops_repeated_rows_before_the_window() {
+    let session = initialize();
+    seed_rows(&session);
+
+    let sql = "SELECT id, owner, priority FROM Ticket WHERE owner = 'ada' \
+               UNION SELECT id, owner, priority FROM Ticket WHERE priority >= 7 \
+               ORDER BY priority DESC";
+    assert_eq!(
+        projection(&session, sql).1,
+        expected_rows(&[
+            (2, "bob", 9),
+            (4, "cyd", 9),
+            (3, "ada", 7),
+            (6, "ada", 5),
+            (1, "ada", 3),
+        ]),
+    );
+    assert_eq!(
+        projection(&session, format!("{sql} LIMIT 2 OFFSET 1").as_str()).1,
+        expected_rows(&[(4, "cyd", 9), (3, "ada", 7)]),
+    );
+
+    let sql = "SELECT owner FROM Ticket WHERE priority > 4 \
+               UNION SELECT owner FROM Ticket WHERE priority < 4 ORDER BY owner";
+    assert_eq!(
+        projection(&session, sql).1,
+        ["ada", "bob", "cyd"]
+            .map(|owner| vec![OutputValue::Text(owner.to_string())])
+            .to_vec(),
+    );
+}
+
+#[test]
+fn union_orders_by_first_branch_labels_and_defaults_to_primary_key_order() {
+    let session = initialize();
+    seed_rows(&session);
+
+    let sql = "SELECT owner AS who, priority FROM Ticket WHERE owner = 'cyd' \
+               UNION ALL SELECT owner, priority FROM Ticket WHERE owner = 'bob' \
+               ORDER BY who ASC";
+    let (columns, rows) = projection(&session, sql);
+    assert_eq!(columns, ["who", "priority"]);
+    assert_eq!(
+        rows,
+        [("bob", 9), ("bob", 1), ("cyd", 9)]
+            .map(|(owner, priority)| vec![
+                OutputValue::Text(owner.to_string()),
+                OutputValue::Nat64(priority),
+            ])
+            .to_vec(),
+    );
+
+    let sql = "SELECT id FROM Ticket WHERE owner = 'cyd' \
+               UNION ALL SELECT id FROM Ticket WHERE owner = 'bob'";
+    assert_eq!(
+        projection(&session, sql).1,
+        [2, 4, 5].map(|id| vec![OutputValue::Nat64(id)]).to_vec(),
+    );
+}
+
+#[test]
+fn union_rejects_unsupported_branch_shapes() {
+    let session = initialize();
+    seed_rows(&session);
+
+    for sql in [
+        "SELECT id, owner FROM Ticket UNION ALL SELECT id FROM Ticket",
+        "SELECT id FROM Ticket UNION ALL SELECT id FROM Ticket ORDER BY priority",
+        "SELECT DISTINCT owner FROM Ticket UNION ALL SELECT owner FROM Ticket",
+        "SELECT owner FROM Ticket GROUP BY owner UNION ALL SELECT owner FROM Ticket",
+        "SELECT COUNT(*) FROM Ticket UNION ALL SELECT COUNT(*) FROM Ticket",
+        "SELECT id FROM Ticket UNION ALL SELECT id FROM Other",
+    ] {
+        session
+            .execute_trusted_sql_query(sql)
+            .expect_err("unsupported UNION shape should fail closed");
+    }
+}
+
+fn initialize() -> TestSession {
+    SchemaFixture::new()
+        .entity(
+            ENTITY_TAG,
+            ENTITY_SOURCE,
+            ENTITY_NAME,
+            vec![
+                field(1, "id", 0, AcceptedFieldKind::Nat64),
+                field(2, "owner", 1, AcceptedFieldKind::Text { max_len: None }),
+                field(3, "priority", 2, AcceptedFieldKind::Nat64),
+            ],
+            vec![
+                field_index(
+                    1,
+                    "owner_idx",
+                    vec![index_path(
+                        2,
+                        1,
+                        "owner",
+                        AcceptedFieldKind::Text { max_len: None },
+                    )],
+                ),
+                field_index(
+                    2,
+                    "priority_idx",
+                    vec![index_path(3, 2, "priority", AcceptedFieldKind::Nat64)],
+                ),
+            ],
+        )
+        .initialize()
+}
+
+fn seed_rows(session: &TestSession) {
+    let rows = [
+        (1, "ada", 3),
+        (2, "bob", 9),
+        (3, "ada", 7),
+        (4, "cyd", 9),
+        (5, "bob", 1),
+        (6, "ada", 5),
+    ]
+    .into_iter()
+    .map(|(id, owner, priority)| row(id, owner, priority))
+    .collect();
+    session
+        .execute_trusted_dynamic_insert_batch(ENTITY_NAME, rows)
+        .expect("union fixture rows should insert");
+}
+
+fn row(id: u64, owner: &str, priority: u64) -> DynamicStructuralPatch {
+    DynamicStructuralPatch::new(vec![
+        (
+            "id".to_string(),
+            DynamicWriteCell::Value(InputValue::Nat64(id)),
+        ),
+        (
+            "owner".to_string(),
+            DynamicWriteCell::Value(InputValue::Text(owner.to_string())),
+        ),
+        (
+            "priority".to_string(),
+            DynamicWriteCell::Value(InputValue::Nat64(priority)),
+        ),
+    ])
+}
+
+fn expected_rows(rows: &[(u64, &str, u64)]) -> Vec<Vec<OutputValue>> {
+    rows.iter()
+        .map(|(id, owner, priority)| {
+            vec![
+                OutputValue::Nat64(*id),
+                OutputValue::Text((*owner).to_string()),
+                OutputValue::Nat64(*priority),
+            ]
+        })
+        .collect()
+}
+
+fn projection(session: &TestSession, sql: &str) -> (Vec<String>, Vec<Vec<OutputValue>>) {
+    let SqlStatementResult::Projection { columns, rows, .. } = session
+        .execute_trusted_sql_query(sql)
+        .expect("union query should execute")
+    else {
+        panic!("union query should return rows");
+    };
+    (columns, rows)
+}
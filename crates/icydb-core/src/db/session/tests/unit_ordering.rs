@@ -107,6 +107,11 @@ fn rejected_sql_fields_keep_exact_role() {
             QueryFieldRole::Projection,
             "missing",
         ),
+        (
+            "SELECT \"missing\" FROM Singleton ORDER BY id LIMIT 1",
+            QueryFieldRole::Projection,
+            "missing",
+        ),
         (
             "SELECT id FROM Singleton WHERE missing = 'x' ORDER BY id LIMIT 1",
             QueryFieldRole::Predicate,
@@ -156,8 +161,8 @@ fn rejected_sql_fields_keep_exact_role() {
     }
 
     let quoted_error = session
-        .execute_trusted_sql_query("SELECT \"missing\" FROM Singleton ORDER BY id LIMIT 1")
-        .expect_err("quoted identifiers are outside the maintained resolver context");
+        .execute_trusted_sql_query("SELECT `missing` FROM Singleton ORDER BY id LIMIT 1")
+        .expect_err("backtick identifiers are outside the maintained resolver context");
     assert_eq!(quoted_error.query_field_context(), None);
 }
 
@@ -0,0 +1,61 @@
+//! End-to-end proof for row-local expressions on the right of `UPDATE ... SET`.
+
+use crate::{
+    db::session::tests::support::{
+        assert_sql_write_boundary, initialize_identity_row, insert_identity_row, projection_rows,
+    },
+    value::OutputValue,
+};
+use icydb_diagnostic_code::SqlWriteBoundaryCode;
+
+#[test]
+fn sql_update_expression_assignments_read_each_selected_pre_image() {
+    let session = initialize_identity_row();
+    let first = insert_identity_row(&session, 10);
+    let second = insert_identity_row(&session, 20);
+
+    let _result = session
+        .execute_trusted_sql_exact_update(
+            format!("UPDATE IdentityRow SET payload = payload * 2 + id WHERE id = {first}")
+                .as_str(),
+            1,
+        )
+        .expect("row-local expression assignment should commit");
+    assert_eq!(
+        projection_rows(
+            &session,
+            "SELECT id, payload FROM IdentityRow ORDER BY id ASC"
+        ),
+        vec![
+            vec![OutputValue::Nat64(first), OutputValue::Nat64(20 + first)],
+            vec![OutputValue::Nat64(second), OutputValue::Nat64(20)],
+        ],
+    );
+
+    for sql in [
+        format!("UPDATE IdentityRow SET payload = payload - 100 WHERE id = {second}"),
+        format!("UPDATE IdentityRow SET payload = payload / 3 WHERE id = {second}"),
+    ] {
+        let error = session
+            .execute_trusted_sql_exact_update(sql.as_str(), 1)
+            .expect_err("incompatible computed values must reject before mutation");
+        assert_sql_write_boundary(
+            &error,
+            SqlWriteBoundaryCode::UpdateComputedValueIncompatible,
+        );
+    }
+
+    let _error = session
+        .execute_trusted_sql_exact_update(
+            format!("UPDATE IdentityRow SET payload = missing + 1 WHERE id = {second}").as_str(),
+            1,
+        )
+        .expect_err("unknown expression fields must reject");
+    assert_eq!(
+        projection_rows(
+            &session,
+            format!("SELECT payload FROM IdentityRow WHERE id = {second}").as_str()
+        ),
+        vec![vec![OutputValue::Nat64(20)]],
+    );
+}
@@ -0,0 +1,177 @@
+//! End-to-end proof for `INSERT ... ON CONFLICT` upserts.
+
+use crate::{
+    db::{
+        SqlStatementResult,
+        session::tests::support::{
+            assert_sql_write_boundary, initialize_identity_row, insert_identity_row,
+            projection_rows,
+        },
+    },
+    value::OutputValue,
+};
+use icydb_diagnostic_code::SqlWriteBoundaryCode;
+
+#[test]
+fn sql_insert_on_conflict_reports_inserted_and_updated_rows() {
+    let session = initialize_identity_row();
+    let first = insert_identity_row(&session, 10);
+    let second = insert_identity_row(&session, 20);
+
+    let result = session
+        .execute_trusted_sql_mutation(
+            format!(
+                "INSERT INTO IdentityRow (id, payload) VALUES ({first}, 5), (DEFAULT, 30) \
+                 ON CONFLICT (id) DO UPDATE SET payload = payload + EXCLUDED.payload \
+                 RETURNING id, payload"
+            )
+            .as_str(),
+        )
+        .expect("mixed upsert batch should commit");
+    let SqlStatementResult::Upsert {
+        inserted_count,
+        updated_count,
+        rows: Some(rows),
+        ..
+    } = result
+    else {
+        panic!("upsert should report its outcome counts with RETURNING rows");
+    };
+    assert_eq!((inserted_count, updated_count), (1, 1));
+    assert_eq!(rows.len(), 2);
+    assert_eq!(
+        rows[0],
+        vec![OutputValue::Nat64(first), OutputValue::Nat64(15)]
+    );
+    assert_eq!(rows[1][1], OutputValue::Nat64(30));
+
+    let result = session
+        .execute_trusted_sql_mutation(
+            format!(
+                "INSERT INTO IdentityRow (id, payload) VALUES ({second}, 99), ({second}, 98) \
+                 ON CONFLICT (id) DO NOTHING"
+            )
+            .as_str(),
+        )
+        .expect("DO NOTHING should skip stored conflicts");
+    assert!(matches!(
+        result,
+        SqlStatementResult::Upsert {
+            inserted_count: 0,
+            updated_count: 0,
+            rows: None,
+            ..
+        }
+    ));
+
+    for (sql, boundary) in [
+        (
+            format!(
+                "INSERT INTO IdentityRow (id, payload) VALUES ({first}, 1) \
+                 ON CONFLICT (payload) DO NOTHING"
+            ),
+            SqlWriteBoundaryCode::InsertConflictTargetNotUnique,
+        ),
+        (
+            format!(
+                "INSERT INTO IdentityRow (id, payload) VALUES ({first}, 1), ({first}, 2) \
+                 ON CONFLICT (id) DO UPDATE SET payload = EXCLUDED.payload"
+            ),
+            SqlWriteBoundaryCode::InsertConflictRowAffectedTwice,
+        ),
+        (
+            format!(
+                "INSERT INTO IdentityRow (id, payload) VALUES ({first}, DEFAULT) \
+                 ON CONFLICT (id) DO UPDATE SET payload = EXCLUDED.payload"
+            ),
+            SqlWriteBoundaryCode::InsertConflictExcludedValueUnavailable,
+        ),
+    ] {
+        let error = session
+            .execute_trusted_sql_mutation(sql.as_str())
+            .expect_err("unsupported upsert shapes must reject before mutation");
+        assert_sql_write_boundary(&error, boundary);
+    }
+    assert_eq!(
+        projection_rows(
+            &session,
+            format!(
+                "SELECT payload FROM IdentityRow WHERE id IN ({first}, {second}) ORDER BY id ASC"
+            )
+            .as_str()
+        ),
+        vec![vec![OutputValue::Nat64(15)], vec![OutputValue::Nat64(20)]],
+    );
+}
+
+#[test]
+fn sql_insert_on_conflict_excluded_reads_insert_defaults_for_omitted_columns() {
+    let session = initialize_identity_row();
+    for ddl in [
+        "ALTER TABLE IdentityRow ADD COLUMN bonus nat64 NOT NULL DEFAULT 7 \
+         EXPECT SCHEMA VERSION 1 SET SCHEMA VERSION 2",
+        "ALTER TABLE IdentityRow ADD COLUMN note text NULL \
+         EXPECT SCHEMA VERSION 2 SET SCHEMA VERSION 3",
+    ] {
+        session
+            .execute_admin_sql_ddl(ddl)
+            .expect("column DDL should publish");
+    }
+    session
+        .execute_trusted_sql_mutation(
+            "INSERT INTO IdentityRow (payload, bonus, note) VALUES (10, 1, 'kept')",
+        )
+        .expect("seed row should insert");
+    let id = match projection_rows(&session, "SELECT id FROM IdentityRow WHERE payload = 10")
+        .as_slice()
+    {
+        [row] => row[0].clone(),
+        rows => panic!("seed row should resolve once: {rows:?}"),
+    };
+    let OutputValue::Nat64(id) = id else {
+        panic!("generated key should be Nat64");
+    };
+
+    // An omitted `bonus` reads its accepted default, an explicit `DEFAULT`
+    // reads the same value, and an omitted nullable `note` reads `NULL`.
+    for sql in [
+        format!(
+            "INSERT INTO IdentityRow (id, payload) VALUES ({id}, 1) \
+             ON CONFLICT (id) DO UPDATE SET payload = payload + EXCLUDED.bonus"
+        ),
+        format!(
+            "INSERT INTO IdentityRow (id, payload, bonus) VALUES ({id}, 1, DEFAULT) \
+             ON CONFLICT (id) DO UPDATE SET bonus = EXCLUDED.bonus + 1"
+        ),
+        format!(
+            "INSERT INTO IdentityRow (id, payload) VALUES ({id}, 1) \
+             ON CONFLICT (id) DO UPDATE SET note = EXCLUDED.note"
+        ),
+    ] {
+        let result = session
+            .execute_trusted_sql_mutation(sql.as_str())
+            .expect("defaulted EXCLUDED values should resolve");
+        assert!(
+            matches!(
+                result,
+                SqlStatementResult::Upsert {
+                    inserted_count: 0,
+                    updated_count: 1,
+                    ..
+                }
+            ),
+            "{sql}",
+        );
+    }
+    assert_eq!(
+        projection_rows(
+            &session,
+            format!("SELECT payload, bonus, note FROM IdentityRow WHERE id = {id}").as_str(),
+        ),
+        vec![vec![
+            OutputValue::Nat64(17),
+            OutputValue::Nat64(8),
+            OutputValue::Null,
+        ]],
+    );
+}
@@ -929,6 +929,29 @@ impl<C: CanisterKind> DbSession<C> {
             .map(|catalog| catalog.is_some())
     }
 
+    /// Return whether one accepted field of a current typed binding is a
+    /// single-valued relation to the entity named by `target`.
+    ///
+    /// `None` means the source binding is stale. Collection relations and
+    /// non-relation fields report `false`.
+    #[doc(hidden)]
+    pub fn typed_binding_relation_targets(
+        &self,
+        binding: &DynamicTypedEntityBinding,
+        relation: &str,
+        target: &DynamicTypedEntityBinding,
+    ) -> Result<Option<bool>, InternalError> {
+        let Some(catalog) = self.current_typed_entity_binding_catalog(binding)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(matches!(
+            catalog.accepted_schema_info().accepted_query_field_kind(relation),
+            Some(AcceptedFieldKind::Relation { target_entity_tag, .. })
+                if target_entity_tag.value() == target.entity_tag
+        )))
+    }
+
     /// Materialize one accepted delete batch, run bounded frontend validation,
     /// then commit it atomically.
     #[cfg(feature = "sql")]
@@ -179,6 +179,8 @@ impl SqlDdlBindError {
             | Self::UnknownFieldPath { .. }
             | Self::UnknownIndex { .. }
             | Self::UnknownColumn { .. }
+            | Self::InvalidGeneratedColumnExpression { .. }
+            | Self::InvalidExpressionIndexKey { .. }
             | Self::EntityMismatch { .. }
             | Self::MissingEntityName
             | Self::NotDdl => SchemaDdlAdmissionError::ValidationFailed,
@@ -190,6 +192,9 @@ impl SqlDdlBindError {
             | Self::GeneratedFieldDropRejected { .. }
             | Self::IndexedFieldDropRejected { .. }
             | Self::GeneratedFieldRenameRejected { .. }
+            | Self::GeneratedColumnSourceRejected { .. }
+            | Self::ExpressionIndexSourceRenameRejected { .. }
+            | Self::UniqueExpressionIndexRejected { .. }
             | Self::ConstraintOwnershipRejected { .. } => {
                 SchemaDdlAdmissionError::UnsupportedTransitionClass
             }
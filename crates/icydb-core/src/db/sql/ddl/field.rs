@@ -4,11 +4,12 @@ use super::{
 };
 use crate::db::{
     schema::{
-        AcceptedSchemaSnapshot, PersistedFieldSnapshot, SchemaDdlFieldAdditionCandidateError,
-        SchemaDdlFieldDefaultCandidateError, SchemaDdlFieldDropCandidateError,
-        SchemaDdlFieldNullabilityCandidateError, SchemaDdlFieldRenameCandidateError,
-        SchemaDdlFieldTypeContract, SchemaInfo, SchemaInsertDefault,
-        build_sql_ddl_field_addition_candidate, encode_sql_ddl_add_column_default,
+        AcceptedSchemaSnapshot, CompiledAcceptedGeneratedFields, PersistedFieldSnapshot,
+        SchemaDdlFieldAdditionCandidateError, SchemaDdlFieldDefaultCandidateError,
+        SchemaDdlFieldDropCandidateError, SchemaDdlFieldNullabilityCandidateError,
+        SchemaDdlFieldRenameCandidateError, SchemaDdlFieldTypeContract, SchemaInfo,
+        SchemaInsertDefault, build_sql_ddl_field_addition_candidate,
+        derive_sql_ddl_field_addition_accepted_after, encode_sql_ddl_add_column_default,
         encode_sql_ddl_alter_column_default, resolve_sql_ddl_field_addition_name_candidate,
         resolve_sql_ddl_field_drop_candidate, resolve_sql_ddl_field_drop_default_candidate,
         resolve_sql_ddl_field_nullability_candidate, resolve_sql_ddl_field_rename_candidate,
@@ -246,10 +247,14 @@ pub(super) fn bind_alter_table_add_column_statement(
         default,
         storage_decode,
         leaf_codec,
+        statement.generated.clone(),
     )
     .map_err(|error| {
         sql_field_addition_candidate_error(entity_name, statement.column_name.as_str(), error)
     })?;
+    if field.generated_expression().is_some() {
+        validate_generated_column_candidate(entity_name, &field, accepted_before, schema)?;
+    }
 
     Ok(BoundSqlDdlRequest {
         schema_version_contract: BoundSqlDdlSchemaVersionContract::default(),
@@ -436,6 +441,7 @@ pub(super) fn bind_alter_table_drop_column_statement(
                 });
             }
         };
+    reject_generated_column_source(entity_name, &field, accepted_before, schema)?;
 
     Ok(BoundSqlDdlRequest {
         schema_version_contract: BoundSqlDdlSchemaVersionContract::default(),
@@ -489,6 +495,8 @@ pub(super) fn bind_alter_table_rename_column_statement(
         });
     }
 
+    reject_generated_column_source(entity_name, &field, accepted_before, schema)?;
+
     Ok(BoundSqlDdlRequest {
         schema_version_contract: BoundSqlDdlSchemaVersionContract::default(),
         statement: BoundSqlDdlStatement::RenameColumn(BoundSqlRenameColumnRequest {
@@ -661,9 +669,67 @@ fn sql_field_rename_candidate_error(
                 column_name: old_column_name.to_string(),
             }
         }
+        SchemaDdlFieldRenameCandidateError::ExpressionIndexSource => {
+            SqlDdlBindError::ExpressionIndexSourceRenameRejected {
+                entity_name: entity_name.to_string(),
+                column_name: old_column_name.to_string(),
+            }
+        }
     }
 }
 
+// Bind one generated column against the accepted-after schema it would join,
+// so invalid, nondeterministic, or generated-reading expressions never publish.
+fn validate_generated_column_candidate(
+    entity_name: &str,
+    field: &PersistedFieldSnapshot,
+    accepted_before: &AcceptedSchemaSnapshot,
+    schema: &SchemaInfo,
+) -> Result<(), SqlDdlBindError> {
+    let invalid = || SqlDdlBindError::InvalidGeneratedColumnExpression {
+        entity_name: entity_name.to_string(),
+        column_name: field.name().to_string(),
+    };
+    let derivation = derive_sql_ddl_field_addition_accepted_after(accepted_before, field.clone())
+        .map_err(|_| invalid())?;
+    CompiledAcceptedGeneratedFields::compile(
+        derivation.accepted_after(),
+        schema.value_catalog_handle(),
+    )
+    .map_err(|_| invalid())?;
+
+    Ok(())
+}
+
+// Generated expressions name their sources by accepted column name, so a
+// source column cannot be dropped or renamed underneath one.
+fn reject_generated_column_source(
+    entity_name: &str,
+    field: &PersistedFieldSnapshot,
+    accepted_before: &AcceptedSchemaSnapshot,
+    schema: &SchemaInfo,
+) -> Result<(), SqlDdlBindError> {
+    let generated =
+        CompiledAcceptedGeneratedFields::compile(accepted_before, schema.value_catalog_handle())
+            .map_err(|_| SqlDdlBindError::GeneratedColumnSourceRejected {
+                entity_name: entity_name.to_string(),
+                column_name: field.name().to_string(),
+            })?;
+    let slot = usize::from(field.slot().get());
+    if generated
+        .fields()
+        .iter()
+        .any(|generated| generated.source_slots().contains(&slot))
+    {
+        return Err(SqlDdlBindError::GeneratedColumnSourceRejected {
+            entity_name: entity_name.to_string(),
+            column_name: field.name().to_string(),
+        });
+    }
+
+    Ok(())
+}
+
 fn schema_field_default_for_sql_default(
     entity_name: &str,
     column_name: &str,
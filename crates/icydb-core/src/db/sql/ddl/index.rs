@@ -4,25 +4,39 @@ use super::{
 };
 use crate::db::{
     predicate::parse_sql_predicate,
-    query::predicate::validate_predicate,
+    query::{
+        plan::{
+            expr::{
+                Expr, ExprType, Function, collect_scalar_expr_field_roots,
+                compile_scalar_projection_expr_with_schema, infer_expr_type,
+            },
+            scalar_expression_index_term,
+        },
+        predicate::validate_predicate,
+    },
     schema::{
-        AcceptedSchemaSnapshot, ConstraintId, PersistedIndexSnapshot,
+        AcceptedSchemaSnapshot, ConstraintId, FieldType, PersistedIndexSnapshot,
         SchemaDdlIndexDropCandidateError, SchemaDdlSecondaryIndexAdditionCandidate,
         SchemaDdlSecondaryIndexAdditionCandidateError, SchemaDdlSecondaryIndexExpressionIntent,
         SchemaDdlSecondaryIndexExpressionOpIntent, SchemaDdlSecondaryIndexFieldPathIntent,
-        SchemaDdlSecondaryIndexKeyCandidateError, SchemaDdlSecondaryIndexKeyIntent, SchemaInfo,
-        SchemaSnapshotAcceptanceError, build_sql_ddl_secondary_index_candidate,
+        SchemaDdlSecondaryIndexKeyCandidateError, SchemaDdlSecondaryIndexKeyIntent,
+        SchemaDdlSecondaryIndexScalarIntent, SchemaInfo, SchemaSnapshotAcceptanceError,
+        build_sql_ddl_secondary_index_candidate,
         resolve_sql_ddl_secondary_index_addition_candidate,
         resolve_sql_ddl_secondary_index_drop_candidate, validate_nullable_unique_index_contract,
     },
     sql::{
         identifier::identifiers_tail_match,
+        lowering::lower_sql_write_value_expr,
+        parse_scalar_expr_sql,
         parser::{
             SqlCreateIndexExpressionKey, SqlCreateIndexKeyItem, SqlCreateIndexStatement,
             SqlCreateIndexUniqueness, SqlDropIndexStatement,
         },
     },
 };
+use icydb_schema::ScalarKind;
+use std::collections::BTreeSet;
 
 ///
 /// BoundSqlCreateIndexRequest
@@ -128,6 +142,7 @@ impl BoundSqlDdlFieldPath {
 pub(in crate::db) enum BoundSqlDdlCreateIndexKey {
     FieldPath(BoundSqlDdlFieldPath),
     Expression(BoundSqlDdlExpressionKey),
+    Scalar(BoundSqlDdlScalarKey),
 }
 
 ///
@@ -162,6 +177,38 @@ impl BoundSqlDdlExpressionKey {
     }
 }
 
+///
+/// BoundSqlDdlScalarKey
+///
+/// Accepted arbitrary scalar-expression index key target for SQL DDL binding.
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(in crate::db) struct BoundSqlDdlScalarKey {
+    sources: Vec<BoundSqlDdlFieldPath>,
+    expression_sql: String,
+    canonical_sql: String,
+}
+
+impl BoundSqlDdlScalarKey {
+    /// Borrow the top-level source fields read by the expression.
+    #[must_use]
+    pub(in crate::db) const fn sources(&self) -> &[BoundSqlDdlFieldPath] {
+        self.sources.as_slice()
+    }
+
+    /// Borrow the expression SQL text rebound when keys are derived.
+    #[must_use]
+    pub(in crate::db) const fn expression_sql(&self) -> &str {
+        self.expression_sql.as_str()
+    }
+
+    /// Borrow the normalized expression fingerprint matched by the planner.
+    #[must_use]
+    pub(in crate::db) const fn canonical_sql(&self) -> &str {
+        self.canonical_sql.as_str()
+    }
+}
+
 pub(super) fn bind_create_index_statement(
     statement: &SqlCreateIndexStatement,
     accepted_before: &AcceptedSchemaSnapshot,
@@ -184,13 +231,30 @@ pub(super) fn bind_create_index_statement(
         .iter()
         .map(|key_item| bind_create_index_key_item(key_item, entity_name, schema))
         .collect::<Result<Vec<_>, _>>()?;
+    let include = statement
+        .include
+        .iter()
+        .map(|field_path| bind_create_index_field_path(field_path, entity_name, schema))
+        .collect::<Result<Vec<_>, _>>()?;
+    if statement.uniqueness == SqlCreateIndexUniqueness::Unique
+        && key_items
+            .iter()
+            .any(|key_item| matches!(key_item, BoundSqlDdlCreateIndexKey::Scalar(_)))
+    {
+        return Err(SqlDdlBindError::UniqueExpressionIndexRejected {
+            index_name: statement.name.clone(),
+        });
+    }
     let field_paths = create_index_field_path_report_items(key_items.as_slice());
     let predicate_sql =
         validated_create_index_predicate_sql(statement.predicate_sql.as_deref(), schema)?;
+    let key_intents =
+        schema_secondary_index_key_intents(key_items.as_slice(), statement.descending.as_slice());
     let candidate_index = candidate_index_snapshot(
         accepted_before,
         statement.name.as_str(),
-        key_items.as_slice(),
+        key_intents.as_slice(),
+        include.as_slice(),
         predicate_sql.as_deref(),
         statement.uniqueness,
         index_store_path,
@@ -333,7 +397,65 @@ fn bind_create_index_key_item(
         SqlCreateIndexKeyItem::Expression(expression) => {
             bind_create_index_expression_key(expression, entity_name, schema)
         }
+        SqlCreateIndexKeyItem::Scalar(expression_sql) => {
+            bind_create_index_scalar_key(expression_sql, entity_name, schema)
+        }
+    }
+}
+
+// Bind one arbitrary key expression through the same parse, lowering, and
+// scalar compile pipeline that later derives its index keys. A bare field
+// binds as an ordinary field-path key so it shares that index contract.
+fn bind_create_index_scalar_key(
+    expression_sql: &str,
+    entity_name: &str,
+    schema: &SchemaInfo,
+) -> Result<BoundSqlDdlCreateIndexKey, SqlDdlBindError> {
+    let invalid = || SqlDdlBindError::InvalidExpressionIndexKey {
+        entity_name: entity_name.to_string(),
+        expression: expression_sql.to_string(),
+    };
+    let parsed = parse_scalar_expr_sql(expression_sql).map_err(|_| invalid())?;
+    let lowered = lower_sql_write_value_expr(&parsed, schema).map_err(|_| invalid())?;
+    if let Expr::Field(field) = &lowered {
+        return bind_create_index_field_path(field.as_str(), entity_name, schema)
+            .map(BoundSqlDdlCreateIndexKey::FieldPath);
+    }
+    if lowered.any_tree_expr(&mut |expr| {
+        matches!(
+            expr,
+            Expr::FunctionCall {
+                function: Function::Now,
+                ..
+            }
+        )
+    }) {
+        return Err(invalid());
+    }
+    let mut roots = BTreeSet::new();
+    if !collect_scalar_expr_field_roots(&lowered, &mut roots) || roots.is_empty() {
+        return Err(invalid());
     }
+    if matches!(
+        infer_expr_type(&lowered, schema),
+        Err(_) | Ok(ExprType::Collection | ExprType::Structured)
+    ) || compile_scalar_projection_expr_with_schema(schema, &lowered).is_none()
+    {
+        return Err(invalid());
+    }
+
+    Ok(BoundSqlDdlCreateIndexKey::Scalar(BoundSqlDdlScalarKey {
+        sources: roots
+            .into_iter()
+            .map(|root| BoundSqlDdlFieldPath {
+                accepted_path: vec![root.clone()],
+                root,
+                segments: Vec::new(),
+            })
+            .collect(),
+        expression_sql: expression_sql.to_string(),
+        canonical_sql: scalar_expression_index_term(&lowered),
+    }))
 }
 
 fn bind_create_index_expression_key(
@@ -341,7 +463,12 @@ fn bind_create_index_expression_key(
     entity_name: &str,
     schema: &SchemaInfo,
 ) -> Result<BoundSqlDdlCreateIndexKey, SqlDdlBindError> {
-    let source = bind_create_index_field_path(expression.field_path.as_str(), entity_name, schema)?;
+    let source = match expression.function {
+        crate::db::sql::parser::SqlCreateIndexExpressionFunction::Elements => {
+            bind_create_index_element_source(expression.field_path.as_str(), entity_name, schema)?
+        }
+        _ => bind_create_index_field_path(expression.field_path.as_str(), entity_name, schema)?,
+    };
 
     Ok(BoundSqlDdlCreateIndexKey::Expression(
         BoundSqlDdlExpressionKey {
@@ -365,6 +492,12 @@ const fn expression_op_from_sql_function(
         crate::db::sql::parser::SqlCreateIndexExpressionFunction::Trim => {
             SchemaDdlSecondaryIndexExpressionOpIntent::Trim
         }
+        crate::db::sql::parser::SqlCreateIndexExpressionFunction::Tokens => {
+            SchemaDdlSecondaryIndexExpressionOpIntent::Tokens
+        }
+        crate::db::sql::parser::SqlCreateIndexExpressionFunction::Elements => {
+            SchemaDdlSecondaryIndexExpressionOpIntent::Elements
+        }
     }
 }
 
@@ -379,9 +512,10 @@ fn create_index_field_path_report_items(
 ) -> Vec<BoundSqlDdlFieldPath> {
     key_items
         .iter()
-        .map(|key_item| match key_item {
-            BoundSqlDdlCreateIndexKey::FieldPath(field_path) => field_path.clone(),
-            BoundSqlDdlCreateIndexKey::Expression(expression) => expression.source().clone(),
+        .flat_map(|key_item| match key_item {
+            BoundSqlDdlCreateIndexKey::FieldPath(field_path) => vec![field_path.clone()],
+            BoundSqlDdlCreateIndexKey::Expression(expression) => vec![expression.source().clone()],
+            BoundSqlDdlCreateIndexKey::Scalar(expression) => expression.sources().to_vec(),
         })
         .collect()
 }
@@ -432,6 +566,38 @@ fn bind_create_index_field_path(
     })
 }
 
+// Bind the collection source of one element index. Collections are not
+// orderable themselves, so indexability is decided by the element kind.
+fn bind_create_index_element_source(
+    field_path: &str,
+    entity_name: &str,
+    schema: &SchemaInfo,
+) -> Result<BoundSqlDdlFieldPath, SqlDdlBindError> {
+    let root = field_path.trim();
+    let Some(field) = schema.field(root) else {
+        return Err(SqlDdlBindError::UnknownFieldPath {
+            entity_name: entity_name.to_string(),
+            field_path: field_path.to_string(),
+        });
+    };
+    let (FieldType::List(element) | FieldType::Set(element)) = field else {
+        return Err(SqlDdlBindError::FieldPathNotIndexable {
+            field_path: field_path.to_string(),
+        });
+    };
+    if !matches!(element.as_ref(), FieldType::Scalar(kind) if *kind != ScalarKind::Enum) {
+        return Err(SqlDdlBindError::FieldPathNotIndexable {
+            field_path: field_path.to_string(),
+        });
+    }
+
+    Ok(BoundSqlDdlFieldPath {
+        root: root.to_string(),
+        segments: Vec::new(),
+        accepted_path: vec![root.to_string()],
+    })
+}
+
 fn sql_secondary_index_addition_candidate_error(
     index_name: &str,
     key_items: &[BoundSqlDdlCreateIndexKey],
@@ -475,34 +641,44 @@ fn create_index_key_report(
 fn candidate_index_snapshot(
     accepted_before: &AcceptedSchemaSnapshot,
     index_name: &str,
-    key_items: &[BoundSqlDdlCreateIndexKey],
+    key_intents: &[SchemaDdlSecondaryIndexKeyIntent],
+    include: &[BoundSqlDdlFieldPath],
     predicate_sql: Option<&str>,
     uniqueness: SqlCreateIndexUniqueness,
     index_store_path: &'static str,
 ) -> Result<PersistedIndexSnapshot, SqlDdlBindError> {
-    let key_intents = schema_secondary_index_key_intents(key_items);
+    let include_intents = include
+        .iter()
+        .map(schema_index_field_path_intent)
+        .collect::<Vec<_>>();
 
     build_sql_ddl_secondary_index_candidate(
         accepted_before,
         index_name.to_string(),
         index_store_path.to_string(),
         matches!(uniqueness, SqlCreateIndexUniqueness::Unique),
-        key_intents.as_slice(),
+        key_intents,
+        include_intents.as_slice(),
         predicate_sql.map(str::to_string),
     )
     .map_err(sql_secondary_index_key_candidate_error)
 }
 
+// The parser records `DESC` keys by their dotted field path, which is the
+// joined accepted path once the key has bound.
 fn schema_secondary_index_key_intents(
     key_items: &[BoundSqlDdlCreateIndexKey],
+    descending: &[String],
 ) -> Vec<SchemaDdlSecondaryIndexKeyIntent> {
     key_items
         .iter()
         .map(|key_item| match key_item {
             BoundSqlDdlCreateIndexKey::FieldPath(field_path) => {
-                SchemaDdlSecondaryIndexKeyIntent::FieldPath(schema_index_field_path_intent(
-                    field_path,
-                ))
+                SchemaDdlSecondaryIndexKeyIntent::FieldPath(
+                    schema_index_field_path_intent(field_path).with_descending(
+                        descending.contains(&field_path.accepted_path().join(".")),
+                    ),
+                )
             }
             BoundSqlDdlCreateIndexKey::Expression(expression) => {
                 SchemaDdlSecondaryIndexKeyIntent::Expression(Box::new(
@@ -513,6 +689,19 @@ fn schema_secondary_index_key_intents(
                     ),
                 ))
             }
+            BoundSqlDdlCreateIndexKey::Scalar(expression) => {
+                SchemaDdlSecondaryIndexKeyIntent::Scalar(Box::new(
+                    SchemaDdlSecondaryIndexScalarIntent::new(
+                        expression
+                            .sources()
+                            .iter()
+                            .map(schema_index_field_path_intent)
+                            .collect(),
+                        expression.expression_sql().to_string(),
+                        expression.canonical_sql().to_string(),
+                    ),
+                ))
+            }
         })
         .collect()
 }
@@ -587,6 +776,7 @@ fn ddl_key_item_text(key_item: &BoundSqlDdlCreateIndexKey) -> String {
     match key_item {
         BoundSqlDdlCreateIndexKey::FieldPath(field_path) => field_path.accepted_path().join("."),
         BoundSqlDdlCreateIndexKey::Expression(expression) => expression.canonical_sql().to_string(),
+        BoundSqlDdlCreateIndexKey::Scalar(expression) => expression.canonical_sql().to_string(),
     }
 }
 
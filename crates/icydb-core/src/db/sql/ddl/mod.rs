@@ -237,6 +237,15 @@ pub(in crate::db) enum SqlDdlBindError {
         index_name: String,
     },
 
+    InvalidExpressionIndexKey {
+        entity_name: String,
+        expression: String,
+    },
+
+    UniqueExpressionIndexRejected {
+        index_name: String,
+    },
+
     InvalidAlterTableAddColumnDefault {
         entity_name: String,
         column_name: String,
@@ -257,6 +266,16 @@ pub(in crate::db) enum SqlDdlBindError {
         column_type: String,
     },
 
+    InvalidGeneratedColumnExpression {
+        entity_name: String,
+        column_name: String,
+    },
+
+    GeneratedColumnSourceRejected {
+        entity_name: String,
+        column_name: String,
+    },
+
     UnknownColumn {
         entity_name: String,
         column_name: String,
@@ -298,6 +317,11 @@ pub(in crate::db) enum SqlDdlBindError {
         column_name: String,
     },
 
+    ExpressionIndexSourceRenameRejected {
+        entity_name: String,
+        column_name: String,
+    },
+
     NonPositiveSchemaVersion {
         clause: &'static str,
     },
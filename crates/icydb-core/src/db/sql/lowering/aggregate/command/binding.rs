@@ -88,7 +88,8 @@ impl AggregateShapeFacts {
                     == Some(false)
             }
             PreparedSqlScalarAggregatePlanFragment::NumericField { .. }
-            | PreparedSqlScalarAggregatePlanFragment::ExtremalWinnerField { .. } => false,
+            | PreparedSqlScalarAggregatePlanFragment::ExtremalWinnerField { .. }
+            | PreparedSqlScalarAggregatePlanFragment::RetainedValueField { .. } => false,
         }
     }
 
@@ -242,7 +243,8 @@ fn indexed_extrema_input_order(
         PreparedSqlScalarAggregatePlanFragment::CountRows
         | PreparedSqlScalarAggregatePlanFragment::CountField
         | PreparedSqlScalarAggregatePlanFragment::NumericField { .. }
-        | PreparedSqlScalarAggregatePlanFragment::ExtremalWinnerField { .. } => return None,
+        | PreparedSqlScalarAggregatePlanFragment::ExtremalWinnerField { .. }
+        | PreparedSqlScalarAggregatePlanFragment::RetainedValueField { .. } => return None,
     };
 
     let mut ordered_fields = Vec::with_capacity(1 + schema.primary_key_names().len());
@@ -51,6 +51,7 @@ impl LoweredSqlGlobalAggregateCommand {
             having,
             order_by,
             limit,
+            limit_per,
             offset,
             entity: _,
             table_alias: _,
@@ -59,6 +60,9 @@ impl LoweredSqlGlobalAggregateCommand {
         if distinct {
             return Err(SqlLoweringError::unsupported_select_distinct());
         }
+        if !limit_per.is_empty() {
+            return Err(SqlLoweringError::unsupported_limit_per());
+        }
         if !group_by.is_empty() {
             return Err(SqlLoweringError::global_aggregate_does_not_support_group_by());
         }
@@ -128,6 +132,7 @@ fn is_direct_count_rows_projection(projection: &SqlProjection) -> bool {
             input: None,
             filter_expr: None,
             distinct: false,
+            arguments: None,
         })]
     )
 }
@@ -1,10 +1,10 @@
 use crate::{
     db::{
-        query::builder::AggregateExpr,
+        query::{builder::AggregateExpr, plan::AggregateArguments},
         schema::SchemaInfo,
         sql::{
             lowering::{
-                SqlLoweringError,
+                AnalyzedLoweredExpr, SqlLoweringError,
                 aggregate::lowering::{
                     LoweredSqlAggregateShape, validate_analyzed_schema_bound_scalar_expr,
                 },
@@ -138,6 +138,18 @@ pub(in crate::db::sql::lowering::aggregate) fn validate_grouped_aggregate_scalar
             SqlLoweringError::unsupported_where_expression,
         )?;
     }
+    for term in aggregate
+        .arguments()
+        .map(AggregateArguments::order_by)
+        .unwrap_or_default()
+    {
+        validate_analyzed_schema_bound_scalar_expr(
+            schema,
+            &AnalyzedLoweredExpr::new(term.expr().clone()),
+            QueryFieldRole::AggregateTarget,
+            SqlLoweringError::unsupported_aggregate_input_expressions,
+        )?;
+    }
 
     Ok(())
 }
@@ -206,12 +218,14 @@ fn sql_expr_fingerprint(expr: &SqlExpr) -> u64 {
         SqlExpr::Like {
             expr,
             pattern,
+            escape,
             negated,
             casefold,
         } => {
             7_u8.hash(&mut hasher);
             sql_expr_fingerprint(expr).hash(&mut hasher);
             pattern.hash(&mut hasher);
+            escape.hash(&mut hasher);
             negated.hash(&mut hasher);
             casefold.hash(&mut hasher);
         }
@@ -243,6 +257,16 @@ fn sql_expr_fingerprint(expr: &SqlExpr) -> u64 {
             }
             sql_expr_option_fingerprint(else_expr.as_deref()).hash(&mut hasher);
         }
+        SqlExpr::SemiJoin {
+            probe,
+            source,
+            negated,
+        } => {
+            12_u8.hash(&mut hasher);
+            sql_expr_option_fingerprint(probe.as_deref()).hash(&mut hasher);
+            source.entity.hash(&mut hasher);
+            negated.hash(&mut hasher);
+        }
     }
 
     hasher.finish()
@@ -267,6 +291,13 @@ const fn sql_aggregate_kind_tag(kind: crate::db::sql::parser::SqlAggregateKind)
         crate::db::sql::parser::SqlAggregateKind::Avg => 2,
         crate::db::sql::parser::SqlAggregateKind::Min => 3,
         crate::db::sql::parser::SqlAggregateKind::Max => 4,
+        crate::db::sql::parser::SqlAggregateKind::VarPop => 5,
+        crate::db::sql::parser::SqlAggregateKind::VarSamp => 6,
+        crate::db::sql::parser::SqlAggregateKind::StddevPop => 7,
+        crate::db::sql::parser::SqlAggregateKind::StddevSamp => 8,
+        crate::db::sql::parser::SqlAggregateKind::PercentileDisc => 9,
+        crate::db::sql::parser::SqlAggregateKind::StringAgg => 10,
+        crate::db::sql::parser::SqlAggregateKind::ArrayAgg => 11,
     }
 }
 
@@ -290,6 +321,8 @@ const fn sql_binary_op_tag(op: crate::db::sql::parser::SqlExprBinaryOp) -> u8 {
         crate::db::sql::parser::SqlExprBinaryOp::Sub => 9,
         crate::db::sql::parser::SqlExprBinaryOp::Mul => 10,
         crate::db::sql::parser::SqlExprBinaryOp::Div => 11,
+        crate::db::sql::parser::SqlExprBinaryOp::IsDistinctFrom => 12,
+        crate::db::sql::parser::SqlExprBinaryOp::IsNotDistinctFrom => 13,
     }
 }
 
@@ -329,5 +362,13 @@ const fn sql_scalar_function_tag(function: crate::db::sql::parser::SqlScalarFunc
         SqlScalarFunction::Trim => 34,
         SqlScalarFunction::Trunc => 35,
         SqlScalarFunction::Upper => 36,
+        SqlScalarFunction::Cast => 37,
+        SqlScalarFunction::Age => 38,
+        SqlScalarFunction::DateTrunc => 39,
+        SqlScalarFunction::Extract => 40,
+        SqlScalarFunction::Now => 41,
+        SqlScalarFunction::RegexpMatches => 42,
+        SqlScalarFunction::Match => 43,
+        SqlScalarFunction::HasKey => 44,
     }
 }
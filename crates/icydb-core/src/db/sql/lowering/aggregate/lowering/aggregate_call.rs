@@ -1,10 +1,14 @@
 use crate::db::{
+    numeric::coerce_numeric_decimal,
     query::{
         builder::{
             AggregateExpr,
             aggregate::{avg, count, count_by, max_by, min_by, sum},
         },
-        plan::expr::{Expr, canonicalize_aggregate_input_expr},
+        plan::{
+            AggregateArguments, NullsOrder, OrderDirection, OrderTerm,
+            expr::{Expr, canonicalize_aggregate_input_expr},
+        },
     },
     schema::SchemaInfo,
     sql::{
@@ -20,9 +24,13 @@ use crate::db::{
             expr::{SqlExprPhase, lower_sql_expr},
             predicate::lower_sql_pre_aggregate_bool_expr,
         },
-        parser::{SqlAggregateCall, SqlAggregateKind, SqlExpr},
+        parser::{
+            SqlAggregateArguments, SqlAggregateCall, SqlAggregateKind, SqlExpr, SqlNullsOrder,
+            SqlOrderDirection, SqlOrderTerm,
+        },
     },
 };
+use crate::types::Decimal;
 
 fn lower_sql_aggregate_shape(
     call: SqlAggregateCall,
@@ -32,7 +40,15 @@ fn lower_sql_aggregate_shape(
         input,
         filter_expr,
         distinct,
+        arguments,
     } = call;
+    let aggregate_kind = kind.aggregate_kind();
+    if distinct && (aggregate_kind.is_statistical() || aggregate_kind.retains_input_values()) {
+        return Err(SqlLoweringError::unsupported_select_projection());
+    }
+    let arguments = arguments
+        .map(|arguments| lower_sql_aggregate_arguments(*arguments))
+        .transpose()?;
     let filter_expr = filter_expr
         .map(|expr| lower_sql_pre_aggregate_bool_expr(expr.as_ref()))
         .map(|expr| expr.map(AnalyzedLoweredExpr::new))
@@ -70,11 +86,72 @@ fn lower_sql_aggregate_shape(
             )),
             filter_expr,
             distinct,
+            arguments,
         }),
         _ => Err(SqlLoweringError::unsupported_select_projection()),
     }
 }
 
+// Lower retained-value aggregate arguments. PERCENTILE_DISC fractions must be
+// numeric literals within `[0, 1]`; aggregate-local ORDER BY terms lower as
+// pre-aggregate scalar expressions so they cannot nest another aggregate.
+fn lower_sql_aggregate_arguments(
+    arguments: SqlAggregateArguments,
+) -> Result<AggregateArguments, SqlLoweringError> {
+    match arguments {
+        SqlAggregateArguments::Percentile {
+            fraction,
+            direction,
+        } => {
+            let fraction = coerce_numeric_decimal(&fraction)
+                .filter(|fraction| *fraction >= Decimal::ZERO && *fraction <= Decimal::new(1, 0))
+                .ok_or_else(SqlLoweringError::unsupported_select_projection)?;
+
+            Ok(AggregateArguments::Percentile {
+                fraction: fraction.normalize(),
+                direction: lower_sql_order_direction(direction),
+            })
+        }
+        SqlAggregateArguments::StringAgg {
+            separator,
+            order_by,
+        } => Ok(AggregateArguments::StringAgg {
+            separator,
+            order_by: lower_sql_aggregate_order_terms(order_by)?,
+        }),
+        SqlAggregateArguments::ArrayAgg { order_by } => Ok(AggregateArguments::ArrayAgg {
+            order_by: lower_sql_aggregate_order_terms(order_by)?,
+        }),
+    }
+}
+
+fn lower_sql_aggregate_order_terms(
+    order_by: Vec<SqlOrderTerm>,
+) -> Result<Vec<OrderTerm>, SqlLoweringError> {
+    order_by
+        .into_iter()
+        .map(|term| {
+            let lowered = OrderTerm::new(
+                lower_sql_expr(&term.field, SqlExprPhase::PreAggregate)?,
+                lower_sql_order_direction(term.direction),
+            );
+
+            Ok(match term.nulls {
+                Some(SqlNullsOrder::First) => lowered.with_nulls(NullsOrder::First),
+                Some(SqlNullsOrder::Last) => lowered.with_nulls(NullsOrder::Last),
+                None => lowered,
+            })
+        })
+        .collect()
+}
+
+const fn lower_sql_order_direction(direction: SqlOrderDirection) -> OrderDirection {
+    match direction {
+        SqlOrderDirection::Asc => OrderDirection::Asc,
+        SqlOrderDirection::Desc => OrderDirection::Desc,
+    }
+}
+
 pub(in crate::db::sql::lowering) fn lower_aggregate_call(
     call: SqlAggregateCall,
 ) -> Result<AggregateExpr, SqlLoweringError> {
@@ -112,10 +189,19 @@ fn lower_sql_aggregate_shape_to_expr(
             input_expr,
             filter_expr,
             distinct,
-        } => Ok(apply_aggregate_filter_expr(
-            kind.lower_expression_owned_aggregate(input_expr.into_expr(), distinct),
-            lowered_filter_expr(filter_expr),
-        )),
+            arguments,
+        } => {
+            let aggregate = kind.lower_expression_owned_aggregate(input_expr.into_expr(), distinct);
+            let aggregate = match arguments {
+                Some(arguments) => aggregate.with_arguments(arguments),
+                None => aggregate,
+            };
+
+            Ok(apply_aggregate_filter_expr(
+                aggregate,
+                lowered_filter_expr(filter_expr),
+            ))
+        }
     }
 }
 
@@ -147,11 +233,18 @@ impl SqlAggregateKind {
         distinct: bool,
     ) -> Result<AggregateExpr, SqlLoweringError> {
         let aggregate = match self {
-            Self::Count => return Err(SqlLoweringError::unsupported_select_projection()),
             Self::Sum => apply_distinct_marker(sum(field), distinct),
             Self::Avg => apply_distinct_marker(avg(field), distinct),
             Self::Min => apply_distinct_marker(min_by(field), distinct),
             Self::Max => apply_distinct_marker(max_by(field), distinct),
+            Self::Count
+            | Self::VarPop
+            | Self::VarSamp
+            | Self::StddevPop
+            | Self::StddevSamp
+            | Self::PercentileDisc
+            | Self::StringAgg
+            | Self::ArrayAgg => return Err(SqlLoweringError::unsupported_select_projection()),
         };
 
         Ok(apply_aggregate_filter_expr(aggregate, filter_expr))
@@ -1,4 +1,7 @@
-use crate::db::sql::{lowering::AnalyzedLoweredExpr, parser::SqlAggregateKind};
+use crate::db::{
+    query::plan::AggregateArguments,
+    sql::{lowering::AnalyzedLoweredExpr, parser::SqlAggregateKind},
+};
 
 ///
 /// LoweredSqlAggregateShape
@@ -26,6 +29,7 @@ pub(in crate::db::sql::lowering::aggregate) enum LoweredSqlAggregateShape {
         input_expr: AnalyzedLoweredExpr,
         filter_expr: Option<AnalyzedLoweredExpr>,
         distinct: bool,
+        arguments: Option<AggregateArguments>,
     },
 }
 
@@ -41,6 +45,16 @@ impl LoweredSqlAggregateShape {
         }
     }
 
+    /// Borrow the lowered retained-value aggregate arguments, when present.
+    pub(in crate::db::sql::lowering::aggregate) const fn arguments(
+        &self,
+    ) -> Option<&AggregateArguments> {
+        match self {
+            Self::ExpressionInput { arguments, .. } => arguments.as_ref(),
+            Self::CountRows { .. } | Self::CountField { .. } | Self::FieldTarget { .. } => None,
+        }
+    }
+
     /// Borrow the lowered aggregate FILTER analysis, when present.
     pub(in crate::db::sql::lowering::aggregate) const fn filter_expr(
         &self,
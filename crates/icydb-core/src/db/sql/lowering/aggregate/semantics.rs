@@ -1,4 +1,4 @@
-use crate::db::query::plan::{AggregateKind, FieldSlot, expr::Expr};
+use crate::db::query::plan::{AggregateArguments, AggregateKind, FieldSlot, expr::Expr};
 use crate::db::sql::lowering::SqlLoweringError;
 
 ///
@@ -67,15 +67,26 @@ pub(in crate::db::sql::lowering::aggregate) enum PreparedAggregateSemantics {
     Max {
         target: PreparedAggregateTarget,
     },
+    Statistic {
+        kind: AggregateKind,
+        target: PreparedAggregateTarget,
+    },
+    Retained {
+        kind: AggregateKind,
+        target: PreparedAggregateTarget,
+        arguments: Option<AggregateArguments>,
+    },
 }
 
 impl PreparedAggregateSemantics {
     // Combine normalized aggregate kind/DISTINCT semantics with a model-bound
-    // target. MIN/MAX deliberately discard the supplied DISTINCT bit.
+    // target. MIN/MAX and the statistical/retained-value families deliberately
+    // discard the supplied DISTINCT bit, which identity already normalized.
     pub(in crate::db::sql::lowering::aggregate) fn try_from_kind_target_and_distinct(
         kind: AggregateKind,
         target: PreparedAggregateTarget,
         distinct: bool,
+        arguments: Option<AggregateArguments>,
     ) -> Result<Self, SqlLoweringError> {
         let semantics = match kind {
             AggregateKind::Count => Self::Count { target, distinct },
@@ -83,6 +94,17 @@ impl PreparedAggregateSemantics {
             AggregateKind::Avg => Self::Avg { target, distinct },
             AggregateKind::Min => Self::Min { target },
             AggregateKind::Max => Self::Max { target },
+            AggregateKind::VarPop
+            | AggregateKind::VarSamp
+            | AggregateKind::StddevPop
+            | AggregateKind::StddevSamp => Self::Statistic { kind, target },
+            AggregateKind::PercentileDisc | AggregateKind::StringAgg | AggregateKind::ArrayAgg => {
+                Self::Retained {
+                    kind,
+                    target,
+                    arguments,
+                }
+            }
             AggregateKind::Exists | AggregateKind::First | AggregateKind::Last => {
                 return Err(SqlLoweringError::unsupported_global_aggregate_projection());
             }
@@ -100,6 +122,7 @@ impl PreparedAggregateSemantics {
             Self::Avg { .. } => AggregateKind::Avg,
             Self::Min { .. } => AggregateKind::Min,
             Self::Max { .. } => AggregateKind::Max,
+            Self::Statistic { kind, .. } | Self::Retained { kind, .. } => *kind,
         }
     }
 
@@ -110,7 +133,10 @@ impl PreparedAggregateSemantics {
             Self::Count { distinct, .. }
             | Self::Sum { distinct, .. }
             | Self::Avg { distinct, .. } => *distinct,
-            Self::Min { .. } | Self::Max { .. } => false,
+            Self::Min { .. }
+            | Self::Max { .. }
+            | Self::Statistic { .. }
+            | Self::Retained { .. } => false,
         }
     }
 
@@ -123,7 +149,9 @@ impl PreparedAggregateSemantics {
             | Self::Sum { target, .. }
             | Self::Avg { target, .. }
             | Self::Min { target }
-            | Self::Max { target } => target,
+            | Self::Max { target }
+            | Self::Statistic { target, .. }
+            | Self::Retained { target, .. } => target,
         }
     }
 
@@ -133,16 +161,29 @@ impl PreparedAggregateSemantics {
         self.target().field_slot()
     }
 
-    // Move this prepared semantic terminal into executor inputs.
+    // Move this prepared semantic terminal into executor inputs plus any
+    // retained-value aggregate arguments.
     pub(in crate::db::sql::lowering::aggregate) fn into_terminal_inputs(
         self,
-    ) -> (Option<FieldSlot>, Option<Expr>) {
+    ) -> (Option<FieldSlot>, Option<Expr>, Option<AggregateArguments>) {
         match self {
             Self::Count { target, .. }
             | Self::Sum { target, .. }
             | Self::Avg { target, .. }
             | Self::Min { target }
-            | Self::Max { target } => target.into_terminal_inputs(),
+            | Self::Max { target }
+            | Self::Statistic { target, .. } => {
+                let (target_slot, input_expr) = target.into_terminal_inputs();
+
+                (target_slot, input_expr, None)
+            }
+            Self::Retained {
+                target, arguments, ..
+            } => {
+                let (target_slot, input_expr) = target.into_terminal_inputs();
+
+                (target_slot, input_expr, arguments)
+            }
         }
     }
 }
@@ -1,10 +1,11 @@
 use crate::db::{
     query::plan::{
-        AggregateKind, FieldSlot, expr::Expr, resolve_aggregate_target_field_slot_with_schema,
+        AggregateArguments, AggregateKind, FieldSlot, expr::Expr,
+        resolve_aggregate_target_field_slot_with_schema,
     },
     schema::SchemaInfo,
     sql::lowering::{
-        SqlLoweringError,
+        AnalyzedLoweredExpr, SqlLoweringError,
         aggregate::{
             lowering::validate_analyzed_schema_bound_scalar_expr,
             semantics::{PreparedAggregateSemantics, PreparedAggregateTarget},
@@ -29,6 +30,7 @@ pub(crate) enum PreparedSqlScalarAggregatePlanFragment {
     CountField,
     NumericField { kind: AggregateKind },
     ExtremalWinnerField { kind: AggregateKind },
+    RetainedValueField { kind: AggregateKind },
 }
 
 ///
@@ -106,10 +108,23 @@ impl PreparedSqlScalarAggregateStrategy {
             None => None,
         };
 
+        let arguments = semantic_identity.arguments().cloned();
+        if let Some(arguments) = arguments.as_ref() {
+            for term in arguments.order_by() {
+                validate_analyzed_schema_bound_scalar_expr(
+                    schema,
+                    &AnalyzedLoweredExpr::new(term.expr().clone()),
+                    QueryFieldRole::AggregateTarget,
+                    SqlLoweringError::unsupported_aggregate_input_expressions,
+                )?;
+            }
+        }
+
         let semantics = PreparedAggregateSemantics::try_from_kind_target_and_distinct(
             kind,
             target,
             distinct_input,
+            arguments,
         )?;
 
         Ok(Self::from_semantics(semantics, filter_expr))
@@ -191,6 +206,12 @@ impl PreparedSqlScalarAggregateStrategy {
                     kind: AggregateKind::Max,
                 }
             }
+            PreparedAggregateSemantics::Statistic { kind, .. } => {
+                PreparedSqlScalarAggregatePlanFragment::NumericField { kind: *kind }
+            }
+            PreparedAggregateSemantics::Retained { kind, .. } => {
+                PreparedSqlScalarAggregatePlanFragment::RetainedValueField { kind: *kind }
+            }
         }
     }
 
@@ -208,6 +229,7 @@ impl PreparedSqlScalarAggregateStrategy {
         Option<Expr>,
         Option<Expr>,
         bool,
+        Option<AggregateArguments>,
     ) {
         let descriptor = self.plan_fragment();
         let Self {
@@ -215,7 +237,7 @@ impl PreparedSqlScalarAggregateStrategy {
             filter_expr,
         } = self;
         let distinct_input = semantics.distinct_input();
-        let (target_slot, input_expr) = semantics.into_terminal_inputs();
+        let (target_slot, input_expr, arguments) = semantics.into_terminal_inputs();
 
         (
             descriptor,
@@ -223,6 +245,7 @@ impl PreparedSqlScalarAggregateStrategy {
             input_expr,
             filter_expr,
             distinct_input,
+            arguments,
         )
     }
 
@@ -248,9 +271,18 @@ fn validate_field_target_sql_aggregate_capabilities(
     };
     let aggregate_input = capabilities.aggregate_input();
     let supported = match kind {
-        AggregateKind::Count => aggregate_input.count(),
-        AggregateKind::Sum | AggregateKind::Avg => aggregate_input.numeric(),
-        AggregateKind::Min | AggregateKind::Max => aggregate_input.extrema(),
+        AggregateKind::Sum
+        | AggregateKind::Avg
+        | AggregateKind::VarPop
+        | AggregateKind::VarSamp
+        | AggregateKind::StddevPop
+        | AggregateKind::StddevSamp => aggregate_input.numeric(),
+        AggregateKind::Min | AggregateKind::Max | AggregateKind::PercentileDisc => {
+            aggregate_input.extrema()
+        }
+        AggregateKind::Count | AggregateKind::StringAgg | AggregateKind::ArrayAgg => {
+            aggregate_input.count()
+        }
         AggregateKind::Exists | AggregateKind::First | AggregateKind::Last => false,
     };
     if !supported {
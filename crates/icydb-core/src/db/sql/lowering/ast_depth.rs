@@ -2,9 +2,9 @@ use crate::db::{
     sql::{
         lowering::SqlLoweringError,
         parser::{
-            SqlAggregateCall, SqlDeleteStatement, SqlExpr, SqlInsertSource, SqlInsertStatement,
-            SqlOrderTerm, SqlProjection, SqlSelectItem, SqlSelectStatement, SqlStatement,
-            SqlUpdateStatement,
+            SqlAggregateCall, SqlAssignment, SqlDeleteStatement, SqlExpr, SqlInsertConflictAction,
+            SqlInsertOnConflict, SqlInsertSource, SqlInsertStatement, SqlOrderTerm, SqlProjection,
+            SqlSelectItem, SqlSelectStatement, SqlStatement, SqlUpdateStatement, SqlWriteValue,
         },
     },
     sql_shared::{MAX_SQL_EXPR_DEPTH, sql_expr_depth_limit_error},
@@ -17,6 +17,16 @@ pub(in crate::db::sql::lowering) fn validate_sql_statement_ast_depth(
 ) -> Result<(), SqlLoweringError> {
     match statement {
         SqlStatement::Select(statement) => validate_select_statement_depth(statement),
+        SqlStatement::Union(statement) => {
+            for branch in &statement.branches {
+                validate_select_statement_depth(branch)?;
+            }
+
+            validate_order_terms_depth(statement.order_by.as_slice())
+        }
+        SqlStatement::With(statement) => statement
+            .selects()
+            .try_for_each(validate_select_statement_depth),
         SqlStatement::Delete(statement) => validate_delete_statement_depth(statement),
         SqlStatement::Insert(statement) => validate_insert_statement_depth(statement),
         SqlStatement::Update(statement) => validate_update_statement_depth(statement),
@@ -25,6 +35,9 @@ pub(in crate::db::sql::lowering) fn validate_sql_statement_ast_depth(
             crate::db::sql::parser::SqlExplainTarget::Select(select) => {
                 validate_select_statement_depth(select)
             }
+            crate::db::sql::parser::SqlExplainTarget::With(with) => {
+                with.selects().try_for_each(validate_select_statement_depth)
+            }
             crate::db::sql::parser::SqlExplainTarget::Delete(delete) => {
                 validate_delete_statement_depth(delete)
             }
@@ -55,16 +68,36 @@ fn validate_delete_statement_depth(statement: &SqlDeleteStatement) -> Result<(),
 
 fn validate_insert_statement_depth(statement: &SqlInsertStatement) -> Result<(), SqlLoweringError> {
     match &statement.source {
-        SqlInsertSource::Values(_) | SqlInsertSource::DefaultValues => Ok(()),
-        SqlInsertSource::Select(select) => validate_select_statement_depth(select),
+        SqlInsertSource::Values(_) | SqlInsertSource::DefaultValues => {}
+        SqlInsertSource::Select(select) => validate_select_statement_depth(select)?,
     }
+    if let Some(SqlInsertOnConflict {
+        action: SqlInsertConflictAction::DoUpdate(assignments),
+        ..
+    }) = &statement.on_conflict
+    {
+        validate_assignments_depth(assignments)?;
+    }
+
+    Ok(())
 }
 
 fn validate_update_statement_depth(statement: &SqlUpdateStatement) -> Result<(), SqlLoweringError> {
+    validate_assignments_depth(statement.assignments.as_slice())?;
     validate_optional_expr_depth(statement.predicate.as_ref())?;
     validate_order_terms_depth(statement.order_by.as_slice())
 }
 
+fn validate_assignments_depth(assignments: &[SqlAssignment]) -> Result<(), SqlLoweringError> {
+    for assignment in assignments {
+        if let SqlWriteValue::Expr(expr) = &assignment.value {
+            validate_expr_depth(expr, 1)?;
+        }
+    }
+
+    Ok(())
+}
+
 fn validate_projection_depth(projection: &SqlProjection) -> Result<(), SqlLoweringError> {
     let SqlProjection::Items(items) = projection else {
         return Ok(());
@@ -137,6 +170,11 @@ fn validate_expr_stack(mut stack: Vec<(&SqlExpr, usize)>) -> Result<(), SqlLower
             | SqlExpr::NullTest { expr, .. }
             | SqlExpr::Like { expr, .. }
             | SqlExpr::Unary { expr, .. } => stack.push((expr, child_depth)),
+            SqlExpr::SemiJoin { probe, .. } => {
+                if let Some(probe) = probe.as_ref() {
+                    stack.push((probe, child_depth));
+                }
+            }
             SqlExpr::FunctionCall { args, .. } => {
                 for arg in args {
                     stack.push((arg, child_depth));
@@ -3,13 +3,20 @@ use crate::{
     db::{
         query::{
             builder::NumericProjectionExpr,
-            plan::expr::{BinaryOp, CaseWhenArm, Expr, FieldId, FieldPath, Function, UnaryOp},
+            plan::expr::{
+                BinaryOp, CaseWhenArm, Expr, FieldId, FieldPath, Function, TextPattern,
+                TextPatternSyntax, UnaryOp, canonicalize_like_pattern,
+                collect_scalar_expr_field_roots,
+            },
         },
+        schema::SchemaInfo,
         sql::parser::{SqlExpr, SqlExprBinaryOp, SqlExprUnaryOp, SqlScalarFunction},
+        text_search::TextMatchQuery,
     },
     value::Value,
 };
-use icydb_diagnostic_code::SqlFeatureCode;
+use icydb_diagnostic_code::{QueryFieldRole, SqlFeatureCode};
+use std::collections::BTreeSet;
 
 ///
 /// SqlExprPhase
@@ -67,9 +74,17 @@ pub(in crate::db::sql::lowering) fn lower_sql_expr(
         SqlExpr::Like {
             expr,
             pattern,
+            escape,
             negated,
             casefold,
-        } => lower_sql_like_expr(expr.as_ref(), pattern.as_str(), *negated, *casefold, phase),
+        } => lower_sql_like_expr(
+            expr.as_ref(),
+            pattern.as_str(),
+            *escape,
+            *negated,
+            *casefold,
+            phase,
+        ),
         SqlExpr::FunctionCall { function, args } => lower_sql_function_call(*function, args, phase),
         SqlExpr::Unary { op, expr } => Ok(Expr::Unary {
             op: lower_sql_unary_op(*op),
@@ -93,9 +108,40 @@ pub(in crate::db::sql::lowering) fn lower_sql_expr(
                 None => Expr::Literal(Value::Null),
             }),
         }),
+        SqlExpr::SemiJoin { .. } => Err(SqlLoweringError::unsupported_semi_join(
+            super::SqlSemiJoinReason::Placement,
+        )),
     }
 }
 
+/// Lower one SQL write-position scalar expression into the planner expression
+/// family evaluated against the selected pre-image row.
+///
+/// Write values are row-local scalar expressions: aggregates and unbound
+/// parameters fail closed exactly as they do in scalar projections, and every
+/// referenced field root must exist in the accepted schema.
+pub(in crate::db) fn lower_sql_write_value_expr(
+    expr: &SqlExpr,
+    schema: &SchemaInfo,
+) -> Result<Expr, SqlLoweringError> {
+    let lowered = lower_sql_expr(expr, SqlExprPhase::Scalar)?;
+    let mut roots = BTreeSet::new();
+    if !collect_scalar_expr_field_roots(&lowered, &mut roots) {
+        return Err(SqlLoweringError::unsupported_select_projection());
+    }
+    if let Some(unknown) = roots
+        .into_iter()
+        .find(|root| schema.field_slot_index(root).is_none())
+    {
+        return Err(SqlLoweringError::unknown_field(
+            QueryFieldRole::Projection,
+            unknown,
+        ));
+    }
+
+    Ok(lowered)
+}
+
 // Lower one parser-owned membership surface onto the existing boolean compare
 // expression family so later WHERE compilation can still reuse the shipped
 // normalized predicate path.
@@ -126,24 +172,38 @@ fn lower_sql_membership_expr(
     }
 }
 
+// Lower one SQL LIKE/ILIKE. Pure prefix patterns keep the plain `STARTS_WITH`
+// shape; any other pattern becomes one bounded residual match, guarded by the
+// pattern's literal prefix so index prefix ranges still apply.
 fn lower_sql_like_expr(
     expr: &SqlExpr,
     pattern: &str,
+    escape: Option<char>,
     negated: bool,
     casefold: bool,
     phase: SqlExprPhase,
 ) -> Result<Expr, SqlLoweringError> {
-    let Some(prefix) = sql_like_prefix_from_pattern(pattern) else {
-        return Err(crate::db::sql_shared::SqlParseError::unsupported_feature(
-            SqlFeatureCode::LikePatternBeyondTrailingPrefix,
-        )
-        .into());
-    };
-
+    let mut canonical = canonicalize_like_pattern(pattern, escape)
+        .map_err(|_| text_pattern_beyond_matcher_bounds())?;
+    if casefold {
+        canonical = canonical.to_lowercase();
+    }
+    let compiled = TextPattern::compile(TextPatternSyntax::Like, &canonical)
+        .map_err(|_| text_pattern_beyond_matcher_bounds())?;
     let target = lower_sql_like_target_expr(expr, casefold, phase)?;
-    let expr = Expr::FunctionCall {
-        function: Function::StartsWith,
-        args: vec![target, Expr::Literal(Value::Text(prefix.to_string()))],
+
+    let expr = if compiled.is_prefix_only() {
+        text_prefix_expr(target, compiled.literal_prefix())
+    } else {
+        let like = Expr::FunctionCall {
+            function: Function::Like,
+            args: vec![target.clone(), Expr::Literal(Value::Text(canonical))],
+        };
+        if negated {
+            like
+        } else {
+            guard_with_literal_prefix(target, compiled.literal_prefix(), like)
+        }
     };
 
     Ok(if negated {
@@ -156,17 +216,86 @@ fn lower_sql_like_expr(
     })
 }
 
-fn sql_like_prefix_from_pattern(pattern: &str) -> Option<&str> {
-    if !pattern.ends_with('%') {
-        return None;
+// Lower `REGEXP_MATCHES(target, 'pattern')`, the shared shape behind `~` and
+// `!~`. Only literal patterns are admitted so the matcher bounds are checked
+// once here instead of per row.
+fn lower_sql_regexp_matches_call(
+    args: &[SqlExpr],
+    phase: SqlExprPhase,
+) -> Result<Expr, SqlLoweringError> {
+    let [target, SqlExpr::Literal(Value::Text(pattern))] = args else {
+        return Err(text_pattern_beyond_matcher_bounds());
+    };
+    let compiled = TextPattern::compile(TextPatternSyntax::Regex, pattern)
+        .map_err(|_| text_pattern_beyond_matcher_bounds())?;
+    let target = lower_sql_expr(target, phase)?;
+
+    if compiled.is_prefix_only() {
+        return Ok(text_prefix_expr(target, compiled.literal_prefix()));
+    }
+    let regex = Expr::FunctionCall {
+        function: Function::RegexpMatches,
+        args: vec![target.clone(), Expr::Literal(Value::Text(pattern.clone()))],
+    };
+
+    Ok(guard_with_literal_prefix(
+        target,
+        compiled.literal_prefix(),
+        regex,
+    ))
+}
+
+// Lower `MATCH(target, 'terms')`. The query must be one literal that parses
+// under the full-text grammar so malformed searches fail at lowering time.
+fn lower_sql_text_match_call(
+    args: &[SqlExpr],
+    phase: SqlExprPhase,
+) -> Result<Expr, SqlLoweringError> {
+    let [target, SqlExpr::Literal(Value::Text(query))] = args else {
+        return Err(text_match_query_shape());
+    };
+    TextMatchQuery::parse(query).map_err(|_| text_match_query_shape())?;
+
+    Ok(Expr::FunctionCall {
+        function: Function::Match,
+        args: vec![
+            lower_sql_expr(target, phase)?,
+            Expr::Literal(Value::Text(query.clone())),
+        ],
+    })
+}
+
+fn text_match_query_shape() -> SqlLoweringError {
+    crate::db::sql_shared::SqlParseError::unsupported_feature(SqlFeatureCode::TextMatchQueryShape)
+        .into()
+}
+
+fn text_prefix_expr(target: Expr, prefix: &str) -> Expr {
+    Expr::FunctionCall {
+        function: Function::StartsWith,
+        args: vec![target, Expr::Literal(Value::Text(prefix.to_string()))],
+    }
+}
+
+// AND one residual pattern match behind its literal-prefix bound. The bound
+// is implied by the match itself, so it only narrows access, never results.
+fn guard_with_literal_prefix(target: Expr, prefix: &str, matched: Expr) -> Expr {
+    if prefix.is_empty() {
+        return matched;
     }
 
-    let prefix = &pattern[..pattern.len() - 1];
-    if prefix.contains('%') || prefix.contains('_') {
-        return None;
+    Expr::Binary {
+        op: BinaryOp::And,
+        left: Box::new(text_prefix_expr(target, prefix)),
+        right: Box::new(matched),
     }
+}
 
-    Some(prefix)
+fn text_pattern_beyond_matcher_bounds() -> SqlLoweringError {
+    crate::db::sql_shared::SqlParseError::unsupported_feature(
+        SqlFeatureCode::TextPatternBeyondMatcherBounds,
+    )
+    .into()
 }
 
 fn lower_sql_like_target_expr(
@@ -196,14 +325,64 @@ fn lower_sql_binary_expr(
     {
         return Ok(expr);
     }
+    let left = lower_sql_expr(left, phase)?;
+    let right = lower_sql_expr(right, phase)?;
+    let Some(planner_op) = lower_sql_binary_op(op) else {
+        let not_distinct = lower_sql_not_distinct_expr(left, right);
+
+        return Ok(if op == SqlExprBinaryOp::IsDistinctFrom {
+            Expr::Unary {
+                op: UnaryOp::Not,
+                expr: Box::new(not_distinct),
+            }
+        } else {
+            not_distinct
+        });
+    };
 
     Ok(Expr::Binary {
-        op: lower_sql_binary_op(op),
-        left: Box::new(lower_sql_expr(left, phase)?),
-        right: Box::new(lower_sql_expr(right, phase)?),
+        op: planner_op,
+        left: Box::new(left),
+        right: Box::new(right),
     })
 }
 
+// Expand null-safe equality onto the ordinary null-test and compare family:
+// `(a IS NULL AND b IS NULL) OR (a IS NOT NULL AND b IS NOT NULL AND a = b)`.
+// The result is never `NULL`, and constant folding reduces a literal side to
+// one null test or one `a IS NOT NULL AND a = literal` conjunction, so
+// predicate normalization and index pushdown see their usual leaves.
+fn lower_sql_not_distinct_expr(left: Expr, right: Expr) -> Expr {
+    let null_test = |function, expr: &Expr| Expr::FunctionCall {
+        function,
+        args: vec![expr.clone()],
+    };
+    let and = |left, right| Expr::Binary {
+        op: BinaryOp::And,
+        left: Box::new(left),
+        right: Box::new(right),
+    };
+    let both_null = and(
+        null_test(Function::IsNull, &left),
+        null_test(Function::IsNull, &right),
+    );
+    let both_present = and(
+        null_test(Function::IsNotNull, &left),
+        null_test(Function::IsNotNull, &right),
+    );
+    let equal = Expr::Binary {
+        op: BinaryOp::Eq,
+        left: Box::new(left),
+        right: Box::new(right),
+    };
+
+    Expr::Binary {
+        op: BinaryOp::Or,
+        left: Box::new(both_null),
+        right: Box::new(and(both_present, equal)),
+    }
+}
+
 fn lower_field_literal_numeric_expr(
     op: SqlExprBinaryOp,
     field: &str,
@@ -230,6 +409,8 @@ fn lower_field_literal_numeric_expr(
         | SqlExprBinaryOp::And
         | SqlExprBinaryOp::Eq
         | SqlExprBinaryOp::Ne
+        | SqlExprBinaryOp::IsDistinctFrom
+        | SqlExprBinaryOp::IsNotDistinctFrom
         | SqlExprBinaryOp::Lt
         | SqlExprBinaryOp::Lte
         | SqlExprBinaryOp::Gt
@@ -262,8 +443,12 @@ const fn lower_sql_unary_op(op: SqlExprUnaryOp) -> UnaryOp {
     }
 }
 
-pub(in crate::db::sql::lowering) const fn lower_sql_binary_op(op: SqlExprBinaryOp) -> BinaryOp {
-    match op {
+// Map one SQL binary operator onto its planner operator. The null-safe
+// comparisons have no single planner operator and return `None`.
+pub(in crate::db::sql::lowering) const fn lower_sql_binary_op(
+    op: SqlExprBinaryOp,
+) -> Option<BinaryOp> {
+    Some(match op {
         SqlExprBinaryOp::Or => BinaryOp::Or,
         SqlExprBinaryOp::And => BinaryOp::And,
         SqlExprBinaryOp::Eq => BinaryOp::Eq,
@@ -276,7 +461,8 @@ pub(in crate::db::sql::lowering) const fn lower_sql_binary_op(op: SqlExprBinaryO
         SqlExprBinaryOp::Sub => BinaryOp::Sub,
         SqlExprBinaryOp::Mul => BinaryOp::Mul,
         SqlExprBinaryOp::Div => BinaryOp::Div,
-    }
+        SqlExprBinaryOp::IsDistinctFrom | SqlExprBinaryOp::IsNotDistinctFrom => return None,
+    })
 }
 
 fn lower_sql_function_call(
@@ -287,6 +473,12 @@ fn lower_sql_function_call(
     if function.uses_numeric_scale_special_case() {
         return lower_sql_numeric_scale_function_call(function, args, phase);
     }
+    if matches!(function, SqlScalarFunction::RegexpMatches) {
+        return lower_sql_regexp_matches_call(args, phase);
+    }
+    if matches!(function, SqlScalarFunction::Match) {
+        return lower_sql_text_match_call(args, phase);
+    }
 
     let function = function.planner_function();
     let args = args
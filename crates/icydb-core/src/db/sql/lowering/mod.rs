@@ -9,9 +9,14 @@ mod analysis;
 mod ast_depth;
 mod expr;
 mod normalize;
+mod parameters;
 mod predicate;
 mod prepare;
+mod relation_path;
 mod select;
+mod semi_join;
+mod union;
+mod with;
 
 ///
 /// TESTS
@@ -37,6 +42,80 @@ pub(crate) enum SqlParameterPlacementReason {
     UnboundExpressionLowering,
 }
 
+///
+/// SqlParameterBindingReason
+///
+/// Compact reason for bind-value sets that do not match the placeholder
+/// slots of one parameterized SQL statement.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum SqlParameterBindingReason {
+    MissingValue,
+    UnusedSlot,
+    ExtraValue,
+    EnumValueUnsupported,
+}
+
+///
+/// SqlSemiJoinReason
+///
+/// Compact reason for `IN (SELECT ...)` and `EXISTS (SELECT ...)` shapes the
+/// bounded semi-join lane does not admit.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum SqlSemiJoinReason {
+    Placement,
+    SourceProjection,
+    SourceAggregation,
+    NestedSubquery,
+}
+
+///
+/// SqlRelationPathReason
+///
+/// Compact reason for `relation.field` projection shapes the relation
+/// traversal lane does not admit.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum SqlRelationPathReason {
+    Placement,
+    NestedPath,
+    CompositeTargetKey,
+}
+
+///
+/// SqlUnionReason
+///
+/// Compact reason for `UNION [ALL]` shapes the bounded branch-merge lane does
+/// not admit.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum SqlUnionReason {
+    EntityMismatch,
+    BranchShape,
+    ProjectionWidth,
+    OrderTerm,
+}
+
+///
+/// SqlCteReason
+///
+/// Compact reason for `WITH` shapes the common-table-expression inlining lane
+/// does not admit.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum SqlCteReason {
+    DuplicateName,
+    Unreferenced,
+    OuterShape,
+    ColumnReference,
+}
+
 #[cfg(feature = "sql")]
 pub(in crate::db::sql::lowering) use aggregate::LoweredSqlGlobalAggregateCommand;
 pub(crate) use aggregate::SqlGlobalAggregateCommand;
@@ -49,6 +128,8 @@ pub(crate) use aggregate::{
 pub(in crate::db::sql::lowering) use analysis::{
     AnalyzedLoweredExpr, LoweredExprAnalysis, LoweredExprSourceRef, analyze_lowered_expr,
 };
+pub(in crate::db) use expr::lower_sql_write_value_expr;
+pub(in crate::db) use parameters::{bind_sql_statement_parameters, sql_statement_parameter_count};
 pub(in crate::db) use prepare::bind_sql_select_statement_structural_with_schema;
 #[cfg(feature = "sql")]
 pub(crate) use prepare::lower_sql_explain_command_from_prepared_statement_with_schema;
@@ -57,6 +138,7 @@ pub(crate) use prepare::{
     lower_prepared_sql_delete_statement, lower_prepared_sql_select_statement_with_schema,
     prepare_sql_statement,
 };
+pub(in crate::db) use relation_path::{SqlRelationPath, extract_prepared_sql_relation_paths};
 pub(crate) use select::LoweredDeleteShape;
 pub(in crate::db::sql::lowering) use select::LoweredSqlFilter;
 pub(in crate::db::sql::lowering) use select::apply_lowered_base_query_shape_with_schema;
@@ -70,6 +152,13 @@ pub(in crate::db) use select::{
     bind_sql_delete_statement_structural_with_schema,
     bind_sql_update_selector_query_structural_with_schema,
 };
+pub(in crate::db) use semi_join::{resolve_sql_statement_semi_joins, sql_statement_has_semi_join};
+pub(in crate::db) use union::lower_sql_union_statement;
+#[cfg(feature = "sql")]
+pub(crate) use with::{
+    SqlCommonTableExpressionMaterialization, SqlCommonTableExpressionSummary,
+    sql_with_statement_common_table_expressions,
+};
 
 ///
 /// LoweredSqlCommand
@@ -78,9 +167,14 @@ pub(in crate::db) use select::{
 /// normalization.
 /// This keeps statement-shape lowering shared across entities before accepted
 /// schema binding happens at the execution boundary.
+/// EXPLAIN commands also carry the common table expressions their query
+/// inlined, so execution rendering can report each one as its own node.
 ///
 #[derive(Clone, Debug)]
-pub struct LoweredSqlCommand(pub(in crate::db::sql::lowering) LoweredSqlCommandInner);
+pub struct LoweredSqlCommand(
+    pub(in crate::db::sql::lowering) LoweredSqlCommandInner,
+    #[cfg(feature = "sql")] pub(in crate::db::sql::lowering) Vec<SqlCommonTableExpressionSummary>,
+);
 
 #[derive(Clone, Debug)]
 pub(in crate::db::sql::lowering) enum LoweredSqlCommandInner {
@@ -121,6 +215,26 @@ impl LoweredSqlCommand {
             LoweredSqlCommandInner::ExplainGlobalAggregate { .. } => None,
         }
     }
+
+    /// Attach the common table expressions one EXPLAIN target inlined.
+    #[cfg(feature = "sql")]
+    #[must_use]
+    pub(in crate::db) fn with_common_table_expressions(
+        mut self,
+        common_table_expressions: Vec<SqlCommonTableExpressionSummary>,
+    ) -> Self {
+        self.1 = common_table_expressions;
+        self
+    }
+
+    /// Borrow the common table expressions this EXPLAIN target inlined.
+    #[cfg(feature = "sql")]
+    #[must_use]
+    pub(in crate::db) const fn common_table_expressions(
+        &self,
+    ) -> &[SqlCommonTableExpressionSummary] {
+        self.1.as_slice()
+    }
 }
 
 ///
@@ -194,6 +308,29 @@ pub(crate) enum SqlLoweringError {
         reason: SqlParameterPlacementReason,
     },
 
+    ParameterBinding {
+        index: usize,
+        reason: SqlParameterBindingReason,
+    },
+
+    UnsupportedSemiJoin {
+        reason: SqlSemiJoinReason,
+    },
+
+    UnsupportedRelationPath {
+        reason: SqlRelationPathReason,
+    },
+
+    UnsupportedLimitPer,
+
+    UnsupportedUnion {
+        reason: SqlUnionReason,
+    },
+
+    UnsupportedCte {
+        reason: SqlCteReason,
+    },
+
     UnsupportedSqlDdl,
 
     #[cfg(feature = "sql")]
@@ -210,7 +347,10 @@ impl SqlLoweringError {
             }
             Self::UnsupportedParameterPlacement {
                 index: Some(index), ..
-            } => vec![(DiagnosticFactTag::ParameterIndex, *index as u64)],
+            }
+            | Self::ParameterBinding { index, .. } => {
+                vec![(DiagnosticFactTag::ParameterIndex, *index as u64)]
+            }
             Self::Parse(_)
             | Self::Query(_)
             | Self::EntityMismatch { .. }
@@ -228,6 +368,11 @@ impl SqlLoweringError {
             | Self::UnsupportedWhereExpression
             | Self::UnknownField { .. }
             | Self::UnsupportedParameterPlacement { index: None, .. }
+            | Self::UnsupportedSemiJoin { .. }
+            | Self::UnsupportedRelationPath { .. }
+            | Self::UnsupportedLimitPer
+            | Self::UnsupportedUnion { .. }
+            | Self::UnsupportedCte { .. }
             | Self::UnsupportedSqlDdl => Vec::new(),
             #[cfg(feature = "sql")]
             Self::UnexpectedQueryLaneStatement => Vec::new(),
@@ -334,6 +479,36 @@ impl SqlLoweringError {
         Self::UnsupportedParameterPlacement { index, reason }
     }
 
+    /// Construct one parameter bind-value mismatch SQL lowering error.
+    pub(crate) const fn parameter_binding(index: usize, reason: SqlParameterBindingReason) -> Self {
+        Self::ParameterBinding { index, reason }
+    }
+
+    /// Construct one unsupported subquery semi-join SQL lowering error.
+    pub(crate) const fn unsupported_semi_join(reason: SqlSemiJoinReason) -> Self {
+        Self::UnsupportedSemiJoin { reason }
+    }
+
+    /// Construct one unsupported relation-path SQL lowering error.
+    pub(crate) const fn unsupported_relation_path(reason: SqlRelationPathReason) -> Self {
+        Self::UnsupportedRelationPath { reason }
+    }
+
+    /// Construct one unsupported `LIMIT ... PER` placement SQL lowering error.
+    const fn unsupported_limit_per() -> Self {
+        Self::UnsupportedLimitPer
+    }
+
+    /// Construct one unsupported `UNION [ALL]` shape SQL lowering error.
+    pub(crate) const fn unsupported_union(reason: SqlUnionReason) -> Self {
+        Self::UnsupportedUnion { reason }
+    }
+
+    /// Construct one unsupported `WITH` shape SQL lowering error.
+    pub(crate) const fn unsupported_cte(reason: SqlCteReason) -> Self {
+        Self::UnsupportedCte { reason }
+    }
+
     /// Construct one unsupported SQL DDL lowering error.
     pub(crate) const fn unsupported_sql_ddl() -> Self {
         Self::UnsupportedSqlDdl
@@ -386,6 +561,27 @@ impl SqlLoweringError {
                 let _ = (index, reason);
                 Some(SqlLoweringCode::ParameterPlacement)
             }
+            Self::ParameterBinding { index, reason } => {
+                let _ = (index, reason);
+                Some(SqlLoweringCode::ParameterBinding)
+            }
+            Self::UnsupportedSemiJoin { reason } => {
+                let _ = reason;
+                Some(SqlLoweringCode::SemiJoinShape)
+            }
+            Self::UnsupportedRelationPath { reason } => {
+                let _ = reason;
+                Some(SqlLoweringCode::RelationPathShape)
+            }
+            Self::UnsupportedLimitPer => Some(SqlLoweringCode::LimitPerShape),
+            Self::UnsupportedUnion { reason } => {
+                let _ = reason;
+                Some(SqlLoweringCode::UnionShape)
+            }
+            Self::UnsupportedCte { reason } => {
+                let _ = reason;
+                Some(SqlLoweringCode::CteShape)
+            }
             Self::UnsupportedSqlDdl => Some(SqlLoweringCode::SqlDdlExecutionUnsupported),
             Self::Parse(_) | Self::Query(_) | Self::UnknownField { .. } => None,
             #[cfg(feature = "sql")]
@@ -436,8 +632,14 @@ impl PreparedSqlStatement {
 
 #[cfg(all(test, feature = "sql"))]
 mod tests {
-    use super::{SqlLoweringError, SqlParameterPlacementReason};
-    use crate::db::QueryError;
+    use super::{
+        SqlLoweringError, SqlParameterBindingReason, SqlParameterPlacementReason,
+        bind_sql_statement_parameters, sql_statement_parameter_count,
+    };
+    use crate::{
+        db::{QueryError, sql::parser::parse_sql},
+        value::Value,
+    };
     use icydb_diagnostic_code::DiagnosticFactTag;
 
     #[test]
@@ -472,4 +674,29 @@ mod tests {
         );
         assert!(unknown.diagnostic_facts().is_empty());
     }
+
+    #[test]
+    fn parameter_binding_reports_missing_and_extra_values_separately() {
+        let statement =
+            parse_sql("SELECT id FROM Row WHERE id = $1").expect("parameterized SQL should parse");
+        let count = sql_statement_parameter_count(&statement).expect("slots should be dense");
+
+        for (values, index, expected) in [
+            (vec![], 0, SqlParameterBindingReason::MissingValue),
+            (
+                vec![Value::Nat64(1), Value::Nat64(2)],
+                1,
+                SqlParameterBindingReason::ExtraValue,
+            ),
+        ] {
+            let Err(SqlLoweringError::ParameterBinding {
+                index: actual_index,
+                reason,
+            }) = bind_sql_statement_parameters(&statement, count, values.as_slice())
+            else {
+                panic!("mismatched bind values should fail binding");
+            };
+            assert_eq!((actual_index, reason), (index, expected));
+        }
+    }
 }
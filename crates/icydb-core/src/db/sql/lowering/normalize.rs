@@ -2,8 +2,10 @@ use crate::db::sql::{
     identifier::{identifier_last_segment, identifiers_tail_match, normalize_identifier_to_scope},
     lowering::SqlLoweringError,
     parser::{
-        SqlAggregateCall, SqlAssignment, SqlDeleteStatement, SqlExpr, SqlOrderTerm, SqlProjection,
+        SqlAggregateArguments, SqlAggregateCall, SqlAssignment, SqlDeleteStatement, SqlExpr,
+        SqlInsertConflictAction, SqlInsertOnConflict, SqlOrderTerm, SqlProjection,
         SqlReturningProjection, SqlSelectItem, SqlSelectStatement, SqlUpdateStatement,
+        SqlWriteValue,
     },
 };
 
@@ -27,7 +29,11 @@ pub(in crate::db::sql::lowering) fn normalize_select_statement_to_expected_entit
     );
     statement.projection =
         normalize_projection_identifiers(statement.projection, entity_scope.as_slice());
-    statement.group_by = normalize_identifier_list(statement.group_by, entity_scope.as_slice());
+    statement.group_by = statement
+        .group_by
+        .into_iter()
+        .map(|key| normalize_sql_expr_to_scope(key, entity_scope.as_slice()))
+        .collect();
     statement.predicate = statement.predicate.map(|predicate| {
         adapt_sql_predicate_identifiers_to_scope(predicate, entity_scope.as_slice())
     });
@@ -43,6 +49,7 @@ pub(in crate::db::sql::lowering) fn normalize_select_statement_to_expected_entit
         statement.projection_aliases.as_slice(),
         entity_scope.as_slice(),
     )?;
+    statement.limit_per = normalize_identifier_list(statement.limit_per, entity_scope.as_slice());
     statement.table_alias = None;
 
     Ok(statement)
@@ -91,6 +98,23 @@ pub(in crate::db::sql::lowering) fn normalize_update_statement_to_expected_entit
     statement
 }
 
+pub(in crate::db::sql::lowering) fn normalize_insert_on_conflict_to_expected_entity(
+    mut on_conflict: SqlInsertOnConflict,
+    entity: &str,
+    expected_entity: &str,
+) -> SqlInsertOnConflict {
+    let entity_scope = sql_statement_scope_candidates(entity, expected_entity, None);
+    on_conflict.target = normalize_identifier_list(on_conflict.target, entity_scope.as_slice());
+    on_conflict.action = match on_conflict.action {
+        SqlInsertConflictAction::DoNothing => SqlInsertConflictAction::DoNothing,
+        SqlInsertConflictAction::DoUpdate(assignments) => SqlInsertConflictAction::DoUpdate(
+            normalize_assignments(assignments, entity_scope.as_slice()),
+        ),
+    };
+
+    on_conflict
+}
+
 pub(in crate::db::sql::lowering) fn normalize_having_clauses(
     clauses: Vec<SqlExpr>,
     projection: &SqlProjection,
@@ -239,6 +263,30 @@ impl<'a> SqlIdentifierNormalizer<'a> {
                 .filter_expr
                 .map(|expr| Box::new(self.normalize_sql_expr(*expr))),
             distinct: aggregate.distinct,
+            arguments: aggregate
+                .arguments
+                .map(|arguments| Box::new(self.normalize_aggregate_arguments(*arguments))),
+        }
+    }
+
+    // Retained-value aggregate ORDER BY terms re-scope exactly like top-level
+    // ORDER BY terms; the fraction and separator literals pass through.
+    fn normalize_aggregate_arguments(
+        self,
+        arguments: SqlAggregateArguments,
+    ) -> SqlAggregateArguments {
+        match arguments {
+            SqlAggregateArguments::Percentile { .. } => arguments,
+            SqlAggregateArguments::StringAgg {
+                separator,
+                order_by,
+            } => SqlAggregateArguments::StringAgg {
+                separator,
+                order_by: normalize_order_terms(order_by, self.entity_scope),
+            },
+            SqlAggregateArguments::ArrayAgg { order_by } => SqlAggregateArguments::ArrayAgg {
+                order_by: normalize_order_terms(order_by, self.entity_scope),
+            },
         }
     }
 
@@ -256,6 +304,9 @@ impl<'a> SqlIdentifierNormalizer<'a> {
             }
             SqlExpr::Literal(literal) => SqlExpr::Literal(literal),
             SqlExpr::Param { index } => SqlExpr::Param { index },
+            // Semi-joins are resolved before lowering; any left here are
+            // rejected by expression lowering with their placement reason.
+            semi_join @ SqlExpr::SemiJoin { .. } => semi_join,
             SqlExpr::Membership {
                 expr,
                 values,
@@ -272,11 +323,13 @@ impl<'a> SqlIdentifierNormalizer<'a> {
             SqlExpr::Like {
                 expr,
                 pattern,
+                escape,
                 negated,
                 casefold,
             } => SqlExpr::Like {
                 expr: Box::new(self.normalize_sql_expr(*expr)),
                 pattern,
+                escape,
                 negated,
                 casefold,
             },
@@ -337,7 +390,8 @@ fn normalize_having_aliases(
         SqlExpr::FieldPath { .. }
         | SqlExpr::Aggregate(_)
         | SqlExpr::Literal(_)
-        | SqlExpr::Param { .. } => Ok(expr),
+        | SqlExpr::Param { .. }
+        | SqlExpr::SemiJoin { .. } => Ok(expr),
         SqlExpr::Membership {
             expr,
             values,
@@ -362,6 +416,7 @@ fn normalize_having_aliases(
         SqlExpr::Like {
             expr,
             pattern,
+            escape,
             negated,
             casefold,
         } => Ok(SqlExpr::Like {
@@ -371,6 +426,7 @@ fn normalize_having_aliases(
                 projection_aliases,
             )?),
             pattern,
+            escape,
             negated,
             casefold,
         }),
@@ -448,6 +504,7 @@ fn normalize_select_order_terms(
             Ok(SqlOrderTerm {
                 field: normalize_sql_expr_to_scope(field, entity_scope),
                 direction: term.direction,
+                nulls: term.nulls,
             })
         })
         .collect()
@@ -469,7 +526,8 @@ fn normalize_order_aliases(
         SqlExpr::FieldPath { .. }
         | SqlExpr::Aggregate(_)
         | SqlExpr::Literal(_)
-        | SqlExpr::Param { .. } => expr,
+        | SqlExpr::Param { .. }
+        | SqlExpr::SemiJoin { .. } => expr,
         SqlExpr::Membership {
             expr,
             values,
@@ -494,6 +552,7 @@ fn normalize_order_aliases(
         SqlExpr::Like {
             expr,
             pattern,
+            escape,
             negated,
             casefold,
         } => SqlExpr::Like {
@@ -503,6 +562,7 @@ fn normalize_order_aliases(
                 projection_aliases,
             )),
             pattern,
+            escape,
             negated,
             casefold,
         },
@@ -617,6 +677,7 @@ pub(in crate::db::sql::lowering) fn normalize_order_terms(
         .map(|term| SqlOrderTerm {
             field: normalize_sql_expr_to_scope(term.field, entity_scope),
             direction: term.direction,
+            nulls: term.nulls,
         })
         .collect()
 }
@@ -629,7 +690,12 @@ fn normalize_assignments(
         .into_iter()
         .map(|assignment| SqlAssignment {
             field: normalize_identifier(assignment.field, entity_scope),
-            value: assignment.value,
+            value: match assignment.value {
+                SqlWriteValue::Expr(expr) => {
+                    SqlWriteValue::Expr(normalize_sql_expr_to_scope(expr, entity_scope))
+                }
+                value @ (SqlWriteValue::Literal(_) | SqlWriteValue::Default) => value,
+            },
         })
         .collect()
 }
@@ -51,8 +51,10 @@ fn local_scalar_select_is_already_local_canonical() {
         order_by: vec![SqlOrderTerm {
             field: sql_order_expr("id"),
             direction: SqlOrderDirection::Asc,
+            nulls: None,
         }],
         limit: Some(3),
+        limit_per: Vec::new(),
         offset: None,
     };
 
@@ -76,8 +78,10 @@ fn local_scalar_select_with_supported_order_expr_is_already_local_canonical() {
         order_by: vec![SqlOrderTerm {
             field: sql_order_expr("LOWER(name)"),
             direction: SqlOrderDirection::Asc,
+            nulls: None,
         }],
         limit: Some(3),
+        limit_per: Vec::new(),
         offset: None,
     };
 
@@ -96,18 +100,21 @@ fn local_grouped_select_with_local_aggregate_is_already_local_canonical() {
                 input: None,
                 filter_expr: None,
                 distinct: false,
+                arguments: None,
             }),
         ]),
         projection_aliases: vec![None, None],
         predicate: None,
         distinct: false,
-        group_by: vec!["age".to_string()],
+        group_by: vec![SqlExpr::Field("age".to_string())],
         having: vec![],
         order_by: vec![SqlOrderTerm {
             field: sql_order_expr("age"),
             direction: SqlOrderDirection::Asc,
+            nulls: None,
         }],
         limit: Some(10),
+        limit_per: Vec::new(),
         offset: None,
     };
 
@@ -134,8 +141,10 @@ fn qualified_field_select_is_not_already_local_canonical() {
         order_by: vec![SqlOrderTerm {
             field: sql_order_expr("PerfAuditUser.id"),
             direction: SqlOrderDirection::Asc,
+            nulls: None,
         }],
         limit: Some(1),
+        limit_per: Vec::new(),
         offset: None,
     };
 
@@ -167,6 +176,7 @@ fn predicate_identifier_normalization_preserves_nested_field_paths() {
         having: vec![],
         order_by: vec![],
         limit: None,
+        limit_per: Vec::new(),
         offset: None,
     };
 
@@ -0,0 +1,297 @@
+//! Module: db::sql::lowering::parameters
+//! Responsibility: placeholder slot discovery and bind-value substitution for
+//! parameterized reduced SQL statements.
+//! Does not own: placeholder syntax, public bind-value conversion, or prepared
+//! statement caching.
+//! Boundary: turns one parsed parameterized statement plus runtime bind values
+//! into the literal statement shape the existing prepare/lowering path admits.
+
+#[cfg(feature = "sql")]
+use crate::db::sql::parser::SqlExplainTarget;
+use crate::{
+    db::sql::{
+        lowering::{SqlLoweringError, SqlParameterBindingReason},
+        parser::{
+            SqlAggregateCall, SqlAssignment, SqlDeleteStatement, SqlExpr, SqlInsertConflictAction,
+            SqlInsertSource, SqlInsertStatement, SqlOrderTerm, SqlProjection, SqlSelectItem,
+            SqlSelectStatement, SqlStatement, SqlUpdateStatement, SqlWriteValue,
+        },
+    },
+    value::Value,
+};
+
+/// Count the bind slots referenced by one parsed statement.
+///
+/// Slots must be dense from `$1`: a statement that references `$1` and `$3`
+/// leaves slot `$2` without an owner and is rejected instead of silently
+/// accepting a bind value that can never influence execution.
+pub(in crate::db) fn sql_statement_parameter_count(
+    statement: &SqlStatement,
+) -> Result<usize, SqlLoweringError> {
+    let mut referenced = Vec::<bool>::new();
+    let mut probe = statement.clone();
+    rewrite_statement_parameters(&mut probe, &mut |index| {
+        if referenced.len() <= index {
+            referenced.resize(index.saturating_add(1), false);
+        }
+        referenced[index] = true;
+
+        Ok(SqlExpr::Param { index })
+    })?;
+
+    if let Some(index) = referenced.iter().position(|referenced| !referenced) {
+        return Err(SqlLoweringError::parameter_binding(
+            index,
+            SqlParameterBindingReason::UnusedSlot,
+        ));
+    }
+
+    Ok(referenced.len())
+}
+
+/// Substitute one runtime bind value for every placeholder in one parsed
+/// statement.
+///
+/// Bound values become ordinary SQL literals, so literal coercion, enum text
+/// admission, and shared plan-cache parameter contracts apply exactly as they
+/// would to the same statement written with inline literals.
+pub(in crate::db) fn bind_sql_statement_parameters(
+    statement: &SqlStatement,
+    parameter_count: usize,
+    values: &[Value],
+) -> Result<SqlStatement, SqlLoweringError> {
+    if values.len() < parameter_count {
+        return Err(SqlLoweringError::parameter_binding(
+            values.len(),
+            SqlParameterBindingReason::MissingValue,
+        ));
+    }
+    if values.len() > parameter_count {
+        return Err(SqlLoweringError::parameter_binding(
+            parameter_count,
+            SqlParameterBindingReason::ExtraValue,
+        ));
+    }
+
+    let mut bound = statement.clone();
+    rewrite_statement_parameters(&mut bound, &mut |index| {
+        values
+            .get(index)
+            .cloned()
+            .map(SqlExpr::Literal)
+            .ok_or_else(|| {
+                SqlLoweringError::parameter_binding(index, SqlParameterBindingReason::MissingValue)
+            })
+    })?;
+
+    Ok(bound)
+}
+
+// Rewrite every placeholder in clause order. Statement families without
+// expression positions have no placeholders to visit.
+fn rewrite_statement_parameters(
+    statement: &mut SqlStatement,
+    rewrite: &mut impl FnMut(usize) -> Result<SqlExpr, SqlLoweringError>,
+) -> Result<(), SqlLoweringError> {
+    match statement {
+        SqlStatement::Select(statement) => rewrite_select_parameters(statement, rewrite),
+        SqlStatement::Union(statement) => statement
+            .branches
+            .iter_mut()
+            .try_for_each(|branch| rewrite_select_parameters(branch, rewrite)),
+        SqlStatement::With(statement) => statement
+            .selects_mut()
+            .try_for_each(|select| rewrite_select_parameters(select, rewrite)),
+        SqlStatement::Delete(statement) => rewrite_delete_parameters(statement, rewrite),
+        SqlStatement::Insert(statement) => rewrite_insert_parameters(statement, rewrite),
+        SqlStatement::Update(statement) => rewrite_update_parameters(statement, rewrite),
+        #[cfg(feature = "sql")]
+        SqlStatement::Explain(statement) => match &mut statement.statement {
+            SqlExplainTarget::Select(select) => rewrite_select_parameters(select, rewrite),
+            SqlExplainTarget::With(with) => with
+                .selects_mut()
+                .try_for_each(|select| rewrite_select_parameters(select, rewrite)),
+            SqlExplainTarget::Delete(delete) => rewrite_delete_parameters(delete, rewrite),
+        },
+        SqlStatement::Ddl(_)
+        | SqlStatement::Describe(_)
+        | SqlStatement::ShowConstraints(_)
+        | SqlStatement::ShowIndexes(_)
+        | SqlStatement::ShowColumns(_)
+        | SqlStatement::ShowRelations(_)
+        | SqlStatement::ShowEntities(_)
+        | SqlStatement::ShowStores(_)
+        | SqlStatement::ShowMemory(_) => Ok(()),
+    }
+}
+
+fn rewrite_select_parameters(
+    statement: &mut SqlSelectStatement,
+    rewrite: &mut impl FnMut(usize) -> Result<SqlExpr, SqlLoweringError>,
+) -> Result<(), SqlLoweringError> {
+    if let SqlProjection::Items(items) = &mut statement.projection {
+        for item in items {
+            match item {
+                SqlSelectItem::Field(_) => {}
+                SqlSelectItem::Aggregate(aggregate) => {
+                    rewrite_aggregate_parameters(aggregate, rewrite)?;
+                }
+                SqlSelectItem::Expr(expr) => rewrite_expr_parameters(expr, rewrite)?,
+            }
+        }
+    }
+    if let Some(predicate) = statement.predicate.as_mut() {
+        rewrite_expr_parameters(predicate, rewrite)?;
+    }
+    for expr in &mut statement.group_by {
+        rewrite_expr_parameters(expr, rewrite)?;
+    }
+    for expr in &mut statement.having {
+        rewrite_expr_parameters(expr, rewrite)?;
+    }
+
+    rewrite_order_terms_parameters(statement.order_by.as_mut_slice(), rewrite)
+}
+
+fn rewrite_delete_parameters(
+    statement: &mut SqlDeleteStatement,
+    rewrite: &mut impl FnMut(usize) -> Result<SqlExpr, SqlLoweringError>,
+) -> Result<(), SqlLoweringError> {
+    if let Some(predicate) = statement.predicate.as_mut() {
+        rewrite_expr_parameters(predicate, rewrite)?;
+    }
+
+    rewrite_order_terms_parameters(statement.order_by.as_mut_slice(), rewrite)
+}
+
+fn rewrite_insert_parameters(
+    statement: &mut SqlInsertStatement,
+    rewrite: &mut impl FnMut(usize) -> Result<SqlExpr, SqlLoweringError>,
+) -> Result<(), SqlLoweringError> {
+    match &mut statement.source {
+        SqlInsertSource::Values(rows) => {
+            for value in rows.iter_mut().flatten() {
+                rewrite_write_value_parameters(value, rewrite)?;
+            }
+        }
+        SqlInsertSource::DefaultValues => {}
+        SqlInsertSource::Select(select) => rewrite_select_parameters(select, rewrite)?,
+    }
+    if let Some(on_conflict) = statement.on_conflict.as_mut()
+        && let SqlInsertConflictAction::DoUpdate(assignments) = &mut on_conflict.action
+    {
+        rewrite_assignments_parameters(assignments, rewrite)?;
+    }
+
+    Ok(())
+}
+
+fn rewrite_update_parameters(
+    statement: &mut SqlUpdateStatement,
+    rewrite: &mut impl FnMut(usize) -> Result<SqlExpr, SqlLoweringError>,
+) -> Result<(), SqlLoweringError> {
+    rewrite_assignments_parameters(statement.assignments.as_mut_slice(), rewrite)?;
+    if let Some(predicate) = statement.predicate.as_mut() {
+        rewrite_expr_parameters(predicate, rewrite)?;
+    }
+
+    rewrite_order_terms_parameters(statement.order_by.as_mut_slice(), rewrite)
+}
+
+fn rewrite_assignments_parameters(
+    assignments: &mut [SqlAssignment],
+    rewrite: &mut impl FnMut(usize) -> Result<SqlExpr, SqlLoweringError>,
+) -> Result<(), SqlLoweringError> {
+    for assignment in assignments {
+        rewrite_write_value_parameters(&mut assignment.value, rewrite)?;
+    }
+
+    Ok(())
+}
+
+// Keep bound write values in the same `Literal` form the parser produces for
+// inline literals so write lanes cannot observe how a value arrived.
+fn rewrite_write_value_parameters(
+    value: &mut SqlWriteValue,
+    rewrite: &mut impl FnMut(usize) -> Result<SqlExpr, SqlLoweringError>,
+) -> Result<(), SqlLoweringError> {
+    let SqlWriteValue::Expr(expr) = value else {
+        return Ok(());
+    };
+    rewrite_expr_parameters(expr, rewrite)?;
+    if let SqlExpr::Literal(literal) = expr {
+        *value = SqlWriteValue::Literal(literal.clone());
+    }
+
+    Ok(())
+}
+
+fn rewrite_order_terms_parameters(
+    order_by: &mut [SqlOrderTerm],
+    rewrite: &mut impl FnMut(usize) -> Result<SqlExpr, SqlLoweringError>,
+) -> Result<(), SqlLoweringError> {
+    for term in order_by {
+        rewrite_expr_parameters(&mut term.field, rewrite)?;
+    }
+
+    Ok(())
+}
+
+fn rewrite_aggregate_parameters(
+    aggregate: &mut SqlAggregateCall,
+    rewrite: &mut impl FnMut(usize) -> Result<SqlExpr, SqlLoweringError>,
+) -> Result<(), SqlLoweringError> {
+    if let Some(input) = aggregate.input.as_deref_mut() {
+        rewrite_expr_parameters(input, rewrite)?;
+    }
+    if let Some(filter_expr) = aggregate.filter_expr.as_deref_mut() {
+        rewrite_expr_parameters(filter_expr, rewrite)?;
+    }
+
+    Ok(())
+}
+
+// Walk one expression tree, including aggregate leaves, and replace each
+// placeholder node with the expression produced for its slot.
+fn rewrite_expr_parameters(
+    expr: &mut SqlExpr,
+    rewrite: &mut impl FnMut(usize) -> Result<SqlExpr, SqlLoweringError>,
+) -> Result<(), SqlLoweringError> {
+    match expr {
+        SqlExpr::Param { index } => {
+            *expr = rewrite(*index)?;
+        }
+        SqlExpr::Field(_) | SqlExpr::FieldPath { .. } | SqlExpr::Literal(_) => {}
+        SqlExpr::Aggregate(aggregate) => rewrite_aggregate_parameters(aggregate, rewrite)?,
+        SqlExpr::Membership { expr, .. }
+        | SqlExpr::NullTest { expr, .. }
+        | SqlExpr::Like { expr, .. }
+        | SqlExpr::Unary { expr, .. } => rewrite_expr_parameters(expr, rewrite)?,
+        SqlExpr::FunctionCall { args, .. } => {
+            for arg in args {
+                rewrite_expr_parameters(arg, rewrite)?;
+            }
+        }
+        SqlExpr::Binary { left, right, .. } => {
+            rewrite_expr_parameters(left, rewrite)?;
+            rewrite_expr_parameters(right, rewrite)?;
+        }
+        SqlExpr::Case { arms, else_expr } => {
+            for arm in arms {
+                rewrite_expr_parameters(&mut arm.condition, rewrite)?;
+                rewrite_expr_parameters(&mut arm.result, rewrite)?;
+            }
+            if let Some(else_expr) = else_expr.as_deref_mut() {
+                rewrite_expr_parameters(else_expr, rewrite)?;
+            }
+        }
+        SqlExpr::SemiJoin { probe, source, .. } => {
+            if let Some(probe) = probe.as_deref_mut() {
+                rewrite_expr_parameters(probe, rewrite)?;
+            }
+            rewrite_select_parameters(source, rewrite)?;
+        }
+    }
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use crate::{
     db::{
-        predicate::{CompareOp, Predicate},
+        predicate::{CoercionId, CompareOp, ComparePredicate, Predicate},
         query::plan::expr::{
             BinaryOp, Expr, FieldId, Function, UnaryOp, compile_normalized_bool_expr_to_predicate,
             derive_normalized_bool_expr_predicate_subset,
@@ -60,6 +60,61 @@ fn lower_sql_where_bool_expr_preserves_upper_prefix_semantics() {
     assert_eq!(right, &Expr::Literal(Value::Text("AL".to_string())));
 }
 
+#[test]
+fn lower_sql_where_bool_expr_guards_general_like_with_its_literal_prefix() {
+    let expr = parse_where_expr("SELECT * FROM users WHERE name LIKE 'al!_%e' ESCAPE '!'");
+
+    let lowered = lower_sql_where_bool_expr(&expr).expect("general LIKE should be admitted");
+    let name = Expr::Field(FieldId::new("name"));
+    assert_eq!(
+        lowered,
+        Expr::Binary {
+            op: BinaryOp::And,
+            left: Box::new(Expr::FunctionCall {
+                function: Function::Like,
+                args: vec![
+                    name.clone(),
+                    Expr::Literal(Value::Text("al\\_%e".to_string()))
+                ],
+            }),
+            right: Box::new(Expr::FunctionCall {
+                function: Function::StartsWith,
+                args: vec![name, Expr::Literal(Value::Text("al_".to_string()))],
+            }),
+        },
+    );
+}
+
+#[test]
+fn lower_sql_where_bool_expr_keeps_regex_prefix_only_patterns_structural() {
+    let prefix_only = parse_where_expr("SELECT * FROM users WHERE name ~ '^al.*'");
+    assert_eq!(
+        lower_sql_where_bool_expr(&prefix_only).expect("prefix regex should be admitted"),
+        Expr::FunctionCall {
+            function: Function::StartsWith,
+            args: vec![
+                Expr::Field(FieldId::new("name")),
+                Expr::Literal(Value::Text("al".to_string())),
+            ],
+        },
+    );
+
+    let negated = parse_where_expr("SELECT * FROM users WHERE name !~ 'a|b'");
+    assert_eq!(
+        lower_sql_where_bool_expr(&negated).expect("negated regex should be admitted"),
+        Expr::Unary {
+            op: UnaryOp::Not,
+            expr: Box::new(Expr::FunctionCall {
+                function: Function::RegexpMatches,
+                args: vec![
+                    Expr::Field(FieldId::new("name")),
+                    Expr::Literal(Value::Text("a|b".to_string())),
+                ],
+            }),
+        },
+    );
+}
+
 #[test]
 fn lower_sql_where_bool_expr_preserves_upper_ordering_semantics() {
     let expr =
@@ -283,6 +338,48 @@ fn derive_where_predicate_subset_recovers_folded_constant_compare_shapes() {
     );
 }
 
+#[test]
+fn derive_where_predicate_subset_recovers_null_safe_distinct_literal_shapes() {
+    let cases = [
+        (
+            "SELECT * FROM users WHERE age IS NOT DISTINCT FROM 21",
+            Predicate::And(vec![
+                Predicate::Compare(ComparePredicate::with_coercion(
+                    "age",
+                    CompareOp::Eq,
+                    Value::Int64(21),
+                    CoercionId::NumericWiden,
+                )),
+                Predicate::IsNotNull {
+                    field: "age".to_string(),
+                },
+            ]),
+        ),
+        (
+            "SELECT * FROM users WHERE age IS NOT DISTINCT FROM NULL",
+            Predicate::IsNull {
+                field: "age".to_string(),
+            },
+        ),
+        (
+            "SELECT * FROM users WHERE age IS DISTINCT FROM NULL",
+            Predicate::IsNotNull {
+                field: "age".to_string(),
+            },
+        ),
+    ];
+
+    for (sql, expected) in cases {
+        let expr = parse_where_expr(sql);
+        let lowered =
+            lower_sql_where_bool_expr(&expr).expect("null-safe distinct WHERE shape should lower");
+        let subset = derive_normalized_bool_expr_predicate_subset(&lowered)
+            .expect("null-safe distinct WHERE shape should recover one predicate subset");
+
+        assert_eq!(subset, expected, "{sql}");
+    }
+}
+
 #[test]
 fn compile_where_bool_expr_requires_normalized_shape() {
     let expr = Expr::Binary {
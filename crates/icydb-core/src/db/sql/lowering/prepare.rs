@@ -10,7 +10,10 @@ use crate::db::sql::{
 };
 use crate::db::{
     MissingRowPolicy, QueryError,
-    query::intent::StructuralQuery,
+    query::{
+        intent::StructuralQuery,
+        plan::expr::{Function, eval_projection_function_call_checked},
+    },
     schema::SchemaInfo,
     sql::{
         lowering::{
@@ -18,18 +21,22 @@ use crate::db::{
             bind_lowered_sql_select_query_structural_with_schema,
             normalize::{
                 ensure_entity_matches_expected, normalize_delete_statement_to_expected_entity,
+                normalize_insert_on_conflict_to_expected_entity,
                 normalize_select_statement_to_expected_entity,
                 normalize_update_statement_to_expected_entity,
             },
             select::{lower_delete_statement_shape, lower_select_shape_with_schema},
+            with::inline_sql_with_statement,
         },
         parser::{
-            SqlAggregateCall, SqlDeleteStatement, SqlExpr, SqlInsertSource, SqlInsertStatement,
-            SqlOrderTerm, SqlProjection, SqlSelectItem, SqlSelectStatement, SqlStatement,
-            SqlUpdateStatement,
+            SqlAggregateCall, SqlAssignment, SqlDeleteStatement, SqlExpr, SqlInsertConflictAction,
+            SqlInsertSource, SqlInsertStatement, SqlOrderTerm, SqlProjection, SqlScalarFunction,
+            SqlSelectItem, SqlSelectStatement, SqlStatement, SqlUnionStatement, SqlUpdateStatement,
+            SqlWriteValue,
         },
     },
 };
+use crate::value::Value;
 use icydb_diagnostic_code::SqlWriteBoundaryCode;
 
 /// Prepare one parsed SQL statement for one expected entity route.
@@ -45,9 +52,9 @@ pub(crate) fn prepare_sql_statement(
     Ok(PreparedSqlStatement { statement })
 }
 
-// Reject placeholders at the prepared-input boundary until a real binding
-// contract exists. This keeps future parameter support focused on replacing
-// this owner instead of hunting for lower-level expression failures.
+// Reject placeholders that reach the prepared-input boundary unbound. Bind
+// values are substituted before prepare, so a surviving placeholder means the
+// statement arrived through a text-only surface without bind values.
 fn validate_prepared_statement_parameters(
     statement: &SqlStatement,
 ) -> Result<(), SqlLoweringError> {
@@ -66,6 +73,11 @@ fn validate_prepared_statement_parameters(
 fn first_statement_parameter_index(statement: &SqlStatement) -> Option<usize> {
     match statement {
         SqlStatement::Select(statement) => first_select_parameter_index(statement),
+        SqlStatement::Union(statement) => statement
+            .branches
+            .iter()
+            .find_map(first_select_parameter_index),
+        SqlStatement::With(statement) => statement.selects().find_map(first_select_parameter_index),
         SqlStatement::Delete(statement) => first_delete_parameter_index(statement),
         SqlStatement::Insert(statement) => first_insert_parameter_index(statement),
         SqlStatement::Update(statement) => first_update_parameter_index(statement),
@@ -114,15 +126,38 @@ fn first_insert_parameter_index(statement: &SqlInsertStatement) -> Option<usize>
         SqlInsertSource::Values(_) | SqlInsertSource::DefaultValues => None,
         SqlInsertSource::Select(select) => first_select_parameter_index(select),
     }
+    .or_else(|| {
+        let SqlInsertConflictAction::DoUpdate(assignments) =
+            &statement.on_conflict.as_ref()?.action
+        else {
+            return None;
+        };
+
+        first_assignments_parameter_index(assignments)
+    })
+}
+
+// Scan one SET assignment list for unsupported placeholders in its
+// expression values.
+fn first_assignments_parameter_index(assignments: &[SqlAssignment]) -> Option<usize> {
+    assignments
+        .iter()
+        .find_map(|assignment| match &assignment.value {
+            SqlWriteValue::Expr(expr) => first_expr_parameter_index(expr),
+            SqlWriteValue::Literal(_) | SqlWriteValue::Default => None,
+        })
 }
 
-// Scan one UPDATE statement for unsupported placeholders in its predicate and
-// ordering clauses. SET values are concrete parsed `Value` payloads today.
+// Scan one UPDATE statement for unsupported placeholders in its SET
+// expressions, predicate, and ordering clauses.
 fn first_update_parameter_index(statement: &SqlUpdateStatement) -> Option<usize> {
-    statement
-        .predicate
-        .as_ref()
-        .and_then(first_expr_parameter_index)
+    first_assignments_parameter_index(statement.assignments.as_slice())
+        .or_else(|| {
+            statement
+                .predicate
+                .as_ref()
+                .and_then(first_expr_parameter_index)
+        })
         .or_else(|| first_order_terms_parameter_index(statement.order_by.as_slice()))
 }
 
@@ -132,6 +167,7 @@ fn first_update_parameter_index(statement: &SqlUpdateStatement) -> Option<usize>
 fn first_explain_parameter_index(statement: &SqlExplainStatement) -> Option<usize> {
     match &statement.statement {
         SqlExplainTarget::Select(select) => first_select_parameter_index(select),
+        SqlExplainTarget::With(with) => with.selects().find_map(first_select_parameter_index),
         SqlExplainTarget::Delete(delete) => first_delete_parameter_index(delete),
     }
 }
@@ -155,7 +191,8 @@ fn first_select_item_parameter_index(item: &SqlSelectItem) -> Option<usize> {
     }
 }
 
-// Scan one aggregate call for placeholders in its input or FILTER expression.
+// Scan one aggregate call for placeholders in its input, FILTER expression,
+// or aggregate-local ORDER BY terms.
 fn first_aggregate_parameter_index(aggregate: &SqlAggregateCall) -> Option<usize> {
     aggregate
         .input
@@ -167,6 +204,12 @@ fn first_aggregate_parameter_index(aggregate: &SqlAggregateCall) -> Option<usize
                 .as_deref()
                 .and_then(first_expr_parameter_index)
         })
+        .or_else(|| {
+            aggregate
+                .arguments
+                .as_deref()
+                .and_then(|arguments| first_order_terms_parameter_index(arguments.order_by()))
+        })
 }
 
 // Scan ORDER BY expression terms for unsupported placeholders.
@@ -280,6 +323,20 @@ fn prepare_statement(
             statement.clone(),
             expected_entity,
         )?)),
+        SqlStatement::Union(statement) => Ok(SqlStatement::Union(SqlUnionStatement {
+            branches: statement
+                .branches
+                .iter()
+                .map(|branch| prepare_select_statement(branch.clone(), expected_entity))
+                .collect::<Result<_, _>>()?,
+            ..statement.clone()
+        })),
+        // Common table expressions inline into one ordinary SELECT here, so
+        // every later lowering stage only ever sees the base entity.
+        SqlStatement::With(statement) => Ok(SqlStatement::Select(prepare_select_statement(
+            inline_sql_with_statement(statement)?,
+            expected_entity,
+        )?)),
         SqlStatement::Delete(statement) => Ok(SqlStatement::Delete(prepare_delete_statement(
             statement.clone(),
             expected_entity,
@@ -338,6 +395,9 @@ fn prepare_explain_statement(
         SqlExplainTarget::Select(select_statement) => {
             SqlExplainTarget::Select(prepare_select_statement(select_statement, expected_entity)?)
         }
+        SqlExplainTarget::With(with_statement) => SqlExplainTarget::Select(
+            prepare_select_statement(inline_sql_with_statement(&with_statement)?, expected_entity)?,
+        ),
         SqlExplainTarget::Delete(delete_statement) => {
             SqlExplainTarget::Delete(prepare_delete_statement(delete_statement, expected_entity)?)
         }
@@ -389,16 +449,60 @@ fn prepare_insert_statement(
 ) -> Result<SqlInsertStatement, SqlLoweringError> {
     ensure_entity_matches_expected(statement.entity.as_str(), expected_entity)?;
 
+    if let SqlInsertSource::Values(tuples) = &mut statement.source {
+        fold_insert_values_cast_exprs(tuples)?;
+    }
     if let SqlInsertSource::Select(select) = statement.source {
         statement.source = SqlInsertSource::Select(Box::new(prepare_insert_select_source(
             *select,
             expected_entity,
         )?));
     }
+    statement.on_conflict = statement.on_conflict.map(|on_conflict| {
+        normalize_insert_on_conflict_to_expected_entity(
+            on_conflict,
+            statement.entity.as_str(),
+            expected_entity,
+        )
+    });
 
     Ok(statement)
 }
 
+// VALUES tuples only admit literals wrapped in CAST conversions. Fold them
+// through the shared checked-cast evaluator here so the write lane keeps
+// consuming literal values only.
+fn fold_insert_values_cast_exprs(
+    tuples: &mut [Vec<SqlWriteValue>],
+) -> Result<(), SqlLoweringError> {
+    for value in tuples.iter_mut().flatten() {
+        if let SqlWriteValue::Expr(expr) = value {
+            *value = SqlWriteValue::Literal(fold_insert_value_cast_expr(expr)?);
+        }
+    }
+
+    Ok(())
+}
+
+fn fold_insert_value_cast_expr(expr: &SqlExpr) -> Result<Value, SqlLoweringError> {
+    match expr {
+        SqlExpr::Literal(value) => Ok(value.clone()),
+        SqlExpr::FunctionCall {
+            function: SqlScalarFunction::Cast,
+            args,
+        } => {
+            let args = args
+                .iter()
+                .map(fold_insert_value_cast_expr)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            eval_projection_function_call_checked(Function::Cast, args.as_slice())
+                .map_err(|err| err.into_query_error().into())
+        }
+        _ => Err(QueryError::invariant().into()),
+    }
+}
+
 // Normalize one SQL INSERT SELECT source and keep it on the scalar query lane.
 fn prepare_insert_select_source(
     statement: SqlSelectStatement,
@@ -439,15 +543,22 @@ fn lower_explain_prepared_with_schema(
         SqlExplainTarget::Select(select_statement) => {
             lower_explain_select_prepared_with_schema(select_statement, mode, verbose, schema)
         }
-        SqlExplainTarget::Delete(delete_statement) => {
-            Ok(LoweredSqlCommand(LoweredSqlCommandInner::Explain {
+        SqlExplainTarget::With(with_statement) => lower_explain_select_prepared_with_schema(
+            inline_sql_with_statement(&with_statement)?,
+            mode,
+            verbose,
+            schema,
+        ),
+        SqlExplainTarget::Delete(delete_statement) => Ok(LoweredSqlCommand(
+            LoweredSqlCommandInner::Explain {
                 mode,
                 verbose,
                 query: Box::new(LoweredSqlQuery::Delete(lower_delete_shape(
                     delete_statement,
                 )?)),
-            }))
-        }
+            },
+            Vec::new(),
+        )),
     }
 }
 
@@ -467,15 +578,19 @@ fn lower_explain_select_prepared_with_schema(
                 verbose,
                 command: Box::new(command),
             },
+            Vec::new(),
         ));
     }
 
     match lower_select_shape_with_schema(statement.clone(), schema) {
-        Ok(query) => Ok(LoweredSqlCommand(LoweredSqlCommandInner::Explain {
-            mode,
-            verbose,
-            query: Box::new(LoweredSqlQuery::Select(query)),
-        })),
+        Ok(query) => Ok(LoweredSqlCommand(
+            LoweredSqlCommandInner::Explain {
+                mode,
+                verbose,
+                query: Box::new(LoweredSqlQuery::Select(query)),
+            },
+            Vec::new(),
+        )),
         Err(SqlLoweringError::UnsupportedSelectProjection) => {
             let command = lower_global_aggregate_select_shape(statement)?;
 
@@ -485,6 +600,7 @@ fn lower_explain_select_prepared_with_schema(
                     verbose,
                     command: Box::new(command),
                 },
+                Vec::new(),
             ))
         }
         Err(err) => Err(err),
@@ -0,0 +1,169 @@
+//! Module: db::sql::lowering::relation_path
+//! Responsibility: recognition and projection rewrite of `relation.field`
+//! paths over single-valued accepted relation fields.
+//! Does not own: target lookup execution, read admission, or result filling.
+//! Boundary: turns each admitted relation path into a projection of the local
+//! relation key plus one session-owned lookup contract for the target field.
+
+use crate::db::{
+    schema::{AcceptedFieldKind, SchemaInfo},
+    sql::{
+        lowering::{PreparedSqlStatement, SqlLoweringError, SqlRelationPathReason},
+        parser::{SqlExpr, SqlProjection, SqlSelectItem, SqlSelectStatement, SqlStatement},
+    },
+};
+
+///
+/// SqlRelationPath
+///
+/// One projected `relation.field` column. The compiled SELECT projects the
+/// local relation key in `column`; execution replaces it with `target_field`
+/// read from the related `target_entity` row, or `NULL` when none exists.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(in crate::db) struct SqlRelationPath {
+    column: usize,
+    target_entity: String,
+    target_field: String,
+}
+
+impl SqlRelationPath {
+    /// Return the output column that carries the local relation key.
+    #[must_use]
+    pub(in crate::db) const fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Borrow the accepted entity name the relation targets.
+    #[must_use]
+    pub(in crate::db) const fn target_entity(&self) -> &str {
+        self.target_entity.as_str()
+    }
+
+    /// Borrow the target field projected through the relation.
+    #[must_use]
+    pub(in crate::db) const fn target_field(&self) -> &str {
+        self.target_field.as_str()
+    }
+}
+
+/// Rewrite every projected `relation.field` path in one prepared scalar
+/// SELECT onto its local relation key column.
+///
+/// Only top-level projection items over a single-valued relation field are
+/// admitted. Paths rooted at record fields are left untouched for ordinary
+/// nested-path lowering. Relation paths inside filters, ordering, grouping,
+/// or aggregate projections fail closed because the target value does not
+/// exist until after the page has been read.
+pub(in crate::db) fn extract_prepared_sql_relation_paths(
+    prepared: PreparedSqlStatement,
+    schema: &SchemaInfo,
+) -> Result<(PreparedSqlStatement, Vec<SqlRelationPath>), SqlLoweringError> {
+    let PreparedSqlStatement {
+        statement: SqlStatement::Select(mut select),
+    } = prepared
+    else {
+        return Ok((prepared, Vec::new()));
+    };
+
+    let relation_paths = rewrite_select_relation_paths(&mut select, schema)?;
+
+    Ok((
+        PreparedSqlStatement {
+            statement: SqlStatement::Select(select),
+        },
+        relation_paths,
+    ))
+}
+
+fn rewrite_select_relation_paths(
+    select: &mut SqlSelectStatement,
+    schema: &SchemaInfo,
+) -> Result<Vec<SqlRelationPath>, SqlLoweringError> {
+    let is_relation_path = |expr: &SqlExpr| relation_path_target(expr, schema).is_some();
+    let clause_has_relation_path = select
+        .predicate
+        .iter()
+        .chain(select.group_by.iter())
+        .chain(select.having.iter())
+        .chain(select.order_by.iter().map(|term| &term.field))
+        .any(|expr| expr_tree_has(expr, &is_relation_path));
+    if clause_has_relation_path {
+        return Err(SqlLoweringError::unsupported_relation_path(
+            SqlRelationPathReason::Placement,
+        ));
+    }
+
+    let SqlProjection::Items(items) = &mut select.projection else {
+        return Ok(Vec::new());
+    };
+    let grouped = select.distinct
+        || !select.group_by.is_empty()
+        || items.iter().any(SqlSelectItem::contains_aggregate);
+    let mut relation_paths = Vec::new();
+    for (column, item) in items.iter_mut().enumerate() {
+        let SqlSelectItem::Expr(expr) = item else {
+            continue;
+        };
+        if let Some(target_entity) = relation_path_target(expr, schema) {
+            let SqlExpr::FieldPath { root, segments } = expr else {
+                continue;
+            };
+            let [target_field] = segments.as_slice() else {
+                return Err(SqlLoweringError::unsupported_relation_path(
+                    SqlRelationPathReason::NestedPath,
+                ));
+            };
+            if grouped {
+                return Err(SqlLoweringError::unsupported_relation_path(
+                    SqlRelationPathReason::Placement,
+                ));
+            }
+
+            // Keep the path text as the output label so the rewrite to the
+            // key column stays invisible to callers.
+            if select.projection_aliases.len() <= column {
+                select.projection_aliases.resize(column + 1, None);
+            }
+            let alias = &mut select.projection_aliases[column];
+            if alias.is_none() {
+                *alias = Some(format!("{root}.{target_field}"));
+            }
+            relation_paths.push(SqlRelationPath {
+                column,
+                target_entity: target_entity.to_string(),
+                target_field: target_field.clone(),
+            });
+            *item = SqlSelectItem::Field(root.clone());
+        } else if expr_tree_has(expr, &is_relation_path) {
+            return Err(SqlLoweringError::unsupported_relation_path(
+                SqlRelationPathReason::Placement,
+            ));
+        }
+    }
+
+    Ok(relation_paths)
+}
+
+// Return the target entity name when one expression is a path rooted at a
+// single-valued relation field.
+fn relation_path_target<'a>(expr: &SqlExpr, schema: &'a SchemaInfo) -> Option<&'a str> {
+    let SqlExpr::FieldPath { root, .. } = expr else {
+        return None;
+    };
+
+    match schema.accepted_query_field_kind(root)? {
+        AcceptedFieldKind::Relation {
+            target_entity_name, ..
+        } => Some(target_entity_name.as_str()),
+        _ => None,
+    }
+}
+
+fn expr_tree_has(expr: &SqlExpr, predicate: &impl Fn(&SqlExpr) -> bool) -> bool {
+    let mut found = false;
+    expr.for_each_tree_expr(&mut |node| found |= predicate(node));
+
+    found
+}
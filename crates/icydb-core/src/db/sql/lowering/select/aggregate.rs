@@ -3,7 +3,7 @@ use crate::{
         query::{
             builder::AggregateExpr,
             plan::{
-                canonicalize_grouped_having_numeric_literal_for_slot,
+                bind_computed_group_key_refs, canonicalize_grouped_having_numeric_literal_for_slot,
                 expr::{BinaryOp, Expr, canonicalize_grouped_having_bool_expr},
                 resolve_group_field_slot_with_schema,
             },
@@ -29,17 +29,19 @@ use crate::{
 pub(super) fn lower_having_clauses(
     having_exprs: Vec<SqlExpr>,
     projection: &SqlProjection,
-    group_by_fields: &[String],
+    group_by: &[Expr],
     grouped_aggregates: &[AggregateExpr],
     schema: &SchemaInfo,
 ) -> Result<Vec<Expr>, SqlLoweringError> {
-    let clauses =
-        lower_having_clauses_with_policy(having_exprs, projection, group_by_fields.is_empty())?;
+    let clauses = lower_having_clauses_with_policy(having_exprs, projection, group_by.is_empty())?;
     let mut lowered = Vec::with_capacity(clauses.len());
     for clause in clauses {
         register_having_analysis_aggregates(clause.analysis(), &mut |aggregate| {
             resolve_having_aggregate_expr_index(aggregate, grouped_aggregates)
         })?;
+        // Bind computed grouped key references before literal canonicalization
+        // so key expressions keep the exact shape declared in GROUP BY.
+        let clause = clause.bind_computed_group_keys(group_by);
         lowered.push(canonicalize_grouped_having_expr_from_lowered_sql_clause(
             schema, clause,
         )?);
@@ -123,6 +125,16 @@ impl LoweredHavingClause {
     fn into_expr(self) -> Expr {
         self.analyzed.into_expr()
     }
+
+    fn bind_computed_group_keys(self, group_by: &[Expr]) -> Self {
+        Self {
+            contains_omitted_else_case: self.contains_omitted_else_case,
+            analyzed: AnalyzedLoweredExpr::new(bind_computed_group_key_refs(
+                self.analyzed.into_expr(),
+                group_by,
+            )),
+        }
+    }
 }
 
 fn lower_having_expr(expr: SqlExpr) -> Result<AnalyzedLoweredExpr, SqlLoweringError> {
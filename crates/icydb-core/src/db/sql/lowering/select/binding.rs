@@ -47,7 +47,8 @@ pub(super) fn canonicalize_sql_predicate_for_schema(
         | Predicate::IsEmpty { .. }
         | Predicate::IsNotEmpty { .. }
         | Predicate::TextContains { .. }
-        | Predicate::TextContainsCi { .. } => predicate,
+        | Predicate::TextContainsCi { .. }
+        | Predicate::TextMatch { .. } => predicate,
     }
 }
 
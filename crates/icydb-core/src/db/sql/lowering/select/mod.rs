@@ -11,6 +11,8 @@ mod projection;
 use crate::db::sql::lowering::{
     LoweredExprAnalysis, LoweredExprSourceRef, SqlLoweringError,
     aggregate::{SqlAggregateCallInterner, lower_grouped_aggregate_call},
+    analyze_lowered_expr,
+    expr::{SqlExprPhase, lower_sql_expr},
     predicate::{
         derive_sql_where_expr_predicate_subset, lower_sql_scalar_where_bool_expr,
         lower_sql_where_bool_expr, lower_sql_where_expr,
@@ -19,7 +21,7 @@ use crate::db::sql::lowering::{
 use crate::db::{
     predicate::{MissingRowPolicy, Predicate},
     query::{
-        builder::AggregateExpr,
+        builder::{AggregateExpr, scalar_projection::render_scalar_projection_expr_plan_label},
         intent::{QueryError, StructuralQuery},
         plan::{
             GroupedExecutionConfig,
@@ -180,12 +182,13 @@ impl LoweredSqlFilter {
 pub(crate) struct LoweredSelectShape {
     projection_selection: LoweredSqlProjectionSelection,
     grouped_aggregates: Vec<AggregateExpr>,
-    group_by_fields: Vec<String>,
+    group_by: Vec<Expr>,
     distinct: bool,
     having: Vec<crate::db::query::plan::expr::Expr>,
     filter: Option<LoweredSqlFilter>,
     order_by: Vec<LoweredSqlOrderTerm>,
     limit: Option<u32>,
+    limit_per: Vec<String>,
     offset: Option<u32>,
 }
 
@@ -248,17 +251,22 @@ pub(in crate::db::sql::lowering) fn lower_select_shape_with_schema(
         having,
         order_by,
         limit,
+        limit_per,
         offset,
         entity: _,
         table_alias: _,
     } = statement;
     let projection_for_having = projection.clone();
+    let group_by = lower_group_by_keys(group_by.as_slice())?;
 
     // Phase 1: resolve scalar/grouped projection shape.
     let is_grouped = !group_by.is_empty();
     if !is_grouped && !having.is_empty() {
         return Err(SqlLoweringError::having_requires_group_by());
     }
+    if is_grouped && !limit_per.is_empty() {
+        return Err(SqlLoweringError::unsupported_limit_per());
+    }
 
     let (projection_selection, grouped_aggregates, normalized_distinct) = if is_grouped {
         let grouped_projection = lower_grouped_projection(
@@ -274,9 +282,13 @@ pub(in crate::db::sql::lowering) fn lower_select_shape_with_schema(
         for expr in having.as_slice() {
             aggregate_call_interner.extend_expr(&mut grouped_aggregates, expr);
         }
+        let group_labels = group_by
+            .iter()
+            .map(render_scalar_projection_expr_plan_label)
+            .collect::<Vec<_>>();
         let projection_selection = grouped_projection.into_projection_selection(
             projection_aggregate_count == grouped_aggregates.len(),
-            group_by.as_slice(),
+            group_labels.as_slice(),
         );
         let grouped_aggregates = lower_grouped_aggregate_calls(grouped_aggregates, schema)?;
         (projection_selection, grouped_aggregates, false)
@@ -314,12 +326,13 @@ pub(in crate::db::sql::lowering) fn lower_select_shape_with_schema(
     Ok(LoweredSelectShape {
         projection_selection,
         grouped_aggregates,
-        group_by_fields: group_by,
+        group_by,
         distinct: normalized_distinct,
         having,
         filter,
         order_by,
         limit,
+        limit_per,
         offset,
     })
 }
@@ -332,18 +345,19 @@ fn apply_lowered_select_shape_with_schema(
     let LoweredSelectShape {
         projection_selection,
         grouped_aggregates,
-        group_by_fields,
+        group_by,
         distinct,
         having,
         filter,
         order_by,
         limit,
+        limit_per,
         offset,
     } = lowered;
     validate_select_sql_capabilities(
         schema,
         &projection_selection,
-        group_by_fields.as_slice(),
+        group_by.as_slice(),
         order_by.as_slice(),
     )?;
 
@@ -351,9 +365,12 @@ fn apply_lowered_select_shape_with_schema(
     // syntax, so its lowering boundary must attach the planner's finite
     // conservative authority rather than leaving complete hash state
     // unbounded.
-    let is_grouped = !group_by_fields.is_empty();
-    for field in group_by_fields {
-        query = query.group_by_with_schema(field, schema)?;
+    let is_grouped = !group_by.is_empty();
+    for key in group_by {
+        query = match key {
+            Expr::Field(field) => query.group_by_with_schema(field.as_str(), schema)?,
+            key => query.group_by_expr_with_schema(key, schema)?,
+        };
     }
     if is_grouped {
         let grouped_execution = GroupedExecutionConfig::planner_default_bounded();
@@ -377,7 +394,17 @@ fn apply_lowered_select_shape_with_schema(
         query = query.having_expr_preserving_shape(clause)?;
     }
 
-    // Phase 4: attach the shared filter/order/page tail through the base-query lane.
+    // Phase 4: `LIMIT k PER ...` bounds each partition instead of the page,
+    // so its row count moves onto the partition window.
+    let limit = match limit {
+        Some(limit) if !limit_per.is_empty() => {
+            query = query.limit_per_partition(limit, limit_per);
+            None
+        }
+        limit => limit,
+    };
+
+    // Phase 5: attach the shared filter/order/page tail through the base-query lane.
     Ok(apply_lowered_base_query_shape_with_schema(
         query,
         LoweredBaseQueryShape {
@@ -390,6 +417,15 @@ fn apply_lowered_select_shape_with_schema(
     ))
 }
 
+// Lower GROUP BY keys as row-local scalar expressions. Bare identifiers stay
+// direct field keys; everything else becomes one computed grouped key.
+fn lower_group_by_keys(group_by: &[SqlExpr]) -> Result<Vec<Expr>, SqlLoweringError> {
+    group_by
+        .iter()
+        .map(|key| lower_sql_expr(key, SqlExprPhase::Scalar))
+        .collect()
+}
+
 fn lower_grouped_aggregate_calls(
     grouped_aggregates: Vec<crate::db::sql::parser::SqlAggregateCall>,
     schema: &SchemaInfo,
@@ -405,11 +441,11 @@ fn lower_grouped_aggregate_calls(
 fn validate_select_sql_capabilities(
     schema: &SchemaInfo,
     projection_selection: &LoweredSqlProjectionSelection,
-    group_by_fields: &[String],
+    group_by: &[Expr],
     order_by: &[LoweredSqlOrderTerm],
 ) -> Result<(), SqlLoweringError> {
     validate_projection_sql_capabilities(schema, projection_selection)?;
-    validate_group_by_sql_capabilities(schema, group_by_fields)?;
+    validate_group_by_sql_capabilities(schema, group_by)?;
     validate_order_sql_capabilities(schema, order_by)?;
 
     Ok(())
@@ -486,15 +522,22 @@ fn ensure_sql_selectable_field_path(
 
 // GROUP BY identity must use the schema-owned groupable capability instead of
 // re-deriving comparable/identity behavior from generated field kinds.
+// Computed keys only require selectable source fields here; their result type
+// is checked by grouped planning.
 fn validate_group_by_sql_capabilities(
     schema: &SchemaInfo,
-    fields: &[String],
+    keys: &[Expr],
 ) -> Result<(), SqlLoweringError> {
-    for field in fields {
+    for key in keys {
+        let Expr::Field(field) = key else {
+            validate_computed_group_key_sql_capabilities(schema, key)?;
+            continue;
+        };
+        let field = field.as_str();
         let Some(capabilities) = schema.sql_capabilities(field) else {
             return Err(SqlLoweringError::unknown_field(
                 QueryFieldRole::GroupBy,
-                field.as_str(),
+                field,
             ));
         };
         // Keep enum grouping closed until the canonical-ID runtime/key route
@@ -507,6 +550,32 @@ fn validate_group_by_sql_capabilities(
     Ok(())
 }
 
+fn validate_computed_group_key_sql_capabilities(
+    schema: &SchemaInfo,
+    key: &Expr,
+) -> Result<(), SqlLoweringError> {
+    for source_ref in analyze_lowered_expr(key).source_refs() {
+        match source_ref {
+            LoweredExprSourceRef::Direct(field) => {
+                let Some(capabilities) = schema.sql_capabilities(field.as_str()) else {
+                    return Err(SqlLoweringError::unknown_field(
+                        QueryFieldRole::GroupBy,
+                        field.as_str(),
+                    ));
+                };
+                if !capabilities.selectable() {
+                    return Err(SqlLoweringError::unsupported_select_group_by());
+                }
+            }
+            LoweredExprSourceRef::Path(path) => {
+                ensure_sql_selectable_field_path(schema, path)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
 // ORDER BY direct fields use accepted top-level orderability. Computed ORDER BY
 // expressions continue through expression planning because their result type,
 // not each input field's type, owns the final orderability decision.
@@ -797,6 +866,7 @@ fn append_primary_key_order_fallback(
         order_by.push(SqlOrderTerm {
             field: SqlExpr::Field(primary_key_name.clone()),
             direction: SqlOrderDirection::Asc,
+            nulls: None,
         });
     }
 }
@@ -810,6 +880,7 @@ mod tests {
         let mut order_by = vec![SqlOrderTerm {
             field: SqlExpr::Field("tenant_id".to_string()),
             direction: SqlOrderDirection::Asc,
+            nulls: None,
         }];
         let primary_key_names = vec!["tenant_id".to_string(), "local_id".to_string()];
 
@@ -1,14 +1,14 @@
 use crate::db::{
     query::{
         intent::StructuralQuery,
-        plan::{OrderSpec, OrderTerm},
+        plan::{NullsOrder, OrderDirection, OrderSpec, OrderTerm},
     },
     sql::{
         lowering::{
             LoweredExprAnalysis, SqlLoweringError, analyze_lowered_expr,
             expr::{SqlExprPhase, lower_sql_expr},
         },
-        parser::{SqlOrderDirection, SqlOrderTerm},
+        parser::{SqlNullsOrder, SqlOrderDirection, SqlOrderTerm},
     },
 };
 
@@ -23,6 +23,7 @@ pub(in crate::db::sql::lowering) struct LoweredSqlOrderTerm {
     pub(in crate::db::sql::lowering) expr: crate::db::query::plan::expr::Expr,
     pub(in crate::db::sql::lowering) analysis: LoweredExprAnalysis,
     pub(in crate::db::sql::lowering) direction: SqlOrderDirection,
+    pub(in crate::db::sql::lowering) nulls: Option<SqlNullsOrder>,
 }
 
 pub(super) fn lower_order_terms(
@@ -43,13 +44,19 @@ pub(super) fn apply_order_terms_structural(
         fields: order_by
             .into_iter()
             .map(|term| {
-                OrderTerm::new(
+                let lowered = OrderTerm::new(
                     term.expr,
                     match term.direction {
-                        SqlOrderDirection::Asc => crate::db::query::plan::OrderDirection::Asc,
-                        SqlOrderDirection::Desc => crate::db::query::plan::OrderDirection::Desc,
+                        SqlOrderDirection::Asc => OrderDirection::Asc,
+                        SqlOrderDirection::Desc => OrderDirection::Desc,
                     },
-                )
+                );
+
+                match term.nulls {
+                    Some(SqlNullsOrder::First) => lowered.with_nulls(NullsOrder::First),
+                    Some(SqlNullsOrder::Last) => lowered.with_nulls(NullsOrder::Last),
+                    None => lowered,
+                }
             })
             .collect(),
     })
@@ -70,6 +77,7 @@ fn lower_order_term(term: SqlOrderTerm) -> Result<LoweredSqlOrderTerm, SqlLoweri
         analysis,
         expr,
         direction: term.direction,
+        nulls: term.nulls,
     })
 }
 
@@ -87,6 +95,7 @@ mod tests {
                 args: vec![SqlExpr::Field("name".to_string())],
             },
             direction: SqlOrderDirection::Asc,
+            nulls: None,
         })
         .expect("order term should lower");
 
@@ -105,6 +114,7 @@ mod tests {
                 segments: vec!["name".to_string()],
             },
             direction: SqlOrderDirection::Asc,
+            nulls: None,
         })
         .expect("field-path order term should lower");
 
@@ -1,5 +1,11 @@
 use crate::db::{
-    query::plan::expr::{Alias, Expr, FieldId, ProjectionField, ProjectionSelection},
+    query::{
+        builder::scalar_projection::render_scalar_projection_expr_plan_label,
+        plan::{
+            bind_computed_group_key_refs,
+            expr::{Alias, Expr, FieldId, ProjectionField, ProjectionSelection},
+        },
+    },
     schema::SchemaInfo,
     sql::{
         identifier::split_qualified_identifier,
@@ -151,7 +157,7 @@ pub(super) fn lower_scalar_projection_selection(
 pub(super) fn lower_grouped_projection(
     projection: SqlProjection,
     projection_aliases: &[Option<String>],
-    group_by: &[String],
+    group_by: &[Expr],
     schema: &SchemaInfo,
 ) -> Result<LoweredGroupedProjection, SqlLoweringError> {
     if group_by.is_empty() {
@@ -161,7 +167,11 @@ pub(super) fn lower_grouped_projection(
     let SqlProjection::Items(items) = projection else {
         return Err(SqlLoweringError::grouped_projection_requires_explicit_list());
     };
-    let grouped_field_names = group_by.iter().map(String::as_str).collect::<Vec<_>>();
+    let group_labels = group_by
+        .iter()
+        .map(render_scalar_projection_expr_plan_label)
+        .collect::<Vec<_>>();
+    let grouped_field_names = group_labels.iter().map(String::as_str).collect::<Vec<_>>();
 
     let mut seen_aggregate = false;
     let mut fields = Vec::with_capacity(items.len());
@@ -171,25 +181,31 @@ pub(super) fn lower_grouped_projection(
 
     for (index, item) in items.into_iter().enumerate() {
         let analyzed = lower_analyzed_select_item_expr(&item, SqlExprPhase::PostAggregate)?;
-        let expr_facts = analyzed.analysis();
-        let contains_aggregate = expr_facts.contains_aggregate();
+        let contains_aggregate = analyzed.analysis().contains_aggregate();
         if seen_aggregate && !contains_aggregate {
             return Err(SqlLoweringError::grouped_projection_scalar_after_aggregate(
                 index,
             ));
         }
+
+        // Computed grouped keys are referenced by label, so the key-reference
+        // check runs over the bound expression while source-field facts stay
+        // with the original projection expression.
+        let (expr, expr_facts) = analyzed.into_parts();
+        let bound = AnalyzedLoweredExpr::new(bind_computed_group_key_refs(expr, group_by));
         validate_grouped_projection_expr(
             index,
             grouped_field_names.as_slice(),
             schema,
-            expr_facts,
+            &expr_facts,
+            bound.analysis(),
         )?;
         seen_aggregate |= contains_aggregate;
         if contains_aggregate {
             aggregate_call_interner.extend_select_item(&mut aggregate_calls, &item);
         }
 
-        let (expr, expr_facts) = analyzed.into_parts();
+        let expr = bound.into_expr();
         fields.push(ProjectionField::Scalar {
             expr,
             alias: projection_aliases
@@ -217,6 +233,7 @@ fn validate_grouped_projection_expr(
     grouped_field_names: &[&str],
     schema: &SchemaInfo,
     analysis: &LoweredExprAnalysis,
+    bound_analysis: &LoweredExprAnalysis,
 ) -> Result<(), SqlLoweringError> {
     if let Some(field) = analysis.first_unknown_field_for_schema(schema) {
         return Err(SqlLoweringError::unknown_field(
@@ -224,7 +241,7 @@ fn validate_grouped_projection_expr(
             field,
         ));
     }
-    if !analysis.references_only_direct_fields(grouped_field_names) {
+    if !bound_analysis.references_only_direct_fields(grouped_field_names) {
         return Err(SqlLoweringError::grouped_projection_references_non_group_field(index));
     }
 
@@ -0,0 +1,286 @@
+//! Module: db::sql::lowering::semi_join
+//! Responsibility: source-shape checks and literal rewrite for uncorrelated
+//! `IN (SELECT ...)` and `EXISTS (SELECT ...)` predicates.
+//! Does not own: source query execution, read admission, or key budgets.
+//! Boundary: turns one parsed SELECT plus session-resolved source values into
+//! the literal membership shape the existing lowering path already admits.
+
+#[cfg(feature = "sql")]
+use crate::db::sql::parser::SqlExplainTarget;
+use crate::{
+    db::sql::{
+        lowering::{SqlLoweringError, SqlSemiJoinReason},
+        parser::{
+            SqlExpr, SqlExprBinaryOp, SqlInsertSource, SqlProjection, SqlSelectItem,
+            SqlSelectStatement, SqlStatement,
+        },
+    },
+    value::Value,
+};
+use std::cmp::Ordering;
+
+/// Return whether one parsed statement carries any subquery semi-join.
+#[must_use]
+pub(in crate::db) fn sql_statement_has_semi_join(statement: &SqlStatement) -> bool {
+    match statement {
+        SqlStatement::Select(select) => select_has_semi_join(select),
+        SqlStatement::Union(union) => union.branches.iter().any(select_has_semi_join),
+        SqlStatement::With(with) => with.selects().any(select_has_semi_join),
+        SqlStatement::Delete(delete) => option_expr_has_semi_join(delete.predicate.as_ref()),
+        SqlStatement::Update(update) => option_expr_has_semi_join(update.predicate.as_ref()),
+        SqlStatement::Insert(insert) => match &insert.source {
+            SqlInsertSource::Select(select) => select_has_semi_join(select),
+            SqlInsertSource::Values(_) | SqlInsertSource::DefaultValues => false,
+        },
+        #[cfg(feature = "sql")]
+        SqlStatement::Explain(explain) => match &explain.statement {
+            SqlExplainTarget::Select(select) => select_has_semi_join(select),
+            SqlExplainTarget::With(with) => with.selects().any(select_has_semi_join),
+            SqlExplainTarget::Delete(delete) => {
+                option_expr_has_semi_join(delete.predicate.as_ref())
+            }
+        },
+        SqlStatement::Ddl(_)
+        | SqlStatement::Describe(_)
+        | SqlStatement::ShowConstraints(_)
+        | SqlStatement::ShowIndexes(_)
+        | SqlStatement::ShowColumns(_)
+        | SqlStatement::ShowRelations(_)
+        | SqlStatement::ShowEntities(_)
+        | SqlStatement::ShowStores(_)
+        | SqlStatement::ShowMemory(_) => false,
+    }
+}
+
+/// Replace every semi-join in one read statement with the literal predicate
+/// its resolved source values imply.
+///
+/// `resolve` executes one source SELECT and returns the first projected value
+/// of every source row. `EXISTS` sources are narrowed to one row before they
+/// reach `resolve`, and only `SELECT` filters may carry semi-joins at all:
+/// write selectors keep their own exact-key admission contracts.
+pub(in crate::db) fn resolve_sql_statement_semi_joins(
+    statement: &SqlStatement,
+    resolve: &mut impl FnMut(&SqlSelectStatement) -> Result<Vec<Value>, SqlLoweringError>,
+) -> Result<SqlStatement, SqlLoweringError> {
+    let mut resolved = statement.clone();
+    match &mut resolved {
+        SqlStatement::Select(select) => resolve_select_semi_joins(select, resolve)?,
+        SqlStatement::Union(union) => {
+            for branch in &mut union.branches {
+                resolve_select_semi_joins(branch, resolve)?;
+            }
+        }
+        SqlStatement::With(with) => {
+            for select in with.selects_mut() {
+                resolve_select_semi_joins(select, resolve)?;
+            }
+        }
+        #[cfg(feature = "sql")]
+        SqlStatement::Explain(explain) => match &mut explain.statement {
+            SqlExplainTarget::Select(select) => resolve_select_semi_joins(select, resolve)?,
+            SqlExplainTarget::With(with) => {
+                for select in with.selects_mut() {
+                    resolve_select_semi_joins(select, resolve)?;
+                }
+            }
+            SqlExplainTarget::Delete(_) => {
+                return Err(SqlLoweringError::unsupported_semi_join(
+                    SqlSemiJoinReason::Placement,
+                ));
+            }
+        },
+        other => {
+            if sql_statement_has_semi_join(other) {
+                return Err(SqlLoweringError::unsupported_semi_join(
+                    SqlSemiJoinReason::Placement,
+                ));
+            }
+        }
+    }
+
+    Ok(resolved)
+}
+
+fn select_has_semi_join(select: &SqlSelectStatement) -> bool {
+    option_expr_has_semi_join(select.predicate.as_ref())
+}
+
+fn option_expr_has_semi_join(expr: Option<&SqlExpr>) -> bool {
+    expr.is_some_and(SqlExpr::contains_semi_join)
+}
+
+fn resolve_select_semi_joins(
+    select: &mut SqlSelectStatement,
+    resolve: &mut impl FnMut(&SqlSelectStatement) -> Result<Vec<Value>, SqlLoweringError>,
+) -> Result<(), SqlLoweringError> {
+    match select.predicate.as_mut() {
+        Some(predicate) => resolve_expr_semi_joins(predicate, resolve),
+        None => Ok(()),
+    }
+}
+
+// Walk one predicate tree and replace each semi-join node in place. Source
+// statements are checked before execution so unsupported shapes never run.
+fn resolve_expr_semi_joins(
+    expr: &mut SqlExpr,
+    resolve: &mut impl FnMut(&SqlSelectStatement) -> Result<Vec<Value>, SqlLoweringError>,
+) -> Result<(), SqlLoweringError> {
+    match expr {
+        SqlExpr::SemiJoin {
+            probe,
+            source,
+            negated,
+        } => {
+            validate_semi_join_source(source, probe.is_some())?;
+            let values = match probe {
+                Some(_) => resolve(source)?,
+                None => resolve(&exists_source(source))?,
+            };
+            let replacement = semi_join_literal_expr(probe.take(), values, *negated);
+            *expr = replacement;
+        }
+        SqlExpr::Field(_)
+        | SqlExpr::FieldPath { .. }
+        | SqlExpr::Aggregate(_)
+        | SqlExpr::Literal(_)
+        | SqlExpr::Param { .. } => {}
+        SqlExpr::Membership { expr, .. }
+        | SqlExpr::NullTest { expr, .. }
+        | SqlExpr::Like { expr, .. }
+        | SqlExpr::Unary { expr, .. } => resolve_expr_semi_joins(expr, resolve)?,
+        SqlExpr::FunctionCall { args, .. } => {
+            for arg in args {
+                resolve_expr_semi_joins(arg, resolve)?;
+            }
+        }
+        SqlExpr::Binary { left, right, .. } => {
+            resolve_expr_semi_joins(left, resolve)?;
+            resolve_expr_semi_joins(right, resolve)?;
+        }
+        SqlExpr::Case { arms, else_expr } => {
+            for arm in arms {
+                resolve_expr_semi_joins(&mut arm.condition, resolve)?;
+                resolve_expr_semi_joins(&mut arm.result, resolve)?;
+            }
+            if let Some(else_expr) = else_expr.as_deref_mut() {
+                resolve_expr_semi_joins(else_expr, resolve)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Admit only flat, uncorrelated row sources: one projected key column for
+// `IN`, no grouping or aggregation, and no further nesting.
+fn validate_semi_join_source(
+    source: &SqlSelectStatement,
+    keyed: bool,
+) -> Result<(), SqlLoweringError> {
+    if select_has_semi_join(source) {
+        return Err(SqlLoweringError::unsupported_semi_join(
+            SqlSemiJoinReason::NestedSubquery,
+        ));
+    }
+    if !source.group_by.is_empty()
+        || !source.having.is_empty()
+        || projection_has_aggregate(&source.projection)
+    {
+        return Err(SqlLoweringError::unsupported_semi_join(
+            SqlSemiJoinReason::SourceAggregation,
+        ));
+    }
+    if keyed && !projection_is_single_field(&source.projection) {
+        return Err(SqlLoweringError::unsupported_semi_join(
+            SqlSemiJoinReason::SourceProjection,
+        ));
+    }
+
+    Ok(())
+}
+
+fn projection_has_aggregate(projection: &SqlProjection) -> bool {
+    let SqlProjection::Items(items) = projection else {
+        return false;
+    };
+
+    items.iter().any(|item| match item {
+        SqlSelectItem::Field(_) => false,
+        SqlSelectItem::Aggregate(_) => true,
+        SqlSelectItem::Expr(expr) => {
+            let mut found = false;
+            expr.for_each_tree_aggregate(&mut |_| found = true);
+            found
+        }
+    })
+}
+
+fn projection_is_single_field(projection: &SqlProjection) -> bool {
+    matches!(
+        projection,
+        SqlProjection::Items(items)
+            if matches!(
+                items.as_slice(),
+                [SqlSelectItem::Field(_) | SqlSelectItem::Expr(SqlExpr::Field(_) | SqlExpr::FieldPath { .. })]
+            )
+    )
+}
+
+// `EXISTS` only needs to observe one row, so ordering is irrelevant and the
+// source is narrowed to `LIMIT 1` before it executes.
+fn exists_source(source: &SqlSelectStatement) -> SqlSelectStatement {
+    let mut source = source.clone();
+    source.order_by.clear();
+    source.limit = Some(1);
+
+    source
+}
+
+// Fold one resolved source onto the literal predicate family. A `NULL`
+// source key never matches, but it makes every non-matching probe unknown:
+// `x NOT IN (.., NULL)` is false or `NULL`, never true, and `x IN (.., NULL)`
+// is true or `NULL`, never false. The unknown arm survives an enclosing `NOT`.
+fn semi_join_literal_expr(
+    probe: Option<Box<SqlExpr>>,
+    values: Vec<Value>,
+    negated: bool,
+) -> SqlExpr {
+    let Some(probe) = probe else {
+        return SqlExpr::Literal(Value::Bool(values.is_empty() == negated));
+    };
+
+    let source_has_null = values.iter().any(|value| matches!(value, Value::Null));
+    let mut values = values
+        .into_iter()
+        .filter(|value| !matches!(value, Value::Null))
+        .collect::<Vec<_>>();
+    values.sort_by(Value::canonical_cmp);
+    values.dedup_by(|left, right| Value::canonical_cmp(left, right) == Ordering::Equal);
+    if values.is_empty() {
+        return SqlExpr::Literal(if source_has_null {
+            Value::Null
+        } else {
+            Value::Bool(negated)
+        });
+    }
+
+    let membership = SqlExpr::Membership {
+        expr: probe,
+        values,
+        negated,
+    };
+    if !source_has_null {
+        return membership;
+    }
+
+    SqlExpr::Binary {
+        op: if negated {
+            SqlExprBinaryOp::And
+        } else {
+            SqlExprBinaryOp::Or
+        },
+        left: Box::new(membership),
+        right: Box::new(SqlExpr::Literal(Value::Null)),
+    }
+}
@@ -0,0 +1,241 @@
+//! Module: db::sql::lowering::union
+//! Responsibility: branch-shape checks and branch rewrite for `UNION [ALL]`.
+//! Does not own: branch execution, ordered branch merging, or DISTINCT state.
+//! Boundary: turns one parsed union chain into per-branch SELECTs that already
+//! emit rows in the combined order, plus the merge contract over their rows.
+
+use crate::db::{
+    query::plan::{NullsOrder, OrderDirection},
+    schema::SchemaInfo,
+    sql::{
+        identifier::{identifier_last_segment, identifiers_tail_match},
+        lowering::{SqlLoweringError, SqlUnionReason},
+        parser::{
+            SqlExpr, SqlNullsOrder, SqlOrderDirection, SqlOrderTerm, SqlProjection, SqlSelectItem,
+            SqlSelectStatement, SqlUnionStatement,
+        },
+    },
+};
+
+///
+/// SqlUnionOrderSlot
+///
+/// One combined ORDER BY term resolved to a visible output column.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(in crate::db) struct SqlUnionOrderSlot {
+    pub(in crate::db) column: usize,
+    pub(in crate::db) direction: OrderDirection,
+    pub(in crate::db) nulls: NullsOrder,
+}
+
+///
+/// LoweredSqlUnion
+///
+/// Branch SELECTs rewritten for one bounded ordered merge.
+///
+/// Every branch projects the same visible columns followed by the hidden
+/// primary-key columns, orders by the combined ORDER BY terms and then the
+/// primary key, and under `UNION ALL` reads at most `offset + limit` rows.
+/// Merging the branch row streams on that same key therefore yields the
+/// combined order.
+///
+
+#[derive(Clone, Debug)]
+pub(in crate::db) struct LoweredSqlUnion {
+    pub(in crate::db) branches: Vec<SqlSelectStatement>,
+    pub(in crate::db) order: Vec<SqlUnionOrderSlot>,
+    pub(in crate::db) visible_width: usize,
+    pub(in crate::db) distinct: bool,
+    pub(in crate::db) offset: usize,
+    pub(in crate::db) limit: Option<usize>,
+}
+
+/// Validate one parsed union chain against its accepted entity and rewrite
+/// its branches for bounded ordered merging.
+pub(in crate::db) fn lower_sql_union_statement(
+    statement: &SqlUnionStatement,
+    schema: &SchemaInfo,
+) -> Result<LoweredSqlUnion, SqlLoweringError> {
+    let entity_name = schema.entity_name().unwrap_or_default();
+    let primary_key = schema.primary_key_names();
+
+    // Phase 1: admit only plain scalar branches over the one union entity.
+    let mut branch_items = Vec::with_capacity(statement.branches.len());
+    for branch in &statement.branches {
+        if !identifiers_tail_match(branch.entity.as_str(), entity_name) {
+            return Err(SqlLoweringError::unsupported_union(
+                SqlUnionReason::EntityMismatch,
+            ));
+        }
+        if branch.distinct
+            || !branch.group_by.is_empty()
+            || !branch.having.is_empty()
+            || !branch.limit_per.is_empty()
+        {
+            return Err(SqlLoweringError::unsupported_union(
+                SqlUnionReason::BranchShape,
+            ));
+        }
+        let (items, aliases) = branch_select_items(branch, schema)?;
+        if branch_items
+            .first()
+            .is_some_and(|(first, _): &(Vec<SqlSelectItem>, _)| first.len() != items.len())
+        {
+            return Err(SqlLoweringError::unsupported_union(
+                SqlUnionReason::ProjectionWidth,
+            ));
+        }
+        branch_items.push((items, aliases));
+    }
+    let Some((first_items, first_aliases)) = branch_items.first() else {
+        return Err(SqlLoweringError::unsupported_union(
+            SqlUnionReason::BranchShape,
+        ));
+    };
+    let visible_width = first_items.len();
+
+    // Phase 2: resolve combined ORDER BY terms against the first branch's
+    // output labels, as the combined column names come from that branch.
+    let order = statement
+        .order_by
+        .iter()
+        .map(|term| union_order_slot(term, first_items, first_aliases))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Phase 3: rewrite each branch to emit its rows in the merge order with
+    // hidden primary-key columns. Only `UNION ALL` pushes the combined window
+    // down: DISTINCT may drop any number of branch rows before the window.
+    let offset = statement.offset.unwrap_or(0) as usize;
+    let limit = statement.limit.map(|limit| limit as usize);
+    let branch_limit = statement
+        .limit
+        .filter(|_| statement.all)
+        .map(|limit| limit.saturating_add(statement.offset.unwrap_or(0)));
+    let branches = statement
+        .branches
+        .iter()
+        .zip(branch_items)
+        .map(|(branch, (mut items, mut aliases))| {
+            let mut order_by = Vec::with_capacity(order.len() + primary_key.len());
+            for (term, slot) in statement.order_by.iter().zip(&order) {
+                order_by.push(SqlOrderTerm {
+                    field: select_item_order_expr(items.get(slot.column))?,
+                    direction: term.direction,
+                    nulls: term.nulls,
+                });
+            }
+            for key in primary_key {
+                order_by.push(SqlOrderTerm {
+                    field: SqlExpr::Field(key.clone()),
+                    direction: SqlOrderDirection::Asc,
+                    nulls: None,
+                });
+                items.push(SqlSelectItem::Field(key.clone()));
+                aliases.push(None);
+            }
+
+            Ok(SqlSelectStatement {
+                projection: SqlProjection::Items(items),
+                projection_aliases: aliases,
+                order_by,
+                limit: branch_limit,
+                offset: None,
+                ..branch.clone()
+            })
+        })
+        .collect::<Result<Vec<_>, SqlLoweringError>>()?;
+
+    Ok(LoweredSqlUnion {
+        branches,
+        order,
+        visible_width,
+        distinct: !statement.all,
+        offset,
+        limit,
+    })
+}
+
+// Expand one branch projection into explicit items so hidden key columns can
+// follow it. `*` expands in accepted slot order, matching its normal output.
+fn branch_select_items(
+    branch: &SqlSelectStatement,
+    schema: &SchemaInfo,
+) -> Result<(Vec<SqlSelectItem>, Vec<Option<String>>), SqlLoweringError> {
+    match &branch.projection {
+        SqlProjection::All => {
+            let items = schema
+                .field_names_in_slot_order()
+                .into_iter()
+                .map(|field| SqlSelectItem::Field(field.to_string()))
+                .collect::<Vec<_>>();
+            let aliases = vec![None; items.len()];
+
+            Ok((items, aliases))
+        }
+        SqlProjection::Items(items) => {
+            if items.iter().any(SqlSelectItem::contains_aggregate) {
+                return Err(SqlLoweringError::unsupported_union(
+                    SqlUnionReason::BranchShape,
+                ));
+            }
+            let mut aliases = branch.projection_aliases.clone();
+            aliases.resize(items.len(), None);
+
+            Ok((items.clone(), aliases))
+        }
+    }
+}
+
+// Resolve one combined ORDER BY term to the visible column it names, either
+// through a projection alias or through a directly projected field.
+fn union_order_slot(
+    term: &SqlOrderTerm,
+    items: &[SqlSelectItem],
+    aliases: &[Option<String>],
+) -> Result<SqlUnionOrderSlot, SqlLoweringError> {
+    let SqlExpr::Field(name) = &term.field else {
+        return Err(SqlLoweringError::unsupported_union(
+            SqlUnionReason::OrderTerm,
+        ));
+    };
+    let by_alias = aliases
+        .iter()
+        .position(|alias| alias.as_deref() == Some(name.as_str()));
+    let column = by_alias
+        .or_else(|| {
+            items.iter().zip(aliases).position(|(item, alias)| {
+                alias.is_none()
+                    && matches!(item, SqlSelectItem::Field(field)
+                        if identifier_last_segment(field) == identifier_last_segment(name))
+            })
+        })
+        .ok_or_else(|| SqlLoweringError::unsupported_union(SqlUnionReason::OrderTerm))?;
+    let direction = match term.direction {
+        SqlOrderDirection::Asc => OrderDirection::Asc,
+        SqlOrderDirection::Desc => OrderDirection::Desc,
+    };
+    let nulls = match term.nulls {
+        Some(SqlNullsOrder::First) => NullsOrder::First,
+        Some(SqlNullsOrder::Last) => NullsOrder::Last,
+        None => NullsOrder::natural_for(direction),
+    };
+
+    Ok(SqlUnionOrderSlot {
+        column,
+        direction,
+        nulls,
+    })
+}
+
+// Order one branch by the expression behind one of its own output columns.
+fn select_item_order_expr(item: Option<&SqlSelectItem>) -> Result<SqlExpr, SqlLoweringError> {
+    match item {
+        Some(SqlSelectItem::Field(field)) => Ok(SqlExpr::Field(field.clone())),
+        Some(SqlSelectItem::Expr(expr)) => Ok(expr.clone()),
+        Some(SqlSelectItem::Aggregate(_)) | None => Err(SqlLoweringError::unsupported_union(
+            SqlUnionReason::BranchShape,
+        )),
+    }
+}
@@ -0,0 +1,547 @@
+//! Module: db::sql::lowering::with
+//! Responsibility: non-recursive `WITH` inlining into one single-entity SELECT.
+//! Does not own: CTE materialization, grouped budgets, or EXPLAIN rendering.
+//! Boundary: rewrites outer column references onto the expressions each
+//! common table expression projects, so downstream lowering sees one ordinary
+//! SELECT whose grouping or DISTINCT stage bounds the CTE materialization.
+
+use crate::db::sql::{
+    identifier::identifier_last_segment,
+    lowering::{
+        SqlCteReason, SqlLoweringError, normalize::normalize_select_statement_to_expected_entity,
+    },
+    parser::{
+        SqlAggregateArguments, SqlAggregateCall, SqlCaseArm, SqlCommonTableExpression, SqlExpr,
+        SqlExprBinaryOp, SqlOrderTerm, SqlProjection, SqlSelectItem, SqlSelectStatement,
+        SqlWithStatement,
+    },
+};
+
+///
+/// SqlCommonTableExpressionMaterialization
+///
+/// Stage that bounds one inlined common table expression once it runs as
+/// part of the outer query.
+///
+
+#[cfg(feature = "sql")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum SqlCommonTableExpressionMaterialization {
+    Inline,
+    Distinct,
+    Grouped,
+}
+
+#[cfg(feature = "sql")]
+impl SqlCommonTableExpressionMaterialization {
+    /// Return the stable EXPLAIN label for this materialization stage.
+    #[must_use]
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Inline => "inline",
+            Self::Distinct => "distinct",
+            Self::Grouped => "grouped",
+        }
+    }
+}
+
+///
+/// SqlCommonTableExpressionSummary
+///
+/// EXPLAIN-facing record of one common table expression the outer query
+/// reads, directly or through another entry.
+///
+
+#[cfg(feature = "sql")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct SqlCommonTableExpressionSummary {
+    pub(crate) name: String,
+    pub(crate) materialization: SqlCommonTableExpressionMaterialization,
+}
+
+/// Inline every common table expression of one parsed `WITH` statement into
+/// its outer SELECT, yielding one SELECT over the base entity.
+pub(in crate::db::sql::lowering) fn inline_sql_with_statement(
+    statement: &SqlWithStatement,
+) -> Result<SqlSelectStatement, SqlLoweringError> {
+    validate_common_table_expression_names(statement)?;
+
+    inline_select(
+        statement.select.clone(),
+        statement.common_table_expressions.as_slice(),
+    )
+}
+
+/// Summarize the common table expressions one `WITH` statement reads, from
+/// the innermost body outwards.
+#[cfg(feature = "sql")]
+#[must_use]
+pub(crate) fn sql_with_statement_common_table_expressions(
+    statement: &SqlWithStatement,
+) -> Vec<SqlCommonTableExpressionSummary> {
+    let mut summaries = Vec::new();
+    let mut entity = statement.select.entity.as_str();
+    let mut remaining = statement.common_table_expressions.as_slice();
+    while let Some(index) = common_table_expression_position(remaining, entity) {
+        let cte = &remaining[index];
+        let materialization = if select_is_grouped(&cte.select) {
+            SqlCommonTableExpressionMaterialization::Grouped
+        } else if cte.select.distinct {
+            SqlCommonTableExpressionMaterialization::Distinct
+        } else {
+            SqlCommonTableExpressionMaterialization::Inline
+        };
+        summaries.push(SqlCommonTableExpressionSummary {
+            name: cte.name.clone(),
+            materialization,
+        });
+        entity = cte.select.entity.as_str();
+        remaining = &remaining[..index];
+    }
+    summaries.reverse();
+
+    summaries
+}
+
+// Names must be unique within one clause, and every entry must feed the outer
+// query: an unread body would otherwise never be validated against a schema.
+fn validate_common_table_expression_names(
+    statement: &SqlWithStatement,
+) -> Result<(), SqlLoweringError> {
+    let ctes = statement.common_table_expressions.as_slice();
+    for (index, cte) in ctes.iter().enumerate() {
+        if ctes[..index]
+            .iter()
+            .any(|earlier| earlier.name.eq_ignore_ascii_case(cte.name.as_str()))
+        {
+            return Err(SqlLoweringError::unsupported_cte(
+                SqlCteReason::DuplicateName,
+            ));
+        }
+    }
+
+    let mut referenced = vec![false; ctes.len()];
+    let mut entity = statement.select.entity.as_str();
+    let mut remaining = ctes.len();
+    while let Some(index) = common_table_expression_position(&ctes[..remaining], entity) {
+        referenced[index] = true;
+        entity = ctes[index].select.entity.as_str();
+        remaining = index;
+    }
+    if referenced.contains(&false) {
+        return Err(SqlLoweringError::unsupported_cte(
+            SqlCteReason::Unreferenced,
+        ));
+    }
+
+    Ok(())
+}
+
+fn common_table_expression_position(
+    ctes: &[SqlCommonTableExpression],
+    entity: &str,
+) -> Option<usize> {
+    ctes.iter()
+        .rposition(|cte| cte.name.eq_ignore_ascii_case(entity))
+}
+
+// Resolve one SELECT against the entries visible to it. Both sides are
+// normalized onto their own FROM name first so qualifiers and ORDER BY /
+// HAVING aliases are already spelled as plain column references.
+fn inline_select(
+    select: SqlSelectStatement,
+    ctes: &[SqlCommonTableExpression],
+) -> Result<SqlSelectStatement, SqlLoweringError> {
+    let entity = select.entity.clone();
+    let select = normalize_select_statement_to_expected_entity(select, entity.as_str())?;
+    let Some(index) = common_table_expression_position(ctes, entity.as_str()) else {
+        return Ok(select);
+    };
+    let body = inline_select(ctes[index].select.clone(), &ctes[..index])?;
+
+    merge_outer_select(select, body)
+}
+
+// Fold one outer SELECT onto the already inlined body it reads. The body's
+// grouping, DISTINCT, and window stay authoritative; the outer query may only
+// add clauses that keep the same result when evaluated after them.
+fn merge_outer_select(
+    outer: SqlSelectStatement,
+    body: SqlSelectStatement,
+) -> Result<SqlSelectStatement, SqlLoweringError> {
+    let columns = CteColumns::from_body(&body);
+    let body_grouped = select_is_grouped(&body);
+    let body_windowed = body.limit.is_some() || body.offset.is_some() || !body.limit_per.is_empty();
+    let outer_grouped = select_is_grouped(&outer);
+    let outer_projects_all = matches!(outer.projection, SqlProjection::All);
+
+    // Phase 1: admit only outer shapes that commute with the body stage.
+    if body_windowed
+        && (outer.predicate.is_some()
+            || outer.distinct
+            || outer_grouped
+            || !outer.order_by.is_empty()
+            || outer.limit.is_some()
+            || outer.offset.is_some()
+            || !outer.limit_per.is_empty())
+    {
+        return Err(SqlLoweringError::unsupported_cte(SqlCteReason::OuterShape));
+    }
+    if (body_grouped || body.distinct)
+        && (outer_grouped || !outer.having.is_empty() || !outer.limit_per.is_empty())
+    {
+        return Err(SqlLoweringError::unsupported_cte(SqlCteReason::OuterShape));
+    }
+    if body_grouped && outer.distinct {
+        return Err(SqlLoweringError::unsupported_cte(SqlCteReason::OuterShape));
+    }
+
+    // Phase 2: rewrite every outer column reference onto the body expression.
+    let (projection, projection_aliases) = if outer_projects_all {
+        (body.projection.clone(), body.projection_aliases.clone())
+    } else {
+        columns.substitute_projection(&outer)?
+    };
+    if body.distinct && !outer.distinct && !columns.covered_by(&projection) {
+        return Err(SqlLoweringError::unsupported_cte(SqlCteReason::OuterShape));
+    }
+    let outer_predicate = outer
+        .predicate
+        .map(|predicate| columns.substitute_expr(predicate))
+        .transpose()?;
+    let outer_group_by = columns.substitute_exprs(outer.group_by)?;
+    let outer_having = columns.substitute_exprs(outer.having)?;
+    let outer_order_by = columns.substitute_order_terms(outer.order_by)?;
+    let outer_limit_per = columns.substitute_limit_per(outer.limit_per)?;
+
+    // Phase 3: place the outer filter after the body stage it must observe.
+    let mut predicate = body.predicate;
+    let mut having = body.having;
+    if body_grouped {
+        having.extend(outer_predicate);
+    } else {
+        predicate = match (predicate, outer_predicate) {
+            (Some(left), Some(right)) => Some(SqlExpr::Binary {
+                op: SqlExprBinaryOp::And,
+                left: Box::new(left),
+                right: Box::new(right),
+            }),
+            (left, right) => left.or(right),
+        };
+        having.extend(outer_having);
+    }
+    let group_by = if body_grouped {
+        body.group_by
+    } else {
+        outer_group_by
+    };
+    let order_by = if outer_order_by.is_empty() && !outer_grouped {
+        body.order_by
+    } else {
+        outer_order_by
+    };
+    let limit_per = if body.limit_per.is_empty() {
+        outer_limit_per
+    } else {
+        body.limit_per
+    };
+
+    Ok(SqlSelectStatement {
+        entity: body.entity,
+        table_alias: None,
+        projection,
+        projection_aliases,
+        predicate,
+        distinct: body.distinct || outer.distinct,
+        group_by,
+        having,
+        order_by,
+        limit: outer.limit.or(body.limit),
+        limit_per,
+        offset: outer.offset.or(body.offset),
+    })
+}
+
+fn select_is_grouped(select: &SqlSelectStatement) -> bool {
+    !select.group_by.is_empty()
+        || matches!(&select.projection, SqlProjection::Items(items)
+            if items.iter().any(SqlSelectItem::contains_aggregate))
+}
+
+///
+/// CteColumns
+///
+/// Output columns one inlined body exposes to the query reading it. A `*`
+/// body passes base-entity fields through unchanged.
+///
+
+enum CteColumns {
+    Passthrough,
+    Projected(Vec<(Option<String>, SqlExpr)>),
+}
+
+impl CteColumns {
+    // Name each projected body column by its alias, or by the last segment of
+    // a plain field. Unnamed computed columns stay projected but unreadable.
+    fn from_body(body: &SqlSelectStatement) -> Self {
+        let SqlProjection::Items(items) = &body.projection else {
+            return Self::Passthrough;
+        };
+
+        Self::Projected(
+            items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    let alias = body.projection_aliases.get(index).cloned().flatten();
+                    let name = alias.or_else(|| match item {
+                        SqlSelectItem::Field(field) => {
+                            identifier_last_segment(field).map(str::to_string)
+                        }
+                        SqlSelectItem::Aggregate(_) | SqlSelectItem::Expr(_) => None,
+                    });
+
+                    (name, SqlExpr::from_select_item(item))
+                })
+                .collect(),
+        )
+    }
+
+    // A DISTINCT body keeps its row set under an outer projection only when
+    // every body column is still projected.
+    fn covered_by(&self, projection: &SqlProjection) -> bool {
+        let SqlProjection::Items(items) = projection else {
+            return true;
+        };
+        let Self::Projected(columns) = self else {
+            return false;
+        };
+
+        columns.iter().all(|(_, column)| {
+            items
+                .iter()
+                .any(|item| &SqlExpr::from_select_item(item) == column)
+        })
+    }
+
+    fn column(&self, name: &str) -> Result<Option<&SqlExpr>, SqlLoweringError> {
+        let Self::Projected(columns) = self else {
+            return Ok(None);
+        };
+
+        columns
+            .iter()
+            .find(|(column, _)| column.as_deref() == Some(name))
+            .map(|(_, expr)| Some(expr))
+            .ok_or_else(|| SqlLoweringError::unsupported_cte(SqlCteReason::ColumnReference))
+    }
+
+    // Rewrite one explicit outer projection. Columns read by name keep that
+    // name as their output label when the body spells them differently.
+    fn substitute_projection(
+        &self,
+        outer: &SqlSelectStatement,
+    ) -> Result<(SqlProjection, Vec<Option<String>>), SqlLoweringError> {
+        let SqlProjection::Items(items) = &outer.projection else {
+            return Ok((SqlProjection::All, Vec::new()));
+        };
+        let mut substituted = Vec::with_capacity(items.len());
+        let mut aliases = Vec::with_capacity(items.len());
+        for (index, item) in items.iter().enumerate() {
+            let expr = self.substitute_expr(SqlExpr::from_select_item(item))?;
+            let alias = outer
+                .projection_aliases
+                .get(index)
+                .cloned()
+                .flatten()
+                .or_else(|| match item {
+                    SqlSelectItem::Field(field) if expr != SqlExpr::Field(field.clone()) => {
+                        Some(field.clone())
+                    }
+                    SqlSelectItem::Field(_)
+                    | SqlSelectItem::Aggregate(_)
+                    | SqlSelectItem::Expr(_) => None,
+                });
+            substituted.push(match expr {
+                SqlExpr::Field(field) => SqlSelectItem::Field(field),
+                SqlExpr::Aggregate(aggregate) => SqlSelectItem::Aggregate(aggregate),
+                expr => SqlSelectItem::Expr(expr),
+            });
+            aliases.push(alias);
+        }
+
+        Ok((SqlProjection::Items(substituted), aliases))
+    }
+
+    fn substitute_exprs(&self, exprs: Vec<SqlExpr>) -> Result<Vec<SqlExpr>, SqlLoweringError> {
+        exprs
+            .into_iter()
+            .map(|expr| self.substitute_expr(expr))
+            .collect()
+    }
+
+    fn substitute_order_terms(
+        &self,
+        terms: Vec<SqlOrderTerm>,
+    ) -> Result<Vec<SqlOrderTerm>, SqlLoweringError> {
+        terms
+            .into_iter()
+            .map(|term| {
+                Ok(SqlOrderTerm {
+                    field: self.substitute_expr(term.field)?,
+                    ..term
+                })
+            })
+            .collect()
+    }
+
+    // `LIMIT ... PER` names partition fields, so each must stay a plain field.
+    fn substitute_limit_per(&self, fields: Vec<String>) -> Result<Vec<String>, SqlLoweringError> {
+        fields
+            .into_iter()
+            .map(|field| match self.column(field.as_str())? {
+                None => Ok(field),
+                Some(SqlExpr::Field(field)) => Ok(field.clone()),
+                Some(_) => Err(SqlLoweringError::unsupported_cte(
+                    SqlCteReason::ColumnReference,
+                )),
+            })
+            .collect()
+    }
+
+    fn substitute_expr(&self, expr: SqlExpr) -> Result<SqlExpr, SqlLoweringError> {
+        Ok(match expr {
+            SqlExpr::Field(field) => self
+                .column(field.as_str())?
+                .cloned()
+                .unwrap_or(SqlExpr::Field(field)),
+            SqlExpr::FieldPath { root, segments } => match self.column(root.as_str())? {
+                None => SqlExpr::FieldPath { root, segments },
+                Some(SqlExpr::Field(field)) => SqlExpr::FieldPath {
+                    root: field.clone(),
+                    segments,
+                },
+                Some(SqlExpr::FieldPath {
+                    root,
+                    segments: prefix,
+                }) => SqlExpr::FieldPath {
+                    root: root.clone(),
+                    segments: prefix.iter().cloned().chain(segments).collect(),
+                },
+                Some(_) => {
+                    return Err(SqlLoweringError::unsupported_cte(
+                        SqlCteReason::ColumnReference,
+                    ));
+                }
+            },
+            SqlExpr::Aggregate(aggregate) => {
+                SqlExpr::Aggregate(self.substitute_aggregate(aggregate)?)
+            }
+            SqlExpr::Literal(literal) => SqlExpr::Literal(literal),
+            SqlExpr::Param { index } => SqlExpr::Param { index },
+            // The subquery reads its own entity; only the probe sees columns.
+            SqlExpr::SemiJoin {
+                probe,
+                source,
+                negated,
+            } => SqlExpr::SemiJoin {
+                probe: probe
+                    .map(|probe| self.substitute_expr(*probe).map(Box::new))
+                    .transpose()?,
+                source,
+                negated,
+            },
+            SqlExpr::Membership {
+                expr,
+                values,
+                negated,
+            } => SqlExpr::Membership {
+                expr: Box::new(self.substitute_expr(*expr)?),
+                values,
+                negated,
+            },
+            SqlExpr::NullTest { expr, negated } => SqlExpr::NullTest {
+                expr: Box::new(self.substitute_expr(*expr)?),
+                negated,
+            },
+            SqlExpr::Like {
+                expr,
+                pattern,
+                escape,
+                negated,
+                casefold,
+            } => SqlExpr::Like {
+                expr: Box::new(self.substitute_expr(*expr)?),
+                pattern,
+                escape,
+                negated,
+                casefold,
+            },
+            SqlExpr::FunctionCall { function, args } => SqlExpr::FunctionCall {
+                function,
+                args: self.substitute_exprs(args)?,
+            },
+            SqlExpr::Unary { op, expr } => SqlExpr::Unary {
+                op,
+                expr: Box::new(self.substitute_expr(*expr)?),
+            },
+            SqlExpr::Binary { op, left, right } => SqlExpr::Binary {
+                op,
+                left: Box::new(self.substitute_expr(*left)?),
+                right: Box::new(self.substitute_expr(*right)?),
+            },
+            SqlExpr::Case { arms, else_expr } => SqlExpr::Case {
+                arms: arms
+                    .into_iter()
+                    .map(|arm| {
+                        Ok(SqlCaseArm {
+                            condition: self.substitute_expr(arm.condition)?,
+                            result: self.substitute_expr(arm.result)?,
+                        })
+                    })
+                    .collect::<Result<_, SqlLoweringError>>()?,
+                else_expr: else_expr
+                    .map(|else_expr| self.substitute_expr(*else_expr).map(Box::new))
+                    .transpose()?,
+            },
+        })
+    }
+
+    fn substitute_aggregate(
+        &self,
+        aggregate: SqlAggregateCall,
+    ) -> Result<SqlAggregateCall, SqlLoweringError> {
+        Ok(SqlAggregateCall {
+            kind: aggregate.kind,
+            input: aggregate
+                .input
+                .map(|input| self.substitute_expr(*input).map(Box::new))
+                .transpose()?,
+            filter_expr: aggregate
+                .filter_expr
+                .map(|expr| self.substitute_expr(*expr).map(Box::new))
+                .transpose()?,
+            distinct: aggregate.distinct,
+            arguments: aggregate
+                .arguments
+                .map(|arguments| {
+                    Ok::<_, SqlLoweringError>(Box::new(match *arguments {
+                        arguments @ SqlAggregateArguments::Percentile { .. } => arguments,
+                        SqlAggregateArguments::StringAgg {
+                            separator,
+                            order_by,
+                        } => SqlAggregateArguments::StringAgg {
+                            separator,
+                            order_by: self.substitute_order_terms(order_by)?,
+                        },
+                        SqlAggregateArguments::ArrayAgg { order_by } => {
+                            SqlAggregateArguments::ArrayAgg {
+                                order_by: self.substitute_order_terms(order_by)?,
+                            }
+                        }
+                    }))
+                })
+                .transpose()?,
+        })
+    }
+}
@@ -19,3 +19,13 @@ pub(in crate::db) fn parse_integrity_sql(
 ) -> Result<SqlIntegrityStatement, parser::SqlParseError> {
     parser::parse_integrity_sql(sql)
 }
+
+/// Parse one standalone row-local scalar expression.
+///
+/// Accepted generated-column metadata stores SQL text, so the write program
+/// compiler re-enters the shared expression grammar through this boundary.
+pub(in crate::db) fn parse_scalar_expr_sql(
+    sql: &str,
+) -> Result<parser::SqlExpr, parser::SqlParseError> {
+    parser::parse_scalar_expr_sql(sql)
+}
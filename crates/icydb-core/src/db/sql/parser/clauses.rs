@@ -6,9 +6,10 @@
 use crate::db::sql::parser::projection::SqlExprParseSurface;
 use crate::db::{
     sql::parser::{
-        Parser, SqlExpr, SqlExprBinaryOp, SqlOrderDirection, SqlOrderTerm, SqlScalarFunction,
+        Parser, SqlExpr, SqlExprBinaryOp, SqlNullsOrder, SqlOrderDirection, SqlOrderTerm,
+        SqlScalarFunction,
     },
-    sql_shared::{Keyword, SqlParseError},
+    sql_shared::{Keyword, SqlParseError, TokenKind},
 };
 use icydb_diagnostic_code::SqlFeatureCode;
 
@@ -25,8 +26,13 @@ impl Parser {
                 self.eat_keyword(Keyword::Asc);
                 SqlOrderDirection::Asc
             };
+            let nulls = self.parse_order_nulls_placement()?;
 
-            terms.push(SqlOrderTerm { field, direction });
+            terms.push(SqlOrderTerm {
+                field,
+                direction,
+                nulls,
+            });
             if !self.eat_comma() {
                 break;
             }
@@ -35,6 +41,20 @@ impl Parser {
         Ok(terms)
     }
 
+    // Parse one optional `NULLS FIRST | NULLS LAST` suffix. `NULLS` stays an
+    // identifier keyword so field names such as `nulls` remain usable.
+    fn parse_order_nulls_placement(&mut self) -> Result<Option<SqlNullsOrder>, SqlParseError> {
+        if !self.eat_identifier_keyword("NULLS") {
+            return Ok(None);
+        }
+        if self.eat_identifier_keyword("FIRST") {
+            return Ok(Some(SqlNullsOrder::First));
+        }
+        self.expect_identifier_keyword("LAST")?;
+
+        Ok(Some(SqlNullsOrder::Last))
+    }
+
     fn parse_order_term_target(&mut self) -> Result<SqlExpr, SqlParseError> {
         if self.peek_keyword(Keyword::Case) {
             return self.parse_sql_expr(SqlExprParseSurface::Projection, 0);
@@ -51,6 +71,9 @@ impl Parser {
         }
 
         let field = self.expect_identifier()?;
+        if matches!(self.peek_kind(), Some(TokenKind::LBracket)) {
+            return self.parse_map_key_subscripts(field);
+        }
         if let Some(op) = self.parse_direct_order_arithmetic_op() {
             return self
                 .parse_projection_arithmetic_from_left(SqlExpr::from_field_identifier(field), op);
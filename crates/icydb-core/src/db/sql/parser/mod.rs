@@ -26,21 +26,21 @@ use icydb_diagnostic_code::SqlFeatureCode;
 
 pub(crate) use crate::db::sql_shared::SqlParseError;
 pub(crate) use model::{
-    SqlAggregateCall, SqlAggregateKind, SqlAlterColumnAction,
+    SqlAggregateArguments, SqlAggregateCall, SqlAggregateKind, SqlAlterColumnAction,
     SqlAlterTableAddCheckConstraintStatement, SqlAlterTableAddColumnStatement,
     SqlAlterTableAlterColumnStatement, SqlAlterTableDropColumnStatement,
     SqlAlterTableDropConstraintStatement, SqlAlterTableRenameColumnStatement,
-    SqlAlterTableValidateConstraintStatement, SqlAssignment, SqlCaseArm,
+    SqlAlterTableValidateConstraintStatement, SqlAssignment, SqlCaseArm, SqlCommonTableExpression,
     SqlCreateIndexExpressionFunction, SqlCreateIndexExpressionKey, SqlCreateIndexKeyItem,
     SqlCreateIndexStatement, SqlCreateIndexUniqueness, SqlDdlSchemaVersionContract,
     SqlDdlStatement, SqlDeleteStatement, SqlDescribeMode, SqlDescribeStatement,
-    SqlDropIndexStatement, SqlExpr, SqlExprBinaryOp, SqlExprUnaryOp, SqlInsertSource,
-    SqlInsertStatement, SqlIntegrityStatement, SqlOrderDirection, SqlOrderTerm, SqlProjection,
-    SqlReturningProjection, SqlScalarFunction, SqlScalarFunctionCallShape, SqlSelectItem,
-    SqlSelectStatement, SqlShowColumnsStatement, SqlShowConstraintsStatement,
-    SqlShowEntitiesStatement, SqlShowIndexesStatement, SqlShowMemoryStatement,
-    SqlShowRelationsStatement, SqlShowStoresStatement, SqlStatement, SqlUpdateStatement,
-    SqlWriteValue,
+    SqlDropIndexStatement, SqlExpr, SqlExprBinaryOp, SqlExprUnaryOp, SqlInsertConflictAction,
+    SqlInsertOnConflict, SqlInsertSource, SqlInsertStatement, SqlIntegrityStatement, SqlNullsOrder,
+    SqlOrderDirection, SqlOrderTerm, SqlProjection, SqlReturningProjection, SqlScalarFunction,
+    SqlScalarFunctionCallShape, SqlSelectItem, SqlSelectStatement, SqlShowColumnsStatement,
+    SqlShowConstraintsStatement, SqlShowEntitiesStatement, SqlShowIndexesStatement,
+    SqlShowMemoryStatement, SqlShowRelationsStatement, SqlShowStoresStatement, SqlStatement,
+    SqlUnionStatement, SqlUpdateStatement, SqlWithStatement, SqlWriteValue,
 };
 #[cfg(feature = "sql")]
 pub(crate) use model::{SqlExplainMode, SqlExplainStatement, SqlExplainTarget};
@@ -150,14 +150,45 @@ pub(super) fn parse_integrity_sql(sql: &str) -> Result<SqlIntegrityStatement, Sq
     Ok(statement)
 }
 
+/// Parse one standalone row-local scalar expression.
+///
+/// Generated-column expressions are persisted as SQL text in accepted schema
+/// metadata and re-enter the shared scalar expression grammar here, using the
+/// same write-value surface as computed `UPDATE` assignments.
+pub(super) fn parse_scalar_expr_sql(sql: &str) -> Result<SqlExpr, SqlParseError> {
+    let tokens = tokenize_sql(sql)?;
+    if tokens.is_empty() {
+        return Err(SqlParseError::EmptyInput);
+    }
+
+    let mut parser = Parser::new(SqlTokenCursor::new(tokens));
+    let expr = parser.parse_sql_expr(projection::SqlExprParseSurface::WriteValue, 0)?;
+
+    if !parser.is_eof() {
+        return Err(SqlParseError::expected_end_of_input(parser.peek_kind()));
+    }
+
+    Ok(expr)
+}
+
 // Parser state over one pre-tokenized SQL statement.
 struct Parser {
     cursor: SqlTokenCursor,
     attribution: SqlParsePhaseAttribution,
     next_param_index: usize,
+    param_style: Option<ParamPlaceholderStyle>,
     expr_depth: usize,
 }
 
+// Placeholder numbering style fixed by the first placeholder in one statement.
+// Anonymous `?` slots number themselves in source order, so mixing them with
+// explicit `$n` slots would make the bind-value order ambiguous.
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum ParamPlaceholderStyle {
+    Anonymous,
+    Positional,
+}
+
 impl Parser {
     const fn new(cursor: SqlTokenCursor) -> Self {
         Self {
@@ -169,6 +200,7 @@ impl Parser {
                 predicate: 0,
             },
             next_param_index: 0,
+            param_style: None,
             expr_depth: 0,
         }
     }
@@ -272,8 +304,28 @@ impl Parser {
         self.cursor.eat_plus()
     }
 
-    fn eat_question(&mut self) -> bool {
-        self.cursor.eat_question()
+    // Consume one `?` or `$n` placeholder and return its zero-based bind
+    // slot, rejecting statements that mix both placeholder styles.
+    fn eat_param_placeholder(&mut self) -> Result<Option<usize>, SqlParseError> {
+        let (style, index) = if self.cursor.eat_question() {
+            (ParamPlaceholderStyle::Anonymous, self.take_param_index())
+        } else if let Some(index) = self.cursor.eat_positional_param() {
+            (ParamPlaceholderStyle::Positional, index)
+        } else {
+            return Ok(None);
+        };
+        if self.param_style.is_some_and(|current| current != style) {
+            return Err(SqlParseError::invalid_syntax(
+                SqlSyntaxErrorKind::MixedParameterPlaceholders,
+            ));
+        }
+        self.param_style = Some(style);
+
+        Ok(Some(index))
+    }
+
+    fn eat_double_colon(&mut self) -> bool {
+        self.cursor.eat_double_colon()
     }
 
     fn eat_minus(&mut self) -> bool {
@@ -20,6 +20,8 @@ use crate::{
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) enum SqlStatement {
     Select(SqlSelectStatement),
+    Union(SqlUnionStatement),
+    With(SqlWithStatement),
     Delete(SqlDeleteStatement),
     Insert(SqlInsertStatement),
     Update(SqlUpdateStatement),
@@ -108,6 +110,7 @@ pub(crate) struct SqlAlterTableAddColumnStatement {
     pub(crate) column_type: String,
     pub(crate) nullable: bool,
     pub(crate) default: Option<Value>,
+    pub(crate) generated: Option<String>,
     pub(crate) schema_version_contract: SqlDdlSchemaVersionContract,
 }
 
@@ -208,6 +211,8 @@ pub(crate) struct SqlCreateIndexStatement {
     pub(crate) name: String,
     pub(crate) entity: String,
     pub(crate) key_items: Vec<SqlCreateIndexKeyItem>,
+    pub(crate) descending: Vec<String>,
+    pub(crate) include: Vec<String>,
     pub(crate) predicate_sql: Option<String>,
     pub(crate) uniqueness: SqlCreateIndexUniqueness,
     pub(crate) if_not_exists: bool,
@@ -217,16 +222,17 @@ pub(crate) struct SqlCreateIndexStatement {
 ///
 /// SqlCreateIndexKeyItem
 ///
-/// Parser-owned SQL DDL index key item. Field-path keys are executable today;
-/// expression keys are parsed so DDL binding can validate catalog references
-/// before failing closed until the physical expression-index rebuild runner is
-/// available.
+/// Parser-owned SQL DDL index key item. Single-field function keys keep their
+/// dedicated expression shape; any other expression key is carried as its
+/// source SQL so DDL binding can lower it through the scalar expression
+/// pipeline against the accepted catalog.
 ///
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) enum SqlCreateIndexKeyItem {
     FieldPath(String),
     Expression(SqlCreateIndexExpressionKey),
+    Scalar(String),
 }
 
 ///
@@ -251,6 +257,8 @@ impl SqlCreateIndexExpressionKey {
 /// SqlCreateIndexExpressionFunction
 ///
 /// Parser-owned deterministic function subset staged for expression-index DDL.
+/// `Tokens` and `Elements` are never spelled directly; `USING TEXT` and
+/// `USING MULTIVALUE` wrap their single field key.
 ///
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -258,6 +266,8 @@ pub(crate) enum SqlCreateIndexExpressionFunction {
     Lower,
     Upper,
     Trim,
+    Tokens,
+    Elements,
 }
 
 impl SqlCreateIndexExpressionFunction {
@@ -278,6 +288,8 @@ impl SqlCreateIndexExpressionFunction {
             Self::Lower => "LOWER",
             Self::Upper => "UPPER",
             Self::Trim => "TRIM",
+            Self::Tokens => "TOKENS",
+            Self::Elements => "ELEMENTS",
         }
     }
 }
@@ -389,6 +401,8 @@ pub(crate) enum SqlExprUnaryOp {
 /// Parser-owned binary SQL expression operator taxonomy.
 /// This unifies arithmetic, comparison, and boolean operators on the SQL-side
 /// expression boundary instead of scattering clause-local operator enums.
+/// `IsDistinctFrom` and `IsNotDistinctFrom` are the null-safe comparisons;
+/// they never evaluate to `NULL`.
 ///
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -397,6 +411,8 @@ pub(crate) enum SqlExprBinaryOp {
     And,
     Eq,
     Ne,
+    IsDistinctFrom,
+    IsNotDistinctFrom,
     Lt,
     Lte,
     Gt,
@@ -455,6 +471,7 @@ pub(crate) enum SqlExpr {
     Like {
         expr: Box<Self>,
         pattern: String,
+        escape: Option<char>,
         negated: bool,
         casefold: bool,
     },
@@ -475,6 +492,11 @@ pub(crate) enum SqlExpr {
         arms: Vec<SqlCaseArm>,
         else_expr: Option<Box<Self>>,
     },
+    SemiJoin {
+        probe: Option<Box<Self>>,
+        source: Box<SqlSelectStatement>,
+        negated: bool,
+    },
 }
 
 impl SqlExpr {
@@ -532,7 +554,8 @@ impl SqlExpr {
             Self::FieldPath { .. }
             | Self::Aggregate(_)
             | Self::FunctionCall { .. }
-            | Self::Case { .. } => false,
+            | Self::Case { .. }
+            | Self::SemiJoin { .. } => false,
         })
     }
 
@@ -552,7 +575,8 @@ impl SqlExpr {
             | Self::FunctionCall { .. }
             | Self::Unary { .. }
             | Self::Binary { .. }
-            | Self::Case { .. } => true,
+            | Self::Case { .. }
+            | Self::SemiJoin { .. } => true,
         })
     }
 
@@ -565,6 +589,13 @@ impl SqlExpr {
         )
     }
 
+    /// Return whether this SQL expression tree contains any `IN (SELECT ...)`
+    /// or `EXISTS (SELECT ...)` semi-join that still needs session resolution.
+    #[must_use]
+    pub(in crate::db) fn contains_semi_join(&self) -> bool {
+        self.any_tree_expr(&mut |expr| matches!(expr, Self::SemiJoin { .. }))
+    }
+
     /// Visit every SQL expression node through the owner-local parser
     /// traversal contract.
     pub(in crate::db::sql) fn for_each_tree_expr(&self, visit: &mut impl FnMut(&Self)) {
@@ -598,6 +629,11 @@ impl SqlExpr {
                     else_expr.for_each_tree_expr(visit);
                 }
             }
+            Self::SemiJoin { probe, .. } => {
+                if let Some(probe) = probe.as_ref() {
+                    probe.for_each_tree_expr(visit);
+                }
+            }
         }
     }
 
@@ -649,6 +685,9 @@ impl SqlExpr {
                     .as_ref()
                     .is_some_and(|else_expr| else_expr.any_tree_expr(predicate))
             }
+            Self::SemiJoin { probe, .. } => probe
+                .as_ref()
+                .is_some_and(|probe| probe.any_tree_expr(predicate)),
         }
     }
 
@@ -682,6 +721,9 @@ impl SqlExpr {
                     .as_ref()
                     .is_none_or(|else_expr| else_expr.all_tree_expr(predicate))
             }
+            Self::SemiJoin { probe, .. } => probe
+                .as_ref()
+                .is_none_or(|probe| probe.all_tree_expr(predicate)),
         }
     }
 }
@@ -698,6 +740,13 @@ pub(crate) enum SqlAggregateKind {
     Avg,
     Min,
     Max,
+    VarPop,
+    VarSamp,
+    StddevPop,
+    StddevSamp,
+    PercentileDisc,
+    StringAgg,
+    ArrayAgg,
 }
 
 impl SqlAggregateKind {
@@ -711,7 +760,26 @@ impl SqlAggregateKind {
     /// the shared field-target aggregate shape.
     #[must_use]
     pub(in crate::db::sql) const fn lowers_shared_field_target_shape(self) -> bool {
-        !matches!(self, Self::Count)
+        matches!(self, Self::Sum | Self::Avg | Self::Min | Self::Max)
+    }
+
+    /// Resolve one identifier-spelled aggregate function name. Only the
+    /// statistical and retained-value families are spelled as identifiers;
+    /// the classic five aggregates remain reserved keywords.
+    #[must_use]
+    pub(crate) fn from_identifier(name: &str) -> Option<Self> {
+        let kind = match name.to_ascii_uppercase().as_str() {
+            "VAR_POP" => Self::VarPop,
+            "VAR_SAMP" | "VARIANCE" => Self::VarSamp,
+            "STDDEV_POP" => Self::StddevPop,
+            "STDDEV_SAMP" | "STDDEV" => Self::StddevSamp,
+            "PERCENTILE_DISC" | "MEDIAN" => Self::PercentileDisc,
+            "STRING_AGG" => Self::StringAgg,
+            "ARRAY_AGG" => Self::ArrayAgg,
+            _ => return None,
+        };
+
+        Some(kind)
     }
 
     /// Return the canonical planner aggregate kind for this parsed SQL kind.
@@ -723,6 +791,13 @@ impl SqlAggregateKind {
             Self::Avg => AggregateKind::Avg,
             Self::Min => AggregateKind::Min,
             Self::Max => AggregateKind::Max,
+            Self::VarPop => AggregateKind::VarPop,
+            Self::VarSamp => AggregateKind::VarSamp,
+            Self::StddevPop => AggregateKind::StddevPop,
+            Self::StddevSamp => AggregateKind::StddevSamp,
+            Self::PercentileDisc => AggregateKind::PercentileDisc,
+            Self::StringAgg => AggregateKind::StringAgg,
+            Self::ArrayAgg => AggregateKind::ArrayAgg,
         }
     }
 }
@@ -732,6 +807,7 @@ impl SqlAggregateKind {
 ///
 /// Parsed aggregate call projection item.
 /// `input = None` is only valid for `COUNT(*)`.
+/// `arguments` is only populated for retained-value aggregates.
 ///
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -740,6 +816,7 @@ pub(crate) struct SqlAggregateCall {
     pub(crate) input: Option<Box<SqlExpr>>,
     pub(crate) filter_expr: Option<Box<SqlExpr>>,
     pub(crate) distinct: bool,
+    pub(crate) arguments: Option<Box<SqlAggregateArguments>>,
 }
 
 impl SqlAggregateCall {
@@ -757,6 +834,46 @@ impl SqlAggregateCall {
                 .filter_expr
                 .as_deref()
                 .is_none_or(SqlExpr::is_already_local_scalar)
+            && self.arguments.as_deref().is_none_or(|arguments| {
+                arguments
+                    .order_by()
+                    .iter()
+                    .all(|term| term.field.is_already_local_scalar())
+            })
+    }
+}
+
+///
+/// SqlAggregateArguments
+///
+/// Parsed non-input arguments of one retained-value aggregate call.
+/// `PERCENTILE_DISC` keeps its raw fraction literal and input direction;
+/// `STRING_AGG` and `ARRAY_AGG` keep their aggregate-local ORDER BY terms.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum SqlAggregateArguments {
+    Percentile {
+        fraction: Value,
+        direction: SqlOrderDirection,
+    },
+    StringAgg {
+        separator: String,
+        order_by: Vec<SqlOrderTerm>,
+    },
+    ArrayAgg {
+        order_by: Vec<SqlOrderTerm>,
+    },
+}
+
+impl SqlAggregateArguments {
+    /// Borrow the aggregate-local ORDER BY terms.
+    #[must_use]
+    pub(crate) const fn order_by(&self) -> &[SqlOrderTerm] {
+        match self {
+            Self::Percentile { .. } => &[],
+            Self::StringAgg { order_by, .. } | Self::ArrayAgg { order_by } => order_by.as_slice(),
+        }
     }
 }
 
@@ -772,13 +889,18 @@ impl SqlAggregateCall {
 #[remain::sorted]
 pub(crate) enum SqlScalarFunction {
     Abs,
+    Age,
+    Cast,
     Cbrt,
     Ceiling,
     Coalesce,
     Contains,
+    DateTrunc,
     EndsWith,
     Exp,
+    Extract,
     Floor,
+    HasKey,
     Left,
     Length,
     Ln,
@@ -787,11 +909,14 @@ pub(crate) enum SqlScalarFunction {
     Log10,
     Lower,
     Ltrim,
+    Match,
     Mod,
+    Now,
     NullIf,
     OctetLength,
     Position,
     Power,
+    RegexpMatches,
     Replace,
     Right,
     Round,
@@ -816,12 +941,16 @@ pub(crate) enum SqlScalarFunction {
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub(crate) enum SqlScalarFunctionCallShape {
     BinaryExprArgs,
+    Cast,
+    ExtractField,
     FieldPlusLiteral,
+    NoArgs,
     Position,
     Replace,
     NumericScaleSpecial,
     SharedScalarCall,
     Substring,
+    TemporalUnitExprArgs,
     UnaryExpr,
     VariadicExprArgs,
     WherePredicateExprPair,
@@ -842,13 +971,18 @@ impl SqlScalarFunction {
     pub(in crate::db::sql) const fn planner_function(self) -> Function {
         match self {
             Self::Abs => Function::Abs,
+            Self::Age => Function::Age,
+            Self::Cast => Function::Cast,
             Self::Cbrt => Function::Cbrt,
             Self::Ceiling => Function::Ceiling,
             Self::Coalesce => Function::Coalesce,
             Self::Contains => Function::Contains,
+            Self::DateTrunc => Function::DateTrunc,
             Self::EndsWith => Function::EndsWith,
             Self::Exp => Function::Exp,
+            Self::Extract => Function::Extract,
             Self::Floor => Function::Floor,
+            Self::HasKey => Function::HasKey,
             Self::Left => Function::Left,
             Self::Length => Function::Length,
             Self::Ln => Function::Ln,
@@ -857,11 +991,14 @@ impl SqlScalarFunction {
             Self::Log2 => Function::Log2,
             Self::Lower => Function::Lower,
             Self::Ltrim => Function::Ltrim,
+            Self::Match => Function::Match,
             Self::Mod => Function::Mod,
+            Self::Now => Function::Now,
             Self::NullIf => Function::NullIf,
             Self::OctetLength => Function::OctetLength,
             Self::Position => Function::Position,
             Self::Power => Function::Power,
+            Self::RegexpMatches => Function::RegexpMatches,
             Self::Replace => Function::Replace,
             Self::Right => Function::Right,
             Self::Round => Function::Round,
@@ -881,10 +1018,18 @@ impl SqlScalarFunction {
     pub(in crate::db::sql) const fn non_where_call_shape(self) -> SqlScalarFunctionCallShape {
         match self {
             Self::Round | Self::Trunc => SqlScalarFunctionCallShape::NumericScaleSpecial,
+            Self::Cast => SqlScalarFunctionCallShape::Cast,
             Self::Coalesce => SqlScalarFunctionCallShape::VariadicExprArgs,
-            Self::NullIf | Self::Log | Self::Mod | Self::Power => {
-                SqlScalarFunctionCallShape::BinaryExprArgs
-            }
+            Self::DateTrunc => SqlScalarFunctionCallShape::TemporalUnitExprArgs,
+            Self::Extract => SqlScalarFunctionCallShape::ExtractField,
+            Self::Now => SqlScalarFunctionCallShape::NoArgs,
+            Self::Age
+            | Self::NullIf
+            | Self::Log
+            | Self::Mod
+            | Self::Power
+            | Self::HasKey
+            | Self::RegexpMatches => SqlScalarFunctionCallShape::BinaryExprArgs,
             Self::Trim
             | Self::Ltrim
             | Self::Rtrim
@@ -902,9 +1047,12 @@ impl SqlScalarFunction {
             | Self::Upper
             | Self::Length
             | Self::OctetLength => SqlScalarFunctionCallShape::UnaryExpr,
-            Self::Left | Self::Right | Self::StartsWith | Self::EndsWith | Self::Contains => {
-                SqlScalarFunctionCallShape::FieldPlusLiteral
-            }
+            Self::Left
+            | Self::Right
+            | Self::StartsWith
+            | Self::EndsWith
+            | Self::Contains
+            | Self::Match => SqlScalarFunctionCallShape::FieldPlusLiteral,
             Self::Position => SqlScalarFunctionCallShape::Position,
             Self::Replace => SqlScalarFunctionCallShape::Replace,
             Self::Substring => SqlScalarFunctionCallShape::Substring,
@@ -932,7 +1080,11 @@ impl SqlScalarFunction {
             {
                 SqlScalarFunctionCallShape::WherePredicateExprPair
             }
-            SqlScalarFunctionCallShape::UnaryExpr
+            SqlScalarFunctionCallShape::Cast
+            | SqlScalarFunctionCallShape::ExtractField
+            | SqlScalarFunctionCallShape::NoArgs
+            | SqlScalarFunctionCallShape::TemporalUnitExprArgs
+            | SqlScalarFunctionCallShape::UnaryExpr
             | SqlScalarFunctionCallShape::FieldPlusLiteral
             | SqlScalarFunctionCallShape::Position
             | SqlScalarFunctionCallShape::Replace
@@ -947,7 +1099,7 @@ impl SqlScalarFunction {
     /// Resolve one parsed SQL identifier into one supported scalar function.
     #[must_use]
     pub(crate) fn from_identifier(identifier: &str) -> Option<Self> {
-        const SUPPORTED_SCALAR_FUNCTIONS: [(&str, SqlScalarFunction); 35] = [
+        const SUPPORTED_SCALAR_FUNCTIONS: [(&str, SqlScalarFunction); 43] = [
             ("trim", SqlScalarFunction::Trim),
             ("ltrim", SqlScalarFunction::Ltrim),
             ("rtrim", SqlScalarFunction::Rtrim),
@@ -980,9 +1132,17 @@ impl SqlScalarFunction {
             ("starts_with", SqlScalarFunction::StartsWith),
             ("ends_with", SqlScalarFunction::EndsWith),
             ("contains", SqlScalarFunction::Contains),
+            ("regexp_matches", SqlScalarFunction::RegexpMatches),
+            ("match", SqlScalarFunction::Match),
+            ("has_key", SqlScalarFunction::HasKey),
             ("position", SqlScalarFunction::Position),
             ("replace", SqlScalarFunction::Replace),
             ("substring", SqlScalarFunction::Substring),
+            ("cast", SqlScalarFunction::Cast),
+            ("date_trunc", SqlScalarFunction::DateTrunc),
+            ("extract", SqlScalarFunction::Extract),
+            ("age", SqlScalarFunction::Age),
+            ("now", SqlScalarFunction::Now),
         ];
 
         for (name, function) in SUPPORTED_SCALAR_FUNCTIONS {
@@ -1007,16 +1167,31 @@ pub(crate) enum SqlOrderDirection {
     Desc,
 }
 
+///
+/// SqlNullsOrder
+///
+/// Parsed explicit `NULLS FIRST | NULLS LAST` placement for one `ORDER BY`
+/// item.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum SqlNullsOrder {
+    First,
+    Last,
+}
+
 ///
 /// SqlOrderTerm
 ///
-/// Parsed `ORDER BY` expression and direction pair.
+/// Parsed `ORDER BY` expression, direction, and optional explicit null
+/// placement.
 ///
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct SqlOrderTerm {
     pub(crate) field: SqlExpr,
     pub(crate) direction: SqlOrderDirection,
+    pub(crate) nulls: Option<SqlNullsOrder>,
 }
 
 impl SqlOrderTerm {
@@ -1043,7 +1218,8 @@ impl SqlOrderTerm {
             | SqlExpr::FunctionCall { .. }
             | SqlExpr::Unary { .. }
             | SqlExpr::Binary { .. }
-            | SqlExpr::Case { .. } => None,
+            | SqlExpr::Case { .. }
+            | SqlExpr::SemiJoin { .. } => None,
         }
     }
 }
@@ -1066,10 +1242,11 @@ pub(crate) struct SqlSelectStatement {
     pub(crate) projection_aliases: Vec<Option<String>>,
     pub(crate) predicate: Option<SqlExpr>,
     pub(crate) distinct: bool,
-    pub(crate) group_by: Vec<String>,
+    pub(crate) group_by: Vec<SqlExpr>,
     pub(crate) having: Vec<SqlExpr>,
     pub(crate) order_by: Vec<SqlOrderTerm>,
     pub(crate) limit: Option<u32>,
+    pub(crate) limit_per: Vec<String>,
     pub(crate) offset: Option<u32>,
 }
 
@@ -1087,8 +1264,11 @@ impl SqlSelectStatement {
         if !self.having.is_empty() {
             return false;
         }
+        if !self.group_by.iter().all(SqlExpr::is_already_local_scalar) {
+            return false;
+        }
         if !self
-            .group_by
+            .limit_per
             .iter()
             .all(|field| SqlExpr::identifier_is_already_local(field.as_str()))
         {
@@ -1111,6 +1291,84 @@ impl SqlSelectStatement {
     }
 }
 
+///
+/// SqlUnionStatement
+///
+/// Raw parsed `SELECT ... UNION [ALL] SELECT ...` chain. Every branch uses
+/// the same set quantifier, and the trailing `ORDER BY` / `LIMIT` / `OFFSET`
+/// belong to the combined rows rather than to the last branch.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct SqlUnionStatement {
+    pub(crate) branches: Vec<SqlSelectStatement>,
+    pub(crate) all: bool,
+    pub(crate) order_by: Vec<SqlOrderTerm>,
+    pub(crate) limit: Option<u32>,
+    pub(crate) offset: Option<u32>,
+}
+
+///
+/// SqlCommonTableExpression
+///
+/// One named `name AS (SELECT ...)` entry of a non-recursive `WITH` clause.
+/// The body may read one base entity or one earlier entry of the same clause.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct SqlCommonTableExpression {
+    pub(crate) name: String,
+    pub(crate) select: SqlSelectStatement,
+}
+
+///
+/// SqlWithStatement
+///
+/// Raw parsed `WITH name AS (SELECT ...) [, ...] SELECT ...` statement.
+/// Entries stay in source order; lowering inlines them into the outer SELECT.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct SqlWithStatement {
+    pub(crate) common_table_expressions: Vec<SqlCommonTableExpression>,
+    pub(crate) select: SqlSelectStatement,
+}
+
+impl SqlWithStatement {
+    /// Borrow every SELECT of this statement, entry bodies first.
+    pub(crate) fn selects(&self) -> impl Iterator<Item = &SqlSelectStatement> {
+        self.common_table_expressions
+            .iter()
+            .map(|cte| &cte.select)
+            .chain(std::iter::once(&self.select))
+    }
+
+    /// Mutably borrow every SELECT of this statement, entry bodies first.
+    pub(crate) fn selects_mut(&mut self) -> impl Iterator<Item = &mut SqlSelectStatement> {
+        self.common_table_expressions
+            .iter_mut()
+            .map(|cte| &mut cte.select)
+            .chain(std::iter::once(&mut self.select))
+    }
+
+    /// Resolve the base entity read by the outer SELECT by following entry
+    /// references back to the first body that names a real entity.
+    #[must_use]
+    pub(crate) fn entity(&self) -> &str {
+        let mut entity = self.select.entity.as_str();
+        let mut remaining = self.common_table_expressions.len();
+        while let Some(index) = self.common_table_expressions[..remaining]
+            .iter()
+            .rposition(|cte| cte.name.eq_ignore_ascii_case(entity))
+        {
+            entity = self.common_table_expressions[index].select.entity.as_str();
+            remaining = index;
+        }
+
+        entity
+    }
+}
+
 ///
 /// SqlReturningProjection
 ///
@@ -1169,13 +1427,45 @@ pub(crate) enum SqlInsertSource {
 ///
 /// `DEFAULT` remains contextual write intent instead of becoming a scalar
 /// `Value`, so lowering cannot accidentally admit it in expressions or lose
-/// its request provenance before accepted-schema resolution.
+/// its request provenance before accepted-schema resolution. `Expr` carries
+/// one non-literal scalar expression that session execution evaluates against
+/// the selected pre-image row.
 ///
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) enum SqlWriteValue {
     Literal(Value),
     Default,
+    Expr(SqlExpr),
+}
+
+///
+/// SqlInsertConflictAction
+///
+/// Reduced `ON CONFLICT` action for one `INSERT` row whose conflict target
+/// already matches a stored row.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum SqlInsertConflictAction {
+    DoNothing,
+    DoUpdate(Vec<SqlAssignment>),
+}
+
+///
+/// SqlInsertOnConflict
+///
+/// Parsed `ON CONFLICT (target) DO ...` clause.
+///
+/// The target names either the primary key or the fields of one unique index;
+/// session execution validates it against accepted schema authority.
+/// `DO UPDATE` assignments may reference the proposed row as `EXCLUDED.field`.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct SqlInsertOnConflict {
+    pub(crate) target: Vec<String>,
+    pub(crate) action: SqlInsertConflictAction,
 }
 
 ///
@@ -1185,7 +1475,8 @@ pub(crate) enum SqlWriteValue {
 ///
 /// This stays intentionally narrow in the current slice: one explicit column
 /// list plus either one or more literal `VALUES` tuples or one scalar
-/// `SELECT` source handled later at the session boundary.
+/// `SELECT` source handled later at the session boundary, and one optional
+/// `ON CONFLICT` clause.
 ///
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -1193,13 +1484,15 @@ pub(crate) struct SqlInsertStatement {
     pub(crate) entity: String,
     pub(crate) columns: Vec<String>,
     pub(crate) source: SqlInsertSource,
+    pub(crate) on_conflict: Option<SqlInsertOnConflict>,
     pub(crate) returning: Option<SqlReturningProjection>,
 }
 
 ///
 /// SqlAssignment
 ///
-/// One parsed `UPDATE ... SET field = value-or-default` assignment.
+/// One parsed `UPDATE ... SET field = value` assignment, where the value is
+/// a literal, `DEFAULT`, or one scalar expression over the same row.
 ///
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -1254,6 +1547,7 @@ pub(crate) enum SqlExplainMode {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) enum SqlExplainTarget {
     Select(SqlSelectStatement),
+    With(SqlWithStatement),
     Delete(SqlDeleteStatement),
 }
 
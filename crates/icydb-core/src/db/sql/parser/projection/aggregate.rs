@@ -1,9 +1,11 @@
 use crate::db::{
     sql::parser::{
-        Parser, SqlAggregateCall, SqlAggregateKind, SqlExpr, projection::SqlExprParseSurface,
+        Parser, SqlAggregateArguments, SqlAggregateCall, SqlAggregateKind, SqlExpr,
+        SqlOrderDirection, SqlOrderTerm, projection::SqlExprParseSurface,
     },
-    sql_shared::{Keyword, SqlParseError, TokenKind},
+    sql_shared::{Keyword, SqlExpectedToken, SqlParseError, TokenKind},
 };
+use crate::{types::Decimal, value::Value};
 use icydb_diagnostic_code::SqlFeatureCode;
 
 impl Parser {
@@ -14,6 +16,11 @@ impl Parser {
             Some(TokenKind::Keyword(Keyword::Avg)) => Some(SqlAggregateKind::Avg),
             Some(TokenKind::Keyword(Keyword::Min)) => Some(SqlAggregateKind::Min),
             Some(TokenKind::Keyword(Keyword::Max)) => Some(SqlAggregateKind::Max),
+            Some(TokenKind::Identifier(name))
+                if matches!(self.cursor.peek_next_kind(), Some(TokenKind::LParen)) =>
+            {
+                SqlAggregateKind::from_identifier(name)
+            }
             _ => None,
         }
     }
@@ -22,15 +29,44 @@ impl Parser {
         &mut self,
         kind: SqlAggregateKind,
     ) -> Result<SqlAggregateCall, SqlParseError> {
+        let median = self.cursor.peek_identifier_keyword("MEDIAN");
         let _ = self.cursor.advance();
         self.expect_lparen()?;
         let distinct = self.eat_keyword(Keyword::Distinct);
 
+        if kind == SqlAggregateKind::PercentileDisc && !median {
+            return self.parse_percentile_disc_call(distinct);
+        }
+
         let input = if kind.supports_star_input() && self.eat_star() {
             None
         } else {
             Some(self.parse_aggregate_input_expr()?)
         };
+        let arguments = match kind {
+            SqlAggregateKind::PercentileDisc => Some(SqlAggregateArguments::Percentile {
+                fraction: Value::Decimal(Decimal::new(5, 1)),
+                direction: SqlOrderDirection::Asc,
+            }),
+            SqlAggregateKind::StringAgg => {
+                if !self.eat_comma() {
+                    return Err(SqlParseError::expected(
+                        SqlExpectedToken::Comma,
+                        self.peek_kind(),
+                    ));
+                }
+                let separator = self.expect_string_literal()?;
+
+                Some(SqlAggregateArguments::StringAgg {
+                    separator,
+                    order_by: self.parse_aggregate_order_by()?,
+                })
+            }
+            SqlAggregateKind::ArrayAgg => Some(SqlAggregateArguments::ArrayAgg {
+                order_by: self.parse_aggregate_order_by()?,
+            }),
+            _ => None,
+        };
 
         self.expect_rparen()?;
         let filter_expr = self.parse_aggregate_filter_clause()?;
@@ -40,9 +76,57 @@ impl Parser {
             input: input.map(Box::new),
             filter_expr: filter_expr.map(Box::new),
             distinct,
+            arguments: arguments.map(Box::new),
+        })
+    }
+
+    // Parse the ordered-set form `PERCENTILE_DISC(p) WITHIN GROUP (ORDER BY x)`.
+    // The ordered expression becomes the aggregate input; the fraction literal
+    // is range-checked during lowering.
+    fn parse_percentile_disc_call(
+        &mut self,
+        distinct: bool,
+    ) -> Result<SqlAggregateCall, SqlParseError> {
+        let fraction = self.parse_literal()?;
+        self.expect_rparen()?;
+        self.expect_identifier_keyword("WITHIN")?;
+        self.expect_keyword(Keyword::Group)?;
+        self.expect_lparen()?;
+        self.expect_keyword(Keyword::Order)?;
+        self.expect_keyword(Keyword::By)?;
+        let input = self.parse_aggregate_input_expr()?;
+        let direction = if self.eat_keyword(Keyword::Desc) {
+            SqlOrderDirection::Desc
+        } else {
+            self.eat_keyword(Keyword::Asc);
+            SqlOrderDirection::Asc
+        };
+        self.expect_rparen()?;
+        let filter_expr = self.parse_aggregate_filter_clause()?;
+
+        Ok(SqlAggregateCall {
+            kind: SqlAggregateKind::PercentileDisc,
+            input: Some(Box::new(input)),
+            filter_expr: filter_expr.map(Box::new),
+            distinct,
+            arguments: Some(Box::new(SqlAggregateArguments::Percentile {
+                fraction,
+                direction,
+            })),
         })
     }
 
+    // Parse one optional aggregate-local `ORDER BY` list inside the call
+    // parentheses of STRING_AGG or ARRAY_AGG.
+    fn parse_aggregate_order_by(&mut self) -> Result<Vec<SqlOrderTerm>, SqlParseError> {
+        if !self.eat_keyword(Keyword::Order) {
+            return Ok(Vec::new());
+        }
+        self.expect_keyword(Keyword::By)?;
+
+        self.parse_order_terms()
+    }
+
     // Parse one aggregate-owned FILTER predicate directly onto the aggregate
     // call instead of rewriting it through CASE or a clause-local wrapper.
     fn parse_aggregate_filter_clause(&mut self) -> Result<Option<SqlExpr>, SqlParseError> {
@@ -63,13 +63,14 @@ impl Parser {
         if let Some(kind) = self.parse_aggregate_kind() {
             return self.parse_aggregate_call(kind).map(SqlExpr::Aggregate);
         }
-        if self.eat_question() {
-            return Ok(SqlExpr::Param {
-                index: self.take_param_index(),
-            });
+        if let Some(index) = self.eat_param_placeholder()? {
+            return Ok(SqlExpr::Param { index });
         }
-        if matches!(self.peek_kind(), Some(TokenKind::Identifier(_))) {
+        if self.cursor.peek_identifier() {
             let field = self.expect_identifier()?;
+            if matches!(self.peek_kind(), Some(TokenKind::LBracket)) {
+                return self.parse_map_key_subscripts(field);
+            }
             if self.peek_lparen() {
                 return Err(SqlParseError::unsupported_feature(
                     SqlFeatureCode::NestedProjectionFunctionInArithmetic,
@@ -108,6 +109,8 @@ const fn arithmetic_projection_op_precedence(op: SqlExprBinaryOp) -> u8 {
         | SqlExprBinaryOp::And
         | SqlExprBinaryOp::Eq
         | SqlExprBinaryOp::Ne
+        | SqlExprBinaryOp::IsDistinctFrom
+        | SqlExprBinaryOp::IsNotDistinctFrom
         | SqlExprBinaryOp::Lt
         | SqlExprBinaryOp::Lte
         | SqlExprBinaryOp::Gt
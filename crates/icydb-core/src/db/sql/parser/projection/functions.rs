@@ -1,13 +1,17 @@
 use crate::{
     db::{
+        query::plan::expr::TemporalField,
+        schema::resolve_checked_cast_target,
         sql::parser::{
             Parser, SqlExpr, SqlScalarFunction, SqlScalarFunctionCallShape,
             projection::SqlExprParseSurface,
         },
         sql_shared::{Keyword, SqlExpectedToken, SqlParseError, SqlSyntaxErrorKind, TokenKind},
     },
+    types::Duration,
     value::Value,
 };
+use icydb_diagnostic_code::SqlFeatureCode;
 
 impl Parser {
     pub(in crate::db::sql::parser) fn parse_scalar_function_call(
@@ -33,6 +37,38 @@ impl Parser {
 
                 expr
             }
+            SqlScalarFunctionCallShape::Cast => {
+                self.expect_lparen()?;
+                let input = self.parse_sql_expr(surface, 0)?;
+                self.expect_keyword(Keyword::As)?;
+                let expr = self.parse_cast_target(input)?;
+                self.expect_rparen()?;
+
+                expr
+            }
+            SqlScalarFunctionCallShape::TemporalUnitExprArgs => {
+                self.expect_lparen()?;
+                let expr = self.parse_temporal_unit_function_call(function, surface)?;
+                self.expect_rparen()?;
+
+                expr
+            }
+            SqlScalarFunctionCallShape::ExtractField => {
+                self.expect_lparen()?;
+                let expr = self.parse_extract_function_call(function, surface)?;
+                self.expect_rparen()?;
+
+                expr
+            }
+            SqlScalarFunctionCallShape::NoArgs => {
+                self.expect_lparen()?;
+                self.expect_rparen()?;
+
+                SqlExpr::FunctionCall {
+                    function,
+                    args: Vec::new(),
+                }
+            }
             SqlScalarFunctionCallShape::UnaryExpr => {
                 self.expect_lparen()?;
                 let expr = SqlExpr::FunctionCall {
@@ -73,9 +109,7 @@ impl Parser {
             }
             SqlScalarFunctionCallShape::SharedScalarCall
             | SqlScalarFunctionCallShape::WherePredicateExprPair => {
-                return Err(SqlParseError::unsupported_feature(
-                    icydb_diagnostic_code::SqlFeatureCode::Other,
-                ));
+                return Err(SqlParseError::unsupported_feature(SqlFeatureCode::Other));
             }
         };
 
@@ -114,6 +148,110 @@ impl Parser {
         false
     }
 
+    // Parse one CAST target type name and wrap `input` in the canonical
+    // `CAST(input, 'TYPE')` call shape. `CAST(x AS t)` and postfix `x::t`
+    // both land here, and the type name must resolve inside the same closed
+    // checked-cast target set that schema migrations use.
+    pub(in crate::db::sql::parser) fn parse_cast_target(
+        &mut self,
+        input: SqlExpr,
+    ) -> Result<SqlExpr, SqlParseError> {
+        let mut target = self.expect_identifier()?.to_ascii_uppercase();
+        if self.peek_lparen() {
+            self.expect_lparen()?;
+            let Some(TokenKind::Number(scale)) = self.peek_kind().cloned() else {
+                return Err(SqlParseError::unsupported_feature(
+                    SqlFeatureCode::CastTargetType,
+                ));
+            };
+            let _ = self.cursor.advance();
+            self.expect_rparen()?;
+            target = format!("{target}({scale})");
+        }
+        if resolve_checked_cast_target(target.as_str()).is_none() {
+            return Err(SqlParseError::unsupported_feature(
+                SqlFeatureCode::CastTargetType,
+            ));
+        }
+
+        Ok(SqlExpr::FunctionCall {
+            function: SqlScalarFunction::Cast,
+            args: vec![input, SqlExpr::Literal(Value::Text(target))],
+        })
+    }
+
+    // Parse `DATE_TRUNC('unit', input)` and freeze the unit as one uppercase
+    // text literal so planner typing and evaluation share the same field.
+    fn parse_temporal_unit_function_call(
+        &mut self,
+        function: SqlScalarFunction,
+        surface: SqlExprParseSurface,
+    ) -> Result<SqlExpr, SqlParseError> {
+        let Value::Text(unit) = self.parse_literal()? else {
+            return Err(SqlParseError::unsupported_feature(
+                SqlFeatureCode::TemporalUnit,
+            ));
+        };
+        let Some(field) =
+            TemporalField::from_sql_label(unit.as_str()).filter(|field| field.admits_truncation())
+        else {
+            return Err(SqlParseError::unsupported_feature(
+                SqlFeatureCode::TemporalUnit,
+            ));
+        };
+        self.expect_scalar_function_argument_comma()?;
+        let input = self.parse_sql_expr(surface, 0)?;
+
+        Ok(Self::temporal_field_function_call(function, field, input))
+    }
+
+    // Parse `EXTRACT(FIELD FROM input)` onto the same frozen-field call shape
+    // used by `DATE_TRUNC`.
+    fn parse_extract_function_call(
+        &mut self,
+        function: SqlScalarFunction,
+        surface: SqlExprParseSurface,
+    ) -> Result<SqlExpr, SqlParseError> {
+        let label = self.expect_identifier()?;
+        let Some(field) = TemporalField::from_sql_label(label.as_str()) else {
+            return Err(SqlParseError::unsupported_feature(
+                SqlFeatureCode::TemporalUnit,
+            ));
+        };
+        self.expect_keyword(Keyword::From)?;
+        let input = self.parse_sql_expr(surface, 0)?;
+
+        Ok(Self::temporal_field_function_call(function, field, input))
+    }
+
+    fn temporal_field_function_call(
+        function: SqlScalarFunction,
+        field: TemporalField,
+        input: SqlExpr,
+    ) -> SqlExpr {
+        SqlExpr::FunctionCall {
+            function,
+            args: vec![
+                SqlExpr::Literal(Value::Text(field.sql_label().to_string())),
+                input,
+            ],
+        }
+    }
+
+    // Parse `INTERVAL '<text>'` into one duration literal. The text accepts
+    // the compact `90s` / `24h` / `7d` spellings shared with duration fields
+    // plus one `<count> <unit>` phrase such as `'2 hours'`.
+    pub(in crate::db::sql::parser) fn parse_interval_literal(
+        &mut self,
+    ) -> Result<Value, SqlParseError> {
+        self.expect_identifier_keyword("interval")?;
+        let text = self.expect_string_literal()?;
+
+        interval_duration(text.trim())
+            .map(Value::Duration)
+            .ok_or_else(|| SqlParseError::unsupported_feature(SqlFeatureCode::TemporalUnit))
+    }
+
     fn parse_field_plus_literal_scalar_function_call(
         &mut self,
         function: SqlScalarFunction,
@@ -274,13 +412,17 @@ impl Parser {
             SqlScalarFunctionCallShape::SharedScalarCall => {
                 self.parse_scalar_function_call(function, SqlExprParseSurface::Where)
             }
-            SqlScalarFunctionCallShape::UnaryExpr
+            SqlScalarFunctionCallShape::Cast
+            | SqlScalarFunctionCallShape::ExtractField
+            | SqlScalarFunctionCallShape::NoArgs
+            | SqlScalarFunctionCallShape::TemporalUnitExprArgs
+            | SqlScalarFunctionCallShape::UnaryExpr
             | SqlScalarFunctionCallShape::FieldPlusLiteral
             | SqlScalarFunctionCallShape::Position
             | SqlScalarFunctionCallShape::Replace
-            | SqlScalarFunctionCallShape::Substring => Err(SqlParseError::unsupported_feature(
-                icydb_diagnostic_code::SqlFeatureCode::Other,
-            )),
+            | SqlScalarFunctionCallShape::Substring => {
+                Err(SqlParseError::unsupported_feature(SqlFeatureCode::Other))
+            }
         }
     }
 
@@ -320,3 +462,29 @@ impl Parser {
         Ok(SqlExpr::FunctionCall { function, args })
     }
 }
+
+fn interval_duration(text: &str) -> Option<Duration> {
+    if let Ok(duration) = Duration::parse_flexible(text) {
+        return Some(duration);
+    }
+
+    let mut words = text.split_ascii_whitespace();
+    let count = words.next()?.parse::<u64>().ok()?;
+    let unit = words.next()?.to_ascii_lowercase();
+    if words.next().is_some() {
+        return None;
+    }
+    let millis_per_unit: u64 = match unit.trim_end_matches('s') {
+        "millisecond" => 1,
+        "second" => 1_000,
+        "minute" => 60_000,
+        "hour" => 3_600_000,
+        "day" => 86_400_000,
+        "week" => 604_800_000,
+        _ => return None,
+    };
+
+    count
+        .checked_mul(millis_per_unit)
+        .map(Duration::from_millis)
+}
@@ -8,13 +8,16 @@ mod arithmetic;
 mod functions;
 mod postfix;
 
-use crate::db::{
-    query::plan::expr::FunctionSurface,
-    sql::parser::{
-        Parser, SqlCaseArm, SqlExpr, SqlExprBinaryOp, SqlExprUnaryOp, SqlProjection,
-        SqlScalarFunction, SqlSelectItem,
+use crate::{
+    db::{
+        query::plan::expr::FunctionSurface,
+        sql::parser::{
+            Parser, SqlCaseArm, SqlExpr, SqlExprBinaryOp, SqlExprUnaryOp, SqlProjection,
+            SqlScalarFunction, SqlSelectItem, SqlWriteValue,
+        },
+        sql_shared::{Keyword, SqlExpectedToken, SqlParseError, TokenKind},
     },
-    sql_shared::{Keyword, SqlExpectedToken, SqlParseError, TokenKind},
+    value::Value,
 };
 use icydb_diagnostic_code::SqlFeatureCode;
 
@@ -33,6 +36,10 @@ pub(super) enum SqlExprParseSurface {
     AggregateInputCondition,
     HavingCondition,
     Where,
+    WriteValue,
+    WriteValueCondition,
+    GroupKey,
+    GroupKeyCondition,
 }
 
 impl SqlExprParseSurface {
@@ -43,6 +50,12 @@ impl SqlExprParseSurface {
         )
     }
 
+    // Subquery sources are resolved by the session before lowering, which
+    // only happens for row-filter predicates.
+    const fn allows_semi_join(self) -> bool {
+        matches!(self, Self::Where)
+    }
+
     const fn allows_predicate_postfix(self) -> bool {
         matches!(
             self,
@@ -50,6 +63,8 @@ impl SqlExprParseSurface {
                 | Self::AggregateInputCondition
                 | Self::HavingCondition
                 | Self::Where
+                | Self::WriteValueCondition
+                | Self::GroupKeyCondition
         )
     }
 
@@ -62,6 +77,8 @@ impl SqlExprParseSurface {
             Self::AggregateInput | Self::AggregateInputCondition => Self::AggregateInputCondition,
             Self::HavingCondition => Self::HavingCondition,
             Self::Where => Self::Where,
+            Self::WriteValue | Self::WriteValueCondition => Self::WriteValueCondition,
+            Self::GroupKey | Self::GroupKeyCondition => Self::GroupKeyCondition,
         }
     }
 
@@ -70,8 +87,12 @@ impl SqlExprParseSurface {
     #[must_use]
     const fn function_surface(self) -> FunctionSurface {
         match self {
-            Self::Projection => FunctionSurface::Projection,
-            Self::ProjectionCondition => FunctionSurface::ProjectionCondition,
+            // Write values and grouped keys reuse the scalar projection
+            // function surface.
+            Self::Projection | Self::WriteValue | Self::GroupKey => FunctionSurface::Projection,
+            Self::ProjectionCondition | Self::WriteValueCondition | Self::GroupKeyCondition => {
+                FunctionSurface::ProjectionCondition
+            }
             Self::AggregateInput => FunctionSurface::AggregateInput,
             Self::AggregateInputCondition => FunctionSurface::AggregateInputCondition,
             Self::HavingCondition => FunctionSurface::HavingCondition,
@@ -87,6 +108,36 @@ impl Parser {
         })
     }
 
+    // Parse one UPDATE assignment right-hand side. Literal-only values keep
+    // the fixed-patch write contract; anything else stays a row-local scalar
+    // expression evaluated later against the selected pre-image row.
+    pub(super) fn parse_write_value_expr(&mut self) -> Result<SqlWriteValue, SqlParseError> {
+        let expr = self.record_expr_parse_stage(|parser| {
+            parser.parse_sql_expr(SqlExprParseSurface::WriteValue, 0)
+        })?;
+
+        Ok(match expr {
+            SqlExpr::Literal(value) => SqlWriteValue::Literal(value),
+            expr => SqlWriteValue::Expr(expr),
+        })
+    }
+
+    // Parse one GROUP BY key list. Each key is one row-local scalar
+    // expression; bare identifiers stay plain field references.
+    pub(super) fn parse_group_by_exprs(&mut self) -> Result<Vec<SqlExpr>, SqlParseError> {
+        let mut keys = Vec::new();
+        loop {
+            keys.push(self.record_expr_parse_stage(|parser| {
+                parser.parse_sql_expr(SqlExprParseSurface::GroupKey, 0)
+            })?);
+            if !self.eat_comma() {
+                break;
+            }
+        }
+
+        Ok(keys)
+    }
+
     pub(super) fn parse_projection(
         &mut self,
     ) -> Result<(SqlProjection, Vec<Option<String>>), SqlParseError> {
@@ -130,26 +181,20 @@ impl Parser {
     // shared floor does not pay the full projection expression parser for
     // simple field items and optional bare aliases.
     fn projection_item_is_simple_field(&self) -> bool {
-        if !matches!(self.peek_kind(), Some(TokenKind::Identifier(_))) {
+        if !self.cursor.peek_identifier() {
             return false;
         }
 
         let mut offset = 0usize;
         loop {
-            if !matches!(
-                self.cursor.peek_kind_at(offset),
-                Some(TokenKind::Identifier(_))
-            ) {
+            if !self.cursor.peek_identifier_at(offset) {
                 return false;
             }
 
             if !matches!(self.cursor.peek_kind_at(offset + 1), Some(TokenKind::Dot)) {
                 break;
             }
-            if !matches!(
-                self.cursor.peek_kind_at(offset + 2),
-                Some(TokenKind::Identifier(_))
-            ) {
+            if !self.cursor.peek_identifier_at(offset + 2) {
                 return false;
             }
 
@@ -162,6 +207,7 @@ impl Parser {
                 TokenKind::Comma
                     | TokenKind::Keyword(Keyword::From | Keyword::As)
                     | TokenKind::Identifier(_)
+                    | TokenKind::QuotedIdentifier(_)
             )
         )
     }
@@ -173,7 +219,7 @@ impl Parser {
             return self.expect_identifier().map(Some);
         }
 
-        if matches!(self.peek_kind(), Some(TokenKind::Identifier(_))) {
+        if self.cursor.peek_identifier() {
             return self.expect_identifier().map(Some);
         }
 
@@ -213,6 +259,16 @@ impl Parser {
         let mut binary_chain_depth = 1usize;
 
         loop {
+            // Postfix `::type` binds tighter than every binary operator, and
+            // each cast nests one level like a binary chain link does.
+            if self.eat_double_colon() {
+                binary_chain_depth = binary_chain_depth.saturating_add(1);
+                if binary_chain_depth > crate::db::sql_shared::MAX_SQL_EXPR_DEPTH {
+                    return Err(crate::db::sql_shared::sql_expr_depth_limit_error());
+                }
+                left = self.parse_cast_target(left)?;
+                continue;
+            }
             if surface.allows_predicate_postfix()
                 && self.peek_where_postfix_start()
                 && let Some(expr) = self.try_parse_where_postfix_expr(left.clone(), surface)?
@@ -245,6 +301,23 @@ impl Parser {
         Ok(left)
     }
 
+    // Parse the `['key']` subscripts that follow one field identifier into a
+    // field path. Keys must be string literals so every subscript resolves to
+    // one fixed path segment at plan time.
+    pub(super) fn parse_map_key_subscripts(
+        &mut self,
+        field: String,
+    ) -> Result<SqlExpr, SqlParseError> {
+        let mut segments = field.split('.').map(str::to_string).collect::<Vec<_>>();
+        let root = segments.remove(0);
+        while self.cursor.eat_lbracket() {
+            segments.push(self.expect_string_literal()?);
+            self.cursor.expect_rbracket()?;
+        }
+
+        Ok(SqlExpr::FieldPath { root, segments })
+    }
+
     fn parse_sql_expr_prefix(
         &mut self,
         surface: SqlExprParseSurface,
@@ -256,12 +329,46 @@ impl Parser {
         result
     }
 
+    // `EXISTS` is not reserved, so only `EXISTS (SELECT` on a surface that
+    // admits semi-joins starts a subquery predicate.
+    fn peek_exists_subquery(&self, surface: SqlExprParseSurface) -> bool {
+        surface.allows_semi_join()
+            && self.cursor.peek_identifier_keyword("exists")
+            && matches!(self.cursor.peek_kind_at(1), Some(TokenKind::LParen))
+            && self.cursor.peek_keyword_at(2, Keyword::Select)
+    }
+
+    // Finish one bare field leaf. Value surfaces split dotted identifiers into
+    // field paths, and `['key']` subscripts always address a map entry.
+    fn parse_field_leaf(
+        &mut self,
+        field: String,
+        surface: SqlExprParseSurface,
+    ) -> Result<SqlExpr, SqlParseError> {
+        if matches!(self.peek_kind(), Some(TokenKind::LBracket)) {
+            return self.parse_map_key_subscripts(field);
+        }
+
+        Ok(
+            if matches!(
+                surface,
+                SqlExprParseSurface::Projection
+                    | SqlExprParseSurface::AggregateInput
+                    | SqlExprParseSurface::WriteValue
+            ) {
+                SqlExpr::from_field_identifier(field)
+            } else {
+                SqlExpr::Field(field)
+            },
+        )
+    }
+
     fn parse_sql_expr_prefix_at_current_depth(
         &mut self,
         surface: SqlExprParseSurface,
     ) -> Result<SqlExpr, SqlParseError> {
         if self.eat_keyword(Keyword::Case) {
-            return self.parse_searched_case_expr(surface);
+            return self.parse_case_expr(surface);
         }
         if self.eat_keyword(Keyword::Not) {
             return Ok(SqlExpr::Unary {
@@ -276,10 +383,8 @@ impl Parser {
 
             return Ok(expr);
         }
-        if self.eat_question() {
-            return Ok(SqlExpr::Param {
-                index: self.take_param_index(),
-            });
+        if let Some(index) = self.eat_param_placeholder()? {
+            return Ok(SqlExpr::Param { index });
         }
         if matches!(
             self.peek_kind(),
@@ -293,6 +398,17 @@ impl Parser {
         ) {
             return self.parse_literal().map(SqlExpr::Literal);
         }
+        if self.peek_exists_subquery(surface) {
+            return self.parse_exists_expr();
+        }
+        if self.cursor.peek_identifier_keyword("interval")
+            && matches!(
+                self.cursor.peek_kind_at(1),
+                Some(TokenKind::StringLiteral(_))
+            )
+        {
+            return self.parse_interval_literal().map(SqlExpr::Literal);
+        }
         if surface.allows_aggregates()
             && let Some(kind) = self.parse_aggregate_kind()
         {
@@ -308,16 +424,7 @@ impl Parser {
 
         let field = self.expect_identifier()?;
         if !self.peek_lparen() {
-            return Ok(
-                if matches!(
-                    surface,
-                    SqlExprParseSurface::Projection | SqlExprParseSurface::AggregateInput
-                ) {
-                    SqlExpr::from_field_identifier(field)
-                } else {
-                    SqlExpr::Field(field)
-                },
-            );
+            return self.parse_field_leaf(field, surface);
         }
 
         let Some(function) = SqlScalarFunction::from_identifier(field.as_str()) else {
@@ -359,19 +466,36 @@ impl Parser {
         Ok(call)
     }
 
-    fn parse_searched_case_expr(
-        &mut self,
-        surface: SqlExprParseSurface,
-    ) -> Result<SqlExpr, SqlParseError> {
-        if !self.eat_keyword(Keyword::When) {
-            return Err(SqlParseError::unsupported_feature(
-                SqlFeatureCode::SimpleCaseExpression,
-            ));
-        }
+    // Simple `CASE operand WHEN value ...` desugars here onto the searched
+    // form as `operand = value` conditions, so a `NULL` operand or value
+    // falls through to `ELSE` exactly as standard SQL requires. A literal
+    // `NULL` on either side can never compare equal, so that arm keeps its
+    // result but takes a constant `FALSE` condition instead of a typed
+    // comparison against `NULL`.
+    fn parse_case_expr(&mut self, surface: SqlExprParseSurface) -> Result<SqlExpr, SqlParseError> {
+        let operand = if self.eat_keyword(Keyword::When) {
+            None
+        } else {
+            let operand = self.parse_sql_expr(surface, 0)?;
+            self.expect_keyword(Keyword::When)?;
+            Some(operand)
+        };
 
         let mut arms = Vec::new();
         loop {
-            let condition = self.parse_sql_expr(surface.case_condition_surface(), 0)?;
+            let condition = match operand.as_ref() {
+                Some(operand) => match (operand, self.parse_sql_expr(surface, 0)?) {
+                    (SqlExpr::Literal(Value::Null), _) | (_, SqlExpr::Literal(Value::Null)) => {
+                        SqlExpr::Literal(Value::Bool(false))
+                    }
+                    (operand, value) => SqlExpr::Binary {
+                        op: SqlExprBinaryOp::Eq,
+                        left: Box::new(operand.clone()),
+                        right: Box::new(value),
+                    },
+                },
+                None => self.parse_sql_expr(surface.case_condition_surface(), 0)?,
+            };
             self.expect_keyword(Keyword::Then)?;
             let result = self.parse_sql_expr(surface, 0)?;
             arms.push(SqlCaseArm { condition, result });
@@ -419,6 +543,8 @@ const fn sql_expr_binary_op_precedence(op: SqlExprBinaryOp) -> u8 {
         SqlExprBinaryOp::And => 2,
         SqlExprBinaryOp::Eq
         | SqlExprBinaryOp::Ne
+        | SqlExprBinaryOp::IsDistinctFrom
+        | SqlExprBinaryOp::IsNotDistinctFrom
         | SqlExprBinaryOp::Lt
         | SqlExprBinaryOp::Lte
         | SqlExprBinaryOp::Gt
@@ -1,7 +1,8 @@
 use crate::{
     db::{
         sql::parser::{
-            Parser, SqlExpr, SqlExprBinaryOp, SqlExprUnaryOp, projection::SqlExprParseSurface,
+            Parser, SqlExpr, SqlExprBinaryOp, SqlExprUnaryOp, SqlScalarFunction,
+            projection::SqlExprParseSurface,
         },
         sql_shared::{Keyword, SqlExpectedToken, SqlParseError, SqlSyntaxErrorKind, TokenKind},
     },
@@ -15,6 +16,10 @@ impl Parser {
             || self.peek_keyword(Keyword::Between)
             || self.cursor.peek_identifier_keyword("LIKE")
             || self.cursor.peek_identifier_keyword("ILIKE")
+            || matches!(
+                self.peek_kind(),
+                Some(TokenKind::Tilde | TokenKind::BangTilde)
+            )
         {
             return true;
         }
@@ -35,6 +40,24 @@ impl Parser {
 
         if self.eat_keyword(Keyword::Is) {
             let negated = self.eat_keyword(Keyword::Not);
+            if self.eat_keyword(Keyword::Distinct) {
+                self.expect_keyword(Keyword::From)?;
+                let op = if negated {
+                    SqlExprBinaryOp::IsNotDistinctFrom
+                } else {
+                    SqlExprBinaryOp::IsDistinctFrom
+                };
+                let right = self.parse_sql_expr(
+                    surface,
+                    super::sql_expr_binary_op_precedence(op).saturating_add(1),
+                )?;
+
+                return Ok(Some(SqlExpr::Binary {
+                    op,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                }));
+            }
             if self.peek_keyword(Keyword::Null) {
                 let _ = self.cursor.advance();
 
@@ -95,7 +118,7 @@ impl Parser {
                 let _ = self.cursor.advance();
                 let _ = self.cursor.advance();
 
-                return self.parse_where_in_expr(left, true).map(Some);
+                return self.parse_where_in_expr(left, true, surface).map(Some);
             }
             if self.cursor.peek_keyword_at(1, Keyword::Between) {
                 let _ = self.cursor.advance();
@@ -105,8 +128,15 @@ impl Parser {
             }
         }
 
+        if let Some(kind @ (TokenKind::Tilde | TokenKind::BangTilde)) = self.peek_kind() {
+            let negated = matches!(kind, TokenKind::BangTilde);
+            let _ = self.cursor.advance();
+
+            return self.parse_where_regex_match_expr(left, negated).map(Some);
+        }
+
         if self.eat_keyword(Keyword::In) {
-            return self.parse_where_in_expr(left, false).map(Some);
+            return self.parse_where_in_expr(left, false, surface).map(Some);
         }
         if self.eat_keyword(Keyword::Between) {
             return self
@@ -117,6 +147,22 @@ impl Parser {
         Ok(None)
     }
 
+    // Parse `EXISTS (SELECT ...)`. `NOT EXISTS` arrives through the shared
+    // unary `NOT` prefix, so the semi-join itself is never negated here.
+    pub(super) fn parse_exists_expr(&mut self) -> Result<SqlExpr, SqlParseError> {
+        let _ = self.cursor.eat_identifier_keyword("EXISTS");
+        self.expect_lparen()?;
+        self.expect_keyword(Keyword::Select)?;
+        let source = self.parse_select_statement()?;
+        self.expect_rparen()?;
+
+        Ok(SqlExpr::SemiJoin {
+            probe: None,
+            source: Box::new(source),
+            negated: false,
+        })
+    }
+
     fn parse_where_like_expr(
         &mut self,
         left: SqlExpr,
@@ -133,21 +179,83 @@ impl Parser {
                 self.peek_kind(),
             ));
         };
+        let escape = if self.eat_identifier_keyword("ESCAPE") {
+            Some(self.parse_like_escape_char()?)
+        } else {
+            None
+        };
 
         Ok(SqlExpr::Like {
             expr: Box::new(left),
             pattern,
+            escape,
             negated,
             casefold,
         })
     }
 
+    // Parse the single-character string literal after `LIKE ... ESCAPE`.
+    fn parse_like_escape_char(&mut self) -> Result<char, SqlParseError> {
+        if let Some(TokenKind::StringLiteral(escape)) = self.peek_kind() {
+            let mut chars = escape.chars();
+            if let (Some(ch), None) = (chars.next(), chars.next()) {
+                let _ = self.cursor.advance();
+
+                return Ok(ch);
+            }
+        }
+
+        Err(SqlParseError::expected(
+            SqlExpectedToken::LikeEscapeCharacter,
+            self.peek_kind(),
+        ))
+    }
+
+    // Parse `expr ~ 'pattern'` and `expr !~ 'pattern'` onto the shared
+    // `REGEXP_MATCHES` call so lowering sees one regular-expression shape.
+    fn parse_where_regex_match_expr(
+        &mut self,
+        left: SqlExpr,
+        negated: bool,
+    ) -> Result<SqlExpr, SqlParseError> {
+        let Value::Text(pattern) = self.parse_literal()? else {
+            return Err(SqlParseError::expected(
+                SqlExpectedToken::StringLiteral,
+                self.peek_kind(),
+            ));
+        };
+        let expr = SqlExpr::FunctionCall {
+            function: SqlScalarFunction::RegexpMatches,
+            args: vec![left, SqlExpr::Literal(Value::Text(pattern))],
+        };
+
+        Ok(if negated {
+            SqlExpr::Unary {
+                op: SqlExprUnaryOp::Not,
+                expr: Box::new(expr),
+            }
+        } else {
+            expr
+        })
+    }
+
     fn parse_where_in_expr(
         &mut self,
         left: SqlExpr,
         negated: bool,
+        surface: SqlExprParseSurface,
     ) -> Result<SqlExpr, SqlParseError> {
         self.expect_lparen()?;
+        if surface.allows_semi_join() && self.eat_keyword(Keyword::Select) {
+            let source = self.parse_select_statement()?;
+            self.expect_rparen()?;
+
+            return Ok(SqlExpr::SemiJoin {
+                probe: Some(Box::new(left)),
+                source: Box::new(source),
+                negated,
+            });
+        }
         let mut values = Vec::with_capacity(self.cursor.comma_separated_capacity_until_rparen());
         loop {
             values.push(self.parse_literal()?);
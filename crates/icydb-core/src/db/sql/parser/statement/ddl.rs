@@ -245,9 +245,20 @@ impl Parser {
         let name = self.expect_identifier()?;
         self.expect_keyword(Keyword::On)?;
         let entity = self.expect_identifier()?;
+        let method = self.parse_create_index_method()?;
         self.expect_lparen()?;
-        let key_items = self.parse_create_index_key_items()?;
+        let (mut key_items, descending) = self.parse_create_index_key_items()?;
         self.expect_rparen()?;
+        if let Some(function) = method {
+            if !descending.is_empty() {
+                return Err(SqlParseError::unsupported_feature(
+                    SqlFeatureCode::CreateIndexKeyOrderingModifiers,
+                ));
+            }
+            key_items = Self::expanded_index_key_items(key_items, uniqueness, function)?;
+        }
+        let include = self.parse_create_index_include()?;
+        Self::validate_create_index_include(key_items.as_slice(), include.as_slice(), uniqueness)?;
         let schema_version_contract = self.parse_optional_ddl_schema_version_contract()?;
         let predicate_sql = self.parse_create_index_predicate_sql()?;
 
@@ -255,6 +266,8 @@ impl Parser {
             name,
             entity,
             key_items,
+            descending,
+            include,
             predicate_sql,
             uniqueness,
             if_not_exists,
@@ -262,6 +275,110 @@ impl Parser {
         })
     }
 
+    // Parse the optional `INCLUDE (field, ...)` payload list. Included fields
+    // are plain field paths stored beside the key for covering reads.
+    fn parse_create_index_include(&mut self) -> Result<Vec<String>, SqlParseError> {
+        if !self.eat_identifier_keyword("INCLUDE") {
+            return Ok(Vec::new());
+        }
+        self.expect_lparen()?;
+        let mut include = Vec::new();
+        loop {
+            include.push(self.expect_identifier()?);
+            if !self.eat_comma() {
+                break;
+            }
+        }
+        self.expect_rparen()?;
+
+        Ok(include)
+    }
+
+    // Included payload is carried only by non-unique field-path indexes, and
+    // each included field appears once and never repeats a key field.
+    fn validate_create_index_include(
+        key_items: &[SqlCreateIndexKeyItem],
+        include: &[String],
+        uniqueness: SqlCreateIndexUniqueness,
+    ) -> Result<(), SqlParseError> {
+        if include.is_empty() {
+            return Ok(());
+        }
+        let shape_error =
+            || SqlParseError::unsupported_feature(SqlFeatureCode::CreateIndexIncludeShape);
+        if uniqueness == SqlCreateIndexUniqueness::Unique
+            || include.len() > icydb_schema::MAX_INDEX_INCLUDE_FIELDS
+            || key_items
+                .iter()
+                .any(|key_item| !matches!(key_item, SqlCreateIndexKeyItem::FieldPath(_)))
+        {
+            return Err(shape_error());
+        }
+        for (position, field) in include.iter().enumerate() {
+            let repeats_key = key_items.iter().any(
+                |key_item| matches!(key_item, SqlCreateIndexKeyItem::FieldPath(key) if key == field),
+            );
+            if repeats_key || include[..position].contains(field) {
+                return Err(shape_error());
+            }
+        }
+
+        Ok(())
+    }
+
+    // Parse the optional `USING TEXT` / `USING MULTIVALUE` access method.
+    // Ordered indexes are the default, so only the multi-entry methods are
+    // spelled explicitly; each maps onto the expression that expands its key.
+    fn parse_create_index_method(
+        &mut self,
+    ) -> Result<Option<SqlCreateIndexExpressionFunction>, SqlParseError> {
+        if !self.eat_identifier_keyword("USING") {
+            return Ok(None);
+        }
+        if self.eat_identifier_keyword("TEXT") {
+            return Ok(Some(SqlCreateIndexExpressionFunction::Tokens));
+        }
+        if self.eat_identifier_keyword("MULTIVALUE") {
+            return Ok(Some(SqlCreateIndexExpressionFunction::Elements));
+        }
+
+        Err(SqlParseError::unsupported_feature(
+            SqlFeatureCode::CreateIndexModifiers,
+        ))
+    }
+
+    // Text and multivalue indexes are keyed by exactly one plain field and
+    // never enforce uniqueness; the key is stored as the canonical
+    // `TOKENS(field)` or `ELEMENTS(field)` item.
+    fn expanded_index_key_items(
+        key_items: Vec<SqlCreateIndexKeyItem>,
+        uniqueness: SqlCreateIndexUniqueness,
+        function: SqlCreateIndexExpressionFunction,
+    ) -> Result<Vec<SqlCreateIndexKeyItem>, SqlParseError> {
+        let shape_error = || {
+            SqlParseError::unsupported_feature(
+                if function == SqlCreateIndexExpressionFunction::Elements {
+                    SqlFeatureCode::MultivalueIndexKeyShape
+                } else {
+                    SqlFeatureCode::TextIndexKeyShape
+                },
+            )
+        };
+        if uniqueness == SqlCreateIndexUniqueness::Unique {
+            return Err(shape_error());
+        }
+        let [SqlCreateIndexKeyItem::FieldPath(field_path)] = key_items.as_slice() else {
+            return Err(shape_error());
+        };
+
+        Ok(vec![SqlCreateIndexKeyItem::Expression(
+            SqlCreateIndexExpressionKey {
+                function,
+                field_path: field_path.clone(),
+            },
+        )])
+    }
+
     fn parse_create_index_predicate_sql(&mut self) -> Result<Option<String>, SqlParseError> {
         if !self.eat_keyword(Keyword::Where) {
             return Ok(None);
@@ -272,19 +389,24 @@ impl Parser {
         Ok(Some(predicate_sql))
     }
 
+    // Parse the key list. `ASC` is the physical default and is not stored;
+    // `DESC` is recorded per field-path key and has no expression-key form.
     fn parse_create_index_key_items(
         &mut self,
-    ) -> Result<Vec<SqlCreateIndexKeyItem>, SqlParseError> {
+    ) -> Result<(Vec<SqlCreateIndexKeyItem>, Vec<String>), SqlParseError> {
         let mut key_items = Vec::new();
+        let mut descending = Vec::new();
         loop {
             let key_item = self.parse_create_index_key_item()?;
-            if self.eat_keyword(Keyword::Asc) {
-                // ASC is the current physical key default; keep it as syntax
-                // sugar rather than a stored DDL contract.
-            } else if self.peek_keyword(Keyword::Desc) {
-                return Err(SqlParseError::unsupported_feature(
-                    SqlFeatureCode::CreateIndexKeyOrderingModifiers,
-                ));
+            if self.eat_keyword(Keyword::Desc) {
+                let SqlCreateIndexKeyItem::FieldPath(field) = &key_item else {
+                    return Err(SqlParseError::unsupported_feature(
+                        SqlFeatureCode::CreateIndexKeyOrderingModifiers,
+                    ));
+                };
+                descending.push(field.clone());
+            } else {
+                let _ = self.eat_keyword(Keyword::Asc);
             }
             key_items.push(key_item);
 
@@ -293,10 +415,44 @@ impl Parser {
             }
         }
 
-        Ok(key_items)
+        // Descending components are stored only on field-path-only keys.
+        if !descending.is_empty()
+            && key_items
+                .iter()
+                .any(|key_item| !matches!(key_item, SqlCreateIndexKeyItem::FieldPath(_)))
+        {
+            return Err(SqlParseError::unsupported_feature(
+                SqlFeatureCode::CreateIndexKeyOrderingModifiers,
+            ));
+        }
+
+        Ok((key_items, descending))
     }
 
+    // Parse one key item. Plain field paths and single-field `LOWER` /
+    // `UPPER` / `TRIM` calls keep their dedicated shapes; any other function
+    // call or parenthesized expression is kept as source SQL for binding.
     fn parse_create_index_key_item(&mut self) -> Result<SqlCreateIndexKeyItem, SqlParseError> {
+        if ["TOKENS", "ELEMENTS"]
+            .iter()
+            .any(|function| self.cursor.peek_identifier_keyword(function))
+            && matches!(self.cursor.peek_next_kind(), Some(TokenKind::LParen))
+        {
+            return Err(SqlParseError::unsupported_feature(
+                SqlFeatureCode::ExpressionIndexUnsupportedFunction,
+            ));
+        }
+        if self.peek_lparen()
+            || (self.cursor.peek_identifier()
+                && matches!(self.cursor.peek_next_kind(), Some(TokenKind::LParen))
+                && !self.peek_single_field_expression_key())
+        {
+            let sql = self.cursor.sql_until_list_item_end();
+            let _ = self.parse_write_value_expr()?;
+
+            return Ok(SqlCreateIndexKeyItem::Scalar(sql));
+        }
+
         let head = self.expect_identifier()?;
         if !self.peek_lparen() {
             return Ok(SqlCreateIndexKeyItem::FieldPath(head));
@@ -319,6 +475,28 @@ impl Parser {
         ))
     }
 
+    // Probe `LOWER(path)`, `UPPER(path)`, or `TRIM(path)` over one plain
+    // field path without consuming tokens.
+    fn peek_single_field_expression_key(&self) -> bool {
+        if !["LOWER", "UPPER", "TRIM"]
+            .iter()
+            .any(|function| self.cursor.peek_identifier_keyword(function))
+        {
+            return false;
+        }
+        let mut offset = 2;
+        loop {
+            if !self.cursor.peek_identifier_at(offset) {
+                return false;
+            }
+            match self.cursor.peek_kind_at(offset + 1) {
+                Some(TokenKind::Dot) => offset += 2,
+                Some(TokenKind::RParen) => return true,
+                _ => return false,
+            }
+        }
+    }
+
     fn parse_create_index_if_not_exists(&mut self) -> Result<bool, SqlParseError> {
         if self.eat_identifier_keyword("IF") {
             if !self.eat_keyword(Keyword::Not) || !self.eat_identifier_keyword("EXISTS") {
@@ -377,6 +555,7 @@ impl Parser {
         let column_type = self.parse_alter_table_add_column_type()?;
         let mut nullable = true;
         let mut default = None;
+        let mut generated = None;
 
         loop {
             if self.eat_keyword(Keyword::Default) {
@@ -391,21 +570,52 @@ impl Parser {
                 nullable = false;
             } else if self.eat_keyword(Keyword::Null) {
                 nullable = true;
+            } else if self.eat_identifier_keyword("GENERATED") {
+                if generated.is_some() {
+                    return Err(SqlParseError::unsupported_feature(
+                        SqlFeatureCode::AlterTableAddColumnModifiers,
+                    ));
+                }
+                generated = Some(self.parse_generated_column_expression()?);
             } else {
                 break;
             }
         }
 
+        // Existing rows receive their computed value from a bounded backfill,
+        // so the column cannot also claim an insert default or reject NULL
+        // before that backfill has run.
+        if generated.is_some() && (default.is_some() || !nullable) {
+            return Err(SqlParseError::unsupported_feature(
+                SqlFeatureCode::AlterTableAddColumnModifiers,
+            ));
+        }
+
         Ok(SqlAlterTableAddColumnStatement {
             entity,
             column_name,
             column_type,
             nullable,
             default,
+            generated,
             schema_version_contract: SqlDdlSchemaVersionContract::default(),
         })
     }
 
+    // Parse `ALWAYS AS ( expr ) STORED` after `GENERATED` and keep the
+    // expression as normalized SQL text for the accepted schema snapshot.
+    fn parse_generated_column_expression(&mut self) -> Result<String, SqlParseError> {
+        self.expect_identifier_keyword("ALWAYS")?;
+        self.expect_keyword(Keyword::As)?;
+        self.expect_lparen()?;
+        let expression = self.cursor.sql_until_closing_paren();
+        let _ = self.parse_write_value_expr()?;
+        self.expect_rparen()?;
+        self.expect_identifier_keyword("STORED")?;
+
+        Ok(expression)
+    }
+
     fn parse_alter_table_add_column_type(&mut self) -> Result<String, SqlParseError> {
         let head = self.expect_identifier()?;
         if !self.peek_lparen() {
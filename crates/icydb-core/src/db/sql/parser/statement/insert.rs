@@ -1,6 +1,7 @@
 use crate::db::{
     sql::parser::{
-        Parser, SqlInsertSource, SqlInsertStatement, SqlReturningProjection, SqlWriteValue,
+        Parser, SqlExpr, SqlInsertConflictAction, SqlInsertOnConflict, SqlInsertSource,
+        SqlInsertStatement, SqlReturningProjection, SqlWriteValue,
     },
     sql_shared::{Keyword, SqlParseError, SqlSyntaxErrorKind},
 };
@@ -32,6 +33,11 @@ impl Parser {
 
             SqlInsertSource::Values(values)
         };
+        let on_conflict = if self.eat_keyword(Keyword::On) {
+            Some(self.parse_insert_on_conflict()?)
+        } else {
+            None
+        };
         let returning = if self.eat_keyword(Keyword::Returning) {
             Some(self.parse_returning_projection()?)
         } else {
@@ -42,10 +48,30 @@ impl Parser {
             entity,
             columns,
             source,
+            on_conflict,
             returning,
         })
     }
 
+    // Parse the reduced `ON CONFLICT (target) DO NOTHING | DO UPDATE SET ...`
+    // tail after the leading `ON` keyword.
+    fn parse_insert_on_conflict(&mut self) -> Result<SqlInsertOnConflict, SqlParseError> {
+        self.expect_identifier_keyword("CONFLICT")?;
+        self.expect_lparen()?;
+        let target = self.parse_identifier_list()?;
+        self.expect_rparen()?;
+        self.expect_identifier_keyword("DO")?;
+        let action = if self.eat_keyword(Keyword::Update) {
+            self.expect_identifier_keyword("SET")?;
+            SqlInsertConflictAction::DoUpdate(self.parse_update_assignments()?)
+        } else {
+            self.expect_identifier_keyword("NOTHING")?;
+            SqlInsertConflictAction::DoNothing
+        };
+
+        Ok(SqlInsertOnConflict { target, action })
+    }
+
     // Parse one or more reduced SQL VALUES tuples while keeping tuple arity
     // aligned with the explicit INSERT column list.
     fn parse_insert_values_tuples(
@@ -58,11 +84,7 @@ impl Parser {
             self.expect_lparen()?;
             let mut tuple = Vec::new();
             loop {
-                tuple.push(if self.eat_keyword(Keyword::Default) {
-                    SqlWriteValue::Default
-                } else {
-                    SqlWriteValue::Literal(self.parse_literal()?)
-                });
+                tuple.push(self.parse_insert_value()?);
 
                 if self.eat_comma() {
                     continue;
@@ -88,6 +110,51 @@ impl Parser {
         Ok(tuples)
     }
 
+    // Parse one VALUES item: `DEFAULT`, one literal, or one literal wrapped in
+    // `CAST(literal AS type)` / `literal::type` conversions. Casts stay
+    // expression-shaped here and fold back to literals during lowering.
+    fn parse_insert_value(&mut self) -> Result<SqlWriteValue, SqlParseError> {
+        if self.eat_keyword(Keyword::Default) {
+            return Ok(SqlWriteValue::Default);
+        }
+
+        let expr = if self.eat_identifier_keyword("CAST") {
+            self.expect_lparen()?;
+            let input = self.parse_insert_cast_literal_chain()?;
+            self.expect_keyword(Keyword::As)?;
+            let expr = self.parse_cast_target(input)?;
+            self.expect_rparen()?;
+
+            expr
+        } else {
+            self.parse_insert_cast_literal_chain()?
+        };
+        let expr = self.parse_insert_cast_postfix_chain(expr)?;
+
+        Ok(match expr {
+            SqlExpr::Literal(value) => SqlWriteValue::Literal(value),
+            expr => SqlWriteValue::Expr(expr),
+        })
+    }
+
+    // Parse one literal plus any trailing `::type` conversions.
+    fn parse_insert_cast_literal_chain(&mut self) -> Result<SqlExpr, SqlParseError> {
+        let literal = SqlExpr::Literal(self.parse_literal()?);
+
+        self.parse_insert_cast_postfix_chain(literal)
+    }
+
+    fn parse_insert_cast_postfix_chain(
+        &mut self,
+        mut expr: SqlExpr,
+    ) -> Result<SqlExpr, SqlParseError> {
+        while self.eat_double_colon() {
+            expr = self.parse_cast_target(expr)?;
+        }
+
+        Ok(expr)
+    }
+
     pub(super) fn parse_returning_projection(
         &mut self,
     ) -> Result<SqlReturningProjection, SqlParseError> {
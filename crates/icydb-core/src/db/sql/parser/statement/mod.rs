@@ -8,7 +8,9 @@ mod delete;
 mod insert;
 mod integrity;
 mod select;
+mod union;
 mod update;
+mod with;
 
 #[cfg(feature = "sql")]
 use crate::db::sql::parser::{SqlExplainMode, SqlExplainStatement, SqlExplainTarget};
@@ -20,7 +22,7 @@ use crate::db::{
         SqlShowStoresStatement, SqlStatement, SqlUpdateStatement,
     },
     sql_shared::{
-        Keyword, SqlClauseOrderRule, SqlExpectedToken, SqlParseError, SqlSyntaxErrorKind, TokenKind,
+        Keyword, SqlClauseOrderRule, SqlExpectedToken, SqlParseError, SqlSyntaxErrorKind,
     },
 };
 use icydb_diagnostic_code::SqlFeatureCode;
@@ -28,7 +30,15 @@ use icydb_diagnostic_code::SqlFeatureCode;
 impl Parser {
     pub(super) fn parse_statement(&mut self) -> Result<SqlStatement, SqlParseError> {
         if self.eat_keyword(Keyword::Select) {
-            return Ok(SqlStatement::Select(self.parse_select_statement()?));
+            let select = self.parse_select_statement()?;
+            if self.peek_keyword(Keyword::Union) {
+                return Ok(SqlStatement::Union(self.parse_union_statement(select)?));
+            }
+
+            return Ok(SqlStatement::Select(select));
+        }
+        if self.eat_keyword(Keyword::With) {
+            return Ok(SqlStatement::With(self.parse_with_statement()?));
         }
         if self.eat_keyword(Keyword::Delete) {
             return Ok(SqlStatement::Delete(self.parse_delete_statement()?));
@@ -79,6 +89,8 @@ impl Parser {
     ) -> Option<SqlParseError> {
         match statement {
             SqlStatement::Select(select) => self.select_clause_order_error(select),
+            SqlStatement::Union(_) => None,
+            SqlStatement::With(with) => self.select_clause_order_error(&with.select),
             SqlStatement::Delete(delete) => self.delete_clause_order_error(delete),
             SqlStatement::Insert(_) => None,
             SqlStatement::Update(update) => self.update_clause_order_error(update),
@@ -86,6 +98,7 @@ impl Parser {
             #[cfg(feature = "sql")]
             SqlStatement::Explain(explain) => match &explain.statement {
                 SqlExplainTarget::Select(select) => self.select_clause_order_error(select),
+                SqlExplainTarget::With(with) => self.select_clause_order_error(&with.select),
                 SqlExplainTarget::Delete(delete) => self.delete_clause_order_error(delete),
             },
             SqlStatement::Describe(_) => Some(SqlParseError::unsupported_feature(
@@ -183,6 +196,8 @@ impl Parser {
 
         let statement = if self.eat_keyword(Keyword::Select) {
             SqlExplainTarget::Select(self.parse_select_statement()?)
+        } else if self.eat_keyword(Keyword::With) {
+            SqlExplainTarget::With(self.parse_with_statement()?)
         } else if self.eat_keyword(Keyword::Delete) {
             SqlExplainTarget::Delete(self.parse_delete_statement()?)
         } else if let Some(feature) = self.peek_unsupported_feature() {
@@ -253,14 +268,12 @@ impl Parser {
             return self.expect_identifier().map(Some);
         }
 
-        if let Some(TokenKind::Identifier(value)) = self.peek_kind() {
-            if matches!(
-                value.as_str().to_ascii_uppercase().as_str(),
-                "SET" | "VALUES"
-            ) {
-                return Ok(None);
-            }
-
+        if self.cursor.peek_identifier_keyword("SET")
+            || self.cursor.peek_identifier_keyword("VALUES")
+        {
+            return Ok(None);
+        }
+        if self.cursor.peek_identifier() {
             return self.expect_identifier().map(Some);
         }
 
@@ -4,7 +4,9 @@ use crate::db::{
 };
 
 impl Parser {
-    pub(super) fn parse_select_statement(&mut self) -> Result<SqlSelectStatement, SqlParseError> {
+    pub(in crate::db::sql::parser) fn parse_select_statement(
+        &mut self,
+    ) -> Result<SqlSelectStatement, SqlParseError> {
         let distinct = self.eat_keyword(Keyword::Distinct);
         let (projection, projection_aliases) = self.parse_projection()?;
         self.expect_keyword(Keyword::From)?;
@@ -20,7 +22,7 @@ impl Parser {
 
         let group_by = if self.eat_keyword(Keyword::Group) {
             self.expect_keyword(Keyword::By)?;
-            self.parse_identifier_list()?
+            self.parse_group_by_exprs()?
         } else {
             Vec::new()
         };
@@ -52,6 +54,14 @@ impl Parser {
             None
         };
 
+        // `LIMIT k PER field[, ...]` keeps the first `k` ordered rows of each
+        // partition instead of the first `k` rows overall.
+        let limit_per = if limit.is_some() && self.eat_identifier_keyword("PER") {
+            self.parse_identifier_list()?
+        } else {
+            Vec::new()
+        };
+
         let offset = if self.eat_keyword(Keyword::Offset) {
             Some(self.parse_u32_literal(SqlIntegerLiteralClause::Offset)?)
         } else {
@@ -69,6 +79,7 @@ impl Parser {
             having,
             order_by,
             limit,
+            limit_per,
             offset,
         })
     }
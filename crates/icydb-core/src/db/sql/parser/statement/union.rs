@@ -0,0 +1,57 @@
+use crate::db::{
+    sql::parser::{Parser, SqlSelectStatement, SqlUnionStatement},
+    sql_shared::{Keyword, SqlParseError},
+};
+use icydb_diagnostic_code::SqlFeatureCode;
+
+impl Parser {
+    // Parse the `UNION [ALL] SELECT ...` tail that follows one already parsed
+    // SELECT. Ordering and windowing clauses may only follow the last branch,
+    // where they bind to the combined rows.
+    pub(super) fn parse_union_statement(
+        &mut self,
+        first: SqlSelectStatement,
+    ) -> Result<SqlUnionStatement, SqlParseError> {
+        let mut branches = vec![first];
+        let mut all = None;
+
+        while self.eat_keyword(Keyword::Union) {
+            let branch_all = self.eat_identifier_keyword("ALL");
+            if all.is_some_and(|all| all != branch_all)
+                || branches.last().is_some_and(select_has_trailing_window)
+            {
+                return Err(SqlParseError::unsupported_feature(
+                    SqlFeatureCode::UnionIntersectExcept,
+                ));
+            }
+            all = Some(branch_all);
+
+            self.expect_keyword(Keyword::Select)?;
+            branches.push(self.parse_select_statement()?);
+        }
+
+        let last = branches.last_mut().ok_or_else(|| {
+            SqlParseError::unsupported_feature(SqlFeatureCode::UnionIntersectExcept)
+        })?;
+        let order_by = std::mem::take(&mut last.order_by);
+        let limit = last.limit.take();
+        let offset = last.offset.take();
+
+        Ok(SqlUnionStatement {
+            branches,
+            all: all.unwrap_or(false),
+            order_by,
+            limit,
+            offset,
+        })
+    }
+}
+
+// One non-final branch may not carry its own ordering or window: without
+// parentheses those clauses would be ambiguous with the combined ones.
+const fn select_has_trailing_window(select: &SqlSelectStatement) -> bool {
+    !select.order_by.is_empty()
+        || select.limit.is_some()
+        || select.offset.is_some()
+        || !select.limit_per.is_empty()
+}
@@ -55,7 +55,7 @@ impl Parser {
         })
     }
 
-    fn parse_update_assignments(&mut self) -> Result<Vec<SqlAssignment>, SqlParseError> {
+    pub(super) fn parse_update_assignments(&mut self) -> Result<Vec<SqlAssignment>, SqlParseError> {
         let mut assignments = Vec::new();
         loop {
             let field = self.expect_identifier()?;
@@ -70,7 +70,7 @@ impl Parser {
             let value = if self.eat_keyword(Keyword::Default) {
                 SqlWriteValue::Default
             } else {
-                SqlWriteValue::Literal(self.parse_literal()?)
+                self.parse_write_value_expr()?
             };
             assignments.push(SqlAssignment { field, value });
 
@@ -0,0 +1,42 @@
+use crate::db::{
+    sql::parser::{Parser, SqlCommonTableExpression, SqlWithStatement},
+    sql_shared::{Keyword, SqlParseError},
+};
+use icydb_diagnostic_code::SqlFeatureCode;
+
+impl Parser {
+    // Parse the `name AS (SELECT ...) [, ...] SELECT ...` tail that follows
+    // one `WITH` keyword. Recursive clauses and explicit column lists stay
+    // outside the reduced grammar; columns come from the body projection.
+    pub(super) fn parse_with_statement(&mut self) -> Result<SqlWithStatement, SqlParseError> {
+        if self.eat_identifier_keyword("RECURSIVE") {
+            return Err(SqlParseError::unsupported_feature(SqlFeatureCode::With));
+        }
+
+        let mut common_table_expressions = Vec::new();
+        loop {
+            let name = self.expect_identifier()?;
+            if self.peek_lparen() {
+                return Err(SqlParseError::unsupported_feature(SqlFeatureCode::With));
+            }
+            self.expect_keyword(Keyword::As)?;
+            self.expect_lparen()?;
+            self.expect_keyword(Keyword::Select)?;
+            let select = self.parse_select_statement()?;
+            self.expect_rparen()?;
+            common_table_expressions.push(SqlCommonTableExpression { name, select });
+
+            if !self.eat_comma() {
+                break;
+            }
+        }
+
+        self.expect_keyword(Keyword::Select)?;
+        let select = self.parse_select_statement()?;
+
+        Ok(SqlWithStatement {
+            common_table_expressions,
+            select,
+        })
+    }
+}
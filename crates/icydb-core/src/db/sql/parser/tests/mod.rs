@@ -4,19 +4,20 @@
 //! Boundary: exposes this module API while keeping implementation details internal.
 
 use super::{
-    SqlAggregateCall, SqlAggregateKind, SqlAlterColumnAction, SqlAlterTableAddColumnStatement,
-    SqlAlterTableAlterColumnStatement, SqlAlterTableDropColumnStatement,
-    SqlAlterTableRenameColumnStatement, SqlAssignment, SqlCaseArm,
-    SqlCreateIndexExpressionFunction, SqlCreateIndexExpressionKey, SqlCreateIndexKeyItem,
-    SqlCreateIndexStatement, SqlCreateIndexUniqueness, SqlDdlSchemaVersionContract,
-    SqlDdlStatement, SqlDeleteStatement, SqlDescribeMode, SqlDescribeStatement,
-    SqlDropIndexStatement, SqlExpr, SqlExprBinaryOp, SqlInsertSource, SqlInsertStatement,
-    SqlIntegrityStatement, SqlOrderDirection, SqlOrderTerm, SqlParseError, SqlProjection,
-    SqlReturningProjection, SqlScalarFunction, SqlSelectItem, SqlSelectStatement,
+    SqlAggregateArguments, SqlAggregateCall, SqlAggregateKind, SqlAlterColumnAction,
+    SqlAlterTableAddColumnStatement, SqlAlterTableAlterColumnStatement,
+    SqlAlterTableDropColumnStatement, SqlAlterTableRenameColumnStatement, SqlAssignment,
+    SqlCaseArm, SqlCreateIndexExpressionFunction, SqlCreateIndexExpressionKey,
+    SqlCreateIndexKeyItem, SqlCreateIndexStatement, SqlCreateIndexUniqueness,
+    SqlDdlSchemaVersionContract, SqlDdlStatement, SqlDeleteStatement, SqlDescribeMode,
+    SqlDescribeStatement, SqlDropIndexStatement, SqlExpr, SqlExprBinaryOp, SqlExprUnaryOp,
+    SqlInsertConflictAction, SqlInsertOnConflict, SqlInsertSource, SqlInsertStatement,
+    SqlIntegrityStatement, SqlNullsOrder, SqlOrderDirection, SqlOrderTerm, SqlParseError,
+    SqlProjection, SqlReturningProjection, SqlScalarFunction, SqlSelectItem, SqlSelectStatement,
     SqlShowColumnsStatement, SqlShowConstraintsStatement, SqlShowEntitiesStatement,
     SqlShowIndexesStatement, SqlShowMemoryStatement, SqlShowRelationsStatement,
-    SqlShowStoresStatement, SqlStatement, SqlUpdateStatement, SqlWriteValue, parse_integrity_sql,
-    parse_sql,
+    SqlShowStoresStatement, SqlStatement, SqlUnionStatement, SqlUpdateStatement, SqlWithStatement,
+    SqlWriteValue, parse_integrity_sql, parse_sql,
 };
 #[cfg(feature = "sql")]
 use super::{SqlExplainMode, SqlExplainStatement, SqlExplainTarget};
@@ -97,6 +98,7 @@ fn sql_like_expr(expr: SqlExpr, pattern: &str, negated: bool, casefold: bool) ->
     SqlExpr::Like {
         expr: Box::new(expr),
         pattern: pattern.to_string(),
+        escape: None,
         negated,
         casefold,
     }
@@ -159,6 +161,10 @@ fn sql_expr_from_runtime_predicate(predicate: Predicate) -> SqlExpr {
                 SqlExpr::Literal(value),
             ],
         },
+        Predicate::TextMatch { field, value } => SqlExpr::FunctionCall {
+            function: SqlScalarFunction::Match,
+            args: vec![SqlExpr::Field(field), SqlExpr::Literal(value)],
+        },
     }
 }
 
@@ -207,6 +213,121 @@ fn parse_sql_preserves_placeholder_slot_order_across_where_and_having() {
     std::assert_matches!(having_param.as_ref(), SqlExpr::Param { index: 2 });
 }
 
+#[test]
+fn parse_sql_maps_positional_placeholders_onto_zero_based_slots() {
+    let SqlStatement::Select(statement) =
+        parse_sql("SELECT name FROM ParserEntity WHERE age > $2 AND name = $1 AND age < $2")
+            .expect("positional placeholder SQL should parse")
+    else {
+        panic!("positional placeholder SQL should produce one SELECT statement");
+    };
+
+    let mut slots = Vec::new();
+    let mut pending = vec![statement.predicate.expect("WHERE should be preserved")];
+    while let Some(expr) = pending.pop() {
+        match expr {
+            SqlExpr::Binary { left, right, .. } => {
+                pending.push(*right);
+                pending.push(*left);
+            }
+            SqlExpr::Param { index } => slots.push(index),
+            _ => {}
+        }
+    }
+
+    assert_eq!(slots, vec![1, 0, 1]);
+}
+
+#[test]
+fn parse_sql_rejects_invalid_and_mixed_positional_placeholders() {
+    for sql in [
+        "SELECT name FROM ParserEntity WHERE age > $0",
+        "SELECT name FROM ParserEntity WHERE age > $",
+    ] {
+        assert_eq!(
+            parse_sql(sql).expect_err("positional slots start at `$1`"),
+            super::SqlParseError::InvalidSyntax {
+                kind: SqlSyntaxErrorKind::InvalidNumericLiteral,
+            },
+            "{sql}",
+        );
+    }
+
+    for sql in [
+        "SELECT name FROM ParserEntity WHERE age > ? AND name = $1",
+        "SELECT name FROM ParserEntity WHERE age > $1 AND name = ?",
+    ] {
+        assert_eq!(
+            parse_sql(sql).expect_err("one statement must use one placeholder style"),
+            super::SqlParseError::InvalidSyntax {
+                kind: SqlSyntaxErrorKind::MixedParameterPlaceholders,
+            },
+            "{sql}",
+        );
+    }
+}
+
+#[test]
+fn parse_sql_keeps_in_and_exists_subqueries_as_semi_joins() {
+    let SqlStatement::Select(statement) = parse_sql(
+        "SELECT id FROM Invoice \
+         WHERE customer_id NOT IN (SELECT id FROM Customer WHERE active = TRUE) \
+         AND NOT EXISTS (SELECT id FROM Customer WHERE tier = 'gold')",
+    )
+    .expect("IN and EXISTS subqueries should parse in WHERE") else {
+        panic!("subquery SQL should produce one SELECT statement");
+    };
+    let Some(SqlExpr::Binary {
+        op: SqlExprBinaryOp::And,
+        left,
+        right,
+    }) = statement.predicate
+    else {
+        panic!("WHERE should keep both semi-joins under one AND");
+    };
+
+    let SqlExpr::SemiJoin {
+        probe: Some(probe),
+        source,
+        negated: true,
+    } = *left
+    else {
+        panic!("NOT IN (SELECT ...) should parse as one negated keyed semi-join");
+    };
+    assert_eq!(*probe, SqlExpr::Field("customer_id".to_string()));
+    assert_eq!(source.entity, "Customer");
+    assert_eq!(
+        source.projection,
+        SqlProjection::Items(vec![SqlSelectItem::Field("id".to_string())]),
+    );
+
+    let SqlExpr::Unary {
+        op: SqlExprUnaryOp::Not,
+        expr,
+    } = *right
+    else {
+        panic!("NOT EXISTS should keep the shared unary NOT prefix");
+    };
+    std::assert_matches!(
+        *expr,
+        SqlExpr::SemiJoin {
+            probe: None,
+            negated: false,
+            ..
+        }
+    );
+}
+
+#[test]
+fn parse_sql_rejects_subqueries_outside_row_filters() {
+    for sql in [
+        "SELECT id IN (SELECT id FROM Customer) FROM Invoice",
+        "SELECT COUNT(*) FROM Invoice GROUP BY id HAVING id IN (SELECT id FROM Customer)",
+    ] {
+        parse_sql(sql).expect_err("subqueries stay confined to WHERE filters");
+    }
+}
+
 fn sql_expr_from_compare(compare: ComparePredicate) -> SqlExpr {
     match compare.op() {
         CompareOp::In | CompareOp::NotIn => {
@@ -307,6 +428,7 @@ fn parse_select_statement_with_predicate_order_and_window() {
                     input: None,
                     filter_expr: None,
                     distinct: false,
+                    arguments: None,
                 }),
             ]),
             projection_aliases: vec![None, None],
@@ -331,13 +453,16 @@ fn parse_select_statement_with_predicate_order_and_window() {
                 SqlOrderTerm {
                     field: sql_order_expr("age"),
                     direction: SqlOrderDirection::Desc,
+                    nulls: None,
                 },
                 SqlOrderTerm {
                     field: sql_order_expr("name"),
                     direction: SqlOrderDirection::Asc,
+                    nulls: None,
                 },
             ],
             limit: Some(10),
+            limit_per: Vec::new(),
             offset: Some(5),
         }),
     );
@@ -371,6 +496,7 @@ fn parse_select_statement_with_trim_ltrim_rtrim_lower_upper_and_length_projectio
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -401,6 +527,7 @@ fn parse_select_statement_with_abs_ceil_ceiling_and_floor_projection_items() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -459,6 +586,7 @@ fn parse_select_statement_with_unary_numeric_expression_projection_items() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -526,6 +654,7 @@ fn parse_select_statement_with_unary_text_expression_projection_items() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -565,6 +694,7 @@ fn parse_select_statement_with_coalesce_and_nullif_projection_items() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -592,6 +722,7 @@ fn parse_select_statement_with_scalar_add_projection_item() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -622,9 +753,52 @@ fn parse_select_statement_with_field_path_projection_expr() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
+            offset: None,
+        })
+    );
+}
+
+#[test]
+fn parse_select_statement_with_map_key_subscripts_and_has_key() {
+    let statement = parse_sql(
+        "SELECT settings['theme'] FROM users WHERE HAS_KEY(settings, 'lang') ORDER BY settings['theme']",
+    )
+    .expect("map key subscripts and HAS_KEY should parse");
+    let theme = SqlExpr::FieldPath {
+        root: "settings".to_string(),
+        segments: vec!["theme".to_string()],
+    };
+
+    assert_eq!(
+        statement,
+        SqlStatement::Select(SqlSelectStatement {
+            entity: "users".to_string(),
+            table_alias: None,
+            projection: SqlProjection::Items(vec![SqlSelectItem::Expr(theme.clone())]),
+            projection_aliases: vec![None],
+            predicate: Some(sql_scalar_function_expr(
+                SqlScalarFunction::HasKey,
+                vec![
+                    SqlExpr::Field("settings".to_string()),
+                    SqlExpr::Literal(Value::Text("lang".to_string())),
+                ],
+            )),
+            distinct: false,
+            group_by: vec![],
+            having: vec![],
+            order_by: vec![SqlOrderTerm {
+                field: theme,
+                direction: SqlOrderDirection::Asc,
+                nulls: None,
+            }],
+            limit: None,
+            limit_per: Vec::new(),
             offset: None,
         })
     );
+    parse_sql("SELECT settings[theme] FROM users")
+        .expect_err("map key subscripts should require string literal keys");
 }
 
 #[test]
@@ -669,6 +843,7 @@ fn parse_select_statement_with_scalar_sub_mul_div_projection_items() {
                 having: vec![],
                 order_by: vec![],
                 limit: None,
+                limit_per: Vec::new(),
                 offset: None,
             }),
             "{context} should lower to one bounded arithmetic projection item",
@@ -698,6 +873,7 @@ fn parse_select_statement_with_scalar_field_to_field_projection_item() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -729,6 +905,7 @@ fn parse_select_statement_with_chained_scalar_projection_item_preserves_preceden
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
         "chained scalar arithmetic projection should preserve operator precedence in the parser model",
@@ -764,6 +941,7 @@ fn parse_select_statement_with_parenthesized_round_projection_item() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
         "parenthesized ROUND projection should preserve nested arithmetic structure in the parser model",
@@ -801,6 +979,7 @@ fn parse_select_statement_with_searched_case_projection_item() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
         "searched CASE projection should stay on the shared SQL-expression boundary",
@@ -836,6 +1015,7 @@ fn parse_select_statement_with_searched_case_is_null_condition_projection_item()
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
         "searched CASE projection should keep IS NULL conditions on the shared SQL-expression boundary",
@@ -867,6 +1047,7 @@ fn parse_select_statement_with_searched_case_aggregate_input_expression() {
                 })),
                 filter_expr: None,
                 distinct: false,
+                arguments: None,
             })]),
             projection_aliases: vec![None],
             predicate: None,
@@ -875,6 +1056,7 @@ fn parse_select_statement_with_searched_case_aggregate_input_expression() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
         "searched CASE aggregate inputs should stay on the shared SQL-expression boundary",
@@ -918,8 +1100,10 @@ fn parse_select_statement_with_searched_case_where_expression() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("age"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
         "searched CASE WHERE should stay on the shared pre-aggregate SQL-expression boundary",
@@ -992,8 +1176,10 @@ fn parse_select_statement_with_field_to_field_predicate() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("age"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -1021,6 +1207,7 @@ fn parse_select_statement_with_field_path_predicate() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -1058,8 +1245,10 @@ fn parse_select_statement_with_symmetric_predicate_forms() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("age"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -1114,6 +1303,7 @@ fn parse_select_statement_with_round_projection_items() {
                 having: vec![],
                 order_by: vec![],
                 limit: None,
+                limit_per: Vec::new(),
                 offset: None,
             }),
             "{context} should lower to one bounded ROUND projection item",
@@ -1143,6 +1333,7 @@ fn parse_select_statement_with_scalar_field_plus_field_projection_item() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -1221,18 +1412,234 @@ fn parse_select_statement_with_expression_order_terms() {
                 SqlOrderTerm {
                     field: sql_order_expr("LOWER(name)"),
                     direction: SqlOrderDirection::Desc,
+                    nulls: None,
                 },
                 SqlOrderTerm {
                     field: sql_order_expr("UPPER(email)"),
                     direction: SqlOrderDirection::Asc,
+                    nulls: None,
                 },
             ],
             limit: Some(2),
+            limit_per: Vec::new(),
+            offset: None,
+        }),
+    );
+}
+
+#[test]
+fn parse_select_statement_with_explicit_nulls_order_placement() {
+    let statement =
+        parse_sql("SELECT * FROM users ORDER BY rank DESC NULLS FIRST, nulls NULLS LAST, id")
+            .expect("explicit null placement should parse");
+
+    assert_eq!(
+        statement,
+        SqlStatement::Select(SqlSelectStatement {
+            entity: "users".to_string(),
+            table_alias: None,
+            projection: SqlProjection::All,
+            projection_aliases: Vec::default(),
+            predicate: None,
+            distinct: false,
+            group_by: vec![],
+            having: vec![],
+            order_by: vec![
+                SqlOrderTerm {
+                    field: sql_order_expr("rank"),
+                    direction: SqlOrderDirection::Desc,
+                    nulls: Some(SqlNullsOrder::First),
+                },
+                SqlOrderTerm {
+                    field: sql_order_expr("nulls"),
+                    direction: SqlOrderDirection::Asc,
+                    nulls: Some(SqlNullsOrder::Last),
+                },
+                SqlOrderTerm {
+                    field: sql_order_expr("id"),
+                    direction: SqlOrderDirection::Asc,
+                    nulls: None,
+                },
+            ],
+            limit: None,
+            limit_per: Vec::new(),
+            offset: None,
+        }),
+    );
+}
+
+#[test]
+fn parse_select_statement_with_limit_per_partition_fields() {
+    let statement =
+        parse_sql("SELECT * FROM users ORDER BY team, age DESC LIMIT 3 PER team, region")
+            .expect("LIMIT PER should parse");
+
+    assert_eq!(
+        statement,
+        SqlStatement::Select(SqlSelectStatement {
+            entity: "users".to_string(),
+            table_alias: None,
+            projection: SqlProjection::All,
+            projection_aliases: Vec::default(),
+            predicate: None,
+            distinct: false,
+            group_by: vec![],
+            having: vec![],
+            order_by: vec![
+                SqlOrderTerm {
+                    field: sql_order_expr("team"),
+                    direction: SqlOrderDirection::Asc,
+                    nulls: None,
+                },
+                SqlOrderTerm {
+                    field: sql_order_expr("age"),
+                    direction: SqlOrderDirection::Desc,
+                    nulls: None,
+                },
+            ],
+            limit: Some(3),
+            limit_per: vec!["team".to_string(), "region".to_string()],
             offset: None,
         }),
     );
 }
 
+#[test]
+fn parse_select_statement_rejects_limit_per_without_partition_fields() {
+    for sql in [
+        "SELECT * FROM users ORDER BY team LIMIT 3 PER",
+        "SELECT * FROM users ORDER BY team LIMIT 3 PER team,",
+    ] {
+        assert!(parse_sql(sql).is_err(), "{sql}");
+    }
+}
+
+#[test]
+fn parse_union_all_statement_hoists_trailing_order_and_window() {
+    let statement = parse_sql(
+        "SELECT id, name FROM users WHERE age > 30 UNION ALL SELECT id, name FROM users WHERE active = true ORDER BY name DESC LIMIT 5 OFFSET 2",
+    )
+    .expect("UNION ALL should parse");
+    let SqlStatement::Union(SqlUnionStatement {
+        branches,
+        all,
+        order_by,
+        limit,
+        offset,
+    }) = statement
+    else {
+        panic!("UNION ALL should parse as one union statement");
+    };
+
+    assert!(all);
+    assert_eq!(branches.len(), 2);
+    assert!(branches.iter().all(|branch| branch.order_by.is_empty()
+        && branch.limit.is_none()
+        && branch.offset.is_none()
+        && branch.predicate.is_some()));
+    assert_eq!(
+        order_by,
+        vec![SqlOrderTerm {
+            field: sql_order_expr("name"),
+            direction: SqlOrderDirection::Desc,
+            nulls: None,
+        }],
+    );
+    assert_eq!((limit, offset), (Some(5), Some(2)));
+}
+
+#[test]
+fn parse_union_statement_rejects_mixed_quantifiers_and_branch_windows() {
+    for sql in [
+        "SELECT * FROM users UNION ALL SELECT * FROM users UNION SELECT * FROM users",
+        "SELECT * FROM users ORDER BY name UNION SELECT * FROM users",
+        "SELECT * FROM users UNION ALL SELECT * FROM users LIMIT 2 UNION ALL SELECT * FROM users",
+        "SELECT * FROM users UNION ALL DELETE FROM users",
+    ] {
+        assert!(parse_sql(sql).is_err(), "{sql}");
+    }
+    assert!(matches!(
+        parse_sql("SELECT * FROM users UNION SELECT * FROM users"),
+        Ok(SqlStatement::Union(SqlUnionStatement { all: false, .. })),
+    ));
+}
+
+#[test]
+fn parse_with_statement_keeps_each_body_and_the_outer_select() {
+    let statement = parse_sql(
+        "WITH adults AS (SELECT id, name FROM users WHERE age >= 18), named AS (SELECT name FROM adults) SELECT name FROM named ORDER BY name LIMIT 3",
+    )
+    .expect("WITH should parse");
+    let SqlStatement::With(with) = statement else {
+        panic!("WITH should parse as one with statement");
+    };
+
+    assert_eq!(
+        with.common_table_expressions
+            .iter()
+            .map(|cte| (cte.name.as_str(), cte.select.entity.as_str()))
+            .collect::<Vec<_>>(),
+        [("adults", "users"), ("named", "adults")],
+    );
+    assert_eq!(with.select.entity, "named");
+    assert_eq!(with.select.limit, Some(3));
+    assert_eq!(with.entity(), "users");
+}
+
+#[test]
+fn parse_with_statement_rejects_recursive_and_column_list_forms() {
+    for sql in [
+        "WITH RECURSIVE t AS (SELECT * FROM users) SELECT * FROM t",
+        "WITH t (id) AS (SELECT id FROM users) SELECT id FROM t",
+    ] {
+        assert!(
+            matches!(
+                parse_sql(sql),
+                Err(SqlParseError::UnsupportedFeature {
+                    feature: SqlFeatureCode::With,
+                    ..
+                })
+            ),
+            "{sql}",
+        );
+    }
+    for sql in [
+        "WITH t AS SELECT * FROM users SELECT * FROM t",
+        "WITH t AS (SELECT * FROM users)",
+        "WITH t AS (SELECT * FROM users) DELETE FROM t",
+        "WITH t AS (DELETE FROM users) SELECT * FROM t",
+    ] {
+        assert!(parse_sql(sql).is_err(), "{sql}");
+    }
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn parse_explain_with_statement_targets_the_with_statement() {
+    let statement = parse_sql("EXPLAIN EXECUTION WITH t AS (SELECT * FROM users) SELECT * FROM t")
+        .expect("EXPLAIN WITH should parse");
+
+    assert!(matches!(
+        statement,
+        SqlStatement::Explain(SqlExplainStatement {
+            mode: SqlExplainMode::Execution,
+            statement: SqlExplainTarget::With(SqlWithStatement { .. }),
+            ..
+        }),
+    ));
+}
+
+#[test]
+fn parse_select_statement_rejects_incomplete_nulls_order_placement() {
+    for sql in [
+        "SELECT * FROM users ORDER BY rank NULLS",
+        "SELECT * FROM users ORDER BY rank NULLS MIDDLE",
+        "SELECT * FROM users ORDER BY rank NULLS FIRST DESC",
+    ] {
+        parse_sql(sql).expect_err("null placement must be `NULLS FIRST | LAST` after direction");
+    }
+}
+
 #[test]
 fn parse_select_statement_with_direct_bounded_computed_order_terms() {
     let statement = parse_sql(
@@ -1255,17 +1662,21 @@ fn parse_select_statement_with_direct_bounded_computed_order_terms() {
                 SqlOrderTerm {
                     field: sql_order_expr("age + 1"),
                     direction: SqlOrderDirection::Asc,
+                    nulls: None,
                 },
                 SqlOrderTerm {
                     field: sql_order_expr("age + salary"),
                     direction: SqlOrderDirection::Desc,
+                    nulls: None,
                 },
                 SqlOrderTerm {
                     field: sql_order_expr("ROUND(age / 3, 2)"),
                     direction: SqlOrderDirection::Asc,
+                    nulls: None,
                 },
             ],
             limit: Some(2),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -1294,13 +1705,16 @@ fn parse_select_statement_with_direct_scalar_function_expression_order_terms() {
                 SqlOrderTerm {
                     field: sql_order_expr("ABS(age - 30)"),
                     direction: SqlOrderDirection::Asc,
+                    nulls: None,
                 },
                 SqlOrderTerm {
                     field: sql_order_expr("COALESCE(NULLIF(age, 20), 99)"),
                     direction: SqlOrderDirection::Desc,
+                    nulls: None,
                 },
             ],
             limit: Some(2),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -1328,8 +1742,10 @@ fn parse_select_statement_with_direct_searched_case_order_terms() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("CASE WHEN age >= 21 THEN rank ELSE age END"),
                 direction: SqlOrderDirection::Desc,
+                nulls: None,
             }],
             limit: Some(2),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -1358,13 +1774,16 @@ fn parse_select_statement_with_direct_unary_text_function_expression_order_terms
                 SqlOrderTerm {
                     field: sql_order_expr("LOWER(COALESCE(name, 'fallback'))"),
                     direction: SqlOrderDirection::Asc,
+                    nulls: None,
                 },
                 SqlOrderTerm {
                     field: sql_order_expr("LENGTH(TRIM(name))"),
                     direction: SqlOrderDirection::Desc,
+                    nulls: None,
                 },
             ],
             limit: Some(2),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -1392,29 +1811,36 @@ fn parse_select_statement_with_supported_scalar_text_order_terms() {
                 SqlOrderTerm {
                     field: sql_order_expr("TRIM(name)"),
                     direction: SqlOrderDirection::Asc,
+                    nulls: None,
                 },
                 SqlOrderTerm {
                     field: sql_order_expr("LTRIM(name)"),
                     direction: SqlOrderDirection::Asc,
+                    nulls: None,
                 },
                 SqlOrderTerm {
                     field: sql_order_expr("RTRIM(name)"),
                     direction: SqlOrderDirection::Asc,
+                    nulls: None,
                 },
                 SqlOrderTerm {
                     field: sql_order_expr("LENGTH(name)"),
                     direction: SqlOrderDirection::Desc,
+                    nulls: None,
                 },
                 SqlOrderTerm {
                     field: sql_order_expr("LEFT(name, 2)"),
                     direction: SqlOrderDirection::Asc,
+                    nulls: None,
                 },
                 SqlOrderTerm {
                     field: sql_order_expr("POSITION('a', name)"),
                     direction: SqlOrderDirection::Desc,
+                    nulls: None,
                 },
             ],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -1453,6 +1879,7 @@ fn parse_select_statement_with_left_and_right_projection_items() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -1507,6 +1934,7 @@ fn parse_select_statement_with_starts_ends_and_position_projection_items() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -1537,6 +1965,7 @@ fn parse_select_statement_with_replace_projection_item() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -1576,6 +2005,7 @@ fn parse_select_statement_with_substring_projection_item() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -1600,6 +2030,7 @@ fn parse_delete_statement_with_limit() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("age"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(3),
             offset: None,
@@ -1627,6 +2058,7 @@ fn parse_delete_statement_with_limit_and_offset() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("age"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(3),
             offset: Some(1),
@@ -1656,6 +2088,7 @@ fn parse_delete_statement_accepts_single_table_alias() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("LOWER(u.name)"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(3),
             offset: Some(1),
@@ -1711,6 +2144,7 @@ fn parse_delete_statement_with_direct_starts_with_family() {
                 order_by: vec![SqlOrderTerm {
                     field: sql_order_expr("id"),
                     direction: SqlOrderDirection::Asc,
+                    nulls: None,
                 }],
                 limit: Some(1),
                 offset: None,
@@ -1742,6 +2176,7 @@ fn parse_explain_json_wrapped_select() {
                 having: vec![],
                 order_by: vec![],
                 limit: Some(1),
+                limit_per: Vec::new(),
                 offset: None,
             }),
         }),
@@ -1770,6 +2205,7 @@ fn parse_explain_execution_json_wrapped_select() {
                 having: vec![],
                 order_by: vec![],
                 limit: Some(1),
+                limit_per: Vec::new(),
                 offset: None,
             }),
         }),
@@ -1828,6 +2264,7 @@ fn parse_explain_json_wrapped_delete_with_direct_starts_with_family() {
                     order_by: vec![SqlOrderTerm {
                         field: sql_order_expr("id"),
                         direction: SqlOrderDirection::Asc,
+                        nulls: None,
                     }],
                     limit: Some(1),
                     offset: None,
@@ -2100,6 +2537,8 @@ fn parse_create_index_statement_keeps_ddl_intent_unresolved() {
             name: "user_age_idx".to_string(),
             entity: "public.users".to_string(),
             key_items: ddl_field_paths(&["profile.age"]),
+            descending: Vec::new(),
+            include: Vec::new(),
             predicate_sql: None,
             uniqueness: SqlCreateIndexUniqueness::NonUnique,
             if_not_exists: false,
@@ -2119,6 +2558,8 @@ fn parse_create_multi_field_index_statement_keeps_ddl_intent_unresolved() {
             name: "user_age_name_idx".to_string(),
             entity: "public.users".to_string(),
             key_items: ddl_field_paths(&["age", "name"]),
+            descending: Vec::new(),
+            include: Vec::new(),
             predicate_sql: None,
             uniqueness: SqlCreateIndexUniqueness::NonUnique,
             if_not_exists: false,
@@ -2127,6 +2568,29 @@ fn parse_create_multi_field_index_statement_keeps_ddl_intent_unresolved() {
     );
 }
 
+#[test]
+fn parse_create_index_include_keeps_payload_fields_before_predicate() {
+    let statement = parse_sql(
+        "CREATE INDEX user_score_idx ON users (score) INCLUDE (name, avatar) WHERE active = TRUE",
+    )
+    .expect("CREATE INDEX with INCLUDE payload fields should parse");
+
+    assert_eq!(
+        statement,
+        SqlStatement::Ddl(SqlDdlStatement::CreateIndex(SqlCreateIndexStatement {
+            name: "user_score_idx".to_string(),
+            entity: "users".to_string(),
+            key_items: ddl_field_paths(&["score"]),
+            descending: Vec::new(),
+            include: vec!["name".to_string(), "avatar".to_string()],
+            predicate_sql: Some("active = TRUE".to_string()),
+            uniqueness: SqlCreateIndexUniqueness::NonUnique,
+            if_not_exists: false,
+            schema_version_contract: SqlDdlSchemaVersionContract::default(),
+        })),
+    );
+}
+
 #[test]
 fn parse_create_index_treats_asc_as_default_order() {
     let statement = parse_sql("CREATE INDEX user_age_name_idx ON public.users (age ASC, name ASC)")
@@ -2138,6 +2602,29 @@ fn parse_create_index_treats_asc_as_default_order() {
             name: "user_age_name_idx".to_string(),
             entity: "public.users".to_string(),
             key_items: ddl_field_paths(&["age", "name"]),
+            descending: Vec::new(),
+            include: Vec::new(),
+            predicate_sql: None,
+            uniqueness: SqlCreateIndexUniqueness::NonUnique,
+            if_not_exists: false,
+            schema_version_contract: SqlDdlSchemaVersionContract::default(),
+        })),
+    );
+}
+
+#[test]
+fn parse_create_index_records_descending_field_keys() {
+    let statement = parse_sql("CREATE INDEX player_score_idx ON players (score DESC, id ASC)")
+        .expect("CREATE INDEX with mixed key directions should parse");
+
+    assert_eq!(
+        statement,
+        SqlStatement::Ddl(SqlDdlStatement::CreateIndex(SqlCreateIndexStatement {
+            name: "player_score_idx".to_string(),
+            entity: "players".to_string(),
+            key_items: ddl_field_paths(&["score", "id"]),
+            descending: vec!["score".to_string()],
+            include: Vec::new(),
             predicate_sql: None,
             uniqueness: SqlCreateIndexUniqueness::NonUnique,
             if_not_exists: false,
@@ -2160,6 +2647,7 @@ fn parse_alter_table_add_column_statement_keeps_ddl_intent_unresolved() {
                 column_type: "text".to_string(),
                 nullable: true,
                 default: None,
+                generated: None,
                 schema_version_contract: SqlDdlSchemaVersionContract::default(),
             },
         )),
@@ -2180,6 +2668,7 @@ fn parse_alter_table_add_column_statement_keeps_default_and_nullability_intent()
                 column_type: "nat64".to_string(),
                 nullable: false,
                 default: Some(Value::Int64(7)),
+                generated: None,
                 schema_version_contract: SqlDdlSchemaVersionContract::default(),
             },
         )),
@@ -2200,6 +2689,7 @@ fn parse_alter_table_add_column_statement_accepts_not_null_before_default() {
                 column_type: "nat64".to_string(),
                 nullable: false,
                 default: Some(Value::Int64(0)),
+                generated: None,
                 schema_version_contract: SqlDdlSchemaVersionContract::default(),
             },
         )),
@@ -2221,6 +2711,7 @@ fn parse_alter_table_add_column_statement_keeps_nat_big_max_bytes_type_modifier(
                 column_type: "nat_big(max_bytes=512)".to_string(),
                 nullable: true,
                 default: Some(Value::Int64(0)),
+                generated: None,
                 schema_version_contract: SqlDdlSchemaVersionContract::default(),
             },
         )),
@@ -2228,27 +2719,73 @@ fn parse_alter_table_add_column_statement_keeps_nat_big_max_bytes_type_modifier(
 }
 
 #[test]
-fn parse_alter_table_add_column_statement_rejects_malformed_nat_big_max_bytes_modifier() {
-    for sql in [
-        "ALTER TABLE users ADD COLUMN score nat_big(max_len = 512)",
-        "ALTER TABLE users ADD COLUMN score nat_big(max_bytes = -1)",
-        "ALTER TABLE users ADD COLUMN score nat_big(max_bytes = 4294967296)",
-        "ALTER TABLE users ADD COLUMN score nat_big(max_bytes = 1, max_bytes = 2)",
-    ] {
-        parse_sql(sql).expect_err("malformed nat_big max_bytes modifier should fail parsing");
-    }
-}
-
-#[test]
-fn parse_alter_table_alter_column_statement_keeps_default_intent_unresolved() {
-    let statement = parse_sql("ALTER TABLE users ALTER COLUMN score SET DEFAULT 7")
-        .expect("ALTER TABLE ALTER COLUMN SET DEFAULT should parse as DDL intent");
+fn parse_alter_table_add_column_statement_keeps_generated_expression_text() {
+    let statement = parse_sql(
+        "ALTER TABLE users ADD COLUMN total int64 GENERATED ALWAYS AS (price * quantity) STORED",
+    )
+    .expect("ALTER TABLE ADD COLUMN GENERATED ALWAYS AS should parse");
 
     assert_eq!(
         statement,
-        SqlStatement::Ddl(SqlDdlStatement::AlterTableAlterColumn(
-            SqlAlterTableAlterColumnStatement {
-                entity: "users".to_string(),
+        SqlStatement::Ddl(SqlDdlStatement::AlterTableAddColumn(
+            SqlAlterTableAddColumnStatement {
+                entity: "users".to_string(),
+                column_name: "total".to_string(),
+                column_type: "int64".to_string(),
+                nullable: true,
+                default: None,
+                generated: Some("price * quantity".to_string()),
+                schema_version_contract: SqlDdlSchemaVersionContract::default(),
+            },
+        )),
+    );
+}
+
+#[test]
+fn parse_alter_table_add_column_statement_rejects_generated_with_default_or_not_null() {
+    for sql in [
+        "ALTER TABLE users ADD COLUMN total int64 DEFAULT 0 GENERATED ALWAYS AS (price) STORED",
+        "ALTER TABLE users ADD COLUMN total int64 GENERATED ALWAYS AS (price) STORED NOT NULL",
+        "ALTER TABLE users ADD COLUMN total int64 GENERATED ALWAYS AS (price) STORED GENERATED ALWAYS AS (price) STORED",
+    ] {
+        let err = parse_sql(sql).expect_err("conflicting generated column modifiers should fail");
+
+        assert_eq!(
+            err,
+            SqlParseError::unsupported_feature(SqlFeatureCode::AlterTableAddColumnModifiers),
+        );
+    }
+    for sql in [
+        "ALTER TABLE users ADD COLUMN total int64 GENERATED ALWAYS AS (price)",
+        "ALTER TABLE users ADD COLUMN total int64 GENERATED AS (price) STORED",
+        "ALTER TABLE users ADD COLUMN total int64 GENERATED ALWAYS AS () STORED",
+    ] {
+        parse_sql(sql).expect_err("malformed generated column clause should fail parsing");
+    }
+}
+
+#[test]
+fn parse_alter_table_add_column_statement_rejects_malformed_nat_big_max_bytes_modifier() {
+    for sql in [
+        "ALTER TABLE users ADD COLUMN score nat_big(max_len = 512)",
+        "ALTER TABLE users ADD COLUMN score nat_big(max_bytes = -1)",
+        "ALTER TABLE users ADD COLUMN score nat_big(max_bytes = 4294967296)",
+        "ALTER TABLE users ADD COLUMN score nat_big(max_bytes = 1, max_bytes = 2)",
+    ] {
+        parse_sql(sql).expect_err("malformed nat_big max_bytes modifier should fail parsing");
+    }
+}
+
+#[test]
+fn parse_alter_table_alter_column_statement_keeps_default_intent_unresolved() {
+    let statement = parse_sql("ALTER TABLE users ALTER COLUMN score SET DEFAULT 7")
+        .expect("ALTER TABLE ALTER COLUMN SET DEFAULT should parse as DDL intent");
+
+    assert_eq!(
+        statement,
+        SqlStatement::Ddl(SqlDdlStatement::AlterTableAlterColumn(
+            SqlAlterTableAlterColumnStatement {
+                entity: "users".to_string(),
                 column_name: "score".to_string(),
                 action: SqlAlterColumnAction::SetDefault(Value::Int64(7)),
                 schema_version_contract: SqlDdlSchemaVersionContract::default(),
@@ -2419,6 +2956,8 @@ fn parse_create_unique_index_statement_keeps_ddl_intent_unresolved() {
             name: "user_age_idx".to_string(),
             entity: "public.users".to_string(),
             key_items: ddl_field_paths(&["profile.age"]),
+            descending: Vec::new(),
+            include: Vec::new(),
             predicate_sql: None,
             uniqueness: SqlCreateIndexUniqueness::Unique,
             if_not_exists: false,
@@ -2441,6 +2980,8 @@ fn parse_create_index_keeps_expression_key_intent_unresolved() {
                 SqlCreateIndexExpressionFunction::Lower,
                 "name",
             )],
+            descending: Vec::new(),
+            include: Vec::new(),
             predicate_sql: None,
             uniqueness: SqlCreateIndexUniqueness::NonUnique,
             if_not_exists: false,
@@ -2466,6 +3007,8 @@ fn parse_create_index_keeps_supported_expression_key_family_intent_unresolved()
                 ddl_expression_key(SqlCreateIndexExpressionFunction::Upper, "code"),
                 ddl_expression_key(SqlCreateIndexExpressionFunction::Trim, "email"),
             ],
+            descending: Vec::new(),
+            include: Vec::new(),
             predicate_sql: None,
             uniqueness: SqlCreateIndexUniqueness::NonUnique,
             if_not_exists: false,
@@ -2484,6 +3027,129 @@ fn parse_create_index_rejects_unknown_expression_index_functions() {
     );
 }
 
+#[test]
+fn parse_create_index_keeps_scalar_expression_key_source_unresolved() {
+    let statement = parse_sql(
+        "CREATE INDEX line_revenue_idx ON lines ((price * quantity), COALESCE(name, '?'))",
+    )
+    .expect("scalar expression keys should parse before catalog binding");
+
+    assert_eq!(
+        statement,
+        SqlStatement::Ddl(SqlDdlStatement::CreateIndex(SqlCreateIndexStatement {
+            name: "line_revenue_idx".to_string(),
+            entity: "lines".to_string(),
+            key_items: vec![
+                SqlCreateIndexKeyItem::Scalar("( price * quantity )".to_string()),
+                SqlCreateIndexKeyItem::Scalar("COALESCE ( name , '?' )".to_string()),
+            ],
+            descending: Vec::new(),
+            include: Vec::new(),
+            predicate_sql: None,
+            uniqueness: SqlCreateIndexUniqueness::NonUnique,
+            if_not_exists: false,
+            schema_version_contract: SqlDdlSchemaVersionContract::default(),
+        })),
+    );
+}
+
+#[test]
+fn parse_create_index_using_text_wraps_single_field_in_token_key() {
+    let statement = parse_sql("CREATE INDEX post_body_text ON posts USING TEXT (body)")
+        .expect("text CREATE INDEX should parse");
+
+    assert_eq!(
+        statement,
+        SqlStatement::Ddl(SqlDdlStatement::CreateIndex(SqlCreateIndexStatement {
+            name: "post_body_text".to_string(),
+            entity: "posts".to_string(),
+            key_items: vec![ddl_expression_key(
+                SqlCreateIndexExpressionFunction::Tokens,
+                "body",
+            )],
+            descending: Vec::new(),
+            include: Vec::new(),
+            predicate_sql: None,
+            uniqueness: SqlCreateIndexUniqueness::NonUnique,
+            if_not_exists: false,
+            schema_version_contract: SqlDdlSchemaVersionContract::default(),
+        })),
+    );
+}
+
+#[test]
+fn parse_create_index_using_text_rejects_unsupported_key_shapes() {
+    for (sql, feature) in [
+        (
+            "CREATE UNIQUE INDEX post_body_text ON posts USING TEXT (body)",
+            SqlFeatureCode::TextIndexKeyShape,
+        ),
+        (
+            "CREATE INDEX post_body_text ON posts USING TEXT (title, body)",
+            SqlFeatureCode::TextIndexKeyShape,
+        ),
+        (
+            "CREATE INDEX post_body_text ON posts USING TEXT (LOWER(body))",
+            SqlFeatureCode::TextIndexKeyShape,
+        ),
+        (
+            "CREATE INDEX post_body_text ON posts USING HASH (body)",
+            SqlFeatureCode::CreateIndexModifiers,
+        ),
+        (
+            "CREATE INDEX post_body_text ON posts (TOKENS(body))",
+            SqlFeatureCode::ExpressionIndexUnsupportedFunction,
+        ),
+    ] {
+        assert_eq!(
+            parse_sql(sql),
+            Err(SqlParseError::UnsupportedFeature { feature }),
+            "{sql}",
+        );
+    }
+}
+
+#[test]
+fn parse_create_index_using_multivalue_wraps_single_field_in_element_key() {
+    let statement = parse_sql("CREATE INDEX post_tags ON posts USING MULTIVALUE (tags)")
+        .expect("multivalue CREATE INDEX should parse");
+
+    assert_eq!(
+        statement,
+        SqlStatement::Ddl(SqlDdlStatement::CreateIndex(SqlCreateIndexStatement {
+            name: "post_tags".to_string(),
+            entity: "posts".to_string(),
+            key_items: vec![ddl_expression_key(
+                SqlCreateIndexExpressionFunction::Elements,
+                "tags",
+            )],
+            descending: Vec::new(),
+            include: Vec::new(),
+            predicate_sql: None,
+            uniqueness: SqlCreateIndexUniqueness::NonUnique,
+            if_not_exists: false,
+            schema_version_contract: SqlDdlSchemaVersionContract::default(),
+        })),
+    );
+}
+
+#[test]
+fn parse_create_index_using_multivalue_rejects_unsupported_key_shapes() {
+    for sql in [
+        "CREATE UNIQUE INDEX post_tags ON posts USING MULTIVALUE (tags)",
+        "CREATE INDEX post_tags ON posts USING MULTIVALUE (tags, roles)",
+        "CREATE INDEX post_tags ON posts USING MULTIVALUE (LOWER(tags))",
+    ] {
+        assert_eq!(
+            parse_sql(sql),
+            Err(SqlParseError::UnsupportedFeature {
+                feature: SqlFeatureCode::MultivalueIndexKeyShape,
+            }),
+            "{sql}",
+        );
+    }
+}
+
 #[test]
 fn parse_create_index_keeps_filtered_index_predicate_sql() {
     let statement = parse_sql("CREATE INDEX user_age_idx ON users (age) WHERE active = true")
@@ -2495,6 +3161,8 @@ fn parse_create_index_keeps_filtered_index_predicate_sql() {
             name: "user_age_idx".to_string(),
             entity: "users".to_string(),
             key_items: ddl_field_paths(&["age"]),
+            descending: Vec::new(),
+            include: Vec::new(),
             predicate_sql: Some("active = TRUE".to_string()),
             uniqueness: SqlCreateIndexUniqueness::NonUnique,
             if_not_exists: false,
@@ -2544,6 +3212,8 @@ fn parse_ddl_idempotency_clauses_keep_explicit_intent() {
             name: "user_age_idx".to_string(),
             entity: "users".to_string(),
             key_items: ddl_field_paths(&["age"]),
+            descending: Vec::new(),
+            include: Vec::new(),
             predicate_sql: None,
             uniqueness: SqlCreateIndexUniqueness::NonUnique,
             if_not_exists: true,
@@ -2591,6 +3261,7 @@ fn parse_ddl_schema_version_contracts_keep_transition_intent() {
                 column_type: "text".to_string(),
                 nullable: true,
                 default: None,
+                generated: None,
                 schema_version_contract: SqlDdlSchemaVersionContract {
                     expected_schema_version: Some(3),
                     next_schema_version: Some(4),
@@ -2612,6 +3283,7 @@ fn parse_ddl_schema_version_contracts_keep_transition_intent() {
                 column_type: "text".to_string(),
                 nullable: true,
                 default: None,
+                generated: None,
                 schema_version_contract: SqlDdlSchemaVersionContract {
                     expected_schema_version: Some(3),
                     next_schema_version: Some(4),
@@ -2630,6 +3302,8 @@ fn parse_ddl_schema_version_contracts_keep_transition_intent() {
             name: "user_age_idx".to_string(),
             entity: "users".to_string(),
             key_items: ddl_field_paths(&["age"]),
+            descending: Vec::new(),
+            include: Vec::new(),
             predicate_sql: None,
             uniqueness: SqlCreateIndexUniqueness::NonUnique,
             if_not_exists: false,
@@ -2715,8 +3389,10 @@ fn parse_select_statement_with_qualified_identifiers() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("users.age"),
                 direction: SqlOrderDirection::Desc,
+                nulls: None,
             }],
             limit: Some(10),
+            limit_per: Vec::new(),
             offset: Some(1),
         }),
     );
@@ -2750,8 +3426,10 @@ fn parse_select_statement_with_strict_like_prefix_predicate() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("id"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(1),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -2785,8 +3463,10 @@ fn parse_select_statement_with_angle_bracket_not_equal_predicate() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("id"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(1),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -2820,8 +3500,10 @@ fn parse_select_statement_with_in_trailing_comma_predicate() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("id"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(1),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -2861,8 +3543,10 @@ fn parse_select_statement_with_is_true_and_is_false_predicates() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("id"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(1),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -2885,8 +3569,10 @@ fn parse_select_statement_with_is_true_and_is_false_predicates() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("id"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(1),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -2928,8 +3614,10 @@ fn parse_select_statement_with_is_not_true_and_is_not_false_predicates() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("id"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(1),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -2954,8 +3642,10 @@ fn parse_select_statement_with_is_not_true_and_is_not_false_predicates() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("id"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(1),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -3003,8 +3693,10 @@ fn parse_select_statement_with_field_bound_between_and_not_between_predicates()
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("id"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(1),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -3035,8 +3727,10 @@ fn parse_select_statement_with_field_bound_between_and_not_between_predicates()
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("id"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(1),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -3070,8 +3764,10 @@ fn parse_select_statement_with_strict_not_like_prefix_predicate() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("id"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(1),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -3105,8 +3801,10 @@ fn parse_select_statement_with_ilike_prefix_predicate() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("id"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(1),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -3140,8 +3838,10 @@ fn parse_select_statement_with_not_ilike_prefix_predicate() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("id"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(1),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -3183,8 +3883,10 @@ fn parse_select_statement_with_strict_text_range_predicate() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("id"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(1),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -3218,8 +3920,10 @@ fn parse_select_statement_with_direct_starts_with_predicate() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("id"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(1),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -3253,8 +3957,10 @@ fn parse_select_statement_with_direct_lower_starts_with_predicate() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("id"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(1),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -3289,8 +3995,10 @@ fn parse_select_statement_with_direct_upper_starts_with_predicate() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("id"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(1),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -3324,8 +4032,10 @@ fn parse_select_statement_with_lower_like_prefix_predicate() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("id"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(1),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -3359,8 +4069,10 @@ fn parse_select_statement_with_lower_not_like_prefix_predicate() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("id"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(1),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -3394,8 +4106,10 @@ fn parse_select_statement_with_upper_like_prefix_predicate() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("id"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(1),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -3439,6 +4153,57 @@ fn parse_select_statement_keeps_like_pattern_semantics_for_lowering() {
     }
 }
 
+#[test]
+fn parse_select_statement_accepts_like_escape_and_regex_operators() {
+    let name = || SqlExpr::Field("name".to_string());
+    let regex = |pattern: &str| SqlExpr::FunctionCall {
+        function: SqlScalarFunction::RegexpMatches,
+        args: vec![name(), SqlExpr::Literal(Value::Text(pattern.to_string()))],
+    };
+    let cases = [
+        (
+            "SELECT * FROM users WHERE name NOT LIKE '10!%%' ESCAPE '!'",
+            SqlExpr::Like {
+                expr: Box::new(name()),
+                pattern: "10!%%".to_string(),
+                escape: Some('!'),
+                negated: true,
+                casefold: false,
+            },
+        ),
+        ("SELECT * FROM users WHERE name ~ '^a.c'", regex("^a.c")),
+        (
+            "SELECT * FROM users WHERE name !~ 'x'",
+            SqlExpr::Unary {
+                op: SqlExprUnaryOp::Not,
+                expr: Box::new(regex("x")),
+            },
+        ),
+        (
+            "SELECT * FROM users WHERE regexp_matches(name, 'b+')",
+            regex("b+"),
+        ),
+    ];
+
+    for (sql, expected_predicate) in cases {
+        let SqlStatement::Select(statement) =
+            parse_sql(sql).expect("text pattern SQL should parse")
+        else {
+            panic!("text pattern SQL should parse as SELECT");
+        };
+
+        assert_eq!(statement.predicate, Some(expected_predicate), "{sql}");
+    }
+
+    for sql in [
+        "SELECT * FROM users WHERE name LIKE 'a%' ESCAPE '!!'",
+        "SELECT * FROM users WHERE name LIKE 'a%' ESCAPE 1",
+        "SELECT * FROM users WHERE name ~ 1",
+    ] {
+        parse_sql(sql).expect_err("malformed text pattern operand should fail to parse");
+    }
+}
+
 #[test]
 fn parse_select_grouped_statement_with_qualified_identifiers() {
     let statement = parse_sql(
@@ -3462,6 +4227,7 @@ fn parse_select_grouped_statement_with_qualified_identifiers() {
                     input: None,
                     filter_expr: None,
                     distinct: false,
+                    arguments: None,
                 }),
             ]),
             projection_aliases: vec![None, None],
@@ -3472,13 +4238,15 @@ fn parse_select_grouped_statement_with_qualified_identifiers() {
                 CoercionId::NumericWiden,
             ))),
             distinct: false,
-            group_by: vec!["users.age".to_string()],
+            group_by: vec![SqlExpr::Field("users.age".to_string())],
             having: vec![],
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("users.age"),
                 direction: SqlOrderDirection::Desc,
+                nulls: None,
             }],
             limit: Some(5),
+            limit_per: Vec::new(),
             offset: Some(1),
         }),
     );
@@ -3517,8 +4285,10 @@ fn parse_explain_execution_with_qualified_identifiers() {
                 order_by: vec![SqlOrderTerm {
                     field: sql_order_expr("users.age"),
                     direction: SqlOrderDirection::Desc,
+                    nulls: None,
                 }],
                 limit: Some(1),
+                limit_per: Vec::new(),
                 offset: None,
             }),
         }),
@@ -3558,8 +4328,10 @@ fn parse_explain_execution_verbose_with_qualified_identifiers() {
                 order_by: vec![SqlOrderTerm {
                     field: sql_order_expr("users.age"),
                     direction: SqlOrderDirection::Desc,
+                    nulls: None,
                 }],
                 limit: Some(1),
+                limit_per: Vec::new(),
                 offset: None,
             }),
         }),
@@ -3589,12 +4361,13 @@ fn parse_select_grouped_statement_with_having_clauses() {
                     input: None,
                     filter_expr: None,
                     distinct: false,
+                    arguments: None,
                 }),
             ]),
             projection_aliases: vec![None, None],
             predicate: None,
             distinct: false,
-            group_by: vec!["age".to_string()],
+            group_by: vec![SqlExpr::Field("age".to_string())],
             having: vec![SqlExpr::Binary {
                 op: SqlExprBinaryOp::And,
                 left: Box::new(SqlExpr::Binary {
@@ -3609,6 +4382,7 @@ fn parse_select_grouped_statement_with_having_clauses() {
                         input: None,
                         filter_expr: None,
                         distinct: false,
+                        arguments: None,
                     })),
                     right: Box::new(SqlExpr::Literal(Value::Int64(1))),
                 }),
@@ -3616,8 +4390,10 @@ fn parse_select_grouped_statement_with_having_clauses() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("age"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(10),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -3646,12 +4422,13 @@ fn parse_select_grouped_statement_with_having_is_null_and_is_not_null_clauses()
                     input: None,
                     filter_expr: None,
                     distinct: false,
+                    arguments: None,
                 }),
             ]),
             projection_aliases: vec![None, None],
             predicate: None,
             distinct: false,
-            group_by: vec!["age".to_string()],
+            group_by: vec![SqlExpr::Field("age".to_string())],
             having: vec![SqlExpr::Binary {
                 op: SqlExprBinaryOp::And,
                 left: Box::new(SqlExpr::NullTest {
@@ -3664,6 +4441,7 @@ fn parse_select_grouped_statement_with_having_is_null_and_is_not_null_clauses()
                         input: None,
                         filter_expr: None,
                         distinct: false,
+                        arguments: None,
                     })),
                     negated: false,
                 }),
@@ -3671,8 +4449,10 @@ fn parse_select_grouped_statement_with_having_is_null_and_is_not_null_clauses()
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("age"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(10),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -3737,12 +4517,13 @@ fn parse_select_grouped_statement_with_searched_case_having_exprs() {
                     input: None,
                     filter_expr: None,
                     distinct: false,
+                    arguments: None,
                 }),
             ]),
             projection_aliases: vec![None, None],
             predicate: None,
             distinct: false,
-            group_by: vec!["age".to_string()],
+            group_by: vec![SqlExpr::Field("age".to_string())],
             having: vec![SqlExpr::Binary {
                 op: SqlExprBinaryOp::Eq,
                 left: Box::new(SqlExpr::Case {
@@ -3754,6 +4535,7 @@ fn parse_select_grouped_statement_with_searched_case_having_exprs() {
                                 input: None,
                                 filter_expr: None,
                                 distinct: false,
+                                arguments: None,
                             })),
                             right: Box::new(SqlExpr::Literal(Value::Int64(1))),
                         },
@@ -3766,8 +4548,10 @@ fn parse_select_grouped_statement_with_searched_case_having_exprs() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("age"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(10),
+            limit_per: Vec::new(),
             offset: None,
         }),
         "searched CASE HAVING values should stay on the shared post-aggregate SQL-expression boundary",
@@ -3797,33 +4581,67 @@ fn parse_select_grouped_statement_with_aggregate_order_terms() {
                     input: Some(Box::new(SqlExpr::Field("score".to_string()))),
                     filter_expr: None,
                     distinct: false,
+                    arguments: None,
                 }),
             ]),
             projection_aliases: vec![None, None],
             predicate: None,
             distinct: false,
-            group_by: vec!["age".to_string()],
+            group_by: vec![SqlExpr::Field("age".to_string())],
             having: vec![],
             order_by: vec![
                 SqlOrderTerm {
                     field: sql_order_expr("AVG(score)"),
                     direction: SqlOrderDirection::Desc,
+                    nulls: None,
                 },
                 SqlOrderTerm {
                     field: sql_order_expr("ROUND(AVG(score), 2)"),
                     direction: SqlOrderDirection::Asc,
+                    nulls: None,
                 },
                 SqlOrderTerm {
                     field: sql_order_expr("age"),
                     direction: SqlOrderDirection::Asc,
+                    nulls: None,
                 },
             ],
             limit: Some(10),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
 }
 
+#[test]
+fn parse_select_group_by_keeps_scalar_expression_keys() {
+    let SqlStatement::Select(statement) = parse_sql(
+        "SELECT LOWER(name), score / 100, COUNT(*) \
+         FROM users \
+         GROUP BY LOWER(name), score / 100, age",
+    )
+    .expect("grouped expression keys should parse") else {
+        panic!("grouped expression SQL should parse as SELECT");
+    };
+
+    assert_eq!(
+        statement.group_by,
+        vec![
+            sql_scalar_function_field_expr(SqlScalarFunction::Lower, "name"),
+            sql_binary_expr(
+                SqlExpr::Field("score".to_string()),
+                SqlExprBinaryOp::Div,
+                SqlExpr::Literal(Value::Int64(100)),
+            ),
+            SqlExpr::Field("age".to_string()),
+        ],
+    );
+    assert!(
+        parse_sql("SELECT COUNT(*) FROM users GROUP BY COUNT(*)").is_err(),
+        "GROUP BY keys must stay row-local and reject aggregate calls",
+    );
+}
+
 #[test]
 fn parse_select_grouped_statement_with_wrapped_aggregate_order_terms() {
     let statement = parse_sql(
@@ -3933,6 +4751,7 @@ fn parse_insert_statement_with_explicit_columns_and_values() {
                 sql_write_literal(Value::Text("Ada".to_string())),
                 sql_write_literal(Value::Int64(21)),
             ]]),
+            on_conflict: None,
             returning: None,
         }),
     );
@@ -3952,6 +4771,7 @@ fn parse_insert_statement_preserves_explicit_default_values() {
                 SqlWriteValue::Default,
                 sql_write_literal(Value::Text("Ada".to_string())),
             ]]),
+            on_conflict: None,
             returning: None,
         }),
     );
@@ -3968,6 +4788,7 @@ fn parse_insert_statement_preserves_default_values_source() {
             entity: "users".to_string(),
             columns: Vec::new(),
             source: SqlInsertSource::DefaultValues,
+            on_conflict: None,
             returning: None,
         }),
     );
@@ -3987,6 +4808,7 @@ fn parse_insert_statement_with_hex_blob_literal_values() {
                 sql_write_literal(Value::Int64(7)),
                 sql_write_literal(Value::Blob(vec![0x0A, 0x0B, 0xFF])),
             ]]),
+            on_conflict: None,
             returning: None,
         }),
     );
@@ -4015,6 +4837,7 @@ fn parse_insert_statement_with_multiple_values_tuples() {
                     sql_write_literal(Value::Int64(22))
                 ],
             ]),
+            on_conflict: None,
             returning: None,
         }),
     );
@@ -4071,6 +4894,54 @@ fn parse_update_statement_preserves_explicit_default_assignment() {
     );
 }
 
+#[test]
+fn parse_update_statement_accepts_expression_assignments() {
+    let statement =
+        parse_sql("UPDATE users SET score = score + 1, name = LOWER(name), age = -3 WHERE id = 7")
+            .expect("update expression assignments should parse");
+
+    let SqlStatement::Update(statement) = statement else {
+        panic!("expected UPDATE statement");
+    };
+    assert_eq!(
+        statement.assignments,
+        vec![
+            SqlAssignment {
+                field: "score".to_string(),
+                value: SqlWriteValue::Expr(SqlExpr::Binary {
+                    op: SqlExprBinaryOp::Add,
+                    left: Box::new(SqlExpr::Field("score".to_string())),
+                    right: Box::new(SqlExpr::Literal(Value::Int64(1))),
+                }),
+            },
+            SqlAssignment {
+                field: "name".to_string(),
+                value: SqlWriteValue::Expr(SqlExpr::FunctionCall {
+                    function: SqlScalarFunction::Lower,
+                    args: vec![SqlExpr::Field("name".to_string())],
+                }),
+            },
+            SqlAssignment {
+                field: "age".to_string(),
+                value: sql_write_literal(Value::Int64(-3)),
+            },
+        ]
+    );
+}
+
+#[test]
+fn parse_update_statement_rejects_aggregate_assignment_values() {
+    for sql in [
+        "UPDATE users SET score = COUNT(*) WHERE id = 7",
+        "UPDATE users SET score = SUM(score) WHERE id = 7",
+    ] {
+        assert!(
+            parse_sql(sql).is_err(),
+            "aggregate UPDATE assignment values must reject: {sql}"
+        );
+    }
+}
+
 #[test]
 fn parse_default_rejects_non_write_positions() {
     for sql in [
@@ -4146,10 +5017,12 @@ fn parse_update_statement_with_order_limit_and_offset() {
                 SqlOrderTerm {
                     field: sql_order_expr("age"),
                     direction: SqlOrderDirection::Desc,
+                    nulls: None,
                 },
                 SqlOrderTerm {
                     field: sql_order_expr("id"),
                     direction: SqlOrderDirection::Asc,
+                    nulls: None,
                 },
             ],
             limit: Some(2),
@@ -4198,6 +5071,7 @@ fn parse_insert_statement_with_returning_field_list_parses() {
                 sql_write_literal(Value::Int64(1)),
                 sql_write_literal(Value::Text("Ada".to_string()))
             ]]),
+            on_conflict: None,
             returning: Some(SqlReturningProjection::Fields(vec![
                 "id".to_string(),
                 "name".to_string(),
@@ -4283,6 +5157,7 @@ fn parse_insert_statement_without_column_list_parses() {
             entity: "users".to_string(),
             columns: vec![],
             source: SqlInsertSource::Values(vec![vec![sql_write_literal(Value::Int64(1))]]),
+            on_conflict: None,
             returning: None,
         }),
     );
@@ -4320,10 +5195,13 @@ fn parse_insert_statement_with_field_only_select_source_parses() {
                 order_by: vec![SqlOrderTerm {
                     field: sql_order_expr("id"),
                     direction: SqlOrderDirection::Asc,
+                    nulls: None,
                 }],
                 limit: Some(1),
+                limit_per: Vec::new(),
                 offset: None,
             })),
+            on_conflict: None,
             returning: None,
         }),
     );
@@ -4362,10 +5240,13 @@ fn parse_insert_statement_with_computed_select_source_parses() {
                 order_by: vec![SqlOrderTerm {
                     field: sql_order_expr("id"),
                     direction: SqlOrderDirection::Asc,
+                    nulls: None,
                 }],
                 limit: Some(1),
+                limit_per: Vec::new(),
                 offset: None,
             })),
+            on_conflict: None,
             returning: None,
         }),
     );
@@ -4385,6 +5266,7 @@ fn parse_insert_statement_accepts_single_table_alias() {
                 sql_write_literal(Value::Int64(1)),
                 sql_write_literal(Value::Text("Ada".to_string())),
             ]]),
+            on_conflict: None,
             returning: None,
         }),
     );
@@ -4401,6 +5283,7 @@ fn parse_insert_statement_accepts_as_table_alias_without_column_list() {
             entity: "users".to_string(),
             columns: vec![],
             source: SqlInsertSource::Values(vec![vec![sql_write_literal(Value::Int64(1))]]),
+            on_conflict: None,
             returning: None,
         }),
     );
@@ -4441,11 +5324,223 @@ fn parse_insert_statement_without_column_list_accepts_multiple_values_tuples() {
                     sql_write_literal(Value::Int64(22))
                 ],
             ]),
+            on_conflict: None,
             returning: None,
         }),
     );
 }
 
+#[test]
+fn parse_insert_statement_with_on_conflict_do_nothing_parses() {
+    let statement =
+        parse_sql("INSERT INTO users (id, name) VALUES (1, 'Ada') ON CONFLICT (id) DO NOTHING")
+            .expect("INSERT ON CONFLICT DO NOTHING should parse");
+
+    assert_eq!(
+        statement,
+        SqlStatement::Insert(SqlInsertStatement {
+            entity: "users".to_string(),
+            columns: vec!["id".to_string(), "name".to_string()],
+            source: SqlInsertSource::Values(vec![vec![
+                sql_write_literal(Value::Int64(1)),
+                sql_write_literal(Value::Text("Ada".to_string())),
+            ]]),
+            on_conflict: Some(SqlInsertOnConflict {
+                target: vec!["id".to_string()],
+                action: SqlInsertConflictAction::DoNothing,
+            }),
+            returning: None,
+        }),
+    );
+}
+
+#[test]
+fn parse_insert_statement_with_on_conflict_do_update_keeps_excluded_references() {
+    let statement = parse_sql(
+        "INSERT INTO users (id, name, age) VALUES (1, 'Ada', 21) \
+         ON CONFLICT (id) DO UPDATE SET name = EXCLUDED.name, age = age + 1 RETURNING id",
+    )
+    .expect("INSERT ON CONFLICT DO UPDATE should parse");
+
+    let SqlStatement::Insert(SqlInsertStatement {
+        on_conflict: Some(on_conflict),
+        returning,
+        ..
+    }) = statement
+    else {
+        panic!("expected INSERT with ON CONFLICT clause");
+    };
+
+    assert_eq!(on_conflict.target, vec!["id".to_string()]);
+    assert_eq!(
+        on_conflict.action,
+        SqlInsertConflictAction::DoUpdate(vec![
+            SqlAssignment {
+                field: "name".to_string(),
+                value: SqlWriteValue::Expr(SqlExpr::FieldPath {
+                    root: "EXCLUDED".to_string(),
+                    segments: vec!["name".to_string()],
+                }),
+            },
+            SqlAssignment {
+                field: "age".to_string(),
+                value: SqlWriteValue::Expr(SqlExpr::Binary {
+                    op: SqlExprBinaryOp::Add,
+                    left: Box::new(SqlExpr::Field("age".to_string())),
+                    right: Box::new(SqlExpr::Literal(Value::Int64(1))),
+                }),
+            },
+        ]),
+    );
+    assert_eq!(
+        returning,
+        Some(SqlReturningProjection::Fields(vec!["id".to_string()]))
+    );
+}
+
+#[test]
+fn parse_insert_statement_rejects_on_conflict_without_action() {
+    parse_sql("INSERT INTO users (id) VALUES (1) ON CONFLICT (id)")
+        .expect_err("ON CONFLICT without DO action should stay fail-closed");
+}
+
+fn sql_cast_expr(input: SqlExpr, target: &str) -> SqlExpr {
+    sql_scalar_function_expr(
+        SqlScalarFunction::Cast,
+        vec![input, SqlExpr::Literal(Value::Text(target.to_string()))],
+    )
+}
+
+#[test]
+fn parse_select_cast_and_postfix_cast_share_one_call_shape() {
+    let SqlStatement::Select(statement) =
+        parse_sql("SELECT CAST(age AS int64), name::TEXT, price::decimal(2)::text FROM users")
+            .expect("CAST projections should parse")
+    else {
+        panic!("CAST SQL should parse as SELECT");
+    };
+
+    assert_eq!(
+        statement.projection,
+        SqlProjection::Items(vec![
+            SqlSelectItem::Expr(sql_cast_expr(SqlExpr::Field("age".to_string()), "INT64")),
+            SqlSelectItem::Expr(sql_cast_expr(SqlExpr::Field("name".to_string()), "TEXT")),
+            SqlSelectItem::Expr(sql_cast_expr(
+                sql_cast_expr(SqlExpr::Field("price".to_string()), "DECIMAL(2)"),
+                "TEXT",
+            )),
+        ]),
+    );
+}
+
+#[test]
+fn parse_insert_statement_keeps_cast_values_expression_shaped() {
+    let SqlStatement::Insert(statement) =
+        parse_sql("INSERT INTO users (id, age) VALUES (CAST('7' AS NAT64), '21'::int64)")
+            .expect("INSERT VALUES with CAST should parse")
+    else {
+        panic!("INSERT SQL should parse as INSERT");
+    };
+
+    assert_eq!(
+        statement.source,
+        SqlInsertSource::Values(vec![vec![
+            SqlWriteValue::Expr(sql_cast_expr(
+                SqlExpr::Literal(Value::Text("7".to_string())),
+                "NAT64",
+            )),
+            SqlWriteValue::Expr(sql_cast_expr(
+                SqlExpr::Literal(Value::Text("21".to_string())),
+                "INT64",
+            )),
+        ]]),
+    );
+    parse_sql("INSERT INTO users (id) VALUES (CAST(age AS INT64))")
+        .expect_err("INSERT VALUES CAST operands must stay literal");
+}
+
+#[test]
+fn parse_sql_rejects_unknown_cast_targets() {
+    for sql in [
+        "SELECT CAST(age AS FLOAT64) FROM users",
+        "SELECT age::blob FROM users",
+        "SELECT CAST(age AS DECIMAL(x)) FROM users",
+    ] {
+        assert_eq!(
+            parse_sql(sql),
+            Err(SqlParseError::UnsupportedFeature {
+                feature: SqlFeatureCode::CastTargetType,
+            }),
+            "{sql}",
+        );
+    }
+}
+
+fn sql_temporal_field_expr(function: SqlScalarFunction, field: &str, input: SqlExpr) -> SqlExpr {
+    sql_scalar_function_expr(
+        function,
+        vec![SqlExpr::Literal(Value::Text(field.to_string())), input],
+    )
+}
+
+#[test]
+fn parse_select_temporal_functions_freeze_uppercase_field_literals() {
+    let SqlStatement::Select(statement) = parse_sql(
+        "SELECT DATE_TRUNC('hour', created_at), EXTRACT(dow FROM created_at), NOW(), \
+         AGE(NOW(), created_at), created_at + INTERVAL '2 days' FROM events",
+    )
+    .expect("temporal projections should parse") else {
+        panic!("temporal SQL should parse as SELECT");
+    };
+    let created_at = || SqlExpr::Field("created_at".to_string());
+    let now = || sql_scalar_function_expr(SqlScalarFunction::Now, Vec::new());
+
+    assert_eq!(
+        statement.projection,
+        SqlProjection::Items(vec![
+            SqlSelectItem::Expr(sql_temporal_field_expr(
+                SqlScalarFunction::DateTrunc,
+                "HOUR",
+                created_at(),
+            )),
+            SqlSelectItem::Expr(sql_temporal_field_expr(
+                SqlScalarFunction::Extract,
+                "DOW",
+                created_at(),
+            )),
+            SqlSelectItem::Expr(now()),
+            SqlSelectItem::Expr(sql_scalar_function_expr(
+                SqlScalarFunction::Age,
+                vec![now(), created_at()],
+            )),
+            SqlSelectItem::Expr(sql_binary_expr(
+                created_at(),
+                SqlExprBinaryOp::Add,
+                SqlExpr::Literal(Value::Duration(crate::types::Duration::from_days(2))),
+            )),
+        ]),
+    );
+}
+
+#[test]
+fn parse_sql_rejects_unknown_temporal_units_and_intervals() {
+    for sql in [
+        "SELECT DATE_TRUNC('dow', created_at) FROM events",
+        "SELECT DATE_TRUNC('fortnight', created_at) FROM events",
+        "SELECT EXTRACT(century FROM created_at) FROM events",
+        "SELECT created_at + INTERVAL '3 months' FROM events",
+        "SELECT * FROM events WHERE created_at > NOW() - INTERVAL 'soon'",
+    ] {
+        assert_eq!(
+            parse_sql(sql),
+            Err(SqlParseError::UnsupportedFeature {
+                feature: SqlFeatureCode::TemporalUnit,
+            }),
+            "{sql}",
+        );
+    }
+}
+
 #[test]
 fn parse_sql_unsupported_feature_codes_are_stable() {
     let cases = [
@@ -4454,11 +5549,11 @@ fn parse_sql_unsupported_feature_codes_are_stable() {
             SqlFeatureCode::Join,
         ),
         (
-            "WITH cte AS (SELECT * FROM users) SELECT * FROM cte",
+            "WITH RECURSIVE cte AS (SELECT * FROM users) SELECT * FROM cte",
             SqlFeatureCode::With,
         ),
         (
-            "SELECT * FROM users UNION SELECT * FROM users",
+            "SELECT * FROM users LIMIT 1 UNION SELECT * FROM users",
             SqlFeatureCode::UnionIntersectExcept,
         ),
         (
@@ -4479,7 +5574,7 @@ fn parse_sql_unsupported_feature_codes_are_stable() {
             SqlFeatureCode::MultiStatementSql,
         ),
         (
-            "SELECT \"name\" FROM users",
+            "SELECT `name` FROM users",
             SqlFeatureCode::QuotedIdentifiers,
         ),
         (
@@ -4521,7 +5616,11 @@ fn parse_sql_unsupported_feature_codes_are_stable() {
         ("SHOW STORES users", SqlFeatureCode::ShowStoresModifiers),
         ("SHOW MEMORY users", SqlFeatureCode::ShowMemoryModifiers),
         (
-            "CREATE INDEX user_age_idx ON users (age DESC)",
+            "CREATE INDEX user_name_idx ON users (LOWER(name) DESC)",
+            SqlFeatureCode::CreateIndexKeyOrderingModifiers,
+        ),
+        (
+            "CREATE INDEX user_name_text_idx ON users USING TEXT (name DESC)",
             SqlFeatureCode::CreateIndexKeyOrderingModifiers,
         ),
     ];
@@ -4639,18 +5738,68 @@ fn parse_sql_accepts_wrapped_like_targets_in_where() {
 }
 
 #[test]
-fn parse_select_statement_rejects_simple_case_expressions() {
-    let err = parse_sql("SELECT CASE age WHEN 21 THEN 'adult' ELSE 'minor' END FROM users")
-        .expect_err("simple CASE expressions should stay fail-closed");
+fn parse_select_statement_desugars_simple_case_onto_searched_case() {
+    let simple = parse_sql(
+        "SELECT CASE age WHEN 21 THEN 'adult' WHEN 18 THEN 'young' ELSE 'minor' END FROM users",
+    )
+    .expect("simple CASE expressions should parse");
+    let searched = parse_sql(
+        "SELECT CASE WHEN age = 21 THEN 'adult' WHEN age = 18 THEN 'young' ELSE 'minor' END \
+         FROM users",
+    )
+    .expect("equivalent searched CASE expression should parse");
 
     assert_eq!(
-        err,
-        super::SqlParseError::UnsupportedFeature {
-            feature: SqlFeatureCode::SimpleCaseExpression
-        }
+        simple, searched,
+        "simple CASE should desugar onto operand-equality searched CASE arms",
+    );
+
+    let null_arm =
+        parse_sql("SELECT CASE age WHEN NULL THEN 'unknown' ELSE 'known' END FROM users")
+            .expect("simple CASE with a NULL WHEN value should parse");
+    let never_matching =
+        parse_sql("SELECT CASE WHEN FALSE THEN 'unknown' ELSE 'known' END FROM users")
+            .expect("searched CASE with a constant FALSE condition should parse");
+    assert_eq!(
+        null_arm, never_matching,
+        "a NULL WHEN value can never equal the operand",
     );
 }
 
+#[test]
+fn parse_select_statement_with_null_safe_distinct_predicates() {
+    for (sql, op) in [
+        (
+            "SELECT * FROM users WHERE age IS DISTINCT FROM rank + 1",
+            SqlExprBinaryOp::IsDistinctFrom,
+        ),
+        (
+            "SELECT * FROM users WHERE age IS NOT DISTINCT FROM rank + 1",
+            SqlExprBinaryOp::IsNotDistinctFrom,
+        ),
+    ] {
+        let SqlStatement::Select(select) =
+            parse_sql(sql).expect("null-safe distinct predicate should parse")
+        else {
+            panic!("expected SELECT statement");
+        };
+
+        assert_eq!(
+            select.predicate,
+            Some(SqlExpr::Binary {
+                op,
+                left: Box::new(SqlExpr::Field("age".to_string())),
+                right: Box::new(SqlExpr::Binary {
+                    op: SqlExprBinaryOp::Add,
+                    left: Box::new(SqlExpr::Field("rank".to_string())),
+                    right: Box::new(SqlExpr::Literal(Value::Int64(1))),
+                }),
+            }),
+            "{sql}",
+        );
+    }
+}
+
 #[test]
 fn parse_sql_accepts_projection_aliases() {
     let statement = parse_sql(
@@ -4670,18 +5819,21 @@ fn parse_sql_accepts_projection_aliases() {
                     input: None,
                     filter_expr: None,
                     distinct: false,
+                    arguments: None,
                 }),
             ]),
             projection_aliases: vec![Some("display_name".to_string()), Some("total".to_string())],
             predicate: None,
             distinct: false,
-            group_by: vec!["name".to_string()],
+            group_by: vec![SqlExpr::Field("name".to_string())],
             having: vec![],
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("name"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -4708,6 +5860,7 @@ fn parse_sql_accepts_bare_projection_aliases() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -4773,6 +5926,7 @@ fn parse_sql_accepts_coalesce_and_nullif_in_where() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -4793,6 +5947,7 @@ fn parse_sql_accepts_distinct_aggregate_qualifier() {
                 input: Some(Box::new(SqlExpr::Field("age".to_string()))),
                 filter_expr: None,
                 distinct: true,
+                arguments: None,
             })]),
             projection_aliases: vec![None],
             predicate: None,
@@ -4801,6 +5956,7 @@ fn parse_sql_accepts_distinct_aggregate_qualifier() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -4825,6 +5981,7 @@ fn parse_sql_accepts_aggregate_filter_clauses() {
                     right: Box::new(SqlExpr::Literal(Value::Int64(1))),
                 })),
                 distinct: false,
+                arguments: None,
             })]),
             projection_aliases: vec![None],
             predicate: None,
@@ -4833,6 +5990,7 @@ fn parse_sql_accepts_aggregate_filter_clauses() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -4852,6 +6010,125 @@ fn parse_sql_rejects_aggregate_filter_window_pairing() {
     );
 }
 
+fn parsed_projection_aggregates(sql: &str) -> Vec<SqlAggregateCall> {
+    let statement = parse_sql(sql).expect("aggregate projection should parse");
+    let SqlStatement::Select(SqlSelectStatement {
+        projection: SqlProjection::Items(items),
+        ..
+    }) = statement
+    else {
+        panic!("aggregate projection should parse as one SELECT item list");
+    };
+
+    items
+        .into_iter()
+        .map(|item| match item {
+            SqlSelectItem::Aggregate(aggregate) => aggregate,
+            other => panic!("expected aggregate projection item, found {other:?}"),
+        })
+        .collect()
+}
+
+#[test]
+fn parse_sql_accepts_statistical_aggregate_spellings() {
+    let aggregates = parsed_projection_aggregates(
+        "SELECT VAR_POP(age), VARIANCE(age), var_samp(age), STDDEV_POP(age), STDDEV(age), STDDEV_SAMP(age) FROM users",
+    );
+    let kinds = aggregates
+        .iter()
+        .map(|aggregate| aggregate.kind)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        kinds,
+        vec![
+            SqlAggregateKind::VarPop,
+            SqlAggregateKind::VarSamp,
+            SqlAggregateKind::VarSamp,
+            SqlAggregateKind::StddevPop,
+            SqlAggregateKind::StddevSamp,
+            SqlAggregateKind::StddevSamp,
+        ],
+    );
+    assert!(
+        aggregates
+            .iter()
+            .all(|aggregate| aggregate.arguments.is_none()),
+        "statistical aggregates carry no retained-value arguments",
+    );
+}
+
+#[test]
+fn parse_sql_accepts_percentile_disc_and_median() {
+    let aggregates = parsed_projection_aggregates(
+        "SELECT PERCENTILE_DISC(0.9) WITHIN GROUP (ORDER BY age DESC), MEDIAN(age) FROM users",
+    );
+
+    assert_eq!(aggregates.len(), 2);
+    assert_eq!(aggregates[0].kind, SqlAggregateKind::PercentileDisc);
+    assert_eq!(
+        aggregates[0].input.as_deref(),
+        Some(&SqlExpr::Field("age".to_string())),
+    );
+    assert!(matches!(
+        aggregates[0].arguments.as_deref(),
+        Some(SqlAggregateArguments::Percentile {
+            direction: SqlOrderDirection::Desc,
+            ..
+        })
+    ));
+    assert_eq!(aggregates[1].kind, SqlAggregateKind::PercentileDisc);
+    assert_eq!(
+        aggregates[1].arguments.as_deref(),
+        Some(&SqlAggregateArguments::Percentile {
+            fraction: Value::Decimal(crate::types::Decimal::new(5, 1)),
+            direction: SqlOrderDirection::Asc,
+        }),
+    );
+}
+
+#[test]
+fn parse_sql_accepts_string_agg_and_array_agg_order_by() {
+    let aggregates = parsed_projection_aggregates(
+        "SELECT STRING_AGG(name, ', ' ORDER BY age DESC NULLS LAST, name), ARRAY_AGG(age) FROM users",
+    );
+
+    assert_eq!(
+        aggregates[0],
+        SqlAggregateCall {
+            kind: SqlAggregateKind::StringAgg,
+            input: Some(Box::new(SqlExpr::Field("name".to_string()))),
+            filter_expr: None,
+            distinct: false,
+            arguments: Some(Box::new(SqlAggregateArguments::StringAgg {
+                separator: ", ".to_string(),
+                order_by: vec![
+                    SqlOrderTerm {
+                        field: SqlExpr::Field("age".to_string()),
+                        direction: SqlOrderDirection::Desc,
+                        nulls: Some(SqlNullsOrder::Last),
+                    },
+                    SqlOrderTerm {
+                        field: SqlExpr::Field("name".to_string()),
+                        direction: SqlOrderDirection::Asc,
+                        nulls: None,
+                    },
+                ],
+            })),
+        },
+    );
+    assert_eq!(
+        aggregates[1].arguments.as_deref(),
+        Some(&SqlAggregateArguments::ArrayAgg { order_by: vec![] }),
+    );
+}
+
+#[test]
+fn parse_sql_rejects_string_agg_without_separator() {
+    parse_sql("SELECT STRING_AGG(name) FROM users")
+        .expect_err("STRING_AGG requires one separator argument");
+}
+
 #[test]
 fn parse_sql_accepts_expression_aggregate_inputs() {
     let statement = parse_sql("SELECT AVG(age + 1), COUNT(1), ROUND(AVG(age + 1), 2) FROM users")
@@ -4872,12 +6149,14 @@ fn parse_sql_accepts_expression_aggregate_inputs() {
                     })),
                     filter_expr: None,
                     distinct: false,
+                    arguments: None,
                 }),
                 SqlSelectItem::Aggregate(SqlAggregateCall {
                     kind: SqlAggregateKind::Count,
                     input: Some(Box::new(SqlExpr::Literal(Value::Int64(1)))),
                     filter_expr: None,
                     distinct: false,
+                    arguments: None,
                 }),
                 sql_round_item(
                     SqlExpr::Aggregate(SqlAggregateCall {
@@ -4889,6 +6168,7 @@ fn parse_sql_accepts_expression_aggregate_inputs() {
                         })),
                         filter_expr: None,
                         distinct: false,
+                        arguments: None,
                     },),
                     Value::Int64(2),
                 ),
@@ -4900,6 +6180,7 @@ fn parse_sql_accepts_expression_aggregate_inputs() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -4928,12 +6209,13 @@ fn parse_select_grouped_statement_with_unary_text_having_exprs() {
                     input: None,
                     filter_expr: None,
                     distinct: false,
+                    arguments: None,
                 }),
             ]),
             projection_aliases: vec![None, None],
             predicate: None,
             distinct: false,
-            group_by: vec!["name".to_string()],
+            group_by: vec![SqlExpr::Field("name".to_string())],
             having: vec![sql_binary_expr(
                 sql_scalar_function_expr(
                     SqlScalarFunction::Lower,
@@ -4951,8 +6233,10 @@ fn parse_select_grouped_statement_with_unary_text_having_exprs() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("name"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(10),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -5004,6 +6288,7 @@ fn parse_sql_accepts_unary_text_function_wrapped_expression_aggregate_inputs() {
                     ))),
                     filter_expr: None,
                     distinct: false,
+                    arguments: None,
                 }),
                 SqlSelectItem::Aggregate(SqlAggregateCall {
                     kind: SqlAggregateKind::Max,
@@ -5016,6 +6301,7 @@ fn parse_sql_accepts_unary_text_function_wrapped_expression_aggregate_inputs() {
                     ))),
                     filter_expr: None,
                     distinct: false,
+                    arguments: None,
                 }),
             ]),
             projection_aliases: vec![None, None],
@@ -5025,6 +6311,7 @@ fn parse_sql_accepts_unary_text_function_wrapped_expression_aggregate_inputs() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -5047,6 +6334,7 @@ fn parse_sql_accepts_table_alias_identifier_form() {
             having: vec![],
             order_by: vec![],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -5078,8 +6366,10 @@ fn parse_sql_accepts_table_alias_as_form() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("LOWER(u.name)"),
                 direction: SqlOrderDirection::Asc,
+                nulls: None,
             }],
             limit: Some(1),
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
@@ -5114,17 +6404,19 @@ fn parse_sql_accepts_table_alias_for_schema_qualified_entity() {
             order_by: vec![SqlOrderTerm {
                 field: sql_order_expr("u.age"),
                 direction: SqlOrderDirection::Desc,
+                nulls: None,
             }],
             limit: None,
+            limit_per: Vec::new(),
             offset: None,
         }),
     );
 }
 
 #[test]
-fn parse_sql_rejects_quoted_identifier_syntax() {
-    let err = parse_sql("SELECT \"name\" FROM users")
-        .expect_err("quoted identifiers should be rejected in reduced parser");
+fn parse_sql_rejects_backtick_identifier_syntax() {
+    let err = parse_sql("SELECT `name` FROM users")
+        .expect_err("backtick identifiers should be rejected in reduced parser");
 
     assert_eq!(
         err,
@@ -5134,6 +6426,122 @@ fn parse_sql_rejects_quoted_identifier_syntax() {
     );
 }
 
+#[test]
+fn parse_sql_quoted_identifiers_match_bare_spelling() {
+    let cases = [
+        (
+            "SELECT \"name\", \"age\" AS \"years\" FROM \"public\".\"users\" WHERE \"age\" > 1 ORDER BY \"name\" LIMIT 2",
+            "SELECT name, age AS years FROM public.users WHERE age > 1 ORDER BY name LIMIT 2",
+        ),
+        (
+            "UPDATE \"users\" SET \"age\" = 2 WHERE \"name\" = 'Ada'",
+            "UPDATE users SET age = 2 WHERE name = 'Ada'",
+        ),
+        (
+            "INSERT INTO \"users\" (\"id\", \"name\") VALUES (1, 'Ada')",
+            "INSERT INTO users (id, name) VALUES (1, 'Ada')",
+        ),
+        (
+            "DELETE FROM \"users\" WHERE \"age\" < 3",
+            "DELETE FROM users WHERE age < 3",
+        ),
+        (
+            "CREATE INDEX name_idx ON \"users\" (\"name\")",
+            "CREATE INDEX name_idx ON users (name)",
+        ),
+        ("SHOW INDEXES FROM \"users\"", "SHOW INDEXES FROM users"),
+        ("DESCRIBE \"users\"", "DESCRIBE users"),
+    ];
+
+    for (quoted, bare) in cases {
+        assert_eq!(
+            parse_sql(quoted).expect("quoted statement should parse"),
+            parse_sql(bare).expect("bare statement should parse"),
+            "quoted spelling should parse like {bare}",
+        );
+    }
+}
+
+#[test]
+fn parse_sql_quoted_identifiers_admit_reserved_and_exact_case_names() {
+    let SqlStatement::Select(select) =
+        parse_sql("SELECT \"order\", \"Group\" \"like\" FROM users ORDER BY \"order\" DESC")
+            .expect("quoted reserved names should parse")
+    else {
+        panic!("expected SELECT statement");
+    };
+    assert_eq!(
+        select.projection,
+        SqlProjection::Items(vec![
+            SqlSelectItem::Field("order".to_string()),
+            SqlSelectItem::Field("Group".to_string()),
+        ]),
+    );
+    assert_eq!(
+        select.projection_aliases,
+        vec![None, Some("like".to_string())]
+    );
+    assert_eq!(
+        select.order_by,
+        vec![SqlOrderTerm {
+            field: SqlExpr::Field("order".to_string()),
+            direction: SqlOrderDirection::Desc,
+            nulls: None,
+        }],
+    );
+
+    let SqlStatement::Update(update) =
+        parse_sql("UPDATE users SET \"limit\" = 3, \"say \"\"hi\"\"\" = 'x' WHERE id = 1")
+            .expect("quoted assignment targets should parse")
+    else {
+        panic!("expected UPDATE statement");
+    };
+    assert_eq!(
+        update
+            .assignments
+            .iter()
+            .map(|assignment| assignment.field.as_str())
+            .collect::<Vec<_>>(),
+        vec!["limit", "say \"hi\""],
+    );
+
+    let SqlStatement::Ddl(SqlDdlStatement::CreateIndex(create)) =
+        parse_sql("CREATE INDEX order_idx ON users (\"order\") WHERE \"group\" IS NOT NULL")
+            .expect("quoted DDL key and predicate names should parse")
+    else {
+        panic!("expected CREATE INDEX statement");
+    };
+    assert_eq!(create.key_items, ddl_field_paths(&["order"]));
+    assert_eq!(
+        create.predicate_sql.as_deref(),
+        Some("\"group\" IS NOT NULL")
+    );
+}
+
+#[test]
+fn parse_sql_rejects_malformed_quoted_identifiers() {
+    for (sql, kind) in [
+        (
+            "SELECT \"\" FROM users",
+            SqlSyntaxErrorKind::QuotedIdentifierInvalid,
+        ),
+        (
+            "SELECT \"users.name\" FROM users",
+            SqlSyntaxErrorKind::QuotedIdentifierInvalid,
+        ),
+        (
+            "SELECT \"name FROM users",
+            SqlSyntaxErrorKind::QuotedIdentifierUnterminated,
+        ),
+    ] {
+        assert_eq!(
+            parse_sql(sql),
+            Err(SqlParseError::InvalidSyntax { kind }),
+            "unexpected result for {sql}",
+        );
+    }
+}
+
 #[test]
 fn parse_sql_normalization_is_case_and_whitespace_insensitive() {
     let canonical = parse_sql("SELECT name FROM users WHERE active = true ORDER BY name LIMIT 5")
@@ -193,6 +193,24 @@ impl SqlTokenCursor {
         )
     }
 
+    // Report whether the next token names a field, entity, or alias. Quoted
+    // identifiers qualify here but never match identifier-keyword probes.
+    pub(in crate::db) fn peek_identifier(&self) -> bool {
+        matches!(
+            self.peek_kind(),
+            Some(TokenKind::Identifier(_) | TokenKind::QuotedIdentifier(_))
+        )
+    }
+
+    // Mirror `peek_identifier` for fixed-offset lookahead.
+    #[cfg(feature = "sql")]
+    pub(crate) fn peek_identifier_at(&self, offset: usize) -> bool {
+        matches!(
+            self.peek_kind_at(offset),
+            Some(TokenKind::Identifier(_) | TokenKind::QuotedIdentifier(_))
+        )
+    }
+
     pub(in crate::db) fn peek_identifier_keyword(&self, keyword: &str) -> bool {
         matches!(
             self.peek_kind(),
@@ -218,7 +236,7 @@ impl SqlTokenCursor {
             return Err(SqlParseError::expected(SqlExpectedToken::Identifier, None));
         };
         let token_kind = std::mem::replace(&mut token.kind, TokenKind::Comma);
-        let TokenKind::Identifier(name) = token_kind else {
+        let (TokenKind::Identifier(name) | TokenKind::QuotedIdentifier(name)) = token_kind else {
             token.kind = token_kind;
             return Err(SqlParseError::expected(
                 SqlExpectedToken::Identifier,
@@ -299,6 +317,27 @@ impl SqlTokenCursor {
         true
     }
 
+    #[cfg(feature = "sql")]
+    pub(crate) fn eat_positional_param(&mut self) -> Option<usize> {
+        let Some(TokenKind::PositionalParam(index)) = self.peek_kind() else {
+            return None;
+        };
+        let index = *index;
+
+        self.pos += 1;
+        Some(index)
+    }
+
+    #[cfg(feature = "sql")]
+    pub(crate) fn eat_double_colon(&mut self) -> bool {
+        if !matches!(self.peek_kind(), Some(TokenKind::DoubleColon)) {
+            return false;
+        }
+
+        self.pos += 1;
+        true
+    }
+
     #[cfg(feature = "sql")]
     pub(crate) fn eat_minus(&mut self) -> bool {
         if !matches!(self.peek_kind(), Some(TokenKind::Minus)) {
@@ -337,6 +376,29 @@ impl SqlTokenCursor {
         true
     }
 
+    #[cfg(feature = "sql")]
+    pub(crate) fn eat_lbracket(&mut self) -> bool {
+        if !matches!(self.peek_kind(), Some(TokenKind::LBracket)) {
+            return false;
+        }
+
+        self.pos += 1;
+        true
+    }
+
+    #[cfg(feature = "sql")]
+    pub(crate) fn expect_rbracket(&mut self) -> Result<(), SqlParseError> {
+        if matches!(self.peek_kind(), Some(TokenKind::RBracket)) {
+            self.pos += 1;
+            return Ok(());
+        }
+
+        Err(SqlParseError::expected(
+            SqlExpectedToken::RBracket,
+            self.peek_kind(),
+        ))
+    }
+
     pub(in crate::db) fn eat_semicolon(&mut self) -> bool {
         if !matches!(self.peek_kind(), Some(TokenKind::Semicolon)) {
             return false;
@@ -378,6 +440,64 @@ impl SqlTokenCursor {
         self.tokens.get(self.pos + 1).map(|token| &token.kind)
     }
 
+    // Render the unconsumed tokens up to the `)` that closes the current
+    // parenthesized span. Parsing moves text payloads out of consumed tokens,
+    // so callers that keep source text must render before they parse.
+    #[cfg(feature = "sql")]
+    pub(crate) fn sql_until_closing_paren(&self) -> String {
+        let mut depth = 0usize;
+        self.tokens[self.pos..]
+            .iter()
+            .map(|token| &token.kind)
+            .take_while(|kind| match kind {
+                TokenKind::LParen => {
+                    depth += 1;
+                    true
+                }
+                TokenKind::RParen if depth == 0 => false,
+                TokenKind::RParen => {
+                    depth -= 1;
+                    true
+                }
+                _ => true,
+            })
+            .map(token_kind_sql_fragment)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    // Render the unconsumed tokens of one comma-separated list item: up to
+    // the next top-level `,`, the `)` closing the list, or a trailing
+    // `ASC` / `DESC` ordering modifier.
+    #[cfg(feature = "sql")]
+    pub(crate) fn sql_until_list_item_end(&self) -> String {
+        let mut depth = 0usize;
+        self.tokens[self.pos..]
+            .iter()
+            .map(|token| &token.kind)
+            .take_while(|kind| match kind {
+                TokenKind::LParen => {
+                    depth += 1;
+                    true
+                }
+                TokenKind::RParen
+                | TokenKind::Comma
+                | TokenKind::Keyword(Keyword::Asc | Keyword::Desc)
+                    if depth == 0 =>
+                {
+                    false
+                }
+                TokenKind::RParen => {
+                    depth -= 1;
+                    true
+                }
+                _ => true,
+            })
+            .map(token_kind_sql_fragment)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
     #[cfg(feature = "sql")]
     pub(crate) fn remaining_sql_until_semicolon(&self) -> String {
         self.tokens[self.pos..]
@@ -0,0 +1,66 @@
+//! Module: db::sql_shared::identifier
+//! Responsibility: SQL spelling of accepted schema names.
+//! Does not own: identifier resolution, qualifier reduction, or lexing.
+//! Boundary: renders names so the shared lexer reads them back unchanged.
+
+use crate::db::sql_shared::lexer::keywords::{
+    is_identifier_continue, is_identifier_start, keyword_from_ident_bytes,
+};
+
+/// Return whether one schema name must be written as a quoted identifier.
+///
+/// Bare identifiers are ASCII words that are not reserved keywords; every
+/// other name only round-trips through the lexer inside double quotes.
+#[must_use]
+pub(crate) fn sql_identifier_requires_quoting(name: &str) -> bool {
+    let bytes = name.as_bytes();
+    let Some((first, rest)) = bytes.split_first() else {
+        return true;
+    };
+
+    !is_identifier_start(*first)
+        || !rest.iter().copied().all(is_identifier_continue)
+        || keyword_from_ident_bytes(bytes).is_some()
+}
+
+/// Render one name as a double-quoted identifier, doubling embedded quotes.
+#[must_use]
+pub(crate) fn quote_sql_identifier(name: &str) -> String {
+    let mut quoted = String::with_capacity(name.len() + 2);
+    quoted.push('"');
+    for ch in name.chars() {
+        if ch == '"' {
+            quoted.push('"');
+        }
+        quoted.push(ch);
+    }
+    quoted.push('"');
+
+    quoted
+}
+
+/// Render one name in the spelling SQL must use to reference it.
+#[must_use]
+pub(crate) fn render_sql_identifier(name: &str) -> String {
+    if sql_identifier_requires_quoting(name) {
+        quote_sql_identifier(name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// Render one dotted field path with every segment in its SQL spelling.
+#[must_use]
+pub(crate) fn render_sql_identifier_path<'a>(
+    segments: impl IntoIterator<Item = &'a str>,
+) -> String {
+    let mut rendered = String::new();
+    for (index, segment) in segments.into_iter().enumerate() {
+        if index > 0 {
+            rendered.push('.');
+        }
+        rendered.push_str(render_sql_identifier(segment).as_str());
+    }
+
+    rendered
+}
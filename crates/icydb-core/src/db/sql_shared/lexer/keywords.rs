@@ -84,15 +84,15 @@ const KEYWORDS_LEN_9: &[(&str, Keyword)] = &[
     ("RETURNING", Keyword::Returning),
 ];
 
-pub(super) const fn is_identifier_start(byte: u8) -> bool {
+pub(in crate::db::sql_shared) const fn is_identifier_start(byte: u8) -> bool {
     byte.is_ascii_alphabetic() || byte == b'_'
 }
 
-pub(super) const fn is_identifier_continue(byte: u8) -> bool {
+pub(in crate::db::sql_shared) const fn is_identifier_continue(byte: u8) -> bool {
     byte.is_ascii_alphanumeric() || byte == b'_'
 }
 
-pub(super) fn keyword_from_ident_bytes(value: &[u8]) -> Option<Keyword> {
+pub(in crate::db::sql_shared) fn keyword_from_ident_bytes(value: &[u8]) -> Option<Keyword> {
     match value.len() {
         2 => find_keyword_by_length(value, KEYWORDS_LEN_2),
         3 => find_keyword_by_length(value, KEYWORDS_LEN_3),
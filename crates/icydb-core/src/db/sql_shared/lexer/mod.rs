@@ -1,4 +1,4 @@
-mod keywords;
+pub(in crate::db::sql_shared) mod keywords;
 mod scan;
 mod token_body;
 
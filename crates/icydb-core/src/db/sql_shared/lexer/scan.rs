@@ -42,10 +42,16 @@ impl<'a> Lexer<'a> {
         } else {
             match byte {
                 b'\'' => TokenKind::StringLiteral(self.lex_string_literal()?),
+                b':' if self.peek_next_byte() == Some(b':') => {
+                    self.pos += 2;
+                    TokenKind::DoubleColon
+                }
+                b'$' => self.lex_positional_param()?,
                 b'X' | b'x' if self.peek_next_byte() == Some(b'\'') => {
                     TokenKind::BlobLiteral(self.lex_blob_literal()?)
                 }
-                b'"' | b'`' => {
+                b'"' => TokenKind::QuotedIdentifier(self.lex_quoted_identifier()?),
+                b'`' => {
                     return Err(SqlParseError::unsupported_feature(
                         SqlFeatureCode::QuotedIdentifiers,
                     ));
@@ -74,9 +80,12 @@ impl<'a> Lexer<'a> {
             b'/' => TokenKind::Slash,
             b'(' => TokenKind::LParen,
             b')' => TokenKind::RParen,
+            b'[' => TokenKind::LBracket,
+            b']' => TokenKind::RBracket,
             b';' => TokenKind::Semicolon,
             b'*' => TokenKind::Star,
             b'=' => TokenKind::Eq,
+            b'~' => TokenKind::Tilde,
             _ => return None,
         };
         self.pos += 1;
@@ -93,6 +102,8 @@ impl<'a> Lexer<'a> {
             b'!' => {
                 if self.consume_if(b'=') {
                     Ok(TokenKind::Ne)
+                } else if self.consume_if(b'~') {
+                    Ok(TokenKind::BangTilde)
                 } else {
                     Err(SqlParseError::invalid_syntax(
                         SqlSyntaxErrorKind::UnexpectedBang,
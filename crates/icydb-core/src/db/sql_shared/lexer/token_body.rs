@@ -37,29 +37,56 @@ impl Lexer<'_> {
     pub(super) fn lex_string_literal(
         &mut self,
     ) -> Result<String, crate::db::sql_shared::SqlParseError> {
-        self.expect_byte(b'\'')?;
+        self.lex_quoted_body(b'\'', SqlSyntaxErrorKind::StringLiteralUnterminated)
+    }
+
+    // Scan one `"name"` identifier. Quoted names keep their exact text, with
+    // `""` standing for one embedded quote. Empty names and names carrying
+    // the path separator or control bytes have no schema spelling and fail
+    // closed here instead of resolving as a different dotted path.
+    pub(super) fn lex_quoted_identifier(
+        &mut self,
+    ) -> Result<String, crate::db::sql_shared::SqlParseError> {
+        let name = self.lex_quoted_body(b'"', SqlSyntaxErrorKind::QuotedIdentifierUnterminated)?;
+        if name.is_empty() || name.contains('.') || name.chars().any(char::is_control) {
+            return Err(crate::db::sql_shared::SqlParseError::invalid_syntax(
+                SqlSyntaxErrorKind::QuotedIdentifierInvalid,
+            ));
+        }
+
+        Ok(name)
+    }
+
+    // Scan one body delimited by `quote`, where a doubled quote stands for
+    // one literal quote byte. Bodies without escapes borrow one source slice.
+    fn lex_quoted_body(
+        &mut self,
+        quote: u8,
+        unterminated: SqlSyntaxErrorKind,
+    ) -> Result<String, crate::db::sql_shared::SqlParseError> {
+        self.expect_byte(quote)?;
         let mut segment_start = self.pos;
         let mut out = None;
 
         loop {
             let Some(relative_quote) = self.bytes[self.pos..]
                 .iter()
-                .position(|byte| *byte == b'\'')
+                .position(|byte| *byte == quote)
             else {
                 return Err(crate::db::sql_shared::SqlParseError::invalid_syntax(
-                    SqlSyntaxErrorKind::StringLiteralUnterminated,
+                    unterminated,
                 ));
             };
             let quote_pos = self.pos + relative_quote;
             self.pos = quote_pos + 1;
 
-            if self.peek_byte() == Some(b'\'') {
+            if self.peek_byte() == Some(quote) {
                 let out = out.get_or_insert_with(|| {
                     String::with_capacity(quote_pos.saturating_sub(segment_start) + 1)
                 });
                 out.push_str(self.source_slice(segment_start, quote_pos));
                 self.pos += 1;
-                out.push('\'');
+                out.push(char::from(quote));
                 segment_start = self.pos;
                 continue;
             }
@@ -93,6 +120,26 @@ impl Lexer<'_> {
         self.source_slice(start, self.pos).to_owned()
     }
 
+    // Scan one `$n` positional parameter into its zero-based slot. Numbering
+    // starts at `$1`, so `$0`, a missing number, and overflow all fail closed.
+    pub(super) fn lex_positional_param(
+        &mut self,
+    ) -> Result<TokenKind, crate::db::sql_shared::SqlParseError> {
+        self.pos += 1;
+        let start = self.pos;
+        let len = self.bytes.len();
+        while self.pos < len && self.bytes[self.pos].is_ascii_digit() {
+            self.pos += 1;
+        }
+
+        self.source_slice(start, self.pos)
+            .parse::<usize>()
+            .ok()
+            .and_then(|number| number.checked_sub(1))
+            .map(TokenKind::PositionalParam)
+            .ok_or_else(crate::db::sql_shared::SqlParseError::invalid_numeric_literal)
+    }
+
     pub(super) fn lex_identifier_or_keyword(&mut self) -> TokenKind {
         let start = self.pos;
         let len = self.bytes.len();
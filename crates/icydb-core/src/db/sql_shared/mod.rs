@@ -5,6 +5,7 @@
 //! this ungated lexical layer.
 
 mod cursor;
+mod identifier;
 mod lexer;
 mod literal_render;
 #[cfg(test)]
@@ -12,6 +13,9 @@ mod tests;
 pub(in crate::db::sql_shared) mod types;
 
 pub(crate) use cursor::SqlTokenCursor;
+#[cfg(feature = "sql")]
+pub(crate) use identifier::quote_sql_identifier;
+pub(crate) use identifier::{render_sql_identifier, render_sql_identifier_path};
 pub(crate) use lexer::tokenize_sql;
 pub(in crate::db) use literal_render::render_scalar_sql_value;
 pub(crate) use types::{Keyword, SqlExpectedToken, SqlParseError, SqlSyntaxErrorKind, TokenKind};
@@ -1,4 +1,7 @@
-use crate::db::sql_shared::{Keyword, SqlSyntaxErrorKind, TokenKind, tokenize_sql};
+use crate::db::sql_shared::{
+    Keyword, SqlSyntaxErrorKind, TokenKind, render_sql_identifier, render_sql_identifier_path,
+    tokenize_sql,
+};
 
 #[test]
 fn tokenize_sql_classifies_mixed_case_keywords_without_normalization_changes() {
@@ -75,6 +78,40 @@ fn tokenize_sql_preserves_qualified_identifier_segments() {
     );
 }
 
+#[test]
+fn tokenize_sql_keeps_quoted_identifiers_exact_and_out_of_keyword_space() {
+    let tokens = tokenize_sql("\"order\" \"Mixed Case\" \"say \"\"hi\"\"\"")
+        .expect("quoted identifiers should tokenize");
+
+    let kinds = tokens
+        .into_iter()
+        .map(|token| token.kind)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::QuotedIdentifier("order".to_string()),
+            TokenKind::QuotedIdentifier("Mixed Case".to_string()),
+            TokenKind::QuotedIdentifier("say \"hi\"".to_string()),
+        ],
+    );
+}
+
+#[test]
+fn render_sql_identifier_quotes_only_names_a_bare_identifier_cannot_spell() {
+    assert_eq!(render_sql_identifier("order_total"), "order_total");
+    assert_eq!(render_sql_identifier("Score"), "Score");
+    assert_eq!(render_sql_identifier("order"), "\"order\"");
+    assert_eq!(render_sql_identifier("Group"), "\"Group\"");
+    assert_eq!(render_sql_identifier("2nd"), "\"2nd\"");
+    assert_eq!(render_sql_identifier("say \"hi\""), "\"say \"\"hi\"\"\"");
+    assert_eq!(
+        render_sql_identifier_path(["address", "limit"]),
+        "address.\"limit\""
+    );
+}
+
 #[test]
 fn tokenize_sql_decodes_string_literals_without_losing_escaped_or_utf8_text() {
     let tokens = tokenize_sql("'Draft' '' 'Review''s' 'caf\u{00e9}'")
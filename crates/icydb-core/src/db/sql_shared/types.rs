@@ -1,3 +1,5 @@
+#[cfg(feature = "sql")]
+use crate::db::sql_shared::quote_sql_identifier;
 use crate::{types::Decimal, value::Value};
 use icydb_diagnostic_code::SqlFeatureCode;
 use std::str::FromStr;
@@ -67,6 +69,8 @@ pub(crate) enum SqlSyntaxErrorKind {
     BlobLiteralNonHexDigit,
     BlobLiteralUnterminated,
     StringLiteralUnterminated,
+    QuotedIdentifierUnterminated,
+    QuotedIdentifierInvalid,
     BlobLiteralOddHexLength,
     BlobLiteralTooLarge {
         max_decoded_bytes: usize,
@@ -104,6 +108,8 @@ pub(crate) enum SqlSyntaxErrorKind {
     RoundScaleRequiresIntegerLiteral,
     #[cfg(feature = "sql")]
     InRequiresLiteral,
+    #[cfg(feature = "sql")]
+    MixedParameterPlaceholders,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -111,6 +117,7 @@ pub(crate) enum SqlExpectedToken {
     Literal,
     LikeStringPattern,
     IlikeStringPattern,
+    LikeEscapeCharacter,
     StartsWithSecondArgument,
     BooleanOrNullLiteral,
     NumericLiteralAfterMinus,
@@ -125,6 +132,8 @@ pub(crate) enum SqlExpectedToken {
     LParen,
     RParen,
     #[cfg(feature = "sql")]
+    RBracket,
+    #[cfg(feature = "sql")]
     Eq,
     #[cfg(feature = "sql")]
     UpdateAssignmentEq,
@@ -175,8 +184,10 @@ pub(crate) enum SqlIdentifierKeyword {
     Check,
     Continue,
     Deep,
+    First,
     Integrity,
     Into,
+    Last,
     MaxBytes,
     Quick,
     Schema,
@@ -196,8 +207,10 @@ impl SqlIdentifierKeyword {
             "CHECK" => Self::Check,
             "CONTINUE" => Self::Continue,
             "DEEP" => Self::Deep,
+            "FIRST" => Self::First,
             "INTEGRITY" => Self::Integrity,
             "INTO" => Self::Into,
+            "LAST" => Self::Last,
             "max_bytes" => Self::MaxBytes,
             "QUICK" => Self::Quick,
             "SCHEMA" => Self::Schema,
@@ -226,6 +239,8 @@ pub(crate) enum SqlFoundToken {
     Slash,
     LParen,
     RParen,
+    LBracket,
+    RBracket,
     Semicolon,
     Star,
     Eq,
@@ -234,18 +249,21 @@ pub(crate) enum SqlFoundToken {
     Lte,
     Gt,
     Gte,
+    DoubleColon,
+    Tilde,
+    BangTilde,
 }
 
 impl SqlFoundToken {
     const fn from_token_kind(kind: Option<&TokenKind>) -> Self {
         match kind {
             None => Self::EndOfInput,
-            Some(TokenKind::Identifier(_)) => Self::Identifier,
+            Some(TokenKind::Identifier(_) | TokenKind::QuotedIdentifier(_)) => Self::Identifier,
             Some(TokenKind::Number(_)) => Self::Number,
             Some(TokenKind::StringLiteral(_)) => Self::StringLiteral,
             Some(TokenKind::BlobLiteral(_)) => Self::BlobLiteral,
             Some(TokenKind::Keyword(keyword)) => Self::Keyword(*keyword),
-            Some(TokenKind::Question) => Self::Question,
+            Some(TokenKind::Question | TokenKind::PositionalParam(_)) => Self::Question,
             Some(TokenKind::Comma) => Self::Comma,
             Some(TokenKind::Dot) => Self::Dot,
             Some(TokenKind::Plus) => Self::Plus,
@@ -253,6 +271,8 @@ impl SqlFoundToken {
             Some(TokenKind::Slash) => Self::Slash,
             Some(TokenKind::LParen) => Self::LParen,
             Some(TokenKind::RParen) => Self::RParen,
+            Some(TokenKind::LBracket) => Self::LBracket,
+            Some(TokenKind::RBracket) => Self::RBracket,
             Some(TokenKind::Semicolon) => Self::Semicolon,
             Some(TokenKind::Star) => Self::Star,
             Some(TokenKind::Eq) => Self::Eq,
@@ -261,6 +281,9 @@ impl SqlFoundToken {
             Some(TokenKind::Lte) => Self::Lte,
             Some(TokenKind::Gt) => Self::Gt,
             Some(TokenKind::Gte) => Self::Gte,
+            Some(TokenKind::DoubleColon) => Self::DoubleColon,
+            Some(TokenKind::Tilde) => Self::Tilde,
+            Some(TokenKind::BangTilde) => Self::BangTilde,
         }
     }
 }
@@ -426,11 +449,13 @@ impl Keyword {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) enum TokenKind {
     Identifier(String),
+    QuotedIdentifier(String),
     Number(String),
     StringLiteral(String),
     BlobLiteral(Vec<u8>),
     Keyword(Keyword),
     Question,
+    PositionalParam(usize),
     Comma,
     Dot,
     Plus,
@@ -438,6 +463,8 @@ pub(crate) enum TokenKind {
     Slash,
     LParen,
     RParen,
+    LBracket,
+    RBracket,
     Semicolon,
     Star,
     Eq,
@@ -446,6 +473,9 @@ pub(crate) enum TokenKind {
     Lte,
     Gt,
     Gte,
+    DoubleColon,
+    Tilde,
+    BangTilde,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -457,6 +487,7 @@ pub(crate) struct Token {
 pub(crate) fn token_kind_sql_fragment(kind: &TokenKind) -> String {
     match kind {
         TokenKind::Identifier(name) | TokenKind::Number(name) => name.clone(),
+        TokenKind::QuotedIdentifier(name) => quote_sql_identifier(name),
         TokenKind::StringLiteral(value) => format!("'{}'", value.replace('\'', "''")),
         TokenKind::BlobLiteral(bytes) => {
             let mut rendered = String::with_capacity(bytes.len().saturating_mul(2) + 3);
@@ -470,6 +501,7 @@ pub(crate) fn token_kind_sql_fragment(kind: &TokenKind) -> String {
         }
         TokenKind::Keyword(keyword) => keyword.as_str().to_string(),
         TokenKind::Question => "?".to_string(),
+        TokenKind::PositionalParam(index) => format!("${}", index.saturating_add(1)),
         TokenKind::Comma => ",".to_string(),
         TokenKind::Dot => ".".to_string(),
         TokenKind::Plus => "+".to_string(),
@@ -477,6 +509,8 @@ pub(crate) fn token_kind_sql_fragment(kind: &TokenKind) -> String {
         TokenKind::Slash => "/".to_string(),
         TokenKind::LParen => "(".to_string(),
         TokenKind::RParen => ")".to_string(),
+        TokenKind::LBracket => "[".to_string(),
+        TokenKind::RBracket => "]".to_string(),
         TokenKind::Semicolon => ";".to_string(),
         TokenKind::Star => "*".to_string(),
         TokenKind::Eq => "=".to_string(),
@@ -485,6 +519,9 @@ pub(crate) fn token_kind_sql_fragment(kind: &TokenKind) -> String {
         TokenKind::Lte => "<=".to_string(),
         TokenKind::Gt => ">".to_string(),
         TokenKind::Gte => ">=".to_string(),
+        TokenKind::DoubleColon => "::".to_string(),
+        TokenKind::Tilde => "~".to_string(),
+        TokenKind::BangTilde => "!~".to_string(),
     }
 }
 
@@ -0,0 +1,297 @@
+//! Module: db::text_search
+//! Responsibility: full-text tokenization and `MATCH` query semantics.
+//! Does not own: index key framing, planner route selection, or SQL parsing.
+//! Boundary: text-index maintenance, predicate evaluation, and planner routing share this authority.
+
+use crate::value::lower_text;
+use std::{collections::BTreeSet, ops::Bound};
+
+/// Maximum number of characters retained per token. Longer words are truncated
+/// identically at write and query time so postings and terms stay aligned.
+pub(in crate::db) const MAX_TEXT_TOKEN_CHARS: usize = 64;
+
+/// Maximum number of distinct tokens one indexed value may contribute.
+pub(in crate::db) const MAX_TEXT_INDEX_TOKENS_PER_VALUE: usize = 1_024;
+
+/// Maximum number of terms one `MATCH` query may reference across all groups.
+pub(in crate::db) const MAX_TEXT_MATCH_TERMS: usize = 32;
+
+const MATCH_OR_KEYWORD: &str = "OR";
+const MATCH_PREFIX_MARKER: char = '*';
+
+///
+/// TextTokenLimitExceeded
+///
+/// One indexed text value produced more distinct tokens than
+/// `MAX_TEXT_INDEX_TOKENS_PER_VALUE`. Index maintenance fails closed instead
+/// of silently dropping postings.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(in crate::db) struct TextTokenLimitExceeded;
+
+///
+/// TextMatchQueryError
+///
+/// Structural rejection reasons for one `MATCH` query string.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(in crate::db) enum TextMatchQueryError {
+    Empty,
+    DanglingOr,
+    BarePrefixMarker,
+    TooManyTerms,
+}
+
+///
+/// TextMatchTerm
+///
+/// One normalized `MATCH` term. `Exact` requires the token itself; `Prefix`
+/// requires any token starting with the stored text.
+///
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(in crate::db) enum TextMatchTerm {
+    Exact(String),
+    Prefix(String),
+}
+
+impl TextMatchTerm {
+    fn matches_tokens(&self, tokens: &BTreeSet<String>) -> bool {
+        match self {
+            Self::Exact(token) => tokens.contains(token),
+            Self::Prefix(prefix) => tokens
+                .range::<str, _>((Bound::Included(prefix.as_str()), Bound::Unbounded))
+                .next()
+                .is_some_and(|token| token.starts_with(prefix.as_str())),
+        }
+    }
+}
+
+///
+/// TextMatchQuery
+///
+/// Parsed `MATCH` query in disjunctive normal form: the query matches when
+/// every term of at least one group matches. Whitespace separates terms inside
+/// one group, an uppercase `OR` separates groups, and a trailing `*` turns the
+/// final token of a term into a prefix term.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(in crate::db) struct TextMatchQuery {
+    groups: Vec<Vec<TextMatchTerm>>,
+}
+
+impl TextMatchQuery {
+    /// Parse one `MATCH` query string.
+    pub(in crate::db) fn parse(query: &str) -> Result<Self, TextMatchQueryError> {
+        let mut groups = Vec::new();
+        let mut group = BTreeSet::new();
+        let mut saw_or = false;
+        let mut term_count = 0usize;
+
+        for word in query.split_whitespace() {
+            if word == MATCH_OR_KEYWORD {
+                if group.is_empty() {
+                    return Err(TextMatchQueryError::DanglingOr);
+                }
+                groups.push(std::mem::take(&mut group).into_iter().collect());
+                saw_or = true;
+                continue;
+            }
+
+            let (body, prefix) = match word.strip_suffix(MATCH_PREFIX_MARKER) {
+                Some(body) => (body, true),
+                None => (word, false),
+            };
+            let mut tokens = tokenize_ordered(body);
+            let last = tokens.pop();
+            let Some(last) = last else {
+                if prefix {
+                    return Err(TextMatchQueryError::BarePrefixMarker);
+                }
+                continue;
+            };
+            for token in tokens {
+                term_count = term_count.saturating_add(1);
+                group.insert(TextMatchTerm::Exact(token));
+            }
+            term_count = term_count.saturating_add(1);
+            group.insert(if prefix {
+                TextMatchTerm::Prefix(last)
+            } else {
+                TextMatchTerm::Exact(last)
+            });
+            if term_count > MAX_TEXT_MATCH_TERMS {
+                return Err(TextMatchQueryError::TooManyTerms);
+            }
+        }
+
+        if group.is_empty() {
+            return Err(if saw_or {
+                TextMatchQueryError::DanglingOr
+            } else {
+                TextMatchQueryError::Empty
+            });
+        }
+        groups.push(group.into_iter().collect());
+
+        Ok(Self { groups })
+    }
+
+    /// Borrow the disjunctive term groups of this query.
+    #[must_use]
+    pub(in crate::db) const fn groups(&self) -> &[Vec<TextMatchTerm>] {
+        self.groups.as_slice()
+    }
+
+    /// Return whether one text value satisfies this query.
+    #[must_use]
+    pub(in crate::db) fn matches_text(&self, text: &str) -> bool {
+        let tokens = tokenize_ordered(text).into_iter().collect::<BTreeSet<_>>();
+
+        self.matches_tokens(&tokens)
+    }
+
+    fn matches_tokens(&self, tokens: &BTreeSet<String>) -> bool {
+        self.groups
+            .iter()
+            .any(|group| group.iter().all(|term| term.matches_tokens(tokens)))
+    }
+}
+
+/// Return whether `text` satisfies the `MATCH` query string `query`.
+///
+/// Malformed queries match nothing so residual evaluation fails closed.
+#[must_use]
+pub(in crate::db) fn text_matches_query(text: &str, query: &str) -> bool {
+    TextMatchQuery::parse(query).is_ok_and(|query| query.matches_text(text))
+}
+
+/// Derive the distinct index tokens of one text value.
+pub(in crate::db) fn tokenize_indexed_text(
+    text: &str,
+) -> Result<BTreeSet<String>, TextTokenLimitExceeded> {
+    let mut tokens = BTreeSet::new();
+    for token in tokenize_ordered(text) {
+        tokens.insert(token);
+        if tokens.len() > MAX_TEXT_INDEX_TOKENS_PER_VALUE {
+            return Err(TextTokenLimitExceeded);
+        }
+    }
+
+    Ok(tokens)
+}
+
+// Split on every non-alphanumeric character, lowercase with the canonical
+// `LOWER` transform, and truncate each token to the shared character bound.
+fn tokenize_ordered(text: &str) -> Vec<String> {
+    text.split(|ch: char| !ch.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let lowered = lower_text(word);
+            match lowered.char_indices().nth(MAX_TEXT_TOKEN_CHARS) {
+                Some((end, _)) => lowered[..end].to_string(),
+                None => lowered,
+            }
+        })
+        .collect()
+}
+
+///
+/// TESTS
+///
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exact(text: &str) -> TextMatchTerm {
+        TextMatchTerm::Exact(text.to_string())
+    }
+
+    fn prefix(text: &str) -> TextMatchTerm {
+        TextMatchTerm::Prefix(text.to_string())
+    }
+
+    #[test]
+    fn tokenizer_lowercases_splits_and_dedupes() {
+        let tokens = tokenize_indexed_text("Hello, hello WORLD-wide café!")
+            .expect("small text should tokenize");
+
+        assert_eq!(
+            tokens.into_iter().collect::<Vec<_>>(),
+            ["café", "hello", "wide", "world"],
+        );
+    }
+
+    #[test]
+    fn tokenizer_truncates_long_words_and_bounds_distinct_tokens() {
+        let long_word = "a".repeat(MAX_TEXT_TOKEN_CHARS + 10);
+        let tokens = tokenize_indexed_text(&long_word).expect("one long word should tokenize");
+        assert_eq!(
+            tokens.into_iter().next().map(|token| token.len()),
+            Some(MAX_TEXT_TOKEN_CHARS),
+        );
+
+        let many = (0..=MAX_TEXT_INDEX_TOKENS_PER_VALUE)
+            .map(|value| format!("w{value}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        assert_eq!(tokenize_indexed_text(&many), Err(TextTokenLimitExceeded));
+    }
+
+    #[test]
+    fn query_parser_builds_or_groups_of_and_terms() {
+        let query = TextMatchQuery::parse("Rust data* OR ice-cream").expect("query should parse");
+
+        assert_eq!(
+            query.groups(),
+            [
+                vec![exact("rust"), prefix("data")],
+                vec![exact("cream"), exact("ice")],
+            ],
+        );
+    }
+
+    #[test]
+    fn query_parser_rejects_malformed_queries() {
+        assert_eq!(
+            TextMatchQuery::parse("   "),
+            Err(TextMatchQueryError::Empty)
+        );
+        assert_eq!(
+            TextMatchQuery::parse("OR rust"),
+            Err(TextMatchQueryError::DanglingOr),
+        );
+        assert_eq!(
+            TextMatchQuery::parse("rust OR"),
+            Err(TextMatchQueryError::DanglingOr),
+        );
+        assert_eq!(
+            TextMatchQuery::parse("rust *"),
+            Err(TextMatchQueryError::BarePrefixMarker),
+        );
+        let many = (0..=MAX_TEXT_MATCH_TERMS)
+            .map(|value| format!("w{value}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        assert_eq!(
+            TextMatchQuery::parse(&many),
+            Err(TextMatchQueryError::TooManyTerms),
+        );
+    }
+
+    #[test]
+    fn query_matches_and_or_and_prefix_terms() {
+        let text = "The quick brown fox jumps";
+
+        assert!(text_matches_query(text, "QUICK fox"));
+        assert!(!text_matches_query(text, "quick cat"));
+        assert!(text_matches_query(text, "cat OR fox"));
+        assert!(text_matches_query(text, "jum*"));
+        assert!(!text_matches_query(text, "jumped*"));
+        assert!(!text_matches_query(text, "OR"));
+    }
+}
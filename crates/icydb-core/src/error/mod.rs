@@ -1816,6 +1816,18 @@ impl InternalError {
         Self::index_unsupported()
     }
 
+    /// Construct the canonical text-index error for one value whose distinct
+    /// token count exceeds the per-value posting bound.
+    pub(crate) fn index_text_token_limit_exceeded() -> Self {
+        Self::index_unsupported()
+    }
+
+    /// Construct the canonical multivalue-index error for one collection whose
+    /// distinct element count exceeds the per-row entry bound.
+    pub(crate) fn index_multivalue_element_limit_exceeded() -> Self {
+        Self::index_unsupported()
+    }
+
     /// Construct a serialize-origin unsupported error.
     pub(crate) fn serialize_unsupported() -> Self {
         Self::new(ErrorClass::Unsupported, ErrorOrigin::Serialize)
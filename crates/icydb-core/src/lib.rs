@@ -55,6 +55,13 @@ pub(crate) mod testing;
 /// simplifies sizing tests in the stores.
 pub const MAX_INDEX_FIELDS: usize = 4;
 
+/// Maximum number of distinct elements one row may contribute to a multivalue
+/// (collection element) index.
+///
+/// Writes whose indexed list or set exceeds this bound are rejected, which
+/// caps the per-row write amplification of element indexes.
+pub const MAX_MULTIVALUE_INDEX_ELEMENTS: usize = 256;
+
 ///
 /// Prelude
 ///
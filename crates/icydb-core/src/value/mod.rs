@@ -181,7 +181,8 @@ pub enum Value {
     ///
     /// - Maps are unordered values; insertion order is discarded.
     /// - Entries are always sorted by canonical key order and keys are unique.
-    /// - Map fields remain non-queryable and persist as atomic value replacements.
+    /// - Text-keyed map fields are queryable one fixed key at a time through
+    ///   `field['key']` and `HAS_KEY`, evaluated as residual expressions.
     /// - Persistence treats map fields as atomic value replacements per row save.
     Map(Vec<(Self, Self)>),
     Null,
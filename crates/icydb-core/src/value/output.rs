@@ -5,7 +5,6 @@ use crate::types::{
 use candid::CandidType;
 use serde::Deserialize;
 
-#[cfg(test)]
 use crate::value::Value;
 
 //
@@ -94,6 +93,42 @@ impl OutputValueEnum {
     }
 }
 
+impl OutputValue {
+    /// Convert one scalar key-shaped output value back into its runtime value.
+    ///
+    /// Returns `None` for enums, collections, floating-point values, and
+    /// `NULL`, none of which can be one primary-key value.
+    #[must_use]
+    pub(crate) fn to_key_value(&self) -> Option<Value> {
+        Some(match self {
+            Self::Account(value) => Value::Account(*value),
+            Self::Blob(value) => Value::Blob(value.clone()),
+            Self::Bool(value) => Value::Bool(*value),
+            Self::Date(value) => Value::Date(*value),
+            Self::Duration(value) => Value::Duration(*value),
+            Self::Int64(value) => Value::Int64(*value),
+            Self::Int128(value) => Value::Int128(*value),
+            Self::IntBig(value) => Value::IntBig(value.clone()),
+            Self::Principal(value) => Value::Principal(*value),
+            Self::Subaccount(value) => Value::Subaccount(*value),
+            Self::Text(value) => Value::Text(value.clone()),
+            Self::Timestamp(value) => Value::Timestamp(*value),
+            Self::Nat64(value) => Value::Nat64(*value),
+            Self::Nat128(value) => Value::Nat128(*value),
+            Self::NatBig(value) => Value::NatBig(value.clone()),
+            Self::Ulid(value) => Value::Ulid(*value),
+            Self::Unit => Value::Unit,
+            Self::Decimal(_)
+            | Self::Enum(_)
+            | Self::Float32(_)
+            | Self::Float64(_)
+            | Self::List(_)
+            | Self::Map(_)
+            | Self::Null => return None,
+        })
+    }
+}
+
 #[cfg(test)]
 impl From<Value> for OutputValue {
     fn from(value: Value) -> Self {
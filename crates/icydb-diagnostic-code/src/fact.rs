@@ -387,6 +387,15 @@ define_numeric_fact_value_registry! {
         Trunc = 37;
         Upper = 38;
         InList = 39;
+        Cast = 40;
+        Age = 41;
+        DateTrunc = 42;
+        Extract = 43;
+        Now = 44;
+        Like = 45;
+        RegexpMatches = 46;
+        Match = 47;
+        HasKey = 48;
     }
 }
 
@@ -425,6 +434,13 @@ define_numeric_fact_value_registry! {
         Max = 6;
         First = 7;
         Last = 8;
+        VarPop = 9;
+        VarSamp = 10;
+        StddevPop = 11;
+        StddevSamp = 12;
+        PercentileDisc = 13;
+        StringAgg = 14;
+        ArrayAgg = 15;
     }
 }
 
@@ -603,7 +619,7 @@ fn validate_diagnostic_fact_schema(
         141 | 142 | 169 | 170 => {
             tags_match(fact_count, &fact_at, &[DiagnosticFactTag::ProjectionIndex])
         }
-        175 => tags_match(fact_count, &fact_at, &[DiagnosticFactTag::ParameterIndex]),
+        175 | 299 => tags_match(fact_count, &fact_at, &[DiagnosticFactTag::ParameterIndex]),
         177 | 237 => {
             tags_match(fact_count, &fact_at, &[DiagnosticFactTag::Limit])
                 || tags_match(
@@ -747,7 +763,7 @@ fn validate_diagnostic_fact_schema(
 const fn diagnostic_fact_maximum(code: ErrorCode) -> usize {
     match code.raw() {
         6 | 16 | 18 | 24 | 197 | 198 | 233 | 239 | 240 | 274 => 3,
-        141 | 142 | 169 | 170 | 175 | 235 => 1,
+        141 | 142 | 169 | 170 | 175 | 235 | 299 => 1,
         19 | 21 | 138 | 177 | 178 | 180 | 201 | 202 | 203 | 205 | 236 | 237 | 238 | 269 | 270
         | 271 | 272 | 283 | 285 => 2,
         3 | 20 => 5,
@@ -1343,13 +1359,13 @@ mod tests {
         assert_eq!(DiagnosticTypeFamily::known(0), None);
         assert_eq!(DiagnosticTypeFamily::known(10), None);
 
-        for raw in 1..=39 {
+        for raw in 1..=48 {
             let value = DiagnosticFunctionKind::known(raw).expect("function kind should be known");
             assert_eq!(value.raw(), raw);
             assert_eq!(format!("{value:?}"), raw.to_string());
         }
         assert_eq!(DiagnosticFunctionKind::known(0), None);
-        assert_eq!(DiagnosticFunctionKind::known(40), None);
+        assert_eq!(DiagnosticFunctionKind::known(49), None);
 
         for raw in 1..=18 {
             let value = DiagnosticOperatorKind::known(raw).expect("operator kind should be known");
@@ -1359,14 +1375,14 @@ mod tests {
         assert_eq!(DiagnosticOperatorKind::known(0), None);
         assert_eq!(DiagnosticOperatorKind::known(19), None);
 
-        for raw in 1..=8 {
+        for raw in 1..=15 {
             let value =
                 DiagnosticAggregateKind::known(raw).expect("aggregate kind should be known");
             assert_eq!(value.raw(), raw);
             assert_eq!(format!("{value:?}"), raw.to_string());
         }
         assert_eq!(DiagnosticAggregateKind::known(0), None);
-        assert_eq!(DiagnosticAggregateKind::known(9), None);
+        assert_eq!(DiagnosticAggregateKind::known(16), None);
     }
 
     #[test]
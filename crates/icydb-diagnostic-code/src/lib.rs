@@ -406,6 +406,12 @@ pub enum QueryProjectionCode {
     IntegerOrNullArgumentRequired,
     UnaryOperandIncompatible,
     BinaryOperandsIncompatible,
+    CastOverflow,
+    CastNegativeToUnsigned,
+    CastPrecisionLoss,
+    CastNotRepresentable,
+    TemporalInputRequired,
+    PatternMatchStepLimit,
 }
 
 impl fmt::Debug for QueryProjectionCode {
@@ -660,6 +666,13 @@ pub enum SqlFeatureCode {
     AlterTableValidateBeyondConstraint,
     AlterTableValidateConstraintModifiers,
     ShowRelationsModifiers,
+    CastTargetType,
+    TemporalUnit,
+    TextPatternBeyondMatcherBounds,
+    TextIndexKeyShape,
+    TextMatchQueryShape,
+    MultivalueIndexKeyShape,
+    CreateIndexIncludeShape,
 }
 
 impl fmt::Debug for SqlFeatureCode {
@@ -696,6 +709,12 @@ pub enum SqlLoweringCode {
     WhereExpressionShape,
     ParameterPlacement,
     SqlDdlExecutionUnsupported,
+    ParameterBinding,
+    SemiJoinShape,
+    RelationPathShape,
+    LimitPerShape,
+    UnionShape,
+    CteShape,
 }
 
 impl fmt::Debug for SqlLoweringCode {
@@ -787,6 +806,13 @@ pub enum SqlWriteBoundaryCode {
     ResumableUpdateSingleRowResourceExceeded,
     ResumableUpdateManagedFieldHasGlobalConstraint,
     ResumableUpdateContinuationOperationMismatch,
+    UpdateComputedValueIncompatible,
+    ResumableUpdateComputedAssignmentUnsupported,
+    InsertConflictTargetNotUnique,
+    InsertConflictRequiresValues,
+    InsertConflictExcludedValueUnavailable,
+    InsertConflictRowAffectedTwice,
+    GeneratedValueIncompatible,
 }
 
 impl fmt::Debug for SqlWriteBoundaryCode {
@@ -1113,7 +1139,7 @@ mod tests {
             .expect("public error-code registry is non-empty")
             .raw();
 
-        assert_eq!(last, 285);
+        assert_eq!(last, 311);
     }
 
     #[test]
@@ -713,4 +713,56 @@ define_error_code_registry! {
         detail(RuntimeBoundary { boundary: RuntimeBoundaryCode::ConvergenceBacklogPressure });
     RUNTIME_BOUNDARY_MUTATION_BATCH_TOO_MANY_ENTITIES = 285 => RuntimeUnsupported,
         detail(RuntimeBoundary { boundary: RuntimeBoundaryCode::MutationBatchTooManyEntities });
+    SQL_WRITE_UPDATE_COMPUTED_VALUE_INCOMPATIBLE = 286 => QuerySqlWriteBoundary,
+        detail(SqlWriteBoundary { boundary: SqlWriteBoundaryCode::UpdateComputedValueIncompatible });
+    SQL_WRITE_RESUMABLE_UPDATE_COMPUTED_ASSIGNMENT_UNSUPPORTED = 287 => QuerySqlWriteBoundary,
+        detail(SqlWriteBoundary { boundary: SqlWriteBoundaryCode::ResumableUpdateComputedAssignmentUnsupported });
+    SQL_WRITE_INSERT_CONFLICT_TARGET_NOT_UNIQUE = 288 => QuerySqlWriteBoundary,
+        detail(SqlWriteBoundary { boundary: SqlWriteBoundaryCode::InsertConflictTargetNotUnique });
+    SQL_WRITE_INSERT_CONFLICT_REQUIRES_VALUES = 289 => QuerySqlWriteBoundary,
+        detail(SqlWriteBoundary { boundary: SqlWriteBoundaryCode::InsertConflictRequiresValues });
+    SQL_WRITE_INSERT_CONFLICT_EXCLUDED_VALUE_UNAVAILABLE = 290 => QuerySqlWriteBoundary,
+        detail(SqlWriteBoundary { boundary: SqlWriteBoundaryCode::InsertConflictExcludedValueUnavailable });
+    SQL_WRITE_INSERT_CONFLICT_ROW_AFFECTED_TWICE = 291 => QuerySqlWriteBoundary,
+        detail(SqlWriteBoundary { boundary: SqlWriteBoundaryCode::InsertConflictRowAffectedTwice });
+    SQL_FEATURE_CAST_TARGET_TYPE = 292 => QueryUnsupportedSqlFeature,
+        detail(UnsupportedSqlFeature { feature: SqlFeatureCode::CastTargetType });
+    QUERY_PROJECTION_CAST_OVERFLOW = 293 => QueryUnsupportedProjection,
+        detail(QueryProjection { reason: QueryProjectionCode::CastOverflow });
+    QUERY_PROJECTION_CAST_NEGATIVE_TO_UNSIGNED = 294 => QueryUnsupportedProjection,
+        detail(QueryProjection { reason: QueryProjectionCode::CastNegativeToUnsigned });
+    QUERY_PROJECTION_CAST_PRECISION_LOSS = 295 => QueryUnsupportedProjection,
+        detail(QueryProjection { reason: QueryProjectionCode::CastPrecisionLoss });
+    QUERY_PROJECTION_CAST_NOT_REPRESENTABLE = 296 => QueryUnsupportedProjection,
+        detail(QueryProjection { reason: QueryProjectionCode::CastNotRepresentable });
+    SQL_FEATURE_TEMPORAL_UNIT = 297 => QueryUnsupportedSqlFeature,
+        detail(UnsupportedSqlFeature { feature: SqlFeatureCode::TemporalUnit });
+    QUERY_PROJECTION_TEMPORAL_INPUT_REQUIRED = 298 => QueryUnsupportedProjection,
+        detail(QueryProjection { reason: QueryProjectionCode::TemporalInputRequired });
+    SQL_LOWERING_PARAMETER_BINDING = 299 => QueryUnsupportedSqlFeature,
+        detail(SqlLowering { reason: SqlLoweringCode::ParameterBinding });
+    SQL_LOWERING_SEMI_JOIN_SHAPE = 300 => QueryUnsupportedSqlFeature,
+        detail(SqlLowering { reason: SqlLoweringCode::SemiJoinShape });
+    SQL_LOWERING_RELATION_PATH_SHAPE = 301 => QueryUnsupportedSqlFeature,
+        detail(SqlLowering { reason: SqlLoweringCode::RelationPathShape });
+    SQL_LOWERING_LIMIT_PER_SHAPE = 302 => QueryUnsupportedSqlFeature,
+        detail(SqlLowering { reason: SqlLoweringCode::LimitPerShape });
+    SQL_FEATURE_TEXT_PATTERN_BEYOND_MATCHER_BOUNDS = 303 => QueryUnsupportedSqlFeature,
+        detail(UnsupportedSqlFeature { feature: SqlFeatureCode::TextPatternBeyondMatcherBounds });
+    QUERY_PROJECTION_PATTERN_MATCH_STEP_LIMIT = 304 => QueryUnsupportedProjection,
+        detail(QueryProjection { reason: QueryProjectionCode::PatternMatchStepLimit });
+    SQL_FEATURE_TEXT_INDEX_KEY_SHAPE = 305 => QueryUnsupportedSqlFeature,
+        detail(UnsupportedSqlFeature { feature: SqlFeatureCode::TextIndexKeyShape });
+    SQL_FEATURE_TEXT_MATCH_QUERY_SHAPE = 306 => QueryUnsupportedSqlFeature,
+        detail(UnsupportedSqlFeature { feature: SqlFeatureCode::TextMatchQueryShape });
+    SQL_FEATURE_MULTIVALUE_INDEX_KEY_SHAPE = 307 => QueryUnsupportedSqlFeature,
+        detail(UnsupportedSqlFeature { feature: SqlFeatureCode::MultivalueIndexKeyShape });
+    SQL_LOWERING_UNION_SHAPE = 308 => QueryUnsupportedSqlFeature,
+        detail(SqlLowering { reason: SqlLoweringCode::UnionShape });
+    SQL_LOWERING_CTE_SHAPE = 309 => QueryUnsupportedSqlFeature,
+        detail(SqlLowering { reason: SqlLoweringCode::CteShape });
+    SQL_FEATURE_CREATE_INDEX_INCLUDE_SHAPE = 310 => QueryUnsupportedSqlFeature,
+        detail(UnsupportedSqlFeature { feature: SqlFeatureCode::CreateIndexIncludeShape });
+    SQL_WRITE_GENERATED_VALUE_INCOMPATIBLE = 311 => QuerySqlWriteBoundary,
+        detail(SqlWriteBoundary { boundary: SqlWriteBoundaryCode::GeneratedValueIncompatible });
 }
@@ -190,6 +190,8 @@ impl Entity {
         for index in &self.indexes {
             Self::validate_index_shape(index, def_ident)?;
             self.validate_index_fields(index)?;
+            Self::validate_index_descending(index)?;
+            self.validate_index_include(index)?;
             Self::validate_index_name(index, entity_name, def_ident)?;
             self.validate_index_predicate(index)?;
             canonical_index_terms.push(index.validated_key_item_terms());
@@ -229,12 +231,132 @@ impl Entity {
                         .with_span(&span),
                 );
             };
-            if entity_field.value.cardinality() == Cardinality::Many {
+            // Multivalue indexes are the only kind that expand a collection;
+            // named item types are checked as list/set once the schema lowers.
+            if index.kind == IndexKind::Multivalue {
+                if entity_field.value.cardinality() != Cardinality::Many
+                    && entity_field.value.item.is.is_none()
+                {
+                    return Err(DarlingError::custom(format!(
+                        "multivalue index field '{field_name}' must be a list or set field"
+                    ))
+                    .with_span(&span));
+                }
+            } else if entity_field.value.cardinality() == Cardinality::Many {
                 return Err(DarlingError::custom(
                     "cannot add an index field with many cardinality",
                 )
                 .with_span(&span));
             }
+            if index.kind == IndexKind::Text
+                && entity_field.value.item.primitive != Some(Primitive::Text)
+            {
+                return Err(DarlingError::custom(format!(
+                    "text index field '{field_name}' must be a Text field"
+                ))
+                .with_span(&span));
+            }
+        }
+
+        Ok(())
+    }
+
+    // Validate descending key markers: each names one plain key field of a
+    // standard field-only index, because expression keys keep their ascending
+    // encoding.
+    fn validate_index_descending(index: &Index) -> Result<(), DarlingError> {
+        let Some(first) = index.desc.first() else {
+            return Ok(());
+        };
+        if index.kind != IndexKind::Standard {
+            return Err(
+                DarlingError::custom("only standard indexes accept desc = [...]").with_span(first),
+            );
+        }
+
+        let key_items = index.parsed_key_items()?;
+        if key_items
+            .iter()
+            .any(|item| matches!(item, IndexKeyItemSpec::Expression(_)))
+        {
+            return Err(DarlingError::custom(
+                "desc = [...] requires an index without expression key items",
+            )
+            .with_span(first));
+        }
+        let mut seen = HashSet::new();
+        for literal in &index.desc {
+            let field_name = literal.value();
+            if !seen.insert(field_name.clone()) {
+                return Err(DarlingError::custom(format!(
+                    "index desc contains duplicate field '{field_name}'"
+                ))
+                .with_span(literal));
+            }
+            if !key_items
+                .iter()
+                .any(|item| matches!(item, IndexKeyItemSpec::Field(field) if field == &field_name))
+            {
+                return Err(DarlingError::custom(format!(
+                    "index desc field '{field_name}' is not an index key field"
+                ))
+                .with_span(literal));
+            }
+        }
+
+        Ok(())
+    }
+
+    // Validate covering payload fields: plain single-value entity fields that
+    // are not already key components of a standard index.
+    fn validate_index_include(&self, index: &Index) -> Result<(), DarlingError> {
+        let Some(first) = index.include.first() else {
+            return Ok(());
+        };
+        if index.kind != IndexKind::Standard {
+            return Err(
+                DarlingError::custom("only standard indexes accept include = [...]")
+                    .with_span(first),
+            );
+        }
+        if index.include.len() > icydb_schema::MAX_INDEX_INCLUDE_FIELDS {
+            return Err(DarlingError::custom(format!(
+                "index include accepts at most {} fields",
+                icydb_schema::MAX_INDEX_INCLUDE_FIELDS
+            ))
+            .with_span(first));
+        }
+
+        let key_fields = index.validated_field_idents();
+        let mut seen = HashSet::new();
+        for literal in &index.include {
+            let field_name = literal.value();
+            if !seen.insert(field_name.clone()) {
+                return Err(DarlingError::custom(format!(
+                    "index include contains duplicate field '{field_name}'"
+                ))
+                .with_span(literal));
+            }
+
+            let field = format_ident!("{}", field_name);
+            if key_fields.contains(&field) {
+                return Err(DarlingError::custom(format!(
+                    "index include field '{field_name}' is already an index key field"
+                ))
+                .with_span(literal));
+            }
+            let Some(entity_field) = self.fields.get(&field) else {
+                return Err(DarlingError::custom(format!(
+                    "index include field '{field_name}' not found"
+                ))
+                .with_span(literal));
+            };
+            if entity_field.value.cardinality() == Cardinality::Many {
+                return Err(
+                    DarlingError::custom("cannot include a field with many cardinality")
+                        .with_span(literal),
+                );
+            }
         }
 
         Ok(())
@@ -456,6 +578,18 @@ impl ValidateNode for Entity {
                 ));
             }
         }
+        for field in self
+            .fields
+            .iter()
+            .filter(|field| matches!(field.generated, Some(FieldGeneration::Always(_))))
+        {
+            if self.primary_key.fields().contains(&field.name) {
+                errors.push(syn::Error::new_spanned(
+                    &field.name,
+                    "generated(always = ...) cannot be used on a primary-key field",
+                ));
+            }
+        }
 
         errors
     }
@@ -6,8 +6,8 @@
 use super::{Entity, Timestamps, composite_primary_key_type_part, entity_typed_adapter_tokens};
 use crate::authoring_types::Primitive;
 use crate::node::{
-    Arg, Def, Field, FieldGeneration, FieldList, FieldWriteManagement, HasSchemaPart, Index, Item,
-    PrimaryKey, PrimaryKeySource, Relation, Type, ValidateNode, Value,
+    Arg, Def, Field, FieldGeneration, FieldList, FieldWriteManagement, HasSchemaPart, Index,
+    IndexKind, Item, PrimaryKey, PrimaryKeySource, Relation, Type, ValidateNode, Value,
 };
 use darling::{FromMeta, ast::NestedMeta};
 use proc_macro2::Span;
@@ -395,6 +395,9 @@ fn validate_rejects_index_field_not_found() {
         vec![Index {
             fields: field_list(&["missing_field"]),
             unique: false,
+            kind: IndexKind::Standard,
+            desc: Vec::new(),
+            include: Vec::new(),
             predicate: None,
         }],
     );
@@ -415,6 +418,9 @@ fn validate_rejects_many_cardinality_index_field() {
         vec![Index {
             fields: field_list(&["tags"]),
             unique: false,
+            kind: IndexKind::Standard,
+            desc: Vec::new(),
+            include: Vec::new(),
             predicate: None,
         }],
     );
@@ -428,6 +434,102 @@ fn validate_rejects_many_cardinality_index_field() {
     );
 }
 
+#[test]
+fn validate_accepts_multivalue_index_on_many_cardinality_field() {
+    let entity = entity_with_fields_and_indexes(
+        vec![scalar_field("id"), many_scalar_field("tags")],
+        vec![Index {
+            fields: field_list(&["tags"]),
+            unique: false,
+            kind: IndexKind::Multivalue,
+            desc: Vec::new(),
+            include: Vec::new(),
+            predicate: None,
+        }],
+    );
+
+    entity
+        .validate()
+        .expect("multivalue index on a list field should validate");
+}
+
+#[test]
+fn validate_rejects_multivalue_index_on_scalar_field() {
+    let entity = entity_with_fields_and_indexes(
+        vec![scalar_field("id"), scalar_field("email")],
+        vec![Index {
+            fields: field_list(&["email"]),
+            unique: false,
+            kind: IndexKind::Multivalue,
+            desc: Vec::new(),
+            include: Vec::new(),
+            predicate: None,
+        }],
+    );
+    let err = entity
+        .validate()
+        .expect_err("multivalue index on a scalar field should fail");
+    assert!(
+        err.to_string()
+            .contains("multivalue index field 'email' must be a list or set field"),
+        "unexpected validation error: {err}",
+    );
+}
+
+#[test]
+fn validate_rejects_index_include_that_repeats_a_key_field() {
+    let entity = entity_with_fields_and_indexes(
+        vec![
+            scalar_field("id"),
+            scalar_field("email"),
+            scalar_field("name"),
+        ],
+        vec![Index {
+            fields: field_list(&["email"]),
+            unique: false,
+            kind: IndexKind::Standard,
+            desc: Vec::new(),
+            include: field_list(&["name", "email"]),
+            predicate: None,
+        }],
+    );
+    let err = entity
+        .validate()
+        .expect_err("include field repeating a key field should fail");
+    assert!(
+        err.to_string()
+            .contains("index include field 'email' is already an index key field"),
+        "unexpected validation error: {err}",
+    );
+}
+
+#[test]
+fn validate_rejects_index_desc_on_expression_index() {
+    let entity = entity_with_fields_and_indexes(
+        vec![
+            scalar_field("id"),
+            scalar_field("email"),
+            scalar_field("name"),
+        ],
+        vec![Index {
+            fields: field_list(&["LOWER(email)", "name"]),
+            unique: false,
+            kind: IndexKind::Standard,
+            desc: field_list(&["name"]),
+            include: Vec::new(),
+            predicate: None,
+        }],
+    );
+    let err = entity
+        .validate()
+        .expect_err("desc on an expression index should fail");
+    assert!(
+        err.to_string()
+            .contains("desc = [...] requires an index without expression key items"),
+        "unexpected validation error: {err}",
+    );
+}
+
 #[test]
 fn validate_rejects_expression_index_field_not_found() {
     let entity = entity_with_fields_and_indexes(
@@ -435,6 +537,9 @@ fn validate_rejects_expression_index_field_not_found() {
         vec![Index {
             fields: field_list(&["LOWER(name)"]),
             unique: false,
+            kind: IndexKind::Standard,
+            desc: Vec::new(),
+            include: Vec::new(),
             predicate: None,
         }],
     );
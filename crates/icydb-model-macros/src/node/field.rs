@@ -92,6 +92,7 @@ impl<'a> IntoIterator for &'a FieldList {
 #[derive(Clone, Debug)]
 pub(crate) enum FieldGeneration {
     Insert(Arg),
+    Always(String),
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -102,54 +103,69 @@ pub(crate) enum FieldWriteManagement {
 
 impl FromMeta for FieldGeneration {
     fn from_list(items: &[NestedMeta]) -> Result<Self, DarlingError> {
-        let mut insert = None;
+        let mut generation = None;
 
         for item in items {
             let NestedMeta::Meta(syn::Meta::NameValue(name_value)) = item else {
                 return Err(DarlingError::custom(
-                    "generated(...) currently requires insert = \"...\"",
+                    "generated(...) currently requires insert = \"...\" or always = \"...\"",
                 ));
             };
 
-            if !name_value.path.is_ident("insert") {
+            let next = if name_value.path.is_ident("insert") {
+                let generator = generation_string(name_value, "insert", "generator path")?;
+                Self::Insert(Arg::FuncPath(syn::parse_str(&generator).map_err(|_| {
+                    DarlingError::custom(
+                        "generated(insert = ...) currently requires a quoted generator path",
+                    )
+                })?))
+            } else if name_value.path.is_ident("always") {
+                let expression = generation_string(name_value, "always", "SQL expression")?;
+                if expression.trim().is_empty() {
+                    return Err(DarlingError::custom(
+                        "generated(always = ...) requires a non-empty SQL expression",
+                    ));
+                }
+                Self::Always(expression)
+            } else {
                 return Err(DarlingError::custom(
-                    "generated(...) currently supports only insert = \"...\"",
-                ));
-            }
-
-            let syn::Expr::Lit(expr_lit) = &name_value.value else {
-                return Err(DarlingError::custom(
-                    "generated(insert = ...) currently requires a quoted generator path",
+                    "generated(...) currently supports only insert = \"...\" or always = \"...\"",
                 ));
             };
-
-            let syn::Lit::Str(generator) = &expr_lit.lit else {
+            if generation.replace(next).is_some() {
                 return Err(DarlingError::custom(
-                    "generated(insert = ...) currently requires a quoted generator path",
-                ));
-            };
-            let arg = Arg::FuncPath(syn::parse_str(&generator.value()).map_err(|_| {
-                DarlingError::custom(
-                    "generated(insert = ...) currently requires a quoted generator path",
-                )
-            })?);
-            if insert.replace(arg).is_some() {
-                return Err(DarlingError::custom(
-                    "generated(...) currently accepts only one insert = \"...\" argument",
+                    "generated(...) currently accepts only one insert or always argument",
                 ));
             }
         }
 
-        let Some(insert) = insert else {
-            return Err(DarlingError::custom(
-                "generated(...) currently requires insert = \"...\"",
-            ));
-        };
-
-        Ok(Self::Insert(insert))
+        generation.ok_or_else(|| {
+            DarlingError::custom(
+                "generated(...) currently requires insert = \"...\" or always = \"...\"",
+            )
+        })
     }
 }
 
+// Read one quoted `generated(key = "...")` argument.
+fn generation_string(
+    name_value: &syn::MetaNameValue,
+    key: &str,
+    expected: &str,
+) -> Result<String, DarlingError> {
+    let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Str(value),
+        ..
+    }) = &name_value.value
+    else {
+        return Err(DarlingError::custom(format!(
+            "generated({key} = ...) currently requires a quoted {expected}"
+        )));
+    };
+
+    Ok(value.value())
+}
+
 impl HasSchemaPart for FieldGeneration {
     fn schema_part(&self) -> TokenStream {
         match self {
@@ -157,6 +173,9 @@ impl HasSchemaPart for FieldGeneration {
                 let arg = quote_one(arg, Arg::schema_part);
                 quote!(::icydb_model::node::FieldGeneration::Insert(#arg))
             }
+            Self::Always(expression) => {
+                quote!(::icydb_model::node::FieldGeneration::Always(#expression))
+            }
         }
     }
 }
@@ -193,10 +212,11 @@ impl Field {
     /// Return whether this field requests database-owned unsigned identity
     /// generation.
     pub(crate) fn is_identity_generated(&self) -> bool {
-        self.generated.as_ref().is_some_and(|generated| {
-            let FieldGeneration::Insert(generator) = generated;
-            generated_insert_contract(generator) == Some(GeneratedInsertContract::Identity)
-        })
+        matches!(
+            &self.generated,
+            Some(FieldGeneration::Insert(generator))
+                if generated_insert_contract(generator) == Some(GeneratedInsertContract::Identity)
+        )
     }
 
     pub fn validate(&self) -> Result<(), DarlingError> {
@@ -254,8 +274,13 @@ impl Field {
     pub fn default_matches_implicit_default(&self) -> bool {
         let Some(default) = &self.default else {
             return match self.value.cardinality() {
-                Cardinality::One => self.write_management.is_some(),
-                Cardinality::Opt | Cardinality::Many => self.generated.is_none(),
+                Cardinality::One => {
+                    self.write_management.is_some()
+                        || matches!(self.generated, Some(FieldGeneration::Always(_)))
+                }
+                Cardinality::Opt | Cardinality::Many => {
+                    !matches!(self.generated, Some(FieldGeneration::Insert(_)))
+                }
             };
         };
 
@@ -281,6 +306,14 @@ impl Field {
             }
             return Some(quote!(#generator.into()));
         }
+        if matches!(self.generated, Some(FieldGeneration::Always(_))) {
+            // Stored computed values are always recomputed by the database, so
+            // Rust construction only needs the ordinary placeholder value.
+            return Some(match self.value.cardinality() {
+                Cardinality::Opt => quote!(None),
+                Cardinality::One | Cardinality::Many => quote!(Default::default()),
+            });
+        }
 
         match (&self.default, self.value.cardinality()) {
             (Some(default), _) => Some(schema_default_rust_expr(default, &self.value)),
@@ -327,11 +360,50 @@ impl Field {
         primitive_default_matches(primitive, default)
     }
 
+    // `generated(always = "...")` stores one scalar value recomputed by the
+    // database. The SQL expression itself binds later against accepted schema.
+    fn validate_generated_always(&self) -> Result<(), DarlingError> {
+        if self.write_management.is_some() {
+            return Err(DarlingError::custom(
+                "generated(always = ...) cannot be combined with auto-managed write fields",
+            )
+            .with_span(&self.name));
+        }
+
+        if self.default.is_some() {
+            return Err(DarlingError::custom(
+                "generated(always = ...) cannot be combined with a database default",
+            )
+            .with_span(&self.name));
+        }
+
+        if self.value.cardinality() == Cardinality::Many {
+            return Err(DarlingError::custom(
+                "generated(always = ...) currently supports only single-value fields",
+            )
+            .with_span(&self.name));
+        }
+
+        if self.value.item.primitive.is_none()
+            || self.value.item.is.is_some()
+            || self.value.item.relation.is_some()
+        {
+            return Err(DarlingError::custom(
+                "generated(always = ...) currently supports only primitive scalar fields",
+            )
+            .with_span(&self.name));
+        }
+
+        Ok(())
+    }
+
     // `generated(insert = "...")` stays schema-owned and explicit. Only one
     // small allowlist of write-time generators is admitted in this release.
     fn validate_generated(&self) -> Result<(), DarlingError> {
-        let Some(FieldGeneration::Insert(generator)) = self.generated.as_ref() else {
-            return Ok(());
+        let generator = match self.generated.as_ref() {
+            None => return Ok(()),
+            Some(FieldGeneration::Always(_)) => return self.validate_generated_always(),
+            Some(FieldGeneration::Insert(generator)) => generator,
         };
 
         if self.write_management.is_some() {
@@ -163,6 +163,38 @@ fn generated_clause_requires_a_quoted_function_path() {
     );
 }
 
+#[test]
+fn generated_always_keeps_expression_and_rejects_defaults() {
+    let generated = FieldGeneration::from_list(&[NestedMeta::Meta(syn::Meta::NameValue(
+        parse_quote!(always = "price * quantity"),
+    ))])
+    .expect("a quoted generated expression should parse");
+    let FieldGeneration::Always(expression) = &generated else {
+        panic!("generated always must retain the authored expression");
+    };
+    assert_eq!(expression, "price * quantity");
+
+    let mut total = field("total", Primitive::Int64);
+    total.generated = Some(generated);
+    total
+        .validate()
+        .expect("primitive generated column should validate");
+    assert!(total.default_matches_implicit_default());
+    assert_eq!(
+        total
+            .rust_default_expr()
+            .expect("generated column should construct")
+            .to_string(),
+        quote!(Default::default()).to_string(),
+    );
+
+    total.default = Some(Arg::FuncPath(parse_quote!(i64::default)));
+    let error = total
+        .validate()
+        .expect_err("generated columns must not carry database defaults");
+    assert!(error.to_string().contains("database default"));
+}
+
 #[test]
 fn identity_like_defaults_require_explicit_persisted_literals() {
     let mut id = field("id", Primitive::Ulid);
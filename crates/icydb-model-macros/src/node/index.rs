@@ -26,29 +26,36 @@ pub struct Index {
 
     pub(crate) unique: bool,
 
+    pub(crate) kind: IndexKind,
+
+    // Direct key fields whose ordered segments sort in descending order.
+    pub(crate) desc: Vec<LitStr>,
+
+    // Non-key payload fields carried in each index entry for covering reads.
+    pub(crate) include: Vec<LitStr>,
+
     // Raw SQL predicate text is accepted at the derive boundary and lowered
     // into canonical predicate semantics during macro expansion.
     pub(crate) predicate: Option<String>,
 }
 
+const INDEX_ARGS_USAGE: &str = "index(...) supports field = \"...\", fields = [...], unique, kind = \"...\", desc = [...], include = [...], and predicate = \"...\"";
+
+const INDEX_UNIQUE_DUPLICATE: &str = "index(...) accepts only one unique argument";
+
 impl FromMeta for Index {
     fn from_list(items: &[NestedMeta]) -> Result<Self, DarlingError> {
         let mut fields = None;
-        let mut unique = false;
-        let mut unique_seen = false;
+        let mut unique = None;
         let mut predicate = None;
+        let mut kind = None;
+        let mut desc = None;
+        let mut include = None;
 
         for item in items {
             match item {
                 NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("unique") => {
-                    if unique_seen {
-                        return Err(DarlingError::custom(
-                            "index(...) accepts only one unique argument",
-                        )
-                        .with_span(path));
-                    }
-                    unique = true;
-                    unique_seen = true;
+                    set_index_arg_once(&mut unique, true, INDEX_UNIQUE_DUPLICATE, path)?;
                 }
                 NestedMeta::Meta(syn::Meta::NameValue(name_value)) => {
                     if name_value.path.is_ident("field") {
@@ -72,14 +79,42 @@ impl FromMeta for Index {
                     }
 
                     if name_value.path.is_ident("unique") {
-                        if unique_seen {
-                            return Err(DarlingError::custom(
-                                "index(...) accepts only one unique argument",
-                            )
-                            .with_span(&name_value.path));
-                        }
-                        unique = parse_index_bool_arg(&name_value.value)?;
-                        unique_seen = true;
+                        set_index_arg_once(
+                            &mut unique,
+                            parse_index_bool_arg(&name_value.value)?,
+                            INDEX_UNIQUE_DUPLICATE,
+                            &name_value.path,
+                        )?;
+                        continue;
+                    }
+
+                    if name_value.path.is_ident("kind") {
+                        set_index_arg_once(
+                            &mut kind,
+                            parse_index_kind_arg(&name_value.value)?,
+                            "index(...) accepts only one kind = \"...\" argument",
+                            &name_value.path,
+                        )?;
+                        continue;
+                    }
+
+                    if name_value.path.is_ident("desc") {
+                        set_index_arg_once(
+                            &mut desc,
+                            parse_field_list_arg("index", &name_value.value)?,
+                            "index(...) accepts only one desc = [...] argument",
+                            &name_value.path,
+                        )?;
+                        continue;
+                    }
+
+                    if name_value.path.is_ident("include") {
+                        set_index_arg_once(
+                            &mut include,
+                            parse_field_list_arg("index", &name_value.value)?,
+                            "index(...) accepts only one include = [...] argument",
+                            &name_value.path,
+                        )?;
                         continue;
                     }
 
@@ -93,22 +128,12 @@ impl FromMeta for Index {
                         continue;
                     }
 
-                    return Err(DarlingError::custom(
-                        "index(...) supports field = \"...\", fields = [...], unique, and predicate = \"...\"",
-                    )
-                    .with_span(&name_value.path));
+                    return Err(DarlingError::custom(INDEX_ARGS_USAGE).with_span(&name_value.path));
                 }
                 NestedMeta::Meta(syn::Meta::Path(path)) => {
-                    return Err(DarlingError::custom(
-                        "index(...) supports field = \"...\", fields = [...], unique, and predicate = \"...\"",
-                    )
-                    .with_span(path));
-                }
-                _ => {
-                    return Err(DarlingError::custom(
-                        "index(...) supports field = \"...\", fields = [...], unique, and predicate = \"...\"",
-                    ));
+                    return Err(DarlingError::custom(INDEX_ARGS_USAGE).with_span(path));
                 }
+                _ => return Err(DarlingError::custom(INDEX_ARGS_USAGE)),
             }
         }
 
@@ -124,12 +149,31 @@ impl FromMeta for Index {
 
         Ok(Self {
             fields,
-            unique,
+            unique: unique.unwrap_or_default(),
+            kind: kind.unwrap_or_default(),
+            desc: desc.unwrap_or_default(),
+            include: include.unwrap_or_default(),
             predicate,
         })
     }
 }
 
+///
+/// IndexKind
+///
+/// Declared storage kind for one generated index. `Text` indexes store one
+/// posting per distinct token of a single text field; `Multivalue` indexes
+/// store one entry per distinct element of a single list or set field.
+///
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum IndexKind {
+    #[default]
+    Standard,
+    Text,
+    Multivalue,
+}
+
 fn set_index_arg_once<T>(
     target: &mut Option<T>,
     value: T,
@@ -159,6 +203,28 @@ fn parse_index_string_arg(expr: &syn::Expr) -> Result<String, DarlingError> {
     Ok(literal.value())
 }
 
+fn parse_index_kind_arg(expr: &syn::Expr) -> Result<IndexKind, DarlingError> {
+    let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Str(literal),
+        ..
+    }) = expr
+    else {
+        return Err(
+            DarlingError::custom("index(kind = ...) requires a string literal").with_span(expr),
+        );
+    };
+
+    match literal.value().as_str() {
+        "standard" => Ok(IndexKind::Standard),
+        "text" => Ok(IndexKind::Text),
+        "multivalue" => Ok(IndexKind::Multivalue),
+        other => Err(DarlingError::custom(format!(
+            "unsupported index kind '{other}'; expected \"standard\", \"text\", or \"multivalue\""
+        ))
+        .with_span(literal)),
+    }
+}
+
 fn parse_index_bool_arg(expr: &syn::Expr) -> Result<bool, DarlingError> {
     let syn::Expr::Lit(expr_lit) = expr else {
         return Err(
@@ -209,7 +275,7 @@ impl Index {
             .transpose()?
             .unwrap_or_else(|| quote! { None });
 
-        Ok(quote! {
+        let index = quote! {
             ::icydb_model::node::Index::new_with_key_items_and_predicate(
                 #name,
                 #fields,
@@ -218,7 +284,20 @@ impl Index {
                 #predicate,
                 #predicate_expression,
             )
-        })
+        };
+        let index = if self.desc.is_empty() {
+            index
+        } else {
+            let desc = quote_slice(&self.desc, |field| quote! { #field });
+            quote! { #index.with_descending(#desc) }
+        };
+        if self.include.is_empty() {
+            return Ok(index);
+        }
+
+        let include = quote_slice(&self.include, |field| quote! { #field });
+
+        Ok(quote! { #index.with_include(#include) })
     }
 
     /// Build the canonical index name (`idx_entity__key_item...`) shared across
@@ -236,7 +315,35 @@ impl Index {
     }
 
     pub(crate) fn parsed_key_items(&self) -> Result<Vec<IndexKeyItemSpec>, DarlingError> {
-        parse_index_key_items(self.fields.as_slice())
+        let key_items = parse_index_key_items(self.fields.as_slice())?;
+        if self.kind == IndexKind::Standard {
+            return Ok(key_items);
+        }
+
+        // Text and multivalue indexes expand exactly one plain field and can
+        // never be unique because one row owns many entries.
+        let label = match self.kind {
+            IndexKind::Text => "text",
+            IndexKind::Standard | IndexKind::Multivalue => "multivalue",
+        };
+        if self.unique {
+            return Err(DarlingError::custom(format!(
+                "{label} indexes cannot be unique"
+            )));
+        }
+        let [IndexKeyItemSpec::Field(field)] = key_items.as_slice() else {
+            return Err(DarlingError::custom(format!(
+                "{label} indexes require exactly one plain field"
+            )));
+        };
+        let expression = match self.kind {
+            IndexKind::Text => IndexExpressionSpec::Tokens(field.clone()),
+            IndexKind::Standard | IndexKind::Multivalue => {
+                IndexExpressionSpec::Elements(field.clone())
+            }
+        };
+
+        Ok(vec![IndexKeyItemSpec::Expression(expression)])
     }
 
     pub(crate) fn referenced_field_literals(&self) -> Result<Vec<(Ident, LitStr)>, DarlingError> {
@@ -249,10 +356,19 @@ impl Index {
             .collect()
     }
 
+    // Descending key fields carry their direction so generated names and
+    // redundant-prefix checks distinguish `(score DESC)` from `(score)`.
     pub(crate) fn validated_key_item_terms(&self) -> Vec<String> {
         self.validated_key_items()
             .into_iter()
-            .map(|item| item.canonical_text())
+            .map(|item| match &item {
+                IndexKeyItemSpec::Field(field)
+                    if self.desc.iter().any(|desc| field == desc.value().as_str()) =>
+                {
+                    format!("{field} DESC")
+                }
+                _ => item.canonical_text(),
+            })
             .collect()
     }
 
@@ -358,6 +474,8 @@ pub(crate) enum IndexExpressionSpec {
     Year(Ident),
     Month(Ident),
     Day(Ident),
+    Tokens(Ident),
+    Elements(Ident),
 }
 
 impl IndexExpressionSpec {
@@ -370,7 +488,9 @@ impl IndexExpressionSpec {
             | Self::Date(field)
             | Self::Year(field)
             | Self::Month(field)
-            | Self::Day(field) => field,
+            | Self::Day(field)
+            | Self::Tokens(field)
+            | Self::Elements(field) => field,
         }
     }
 
@@ -384,6 +504,8 @@ impl IndexExpressionSpec {
             Self::Year(field) => format!("YEAR({field})"),
             Self::Month(field) => format!("MONTH({field})"),
             Self::Day(field) => format!("DAY({field})"),
+            Self::Tokens(field) => format!("TOKENS({field})"),
+            Self::Elements(field) => format!("ELEMENTS({field})"),
         }
     }
 
@@ -401,6 +523,10 @@ impl IndexExpressionSpec {
             Self::Year(_) => quote! { ::icydb_model::node::IndexExpression::Year(#field) },
             Self::Month(_) => quote! { ::icydb_model::node::IndexExpression::Month(#field) },
             Self::Day(_) => quote! { ::icydb_model::node::IndexExpression::Day(#field) },
+            Self::Tokens(_) => quote! { ::icydb_model::node::IndexExpression::Tokens(#field) },
+            Self::Elements(_) => {
+                quote! { ::icydb_model::node::IndexExpression::Elements(#field) }
+            }
         }
     }
 }
@@ -914,7 +1040,7 @@ fn parse_index_field_ident(field: &str, literal: &LitStr) -> Result<Ident, Darli
 
 #[cfg(test)]
 mod tests {
-    use crate::node::index::{Index, IndexExpressionSpec, IndexKeyItemSpec};
+    use crate::node::index::{Index, IndexExpressionSpec, IndexKeyItemSpec, IndexKind};
     use darling::{FromMeta, ast::NestedMeta};
     use proc_macro2::Span;
     use quote::quote;
@@ -991,6 +1117,9 @@ mod tests {
         let index = Index {
             fields: field_list(&["tenant_id", "LOWER(email)"]),
             unique: true,
+            kind: IndexKind::Standard,
+            desc: Vec::new(),
+            include: Vec::new(),
             predicate: None,
         };
 
@@ -1012,9 +1141,74 @@ mod tests {
         let index = Index {
             fields: field_list(&["LOWER(email)"]),
             unique: false,
+            kind: IndexKind::Standard,
+            desc: Vec::new(),
+            include: Vec::new(),
             predicate: None,
         };
 
         assert_eq!(index.generated_name("User"), "idx_user__lower_email");
     }
+
+    #[test]
+    fn text_kind_wraps_single_field_in_token_expression() {
+        let index = parse_index(quote!(field = "body", kind = "text"))
+            .expect("text index declaration should parse");
+
+        assert_eq!(index.kind, IndexKind::Text);
+        assert_eq!(
+            index
+                .parsed_key_items()
+                .expect("text index key should parse"),
+            vec![IndexKeyItemSpec::Expression(IndexExpressionSpec::Tokens(
+                syn::parse_quote!(body)
+            ))],
+        );
+        assert_eq!(index.generated_name("Doc"), "idx_doc__tokens_body");
+    }
+
+    #[test]
+    fn text_kind_rejects_unique_and_composite_keys() {
+        let unique = parse_index(quote!(field = "body", kind = "text", unique))
+            .expect("text index arguments should parse");
+        let composite = parse_index(quote!(fields = ["title", "body"], kind = "text"))
+            .expect("text index arguments should parse");
+        let unknown = parse_index(quote!(field = "body", kind = "hash"))
+            .expect_err("unknown index kind should be rejected");
+
+        assert!(unique.parsed_key_items().is_err());
+        assert!(composite.parsed_key_items().is_err());
+        assert!(
+            unknown.to_string().contains("unsupported index kind"),
+            "unexpected error: {unknown}",
+        );
+    }
+
+    #[test]
+    fn multivalue_kind_wraps_single_field_in_element_expression() {
+        let index = parse_index(quote!(field = "tags", kind = "multivalue"))
+            .expect("multivalue index declaration should parse");
+
+        assert_eq!(index.kind, IndexKind::Multivalue);
+        assert_eq!(
+            index
+                .parsed_key_items()
+                .expect("multivalue index key should parse"),
+            vec![IndexKeyItemSpec::Expression(IndexExpressionSpec::Elements(
+                syn::parse_quote!(tags)
+            ))],
+        );
+        assert_eq!(index.generated_name("Post"), "idx_post__elements_tags");
+    }
+
+    #[test]
+    fn multivalue_kind_rejects_unique_and_composite_keys() {
+        let unique = parse_index(quote!(field = "tags", kind = "multivalue", unique))
+            .expect("multivalue index arguments should parse");
+        let composite = parse_index(quote!(fields = ["tags", "roles"], kind = "multivalue"))
+            .expect("multivalue index arguments should parse");
+
+        assert!(unique.parsed_key_items().is_err());
+        assert!(composite.parsed_key_items().is_err());
+    }
 }
@@ -406,12 +406,17 @@ fn scalar_type_tokens(value: &LitStr) -> Result<TokenStream, DarlingError> {
         }
         return Ok(quote!(::icydb_model::schema::ScalarType::Decimal { scale: #scale }));
     }
+    if text == "Text" {
+        return Ok(quote!(::icydb_model::schema::ScalarType::Text {
+            max_len: None
+        }));
+    }
     let variant = match text.as_str() {
         "Int8" | "Int16" | "Int32" | "Int64" | "Int128" | "Nat8" | "Nat16" | "Nat32" | "Nat64"
-        | "Nat128" => Ident::new(text.as_str(), value.span()),
+        | "Nat128" | "Timestamp" | "Ulid" => Ident::new(text.as_str(), value.span()),
         _ => {
             return Err(DarlingError::custom(
-                "checked_cast target must be a fixed Int/Nat kind or Decimal(scale)",
+                "checked_cast target must be a fixed Int/Nat kind, Decimal(scale), Text, Timestamp, or Ulid",
             )
             .with_span(value));
         }
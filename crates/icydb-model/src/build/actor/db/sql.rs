@@ -28,19 +28,19 @@ impl SqlSurfaceTokens {
 
     fn readonly_dispatch_tokens(&self) -> TokenStream {
         let entity_dispatch = if self.has_entities {
-            quote! { db()?.execute_trusted_sql_query_with_perf_attribution(sql) }
+            quote! { db()?.execute_trusted_sql_query_request_with_perf_attribution(request) }
         } else {
             empty_sql_surface_query_dispatch()
         };
         let show_entities_dispatch = if self.has_entities {
-            quote! { db()?.execute_trusted_sql_query_with_perf_attribution(sql) }
+            quote! { db()?.execute_trusted_sql_query_request_with_perf_attribution(request) }
         } else {
             empty_sql_surface_query_dispatch()
         };
 
         quote! {
             fn __icydb_query_dispatch<const INTROSPECTION: bool>(
-                sql: &str,
+                request: ::icydb::db::sql::SqlQueryRequest,
             ) -> Result<
                 (
                     ::icydb::db::sql::SqlQueryResult,
@@ -48,7 +48,7 @@ impl SqlSurfaceTokens {
                 ),
                 ::icydb::Error,
             > {
-                let dispatch = ::icydb::__macro::sql_statement_dispatch(sql)?;
+                let dispatch = ::icydb::__macro::sql_statement_dispatch(request.sql.as_str())?;
                 if !INTROSPECTION && dispatch.requires_introspection() {
                     return Err(::icydb::Error::from_runtime_boundary(
                         ::icydb::diagnostic::RuntimeBoundaryCode::SqlIntrospectionDisabled,
@@ -162,10 +162,9 @@ impl SqlSurfaceTokens {
 
         quote! {
             pub(crate) fn __icydb_endpoint_handler_sql_query<const INTROSPECTION: bool>(
-                sql: String,
+                request: ::icydb::db::sql::SqlQueryRequest,
             ) -> Result<::icydb::db::sql::SqlQueryPerfResult, ::icydb::Error> {
-                let (result, attribution) =
-                    __icydb_query_dispatch::<INTROSPECTION>(sql.as_str())?;
+                let (result, attribution) = __icydb_query_dispatch::<INTROSPECTION>(request)?;
 
                 ::icydb::db::sql::SqlQueryPerfResult::from_attribution(
                     result,
@@ -323,7 +322,9 @@ mod tests {
         surface.push_entity("Character");
         let surface = compact_tokens(quote!(#surface));
 
-        assert!(surface.contains("execute_trusted_sql_query_with_perf_attribution(sql)"));
+        assert!(
+            surface.contains("execute_trusted_sql_query_request_with_perf_attribution(request)")
+        );
         assert!(surface.contains("into_deliverable_query_reply()"));
         assert!(surface.contains("execute_admin_sql_ddl(sql)"));
     }
@@ -362,8 +363,8 @@ mod tests {
         let entity = compact_tokens(quote!(#entity));
 
         assert!(empty.contains("SqlQueryNoConfiguredEntities"));
-        assert!(!empty.contains("execute_trusted_sql_query_with_perf_attribution"));
-        assert!(entity.contains("execute_trusted_sql_query_with_perf_attribution"));
+        assert!(!empty.contains("execute_trusted_sql_query_request_with_perf_attribution"));
+        assert!(entity.contains("execute_trusted_sql_query_request_with_perf_attribution"));
         assert!(entity.contains("DELETEFROMCharacter"));
     }
 }
@@ -26,10 +26,10 @@ use thiserror::Error;
 
 use crate::{
     node::{
-        Arg, ArgNumber, Canister, CheckConstraint, Entity, Enum, Field, FieldWriteManagement,
-        Index, IndexExpression, IndexKeyItem, IndexKeyItemsRef, Item, ItemTarget, List, Map,
-        Record, RelationEdge, RuleNumber, Schema, SchemaNode, Set, SourceRule,
-        SourceRuleAuthoringOperation, Store, Tuple, Value,
+        Arg, ArgNumber, Canister, CheckConstraint, Entity, Enum, Field, FieldGeneration,
+        FieldWriteManagement, Index, IndexExpression, IndexKeyItem, IndexKeyItemsRef, Item,
+        ItemTarget, List, Map, Record, RelationEdge, RuleNumber, Schema, SchemaNode, Set,
+        SourceRule, SourceRuleAuthoringOperation, Store, Tuple, Value,
     },
     types::{Cardinality, Primitive},
 };
@@ -213,7 +213,9 @@ fn lower_entity_field(
 ) -> Result<FieldFragment, FragmentLoweringError> {
     let field_type = lower_value_type(schema, field.value(), pending_types)?;
     let nullable = field.value().cardinality() == Cardinality::Opt;
-    let insert_policy = if field.generated().is_some() {
+    let insert_policy = if let Some(FieldGeneration::Always(expression)) = field.generated() {
+        FieldInsertPolicy::Computed((*expression).to_string())
+    } else if field.generated().is_some() {
         FieldInsertPolicy::Generated
     } else if let Some(default) = field.default() {
         FieldInsertPolicy::Default(lower_default(schema, field, default)?)
@@ -251,12 +253,24 @@ fn lower_index(
             .map(|item| lower_index_key(entity, item))
             .collect::<Result<Vec<_>, _>>()?,
     };
+    let include = index
+        .include()
+        .iter()
+        .map(|field| entity_field_source_key(entity, field))
+        .collect::<Result<Vec<_>, _>>()?;
+    let descending = index
+        .descending()
+        .iter()
+        .map(|field| entity_field_source_key(entity, field))
+        .collect::<Result<Vec<_>, _>>()?;
     IndexFragment::try_new(
         SchemaName::try_new(index.name())?,
         key,
         index.is_unique(),
         index.source_predicate(schema)?,
-    )
+    )?
+    .with_descending(descending)?
+    .with_include(include)
     .map_err(Into::into)
 }
 
@@ -277,6 +291,8 @@ fn lower_index_key(
         IndexKeyItem::Expression(IndexExpression::Year(_)) => IndexKeyFragment::Year(field),
         IndexKeyItem::Expression(IndexExpression::Month(_)) => IndexKeyFragment::Month(field),
         IndexKeyItem::Expression(IndexExpression::Day(_)) => IndexKeyFragment::Day(field),
+        IndexKeyItem::Expression(IndexExpression::Tokens(_)) => IndexKeyFragment::Tokens(field),
+        IndexKeyItem::Expression(IndexExpression::Elements(_)) => IndexKeyFragment::Elements(field),
     })
 }
 
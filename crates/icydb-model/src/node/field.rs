@@ -44,6 +44,7 @@ impl VisitableNode for FieldList {
 #[derive(Clone, Debug, Serialize)]
 pub enum FieldGeneration {
     Insert(Arg),
+    Always(&'static str),
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
@@ -23,6 +23,8 @@ pub enum IndexExpression {
     Year(&'static str),
     Month(&'static str),
     Day(&'static str),
+    Tokens(&'static str),
+    Elements(&'static str),
 }
 
 impl IndexExpression {
@@ -37,7 +39,9 @@ impl IndexExpression {
             | Self::Date(field)
             | Self::Year(field)
             | Self::Month(field)
-            | Self::Day(field) => field,
+            | Self::Day(field)
+            | Self::Tokens(field)
+            | Self::Elements(field) => field,
         }
     }
 }
@@ -53,6 +57,8 @@ impl Display for IndexExpression {
             Self::Year(field) => write!(f, "YEAR({field})"),
             Self::Month(field) => write!(f, "MONTH({field})"),
             Self::Day(field) => write!(f, "DAY({field})"),
+            Self::Tokens(field) => write!(f, "TOKENS({field})"),
+            Self::Elements(field) => write!(f, "ELEMENTS({field})"),
         }
     }
 }
@@ -117,6 +123,14 @@ pub struct Index {
     #[serde(skip_serializing_if = "Not::not")]
     unique: bool,
 
+    // Direct key fields whose ordered segments sort in descending order.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    descending: &'static [&'static str],
+
+    // Non-key payload fields stored in each index entry for covering reads.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    include: &'static [&'static str],
+
     // Raw predicate SQL remains input metadata until lowered into canonical
     // predicate semantics at runtime schema boundary.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -178,11 +192,25 @@ impl Index {
             fields,
             key_items,
             unique,
+            descending: &[],
+            include: &[],
             predicate,
             predicate_expression,
         }
     }
 
+    /// Mark direct key fields that sort in descending order.
+    #[must_use]
+    pub const fn with_descending(self, descending: &'static [&'static str]) -> Self {
+        Self { descending, ..self }
+    }
+
+    /// Attach non-key payload fields carried in each index entry.
+    #[must_use]
+    pub const fn with_include(self, include: &'static [&'static str]) -> Self {
+        Self { include, ..self }
+    }
+
     /// Borrow the current index name.
     #[must_use]
     pub const fn name(&self) -> &'static str {
@@ -229,6 +257,18 @@ impl Index {
         self.unique
     }
 
+    /// Borrow the direct key fields that sort in descending order.
+    #[must_use]
+    pub const fn descending(&self) -> &'static [&'static str] {
+        self.descending
+    }
+
+    /// Borrow the non-key payload fields carried in each index entry.
+    #[must_use]
+    pub const fn include(&self) -> &'static [&'static str] {
+        self.include
+    }
+
     /// Return optional conditional-index predicate SQL metadata.
     ///
     /// This text is input-only; runtime/planner semantics must consume the
@@ -262,21 +302,35 @@ impl Index {
     }
 
     fn joined_key_items(&self) -> String {
+        let mut joined = String::new();
+        let mut push = |text: &str, field: Option<&str>| {
+            if !joined.is_empty() {
+                joined.push_str(", ");
+            }
+            joined.push_str(text);
+            if field.is_some_and(|field| self.descending.contains(&field)) {
+                joined.push_str(" DESC");
+            }
+        };
+
         match self.key_items() {
-            IndexKeyItemsRef::Fields(fields) => fields.join(", "),
+            IndexKeyItemsRef::Fields(fields) => {
+                for field in fields {
+                    push(field, Some(field));
+                }
+            }
             IndexKeyItemsRef::Items(items) => {
-                let mut joined = String::new();
-
                 for item in items {
-                    if !joined.is_empty() {
-                        joined.push_str(", ");
-                    }
-                    joined.push_str(item.canonical_text().as_str());
+                    let field = match item {
+                        IndexKeyItem::Field(field) => Some(*field),
+                        IndexKeyItem::Expression(_) => None,
+                    };
+                    push(item.canonical_text().as_str(), field);
                 }
-
-                joined
             }
         }
+
+        joined
     }
 }
 
@@ -285,16 +339,18 @@ impl Display for Index {
         let fields = self.joined_key_items();
 
         if self.is_unique() {
-            if let Some(predicate) = self.predicate() {
-                write!(f, "UNIQUE ({fields}) WHERE {predicate}")
-            } else {
-                write!(f, "UNIQUE ({fields})")
-            }
-        } else if let Some(predicate) = self.predicate() {
-            write!(f, "({fields}) WHERE {predicate}")
+            write!(f, "UNIQUE ({fields})")?;
         } else {
-            write!(f, "({fields})")
+            write!(f, "({fields})")?;
+        }
+        if !self.include.is_empty() {
+            write!(f, " INCLUDE ({})", self.include.join(", "))?;
+        }
+        if let Some(predicate) = self.predicate() {
+            write!(f, " WHERE {predicate}")?;
         }
+
+        Ok(())
     }
 }
 
@@ -353,6 +409,33 @@ mod tests {
         assert_eq!(index.to_string(), "UNIQUE (email)");
     }
 
+    #[test]
+    fn index_with_include_renders_payload_fields_before_predicate() {
+        let index = Index::new_with_predicate(
+            "idx_user__score",
+            &["score"],
+            false,
+            Some("active = true"),
+            None,
+        )
+        .with_include(&["name", "avatar"]);
+
+        assert_eq!(index.include(), &["name", "avatar"]);
+        assert_eq!(
+            index.to_string(),
+            "(score) INCLUDE (name, avatar) WHERE active = true"
+        );
+    }
+
+    #[test]
+    fn index_with_descending_renders_direction_after_key_field() {
+        let index = Index::new("idx_player__score__id", &["score", "id"], false)
+            .with_descending(&["score"]);
+
+        assert_eq!(index.descending(), &["score"]);
+        assert_eq!(index.to_string(), "(score DESC, id)");
+    }
+
     #[test]
     fn index_with_explicit_key_items_exposes_expression_items() {
         static KEY_ITEMS: [IndexKeyItem; 2] = [
@@ -39,6 +39,12 @@ pub const MAX_FRAGMENT_FIELDS: usize = 256;
 /// Maximum number of indexes in one entity definition.
 pub const MAX_FRAGMENT_INDEXES: usize = 64;
 
+/// Maximum number of non-key payload columns one index may include.
+pub const MAX_INDEX_INCLUDE_FIELDS: usize = 8;
+
+/// Maximum byte length of one stored generated-field SQL expression.
+pub const MAX_GENERATED_FIELD_EXPRESSION_BYTES: usize = 4 * 1024;
+
 /// Maximum number of relations in one entity definition.
 pub const MAX_FRAGMENT_RELATIONS: usize = 64;
 
@@ -3,9 +3,10 @@ use crate::{
     EnumTypeFragment, EnumVariantFragment, FieldFragment, FieldInsertPolicy, FieldManagementPolicy,
     IndexFragment, IndexKeyFragment, MAX_FRAGMENT_CONSTRAINTS, MAX_FRAGMENT_ENTITIES,
     MAX_FRAGMENT_FIELDS, MAX_FRAGMENT_INDEXES, MAX_FRAGMENT_RELATIONS, MAX_FRAGMENT_TYPES,
-    MAX_SCHEMA_FRAGMENT_BYTES, NamedTypeFragment, RecordFieldFragment, RecordTypeFragment,
-    RelationDeleteAction, RelationFragment, SchemaContractError, SchemaFragment,
-    TargetedRuleFragment, TupleElementFragment,
+    MAX_GENERATED_FIELD_EXPRESSION_BYTES, MAX_INDEX_INCLUDE_FIELDS, MAX_SCHEMA_FRAGMENT_BYTES,
+    NamedTypeFragment, RecordFieldFragment, RecordTypeFragment, RelationDeleteAction,
+    RelationFragment, SchemaContractError, SchemaFragment, TargetedRuleFragment,
+    TupleElementFragment,
 };
 
 use super::{
@@ -117,6 +118,10 @@ pub(super) fn encode_field(
             super::value::encode_literal(writer, literal)?;
         }
         FieldInsertPolicy::Generated => writer.push_u8(3)?,
+        FieldInsertPolicy::Computed(expression) => {
+            writer.push_u8(4)?;
+            writer.push_string(expression)?;
+        }
     }
     match field.management() {
         None => writer.push_u8(0)?,
@@ -141,6 +146,9 @@ fn decode_fields(reader: &mut WireReader<'_>) -> Result<Vec<FieldFragment>, Sche
             1 => FieldInsertPolicy::Nullable,
             2 => FieldInsertPolicy::Default(super::value::decode_literal(reader)?),
             3 => FieldInsertPolicy::Generated,
+            4 => FieldInsertPolicy::Computed(
+                reader.read_string(MAX_GENERATED_FIELD_EXPRESSION_BYTES)?,
+            ),
             _ => return Err(SchemaContractError::Decode),
         };
         let management = match reader.read_u8()? {
@@ -187,6 +195,12 @@ fn decode_field_keys(
     Ok(fields)
 }
 
+// Index trailer flags. Bit 0 keeps the original predicate-presence byte, so
+// indexes without included columns or descending keys encode exactly as before.
+const INDEX_TRAILER_PREDICATE: u8 = 0b001;
+const INDEX_TRAILER_INCLUDE: u8 = 0b010;
+const INDEX_TRAILER_DESCENDING: u8 = 0b100;
+
 fn encode_index(writer: &mut WireWriter, index: &IndexFragment) -> Result<(), SchemaContractError> {
     encode_schema_name(writer, index.name())?;
     writer.push_len(index.key().len())?;
@@ -201,15 +215,33 @@ fn encode_index(writer: &mut WireWriter, index: &IndexFragment) -> Result<(), Sc
             IndexKeyFragment::Year(_) => 6,
             IndexKeyFragment::Month(_) => 7,
             IndexKeyFragment::Day(_) => 8,
+            IndexKeyFragment::Tokens(_) => 9,
+            IndexKeyFragment::Elements(_) => 10,
         };
         writer.push_u8(tag)?;
         encode_source_key(writer, component.field().as_str())?;
     }
     writer.push_bool(index.unique())?;
-    writer.push_bool(index.predicate().is_some())?;
+    let mut trailer = 0;
+    if index.predicate().is_some() {
+        trailer |= INDEX_TRAILER_PREDICATE;
+    }
+    if !index.include().is_empty() {
+        trailer |= INDEX_TRAILER_INCLUDE;
+    }
+    if !index.descending().is_empty() {
+        trailer |= INDEX_TRAILER_DESCENDING;
+    }
+    writer.push_u8(trailer)?;
     if let Some(predicate) = index.predicate() {
         encode_expression(writer, predicate)?;
     }
+    if !index.include().is_empty() {
+        encode_field_keys(writer, index.include())?;
+    }
+    if !index.descending().is_empty() {
+        encode_field_keys(writer, index.descending())?;
+    }
     Ok(())
 }
 
@@ -238,15 +270,45 @@ fn decode_indexes(reader: &mut WireReader<'_>) -> Result<Vec<IndexFragment>, Sch
                 6 => IndexKeyFragment::Year(field),
                 7 => IndexKeyFragment::Month(field),
                 8 => IndexKeyFragment::Day(field),
+                9 => IndexKeyFragment::Tokens(field),
+                10 => IndexKeyFragment::Elements(field),
                 _ => return Err(SchemaContractError::Decode),
             });
         }
         let unique = reader.read_bool()?;
-        let predicate = reader
-            .read_bool()?
+        let trailer = reader.read_u8()?;
+        if trailer & !(INDEX_TRAILER_PREDICATE | INDEX_TRAILER_INCLUDE | INDEX_TRAILER_DESCENDING)
+            != 0
+        {
+            return Err(SchemaContractError::Decode);
+        }
+        let predicate = (trailer & INDEX_TRAILER_PREDICATE != 0)
             .then(|| decode_expression(reader))
             .transpose()?;
-        indexes.push(IndexFragment::try_new(name, key, unique, predicate)?);
+        let include = if trailer & INDEX_TRAILER_INCLUDE == 0 {
+            Vec::new()
+        } else {
+            let include =
+                decode_field_keys(reader, "index include fields", MAX_INDEX_INCLUDE_FIELDS)?;
+            if include.is_empty() {
+                return Err(SchemaContractError::Decode);
+            }
+            include
+        };
+        let descending = if trailer & INDEX_TRAILER_DESCENDING == 0 {
+            Vec::new()
+        } else {
+            let descending = decode_field_keys(reader, "index descending fields", key_len)?;
+            if descending.is_empty() {
+                return Err(SchemaContractError::Decode);
+            }
+            descending
+        };
+        indexes.push(
+            IndexFragment::try_new(name, key, unique, predicate)?
+                .with_descending(descending)?
+                .with_include(include)?,
+        );
     }
     Ok(indexes)
 }
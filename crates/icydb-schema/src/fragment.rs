@@ -5,7 +5,8 @@ use std::collections::BTreeSet;
 use crate::{
     ConstraintSourceKey, Decimal, DeclaredEntityVersion, EntitySourceKey, FieldSourceKey,
     IndexSourceKey, MAX_FRAGMENT_CONSTRAINTS, MAX_FRAGMENT_ENTITIES, MAX_FRAGMENT_FIELDS,
-    MAX_FRAGMENT_INDEXES, MAX_FRAGMENT_RELATIONS, MAX_FRAGMENT_TYPES, MAX_SCHEMA_FIELD_TYPE_DEPTH,
+    MAX_FRAGMENT_INDEXES, MAX_FRAGMENT_RELATIONS, MAX_FRAGMENT_TYPES,
+    MAX_GENERATED_FIELD_EXPRESSION_BYTES, MAX_INDEX_INCLUDE_FIELDS, MAX_SCHEMA_FIELD_TYPE_DEPTH,
     RelationSourceKey, RuleSourceKey, ScalarKind, ScalarLiteral, SchemaContractError, SchemaName,
     SourceCheckExpr, SourceRuleOperation, TypeSourceKey,
 };
@@ -207,6 +208,9 @@ pub enum FieldInsertPolicy {
     Default(ScalarLiteral),
     /// IcyDB generates the value.
     Generated,
+    /// IcyDB stores the value of this row-local SQL scalar expression,
+    /// recomputed from the other fields on every insert and update.
+    Computed(String),
 }
 
 /// Accepted database-owned lifecycle policy.
@@ -301,6 +305,13 @@ impl FieldFragment {
         if matches!(self.insert_policy, FieldInsertPolicy::Nullable) && !self.nullable {
             return Err(SchemaContractError::InvalidFieldPolicy);
         }
+        if let FieldInsertPolicy::Computed(expression) = &self.insert_policy
+            && (expression.trim().is_empty()
+                || expression.len() > MAX_GENERATED_FIELD_EXPRESSION_BYTES
+                || !matches!(self.field_type, FieldType::Scalar(_)))
+        {
+            return Err(SchemaContractError::InvalidFieldPolicy);
+        }
         if self.management.is_some()
             && (!matches!(self.field_type, FieldType::Scalar(ScalarType::Timestamp))
                 || self.nullable
@@ -333,6 +344,10 @@ pub enum IndexKeyFragment {
     Month(FieldSourceKey),
     /// Day extraction expression.
     Day(FieldSourceKey),
+    /// Full-text token expression (one entry per distinct token).
+    Tokens(FieldSourceKey),
+    /// Collection element expression (one entry per distinct element).
+    Elements(FieldSourceKey),
 }
 
 impl IndexKeyFragment {
@@ -348,7 +363,9 @@ impl IndexKeyFragment {
             | Self::Date(field)
             | Self::Year(field)
             | Self::Month(field)
-            | Self::Day(field) => field,
+            | Self::Day(field)
+            | Self::Tokens(field)
+            | Self::Elements(field) => field,
         }
     }
 }
@@ -359,6 +376,8 @@ pub struct IndexFragment {
     source_key: IndexSourceKey,
     name: SchemaName,
     key: Vec<IndexKeyFragment>,
+    descending: Vec<FieldSourceKey>,
+    include: Vec<FieldSourceKey>,
     unique: bool,
     predicate: Option<SourceCheckExpr>,
 }
@@ -368,7 +387,9 @@ impl IndexFragment {
     ///
     /// # Errors
     ///
-    /// Returns a typed reference-list error when no key component is present.
+    /// Returns a typed reference-list error when no key component is present,
+    /// or when a token or element component is combined with other
+    /// components or uniqueness.
     pub fn try_new(
         name: SchemaName,
         key: Vec<IndexKeyFragment>,
@@ -378,6 +399,15 @@ impl IndexFragment {
         if key.is_empty() {
             return Err(SchemaContractError::InvalidReferenceList);
         }
+        if key.iter().any(|component| {
+            matches!(
+                component,
+                IndexKeyFragment::Tokens(_) | IndexKeyFragment::Elements(_)
+            )
+        }) && (key.len() != 1 || unique)
+        {
+            return Err(SchemaContractError::InvalidReferenceList);
+        }
         if let Some(predicate) = &predicate {
             predicate.validate()?;
         }
@@ -385,11 +415,72 @@ impl IndexFragment {
             source_key: IndexSourceKey::from_name(&name),
             name,
             key,
+            descending: Vec::new(),
+            include: Vec::new(),
             unique,
             predicate,
         })
     }
 
+    /// Mark direct field key components that sort in descending order.
+    ///
+    /// # Errors
+    ///
+    /// Returns a typed reference-list error when a descending field repeats,
+    /// is not a key component, or is attached to an index with expression key
+    /// components.
+    pub fn with_descending(
+        mut self,
+        descending: Vec<FieldSourceKey>,
+    ) -> Result<Self, SchemaContractError> {
+        if descending.is_empty() {
+            self.descending = descending;
+            return Ok(self);
+        }
+        ensure_unique(&descending)?;
+        if self
+            .key
+            .iter()
+            .any(|component| !matches!(component, IndexKeyFragment::Field(_)))
+            || descending
+                .iter()
+                .any(|field| !self.key.iter().any(|component| component.field() == field))
+        {
+            return Err(SchemaContractError::InvalidReferenceList);
+        }
+        self.descending = descending;
+        Ok(self)
+    }
+
+    /// Attach non-key payload columns carried by every index entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns a typed reference-list error when an included column repeats,
+    /// is already a key component, exceeds the include bound, or is attached
+    /// to an index with expression key components.
+    pub fn with_include(
+        mut self,
+        include: Vec<FieldSourceKey>,
+    ) -> Result<Self, SchemaContractError> {
+        if include.is_empty() {
+            self.include = include;
+            return Ok(self);
+        }
+        if include.len() > MAX_INDEX_INCLUDE_FIELDS {
+            return Err(SchemaContractError::InvalidReferenceList);
+        }
+        ensure_unique(&include)?;
+        if self.key.iter().any(|component| match component {
+            IndexKeyFragment::Field(field) => include.contains(field),
+            _ => true,
+        }) {
+            return Err(SchemaContractError::InvalidReferenceList);
+        }
+        self.include = include;
+        Ok(self)
+    }
+
     /// Borrow the typed proposal key derived from the current index name.
     #[must_use]
     pub const fn source_key(&self) -> &IndexSourceKey {
@@ -408,6 +499,18 @@ impl IndexFragment {
         &self.key
     }
 
+    /// Borrow direct field key components that sort in descending order.
+    #[must_use]
+    pub fn descending(&self) -> &[FieldSourceKey] {
+        &self.descending
+    }
+
+    /// Borrow ordered non-key payload columns.
+    #[must_use]
+    pub fn include(&self) -> &[FieldSourceKey] {
+        &self.include
+    }
+
     /// Return whether the index enforces uniqueness.
     #[must_use]
     pub const fn unique(&self) -> bool {
@@ -426,7 +529,9 @@ impl IndexFragment {
             self.key.clone(),
             self.unique,
             self.predicate.clone(),
-        )?;
+        )?
+        .with_descending(self.descending.clone())?
+        .with_include(self.include.clone())?;
         ensure_canonical_rebuild(self, &rebuilt)
     }
 }
@@ -741,20 +846,11 @@ impl EntityFragment {
             return Err(SchemaContractError::InvalidLocalReference);
         }
         validate_insert_generation(&fields, &primary_key)?;
-        for index in &indexes {
-            if index
-                .key()
-                .iter()
-                .any(|component| !field_keys.contains(component.field()))
-                || index.predicate().is_some_and(|predicate| {
-                    predicate
-                        .dependencies()
-                        .iter()
-                        .any(|field| !field_keys.contains(field))
-                })
-            {
-                return Err(SchemaContractError::InvalidLocalReference);
-            }
+        if indexes
+            .iter()
+            .any(|index| !index_references_local_fields(index, &field_keys))
+        {
+            return Err(SchemaContractError::InvalidLocalReference);
         }
         for relation in &relations {
             if relation
@@ -859,11 +955,17 @@ impl EntityFragment {
 // Keep the public proposal contract exact even when fragments are constructed
 // without the source macro. Numeric generation is unsigned identity
 // generation and therefore belongs only to one non-null scalar primary key.
+// Computed values derive from the row they identify, so they never key it.
 fn validate_insert_generation(
     fields: &[FieldFragment],
     primary_key: &[FieldSourceKey],
 ) -> Result<(), SchemaContractError> {
     for field in fields {
+        if matches!(field.insert_policy(), FieldInsertPolicy::Computed(_))
+            && primary_key.contains(field.source_key())
+        {
+            return Err(SchemaContractError::InvalidFieldPolicy);
+        }
         if !matches!(field.insert_policy(), FieldInsertPolicy::Generated) {
             continue;
         }
@@ -1409,6 +1511,28 @@ fn ensure_canonical_rebuild<T: PartialEq>(
     Ok(())
 }
 
+// Return whether every field an index keys, includes, or filters on is a
+// field of the owning entity.
+fn index_references_local_fields(
+    index: &IndexFragment,
+    field_keys: &BTreeSet<FieldSourceKey>,
+) -> bool {
+    index
+        .key()
+        .iter()
+        .all(|component| field_keys.contains(component.field()))
+        && index
+            .include()
+            .iter()
+            .all(|field| field_keys.contains(field))
+        && !index.predicate().is_some_and(|predicate| {
+            predicate
+                .dependencies()
+                .iter()
+                .any(|field| !field_keys.contains(field))
+        })
+}
+
 fn ensure_unique<T>(values: &[T]) -> Result<(), SchemaContractError>
 where
     T: Ord,
@@ -428,6 +428,9 @@ const fn is_v1_cast_target(target: ScalarType) -> bool {
             | ScalarType::Nat64
             | ScalarType::Nat128
             | ScalarType::Decimal { .. }
+            | ScalarType::Text { max_len: None }
+            | ScalarType::Timestamp
+            | ScalarType::Ulid
     )
 }
 
@@ -3,7 +3,8 @@ use crate::{
     DeclaredEntityVersion, Duration, EntityFragment, EntityMigration, EntitySourceKey,
     EntityStoreAssignment, EnumTypeFragment, EnumVariantFragment, ExpectedAcceptedHead,
     ExpectedSchemaFingerprint, FieldFragment, FieldInsertPolicy, FieldSourceKey, FieldType,
-    Float32, Float64, IntBig, MAX_SCHEMA_FIELD_TYPE_DEPTH, MAX_SCHEMA_PROPOSAL_BYTES,
+    Float32, Float64, IndexFragment, IndexKeyFragment, IntBig,
+    MAX_GENERATED_FIELD_EXPRESSION_BYTES, MAX_SCHEMA_FIELD_TYPE_DEPTH, MAX_SCHEMA_PROPOSAL_BYTES,
     MAX_SOURCE_KEY_BYTES, NamedTypeFragment, NatBig, ProposalContractVersion, RecordFieldFragment,
     RecordTypeFragment, RelationDeleteAction, RelationFragment, RuleSourceKey, ScalarLiteral,
     ScalarType, SchemaCapability, SchemaContractError, SchemaFragment, SchemaMigrationPlan,
@@ -489,6 +490,157 @@ fn named_collection_fragments_roundtrip_canonically() {
     );
 }
 
+#[test]
+fn index_include_columns_are_closed_and_roundtrip_canonically() {
+    let id_key = source("id", FieldSourceKey::try_new);
+    let score_key = source("score", FieldSourceKey::try_new);
+    let name_key = source("name", FieldSourceKey::try_new);
+    let missing_key = source("missing", FieldSourceKey::try_new);
+    let field = |name: &str, field_type| {
+        FieldFragment::new(
+            SchemaName::try_new(name).expect("name should admit"),
+            field_type,
+            false,
+            FieldInsertPolicy::Required,
+            None,
+        )
+    };
+    let fields = vec![
+        field("id", FieldType::Scalar(ScalarType::Nat64)),
+        field("score", FieldType::Scalar(ScalarType::Int64)),
+        field(
+            "name",
+            FieldType::Scalar(ScalarType::Text { max_len: None }),
+        ),
+    ];
+    let by_score = || {
+        IndexFragment::try_new(
+            SchemaName::try_new("by_score").expect("name should admit"),
+            vec![IndexKeyFragment::Field(score_key.clone())],
+            false,
+            None,
+        )
+        .expect("index should admit")
+    };
+    let entity = |index: IndexFragment| {
+        EntityFragment::try_new(
+            SchemaName::try_new("Player").expect("name should admit"),
+            version_one(),
+            fields.clone(),
+            vec![id_key.clone()],
+            vec![index],
+            Vec::new(),
+            Vec::new(),
+        )
+    };
+
+    assert_eq!(
+        by_score().with_include(vec![score_key.clone()]),
+        Err(SchemaContractError::InvalidReferenceList),
+        "a key component cannot also be an included column",
+    );
+    assert_eq!(
+        by_score().with_include(vec![name_key.clone(), name_key.clone()]),
+        Err(SchemaContractError::InvalidReferenceList),
+    );
+    assert_eq!(
+        entity(
+            by_score()
+                .with_include(vec![missing_key])
+                .expect("include list should admit")
+        ),
+        Err(SchemaContractError::InvalidLocalReference),
+    );
+
+    let covering = by_score()
+        .with_include(vec![name_key.clone()])
+        .expect("include list should admit");
+    assert_eq!(covering.include(), std::slice::from_ref(&name_key));
+    let fragment = SchemaFragment::try_new(
+        vec![entity(covering).expect("entity should admit")],
+        Vec::new(),
+    )
+    .expect("fragment should admit");
+    let bytes = encode_schema_fragment(&fragment).expect("fragment should encode");
+    assert_eq!(
+        decode_schema_fragment(&bytes).expect("fragment should decode"),
+        fragment,
+    );
+}
+
+#[test]
+fn index_descending_keys_are_closed_and_roundtrip_canonically() {
+    let id_key = source("id", FieldSourceKey::try_new);
+    let score_key = source("score", FieldSourceKey::try_new);
+    let name_key = source("name", FieldSourceKey::try_new);
+    let field = |name: &str, field_type| {
+        FieldFragment::new(
+            SchemaName::try_new(name).expect("name should admit"),
+            field_type,
+            false,
+            FieldInsertPolicy::Required,
+            None,
+        )
+    };
+    let index = |key: Vec<IndexKeyFragment>| {
+        IndexFragment::try_new(
+            SchemaName::try_new("by_score").expect("name should admit"),
+            key,
+            false,
+            None,
+        )
+        .expect("index should admit")
+    };
+
+    assert_eq!(
+        index(vec![IndexKeyFragment::Field(score_key.clone())])
+            .with_descending(vec![name_key.clone()]),
+        Err(SchemaContractError::InvalidReferenceList),
+        "descending fields must name direct key components",
+    );
+    assert_eq!(
+        index(vec![
+            IndexKeyFragment::Lower(name_key),
+            IndexKeyFragment::Field(score_key.clone()),
+        ])
+        .with_descending(vec![score_key.clone()]),
+        Err(SchemaContractError::InvalidReferenceList),
+        "descending keys require a field-only index",
+    );
+
+    let leaderboard = index(vec![
+        IndexKeyFragment::Field(score_key.clone()),
+        IndexKeyFragment::Field(id_key.clone()),
+    ])
+    .with_descending(vec![score_key.clone()])
+    .expect("descending list should admit");
+    assert_eq!(leaderboard.descending(), std::slice::from_ref(&score_key));
+    let fragment = SchemaFragment::try_new(
+        vec![
+            EntityFragment::try_new(
+                SchemaName::try_new("Player").expect("name should admit"),
+                version_one(),
+                vec![
+                    field("id", FieldType::Scalar(ScalarType::Nat64)),
+                    field("score", FieldType::Scalar(ScalarType::Int64)),
+                ],
+                vec![id_key],
+                vec![leaderboard],
+                Vec::new(),
+                Vec::new(),
+            )
+            .expect("entity should admit"),
+        ],
+        Vec::new(),
+    )
+    .expect("fragment should admit");
+    let bytes = encode_schema_fragment(&fragment).expect("fragment should encode");
+    assert_eq!(
+        decode_schema_fragment(&bytes).expect("fragment should decode"),
+        fragment,
+    );
+}
+
 #[test]
 fn repeated_field_shape_rejects_excessive_inline_depth() {
     let mut field_type = FieldType::Scalar(ScalarType::Nat64);
@@ -540,6 +692,13 @@ fn entity_with_default(
 
 fn entity_with_non_primary_generated_field(
     field_type: FieldType,
+) -> Result<EntityFragment, SchemaContractError> {
+    entity_with_non_primary_field(field_type, FieldInsertPolicy::Generated)
+}
+
+fn entity_with_non_primary_field(
+    field_type: FieldType,
+    insert_policy: FieldInsertPolicy,
 ) -> Result<EntityFragment, SchemaContractError> {
     let id = source("id", FieldSourceKey::try_new);
     EntityFragment::try_new(
@@ -557,7 +716,7 @@ fn entity_with_non_primary_generated_field(
                 SchemaName::try_new("value").expect("name should admit"),
                 field_type,
                 false,
-                FieldInsertPolicy::Generated,
+                insert_policy,
                 None,
             ),
         ],
@@ -790,6 +949,44 @@ fn generated_policy_rejects_nullable_or_collection_identity_shapes() {
     );
 }
 
+#[test]
+fn computed_policy_admits_bounded_non_key_scalar_expressions() {
+    let computed = |expression: &str| FieldInsertPolicy::Computed(expression.to_string());
+    let entity = entity_with_non_primary_field(
+        FieldType::Scalar(ScalarType::Int64),
+        computed("price * quantity"),
+    )
+    .expect("non-key computed scalar should admit");
+    let fragment =
+        SchemaFragment::try_new(vec![entity], Vec::new()).expect("fragment should admit");
+    let bytes = encode_schema_fragment(&fragment).expect("fragment should encode");
+    assert_eq!(
+        decode_schema_fragment(&bytes).expect("fragment should decode"),
+        fragment,
+    );
+
+    for (field_type, expression) in [
+        (FieldType::Scalar(ScalarType::Int64), " ".to_string()),
+        (
+            FieldType::Scalar(ScalarType::Int64),
+            "1".repeat(MAX_GENERATED_FIELD_EXPRESSION_BYTES + 1),
+        ),
+        (
+            FieldType::List(Box::new(FieldType::Scalar(ScalarType::Int64))),
+            "price".to_string(),
+        ),
+    ] {
+        assert_eq!(
+            entity_with_non_primary_field(field_type, FieldInsertPolicy::Computed(expression)),
+            Err(SchemaContractError::InvalidFieldPolicy),
+        );
+    }
+    assert_eq!(
+        entity_with_field(FieldType::Scalar(ScalarType::Int64), computed("1 + 1")),
+        Err(SchemaContractError::InvalidFieldPolicy),
+    );
+}
+
 #[test]
 fn one_entity_cannot_duplicate_a_managed_timestamp_policy() {
     let id = source("id", FieldSourceKey::try_new);
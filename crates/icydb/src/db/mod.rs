@@ -43,8 +43,6 @@ pub use icydb_core::db::{
 };
 #[cfg(feature = "sql")]
 pub use response::ExecutionTrace;
-#[cfg(feature = "sql")]
-pub use session::SqlIntegrityError;
 pub use session::{
     DbSession, ExhaustiveReadError, IntegrityCheckError, OutputRow, RequestExecutionFuture,
     RequestExecutionRoot, StructuralMutation, StructuralPatch, TrustedTypedWriteBatch,
@@ -53,6 +51,8 @@ pub use session::{
     TypedWriteHandle, WriteCell, with_request_execution, with_request_execution_async,
     with_request_execution_root,
 };
+#[cfg(feature = "sql")]
+pub use session::{PreparedSql, SqlIntegrityError};
 pub use startup::{
     __clear_generated_startup_failure, __install_startup_recovery_wakeup,
     __observe_generated_startup_state, __record_generated_schema_startup_failure,
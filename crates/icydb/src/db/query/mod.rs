@@ -10,18 +10,19 @@
 mod typed;
 
 pub use typed::{
-    ExhaustivePage, LivePage, MAX_TYPED_EXACT_KEY_BATCH_INPUT_BYTES,
-    MAX_TYPED_EXACT_KEY_BATCH_ITEMS, MAX_TYPED_EXACT_KEY_BATCH_RESULT_BYTES,
-    MAX_TYPED_EXACT_KEY_BATCH_STORED_BYTES, Query, TypedExhaustiveQueryError, TypedQueryError,
+    ExhaustivePage, IncludeQuery, IncludedLivePage, IncludedRow, LivePage,
+    MAX_TYPED_EXACT_KEY_BATCH_INPUT_BYTES, MAX_TYPED_EXACT_KEY_BATCH_ITEMS,
+    MAX_TYPED_EXACT_KEY_BATCH_RESULT_BYTES, MAX_TYPED_EXACT_KEY_BATCH_STORED_BYTES, Query,
+    TypedExhaustiveQueryError, TypedQueryError,
 };
 
 pub use icydb_core::db::{
     AggregateExpr, CompareOp, ExplainAccessCandidate, ExplainAccessDecision,
     ExplainAccessDecisionKind, ExplainEligibleAlternative, ExplainPlan, ExplainRejectedIndex,
-    ExplainResidualSummary, ExplainSelectedAccess, FieldRef, FilterExpr, FilterValue,
-    MissingRowPolicy, NumericProjectionExpr, OrderDirection, OrderExpr, OrderTerm,
-    RoundProjectionExpr, TextProjectionExpr, TraceReuseEvent, ValueProjectionExpr, add, asc, avg,
-    contains, count, count_by, desc, div, ends_with, exists, field, first, last, left, length,
-    lower, ltrim, max, max_by, min, min_by, mul, position, replace, right, round, round_expr,
-    rtrim, starts_with, sub, substring, substring_with_length, sum, trim, upper,
+    ExplainResidualSummary, ExplainSelectedAccess, FieldRef, FilterExpr, FilterValue, GroupExpr,
+    MapKeyRef, MissingRowPolicy, NullsOrder, NumericProjectionExpr, OrderDirection, OrderExpr,
+    OrderTerm, RoundProjectionExpr, TextProjectionExpr, TraceReuseEvent, ValueProjectionExpr, add,
+    asc, avg, contains, count, count_by, desc, div, ends_with, exists, field, first, last, left,
+    length, lower, ltrim, max, max_by, min, min_by, mul, position, replace, right, round,
+    round_expr, rtrim, starts_with, sub, substring, substring_with_length, sum, trim, upper,
 };
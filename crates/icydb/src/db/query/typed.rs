@@ -14,7 +14,8 @@ use crate::{
     types::Id,
 };
 use candid::CandidType;
-use icydb_core::db::{AggregateExpr, FilterExpr, OrderTerm};
+use icydb_core::db::{AggregateExpr, FilterExpr, GroupExpr, OrderTerm};
+use icydb_model::TypedOutputValue;
 use serde::Deserialize;
 use std::{error::Error as StdError, fmt, marker::PhantomData};
 
@@ -38,6 +39,18 @@ pub struct LivePage<Row> {
     pub work: crate::db::ScalarPageWork,
 }
 
+/// One typed row paired with the target of one included relation.
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct IncludedRow<Row, Related> {
+    /// Decoded source row.
+    pub row: Row,
+    /// Decoded related row, or `None` for a null key or a missing target.
+    pub related: Option<Related>,
+}
+
+/// One revision-tolerant bounded typed page with one included relation.
+pub type IncludedLivePage<Row, Related> = LivePage<IncludedRow<Row, Related>>;
+
 /// One revision-strict bounded typed page.
 #[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
 pub struct ExhaustivePage<Row> {
@@ -163,6 +176,14 @@ where
         self
     }
 
+    /// Append one deterministic scalar expression to the grouped key in
+    /// declaration order.
+    #[must_use]
+    pub fn group_by_expr(mut self, expr: impl Into<GroupExpr>) -> Self {
+        self.request = self.request.group_by_expr(expr);
+        self
+    }
+
     /// Append one grouped aggregate in declaration order.
     #[must_use]
     pub fn aggregate(mut self, aggregate: AggregateExpr) -> Self {
@@ -184,6 +205,25 @@ where
         self
     }
 
+    /// Load the target of one single-valued relation field with each page.
+    ///
+    /// `relation` names an accepted relation field of `E` whose target is
+    /// `T`. Each page issues one [`DbSession::get_many`] read over its distinct
+    /// relation keys, so the page limit must stay within
+    /// [`MAX_TYPED_EXACT_KEY_BATCH_ITEMS`]. An explicit selection must keep
+    /// the relation field.
+    #[must_use]
+    pub fn include<T>(self, relation: impl Into<String>) -> IncludeQuery<'session, C, E, T>
+    where
+        T: EntityKey + TypedEntityAdapter,
+    {
+        IncludeQuery {
+            query: self,
+            relation: relation.into(),
+            target: PhantomData,
+        }
+    }
+
     /// Return exact visible cardinality without scanning rows.
     ///
     /// This terminal accepts a bare entity query or one strict equality or
@@ -295,6 +335,124 @@ where
     }
 }
 
+///
+/// IncludeQuery
+///
+/// Typed live-page read that attaches the target row of one single-valued
+/// relation to every source row. Target rows are read after the source page
+/// through the bounded exact-key lane; relation loading never joins inside
+/// the planner.
+///
+pub struct IncludeQuery<'session, C, E, T>
+where
+    C: CanisterKind,
+    E: TypedEntityAdapter,
+{
+    query: Query<'session, C, E>,
+    relation: String,
+    target: PhantomData<fn() -> T>,
+}
+
+impl<C, E, T> IncludeQuery<'_, C, E, T>
+where
+    C: CanisterKind,
+    E: TypedEntityAdapter,
+    T: EntityKey + TypedEntityAdapter,
+    T::Key: TypedOutputValue,
+    T::Row: Clone,
+{
+    /// Execute one revision-tolerant bounded page with its included targets.
+    ///
+    /// Missing targets and null relation keys attach `None`.
+    pub fn execute_live_page(
+        self,
+        continuation: Option<&str>,
+    ) -> Result<IncludedLivePage<E::Row, T::Row>, TypedQueryError> {
+        let Self {
+            query, relation, ..
+        } = self;
+        let session = query.session;
+        let target = T::typed_binding(session).map_err(typed_query_error_from_binding)?;
+        let is_relation = session
+            .typed_relation_targets(&query.binding, relation.as_str(), &target)
+            .map_err(TypedQueryError::Database)?
+            .ok_or({
+                TypedQueryError::Row(TypedRowError::Adapter(
+                    crate::db::TypedAdapterError::StaleBinding,
+                ))
+            })?;
+        if !is_relation {
+            return Err(TypedQueryError::Row(TypedRowError::Adapter(
+                crate::db::TypedAdapterError::FieldUnavailable,
+            )));
+        }
+        let result = session
+            .execute_public_typed_live_page(&query.binding, &query.request, continuation)
+            .map_err(TypedQueryError::Database)?
+            .ok_or({
+                TypedQueryError::Row(TypedRowError::Adapter(
+                    crate::db::TypedAdapterError::StaleBinding,
+                ))
+            })?;
+        let column = result
+            .columns
+            .iter()
+            .position(|column| *column == relation)
+            .ok_or({
+                TypedQueryError::Row(TypedRowError::Adapter(
+                    crate::db::TypedAdapterError::RowFieldUnavailable,
+                ))
+            })?;
+
+        // Phase 1: decode source rows and the relation key each one carries.
+        let mut rows = Vec::with_capacity(result.rows.len());
+        let mut ids = Vec::with_capacity(result.rows.len());
+        for (row_index, values) in result.rows.iter().enumerate() {
+            let row = session
+                .typed_live_page_row(&query.binding, &result, row_index)
+                .map_err(TypedQueryError::Row)?;
+            rows.push(
+                E::decode_row(&query.binding, row)
+                    .map_err(|error| TypedQueryError::Row(TypedRowError::Adapter(error)))?,
+            );
+            let value = values.get(column).ok_or({
+                TypedQueryError::Row(TypedRowError::Adapter(
+                    crate::db::TypedAdapterError::RowShapeMismatch,
+                ))
+            })?;
+            let key = <Option<T::Key>>::decode_typed_output(&query.binding, value)
+                .map_err(|error| TypedQueryError::Row(TypedRowError::Adapter(error.into())))?;
+            ids.push(key.map(Id::<T>::from_key));
+        }
+
+        // Phase 2: read every distinct target once through one exact-key batch.
+        let mut distinct = ids.iter().flatten().copied().collect::<Vec<_>>();
+        distinct.sort_unstable();
+        distinct.dedup();
+        let targets = if distinct.is_empty() {
+            Vec::new()
+        } else {
+            session.get_many::<T>(distinct.as_slice())?
+        };
+        let rows = rows
+            .into_iter()
+            .zip(ids)
+            .map(|(row, id)| IncludedRow {
+                row,
+                related: id
+                    .and_then(|id| distinct.binary_search(&id).ok())
+                    .and_then(|index| targets.get(index).cloned().flatten()),
+            })
+            .collect();
+
+        Ok(LivePage {
+            rows,
+            continuation: result.continuation,
+            work: result.work,
+        })
+    }
+}
+
 impl<C: CanisterKind> DbSession<C> {
     /// Start one typed read bound to current accepted schema authority.
     pub fn query<E>(&self) -> Result<Query<'_, C, E>, TypedBindingError>
@@ -373,7 +373,10 @@ fn generated_fragment_capabilities(
                 icydb_schema::FieldType::Named(_) | icydb_schema::FieldType::List(_)
             );
             insert_defaults |= matches!(field.insert_policy(), FieldInsertPolicy::Default(_));
-            generated_values |= matches!(field.insert_policy(), FieldInsertPolicy::Generated);
+            generated_values |= matches!(
+                field.insert_policy(),
+                FieldInsertPolicy::Generated | FieldInsertPolicy::Computed(_)
+            );
             managed_timestamps |= field.management().is_some();
         }
     }
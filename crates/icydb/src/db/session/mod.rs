@@ -26,6 +26,8 @@ pub use request::{
     with_request_execution_async, with_request_execution_root,
 };
 #[cfg(feature = "sql")]
+pub use sql::PreparedSql;
+#[cfg(feature = "sql")]
 pub use sql::{
     SqlExecutionPerfAttribution, SqlPureCoveringPerfAttribution, SqlQueryPerfAttribution,
 };
@@ -5,13 +5,44 @@
 //! Boundary: wraps core SQL execution with public response conversion.
 
 use crate::{
-    db::{session::DbSession, sql::SqlQueryResult},
+    db::{
+        session::DbSession,
+        sql::{SqlQueryRequest, SqlQueryResult},
+    },
     error::Error,
     traits::CanisterKind,
+    value::InputValue,
 };
 
 use icydb_core as core;
 
+///
+/// PreparedSql
+///
+/// One trusted SQL query prepared once for repeated execution with `$1..$n`
+/// bind values. Obtain it from `DbSession::prepare_trusted_sql_query`.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PreparedSql {
+    inner: core::db::PreparedSqlQuery,
+    entity: String,
+}
+
+impl PreparedSql {
+    /// Borrow the parameterized SQL text this handle executes.
+    #[must_use]
+    pub fn sql(&self) -> &str {
+        self.inner.sql()
+    }
+
+    /// Return how many bind values each execution must supply.
+    #[must_use]
+    pub const fn parameter_count(&self) -> usize {
+        self.inner.parameter_count()
+    }
+}
+
 /// SQL query attribution envelope used by generated canister endpoints.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct SqlQueryPerfAttribution {
@@ -123,6 +154,175 @@ impl<C: CanisterKind> DbSession<C> {
         Ok(Self::sql_query_result_from_statement(result, entity))
     }
 
+    /// Execute one trusted/admin reduced SQL query as one resumable page.
+    ///
+    /// Scalar `SELECT ... ORDER BY ... LIMIT n` without `OFFSET` uses `n` as
+    /// the page size and returns `ProjectionPage` with a `next_cursor` while
+    /// rows remain. Pass that cursor back with the same statement to resume.
+    /// Cursors follow live-state keyset semantics, not snapshot isolation.
+    pub fn execute_trusted_sql_query_page(
+        &self,
+        sql: &str,
+        cursor: Option<&str>,
+    ) -> Result<SqlQueryResult, Error> {
+        let (result, entity) = self
+            .inner
+            .execute_trusted_sql_query_page_with_entity_name(sql, cursor)?;
+        Ok(Self::sql_query_result_from_statement(result, entity))
+    }
+
+    /// Prepare one trusted/admin reduced SQL query that uses `$1..$n`
+    /// placeholders.
+    ///
+    /// Placeholders may appear in scalar expression positions such as
+    /// comparison operands, projections, and function arguments. `IN (...)`
+    /// lists and `LIKE`/`~` patterns still require literals. Executions reuse
+    /// the compiled command when bound with the same parameter values.
+    pub fn prepare_trusted_sql_query(&self, sql: &str) -> Result<PreparedSql, Error> {
+        let entity = core::db::sql_statement_entity_name(sql)?.unwrap_or_default();
+        let inner = self.inner.prepare_trusted_sql_query(sql)?;
+
+        Ok(PreparedSql { inner, entity })
+    }
+
+    /// Execute one prepared SQL query with one bind value per placeholder.
+    ///
+    /// `params[0]` binds `$1`. Bind enum fields with the variant name as
+    /// `InputValue::Text`, the same form SQL enum literals use.
+    pub fn execute_prepared_sql_query(
+        &self,
+        prepared: &PreparedSql,
+        params: Vec<InputValue>,
+    ) -> Result<SqlQueryResult, Error> {
+        let result = self
+            .inner
+            .execute_prepared_sql_query(&prepared.inner, params)?;
+        Ok(Self::sql_query_result_from_statement(
+            result,
+            prepared.entity.clone(),
+        ))
+    }
+
+    /// Execute one prepared SQL query as one resumable page.
+    ///
+    /// A cursor only resumes the same statement executed with the same bind
+    /// values.
+    pub fn execute_prepared_sql_query_page(
+        &self,
+        prepared: &PreparedSql,
+        params: Vec<InputValue>,
+        cursor: Option<&str>,
+    ) -> Result<SqlQueryResult, Error> {
+        let result = self
+            .inner
+            .execute_prepared_sql_query_page(&prepared.inner, params, cursor)?;
+        Ok(Self::sql_query_result_from_statement(
+            result,
+            prepared.entity.clone(),
+        ))
+    }
+
+    /// Execute one generated-endpoint SQL query request and return the shell
+    /// perf envelope shape.
+    ///
+    /// Requests without bind values keep the plain text lane; requests with
+    /// bind values resolve the parameterized template before binding. Only
+    /// requests that ask for a page or carry a cursor execute as one
+    /// resumable page; all others return one unpaged result.
+    #[doc(hidden)]
+    pub fn execute_trusted_sql_query_request_with_perf_attribution(
+        &self,
+        request: SqlQueryRequest,
+    ) -> Result<(SqlQueryResult, SqlQueryPerfAttribution), Error> {
+        let paged = request.is_paged();
+        let SqlQueryRequest {
+            sql,
+            params,
+            cursor,
+            ..
+        } = request;
+        match (params.is_empty(), paged) {
+            (true, false) => self.execute_trusted_sql_query_with_perf_attribution(sql.as_str()),
+            (true, true) => self.execute_trusted_sql_query_page_with_perf_attribution(
+                sql.as_str(),
+                cursor.as_deref(),
+            ),
+            (false, false) => self
+                .execute_trusted_sql_query_with_params_with_perf_attribution(sql.as_str(), params),
+            (false, true) => self.execute_trusted_sql_query_with_params_page_with_perf_attribution(
+                sql.as_str(),
+                params,
+                cursor.as_deref(),
+            ),
+        }
+    }
+
+    #[cfg(not(feature = "diagnostics"))]
+    fn execute_trusted_sql_query_with_params_with_perf_attribution(
+        &self,
+        sql: &str,
+        params: Vec<InputValue>,
+    ) -> Result<(SqlQueryResult, SqlQueryPerfAttribution), Error> {
+        let (result, entity) = self
+            .inner
+            .execute_trusted_sql_query_with_params_with_entity_name(sql, params)?;
+
+        Ok((
+            Self::sql_query_result_from_statement(result, entity),
+            SqlQueryPerfAttribution::default(),
+        ))
+    }
+
+    #[cfg(feature = "diagnostics")]
+    fn execute_trusted_sql_query_with_params_with_perf_attribution(
+        &self,
+        sql: &str,
+        params: Vec<InputValue>,
+    ) -> Result<(SqlQueryResult, SqlQueryPerfAttribution), Error> {
+        let entity = core::db::sql_statement_entity_name(sql)?.unwrap_or_default();
+        let (result, attribution) = self
+            .inner
+            .execute_trusted_sql_query_with_params_with_attribution(sql, params)?;
+        let (result, attribution) =
+            Self::finish_trusted_sql_query_attribution(result, entity, attribution);
+
+        Ok((result, SqlQueryPerfAttribution::from(attribution)))
+    }
+
+    #[cfg(not(feature = "diagnostics"))]
+    fn execute_trusted_sql_query_with_params_page_with_perf_attribution(
+        &self,
+        sql: &str,
+        params: Vec<InputValue>,
+        cursor: Option<&str>,
+    ) -> Result<(SqlQueryResult, SqlQueryPerfAttribution), Error> {
+        let (result, entity) = self
+            .inner
+            .execute_trusted_sql_query_with_params_page_with_entity_name(sql, params, cursor)?;
+
+        Ok((
+            Self::sql_query_result_from_statement(result, entity),
+            SqlQueryPerfAttribution::default(),
+        ))
+    }
+
+    #[cfg(feature = "diagnostics")]
+    fn execute_trusted_sql_query_with_params_page_with_perf_attribution(
+        &self,
+        sql: &str,
+        params: Vec<InputValue>,
+        cursor: Option<&str>,
+    ) -> Result<(SqlQueryResult, SqlQueryPerfAttribution), Error> {
+        let entity = core::db::sql_statement_entity_name(sql)?.unwrap_or_default();
+        let (result, attribution) = self
+            .inner
+            .execute_trusted_sql_query_with_params_page_with_attribution(sql, params, cursor)?;
+        let (result, attribution) =
+            Self::finish_trusted_sql_query_attribution(result, entity, attribution);
+
+        Ok((result, SqlQueryPerfAttribution::from(attribution)))
+    }
+
     /// Execute one trusted/admin SQL query and return the shell perf envelope shape.
     ///
     /// This helper is used by generated authorized SQL surfaces and keeps the
@@ -139,6 +339,21 @@ impl<C: CanisterKind> DbSession<C> {
         ))
     }
 
+    /// Execute one trusted/admin SQL query page and return the shell perf
+    /// envelope shape.
+    #[cfg(not(feature = "diagnostics"))]
+    #[doc(hidden)]
+    pub fn execute_trusted_sql_query_page_with_perf_attribution(
+        &self,
+        sql: &str,
+        cursor: Option<&str>,
+    ) -> Result<(SqlQueryResult, SqlQueryPerfAttribution), Error> {
+        Ok((
+            self.execute_trusted_sql_query_page(sql, cursor)?,
+            SqlQueryPerfAttribution::default(),
+        ))
+    }
+
     /// Execute one trusted/admin SQL query and return the shell perf envelope shape.
     ///
     /// This helper is used by generated authorized SQL surfaces and keeps the
@@ -154,6 +369,25 @@ impl<C: CanisterKind> DbSession<C> {
         Ok((result, SqlQueryPerfAttribution::from(attribution)))
     }
 
+    /// Execute one trusted/admin SQL query page and return the shell perf
+    /// envelope shape.
+    #[cfg(feature = "diagnostics")]
+    #[doc(hidden)]
+    pub fn execute_trusted_sql_query_page_with_perf_attribution(
+        &self,
+        sql: &str,
+        cursor: Option<&str>,
+    ) -> Result<(SqlQueryResult, SqlQueryPerfAttribution), Error> {
+        let entity = core::db::sql_statement_entity_name(sql)?.unwrap_or_default();
+        let (result, attribution) = self
+            .inner
+            .execute_trusted_sql_query_page_with_attribution(sql, cursor)?;
+        let (result, attribution) =
+            Self::finish_trusted_sql_query_attribution(result, entity, attribution);
+
+        Ok((result, SqlQueryPerfAttribution::from(attribution)))
+    }
+
     /// Execute one trusted/admin reduced SQL query and report the top-level
     /// compile/execute cost split at the SQL seam.
     ///
@@ -166,16 +400,32 @@ impl<C: CanisterKind> DbSession<C> {
         sql: &str,
     ) -> Result<(SqlQueryResult, crate::db::SqlQueryExecutionAttribution), Error> {
         let entity = core::db::sql_statement_entity_name(sql)?.unwrap_or_default();
-        let (result, mut attribution) =
-            self.inner.execute_trusted_sql_query_with_attribution(sql)?;
+        let (result, attribution) = self.inner.execute_trusted_sql_query_with_attribution(sql)?;
+
+        Ok(Self::finish_trusted_sql_query_attribution(
+            result,
+            entity,
+            attribution,
+        ))
+    }
+
+    // Package one executed trusted SQL statement and fold the measured
+    // response-decode phase into its attribution.
+    #[cfg(feature = "diagnostics")]
+    fn finish_trusted_sql_query_attribution(
+        result: core::db::SqlStatementResult,
+        entity: String,
+        attribution: crate::db::SqlQueryExecutionAttribution,
+    ) -> (SqlQueryResult, crate::db::SqlQueryExecutionAttribution) {
         let (response_decode_local_instructions, result) =
             measure_sql_response_decode_stage(|| {
                 Self::sql_query_result_from_statement(result, entity)
             });
-        attribution =
-            finalize_trusted_sql_query_attribution(attribution, response_decode_local_instructions);
 
-        Ok((result, attribution))
+        (
+            result,
+            finalize_trusted_sql_query_attribution(attribution, response_decode_local_instructions),
+        )
     }
 
     /// Execute one trusted SQL `INSERT` or `DELETE` against one entity type.
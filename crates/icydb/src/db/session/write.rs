@@ -1035,6 +1035,19 @@ impl<C: CanisterKind> DbSession<C> {
             .execute_trusted_dynamic_insert_batch(entity, patches)?)
     }
 
+    // Check that one accepted field of a current source binding is a
+    // single-valued relation to the entity bound by `target`.
+    pub(crate) fn typed_relation_targets(
+        &self,
+        binding: &TypedEntityBinding,
+        relation: &str,
+        target: &TypedEntityBinding,
+    ) -> Result<Option<bool>, Error> {
+        self.inner
+            .typed_binding_relation_targets(&binding.inner, relation, &target.inner)
+            .map_err(Error::from)
+    }
+
     fn typed_output_row(
         &self,
         binding: &TypedEntityBinding,
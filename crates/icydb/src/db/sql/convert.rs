@@ -12,7 +12,10 @@ use crate::db::{
     },
 };
 
-use icydb_core::db::{GroupedRow, SqlStatementResult};
+use icydb_core::{
+    db::{GroupedRow, SqlDdlPreparationReport, SqlStatementResult},
+    value::OutputValue,
+};
 
 pub(crate) fn sql_query_result_from_statement(
     result: SqlStatementResult,
@@ -28,18 +31,40 @@ pub(crate) fn sql_query_result_from_statement(
             fixed_scales,
             rows,
             row_count,
+            next_cursor,
         } => {
             // Preserve projection-local display contracts such as
             // `ROUND(..., scale)` before packaging the outward shell rows.
             let rows = sql_projection_output_rows(fixed_scales.as_slice(), rows);
-
-            SqlQueryResult::Projection(RowProjectionOutput {
+            let projection = RowProjectionOutput {
                 entity: entity_name,
                 columns,
                 rows,
                 row_count,
-            })
+            };
+
+            match next_cursor {
+                Some(next_cursor) => SqlQueryResult::ProjectionPage {
+                    projection,
+                    next_cursor,
+                },
+                None => SqlQueryResult::Projection(projection),
+            }
         }
+        SqlStatementResult::Upsert {
+            inserted_count,
+            updated_count,
+            columns,
+            fixed_scales,
+            rows,
+        } => sql_upsert_output(
+            entity_name,
+            inserted_count,
+            updated_count,
+            columns,
+            fixed_scales,
+            rows,
+        ),
         SqlStatementResult::Grouped {
             columns,
             fixed_scales,
@@ -77,28 +102,32 @@ pub(crate) fn sql_query_result_from_statement(
             SqlQueryResult::ShowStores { stores, verbose }
         }
         SqlStatementResult::ShowMemory(memory) => SqlQueryResult::ShowMemory { memory },
-        SqlStatementResult::Ddl(report) => SqlQueryResult::Ddl {
-            entity: entity_name,
-            mutation_kind: report.mutation_kind().as_str().to_string(),
-            target_index: report.target_index().to_string(),
-            target_store: report.target_store().to_string(),
-            field_path: report.field_path().to_vec(),
-            status: report.execution_status().as_str().to_string(),
-            rows_scanned: usize_to_u64_saturating(report.rows_scanned()),
-            index_keys_written: usize_to_u64_saturating(report.index_keys_written()),
-            constraint_validation: report.constraint_validation().map(|validation| {
-                SqlConstraintValidationOutput {
-                    constraint_id: validation.constraint_id(),
-                    activation_epoch: validation.activation_epoch(),
-                    page_sequence: validation.page_sequence(),
-                    state: validation.state().as_str().to_string(),
-                    revision_status: validation.revision_status().as_str().to_string(),
-                    rows_scanned: validation.rows_scanned(),
-                    findings: validation.findings().to_vec(),
-                    complete: validation.complete(),
-                }
-            }),
-        },
+        SqlStatementResult::Ddl(report) => sql_ddl_output(entity_name, &report),
+    }
+}
+
+fn sql_ddl_output(entity_name: String, report: &SqlDdlPreparationReport) -> SqlQueryResult {
+    SqlQueryResult::Ddl {
+        entity: entity_name,
+        mutation_kind: report.mutation_kind().as_str().to_string(),
+        target_index: report.target_index().to_string(),
+        target_store: report.target_store().to_string(),
+        field_path: report.field_path().to_vec(),
+        status: report.execution_status().as_str().to_string(),
+        rows_scanned: usize_to_u64_saturating(report.rows_scanned()),
+        index_keys_written: usize_to_u64_saturating(report.index_keys_written()),
+        constraint_validation: report.constraint_validation().map(|validation| {
+            SqlConstraintValidationOutput {
+                constraint_id: validation.constraint_id(),
+                activation_epoch: validation.activation_epoch(),
+                page_sequence: validation.page_sequence(),
+                state: validation.state().as_str().to_string(),
+                revision_status: validation.revision_status().as_str().to_string(),
+                rows_scanned: validation.rows_scanned(),
+                findings: validation.findings().to_vec(),
+                complete: validation.complete(),
+            }
+        }),
     }
 }
 
@@ -106,6 +135,33 @@ fn usize_to_u64_saturating(value: usize) -> u64 {
     u64::try_from(value).unwrap_or(u64::MAX)
 }
 
+fn sql_upsert_output(
+    entity_name: String,
+    inserted_count: u32,
+    updated_count: u32,
+    columns: Vec<String>,
+    fixed_scales: Vec<Option<u32>>,
+    rows: Option<Vec<Vec<OutputValue>>>,
+) -> SqlQueryResult {
+    let returning = rows.map(|rows| {
+        let row_count = u32::try_from(rows.len()).unwrap_or(u32::MAX);
+
+        RowProjectionOutput {
+            entity: entity_name.clone(),
+            columns,
+            rows: sql_projection_output_rows(fixed_scales.as_slice(), rows),
+            row_count,
+        }
+    });
+
+    SqlQueryResult::Upsert {
+        entity: entity_name,
+        inserted_count,
+        updated_count,
+        returning,
+    }
+}
+
 fn sql_grouped_rows_output(
     entity_name: String,
     columns: Vec<String>,
@@ -25,5 +25,6 @@ pub use table_render::{
     render_show_stores_lines, render_show_stores_verbose_lines,
 };
 pub use types::{
-    SqlConstraintValidationOutput, SqlGroupedRowsOutput, SqlQueryPerfResult, SqlQueryResult,
+    SqlConstraintValidationOutput, SqlGroupedRowsOutput, SqlQueryPerfResult, SqlQueryRequest,
+    SqlQueryResult,
 };
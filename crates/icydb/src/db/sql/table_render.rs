@@ -375,6 +375,40 @@ pub fn render_count_lines(entity: &str, row_count: u32) -> Vec<String> {
     )]
 }
 
+// Render one SQL upsert payload as its outcome counts followed by the
+// optional RETURNING table.
+#[must_use]
+pub(in crate::db::sql) fn render_upsert_lines(
+    entity: &str,
+    inserted_count: u32,
+    updated_count: u32,
+    returning: Option<&RowProjectionOutput>,
+) -> Vec<String> {
+    let mut lines = vec![format!(
+        "surface=upsert entity={entity} inserted_count={inserted_count} updated_count={updated_count}"
+    )];
+    if let Some(returning) = returning {
+        lines.extend(render_query_rows_lines(returning));
+    }
+
+    lines
+}
+
+#[cfg_attr(
+    doc,
+    doc = "Render one paged SQL projection payload with its continuation cursor."
+)]
+#[must_use]
+pub(in crate::db::sql) fn render_projection_page_lines(
+    projection: &RowProjectionOutput,
+    next_cursor: &str,
+) -> Vec<String> {
+    let mut lines = vec![format!("next_cursor={next_cursor}")];
+    lines.extend(render_query_rows_lines(projection));
+
+    lines
+}
+
 #[cfg_attr(doc, doc = "Render one SQL DDL payload into deterministic lines.")]
 #[must_use]
 pub(in crate::db::sql) fn render_sql_ddl_lines(input: SqlDdlRenderInput<'_>) -> Vec<String> {
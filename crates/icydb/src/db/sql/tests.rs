@@ -1077,6 +1077,37 @@ fn sql_query_result_from_statement_preserves_count_entity_and_row_count() {
     );
 }
 
+#[test]
+fn sql_query_result_from_statement_packages_upsert_counts_and_returning_rows() {
+    let result = sql_query_result_from_statement(
+        SqlStatementResult::Upsert {
+            inserted_count: 1,
+            updated_count: 1,
+            columns: vec!["name".to_string()],
+            fixed_scales: vec![None],
+            rows: Some(vec![vec![text("alice")], vec![text("bob")]]),
+        },
+        "User".to_string(),
+    );
+
+    let SqlQueryResult::Upsert {
+        entity,
+        inserted_count,
+        updated_count,
+        returning: Some(returning),
+    } = &result
+    else {
+        panic!("upsert packaging should keep RETURNING rows");
+    };
+    assert_eq!(entity, "User");
+    assert_eq!((*inserted_count, *updated_count), (1, 1));
+    assert_eq!(returning.row_count, 2);
+    assert_eq!(
+        result.render_lines().first(),
+        Some(&"surface=upsert entity=User inserted_count=1 updated_count=1".to_string()),
+    );
+}
+
 #[test]
 fn sql_query_result_from_statement_preserves_text_projection_values() {
     let result = sql_query_result_from_statement(
@@ -1085,6 +1116,7 @@ fn sql_query_result_from_statement_preserves_text_projection_values() {
             fixed_scales: vec![None],
             rows: vec![vec![text("alice")], vec![text("bob")]],
             row_count: 2,
+            next_cursor: None,
         },
         "User".to_string(),
     );
@@ -1109,6 +1141,7 @@ fn sql_query_result_from_statement_keeps_blob_projection_typed_until_rendering()
             fixed_scales: vec![None],
             rows: vec![vec![OutputValue::Blob(vec![0xab, 0xcd])]],
             row_count: 1,
+            next_cursor: None,
         },
         "Blob".to_string(),
     );
@@ -1184,6 +1217,7 @@ fn sql_query_result_from_statement_preserves_semantic_projection_value_variants(
                 OutputValue::Null,
             ]],
             row_count: 1,
+            next_cursor: None,
         },
         "Scalar".to_string(),
     );
@@ -1231,6 +1265,7 @@ fn sql_query_result_from_statement_preserves_scalar_arithmetic_and_round_project
                 ],
             ],
             row_count: 2,
+            next_cursor: None,
         },
         "User".to_string(),
     );
@@ -1264,6 +1299,7 @@ fn sql_query_result_from_statement_preserves_fixed_scale_for_zero_round_projecti
             fixed_scales: vec![Some(3)],
             rows: vec![vec![OutputValue::Decimal(Decimal::ZERO)]],
             row_count: 1,
+            next_cursor: None,
         },
         "User".to_string(),
     );
@@ -1290,6 +1326,7 @@ fn sql_query_result_from_statement_preserves_fixed_scale_for_aliased_round_proje
                 Decimal::from_i128(16).expect("16 decimal"),
             )]],
             row_count: 1,
+            next_cursor: None,
         },
         "User".to_string(),
     );
@@ -1378,6 +1415,39 @@ fn sql_query_result_from_statement_preserves_grouped_rows_and_cursor() {
     );
 }
 
+#[test]
+fn sql_query_result_from_statement_packages_paged_projection_with_cursor() {
+    let result = sql_query_result_from_statement(
+        SqlStatementResult::Projection {
+            columns: vec!["name".to_string()],
+            fixed_scales: vec![None],
+            rows: vec![vec![text("alice")], vec![text("bob")]],
+            row_count: 2,
+            next_cursor: Some("0a0b".to_string()),
+        },
+        "User".to_string(),
+    );
+
+    assert_eq!(
+        result,
+        SqlQueryResult::ProjectionPage {
+            projection: RowProjectionOutput {
+                entity: "User".to_string(),
+                columns: vec!["name".to_string()],
+                rows: vec![vec![text("alice")], vec![text("bob")]],
+                row_count: 2,
+            },
+            next_cursor: "0a0b".to_string(),
+        },
+        "public SQL packaging must keep the scalar continuation cursor beside the projection rows",
+    );
+    assert_eq!(
+        result.render_lines().first(),
+        Some(&"next_cursor=0a0b".to_string()),
+        "paged projection rendering should lead with the continuation cursor",
+    );
+}
+
 #[test]
 fn sql_query_result_renders_ddl_publication_payload() {
     let result = SqlQueryResult::Ddl {
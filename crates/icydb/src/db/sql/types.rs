@@ -13,12 +13,14 @@ use crate::{
         sql::table_render::{
             SqlDdlRenderInput, render_constraint_validation_finding_line, render_count_lines,
             render_describe_output_lines, render_explain_lines, render_grouped_lines,
-            render_query_rows_lines, render_show_columns_lines, render_show_constraints_lines,
-            render_show_entities_lines, render_show_entities_verbose_lines,
-            render_show_indexes_lines, render_show_memory_lines, render_show_relations_lines,
-            render_show_stores_lines, render_show_stores_verbose_lines, render_sql_ddl_lines,
+            render_projection_page_lines, render_query_rows_lines, render_show_columns_lines,
+            render_show_constraints_lines, render_show_entities_lines,
+            render_show_entities_verbose_lines, render_show_indexes_lines,
+            render_show_memory_lines, render_show_relations_lines, render_show_stores_lines,
+            render_show_stores_verbose_lines, render_sql_ddl_lines, render_upsert_lines,
         },
     },
+    value::InputValue,
 };
 
 use candid::CandidType;
@@ -60,6 +62,62 @@ pub struct SqlConstraintValidationOutput {
     pub complete: bool,
 }
 
+/// Stable request envelope accepted by the fixed administrative SQL query
+/// endpoint.
+#[derive(CandidType, Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+pub struct SqlQueryRequest {
+    /// Reduced SQL statement text, optionally using `$1..$n` placeholders.
+    pub sql: String,
+    /// Bind values in placeholder order: `params[0]` binds `$1`.
+    pub params: Vec<InputValue>,
+    /// Whether to return the first page of a resumable scalar `SELECT`.
+    /// Requests with a cursor page even when this flag is unset.
+    pub page: bool,
+    /// Continuation cursor returned by the previous page of this request.
+    pub cursor: Option<String>,
+}
+
+impl SqlQueryRequest {
+    /// Build one unpaged request for SQL text without bind values.
+    #[must_use]
+    pub fn new(sql: impl Into<String>) -> Self {
+        Self {
+            sql: sql.into(),
+            params: Vec::new(),
+            page: false,
+            cursor: None,
+        }
+    }
+
+    /// Attach the bind values for `$1..$n`.
+    #[must_use]
+    pub fn with_params(mut self, params: Vec<InputValue>) -> Self {
+        self.params = params;
+        self
+    }
+
+    /// Request the first resumable page instead of one unpaged result.
+    #[must_use]
+    pub const fn paged(mut self) -> Self {
+        self.page = true;
+        self
+    }
+
+    /// Attach the continuation cursor returned by the previous page.
+    #[must_use]
+    pub fn with_cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.page = true;
+        self.cursor = Some(cursor.into());
+        self
+    }
+
+    /// Return whether this request executes as one resumable page.
+    #[must_use]
+    pub const fn is_paged(&self) -> bool {
+        self.page || self.cursor.is_some()
+    }
+}
+
 /// Stable result envelope returned by the fixed administrative SQL query
 /// endpoint.
 #[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
@@ -146,6 +204,17 @@ pub enum SqlQueryResult {
         row_count: u32,
     },
     Projection(RowProjectionOutput),
+    /// One ordered scalar SQL page with an opaque continuation cursor.
+    ProjectionPage {
+        projection: RowProjectionOutput,
+        next_cursor: String,
+    },
+    Upsert {
+        entity: String,
+        inserted_count: u32,
+        updated_count: u32,
+        returning: Option<RowProjectionOutput>,
+    },
     Grouped(SqlGroupedRowsOutput),
     Explain {
         entity: String,
@@ -194,6 +263,21 @@ impl SqlQueryResult {
         match self {
             Self::Count { entity, row_count } => render_count_lines(entity.as_str(), *row_count),
             Self::Projection(rows) => render_query_rows_lines(rows),
+            Self::ProjectionPage {
+                projection,
+                next_cursor,
+            } => render_projection_page_lines(projection, next_cursor.as_str()),
+            Self::Upsert {
+                entity,
+                inserted_count,
+                updated_count,
+                returning,
+            } => render_upsert_lines(
+                entity.as_str(),
+                *inserted_count,
+                *updated_count,
+                returning.as_ref(),
+            ),
             Self::Grouped(rows) => render_grouped_lines(rows),
             Self::Explain { explain, .. } => render_explain_lines(explain.as_str()),
             Self::Describe(output) => render_describe_output_lines(output),
@@ -590,7 +590,7 @@ macro_rules! __icydb_endpoints_internal {
             "icydb_sql_query";
             #[$crate::__reexports::ic_cdk::query(name = "icydb_query")]
             fn __icydb_export_icydb_query(
-                sql: String,
+                request: __icydb_facade::db::sql::SqlQueryRequest,
             ) -> Result<__icydb_facade::db::sql::SqlQueryPerfResult, __icydb_facade::Error> {
                 let guard: $crate::ReadAuthorizationGuard = $guard;
                 $crate::__macro::authorize_sql_read(
@@ -599,7 +599,7 @@ macro_rules! __icydb_endpoints_internal {
                 )?;
                 $crate::__macro::with_query_metrics_context(|| {
                     $crate::db::with_request_execution(|| {
-                        crate::__icydb_generated::endpoint_handlers::sql_query::<false>(sql)
+                        crate::__icydb_generated::endpoint_handlers::sql_query::<false>(request)
                     })
                 })
             }
@@ -619,7 +619,7 @@ macro_rules! __icydb_endpoints_internal {
             "icydb_sql_query";
             #[$crate::__reexports::ic_cdk::query(name = "icydb_query")]
             fn __icydb_export_icydb_query(
-                sql: String,
+                request: __icydb_facade::db::sql::SqlQueryRequest,
             ) -> Result<__icydb_facade::db::sql::SqlQueryPerfResult, __icydb_facade::Error> {
                 let guard: $crate::ReadAuthorizationGuard = $guard;
                 $crate::__macro::authorize_sql_read(
@@ -628,7 +628,7 @@ macro_rules! __icydb_endpoints_internal {
                 )?;
                 $crate::__macro::with_query_metrics_context(|| {
                     $crate::db::with_request_execution(|| {
-                        crate::__icydb_generated::endpoint_handlers::sql_query::<true>(sql)
+                        crate::__icydb_generated::endpoint_handlers::sql_query::<true>(request)
                     })
                 })
             }
@@ -645,12 +645,12 @@ macro_rules! __icydb_endpoints_internal {
             "icydb_sql_query";
             #[$crate::__reexports::ic_cdk::query(name = "icydb_query")]
             fn __icydb_export_icydb_query(
-                sql: String,
+                request: __icydb_facade::db::sql::SqlQueryRequest,
             ) -> Result<__icydb_facade::db::sql::SqlQueryPerfResult, __icydb_facade::Error> {
                 crate::__icydb_generated::endpoint_authorization::require_sql_controller()?;
                 $crate::__macro::with_query_metrics_context(|| {
                     $crate::db::with_request_execution(|| {
-                        crate::__icydb_generated::endpoint_handlers::sql_query::<false>(sql)
+                        crate::__icydb_generated::endpoint_handlers::sql_query::<false>(request)
                     })
                 })
             }
@@ -667,12 +667,12 @@ macro_rules! __icydb_endpoints_internal {
             "icydb_sql_query";
             #[$crate::__reexports::ic_cdk::query(name = "icydb_query")]
             fn __icydb_export_icydb_query(
-                sql: String,
+                request: __icydb_facade::db::sql::SqlQueryRequest,
             ) -> Result<__icydb_facade::db::sql::SqlQueryPerfResult, __icydb_facade::Error> {
                 crate::__icydb_generated::endpoint_authorization::require_sql_controller()?;
                 $crate::__macro::with_query_metrics_context(|| {
                     $crate::db::with_request_execution(|| {
-                        crate::__icydb_generated::endpoint_handlers::sql_query::<true>(sql)
+                        crate::__icydb_generated::endpoint_handlers::sql_query::<true>(request)
                     })
                 })
             }
@@ -3,7 +3,7 @@ use icydb::{
         DbSession, DynamicQuery, TypedEntityAdapter,
         query::{Query, count},
     },
-    traits::CanisterKind,
+    traits::{CanisterKind, EntityKey},
 };
 
 #[allow(dead_code)]
@@ -66,5 +66,20 @@ where
         .execute_grouped();
 }
 
+#[allow(dead_code)]
+fn typed_relation_includes_compile_without_sql<C, E, T>(query: Query<'_, C, E>)
+where
+    C: CanisterKind,
+    E: TypedEntityAdapter,
+    T: EntityKey + TypedEntityAdapter,
+    T::Key: icydb_model::TypedOutputValue,
+    T::Row: Clone,
+{
+    let _ = query
+        .limit(25)
+        .include::<T>("author_id")
+        .execute_live_page(None);
+}
+
 #[test]
 fn public_query_facade_compile_contract() {}
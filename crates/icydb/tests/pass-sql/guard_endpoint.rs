@@ -7,7 +7,7 @@ mod __icydb_generated {
 
     pub(crate) mod endpoint_handlers {
         pub(crate) fn sql_query<const INTROSPECTION: bool>(
-            _: String,
+            _: icydb::db::sql::SqlQueryRequest,
         ) -> Result<icydb::db::sql::SqlQueryPerfResult, icydb::Error> {
             let _ = INTROSPECTION;
             unimplemented!()
@@ -319,6 +319,22 @@ pub struct SqlTestEnrollmentUserPrincipal {}
 )]
 pub struct SqlTestEnrollmentRobot {}
 
+/// Device whose optional owner drives typed relation-include proofs.
+#[entity(
+    store = "SqlTestStore",
+    version = 1,
+    pk(field = "id"),
+    fields(
+        field(name = "id", value(item(prim = "Nat64"))),
+        field(
+            name = "user_id",
+            value(opt, item(rel = "SqlTestEnrollmentUser", prim = "Ulid"))
+        ),
+        field(name = "label", value(item(prim = "Text", max_len = 64)))
+    )
+)]
+pub struct SqlTestEnrollmentDevice {}
+
 /// Reference-shaped accepted schema for typed rejected-field diagnostics.
 #[entity(
     store = "SqlTestStore",
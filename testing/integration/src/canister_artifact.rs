@@ -16,7 +16,7 @@ use icydb::{
     db::{
         EntitySchemaDescription, IntegrityCheckResult, SchemaMigrationCommand,
         SchemaMigrationStatusPage, SchemaMigrationStatusRequest, SqlIntegrityError, StorageReport,
-        sql::{SqlQueryPerfResult, SqlQueryResult},
+        sql::{SqlQueryPerfResult, SqlQueryRequest, SqlQueryResult},
     },
     metrics::{CompactMetricsReport, EventReport},
 };
@@ -488,7 +488,7 @@ pub fn inspect_candid_methods(candid: &str) -> Result<BTreeSet<CanisterMethod>,
     Err("unterminated Candid service declaration".to_string())
 }
 
-/// Render the normative 0.217 endpoint ABI foundation from maintained Rust
+/// Render the normative 0.237 endpoint ABI foundation from maintained Rust
 /// DTOs.
 ///
 /// All transitive records are derived from their maintained public Rust DTOs.
@@ -496,7 +496,7 @@ pub fn inspect_candid_methods(candid: &str) -> Result<BTreeSet<CanisterMethod>,
 pub fn render_endpoint_abi_foundation() -> String {
     let mut container = TypeContainer::new();
     let methods = vec![
-        endpoint_method::<String, Result<SqlQueryPerfResult, Error>>(
+        endpoint_method::<SqlQueryRequest, Result<SqlQueryPerfResult, Error>>(
             &mut container,
             "icydb_query",
             CanisterMethodMode::Query,
@@ -829,7 +829,7 @@ mod tests {
     fn endpoint_abi_foundation_matches_golden() {
         assert_eq!(
             render_endpoint_abi_foundation(),
-            include_str!("contracts/0.217/endpoint-abi-foundation.did")
+            include_str!("contracts/0.237/endpoint-abi-foundation.did")
         );
     }
 
@@ -27,6 +27,7 @@ pub const SQL_PERFORMANCE_BROAD_CONTRACT_FEATURES: &[&str] = &[
     "predicate.field_comparison",
     "predicate.grouped_where_field_comparison",
     "predicate.membership",
+    "predicate.non_prefix_pattern",
     "predicate.null",
     "predicate.prefix_pattern",
     "predicate.range",
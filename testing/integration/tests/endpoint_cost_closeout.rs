@@ -1,7 +1,10 @@
 use std::{env, fs, path::PathBuf};
 
 use ic_testkit::pic::StandaloneCanisterFixture;
-use icydb::{Error, db::sql::SqlQueryPerfResult};
+use icydb::{
+    Error,
+    db::sql::{SqlQueryPerfResult, SqlQueryRequest},
+};
 use icydb_testing_integration::install_prebuilt_fixture_canister;
 
 const SAMPLE_CALLS: u64 = 32;
@@ -44,14 +47,14 @@ fn measure_sql_queries(fixture: &StandaloneCanisterFixture) -> u64 {
     loaded.expect("fixture load should succeed");
 
     let warm: Result<SqlQueryPerfResult, Error> = fixture
-        .query_candid("icydb_query", (SQL.to_string(),))
+        .query_candid("icydb_query", (SqlQueryRequest::new(SQL),))
         .expect("warm SQL response should decode");
     warm.expect("warm SQL query should succeed");
 
     let mut instructions = 0_u64;
     for _ in 0..SAMPLE_CALLS {
         let result: Result<SqlQueryPerfResult, Error> = fixture
-            .query_candid("icydb_query", (SQL.to_string(),))
+            .query_candid("icydb_query", (SqlQueryRequest::new(SQL),))
             .expect("SQL response should decode");
         let result = result.expect("SQL query should succeed");
         instructions = instructions
@@ -1,5 +1,9 @@
 use ic_testkit::pic::StandaloneCanisterFixture;
-use icydb::{Error, db::sql::SqlQueryPerfResult, metrics::CompactMetricsReport};
+use icydb::{
+    Error,
+    db::sql::{SqlQueryPerfResult, SqlQueryRequest},
+    metrics::CompactMetricsReport,
+};
 use icydb_testing_integration::{install_fixture_canister, reset_icydb_fixtures};
 
 const QUERY_SQL: &str = "SELECT name FROM SqlTestUser ORDER BY age ASC LIMIT 2";
@@ -24,7 +28,7 @@ fn metrics_activity(fixture: &StandaloneCanisterFixture) -> u64 {
 
 fn call_generated_query(fixture: &StandaloneCanisterFixture) {
     let result: Result<SqlQueryPerfResult, Error> = fixture
-        .query_candid("icydb_query", (QUERY_SQL.to_string(),))
+        .query_candid("icydb_query", (SqlQueryRequest::new(QUERY_SQL),))
         .expect("generated SQL query response should decode");
     result.expect("generated SQL query should succeed");
 }
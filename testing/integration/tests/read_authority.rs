@@ -6,7 +6,7 @@ use icydb::{
     Error, ErrorCode,
     db::{
         EntitySchemaDescription,
-        sql::{SqlQueryPerfResult, SqlQueryResult},
+        sql::{SqlQueryPerfResult, SqlQueryRequest, SqlQueryResult},
     },
 };
 use icydb_testing_integration::{
@@ -32,7 +32,11 @@ fn query_sql(
     caller: Principal,
 ) -> Result<SqlQueryResult, Error> {
     let result: Result<SqlQueryPerfResult, Error> = fixture
-        .query_candid_as(caller, "icydb_query", ("SHOW ENTITIES".to_string(),))
+        .query_candid_as(
+            caller,
+            "icydb_query",
+            (SqlQueryRequest::new("SHOW ENTITIES"),),
+        )
         .expect("SQL response should decode");
     result.map(|response| response.result)
 }
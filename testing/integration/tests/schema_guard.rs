@@ -1,7 +1,10 @@
 use std::{collections::BTreeSet, fs, process::Command};
 
 use candid::{CandidType, Principal};
-use icydb::{Error, ErrorCode, db::EntitySchemaDescription};
+use icydb::{
+    Error, ErrorCode,
+    db::{EntitySchemaDescription, sql::SqlQueryRequest},
+};
 use icydb_testing_integration::{
     CanisterBuildOptions, CanisterBuildProfile, CanisterCandidExportMode, CanisterWasmProfile,
     build_canister_with_options, build_fixture_canister_wasm_bytes_with_options,
@@ -91,7 +94,7 @@ fn public_controller_and_guarded_schema_modes_preserve_distinct_authority() {
     let absent_sql = guarded.query_candid_as::<Result<(), Error>, _>(
         principal(42),
         "icydb_query",
-        ("SHOW ENTITIES".to_string(),),
+        (SqlQueryRequest::new("SHOW ENTITIES"),),
     );
     assert!(
         absent_sql.is_err(),
@@ -6,7 +6,7 @@ use icydb::{
     db::{
         SchemaMigrationCommand, SchemaMigrationPhase, SchemaMigrationStatusPage,
         SchemaMigrationStatusRequest,
-        sql::{SqlQueryPerfResult, SqlQueryResult},
+        sql::{SqlQueryPerfResult, SqlQueryRequest, SqlQueryResult},
     },
 };
 use icydb_testing_integration::install_prebuilt_fixture_canister;
@@ -168,7 +168,7 @@ fn advance(
 
 fn query_sql(fixture: &StandaloneCanisterFixture, sql: &str) -> Result<SqlQueryResult, Error> {
     let result: Result<SqlQueryPerfResult, Error> = fixture
-        .query_candid("icydb_query", (sql.to_string(),))
+        .query_candid("icydb_query", (SqlQueryRequest::new(sql),))
         .expect("SQL query response should decode");
     result.map(|response| response.result)
 }
@@ -30,7 +30,7 @@ use icydb::{
         EntitySchemaDescription, IntegrityCheckResult, QuickIntegrityStatus, RowProjectionOutput,
         SqlColumnDefault, SqlColumnExtra, SqlColumnKey, SqlDescribeOutput, SqlIntegrityError,
         SqlQueryExecutionAttribution, SqlShowColumnsOutput, StorageReport,
-        sql::{SqlGroupedRowsOutput, SqlQueryPerfResult, SqlQueryResult},
+        sql::{SqlGroupedRowsOutput, SqlQueryPerfResult, SqlQueryRequest, SqlQueryResult},
     },
     diagnostic::{DiagnosticCode, RuntimeBoundaryCode},
     metrics::CompactMetricsReport,
@@ -278,6 +278,31 @@ fn sql_canister_compact_introspection_is_shared_typed_and_bounded() {
     assert_eq!(compact_error.class(), verbose_error.class());
 }
 
+#[test]
+fn typed_relation_include_attaches_shared_targets_and_skips_null_keys() {
+    let fixture = install_sql_canister_fixture();
+    let rows: Result<Vec<(u64, String, Option<String>)>, Error> = fixture
+        .update_candid("verify_typed_relation_include", ())
+        .expect("typed relation include result should decode");
+
+    assert_eq!(
+        rows.expect("typed relation include endpoint should succeed"),
+        vec![
+            (
+                1,
+                "Owned Phone".to_string(),
+                Some("Device Owner".to_string()),
+            ),
+            (2, "Unassigned Tablet".to_string(), None),
+            (
+                3,
+                "Owned Laptop".to_string(),
+                Some("Device Owner".to_string()),
+            ),
+        ],
+    );
+}
+
 #[test]
 #[ignore = "release-closeout instruction probe over the exact prepared-commit work bound"]
 fn identity_closeout_reports_one_row_and_maximum_batch_instruction_costs() {
@@ -385,7 +410,7 @@ fn query_sql_with_perf(
     sql: &str,
 ) -> Result<SqlQueryPerfResult, Error> {
     fixture
-        .query_candid("icydb_query", (sql.to_string(),))
+        .query_candid("icydb_query", (SqlQueryRequest::new(sql),))
         .expect("sql query canister call should decode")
 }
 
@@ -3235,7 +3260,11 @@ fn source_declared_controller_endpoints_authorize_before_private_handlers() {
     );
 
     let query: Result<SqlQueryPerfResult, Error> = fixture
-        .query_candid_as(outsider, "icydb_query", ("not valid SQL".to_string(),))
+        .query_candid_as(
+            outsider,
+            "icydb_query",
+            (SqlQueryRequest::new("not valid SQL"),),
+        )
         .expect("non-controller SQL query response should decode");
     assert_eq!(query, Err(sql_error.clone()));
 
@@ -564,9 +564,9 @@ const PROVIDERS: &[ProviderSpec] = &[
         [Execute]
     ),
     provider!(
-        "core.query.simple_case_rejected",
+        "core.query.simple_case_desugared",
         "crates/icydb-core/src/db/sql/parser/tests/mod.rs",
-        "parse_select_statement_rejects_simple_case_expressions",
+        "parse_select_statement_desugars_simple_case_onto_searched_case",
         ContractAssertion,
         [Parse]
     ),
@@ -661,6 +661,20 @@ const PROVIDERS: &[ProviderSpec] = &[
         ContractAssertion,
         [Execute]
     ),
+    provider!(
+        "core.query.general_text_pattern",
+        "crates/icydb-core/src/db/session/tests/text_pattern.rs",
+        "like_matches_general_patterns_with_escape_and_casefold",
+        ContractAssertion,
+        [Execute]
+    ),
+    provider!(
+        "core.query.bounded_regex",
+        "crates/icydb-core/src/db/session/tests/text_pattern.rs",
+        "regex_operators_match_bounded_patterns",
+        ContractAssertion,
+        [Execute]
+    ),
     provider!(
         "core.query.starts_with",
         "crates/icydb-core/src/db/sql/parser/tests/mod.rs",
@@ -2083,13 +2097,13 @@ const MANIFEST: &[CoverageCell] = &[
     cell!(
         "expression.simple_case",
         Syntax,
-        Rejected,
+        Accepted,
         "Shared SQL Expression Family",
         Expression,
         REQ_PARSE,
         PERF_NONE,
-        ELIGIBLE_REJECTION,
-        ["core.query.simple_case_rejected"],
+        ELIGIBLE_FRONTEND,
+        ["core.query.simple_case_desugared"],
         NO_EXTERNAL_SYNTAX
     ),
     cell!(
@@ -2274,13 +2288,16 @@ const MANIFEST: &[CoverageCell] = &[
     cell!(
         "predicate.non_prefix_pattern",
         Semantic,
-        Rejected,
+        Accepted,
         "Predicates",
         Expression,
-        REQ_LOWER,
-        PERF_NONE,
-        ELIGIBLE_REJECTION,
-        ["core.query.unsupported_expressions"],
+        REQ_EXECUTE,
+        PERF_BROAD,
+        ELIGIBLE_FRONTEND,
+        [
+            "core.query.general_text_pattern",
+            "core.query.bounded_regex"
+        ],
         NO_EXTERNAL_TEXT
     ),
     cell!(
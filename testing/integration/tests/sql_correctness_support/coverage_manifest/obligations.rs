@@ -1722,7 +1722,7 @@ fn no_interaction_reason(feature_id: &str) -> Option<&'static str> {
 }
 
 const EXPECTED_MANIFEST_FEATURE_COUNT: usize = 106;
-const EXPECTED_DETERMINISTIC_PROVIDER_COUNT: usize = 98;
+const EXPECTED_DETERMINISTIC_PROVIDER_COUNT: usize = 100;
 
 #[derive(Debug, Serialize)]
 struct GroupProjection {
@@ -3,7 +3,10 @@ use std::{collections::BTreeSet, fs, process::Command};
 use candid::{CandidType, Principal};
 use icydb::{
     Error, ErrorCode,
-    db::{EntitySchemaDescription, sql::SqlQueryPerfResult},
+    db::{
+        EntitySchemaDescription,
+        sql::{SqlQueryPerfResult, SqlQueryRequest},
+    },
 };
 use icydb_testing_integration::{
     CanisterBuildOptions, CanisterBuildProfile, CanisterCandidExportMode, CanisterWasmProfile,
@@ -45,7 +48,7 @@ fn guarded_sql_authorizes_one_reader_and_preserves_the_read_only_lane() {
         "EXPLAIN SELECT id FROM OneSimpleEntity01 ORDER BY id ASC LIMIT 1",
     ] {
         let allowed: Result<SqlQueryPerfResult, Error> = fixture
-            .query_candid_as(reader, "icydb_query", (sql.to_string(),))
+            .query_candid_as(reader, "icydb_query", (SqlQueryRequest::new(sql),))
             .expect("guarded SQL success should decode");
         allowed.unwrap_or_else(|error| panic!("the SQL guard should admit {sql:?}: {error:?}"));
     }
@@ -61,7 +64,11 @@ fn guarded_sql_authorizes_one_reader_and_preserves_the_read_only_lane() {
     );
 
     let denied: Result<SqlQueryPerfResult, Error> = fixture
-        .query_candid_as(outsider, "icydb_query", ("not valid SQL".to_string(),))
+        .query_candid_as(
+            outsider,
+            "icydb_query",
+            (SqlQueryRequest::new("not valid SQL"),),
+        )
         .expect("guarded SQL denial should decode");
     assert_eq!(
         denied.expect_err("the outsider should be denied").code(),
@@ -69,7 +76,7 @@ fn guarded_sql_authorizes_one_reader_and_preserves_the_read_only_lane() {
     );
 
     let controller: Result<SqlQueryPerfResult, Error> = fixture
-        .query_candid("icydb_query", ("SHOW ENTITIES".to_string(),))
+        .query_candid("icydb_query", (SqlQueryRequest::new("SHOW ENTITIES"),))
         .expect("controller denial should decode");
     assert_eq!(
         controller
@@ -82,10 +89,9 @@ fn guarded_sql_authorizes_one_reader_and_preserves_the_read_only_lane() {
         .query_candid_as(
             reader,
             "icydb_query",
-            (
-                "INSERT INTO OneSimpleEntity01 (id) VALUES ('00000000000000000000000000')"
-                    .to_string(),
-            ),
+            (SqlQueryRequest::new(
+                "INSERT INTO OneSimpleEntity01 (id) VALUES ('00000000000000000000000000')",
+            ),),
         )
         .expect("authorized mutation rejection should decode");
     assert_eq!(
@@ -104,7 +110,7 @@ fn guarded_sql_rejects_anonymous_before_application_code_and_propagates_guard_tr
         .query_candid_as(
             Principal::anonymous(),
             "icydb_query",
-            ("SHOW ENTITIES".to_string(),),
+            (SqlQueryRequest::new("SHOW ENTITIES"),),
         )
         .expect("anonymous denial should decode instead of reaching the trapping guard");
     assert_eq!(
@@ -115,7 +121,7 @@ fn guarded_sql_rejects_anonymous_before_application_code_and_propagates_guard_tr
     let trapped = fixture.query_candid_as::<Result<SqlQueryPerfResult, Error>, _>(
         principal(44),
         "icydb_query",
-        ("SHOW ENTITIES".to_string(),),
+        (SqlQueryRequest::new("SHOW ENTITIES"),),
     );
     assert!(trapped.is_err(), "application guard traps must propagate");
 }
@@ -739,6 +739,11 @@ fn summarize_perf_outcome(result: &SqlQueryResult) -> SqlPerfOutcome {
             entity: rows.entity.clone(),
             row_count: usize::try_from(rows.row_count).unwrap_or(usize::MAX),
         },
+        SqlQueryResult::ProjectionPage { projection, .. } => SqlPerfOutcome {
+            result_kind: "projection_page",
+            entity: projection.entity.clone(),
+            row_count: usize::try_from(projection.row_count).unwrap_or(usize::MAX),
+        },
         SqlQueryResult::Grouped(rows) => SqlPerfOutcome {
             result_kind: "grouped",
             entity: rows.entity.clone(),
@@ -801,6 +806,17 @@ fn summarize_perf_outcome(result: &SqlQueryResult) -> SqlPerfOutcome {
             entity: String::new(),
             row_count: stores.len(),
         },
+        SqlQueryResult::Upsert {
+            entity,
+            inserted_count,
+            updated_count,
+            ..
+        } => SqlPerfOutcome {
+            result_kind: "upsert",
+            entity: entity.clone(),
+            row_count: usize::try_from(inserted_count.saturating_add(*updated_count))
+                .unwrap_or(usize::MAX),
+        },
         SqlQueryResult::ShowMemory { memory } => SqlPerfOutcome {
             result_kind: "show_memory",
             entity: String::new(),
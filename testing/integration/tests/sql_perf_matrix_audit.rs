@@ -1222,6 +1222,21 @@ fn performance_contract_sentinel_matrix() -> Vec<MatrixScenario> {
                 true,
             ),
         ),
+        scenario(
+            "user.contract.non_prefix_pattern",
+            MatrixSurface::User,
+            "contract.non_prefix_pattern",
+            "SELECT id, name FROM PerfAuditUser WHERE name LIKE 'A%e' ORDER BY name ASC, id ASC LIMIT 3",
+            read_metadata(
+                &["predicate.non_prefix_pattern"],
+                QueryShape::Scalar,
+                ValueTypeFamily::Mixed,
+                PredicateFamily::Compound,
+                WindowSpec::ordered(3, 0, "name ASC, id ASC"),
+                secondary_index_route(),
+                true,
+            ),
+        ),
         scenario(
             "user.contract.starts_with_expression_arguments",
             MatrixSurface::User,
@@ -2937,6 +2952,11 @@ fn summarize_perf_outcome(result: &SqlQueryResult) -> MatrixOutcome {
             entity: rows.entity.clone(),
             row_count: usize::try_from(rows.row_count).unwrap_or(usize::MAX),
         },
+        SqlQueryResult::ProjectionPage { projection, .. } => MatrixOutcome {
+            result_kind: "projection_page".to_string(),
+            entity: projection.entity.clone(),
+            row_count: usize::try_from(projection.row_count).unwrap_or(usize::MAX),
+        },
         SqlQueryResult::Grouped(rows) => MatrixOutcome {
             result_kind: "grouped".to_string(),
             entity: rows.entity.clone(),
@@ -2999,6 +3019,17 @@ fn summarize_perf_outcome(result: &SqlQueryResult) -> MatrixOutcome {
             entity: String::new(),
             row_count: stores.len(),
         },
+        SqlQueryResult::Upsert {
+            entity,
+            inserted_count,
+            updated_count,
+            ..
+        } => MatrixOutcome {
+            result_kind: "upsert".to_string(),
+            entity: entity.clone(),
+            row_count: usize::try_from(inserted_count.saturating_add(*updated_count))
+                .unwrap_or(usize::MAX),
+        },
         SqlQueryResult::ShowMemory { memory } => MatrixOutcome {
             result_kind: "show_memory".to_string(),
             entity: String::new(),
@@ -5636,8 +5667,8 @@ fn sql_perf_p1_receipts_cover_the_profile_exactly() {
         .collect::<Vec<_>>();
 
     assert_eq!(receipts.len(), 8);
-    assert_eq!(shard_counts, vec![235, 222, 236, 208, 222, 226, 232, 206]);
-    assert_eq!(shard_counts.iter().sum::<usize>(), 1_787);
+    assert_eq!(shard_counts, vec![235, 223, 236, 208, 222, 226, 232, 206]);
+    assert_eq!(shard_counts.iter().sum::<usize>(), 1_788);
     assert!(receipts.iter().all(|receipt| receipt.complete));
     validate_p1_shard_receipts(SQL_PERFORMANCE_PROFILE, &receipts)
         .expect("all deterministic receipts should merge");
@@ -5765,7 +5796,7 @@ fn sql_perf_p1_shard_artifacts_are_strict_bounded_and_merge_exactly() {
     )
     .expect("all exact shard reports should merge");
     assert_eq!(merged.receipts.len(), 8);
-    assert_eq!(merged.samples.len(), 1_787);
+    assert_eq!(merged.samples.len(), 1_788);
     assert!(merged.failures.is_empty());
     assert!(
         merged
@@ -1642,10 +1642,10 @@ mod tests {
 
         assert_eq!(selection, reversed);
         assert_eq!(selection.candidate_count, selection.candidates.len());
-        assert_eq!(selection.candidate_count, 76);
+        assert_eq!(selection.candidate_count, 77);
         assert_eq!(
             selection.p2_scenario_set_hash,
-            "9e44baa97e85e1b21d524a3c7dfe7d2536fa443a18f4e95252159d99e4c8f0c0"
+            "b8d7f4fd02c8d5c4616cd33da4f39e72c422d5cc329f565d5906deaf19344b90"
         );
         assert!(
             selection
@@ -21,9 +21,9 @@ pub(crate) const SQL_PERFORMANCE_PROFILE_VERSION: u32 = 1;
 /// Stable identity of the PocketIC 15 SQL performance authority.
 pub(crate) const SQL_PERFORMANCE_PROFILE_ID: &str = "icydb-sql-performance/0.237/v1";
 
-const EXPECTED_SCENARIO_COUNT: usize = 1_787;
+const EXPECTED_SCENARIO_COUNT: usize = 1_788;
 const EXPECTED_SCENARIO_SET_HASH: &str =
-    "a6823a84aa768257b2dc27d166e79c20260c5629eb33c70f590d308c64a1f80b";
+    "5f4ffada5edddda9c161db70c522c6a06924a3861419b35e5d01168f323e556a";
 const EXPECTED_SCALE_SCENARIO_COUNT: usize = 174;
 const EXPECTED_SCALE_SCENARIO_SET_HASH: &str =
     "760da64bc2ee52a23462c94eb4779de32988c92a690e03664368e071a4d3473e";
@@ -640,7 +640,7 @@ mod tests {
 
         assert_eq!(profile.version(), SQL_PERFORMANCE_PROFILE_VERSION);
         assert_eq!(profile.identity(), SQL_PERFORMANCE_PROFILE_ID);
-        assert_eq!(profile.expected_scenario_count(), 1_787);
+        assert_eq!(profile.expected_scenario_count(), 1_788);
         assert_eq!(
             profile.expected_scenario_set_hash(),
             EXPECTED_SCENARIO_SET_HASH
@@ -616,7 +616,13 @@ const fn result_rows(result: &SqlQueryResult) -> u32 {
     match result {
         SqlQueryResult::Count { row_count, .. } => *row_count,
         SqlQueryResult::Projection(rows) => rows.row_count,
+        SqlQueryResult::ProjectionPage { projection, .. } => projection.row_count,
         SqlQueryResult::Grouped(rows) => rows.row_count,
+        SqlQueryResult::Upsert {
+            inserted_count,
+            updated_count,
+            ..
+        } => inserted_count.saturating_add(*updated_count),
         SqlQueryResult::Explain { .. }
         | SqlQueryResult::Describe(_)
         | SqlQueryResult::ShowIndexes { .. }